@@ -0,0 +1,98 @@
+// Bifrost service worker.
+//
+// Caches the pages and static assets of the app shell as they are fetched so the app can be
+// opened without a connection. Pages and assets are always fetched from the network first
+// and only served from the cache when offline. API responses are never cached, so the client
+// shows an offline state instead of stale member data. Also shows Web Push notifications and
+// opens the page they link to when clicked.
+
+const CACHE_NAME = "bifrost-shell-v1";
+const SHELL_URL = "/";
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(CACHE_NAME)
+      .then((cache) => cache.add(SHELL_URL))
+      .then(() => self.skipWaiting()),
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys.filter((key) => key !== CACHE_NAME).map((key) => caches.delete(key)),
+        ),
+      )
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+
+  if (request.method !== "GET" || url.origin !== self.location.origin) {
+    return;
+  }
+
+  if (url.pathname.startsWith("/api/")) {
+    return;
+  }
+
+  event.respondWith(networkFirst(request));
+});
+
+async function networkFirst(request) {
+  const cache = await caches.open(CACHE_NAME);
+
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      cache.put(request, response.clone());
+    }
+    return response;
+  } catch (error) {
+    const cached = await cache.match(request);
+    if (cached) {
+      return cached;
+    }
+    if (request.mode === "navigate") {
+      const shell = await cache.match(SHELL_URL);
+      if (shell) {
+        return shell;
+      }
+    }
+    throw error;
+  }
+}
+
+self.addEventListener("push", (event) => {
+  const notification = event.data ? event.data.json() : {};
+
+  event.waitUntil(
+    self.registration.showNotification(notification.title || "Bifrost", {
+      body: notification.body,
+      icon: "/icon-512.png",
+      data: { url: notification.url || "/auth" },
+    }),
+  );
+});
+
+self.addEventListener("notificationclick", (event) => {
+  event.notification.close();
+  const url = new URL(event.notification.data.url, self.location.origin).href;
+
+  event.waitUntil(
+    self.clients.matchAll({ type: "window", includeUncontrolled: true }).then((windows) => {
+      const existing = windows.find((client) => client.url === url);
+      if (existing) {
+        return existing.focus();
+      }
+      return self.clients.openWindow(url);
+    }),
+  );
+});
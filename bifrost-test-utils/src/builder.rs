@@ -39,6 +39,7 @@ pub struct TestBuilder {
     character_affiliation_endpoints:
         Vec<(Vec<eve_esi::model::character::CharacterAffiliation>, usize)>,
     jwt_configs: Vec<(i64, String)>, // (character_id, owner_hash)
+    sso_logins: Vec<(i64, String)>,  // (character_id, owner_hash), in login order
     corporation_error_endpoints: Vec<(i64, usize, usize)>, // (corporation_id, status_code, expected_requests)
     corporation_not_modified_endpoints: Vec<(i64, usize)>, // (corporation_id, expected_requests)
     alliance_error_endpoints: Vec<(i64, usize, usize)>, // (alliance_id, status_code, expected_requests)
@@ -70,6 +71,7 @@ impl TestBuilder {
             character_endpoints: Vec::new(),
             character_affiliation_endpoints: Vec::new(),
             jwt_configs: Vec::new(),
+            sso_logins: Vec::new(),
             corporation_error_endpoints: Vec::new(),
             corporation_not_modified_endpoints: Vec::new(),
             alliance_error_endpoints: Vec::new(),
@@ -82,7 +84,9 @@ impl TestBuilder {
     /// Add standard user-related tables to the test database.
     ///
     /// Creates all tables required for user authentication and character management:
-    /// EveFaction, EveAlliance, EveCorporation, EveCharacter, BifrostUser, and BifrostUserCharacter.
+    /// EveFaction, EveAlliance, EveCorporation, EveCharacter, BifrostUser, BifrostUserCharacter,
+    /// BifrostUserCharacterSummary, BifrostRole, and BifrostUserRole. The built-in roles are not
+    /// seeded.
     ///
     /// # Arguments
     /// - `self` - The builder instance
@@ -483,6 +487,24 @@ impl TestBuilder {
         self
     }
 
+    /// Add a mocked EVE SSO login for a character.
+    ///
+    /// Unlike `with_jwt_endpoints`, the token endpoint of each login answers a single token
+    /// exchange, so several logins can be performed in one test. Logins are answered in the
+    /// order they were added, which lets end-to-end tests log in with one character and then
+    /// link or change to another.
+    ///
+    /// # Arguments
+    /// - `character_id` - Character ID to include in JWT claims
+    /// - `owner_hash` - Owner hash to include in JWT claims
+    ///
+    /// # Returns
+    /// - `Self` - The builder instance for method chaining
+    pub fn with_sso_login(mut self, character_id: i64, owner_hash: impl Into<String>) -> Self {
+        self.sso_logins.push((character_id, owner_hash.into()));
+        self
+    }
+
     /// Add a custom mock endpoint with full control.
     ///
     /// Allows complete customization of mock endpoint behavior by providing direct access
@@ -527,6 +549,9 @@ impl TestBuilder {
                 schema.create_table_from_entity(entity::prelude::EveCharacter),
                 schema.create_table_from_entity(entity::prelude::BifrostUser),
                 schema.create_table_from_entity(entity::prelude::BifrostUserCharacter),
+                schema.create_table_from_entity(entity::prelude::BifrostUserCharacterSummary),
+                schema.create_table_from_entity(entity::prelude::BifrostRole),
+                schema.create_table_from_entity(entity::prelude::BifrostUserRole),
             ]);
         }
 
@@ -616,6 +641,14 @@ impl TestBuilder {
             mocks.extend(setup.auth().create_jwt_endpoints(char_id, &owner_hash));
         }
 
+        if !self.sso_logins.is_empty() {
+            mocks.push(setup.auth().create_jwks_endpoint());
+        }
+
+        for (char_id, owner_hash) in self.sso_logins {
+            mocks.push(setup.auth().create_sso_token_endpoint(char_id, &owner_hash));
+        }
+
         for (corp_id, status_code, expected) in self.corporation_error_endpoints {
             mocks.push(setup.eve().create_corporation_endpoint_error(
                 corp_id,
@@ -0,0 +1,171 @@
+//! Failure injection for resilience tests.
+//!
+//! This module provides [`ChaosProxy`], a TCP proxy placed between the code under test and a
+//! backing service such as Redis, which can be switched mid-test to delay traffic or refuse
+//! connections. Database failures are injected through [`TestContext`] instead, see
+//! [`TestContext::set_database_read_only`].
+
+use std::{sync::Arc, time::Duration};
+
+use sea_orm::{ConnectionTrait, DbErr};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::watch,
+    task::JoinHandle,
+};
+
+use crate::TestContext;
+
+/// Behavior of a [`ChaosProxy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChaosMode {
+    /// Forward traffic unchanged.
+    Pass,
+    /// Forward traffic, delaying every chunk by the given duration.
+    Latency(Duration),
+    /// Close open connections and refuse new ones, as if the service went down.
+    Refuse,
+}
+
+/// TCP proxy that injects latency or outages between a client and a service.
+///
+/// The proxy listens on a random local port and forwards each connection to the target
+/// address. Switching to [`ChaosMode::Refuse`] closes every open connection, so clients see
+/// the same errors as when the service goes down.
+///
+/// # Example
+///
+/// ```ignore
+/// let proxy = ChaosProxy::start("127.0.0.1:6379").await?;
+/// let config = Config::from_url(&format!("redis://{}", proxy.addr()))?;
+///
+/// proxy.set_mode(ChaosMode::Refuse);
+/// // Redis commands now fail
+/// proxy.set_mode(ChaosMode::Pass);
+/// ```
+pub struct ChaosProxy {
+    addr: std::net::SocketAddr,
+    mode: Arc<watch::Sender<ChaosMode>>,
+    task: JoinHandle<()>,
+}
+
+impl ChaosProxy {
+    /// Start a proxy forwarding to the target address.
+    ///
+    /// # Arguments
+    /// - `target` - Address of the service to forward connections to, e.g. `127.0.0.1:6379`
+    ///
+    /// # Returns
+    /// - `Ok(ChaosProxy)` - Proxy listening on a random local port in [`ChaosMode::Pass`]
+    /// - `Err(std::io::Error)` - Failed to bind the local port
+    pub async fn start(target: impl Into<String>) -> Result<Self, std::io::Error> {
+        let target = target.into();
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let (mode, _) = watch::channel(ChaosMode::Pass);
+        let mode = Arc::new(mode);
+
+        let accept_mode = mode.clone();
+        let task = tokio::spawn(async move {
+            while let Ok((client, _)) = listener.accept().await {
+                if *accept_mode.borrow() == ChaosMode::Refuse {
+                    continue;
+                }
+
+                let target = target.clone();
+                let mode = accept_mode.subscribe();
+                tokio::spawn(async move {
+                    if let Ok(server) = TcpStream::connect(&target).await {
+                        forward(client, server, mode).await;
+                    }
+                });
+            }
+        });
+
+        Ok(Self { addr, mode, task })
+    }
+
+    /// Address clients should connect to instead of the target.
+    pub fn addr(&self) -> std::net::SocketAddr {
+        self.addr
+    }
+
+    /// Switch the proxy behavior, applying to open and new connections.
+    pub fn set_mode(&self, mode: ChaosMode) {
+        self.mode.send_replace(mode);
+    }
+}
+
+impl Drop for ChaosProxy {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Forward traffic in both directions until either side closes or the proxy refuses traffic.
+async fn forward(client: TcpStream, server: TcpStream, mode: watch::Receiver<ChaosMode>) {
+    let (client_read, client_write) = client.into_split();
+    let (server_read, server_write) = server.into_split();
+
+    tokio::select! {
+        _ = pipe(client_read, server_write, mode.clone()) => {}
+        _ = pipe(server_read, client_write, mode.clone()) => {}
+        _ = refused(mode) => {}
+    }
+}
+
+/// Copy data from one half of a connection to the other, applying the proxy latency.
+async fn pipe(
+    mut reader: tokio::net::tcp::OwnedReadHalf,
+    mut writer: tokio::net::tcp::OwnedWriteHalf,
+    mode: watch::Receiver<ChaosMode>,
+) {
+    let mut buffer = [0u8; 8192];
+
+    loop {
+        let read = match reader.read(&mut buffer).await {
+            Ok(0) | Err(_) => return,
+            Ok(read) => read,
+        };
+
+        let current = *mode.borrow();
+        if let ChaosMode::Latency(delay) = current {
+            tokio::time::sleep(delay).await;
+        }
+
+        if writer.write_all(&buffer[..read]).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Resolve once the proxy switches to [`ChaosMode::Refuse`].
+async fn refused(mut mode: watch::Receiver<ChaosMode>) {
+    let _ = mode.wait_for(|mode| *mode == ChaosMode::Refuse).await;
+}
+
+impl TestContext {
+    /// Make the test database reject or accept writes.
+    ///
+    /// While read-only, every insert, update and delete fails with a database error, which
+    /// simulates a database failing mid-test without losing the in-memory data.
+    ///
+    /// # Arguments
+    /// - `read_only` - Whether writes should fail
+    ///
+    /// # Returns
+    /// - `Ok(())` - Database mode changed
+    /// - `Err(DbErr)` - Failed to change the database mode
+    pub async fn set_database_read_only(&self, read_only: bool) -> Result<(), DbErr> {
+        let pragma = if read_only {
+            "PRAGMA query_only = ON"
+        } else {
+            "PRAGMA query_only = OFF"
+        };
+
+        self.db.execute_unprepared(pragma).await?;
+
+        Ok(())
+    }
+}
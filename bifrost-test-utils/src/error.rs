@@ -34,4 +34,10 @@ pub enum TestError {
     /// Occurs when Redis client operations fail during test setup.
     #[error(transparent)]
     FredError(#[from] fred::error::Error),
+
+    /// Error from I/O operations
+    ///
+    /// Occurs when a [`ChaosProxy`](crate::chaos::ChaosProxy) fails to bind its local port.
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
 }
@@ -101,6 +101,7 @@
 //! ```
 
 pub mod builder;
+pub mod chaos;
 pub mod constant;
 pub mod context;
 pub mod error;
@@ -136,7 +137,11 @@ pub mod user_factory {
 /// ```
 pub mod prelude {
     pub use crate::{
-        auth_factory, builder::TestBuilder, context::TestContext, error::TestError, factory,
-        user_factory,
+        auth_factory,
+        builder::TestBuilder,
+        chaos::{ChaosMode, ChaosProxy},
+        context::TestContext,
+        error::TestError,
+        factory, user_factory,
     };
 }
@@ -2,9 +2,52 @@
 
 pub mod prelude;
 
+pub mod bifrost_affiliation_history;
+pub mod bifrost_announcement;
+pub mod bifrost_announcement_recipient;
+pub mod bifrost_api_key;
+pub mod bifrost_approval_request;
+pub mod bifrost_campaign;
+pub mod bifrost_character_count_distribution;
+pub mod bifrost_character_token;
+pub mod bifrost_corporation_member_director;
+pub mod bifrost_corporation_user_count;
+pub mod bifrost_doctrine;
+pub mod bifrost_doctrine_fitting;
+pub mod bifrost_fitting;
+pub mod bifrost_fitting_skill;
+pub mod bifrost_group;
+pub mod bifrost_group_member;
+pub mod bifrost_group_rule;
+pub mod bifrost_member_filter;
+pub mod bifrost_note;
+pub mod bifrost_onboarding_completion;
+pub mod bifrost_onboarding_step;
+pub mod bifrost_page;
+pub mod bifrost_page_revision;
+pub mod bifrost_push_subscription;
+pub mod bifrost_reauth_campaign;
+pub mod bifrost_reauth_campaign_user;
+pub mod bifrost_recruitment_listing;
+pub mod bifrost_role;
+pub mod bifrost_saved_query;
+pub mod bifrost_screening_report;
+pub mod bifrost_skill_plan;
+pub mod bifrost_tag;
 pub mod bifrost_user;
+pub mod bifrost_user_ban;
 pub mod bifrost_user_character;
+pub mod bifrost_user_character_summary;
+pub mod bifrost_user_consent;
+pub mod bifrost_user_discord;
+pub mod bifrost_user_preference;
+pub mod bifrost_user_role;
+pub mod bifrost_webhook;
+pub mod bifrost_widget;
+pub mod bifrost_worker_job_history;
 pub mod eve_alliance;
 pub mod eve_character;
+pub mod eve_character_skill;
 pub mod eve_corporation;
+pub mod eve_corporation_member;
 pub mod eve_faction;
@@ -1,8 +1,51 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.19
 
+pub use super::bifrost_affiliation_history::Entity as BifrostAffiliationHistory;
+pub use super::bifrost_announcement::Entity as BifrostAnnouncement;
+pub use super::bifrost_announcement_recipient::Entity as BifrostAnnouncementRecipient;
+pub use super::bifrost_api_key::Entity as BifrostApiKey;
+pub use super::bifrost_approval_request::Entity as BifrostApprovalRequest;
+pub use super::bifrost_campaign::Entity as BifrostCampaign;
+pub use super::bifrost_character_count_distribution::Entity as BifrostCharacterCountDistribution;
+pub use super::bifrost_character_token::Entity as BifrostCharacterToken;
+pub use super::bifrost_corporation_member_director::Entity as BifrostCorporationMemberDirector;
+pub use super::bifrost_corporation_user_count::Entity as BifrostCorporationUserCount;
+pub use super::bifrost_doctrine::Entity as BifrostDoctrine;
+pub use super::bifrost_doctrine_fitting::Entity as BifrostDoctrineFitting;
+pub use super::bifrost_fitting::Entity as BifrostFitting;
+pub use super::bifrost_fitting_skill::Entity as BifrostFittingSkill;
+pub use super::bifrost_group::Entity as BifrostGroup;
+pub use super::bifrost_group_member::Entity as BifrostGroupMember;
+pub use super::bifrost_group_rule::Entity as BifrostGroupRule;
+pub use super::bifrost_member_filter::Entity as BifrostMemberFilter;
+pub use super::bifrost_note::Entity as BifrostNote;
+pub use super::bifrost_onboarding_completion::Entity as BifrostOnboardingCompletion;
+pub use super::bifrost_onboarding_step::Entity as BifrostOnboardingStep;
+pub use super::bifrost_page::Entity as BifrostPage;
+pub use super::bifrost_page_revision::Entity as BifrostPageRevision;
+pub use super::bifrost_push_subscription::Entity as BifrostPushSubscription;
+pub use super::bifrost_reauth_campaign::Entity as BifrostReauthCampaign;
+pub use super::bifrost_reauth_campaign_user::Entity as BifrostReauthCampaignUser;
+pub use super::bifrost_recruitment_listing::Entity as BifrostRecruitmentListing;
+pub use super::bifrost_role::Entity as BifrostRole;
+pub use super::bifrost_saved_query::Entity as BifrostSavedQuery;
+pub use super::bifrost_screening_report::Entity as BifrostScreeningReport;
+pub use super::bifrost_skill_plan::Entity as BifrostSkillPlan;
+pub use super::bifrost_tag::Entity as BifrostTag;
 pub use super::bifrost_user::Entity as BifrostUser;
+pub use super::bifrost_user_ban::Entity as BifrostUserBan;
 pub use super::bifrost_user_character::Entity as BifrostUserCharacter;
+pub use super::bifrost_user_character_summary::Entity as BifrostUserCharacterSummary;
+pub use super::bifrost_user_consent::Entity as BifrostUserConsent;
+pub use super::bifrost_user_discord::Entity as BifrostUserDiscord;
+pub use super::bifrost_user_preference::Entity as BifrostUserPreference;
+pub use super::bifrost_user_role::Entity as BifrostUserRole;
+pub use super::bifrost_webhook::Entity as BifrostWebhook;
+pub use super::bifrost_widget::Entity as BifrostWidget;
+pub use super::bifrost_worker_job_history::Entity as BifrostWorkerJobHistory;
 pub use super::eve_alliance::Entity as EveAlliance;
 pub use super::eve_character::Entity as EveCharacter;
+pub use super::eve_character_skill::Entity as EveCharacterSkill;
 pub use super::eve_corporation::Entity as EveCorporation;
+pub use super::eve_corporation_member::Entity as EveCorporationMember;
 pub use super::eve_faction::Entity as EveFaction;
@@ -6,6 +6,40 @@ mod m20251017_000003_create_eve_corporation_table;
 mod m20251017_000004_create_eve_character_table;
 mod m20251017_000005_create_bifrost_user_table;
 mod m20251017_000006_create_bifrost_user_character_table;
+mod m20261016_000001_create_bifrost_fitting_table;
+mod m20261016_000002_create_bifrost_doctrine_table;
+mod m20261016_000003_create_bifrost_doctrine_fitting_table;
+mod m20261016_000004_create_bifrost_recruitment_listing_table;
+mod m20261016_000005_create_bifrost_screening_report_table;
+mod m20261016_000006_create_bifrost_user_consent_table;
+mod m20261016_000007_create_bifrost_widget_table;
+mod m20261016_000008_create_bifrost_dashboard_summary_tables;
+mod m20261016_000009_create_bifrost_skill_plan_table;
+mod m20261016_000010_create_bifrost_campaign_table;
+mod m20261016_000011_create_bifrost_user_preference_table;
+mod m20261016_000012_create_bifrost_push_subscription_table;
+mod m20261016_000013_create_bifrost_user_character_summary_table;
+mod m20261016_000014_create_bifrost_page_tables;
+mod m20261016_000015_create_bifrost_announcement_tables;
+mod m20261016_000016_add_bifrost_user_preference_weekly_digest;
+mod m20261016_000017_create_bifrost_reauth_campaign_tables;
+mod m20261016_000018_create_bifrost_data_api_tables;
+mod m20261016_000019_create_bifrost_affiliation_history_table;
+mod m20261016_000020_create_bifrost_annotation_tables;
+mod m20261016_000021_create_bifrost_member_filter_table;
+mod m20261016_000022_create_bifrost_approval_request_table;
+mod m20261016_000023_create_bifrost_worker_job_history_table;
+mod m20261016_000024_create_bifrost_onboarding_tables;
+mod m20261016_000025_create_eve_corporation_member_tables;
+mod m20261016_000026_create_bifrost_character_token_table;
+mod m20261016_000027_create_eve_character_skill_table;
+mod m20261016_000028_create_bifrost_webhook_table;
+mod m20261016_000029_create_bifrost_user_discord_table;
+mod m20261016_000030_create_bifrost_role_tables;
+mod m20261016_000031_create_bifrost_user_ban_table;
+mod m20261016_000032_create_bifrost_group_tables;
+mod m20261017_000033_create_bifrost_group_rule_table;
+mod m20261017_000034_create_bifrost_fitting_skill_table;
 
 pub struct Migrator;
 
@@ -19,6 +53,40 @@ impl MigratorTrait for Migrator {
             Box::new(m20251017_000004_create_eve_character_table::Migration),
             Box::new(m20251017_000005_create_bifrost_user_table::Migration),
             Box::new(m20251017_000006_create_bifrost_user_character_table::Migration),
+            Box::new(m20261016_000001_create_bifrost_fitting_table::Migration),
+            Box::new(m20261016_000002_create_bifrost_doctrine_table::Migration),
+            Box::new(m20261016_000003_create_bifrost_doctrine_fitting_table::Migration),
+            Box::new(m20261016_000004_create_bifrost_recruitment_listing_table::Migration),
+            Box::new(m20261016_000005_create_bifrost_screening_report_table::Migration),
+            Box::new(m20261016_000006_create_bifrost_user_consent_table::Migration),
+            Box::new(m20261016_000007_create_bifrost_widget_table::Migration),
+            Box::new(m20261016_000008_create_bifrost_dashboard_summary_tables::Migration),
+            Box::new(m20261016_000009_create_bifrost_skill_plan_table::Migration),
+            Box::new(m20261016_000010_create_bifrost_campaign_table::Migration),
+            Box::new(m20261016_000011_create_bifrost_user_preference_table::Migration),
+            Box::new(m20261016_000012_create_bifrost_push_subscription_table::Migration),
+            Box::new(m20261016_000013_create_bifrost_user_character_summary_table::Migration),
+            Box::new(m20261016_000014_create_bifrost_page_tables::Migration),
+            Box::new(m20261016_000015_create_bifrost_announcement_tables::Migration),
+            Box::new(m20261016_000016_add_bifrost_user_preference_weekly_digest::Migration),
+            Box::new(m20261016_000017_create_bifrost_reauth_campaign_tables::Migration),
+            Box::new(m20261016_000018_create_bifrost_data_api_tables::Migration),
+            Box::new(m20261016_000019_create_bifrost_affiliation_history_table::Migration),
+            Box::new(m20261016_000020_create_bifrost_annotation_tables::Migration),
+            Box::new(m20261016_000021_create_bifrost_member_filter_table::Migration),
+            Box::new(m20261016_000022_create_bifrost_approval_request_table::Migration),
+            Box::new(m20261016_000023_create_bifrost_worker_job_history_table::Migration),
+            Box::new(m20261016_000024_create_bifrost_onboarding_tables::Migration),
+            Box::new(m20261016_000025_create_eve_corporation_member_tables::Migration),
+            Box::new(m20261016_000026_create_bifrost_character_token_table::Migration),
+            Box::new(m20261016_000027_create_eve_character_skill_table::Migration),
+            Box::new(m20261016_000028_create_bifrost_webhook_table::Migration),
+            Box::new(m20261016_000029_create_bifrost_user_discord_table::Migration),
+            Box::new(m20261016_000030_create_bifrost_role_tables::Migration),
+            Box::new(m20261016_000031_create_bifrost_user_ban_table::Migration),
+            Box::new(m20261016_000032_create_bifrost_group_tables::Migration),
+            Box::new(m20261017_000033_create_bifrost_group_rule_table::Migration),
+            Box::new(m20261017_000034_create_bifrost_fitting_skill_table::Migration),
         ]
     }
 }
@@ -110,7 +110,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum BifrostUserCharacter {
+pub enum BifrostUserCharacter {
     Table,
     Id,
     UserId,
@@ -0,0 +1,92 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_FITTING_SHIP_TYPE_NAME: &str = "idx_bifrost_fitting_ship_type_name";
+static FK_FITTING_CREATED_BY_USER_ID: &str = "fk_bifrost_fitting_created_by_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostFitting::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostFitting::Id))
+                    .col(string(BifrostFitting::Name))
+                    .col(string(BifrostFitting::ShipTypeName))
+                    .col(text(BifrostFitting::Eft))
+                    .col(integer(BifrostFitting::CreatedByUserId))
+                    .col(timestamp(BifrostFitting::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostFitting::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_FITTING_SHIP_TYPE_NAME)
+                    .table(BifrostFitting::Table)
+                    .col(BifrostFitting::ShipTypeName)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_FITTING_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostFitting::Table)
+                    .from_col(BifrostFitting::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_FITTING_CREATED_BY_USER_ID)
+                    .table(BifrostFitting::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_FITTING_SHIP_TYPE_NAME)
+                    .table(BifrostFitting::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostFitting::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostFitting {
+    Table,
+    Id,
+    Name,
+    ShipTypeName,
+    Eft,
+    CreatedByUserId,
+    CreatedAt,
+    UpdatedAt,
+}
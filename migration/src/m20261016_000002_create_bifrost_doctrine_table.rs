@@ -0,0 +1,43 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostDoctrine::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostDoctrine::Id))
+                    .col(string_uniq(BifrostDoctrine::Name))
+                    .col(text_null(BifrostDoctrine::Description))
+                    .col(timestamp(BifrostDoctrine::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostDoctrine::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BifrostDoctrine::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostDoctrine {
+    Table,
+    Id,
+    Name,
+    Description,
+    CreatedAt,
+    UpdatedAt,
+}
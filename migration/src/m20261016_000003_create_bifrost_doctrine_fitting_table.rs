@@ -0,0 +1,143 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20261016_000001_create_bifrost_fitting_table::BifrostFitting,
+    m20261016_000002_create_bifrost_doctrine_table::BifrostDoctrine,
+};
+
+static IDX_DOCTRINE_FITTING_DOCTRINE_ID_FITTING_ID: &str =
+    "idx_bifrost_doctrine_fitting_doctrine_id_fitting_id";
+static IDX_DOCTRINE_FITTING_FITTING_ID: &str = "idx_bifrost_doctrine_fitting_fitting_id";
+static FK_DOCTRINE_FITTING_DOCTRINE_ID: &str = "fk_bifrost_doctrine_fitting_doctrine_id";
+static FK_DOCTRINE_FITTING_FITTING_ID: &str = "fk_bifrost_doctrine_fitting_fitting_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostDoctrineFitting::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostDoctrineFitting::Id))
+                    .col(integer(BifrostDoctrineFitting::DoctrineId))
+                    .col(integer(BifrostDoctrineFitting::FittingId))
+                    .col(
+                        timestamp(BifrostDoctrineFitting::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_DOCTRINE_FITTING_DOCTRINE_ID_FITTING_ID)
+                    .table(BifrostDoctrineFitting::Table)
+                    .col(BifrostDoctrineFitting::DoctrineId)
+                    .col(BifrostDoctrineFitting::FittingId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_DOCTRINE_FITTING_FITTING_ID)
+                    .table(BifrostDoctrineFitting::Table)
+                    .col(BifrostDoctrineFitting::FittingId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_DOCTRINE_FITTING_DOCTRINE_ID)
+                    .from_tbl(BifrostDoctrineFitting::Table)
+                    .from_col(BifrostDoctrineFitting::DoctrineId)
+                    .to_tbl(BifrostDoctrine::Table)
+                    .to_col(BifrostDoctrine::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_DOCTRINE_FITTING_FITTING_ID)
+                    .from_tbl(BifrostDoctrineFitting::Table)
+                    .from_col(BifrostDoctrineFitting::FittingId)
+                    .to_tbl(BifrostFitting::Table)
+                    .to_col(BifrostFitting::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_DOCTRINE_FITTING_FITTING_ID)
+                    .table(BifrostDoctrineFitting::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_DOCTRINE_FITTING_DOCTRINE_ID)
+                    .table(BifrostDoctrineFitting::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_DOCTRINE_FITTING_FITTING_ID)
+                    .table(BifrostDoctrineFitting::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_DOCTRINE_FITTING_DOCTRINE_ID_FITTING_ID)
+                    .table(BifrostDoctrineFitting::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostDoctrineFitting::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostDoctrineFitting {
+    Table,
+    Id,
+    DoctrineId,
+    FittingId,
+    CreatedAt,
+}
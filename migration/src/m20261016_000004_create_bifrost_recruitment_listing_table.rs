@@ -0,0 +1,82 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000003_create_eve_corporation_table::EveCorporation;
+
+static FK_RECRUITMENT_LISTING_CORPORATION_ID: &str =
+    "fk_bifrost_recruitment_listing_corporation_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostRecruitmentListing::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostRecruitmentListing::Id))
+                    .col(integer_uniq(BifrostRecruitmentListing::CorporationId))
+                    .col(text(BifrostRecruitmentListing::Description))
+                    .col(string(BifrostRecruitmentListing::Timezone))
+                    .col(
+                        timestamp(BifrostRecruitmentListing::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp(BifrostRecruitmentListing::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_RECRUITMENT_LISTING_CORPORATION_ID)
+                    .from_tbl(BifrostRecruitmentListing::Table)
+                    .from_col(BifrostRecruitmentListing::CorporationId)
+                    .to_tbl(EveCorporation::Table)
+                    .to_col(EveCorporation::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_RECRUITMENT_LISTING_CORPORATION_ID)
+                    .table(BifrostRecruitmentListing::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostRecruitmentListing::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostRecruitmentListing {
+    Table,
+    Id,
+    CorporationId,
+    Description,
+    Timezone,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,122 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20251017_000004_create_eve_character_table::EveCharacter,
+    m20251017_000005_create_bifrost_user_table::BifrostUser,
+};
+
+static IDX_SCREENING_REPORT_CHARACTER_ID: &str = "idx_bifrost_screening_report_character_id";
+static FK_SCREENING_REPORT_CHARACTER_ID: &str = "fk_bifrost_screening_report_character_id";
+static FK_SCREENING_REPORT_REQUESTED_BY_USER_ID: &str =
+    "fk_bifrost_screening_report_requested_by_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostScreeningReport::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostScreeningReport::Id))
+                    .col(integer(BifrostScreeningReport::CharacterId))
+                    .col(integer(BifrostScreeningReport::RequestedByUserId))
+                    .col(text(BifrostScreeningReport::Report))
+                    .col(
+                        timestamp(BifrostScreeningReport::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_SCREENING_REPORT_CHARACTER_ID)
+                    .table(BifrostScreeningReport::Table)
+                    .col(BifrostScreeningReport::CharacterId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_SCREENING_REPORT_CHARACTER_ID)
+                    .from_tbl(BifrostScreeningReport::Table)
+                    .from_col(BifrostScreeningReport::CharacterId)
+                    .to_tbl(EveCharacter::Table)
+                    .to_col(EveCharacter::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_SCREENING_REPORT_REQUESTED_BY_USER_ID)
+                    .from_tbl(BifrostScreeningReport::Table)
+                    .from_col(BifrostScreeningReport::RequestedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_SCREENING_REPORT_REQUESTED_BY_USER_ID)
+                    .table(BifrostScreeningReport::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_SCREENING_REPORT_CHARACTER_ID)
+                    .table(BifrostScreeningReport::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_SCREENING_REPORT_CHARACTER_ID)
+                    .table(BifrostScreeningReport::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostScreeningReport::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostScreeningReport {
+    Table,
+    Id,
+    CharacterId,
+    RequestedByUserId,
+    Report,
+    CreatedAt,
+}
@@ -0,0 +1,91 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_USER_CONSENT_USER_ID_CATEGORY: &str = "idx_bifrost_user_consent_user_id_category";
+static FK_USER_CONSENT_USER_ID: &str = "fk_bifrost_user_consent_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostUserConsent::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostUserConsent::Id))
+                    .col(integer(BifrostUserConsent::UserId))
+                    .col(string(BifrostUserConsent::Category))
+                    .col(
+                        timestamp(BifrostUserConsent::GrantedAt).default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_USER_CONSENT_USER_ID_CATEGORY)
+                    .table(BifrostUserConsent::Table)
+                    .col(BifrostUserConsent::UserId)
+                    .col(BifrostUserConsent::Category)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_USER_CONSENT_USER_ID)
+                    .from_tbl(BifrostUserConsent::Table)
+                    .from_col(BifrostUserConsent::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_USER_CONSENT_USER_ID)
+                    .table(BifrostUserConsent::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_USER_CONSENT_USER_ID_CATEGORY)
+                    .table(BifrostUserConsent::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostUserConsent::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostUserConsent {
+    Table,
+    Id,
+    UserId,
+    Category,
+    GrantedAt,
+}
@@ -0,0 +1,97 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20251017_000003_create_eve_corporation_table::EveCorporation,
+    m20251017_000005_create_bifrost_user_table::BifrostUser,
+};
+
+static FK_WIDGET_USER_ID: &str = "fk_bifrost_widget_user_id";
+static FK_WIDGET_CORPORATION_ID: &str = "fk_bifrost_widget_corporation_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostWidget::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostWidget::Id))
+                    .col(integer(BifrostWidget::UserId))
+                    .col(integer(BifrostWidget::CorporationId))
+                    .col(string(BifrostWidget::Kind))
+                    .col(string_uniq(BifrostWidget::Token))
+                    .col(timestamp(BifrostWidget::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_WIDGET_USER_ID)
+                    .from_tbl(BifrostWidget::Table)
+                    .from_col(BifrostWidget::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_WIDGET_CORPORATION_ID)
+                    .from_tbl(BifrostWidget::Table)
+                    .from_col(BifrostWidget::CorporationId)
+                    .to_tbl(EveCorporation::Table)
+                    .to_col(EveCorporation::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_WIDGET_CORPORATION_ID)
+                    .table(BifrostWidget::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_WIDGET_USER_ID)
+                    .table(BifrostWidget::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostWidget::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostWidget {
+    Table,
+    Id,
+    UserId,
+    CorporationId,
+    Kind,
+    Token,
+    CreatedAt,
+}
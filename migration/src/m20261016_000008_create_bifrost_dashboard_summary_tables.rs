@@ -0,0 +1,104 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000003_create_eve_corporation_table::EveCorporation;
+
+static FK_CORPORATION_USER_COUNT_CORPORATION_ID: &str =
+    "fk_bifrost_corporation_user_count_corporation_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostCorporationUserCount::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostCorporationUserCount::Id))
+                    .col(integer_uniq(BifrostCorporationUserCount::CorporationId))
+                    .col(big_integer(BifrostCorporationUserCount::UserCount))
+                    .col(timestamp(BifrostCorporationUserCount::RefreshedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_CORPORATION_USER_COUNT_CORPORATION_ID)
+                    .from_tbl(BifrostCorporationUserCount::Table)
+                    .from_col(BifrostCorporationUserCount::CorporationId)
+                    .to_tbl(EveCorporation::Table)
+                    .to_col(EveCorporation::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostCharacterCountDistribution::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostCharacterCountDistribution::Id))
+                    .col(big_integer_uniq(
+                        BifrostCharacterCountDistribution::CharacterCount,
+                    ))
+                    .col(big_integer(BifrostCharacterCountDistribution::UserCount))
+                    .col(timestamp(BifrostCharacterCountDistribution::RefreshedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostCharacterCountDistribution::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_CORPORATION_USER_COUNT_CORPORATION_ID)
+                    .table(BifrostCorporationUserCount::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostCorporationUserCount::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostCorporationUserCount {
+    Table,
+    Id,
+    CorporationId,
+    UserCount,
+    RefreshedAt,
+}
+
+#[derive(DeriveIden)]
+enum BifrostCharacterCountDistribution {
+    Table,
+    Id,
+    CharacterCount,
+    UserCount,
+    RefreshedAt,
+}
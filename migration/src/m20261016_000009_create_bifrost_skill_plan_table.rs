@@ -0,0 +1,72 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static FK_SKILL_PLAN_CREATED_BY_USER_ID: &str = "fk_bifrost_skill_plan_created_by_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostSkillPlan::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostSkillPlan::Id))
+                    .col(string_uniq(BifrostSkillPlan::Name))
+                    .col(text_null(BifrostSkillPlan::Description))
+                    .col(text(BifrostSkillPlan::Plan))
+                    .col(integer(BifrostSkillPlan::CreatedByUserId))
+                    .col(timestamp(BifrostSkillPlan::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostSkillPlan::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_SKILL_PLAN_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostSkillPlan::Table)
+                    .from_col(BifrostSkillPlan::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_SKILL_PLAN_CREATED_BY_USER_ID)
+                    .table(BifrostSkillPlan::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostSkillPlan::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostSkillPlan {
+    Table,
+    Id,
+    Name,
+    Description,
+    Plan,
+    CreatedByUserId,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,74 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static FK_CAMPAIGN_CREATED_BY_USER_ID: &str = "fk_bifrost_campaign_created_by_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostCampaign::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostCampaign::Id))
+                    .col(string_uniq(BifrostCampaign::Name))
+                    .col(string(BifrostCampaign::StagingSystem))
+                    .col(timestamp(BifrostCampaign::StartsAt))
+                    .col(timestamp_null(BifrostCampaign::EndsAt))
+                    .col(integer(BifrostCampaign::CreatedByUserId))
+                    .col(timestamp(BifrostCampaign::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostCampaign::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_CAMPAIGN_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostCampaign::Table)
+                    .from_col(BifrostCampaign::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_CAMPAIGN_CREATED_BY_USER_ID)
+                    .table(BifrostCampaign::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostCampaign::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostCampaign {
+    Table,
+    Id,
+    Name,
+    StagingSystem,
+    StartsAt,
+    EndsAt,
+    CreatedByUserId,
+    CreatedAt,
+    UpdatedAt,
+}
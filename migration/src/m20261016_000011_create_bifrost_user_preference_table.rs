@@ -0,0 +1,71 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static FK_USER_PREFERENCE_USER_ID: &str = "fk_bifrost_user_preference_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostUserPreference::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostUserPreference::Id))
+                    .col(integer_uniq(BifrostUserPreference::UserId))
+                    .col(text(BifrostUserPreference::DashboardLayout))
+                    .col(
+                        timestamp(BifrostUserPreference::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_USER_PREFERENCE_USER_ID)
+                    .from_tbl(BifrostUserPreference::Table)
+                    .from_col(BifrostUserPreference::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_USER_PREFERENCE_USER_ID)
+                    .table(BifrostUserPreference::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostUserPreference::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostUserPreference {
+    Table,
+    Id,
+    UserId,
+    DashboardLayout,
+    UpdatedAt,
+    WeeklyDigest,
+}
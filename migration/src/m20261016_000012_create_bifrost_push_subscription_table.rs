@@ -0,0 +1,78 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static FK_PUSH_SUBSCRIPTION_USER_ID: &str = "fk_bifrost_push_subscription_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostPushSubscription::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostPushSubscription::Id))
+                    .col(integer(BifrostPushSubscription::UserId))
+                    .col(text_uniq(BifrostPushSubscription::Endpoint))
+                    .col(string(BifrostPushSubscription::P256dh))
+                    .col(string(BifrostPushSubscription::Auth))
+                    .col(
+                        timestamp(BifrostPushSubscription::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_PUSH_SUBSCRIPTION_USER_ID)
+                    .from_tbl(BifrostPushSubscription::Table)
+                    .from_col(BifrostPushSubscription::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_PUSH_SUBSCRIPTION_USER_ID)
+                    .table(BifrostPushSubscription::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostPushSubscription::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostPushSubscription {
+    Table,
+    Id,
+    UserId,
+    Endpoint,
+    P256dh,
+    Auth,
+    CreatedAt,
+}
@@ -0,0 +1,222 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20251017_000002_create_eve_alliance_table::EveAlliance,
+    m20251017_000003_create_eve_corporation_table::EveCorporation,
+    m20251017_000004_create_eve_character_table::EveCharacter,
+    m20251017_000005_create_bifrost_user_table::BifrostUser,
+    m20251017_000006_create_bifrost_user_character_table::BifrostUserCharacter,
+};
+
+static IDX_USER_CHARACTER_SUMMARY_USER_ID: &str = "idx_bifrost_user_character_summary_user_id";
+static IDX_USER_CHARACTER_SUMMARY_CORPORATION_ID: &str =
+    "idx_bifrost_user_character_summary_corporation_id";
+static IDX_USER_CHARACTER_SUMMARY_ALLIANCE_ID: &str =
+    "idx_bifrost_user_character_summary_alliance_id";
+static FK_USER_CHARACTER_SUMMARY_USER_ID: &str = "fk_bifrost_user_character_summary_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostUserCharacterSummary::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostUserCharacterSummary::Id))
+                    .col(integer(BifrostUserCharacterSummary::UserId))
+                    .col(boolean(BifrostUserCharacterSummary::IsMain))
+                    .col(big_integer_uniq(BifrostUserCharacterSummary::CharacterId))
+                    .col(string(BifrostUserCharacterSummary::CharacterName))
+                    .col(timestamp(
+                        BifrostUserCharacterSummary::CharacterInfoUpdatedAt,
+                    ))
+                    .col(timestamp(
+                        BifrostUserCharacterSummary::CharacterAffiliationUpdatedAt,
+                    ))
+                    .col(big_integer(BifrostUserCharacterSummary::CorporationId))
+                    .col(string(BifrostUserCharacterSummary::CorporationName))
+                    .col(timestamp(
+                        BifrostUserCharacterSummary::CorporationInfoUpdatedAt,
+                    ))
+                    .col(timestamp(
+                        BifrostUserCharacterSummary::CorporationAffiliationUpdatedAt,
+                    ))
+                    .col(big_integer_null(BifrostUserCharacterSummary::AllianceId))
+                    .col(string_null(BifrostUserCharacterSummary::AllianceName))
+                    .col(timestamp_null(
+                        BifrostUserCharacterSummary::AllianceUpdatedAt,
+                    ))
+                    .col(timestamp(BifrostUserCharacterSummary::RefreshedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        for (name, column) in [
+            (
+                IDX_USER_CHARACTER_SUMMARY_USER_ID,
+                BifrostUserCharacterSummary::UserId,
+            ),
+            (
+                IDX_USER_CHARACTER_SUMMARY_CORPORATION_ID,
+                BifrostUserCharacterSummary::CorporationId,
+            ),
+            (
+                IDX_USER_CHARACTER_SUMMARY_ALLIANCE_ID,
+                BifrostUserCharacterSummary::AllianceId,
+            ),
+        ] {
+            manager
+                .create_index(
+                    Index::create()
+                        .name(name)
+                        .table(BifrostUserCharacterSummary::Table)
+                        .col(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_USER_CHARACTER_SUMMARY_USER_ID)
+                    .from_tbl(BifrostUserCharacterSummary::Table)
+                    .from_col(BifrostUserCharacterSummary::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Backfill the summary for existing users, later changes keep it up to date
+        let backfill = Query::insert()
+            .into_table(BifrostUserCharacterSummary::Table)
+            .columns([
+                BifrostUserCharacterSummary::UserId,
+                BifrostUserCharacterSummary::IsMain,
+                BifrostUserCharacterSummary::CharacterId,
+                BifrostUserCharacterSummary::CharacterName,
+                BifrostUserCharacterSummary::CharacterInfoUpdatedAt,
+                BifrostUserCharacterSummary::CharacterAffiliationUpdatedAt,
+                BifrostUserCharacterSummary::CorporationId,
+                BifrostUserCharacterSummary::CorporationName,
+                BifrostUserCharacterSummary::CorporationInfoUpdatedAt,
+                BifrostUserCharacterSummary::CorporationAffiliationUpdatedAt,
+                BifrostUserCharacterSummary::AllianceId,
+                BifrostUserCharacterSummary::AllianceName,
+                BifrostUserCharacterSummary::AllianceUpdatedAt,
+                BifrostUserCharacterSummary::RefreshedAt,
+            ])
+            .select_from(
+                Query::select()
+                    .column((BifrostUserCharacter::Table, BifrostUserCharacter::UserId))
+                    .expr(
+                        Expr::col((BifrostUser::Table, BifrostUser::MainCharacterId))
+                            .equals((EveCharacter::Table, EveCharacter::Id)),
+                    )
+                    .column((EveCharacter::Table, EveCharacter::CharacterId))
+                    .column((EveCharacter::Table, EveCharacter::Name))
+                    .column((EveCharacter::Table, EveCharacter::InfoUpdatedAt))
+                    .column((EveCharacter::Table, EveCharacter::AffiliationUpdatedAt))
+                    .column((EveCorporation::Table, EveCorporation::CorporationId))
+                    .column((EveCorporation::Table, EveCorporation::Name))
+                    .column((EveCorporation::Table, EveCorporation::InfoUpdatedAt))
+                    .column((EveCorporation::Table, EveCorporation::AffiliationUpdatedAt))
+                    .column((EveAlliance::Table, EveAlliance::AllianceId))
+                    .column((EveAlliance::Table, EveAlliance::Name))
+                    .column((EveAlliance::Table, EveAlliance::UpdatedAt))
+                    .expr(Expr::current_timestamp())
+                    .from(BifrostUserCharacter::Table)
+                    .inner_join(
+                        BifrostUser::Table,
+                        Expr::col((BifrostUser::Table, BifrostUser::Id))
+                            .equals((BifrostUserCharacter::Table, BifrostUserCharacter::UserId)),
+                    )
+                    .inner_join(
+                        EveCharacter::Table,
+                        Expr::col((EveCharacter::Table, EveCharacter::Id)).equals((
+                            BifrostUserCharacter::Table,
+                            BifrostUserCharacter::CharacterId,
+                        )),
+                    )
+                    .inner_join(
+                        EveCorporation::Table,
+                        Expr::col((EveCorporation::Table, EveCorporation::Id))
+                            .equals((EveCharacter::Table, EveCharacter::CorporationId)),
+                    )
+                    .left_join(
+                        EveAlliance::Table,
+                        Expr::col((EveAlliance::Table, EveAlliance::Id))
+                            .equals((EveCorporation::Table, EveCorporation::AllianceId)),
+                    )
+                    .to_owned(),
+            )
+            .map_err(|e| DbErr::Migration(e.to_string()))?
+            .to_owned();
+
+        manager.get_connection().execute(&backfill).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_USER_CHARACTER_SUMMARY_USER_ID)
+                    .table(BifrostUserCharacterSummary::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        for name in [
+            IDX_USER_CHARACTER_SUMMARY_ALLIANCE_ID,
+            IDX_USER_CHARACTER_SUMMARY_CORPORATION_ID,
+            IDX_USER_CHARACTER_SUMMARY_USER_ID,
+        ] {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(name)
+                        .table(BifrostUserCharacterSummary::Table)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostUserCharacterSummary::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostUserCharacterSummary {
+    Table,
+    Id,
+    UserId,
+    IsMain,
+    CharacterId,
+    CharacterName,
+    CharacterInfoUpdatedAt,
+    CharacterAffiliationUpdatedAt,
+    CorporationId,
+    CorporationName,
+    CorporationInfoUpdatedAt,
+    CorporationAffiliationUpdatedAt,
+    AllianceId,
+    AllianceName,
+    AllianceUpdatedAt,
+    RefreshedAt,
+}
@@ -0,0 +1,126 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static FK_PAGE_REVISION_PAGE_ID: &str = "fk_bifrost_page_revision_page_id";
+static FK_PAGE_REVISION_EDITED_BY_USER_ID: &str = "fk_bifrost_page_revision_edited_by_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostPage::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostPage::Id))
+                    .col(string_uniq(BifrostPage::Slug))
+                    .col(string(BifrostPage::Title))
+                    .col(text(BifrostPage::Content))
+                    .col(timestamp(BifrostPage::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostPage::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostPageRevision::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostPageRevision::Id))
+                    .col(integer(BifrostPageRevision::PageId))
+                    .col(string(BifrostPageRevision::Title))
+                    .col(text(BifrostPageRevision::Content))
+                    .col(integer(BifrostPageRevision::EditedByUserId))
+                    .col(
+                        timestamp(BifrostPageRevision::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_PAGE_REVISION_PAGE_ID)
+                    .from_tbl(BifrostPageRevision::Table)
+                    .from_col(BifrostPageRevision::PageId)
+                    .to_tbl(BifrostPage::Table)
+                    .to_col(BifrostPage::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_PAGE_REVISION_EDITED_BY_USER_ID)
+                    .from_tbl(BifrostPageRevision::Table)
+                    .from_col(BifrostPageRevision::EditedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_PAGE_REVISION_EDITED_BY_USER_ID)
+                    .table(BifrostPageRevision::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_PAGE_REVISION_PAGE_ID)
+                    .table(BifrostPageRevision::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostPageRevision::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostPage::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostPage {
+    Table,
+    Id,
+    Slug,
+    Title,
+    Content,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BifrostPageRevision {
+    Table,
+    Id,
+    PageId,
+    Title,
+    Content,
+    EditedByUserId,
+    CreatedAt,
+}
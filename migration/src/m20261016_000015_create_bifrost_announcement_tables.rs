@@ -0,0 +1,175 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_ANNOUNCEMENT_RECIPIENT_ANNOUNCEMENT_ID_USER_ID: &str =
+    "idx_bifrost_announcement_recipient_announcement_id_user_id";
+static FK_ANNOUNCEMENT_CREATED_BY_USER_ID: &str = "fk_bifrost_announcement_created_by_user_id";
+static FK_ANNOUNCEMENT_RECIPIENT_ANNOUNCEMENT_ID: &str =
+    "fk_bifrost_announcement_recipient_announcement_id";
+static FK_ANNOUNCEMENT_RECIPIENT_USER_ID: &str = "fk_bifrost_announcement_recipient_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostAnnouncement::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostAnnouncement::Id))
+                    .col(string(BifrostAnnouncement::Title))
+                    .col(text(BifrostAnnouncement::Body))
+                    .col(string(BifrostAnnouncement::Audience))
+                    .col(big_integer_null(BifrostAnnouncement::AudienceId))
+                    .col(integer(BifrostAnnouncement::CreatedByUserId))
+                    .col(
+                        timestamp(BifrostAnnouncement::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostAnnouncementRecipient::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostAnnouncementRecipient::Id))
+                    .col(integer(BifrostAnnouncementRecipient::AnnouncementId))
+                    .col(integer(BifrostAnnouncementRecipient::UserId))
+                    .col(timestamp_null(BifrostAnnouncementRecipient::ReadAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_ANNOUNCEMENT_RECIPIENT_ANNOUNCEMENT_ID_USER_ID)
+                    .table(BifrostAnnouncementRecipient::Table)
+                    .col(BifrostAnnouncementRecipient::AnnouncementId)
+                    .col(BifrostAnnouncementRecipient::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_ANNOUNCEMENT_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostAnnouncement::Table)
+                    .from_col(BifrostAnnouncement::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_ANNOUNCEMENT_RECIPIENT_ANNOUNCEMENT_ID)
+                    .from_tbl(BifrostAnnouncementRecipient::Table)
+                    .from_col(BifrostAnnouncementRecipient::AnnouncementId)
+                    .to_tbl(BifrostAnnouncement::Table)
+                    .to_col(BifrostAnnouncement::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_ANNOUNCEMENT_RECIPIENT_USER_ID)
+                    .from_tbl(BifrostAnnouncementRecipient::Table)
+                    .from_col(BifrostAnnouncementRecipient::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_ANNOUNCEMENT_RECIPIENT_USER_ID)
+                    .table(BifrostAnnouncementRecipient::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_ANNOUNCEMENT_RECIPIENT_ANNOUNCEMENT_ID)
+                    .table(BifrostAnnouncementRecipient::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_ANNOUNCEMENT_CREATED_BY_USER_ID)
+                    .table(BifrostAnnouncement::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_ANNOUNCEMENT_RECIPIENT_ANNOUNCEMENT_ID_USER_ID)
+                    .table(BifrostAnnouncementRecipient::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostAnnouncementRecipient::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostAnnouncement::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostAnnouncement {
+    Table,
+    Id,
+    Title,
+    Body,
+    Audience,
+    AudienceId,
+    CreatedByUserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BifrostAnnouncementRecipient {
+    Table,
+    Id,
+    AnnouncementId,
+    UserId,
+    ReadAt,
+}
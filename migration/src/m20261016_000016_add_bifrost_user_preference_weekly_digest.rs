@@ -0,0 +1,35 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261016_000011_create_bifrost_user_preference_table::BifrostUserPreference;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BifrostUserPreference::Table)
+                    .add_column(boolean(BifrostUserPreference::WeeklyDigest).default(true))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(BifrostUserPreference::Table)
+                    .drop_column(BifrostUserPreference::WeeklyDigest)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
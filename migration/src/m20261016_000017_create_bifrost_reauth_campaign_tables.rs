@@ -0,0 +1,177 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_REAUTH_CAMPAIGN_USER_CAMPAIGN_ID_USER_ID: &str =
+    "idx_bifrost_reauth_campaign_user_campaign_id_user_id";
+static FK_REAUTH_CAMPAIGN_CREATED_BY_USER_ID: &str =
+    "fk_bifrost_reauth_campaign_created_by_user_id";
+static FK_REAUTH_CAMPAIGN_USER_CAMPAIGN_ID: &str = "fk_bifrost_reauth_campaign_user_campaign_id";
+static FK_REAUTH_CAMPAIGN_USER_USER_ID: &str = "fk_bifrost_reauth_campaign_user_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostReauthCampaign::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostReauthCampaign::Id))
+                    .col(string(BifrostReauthCampaign::Name))
+                    .col(text(BifrostReauthCampaign::Scopes))
+                    .col(string(BifrostReauthCampaign::Audience))
+                    .col(big_integer_null(BifrostReauthCampaign::AudienceId))
+                    .col(timestamp_null(BifrostReauthCampaign::Deadline))
+                    .col(integer(BifrostReauthCampaign::CreatedByUserId))
+                    .col(
+                        timestamp(BifrostReauthCampaign::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostReauthCampaignUser::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostReauthCampaignUser::Id))
+                    .col(integer(BifrostReauthCampaignUser::CampaignId))
+                    .col(integer(BifrostReauthCampaignUser::UserId))
+                    .col(timestamp_null(BifrostReauthCampaignUser::CompletedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_REAUTH_CAMPAIGN_USER_CAMPAIGN_ID_USER_ID)
+                    .table(BifrostReauthCampaignUser::Table)
+                    .col(BifrostReauthCampaignUser::CampaignId)
+                    .col(BifrostReauthCampaignUser::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_REAUTH_CAMPAIGN_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostReauthCampaign::Table)
+                    .from_col(BifrostReauthCampaign::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_REAUTH_CAMPAIGN_USER_CAMPAIGN_ID)
+                    .from_tbl(BifrostReauthCampaignUser::Table)
+                    .from_col(BifrostReauthCampaignUser::CampaignId)
+                    .to_tbl(BifrostReauthCampaign::Table)
+                    .to_col(BifrostReauthCampaign::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_REAUTH_CAMPAIGN_USER_USER_ID)
+                    .from_tbl(BifrostReauthCampaignUser::Table)
+                    .from_col(BifrostReauthCampaignUser::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_REAUTH_CAMPAIGN_USER_USER_ID)
+                    .table(BifrostReauthCampaignUser::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_REAUTH_CAMPAIGN_USER_CAMPAIGN_ID)
+                    .table(BifrostReauthCampaignUser::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_REAUTH_CAMPAIGN_CREATED_BY_USER_ID)
+                    .table(BifrostReauthCampaign::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_REAUTH_CAMPAIGN_USER_CAMPAIGN_ID_USER_ID)
+                    .table(BifrostReauthCampaignUser::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostReauthCampaignUser::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostReauthCampaign::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostReauthCampaign {
+    Table,
+    Id,
+    Name,
+    Scopes,
+    Audience,
+    AudienceId,
+    Deadline,
+    CreatedByUserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BifrostReauthCampaignUser {
+    Table,
+    Id,
+    CampaignId,
+    UserId,
+    CompletedAt,
+}
@@ -0,0 +1,128 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static FK_SAVED_QUERY_CREATED_BY_USER_ID: &str = "fk_bifrost_saved_query_created_by_user_id";
+static FK_API_KEY_CREATED_BY_USER_ID: &str = "fk_bifrost_api_key_created_by_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostSavedQuery::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostSavedQuery::Id))
+                    .col(string_uniq(BifrostSavedQuery::Slug))
+                    .col(string(BifrostSavedQuery::Name))
+                    .col(text(BifrostSavedQuery::Sql))
+                    .col(text(BifrostSavedQuery::Parameters))
+                    .col(integer(BifrostSavedQuery::RowLimit))
+                    .col(integer(BifrostSavedQuery::CreatedByUserId))
+                    .col(timestamp(BifrostSavedQuery::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostSavedQuery::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostApiKey::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostApiKey::Id))
+                    .col(string(BifrostApiKey::Name))
+                    .col(string_uniq(BifrostApiKey::KeyHash))
+                    .col(integer(BifrostApiKey::CreatedByUserId))
+                    .col(timestamp(BifrostApiKey::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp_null(BifrostApiKey::LastUsedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_SAVED_QUERY_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostSavedQuery::Table)
+                    .from_col(BifrostSavedQuery::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_API_KEY_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostApiKey::Table)
+                    .from_col(BifrostApiKey::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_API_KEY_CREATED_BY_USER_ID)
+                    .table(BifrostApiKey::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_SAVED_QUERY_CREATED_BY_USER_ID)
+                    .table(BifrostSavedQuery::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostApiKey::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostSavedQuery::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostSavedQuery {
+    Table,
+    Id,
+    Slug,
+    Name,
+    Sql,
+    Parameters,
+    RowLimit,
+    CreatedByUserId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BifrostApiKey {
+    Table,
+    Id,
+    Name,
+    KeyHash,
+    CreatedByUserId,
+    CreatedAt,
+    LastUsedAt,
+}
@@ -0,0 +1,144 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20251017_000002_create_eve_alliance_table::EveAlliance,
+    m20251017_000003_create_eve_corporation_table::EveCorporation,
+    m20251017_000004_create_eve_character_table::EveCharacter,
+};
+
+static IDX_AFFILIATION_HISTORY_CHARACTER_ID: &str = "idx_bifrost_affiliation_history_character_id";
+static FK_AFFILIATION_HISTORY_CHARACTER_ID: &str = "fk_bifrost_affiliation_history_character_id";
+static FK_AFFILIATION_HISTORY_CORPORATION_ID: &str =
+    "fk_bifrost_affiliation_history_corporation_id";
+static FK_AFFILIATION_HISTORY_ALLIANCE_ID: &str = "fk_bifrost_affiliation_history_alliance_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostAffiliationHistory::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostAffiliationHistory::Id))
+                    .col(integer(BifrostAffiliationHistory::CharacterId))
+                    .col(integer(BifrostAffiliationHistory::CorporationId))
+                    .col(integer_null(BifrostAffiliationHistory::AllianceId))
+                    .col(timestamp(BifrostAffiliationHistory::StartedAt))
+                    .col(timestamp_null(BifrostAffiliationHistory::EndedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_AFFILIATION_HISTORY_CHARACTER_ID)
+                    .table(BifrostAffiliationHistory::Table)
+                    .col(BifrostAffiliationHistory::CharacterId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_AFFILIATION_HISTORY_CHARACTER_ID)
+                    .from_tbl(BifrostAffiliationHistory::Table)
+                    .from_col(BifrostAffiliationHistory::CharacterId)
+                    .to_tbl(EveCharacter::Table)
+                    .to_col(EveCharacter::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_AFFILIATION_HISTORY_CORPORATION_ID)
+                    .from_tbl(BifrostAffiliationHistory::Table)
+                    .from_col(BifrostAffiliationHistory::CorporationId)
+                    .to_tbl(EveCorporation::Table)
+                    .to_col(EveCorporation::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_AFFILIATION_HISTORY_ALLIANCE_ID)
+                    .from_tbl(BifrostAffiliationHistory::Table)
+                    .from_col(BifrostAffiliationHistory::AllianceId)
+                    .to_tbl(EveAlliance::Table)
+                    .to_col(EveAlliance::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_AFFILIATION_HISTORY_ALLIANCE_ID)
+                    .table(BifrostAffiliationHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_AFFILIATION_HISTORY_CORPORATION_ID)
+                    .table(BifrostAffiliationHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_AFFILIATION_HISTORY_CHARACTER_ID)
+                    .table(BifrostAffiliationHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_AFFILIATION_HISTORY_CHARACTER_ID)
+                    .table(BifrostAffiliationHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostAffiliationHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostAffiliationHistory {
+    Table,
+    Id,
+    CharacterId,
+    CorporationId,
+    AllianceId,
+    StartedAt,
+    EndedAt,
+}
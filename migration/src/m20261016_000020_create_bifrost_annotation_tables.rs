@@ -0,0 +1,168 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_TAG_SUBJECT_TYPE_SUBJECT_ID_NAME: &str = "idx_bifrost_tag_subject_type_subject_id_name";
+static IDX_NOTE_SUBJECT_TYPE_SUBJECT_ID: &str = "idx_bifrost_note_subject_type_subject_id";
+static FK_TAG_CREATED_BY_USER_ID: &str = "fk_bifrost_tag_created_by_user_id";
+static FK_NOTE_CREATED_BY_USER_ID: &str = "fk_bifrost_note_created_by_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostTag::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostTag::Id))
+                    .col(string(BifrostTag::SubjectType))
+                    .col(big_integer(BifrostTag::SubjectId))
+                    .col(string(BifrostTag::Name))
+                    .col(integer(BifrostTag::CreatedByUserId))
+                    .col(timestamp(BifrostTag::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostNote::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostNote::Id))
+                    .col(string(BifrostNote::SubjectType))
+                    .col(big_integer(BifrostNote::SubjectId))
+                    .col(text(BifrostNote::Body))
+                    .col(string(BifrostNote::Visibility))
+                    .col(integer(BifrostNote::CreatedByUserId))
+                    .col(timestamp(BifrostNote::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_TAG_SUBJECT_TYPE_SUBJECT_ID_NAME)
+                    .table(BifrostTag::Table)
+                    .col(BifrostTag::SubjectType)
+                    .col(BifrostTag::SubjectId)
+                    .col(BifrostTag::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_NOTE_SUBJECT_TYPE_SUBJECT_ID)
+                    .table(BifrostNote::Table)
+                    .col(BifrostNote::SubjectType)
+                    .col(BifrostNote::SubjectId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_TAG_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostTag::Table)
+                    .from_col(BifrostTag::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_NOTE_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostNote::Table)
+                    .from_col(BifrostNote::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_NOTE_CREATED_BY_USER_ID)
+                    .table(BifrostNote::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_TAG_CREATED_BY_USER_ID)
+                    .table(BifrostTag::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_NOTE_SUBJECT_TYPE_SUBJECT_ID)
+                    .table(BifrostNote::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_TAG_SUBJECT_TYPE_SUBJECT_ID_NAME)
+                    .table(BifrostTag::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostNote::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostTag::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostTag {
+    Table,
+    Id,
+    SubjectType,
+    SubjectId,
+    Name,
+    CreatedByUserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BifrostNote {
+    Table,
+    Id,
+    SubjectType,
+    SubjectId,
+    Body,
+    Visibility,
+    CreatedByUserId,
+    CreatedAt,
+}
@@ -0,0 +1,102 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_MEMBER_FILTER_USER_ID_LIST_NAME: &str = "idx_bifrost_member_filter_user_id_list_name";
+static FK_MEMBER_FILTER_USER_ID: &str = "fk_bifrost_member_filter_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostMemberFilter::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostMemberFilter::Id))
+                    .col(integer(BifrostMemberFilter::UserId))
+                    .col(string(BifrostMemberFilter::List))
+                    .col(string(BifrostMemberFilter::Name))
+                    .col(text(BifrostMemberFilter::Filter))
+                    .col(
+                        timestamp(BifrostMemberFilter::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp(BifrostMemberFilter::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_MEMBER_FILTER_USER_ID_LIST_NAME)
+                    .table(BifrostMemberFilter::Table)
+                    .col(BifrostMemberFilter::UserId)
+                    .col(BifrostMemberFilter::List)
+                    .col(BifrostMemberFilter::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_MEMBER_FILTER_USER_ID)
+                    .from_tbl(BifrostMemberFilter::Table)
+                    .from_col(BifrostMemberFilter::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_MEMBER_FILTER_USER_ID)
+                    .table(BifrostMemberFilter::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_MEMBER_FILTER_USER_ID_LIST_NAME)
+                    .table(BifrostMemberFilter::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostMemberFilter::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostMemberFilter {
+    Table,
+    Id,
+    UserId,
+    List,
+    Name,
+    Filter,
+    CreatedAt,
+    UpdatedAt,
+}
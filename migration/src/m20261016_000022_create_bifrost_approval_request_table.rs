@@ -0,0 +1,104 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_APPROVAL_REQUEST_STATUS: &str = "idx_bifrost_approval_request_status";
+static FK_APPROVAL_REQUEST_REQUESTED_BY_USER_ID: &str =
+    "fk_bifrost_approval_request_requested_by_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostApprovalRequest::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostApprovalRequest::Id))
+                    .col(string(BifrostApprovalRequest::Action))
+                    .col(text(BifrostApprovalRequest::Payload))
+                    .col(string(BifrostApprovalRequest::Status))
+                    .col(integer(BifrostApprovalRequest::RequestedByUserId))
+                    .col(integer_null(BifrostApprovalRequest::DecidedByUserId))
+                    .col(
+                        timestamp(BifrostApprovalRequest::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp(BifrostApprovalRequest::ExpiresAt))
+                    .col(timestamp_null(BifrostApprovalRequest::DecidedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_APPROVAL_REQUEST_STATUS)
+                    .table(BifrostApprovalRequest::Table)
+                    .col(BifrostApprovalRequest::Status)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_APPROVAL_REQUEST_REQUESTED_BY_USER_ID)
+                    .from_tbl(BifrostApprovalRequest::Table)
+                    .from_col(BifrostApprovalRequest::RequestedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_APPROVAL_REQUEST_REQUESTED_BY_USER_ID)
+                    .table(BifrostApprovalRequest::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_APPROVAL_REQUEST_STATUS)
+                    .table(BifrostApprovalRequest::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostApprovalRequest::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostApprovalRequest {
+    Table,
+    Id,
+    Action,
+    Payload,
+    Status,
+    RequestedByUserId,
+    DecidedByUserId,
+    CreatedAt,
+    ExpiresAt,
+    DecidedAt,
+}
@@ -0,0 +1,97 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+static IDX_WORKER_JOB_HISTORY_KIND_ENTITY: &str = "idx_bifrost_worker_job_history_kind_entity";
+static IDX_WORKER_JOB_HISTORY_FINISHED_AT: &str = "idx_bifrost_worker_job_history_finished_at";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostWorkerJobHistory::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostWorkerJobHistory::Id))
+                    .col(string(BifrostWorkerJobHistory::JobKind))
+                    .col(big_integer_null(BifrostWorkerJobHistory::EntityId))
+                    .col(string(BifrostWorkerJobHistory::Outcome))
+                    .col(text_null(BifrostWorkerJobHistory::Error))
+                    .col(big_integer(BifrostWorkerJobHistory::DurationMs))
+                    .col(
+                        timestamp(BifrostWorkerJobHistory::FinishedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_WORKER_JOB_HISTORY_KIND_ENTITY)
+                    .table(BifrostWorkerJobHistory::Table)
+                    .col(BifrostWorkerJobHistory::JobKind)
+                    .col(BifrostWorkerJobHistory::EntityId)
+                    .col(BifrostWorkerJobHistory::FinishedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_WORKER_JOB_HISTORY_FINISHED_AT)
+                    .table(BifrostWorkerJobHistory::Table)
+                    .col(BifrostWorkerJobHistory::FinishedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_WORKER_JOB_HISTORY_FINISHED_AT)
+                    .table(BifrostWorkerJobHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_WORKER_JOB_HISTORY_KIND_ENTITY)
+                    .table(BifrostWorkerJobHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostWorkerJobHistory::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostWorkerJobHistory {
+    Table,
+    Id,
+    JobKind,
+    EntityId,
+    Outcome,
+    Error,
+    DurationMs,
+    FinishedAt,
+}
@@ -0,0 +1,180 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_ONBOARDING_COMPLETION_STEP_ID_USER_ID: &str =
+    "idx_bifrost_onboarding_completion_step_id_user_id";
+static FK_ONBOARDING_STEP_CREATED_BY_USER_ID: &str =
+    "fk_bifrost_onboarding_step_created_by_user_id";
+static FK_ONBOARDING_COMPLETION_STEP_ID: &str = "fk_bifrost_onboarding_completion_step_id";
+static FK_ONBOARDING_COMPLETION_USER_ID: &str = "fk_bifrost_onboarding_completion_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostOnboardingStep::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostOnboardingStep::Id))
+                    .col(string(BifrostOnboardingStep::Title))
+                    .col(text(BifrostOnboardingStep::Description))
+                    .col(string(BifrostOnboardingStep::Kind))
+                    .col(text_null(BifrostOnboardingStep::Target))
+                    .col(integer(BifrostOnboardingStep::Position))
+                    .col(integer(BifrostOnboardingStep::CreatedByUserId))
+                    .col(
+                        timestamp(BifrostOnboardingStep::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostOnboardingCompletion::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostOnboardingCompletion::Id))
+                    .col(integer(BifrostOnboardingCompletion::StepId))
+                    .col(integer(BifrostOnboardingCompletion::UserId))
+                    .col(
+                        timestamp(BifrostOnboardingCompletion::CompletedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_ONBOARDING_COMPLETION_STEP_ID_USER_ID)
+                    .table(BifrostOnboardingCompletion::Table)
+                    .col(BifrostOnboardingCompletion::StepId)
+                    .col(BifrostOnboardingCompletion::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_ONBOARDING_STEP_CREATED_BY_USER_ID)
+                    .from_tbl(BifrostOnboardingStep::Table)
+                    .from_col(BifrostOnboardingStep::CreatedByUserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_ONBOARDING_COMPLETION_STEP_ID)
+                    .from_tbl(BifrostOnboardingCompletion::Table)
+                    .from_col(BifrostOnboardingCompletion::StepId)
+                    .to_tbl(BifrostOnboardingStep::Table)
+                    .to_col(BifrostOnboardingStep::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_ONBOARDING_COMPLETION_USER_ID)
+                    .from_tbl(BifrostOnboardingCompletion::Table)
+                    .from_col(BifrostOnboardingCompletion::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_ONBOARDING_COMPLETION_USER_ID)
+                    .table(BifrostOnboardingCompletion::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_ONBOARDING_COMPLETION_STEP_ID)
+                    .table(BifrostOnboardingCompletion::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_ONBOARDING_STEP_CREATED_BY_USER_ID)
+                    .table(BifrostOnboardingStep::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_ONBOARDING_COMPLETION_STEP_ID_USER_ID)
+                    .table(BifrostOnboardingCompletion::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostOnboardingCompletion::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostOnboardingStep::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostOnboardingStep {
+    Table,
+    Id,
+    Title,
+    Description,
+    Kind,
+    Target,
+    Position,
+    CreatedByUserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BifrostOnboardingCompletion {
+    Table,
+    Id,
+    StepId,
+    UserId,
+    CompletedAt,
+}
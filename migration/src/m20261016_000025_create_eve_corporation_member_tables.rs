@@ -0,0 +1,206 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{
+    m20251017_000003_create_eve_corporation_table::EveCorporation,
+    m20251017_000004_create_eve_character_table::EveCharacter,
+};
+
+static IDX_CORPORATION_MEMBER_CORPORATION_ID_CHARACTER_ID: &str =
+    "idx_eve_corporation_member_corporation_id_character_id";
+static IDX_CORPORATION_MEMBER_CHARACTER_ID: &str = "idx_eve_corporation_member_character_id";
+static FK_CORPORATION_MEMBER_CORPORATION_ID: &str = "fk_eve_corporation_member_corporation_id";
+static FK_CORPORATION_MEMBER_DIRECTOR_CORPORATION_ID: &str =
+    "fk_bifrost_corporation_member_director_corporation_id";
+static FK_CORPORATION_MEMBER_DIRECTOR_CHARACTER_ID: &str =
+    "fk_bifrost_corporation_member_director_character_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EveCorporationMember::Table)
+                    .if_not_exists()
+                    .col(pk_auto(EveCorporationMember::Id))
+                    .col(integer(EveCorporationMember::CorporationId))
+                    .col(big_integer(EveCorporationMember::CharacterId))
+                    .col(
+                        timestamp(EveCorporationMember::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostCorporationMemberDirector::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostCorporationMemberDirector::Id))
+                    .col(integer_uniq(
+                        BifrostCorporationMemberDirector::CorporationId,
+                    ))
+                    .col(integer(BifrostCorporationMemberDirector::CharacterId))
+                    .col(timestamp_null(BifrostCorporationMemberDirector::FailedAt))
+                    .col(
+                        timestamp(BifrostCorporationMemberDirector::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp(BifrostCorporationMemberDirector::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_CORPORATION_MEMBER_CORPORATION_ID_CHARACTER_ID)
+                    .table(EveCorporationMember::Table)
+                    .col(EveCorporationMember::CorporationId)
+                    .col(EveCorporationMember::CharacterId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_CORPORATION_MEMBER_CHARACTER_ID)
+                    .table(EveCorporationMember::Table)
+                    .col(EveCorporationMember::CharacterId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_CORPORATION_MEMBER_CORPORATION_ID)
+                    .from_tbl(EveCorporationMember::Table)
+                    .from_col(EveCorporationMember::CorporationId)
+                    .to_tbl(EveCorporation::Table)
+                    .to_col(EveCorporation::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_CORPORATION_MEMBER_DIRECTOR_CORPORATION_ID)
+                    .from_tbl(BifrostCorporationMemberDirector::Table)
+                    .from_col(BifrostCorporationMemberDirector::CorporationId)
+                    .to_tbl(EveCorporation::Table)
+                    .to_col(EveCorporation::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_CORPORATION_MEMBER_DIRECTOR_CHARACTER_ID)
+                    .from_tbl(BifrostCorporationMemberDirector::Table)
+                    .from_col(BifrostCorporationMemberDirector::CharacterId)
+                    .to_tbl(EveCharacter::Table)
+                    .to_col(EveCharacter::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_CORPORATION_MEMBER_DIRECTOR_CHARACTER_ID)
+                    .table(BifrostCorporationMemberDirector::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_CORPORATION_MEMBER_DIRECTOR_CORPORATION_ID)
+                    .table(BifrostCorporationMemberDirector::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_CORPORATION_MEMBER_CORPORATION_ID)
+                    .table(EveCorporationMember::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_CORPORATION_MEMBER_CHARACTER_ID)
+                    .table(EveCorporationMember::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_CORPORATION_MEMBER_CORPORATION_ID_CHARACTER_ID)
+                    .table(EveCorporationMember::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BifrostCorporationMemberDirector::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(EveCorporationMember::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum EveCorporationMember {
+    Table,
+    Id,
+    CorporationId,
+    CharacterId,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BifrostCorporationMemberDirector {
+    Table,
+    Id,
+    CorporationId,
+    CharacterId,
+    FailedAt,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,77 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000004_create_eve_character_table::EveCharacter;
+
+static FK_CHARACTER_TOKEN_CHARACTER_ID: &str = "fk_bifrost_character_token_character_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostCharacterToken::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostCharacterToken::Id))
+                    .col(integer_uniq(BifrostCharacterToken::CharacterId))
+                    .col(text(BifrostCharacterToken::RefreshToken))
+                    .col(text(BifrostCharacterToken::Scopes))
+                    .col(
+                        timestamp(BifrostCharacterToken::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        timestamp(BifrostCharacterToken::UpdatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_CHARACTER_TOKEN_CHARACTER_ID)
+                    .from_tbl(BifrostCharacterToken::Table)
+                    .from_col(BifrostCharacterToken::CharacterId)
+                    .to_tbl(EveCharacter::Table)
+                    .to_col(EveCharacter::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_CHARACTER_TOKEN_CHARACTER_ID)
+                    .table(BifrostCharacterToken::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostCharacterToken::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostCharacterToken {
+    Table,
+    Id,
+    CharacterId,
+    RefreshToken,
+    Scopes,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,96 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000004_create_eve_character_table::EveCharacter;
+
+static IDX_CHARACTER_SKILL_CHARACTER_ID_SKILL_ID: &str =
+    "idx_eve_character_skill_character_id_skill_id";
+static FK_CHARACTER_SKILL_CHARACTER_ID: &str = "fk_eve_character_skill_character_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(EveCharacterSkill::Table)
+                    .if_not_exists()
+                    .col(pk_auto(EveCharacterSkill::Id))
+                    .col(integer(EveCharacterSkill::CharacterId))
+                    .col(big_integer(EveCharacterSkill::SkillId))
+                    .col(integer(EveCharacterSkill::ActiveLevel))
+                    .col(integer(EveCharacterSkill::TrainedLevel))
+                    .col(big_integer(EveCharacterSkill::Skillpoints))
+                    .col(timestamp(EveCharacterSkill::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_CHARACTER_SKILL_CHARACTER_ID_SKILL_ID)
+                    .table(EveCharacterSkill::Table)
+                    .col(EveCharacterSkill::CharacterId)
+                    .col(EveCharacterSkill::SkillId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_CHARACTER_SKILL_CHARACTER_ID)
+                    .from_tbl(EveCharacterSkill::Table)
+                    .from_col(EveCharacterSkill::CharacterId)
+                    .to_tbl(EveCharacter::Table)
+                    .to_col(EveCharacter::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_CHARACTER_SKILL_CHARACTER_ID)
+                    .table(EveCharacterSkill::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_CHARACTER_SKILL_CHARACTER_ID_SKILL_ID)
+                    .table(EveCharacterSkill::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(EveCharacterSkill::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum EveCharacterSkill {
+    Table,
+    Id,
+    CharacterId,
+    SkillId,
+    ActiveLevel,
+    TrainedLevel,
+    Skillpoints,
+    UpdatedAt,
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostWebhook::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostWebhook::Id))
+                    .col(string(BifrostWebhook::Name))
+                    .col(text(BifrostWebhook::Url))
+                    .col(text(BifrostWebhook::Events))
+                    .col(boolean(BifrostWebhook::Enabled).default(true))
+                    .col(timestamp(BifrostWebhook::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostWebhook::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BifrostWebhook::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostWebhook {
+    Table,
+    Id,
+    Name,
+    Url,
+    Events,
+    Enabled,
+    CreatedAt,
+    UpdatedAt,
+}
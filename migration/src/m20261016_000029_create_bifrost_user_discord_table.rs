@@ -0,0 +1,73 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static FK_USER_DISCORD_USER_ID: &str = "fk_bifrost_user_discord_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostUserDiscord::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostUserDiscord::Id))
+                    .col(integer_uniq(BifrostUserDiscord::UserId))
+                    .col(big_integer_uniq(BifrostUserDiscord::DiscordId))
+                    .col(string(BifrostUserDiscord::Username))
+                    .col(timestamp(BifrostUserDiscord::LinkedAt).default(Expr::current_timestamp()))
+                    .col(
+                        timestamp(BifrostUserDiscord::UpdatedAt).default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_USER_DISCORD_USER_ID)
+                    .from_tbl(BifrostUserDiscord::Table)
+                    .from_col(BifrostUserDiscord::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_USER_DISCORD_USER_ID)
+                    .table(BifrostUserDiscord::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostUserDiscord::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostUserDiscord {
+    Table,
+    Id,
+    UserId,
+    DiscordId,
+    Username,
+    LinkedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,190 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_USER_ROLE_USER_ID_ROLE_ID: &str = "idx_bifrost_user_role_user_id_role_id";
+static FK_USER_ROLE_USER_ID: &str = "fk_bifrost_user_role_user_id";
+static FK_USER_ROLE_ROLE_ID: &str = "fk_bifrost_user_role_role_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostRole::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostRole::Id))
+                    .col(string_uniq(BifrostRole::Name))
+                    .col(text(BifrostRole::Description))
+                    .col(text(BifrostRole::Permissions))
+                    .col(boolean(BifrostRole::BuiltIn).default(false))
+                    .col(timestamp(BifrostRole::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostRole::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostUserRole::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostUserRole::Id))
+                    .col(integer(BifrostUserRole::UserId))
+                    .col(integer(BifrostUserRole::RoleId))
+                    .col(timestamp(BifrostUserRole::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_USER_ROLE_USER_ID_ROLE_ID)
+                    .table(BifrostUserRole::Table)
+                    .col(BifrostUserRole::UserId)
+                    .col(BifrostUserRole::RoleId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_USER_ROLE_USER_ID)
+                    .from_tbl(BifrostUserRole::Table)
+                    .from_col(BifrostUserRole::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_USER_ROLE_ROLE_ID)
+                    .from_tbl(BifrostUserRole::Table)
+                    .from_col(BifrostUserRole::RoleId)
+                    .to_tbl(BifrostRole::Table)
+                    .to_col(BifrostRole::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Seed the built-in roles, admin grants every permission and member applies to every user
+        let seed = Query::insert()
+            .into_table(BifrostRole::Table)
+            .columns([
+                BifrostRole::Name,
+                BifrostRole::Description,
+                BifrostRole::Permissions,
+                BifrostRole::BuiltIn,
+            ])
+            .values_panic([
+                "admin".into(),
+                "Full access to every admin feature".into(),
+                "admin".into(),
+                true.into(),
+            ])
+            .values_panic([
+                "member".into(),
+                "Permissions granted to every user".into(),
+                "".into(),
+                true.into(),
+            ])
+            .to_owned();
+
+        manager.get_connection().execute(&seed).await?;
+
+        // Existing deployments have no roles assigned yet, grant admin to the first user so
+        // the admin endpoints stay reachable
+        let first_admin = Query::insert()
+            .into_table(BifrostUserRole::Table)
+            .columns([BifrostUserRole::UserId, BifrostUserRole::RoleId])
+            .select_from(
+                Query::select()
+                    .column((BifrostUser::Table, BifrostUser::Id))
+                    .column((BifrostRole::Table, BifrostRole::Id))
+                    .from(BifrostUser::Table)
+                    .from(BifrostRole::Table)
+                    .and_where(Expr::col((BifrostRole::Table, BifrostRole::Name)).eq("admin"))
+                    .order_by((BifrostUser::Table, BifrostUser::Id), Order::Asc)
+                    .limit(1)
+                    .to_owned(),
+            )
+            .map_err(|e| DbErr::Migration(e.to_string()))?
+            .to_owned();
+
+        manager.get_connection().execute(&first_admin).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_USER_ROLE_ROLE_ID)
+                    .table(BifrostUserRole::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_USER_ROLE_USER_ID)
+                    .table(BifrostUserRole::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_USER_ROLE_USER_ID_ROLE_ID)
+                    .table(BifrostUserRole::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostUserRole::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostRole::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostRole {
+    Table,
+    Id,
+    Name,
+    Description,
+    Permissions,
+    BuiltIn,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostUserRole {
+    Table,
+    Id,
+    UserId,
+    RoleId,
+    CreatedAt,
+}
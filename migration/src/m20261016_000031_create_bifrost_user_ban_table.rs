@@ -0,0 +1,95 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_USER_BAN_USER_ID: &str = "idx_bifrost_user_ban_user_id";
+static FK_USER_BAN_USER_ID: &str = "fk_bifrost_user_ban_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostUserBan::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostUserBan::Id))
+                    .col(integer(BifrostUserBan::UserId))
+                    .col(text(BifrostUserBan::Reason))
+                    .col(integer_null(BifrostUserBan::IssuedByUserId))
+                    .col(timestamp(BifrostUserBan::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp_null(BifrostUserBan::ExpiresAt))
+                    .col(timestamp_null(BifrostUserBan::LiftedAt))
+                    .col(integer_null(BifrostUserBan::LiftedByUserId))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_USER_BAN_USER_ID)
+                    .table(BifrostUserBan::Table)
+                    .col(BifrostUserBan::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_USER_BAN_USER_ID)
+                    .from_tbl(BifrostUserBan::Table)
+                    .from_col(BifrostUserBan::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_USER_BAN_USER_ID)
+                    .table(BifrostUserBan::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_USER_BAN_USER_ID)
+                    .table(BifrostUserBan::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostUserBan::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostUserBan {
+    Table,
+    Id,
+    UserId,
+    Reason,
+    IssuedByUserId,
+    CreatedAt,
+    ExpiresAt,
+    LiftedAt,
+    LiftedByUserId,
+}
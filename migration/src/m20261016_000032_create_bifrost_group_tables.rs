@@ -0,0 +1,171 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20251017_000005_create_bifrost_user_table::BifrostUser;
+
+static IDX_GROUP_MEMBER_GROUP_ID_USER_ID: &str = "idx_bifrost_group_member_group_id_user_id";
+static IDX_GROUP_MEMBER_USER_ID: &str = "idx_bifrost_group_member_user_id";
+static FK_GROUP_MEMBER_GROUP_ID: &str = "fk_bifrost_group_member_group_id";
+static FK_GROUP_MEMBER_USER_ID: &str = "fk_bifrost_group_member_user_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostGroup::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostGroup::Id))
+                    .col(string_uniq(BifrostGroup::Name))
+                    .col(text(BifrostGroup::Description))
+                    .col(string(BifrostGroup::JoinPolicy))
+                    .col(timestamp(BifrostGroup::CreatedAt).default(Expr::current_timestamp()))
+                    .col(timestamp(BifrostGroup::UpdatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostGroupMember::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostGroupMember::Id))
+                    .col(integer(BifrostGroupMember::GroupId))
+                    .col(integer(BifrostGroupMember::UserId))
+                    .col(string(BifrostGroupMember::Status))
+                    .col(
+                        timestamp(BifrostGroupMember::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(timestamp_null(BifrostGroupMember::DecidedAt))
+                    .col(integer_null(BifrostGroupMember::DecidedByUserId))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_GROUP_MEMBER_GROUP_ID_USER_ID)
+                    .table(BifrostGroupMember::Table)
+                    .col(BifrostGroupMember::GroupId)
+                    .col(BifrostGroupMember::UserId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_GROUP_MEMBER_USER_ID)
+                    .table(BifrostGroupMember::Table)
+                    .col(BifrostGroupMember::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_GROUP_MEMBER_GROUP_ID)
+                    .from_tbl(BifrostGroupMember::Table)
+                    .from_col(BifrostGroupMember::GroupId)
+                    .to_tbl(BifrostGroup::Table)
+                    .to_col(BifrostGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_GROUP_MEMBER_USER_ID)
+                    .from_tbl(BifrostGroupMember::Table)
+                    .from_col(BifrostGroupMember::UserId)
+                    .to_tbl(BifrostUser::Table)
+                    .to_col(BifrostUser::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_GROUP_MEMBER_USER_ID)
+                    .table(BifrostGroupMember::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_GROUP_MEMBER_GROUP_ID)
+                    .table(BifrostGroupMember::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_GROUP_MEMBER_USER_ID)
+                    .table(BifrostGroupMember::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_GROUP_MEMBER_GROUP_ID_USER_ID)
+                    .table(BifrostGroupMember::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostGroupMember::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostGroup::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostGroup {
+    Table,
+    Id,
+    Name,
+    Description,
+    JoinPolicy,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostGroupMember {
+    Table,
+    Id,
+    GroupId,
+    UserId,
+    Status,
+    CreatedAt,
+    DecidedAt,
+    DecidedByUserId,
+}
@@ -0,0 +1,93 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261016_000032_create_bifrost_group_tables::BifrostGroup;
+
+static IDX_GROUP_RULE_GROUP_ID_KIND_ENTITY_ID: &str =
+    "idx_bifrost_group_rule_group_id_kind_entity_id";
+static FK_GROUP_RULE_GROUP_ID: &str = "fk_bifrost_group_rule_group_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostGroupRule::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostGroupRule::Id))
+                    .col(integer(BifrostGroupRule::GroupId))
+                    .col(string(BifrostGroupRule::Kind))
+                    .col(big_integer(BifrostGroupRule::EntityId))
+                    .col(timestamp(BifrostGroupRule::CreatedAt).default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_GROUP_RULE_GROUP_ID_KIND_ENTITY_ID)
+                    .table(BifrostGroupRule::Table)
+                    .col(BifrostGroupRule::GroupId)
+                    .col(BifrostGroupRule::Kind)
+                    .col(BifrostGroupRule::EntityId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_GROUP_RULE_GROUP_ID)
+                    .from_tbl(BifrostGroupRule::Table)
+                    .from_col(BifrostGroupRule::GroupId)
+                    .to_tbl(BifrostGroup::Table)
+                    .to_col(BifrostGroup::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_GROUP_RULE_GROUP_ID)
+                    .table(BifrostGroupRule::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_GROUP_RULE_GROUP_ID_KIND_ENTITY_ID)
+                    .table(BifrostGroupRule::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostGroupRule::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BifrostGroupRule {
+    Table,
+    Id,
+    GroupId,
+    Kind,
+    EntityId,
+    CreatedAt,
+}
@@ -0,0 +1,90 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261016_000001_create_bifrost_fitting_table::BifrostFitting;
+
+static IDX_FITTING_SKILL_FITTING_ID_SKILL_ID: &str =
+    "idx_bifrost_fitting_skill_fitting_id_skill_id";
+static FK_FITTING_SKILL_FITTING_ID: &str = "fk_bifrost_fitting_skill_fitting_id";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BifrostFittingSkill::Table)
+                    .if_not_exists()
+                    .col(pk_auto(BifrostFittingSkill::Id))
+                    .col(integer(BifrostFittingSkill::FittingId))
+                    .col(big_integer(BifrostFittingSkill::SkillId))
+                    .col(integer(BifrostFittingSkill::Level))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name(IDX_FITTING_SKILL_FITTING_ID_SKILL_ID)
+                    .table(BifrostFittingSkill::Table)
+                    .col(BifrostFittingSkill::FittingId)
+                    .col(BifrostFittingSkill::SkillId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FK_FITTING_SKILL_FITTING_ID)
+                    .from_tbl(BifrostFittingSkill::Table)
+                    .from_col(BifrostFittingSkill::FittingId)
+                    .to_tbl(BifrostFitting::Table)
+                    .to_col(BifrostFitting::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FK_FITTING_SKILL_FITTING_ID)
+                    .table(BifrostFittingSkill::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(IDX_FITTING_SKILL_FITTING_ID_SKILL_ID)
+                    .table(BifrostFittingSkill::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(BifrostFittingSkill::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum BifrostFittingSkill {
+    Table,
+    Id,
+    FittingId,
+    SkillId,
+    Level,
+}
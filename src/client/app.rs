@@ -1,8 +1,9 @@
 use dioxus::prelude::*;
 use dioxus_logger::tracing;
 
+use crate::client::components::OfflineBanner;
 use crate::client::router::Route;
-use crate::client::store::user::UserState;
+use crate::client::store::{branding::BrandingState, network::NetworkState, user::UserState};
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
 const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
@@ -39,12 +40,71 @@ pub fn App() -> Element {
         }
     }
 
-    // Make user_store available globally via context
+    // Initialize the network store, assuming online until the browser reports otherwise
+    let mut network_store = use_store(|| NetworkState { online: true });
+
+    // Register the service worker and track online status
+    #[cfg(feature = "web")]
+    use_future(move || async move {
+        use crate::client::store::network::watch_network;
+
+        let mut watcher = watch_network();
+        while let Ok(online) = watcher.recv::<bool>().await {
+            network_store.write().online = online;
+        }
+    });
+
+    // Initialize the branding store with Bifrost's default look until settings are fetched
+    let mut branding_store = use_store(|| BrandingState {
+        branding: Default::default(),
+    });
+
+    // Fetch branding settings on first load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::store::branding::get_branding;
+
+        let future = use_resource(|| async move { get_branding().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(branding)) => {
+                if branding_store.read().branding != *branding {
+                    branding_store.write().branding = branding.clone();
+                }
+            }
+            Some(Err(err)) => tracing::error!(err),
+            None => (),
+        }
+    }
+
+    // Make user_store, network_store, and branding_store available globally via context
     use_context_provider(|| user_store);
+    use_context_provider(|| network_store);
+    use_context_provider(|| branding_store);
+
+    let branding = branding_store.read().branding.clone();
+    let title = branding
+        .org_name
+        .clone()
+        .unwrap_or_else(|| "Bifrost".to_string());
+    // Override the primary color of every theme, the server only accepts hex colors so the
+    // value can't break out of the declaration
+    let primary_color_css = branding
+        .primary_color
+        .map(|color| format!(":root {{ --color-primary: {} !important; }}", color));
 
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
+        document::Link { rel: "manifest", href: "/manifest.webmanifest" }
+        document::Link { rel: "apple-touch-icon", href: "/icon-512.png" }
         document::Link { rel: "stylesheet", href: TAILWIND_CSS }
+        document::Meta { name: "viewport", content: "width=device-width, initial-scale=1" }
+        document::Meta { name: "theme-color", content: "#1d232a" }
+        document::Title { "{title}" }
+        if let Some(css) = primary_color_css {
+            document::Style { "{css}" }
+        }
+        OfflineBanner {}
         Router::<Route> {}
     }
 }
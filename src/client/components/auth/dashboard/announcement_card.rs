@@ -0,0 +1,89 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::model::announcement::InboxAnnouncementDto;
+
+#[component]
+pub fn DashboardAnnouncementCard() -> Element {
+    let mut inbox = use_signal(Vec::<InboxAnnouncementDto>::new);
+
+    // Retrieve announcement inbox on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::announcement::get_inbox;
+
+        let future = use_resource(|| async move { get_inbox().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                inbox.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let mark_read = use_callback(move |announcement_id: i32| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::announcement::mark_announcement_read;
+
+            match mark_announcement_read(announcement_id).await {
+                Ok(()) => {
+                    if let Some(announcement) = inbox
+                        .write()
+                        .iter_mut()
+                        .find(|announcement| announcement.id == announcement_id)
+                    {
+                        announcement.read = true;
+                    }
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (announcement_id, inbox);
+    });
+
+    rsx!(
+        div {
+            class: "card shadow-sm w-full min-w-0 flex-1",
+            div {
+                class: "card-body",
+                h2 {
+                    class: "card-title",
+                    "Announcements"
+                }
+                if inbox.read().is_empty() {
+                    p { class: "opacity-70", "No announcements yet." }
+                }
+                for announcement in inbox.read().iter() {
+                    div { key: "{announcement.id}", class: "flex flex-col gap-1 border-t border-base-300 pt-2",
+                        div { class: "flex items-center gap-2",
+                            span { class: "font-semibold flex-1", "{announcement.title}" }
+                            if announcement.read {
+                                span { class: "text-sm opacity-70", "{announcement.created_at}" }
+                            } else {
+                                span { class: "badge badge-primary", "New" }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    onclick: {
+                                        let announcement_id = announcement.id;
+                                        move |_| mark_read.call(announcement_id)
+                                    },
+                                    "Mark read"
+                                }
+                            }
+                        }
+                        div { class: "text-sm", dangerous_inner_html: "{announcement.html}" }
+                    }
+                }
+            }
+        }
+    )
+}
@@ -1,5 +1,5 @@
 use dioxus::prelude::*;
-use dioxus_free_icons::icons::fa_solid_icons::{FaLink, FaShuffle};
+use dioxus_free_icons::icons::fa_solid_icons::{FaLink, FaListCheck, FaShuffle};
 use dioxus_free_icons::Icon;
 
 use crate::{client::store::user::UserState, model::user::CharacterDto};
@@ -13,7 +13,7 @@ pub fn DashboardCharacterCard(characters: Signal<Vec<CharacterDto>>) -> Element
 
     rsx!(
         div {
-            class: "card shadow-sm w-full flex-1",
+            class: "card shadow-sm w-full min-w-0 flex-1",
             div {
                 class: "card-body",
                 h2 {
@@ -26,7 +26,7 @@ pub fn DashboardCharacterCard(characters: Signal<Vec<CharacterDto>>) -> Element
                             div {
                                 class: "w-24 h-24 rounded-full",
                                 img {
-                                    src: format!("https://images.evetech.net/characters/{}/portrait?size=128", user.character_id),
+                                    src: format!("{}?size=128", user.portrait_url),
                                     alt: "{user.character_name}",
                                 }
                             }
@@ -48,7 +48,7 @@ pub fn DashboardCharacterCard(characters: Signal<Vec<CharacterDto>>) -> Element
                     ul { class: "flex flex-wrap gap-2 justify-center",
                         li {
                             a {
-                                href: "/api/auth/login",
+                                href: "/api/auth/login?intent=link_alt",
                                 button { class: "btn btn-outline w-42 flex gap-2",
                                     Icon {
                                         width: 24,
@@ -63,7 +63,22 @@ pub fn DashboardCharacterCard(characters: Signal<Vec<CharacterDto>>) -> Element
                         }
                         li {
                             a {
-                                href: "/api/auth/login?change_main=true",
+                                href: "/api/auth/login?intent=link_alt&link_mode=true",
+                                button { class: "btn btn-outline w-42 flex gap-2",
+                                    Icon {
+                                        width: 24,
+                                        height: 24,
+                                        icon: FaListCheck
+                                    }
+                                    p {
+                                        "Link Multiple"
+                                    }
+                                }
+                            }
+                        }
+                        li {
+                            a {
+                                href: "/api/auth/login?intent=change_main",
                                 button { class: "btn btn-outline w-42 flex gap-2",
                                     Icon {
                                         width: 24,
@@ -108,7 +123,7 @@ fn CharacterTable(characters: Signal<Vec<CharacterDto>>) -> Element {
                                         div {
                                             class: "w-10 h-10 rounded-full",
                                             img {
-                                                src: format!("https://images.evetech.net/characters/{}/portrait?size=64", c.id),
+                                                src: format!("{}?size=64", c.portrait_url),
                                                 alt: "{c.name}",
                                             }
                                         }
@@ -125,7 +140,7 @@ fn CharacterTable(characters: Signal<Vec<CharacterDto>>) -> Element {
                                         div {
                                             class: "w-10 h-10",
                                             img {
-                                                src: format!("https://images.evetech.net/corporations/{}/logo?size=64", c.corporation.id),
+                                                src: format!("{}?size=64", c.corporation.logo_url),
                                                 alt: "{c.corporation.name}",
                                             }
                                         }
@@ -144,7 +159,7 @@ fn CharacterTable(characters: Signal<Vec<CharacterDto>>) -> Element {
                                                 div {
                                                     class: "w-10 h-10",
                                                     img {
-                                                        src: format!("https://images.evetech.net/alliances/{}/logo?size=64", alliance.id),
+                                                        src: format!("{}?size=64", alliance.logo_url),
                                                         alt: "{alliance.name}",
                                                     }
                                                 }
@@ -0,0 +1,59 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{client::router::Route, model::consent::ConsentDto};
+
+#[component]
+pub fn DashboardDataSharingCard() -> Element {
+    let mut consents = use_signal(Vec::<ConsentDto>::new);
+
+    // Retrieve consent status on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::user_consent::get_consents;
+
+        let future = use_resource(|| async move { get_consents().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                consents.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    rsx!(
+        div {
+            class: "card shadow-sm w-full min-w-0 flex-1",
+            div {
+                class: "card-body",
+                h2 {
+                    class: "card-title",
+                    "Data Sharing"
+                }
+                ul { class: "flex flex-col gap-2",
+                    for consent in consents.read().iter() {
+                        li { key: "{consent.category.as_str()}", class: "flex items-center gap-2",
+                            span { class: "capitalize flex-1", "{consent.category.as_str()}" }
+                            if consent.granted {
+                                span { class: "badge badge-primary", "Shared" }
+                            } else {
+                                span { class: "badge badge-outline", "Not shared" }
+                            }
+                        }
+                    }
+                }
+                div { class: "card-actions justify-end",
+                    Link {
+                        to: Route::Consent {},
+                        class: "btn btn-outline",
+                        "Manage"
+                    }
+                }
+            }
+        }
+    )
+}
@@ -1,5 +1,13 @@
+pub mod announcement_card;
 pub mod character_card;
+pub mod data_sharing_card;
+pub mod notification_card;
+pub mod onboarding_card;
 pub mod update_card;
 
+pub use announcement_card::DashboardAnnouncementCard;
 pub use character_card::DashboardCharacterCard;
+pub use data_sharing_card::DashboardDataSharingCard;
+pub use notification_card::DashboardNotificationCard;
+pub use onboarding_card::DashboardOnboardingCard;
 pub use update_card::DashboardUpdateCard;
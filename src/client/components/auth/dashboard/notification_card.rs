@@ -0,0 +1,133 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+#[component]
+pub fn DashboardNotificationCard() -> Element {
+    let mut vapid_public_key = use_signal(|| None::<String>);
+    // None while loading or if the browser doesn't support push notifications
+    let mut subscribed = use_signal(|| None::<bool>);
+    let mut pending = use_signal(|| false);
+    let mut status = use_signal(|| None::<String>);
+
+    // Retrieve push settings and subscription state of this browser on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::push::{get_push_config, is_subscribed};
+
+        let future = use_resource(|| async move {
+            let config = get_push_config().await?;
+            Ok::<_, String>((config, is_subscribed().await))
+        });
+
+        match &*future.read_unchecked() {
+            Some(Ok((config, browser_subscribed))) => {
+                vapid_public_key.set(config.vapid_public_key.clone());
+                subscribed.set(*browser_subscribed);
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let toggle = move |_| {
+        let enable = subscribed() != Some(true);
+        let key = vapid_public_key();
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::push::{subscribe, unsubscribe};
+
+            pending.set(true);
+            let result = match (enable, key) {
+                (true, Some(key)) => subscribe(key).await,
+                (true, None) => Ok(()),
+                (false, _) => unsubscribe().await,
+            };
+            match result {
+                Ok(()) => {
+                    subscribed.set(Some(enable));
+                    status.set(None);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                    status.set(Some(
+                        "Couldn't update notifications for this device.".to_string(),
+                    ));
+                }
+            }
+            pending.set(false);
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (enable, key, pending, status);
+    };
+
+    let send_test = move |_| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::push::send_test_notification;
+
+            match send_test_notification().await {
+                Ok(()) => status.set(Some("Test notification sent.".to_string())),
+                Err(err) => {
+                    tracing::error!(err);
+                    status.set(Some("Couldn't send a test notification.".to_string()));
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = status;
+    };
+
+    rsx!(
+        div {
+            class: "card shadow-sm w-full min-w-0 flex-1",
+            div {
+                class: "card-body",
+                h2 {
+                    class: "card-title",
+                    "Notifications"
+                }
+                if vapid_public_key.read().is_none() {
+                    p { class: "opacity-70",
+                        "Push notifications are not enabled on this server."
+                    }
+                } else if subscribed().is_none() {
+                    p { class: "opacity-70",
+                        "This browser doesn't support push notifications. On iOS, add Bifrost to your home screen first."
+                    }
+                } else {
+                    div { class: "flex items-center gap-2",
+                        span { class: "flex-1", "This device" }
+                        if subscribed() == Some(true) {
+                            span { class: "badge badge-primary", "Enabled" }
+                        } else {
+                            span { class: "badge badge-outline", "Disabled" }
+                        }
+                    }
+                    if let Some(message) = status() {
+                        p { class: "text-sm opacity-70", "{message}" }
+                    }
+                    div { class: "card-actions justify-end",
+                        if subscribed() == Some(true) {
+                            button {
+                                class: "btn btn-ghost",
+                                onclick: send_test,
+                                "Send test"
+                            }
+                        }
+                        button {
+                            class: "btn btn-outline",
+                            disabled: pending(),
+                            onclick: toggle,
+                            if subscribed() == Some(true) { "Disable" } else { "Enable" }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
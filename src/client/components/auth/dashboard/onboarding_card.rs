@@ -0,0 +1,169 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::router::Route,
+    model::onboarding::{OnboardingChecklistItemDto, OnboardingStepKind},
+};
+
+#[component]
+pub fn DashboardOnboardingCard() -> Element {
+    let mut checklist = use_signal(Vec::<OnboardingChecklistItemDto>::new);
+
+    // Retrieve onboarding checklist on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::onboarding::get_onboarding_checklist;
+
+        let future = use_resource(|| async move { get_onboarding_checklist().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                checklist.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let complete = use_callback(move |step_id: i32| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::onboarding::complete_onboarding_step;
+
+            match complete_onboarding_step(step_id).await {
+                Ok(()) => {
+                    if let Some(item) = checklist
+                        .write()
+                        .iter_mut()
+                        .find(|item| item.step.id == step_id)
+                    {
+                        item.completed = true;
+                    }
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (step_id, checklist);
+    });
+
+    let step_count = checklist.read().len();
+    let completed_count = checklist
+        .read()
+        .iter()
+        .filter(|item| item.completed)
+        .count();
+
+    rsx!(
+        div {
+            class: "card shadow-sm w-full min-w-0 flex-1",
+            div {
+                class: "card-body",
+                h2 {
+                    class: "card-title",
+                    "Onboarding"
+                }
+                if step_count == 0 {
+                    p { class: "opacity-70", "No onboarding steps to complete." }
+                } else {
+                    progress {
+                        class: "progress progress-primary w-full",
+                        value: "{completed_count}",
+                        max: "{step_count}",
+                    }
+                    span { class: "text-sm opacity-70", "{completed_count} of {step_count} steps completed" }
+                }
+                for item in checklist.read().iter() {
+                    div { key: "{item.step.id}", class: "flex flex-col gap-1 border-t border-base-300 pt-2",
+                        div { class: "flex items-center gap-2",
+                            input {
+                                r#type: "checkbox",
+                                class: "checkbox checkbox-primary checkbox-sm",
+                                checked: item.completed,
+                                disabled: true,
+                            }
+                            span { class: "font-semibold flex-1", "{item.step.title}" }
+                            if !item.completed {
+                                StepAction { item: item.clone(), on_complete: complete }
+                            }
+                        }
+                        if !item.step.description.is_empty() {
+                            p { class: "text-sm", "{item.step.description}" }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn StepAction(item: OnboardingChecklistItemDto, on_complete: Callback<i32>) -> Element {
+    let step_id = item.step.id;
+    let target = item.step.target.clone().unwrap_or_default();
+
+    match item.step.kind {
+        OnboardingStepKind::LinkAlts => rsx!(
+            Link {
+                to: Route::LinkCharacters {},
+                class: "btn btn-outline btn-sm",
+                "Link characters"
+            }
+        ),
+        OnboardingStepKind::GrantScopes => rsx!(
+            a {
+                class: "btn btn-outline btn-sm",
+                href: grant_scopes_href(&target),
+                "Grant access"
+            }
+        ),
+        OnboardingStepKind::ReadPage => rsx!(
+            a {
+                class: "btn btn-ghost btn-sm",
+                href: "/pages/{target}",
+                target: "_blank",
+                "Open page"
+            }
+            button {
+                class: "btn btn-outline btn-sm",
+                onclick: move |_| on_complete.call(step_id),
+                "Mark read"
+            }
+        ),
+        OnboardingStepKind::JoinDiscord => rsx!(
+            a {
+                class: "btn btn-ghost btn-sm",
+                href: "{target}",
+                target: "_blank",
+                rel: "noopener noreferrer",
+                "Open invite"
+            }
+            button {
+                class: "btn btn-outline btn-sm",
+                onclick: move |_| on_complete.call(step_id),
+                "Mark joined"
+            }
+        ),
+        OnboardingStepKind::Manual => rsx!(
+            button {
+                class: "btn btn-outline btn-sm",
+                onclick: move |_| on_complete.call(step_id),
+                "Mark done"
+            }
+        ),
+    }
+}
+
+/// Builds the login URL granting a step's space-separated scopes and returning to the dashboard.
+fn grant_scopes_href(scopes: &str) -> String {
+    format!(
+        "/api/auth/login?intent=add_scopes&scopes={}&next=/auth",
+        scopes.split_whitespace().collect::<Vec<_>>().join("%20")
+    )
+}
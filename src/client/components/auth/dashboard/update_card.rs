@@ -58,7 +58,7 @@ pub fn DashboardUpdateCard(characters: Signal<Vec<CharacterDto>>) -> Element {
 
     rsx!(
         div {
-            class: "card shadow-sm w-full flex-1",
+            class: "card shadow-sm w-full min-w-0 flex-1",
             div {
                 class: "card-body",
                 h2 {
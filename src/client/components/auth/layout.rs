@@ -1,23 +1,38 @@
 use dioxus::prelude::*;
 
 use crate::client::{
-    components::{auth::AuthNavbar, Page},
+    components::{
+        auth::{AuthNavbar, ReauthBanner},
+        Page,
+    },
     router::Route,
-    store::user::UserState,
+    store::{network::NetworkState, user::UserState},
 };
 
 #[component]
 pub fn AuthLayout() -> Element {
     let user_store = use_context::<Store<UserState>>();
+    let network_store = use_context::<Store<NetworkState>>();
     let nav = navigator();
+    let route = use_route::<Route>();
 
     let user_logged_in = user_store.read().user.is_some();
     let fetch_completed = user_store.read().fetched;
+    let online = network_store.read().online;
 
-    // Redirect unauthenticated user to login after fetch completes
-    use_effect(use_reactive!(|(user_logged_in, fetch_completed)| {
-        if !user_logged_in && fetch_completed {
-            nav.push(Route::Home {});
+    // Redirect unauthenticated user to login after fetch completes, returning to the
+    // current page once logged in. The user can't be fetched while offline, so don't
+    // redirect to a login that can't load.
+    use_effect(use_reactive!(|(
+        user_logged_in,
+        fetch_completed,
+        online,
+    )| {
+        if !user_logged_in && fetch_completed && online {
+            nav.push(NavigationTarget::<Route>::External(format!(
+                "/api/auth/login?next={}",
+                route
+            )));
         }
     }));
 
@@ -32,7 +47,14 @@ pub fn AuthLayout() -> Element {
         // Render auth pages if user is fetched and logged in
         } else if user_logged_in {
             AuthNavbar {}
+            ReauthBanner {}
             Outlet::<Route> {}
+        // Explain why nothing is shown if the user couldn't be fetched while offline
+        } else if !online {
+            Page { class: "flex flex-col items-center justify-center gap-2 text-center",
+                h1 { class: "text-2xl font-bold", "You're offline" }
+                p { class: "opacity-70", "Reconnect to sign in and load your characters." }
+            }
         }
         // If fetched and not logged in, render nothing while redirecting
         // via the use_effect
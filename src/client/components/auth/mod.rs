@@ -1,6 +1,8 @@
 pub mod dashboard;
 pub mod layout;
 pub mod navbar;
+pub mod reauth_banner;
 
 pub use layout::AuthLayout;
 pub use navbar::AuthNavbar;
+pub use reauth_banner::ReauthBanner;
@@ -1,12 +1,15 @@
 use dioxus::prelude::*;
 
-use crate::client::components::BifrostTitleButton;
+use crate::client::{
+    components::{BifrostTitleButton, NavLinks},
+    router::Route,
+};
 
 #[component]
 pub fn AuthNavbar() -> Element {
     rsx! {
         div {
-            class: "navbar bg-base-200 fixed",
+            class: "navbar bg-base-200 fixed z-40",
             div {
                 class: "navbar-start",
                 BifrostTitleButton {}
@@ -14,7 +17,21 @@ pub fn AuthNavbar() -> Element {
             }
             div {
                 class: "navbar-end",
-                div { class: "h-10",
+                // Inline links on larger screens
+                div { class: "h-10 hidden sm:flex gap-2",
+                    ul { class: "flex gap-2",
+                        NavLinks { class: "btn btn-ghost" }
+                    }
+                    Link {
+                        to: Route::Admin {},
+                        class: "btn btn-ghost",
+                        "Admin"
+                    }
+                    Link {
+                        to: Route::Consent {},
+                        class: "btn btn-ghost",
+                        "Data Sharing"
+                    }
                     a { href: "/api/auth/logout",
                         button {
                             class: "btn btn-outline",
@@ -22,6 +39,29 @@ pub fn AuthNavbar() -> Element {
                         }
                     }
                 }
+                // Collapsed menu on phones
+                div { class: "dropdown dropdown-end sm:hidden",
+                    div {
+                        tabindex: "0",
+                        role: "button",
+                        class: "btn btn-ghost",
+                        "Menu"
+                    }
+                    ul {
+                        tabindex: "0",
+                        class: "menu dropdown-content bg-base-200 rounded-box w-48 p-2 shadow",
+                        li {
+                            Link { to: Route::Admin {}, "Admin" }
+                        }
+                        li {
+                            Link { to: Route::Consent {}, "Data Sharing" }
+                        }
+                        NavLinks {}
+                        li {
+                            a { href: "/api/auth/logout", "Logout" }
+                        }
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+#[cfg(feature = "web")]
+use dioxus_logger::tracing;
+
+use crate::{client::router::Route, model::reauth_campaign::PendingReauthCampaignDto};
+
+/// Asks the user to log in again for each re-authentication campaign they haven't completed.
+#[component]
+pub fn ReauthBanner() -> Element {
+    let mut campaigns = use_signal(Vec::<PendingReauthCampaignDto>::new);
+    let route = use_route::<Route>();
+
+    // Retrieve pending campaigns on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::reauth_campaign::get_pending_reauth_campaigns;
+
+        let future = use_resource(|| async move { get_pending_reauth_campaigns().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                campaigns.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    rsx! {
+        if !campaigns.read().is_empty() {
+            div { class: "toast toast-bottom toast-center z-50",
+                for campaign in campaigns.read().iter() {
+                    div {
+                        key: "{campaign.id}",
+                        class: if campaign.restricted { "alert alert-error" } else { "alert alert-warning" },
+                        role: "alert",
+                        div { class: "flex flex-col",
+                            span { class: "font-semibold", "{campaign.name}" }
+                            span { class: "text-sm",
+                                if campaign.restricted {
+                                    "Your access is restricted until you log in again to grant the requested permissions."
+                                } else if let Some(deadline) = campaign.deadline {
+                                    "Log in again to grant the requested permissions before {deadline} UTC."
+                                } else {
+                                    "Log in again to grant the requested permissions."
+                                }
+                            }
+                        }
+                        a {
+                            class: "btn btn-sm",
+                            href: login_href(campaign, &route),
+                            "Log in again"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Builds the login URL granting a campaign's scopes and returning to the current page.
+fn login_href(campaign: &PendingReauthCampaignDto, route: &Route) -> String {
+    format!(
+        "/api/auth/login?intent=add_scopes&scopes={}&next={}",
+        campaign.scopes.join("%20"),
+        route
+    )
+}
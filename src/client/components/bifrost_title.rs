@@ -1,15 +1,25 @@
 use dioxus::prelude::*;
 
-use crate::client::router::Route;
+use crate::client::{router::Route, store::branding::BrandingState};
 
 #[component]
 pub fn BifrostTitleButton() -> Element {
+    let branding_store = use_context::<Store<BrandingState>>();
+    let branding = branding_store.read().branding.clone();
+
     rsx!(
         Link {
             to: Route::Home {},
             div { class: "flex items-center gap-2",
+                if let Some(logo_url) = branding.logo_url {
+                    img {
+                        class: "h-8 w-8 rounded",
+                        src: "{logo_url}",
+                        alt: "",
+                    }
+                }
                 p { class: "text-xl",
-                    "Bifrost"
+                    {branding.org_name.unwrap_or_else(|| "Bifrost".to_string())}
                 }
                 p { class: "text-xs",
                     {env!("CARGO_PKG_VERSION")}
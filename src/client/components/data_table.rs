@@ -0,0 +1,169 @@
+use dioxus::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DataTableSort {
+    pub column: &'static str,
+    pub direction: SortDirection,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataTableColumn {
+    pub key: &'static str,
+    pub label: &'static str,
+    pub sortable: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataTableRow {
+    pub key: String,
+    pub cells: Vec<String>,
+}
+
+/// Cursor position of a paginated API listing.
+///
+/// Pages read `current` in their `use_resource` so the listing is fetched again whenever the
+/// table moves to another page, and store the cursor returned by the API with `set_next`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CursorPagination {
+    pub current: Option<String>,
+    pub next: Option<String>,
+    previous: Vec<Option<String>>,
+}
+
+impl CursorPagination {
+    pub fn has_previous(&self) -> bool {
+        !self.previous.is_empty()
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+
+    pub fn set_next(&mut self, next: Option<String>) {
+        self.next = next;
+    }
+
+    pub fn go_next(&mut self) {
+        if let Some(next) = self.next.take() {
+            self.previous
+                .push(std::mem::replace(&mut self.current, Some(next)));
+        }
+    }
+
+    pub fn go_previous(&mut self) {
+        if let Some(previous) = self.previous.pop() {
+            self.current = previous;
+            self.next = None;
+        }
+    }
+
+    /// Returns to the first page, used when the sort order or filters change.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[component]
+pub fn DataTable(
+    columns: Vec<DataTableColumn>,
+    rows: Vec<DataTableRow>,
+    sort: Signal<Option<DataTableSort>>,
+    pagination: Signal<CursorPagination>,
+    loading: bool,
+    empty_message: Option<&'static str>,
+) -> Element {
+    let mut toggle_sort = move |column: &'static str| {
+        let direction = match *sort.read() {
+            Some(DataTableSort {
+                column: current,
+                direction: SortDirection::Ascending,
+            }) if current == column => SortDirection::Descending,
+            _ => SortDirection::Ascending,
+        };
+
+        sort.set(Some(DataTableSort { column, direction }));
+        pagination.write().reset();
+    };
+
+    let column_count = columns.len();
+
+    rsx!(
+        div { class: "flex flex-col gap-2 w-full",
+            div { class: "overflow-x-auto",
+                table { class: "table",
+                    thead {
+                        tr {
+                            for column in columns.into_iter() {
+                                if column.sortable {
+                                    th { key: "{column.key}",
+                                        button {
+                                            class: "flex items-center gap-1 cursor-pointer",
+                                            onclick: move |_| toggle_sort(column.key),
+                                            "{column.label}"
+                                            match *sort.read() {
+                                                Some(DataTableSort { column: current, direction }) if current == column.key => {
+                                                    match direction {
+                                                        SortDirection::Ascending => rsx!(span { "▲" }),
+                                                        SortDirection::Descending => rsx!(span { "▼" }),
+                                                    }
+                                                }
+                                                _ => rsx!(),
+                                            }
+                                        }
+                                    }
+                                } else {
+                                    th { key: "{column.key}", "{column.label}" }
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        if loading {
+                            tr {
+                                td { colspan: "{column_count}",
+                                    div { class: "skeleton h-8 w-full" }
+                                }
+                            }
+                        } else if rows.is_empty() {
+                            tr {
+                                td { colspan: "{column_count}", class: "text-center opacity-70",
+                                    "{empty_message.unwrap_or(\"Nothing to show.\")}"
+                                }
+                            }
+                        } else {
+                            for row in rows.into_iter() {
+                                tr { key: "{row.key}",
+                                    for (index, cell) in row.cells.into_iter().enumerate() {
+                                        td { key: "{index}", "{cell}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if pagination.read().has_previous() || pagination.read().has_next() {
+                div { class: "join self-end",
+                    button {
+                        class: "join-item btn btn-sm",
+                        disabled: loading || !pagination.read().has_previous(),
+                        onclick: move |_| pagination.write().go_previous(),
+                        "Previous"
+                    }
+                    button {
+                        class: "join-item btn btn-sm",
+                        disabled: loading || !pagination.read().has_next(),
+                        onclick: move |_| pagination.write().go_next(),
+                        "Next"
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::model::api::ValidationErrorDto;
+
+/// Validation errors of a form keyed by field name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormErrors {
+    pub form: Option<String>,
+    pub fields: HashMap<String, String>,
+}
+
+impl FormErrors {
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.form.is_none() && self.fields.is_empty()
+    }
+}
+
+impl From<ValidationErrorDto> for FormErrors {
+    fn from(dto: ValidationErrorDto) -> Self {
+        let mut errors = Self::default();
+
+        for error in dto.errors {
+            // Keep the first error of each field, later ones usually follow from it
+            errors.fields.entry(error.field).or_insert(error.message);
+        }
+        if errors.fields.is_empty() {
+            errors.form = Some(dto.title);
+        }
+
+        errors
+    }
+}
+
+#[component]
+pub fn Form(
+    on_submit: EventHandler<()>,
+    errors: Signal<FormErrors>,
+    submitting: bool,
+    submit_label: &'static str,
+    children: Element,
+) -> Element {
+    rsx!(
+        form {
+            class: "flex flex-col gap-2",
+            onsubmit: move |event| {
+                event.prevent_default();
+                on_submit.call(());
+            },
+            {children}
+            if let Some(error) = errors.read().form.as_ref() {
+                div { class: "alert alert-error", role: "alert", "{error}" }
+            }
+            div { class: "flex justify-end",
+                button {
+                    r#type: "submit",
+                    class: "btn btn-primary",
+                    disabled: submitting,
+                    if submitting {
+                        span { class: "loading loading-spinner loading-sm" }
+                    }
+                    "{submit_label}"
+                }
+            }
+        }
+    )
+}
+
+#[component]
+pub fn FormField(
+    name: &'static str,
+    label: &'static str,
+    value: Signal<String>,
+    errors: Signal<FormErrors>,
+    input_type: Option<&'static str>,
+    placeholder: Option<&'static str>,
+) -> Element {
+    let error = errors.read().field(name).map(str::to_string);
+    let input_class = if error.is_some() {
+        "input input-error w-full"
+    } else {
+        "input w-full"
+    };
+
+    rsx!(
+        fieldset { class: "fieldset",
+            legend { class: "fieldset-legend", "{label}" }
+            input {
+                class: input_class,
+                name: name,
+                r#type: input_type.unwrap_or("text"),
+                placeholder: placeholder.unwrap_or_default(),
+                value: "{value}",
+                aria_invalid: error.is_some(),
+                oninput: move |event| {
+                    value.set(event.value());
+                    // Clear the error once the user edits the field
+                    if errors.read().fields.contains_key(name) {
+                        errors.write().fields.remove(name);
+                    }
+                },
+            }
+            if let Some(error) = error {
+                p { class: "label text-error", "{error}" }
+            }
+        }
+    )
+}
@@ -1,10 +1,18 @@
 pub mod auth;
 pub mod bifrost_title;
+pub mod data_table;
 pub mod eve_login;
+pub mod form;
+pub mod nav_links;
 pub mod navbar;
+pub mod offline_banner;
 pub mod page;
 
 pub use bifrost_title::BifrostTitleButton;
+pub use data_table::DataTable;
 pub use eve_login::EveLogin;
+pub use form::{Form, FormField};
+pub use nav_links::NavLinks;
 pub use navbar::Navbar;
+pub use offline_banner::OfflineBanner;
 pub use page::Page;
@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+
+use crate::client::store::branding::BrandingState;
+
+/// Links to external tools configured by the instance's branding settings
+///
+/// Renders one `li` per link so it can be placed in both inline lists and dropdown menus.
+#[component]
+pub fn NavLinks(class: Option<&'static str>) -> Element {
+    let branding_store = use_context::<Store<BrandingState>>();
+    let class: &str = class.unwrap_or_default();
+    let nav_links = branding_store.read().branding.nav_links.clone();
+
+    rsx!(
+        for link in nav_links {
+            li {
+                a {
+                    class: "{class}",
+                    href: "{link.url}",
+                    target: "_blank",
+                    rel: "noopener noreferrer",
+                    "{link.label}"
+                }
+            }
+        }
+    )
+}
@@ -1,7 +1,7 @@
 use dioxus::prelude::*;
 
 use crate::client::{
-    components::{BifrostTitleButton, EveLogin},
+    components::{BifrostTitleButton, EveLogin, NavLinks},
     router::Route,
     store::user::UserState,
 };
@@ -20,6 +20,7 @@ pub fn Navbar() -> Element {
             div {
                 class: "navbar-end",
                 ul { class: "flex gap-2 h-10",
+                    NavLinks { class: "btn btn-ghost" }
                     // Conditionally render based on whether user is logged in
                     if user_store.read().user.is_some() {
                         // User is logged in, show link to auth page
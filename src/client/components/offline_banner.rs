@@ -0,0 +1,18 @@
+use dioxus::prelude::*;
+
+use crate::client::store::network::NetworkState;
+
+#[component]
+pub fn OfflineBanner() -> Element {
+    let network_store = use_context::<Store<NetworkState>>();
+
+    rsx!(
+        if !network_store.read().online {
+            div {
+                class: "fixed bottom-0 inset-x-0 z-50 bg-warning text-warning-content text-center text-sm p-2",
+                role: "status",
+                "You are offline. Pages may be out of date and changes can't be saved until you reconnect."
+            }
+        }
+    )
+}
@@ -6,7 +6,7 @@ pub fn Page(class: Option<&'static str>, children: Element) -> Element {
 
     rsx!(
         div {
-            class: "min-h-screen pt-[64px] p-4 {class}",
+            class: "min-h-screen pt-[64px] p-2 sm:p-4 {class}",
             {children}
         }
     )
@@ -2,7 +2,10 @@ use dioxus::prelude::*;
 
 use crate::client::{
     components::{auth::AuthLayout, Navbar},
-    routes::{auth::Dashboard, Home, NotFound},
+    routes::{
+        auth::{Admin, Consent, Dashboard, LinkCharacters},
+        Home, NotFound, Recruitment,
+    },
 };
 
 use crate::client::routes::NotFound as AuthNotFound;
@@ -15,6 +18,9 @@ pub enum Route {
     #[route("/")]
     Home {},
 
+    #[route("/recruitment")]
+    Recruitment {},
+
     #[route("/:..segments")]
     NotFound { segments: Vec<String> },
 
@@ -27,6 +33,15 @@ pub enum Route {
         #[route("/")]
         Dashboard {},
 
+        #[route("/consent")]
+        Consent {},
+
+        #[route("/link-characters")]
+        LinkCharacters {},
+
+        #[route("/admin")]
+        Admin {},
+
         #[route("/:..segments")]
         AuthNotFound { segments: Vec<String> },
 }
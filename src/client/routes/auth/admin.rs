@@ -0,0 +1,2131 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::components::Page,
+    model::{
+        annotation::{AnnotationSubject, AnnotationsDto, NoteDto, NoteVisibility, TagDto},
+        announcement::{AnnouncementAudience, AnnouncementDto},
+        approval::{ApprovalRequestDto, ApprovalStatus},
+        data_api::{ApiKeyDto, SavedQueryDto, SavedQueryParameterDto, SavedQueryParameterKind},
+        member::{
+            MemberBulkAction, MemberBulkActionDto, MemberFilterDto, MemberList,
+            SaveMemberFilterDto, SavedMemberFilterDto,
+        },
+        onboarding::{IncompleteOnboardingDto, OnboardingStepDto, OnboardingStepKind},
+        page::{PageRevisionDto, PageSummaryDto},
+        reauth_campaign::ReauthCampaignDto,
+        telemetry::TelemetryStatusDto,
+        widget::WidgetDto,
+    },
+};
+
+#[component]
+pub fn Admin() -> Element {
+    let mut telemetry = use_signal(|| None::<TelemetryStatusDto>);
+    let mut widgets = use_signal(Vec::<WidgetDto>::new);
+    let mut pages = use_signal(Vec::<PageSummaryDto>::new);
+    let mut announcements = use_signal(Vec::<AnnouncementDto>::new);
+    let mut reauth_campaigns = use_signal(Vec::<ReauthCampaignDto>::new);
+    let mut saved_queries = use_signal(Vec::<SavedQueryDto>::new);
+    let mut api_keys = use_signal(Vec::<ApiKeyDto>::new);
+
+    // Retrieve telemetry status on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::get_telemetry_status::get_telemetry_status;
+
+        let future = use_resource(|| async move { get_telemetry_status().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                telemetry.set(Some(result.clone()));
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    // Retrieve widgets on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::widget::get_widgets;
+
+        let future = use_resource(|| async move { get_widgets().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                widgets.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    // Retrieve pages on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::page::get_pages;
+
+        let future = use_resource(|| async move { get_pages().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                pages.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    // Retrieve announcements on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::announcement::get_announcements;
+
+        let future = use_resource(|| async move { get_announcements().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                announcements.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    // Retrieve re-authentication campaigns on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::reauth_campaign::get_reauth_campaigns;
+
+        let future = use_resource(|| async move { get_reauth_campaigns().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                reauth_campaigns.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    // Retrieve saved queries on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::data_api::get_saved_queries;
+
+        let future = use_resource(|| async move { get_saved_queries().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                saved_queries.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    // Retrieve API keys on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::data_api::get_api_keys;
+
+        let future = use_resource(|| async move { get_api_keys().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                api_keys.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    rsx!(
+        Title { "Admin | Bifrost" }
+        Meta {
+            name: "description",
+            content: "Bifrost deployment administration."
+        }
+        Page { class: "flex flex-col items-center",
+            div { class: "w-full max-w-[960px] pt-4 flex flex-col gap-4 px-4",
+                h1 { class: "text-2xl font-bold", "Admin" }
+                ReadOnlyCard {}
+                if let Some(status) = telemetry.read().as_ref() {
+                    TelemetryCard { status: status.clone() }
+                } else {
+                    div { class: "skeleton h-32 w-full" }
+                }
+                WidgetsCard { widgets: widgets }
+                PagesCard { pages: pages }
+                AnnouncementsCard { announcements: announcements }
+                ReauthCampaignsCard { campaigns: reauth_campaigns }
+                OnboardingCard {}
+                SavedQueriesCard { queries: saved_queries }
+                ApiKeysCard { api_keys: api_keys }
+                AnnotationsCard {}
+                MembersCard {}
+                ApprovalsCard {}
+            }
+        }
+    )
+}
+
+#[component]
+fn ReadOnlyCard() -> Element {
+    let mut enabled = use_signal(|| None::<bool>);
+    let mut status = use_signal(|| None::<String>);
+
+    // Retrieve read-only mode on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::maintenance::get_read_only;
+
+        let future = use_resource(|| async move { get_read_only().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                enabled.set(Some(result.enabled));
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let toggle = move |_| {
+        let enable = enabled() != Some(true);
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::maintenance::set_read_only;
+
+            match set_read_only(enable).await {
+                Ok(result) => {
+                    enabled.set(Some(result.enabled));
+                    status.set(None);
+                }
+                Err(err) => {
+                    status.set(Some(err));
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (enable, enabled, status);
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                div { class: "flex items-center gap-2",
+                    h2 { class: "card-title", "Read-Only Mode" }
+                    if enabled() == Some(true) {
+                        span { class: "badge badge-warning", "Enabled" }
+                    } else if enabled() == Some(false) {
+                        span { class: "badge badge-outline", "Disabled" }
+                    }
+                }
+                p {
+                    "Enable before database maintenance. Changes are rejected until it is disabled "
+                    "again while pages keep loading, and background jobs wait in the queue."
+                }
+                div {
+                    button {
+                        class: if enabled() == Some(true) { "btn btn-sm btn-primary" } else { "btn btn-sm btn-warning" },
+                        disabled: enabled().is_none(),
+                        onclick: toggle,
+                        if enabled() == Some(true) {
+                            "Disable read-only mode"
+                        } else {
+                            "Enable read-only mode"
+                        }
+                    }
+                }
+                if let Some(message) = status.read().as_ref() {
+                    p { class: "text-sm", "{message}" }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn TelemetryCard(status: TelemetryStatusDto) -> Element {
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                div { class: "flex items-center gap-2",
+                    h2 { class: "card-title", "Anonymous Telemetry" }
+                    if status.enabled {
+                        span { class: "badge badge-primary", "Enabled" }
+                    } else {
+                        span { class: "badge badge-outline", "Disabled" }
+                    }
+                }
+                if let Some(endpoint) = &status.endpoint {
+                    p { "Once a day, the report below is sent to " code { "{endpoint}" } "." }
+                } else {
+                    p {
+                        "Nothing is sent. Set " code { "TELEMETRY_ENDPOINT" }
+                        " to share the report below and help maintainers prioritize features."
+                    }
+                }
+                table { class: "table",
+                    tbody {
+                        tr { th { "Version" } td { "{status.report.version}" } }
+                        tr { th { "Users" } td { "{status.report.user_count_bucket}" } }
+                        tr {
+                            th { "Enabled features" }
+                            td {
+                                if status.report.features.is_empty() {
+                                    "None"
+                                } else {
+                                    "{status.report.features.join(\", \")}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn WidgetsCard(widgets: Signal<Vec<WidgetDto>>) -> Element {
+    let mut corporation_id = use_signal(String::new);
+
+    let create = move |_| {
+        let Ok(corporation_id) = corporation_id.read().trim().parse::<i64>() else {
+            tracing::error!("Corporation ID must be a number");
+            return;
+        };
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{
+                client::util::widget::create_widget,
+                model::widget::{CreateWidgetDto, WidgetKind},
+            };
+
+            let widget = CreateWidgetDto {
+                kind: WidgetKind::CorporationMemberCount,
+                corporation_id,
+            };
+
+            match create_widget(widget).await {
+                Ok(widget) => widgets.write().push(widget),
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (corporation_id, widgets);
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Embeddable Widgets" }
+                p {
+                    "Widgets can be embedded on external websites as an iframe or fetched as JSON. "
+                    "Anyone with a widget's link can view it, so revoke widgets you no longer use."
+                }
+                div { class: "flex gap-2",
+                    input {
+                        class: "input flex-1",
+                        placeholder: "Corporation ID",
+                        value: "{corporation_id}",
+                        oninput: move |event| corporation_id.set(event.value()),
+                    }
+                    button { class: "btn btn-primary", onclick: create, "Create member count widget" }
+                }
+                for widget in widgets.read().iter() {
+                    WidgetRow { key: "{widget.id}", widgets: widgets, widget: widget.clone() }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn WidgetRow(widgets: Signal<Vec<WidgetDto>>, widget: WidgetDto) -> Element {
+    let revoke = move |_| {
+        let widget_id = widget.id;
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::widget::delete_widget;
+
+            match delete_widget(widget_id).await {
+                Ok(()) => {
+                    widgets.write().retain(|widget| widget.id != widget_id);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (widget_id, widgets);
+    };
+
+    rsx!(
+        div { class: "flex flex-row items-center gap-4 border-t border-base-300 pt-2",
+            div { class: "flex flex-col flex-1 min-w-0",
+                span { class: "font-semibold", "{widget.corporation_name}" }
+                span { class: "text-sm opacity-70", "{widget.kind.description()}" }
+                code { class: "text-xs truncate", "/api/widgets/{widget.token}/embed" }
+            }
+            button { class: "btn btn-outline btn-error btn-sm", onclick: revoke, "Revoke" }
+        }
+    )
+}
+
+#[component]
+fn AnnouncementsCard(announcements: Signal<Vec<AnnouncementDto>>) -> Element {
+    let mut title = use_signal(String::new);
+    let mut body = use_signal(String::new);
+    let mut audience = use_signal(|| AnnouncementAudience::All);
+    let mut audience_id = use_signal(String::new);
+    let mut send_push = use_signal(|| false);
+    let mut send_discord = use_signal(|| false);
+
+    let post = move |_| {
+        let target = *audience.read();
+        let target_id = match target {
+            AnnouncementAudience::All => None,
+            _ => {
+                let Ok(target_id) = audience_id.read().trim().parse::<i64>() else {
+                    tracing::error!("Corporation or alliance ID must be a number");
+                    return;
+                };
+                Some(target_id)
+            }
+        };
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{
+                client::util::announcement::create_announcement,
+                model::announcement::CreateAnnouncementDto,
+            };
+
+            let announcement = CreateAnnouncementDto {
+                title: title.read().clone(),
+                body: body.read().clone(),
+                audience: target,
+                audience_id: target_id,
+                send_push: *send_push.read(),
+                send_discord: *send_discord.read(),
+            };
+
+            match create_announcement(announcement).await {
+                Ok(announcement) => {
+                    announcements.write().insert(0, announcement);
+                    title.set(String::new());
+                    body.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (
+            target_id,
+            title,
+            body,
+            send_push,
+            send_discord,
+            announcements,
+        );
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Announcements" }
+                p {
+                    "Announcements are written in Markdown and shown in the inbox of every member "
+                    "of the audience. Read counts show how many recipients have opened them."
+                }
+                input {
+                    class: "input w-full",
+                    placeholder: "Title",
+                    value: "{title}",
+                    oninput: move |event| title.set(event.value()),
+                }
+                textarea {
+                    class: "textarea w-full h-32 font-mono",
+                    placeholder: "Message in Markdown",
+                    value: "{body}",
+                    oninput: move |event| body.set(event.value()),
+                }
+                div { class: "flex flex-wrap items-center gap-4",
+                    select {
+                        class: "select w-56",
+                        onchange: move |event| {
+                            if let Some(selected) = AnnouncementAudience::from_name(&event.value()) {
+                                audience.set(selected);
+                            }
+                        },
+                        for choice in AnnouncementAudience::ALL {
+                            option {
+                                value: choice.as_str(),
+                                selected: choice == *audience.read(),
+                                "{choice.description()}"
+                            }
+                        }
+                    }
+                    if *audience.read() != AnnouncementAudience::All {
+                        input {
+                            class: "input w-48",
+                            placeholder: "Corporation or alliance ID",
+                            value: "{audience_id}",
+                            oninput: move |event| audience_id.set(event.value()),
+                        }
+                    }
+                    label { class: "flex items-center gap-2",
+                        input {
+                            r#type: "checkbox",
+                            class: "checkbox",
+                            checked: *send_push.read(),
+                            onchange: move |_| {
+                                let checked = !*send_push.read();
+                                send_push.set(checked);
+                            },
+                        }
+                        "Push notification"
+                    }
+                    label { class: "flex items-center gap-2",
+                        input {
+                            r#type: "checkbox",
+                            class: "checkbox",
+                            checked: *send_discord.read(),
+                            onchange: move |_| {
+                                let checked = !*send_discord.read();
+                                send_discord.set(checked);
+                            },
+                        }
+                        "Discord"
+                    }
+                    button { class: "btn btn-primary ml-auto", onclick: post, "Post announcement" }
+                }
+                for announcement in announcements.read().iter() {
+                    div {
+                        key: "{announcement.id}",
+                        class: "flex flex-row items-center gap-4 border-t border-base-300 pt-2",
+                        div { class: "flex flex-col flex-1 min-w-0",
+                            span { class: "font-semibold", "{announcement.title}" }
+                            span { class: "text-sm opacity-70",
+                                "{announcement.audience.description()}"
+                                if let Some(audience_id) = announcement.audience_id {
+                                    " ({audience_id})"
+                                }
+                                " · {announcement.created_at}"
+                            }
+                        }
+                        span { class: "text-sm",
+                            "{announcement.read_count} / {announcement.recipient_count} read"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn ReauthCampaignsCard(campaigns: Signal<Vec<ReauthCampaignDto>>) -> Element {
+    let mut name = use_signal(String::new);
+    let mut scopes = use_signal(String::new);
+    let mut audience = use_signal(|| AnnouncementAudience::All);
+    let mut audience_id = use_signal(String::new);
+    let mut deadline = use_signal(String::new);
+
+    let launch = move |_| {
+        let target = *audience.read();
+        let target_id = match target {
+            AnnouncementAudience::All => None,
+            _ => {
+                let Ok(target_id) = audience_id.read().trim().parse::<i64>() else {
+                    tracing::error!("Corporation or alliance ID must be a number");
+                    return;
+                };
+                Some(target_id)
+            }
+        };
+        let campaign_deadline = match deadline.read().trim() {
+            "" => None,
+            value => {
+                let Ok(value) = chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M")
+                else {
+                    tracing::error!("Deadline must be a valid date and time");
+                    return;
+                };
+                Some(value)
+            }
+        };
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{
+                client::util::reauth_campaign::create_reauth_campaign,
+                model::reauth_campaign::CreateReauthCampaignDto,
+            };
+
+            let campaign = CreateReauthCampaignDto {
+                name: name.read().clone(),
+                scopes: vec![scopes.read().clone()],
+                audience: target,
+                audience_id: target_id,
+                deadline: campaign_deadline,
+            };
+
+            match create_reauth_campaign(campaign).await {
+                Ok(campaign) => {
+                    campaigns.write().insert(0, campaign);
+                    name.set(String::new());
+                    scopes.set(String::new());
+                    deadline.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (
+            target_id,
+            campaign_deadline,
+            name,
+            scopes,
+            deadline,
+            campaigns,
+        );
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Re-authentication Campaigns" }
+                p {
+                    "Campaigns ask every member of the audience to log in again and grant new ESI "
+                    "scopes. Members who haven't granted them by the deadline lose access until they do."
+                }
+                input {
+                    class: "input w-full",
+                    placeholder: "Name",
+                    value: "{name}",
+                    oninput: move |event| name.set(event.value()),
+                }
+                input {
+                    class: "input w-full font-mono",
+                    placeholder: "Space-separated ESI scopes",
+                    value: "{scopes}",
+                    oninput: move |event| scopes.set(event.value()),
+                }
+                div { class: "flex flex-wrap items-center gap-4",
+                    select {
+                        class: "select w-56",
+                        onchange: move |event| {
+                            if let Some(selected) = AnnouncementAudience::from_name(&event.value()) {
+                                audience.set(selected);
+                            }
+                        },
+                        for choice in AnnouncementAudience::ALL {
+                            option {
+                                value: choice.as_str(),
+                                selected: choice == *audience.read(),
+                                "{choice.description()}"
+                            }
+                        }
+                    }
+                    if *audience.read() != AnnouncementAudience::All {
+                        input {
+                            class: "input w-48",
+                            placeholder: "Corporation or alliance ID",
+                            value: "{audience_id}",
+                            oninput: move |event| audience_id.set(event.value()),
+                        }
+                    }
+                    label { class: "flex items-center gap-2",
+                        "Deadline (UTC)"
+                        input {
+                            r#type: "datetime-local",
+                            class: "input w-56",
+                            value: "{deadline}",
+                            oninput: move |event| deadline.set(event.value()),
+                        }
+                    }
+                    button { class: "btn btn-primary ml-auto", onclick: launch, "Launch campaign" }
+                }
+                for campaign in campaigns.read().iter() {
+                    div {
+                        key: "{campaign.id}",
+                        class: "flex flex-row items-center gap-4 border-t border-base-300 pt-2",
+                        div { class: "flex flex-col flex-1 min-w-0",
+                            span { class: "font-semibold", "{campaign.name}" }
+                            span { class: "text-sm font-mono truncate", {campaign.scopes.join(" ")} }
+                            span { class: "text-sm opacity-70",
+                                "{campaign.audience.description()}"
+                                if let Some(audience_id) = campaign.audience_id {
+                                    " ({audience_id})"
+                                }
+                                if let Some(deadline) = campaign.deadline {
+                                    " · due {deadline} UTC"
+                                }
+                            }
+                        }
+                        span { class: "text-sm",
+                            "{campaign.completed_count} / {campaign.user_count} ({campaign.completion_percent()}%)"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn OnboardingCard() -> Element {
+    let mut steps = use_signal(Vec::<OnboardingStepDto>::new);
+    let mut incomplete = use_signal(Vec::<IncompleteOnboardingDto>::new);
+    let mut title = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut kind = use_signal(|| OnboardingStepKind::Manual);
+    let mut target = use_signal(String::new);
+
+    // Retrieve onboarding steps on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::onboarding::get_onboarding_steps;
+
+        let future = use_resource(|| async move { get_onboarding_steps().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                steps.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    // Retrieve users with incomplete onboarding on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::onboarding::get_incomplete_onboarding;
+
+        let future = use_resource(|| async move { get_incomplete_onboarding().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                incomplete.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let add = move |_| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{
+                client::util::onboarding::create_onboarding_step,
+                model::onboarding::CreateOnboardingStepDto,
+            };
+
+            let step = CreateOnboardingStepDto {
+                title: title.read().clone(),
+                description: description.read().clone(),
+                kind: *kind.read(),
+                target: Some(target.read().clone()),
+            };
+
+            match create_onboarding_step(step).await {
+                Ok(step) => {
+                    steps.write().push(step);
+                    title.set(String::new());
+                    description.set(String::new());
+                    target.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (title, description, kind, target, steps);
+    };
+
+    let delete = move |step_id: i32| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::onboarding::delete_onboarding_step;
+
+            match delete_onboarding_step(step_id).await {
+                Ok(()) => {
+                    steps.write().retain(|step| step.id != step_id);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (step_id, steps);
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Onboarding" }
+                p {
+                    "New members see these steps as a checklist on their dashboard. Linking alts "
+                    "and granting scopes are completed automatically; other steps are marked "
+                    "completed by the member."
+                }
+                div { class: "flex flex-wrap items-center gap-4",
+                    input {
+                        class: "input flex-1",
+                        placeholder: "Title",
+                        value: "{title}",
+                        oninput: move |event| title.set(event.value()),
+                    }
+                    select {
+                        class: "select w-56",
+                        onchange: move |event| {
+                            if let Some(selected) = OnboardingStepKind::from_name(&event.value()) {
+                                kind.set(selected);
+                            }
+                        },
+                        for choice in OnboardingStepKind::ALL {
+                            option {
+                                value: choice.as_str(),
+                                selected: choice == *kind.read(),
+                                "{choice.description()}"
+                            }
+                        }
+                    }
+                }
+                input {
+                    class: "input w-full",
+                    placeholder: "Description",
+                    value: "{description}",
+                    oninput: move |event| description.set(event.value()),
+                }
+                div { class: "flex flex-wrap items-center gap-4",
+                    if let Some(placeholder) = kind.read().target_description() {
+                        input {
+                            class: "input flex-1 font-mono",
+                            placeholder: placeholder,
+                            value: "{target}",
+                            oninput: move |event| target.set(event.value()),
+                        }
+                    }
+                    button { class: "btn btn-primary ml-auto", onclick: add, "Add step" }
+                }
+                for step in steps.read().iter().cloned() {
+                    div {
+                        key: "{step.id}",
+                        class: "flex flex-row items-center gap-4 border-t border-base-300 pt-2",
+                        div { class: "flex flex-col flex-1 min-w-0",
+                            span { class: "font-semibold", "{step.title}" }
+                            span { class: "text-sm opacity-70",
+                                "{step.kind.description()}"
+                                if let Some(target) = step.target.as_ref() {
+                                    " · {target}"
+                                }
+                            }
+                        }
+                        button {
+                            class: "btn btn-sm btn-outline",
+                            onclick: move |_| delete(step.id),
+                            "Delete"
+                        }
+                    }
+                }
+                div { class: "divider my-0" }
+                h3 { class: "font-semibold", "Incomplete onboarding" }
+                if incomplete.read().is_empty() {
+                    p { class: "text-sm opacity-70", "Every member has completed onboarding." }
+                } else {
+                    table { class: "table table-sm",
+                        thead {
+                            tr {
+                                th { "Member" }
+                                th { "Progress" }
+                                th { "Missing" }
+                            }
+                        }
+                        tbody {
+                            for user in incomplete.read().iter() {
+                                tr { key: "{user.user_id}",
+                                    td {
+                                        if let Some(name) = user.main_character_name.as_ref() {
+                                            "{name}"
+                                        } else {
+                                            "User {user.user_id}"
+                                        }
+                                    }
+                                    td { "{user.completed_count} / {user.step_count}" }
+                                    td { {user.missing_steps.join(", ")} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn SavedQueriesCard(queries: Signal<Vec<SavedQueryDto>>) -> Element {
+    let mut slug = use_signal(String::new);
+    let mut name = use_signal(String::new);
+    let mut sql = use_signal(String::new);
+    let mut parameters = use_signal(String::new);
+    let mut row_limit = use_signal(|| "1000".to_string());
+
+    let save = move |_| {
+        let Ok(limit) = row_limit.read().trim().parse::<u32>() else {
+            tracing::error!("Row limit must be a number");
+            return;
+        };
+        let mut query_parameters = Vec::new();
+        for parameter in parameters.read().split_whitespace() {
+            let Some((parameter_name, kind)) = parameter
+                .split_once(':')
+                .and_then(|(name, kind)| Some((name, SavedQueryParameterKind::from_name(kind)?)))
+            else {
+                tracing::error!("Parameters must be written as name:integer or name:text");
+                return;
+            };
+            query_parameters.push(SavedQueryParameterDto {
+                name: parameter_name.to_string(),
+                kind,
+            });
+        }
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{
+                client::util::data_api::save_saved_query, model::data_api::SaveSavedQueryDto,
+            };
+
+            let query_slug = slug.read().trim().to_string();
+            let query = SaveSavedQueryDto {
+                name: name.read().clone(),
+                sql: sql.read().clone(),
+                parameters: query_parameters,
+                row_limit: limit,
+            };
+
+            match save_saved_query(&query_slug, query).await {
+                Ok(query) => {
+                    let mut queries = queries.write();
+                    queries.retain(|existing| existing.slug != query.slug);
+                    queries.push(query);
+                    queries.sort_by(|a, b| a.slug.cmp(&b.slug));
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (limit, query_parameters, slug, name, sql, queries);
+    };
+
+    let delete = use_callback(move |query_slug: String| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::data_api::delete_saved_query;
+
+            match delete_saved_query(&query_slug).await {
+                Ok(()) => {
+                    queries.write().retain(|query| query.slug != query_slug);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (query_slug, queries);
+    });
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Saved Queries" }
+                p {
+                    "Saved queries are read-only SELECT statements BI tools can run at "
+                    "/api/data/queries/{slug} with an API key. Parameters are passed as query string "
+                    "parameters and bound to $1, $2, ... in the order they are listed."
+                }
+                div { class: "flex gap-2",
+                    input {
+                        class: "input w-48",
+                        placeholder: "Slug",
+                        value: "{slug}",
+                        oninput: move |event| slug.set(event.value()),
+                    }
+                    input {
+                        class: "input flex-1",
+                        placeholder: "Name",
+                        value: "{name}",
+                        oninput: move |event| name.set(event.value()),
+                    }
+                }
+                textarea {
+                    class: "textarea w-full h-32 font-mono",
+                    placeholder: "SELECT ... WHERE corporation_id = $1",
+                    value: "{sql}",
+                    oninput: move |event| sql.set(event.value()),
+                }
+                div { class: "flex gap-2",
+                    input {
+                        class: "input flex-1 font-mono",
+                        placeholder: "Parameters, e.g. corporation_id:integer",
+                        value: "{parameters}",
+                        oninput: move |event| parameters.set(event.value()),
+                    }
+                    input {
+                        class: "input w-32",
+                        placeholder: "Row limit",
+                        value: "{row_limit}",
+                        oninput: move |event| row_limit.set(event.value()),
+                    }
+                    button { class: "btn btn-primary", onclick: save, "Save query" }
+                }
+                for query in queries.read().iter() {
+                    div {
+                        key: "{query.slug}",
+                        class: "flex flex-row items-center gap-4 border-t border-base-300 pt-2",
+                        div { class: "flex flex-col flex-1 min-w-0",
+                            span { class: "font-semibold", "{query.name}" }
+                            span { class: "text-sm font-mono truncate", "/api/data/queries/{query.slug}" }
+                            span { class: "text-sm opacity-70",
+                                "Up to {query.row_limit} rows"
+                                for parameter in query.parameters.iter() {
+                                    " · {parameter.name} ({parameter.kind.as_str()})"
+                                }
+                            }
+                        }
+                        button {
+                            class: "btn btn-sm btn-error btn-outline",
+                            onclick: {
+                                let query_slug = query.slug.clone();
+                                move |_| delete.call(query_slug.clone())
+                            },
+                            "Delete"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn ApiKeysCard(api_keys: Signal<Vec<ApiKeyDto>>) -> Element {
+    let mut name = use_signal(String::new);
+    let mut created_key = use_signal(|| None::<String>);
+
+    let create = move |_| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{client::util::data_api::create_api_key, model::data_api::CreateApiKeyDto};
+
+            let api_key = CreateApiKeyDto {
+                name: name.read().clone(),
+            };
+
+            match create_api_key(api_key).await {
+                Ok(created) => {
+                    api_keys.write().push(created.api_key);
+                    created_key.set(Some(created.key));
+                    name.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (name, created_key, api_keys);
+    };
+
+    let revoke = use_callback(move |api_key_id: i32| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::data_api::delete_api_key;
+
+            match delete_api_key(api_key_id).await {
+                Ok(()) => {
+                    api_keys.write().retain(|api_key| api_key.id != api_key_id);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (api_key_id, api_keys);
+    });
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "API Keys" }
+                p {
+                    "API keys let BI tools such as Grafana or Metabase run saved queries by sending "
+                    "an Authorization: Bearer header. Keys are shown once when created."
+                }
+                div { class: "flex gap-2",
+                    input {
+                        class: "input flex-1",
+                        placeholder: "Tool name",
+                        value: "{name}",
+                        oninput: move |event| name.set(event.value()),
+                    }
+                    button { class: "btn btn-primary", onclick: create, "Create API key" }
+                }
+                if let Some(key) = created_key.read().as_ref() {
+                    div { class: "alert", role: "alert",
+                        span { "Copy this key now, it won't be shown again: " }
+                        code { class: "font-mono break-all", "{key}" }
+                    }
+                }
+                for api_key in api_keys.read().iter() {
+                    div {
+                        key: "{api_key.id}",
+                        class: "flex flex-row items-center gap-4 border-t border-base-300 pt-2",
+                        div { class: "flex flex-col flex-1 min-w-0",
+                            span { class: "font-semibold", "{api_key.name}" }
+                            span { class: "text-sm opacity-70",
+                                if let Some(last_used_at) = api_key.last_used_at {
+                                    "Last used {last_used_at}"
+                                } else {
+                                    "Never used"
+                                }
+                            }
+                        }
+                        button {
+                            class: "btn btn-sm btn-error btn-outline",
+                            onclick: {
+                                let api_key_id = api_key.id;
+                                move |_| revoke.call(api_key_id)
+                            },
+                            "Revoke"
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn PagesCard(pages: Signal<Vec<PageSummaryDto>>) -> Element {
+    let mut slug = use_signal(String::new);
+    let mut title = use_signal(String::new);
+    let mut content = use_signal(String::new);
+    let mut revisions = use_signal(Vec::<PageRevisionDto>::new);
+
+    let edit = move |page_slug: String| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::page::{get_page, get_page_revisions};
+
+            match get_page(&page_slug).await {
+                Ok(page) => {
+                    slug.set(page.slug);
+                    title.set(page.title);
+                    content.set(page.content);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                    return;
+                }
+            }
+            match get_page_revisions(&page_slug).await {
+                Ok(result) => revisions.set(result),
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (page_slug, slug, title, content, revisions);
+    };
+
+    let save = move |_| {
+        let page_slug = slug.read().trim().to_string();
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{
+                client::util::page::{get_page_revisions, save_page},
+                model::page::SavePageDto,
+            };
+
+            let page = SavePageDto {
+                title: title.read().clone(),
+                content: content.read().clone(),
+            };
+
+            match save_page(&page_slug, page).await {
+                Ok(page) => {
+                    let summary = PageSummaryDto {
+                        slug: page.slug.clone(),
+                        title: page.title.clone(),
+                        updated_at: page.updated_at,
+                    };
+                    let mut pages = pages.write();
+                    pages.retain(|existing| existing.slug != summary.slug);
+                    pages.push(summary);
+                    pages.sort_by(|a, b| a.title.cmp(&b.title));
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                    return;
+                }
+            }
+            match get_page_revisions(&page_slug).await {
+                Ok(result) => revisions.set(result),
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (page_slug, title, content, pages, revisions);
+    };
+
+    let restore = use_callback(move |revision_id: i32| {
+        let page_slug = slug.read().clone();
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::page::{get_page_revisions, restore_page_revision};
+
+            match restore_page_revision(&page_slug, revision_id).await {
+                Ok(page) => {
+                    title.set(page.title);
+                    content.set(page.content);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                    return;
+                }
+            }
+            match get_page_revisions(&page_slug).await {
+                Ok(result) => revisions.set(result),
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (page_slug, revision_id, title, content, revisions);
+    });
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Pages" }
+                p {
+                    "Pages are written in Markdown and published at "
+                    code { "/pages/<slug>" }
+                    ". Add them to the navigation bar with "
+                    code { "BRANDING_NAV_LINKS" }
+                    "."
+                }
+                for page in pages.read().iter() {
+                    PageRow { key: "{page.slug}", pages: pages, page: page.clone(), on_edit: edit }
+                }
+                div { class: "flex gap-2",
+                    input {
+                        class: "input w-48",
+                        placeholder: "Slug",
+                        value: "{slug}",
+                        oninput: move |event| slug.set(event.value()),
+                    }
+                    input {
+                        class: "input flex-1",
+                        placeholder: "Title",
+                        value: "{title}",
+                        oninput: move |event| title.set(event.value()),
+                    }
+                }
+                textarea {
+                    class: "textarea w-full h-64 font-mono",
+                    placeholder: "Content in Markdown",
+                    value: "{content}",
+                    oninput: move |event| content.set(event.value()),
+                }
+                button { class: "btn btn-primary self-end", onclick: save, "Save page" }
+                if !revisions.read().is_empty() {
+                    h3 { class: "font-semibold", "Revisions" }
+                    for revision in revisions.read().iter() {
+                        div {
+                            key: "{revision.id}",
+                            class: "flex flex-row items-center gap-4 border-t border-base-300 pt-2",
+                            span { class: "flex-1", "{revision.title}" }
+                            span { class: "text-sm opacity-70", "{revision.created_at}" }
+                            button {
+                                class: "btn btn-outline btn-sm",
+                                onclick: {
+                                    let revision_id = revision.id;
+                                    move |_| restore.call(revision_id)
+                                },
+                                "Restore"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn PageRow(
+    pages: Signal<Vec<PageSummaryDto>>,
+    page: PageSummaryDto,
+    on_edit: EventHandler<String>,
+) -> Element {
+    let page_slug = page.slug.clone();
+    let delete = move |_| {
+        let page_slug = page_slug.clone();
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::page::delete_page;
+
+            match delete_page(&page_slug).await {
+                Ok(()) => {
+                    pages.write().retain(|page| page.slug != page_slug);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (page_slug, pages);
+    };
+
+    rsx!(
+        div { class: "flex flex-row items-center gap-4 border-t border-base-300 pt-2",
+            div { class: "flex flex-col flex-1 min-w-0",
+                a { class: "font-semibold link", href: "/pages/{page.slug}", target: "_blank", "{page.title}" }
+                code { class: "text-xs truncate", "/pages/{page.slug}" }
+            }
+            button {
+                class: "btn btn-outline btn-sm",
+                onclick: {
+                    let page_slug = page.slug.clone();
+                    move |_| on_edit.call(page_slug.clone())
+                },
+                "Edit"
+            }
+            button { class: "btn btn-outline btn-error btn-sm", onclick: delete, "Delete" }
+        }
+    )
+}
+
+#[component]
+fn AnnotationsCard() -> Element {
+    let mut subject = use_signal(|| AnnotationSubject::User);
+    let mut subject_id = use_signal(String::new);
+    let mut annotations = use_signal(|| None::<AnnotationsDto>);
+    let mut tag_name = use_signal(String::new);
+    let mut note_body = use_signal(String::new);
+    let mut note_visibility = use_signal(|| NoteVisibility::Officers);
+
+    let load = move |_| {
+        let target = *subject.read();
+        let Ok(target_id) = subject_id.read().trim().parse::<i64>() else {
+            tracing::error!("Subject ID must be a number");
+            return;
+        };
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::annotation::get_annotations;
+
+            match get_annotations(target, target_id).await {
+                Ok(loaded) => annotations.set(Some(loaded)),
+                Err(err) => {
+                    annotations.set(None);
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (target, target_id, annotations);
+    };
+
+    let add_tag = move |_| {
+        let Some((target, target_id)) = annotations
+            .read()
+            .as_ref()
+            .map(|loaded| (loaded.subject, loaded.subject_id))
+        else {
+            return;
+        };
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{client::util::annotation::add_tag, model::annotation::CreateTagDto};
+
+            let tag = CreateTagDto {
+                name: tag_name.read().clone(),
+            };
+
+            match add_tag(target, target_id, tag).await {
+                Ok(tag) => {
+                    if let Some(loaded) = annotations.write().as_mut() {
+                        if !loaded.tags.iter().any(|existing| existing.id == tag.id) {
+                            loaded.tags.push(tag);
+                            loaded.tags.sort_by(|a, b| a.name.cmp(&b.name));
+                        }
+                    }
+                    tag_name.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (target, target_id, tag_name);
+    };
+
+    let add_note = move |_| {
+        let Some((target, target_id)) = annotations
+            .read()
+            .as_ref()
+            .map(|loaded| (loaded.subject, loaded.subject_id))
+        else {
+            return;
+        };
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{client::util::annotation::add_note, model::annotation::CreateNoteDto};
+
+            let note = CreateNoteDto {
+                body: note_body.read().clone(),
+                visibility: *note_visibility.read(),
+            };
+
+            match add_note(target, target_id, note).await {
+                Ok(note) => {
+                    if let Some(loaded) = annotations.write().as_mut() {
+                        loaded.notes.insert(0, note);
+                    }
+                    note_body.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (target, target_id, note_body);
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Tags & notes" }
+                p {
+                    "Tag users, characters, and corporations and keep notes about them. Notes are "
+                    "shared with all officers unless kept to yourself."
+                }
+                div { class: "flex flex-wrap items-center gap-4",
+                    select {
+                        class: "select w-40",
+                        onchange: move |event| {
+                            if let Some(selected) = AnnotationSubject::from_name(&event.value()) {
+                                subject.set(selected);
+                            }
+                        },
+                        for choice in AnnotationSubject::ALL {
+                            option {
+                                value: choice.as_str(),
+                                selected: choice == *subject.read(),
+                                "{choice.description()}"
+                            }
+                        }
+                    }
+                    input {
+                        class: "input w-48",
+                        placeholder: "User, character, or corporation ID",
+                        value: "{subject_id}",
+                        oninput: move |event| subject_id.set(event.value()),
+                    }
+                    button { class: "btn btn-primary", onclick: load, "Load" }
+                }
+                if let Some(loaded) = annotations.read().as_ref() {
+                    h3 { class: "font-semibold",
+                        "{loaded.subject.description()} {loaded.subject_id}"
+                    }
+                    div { class: "flex flex-wrap items-center gap-2",
+                        for tag in loaded.tags.iter() {
+                            TagBadge {
+                                key: "{tag.id}",
+                                annotations: annotations,
+                                tag: tag.clone(),
+                            }
+                        }
+                        input {
+                            class: "input input-sm w-40",
+                            placeholder: "New tag",
+                            value: "{tag_name}",
+                            oninput: move |event| tag_name.set(event.value()),
+                        }
+                        button { class: "btn btn-sm", onclick: add_tag, "Add tag" }
+                    }
+                    textarea {
+                        class: "textarea w-full h-24",
+                        placeholder: "Note",
+                        value: "{note_body}",
+                        oninput: move |event| note_body.set(event.value()),
+                    }
+                    div { class: "flex flex-wrap items-center gap-4",
+                        select {
+                            class: "select w-40",
+                            onchange: move |event| {
+                                if let Some(selected) = NoteVisibility::from_name(&event.value()) {
+                                    note_visibility.set(selected);
+                                }
+                            },
+                            for choice in NoteVisibility::ALL {
+                                option {
+                                    value: choice.as_str(),
+                                    selected: choice == *note_visibility.read(),
+                                    "{choice.description()}"
+                                }
+                            }
+                        }
+                        button { class: "btn btn-primary ml-auto", onclick: add_note, "Add note" }
+                    }
+                    for note in loaded.notes.iter() {
+                        NoteRow {
+                            key: "{note.id}",
+                            annotations: annotations,
+                            note: note.clone(),
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn TagBadge(annotations: Signal<Option<AnnotationsDto>>, tag: TagDto) -> Element {
+    let remove = move |_| {
+        let tag_id = tag.id;
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::annotation::remove_tag;
+
+            match remove_tag(tag_id).await {
+                Ok(()) => {
+                    if let Some(loaded) = annotations.write().as_mut() {
+                        loaded.tags.retain(|tag| tag.id != tag_id);
+                    }
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (tag_id, annotations);
+    };
+
+    rsx!(
+        span { class: "badge badge-outline gap-1",
+            "{tag.name}"
+            button { class: "cursor-pointer", title: "Remove tag", onclick: remove, "×" }
+        }
+    )
+}
+
+#[component]
+fn NoteRow(annotations: Signal<Option<AnnotationsDto>>, note: NoteDto) -> Element {
+    let delete = move |_| {
+        let note_id = note.id;
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::annotation::delete_note;
+
+            match delete_note(note_id).await {
+                Ok(()) => {
+                    if let Some(loaded) = annotations.write().as_mut() {
+                        loaded.notes.retain(|note| note.id != note_id);
+                    }
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (note_id, annotations);
+    };
+
+    rsx!(
+        div { class: "flex flex-row items-start gap-4 border-t border-base-300 pt-2",
+            div { class: "flex flex-col flex-1 min-w-0",
+                p { class: "whitespace-pre-wrap", "{note.body}" }
+                span { class: "text-sm opacity-70",
+                    "User {note.created_by_user_id} · {note.created_at} · {note.visibility.description()}"
+                }
+            }
+            button { class: "btn btn-outline btn-error btn-sm", onclick: delete, "Delete" }
+        }
+    )
+}
+
+#[component]
+fn MembersCard() -> Element {
+    let mut list = use_signal(|| MemberList::Users);
+    let mut name = use_signal(String::new);
+    let mut corporation_id = use_signal(String::new);
+    let mut alliance_id = use_signal(String::new);
+    let mut tag = use_signal(String::new);
+    let mut filters = use_signal(Vec::<SavedMemberFilterDto>::new);
+    let mut filter_name = use_signal(String::new);
+    // Listed members as (ID, name, affiliation) of users' main characters or of characters
+    let mut members = use_signal(Vec::<(i64, String, String)>::new);
+    let mut selected = use_signal(Vec::<i64>::new);
+    let mut notification_title = use_signal(String::new);
+    let mut notification_body = use_signal(String::new);
+    let mut status = use_signal(|| None::<String>);
+
+    // Retrieve saved filters on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::member::get_member_filters;
+
+        let future = use_resource(|| async move { get_member_filters().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                filters.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let current_filter = move || {
+        let text = |value: Signal<String>| {
+            let value = value.read().trim().to_string();
+            (!value.is_empty()).then_some(value)
+        };
+
+        MemberFilterDto {
+            name: text(name),
+            corporation_id: corporation_id.read().trim().parse().ok(),
+            alliance_id: alliance_id.read().trim().parse().ok(),
+            tag: text(tag),
+        }
+    };
+
+    let mut search = move || {
+        let target = *list.read();
+        let filter = current_filter();
+        selected.set(Vec::new());
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::member::{get_member_characters, get_member_users};
+
+            let result = match target {
+                MemberList::Users => get_member_users(&filter).await.map(|users| {
+                    users
+                        .into_iter()
+                        .map(|user| {
+                            (
+                                user.user_id as i64,
+                                format!(
+                                    "{} (+{} alts)",
+                                    user.main_character_name,
+                                    user.character_count - 1
+                                ),
+                                affiliation(&user.corporation_name, user.alliance_name.as_deref()),
+                            )
+                        })
+                        .collect()
+                }),
+                MemberList::Characters => get_member_characters(&filter).await.map(|characters| {
+                    characters
+                        .into_iter()
+                        .map(|character| {
+                            (
+                                character.character_id,
+                                character.character_name,
+                                affiliation(
+                                    &character.corporation_name,
+                                    character.alliance_name.as_deref(),
+                                ),
+                            )
+                        })
+                        .collect()
+                }),
+            };
+
+            match result {
+                Ok(loaded) => members.set(loaded),
+                Err(err) => {
+                    members.set(Vec::new());
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (target, filter, members);
+    };
+
+    let save_filter = move |_| {
+        let filter = SaveMemberFilterDto {
+            list: *list.read(),
+            name: filter_name.read().clone(),
+            filter: current_filter(),
+        };
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::member::save_member_filter;
+
+            match save_member_filter(filter).await {
+                Ok(saved) => {
+                    let mut filters = filters.write();
+                    filters.retain(|existing| existing.id != saved.id);
+                    filters.push(saved);
+                    filters.sort_by(|a, b| {
+                        (a.list.as_str(), &a.name).cmp(&(b.list.as_str(), &b.name))
+                    });
+                    filter_name.set(String::new());
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (filter, filters, filter_name);
+    };
+
+    let mut run_action = move |action: MemberBulkAction| {
+        let payload = MemberBulkActionDto {
+            list: *list.read(),
+            ids: selected.read().clone(),
+            action,
+        };
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::member::run_member_bulk_action;
+
+            match run_member_bulk_action(payload).await {
+                Ok(results) => {
+                    let failed = results
+                        .iter()
+                        .filter(|result| result.error.is_some())
+                        .count();
+                    let jobs: usize = results.iter().map(|result| result.job_ids.len()).sum();
+                    status.set(Some(format!(
+                        "Queued {} job(s) for {} member(s), {} skipped",
+                        jobs,
+                        results.len() - failed,
+                        failed
+                    )));
+                }
+                Err(err) => {
+                    status.set(Some(err));
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (payload, status);
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Members" }
+                p {
+                    "Filter users and characters, save the filters you use often, and refresh or "
+                    "notify the selected members in bulk."
+                }
+                div { class: "flex flex-wrap items-center gap-4",
+                    select {
+                        class: "select w-40",
+                        onchange: move |event| {
+                            if let Some(selected_list) = MemberList::from_name(&event.value()) {
+                                list.set(selected_list);
+                                members.set(Vec::new());
+                                selected.set(Vec::new());
+                            }
+                        },
+                        for choice in MemberList::ALL {
+                            option {
+                                value: choice.as_str(),
+                                selected: choice == *list.read(),
+                                "{choice.description()}"
+                            }
+                        }
+                    }
+                    input {
+                        class: "input w-40",
+                        placeholder: "Character name",
+                        value: "{name}",
+                        oninput: move |event| name.set(event.value()),
+                    }
+                    input {
+                        class: "input w-40",
+                        placeholder: "Corporation ID",
+                        value: "{corporation_id}",
+                        oninput: move |event| corporation_id.set(event.value()),
+                    }
+                    input {
+                        class: "input w-40",
+                        placeholder: "Alliance ID",
+                        value: "{alliance_id}",
+                        oninput: move |event| alliance_id.set(event.value()),
+                    }
+                    input {
+                        class: "input w-32",
+                        placeholder: "Tag",
+                        value: "{tag}",
+                        oninput: move |event| tag.set(event.value()),
+                    }
+                    button { class: "btn btn-primary", onclick: move |_| search(), "Search" }
+                }
+                div { class: "flex flex-wrap items-center gap-2",
+                    for saved in filters.read().iter().filter(|saved| saved.list == *list.read()) {
+                        SavedFilterBadge {
+                            key: "{saved.id}",
+                            filters: filters,
+                            saved: saved.clone(),
+                            on_apply: move |filter: MemberFilterDto| {
+                                name.set(filter.name.unwrap_or_default());
+                                corporation_id
+                                    .set(filter.corporation_id.map(|id| id.to_string()).unwrap_or_default());
+                                alliance_id
+                                    .set(filter.alliance_id.map(|id| id.to_string()).unwrap_or_default());
+                                tag.set(filter.tag.unwrap_or_default());
+                                search();
+                            },
+                        }
+                    }
+                    input {
+                        class: "input input-sm w-40",
+                        placeholder: "Filter name",
+                        value: "{filter_name}",
+                        oninput: move |event| filter_name.set(event.value()),
+                    }
+                    button { class: "btn btn-sm", onclick: save_filter, "Save filter" }
+                }
+                if !members.read().is_empty() {
+                    table { class: "table table-sm",
+                        thead {
+                            tr {
+                                th {
+                                    input {
+                                        r#type: "checkbox",
+                                        class: "checkbox checkbox-sm",
+                                        checked: selected.read().len() == members.read().len(),
+                                        onchange: move |event| {
+                                            if event.checked() {
+                                                selected
+                                                    .set(members.read().iter().map(|(id, _, _)| *id).collect());
+                                            } else {
+                                                selected.set(Vec::new());
+                                            }
+                                        },
+                                    }
+                                }
+                                th { "Name" }
+                                th { "Affiliation" }
+                            }
+                        }
+                        tbody {
+                            for (id, member_name, member_affiliation) in members.read().iter().cloned() {
+                                tr { key: "{id}",
+                                    td {
+                                        input {
+                                            r#type: "checkbox",
+                                            class: "checkbox checkbox-sm",
+                                            checked: selected.read().contains(&id),
+                                            onchange: move |event| {
+                                                if event.checked() {
+                                                    selected.write().push(id);
+                                                } else {
+                                                    selected.write().retain(|selected_id| *selected_id != id);
+                                                }
+                                            },
+                                        }
+                                    }
+                                    td { "{member_name}" }
+                                    td { "{member_affiliation}" }
+                                }
+                            }
+                        }
+                    }
+                    div { class: "flex flex-wrap items-center gap-4",
+                        span { class: "text-sm opacity-70", "{selected.read().len()} selected" }
+                        button {
+                            class: "btn",
+                            disabled: selected.read().is_empty(),
+                            onclick: move |_| run_action(MemberBulkAction::ForceRefresh),
+                            "Force refresh"
+                        }
+                        input {
+                            class: "input w-48",
+                            placeholder: "Notification title",
+                            value: "{notification_title}",
+                            oninput: move |event| notification_title.set(event.value()),
+                        }
+                        input {
+                            class: "input w-64",
+                            placeholder: "Notification body",
+                            value: "{notification_body}",
+                            oninput: move |event| notification_body.set(event.value()),
+                        }
+                        button {
+                            class: "btn",
+                            disabled: selected.read().is_empty(),
+                            onclick: move |_| {
+                                run_action(MemberBulkAction::SendNotification {
+                                    title: notification_title.read().clone(),
+                                    body: notification_body.read().clone(),
+                                })
+                            },
+                            "Send notification"
+                        }
+                    }
+                }
+                if let Some(message) = status.read().as_ref() {
+                    p { class: "text-sm", "{message}" }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn ApprovalsCard() -> Element {
+    let mut requests = use_signal(Vec::<ApprovalRequestDto>::new);
+    let mut status = use_signal(|| None::<String>);
+
+    // Retrieve approval requests on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::approval::get_approval_requests;
+
+        let future = use_resource(|| async move { get_approval_requests().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                requests.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let decide = move |request_id: i32, approve: bool| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::approval::decide_approval_request;
+
+            match decide_approval_request(request_id, approve).await {
+                Ok(decided) => {
+                    for request in requests.write().iter_mut() {
+                        if request.id == decided.id {
+                            *request = decided.clone();
+                        }
+                    }
+                    status.set(None);
+                }
+                Err(err) => {
+                    status.set(Some(err));
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (request_id, approve, requests, status);
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-col gap-2",
+                h2 { class: "card-title", "Approvals" }
+                p {
+                    "Sensitive actions configured with APPROVAL_REQUIRED_ACTIONS wait here until "
+                    "an admin other than the requester approves them."
+                }
+                if requests.read().is_empty() {
+                    p { class: "text-sm opacity-70", "No approval requests." }
+                } else {
+                    table { class: "table table-sm",
+                        thead {
+                            tr {
+                                th { "Action" }
+                                th { "Requested by" }
+                                th { "Expires" }
+                                th { "Status" }
+                                th {}
+                            }
+                        }
+                        tbody {
+                            for request in requests.read().iter().cloned() {
+                                tr { key: "{request.id}",
+                                    td { "{request.payload.summary()}" }
+                                    td { "User {request.requested_by_user_id}" }
+                                    td { "{request.expires_at}" }
+                                    td { "{request.status.as_str()}" }
+                                    td {
+                                        if request.status == ApprovalStatus::Pending {
+                                            div { class: "flex gap-2",
+                                                button {
+                                                    class: "btn btn-sm btn-primary",
+                                                    onclick: move |_| decide(request.id, true),
+                                                    "Approve"
+                                                }
+                                                button {
+                                                    class: "btn btn-sm",
+                                                    onclick: move |_| decide(request.id, false),
+                                                    "Reject"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                if let Some(message) = status.read().as_ref() {
+                    p { class: "text-sm", "{message}" }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn SavedFilterBadge(
+    filters: Signal<Vec<SavedMemberFilterDto>>,
+    saved: SavedMemberFilterDto,
+    on_apply: EventHandler<MemberFilterDto>,
+) -> Element {
+    let filter = saved.filter.clone();
+
+    let delete = move |_| {
+        let filter_id = saved.id;
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::member::delete_member_filter;
+
+            match delete_member_filter(filter_id).await {
+                Ok(()) => {
+                    filters.write().retain(|filter| filter.id != filter_id);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (filter_id, filters);
+    };
+
+    rsx!(
+        span { class: "badge badge-outline gap-1",
+            button {
+                class: "cursor-pointer",
+                title: "Apply filter",
+                onclick: move |_| on_apply.call(filter.clone()),
+                "{saved.name}"
+            }
+            button { class: "cursor-pointer", title: "Delete filter", onclick: delete, "×" }
+        }
+    )
+}
+
+/// Formats a corporation and optional alliance name for the member list.
+#[cfg(feature = "web")]
+fn affiliation(corporation_name: &str, alliance_name: Option<&str>) -> String {
+    match alliance_name {
+        Some(alliance_name) => format!("{} / {}", corporation_name, alliance_name),
+        None => corporation_name.to_string(),
+    }
+}
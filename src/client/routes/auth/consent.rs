@@ -0,0 +1,90 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{client::components::Page, model::consent::ConsentDto};
+
+#[component]
+pub fn Consent() -> Element {
+    let mut consents = use_signal(Vec::<ConsentDto>::new);
+
+    // Retrieve consent status on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::user_consent::get_consents;
+
+        let future = use_resource(|| async move { get_consents().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                consents.set(result.clone());
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    rsx!(
+        Title { "Data Sharing | Bifrost" }
+        Meta {
+            name: "description",
+            content: "Choose which of your character data is shared with your organization."
+        }
+        Page { class: "flex flex-col items-center",
+            div { class: "w-full max-w-[960px] pt-4 flex flex-col gap-4 px-4",
+                h1 { class: "text-2xl font-bold", "Data Sharing" }
+                p { class: "opacity-70",
+                    "Your organization requests access to the data below. Revoking access deletes the data already shared."
+                }
+                for (index, consent) in consents.read().iter().enumerate() {
+                    ConsentCard { key: "{consent.category.as_str()}", consents: consents, index: index, consent: consent.clone() }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn ConsentCard(consents: Signal<Vec<ConsentDto>>, index: usize, consent: ConsentDto) -> Element {
+    let toggle = move |_| {
+        let category = consent.category;
+        let granted = !consent.granted;
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::user_consent::set_consent;
+
+            match set_consent(category, granted).await {
+                Ok(()) => {
+                    if let Some(consent) = consents.write().get_mut(index) {
+                        consent.granted = granted;
+                    }
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (category, granted, index, consents);
+    };
+
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-row items-center gap-4",
+                div { class: "flex flex-col flex-1",
+                    h2 { class: "card-title capitalize", "{consent.category.as_str()}" }
+                    p { class: "text-sm opacity-70", "{consent.description}" }
+                }
+                input {
+                    r#type: "checkbox",
+                    class: "toggle toggle-primary",
+                    checked: consent.granted,
+                    onchange: toggle,
+                }
+            }
+        }
+    )
+}
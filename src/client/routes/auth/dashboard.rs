@@ -3,15 +3,21 @@ use dioxus_logger::tracing;
 
 use crate::{
     client::components::{
-        auth::dashboard::{DashboardCharacterCard, DashboardUpdateCard},
+        auth::dashboard::{
+            DashboardAnnouncementCard, DashboardCharacterCard, DashboardDataSharingCard,
+            DashboardNotificationCard, DashboardOnboardingCard, DashboardUpdateCard,
+        },
         Page,
     },
-    model::user::CharacterDto,
+    model::{preference::DashboardWidget, user::CharacterDto},
 };
 
 #[component]
 pub fn Dashboard() -> Element {
     let mut characters = use_signal(Vec::<CharacterDto>::new);
+    let mut layout = use_signal(|| DashboardWidget::DEFAULT_LAYOUT.to_vec());
+    let mut weekly_digest = use_signal(|| true);
+    let mut editing = use_signal(|| false);
 
     // Retrieve user characters on component load
     #[cfg(feature = "web")]
@@ -31,6 +37,57 @@ pub fn Dashboard() -> Element {
         }
     }
 
+    // Retrieve dashboard layout on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::user_preferences::get_preferences;
+
+        let future = use_resource(|| async move { get_preferences().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(preferences)) => {
+                layout.set(preferences.dashboard_layout.clone());
+                weekly_digest.set(preferences.weekly_digest);
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let save = move |_| {
+        let dashboard_layout = layout.read().clone();
+        let weekly_digest_enabled = *weekly_digest.read();
+
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::{
+                client::util::user_preferences::set_preferences,
+                model::preference::UserPreferencesDto,
+            };
+
+            match set_preferences(UserPreferencesDto {
+                dashboard_layout,
+                weekly_digest: weekly_digest_enabled,
+            })
+            .await
+            {
+                Ok(preferences) => {
+                    layout.set(preferences.dashboard_layout);
+                    weekly_digest.set(preferences.weekly_digest);
+                    editing.set(false);
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = (dashboard_layout, weekly_digest_enabled);
+    };
+
     rsx!(
         Title { "Dashboard | Bifrost" }
         Meta {
@@ -38,9 +95,100 @@ pub fn Dashboard() -> Element {
             content: "EVE Online authentication platform for coalitions, alliances, and corporations."
         }
         Page { class: "flex flex-col items-center",
+            div { class: "w-full max-w-[1440px] pt-4 flex justify-end gap-2 px-4",
+                if editing() {
+                    button { class: "btn btn-primary", onclick: save, "Save layout" }
+                } else {
+                    button { class: "btn btn-ghost", onclick: move |_| editing.set(true), "Customize" }
+                }
+            }
+            if editing() {
+                LayoutEditor { layout: layout, weekly_digest: weekly_digest }
+            }
             div { class: "w-full h-full max-w-[1440px] pt-4 flex flex-wrap justify-center gap-4 px-4",
-                DashboardCharacterCard { characters: characters }
-                DashboardUpdateCard { characters: characters }
+                {layout.read().iter().map(|widget| match widget {
+                    DashboardWidget::Characters => rsx!(
+                        DashboardCharacterCard { key: "characters", characters: characters }
+                    ),
+                    DashboardWidget::Updates => rsx!(
+                        DashboardUpdateCard { key: "updates", characters: characters }
+                    ),
+                    DashboardWidget::DataSharing => rsx!(
+                        DashboardDataSharingCard { key: "data_sharing" }
+                    ),
+                    DashboardWidget::Notifications => rsx!(
+                        DashboardNotificationCard { key: "notifications" }
+                    ),
+                    DashboardWidget::Announcements => rsx!(
+                        DashboardAnnouncementCard { key: "announcements" }
+                    ),
+                    DashboardWidget::Onboarding => rsx!(
+                        DashboardOnboardingCard { key: "onboarding" }
+                    ),
+                })}
+            }
+        }
+    )
+}
+
+#[component]
+fn LayoutEditor(layout: Signal<Vec<DashboardWidget>>, weekly_digest: Signal<bool>) -> Element {
+    let hidden: Vec<DashboardWidget> = DashboardWidget::ALL
+        .into_iter()
+        .filter(|widget| !layout.read().contains(widget))
+        .collect();
+
+    rsx!(
+        div { class: "w-full max-w-[1440px] pt-4 px-4",
+            div { class: "card shadow-sm w-full",
+                div { class: "card-body flex flex-col gap-2",
+                    h2 { class: "card-title", "Dashboard Layout" }
+                    for (index, widget) in layout.read().iter().copied().enumerate() {
+                        div { key: "{widget.as_str()}", class: "flex items-center gap-2",
+                            span { class: "flex-1", "{widget.title()}" }
+                            button {
+                                class: "btn btn-sm btn-ghost",
+                                disabled: index == 0,
+                                onclick: move |_| layout.write().swap(index - 1, index),
+                                "Up"
+                            }
+                            button {
+                                class: "btn btn-sm btn-ghost",
+                                disabled: index + 1 == layout.read().len(),
+                                onclick: move |_| layout.write().swap(index, index + 1),
+                                "Down"
+                            }
+                            button {
+                                class: "btn btn-sm btn-outline",
+                                onclick: move |_| { layout.write().remove(index); },
+                                "Hide"
+                            }
+                        }
+                    }
+                    for widget in hidden {
+                        div { key: "{widget.as_str()}", class: "flex items-center gap-2 opacity-70",
+                            span { class: "flex-1", "{widget.title()}" }
+                            button {
+                                class: "btn btn-sm btn-outline",
+                                onclick: move |_| layout.write().push(widget),
+                                "Show"
+                            }
+                        }
+                    }
+                    div { class: "divider my-0" }
+                    label { class: "flex items-center gap-2",
+                        span { class: "flex-1", "Receive the weekly digest" }
+                        input {
+                            r#type: "checkbox",
+                            class: "toggle toggle-primary",
+                            checked: *weekly_digest.read(),
+                            onchange: move |_| {
+                                let checked = !*weekly_digest.read();
+                                weekly_digest.set(checked);
+                            },
+                        }
+                    }
+                }
             }
         }
     )
@@ -0,0 +1,131 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{
+    client::{components::Page, router::Route},
+    model::user::LinkModeDto,
+};
+
+#[component]
+pub fn LinkCharacters() -> Element {
+    let mut link_mode = use_signal(|| None::<LinkModeDto>);
+    let mut link_count = use_signal(|| 5u32);
+    let nav = navigator();
+
+    // Retrieve linking mode status on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::link_mode::get_link_mode;
+
+        let future = use_resource(|| async move { get_link_mode().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                link_mode.set(Some(result.clone()));
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+            }
+            None => (),
+        }
+    }
+
+    let done = move |_| {
+        #[cfg(feature = "web")]
+        spawn(async move {
+            use crate::client::util::link_mode::exit_link_mode;
+
+            match exit_link_mode().await {
+                Ok(_) => {
+                    nav.push(Route::Dashboard {});
+                }
+                Err(err) => {
+                    tracing::error!(err);
+                }
+            }
+        });
+
+        #[cfg(not(feature = "web"))]
+        let _ = nav;
+    };
+
+    let link_mode = link_mode.read();
+    let results = link_mode
+        .as_ref()
+        .map(|link_mode| link_mode.results.clone())
+        .unwrap_or_default();
+    let linked_count = results.iter().filter(|result| result.linked).count();
+    let queued = link_mode
+        .as_ref()
+        .map(|link_mode| link_mode.queued)
+        .unwrap_or_default();
+
+    rsx!(
+        Title { "Link Characters | Bifrost" }
+        Meta {
+            name: "description",
+            content: "Link several characters to your account in a row."
+        }
+        Page { class: "flex flex-col items-center",
+            div { class: "w-full max-w-[960px] pt-4 flex flex-col gap-4 px-4",
+                h1 { class: "text-2xl font-bold", "Link Characters" }
+                p { class: "opacity-70",
+                    "Log in with each character you want to link. You'll return here after every login until you select Done."
+                }
+                div { class: "flex gap-2",
+                    a { href: "/api/auth/login?intent=link_alt&link_mode=true",
+                        button { class: "btn btn-primary", "Link next character" }
+                    }
+                    button { class: "btn btn-outline", onclick: done, "Done" }
+                }
+                div { class: "flex gap-2 items-center",
+                    input {
+                        class: "input input-bordered w-24",
+                        r#type: "number",
+                        min: "1",
+                        max: "100",
+                        value: "{link_count}",
+                        oninput: move |event| {
+                            if let Ok(count) = event.value().parse::<u32>() {
+                                link_count.set(count.clamp(1, 100));
+                            }
+                        },
+                    }
+                    a { href: "/api/auth/login?intent=link_alt&link_count={link_count}",
+                        button { class: "btn btn-outline", "Link characters in a row" }
+                    }
+                }
+                if queued > 0 {
+                    p { class: "opacity-70", "{queued} more characters queued for linking" }
+                }
+                div { class: "card shadow-sm w-full",
+                    div { class: "card-body",
+                        h2 { class: "card-title", "Linked {linked_count} of {results.len()}" }
+                        table { class: "table",
+                            tbody {
+                                for result in results.iter() {
+                                    tr {
+                                        td {
+                                            if let Some(name) = &result.character_name {
+                                                "{name}"
+                                            } else {
+                                                "Unknown character"
+                                            }
+                                        }
+                                        td {
+                                            if result.linked {
+                                                span { class: "badge badge-success", "Linked" }
+                                            } else {
+                                                span { class: "badge badge-error", "Failed" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
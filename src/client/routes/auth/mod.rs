@@ -1,3 +1,9 @@
+pub mod admin;
+pub mod consent;
 pub mod dashboard;
+pub mod link_characters;
 
+pub use admin::Admin;
+pub use consent::Consent;
 pub use dashboard::Dashboard;
+pub use link_characters::LinkCharacters;
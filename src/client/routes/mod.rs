@@ -1,6 +1,8 @@
 pub mod auth;
 pub mod home;
 pub mod not_found;
+pub mod recruitment;
 
 pub use home::Home;
 pub use not_found::NotFound;
+pub use recruitment::Recruitment;
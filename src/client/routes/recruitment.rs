@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+use dioxus_logger::tracing;
+
+use crate::{client::components::Page, model::recruitment::RecruitmentListingDto};
+
+#[component]
+pub fn Recruitment() -> Element {
+    let mut listings = use_signal(Vec::<RecruitmentListingDto>::new);
+    let mut fetched = use_signal(|| false);
+
+    // Retrieve recruitment listings on component load
+    #[cfg(feature = "web")]
+    {
+        use crate::client::util::get_recruitment_listings::get_recruitment_listings;
+
+        let future = use_resource(|| async move { get_recruitment_listings().await });
+
+        match &*future.read_unchecked() {
+            Some(Ok(result)) => {
+                listings.set(result.clone());
+                fetched.set(true);
+            }
+            Some(Err(err)) => {
+                tracing::error!(err);
+                fetched.set(true);
+            }
+            None => (),
+        }
+    }
+
+    rsx!(
+        Title { "Recruitment | Bifrost" }
+        Meta {
+            name: "description",
+            content: "Corporations currently recruiting new members."
+        }
+        Page { class: "flex flex-col items-center",
+            div { class: "w-full max-w-[1440px] pt-4 flex flex-col gap-4 px-4",
+                h1 { class: "text-2xl font-bold", "Recruitment" }
+                if listings.read().is_empty() {
+                    if *fetched.read() {
+                        p { "No corporations are currently recruiting." }
+                    } else {
+                        div { class: "skeleton h-32 w-full" }
+                    }
+                }
+                for listing in listings.read().iter() {
+                    RecruitmentListingCard { key: "{listing.corporation_id}", listing: listing.clone() }
+                }
+            }
+        }
+    )
+}
+
+#[component]
+fn RecruitmentListingCard(listing: RecruitmentListingDto) -> Element {
+    rsx!(
+        div { class: "card shadow-sm w-full",
+            div { class: "card-body flex flex-row gap-4",
+                div { class: "avatar",
+                    div { class: "w-16 h-16 rounded",
+                        img {
+                            src: format!("/img/corporations/{}?size=64", listing.corporation_id),
+                            alt: "{listing.corporation_name}",
+                        }
+                    }
+                }
+                div { class: "flex flex-col gap-2 flex-1",
+                    h2 { class: "card-title",
+                        "{listing.corporation_name} [{listing.corporation_ticker}]"
+                    }
+                    if let Some(alliance_name) = &listing.alliance_name {
+                        p { class: "text-sm opacity-70", "{alliance_name}" }
+                    }
+                    div { class: "flex gap-2",
+                        span { class: "badge badge-outline", "{listing.member_count} members" }
+                        span { class: "badge badge-outline", "{listing.timezone}" }
+                    }
+                    p { class: "whitespace-pre-line", "{listing.description}" }
+                }
+            }
+        }
+    )
+}
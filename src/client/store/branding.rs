@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+
+use crate::model::{api::ErrorDto, branding::BrandingDto};
+
+#[derive(Store)]
+pub struct BrandingState {
+    pub branding: BrandingDto,
+}
+
+/// Retrieve the instance's branding settings from API
+#[cfg(feature = "web")]
+pub async fn get_branding() -> Result<BrandingDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/branding")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let branding = response
+                .json::<BrandingDto>()
+                .await
+                .map_err(|e| format!("Failed to parse branding settings: {}", e))?;
+            Ok(branding)
+        }
+        _ => {
+            if let Ok(error_dto) = response.json::<ErrorDto>().await {
+                Err(format!(
+                    "Request failed with status {}: {}",
+                    response.status(),
+                    error_dto.error
+                ))
+            } else {
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                Err(format!(
+                    "Request failed with status {}: {}",
+                    response.status(),
+                    error_text
+                ))
+            }
+        }
+    }
+}
@@ -1 +1,3 @@
+pub mod branding;
+pub mod network;
 pub mod user;
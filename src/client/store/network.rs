@@ -0,0 +1,23 @@
+use dioxus::prelude::*;
+
+#[derive(Store)]
+pub struct NetworkState {
+    pub online: bool,
+}
+
+/// Register the service worker and report browser online status changes
+///
+/// Sends the current status once, then every time the browser goes online or offline.
+#[cfg(feature = "web")]
+pub fn watch_network() -> document::Eval {
+    document::eval(
+        r#"
+        if ("serviceWorker" in navigator) {
+            navigator.serviceWorker.register("/sw.js").catch((err) => console.error(err));
+        }
+        window.addEventListener("online", () => dioxus.send(true));
+        window.addEventListener("offline", () => dioxus.send(false));
+        dioxus.send(navigator.onLine);
+        "#,
+    )
+}
@@ -0,0 +1,164 @@
+#[cfg(feature = "web")]
+use crate::model::annotation::{
+    AnnotationSubject, AnnotationsDto, CreateNoteDto, CreateTagDto, NoteDto, TagDto,
+};
+
+/// Retrieve the tags and notes of a user, character, or corporation from API
+#[cfg(feature = "web")]
+pub async fn get_annotations(
+    subject: AnnotationSubject,
+    subject_id: i64,
+) -> Result<AnnotationsDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get(&format!(
+        "/api/admin/annotations/{}/{}",
+        subject.as_str(),
+        subject_id
+    ))
+    .credentials(reqwasm::http::RequestCredentials::Include)
+    .send()
+    .await
+    .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let annotations = response
+                .json::<AnnotationsDto>()
+                .await
+                .map_err(|e| format!("Failed to parse annotation data: {}", e))?;
+            Ok(annotations)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Tag a user, character, or corporation via API
+#[cfg(feature = "web")]
+pub async fn add_tag(
+    subject: AnnotationSubject,
+    subject_id: i64,
+    tag: CreateTagDto,
+) -> Result<TagDto, String> {
+    use reqwasm::http::Request;
+
+    let body =
+        serde_json::to_string(&tag).map_err(|e| format!("Failed to serialize tag: {}", e))?;
+
+    let response = Request::post(&format!(
+        "/api/admin/annotations/{}/{}/tags",
+        subject.as_str(),
+        subject_id
+    ))
+    .credentials(reqwasm::http::RequestCredentials::Include)
+    .header("Content-Type", "application/json")
+    .body(body)
+    .send()
+    .await
+    .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        201 => {
+            let tag = response
+                .json::<TagDto>()
+                .await
+                .map_err(|e| format!("Failed to parse tag data: {}", e))?;
+            Ok(tag)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Add a note about a user, character, or corporation via API
+#[cfg(feature = "web")]
+pub async fn add_note(
+    subject: AnnotationSubject,
+    subject_id: i64,
+    note: CreateNoteDto,
+) -> Result<NoteDto, String> {
+    use reqwasm::http::Request;
+
+    let body =
+        serde_json::to_string(&note).map_err(|e| format!("Failed to serialize note: {}", e))?;
+
+    let response = Request::post(&format!(
+        "/api/admin/annotations/{}/{}/notes",
+        subject.as_str(),
+        subject_id
+    ))
+    .credentials(reqwasm::http::RequestCredentials::Include)
+    .header("Content-Type", "application/json")
+    .body(body)
+    .send()
+    .await
+    .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        201 => {
+            let note = response
+                .json::<NoteDto>()
+                .await
+                .map_err(|e| format!("Failed to parse note data: {}", e))?;
+            Ok(note)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Remove a tag via API
+#[cfg(feature = "web")]
+pub async fn remove_tag(tag_id: i32) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete(&format!("/api/admin/tags/{}", tag_id))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Delete a note via API
+#[cfg(feature = "web")]
+pub async fn delete_note(note_id: i32) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete(&format!("/api/admin/notes/{}", note_id))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
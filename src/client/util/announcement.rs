@@ -0,0 +1,119 @@
+#[cfg(feature = "web")]
+use crate::model::announcement::{AnnouncementDto, CreateAnnouncementDto, InboxAnnouncementDto};
+
+/// Retrieve all announcements with their read receipts from API
+#[cfg(feature = "web")]
+pub async fn get_announcements() -> Result<Vec<AnnouncementDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/announcements")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let announcements = response
+                .json::<Vec<AnnouncementDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse announcement data: {}", e))?;
+            Ok(announcements)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Post an announcement via API
+#[cfg(feature = "web")]
+pub async fn create_announcement(
+    announcement: CreateAnnouncementDto,
+) -> Result<AnnouncementDto, String> {
+    use reqwasm::http::Request;
+
+    let body = serde_json::to_string(&announcement)
+        .map_err(|e| format!("Failed to serialize announcement: {}", e))?;
+
+    let response = Request::post("/api/admin/announcements")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        201 => {
+            let announcement = response
+                .json::<AnnouncementDto>()
+                .await
+                .map_err(|e| format!("Failed to parse announcement data: {}", e))?;
+            Ok(announcement)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve the current user's announcement inbox from API
+#[cfg(feature = "web")]
+pub async fn get_inbox() -> Result<Vec<InboxAnnouncementDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/user/announcements")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let inbox = response
+                .json::<Vec<InboxAnnouncementDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse announcement data: {}", e))?;
+            Ok(inbox)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Mark an announcement in the current user's inbox read via API
+#[cfg(feature = "web")]
+pub async fn mark_announcement_read(announcement_id: i32) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::post(&format!("/api/user/announcements/{}/read", announcement_id))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(feature = "web")]
+use crate::model::approval::ApprovalRequestDto;
+
+/// Retrieve the most recent approval requests from API
+#[cfg(feature = "web")]
+pub async fn get_approval_requests() -> Result<Vec<ApprovalRequestDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/approvals")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let requests = response
+                .json::<Vec<ApprovalRequestDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse approval request data: {}", e))?;
+            Ok(requests)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Approve or reject an approval request via API
+#[cfg(feature = "web")]
+pub async fn decide_approval_request(
+    request_id: i32,
+    approve: bool,
+) -> Result<ApprovalRequestDto, String> {
+    use reqwasm::http::Request;
+
+    let decision = if approve { "approve" } else { "reject" };
+    let response = Request::post(&format!("/api/admin/approvals/{}/{}", request_id, decision))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let request = response
+                .json::<ApprovalRequestDto>()
+                .await
+                .map_err(|e| format!("Failed to parse approval request data: {}", e))?;
+            Ok(request)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
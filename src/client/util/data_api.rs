@@ -0,0 +1,167 @@
+#[cfg(feature = "web")]
+use crate::model::data_api::{
+    ApiKeyDto, CreateApiKeyDto, CreatedApiKeyDto, SaveSavedQueryDto, SavedQueryDto,
+};
+
+/// Retrieve all saved queries of the data access API from API
+#[cfg(feature = "web")]
+pub async fn get_saved_queries() -> Result<Vec<SavedQueryDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/saved-queries")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let queries = response
+                .json::<Vec<SavedQueryDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse saved query data: {}", e))?;
+            Ok(queries)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Create or replace a saved query via API
+#[cfg(feature = "web")]
+pub async fn save_saved_query(
+    slug: &str,
+    query: SaveSavedQueryDto,
+) -> Result<SavedQueryDto, String> {
+    use reqwasm::http::Request;
+
+    let body = serde_json::to_string(&query)
+        .map_err(|e| format!("Failed to serialize saved query: {}", e))?;
+
+    let response = Request::put(&format!("/api/admin/saved-queries/{}", slug))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let query = response
+                .json::<SavedQueryDto>()
+                .await
+                .map_err(|e| format!("Failed to parse saved query data: {}", e))?;
+            Ok(query)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Delete a saved query via API
+#[cfg(feature = "web")]
+pub async fn delete_saved_query(slug: &str) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete(&format!("/api/admin/saved-queries/{}", slug))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve all data access API keys from API
+#[cfg(feature = "web")]
+pub async fn get_api_keys() -> Result<Vec<ApiKeyDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/api-keys")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let api_keys = response
+                .json::<Vec<ApiKeyDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse API key data: {}", e))?;
+            Ok(api_keys)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Create a data access API key via API
+#[cfg(feature = "web")]
+pub async fn create_api_key(api_key: CreateApiKeyDto) -> Result<CreatedApiKeyDto, String> {
+    use reqwasm::http::Request;
+
+    let body = serde_json::to_string(&api_key)
+        .map_err(|e| format!("Failed to serialize API key: {}", e))?;
+
+    let response = Request::post("/api/admin/api-keys")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        201 => {
+            let api_key = response
+                .json::<CreatedApiKeyDto>()
+                .await
+                .map_err(|e| format!("Failed to parse API key data: {}", e))?;
+            Ok(api_key)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Revoke a data access API key via API
+#[cfg(feature = "web")]
+pub async fn delete_api_key(api_key_id: i32) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete(&format!("/api/admin/api-keys/{}", api_key_id))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -0,0 +1,27 @@
+#[cfg(feature = "web")]
+use crate::client::components::form::FormErrors;
+
+/// Build form errors from a failed API response
+///
+/// Responses with status 422 carry field errors as problem details, any other failure is
+/// shown as an error of the whole form.
+#[cfg(feature = "web")]
+pub async fn form_errors(response: reqwasm::http::Response) -> FormErrors {
+    use crate::model::api::{ErrorDto, ValidationErrorDto};
+
+    if response.status() == 422 {
+        if let Ok(validation_error) = response.json::<ValidationErrorDto>().await {
+            return validation_error.into();
+        }
+    } else if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        return FormErrors {
+            form: Some(error_dto.error),
+            ..Default::default()
+        };
+    }
+
+    FormErrors {
+        form: Some(format!("Request failed with status {}", response.status())),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(feature = "web")]
+use crate::model::recruitment::RecruitmentListingDto;
+
+/// Retrieve public corporation recruitment listings from API
+#[cfg(feature = "web")]
+pub async fn get_recruitment_listings() -> Result<Vec<RecruitmentListingDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/recruitment")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let listings = response
+                .json::<Vec<RecruitmentListingDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse recruitment listing data: {}", e))?;
+            Ok(listings)
+        }
+        _ => {
+            use crate::model::api::ErrorDto;
+
+            if let Ok(error_dto) = response.json::<ErrorDto>().await {
+                Err(format!(
+                    "Request failed with status {}: {}",
+                    response.status(),
+                    error_dto.error
+                ))
+            } else {
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                Err(format!(
+                    "Request failed with status {}: {}",
+                    response.status(),
+                    error_text
+                ))
+            }
+        }
+    }
+}
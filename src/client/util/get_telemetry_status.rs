@@ -0,0 +1,45 @@
+#[cfg(feature = "web")]
+use crate::model::telemetry::TelemetryStatusDto;
+
+/// Retrieve telemetry status and report preview from API
+#[cfg(feature = "web")]
+pub async fn get_telemetry_status() -> Result<TelemetryStatusDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/telemetry")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let status = response
+                .json::<TelemetryStatusDto>()
+                .await
+                .map_err(|e| format!("Failed to parse telemetry status: {}", e))?;
+            Ok(status)
+        }
+        _ => {
+            use crate::model::api::ErrorDto;
+
+            if let Ok(error_dto) = response.json::<ErrorDto>().await {
+                Err(format!(
+                    "Request failed with status {}: {}",
+                    response.status(),
+                    error_dto.error
+                ))
+            } else {
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                Err(format!(
+                    "Request failed with status {}: {}",
+                    response.status(),
+                    error_text
+                ))
+            }
+        }
+    }
+}
@@ -1,46 +1,64 @@
 #[cfg(feature = "web")]
-use crate::model::user::CharacterDto;
+use crate::model::user::{CharacterDto, UserCharacterPageDto};
 
-/// Retrieve user characters from API
+/// Retrieve all user characters from API, requesting pages as large as the API allows until
+/// the last page
 #[cfg(feature = "web")]
 pub async fn get_user_characters() -> Result<Vec<CharacterDto>, String> {
     use reqwasm::http::Request;
 
-    let response = Request::get("/api/user/characters")
+    let mut characters = Vec::new();
+    let mut page_number = 1;
+
+    loop {
+        let response = Request::get(&format!(
+            "/api/user/characters?per_page=200&page={}",
+            page_number
+        ))
         .credentials(reqwasm::http::RequestCredentials::Include)
         .send()
         .await
         .map_err(|e| format!("Failed to send request: {}", e))?;
 
-    match response.status() {
-        200 => {
-            let chars = response
-                .json::<Vec<CharacterDto>>()
-                .await
-                .map_err(|e| format!("Failed to parse user character data: {}", e))?;
-            Ok(chars)
-        }
-        404 => Ok(Vec::new()),
-        _ => {
-            use crate::model::api::ErrorDto;
-
-            if let Ok(error_dto) = response.json::<ErrorDto>().await {
-                Err(format!(
-                    "Request failed with status {}: {}",
-                    response.status(),
-                    error_dto.error
-                ))
-            } else {
-                let error_text = response
-                    .text()
+        match response.status() {
+            200 => {
+                let page = response
+                    .json::<UserCharacterPageDto>()
                     .await
-                    .unwrap_or_else(|_| "Unknown error".to_string());
-                Err(format!(
-                    "Request failed with status {}: {}",
-                    response.status(),
-                    error_text
-                ))
+                    .map_err(|e| format!("Failed to parse user character data: {}", e))?;
+                characters.extend(page.characters);
+
+                if page.page >= page.total_pages {
+                    return Ok(characters);
+                }
+                page_number = page.page + 1;
             }
+            404 => return Ok(characters),
+            _ => return Err(request_error(response).await),
         }
     }
 }
+
+/// Builds the error message of a failed request from its response
+#[cfg(feature = "web")]
+async fn request_error(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(feature = "web")]
+use crate::model::user::LinkModeDto;
+
+/// Retrieve character linking mode status from API
+#[cfg(feature = "web")]
+pub async fn get_link_mode() -> Result<LinkModeDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/auth/link-mode")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    parse_link_mode(response).await
+}
+
+/// Exit character linking mode via API
+#[cfg(feature = "web")]
+pub async fn exit_link_mode() -> Result<LinkModeDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete("/api/auth/link-mode")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    parse_link_mode(response).await
+}
+
+/// Parse a linking mode response, building an error message on failure
+#[cfg(feature = "web")]
+async fn parse_link_mode(response: reqwasm::http::Response) -> Result<LinkModeDto, String> {
+    match response.status() {
+        200 => {
+            let link_mode = response
+                .json::<LinkModeDto>()
+                .await
+                .map_err(|e| format!("Failed to parse linking mode: {}", e))?;
+            Ok(link_mode)
+        }
+        _ => {
+            use crate::model::api::ErrorDto;
+
+            if let Ok(error_dto) = response.json::<ErrorDto>().await {
+                Err(format!(
+                    "Request failed with status {}: {}",
+                    response.status(),
+                    error_dto.error
+                ))
+            } else {
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                Err(format!(
+                    "Request failed with status {}: {}",
+                    response.status(),
+                    error_text
+                ))
+            }
+        }
+    }
+}
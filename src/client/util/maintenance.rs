@@ -0,0 +1,77 @@
+#[cfg(feature = "web")]
+use crate::model::maintenance::ReadOnlyStatusDto;
+
+/// Retrieve whether read-only mode is enabled from API
+#[cfg(feature = "web")]
+pub async fn get_read_only() -> Result<ReadOnlyStatusDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/read-only")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let status = response
+                .json::<ReadOnlyStatusDto>()
+                .await
+                .map_err(|e| format!("Failed to parse read-only mode: {}", e))?;
+            Ok(status)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Enable or disable read-only mode via API
+#[cfg(feature = "web")]
+pub async fn set_read_only(enabled: bool) -> Result<ReadOnlyStatusDto, String> {
+    use reqwasm::http::Request;
+
+    let body = serde_json::to_string(&ReadOnlyStatusDto { enabled })
+        .map_err(|e| format!("Failed to serialize read-only mode: {}", e))?;
+
+    let response = Request::put("/api/admin/read-only")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let status = response
+                .json::<ReadOnlyStatusDto>()
+                .await
+                .map_err(|e| format!("Failed to parse read-only mode: {}", e))?;
+            Ok(status)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -0,0 +1,217 @@
+#[cfg(feature = "web")]
+use crate::model::member::{
+    MemberBulkActionDto, MemberBulkResultDto, MemberCharacterDto, MemberFilterDto, MemberUserDto,
+    SaveMemberFilterDto, SavedMemberFilterDto,
+};
+
+/// Retrieve the users with a character matching a filter from API
+#[cfg(feature = "web")]
+pub async fn get_member_users(filter: &MemberFilterDto) -> Result<Vec<MemberUserDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get(&format!("/api/admin/members/users{}", filter_query(filter)))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let users = response
+                .json::<Vec<MemberUserDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse user data: {}", e))?;
+            Ok(users)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve the owned characters matching a filter from API
+#[cfg(feature = "web")]
+pub async fn get_member_characters(
+    filter: &MemberFilterDto,
+) -> Result<Vec<MemberCharacterDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get(&format!(
+        "/api/admin/members/characters{}",
+        filter_query(filter)
+    ))
+    .credentials(reqwasm::http::RequestCredentials::Include)
+    .send()
+    .await
+    .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let characters = response
+                .json::<Vec<MemberCharacterDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse character data: {}", e))?;
+            Ok(characters)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve the current user's saved member filters from API
+#[cfg(feature = "web")]
+pub async fn get_member_filters() -> Result<Vec<SavedMemberFilterDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/members/filters")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let filters = response
+                .json::<Vec<SavedMemberFilterDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse filter data: {}", e))?;
+            Ok(filters)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Save a member filter via API
+#[cfg(feature = "web")]
+pub async fn save_member_filter(
+    filter: SaveMemberFilterDto,
+) -> Result<SavedMemberFilterDto, String> {
+    use reqwasm::http::Request;
+
+    let body =
+        serde_json::to_string(&filter).map_err(|e| format!("Failed to serialize filter: {}", e))?;
+
+    let response = Request::post("/api/admin/members/filters")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let filter = response
+                .json::<SavedMemberFilterDto>()
+                .await
+                .map_err(|e| format!("Failed to parse filter data: {}", e))?;
+            Ok(filter)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Delete a saved member filter via API
+#[cfg(feature = "web")]
+pub async fn delete_member_filter(filter_id: i32) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete(&format!("/api/admin/members/filters/{}", filter_id))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Run a bulk action on the selected users or characters via API
+#[cfg(feature = "web")]
+pub async fn run_member_bulk_action(
+    action: MemberBulkActionDto,
+) -> Result<Vec<MemberBulkResultDto>, String> {
+    use reqwasm::http::Request;
+
+    let body = serde_json::to_string(&action)
+        .map_err(|e| format!("Failed to serialize bulk action: {}", e))?;
+
+    let response = Request::post("/api/admin/members/bulk")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        202 => {
+            let results = response
+                .json::<Vec<MemberBulkResultDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse bulk action results: {}", e))?;
+            Ok(results)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build the query string of a member filter, leaving out unset criteria
+#[cfg(feature = "web")]
+fn filter_query(filter: &MemberFilterDto) -> String {
+    let params: Vec<String> = [
+        ("name", filter.name.clone()),
+        (
+            "corporation_id",
+            filter.corporation_id.map(|id| id.to_string()),
+        ),
+        ("alliance_id", filter.alliance_id.map(|id| id.to_string())),
+        ("tag", filter.tag.clone()),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| value.map(|value| format!("{}={}", key, encode(&value))))
+    .collect();
+
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", params.join("&"))
+    }
+}
+
+/// Percent-encode a query parameter value
+#[cfg(feature = "web")]
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -1 +1,18 @@
+pub mod annotation;
+pub mod announcement;
+pub mod approval;
+pub mod data_api;
+pub mod form;
+pub mod get_recruitment_listings;
+pub mod get_telemetry_status;
 pub mod get_user_character;
+pub mod link_mode;
+pub mod maintenance;
+pub mod member;
+pub mod onboarding;
+pub mod page;
+pub mod push;
+pub mod reauth_campaign;
+pub mod user_consent;
+pub mod user_preferences;
+pub mod widget;
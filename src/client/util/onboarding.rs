@@ -0,0 +1,161 @@
+#[cfg(feature = "web")]
+use crate::model::onboarding::{
+    CreateOnboardingStepDto, IncompleteOnboardingDto, OnboardingChecklistItemDto, OnboardingStepDto,
+};
+
+/// Retrieve all onboarding steps from API
+#[cfg(feature = "web")]
+pub async fn get_onboarding_steps() -> Result<Vec<OnboardingStepDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/onboarding/steps")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let steps = response
+                .json::<Vec<OnboardingStepDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse onboarding step data: {}", e))?;
+            Ok(steps)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Define an onboarding step via API
+#[cfg(feature = "web")]
+pub async fn create_onboarding_step(
+    step: CreateOnboardingStepDto,
+) -> Result<OnboardingStepDto, String> {
+    use reqwasm::http::Request;
+
+    let body = serde_json::to_string(&step)
+        .map_err(|e| format!("Failed to serialize onboarding step: {}", e))?;
+
+    let response = Request::post("/api/admin/onboarding/steps")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        201 => {
+            let step = response
+                .json::<OnboardingStepDto>()
+                .await
+                .map_err(|e| format!("Failed to parse onboarding step data: {}", e))?;
+            Ok(step)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Delete an onboarding step via API
+#[cfg(feature = "web")]
+pub async fn delete_onboarding_step(step_id: i32) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete(&format!("/api/admin/onboarding/steps/{}", step_id))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve the users who haven't completed onboarding from API
+#[cfg(feature = "web")]
+pub async fn get_incomplete_onboarding() -> Result<Vec<IncompleteOnboardingDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/onboarding/incomplete")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let users = response
+                .json::<Vec<IncompleteOnboardingDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse user data: {}", e))?;
+            Ok(users)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve the current user's onboarding checklist from API
+#[cfg(feature = "web")]
+pub async fn get_onboarding_checklist() -> Result<Vec<OnboardingChecklistItemDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/user/onboarding")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let checklist = response
+                .json::<Vec<OnboardingChecklistItemDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse checklist data: {}", e))?;
+            Ok(checklist)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Mark an onboarding step completed via API
+#[cfg(feature = "web")]
+pub async fn complete_onboarding_step(step_id: i32) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::post(&format!("/api/user/onboarding/{}/complete", step_id))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -0,0 +1,164 @@
+#[cfg(feature = "web")]
+use crate::model::page::{PageDto, PageRevisionDto, PageSummaryDto, SavePageDto};
+
+/// Retrieve the slug and title of all pages from API
+#[cfg(feature = "web")]
+pub async fn get_pages() -> Result<Vec<PageSummaryDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/pages")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let pages = response
+                .json::<Vec<PageSummaryDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse page data: {}", e))?;
+            Ok(pages)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve a page from API
+#[cfg(feature = "web")]
+pub async fn get_page(slug: &str) -> Result<PageDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get(&format!("/api/pages/{}", slug))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let page = response
+                .json::<PageDto>()
+                .await
+                .map_err(|e| format!("Failed to parse page data: {}", e))?;
+            Ok(page)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Create or update a page via API
+#[cfg(feature = "web")]
+pub async fn save_page(slug: &str, page: SavePageDto) -> Result<PageDto, String> {
+    use reqwasm::http::Request;
+
+    let body =
+        serde_json::to_string(&page).map_err(|e| format!("Failed to serialize page: {}", e))?;
+
+    let response = Request::put(&format!("/api/admin/pages/{}", slug))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let page = response
+                .json::<PageDto>()
+                .await
+                .map_err(|e| format!("Failed to parse page data: {}", e))?;
+            Ok(page)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Delete a page via API
+#[cfg(feature = "web")]
+pub async fn delete_page(slug: &str) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete(&format!("/api/admin/pages/{}", slug))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve the revision history of a page from API
+#[cfg(feature = "web")]
+pub async fn get_page_revisions(slug: &str) -> Result<Vec<PageRevisionDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get(&format!("/api/admin/pages/{}/revisions", slug))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let revisions = response
+                .json::<Vec<PageRevisionDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse revision data: {}", e))?;
+            Ok(revisions)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Restore a page to one of its revisions via API
+#[cfg(feature = "web")]
+pub async fn restore_page_revision(slug: &str, revision_id: i32) -> Result<PageDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::post(&format!(
+        "/api/admin/pages/{}/revisions/{}/restore",
+        slug, revision_id
+    ))
+    .credentials(reqwasm::http::RequestCredentials::Include)
+    .send()
+    .await
+    .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let page = response
+                .json::<PageDto>()
+                .await
+                .map_err(|e| format!("Failed to parse page data: {}", e))?;
+            Ok(page)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -0,0 +1,196 @@
+#[cfg(feature = "web")]
+use crate::model::push::{CreatePushSubscriptionDto, PushConfigDto};
+
+/// Retrieve the VAPID public key browsers subscribe with from API
+#[cfg(feature = "web")]
+pub async fn get_push_config() -> Result<PushConfigDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/push/config")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let config = response
+                .json::<PushConfigDto>()
+                .await
+                .map_err(|e| format!("Failed to parse push settings: {}", e))?;
+            Ok(config)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Subscribe this browser to push notifications and register the subscription via API
+///
+/// Asks for notification permission if it hasn't been granted yet.
+#[cfg(feature = "web")]
+pub async fn subscribe(vapid_public_key: String) -> Result<(), String> {
+    use dioxus::prelude::document;
+    use reqwasm::http::Request;
+
+    #[derive(serde::Deserialize)]
+    struct BrowserSubscription {
+        subscription: Option<CreatePushSubscriptionDto>,
+        error: Option<String>,
+    }
+
+    let mut eval = document::eval(
+        r#"
+        const key = await dioxus.recv();
+        try {
+            if (await Notification.requestPermission() !== "granted") {
+                throw new Error("Notification permission was denied");
+            }
+            const padding = "=".repeat((4 - (key.length % 4)) % 4);
+            const raw = atob((key + padding).replace(/-/g, "+").replace(/_/g, "/"));
+            const registration = await navigator.serviceWorker.ready;
+            const subscription = await registration.pushManager.subscribe({
+                userVisibleOnly: true,
+                applicationServerKey: Uint8Array.from(raw, (c) => c.charCodeAt(0)),
+            });
+            dioxus.send({ subscription: subscription.toJSON() });
+        } catch (err) {
+            dioxus.send({ error: String(err) });
+        }
+        "#,
+    );
+    eval.send(vapid_public_key)
+        .map_err(|e| format!("Failed to subscribe browser: {}", e))?;
+    let result = eval
+        .recv::<BrowserSubscription>()
+        .await
+        .map_err(|e| format!("Failed to subscribe browser: {}", e))?;
+    let subscription = match (result.subscription, result.error) {
+        (Some(subscription), None) => subscription,
+        (_, error) => {
+            return Err(format!(
+                "Failed to subscribe browser: {}",
+                error.unwrap_or_else(|| "Unknown error".to_string())
+            ))
+        }
+    };
+
+    let body = serde_json::to_string(&subscription)
+        .map_err(|e| format!("Failed to serialize subscription: {}", e))?;
+
+    let response = Request::post("/api/user/push-subscriptions")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Unsubscribe this browser from push notifications and remove the subscription via API
+#[cfg(feature = "web")]
+pub async fn unsubscribe() -> Result<(), String> {
+    use dioxus::prelude::document;
+    use reqwasm::http::Request;
+
+    let mut eval = document::eval(
+        r#"
+        const registration = await navigator.serviceWorker.ready;
+        const subscription = await registration.pushManager.getSubscription();
+        if (subscription) {
+            await subscription.unsubscribe();
+        }
+        dioxus.send(subscription ? subscription.endpoint : null);
+        "#,
+    );
+    let Some(endpoint) = eval
+        .recv::<Option<String>>()
+        .await
+        .map_err(|e| format!("Failed to unsubscribe browser: {}", e))?
+    else {
+        return Ok(());
+    };
+
+    let body = serde_json::json!({ "endpoint": endpoint }).to_string();
+
+    let response = Request::delete("/api/user/push-subscriptions")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        // Subscription may already have been removed after the push service expired it
+        204 | 404 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Check whether this browser is subscribed to push notifications
+///
+/// Returns `None` if the browser doesn't support push notifications.
+#[cfg(feature = "web")]
+pub async fn is_subscribed() -> Option<bool> {
+    use dioxus::prelude::document;
+
+    let mut eval = document::eval(
+        r#"
+        if (!("serviceWorker" in navigator) || !("PushManager" in window)) {
+            dioxus.send(null);
+        } else {
+            const registration = await navigator.serviceWorker.ready;
+            const subscription = await registration.pushManager.getSubscription();
+            dioxus.send(subscription !== null);
+        }
+        "#,
+    );
+
+    eval.recv::<Option<bool>>().await.ok().flatten()
+}
+
+/// Send a test notification to every subscribed browser of the current user via API
+#[cfg(feature = "web")]
+pub async fn send_test_notification() -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::post("/api/user/push-subscriptions/test")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        202 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(feature = "web")]
+use crate::model::reauth_campaign::{
+    CreateReauthCampaignDto, PendingReauthCampaignDto, ReauthCampaignDto,
+};
+
+/// Retrieve all re-authentication campaigns with their completion from API
+#[cfg(feature = "web")]
+pub async fn get_reauth_campaigns() -> Result<Vec<ReauthCampaignDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/reauth-campaigns")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let campaigns = response
+                .json::<Vec<ReauthCampaignDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse campaign data: {}", e))?;
+            Ok(campaigns)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Launch a re-authentication campaign via API
+#[cfg(feature = "web")]
+pub async fn create_reauth_campaign(
+    campaign: CreateReauthCampaignDto,
+) -> Result<ReauthCampaignDto, String> {
+    use reqwasm::http::Request;
+
+    let body = serde_json::to_string(&campaign)
+        .map_err(|e| format!("Failed to serialize campaign: {}", e))?;
+
+    let response = Request::post("/api/admin/reauth-campaigns")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        201 => {
+            let campaign = response
+                .json::<ReauthCampaignDto>()
+                .await
+                .map_err(|e| format!("Failed to parse campaign data: {}", e))?;
+            Ok(campaign)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Retrieve the current user's pending re-authentication campaigns from API
+#[cfg(feature = "web")]
+pub async fn get_pending_reauth_campaigns() -> Result<Vec<PendingReauthCampaignDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/user/reauth-campaigns")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let campaigns = response
+                .json::<Vec<PendingReauthCampaignDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse campaign data: {}", e))?;
+            Ok(campaigns)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
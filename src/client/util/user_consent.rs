@@ -0,0 +1,73 @@
+#[cfg(feature = "web")]
+use crate::model::consent::{ConsentCategory, ConsentDto};
+
+/// Retrieve data-sharing consent status for the current user from API
+#[cfg(feature = "web")]
+pub async fn get_consents() -> Result<Vec<ConsentDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/user/consents")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let consents = response
+                .json::<Vec<ConsentDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse consent data: {}", e))?;
+            Ok(consents)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Grant or revoke data-sharing consent for a category via API
+#[cfg(feature = "web")]
+pub async fn set_consent(category: ConsentCategory, granted: bool) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let url = format!("/api/user/consents/{}", category.as_str());
+    let request = if granted {
+        Request::put(&url)
+    } else {
+        Request::delete(&url)
+    };
+
+    let response = request
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
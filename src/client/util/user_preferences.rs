@@ -0,0 +1,79 @@
+#[cfg(feature = "web")]
+use crate::model::preference::UserPreferencesDto;
+
+/// Retrieve preferences of the current user from API
+#[cfg(feature = "web")]
+pub async fn get_preferences() -> Result<UserPreferencesDto, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/user/preferences")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let preferences = response
+                .json::<UserPreferencesDto>()
+                .await
+                .map_err(|e| format!("Failed to parse preference data: {}", e))?;
+            Ok(preferences)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Save preferences of the current user via API
+#[cfg(feature = "web")]
+pub async fn set_preferences(
+    preferences: UserPreferencesDto,
+) -> Result<UserPreferencesDto, String> {
+    use reqwasm::http::Request;
+
+    let body = serde_json::to_string(&preferences)
+        .map_err(|e| format!("Failed to serialize preferences: {}", e))?;
+
+    let response = Request::put("/api/user/preferences")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let preferences = response
+                .json::<UserPreferencesDto>()
+                .await
+                .map_err(|e| format!("Failed to parse preference data: {}", e))?;
+            Ok(preferences)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "web")]
+use crate::model::widget::{CreateWidgetDto, WidgetDto};
+
+/// Retrieve widgets created by the current user from API
+#[cfg(feature = "web")]
+pub async fn get_widgets() -> Result<Vec<WidgetDto>, String> {
+    use reqwasm::http::Request;
+
+    let response = Request::get("/api/admin/widgets")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        200 => {
+            let widgets = response
+                .json::<Vec<WidgetDto>>()
+                .await
+                .map_err(|e| format!("Failed to parse widget data: {}", e))?;
+            Ok(widgets)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Create a widget via API
+#[cfg(feature = "web")]
+pub async fn create_widget(widget: CreateWidgetDto) -> Result<WidgetDto, String> {
+    use reqwasm::http::Request;
+
+    let body =
+        serde_json::to_string(&widget).map_err(|e| format!("Failed to serialize widget: {}", e))?;
+
+    let response = Request::post("/api/admin/widgets")
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        201 => {
+            let widget = response
+                .json::<WidgetDto>()
+                .await
+                .map_err(|e| format!("Failed to parse widget data: {}", e))?;
+            Ok(widget)
+        }
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Revoke a widget via API
+#[cfg(feature = "web")]
+pub async fn delete_widget(widget_id: i32) -> Result<(), String> {
+    use reqwasm::http::Request;
+
+    let response = Request::delete(&format!("/api/admin/widgets/{}", widget_id))
+        .credentials(reqwasm::http::RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e))?;
+
+    match response.status() {
+        204 => Ok(()),
+        _ => Err(error_message(response).await),
+    }
+}
+
+/// Build an error message from a failed API response
+#[cfg(feature = "web")]
+async fn error_message(response: reqwasm::http::Response) -> String {
+    use crate::model::api::ErrorDto;
+
+    if let Ok(error_dto) = response.json::<ErrorDto>().await {
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_dto.error
+        )
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        format!(
+            "Request failed with status {}: {}",
+            response.status(),
+            error_text
+        )
+    }
+}
@@ -21,13 +21,14 @@ use bifrost::server;
 /// # Full-Stack Server (`--features server`)
 /// When the `server` feature is enabled, launches the backend with with:
 /// - **Configuration**: Loads environment variables and validates required settings
-/// - **Database**: Connects to PostgreSQL and runs migrations
+/// - **Database**: Connects to PostgreSQL and runs migrations, including plugin migrations
 /// - **Redis/Valkey**: Establishes connection pool for sessions and worker queue
 /// - **Session Management**: Configures secure session cookies with Redis backend
 /// - **ESI Client**: Builds OAuth-enabled EVE Online API client
 /// - **Worker System**: Starts background worker pool for ESI data refresh jobs
 /// - **Job Scheduler**: Initializes cron-based scheduler for automated data updates
 /// - **HTTP Router**: Configures API routes and Dioxus SSR with middleware
+/// - **Plugins**: Adds routes, worker jobs, scheduled jobs, and migrations of registered plugins
 ///
 /// # Startup Sequence (Server)
 /// 1. Load `.env` file if present (for local development)
@@ -36,15 +37,23 @@ use bifrost::server;
 /// 4. Connect to Redis/Valkey for sessions and worker queue
 /// 5. Configure session management with secure cookies
 /// 6. Build ESI client with OAuth credentials
-/// 7. Start background worker pool to process jobs
-/// 8. Start job scheduler to enqueue periodic refresh jobs
-/// 9. Build combined router (Dioxus SSR + API routes + session middleware)
-/// 10. Start HTTP server
+/// 7. Preload lookup data such as NPC factions unless warm-up is disabled
+/// 8. Start background worker pool to process jobs
+/// 9. Start job scheduler to enqueue periodic refresh jobs, supervised with the queue cleanup task
+/// 10. Build combined router (Dioxus SSR + API routes + session middleware)
+/// 11. Start HTTP server
 ///
 /// # Environment Variables (Server)
 /// See `server::config::Config::from_env()` for required environment variables including
 /// database URLs, ESI credentials, contact email, and worker pool size.
 ///
+/// # Commands (Server)
+/// - `check-config [example.env]` - Prints the effective configuration with secrets redacted,
+///   validates it, and compares the environment against an example env file, exiting with a
+///   non-zero status if problems are found instead of starting the server
+/// - `openapi` - Prints the OpenAPI document served at `/api/openapi.json`, for generating
+///   typed API clients without starting the server
+///
 /// # Panics
 /// Panics if server initialization fails (missing environment variables, connection failures,
 /// etc.) as the application cannot function without required infrastructure.
@@ -52,38 +61,190 @@ fn main() {
     #[cfg(not(feature = "server"))]
     dioxus::launch(client::App);
 
+    #[cfg(feature = "server")]
+    if std::env::args().nth(1).as_deref() == Some("check-config") {
+        dotenvy::dotenv().ok();
+        let example = std::env::args().nth(2).map(std::path::PathBuf::from);
+        std::process::exit(server::util::config_check::run(
+            example.as_deref(),
+            &plugins(),
+        ));
+    }
+
+    #[cfg(feature = "server")]
+    if std::env::args().nth(1).as_deref() == Some("openapi") {
+        match server::router::openapi().to_pretty_json() {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Failed to serialize OpenAPI document: {}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     #[cfg(feature = "server")]
     dioxus::serve(|| async move {
         use dioxus_logger::tracing;
 
-        use crate::server::{config::Config, model::app::AppState, startup};
+        use crate::server::{
+            config::Config,
+            model::app::AppState,
+            startup::{self, TaskSupervisor},
+        };
 
         dotenvy::dotenv().ok();
         let config = Config::from_env()?;
 
+        let plugins = plugins();
+
         let db = startup::connect_to_database(&config).await?;
+        if !plugins.is_empty() {
+            plugins.run_migrations(&db).await?;
+        }
         let redis_pool = startup::connect_to_redis(&config).await?;
-        let session = startup::connect_to_session(redis_pool.clone()).await?;
+        let session = startup::connect_to_session(&config, redis_pool.clone()).await?;
         let esi_client = startup::build_esi_client(&config)?;
 
         let esi_provider = server::service::eve::esi::EsiProvider::new(esi_client);
+        let supervisor = TaskSupervisor::new();
+        let read_only = server::util::read_only::ReadOnlyMode::new(config.read_only);
+        if config.read_only {
+            tracing::warn!("Starting in read-only mode, writes are rejected until it is disabled");
+        }
 
-        let worker =
-            startup::start_workers(&config, db.clone(), redis_pool, esi_provider.clone()).await?;
-        startup::start_scheduler(db.clone(), worker.queue.clone()).await?;
+        if config.warmup_enabled {
+            // Lookup data is loaded on demand if warm-up fails, so don't prevent startup
+            if let Err(e) = startup::warm_up(&db, &esi_provider).await {
+                tracing::warn!("Warm-up failed, continuing startup: {}", e);
+            }
+        }
+
+        let worker = startup::start_workers(
+            &config,
+            db.clone(),
+            redis_pool.clone(),
+            esi_provider.clone(),
+            plugins.clone(),
+            read_only.clone(),
+            &supervisor,
+        )
+        .await?;
+        let telemetry = server::service::telemetry::TelemetryConfig::from_config(&config);
+        let push = server::service::push::PushConfig::from_config(&config);
+        let search = server::service::search::SearchConfig::from_config(&config)?;
+        let image_proxy = server::service::image::ImageProxyConfig::from_config(&config)?;
+        let object_storage = startup::build_object_storage(&config)?;
+        let branding = config.branding.clone();
+        let scheduler = config.scheduler;
+        let scheduler_cron = config.scheduler_cron.clone();
+        let approvals = server::service::approval::ApprovalConfig::from_config(&config);
+        let cipher = server::util::crypto::ColumnCipher::new(config.encryption_keys.clone());
+        let scope_sets = config.scope_sets.clone();
+        let discord = server::service::auth::discord::DiscordConfig::from_config(&config)?;
+        let session_expiry = config.session_expiry;
+        let auth_rate_limiter = server::util::rate_limit::AuthRateLimiter::new(
+            redis_pool.clone(),
+            config.auth_rate_limit,
+        );
+        startup::start_search_reindex(db.clone(), search.clone(), &supervisor);
+        startup::start_scheduler(
+            db.clone(),
+            worker.queue.clone(),
+            telemetry.clone(),
+            plugins.clone(),
+            scheduler,
+            scheduler_cron,
+            &supervisor,
+        )
+        .await?;
 
         tracing::info!("Starting server");
 
         let mut router = dioxus::server::router(client::App);
-        let server_routes = server::router::routes()
-            .with_state(AppState {
-                db,
-                esi_provider,
-                worker,
-            })
+        let state = AppState {
+            db,
+            esi_provider,
+            worker,
+            telemetry,
+            push,
+            search,
+            image_proxy,
+            object_storage,
+            branding,
+            scheduler,
+            approvals,
+            read_only: read_only.clone(),
+            supervisor,
+            cipher,
+            scope_sets,
+            discord,
+            redis_pool,
+            session_expiry,
+        };
+        let server_routes = server::router::routes(config.api_docs_enabled)
+            .merge(plugins.routes())
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                server::util::session_expiry::apply_session_expiry,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                server::util::permission::require_admin_permissions,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                server::util::suspension::reject_suspended_users,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                server::util::session_index::index_sessions,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                auth_rate_limiter,
+                server::util::rate_limit::limit_auth_requests,
+            ))
+            .with_state(state)
             .layer(session);
         router = router.merge(server_routes);
 
+        // Count queries inside the client info span so warnings carry the request context
+        if cfg!(debug_assertions) {
+            router = router.layer(axum::middleware::from_fn(
+                server::util::query_metrics::count_request_queries,
+            ));
+        }
+        router = router.layer(axum::middleware::from_fn_with_state(
+            read_only,
+            server::util::read_only::reject_writes_when_read_only,
+        ));
+        router = router.layer(axum::middleware::from_fn_with_state(
+            config.request_limits,
+            server::util::limits::apply_request_limits,
+        ));
+        router = router.layer(axum::middleware::from_fn_with_state(
+            config.trusted_proxies.clone(),
+            server::util::proxy::record_client_info,
+        ));
+
+        if config.static_cache_enabled {
+            router = router.layer(axum::middleware::from_fn(
+                server::util::cache::static_asset_cache,
+            ));
+        }
+        if config.compression_enabled {
+            router = router.layer(tower_http::compression::CompressionLayer::new());
+        }
+
         Ok(router)
     })
 }
+
+/// Plugins registered with the server
+///
+/// Shared by the server and the `check-config` command so both see the same scheduled jobs.
+#[cfg(feature = "server")]
+fn plugins() -> server::plugin::PluginRegistry {
+    // Register plugins here, e.g. `PluginRegistry::new().with_plugin(MyPlugin)`
+    server::plugin::PluginRegistry::new()
+}
@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct AffiliationIntervalDto {
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub alliance_id: Option<i64>,
+    pub alliance_name: Option<String>,
+    pub started_at: NaiveDateTime,
+    pub ended_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct AffiliationHistoryDto {
+    pub character_id: i64,
+    pub character_name: String,
+    pub intervals: Vec<AffiliationIntervalDto>,
+}
@@ -0,0 +1,121 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationSubject {
+    User,
+    Character,
+    Corporation,
+}
+
+impl AnnotationSubject {
+    pub const ALL: [AnnotationSubject; 3] = [
+        AnnotationSubject::User,
+        AnnotationSubject::Character,
+        AnnotationSubject::Corporation,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnnotationSubject::User => "user",
+            AnnotationSubject::Character => "character",
+            AnnotationSubject::Corporation => "corporation",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|subject| subject.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            AnnotationSubject::User => "User",
+            AnnotationSubject::Character => "Character",
+            AnnotationSubject::Corporation => "Corporation",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum NoteVisibility {
+    Officers,
+    Author,
+}
+
+impl NoteVisibility {
+    pub const ALL: [NoteVisibility; 2] = [NoteVisibility::Officers, NoteVisibility::Author];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteVisibility::Officers => "officers",
+            NoteVisibility::Author => "author",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|visibility| visibility.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            NoteVisibility::Officers => "All officers",
+            NoteVisibility::Author => "Only me",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateTagDto {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct TagDto {
+    pub id: i32,
+    pub name: String,
+    pub created_by_user_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateNoteDto {
+    pub body: String,
+    pub visibility: NoteVisibility,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct NoteDto {
+    pub id: i32,
+    pub body: String,
+    pub visibility: NoteVisibility,
+    pub created_by_user_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct AnnotationsDto {
+    pub subject: AnnotationSubject,
+    pub subject_id: i64,
+    pub tags: Vec<TagDto>,
+    pub notes: Vec<NoteDto>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct TaggedSubjectDto {
+    pub subject: AnnotationSubject,
+    pub subject_id: i64,
+}
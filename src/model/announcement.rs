@@ -0,0 +1,76 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AnnouncementAudience {
+    All,
+    Corporation,
+    Alliance,
+}
+
+impl AnnouncementAudience {
+    pub const ALL: [AnnouncementAudience; 3] = [
+        AnnouncementAudience::All,
+        AnnouncementAudience::Corporation,
+        AnnouncementAudience::Alliance,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnnouncementAudience::All => "all",
+            AnnouncementAudience::Corporation => "corporation",
+            AnnouncementAudience::Alliance => "alliance",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|audience| audience.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            AnnouncementAudience::All => "All members",
+            AnnouncementAudience::Corporation => "Members of a corporation",
+            AnnouncementAudience::Alliance => "Members of an alliance",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateAnnouncementDto {
+    pub title: String,
+    pub body: String,
+    pub audience: AnnouncementAudience,
+    pub audience_id: Option<i64>,
+    pub send_push: bool,
+    pub send_discord: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct AnnouncementDto {
+    pub id: i32,
+    pub title: String,
+    pub body: String,
+    pub audience: AnnouncementAudience,
+    pub audience_id: Option<i64>,
+    pub created_by_user_id: i32,
+    pub created_at: NaiveDateTime,
+    pub recipient_count: u64,
+    pub read_count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct InboxAnnouncementDto {
+    pub id: i32,
+    pub title: String,
+    pub html: String,
+    pub created_at: NaiveDateTime,
+    pub read: bool,
+}
@@ -7,3 +7,37 @@ pub struct ErrorDto {
     /// The error message
     pub error: String,
 }
+
+/// The response when a request can't be served right now, following RFC 9457 problem details
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ProblemDto {
+    /// A short summary of the problem
+    pub title: String,
+    /// The HTTP status code
+    pub status: u16,
+    /// Why the request failed and what the client can do about it
+    pub detail: String,
+}
+
+/// The response when a request fails validation, following RFC 9457 problem details
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ValidationErrorDto {
+    /// A short summary of the problem
+    pub title: String,
+    /// The HTTP status code, always 422
+    pub status: u16,
+    /// The fields that failed validation
+    pub errors: Vec<FieldErrorDto>,
+}
+
+/// A validation error of a single request field
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct FieldErrorDto {
+    /// The name of the field in the request body
+    pub field: String,
+    /// Why the value was rejected
+    pub message: String,
+}
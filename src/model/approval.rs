@@ -0,0 +1,112 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalAction {
+    MergeUsers,
+    GrantAdmin,
+}
+
+impl ApprovalAction {
+    pub const ALL: [ApprovalAction; 2] = [ApprovalAction::MergeUsers, ApprovalAction::GrantAdmin];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApprovalAction::MergeUsers => "merge_users",
+            ApprovalAction::GrantAdmin => "grant_admin",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ApprovalAction::MergeUsers => "Merge users",
+            ApprovalAction::GrantAdmin => "Grant admin",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Expired,
+    Failed,
+}
+
+impl ApprovalStatus {
+    pub const ALL: [ApprovalStatus; 5] = [
+        ApprovalStatus::Pending,
+        ApprovalStatus::Approved,
+        ApprovalStatus::Rejected,
+        ApprovalStatus::Expired,
+        ApprovalStatus::Failed,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApprovalStatus::Pending => "pending",
+            ApprovalStatus::Approved => "approved",
+            ApprovalStatus::Rejected => "rejected",
+            ApprovalStatus::Expired => "expired",
+            ApprovalStatus::Failed => "failed",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ApprovalPayload {
+    MergeUsers { keep: i32, remove: i32 },
+    GrantAdmin { role_id: i32, user_id: i32 },
+}
+
+impl ApprovalPayload {
+    pub fn action(&self) -> ApprovalAction {
+        match self {
+            ApprovalPayload::MergeUsers { .. } => ApprovalAction::MergeUsers,
+            ApprovalPayload::GrantAdmin { .. } => ApprovalAction::GrantAdmin,
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        match self {
+            ApprovalPayload::MergeUsers { keep, remove } => {
+                format!("Merge user {} into user {}", remove, keep)
+            }
+            ApprovalPayload::GrantAdmin { role_id, user_id } => {
+                format!("Assign admin role {} to user {}", role_id, user_id)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ApprovalRequestDto {
+    pub id: i32,
+    pub payload: ApprovalPayload,
+    pub status: ApprovalStatus,
+    pub requested_by_user_id: i32,
+    pub decided_by_user_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub decided_at: Option<NaiveDateTime>,
+}
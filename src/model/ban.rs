@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateUserBanDto {
+    pub reason: String,
+    /// Hours until the ban expires, `None` for a permanent ban
+    pub duration_hours: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserBanDto {
+    pub id: i32,
+    pub user_id: i32,
+    pub reason: String,
+    pub issued_by_user_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub expires_at: Option<NaiveDateTime>,
+    pub lifted_at: Option<NaiveDateTime>,
+    pub lifted_by_user_id: Option<i32>,
+    pub active: bool,
+}
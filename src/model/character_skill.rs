@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+pub const CHARACTER_SKILLS_SCOPE: &str = "esi-skills.read_skills.v1";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CharacterSkillDto {
+    pub skill_id: i64,
+    pub active_level: i32,
+    pub trained_level: i32,
+    pub skillpoints: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CharacterSkillsDto {
+    pub character_id: i64,
+    pub character_name: String,
+    pub total_skillpoints: i64,
+    pub updated_at: NaiveDateTime,
+    pub skills: Vec<CharacterSkillDto>,
+}
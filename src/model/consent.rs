@@ -0,0 +1,50 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentCategory {
+    Assets,
+    Wallet,
+    Location,
+}
+
+impl ConsentCategory {
+    pub const ALL: [ConsentCategory; 3] = [
+        ConsentCategory::Assets,
+        ConsentCategory::Wallet,
+        ConsentCategory::Location,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConsentCategory::Assets => "assets",
+            ConsentCategory::Wallet => "wallet",
+            ConsentCategory::Location => "location",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ConsentCategory::Assets => "Items and ships owned by your characters",
+            ConsentCategory::Wallet => "Wallet balance, journal, and transactions",
+            ConsentCategory::Location => "Current solar system, station, and ship",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ConsentDto {
+    pub category: ConsentCategory,
+    pub description: String,
+    pub granted: bool,
+    pub granted_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+pub const CORPORATION_MEMBERSHIP_SCOPE: &str = "esi-corporation.read_corporation_membership.v1";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CorporationMemberDto {
+    pub character_id: i64,
+    pub character_name: Option<String>,
+    pub user_id: Option<i32>,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CorporationMembersDto {
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub member_count: u64,
+    pub registered_count: u64,
+    pub members: Vec<CorporationMemberDto>,
+}
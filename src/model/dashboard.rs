@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CorporationUserCountDto {
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub user_count: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CharacterCountDto {
+    pub character_count: i64,
+    pub user_count: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DashboardSummaryDto {
+    pub corporation_user_counts: Vec<CorporationUserCountDto>,
+    pub character_count_distribution: Vec<CharacterCountDto>,
+    pub refreshed_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,88 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SavedQueryParameterKind {
+    Integer,
+    Text,
+}
+
+impl SavedQueryParameterKind {
+    pub const ALL: [SavedQueryParameterKind; 2] = [
+        SavedQueryParameterKind::Integer,
+        SavedQueryParameterKind::Text,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SavedQueryParameterKind::Integer => "integer",
+            SavedQueryParameterKind::Text => "text",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SavedQueryParameterDto {
+    pub name: String,
+    pub kind: SavedQueryParameterKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SaveSavedQueryDto {
+    pub name: String,
+    pub sql: String,
+    pub parameters: Vec<SavedQueryParameterDto>,
+    pub row_limit: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SavedQueryDto {
+    pub id: i32,
+    pub slug: String,
+    pub name: String,
+    pub sql: String,
+    pub parameters: Vec<SavedQueryParameterDto>,
+    pub row_limit: u32,
+    pub created_by_user_id: i32,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SavedQueryResultDto {
+    #[cfg_attr(feature = "server", schema(value_type = Vec<Object>))]
+    pub rows: Vec<serde_json::Value>,
+    pub truncated: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateApiKeyDto {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ApiKeyDto {
+    pub id: i32,
+    pub name: String,
+    pub created_by_user_id: i32,
+    pub created_at: NaiveDateTime,
+    pub last_used_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreatedApiKeyDto {
+    pub api_key: ApiKeyDto,
+    pub key: String,
+}
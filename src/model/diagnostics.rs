@@ -0,0 +1,18 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct BackgroundTaskDto {
+    pub name: String,
+    pub status: String,
+    pub restarts: u32,
+    pub last_error: Option<String>,
+    pub started_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DiagnosticsDto {
+    pub tasks: Vec<BackgroundTaskDto>,
+}
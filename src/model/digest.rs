@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DigestCampaignDto {
+    pub name: String,
+    pub staging_system: String,
+    pub starts_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WeeklyDigestDto {
+    pub period_start: NaiveDateTime,
+    pub period_end: NaiveDateTime,
+    pub new_members: Vec<String>,
+    pub upcoming_campaigns: Vec<DigestCampaignDto>,
+    pub queued_jobs: u64,
+    pub dead_letter_jobs: u64,
+}
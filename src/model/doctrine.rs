@@ -0,0 +1,110 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct FittingDto {
+    pub id: i32,
+    pub name: String,
+    pub ship_type_name: String,
+    pub items: Vec<FittingItemDto>,
+    pub eft: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct FittingItemDto {
+    pub type_name: String,
+    pub quantity: i32,
+    pub charge_name: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DoctrineDto {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub fittings: Vec<FittingDto>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateFittingDto {
+    pub eft: String,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateDoctrineDto {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct FittingSkillDto {
+    pub skill_id: i64,
+    pub level: i32,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct MissingSkillDto {
+    pub skill_id: i64,
+    pub required_level: i32,
+    pub active_level: i32,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct FittingReadinessDto {
+    pub fitting_id: i32,
+    pub fitting_name: String,
+    pub ship_type_name: String,
+    pub can_fly: bool,
+    pub missing_skills: Vec<MissingSkillDto>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CharacterDoctrineReadinessDto {
+    pub character_id: i64,
+    pub character_name: String,
+    pub skills_tracked: bool,
+    pub fittings: Vec<FittingReadinessDto>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserDoctrineReadinessDto {
+    pub doctrine_id: i32,
+    pub doctrine_name: String,
+    pub characters: Vec<CharacterDoctrineReadinessDto>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CorporationFittingReadinessDto {
+    pub fitting_id: i32,
+    pub fitting_name: String,
+    pub ship_type_name: String,
+    pub pilot_count: u64,
+    pub readiness_percent: u64,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CorporationDoctrineReadinessDto {
+    pub corporation_id: i64,
+    pub doctrine_id: i32,
+    pub doctrine_name: String,
+    pub tracked_character_count: u64,
+    pub pilot_count: u64,
+    pub readiness_percent: u64,
+    pub fittings: Vec<CorporationFittingReadinessDto>,
+}
@@ -0,0 +1,52 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    affiliation_history::AffiliationHistoryDto, announcement::InboxAnnouncementDto,
+    consent::ConsentDto, preference::UserPreferencesDto, user::CharacterDto,
+};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CharacterExportDto {
+    pub character_id: i64,
+    pub name: String,
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub alliance_id: Option<i64>,
+    pub user_id: Option<i32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct StoredExportDto {
+    pub url: String,
+    pub expires_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserDataExportQueuedDto {
+    pub job_id: String,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserDataArchiveDto {
+    pub user_id: i32,
+    pub registered_at: NaiveDateTime,
+    pub exported_at: NaiveDateTime,
+    pub characters: Vec<CharacterDto>,
+    pub affiliation_history: Vec<AffiliationHistoryDto>,
+    pub preferences: UserPreferencesDto,
+    pub consents: Vec<ConsentDto>,
+    pub announcements: Vec<InboxAnnouncementDto>,
+    pub push_subscriptions: Vec<ExportedPushSubscriptionDto>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ExportedPushSubscriptionDto {
+    pub endpoint: String,
+    pub created_at: NaiveDateTime,
+}
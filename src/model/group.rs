@@ -0,0 +1,155 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum GroupJoinPolicy {
+    Open,
+    Request,
+    Hidden,
+}
+
+impl GroupJoinPolicy {
+    pub const ALL: [GroupJoinPolicy; 3] = [
+        GroupJoinPolicy::Open,
+        GroupJoinPolicy::Request,
+        GroupJoinPolicy::Hidden,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupJoinPolicy::Open => "open",
+            GroupJoinPolicy::Request => "request",
+            GroupJoinPolicy::Hidden => "hidden",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            GroupJoinPolicy::Open => "Anyone can join",
+            GroupJoinPolicy::Request => "Anyone can request to join, an admin approves",
+            GroupJoinPolicy::Hidden => "Only listed to members, admins add members",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum GroupMemberStatus {
+    Pending,
+    Member,
+}
+
+impl GroupMemberStatus {
+    pub const ALL: [GroupMemberStatus; 2] = [GroupMemberStatus::Pending, GroupMemberStatus::Member];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupMemberStatus::Pending => "pending",
+            GroupMemberStatus::Member => "member",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum GroupRuleKind {
+    Corporation,
+    Alliance,
+    Faction,
+}
+
+impl GroupRuleKind {
+    pub const ALL: [GroupRuleKind; 3] = [
+        GroupRuleKind::Corporation,
+        GroupRuleKind::Alliance,
+        GroupRuleKind::Faction,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupRuleKind::Corporation => "corporation",
+            GroupRuleKind::Alliance => "alliance",
+            GroupRuleKind::Faction => "faction",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateGroupDto {
+    pub name: String,
+    pub description: String,
+    pub join_policy: GroupJoinPolicy,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct GroupDto {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub join_policy: GroupJoinPolicy,
+    pub member_count: u64,
+    pub pending_count: u64,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserGroupDto {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub join_policy: GroupJoinPolicy,
+    pub status: Option<GroupMemberStatus>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct GroupMemberDto {
+    pub user_id: i32,
+    pub main_character_id: Option<i64>,
+    pub main_character_name: Option<String>,
+    pub status: GroupMemberStatus,
+    pub created_at: NaiveDateTime,
+    pub decided_at: Option<NaiveDateTime>,
+    pub decided_by_user_id: Option<i32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateGroupRuleDto {
+    pub kind: GroupRuleKind,
+    pub entity_id: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct GroupRuleDto {
+    pub id: i32,
+    pub group_id: i32,
+    pub kind: GroupRuleKind,
+    pub entity_id: i64,
+    pub created_at: NaiveDateTime,
+}
@@ -0,0 +1,109 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MemberList {
+    Users,
+    Characters,
+}
+
+impl MemberList {
+    pub const ALL: [MemberList; 2] = [MemberList::Users, MemberList::Characters];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemberList::Users => "users",
+            MemberList::Characters => "characters",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|list| list.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            MemberList::Users => "Users",
+            MemberList::Characters => "Characters",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct MemberFilterDto {
+    pub name: Option<String>,
+    pub corporation_id: Option<i64>,
+    pub alliance_id: Option<i64>,
+    pub tag: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct MemberUserDto {
+    pub user_id: i32,
+    pub main_character_id: i64,
+    pub main_character_name: String,
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub alliance_id: Option<i64>,
+    pub alliance_name: Option<String>,
+    pub character_count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct MemberCharacterDto {
+    pub character_id: i64,
+    pub character_name: String,
+    pub user_id: i32,
+    pub is_main: bool,
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub alliance_id: Option<i64>,
+    pub alliance_name: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SaveMemberFilterDto {
+    pub list: MemberList,
+    pub name: String,
+    pub filter: MemberFilterDto,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SavedMemberFilterDto {
+    pub id: i32,
+    pub list: MemberList,
+    pub name: String,
+    pub filter: MemberFilterDto,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum MemberBulkAction {
+    ForceRefresh,
+    SendNotification { title: String, body: String },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct MemberBulkActionDto {
+    pub list: MemberList,
+    pub ids: Vec<i64>,
+    pub action: MemberBulkAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct MemberBulkResultDto {
+    pub id: i64,
+    pub job_ids: Vec<String>,
+    pub error: Option<String>,
+}
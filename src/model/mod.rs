@@ -1,2 +1,36 @@
+pub mod affiliation_history;
+pub mod annotation;
+pub mod announcement;
 pub mod api;
+pub mod approval;
+pub mod ban;
+pub mod branding;
+pub mod campaign;
+pub mod character_skill;
+pub mod consent;
+pub mod corporation_member;
+pub mod dashboard;
+pub mod data_api;
+pub mod diagnostics;
+pub mod digest;
+pub mod doctrine;
+pub mod export;
+pub mod group;
+pub mod maintenance;
+pub mod member;
+pub mod onboarding;
+pub mod page;
+pub mod preference;
+pub mod push;
+pub mod reauth_campaign;
+pub mod recruitment;
+pub mod role;
+pub mod scheduler;
+pub mod screening;
+pub mod search;
+pub mod skill_plan;
+pub mod telemetry;
 pub mod user;
+pub mod webhook;
+pub mod widget;
+pub mod worker;
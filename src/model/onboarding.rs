@@ -0,0 +1,106 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStepKind {
+    LinkAlts,
+    GrantScopes,
+    ReadPage,
+    JoinDiscord,
+    Manual,
+}
+
+impl OnboardingStepKind {
+    pub const ALL: [OnboardingStepKind; 5] = [
+        OnboardingStepKind::LinkAlts,
+        OnboardingStepKind::GrantScopes,
+        OnboardingStepKind::ReadPage,
+        OnboardingStepKind::JoinDiscord,
+        OnboardingStepKind::Manual,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OnboardingStepKind::LinkAlts => "link_alts",
+            OnboardingStepKind::GrantScopes => "grant_scopes",
+            OnboardingStepKind::ReadPage => "read_page",
+            OnboardingStepKind::JoinDiscord => "join_discord",
+            OnboardingStepKind::Manual => "manual",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            OnboardingStepKind::LinkAlts => "Link all alts",
+            OnboardingStepKind::GrantScopes => "Grant ESI scopes",
+            OnboardingStepKind::ReadPage => "Read a page",
+            OnboardingStepKind::JoinDiscord => "Join Discord",
+            OnboardingStepKind::Manual => "Other",
+        }
+    }
+
+    pub fn target_description(&self) -> Option<&'static str> {
+        match self {
+            OnboardingStepKind::GrantScopes => Some("Space-separated ESI scopes"),
+            OnboardingStepKind::ReadPage => Some("Page slug"),
+            OnboardingStepKind::JoinDiscord => Some("Invite URL"),
+            OnboardingStepKind::LinkAlts | OnboardingStepKind::Manual => None,
+        }
+    }
+
+    pub fn is_self_reported(&self) -> bool {
+        matches!(
+            self,
+            OnboardingStepKind::ReadPage
+                | OnboardingStepKind::JoinDiscord
+                | OnboardingStepKind::Manual
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateOnboardingStepDto {
+    pub title: String,
+    pub description: String,
+    pub kind: OnboardingStepKind,
+    pub target: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct OnboardingStepDto {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+    pub kind: OnboardingStepKind,
+    pub target: Option<String>,
+    pub position: i32,
+    pub created_by_user_id: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct OnboardingChecklistItemDto {
+    pub step: OnboardingStepDto,
+    pub completed: bool,
+    pub completed_at: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct IncompleteOnboardingDto {
+    pub user_id: i32,
+    pub main_character_id: Option<i64>,
+    pub main_character_name: Option<String>,
+    pub completed_count: u64,
+    pub step_count: u64,
+    pub missing_steps: Vec<String>,
+}
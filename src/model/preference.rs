@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardWidget {
+    Characters,
+    Updates,
+    DataSharing,
+    Notifications,
+    Announcements,
+    Onboarding,
+}
+
+impl DashboardWidget {
+    pub const ALL: [DashboardWidget; 6] = [
+        DashboardWidget::Characters,
+        DashboardWidget::Updates,
+        DashboardWidget::DataSharing,
+        DashboardWidget::Notifications,
+        DashboardWidget::Announcements,
+        DashboardWidget::Onboarding,
+    ];
+
+    pub const DEFAULT_LAYOUT: [DashboardWidget; 4] = [
+        DashboardWidget::Onboarding,
+        DashboardWidget::Characters,
+        DashboardWidget::Announcements,
+        DashboardWidget::Updates,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardWidget::Characters => "characters",
+            DashboardWidget::Updates => "updates",
+            DashboardWidget::DataSharing => "data_sharing",
+            DashboardWidget::Notifications => "notifications",
+            DashboardWidget::Announcements => "announcements",
+            DashboardWidget::Onboarding => "onboarding",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|widget| widget.as_str() == value)
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            DashboardWidget::Characters => "My Characters",
+            DashboardWidget::Updates => "Update Information",
+            DashboardWidget::DataSharing => "Data Sharing",
+            DashboardWidget::Notifications => "Notifications",
+            DashboardWidget::Announcements => "Announcements",
+            DashboardWidget::Onboarding => "Onboarding Checklist",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserPreferencesDto {
+    pub dashboard_layout: Vec<DashboardWidget>,
+    pub weekly_digest: bool,
+}
@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::model::announcement::AnnouncementAudience;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateReauthCampaignDto {
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub audience: AnnouncementAudience,
+    pub audience_id: Option<i64>,
+    pub deadline: Option<NaiveDateTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ReauthCampaignDto {
+    pub id: i32,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub audience: AnnouncementAudience,
+    pub audience_id: Option<i64>,
+    pub deadline: Option<NaiveDateTime>,
+    pub created_by_user_id: i32,
+    pub created_at: NaiveDateTime,
+    pub user_count: u64,
+    pub completed_count: u64,
+}
+
+impl ReauthCampaignDto {
+    pub fn completion_percent(&self) -> u64 {
+        if self.user_count == 0 {
+            return 100;
+        }
+
+        self.completed_count * 100 / self.user_count
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct PendingReauthCampaignDto {
+    pub id: i32,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub deadline: Option<NaiveDateTime>,
+    pub restricted: bool,
+}
@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct RecruitmentListingDto {
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub corporation_ticker: String,
+    pub alliance_id: Option<i64>,
+    pub alliance_name: Option<String>,
+    pub member_count: i64,
+    pub description: String,
+    pub timezone: String,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UpsertRecruitmentListingDto {
+    pub description: String,
+    pub timezone: String,
+}
@@ -0,0 +1,89 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+pub const ADMIN_ROLE: &str = "admin";
+pub const MEMBER_ROLE: &str = "member";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    Admin,
+    ManageRoles,
+    ManageMembers,
+    ManageContent,
+    ManageIntegrations,
+    DecideApprovals,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 6] = [
+        Permission::Admin,
+        Permission::ManageRoles,
+        Permission::ManageMembers,
+        Permission::ManageContent,
+        Permission::ManageIntegrations,
+        Permission::DecideApprovals,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::Admin => "admin",
+            Permission::ManageRoles => "manage_roles",
+            Permission::ManageMembers => "manage_members",
+            Permission::ManageContent => "manage_content",
+            Permission::ManageIntegrations => "manage_integrations",
+            Permission::DecideApprovals => "decide_approvals",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|permission| permission.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Permission::Admin => "Full access to every admin feature",
+            Permission::ManageRoles => "Create roles and assign them to users",
+            Permission::ManageMembers => {
+                "Manage members, their notes, tags, bans, and groups, screen recruits, merge users"
+            }
+            Permission::ManageContent => {
+                "Edit announcements, pages, widgets, onboarding, doctrines, skill plans, campaigns"
+            }
+            Permission::ManageIntegrations => "Manage webhooks, API keys, and saved queries",
+            Permission::DecideApprovals => "Approve or reject sensitive admin actions",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateRoleDto {
+    pub name: String,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UpdateRoleDto {
+    pub name: String,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct RoleDto {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub permissions: Vec<Permission>,
+    pub built_in: bool,
+    pub user_count: u64,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
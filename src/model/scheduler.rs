@@ -0,0 +1,46 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SchedulerJobKind {
+    Faction,
+    Alliance,
+    Corporation,
+    Character,
+    Affiliation,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ScheduledJobPreviewDto {
+    pub job: String,
+    pub scheduled_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SchedulerPreviewDto {
+    pub kind: SchedulerJobKind,
+    pub jobs: Vec<ScheduledJobPreviewDto>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct EntityFreshnessDto {
+    pub kind: SchedulerJobKind,
+    pub cache_duration_secs: i64,
+    pub count: u64,
+    pub overdue: u64,
+    pub p50_age_secs: Option<i64>,
+    pub p95_age_secs: Option<i64>,
+    pub max_age_secs: Option<i64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct FreshnessReportDto {
+    pub generated_at: NaiveDateTime,
+    pub entities: Vec<EntityFreshnessDto>,
+}
@@ -0,0 +1,14 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::model::user::CharacterDto;
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ScreeningReportDto {
+    pub id: i32,
+    pub requested_by_user_id: i32,
+    pub character: CharacterDto,
+    pub known_alts: Vec<CharacterDto>,
+    pub created_at: NaiveDateTime,
+}
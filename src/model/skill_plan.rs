@@ -0,0 +1,29 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SkillPlanDto {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub entries: Vec<SkillPlanEntryDto>,
+    pub plan: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct SkillPlanEntryDto {
+    pub skill_name: String,
+    pub level: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateSkillPlanDto {
+    pub name: String,
+    pub description: Option<String>,
+    pub plan: String,
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct TelemetryReportDto {
+    pub version: String,
+    pub user_count_bucket: String,
+    pub features: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct TelemetryStatusDto {
+    pub enabled: bool,
+    pub endpoint: Option<String>,
+    pub report: TelemetryReportDto,
+}
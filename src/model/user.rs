@@ -7,6 +7,7 @@ pub struct UserDto {
     pub id: i32,
     pub character_id: i64,
     pub character_name: String,
+    pub portrait_url: String,
 }
 
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
@@ -14,6 +15,7 @@ pub struct UserDto {
 pub struct CharacterDto {
     pub id: i64,
     pub name: String,
+    pub portrait_url: String,
     pub corporation: CorporationDto,
     pub alliance: Option<AllianceDto>,
     pub info_updated_at: NaiveDateTime,
@@ -25,6 +27,7 @@ pub struct CharacterDto {
 pub struct CorporationDto {
     pub id: i64,
     pub name: String,
+    pub logo_url: String,
     pub info_updated_at: NaiveDateTime,
     pub affiliation_updated_at: NaiveDateTime,
 }
@@ -34,5 +37,106 @@ pub struct CorporationDto {
 pub struct AllianceDto {
     pub id: i64,
     pub name: String,
+    pub logo_url: String,
     pub updated_at: NaiveDateTime,
 }
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct LinkModeDto {
+    pub active: bool,
+    pub results: Vec<LinkedCharacterDto>,
+    pub queued: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct LinkedCharacterDto {
+    pub character_id: Option<i64>,
+    pub character_name: Option<String>,
+    pub linked: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct PendingTransferDto {
+    pub from_user_id: i32,
+    pub character_id: i64,
+    pub character_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserMergeDto {
+    pub kept_user_id: i32,
+    pub removed_user_id: i32,
+    pub characters_moved: u64,
+    pub consents_merged: u64,
+    pub roles_merged: u64,
+    pub widgets_moved: u64,
+    pub fittings_moved: u64,
+    pub skill_plans_moved: u64,
+    pub campaigns_moved: u64,
+    pub push_subscriptions_moved: u64,
+    pub screening_reports_moved: u64,
+    pub page_revisions_moved: u64,
+    pub announcements_moved: u64,
+    pub reauth_campaigns_moved: u64,
+    pub onboarding_steps_moved: u64,
+    pub saved_queries_moved: u64,
+    pub api_keys_moved: u64,
+    pub tags_moved: u64,
+    pub notes_moved: u64,
+    pub annotations_moved: u64,
+    pub approval_requests_moved: u64,
+    pub bans_moved: u64,
+    pub group_memberships_moved: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DiscordAccountDto {
+    pub discord_id: String,
+    pub username: String,
+    pub linked_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserSessionDto {
+    pub id: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub last_seen_at: NaiveDateTime,
+    pub current: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum UserCharacterSort {
+    #[default]
+    Name,
+    Corporation,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserCharacterQueryDto {
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+    pub sort: Option<UserCharacterSort>,
+    pub corporation_id: Option<i64>,
+    pub alliance_id: Option<i64>,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UserCharacterPageDto {
+    pub total: u64,
+    pub total_pages: u64,
+    pub page: u64,
+    pub per_page: u64,
+    pub characters: Vec<CharacterDto>,
+}
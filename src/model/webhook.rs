@@ -0,0 +1,68 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    CharacterLinked,
+    CharacterTransferred,
+    MainChanged,
+}
+
+impl WebhookEvent {
+    pub const ALL: [WebhookEvent; 3] = [
+        WebhookEvent::CharacterLinked,
+        WebhookEvent::CharacterTransferred,
+        WebhookEvent::MainChanged,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::CharacterLinked => "character_linked",
+            WebhookEvent::CharacterTransferred => "character_transferred",
+            WebhookEvent::MainChanged => "main_changed",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|event| event.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            WebhookEvent::CharacterLinked => "Character linked to a user",
+            WebhookEvent::CharacterTransferred => "Character transferred between users",
+            WebhookEvent::MainChanged => "User changed their main character",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateWebhookDto {
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct UpdateWebhookDto {
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WebhookDto {
+    pub id: i32,
+    pub name: String,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    pub enabled: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,58 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WidgetKind {
+    CorporationMemberCount,
+}
+
+impl WidgetKind {
+    pub const ALL: [WidgetKind; 1] = [WidgetKind::CorporationMemberCount];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WidgetKind::CorporationMemberCount => "corporation_member_count",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            WidgetKind::CorporationMemberCount => "Corporation member count",
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WidgetDto {
+    pub id: i32,
+    pub kind: WidgetKind,
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub token: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct CreateWidgetDto {
+    pub kind: WidgetKind,
+    pub corporation_id: i64,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WidgetDataDto {
+    pub kind: WidgetKind,
+    pub corporation_id: i64,
+    pub corporation_name: String,
+    pub corporation_ticker: String,
+    pub member_count: i64,
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,99 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DeadLetterJobDto {
+    pub id: u64,
+    pub job: String,
+    pub payload: String,
+    pub error: String,
+    pub attempt_count: u32,
+    pub failed_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct ReplayDeadLetterDto {
+    pub payload: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct DeadLetterReplayDto {
+    pub id: u64,
+    pub payload: String,
+    pub queued: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerJobState {
+    Queued,
+    Running,
+    Succeeded,
+    PartiallySucceeded,
+    Failed,
+    Timeout,
+}
+
+impl WorkerJobState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkerJobState::Queued => "queued",
+            WorkerJobState::Running => "running",
+            WorkerJobState::Succeeded => "succeeded",
+            WorkerJobState::PartiallySucceeded => "partially_succeeded",
+            WorkerJobState::Failed => "failed",
+            WorkerJobState::Timeout => "timeout",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WorkerJobStatusDto {
+    pub job_id: String,
+    pub job: String,
+    pub state: WorkerJobState,
+    pub error: Option<String>,
+    pub failed_items: Vec<WorkerJobItemFailureDto>,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WorkerJobItemFailureDto {
+    pub id: i64,
+    pub error: String,
+    pub retryable: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WorkerQueueQueryDto {
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+    pub offset: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct QueuedWorkerJobDto {
+    pub job_id: String,
+    pub job: String,
+    pub payload: String,
+    pub scheduled_at: NaiveDateTime,
+    pub attempt_count: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(utoipa::ToSchema))]
+pub struct WorkerQueuePageDto {
+    pub total: u64,
+    pub offset: u64,
+    pub limit: u64,
+    pub jobs: Vec<QueuedWorkerJobDto>,
+}
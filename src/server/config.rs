@@ -2,10 +2,123 @@
 //!
 //! This module provides the `Config` struct for loading and validating server configuration
 //! from environment variables. Configuration includes database URLs, ESI OAuth credentials,
-//! contact information, and worker pool sizing. All required environment variables must be
-//! present or the application will fail to start with a descriptive error.
+//! contact information, worker pool sizing, session cookie attributes, trusted reverse
+//! proxies, HTTP response compression and caching, request timeouts and body size limits, and
+//! scheduler batching and cron schedules. All required environment variables must be present or the application
+//! will fail to start with a descriptive error.
 
-use crate::server::error::{config::ConfigError, AppError};
+use std::{path::PathBuf, str::FromStr, time::Duration};
+
+use tokio_cron_scheduler::Job;
+use tower_sessions::cookie::SameSite;
+
+use crate::{
+    model::approval::ApprovalAction,
+    server::{
+        error::{config::ConfigError, AppError},
+        scheduler::config::{CronSchedules, SchedulerSettings},
+        service::auth::discord::DiscordOAuthSettings,
+        util::{
+            branding::{parse_color, parse_nav_links, parse_url, BrandingError, BrandingSettings},
+            crypto::{parse_encryption_keys, EncryptionKey},
+            limits::RequestLimits,
+            object_storage::ObjectStorageSettings,
+            proxy::TrustedProxies,
+            rate_limit::AuthRateLimit,
+            scope_set::ScopeSets,
+            session_expiry::SessionExpiry,
+            web_push::VapidKey,
+        },
+    },
+};
+
+/// Default name of the session cookie, matching the tower-sessions default.
+const DEFAULT_SESSION_COOKIE_NAME: &str = "id";
+
+/// Default time cached EVE images are served before being refetched (1 day).
+const DEFAULT_IMAGE_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Default region of the object storage bucket, accepted by most self-hosted stores.
+const DEFAULT_OBJECT_STORAGE_REGION: &str = "us-east-1";
+
+/// Default number of hours approval requests can be decided in.
+const DEFAULT_APPROVAL_EXPIRY_HOURS: u32 = 24;
+
+/// Maximum number of shards affiliation updates can be partitioned into.
+const MAX_AFFILIATION_SHARDS: u32 = 1000;
+
+/// Environment variables that must be set for the server to start.
+pub const REQUIRED_ENV_VARS: [&str; 7] = [
+    "CONTACT_EMAIL",
+    "ESI_CLIENT_ID",
+    "ESI_CLIENT_SECRET",
+    "ESI_CALLBACK_URL",
+    "DATABASE_URL",
+    "VALKEY_URL",
+    "WORKERS",
+];
+
+/// Environment variables read by the server that may be left unset.
+pub const OPTIONAL_ENV_VARS: &[&str] = &[
+    "ESI_SCOPE_SETS",
+    "ENCRYPTION_KEYS",
+    "TELEMETRY_ENDPOINT",
+    "VAPID_PRIVATE_KEY",
+    "SESSION_COOKIE_NAME",
+    "SESSION_COOKIE_DOMAIN",
+    "SESSION_COOKIE_SAME_SITE",
+    "SESSION_COOKIE_SECURE",
+    "SESSION_INACTIVITY_DAYS",
+    "SESSION_REMEMBER_ME_DAYS",
+    "TRUSTED_PROXIES",
+    "COMPRESSION_ENABLED",
+    "STATIC_CACHE_ENABLED",
+    "API_DOCS_ENABLED",
+    "REQUEST_TIMEOUT_SECS",
+    "LONG_REQUEST_TIMEOUT_SECS",
+    "REQUEST_BODY_LIMIT_BYTES",
+    "IMPORT_BODY_LIMIT_BYTES",
+    "AUTH_RATE_LIMIT_PER_IP",
+    "AUTH_RATE_LIMIT_PER_SESSION",
+    "AUTH_RATE_LIMIT_WINDOW_SECS",
+    "WARMUP_ENABLED",
+    "MEILISEARCH_URL",
+    "MEILISEARCH_API_KEY",
+    "OBJECT_STORAGE_ENDPOINT",
+    "OBJECT_STORAGE_BUCKET",
+    "OBJECT_STORAGE_REGION",
+    "OBJECT_STORAGE_ACCESS_KEY_ID",
+    "OBJECT_STORAGE_SECRET_ACCESS_KEY",
+    "IMAGE_CACHE_DIR",
+    "IMAGE_CACHE_TTL_SECS",
+    "BRANDING_ORG_NAME",
+    "BRANDING_LOGO_URL",
+    "BRANDING_PRIMARY_COLOR",
+    "BRANDING_NAV_LINKS",
+    "DISCORD_WEBHOOK_URL",
+    "DISCORD_CLIENT_ID",
+    "DISCORD_CLIENT_SECRET",
+    "DISCORD_CALLBACK_URL",
+    "SCHEDULER_STAGGER_WINDOW_SECS",
+    "SCHEDULER_MIN_BATCH_SIZE",
+    "SCHEDULER_MAX_BATCH_SIZE",
+    "SCHEDULER_JITTER_SECS",
+    "SCHEDULER_AFFILIATION_SHARDS",
+    "SCHEDULER_FACTION_CRON",
+    "SCHEDULER_ALLIANCE_CRON",
+    "SCHEDULER_CORPORATION_CRON",
+    "SCHEDULER_CHARACTER_CRON",
+    "SCHEDULER_AFFILIATION_CRON",
+    "SCHEDULER_DASHBOARD_CRON",
+    "SCHEDULER_CORPORATION_MEMBER_CRON",
+    "SCHEDULER_CHARACTER_SKILL_CRON",
+    "SCHEDULER_TOKEN_PRUNE_CRON",
+    "SCHEDULER_DIGEST_CRON",
+    "SCHEDULER_TELEMETRY_CRON",
+    "APPROVAL_REQUIRED_ACTIONS",
+    "APPROVAL_EXPIRY_HOURS",
+    "READ_ONLY_MODE",
+];
 
 /// Server configuration loaded from environment variables.
 ///
@@ -22,6 +135,64 @@ use crate::server::error::{config::ConfigError, AppError};
 /// - `DATABASE_URL` - PostgreSQL database connection string
 /// - `VALKEY_URL` - Redis/Valkey connection string for sessions and worker queue
 /// - `WORKERS` - Number of worker threads for background job processing (must be a valid number)
+/// - `ESI_SCOPE_SETS` - Optional comma-separated `name:scopes` ESI scope sets logins can request (none if unset)
+/// - `ENCRYPTION_KEYS` - Optional keys for encrypting sensitive columns (`id:base64_key`, active key first)
+/// - `TELEMETRY_ENDPOINT` - Optional URL to send anonymous usage statistics to (disabled if unset)
+/// - `VAPID_PRIVATE_KEY` - Optional base64url P-256 private key for Web Push (disabled if unset)
+/// - `SESSION_COOKIE_NAME` - Optional session cookie name (defaults to `id`)
+/// - `SESSION_COOKIE_DOMAIN` - Optional session cookie domain (defaults to the request host)
+/// - `SESSION_COOKIE_SAME_SITE` - Optional `strict`, `lax`, or `none` (defaults to `lax`)
+/// - `SESSION_COOKIE_SECURE` - Optional `true`/`false` (defaults to `true` in release builds)
+/// - `SESSION_INACTIVITY_DAYS` - Optional days sessions stay valid without requests (defaults to `7`)
+/// - `SESSION_REMEMBER_ME_DAYS` - Optional days "remember me" sessions stay valid without requests (defaults to `30`)
+/// - `TRUSTED_PROXIES` - Optional reverse proxy IPs/CIDR networks whose forwarding headers are trusted
+/// - `COMPRESSION_ENABLED` - Optional `true`/`false` to compress responses (defaults to `true`)
+/// - `STATIC_CACHE_ENABLED` - Optional `true`/`false` to add caching headers to static assets (defaults to `true`)
+/// - `API_DOCS_ENABLED` - Optional `true`/`false` to serve Swagger UI at `/api/docs` (defaults to `true`)
+/// - `REQUEST_TIMEOUT_SECS` - Optional seconds API requests have to respond (defaults to `30`)
+/// - `LONG_REQUEST_TIMEOUT_SECS` - Optional seconds exports have to respond (defaults to `300`)
+/// - `REQUEST_BODY_LIMIT_BYTES` - Optional maximum request body size (defaults to 64 KiB)
+/// - `IMPORT_BODY_LIMIT_BYTES` - Optional maximum body size for fitting and skill plan imports (defaults to 2 MiB)
+/// - `AUTH_RATE_LIMIT_PER_IP` - Optional SSO logins and callbacks allowed per IP address and window, `0` disables (defaults to `30`)
+/// - `AUTH_RATE_LIMIT_PER_SESSION` - Optional SSO logins and callbacks allowed per session and window, `0` disables (defaults to `10`)
+/// - `AUTH_RATE_LIMIT_WINDOW_SECS` - Optional seconds SSO requests are counted in (defaults to `60`)
+/// - `WARMUP_ENABLED` - Optional `true`/`false` to preload lookup data before serving traffic (defaults to `true`)
+/// - `MEILISEARCH_URL` - Optional Meilisearch URL used for search (defaults to searching the database)
+/// - `MEILISEARCH_API_KEY` - Optional API key for the Meilisearch instance
+/// - `OBJECT_STORAGE_ENDPOINT` - Optional S3-compatible endpoint for storing exports (disabled if unset)
+/// - `OBJECT_STORAGE_BUCKET` - Bucket exports are stored in (required with `OBJECT_STORAGE_ENDPOINT`)
+/// - `OBJECT_STORAGE_REGION` - Optional region of the bucket (defaults to `us-east-1`)
+/// - `OBJECT_STORAGE_ACCESS_KEY_ID` - Access key ID (required with `OBJECT_STORAGE_ENDPOINT`)
+/// - `OBJECT_STORAGE_SECRET_ACCESS_KEY` - Secret access key (required with `OBJECT_STORAGE_ENDPOINT`)
+/// - `IMAGE_CACHE_DIR` - Optional directory EVE images are proxied and cached in (images redirect to CCP if unset)
+/// - `IMAGE_CACHE_TTL_SECS` - Optional seconds cached EVE images are kept before refetching (defaults to `86400`)
+/// - `BRANDING_ORG_NAME` - Optional organization name shown instead of "Bifrost"
+/// - `BRANDING_LOGO_URL` - Optional URL of a logo shown next to the organization name
+/// - `BRANDING_PRIMARY_COLOR` - Optional primary theme color in `#rrggbb` notation
+/// - `BRANDING_NAV_LINKS` - Optional comma-separated `Label|URL` links to external tools
+/// - `DISCORD_WEBHOOK_URL` - Optional Discord webhook announcements can be posted to (disabled if unset)
+/// - `DISCORD_CLIENT_ID` - Optional Discord application client ID for linking Discord accounts (disabled if unset)
+/// - `DISCORD_CLIENT_SECRET` - Discord application client secret (required with `DISCORD_CLIENT_ID`)
+/// - `DISCORD_CALLBACK_URL` - OAuth callback URL registered with Discord (required with `DISCORD_CLIENT_ID`)
+/// - `SCHEDULER_STAGGER_WINDOW_SECS` - Optional seconds refresh jobs are staggered across (defaults to each schedule interval)
+/// - `SCHEDULER_MIN_BATCH_SIZE` - Optional minimum entities refreshed per scheduler run (defaults to `100`)
+/// - `SCHEDULER_MAX_BATCH_SIZE` - Optional maximum entities refreshed per scheduler run (unlimited if unset)
+/// - `SCHEDULER_JITTER_SECS` - Optional maximum random delay added to each refresh job (defaults to `0`)
+/// - `SCHEDULER_AFFILIATION_SHARDS` - Optional number of shards affiliation updates rotate through, one per run (defaults to `1`)
+/// - `SCHEDULER_FACTION_CRON` - Optional cron expression for faction info updates (defaults to the built-in schedule)
+/// - `SCHEDULER_ALLIANCE_CRON` - Optional cron expression for alliance info updates (defaults to the built-in schedule)
+/// - `SCHEDULER_CORPORATION_CRON` - Optional cron expression for corporation info updates (defaults to the built-in schedule)
+/// - `SCHEDULER_CHARACTER_CRON` - Optional cron expression for character info updates (defaults to the built-in schedule)
+/// - `SCHEDULER_AFFILIATION_CRON` - Optional cron expression for character affiliation updates (defaults to the built-in schedule)
+/// - `SCHEDULER_DASHBOARD_CRON` - Optional cron expression for admin dashboard summary refreshes (defaults to the built-in schedule)
+/// - `SCHEDULER_CORPORATION_MEMBER_CRON` - Optional cron expression for corporation member list refreshes (defaults to the built-in schedule)
+/// - `SCHEDULER_CHARACTER_SKILL_CRON` - Optional cron expression for character skill snapshot refreshes (defaults to the built-in schedule)
+/// - `SCHEDULER_TOKEN_PRUNE_CRON` - Optional cron expression for pruning revoked character tokens (defaults to the built-in schedule)
+/// - `SCHEDULER_DIGEST_CRON` - Optional cron expression for the weekly digest (defaults to the built-in schedule)
+/// - `SCHEDULER_TELEMETRY_CRON` - Optional cron expression for the telemetry report (defaults to the built-in schedule)
+/// - `APPROVAL_REQUIRED_ACTIONS` - Optional comma-separated sensitive actions a second admin must approve (none if unset)
+/// - `APPROVAL_EXPIRY_HOURS` - Optional hours approval requests stay open (defaults to `24`)
+/// - `READ_ONLY_MODE` - Optional flag starting the server in read-only mode (defaults to `false`)
 pub struct Config {
     /// Contact email address for user agent identification.
     ///
@@ -69,6 +240,165 @@ pub struct Config {
     /// Controls the size of the worker pool that processes background jobs (ESI data refresh,
     /// etc.). Higher values allow more concurrent job processing but consume more resources.
     pub workers: usize,
+
+    /// Named sets of ESI scopes logins can request with the `scopes` query parameter.
+    ///
+    /// Lets deployments request the scope bundles their tools need without code changes.
+    /// Empty if `ESI_SCOPE_SETS` is not set, in which case logins can only request raw scopes.
+    pub scope_sets: ScopeSets,
+
+    /// Keys used to encrypt sensitive columns such as wallet journals and locations.
+    ///
+    /// The first key is the active key used for new encryptions; the remaining keys are kept
+    /// to decrypt values written before a key rotation. Empty if `ENCRYPTION_KEYS` is not
+    /// set, in which case storing encrypted data fails.
+    pub encryption_keys: Vec<EncryptionKey>,
+
+    /// Endpoint anonymous telemetry reports are sent to.
+    ///
+    /// Telemetry is opt-in: reports are only sent if `TELEMETRY_ENDPOINT` is set to a
+    /// non-empty URL.
+    pub telemetry_endpoint: Option<String>,
+
+    /// VAPID key used to sign Web Push messages.
+    ///
+    /// Push notifications are disabled if `VAPID_PRIVATE_KEY` is not set. Replacing the key
+    /// invalidates all existing push subscriptions.
+    pub vapid_key: Option<VapidKey>,
+
+    /// Name of the session cookie.
+    ///
+    /// Change this when running several instances on the same domain so their sessions do
+    /// not overwrite each other.
+    pub session_cookie_name: String,
+
+    /// Domain attribute of the session cookie.
+    ///
+    /// If unset the cookie is only sent to the exact host that set it.
+    pub session_cookie_domain: Option<String>,
+
+    /// SameSite attribute of the session cookie.
+    ///
+    /// Must stay `Lax` or `None` for the EVE Online SSO callback, which is a cross-site
+    /// navigation, to receive the session holding the CSRF state.
+    pub session_cookie_same_site: SameSite,
+
+    /// Whether the session cookie is only sent over HTTPS.
+    ///
+    /// Defaults to enabled in release builds. Keep enabled behind a TLS-terminating reverse
+    /// proxy even though the proxy connects to Bifrost over plain HTTP.
+    pub session_cookie_secure: bool,
+
+    /// Days of inactivity after which sessions expire.
+    ///
+    /// Each request extends the session, so only sessions that go unused for this long expire.
+    /// Sessions logged in with "remember me" use the longer remember me expiry.
+    pub session_expiry: SessionExpiry,
+
+    /// Reverse proxies whose `X-Forwarded-For` and `X-Forwarded-Proto` headers are trusted.
+    ///
+    /// Empty if `TRUSTED_PROXIES` is not set, in which case forwarding headers are ignored
+    /// and the connecting peer is treated as the client.
+    pub trusted_proxies: TrustedProxies,
+
+    /// Whether responses are compressed with Brotli or gzip.
+    ///
+    /// Disable if a reverse proxy in front of Bifrost already compresses responses.
+    pub compression_enabled: bool,
+
+    /// Whether `Cache-Control` and `ETag` headers are added to static frontend assets.
+    pub static_cache_enabled: bool,
+
+    /// Whether Swagger UI is served at `/api/docs`.
+    ///
+    /// The OpenAPI document at `/api/openapi.json` is served either way, so clients can still
+    /// be generated from it.
+    pub api_docs_enabled: bool,
+
+    /// Timeouts and body size limits applied to incoming requests.
+    ///
+    /// Keeps handlers waiting on slow ESI responses from holding connections open
+    /// indefinitely and rejects oversized request bodies before they are buffered.
+    pub request_limits: RequestLimits,
+
+    /// Limits on how often clients may start EVE Online SSO logins and complete callbacks.
+    ///
+    /// Requests are counted per IP address and per session, keeping clients from exhausting
+    /// the ESI error budget or guessing CSRF states.
+    pub auth_rate_limit: AuthRateLimit,
+
+    /// Whether lookup data such as NPC factions is preloaded before serving traffic.
+    ///
+    /// Moves the ESI requests for stale lookup data from the first user requests after a
+    /// deploy to startup.
+    pub warmup_enabled: bool,
+
+    /// URL of the Meilisearch instance used for search.
+    ///
+    /// Search falls back to case-insensitive matching in the database if `MEILISEARCH_URL`
+    /// is not set.
+    pub meilisearch_url: Option<String>,
+
+    /// API key sent to the Meilisearch instance.
+    ///
+    /// Only required if the instance is started with a master key.
+    pub meilisearch_api_key: Option<String>,
+
+    /// S3-compatible bucket large exports are stored in.
+    ///
+    /// Exports are only streamed through the API if `OBJECT_STORAGE_ENDPOINT` is not set.
+    pub object_storage: Option<ObjectStorageSettings>,
+
+    /// Directory EVE portraits and logos are cached in by the image proxy.
+    ///
+    /// Image requests are redirected to the EVE image server if `IMAGE_CACHE_DIR` is not set.
+    pub image_cache_dir: Option<PathBuf>,
+
+    /// How long cached EVE images are served before they are fetched again.
+    pub image_cache_ttl: Duration,
+
+    /// Organization name, logo, color, and navigation links applied by the frontend.
+    ///
+    /// Unset settings keep Bifrost's default look.
+    pub branding: BrandingSettings,
+
+    /// Discord webhook announcements are posted to when admins choose to.
+    ///
+    /// Announcements are only delivered in-app and over Web Push if `DISCORD_WEBHOOK_URL` is
+    /// not set.
+    pub discord_webhook_url: Option<reqwest::Url>,
+
+    /// Discord application users link their Discord accounts with.
+    ///
+    /// Discord account linking is disabled if `DISCORD_CLIENT_ID` is not set.
+    pub discord_oauth: Option<DiscordOAuthSettings>,
+
+    /// Stagger window, batch sizes, and jitter of scheduled EVE data refreshes.
+    ///
+    /// Small deployments can shorten the stagger window to finish each refresh run sooner,
+    /// while large deployments can lengthen it to spread the load over hours.
+    pub scheduler: SchedulerSettings,
+
+    /// Cron expressions the built-in scheduled jobs run on.
+    ///
+    /// Lets operators tune how often EVE data is refreshed and reports are sent without
+    /// recompiling. Each expression is validated while loading the configuration.
+    pub scheduler_cron: CronSchedules,
+
+    /// Sensitive admin actions that only run once a second admin approves them.
+    ///
+    /// Empty if `APPROVAL_REQUIRED_ACTIONS` is not set, in which case every action runs as
+    /// soon as an admin requests it.
+    pub approval_required_actions: Vec<ApprovalAction>,
+
+    /// How long approval requests can be approved or rejected before they expire.
+    pub approval_expiry: chrono::Duration,
+
+    /// Whether the server starts in read-only mode.
+    ///
+    /// Lets operators keep the database untouched from startup, e.g. while a replica is
+    /// promoted. Admins can disable read-only mode at runtime once maintenance is over.
+    pub read_only: bool,
 }
 
 impl Config {
@@ -88,10 +418,61 @@ impl Config {
     /// - `VALKEY_URL` - Redis/Valkey connection string
     /// - `WORKERS` - Number of worker threads (must be parseable as usize)
     ///
+    /// # Optional Environment Variables
+    /// - `ESI_SCOPE_SETS` - Comma-separated `name:scopes` sets of space-separated ESI scopes
+    /// - `ENCRYPTION_KEYS` - Comma-separated `id:base64_key` list of 32-byte AES keys, active key first
+    /// - `TELEMETRY_ENDPOINT` - URL to send anonymous usage statistics to, enables telemetry
+    /// - `VAPID_PRIVATE_KEY` - Base64url-encoded 32-byte P-256 private key, enables Web Push
+    /// - `SESSION_COOKIE_NAME` - Session cookie name
+    /// - `SESSION_COOKIE_DOMAIN` - Session cookie domain
+    /// - `SESSION_COOKIE_SAME_SITE` - Session cookie SameSite attribute (`strict`, `lax`, `none`)
+    /// - `SESSION_COOKIE_SECURE` - Whether the session cookie requires HTTPS (`true`, `false`)
+    /// - `SESSION_INACTIVITY_DAYS` - Days sessions stay valid without requests
+    /// - `SESSION_REMEMBER_ME_DAYS` - Days "remember me" sessions stay valid without requests
+    /// - `TRUSTED_PROXIES` - Comma-separated reverse proxy IPs or CIDR networks
+    /// - `COMPRESSION_ENABLED` - Whether responses are compressed (`true`, `false`)
+    /// - `STATIC_CACHE_ENABLED` - Whether static assets get caching headers (`true`, `false`)
+    /// - `API_DOCS_ENABLED` - Whether Swagger UI is served at `/api/docs` (`true`, `false`)
+    /// - `REQUEST_TIMEOUT_SECS` - Seconds API requests have to respond
+    /// - `LONG_REQUEST_TIMEOUT_SECS` - Seconds exports have to respond
+    /// - `REQUEST_BODY_LIMIT_BYTES` - Maximum request body size in bytes
+    /// - `IMPORT_BODY_LIMIT_BYTES` - Maximum fitting and skill plan import body size in bytes
+    /// - `AUTH_RATE_LIMIT_PER_IP` - SSO logins and callbacks allowed per IP address and window
+    /// - `AUTH_RATE_LIMIT_PER_SESSION` - SSO logins and callbacks allowed per session and window
+    /// - `AUTH_RATE_LIMIT_WINDOW_SECS` - Seconds SSO logins and callbacks are counted in
+    /// - `WARMUP_ENABLED` - Whether lookup data is preloaded at startup (`true`, `false`)
+    /// - `MEILISEARCH_URL` - Meilisearch instance URL, enables the Meilisearch search backend
+    /// - `MEILISEARCH_API_KEY` - API key sent to the Meilisearch instance
+    /// - `OBJECT_STORAGE_ENDPOINT` - S3-compatible endpoint URL, enables storing exports
+    /// - `OBJECT_STORAGE_BUCKET` - Bucket exports are stored in
+    /// - `OBJECT_STORAGE_REGION` - Region of the bucket
+    /// - `OBJECT_STORAGE_ACCESS_KEY_ID` - Access key ID used to sign requests
+    /// - `OBJECT_STORAGE_SECRET_ACCESS_KEY` - Secret access key used to sign requests
+    /// - `IMAGE_CACHE_DIR` - Directory proxied EVE images are cached in, enables the image proxy
+    /// - `IMAGE_CACHE_TTL_SECS` - Seconds cached EVE images are served before being refetched
+    /// - `BRANDING_ORG_NAME` - Organization name shown in the navigation bar and page title
+    /// - `BRANDING_LOGO_URL` - `http(s)` URL or path of the logo shown in the navigation bar
+    /// - `BRANDING_PRIMARY_COLOR` - Primary theme color in `#rgb` or `#rrggbb` notation
+    /// - `BRANDING_NAV_LINKS` - Comma-separated `Label|URL` links shown in the navigation bar
+    /// - `DISCORD_WEBHOOK_URL` - Discord webhook URL, enables posting announcements to Discord
+    /// - `DISCORD_CLIENT_ID` - Discord application client ID, enables linking Discord accounts
+    /// - `DISCORD_CLIENT_SECRET` - Discord application client secret
+    /// - `DISCORD_CALLBACK_URL` - OAuth callback URL registered with the Discord application
+    /// - `SCHEDULER_STAGGER_WINDOW_SECS` - Seconds scheduled refresh jobs are staggered across
+    /// - `SCHEDULER_MIN_BATCH_SIZE` - Minimum number of entities refreshed per scheduler run
+    /// - `SCHEDULER_MAX_BATCH_SIZE` - Maximum number of entities refreshed per scheduler run
+    /// - `SCHEDULER_JITTER_SECS` - Maximum seconds of random delay added to each refresh job
+    /// - `SCHEDULER_AFFILIATION_SHARDS` - Number of shards affiliation updates rotate through
+    /// - `SCHEDULER_*_CRON` - Cron expressions overriding the schedule of each built-in job
+    /// - `APPROVAL_REQUIRED_ACTIONS` - Comma-separated actions requiring a second admin
+    ///   (`merge_users`, `grant_admin`)
+    /// - `APPROVAL_EXPIRY_HOURS` - Hours approval requests can be decided in
+    /// - `READ_ONLY_MODE` - Whether the server starts rejecting writes for database maintenance
+    ///
     /// # Returns
     /// - `Ok(Config)` - Configuration successfully loaded and validated
-    /// - `Err(AppError::Config(ConfigError::MissingEnvVar))` - Required environment variable not set
-    /// - `Err(AppError::Config(ConfigError::InvalidEnvValue))` - Environment variable has invalid format (e.g., WORKERS not a number)
+    /// - `Err(AppError::Config(ConfigError::MissingEnvVar))` - Required environment variable not set, object storage credentials missing while `OBJECT_STORAGE_ENDPOINT` is set, or Discord credentials missing while `DISCORD_CLIENT_ID` is set
+    /// - `Err(AppError::Config(ConfigError::InvalidEnvValue))` - Environment variable has invalid format (e.g., WORKERS not a number, malformed ESI_SCOPE_SETS or ENCRYPTION_KEYS, VAPID_PRIVATE_KEY, TRUSTED_PROXIES, OBJECT_STORAGE_ENDPOINT, DISCORD_WEBHOOK_URL, DISCORD_CALLBACK_URL, or branding settings, non-boolean toggles, non-numeric request limits or scheduler settings, a zero stagger window, a maximum batch size below the minimum, an affiliation shard count outside 1-1000, invalid scheduler cron expressions, unknown approval actions, a zero approval expiry, SameSite `none` without secure cookies, a zero session expiry or a remember me expiry shorter than the session expiry)
     ///
     /// # Example
     /// ```ignore
@@ -115,6 +496,109 @@ impl Config {
             env!("CARGO_PKG_REPOSITORY")
         );
 
+        let session_cookie_same_site = match optional_env("SESSION_COOKIE_SAME_SITE")
+            .map(|value| value.to_ascii_lowercase())
+            .as_deref()
+        {
+            None | Some("lax") => SameSite::Lax,
+            Some("strict") => SameSite::Strict,
+            Some("none") => SameSite::None,
+            Some(_) => {
+                return Err(ConfigError::InvalidEnvValue {
+                    var: "SESSION_COOKIE_SAME_SITE".to_string(),
+                    reason: "must be one of strict, lax, or none".to_string(),
+                }
+                .into())
+            }
+        };
+        // Allow testing over HTTP in development (debug) builds
+        let session_cookie_secure =
+            optional_bool_env("SESSION_COOKIE_SECURE")?.unwrap_or(!cfg!(debug_assertions));
+        if session_cookie_same_site == SameSite::None && !session_cookie_secure {
+            // Browsers reject SameSite=None cookies without the Secure attribute
+            return Err(ConfigError::InvalidEnvValue {
+                var: "SESSION_COOKIE_SAME_SITE".to_string(),
+                reason: "none requires SESSION_COOKIE_SECURE to be true".to_string(),
+            }
+            .into());
+        }
+
+        let session_expiry = parse_session_expiry()?;
+
+        let defaults = RequestLimits::default();
+        let request_limits = RequestLimits {
+            timeout: optional_number_env("REQUEST_TIMEOUT_SECS")?
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.timeout),
+            long_timeout: optional_number_env("LONG_REQUEST_TIMEOUT_SECS")?
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.long_timeout),
+            body_limit: optional_number_env("REQUEST_BODY_LIMIT_BYTES")?
+                .unwrap_or(defaults.body_limit),
+            import_body_limit: optional_number_env("IMPORT_BODY_LIMIT_BYTES")?
+                .unwrap_or(defaults.import_body_limit),
+        };
+
+        let auth_rate_limit = parse_auth_rate_limit()?;
+
+        let object_storage = match optional_env("OBJECT_STORAGE_ENDPOINT") {
+            None => None,
+            Some(endpoint) => Some(ObjectStorageSettings {
+                endpoint: reqwest::Url::parse(&endpoint).map_err(|e| {
+                    ConfigError::InvalidEnvValue {
+                        var: "OBJECT_STORAGE_ENDPOINT".to_string(),
+                        reason: e.to_string(),
+                    }
+                })?,
+                bucket: required_when_enabled("OBJECT_STORAGE_BUCKET")?,
+                region: optional_env("OBJECT_STORAGE_REGION")
+                    .unwrap_or_else(|| DEFAULT_OBJECT_STORAGE_REGION.to_string()),
+                access_key_id: required_when_enabled("OBJECT_STORAGE_ACCESS_KEY_ID")?,
+                secret_access_key: required_when_enabled("OBJECT_STORAGE_SECRET_ACCESS_KEY")?,
+            }),
+        };
+
+        let discord_oauth = match optional_env("DISCORD_CLIENT_ID") {
+            None => None,
+            Some(client_id) => Some(DiscordOAuthSettings {
+                client_id,
+                client_secret: required_when_enabled("DISCORD_CLIENT_SECRET")?,
+                callback_url: reqwest::Url::parse(&required_when_enabled("DISCORD_CALLBACK_URL")?)
+                    .map_err(|e| ConfigError::InvalidEnvValue {
+                        var: "DISCORD_CALLBACK_URL".to_string(),
+                        reason: e.to_string(),
+                    })?,
+            }),
+        };
+
+        let branding = BrandingSettings {
+            org_name: optional_env("BRANDING_ORG_NAME"),
+            logo_url: optional_env("BRANDING_LOGO_URL")
+                .map(|url| parse_url(&url))
+                .transpose()
+                .map_err(|e| invalid_branding("BRANDING_LOGO_URL", e))?,
+            primary_color: optional_env("BRANDING_PRIMARY_COLOR")
+                .map(|color| parse_color(&color))
+                .transpose()
+                .map_err(|e| invalid_branding("BRANDING_PRIMARY_COLOR", e))?,
+            nav_links: parse_nav_links(&std::env::var("BRANDING_NAV_LINKS").unwrap_or_default())
+                .map_err(|e| invalid_branding("BRANDING_NAV_LINKS", e))?,
+        };
+
+        let scheduler = parse_scheduler_settings()?;
+        let scheduler_cron = parse_cron_schedules()?;
+        let approval_required_actions = parse_approval_actions()?;
+
+        let approval_expiry_hours = optional_number_env::<u32>("APPROVAL_EXPIRY_HOURS")?
+            .unwrap_or(DEFAULT_APPROVAL_EXPIRY_HOURS);
+        if approval_expiry_hours == 0 {
+            return Err(ConfigError::InvalidEnvValue {
+                var: "APPROVAL_EXPIRY_HOURS".to_string(),
+                reason: "must be greater than 0".to_string(),
+            }
+            .into());
+        }
+
         Ok(Self {
             contact_email,
             esi_client_id: std::env::var("ESI_CLIENT_ID")
@@ -135,6 +619,356 @@ impl Config {
                     reason: format!("must be a valid number: {}", e),
                 })?,
             user_agent,
+            scope_sets: ScopeSets::parse(&std::env::var("ESI_SCOPE_SETS").unwrap_or_default())
+                .map_err(|e| ConfigError::InvalidEnvValue {
+                    var: "ESI_SCOPE_SETS".to_string(),
+                    reason: e.to_string(),
+                })?,
+            encryption_keys: parse_encryption_keys(
+                &std::env::var("ENCRYPTION_KEYS").unwrap_or_default(),
+            )
+            .map_err(|e| ConfigError::InvalidEnvValue {
+                var: "ENCRYPTION_KEYS".to_string(),
+                reason: e.to_string(),
+            })?,
+            telemetry_endpoint: std::env::var("TELEMETRY_ENDPOINT")
+                .ok()
+                .filter(|endpoint| !endpoint.trim().is_empty()),
+            vapid_key: optional_env("VAPID_PRIVATE_KEY")
+                .map(|key| VapidKey::from_base64(&key))
+                .transpose()
+                .map_err(|e| ConfigError::InvalidEnvValue {
+                    var: "VAPID_PRIVATE_KEY".to_string(),
+                    reason: e.to_string(),
+                })?,
+            session_cookie_name: optional_env("SESSION_COOKIE_NAME")
+                .unwrap_or_else(|| DEFAULT_SESSION_COOKIE_NAME.to_string()),
+            session_cookie_domain: optional_env("SESSION_COOKIE_DOMAIN"),
+            session_cookie_same_site,
+            session_cookie_secure,
+            session_expiry,
+            trusted_proxies: TrustedProxies::parse(
+                &std::env::var("TRUSTED_PROXIES").unwrap_or_default(),
+            )
+            .map_err(|e| ConfigError::InvalidEnvValue {
+                var: "TRUSTED_PROXIES".to_string(),
+                reason: e.to_string(),
+            })?,
+            compression_enabled: optional_bool_env("COMPRESSION_ENABLED")?.unwrap_or(true),
+            static_cache_enabled: optional_bool_env("STATIC_CACHE_ENABLED")?.unwrap_or(true),
+            api_docs_enabled: optional_bool_env("API_DOCS_ENABLED")?.unwrap_or(true),
+            request_limits,
+            auth_rate_limit,
+            warmup_enabled: optional_bool_env("WARMUP_ENABLED")?.unwrap_or(true),
+            meilisearch_url: optional_env("MEILISEARCH_URL"),
+            meilisearch_api_key: optional_env("MEILISEARCH_API_KEY"),
+            object_storage,
+            image_cache_dir: optional_env("IMAGE_CACHE_DIR").map(PathBuf::from),
+            image_cache_ttl: optional_number_env("IMAGE_CACHE_TTL_SECS")?
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_IMAGE_CACHE_TTL),
+            branding,
+            discord_webhook_url: optional_env("DISCORD_WEBHOOK_URL")
+                .map(|url| reqwest::Url::parse(&url))
+                .transpose()
+                .map_err(|e| ConfigError::InvalidEnvValue {
+                    var: "DISCORD_WEBHOOK_URL".to_string(),
+                    reason: e.to_string(),
+                })?,
+            discord_oauth,
+            scheduler,
+            scheduler_cron,
+            approval_required_actions,
+            approval_expiry: chrono::Duration::hours(approval_expiry_hours.into()),
+            read_only: optional_bool_env("READ_ONLY_MODE")?.unwrap_or(false),
         })
     }
 }
+
+/// Reads the scheduler batching settings, keeping the defaults for unset variables.
+///
+/// # Returns
+/// - `Ok(SchedulerSettings)` - Valid scheduler settings
+/// - `Err(ConfigError::InvalidEnvValue)` - A setting is not a number, the stagger window is
+///   zero, the maximum batch size is below the minimum, or the affiliation shard count is
+///   outside 1 to `MAX_AFFILIATION_SHARDS`
+fn parse_scheduler_settings() -> Result<SchedulerSettings, ConfigError> {
+    let defaults = SchedulerSettings::default();
+
+    let stagger_window = optional_number_env::<u32>("SCHEDULER_STAGGER_WINDOW_SECS")?;
+    if stagger_window == Some(0) {
+        return Err(ConfigError::InvalidEnvValue {
+            var: "SCHEDULER_STAGGER_WINDOW_SECS".to_string(),
+            reason: "must be greater than 0".to_string(),
+        });
+    }
+
+    let min_batch_size =
+        optional_number_env("SCHEDULER_MIN_BATCH_SIZE")?.unwrap_or(defaults.min_batch_size);
+    let max_batch_size = optional_number_env("SCHEDULER_MAX_BATCH_SIZE")?;
+    if max_batch_size.is_some_and(|max| max < min_batch_size) {
+        return Err(ConfigError::InvalidEnvValue {
+            var: "SCHEDULER_MAX_BATCH_SIZE".to_string(),
+            reason: format!(
+                "must be at least SCHEDULER_MIN_BATCH_SIZE ({})",
+                min_batch_size
+            ),
+        });
+    }
+
+    let affiliation_shards = optional_number_env::<u32>("SCHEDULER_AFFILIATION_SHARDS")?
+        .unwrap_or(defaults.affiliation_shards);
+    if !(1..=MAX_AFFILIATION_SHARDS).contains(&affiliation_shards) {
+        return Err(ConfigError::InvalidEnvValue {
+            var: "SCHEDULER_AFFILIATION_SHARDS".to_string(),
+            reason: format!("must be between 1 and {}", MAX_AFFILIATION_SHARDS),
+        });
+    }
+
+    Ok(SchedulerSettings {
+        stagger_window: stagger_window.map(|secs| chrono::Duration::seconds(secs.into())),
+        min_batch_size,
+        max_batch_size,
+        jitter: optional_number_env::<u32>("SCHEDULER_JITTER_SECS")?
+            .map(|secs| chrono::Duration::seconds(secs.into()))
+            .unwrap_or(defaults.jitter),
+        affiliation_shards,
+    })
+}
+
+/// Reads the sliding session expiry, keeping the defaults for unset variables.
+///
+/// # Returns
+/// - `Ok(SessionExpiry)` - Valid session expiry
+/// - `Err(ConfigError::InvalidEnvValue)` - A setting is not a number, is zero, or the remember
+///   me expiry is shorter than the expiry of other sessions
+fn parse_session_expiry() -> Result<SessionExpiry, ConfigError> {
+    let defaults = SessionExpiry::default();
+
+    let inactivity_days =
+        optional_number_env::<u32>("SESSION_INACTIVITY_DAYS")?.unwrap_or(defaults.inactivity_days);
+    if inactivity_days == 0 {
+        return Err(ConfigError::InvalidEnvValue {
+            var: "SESSION_INACTIVITY_DAYS".to_string(),
+            reason: "must be greater than 0".to_string(),
+        });
+    }
+
+    // Keep remembered sessions at least as long as other sessions if only the session expiry
+    // is configured
+    let remember_me_days = optional_number_env::<u32>("SESSION_REMEMBER_ME_DAYS")?
+        .unwrap_or(defaults.remember_me_days.max(inactivity_days));
+    if remember_me_days < inactivity_days {
+        return Err(ConfigError::InvalidEnvValue {
+            var: "SESSION_REMEMBER_ME_DAYS".to_string(),
+            reason: "must not be less than SESSION_INACTIVITY_DAYS".to_string(),
+        });
+    }
+
+    Ok(SessionExpiry {
+        inactivity_days,
+        remember_me_days,
+    })
+}
+
+/// Reads the rate limits of the EVE Online SSO endpoints, keeping the defaults for unset
+/// variables.
+///
+/// # Returns
+/// - `Ok(AuthRateLimit)` - Valid rate limits
+/// - `Err(ConfigError::InvalidEnvValue)` - A setting is not a number or the window is zero
+fn parse_auth_rate_limit() -> Result<AuthRateLimit, ConfigError> {
+    let defaults = AuthRateLimit::default();
+
+    let window = optional_number_env::<u64>("AUTH_RATE_LIMIT_WINDOW_SECS")?;
+    if window == Some(0) {
+        return Err(ConfigError::InvalidEnvValue {
+            var: "AUTH_RATE_LIMIT_WINDOW_SECS".to_string(),
+            reason: "must be greater than 0".to_string(),
+        });
+    }
+
+    Ok(AuthRateLimit {
+        per_ip: optional_number_env("AUTH_RATE_LIMIT_PER_IP")?.unwrap_or(defaults.per_ip),
+        per_session: optional_number_env("AUTH_RATE_LIMIT_PER_SESSION")?
+            .unwrap_or(defaults.per_session),
+        window: window.map(Duration::from_secs).unwrap_or(defaults.window),
+    })
+}
+
+/// Reads the sensitive admin actions that require approval by a second admin.
+///
+/// # Returns
+/// - `Ok(Vec<ApprovalAction>)` - Deduplicated actions, empty if the variable is unset
+/// - `Err(ConfigError::InvalidEnvValue)` - An action name is not known
+fn parse_approval_actions() -> Result<Vec<ApprovalAction>, ConfigError> {
+    let mut actions = Vec::new();
+
+    for name in optional_env("APPROVAL_REQUIRED_ACTIONS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        let action =
+            ApprovalAction::from_name(name).ok_or_else(|| ConfigError::InvalidEnvValue {
+                var: "APPROVAL_REQUIRED_ACTIONS".to_string(),
+                reason: format!(
+                    "unknown action '{}', expected one of {}",
+                    name,
+                    ApprovalAction::ALL
+                        .iter()
+                        .map(|action| action.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            })?;
+        if !actions.contains(&action) {
+            actions.push(action);
+        }
+    }
+
+    Ok(actions)
+}
+
+/// Reads the cron expressions of the built-in scheduled jobs, keeping the defaults for unset
+/// variables.
+///
+/// # Returns
+/// - `Ok(CronSchedules)` - Valid cron expressions
+/// - `Err(ConfigError::InvalidEnvValue)` - An expression can't be scheduled
+fn parse_cron_schedules() -> Result<CronSchedules, ConfigError> {
+    let defaults = CronSchedules::default();
+
+    Ok(CronSchedules {
+        faction: optional_cron_env("SCHEDULER_FACTION_CRON")?.unwrap_or(defaults.faction),
+        alliance: optional_cron_env("SCHEDULER_ALLIANCE_CRON")?.unwrap_or(defaults.alliance),
+        corporation: optional_cron_env("SCHEDULER_CORPORATION_CRON")?
+            .unwrap_or(defaults.corporation),
+        character: optional_cron_env("SCHEDULER_CHARACTER_CRON")?.unwrap_or(defaults.character),
+        character_affiliation: optional_cron_env("SCHEDULER_AFFILIATION_CRON")?
+            .unwrap_or(defaults.character_affiliation),
+        dashboard: optional_cron_env("SCHEDULER_DASHBOARD_CRON")?.unwrap_or(defaults.dashboard),
+        corporation_member: optional_cron_env("SCHEDULER_CORPORATION_MEMBER_CRON")?
+            .unwrap_or(defaults.corporation_member),
+        character_skill: optional_cron_env("SCHEDULER_CHARACTER_SKILL_CRON")?
+            .unwrap_or(defaults.character_skill),
+        token_prune: optional_cron_env("SCHEDULER_TOKEN_PRUNE_CRON")?
+            .unwrap_or(defaults.token_prune),
+        digest: optional_cron_env("SCHEDULER_DIGEST_CRON")?.unwrap_or(defaults.digest),
+        telemetry: optional_cron_env("SCHEDULER_TELEMETRY_CRON")?.unwrap_or(defaults.telemetry),
+    })
+}
+
+/// Reads an optional cron expression environment variable, treating empty values as unset.
+///
+/// Expressions use the six-field format of the scheduler, starting with seconds.
+///
+/// # Arguments
+/// - `var` - Name of the environment variable
+///
+/// # Returns
+/// - `Ok(Some(String))` - Variable is set to an expression the scheduler accepts
+/// - `Ok(None)` - Variable is unset or empty
+/// - `Err(ConfigError::InvalidEnvValue)` - Variable is set to an invalid expression
+fn optional_cron_env(var: &str) -> Result<Option<String>, ConfigError> {
+    optional_env(var)
+        .map(|cron| {
+            Job::new(cron.as_str(), |_, _| {})
+                .map(|_| cron)
+                .map_err(|e| ConfigError::InvalidEnvValue {
+                    var: var.to_string(),
+                    reason: format!("must be a valid cron expression: {}", e),
+                })
+        })
+        .transpose()
+}
+
+/// Reads a setting that must be set if the feature it belongs to is enabled.
+///
+/// Used for object storage settings required with `OBJECT_STORAGE_ENDPOINT` and Discord
+/// credentials required with `DISCORD_CLIENT_ID`.
+///
+/// # Arguments
+/// - `var` - Name of the environment variable
+///
+/// # Returns
+/// - `Ok(String)` - Trimmed, non-empty value
+/// - `Err(ConfigError::MissingEnvVar)` - Variable is unset or empty
+fn required_when_enabled(var: &str) -> Result<String, ConfigError> {
+    optional_env(var).ok_or_else(|| ConfigError::MissingEnvVar(var.to_string()))
+}
+
+/// Maps an invalid branding setting to a configuration error.
+///
+/// # Arguments
+/// - `var` - Name of the environment variable
+/// - `error` - Reason the setting was rejected
+///
+/// # Returns
+/// - `ConfigError::InvalidEnvValue` - Error naming the variable and reason
+fn invalid_branding(var: &str, error: BrandingError) -> ConfigError {
+    ConfigError::InvalidEnvValue {
+        var: var.to_string(),
+        reason: error.to_string(),
+    }
+}
+
+/// Reads an optional environment variable, treating empty values as unset.
+///
+/// # Arguments
+/// - `var` - Name of the environment variable
+///
+/// # Returns
+/// - `Some(String)` - Trimmed, non-empty value
+/// - `None` - Variable is unset or empty
+fn optional_env(var: &str) -> Option<String> {
+    std::env::var(var)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Reads an optional boolean environment variable, treating empty values as unset.
+///
+/// # Arguments
+/// - `var` - Name of the environment variable
+///
+/// # Returns
+/// - `Ok(Some(bool))` - Variable is set to `true` or `false`
+/// - `Ok(None)` - Variable is unset or empty
+/// - `Err(ConfigError::InvalidEnvValue)` - Variable is set to any other value
+fn optional_bool_env(var: &str) -> Result<Option<bool>, ConfigError> {
+    optional_env(var)
+        .map(|value| {
+            value.parse().map_err(|_| ConfigError::InvalidEnvValue {
+                var: var.to_string(),
+                reason: "must be true or false".to_string(),
+            })
+        })
+        .transpose()
+}
+
+/// Reads an optional numeric environment variable, treating empty values as unset.
+///
+/// # Arguments
+/// - `var` - Name of the environment variable
+///
+/// # Returns
+/// - `Ok(Some(T))` - Variable is set to a valid number
+/// - `Ok(None)` - Variable is unset or empty
+/// - `Err(ConfigError::InvalidEnvValue)` - Variable is set to any other value
+fn optional_number_env<T>(var: &str) -> Result<Option<T>, ConfigError>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    optional_env(var)
+        .map(|value| {
+            value.parse().map_err(|e| ConfigError::InvalidEnvValue {
+                var: var.to_string(),
+                reason: format!("must be a valid number: {}", e),
+            })
+        })
+        .transpose()
+}
@@ -0,0 +1,59 @@
+//! Affiliation history controller endpoints.
+//!
+//! This module provides an HTTP endpoint for viewing the corporation and alliance membership
+//! intervals recorded for a character, so diplomats can check where a character was at a given
+//! time. This endpoint requires an active session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{affiliation_history::AffiliationHistoryDto, api::ErrorDto},
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::affiliation_history::AffiliationHistoryService,
+    },
+};
+
+/// OpenAPI tag for affiliation history endpoints.
+pub static AFFILIATION_HISTORY_TAG: &str = "affiliation_history";
+
+/// Retrieves the affiliation history of a character.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `character_id` - EVE Online character ID
+///
+/// # Returns
+/// - `Ok(AffiliationHistoryDto)` - Membership intervals ordered oldest first
+/// - `Err(AppError)` - User not in session, character not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/characters/{character_id}/affiliation-history",
+    tag = AFFILIATION_HISTORY_TAG,
+    params(("character_id" = i64, Path, description = "EVE Online character ID")),
+    responses(
+        (status = 200, description = "Success when retrieving affiliation history", body = AffiliationHistoryDto),
+        (status = 404, description = "User or character not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_affiliation_history(
+    State(state): State<AppState>,
+    session: Session,
+    Path(character_id): Path<i64>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let history = AffiliationHistoryService::new(&state.db)
+        .get_history(character_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(history)).into_response())
+}
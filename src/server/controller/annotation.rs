@@ -0,0 +1,276 @@
+//! Annotation controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to attach free-form tags and timestamped
+//! notes to users, characters, and corporations, remove them, and find every subject carrying
+//! a tag. Notes shared with officers are readable by every admin, while private notes are only
+//! returned to their author. All endpoints require an active session.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        annotation::{
+            AnnotationSubject, AnnotationsDto, CreateNoteDto, CreateTagDto, NoteDto, TagDto,
+            TaggedSubjectDto,
+        },
+        api::ErrorDto,
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::annotation::AnnotationService,
+    },
+};
+
+/// OpenAPI tag for annotation endpoints.
+pub static ANNOTATION_TAG: &str = "annotation";
+
+/// Query parameters for the tag search endpoint.
+///
+/// # Fields
+/// - `name` - Name of the tag to search for
+#[derive(Deserialize)]
+pub struct TagSearchParams {
+    /// Name of the tag to search for.
+    pub name: String,
+}
+
+/// Retrieves the tags and the notes the current user can read for a subject.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `subject` - Kind of the subject
+/// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID
+///
+/// # Returns
+/// - `Ok(AnnotationsDto)` - 200 OK with the subject's tags and notes
+/// - `Err(AppError)` - User not in session, subject not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/annotations/{subject}/{subject_id}",
+    tag = ANNOTATION_TAG,
+    params(
+        ("subject" = AnnotationSubject, Path, description = "Kind of the subject: user, character, or corporation"),
+        ("subject_id" = i64, Path, description = "Bifrost user ID or EVE Online character or corporation ID")
+    ),
+    responses(
+        (status = 200, description = "Success when retrieving annotations", body = AnnotationsDto),
+        (status = 404, description = "User or subject not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_annotations(
+    State(state): State<AppState>,
+    session: Session,
+    Path((subject, subject_id)): Path<(AnnotationSubject, i64)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let annotations = AnnotationService::new(&state.db)
+        .get_annotations(user.id, subject, subject_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(annotations)).into_response())
+}
+
+/// Attaches a tag to a subject.
+///
+/// Adding a tag the subject already carries returns the existing tag.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `subject` - Kind of the subject
+/// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID
+/// - `payload` - Name of the tag
+///
+/// # Returns
+/// - `Ok(TagDto)` - 201 Created with the tag
+/// - `Err(AppError)` - User not in session, invalid tag, subject not found, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/annotations/{subject}/{subject_id}/tags",
+    tag = ANNOTATION_TAG,
+    params(
+        ("subject" = AnnotationSubject, Path, description = "Kind of the subject: user, character, or corporation"),
+        ("subject_id" = i64, Path, description = "Bifrost user ID or EVE Online character or corporation ID")
+    ),
+    request_body = CreateTagDto,
+    responses(
+        (status = 201, description = "Tag added", body = TagDto),
+        (status = 400, description = "Empty or overlong tag", body = ErrorDto),
+        (status = 404, description = "User or subject not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn add_tag(
+    State(state): State<AppState>,
+    session: Session,
+    Path((subject, subject_id)): Path<(AnnotationSubject, i64)>,
+    Json(payload): Json<CreateTagDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let tag = AnnotationService::new(&state.db)
+        .add_tag(user.id, subject, subject_id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(tag)).into_response())
+}
+
+/// Adds a note about a subject.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `subject` - Kind of the subject
+/// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID
+/// - `payload` - Body and visibility of the note
+///
+/// # Returns
+/// - `Ok(NoteDto)` - 201 Created with the note
+/// - `Err(AppError)` - User not in session, invalid note, subject not found, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/annotations/{subject}/{subject_id}/notes",
+    tag = ANNOTATION_TAG,
+    params(
+        ("subject" = AnnotationSubject, Path, description = "Kind of the subject: user, character, or corporation"),
+        ("subject_id" = i64, Path, description = "Bifrost user ID or EVE Online character or corporation ID")
+    ),
+    request_body = CreateNoteDto,
+    responses(
+        (status = 201, description = "Note added", body = NoteDto),
+        (status = 400, description = "Empty or overlong note", body = ErrorDto),
+        (status = 404, description = "User or subject not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn add_note(
+    State(state): State<AppState>,
+    session: Session,
+    Path((subject, subject_id)): Path<(AnnotationSubject, i64)>,
+    Json(payload): Json<CreateNoteDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let note = AnnotationService::new(&state.db)
+        .add_note(user.id, subject, subject_id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(note)).into_response())
+}
+
+/// Removes a tag from its subject.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `tag_id` - ID of the tag
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the tag was removed
+/// - `Err(AppError)` - User not in session, tag not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/tags/{tag_id}",
+    tag = ANNOTATION_TAG,
+    params(("tag_id" = i32, Path, description = "ID of the tag")),
+    responses(
+        (status = 204, description = "Tag removed"),
+        (status = 404, description = "User or tag not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn remove_tag(
+    State(state): State<AppState>,
+    session: Session,
+    Path(tag_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    AnnotationService::new(&state.db)
+        .remove_tag(user.id, tag_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Deletes a note the current user can read.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `note_id` - ID of the note
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the note was deleted
+/// - `Err(AppError)` - User not in session, note not found or private to another author, or
+///   database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/notes/{note_id}",
+    tag = ANNOTATION_TAG,
+    params(("note_id" = i32, Path, description = "ID of the note")),
+    responses(
+        (status = 204, description = "Note deleted"),
+        (status = 404, description = "User or note not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_note(
+    State(state): State<AppState>,
+    session: Session,
+    Path(note_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    AnnotationService::new(&state.db)
+        .delete_note(user.id, note_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Finds every user, character, and corporation carrying a tag.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `params` - Query parameters with the tag name
+///
+/// # Returns
+/// - `Ok(Vec<TaggedSubjectDto>)` - 200 OK with the tagged subjects
+/// - `Err(AppError)` - User not in session, invalid tag, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/tags",
+    tag = ANNOTATION_TAG,
+    params(("name" = String, Query, description = "Name of the tag")),
+    responses(
+        (status = 200, description = "Success when searching by tag", body = Vec<TaggedSubjectDto>),
+        (status = 400, description = "Empty or overlong tag", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn find_by_tag(
+    State(state): State<AppState>,
+    session: Session,
+    params: Query<TagSearchParams>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let subjects = AnnotationService::new(&state.db)
+        .find_by_tag(&params.0.name)
+        .await?;
+
+    Ok((StatusCode::OK, Json(subjects)).into_response())
+}
@@ -0,0 +1,205 @@
+//! Announcement controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to post announcements to all members or the
+//! members of a corporation or alliance and review their read receipts, and for users to read
+//! announcements from their inbox. Posted announcements can additionally be delivered over Web
+//! Push and to the configured Discord webhook. All endpoints require an active session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        announcement::{AnnouncementDto, CreateAnnouncementDto, InboxAnnouncementDto},
+        api::ErrorDto,
+        push::PushNotificationDto,
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::AppError,
+        model::{app::AppState, worker::WorkerJob},
+        service::announcement::AnnouncementService,
+    },
+};
+
+/// OpenAPI tag for announcement endpoints.
+pub static ANNOUNCEMENT_TAG: &str = "announcement";
+
+/// Maximum number of characters of the announcement body shown in push notifications.
+const PUSH_BODY_LENGTH: usize = 200;
+
+/// Posts an announcement to the inbox of every user in its audience.
+///
+/// If requested, a push notification is queued for every recipient and the announcement is
+/// queued for the Discord webhook. Push notifications are skipped if they are disabled.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and worker queue
+/// - `session` - User's session containing their user ID
+/// - `payload` - Title, Markdown body, audience, and delivery channels of the announcement
+///
+/// # Returns
+/// - `Ok(AnnouncementDto)` - 201 Created with the posted announcement
+/// - `Err(AppError)` - User not in session, invalid announcement, database, or worker queue
+///   error
+#[utoipa::path(
+    post,
+    path = "/api/admin/announcements",
+    tag = ANNOUNCEMENT_TAG,
+    request_body = CreateAnnouncementDto,
+    responses(
+        (status = 201, description = "Announcement posted", body = AnnouncementDto),
+        (status = 400, description = "Empty title or missing audience ID", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_announcement(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateAnnouncementDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let send_push = payload.send_push && state.push.vapid_key.is_some();
+    let send_discord = payload.send_discord;
+
+    let (announcement, recipient_ids) = AnnouncementService::new(&state.db)
+        .create_announcement(user.id, payload)
+        .await?;
+
+    if send_push {
+        let notification = PushNotificationDto {
+            title: announcement.title.clone(),
+            body: announcement.body.chars().take(PUSH_BODY_LENGTH).collect(),
+            url: Some("/auth".to_string()),
+        };
+
+        for user_id in recipient_ids {
+            state
+                .worker
+                .queue
+                .push(WorkerJob::SendPushNotification {
+                    user_id,
+                    notification: notification.clone(),
+                })
+                .await?;
+        }
+    }
+
+    if send_discord {
+        state
+            .worker
+            .queue
+            .push(WorkerJob::SendDiscordMessage {
+                content: format!("**{}**\n\n{}", announcement.title, announcement.body),
+            })
+            .await?;
+    }
+
+    Ok((StatusCode::CREATED, Json(announcement)).into_response())
+}
+
+/// Retrieves all announcements with their read receipts, newest first.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<AnnouncementDto>)` - All announcements with recipient and read counts
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/announcements",
+    tag = ANNOUNCEMENT_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving announcements", body = Vec<AnnouncementDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_announcements(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let announcements = AnnouncementService::new(&state.db)
+        .get_announcements()
+        .await?;
+
+    Ok((StatusCode::OK, Json(announcements)).into_response())
+}
+
+/// Retrieves the announcements in the currently authenticated user's inbox.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<InboxAnnouncementDto>)` - Announcements sent to the user, newest first
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/user/announcements",
+    tag = ANNOUNCEMENT_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving the inbox", body = Vec<InboxAnnouncementDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_inbox(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let inbox = AnnouncementService::new(&state.db)
+        .get_inbox(user.id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(inbox)).into_response())
+}
+
+/// Marks an announcement in the currently authenticated user's inbox as read.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `announcement_id` - ID of the announcement
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the announcement was marked read
+/// - `Err(AppError)` - User not in session, announcement not in the inbox, or database error
+#[utoipa::path(
+    post,
+    path = "/api/user/announcements/{announcement_id}/read",
+    tag = ANNOUNCEMENT_TAG,
+    params(("announcement_id" = i32, Path, description = "ID of the announcement")),
+    responses(
+        (status = 204, description = "Announcement marked read"),
+        (status = 404, description = "User or announcement not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn mark_announcement_read(
+    State(state): State<AppState>,
+    session: Session,
+    Path(announcement_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    AnnouncementService::new(&state.db)
+        .mark_read(user.id, announcement_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,179 @@
+//! Approval request controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to list the approval requests of sensitive
+//! admin actions and to approve or reject them. Actions configured with
+//! `APPROVAL_REQUIRED_ACTIONS` create a pending request instead of running, announced on the
+//! configured Discord webhook, and only run once a second admin approves the request. All
+//! endpoints require an active session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        approval::{ApprovalPayload, ApprovalRequestDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::AppError,
+        model::{app::AppState, worker::WorkerJob},
+        service::approval::ApprovalService,
+    },
+};
+
+/// OpenAPI tag for approval request endpoints.
+pub static APPROVAL_TAG: &str = "approval";
+
+/// Retrieves the most recent approval requests, newest first.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<ApprovalRequestDto>)` - 200 OK with the requests, pending requests past their
+///   expiry reported as expired
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/approvals",
+    tag = APPROVAL_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving approval requests", body = Vec<ApprovalRequestDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_approval_requests(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let requests = ApprovalService::new(&state.db).get_requests().await?;
+
+    Ok((StatusCode::OK, Json(requests)).into_response())
+}
+
+/// Approves a pending request and runs its action.
+///
+/// The request must be approved by an admin other than the one who requested it.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `request_id` - ID of the approval request
+///
+/// # Returns
+/// - `Ok(ApprovalRequestDto)` - 200 OK with the approved request
+/// - `Err(AppError)` - User not in session, request not found, requested by the same admin,
+///   already decided or expired, the action failed, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/approvals/{request_id}/approve",
+    tag = APPROVAL_TAG,
+    params(("request_id" = i32, Path, description = "ID of the approval request")),
+    responses(
+        (status = 200, description = "Request approved and action run", body = ApprovalRequestDto),
+        (status = 403, description = "Request must be approved by another admin", body = ErrorDto),
+        (status = 404, description = "User or request not found", body = ErrorDto),
+        (status = 409, description = "Request already decided or expired", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn approve_request(
+    State(state): State<AppState>,
+    session: Session,
+    Path(request_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let request = ApprovalService::new(&state.db)
+        .approve(user.id, request_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(request)).into_response())
+}
+
+/// Rejects a pending request without running its action.
+///
+/// The admin who requested the action may reject it to withdraw the request.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `request_id` - ID of the approval request
+///
+/// # Returns
+/// - `Ok(ApprovalRequestDto)` - 200 OK with the rejected request
+/// - `Err(AppError)` - User not in session, request not found, already decided or expired, or
+///   database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/approvals/{request_id}/reject",
+    tag = APPROVAL_TAG,
+    params(("request_id" = i32, Path, description = "ID of the approval request")),
+    responses(
+        (status = 200, description = "Request rejected", body = ApprovalRequestDto),
+        (status = 404, description = "User or request not found", body = ErrorDto),
+        (status = 409, description = "Request already decided or expired", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn reject_request(
+    State(state): State<AppState>,
+    session: Session,
+    Path(request_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let request = ApprovalService::new(&state.db)
+        .reject(user.id, request_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(request)).into_response())
+}
+
+/// Creates an approval request for a sensitive action and announces it on Discord.
+///
+/// Called by the endpoints of actions that require approval in place of running them.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection, approval settings, and
+///   worker queue
+/// - `user_id` - ID of the admin requesting the action
+/// - `payload` - Action and its arguments
+///
+/// # Returns
+/// - `Ok(Response)` - 202 Accepted with the pending request
+/// - `Err(AppError)` - Invalid action arguments, database, or worker queue error
+pub async fn request_approval(
+    state: &AppState,
+    user_id: i32,
+    payload: ApprovalPayload,
+) -> Result<Response, AppError> {
+    let request = ApprovalService::new(&state.db)
+        .request(&state.approvals, user_id, payload)
+        .await?;
+
+    state
+        .worker
+        .queue
+        .push(WorkerJob::SendDiscordMessage {
+            content: format!(
+                "**Approval requested:** {} (request #{}) needs a second admin before {} UTC",
+                request.payload.summary(),
+                request.id,
+                request.expires_at.format("%Y-%m-%d %H:%M")
+            ),
+        })
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(request)).into_response())
+}
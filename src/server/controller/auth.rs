@@ -2,43 +2,147 @@
 //!
 //! This module provides HTTP endpoints for EVE Online SSO authentication flow, including
 //! login initiation, OAuth callback handling, logout, and retrieving the current user's
-//! information. It manages session state for CSRF protection and user identity tracking.
+//! information, confirming character transfers between users of the same EVE Online account,
+//! as well as the Discord OAuth flow linking a Discord account to the current user. It manages
+//! session state for CSRF protection and user identity tracking.
 
 use axum::{
     extract::{Query, State},
     http::StatusCode,
     response::{IntoResponse, Redirect},
+    Json,
 };
 use dioxus_logger::tracing;
 use serde::Deserialize;
 use tower_sessions::Session;
 
 use crate::{
-    model::{api::ErrorDto, user::UserDto},
+    model::{
+        api::ErrorDto,
+        character_skill::CHARACTER_SKILLS_SCOPE,
+        corporation_member::CORPORATION_MEMBERSHIP_SCOPE,
+        user::{LinkModeDto, LinkedCharacterDto, PendingTransferDto, UserDto, UserMergeDto},
+    },
     server::{
-        controller::util::{csrf::validate_csrf, get_user::get_user_from_session},
-        error::AppError,
+        controller::util::{
+            csrf::validate_csrf,
+            get_user::{get_user_from_session, get_user_from_session_allowing_reauth},
+            redirect::validate_redirect,
+        },
+        error::{auth::AuthError, AppError},
         model::{
             app::AppState,
             session::{
-                auth::SessionAuthCsrf, change_main::SessionUserChangeMain, user::SessionUserId,
+                auth_flow::{AuthFlow, SessionAuthFlow},
+                discord::{SessionDiscordCsrf, SessionDiscordRedirect},
+                link_mode::SessionUserLinkMode,
+                link_queue::{SessionUserLinkQueue, MAX_LINK_QUEUE_LENGTH},
+                login_intent::LoginIntent,
+                remember_me::SessionUserRememberMe,
+                transfer::SessionPendingTransfer,
+                user::SessionUserId,
+            },
+            worker::WorkerJob,
+        },
+        service::{
+            auth::{
+                callback::CallbackService, discord::DiscordLinkService, login::LoginService,
+                transfer::TransferService,
             },
+            corporation_member::CorporationMemberService,
+            notification::NotificationService,
+            onboarding::OnboardingService,
+            reauth_campaign::ReauthCampaignService,
+            token::TokenService,
         },
-        service::auth::{callback::CallbackService, login::LoginService},
+        util::scope_set::ScopeSets,
     },
 };
 
 /// OpenAPI tag for authentication-related endpoints.
 pub static AUTH_TAG: &str = "auth";
 
+/// Frontend page callbacks redirect to while linking mode is active.
+static LINK_MODE_REDIRECT: &str = "/auth/link-characters";
+
+/// Purpose of a login as requested in the login endpoint's query parameters.
+///
+/// Mirrors [`LoginIntent`] without its data, which is provided by separate parameters.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LoginIntentParam {
+    /// Log in with the character, see [`LoginIntent::Login`].
+    Login,
+    /// Link the character as an alt, see [`LoginIntent::LinkAlt`].
+    LinkAlt,
+    /// Make the character the user's main, see [`LoginIntent::ChangeMain`].
+    ChangeMain,
+    /// Grant the scopes in the `scopes` parameter, see [`LoginIntent::AddScopes`].
+    AddScopes,
+    /// Re-authenticate an owned character, see [`LoginIntent::Reauth`].
+    Reauth,
+}
+
 /// Query parameters for the login endpoint.
 ///
 /// # Fields
-/// - `change_main` - Optional flag to indicate if the login should change the user's main character
+/// - `intent` - Optional purpose of the login, defaults to a plain login
+/// - `scopes` - Optional space-separated scope set names and ESI scopes to request
+/// - `link_mode` - Optional flag to start linking mode for adding several characters in a row
+/// - `link_count` - Optional number of characters to link in a row without returning to the UI
+/// - `next` - Optional internal path to return to after the callback
+/// - `remember_me` - Optional flag to keep the user logged in for longer
 #[derive(Deserialize)]
 pub struct LoginParams {
-    /// If true, the authenticated character will become the user's main character.
-    pub change_main: Option<bool>,
+    /// Purpose of the login; a plain login if not provided.
+    pub intent: Option<LoginIntentParam>,
+    /// Space-separated names of scope sets configured in `ESI_SCOPE_SETS` and ESI scopes to
+    /// request; the default scopes if not provided.
+    pub scopes: Option<String>,
+    /// If true with the `link_alt` intent, linking mode stays active across callbacks until
+    /// the user exits it.
+    pub link_mode: Option<bool>,
+    /// Number of characters to link in a row with the `link_alt` intent. Starts linking mode,
+    /// and each callback sends the user back to EVE Online SSO until that many characters were
+    /// processed.
+    pub link_count: Option<u32>,
+    /// Internal path to redirect to after the callback, ignored unless it is allowlisted.
+    pub next: Option<String>,
+    /// If true, the session logged in by the callback expires after `SESSION_REMEMBER_ME_DAYS`
+    /// of inactivity instead of `SESSION_INACTIVITY_DAYS`.
+    pub remember_me: Option<bool>,
+}
+
+impl LoginParams {
+    /// Resolves the `scopes` parameter into the ESI scopes to request.
+    ///
+    /// # Arguments
+    /// - `scope_sets` - Scope sets configured in `ESI_SCOPE_SETS`
+    ///
+    /// # Returns
+    /// - `Ok(Vec<String>)` - Scopes of the named sets and the listed ESI scopes, empty if the
+    ///   parameter was not provided
+    /// - `Err(AuthError::InvalidScopes)` - The parameter names a set that is not configured
+    pub fn requested_scopes(&self, scope_sets: &ScopeSets) -> Result<Vec<String>, AuthError> {
+        Ok(scope_sets.resolve(self.scopes.as_deref().unwrap_or_default())?)
+    }
+
+    /// Builds the login intent to store in the session from the query parameters.
+    ///
+    /// # Arguments
+    /// - `scopes` - Requested scopes, carried by the `add_scopes` intent
+    ///
+    /// # Returns
+    /// - `LoginIntent` - The requested intent, `LoginIntent::Login` if none was requested
+    pub fn login_intent(&self, scopes: Vec<String>) -> LoginIntent {
+        match self.intent.unwrap_or(LoginIntentParam::Login) {
+            LoginIntentParam::Login => LoginIntent::Login,
+            LoginIntentParam::LinkAlt => LoginIntent::LinkAlt,
+            LoginIntentParam::ChangeMain => LoginIntent::ChangeMain,
+            LoginIntentParam::AddScopes => LoginIntent::AddScopes { scopes },
+            LoginIntentParam::Reauth => LoginIntent::Reauth,
+        }
+    }
 }
 
 /// Query parameters for the OAuth callback endpoint.
@@ -56,20 +160,43 @@ pub struct CallbackParams {
     pub code: String,
 }
 
+/// Query parameters for the Discord linking endpoint.
+///
+/// # Fields
+/// - `next` - Optional internal path to return to after the Discord callback
+#[derive(Deserialize)]
+pub struct DiscordLoginParams {
+    /// Internal path to redirect to after the callback, ignored unless it is allowlisted.
+    pub next: Option<String>,
+}
+
 /// Initiates EVE Online SSO authentication flow.
 ///
-/// Generates an EVE Online SSO login URL with CSRF protection and redirects the user to it.
-/// The CSRF state token is stored in the session for validation during the callback. If the
-/// `change_main` parameter is set, the session is flagged so that the authenticated character
-/// will become the user's new main character after successful login.
+/// Generates an EVE Online SSO login URL with CSRF protection and redirects the user to it. The
+/// CSRF state token and the login intent are stored in the session as the login's `AuthFlow` for
+/// the callback, which uses the intent to decide how to treat the authenticated character. The
+/// `scopes` parameter selects scope sets configured in `ESI_SCOPE_SETS` or lists ESI scopes to
+/// request instead of the default scopes; the requested scopes are stored in the flow so the
+/// callback can verify they were granted. If the `link_mode` parameter is set with the `link_alt`
+/// intent, linking mode is started so consecutive logins each link another character. The
+/// `link_count` parameter additionally queues that many characters, so the callback sends the user
+/// straight back to EVE Online SSO until all of them were linked, and starting a login without it
+/// discards the queue of an earlier, abandoned run. An allowlisted `next` path is stored in the
+/// flow so the callback returns the user to the page they originally tried to access. The
+/// `remember_me` choice is stored the same way and applied once the callback logs the user in.
+/// Starting a login replaces the flow of any earlier login that was never completed.
 ///
 /// # Arguments
 /// - `state` - Application state containing the ESI client for login URL generation
-/// - `session` - User's session for storing CSRF token and change_main flag
-/// - `params` - Query parameters, optionally including `change_main` flag
+/// - `session` - User's session for storing the login flow
+/// - `params` - Query parameters, optionally including the intent, scopes, `link_mode` flag,
+///   `link_count`, `next` path, and `remember_me` flag
 ///
 /// # Returns
 /// - `Ok(Redirect)` - 307 temporary redirect to EVE Online SSO login page
+/// - `Err(AppError::Auth(AuthError::InvalidScopes))` - `scopes` names an unknown scope set
+/// - `Err(AppError::Auth(AuthError::InvalidLinkCount))` - `link_count` is zero or exceeds
+///   `MAX_LINK_QUEUE_LENGTH`
 /// - `Err(AppError)` - Failed to generate login URL or store session data
 #[utoipa::path(
     get,
@@ -77,10 +204,17 @@ pub struct CallbackParams {
     tag = AUTH_TAG,
     responses(
         (status = 307, description = "Redirect to EVE Online login URL"),
+        (status = 400, description = "Unknown scope set requested or invalid link count", body = ErrorDto),
+        (status = 429, description = "Too many logins from this client, retry after the Retry-After header's seconds", body = ErrorDto),
         (status = 500, description = "Internal server error", body = ErrorDto)
     ),
     params(
-        ("change_main" = Option<bool>, Query, description = "If true, change logged in user's main to character"),
+        ("intent" = Option<LoginIntentParam>, Query, description = "Purpose of the login, defaults to login"),
+        ("scopes" = Option<String>, Query, description = "Space-separated names of configured scope sets and ESI scopes to request, e.g. member_audit"),
+        ("link_mode" = Option<bool>, Query, description = "If true with the link_alt intent, keep linking characters to the user across consecutive logins"),
+        ("link_count" = Option<u32>, Query, description = "Number of characters to link in a row with the link_alt intent, returning to EVE Online SSO after each callback"),
+        ("next" = Option<String>, Query, description = "Internal path to return to after login, ignored if not an allowed frontend route"),
+        ("remember_me" = Option<bool>, Query, description = "If true, keep the user logged in for SESSION_REMEMBER_ME_DAYS of inactivity instead of SESSION_INACTIVITY_DAYS"),
     )
 )]
 pub async fn login(
@@ -89,26 +223,84 @@ pub async fn login(
     params: Query<LoginParams>,
 ) -> Result<impl IntoResponse, AppError> {
     let login_service = LoginService::new(&state.esi_provider);
-    let scopes = eve_esi::ScopeBuilder::new().build();
+    let requested_scopes = params.0.requested_scopes(&state.scope_sets)?;
+    let intent = params.0.login_intent(requested_scopes.clone());
 
-    if let Some(true) = params.0.change_main {
-        SessionUserChangeMain::insert(&session, true).await?;
+    let link_count = params
+        .0
+        .link_count
+        .filter(|_| intent == LoginIntent::LinkAlt);
+    if let Some(count) = link_count {
+        if count == 0 || count > MAX_LINK_QUEUE_LENGTH {
+            return Err(AuthError::InvalidLinkCount(count).into());
+        }
     }
 
-    let login = login_service.generate_login_url(scopes)?;
+    if intent == LoginIntent::LinkAlt && (params.0.link_mode == Some(true) || link_count.is_some())
+    {
+        SessionUserLinkMode::start(&session).await?;
+    }
 
-    SessionAuthCsrf::insert(&session, &login.state).await?;
+    // Always replace the queue of an earlier run that was abandoned
+    match link_count {
+        Some(count) => SessionUserLinkQueue::insert(&session, count - 1).await?,
+        None => {
+            SessionUserLinkQueue::remove(&session).await?;
+        }
+    }
+
+    let login = login_service.generate_login_url(login_scopes(&requested_scopes))?;
+
+    let redirect = params.0.next.as_deref().and_then(validate_redirect);
+    if let (None, Some(next)) = (redirect, &params.0.next) {
+        tracing::debug!(
+            "Ignoring post-login redirect to non-allowlisted path {}",
+            next
+        );
+    }
+
+    SessionAuthFlow::initiate(
+        &session,
+        AuthFlow {
+            csrf: login.state,
+            intent,
+            scopes: requested_scopes,
+            redirect: redirect.map(str::to_string),
+            remember_me: params.0.remember_me == Some(true),
+        },
+    )
+    .await?;
 
     Ok(Redirect::temporary(&login.login_url))
 }
 
 /// Handles OAuth callback from EVE Online SSO after successful authentication.
 ///
-/// Validates the CSRF state token, exchanges the authorization code for access/refresh tokens,
-/// verifies the character JWT token, and either creates a new user or associates the character
-/// with an existing user. If `change_main` was set during login, the authenticated character
-/// becomes the user's new main character. The user ID is stored in the session for subsequent
-/// requests.
+/// Validates the CSRF state token against the login flow stored by the login endpoint,
+/// exchanges the authorization code for access/refresh tokens, verifies the character JWT
+/// token, and either creates a new user or associates the character with an existing user
+/// according to the flow's login intent, e.g. making the authenticated character the user's
+/// new main for `LoginIntent::ChangeMain`. Callbacks for expired flows or flows that already
+/// reached their callback are rejected, and the flow is marked completed once the character is
+/// handled. The user ID is stored in the session for subsequent requests, and the user is
+/// redirected to the path stored by the login endpoint's `next` parameter, if any. Logins with
+/// the login endpoint's `remember_me` flag mark the session as remembered, so it expires after
+/// `SESSION_REMEMBER_ME_DAYS` of inactivity instead of `SESSION_INACTIVITY_DAYS`. The callback
+/// fails unless the character granted every scope the login requested. Logins granting scopes
+/// with `LoginIntent::AddScopes` complete the user's re-authentication campaigns and onboarding
+/// steps asking for those scopes. The character's refresh token is stored encrypted if
+/// encryption keys are configured, and characters granting the corporation membership scope
+/// additionally have their corporation's member list fetched in the background. Webhooks are
+/// notified of linked and transferred characters and of changed mains.
+///
+/// A logged in user authenticating a character owned by another user with the same owner hash
+/// doesn't take the character over right away. The transfer is stored in the session instead
+/// and only carried out once the user confirms it with `POST /api/auth/transfer/confirm`.
+///
+/// While linking mode is active, the outcome is recorded in the session and the user is
+/// redirected back to the linking page, including when linking the character failed. If more
+/// characters are queued by the login endpoint's `link_count` parameter, the user is instead
+/// sent straight back to EVE Online SSO to log in with the next character.
 ///
 /// # Arguments
 /// - `state` - Application state containing database and ESI client for callback processing
@@ -116,15 +308,19 @@ pub async fn login(
 /// - `params` - Query parameters containing CSRF state and authorization code from EVE SSO
 ///
 /// # Returns
-/// - `Ok(Redirect)` - 308 permanent redirect to `/auth` after successful authentication
+/// - `Ok(Redirect)` - 308 permanent redirect to the stored `next` path or `/auth` after
+///   successful authentication, 307 temporary redirect to EVE Online SSO while characters
+///   remain queued for linking, or 307 temporary redirect to `/auth/link-characters` while
+///   linking mode is active
 /// - `Err(AppError)` - CSRF validation failed, token exchange failed, or database error
 #[utoipa::path(
     get,
     path = "/api/auth/callback",
     tag = AUTH_TAG,
     responses(
-        (status = 307, description = "Redirect to user information API route"),
-        (status = 400, description = "CSRF state in URL does not match state in session", body = ErrorDto),
+        (status = 307, description = "Redirect to user information API route, or to EVE Online login URL for the next queued character"),
+        (status = 400, description = "CSRF state in URL does not match the login flow, or the flow expired or already completed", body = ErrorDto),
+        (status = 429, description = "Too many callbacks from this client, retry after the Retry-After header's seconds", body = ErrorDto),
         (status = 500, description = "Internal server error", body = ErrorDto)
     ),
     params(
@@ -137,27 +333,507 @@ pub async fn callback(
     session: Session,
     params: Query<CallbackParams>,
 ) -> Result<impl IntoResponse, AppError> {
-    let callback_service = CallbackService::new(&state.db, &state.esi_provider);
-
-    validate_csrf(&session, &params.0.state).await?;
+    let AuthFlow {
+        intent,
+        scopes: requested_scopes,
+        redirect,
+        remember_me,
+        ..
+    } = validate_csrf(&session, &params.0.state).await?;
 
     let maybe_user_id = SessionUserId::get(&session).await?;
-    let change_main = SessionUserChangeMain::remove(&session).await?;
+    let link_mode = SessionUserLinkMode::get(&session).await?.is_some();
 
-    let user_id = callback_service
-        .handle_callback(&params.0.code, maybe_user_id, change_main)
-        .await?;
+    let result = CallbackService::new(&state.db, &state.esi_provider)
+        .with_required_scopes(requested_scopes.clone())
+        .handle_callback(&params.0.code, maybe_user_id, &intent)
+        .await;
+
+    let outcome = match result {
+        Ok(outcome) => outcome,
+        Err(err) if link_mode => {
+            // Record the failure and return to the linking page so the user can continue
+            // with their remaining characters
+            tracing::error!("Failed to link character in linking mode: {}", err);
+
+            SessionUserLinkMode::push(
+                &session,
+                LinkedCharacterDto {
+                    character_id: None,
+                    character_name: None,
+                    linked: false,
+                },
+            )
+            .await?;
+
+            return link_mode_redirect(&state, &session, requested_scopes, remember_me).await;
+        }
+        Err(err) => return Err(err),
+    };
+
+    SessionAuthFlow::complete(&session).await?;
+
+    // Notify webhooks of linked and transferred characters and changed mains; failing to queue
+    // the notifications doesn't fail the login
+    if let Err(err) = NotificationService::new(&state.db)
+        .notify(&state.worker.queue, &outcome.ownership_changes)
+        .await
+    {
+        tracing::error!(
+            "Failed to queue webhook notifications for user {}: {}",
+            outcome.user_id,
+            err
+        );
+    }
+
+    // Granting scopes completes the user's re-authentication campaigns and onboarding steps
+    // asking for them
+    if let LoginIntent::AddScopes { scopes } = &intent {
+        ReauthCampaignService::new(&state.db)
+            .complete_for_scopes(outcome.user_id, scopes)
+            .await?;
+        OnboardingService::new(&state.db)
+            .complete_for_scopes(outcome.user_id, scopes)
+            .await?;
+    }
+
+    // Store the character's refresh token for scoped ESI requests; failing to store a token
+    // doesn't fail the login
+    if let Some(refresh_token) = &outcome.refresh_token {
+        if state.cipher.is_configured() {
+            let stored = TokenService::new(&state.db, &state.esi_provider, &state.cipher)
+                .store_token(outcome.character_id, refresh_token, &outcome.scopes)
+                .await;
+
+            match stored {
+                Ok(()) => {
+                    // Characters granting the skills scope get their skill snapshot right away
+                    // instead of waiting for the next scheduled refresh
+                    if outcome
+                        .scopes
+                        .iter()
+                        .any(|scope| scope == CHARACTER_SKILLS_SCOPE)
+                    {
+                        state
+                            .worker
+                            .queue
+                            .push(WorkerJob::UpdateCharacterSkills {
+                                character_id: outcome.character_id,
+                            })
+                            .await?;
+                    }
+
+                    // Characters granting the corporation membership scope start tracking their
+                    // corporation's member list with the token just stored, unless another
+                    // director already does
+                    if outcome
+                        .scopes
+                        .iter()
+                        .any(|scope| scope == CORPORATION_MEMBERSHIP_SCOPE)
+                    {
+                        let tracked = CorporationMemberService::new(
+                            &state.db,
+                            &state.esi_provider,
+                            &state.cipher,
+                        )
+                        .track_corporation(outcome.character_id)
+                        .await;
+
+                        match tracked {
+                            Ok(Some(corporation_id)) => {
+                                state
+                                    .worker
+                                    .queue
+                                    .push(WorkerJob::UpdateCorporationMembers { corporation_id })
+                                    .await?;
+                            }
+                            // The corporation's working director is kept
+                            Ok(None) => {}
+                            Err(err) => tracing::error!(
+                                "Failed to track corporation membership of character {}: {}",
+                                outcome.character_id,
+                                err
+                            ),
+                        }
+                    }
+                }
+                Err(err) => tracing::error!(
+                    "Failed to store refresh token of character {}: {}",
+                    outcome.character_id,
+                    err
+                ),
+            }
+        }
+    }
 
     if maybe_user_id.is_none() {
         tracing::trace!(
             "Inserting user ID {} into session after successful callback",
-            user_id
+            outcome.user_id
         );
 
-        SessionUserId::insert(&session, user_id).await?;
+        SessionUserId::insert(&session, outcome.user_id).await?;
+
+        if remember_me {
+            SessionUserRememberMe::insert(&session).await?;
+        } else {
+            SessionUserRememberMe::remove(&session).await?;
+        }
+    }
+
+    if let Some(from_user_id) = outcome.pending_transfer {
+        tracing::debug!(
+            "Character {} of user {} awaits transfer to user {}",
+            outcome.character_id,
+            from_user_id,
+            outcome.user_id
+        );
+
+        SessionPendingTransfer::insert(
+            &session,
+            PendingTransferDto {
+                from_user_id,
+                character_id: outcome.character_id,
+                character_name: outcome.character_name.clone(),
+            },
+        )
+        .await?;
+    }
+
+    if link_mode {
+        SessionUserLinkMode::push(
+            &session,
+            LinkedCharacterDto {
+                character_id: Some(outcome.character_id),
+                character_name: Some(outcome.character_name),
+                linked: outcome.pending_transfer.is_none(),
+            },
+        )
+        .await?;
+
+        return link_mode_redirect(&state, &session, requested_scopes, remember_me).await;
+    }
+
+    Ok(Redirect::permanent(redirect.as_deref().unwrap_or("/auth")))
+}
+
+/// Returns the scopes to request from EVE Online SSO for a login.
+///
+/// # Arguments
+/// - `requested_scopes` - Scopes requested with the login endpoint's `scopes` parameter
+///
+/// # Returns
+/// - `Vec<String>` - The requested scopes, or the default scopes if none were requested
+fn login_scopes(requested_scopes: &[String]) -> Vec<String> {
+    if requested_scopes.is_empty() {
+        eve_esi::ScopeBuilder::new().build()
+    } else {
+        requested_scopes.to_vec()
+    }
+}
+
+/// Redirects a callback processed in linking mode to where the user continues linking.
+///
+/// If characters remain queued, a login linking the next character is started with the same
+/// scopes and the user is sent straight back to EVE Online SSO. Otherwise the user returns to
+/// the linking page to review the results.
+///
+/// # Arguments
+/// - `state` - Application state containing the ESI client for login URL generation
+/// - `session` - User's session containing the link queue
+/// - `scopes` - Scopes requested by the login the callback belongs to
+/// - `remember_me` - Remember me choice of the login the callback belongs to
+///
+/// # Returns
+/// - `Ok(Redirect)` - 307 temporary redirect to EVE Online SSO for the next queued character,
+///   or to `/auth/link-characters` once the queue is exhausted
+/// - `Err(AppError)` - Failed to generate login URL or store session data
+async fn link_mode_redirect(
+    state: &AppState,
+    session: &Session,
+    scopes: Vec<String>,
+    remember_me: bool,
+) -> Result<Redirect, AppError> {
+    if !SessionUserLinkQueue::advance(session).await? {
+        return Ok(Redirect::temporary(LINK_MODE_REDIRECT));
+    }
+
+    let login = LoginService::new(&state.esi_provider).generate_login_url(login_scopes(&scopes))?;
+
+    SessionAuthFlow::initiate(
+        session,
+        AuthFlow {
+            csrf: login.state,
+            intent: LoginIntent::LinkAlt,
+            scopes,
+            redirect: None,
+            remember_me,
+        },
+    )
+    .await?;
+
+    Ok(Redirect::temporary(&login.login_url))
+}
+
+/// Initiates the Discord OAuth flow linking a Discord account to the current user.
+///
+/// Generates a Discord authorization URL with CSRF protection and redirects the user to it.
+/// Discord accounts can only be linked to an existing user, so the user must be logged in with
+/// EVE Online first. The CSRF state token is stored in the session under a key separate from
+/// EVE SSO logins, alongside an allowlisted `next` path the callback returns the user to.
+///
+/// # Arguments
+/// - `state` - Application state containing the Discord linking settings
+/// - `session` - User's session for storing the CSRF token
+/// - `params` - Query parameters, optionally including the `next` path
+///
+/// # Returns
+/// - `Ok(Redirect)` - 307 temporary redirect to the Discord authorization page
+/// - `Err(AppError::Auth(AuthError::UserNotInSession))` - User is not logged in
+/// - `Err(AppError::Auth(AuthError::DiscordNotConfigured))` - Discord linking is disabled
+/// - `Err(AppError)` - Failed to store session data
+#[utoipa::path(
+    get,
+    path = "/api/auth/discord/login",
+    tag = AUTH_TAG,
+    responses(
+        (status = 307, description = "Redirect to Discord authorization URL"),
+        (status = 404, description = "User not found or Discord linking disabled", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+    params(
+        ("next" = Option<String>, Query, description = "Internal path to return to after linking, ignored if not an allowed frontend route"),
+    )
+)]
+pub async fn discord_login(
+    State(state): State<AppState>,
+    session: Session,
+    params: Query<DiscordLoginParams>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let login = DiscordLinkService::new(&state.db, &state.discord).generate_login_url()?;
+
+    SessionDiscordCsrf::insert(&session, &login.state).await?;
+
+    // Always replace the redirect of an earlier link that was never completed
+    match params.0.next.as_deref().and_then(validate_redirect) {
+        Some(next) => SessionDiscordRedirect::insert(&session, next).await?,
+        None => {
+            SessionDiscordRedirect::remove(&session).await?;
+        }
     }
 
-    Ok(Redirect::permanent("/auth"))
+    Ok(Redirect::temporary(&login.login_url))
+}
+
+/// Handles the OAuth callback from Discord, linking the authorized account to the current user.
+///
+/// Validates the CSRF state token, exchanges the authorization code for an access token, and
+/// links the Discord account it belongs to, replacing any account the user linked before.
+/// Accounts already linked to a different user are rejected. The user is redirected to the
+/// path stored by the login endpoint's `next` parameter, if any.
+///
+/// # Arguments
+/// - `state` - Application state containing the database and Discord linking settings
+/// - `session` - User's session for CSRF validation and the user ID
+/// - `params` - Query parameters containing CSRF state and authorization code from Discord
+///
+/// # Returns
+/// - `Ok(Redirect)` - 307 temporary redirect to the stored `next` path or `/auth`
+/// - `Err(AppError::Auth(AuthError::CsrfValidationFailed))` - CSRF state mismatch
+/// - `Err(AppError::Auth(AuthError::DiscordAccountLinkedToAnotherUser))` - Account is linked to
+///   another user
+/// - `Err(AppError)` - User not logged in, token exchange failed, or database error
+#[utoipa::path(
+    get,
+    path = "/api/auth/discord/callback",
+    tag = AUTH_TAG,
+    responses(
+        (status = 307, description = "Redirect after linking the Discord account"),
+        (status = 400, description = "CSRF state mismatch or Discord account linked to another user", body = ErrorDto),
+        (status = 404, description = "User not found or Discord linking disabled", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+    params(
+        ("state" = String, Query, description = "CSRF state to be validated during callback against state stored in session"),
+        ("code" = String, Query, description = "Authorization code used to fetch an access token from Discord")
+    )
+)]
+pub async fn discord_callback(
+    State(state): State<AppState>,
+    session: Session,
+    params: Query<CallbackParams>,
+) -> Result<impl IntoResponse, AppError> {
+    SessionDiscordCsrf::validate(&session, &params.0.state).await?;
+    let redirect = SessionDiscordRedirect::remove(&session).await?;
+
+    let user = get_user_from_session(&state, &session).await?;
+
+    let account = DiscordLinkService::new(&state.db, &state.discord)
+        .handle_callback(&params.0.code, user.id)
+        .await?;
+
+    tracing::debug!(
+        "Linked Discord account {} to user {}",
+        account.discord_id,
+        user.id
+    );
+
+    Ok(Redirect::temporary(redirect.as_deref().unwrap_or("/auth")))
+}
+
+/// Retrieves the linking mode status, the characters linked while it is active, and the number
+/// of characters still queued for linking.
+///
+/// # Arguments
+/// - `session` - User's session containing the linking mode
+///
+/// # Returns
+/// - `Ok(LinkModeDto)` - Whether linking mode is active, the recorded results, and the
+///   characters still queued
+/// - `Err(AppError)` - Failed to retrieve session data
+#[utoipa::path(
+    get,
+    path = "/api/auth/link-mode",
+    tag = AUTH_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving linking mode status", body = LinkModeDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_link_mode(session: Session) -> Result<impl IntoResponse, AppError> {
+    let results = SessionUserLinkMode::get(&session).await?;
+    let queued = SessionUserLinkQueue::get(&session).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(LinkModeDto {
+            active: results.is_some(),
+            results: results.unwrap_or_default(),
+            queued: queued.unwrap_or_default(),
+        }),
+    )
+        .into_response())
+}
+
+/// Exits linking mode, returning the characters linked while it was active.
+///
+/// Characters still queued for linking are discarded.
+///
+/// # Arguments
+/// - `session` - User's session containing the linking mode
+///
+/// # Returns
+/// - `Ok(LinkModeDto)` - Inactive linking mode with the final results
+/// - `Err(AppError)` - Failed to update session data
+#[utoipa::path(
+    delete,
+    path = "/api/auth/link-mode",
+    tag = AUTH_TAG,
+    responses(
+        (status = 200, description = "Linking mode exited", body = LinkModeDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn exit_link_mode(session: Session) -> Result<impl IntoResponse, AppError> {
+    let results = SessionUserLinkMode::remove(&session).await?;
+    SessionUserLinkQueue::remove(&session).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(LinkModeDto {
+            active: false,
+            results: results.unwrap_or_default(),
+            queued: 0,
+        }),
+    )
+        .into_response())
+}
+
+/// Retrieves the character transfer awaiting confirmation, if any.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection for user lookup
+/// - `session` - User's session containing the pending transfer
+///
+/// # Returns
+/// - `Ok(Option<PendingTransferDto>)` - The pending transfer, `null` if none is pending
+/// - `Err(AppError)` - User not in session, not found in database, or session error
+#[utoipa::path(
+    get,
+    path = "/api/auth/transfer",
+    tag = AUTH_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving the pending transfer", body = Option<PendingTransferDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_pending_transfer(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let transfer = SessionPendingTransfer::get(&session).await?;
+
+    Ok((StatusCode::OK, Json(transfer)).into_response())
+}
+
+/// Confirms the pending character transfer, merging the user owning the character into the
+/// current user.
+///
+/// The pending transfer is removed from the session whether or not the merge succeeds, so a
+/// failed confirmation requires authenticating the character again. Webhooks are notified of
+/// every character transferred by the merge.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and worker queue
+/// - `session` - User's session containing their user ID and the pending transfer
+///
+/// # Returns
+/// - `Ok(UserMergeDto)` - Summary of the records moved to the current user
+/// - `Err(AppError::Auth(AuthError::NoPendingTransfer))` - No transfer is pending or the
+///   character no longer belongs to the other user
+/// - `Err(AppError)` - User not in session, not found in database, or database error
+#[utoipa::path(
+    post,
+    path = "/api/auth/transfer/confirm",
+    tag = AUTH_TAG,
+    responses(
+        (status = 200, description = "Success when confirming the transfer", body = UserMergeDto),
+        (status = 404, description = "User not found or no transfer pending", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn confirm_transfer(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let Some(transfer) = SessionPendingTransfer::remove(&session).await? else {
+        return Err(AuthError::NoPendingTransfer.into());
+    };
+
+    let outcome = TransferService::new(&state.db)
+        .confirm_transfer(user.id, &transfer)
+        .await?;
+
+    // Failing to queue the notifications doesn't fail the transfer
+    if let Err(err) = NotificationService::new(&state.db)
+        .notify(&state.worker.queue, &outcome.ownership_changes)
+        .await
+    {
+        tracing::error!(
+            "Failed to queue webhook notifications for user {}: {}",
+            user.id,
+            err
+        );
+    }
+
+    Ok((StatusCode::OK, Json(outcome.merge)).into_response())
 }
 
 /// Logs out the current user by clearing their session data.
@@ -199,7 +875,9 @@ pub async fn logout(session: Session) -> Result<impl IntoResponse, AppError> {
 ///
 /// Fetches the user ID from the session and queries the database for complete user information,
 /// including their main character details. Returns a 404 error if the user is not found in the
-/// database (which may indicate their account was deleted or the session is stale).
+/// database (which may indicate their account was deleted or the session is stale). Users
+/// restricted by a re-authentication campaign are still returned so the frontend can prompt
+/// them to re-authenticate.
 ///
 /// # Arguments
 /// - `state` - Application state containing the database connection for user lookup
@@ -222,7 +900,7 @@ pub async fn get_user(
     State(state): State<AppState>,
     session: Session,
 ) -> Result<impl IntoResponse, AppError> {
-    let user = get_user_from_session(&state, &session).await?;
+    let user = get_user_from_session_allowing_reauth(&state, &session).await?;
 
     Ok((StatusCode::OK, axum::Json(user)).into_response())
 }
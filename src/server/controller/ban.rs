@@ -0,0 +1,134 @@
+//! User ban controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to suspend users with temporary or permanent
+//! bans, list a user's bans, and lift bans early. Requests from suspended users are rejected by
+//! the `reject_suspended_users` middleware. All endpoints require an active session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        ban::{CreateUserBanDto, UserBanDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::ban::BanService,
+    },
+};
+
+/// OpenAPI tag for user ban endpoints.
+pub static BAN_TAG: &str = "ban";
+
+/// Retrieves all bans of a user, most recently issued first.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `user_id` - ID of the user
+///
+/// # Returns
+/// - `Ok(Vec<UserBanDto>)` - 200 OK with the bans, including expired and lifted bans
+/// - `Err(AppError)` - User not in session, user not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/users/{user_id}/bans",
+    tag = BAN_TAG,
+    params(("user_id" = i32, Path, description = "ID of the user")),
+    responses(
+        (status = 200, description = "Success when retrieving the user's bans", body = Vec<UserBanDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_user_bans(
+    State(state): State<AppState>,
+    session: Session,
+    Path(user_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let bans = BanService::new(&state.db).get_user_bans(user_id).await?;
+
+    Ok((StatusCode::OK, Json(bans)).into_response())
+}
+
+/// Bans a user, suspending them until the ban expires or is lifted.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `user_id` - ID of the user to ban
+/// - `payload` - Reason and duration in hours of the ban, no duration for a permanent ban
+///
+/// # Returns
+/// - `Ok(UserBanDto)` - 201 Created with the issued ban
+/// - `Err(AppError)` - User not in session, invalid ban, user not found, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{user_id}/bans",
+    tag = BAN_TAG,
+    params(("user_id" = i32, Path, description = "ID of the user to ban")),
+    request_body = CreateUserBanDto,
+    responses(
+        (status = 201, description = "User banned", body = UserBanDto),
+        (status = 400, description = "Invalid ban reason or duration, or banning yourself", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn ban_user(
+    State(state): State<AppState>,
+    session: Session,
+    Path(user_id): Path<i32>,
+    Json(payload): Json<CreateUserBanDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let ban = BanService::new(&state.db)
+        .ban_user(user.id, user_id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(ban)).into_response())
+}
+
+/// Lifts a ban before it ends.
+///
+/// Lifting a ban that was already lifted or has expired returns it unchanged.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `ban_id` - ID of the ban
+///
+/// # Returns
+/// - `Ok(UserBanDto)` - 200 OK with the lifted ban
+/// - `Err(AppError)` - User not in session, ban not found, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/bans/{ban_id}/lift",
+    tag = BAN_TAG,
+    params(("ban_id" = i32, Path, description = "ID of the ban")),
+    responses(
+        (status = 200, description = "Ban lifted", body = UserBanDto),
+        (status = 404, description = "User or ban not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn lift_ban(
+    State(state): State<AppState>,
+    session: Session,
+    Path(ban_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let ban = BanService::new(&state.db).lift_ban(user.id, ban_id).await?;
+
+    Ok((StatusCode::OK, Json(ban)).into_response())
+}
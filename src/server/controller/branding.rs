@@ -0,0 +1,54 @@
+//! Branding controller endpoints.
+//!
+//! This module provides the HTTP endpoint serving the instance's branding settings, which the
+//! frontend layout applies to the navigation bar, page title, and theme. The endpoint is public
+//! since the branding is shown before users log in.
+
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    model::{api::ErrorDto, branding::BrandingDto},
+    server::{error::AppError, model::app::AppState},
+};
+
+/// OpenAPI tag for branding endpoints.
+pub static BRANDING_TAG: &str = "branding";
+
+/// Cache-Control header value for branding responses.
+///
+/// Branding only changes when the server is restarted with new settings, so browsers may
+/// reuse it for a few minutes instead of fetching it on every page load.
+static BRANDING_CACHE_CONTROL: &str = "public, max-age=300";
+
+/// Retrieves the organization name, logo, primary color, and navigation links.
+///
+/// This endpoint is public. Unset settings are `null`, in which case the frontend keeps
+/// Bifrost's default look.
+///
+/// # Arguments
+/// - `state` - Application state containing the branding settings
+///
+/// # Returns
+/// - `Ok(BrandingDto)` - Branding settings configured for this instance
+#[utoipa::path(
+    get,
+    path = "/api/branding",
+    tag = BRANDING_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving branding settings", body = BrandingDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_branding(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+    Ok((
+        StatusCode::OK,
+        [(header::CACHE_CONTROL, BRANDING_CACHE_CONTROL)],
+        Json(state.branding.to_dto()),
+    )
+        .into_response())
+}
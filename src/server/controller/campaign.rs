@@ -0,0 +1,135 @@
+//! Campaign controller endpoints.
+//!
+//! This module provides HTTP endpoints for tracking deployment and war campaigns: creating a
+//! campaign with its staging system and date range, listing campaigns, and deleting them.
+//! These endpoints require an active session, and creating or deleting campaigns requires the
+//! `manage_content` permission.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        campaign::{CampaignDto, CreateCampaignDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::campaign::CampaignService,
+    },
+};
+
+/// OpenAPI tag for campaign endpoints.
+pub static CAMPAIGN_TAG: &str = "campaign";
+
+/// Creates a campaign.
+///
+/// Records a deployment or war with the solar system it stages out of and its date range,
+/// created by the currently authenticated user. Ongoing campaigns omit the end date.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Campaign name, staging system, start date, and optional end date
+///
+/// # Returns
+/// - `Ok(CampaignDto)` - 201 Created with the created campaign
+/// - `Err(AppError)` - User not in session, invalid campaign, duplicate name, or database error
+#[utoipa::path(
+    post,
+    path = "/api/campaigns",
+    tag = CAMPAIGN_TAG,
+    request_body = CreateCampaignDto,
+    responses(
+        (status = 201, description = "Campaign created", body = CampaignDto),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 400, description = "Invalid campaign", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 409, description = "A campaign with that name already exists", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_campaign(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateCampaignDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let campaign = CampaignService::new(&state.db)
+        .create_campaign(user.id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(campaign)).into_response())
+}
+
+/// Retrieves all campaigns.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<CampaignDto>)` - All campaigns, most recently started first
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/campaigns",
+    tag = CAMPAIGN_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving campaigns", body = Vec<CampaignDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_campaigns(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let campaigns = CampaignService::new(&state.db).get_campaigns().await?;
+
+    Ok((StatusCode::OK, Json(campaigns)).into_response())
+}
+
+/// Deletes a campaign.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `campaign_id` - ID of the campaign to delete
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the campaign was deleted
+/// - `Err(AppError)` - User not in session, campaign not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/campaigns/{campaign_id}",
+    tag = CAMPAIGN_TAG,
+    params(("campaign_id" = i32, Path, description = "ID of the campaign to delete")),
+    responses(
+        (status = 204, description = "Campaign deleted"),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 404, description = "User or campaign not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_campaign(
+    State(state): State<AppState>,
+    session: Session,
+    Path(campaign_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    CampaignService::new(&state.db)
+        .delete_campaign(campaign_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,153 @@
+//! Consent controller endpoints.
+//!
+//! This module provides HTTP endpoints for users to review which categories of data the
+//! organization requests and to grant or revoke consent for each category. Revoking consent
+//! schedules deletion of the data already stored for the category. These endpoints require an
+//! active session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        consent::{ConsentCategory, ConsentDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::{consent::ConsentError, AppError},
+        model::{app::AppState, worker::WorkerJob},
+        service::consent::ConsentService,
+    },
+};
+
+/// OpenAPI tag for consent endpoints.
+pub static CONSENT_TAG: &str = "consent";
+
+/// Retrieves the consent status of each data category for the current user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<ConsentDto>)` - Consent status for each data category
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/user/consents",
+    tag = CONSENT_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving consent status", body = Vec<ConsentDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_consents(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let consents = ConsentService::new(&state.db).get_consents(user.id).await?;
+
+    Ok((StatusCode::OK, Json(consents)).into_response())
+}
+
+/// Grants consent for a data category.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `category` - Data category name, e.g. `assets`
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when consent is granted
+/// - `Err(AppError)` - User not in session, unknown category, or database error
+#[utoipa::path(
+    put,
+    path = "/api/user/consents/{category}",
+    tag = CONSENT_TAG,
+    params(("category" = ConsentCategory, Path, description = "Data category name")),
+    responses(
+        (status = 204, description = "Consent granted"),
+        (status = 400, description = "Unknown consent category", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn grant_consent(
+    State(state): State<AppState>,
+    session: Session,
+    Path(category): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+    let category = parse_category(category)?;
+
+    ConsentService::new(&state.db)
+        .grant(user.id, category)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Revokes consent for a data category.
+///
+/// When consent was granted, schedules a job to delete the data already stored for the
+/// category.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and worker queue
+/// - `session` - User's session containing their user ID
+/// - `category` - Data category name, e.g. `assets`
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when consent is revoked
+/// - `Err(AppError)` - User not in session, unknown category, database, or worker queue error
+#[utoipa::path(
+    delete,
+    path = "/api/user/consents/{category}",
+    tag = CONSENT_TAG,
+    params(("category" = ConsentCategory, Path, description = "Data category name")),
+    responses(
+        (status = 204, description = "Consent revoked"),
+        (status = 400, description = "Unknown consent category", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn revoke_consent(
+    State(state): State<AppState>,
+    session: Session,
+    Path(category): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+    let category = parse_category(category)?;
+
+    let revoked = ConsentService::new(&state.db)
+        .revoke(user.id, category)
+        .await?;
+
+    if revoked {
+        state
+            .worker
+            .queue
+            .push(WorkerJob::DeleteConsentData {
+                user_id: user.id,
+                category,
+            })
+            .await?;
+    }
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Parses a consent category from its path name.
+fn parse_category(category: String) -> Result<ConsentCategory, ConsentError> {
+    ConsentCategory::from_name(&category).ok_or(ConsentError::InvalidCategory(category))
+}
@@ -0,0 +1,61 @@
+//! Corporation member controller endpoints.
+//!
+//! This module provides an HTTP endpoint for recruiters to view the member list of a
+//! corporation whose director granted the membership scope, with the Bifrost user owning each
+//! member, so unregistered members can be followed up. This endpoint requires an active
+//! session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, corporation_member::CorporationMembersDto},
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::corporation_member::CorporationMemberService,
+    },
+};
+
+/// OpenAPI tag for corporation member endpoints.
+pub static CORPORATION_MEMBER_TAG: &str = "corporation_member";
+
+/// Retrieves the member list of a corporation.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `corporation_id` - EVE Online corporation ID
+///
+/// # Returns
+/// - `Ok(CorporationMembersDto)` - Members ordered by character ID with registration counts
+/// - `Err(AppError)` - User not in session, corporation not found or not tracked, or database
+///   error
+#[utoipa::path(
+    get,
+    path = "/api/admin/corporations/{corporation_id}/members",
+    tag = CORPORATION_MEMBER_TAG,
+    params(("corporation_id" = i64, Path, description = "EVE Online corporation ID")),
+    responses(
+        (status = 200, description = "Success when retrieving the member list", body = CorporationMembersDto),
+        (status = 404, description = "User or corporation not found, or no director granted the membership scope", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_corporation_members(
+    State(state): State<AppState>,
+    session: Session,
+    Path(corporation_id): Path<i64>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let members = CorporationMemberService::new(&state.db, &state.esi_provider, &state.cipher)
+        .get_members(corporation_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(members)).into_response())
+}
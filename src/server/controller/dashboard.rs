@@ -0,0 +1,50 @@
+//! Admin dashboard controller endpoints.
+//!
+//! This module provides HTTP endpoints for the admin dashboard. Figures are served from the
+//! precomputed summary tables refreshed by the scheduler, so loading the dashboard does not
+//! aggregate the user tables.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, dashboard::DashboardSummaryDto},
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::dashboard::DashboardService,
+    },
+};
+
+/// OpenAPI tag for admin dashboard endpoints.
+pub static DASHBOARD_TAG: &str = "dashboard";
+
+/// Retrieves the precomputed admin dashboard summaries.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(DashboardSummaryDto)` - 200 OK with users per corporation and the characters-per-user
+///   distribution as of the last refresh
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/dashboard",
+    tag = DASHBOARD_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving dashboard summaries", body = DashboardSummaryDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_dashboard_summary(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let summary = DashboardService::new(&state.db).get_summary().await?;
+
+    Ok((StatusCode::OK, Json(summary)).into_response())
+}
@@ -0,0 +1,339 @@
+//! Data access API controller endpoints.
+//!
+//! This module provides HTTP endpoints for BI tools such as Grafana or Metabase to list and
+//! run the saved queries admins define, and for admins to manage saved queries and the API
+//! keys authorizing tools. Endpoints under `/api/data` are authorized by an API key passed as
+//! a bearer token rather than a session, while managing queries and keys requires an active
+//! session.
+
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{NaiveDateTime, Utc};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        data_api::{
+            ApiKeyDto, CreateApiKeyDto, CreatedApiKeyDto, SaveSavedQueryDto, SavedQueryDto,
+            SavedQueryResultDto,
+        },
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::{data_api::DataApiError, AppError},
+        model::app::AppState,
+        service::data_api::DataApiService,
+    },
+};
+
+/// OpenAPI tag for data access API endpoints.
+pub static DATA_API_TAG: &str = "data_api";
+
+/// Lists the saved queries BI tools can run.
+///
+/// This endpoint is authorized by an API key passed as `Authorization: Bearer <key>`.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `headers` - Request headers containing the API key
+///
+/// # Returns
+/// - `Ok(Vec<SavedQueryDto>)` - All saved queries with their parameters
+/// - `Err(AppError)` - Missing or invalid API key, or database error
+#[utoipa::path(
+    get,
+    path = "/api/data/queries",
+    tag = DATA_API_TAG,
+    responses(
+        (status = 200, description = "Success when listing saved queries", body = Vec<SavedQueryDto>),
+        (status = 401, description = "Missing or invalid API key", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn list_data_queries(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    let data_api_service = DataApiService::new(&state.db);
+    data_api_service
+        .authenticate(bearer_token(&headers)?, last_used_at(&state))
+        .await?;
+
+    let queries = data_api_service.get_saved_queries().await?;
+
+    Ok((StatusCode::OK, Json(queries)).into_response())
+}
+
+/// Runs a saved query, returning its rows as JSON objects.
+///
+/// This endpoint is authorized by an API key passed as `Authorization: Bearer <key>`. Query
+/// parameters are passed as query string parameters named after them. The query runs
+/// read-only and returns at most its row limit, with `truncated` set if more rows matched.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `headers` - Request headers containing the API key
+/// - `slug` - URL segment of the saved query
+/// - `params` - Values of the query's parameters
+///
+/// # Returns
+/// - `Ok(SavedQueryResultDto)` - Rows returned by the query
+/// - `Err(AppError)` - Missing or invalid API key, unknown query, invalid parameters, failed
+///   query, or database error
+#[utoipa::path(
+    get,
+    path = "/api/data/queries/{slug}",
+    tag = DATA_API_TAG,
+    params(("slug" = String, Path, description = "URL segment of the saved query")),
+    responses(
+        (status = 200, description = "Success when running the saved query", body = SavedQueryResultDto),
+        (status = 400, description = "Missing or invalid parameter, or the query failed", body = ErrorDto),
+        (status = 401, description = "Missing or invalid API key", body = ErrorDto),
+        (status = 404, description = "Saved query not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn run_data_query(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(slug): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<impl IntoResponse, AppError> {
+    let data_api_service = DataApiService::new(&state.db);
+    data_api_service
+        .authenticate(bearer_token(&headers)?, last_used_at(&state))
+        .await?;
+
+    let result = data_api_service.run_query(&slug, &params).await?;
+
+    Ok((StatusCode::OK, Json(result)).into_response())
+}
+
+/// Retrieves all saved queries for admins.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<SavedQueryDto>)` - All saved queries
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/saved-queries",
+    tag = DATA_API_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving saved queries", body = Vec<SavedQueryDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_saved_queries(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let queries = DataApiService::new(&state.db).get_saved_queries().await?;
+
+    Ok((StatusCode::OK, Json(queries)).into_response())
+}
+
+/// Creates or replaces a saved query.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `slug` - URL segment BI tools request the query by
+/// - `payload` - Name, SQL, parameters, and row limit of the query
+///
+/// # Returns
+/// - `Ok(SavedQueryDto)` - The saved query
+/// - `Err(AppError)` - User not in session, invalid query, or database error
+#[utoipa::path(
+    put,
+    path = "/api/admin/saved-queries/{slug}",
+    tag = DATA_API_TAG,
+    params(("slug" = String, Path, description = "URL segment of the saved query")),
+    request_body = SaveSavedQueryDto,
+    responses(
+        (status = 200, description = "Saved query saved", body = SavedQueryDto),
+        (status = 400, description = "Invalid slug, name, SQL, parameters, or row limit", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn save_saved_query(
+    State(state): State<AppState>,
+    session: Session,
+    Path(slug): Path<String>,
+    Json(payload): Json<SaveSavedQueryDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let query = DataApiService::new(&state.db)
+        .save_query(user.id, &slug, payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(query)).into_response())
+}
+
+/// Deletes a saved query.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `slug` - URL segment of the saved query
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the query was deleted
+/// - `Err(AppError)` - User not in session, query not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/saved-queries/{slug}",
+    tag = DATA_API_TAG,
+    params(("slug" = String, Path, description = "URL segment of the saved query")),
+    responses(
+        (status = 204, description = "Saved query deleted"),
+        (status = 404, description = "User or saved query not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_saved_query(
+    State(state): State<AppState>,
+    session: Session,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    DataApiService::new(&state.db).delete_query(&slug).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves all API keys without the keys themselves.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<ApiKeyDto>)` - All API keys with when they were last used
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/api-keys",
+    tag = DATA_API_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving API keys", body = Vec<ApiKeyDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_api_keys(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let api_keys = DataApiService::new(&state.db).get_api_keys().await?;
+
+    Ok((StatusCode::OK, Json(api_keys)).into_response())
+}
+
+/// Creates an API key for a BI tool.
+///
+/// The key is only included in this response and can't be retrieved later.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Name identifying the tool using the key
+///
+/// # Returns
+/// - `Ok(CreatedApiKeyDto)` - 201 Created with the key
+/// - `Err(AppError)` - User not in session, empty name, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/api-keys",
+    tag = DATA_API_TAG,
+    request_body = CreateApiKeyDto,
+    responses(
+        (status = 201, description = "API key created", body = CreatedApiKeyDto),
+        (status = 400, description = "Empty name", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_api_key(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateApiKeyDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let api_key = DataApiService::new(&state.db)
+        .create_api_key(user.id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(api_key)).into_response())
+}
+
+/// Revokes an API key.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `api_key_id` - ID of the API key
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the key was revoked
+/// - `Err(AppError)` - User not in session, key not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/api-keys/{api_key_id}",
+    tag = DATA_API_TAG,
+    params(("api_key_id" = i32, Path, description = "API key ID")),
+    responses(
+        (status = 204, description = "API key revoked"),
+        (status = 404, description = "User or API key not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_api_key(
+    State(state): State<AppState>,
+    session: Session,
+    Path(api_key_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    DataApiService::new(&state.db)
+        .delete_api_key(api_key_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Extracts the API key from an `Authorization: Bearer <key>` header.
+fn bearer_token(headers: &HeaderMap) -> Result<&str, DataApiError> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .ok_or(DataApiError::InvalidApiKey)
+}
+
+/// Returns the time to record as an API key's last use, or `None` while the database is
+/// read-only for maintenance.
+fn last_used_at(state: &AppState) -> Option<NaiveDateTime> {
+    (!state.read_only.is_enabled()).then(|| Utc::now().naive_utc())
+}
@@ -0,0 +1,62 @@
+//! Admin diagnostics controller endpoints.
+//!
+//! This module provides an HTTP endpoint for administrators to check the health of the
+//! server's background tasks, such as the job scheduler and worker queue cleanup, including
+//! how often they were restarted after failing. This endpoint requires an active session.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        diagnostics::{BackgroundTaskDto, DiagnosticsDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+    },
+};
+
+/// OpenAPI tag for admin diagnostics endpoints.
+pub static DIAGNOSTICS_TAG: &str = "diagnostics";
+
+/// Retrieves the health of the server's supervised background tasks.
+///
+/// # Arguments
+/// - `state` - Application state containing the task supervisor
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(DiagnosticsDto)` - 200 OK with the background tasks ordered by name
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/diagnostics",
+    tag = DIAGNOSTICS_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving diagnostics", body = DiagnosticsDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_diagnostics(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let tasks = state
+        .supervisor
+        .health()
+        .into_iter()
+        .map(|task| BackgroundTaskDto {
+            name: task.name,
+            status: task.status.as_str().to_string(),
+            restarts: task.restarts,
+            last_error: task.last_error,
+            started_at: task.started_at.naive_utc(),
+        })
+        .collect();
+
+    Ok((StatusCode::OK, Json(DiagnosticsDto { tasks })).into_response())
+}
@@ -0,0 +1,481 @@
+//! Doctrine controller endpoints.
+//!
+//! This module provides HTTP endpoints for importing ship fittings in EFT format, grouping
+//! them into doctrines, and reporting who can fly them. These endpoints require an active
+//! session, changing fittings or doctrines requires the `manage_content` permission, and
+//! corporation readiness requires the `manage_members` permission.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        doctrine::{
+            CorporationDoctrineReadinessDto, CreateDoctrineDto, CreateFittingDto, DoctrineDto,
+            FittingDto, FittingSkillDto, UserDoctrineReadinessDto,
+        },
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::AppError,
+        model::app::AppState,
+        service::doctrine::{
+            fitting::FittingService, readiness::DoctrineReadinessService, DoctrineService,
+        },
+    },
+};
+
+/// OpenAPI tag for doctrine and fitting endpoints.
+pub static DOCTRINE_TAG: &str = "doctrine";
+
+/// Imports a ship fitting in EFT format.
+///
+/// Parses the provided EFT text to validate it and stores the fitting, owned by the currently
+/// authenticated user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Fitting text in EFT format
+///
+/// # Returns
+/// - `Ok(FittingDto)` - 201 Created with the imported fitting
+/// - `Err(AppError)` - User not in session, invalid EFT text, or database error
+#[utoipa::path(
+    post,
+    path = "/api/fittings",
+    tag = DOCTRINE_TAG,
+    request_body = CreateFittingDto,
+    responses(
+        (status = 201, description = "Fitting imported", body = FittingDto),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 400, description = "Invalid EFT fitting", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_fitting(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateFittingDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let fitting = FittingService::new(&state.db)
+        .import_fitting(user.id, payload.eft)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(fitting)).into_response())
+}
+
+/// Retrieves all ship fittings.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<FittingDto>)` - All fittings ordered by ship hull and name
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/fittings",
+    tag = DOCTRINE_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving fittings", body = Vec<FittingDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_fittings(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let fittings = FittingService::new(&state.db).get_fittings().await?;
+
+    Ok((StatusCode::OK, Json(fittings)).into_response())
+}
+
+/// Deletes a ship fitting, removing it from any doctrines it belongs to.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `fitting_id` - ID of the fitting to delete
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the fitting was deleted
+/// - `Err(AppError)` - User not in session, fitting not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/fittings/{fitting_id}",
+    tag = DOCTRINE_TAG,
+    params(("fitting_id" = i32, Path, description = "ID of the fitting to delete")),
+    responses(
+        (status = 204, description = "Fitting deleted"),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 404, description = "User or fitting not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_fitting(
+    State(state): State<AppState>,
+    session: Session,
+    Path(fitting_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    FittingService::new(&state.db)
+        .delete_fitting(fitting_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves the skill levels required to fly a fitting.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `fitting_id` - ID of the fitting
+///
+/// # Returns
+/// - `Ok(Vec<FittingSkillDto>)` - Required skills ordered by skill ID
+/// - `Err(AppError)` - User not in session, fitting not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/fittings/{fitting_id}/skills",
+    tag = DOCTRINE_TAG,
+    params(("fitting_id" = i32, Path, description = "ID of the fitting")),
+    responses(
+        (status = 200, description = "Success when retrieving required skills", body = Vec<FittingSkillDto>),
+        (status = 404, description = "User or fitting not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_fitting_skills(
+    State(state): State<AppState>,
+    session: Session,
+    Path(fitting_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let skills = FittingService::new(&state.db)
+        .get_required_skills(fitting_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(skills)).into_response())
+}
+
+/// Replaces the skill levels required to fly a fitting.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `fitting_id` - ID of the fitting
+/// - `payload` - Required skills, each listed once with a level from 1 to 5
+///
+/// # Returns
+/// - `Ok(Vec<FittingSkillDto>)` - Required skills ordered by skill ID
+/// - `Err(AppError)` - User not in session, invalid requirement, fitting not found, or
+///   database error
+#[utoipa::path(
+    put,
+    path = "/api/fittings/{fitting_id}/skills",
+    tag = DOCTRINE_TAG,
+    params(("fitting_id" = i32, Path, description = "ID of the fitting")),
+    request_body = Vec<FittingSkillDto>,
+    responses(
+        (status = 200, description = "Required skills replaced", body = Vec<FittingSkillDto>),
+        (status = 400, description = "Skill listed twice or level outside 1 to 5", body = ErrorDto),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 404, description = "User or fitting not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn set_fitting_skills(
+    State(state): State<AppState>,
+    session: Session,
+    Path(fitting_id): Path<i32>,
+    Json(payload): Json<Vec<FittingSkillDto>>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let skills = FittingService::new(&state.db)
+        .set_required_skills(fitting_id, payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(skills)).into_response())
+}
+
+/// Creates a new doctrine without any fittings.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Doctrine name and optional description
+///
+/// # Returns
+/// - `Ok(DoctrineDto)` - 201 Created with the new doctrine
+/// - `Err(AppError)` - User not in session, duplicate doctrine name, or database error
+#[utoipa::path(
+    post,
+    path = "/api/doctrines",
+    tag = DOCTRINE_TAG,
+    request_body = CreateDoctrineDto,
+    responses(
+        (status = 201, description = "Doctrine created", body = DoctrineDto),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 409, description = "Doctrine name already in use", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_doctrine(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateDoctrineDto>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let doctrine = DoctrineService::new(&state.db)
+        .create_doctrine(payload.name, payload.description)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(doctrine)).into_response())
+}
+
+/// Retrieves all doctrines along with their fittings.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<DoctrineDto>)` - All doctrines ordered by name
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/doctrines",
+    tag = DOCTRINE_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving doctrines", body = Vec<DoctrineDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_doctrines(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let doctrines = DoctrineService::new(&state.db).get_doctrines().await?;
+
+    Ok((StatusCode::OK, Json(doctrines)).into_response())
+}
+
+/// Deletes a doctrine. Fittings belonging to the doctrine are kept.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `doctrine_id` - ID of the doctrine to delete
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the doctrine was deleted
+/// - `Err(AppError)` - User not in session, doctrine not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/doctrines/{doctrine_id}",
+    tag = DOCTRINE_TAG,
+    params(("doctrine_id" = i32, Path, description = "ID of the doctrine to delete")),
+    responses(
+        (status = 204, description = "Doctrine deleted"),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 404, description = "User or doctrine not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_doctrine(
+    State(state): State<AppState>,
+    session: Session,
+    Path(doctrine_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    DoctrineService::new(&state.db)
+        .delete_doctrine(doctrine_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Adds a fitting to a doctrine.
+///
+/// Adding a fitting that already belongs to the doctrine succeeds without changes.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `doctrine_id` - ID of the doctrine
+/// - `fitting_id` - ID of the fitting to add
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the fitting is part of the doctrine
+/// - `Err(AppError)` - User not in session, doctrine or fitting not found, or database error
+#[utoipa::path(
+    put,
+    path = "/api/doctrines/{doctrine_id}/fittings/{fitting_id}",
+    tag = DOCTRINE_TAG,
+    params(
+        ("doctrine_id" = i32, Path, description = "ID of the doctrine"),
+        ("fitting_id" = i32, Path, description = "ID of the fitting to add")
+    ),
+    responses(
+        (status = 204, description = "Fitting added to doctrine"),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 404, description = "User, doctrine, or fitting not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn add_doctrine_fitting(
+    State(state): State<AppState>,
+    session: Session,
+    Path((doctrine_id, fitting_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    DoctrineService::new(&state.db)
+        .add_fitting(doctrine_id, fitting_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Removes a fitting from a doctrine. The fitting itself is kept.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `doctrine_id` - ID of the doctrine
+/// - `fitting_id` - ID of the fitting to remove
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the fitting was removed
+/// - `Err(AppError)` - User not in session, fitting not in doctrine, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/doctrines/{doctrine_id}/fittings/{fitting_id}",
+    tag = DOCTRINE_TAG,
+    params(
+        ("doctrine_id" = i32, Path, description = "ID of the doctrine"),
+        ("fitting_id" = i32, Path, description = "ID of the fitting to remove")
+    ),
+    responses(
+        (status = 204, description = "Fitting removed from doctrine"),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 404, description = "User not found or fitting not in doctrine", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn remove_doctrine_fitting(
+    State(state): State<AppState>,
+    session: Session,
+    Path((doctrine_id, fitting_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    DoctrineService::new(&state.db)
+        .remove_fitting(doctrine_id, fitting_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Reports which fittings of a doctrine each of the current user's characters can fly.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `doctrine_id` - ID of the doctrine
+///
+/// # Returns
+/// - `Ok(UserDoctrineReadinessDto)` - The user's characters with the skills they are missing
+///   for each fitting
+/// - `Err(AppError)` - User not in session, doctrine not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/doctrines/{doctrine_id}/readiness",
+    tag = DOCTRINE_TAG,
+    params(("doctrine_id" = i32, Path, description = "ID of the doctrine")),
+    responses(
+        (status = 200, description = "Success when retrieving readiness", body = UserDoctrineReadinessDto),
+        (status = 404, description = "User or doctrine not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_doctrine_readiness(
+    State(state): State<AppState>,
+    session: Session,
+    Path(doctrine_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let readiness = DoctrineReadinessService::new(&state.db, &state.esi_provider, &state.cipher)
+        .get_user_readiness(user.id, doctrine_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(readiness)).into_response())
+}
+
+/// Reports the share of a corporation's characters able to fly a doctrine.
+///
+/// Only characters whose skills are tracked count towards the readiness percentages.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `corporation_id` - EVE Online ID of the corporation
+/// - `doctrine_id` - ID of the doctrine
+///
+/// # Returns
+/// - `Ok(CorporationDoctrineReadinessDto)` - Pilot counts and readiness percentages for the
+///   doctrine and each of its fittings
+/// - `Err(AppError)` - User not in session, corporation or doctrine not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/corporations/{corporation_id}/doctrines/{doctrine_id}/readiness",
+    tag = DOCTRINE_TAG,
+    params(
+        ("corporation_id" = i64, Path, description = "EVE Online corporation ID"),
+        ("doctrine_id" = i32, Path, description = "ID of the doctrine")
+    ),
+    responses(
+        (status = 200, description = "Success when retrieving readiness", body = CorporationDoctrineReadinessDto),
+        (status = 403, description = "Missing the manage_members permission", body = ErrorDto),
+        (status = 404, description = "User, corporation, or doctrine not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_corporation_doctrine_readiness(
+    State(state): State<AppState>,
+    session: Session,
+    Path((corporation_id, doctrine_id)): Path<(i64, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let readiness = DoctrineReadinessService::new(&state.db, &state.esi_provider, &state.cipher)
+        .get_corporation_readiness(corporation_id, doctrine_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(readiness)).into_response())
+}
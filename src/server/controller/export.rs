@@ -0,0 +1,174 @@
+//! Admin export controller endpoints.
+//!
+//! This module provides HTTP endpoints streaming large datasets as NDJSON (one JSON object
+//! per line). Responses are sent while the export is still being read from the database, so
+//! large exports neither buffer in memory nor wait long enough for reverse proxies to time
+//! out before the first byte is sent. If object storage is configured, exports can instead be
+//! stored in the bucket and downloaded through a presigned URL. Users can also request an
+//! archive of everything Bifrost stores about them, which is stored by a worker job that
+//! notifies them when it is ready.
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        export::{StoredExportDto, UserDataExportQueuedDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::{export::ExportError, push::PushError, AppError},
+        model::{app::AppState, worker::WorkerJob},
+        service::export::{ExportService, NDJSON_CONTENT_TYPE},
+        worker::payload::job_id,
+    },
+};
+
+/// OpenAPI tag for export endpoints.
+pub static EXPORT_TAG: &str = "export";
+
+/// Streams every character as NDJSON.
+///
+/// Each line is a `CharacterExportDto` with the character's corporation, alliance, and the ID
+/// of the user owning the character, if any. If reading the export fails partway through, the
+/// response is aborted rather than ending cleanly, so truncated exports are detectable.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Body)` - 200 OK streaming the NDJSON export
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/export/characters",
+    tag = EXPORT_TAG,
+    responses(
+        (status = 200, description = "NDJSON stream of CharacterExportDto lines", content_type = "application/x-ndjson", body = String),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn export_characters(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let stream = ExportService::new(&state.db).stream_characters();
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, NDJSON_CONTENT_TYPE),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"characters.ndjson\"",
+            ),
+        ],
+        Body::from_stream(stream),
+    )
+        .into_response())
+}
+
+/// Stores every character as NDJSON in object storage and returns a download URL.
+///
+/// The export has the same format as `GET /api/admin/export/characters`. The returned URL is
+/// presigned for one hour and can be opened without a session, so large exports are
+/// downloaded from the bucket instead of through Bifrost.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and object storage
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(StoredExportDto)` - 201 Created with the presigned download URL
+/// - `Err(AppError)` - User not in session, object storage not configured, database error, or
+///   upload failure
+#[utoipa::path(
+    post,
+    path = "/api/admin/export/characters/stored",
+    tag = EXPORT_TAG,
+    responses(
+        (status = 201, description = "Export stored, download it from the returned URL", body = StoredExportDto),
+        (status = 404, description = "User not found or object storage not enabled", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn store_characters_export(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let storage = state
+        .object_storage
+        .as_ref()
+        .ok_or(ExportError::ObjectStorageDisabled)?;
+
+    let export = ExportService::new(&state.db)
+        .store_characters(storage)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(export)).into_response())
+}
+
+/// Requests an archive of everything Bifrost stores about the current user.
+///
+/// Queues a job assembling the user's characters, affiliation history, preferences, consents,
+/// and notifications into a JSON archive. Once stored, the user receives a push notification
+/// linking to a download URL valid for 7 days. Requesting again while an export is queued
+/// returns the queued job.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection, object storage, Web Push
+///   settings, and worker queue
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(UserDataExportQueuedDto)` - 202 Accepted with the ID of the export job
+/// - `Err(AppError)` - User not in session, object storage or push notifications disabled, or
+///   worker queue error
+#[utoipa::path(
+    get,
+    path = "/api/user/export",
+    tag = EXPORT_TAG,
+    responses(
+        (status = 202, description = "Export queued, a notification is sent when it is ready", body = UserDataExportQueuedDto),
+        (status = 404, description = "User not found, or object storage or push notifications disabled", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn request_user_data_export(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    if state.object_storage.is_none() {
+        return Err(ExportError::ObjectStorageDisabled.into());
+    }
+    // The download link is only delivered through the notification
+    if state.push.vapid_key.is_none() {
+        return Err(PushError::PushDisabled.into());
+    }
+
+    let job = WorkerJob::ExportUserData { user_id: user.id };
+    let job_id = job_id(&job)?;
+    // Exports already queued are deduplicated and keep their ID
+    state.worker.queue.push(job).await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(UserDataExportQueuedDto { job_id }),
+    )
+        .into_response())
+}
@@ -0,0 +1,510 @@
+//! Group controller endpoints.
+//!
+//! This module provides HTTP endpoints for users to list, join, and leave groups, and for admins
+//! to create and delete groups, add and remove members, approve requests to join, and restrict
+//! groups to users affiliated with certain corporations, alliances, or factions. Open groups can
+//! be joined directly, request-to-join groups hold requests until an admin approves them, and
+//! hidden groups are only listed to their members. All endpoints require an active session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        group::{
+            CreateGroupDto, CreateGroupRuleDto, GroupDto, GroupMemberDto, GroupRuleDto,
+            UserGroupDto,
+        },
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::AppError,
+        model::app::AppState,
+        service::group::{rule::GroupRuleService, GroupService},
+    },
+};
+
+/// OpenAPI tag for group endpoints.
+pub static GROUP_TAG: &str = "group";
+
+/// Retrieves the groups listed to the user with the user's membership in each.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<UserGroupDto>)` - 200 OK with the open and request-to-join groups, and the hidden
+///   groups the user is a member of
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/groups",
+    tag = GROUP_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving the groups listed to the user", body = Vec<UserGroupDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_user_groups(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let groups = GroupService::new(&state.db)
+        .get_user_groups(user.id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(groups)).into_response())
+}
+
+/// Joins a group, or requests to join it if an admin must approve new members.
+///
+/// Joining a group the user is already a member of or has requested to join returns their
+/// membership unchanged.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+///
+/// # Returns
+/// - `Ok(UserGroupDto)` - 200 OK with the group and the user's membership
+/// - `Err(AppError)` - User not in session, group not found or hidden, user not qualifying for
+///   the group, or database error
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/join",
+    tag = GROUP_TAG,
+    params(("group_id" = i32, Path, description = "ID of the group")),
+    responses(
+        (status = 200, description = "Group joined or request to join sent", body = UserGroupDto),
+        (status = 403, description = "None of the user's characters qualifies for the group", body = ErrorDto),
+        (status = 404, description = "User or group not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn join_group(
+    State(state): State<AppState>,
+    session: Session,
+    Path(group_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let group = GroupService::new(&state.db).join(user.id, group_id).await?;
+
+    Ok((StatusCode::OK, Json(group)).into_response())
+}
+
+/// Leaves a group, or cancels the user's pending request to join it.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the membership or request was removed
+/// - `Err(AppError)` - User not in session, no membership in the group, or database error
+#[utoipa::path(
+    post,
+    path = "/api/groups/{group_id}/leave",
+    tag = GROUP_TAG,
+    params(("group_id" = i32, Path, description = "ID of the group")),
+    responses(
+        (status = 204, description = "Group left or request to join cancelled"),
+        (status = 404, description = "User or membership not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn leave_group(
+    State(state): State<AppState>,
+    session: Session,
+    Path(group_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    GroupService::new(&state.db)
+        .leave(user.id, group_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves all groups with their member and pending request counts.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<GroupDto>)` - 200 OK with all groups ordered by name
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/groups",
+    tag = GROUP_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving all groups", body = Vec<GroupDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_groups(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let groups = GroupService::new(&state.db).get_groups().await?;
+
+    Ok((StatusCode::OK, Json(groups)).into_response())
+}
+
+/// Creates a group.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Name, description, and join policy of the group
+///
+/// # Returns
+/// - `Ok(GroupDto)` - 201 Created with the created group
+/// - `Err(AppError)` - User not in session, invalid or duplicate group, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/groups",
+    tag = GROUP_TAG,
+    request_body = CreateGroupDto,
+    responses(
+        (status = 201, description = "Group created", body = GroupDto),
+        (status = 400, description = "Invalid group name or description, or name already taken", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_group(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateGroupDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let group = GroupService::new(&state.db)
+        .create_group(user.id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(group)).into_response())
+}
+
+/// Deletes a group along with its members and pending requests.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the group was deleted
+/// - `Err(AppError)` - User not in session, group not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/groups/{group_id}",
+    tag = GROUP_TAG,
+    params(("group_id" = i32, Path, description = "ID of the group")),
+    responses(
+        (status = 204, description = "Group deleted"),
+        (status = 404, description = "User or group not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_group(
+    State(state): State<AppState>,
+    session: Session,
+    Path(group_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    GroupService::new(&state.db)
+        .delete_group(user.id, group_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves the members and pending requests of a group, oldest first.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+///
+/// # Returns
+/// - `Ok(Vec<GroupMemberDto>)` - 200 OK with the members and pending requests
+/// - `Err(AppError)` - User not in session, group not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/groups/{group_id}/members",
+    tag = GROUP_TAG,
+    params(("group_id" = i32, Path, description = "ID of the group")),
+    responses(
+        (status = 200, description = "Success when retrieving the group's members and pending requests", body = Vec<GroupMemberDto>),
+        (status = 404, description = "User or group not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_group_members(
+    State(state): State<AppState>,
+    session: Session,
+    Path(group_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let members = GroupService::new(&state.db).get_members(group_id).await?;
+
+    Ok((StatusCode::OK, Json(members)).into_response())
+}
+
+/// Adds a user to a group as a member, approving any pending request of the user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+/// - `user_id` - ID of the user to add
+///
+/// # Returns
+/// - `Ok(GroupMemberDto)` - 200 OK with the user's membership
+/// - `Err(AppError)` - User not in session, group or user not found, user not qualifying for
+///   the group, or database error
+#[utoipa::path(
+    put,
+    path = "/api/admin/groups/{group_id}/members/{user_id}",
+    tag = GROUP_TAG,
+    params(
+        ("group_id" = i32, Path, description = "ID of the group"),
+        ("user_id" = i32, Path, description = "ID of the user to add to the group")
+    ),
+    responses(
+        (status = 200, description = "User added to the group", body = GroupMemberDto),
+        (status = 403, description = "None of the user's characters qualifies for the group", body = ErrorDto),
+        (status = 404, description = "User or group not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn add_group_member(
+    State(state): State<AppState>,
+    session: Session,
+    Path((group_id, user_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let member = GroupService::new(&state.db)
+        .add_member(user.id, group_id, user_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(member)).into_response())
+}
+
+/// Approves a user's pending request to join a group.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+/// - `user_id` - ID of the user who requested to join
+///
+/// # Returns
+/// - `Ok(GroupMemberDto)` - 200 OK with the user's membership
+/// - `Err(AppError)` - User not in session, no request to join, user no longer qualifying for
+///   the group, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/groups/{group_id}/members/{user_id}/approve",
+    tag = GROUP_TAG,
+    params(
+        ("group_id" = i32, Path, description = "ID of the group"),
+        ("user_id" = i32, Path, description = "ID of the user who requested to join")
+    ),
+    responses(
+        (status = 200, description = "Request to join approved", body = GroupMemberDto),
+        (status = 403, description = "None of the user's characters qualifies for the group", body = ErrorDto),
+        (status = 404, description = "User or request to join not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn approve_group_member(
+    State(state): State<AppState>,
+    session: Session,
+    Path((group_id, user_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let member = GroupService::new(&state.db)
+        .approve_member(user.id, group_id, user_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(member)).into_response())
+}
+
+/// Removes a member from a group, or rejects their pending request to join.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+/// - `user_id` - ID of the user to remove
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the membership or request was removed
+/// - `Err(AppError)` - User not in session, no membership in the group, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/groups/{group_id}/members/{user_id}",
+    tag = GROUP_TAG,
+    params(
+        ("group_id" = i32, Path, description = "ID of the group"),
+        ("user_id" = i32, Path, description = "ID of the user to remove from the group")
+    ),
+    responses(
+        (status = 204, description = "Member removed or request to join rejected"),
+        (status = 404, description = "User or membership not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn remove_group_member(
+    State(state): State<AppState>,
+    session: Session,
+    Path((group_id, user_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    GroupService::new(&state.db)
+        .remove_member(user.id, group_id, user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves the eligibility rules of a group.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+///
+/// # Returns
+/// - `Ok(Vec<GroupRuleDto>)` - 200 OK with the rules, empty if every user qualifies
+/// - `Err(AppError)` - User not in session, group not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/groups/{group_id}/rules",
+    tag = GROUP_TAG,
+    params(("group_id" = i32, Path, description = "ID of the group")),
+    responses(
+        (status = 200, description = "Success when retrieving the group's eligibility rules", body = Vec<GroupRuleDto>),
+        (status = 404, description = "User or group not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_group_rules(
+    State(state): State<AppState>,
+    session: Session,
+    Path(group_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let rules = GroupRuleService::new(&state.db).get_rules(group_id).await?;
+
+    Ok((StatusCode::OK, Json(rules)).into_response())
+}
+
+/// Restricts a group to users with a character in a corporation, alliance, or faction.
+///
+/// A group with several rules admits users matching any of them. Existing members are
+/// re-evaluated once their characters' affiliations are next refreshed.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+/// - `payload` - Kind of affiliation and EVE Online ID of the entity it matches
+///
+/// # Returns
+/// - `Ok(GroupRuleDto)` - 201 Created with the added rule
+/// - `Err(AppError)` - User not in session, invalid or duplicate rule, group not found, or
+///   database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/groups/{group_id}/rules",
+    tag = GROUP_TAG,
+    params(("group_id" = i32, Path, description = "ID of the group")),
+    request_body = CreateGroupRuleDto,
+    responses(
+        (status = 201, description = "Rule added", body = GroupRuleDto),
+        (status = 400, description = "Invalid entity ID, or the group already has the rule", body = ErrorDto),
+        (status = 404, description = "User or group not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn add_group_rule(
+    State(state): State<AppState>,
+    session: Session,
+    Path(group_id): Path<i32>,
+    Json(payload): Json<CreateGroupRuleDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let rule = GroupRuleService::new(&state.db)
+        .add_rule(user.id, group_id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(rule)).into_response())
+}
+
+/// Removes an eligibility rule from a group.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `group_id` - ID of the group
+/// - `rule_id` - ID of the rule
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the rule was removed
+/// - `Err(AppError)` - User not in session, rule not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/groups/{group_id}/rules/{rule_id}",
+    tag = GROUP_TAG,
+    params(
+        ("group_id" = i32, Path, description = "ID of the group"),
+        ("rule_id" = i32, Path, description = "ID of the rule")
+    ),
+    responses(
+        (status = 204, description = "Rule removed"),
+        (status = 404, description = "User or rule not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn remove_group_rule(
+    State(state): State<AppState>,
+    session: Session,
+    Path((group_id, rule_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    GroupRuleService::new(&state.db)
+        .remove_rule(user.id, group_id, rule_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,98 @@
+//! Image proxy controller endpoints.
+//!
+//! This module provides the HTTP endpoint serving EVE Online portraits and logos. If
+//! `IMAGE_CACHE_DIR` is configured images are proxied through Bifrost and cached on disk;
+//! otherwise the endpoint redirects to the EVE image server, so clients can always use the
+//! same URLs.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Redirect},
+};
+use serde::Deserialize;
+
+use crate::{
+    model::api::ErrorDto,
+    server::{
+        error::AppError,
+        model::app::AppState,
+        service::image::{ImageService, DEFAULT_IMAGE_SIZE},
+    },
+};
+
+/// OpenAPI tag for image endpoints.
+pub static IMAGE_TAG: &str = "image";
+
+/// Cache-Control header value for proxied images.
+///
+/// Portraits and logos rarely change, so browsers may reuse them for a week without asking
+/// the server again.
+static IMAGE_CACHE_CONTROL: &str = "public, max-age=604800";
+
+/// Query parameters for the image endpoint.
+///
+/// # Fields
+/// - `size` - Image size in pixels
+#[derive(Deserialize)]
+pub struct ImageParams {
+    /// Image size in pixels, one of 32, 64, 128, 256, 512, or 1024; defaults to 128.
+    pub size: Option<u32>,
+}
+
+/// Serves an EVE Online portrait, logo, or type icon.
+///
+/// This endpoint is public. If the image proxy is disabled it redirects to the EVE image
+/// server instead of serving the image itself.
+///
+/// # Arguments
+/// - `state` - Application state containing the image proxy settings
+/// - `category` - Image category, one of `characters`, `corporations`, `alliances`, or `types`
+/// - `id` - EVE Online ID of the entity
+/// - `params` - Query parameters with the image size
+///
+/// # Returns
+/// - `Ok(Response)` - 200 OK with the image, or 307 Temporary Redirect if the proxy is disabled
+/// - `Err(AppError)` - Unknown category, unsupported size, missing image, or fetch failed
+#[utoipa::path(
+    get,
+    path = "/img/{category}/{id}",
+    tag = IMAGE_TAG,
+    params(
+        ("category" = String, Path, description = "Image category: characters, corporations, alliances, or types"),
+        ("id" = i64, Path, description = "EVE Online ID of the entity"),
+        ("size" = Option<u32>, Query, description = "Image size in pixels (default 128)"),
+    ),
+    responses(
+        (status = 200, description = "Success when retrieving the image", content_type = "image/png"),
+        (status = 307, description = "Redirect to the EVE image server if the proxy is disabled"),
+        (status = 400, description = "Unsupported image size", body = ErrorDto),
+        (status = 404, description = "Unknown category or image not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_image(
+    State(state): State<AppState>,
+    Path((category, id)): Path<(String, i64)>,
+    params: Query<ImageParams>,
+) -> Result<impl IntoResponse, AppError> {
+    let size = params.0.size.unwrap_or(DEFAULT_IMAGE_SIZE);
+
+    let Some(image) = ImageService::new(&state.image_proxy)
+        .get_image(&category, id, size)
+        .await?
+    else {
+        let url = ImageService::upstream_url(&category, id, size)?;
+        return Ok(Redirect::temporary(&url).into_response());
+    };
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, image.content_type),
+            (header::CACHE_CONTROL, IMAGE_CACHE_CONTROL),
+        ],
+        image.bytes,
+    )
+        .into_response())
+}
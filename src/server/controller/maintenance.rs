@@ -0,0 +1,98 @@
+//! Maintenance controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to see whether read-only mode is enabled and
+//! to toggle it around database maintenance such as migrations or failovers. While read-only
+//! mode is enabled, requests writing to the database are rejected and workers pause. These
+//! endpoints require an active session.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use dioxus_logger::tracing;
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, maintenance::ReadOnlyStatusDto},
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+    },
+};
+
+/// OpenAPI tag for maintenance endpoints.
+pub static MAINTENANCE_TAG: &str = "maintenance";
+
+/// Retrieves whether read-only mode is enabled.
+///
+/// # Arguments
+/// - `state` - Application state containing the read-only mode flag
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(ReadOnlyStatusDto)` - 200 OK with whether read-only mode is enabled
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/read-only",
+    tag = MAINTENANCE_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving read-only mode", body = ReadOnlyStatusDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_read_only(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ReadOnlyStatusDto {
+            enabled: state.read_only.is_enabled(),
+        }),
+    )
+        .into_response())
+}
+
+/// Enables or disables read-only mode.
+///
+/// Enabling read-only mode rejects requests writing to the database with 503 Service
+/// Unavailable and pauses workers, leaving queued jobs for when it is disabled. The mode isn't
+/// persisted, so it resets to `READ_ONLY_MODE` when the server restarts.
+///
+/// # Arguments
+/// - `state` - Application state containing the read-only mode flag
+/// - `session` - User's session containing their user ID
+/// - `payload` - Whether read-only mode is enabled
+///
+/// # Returns
+/// - `Ok(ReadOnlyStatusDto)` - 200 OK with the new read-only mode
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    put,
+    path = "/api/admin/read-only",
+    tag = MAINTENANCE_TAG,
+    request_body = ReadOnlyStatusDto,
+    responses(
+        (status = 200, description = "Read-only mode updated", body = ReadOnlyStatusDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn set_read_only(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<ReadOnlyStatusDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let was_enabled = state.read_only.set(payload.enabled);
+    if was_enabled != payload.enabled {
+        tracing::info!(
+            user_id = %user.id,
+            enabled = %payload.enabled,
+            "Changed read-only mode"
+        );
+    }
+
+    Ok((StatusCode::OK, Json(payload)).into_response())
+}
@@ -0,0 +1,281 @@
+//! Member list controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to list users and characters filtered by
+//! character name, corporation, alliance, or tag, to save the filters they use often, and to
+//! run bulk actions on the members they selected. Bulk actions are queued as worker jobs whose
+//! IDs are returned so their outcome can be followed. All endpoints require an active session.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        member::{
+            MemberBulkAction, MemberBulkActionDto, MemberBulkResultDto, MemberCharacterDto,
+            MemberFilterDto, MemberUserDto, SaveMemberFilterDto, SavedMemberFilterDto,
+        },
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::{push::PushError, AppError},
+        model::app::AppState,
+        service::member::MemberService,
+        worker::payload::job_id,
+    },
+};
+
+/// OpenAPI tag for member list endpoints.
+pub static MEMBER_TAG: &str = "member";
+
+/// Retrieves the users with any character matching the filter.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `filter` - Query parameters with the character name, corporation, alliance, and tag
+///
+/// # Returns
+/// - `Ok(Vec<MemberUserDto>)` - 200 OK with the matching users and their main character
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/members/users",
+    tag = MEMBER_TAG,
+    params(
+        ("name" = Option<String>, Query, description = "Text a character name must contain, ignoring case"),
+        ("corporation_id" = Option<i64>, Query, description = "EVE Online corporation ID of a character"),
+        ("alliance_id" = Option<i64>, Query, description = "EVE Online alliance ID of a character"),
+        ("tag" = Option<String>, Query, description = "Tag carried by the user or a character")
+    ),
+    responses(
+        (status = 200, description = "Success when listing users", body = Vec<MemberUserDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_member_users(
+    State(state): State<AppState>,
+    session: Session,
+    filter: Query<MemberFilterDto>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let users = MemberService::new(&state.db).get_users(filter.0).await?;
+
+    Ok((StatusCode::OK, Json(users)).into_response())
+}
+
+/// Retrieves the owned characters matching the filter.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `filter` - Query parameters with the character name, corporation, alliance, and tag
+///
+/// # Returns
+/// - `Ok(Vec<MemberCharacterDto>)` - 200 OK with the matching characters
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/members/characters",
+    tag = MEMBER_TAG,
+    params(
+        ("name" = Option<String>, Query, description = "Text the character name must contain, ignoring case"),
+        ("corporation_id" = Option<i64>, Query, description = "EVE Online corporation ID of the character"),
+        ("alliance_id" = Option<i64>, Query, description = "EVE Online alliance ID of the character"),
+        ("tag" = Option<String>, Query, description = "Tag carried by the character or its owner")
+    ),
+    responses(
+        (status = 200, description = "Success when listing characters", body = Vec<MemberCharacterDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_member_characters(
+    State(state): State<AppState>,
+    session: Session,
+    filter: Query<MemberFilterDto>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let characters = MemberService::new(&state.db)
+        .get_characters(filter.0)
+        .await?;
+
+    Ok((StatusCode::OK, Json(characters)).into_response())
+}
+
+/// Retrieves the member list filters the current user saved.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<SavedMemberFilterDto>)` - 200 OK with the saved filters
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/members/filters",
+    tag = MEMBER_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving saved filters", body = Vec<SavedMemberFilterDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_member_filters(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let filters = MemberService::new(&state.db).get_filters(user.id).await?;
+
+    Ok((StatusCode::OK, Json(filters)).into_response())
+}
+
+/// Saves a member list filter for the current user.
+///
+/// Saving a filter with the name of one already saved for the list replaces it.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - List, name, and criteria of the filter
+///
+/// # Returns
+/// - `Ok(SavedMemberFilterDto)` - 200 OK with the saved filter
+/// - `Err(AppError)` - User not in session, invalid name, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/members/filters",
+    tag = MEMBER_TAG,
+    request_body = SaveMemberFilterDto,
+    responses(
+        (status = 200, description = "Filter saved", body = SavedMemberFilterDto),
+        (status = 400, description = "Empty or overlong filter name", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn save_member_filter(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<SaveMemberFilterDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let filter = MemberService::new(&state.db)
+        .save_filter(user.id, payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(filter)).into_response())
+}
+
+/// Deletes one of the current user's saved member list filters.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `filter_id` - ID of the filter
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the filter was deleted
+/// - `Err(AppError)` - User not in session, filter not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/members/filters/{filter_id}",
+    tag = MEMBER_TAG,
+    params(("filter_id" = i32, Path, description = "ID of the saved filter")),
+    responses(
+        (status = 204, description = "Filter deleted"),
+        (status = 404, description = "User or filter not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_member_filter(
+    State(state): State<AppState>,
+    session: Session,
+    Path(filter_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    MemberService::new(&state.db)
+        .delete_filter(user.id, filter_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Runs a bulk action on the selected users or characters.
+///
+/// Force refreshing queues info and affiliation updates for the selected characters, or for
+/// every character of the selected users. Notifications are queued for the selected users, or
+/// for the owners of the selected characters. Each selected member is reported with the IDs
+/// of its queued jobs, which can be looked up with the worker job status endpoint, or the
+/// reason nothing was queued for it.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection, Web Push settings, and
+///   worker queue
+/// - `session` - User's session containing their user ID
+/// - `payload` - List, selected member IDs, and action to run
+///
+/// # Returns
+/// - `Ok(Vec<MemberBulkResultDto>)` - 202 Accepted with the result per selected member
+/// - `Err(AppError)` - User not in session, invalid action, push disabled, database, or worker
+///   queue error
+#[utoipa::path(
+    post,
+    path = "/api/admin/members/bulk",
+    tag = MEMBER_TAG,
+    request_body = MemberBulkActionDto,
+    responses(
+        (status = 202, description = "Bulk action queued", body = Vec<MemberBulkResultDto>),
+        (status = 400, description = "No members, too many members, or notification without a title", body = ErrorDto),
+        (status = 404, description = "User not found or push notifications disabled", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn run_member_bulk_action(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<MemberBulkActionDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    if matches!(payload.action, MemberBulkAction::SendNotification { .. })
+        && state.push.vapid_key.is_none()
+    {
+        return Err(PushError::PushDisabled.into());
+    }
+
+    let items = MemberService::new(&state.db)
+        .resolve_bulk_action(user.id, payload)
+        .await?;
+
+    let mut results = Vec::with_capacity(items.len());
+    for item in items {
+        let mut job_ids = Vec::with_capacity(item.jobs.len());
+        for job in item.jobs {
+            job_ids.push(job_id(&job)?);
+            // Jobs already queued are deduplicated and keep their ID
+            state.worker.queue.push(job).await?;
+        }
+
+        results.push(MemberBulkResultDto {
+            id: item.id,
+            job_ids,
+            error: item.error,
+        });
+    }
+
+    Ok((StatusCode::ACCEPTED, Json(results)).into_response())
+}
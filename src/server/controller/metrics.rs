@@ -0,0 +1,51 @@
+//! Prometheus metrics controller endpoint.
+//!
+//! This module serves worker pool and queue metrics in the Prometheus text exposition format
+//! so operators can monitor job throughput and backlog without tailing logs. The endpoint is
+//! public so Prometheus can scrape it without a session; restrict access to it at the reverse
+//! proxy if the metrics shouldn't be exposed.
+
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+
+use crate::{
+    model::api::ErrorDto,
+    server::{error::AppError, model::app::AppState},
+};
+
+/// OpenAPI tag for metrics endpoints.
+pub static METRICS_TAG: &str = "metrics";
+
+/// Content type of the Prometheus text exposition format.
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Serves worker metrics for Prometheus.
+///
+/// # Arguments
+/// - `state` - Application state containing the worker system
+///
+/// # Returns
+/// - `Ok(String)` - 200 OK with the metrics in the Prometheus text exposition format
+/// - `Err(AppError)` - Failed to read the queue depth from Redis
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = METRICS_TAG,
+    responses(
+        (status = 200, description = "Worker metrics in the Prometheus text format", content_type = "text/plain", body = String),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_metrics(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+    let metrics = state.worker.metrics_snapshot().await?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        metrics.to_prometheus(),
+    )
+        .into_response())
+}
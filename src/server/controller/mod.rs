@@ -1,10 +1,55 @@
 //! HTTP controller endpoints for the Bifrost web API.
 //!
-//! This module contains Axum handlers for authentication, user management, and related
-//! functionality. Controllers handle HTTP requests, validate inputs, interact with services,
-//! and return appropriate HTTP responses. They integrate with tower-sessions for session
-//! management and use utoipa for OpenAPI documentation.
+//! This module contains Axum handlers for character affiliation history, admin tags and notes,
+//! announcements, approval of sensitive admin actions, authentication, user bans, instance
+//! branding, user management, campaigns, data-sharing consent, corporation member lists, admin
+//! dashboards, the data access API for BI tools, background task diagnostics, doctrines, admin
+//! exports and user data downloads, groups users join and leave, proxied EVE images, read-only mode
+//! for database maintenance, admin member lists with saved filters and bulk actions, the onboarding
+//! checklist, admin-edited pages, recruitment, re-authentication campaigns, roles and permissions,
+//! scheduler previews and data freshness reports, screening, entity search, skill plans, telemetry,
+//! user preferences, push notifications, webhooks notified of character ownership changes,
+//! embeddable widgets, worker dead-letter replay, Prometheus worker metrics, installable web app
+//! files, and related functionality.
+//! Controllers handle HTTP requests, validate inputs, interact with services, and return
+//! appropriate HTTP responses. They integrate with tower-sessions for session management and
+//! use utoipa for OpenAPI documentation.
 
+pub mod affiliation_history;
+pub mod annotation;
+pub mod announcement;
+pub mod approval;
 pub mod auth;
+pub mod ban;
+pub mod branding;
+pub mod campaign;
+pub mod consent;
+pub mod corporation_member;
+pub mod dashboard;
+pub mod data_api;
+pub mod diagnostics;
+pub mod doctrine;
+pub mod export;
+pub mod group;
+pub mod image;
+pub mod maintenance;
+pub mod member;
+pub mod metrics;
+pub mod onboarding;
+pub mod page;
+pub mod preference;
+pub mod push;
+pub mod pwa;
+pub mod reauth_campaign;
+pub mod recruitment;
+pub mod role;
+pub mod scheduler;
+pub mod screening;
+pub mod search;
+pub mod skill_plan;
+pub mod telemetry;
 pub mod user;
 pub mod util;
+pub mod webhook;
+pub mod widget;
+pub mod worker;
@@ -0,0 +1,235 @@
+//! Onboarding controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to define the onboarding checklist for new
+//! members, for officers to list the users who haven't completed it, and for users to retrieve
+//! their checklist and mark the steps they completed themselves. All endpoints require an
+//! active session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        onboarding::{
+            CreateOnboardingStepDto, IncompleteOnboardingDto, OnboardingChecklistItemDto,
+            OnboardingStepDto,
+        },
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::onboarding::OnboardingService,
+    },
+};
+
+/// OpenAPI tag for onboarding endpoints.
+pub static ONBOARDING_TAG: &str = "onboarding";
+
+/// Defines a step at the end of the onboarding checklist.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Title, description, kind, and target of the step
+///
+/// # Returns
+/// - `Ok(OnboardingStepDto)` - 201 Created with the created step
+/// - `Err(AppError)` - User not in session, invalid step, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/onboarding/steps",
+    tag = ONBOARDING_TAG,
+    request_body = CreateOnboardingStepDto,
+    responses(
+        (status = 201, description = "Step created", body = OnboardingStepDto),
+        (status = 400, description = "Empty title, or missing or invalid target", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_onboarding_step(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateOnboardingStepDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let step = OnboardingService::new(&state.db)
+        .create_step(user.id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(step)).into_response())
+}
+
+/// Retrieves all onboarding steps in checklist order.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<OnboardingStepDto>)` - 200 OK with all steps
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/onboarding/steps",
+    tag = ONBOARDING_TAG,
+    responses(
+        (status = 200, description = "Success when listing steps", body = Vec<OnboardingStepDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_onboarding_steps(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let steps = OnboardingService::new(&state.db).get_steps().await?;
+
+    Ok((StatusCode::OK, Json(steps)).into_response())
+}
+
+/// Deletes an onboarding step along with its completions.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `step_id` - ID of the step
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the step was deleted
+/// - `Err(AppError)` - User not in session, step not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/onboarding/steps/{step_id}",
+    tag = ONBOARDING_TAG,
+    params(("step_id" = i32, Path, description = "ID of the onboarding step")),
+    responses(
+        (status = 204, description = "Step deleted"),
+        (status = 404, description = "User or step not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_onboarding_step(
+    State(state): State<AppState>,
+    session: Session,
+    Path(step_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    OnboardingService::new(&state.db)
+        .delete_step(step_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves the users who haven't completed every onboarding step.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<IncompleteOnboardingDto>)` - 200 OK with the users and the steps they're missing
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/onboarding/incomplete",
+    tag = ONBOARDING_TAG,
+    responses(
+        (status = 200, description = "Success when listing users with incomplete onboarding", body = Vec<IncompleteOnboardingDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_incomplete_onboarding(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let users = OnboardingService::new(&state.db).get_incomplete().await?;
+
+    Ok((StatusCode::OK, Json(users)).into_response())
+}
+
+/// Retrieves the current user's onboarding checklist.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<OnboardingChecklistItemDto>)` - 200 OK with every step and whether the user
+///   completed it
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/user/onboarding",
+    tag = ONBOARDING_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving the checklist", body = Vec<OnboardingChecklistItemDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_onboarding_checklist(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let checklist = OnboardingService::new(&state.db)
+        .get_checklist(user.id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(checklist)).into_response())
+}
+
+/// Marks an onboarding step the current user completed themselves as completed.
+///
+/// Only steps to read a page, join Discord, or other manual steps can be completed this way;
+/// linking alts and granting scopes are completed automatically.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `step_id` - ID of the step
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the step was completed
+/// - `Err(AppError)` - User not in session, step not found or completed automatically, or
+///   database error
+#[utoipa::path(
+    post,
+    path = "/api/user/onboarding/{step_id}/complete",
+    tag = ONBOARDING_TAG,
+    params(("step_id" = i32, Path, description = "ID of the onboarding step")),
+    responses(
+        (status = 204, description = "Step completed"),
+        (status = 400, description = "Step is completed automatically", body = ErrorDto),
+        (status = 404, description = "User or step not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn complete_onboarding_step(
+    State(state): State<AppState>,
+    session: Session,
+    Path(step_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    OnboardingService::new(&state.db)
+        .complete_step(user.id, step_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,293 @@
+//! Page controller endpoints.
+//!
+//! This module provides HTTP endpoints for the Markdown pages admins publish for rules, SRP
+//! policy, join instructions, and similar information. Reading pages is public, either as JSON
+//! or as a server-rendered HTML page at `/pages/{slug}`, while saving, deleting, and restoring
+//! pages requires an active session.
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{Html, IntoResponse},
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        page::{PageDto, PageRevisionDto, PageSummaryDto, SavePageDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::page::PageService, util::markdown::escape_html,
+    },
+};
+
+/// OpenAPI tag for page endpoints.
+pub static PAGE_TAG: &str = "page";
+
+/// Cache-Control header value for rendered pages.
+///
+/// Pages change only when an admin saves them, so browsers may reuse a rendered page for a
+/// minute without the edit taking long to show up.
+static PAGE_CACHE_CONTROL: &str = "public, max-age=60";
+
+/// Retrieves the slug and title of all pages.
+///
+/// This endpoint is public.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+///
+/// # Returns
+/// - `Ok(Vec<PageSummaryDto>)` - All pages ordered by title
+/// - `Err(AppError)` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/pages",
+    tag = PAGE_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving pages", body = Vec<PageSummaryDto>),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_pages(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+    let pages = PageService::new(&state.db).get_pages().await?;
+
+    Ok((StatusCode::OK, Json(pages)).into_response())
+}
+
+/// Retrieves a page with its Markdown content and rendered HTML.
+///
+/// This endpoint is public.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `slug` - URL segment of the page
+///
+/// # Returns
+/// - `Ok(PageDto)` - The page
+/// - `Err(AppError)` - Page not found or database error
+#[utoipa::path(
+    get,
+    path = "/api/pages/{slug}",
+    tag = PAGE_TAG,
+    params(("slug" = String, Path, description = "URL segment of the page")),
+    responses(
+        (status = 200, description = "Success when retrieving the page", body = PageDto),
+        (status = 404, description = "Page not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_page(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let page = PageService::new(&state.db).get_page(&slug).await?;
+
+    Ok((StatusCode::OK, Json(page)).into_response())
+}
+
+/// Renders a page as a standalone HTML document.
+///
+/// This endpoint is public, so pages can be linked from the navigation bar with
+/// `BRANDING_NAV_LINKS` or shared with people who haven't logged in.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and branding settings
+/// - `slug` - URL segment of the page
+///
+/// # Returns
+/// - `Ok(Html)` - Rendered page
+/// - `Err(AppError)` - Page not found or database error
+#[utoipa::path(
+    get,
+    path = "/pages/{slug}",
+    tag = PAGE_TAG,
+    params(("slug" = String, Path, description = "URL segment of the page")),
+    responses(
+        (status = 200, description = "Success when rendering the page", content_type = "text/html", body = String),
+        (status = 404, description = "Page not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn render_page(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let page = PageService::new(&state.db).get_page(&slug).await?;
+    let site_name = state.branding.org_name.as_deref().unwrap_or("Bifrost");
+
+    Ok((
+        StatusCode::OK,
+        [(header::CACHE_CONTROL, PAGE_CACHE_CONTROL)],
+        Html(render_document(&page, site_name)),
+    )
+        .into_response())
+}
+
+/// Creates or updates a page.
+///
+/// Every save is recorded as a revision attributed to the currently authenticated user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `slug` - URL segment of the page
+/// - `payload` - Page title and Markdown content
+///
+/// # Returns
+/// - `Ok(PageDto)` - The saved page
+/// - `Err(AppError)` - User not in session, invalid slug or title, or database error
+#[utoipa::path(
+    put,
+    path = "/api/admin/pages/{slug}",
+    tag = PAGE_TAG,
+    params(("slug" = String, Path, description = "URL segment of the page")),
+    request_body = SavePageDto,
+    responses(
+        (status = 200, description = "Page saved", body = PageDto),
+        (status = 400, description = "Invalid slug or title", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn save_page(
+    State(state): State<AppState>,
+    session: Session,
+    Path(slug): Path<String>,
+    Json(payload): Json<SavePageDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let page = PageService::new(&state.db)
+        .save_page(user.id, &slug, payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(page)).into_response())
+}
+
+/// Deletes a page along with its revisions.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `slug` - URL segment of the page
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the page was deleted
+/// - `Err(AppError)` - User not in session, page not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/pages/{slug}",
+    tag = PAGE_TAG,
+    params(("slug" = String, Path, description = "URL segment of the page")),
+    responses(
+        (status = 204, description = "Page deleted"),
+        (status = 404, description = "User or page not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_page(
+    State(state): State<AppState>,
+    session: Session,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    PageService::new(&state.db).delete_page(&slug).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves the revision history of a page, newest first.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `slug` - URL segment of the page
+///
+/// # Returns
+/// - `Ok(Vec<PageRevisionDto>)` - Every saved version of the page
+/// - `Err(AppError)` - User not in session, page not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/pages/{slug}/revisions",
+    tag = PAGE_TAG,
+    params(("slug" = String, Path, description = "URL segment of the page")),
+    responses(
+        (status = 200, description = "Success when retrieving page revisions", body = Vec<PageRevisionDto>),
+        (status = 404, description = "User or page not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_page_revisions(
+    State(state): State<AppState>,
+    session: Session,
+    Path(slug): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let revisions = PageService::new(&state.db).get_revisions(&slug).await?;
+
+    Ok((StatusCode::OK, Json(revisions)).into_response())
+}
+
+/// Restores a page to one of its revisions.
+///
+/// The restore is recorded as a new revision attributed to the currently authenticated user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `slug` - URL segment of the page
+/// - `revision_id` - ID of the revision to restore
+///
+/// # Returns
+/// - `Ok(PageDto)` - The restored page
+/// - `Err(AppError)` - User not in session, page or revision not found, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/pages/{slug}/revisions/{revision_id}/restore",
+    tag = PAGE_TAG,
+    params(
+        ("slug" = String, Path, description = "URL segment of the page"),
+        ("revision_id" = i32, Path, description = "ID of the revision to restore")
+    ),
+    responses(
+        (status = 200, description = "Page restored", body = PageDto),
+        (status = 404, description = "User, page, or revision not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn restore_page_revision(
+    State(state): State<AppState>,
+    session: Session,
+    Path((slug, revision_id)): Path<(String, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let page = PageService::new(&state.db)
+        .restore_revision(user.id, &slug, revision_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(page)).into_response())
+}
+
+/// Renders a page as a minimal self-contained HTML document.
+fn render_document(page: &PageDto, site_name: &str) -> String {
+    format!(
+        concat!(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\">",
+            "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">",
+            "<title>{title} | {site_name}</title>",
+            "<style>body{{max-width:48rem;margin:0 auto;padding:1rem;",
+            "font-family:sans-serif;line-height:1.5;}}</style></head>",
+            "<body><main><h1>{title}</h1>{html}</main></body></html>"
+        ),
+        title = escape_html(&page.title),
+        site_name = escape_html(site_name),
+        html = page.html,
+    )
+}
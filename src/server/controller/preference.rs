@@ -0,0 +1,89 @@
+//! User preference controller endpoints.
+//!
+//! This module provides HTTP endpoints for users to read and save their preferences, such as
+//! which widgets the home dashboard shows and in which order. These endpoints require an
+//! active session.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, preference::UserPreferencesDto},
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::preference::PreferenceService,
+    },
+};
+
+/// OpenAPI tag for user preference endpoints.
+pub static PREFERENCE_TAG: &str = "preference";
+
+/// Retrieves the preferences of the current user.
+///
+/// Users who have never saved preferences receive the defaults.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(UserPreferencesDto)` - The user's preferences
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/user/preferences",
+    tag = PREFERENCE_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving preferences", body = UserPreferencesDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_preferences(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let preferences = PreferenceService::new(&state.db)
+        .get_preferences(user.id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(preferences)).into_response())
+}
+
+/// Saves the preferences of the current user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Preferences to save
+///
+/// # Returns
+/// - `Ok(UserPreferencesDto)` - The saved preferences
+/// - `Err(AppError)` - User not in session, invalid dashboard layout, or database error
+#[utoipa::path(
+    put,
+    path = "/api/user/preferences",
+    tag = PREFERENCE_TAG,
+    request_body = UserPreferencesDto,
+    responses(
+        (status = 200, description = "Preferences saved", body = UserPreferencesDto),
+        (status = 400, description = "Invalid dashboard layout", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn update_preferences(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<UserPreferencesDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let preferences = PreferenceService::new(&state.db)
+        .update_preferences(user.id, payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(preferences)).into_response())
+}
@@ -0,0 +1,177 @@
+//! Push notification controller endpoints.
+//!
+//! This module provides HTTP endpoints for users to subscribe their browsers and phones to
+//! Web Push notifications, unsubscribe them, and send themselves a test notification. These
+//! endpoints require an active session.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        push::{
+            CreatePushSubscriptionDto, DeletePushSubscriptionDto, PushConfigDto,
+            PushNotificationDto,
+        },
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::{push::PushError, AppError},
+        model::{app::AppState, worker::WorkerJob},
+        service::push::PushService,
+    },
+};
+
+/// OpenAPI tag for push notification endpoints.
+pub static PUSH_TAG: &str = "push";
+
+/// Retrieves the settings browsers need to subscribe to push notifications.
+///
+/// # Arguments
+/// - `state` - Application state containing the Web Push settings
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(PushConfigDto)` - VAPID public key, `null` if push notifications are disabled
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/push/config",
+    tag = PUSH_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving push settings", body = PushConfigDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_push_config(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let config = PushService::new(&state.db, &state.push).get_config();
+
+    Ok((StatusCode::OK, Json(config)).into_response())
+}
+
+/// Subscribes the current browser to push notifications.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and Web Push settings
+/// - `session` - User's session containing their user ID
+/// - `payload` - Subscription created by the browser's push manager
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the subscription was saved
+/// - `Err(AppError)` - User not in session, push disabled, invalid subscription, or database
+///   error
+#[utoipa::path(
+    post,
+    path = "/api/user/push-subscriptions",
+    tag = PUSH_TAG,
+    request_body = CreatePushSubscriptionDto,
+    responses(
+        (status = 204, description = "Subscription saved"),
+        (status = 400, description = "Invalid subscription", body = ErrorDto),
+        (status = 404, description = "User not found or push notifications disabled", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_push_subscription(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreatePushSubscriptionDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    PushService::new(&state.db, &state.push)
+        .subscribe(user.id, payload)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Unsubscribes a browser of the current user from push notifications.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Push service endpoint of the subscription
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the subscription was deleted
+/// - `Err(AppError)` - User not in session, subscription not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/user/push-subscriptions",
+    tag = PUSH_TAG,
+    request_body = DeletePushSubscriptionDto,
+    responses(
+        (status = 204, description = "Subscription deleted"),
+        (status = 404, description = "User or subscription not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_push_subscription(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<DeletePushSubscriptionDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    PushService::new(&state.db, &state.push)
+        .unsubscribe(user.id, &payload.endpoint)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Sends a test notification to every browser the current user subscribed on.
+///
+/// The notification is delivered by a worker job, so the response does not indicate whether
+/// any push service accepted it.
+///
+/// # Arguments
+/// - `state` - Application state containing the Web Push settings and worker queue
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(())` - 202 Accepted when the notification was queued
+/// - `Err(AppError)` - User not in session, push disabled, or worker queue error
+#[utoipa::path(
+    post,
+    path = "/api/user/push-subscriptions/test",
+    tag = PUSH_TAG,
+    responses(
+        (status = 202, description = "Test notification queued"),
+        (status = 404, description = "User not found or push notifications disabled", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn send_test_push_notification(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    if state.push.vapid_key.is_none() {
+        return Err(PushError::PushDisabled.into());
+    }
+
+    state
+        .worker
+        .queue
+        .push(WorkerJob::SendPushNotification {
+            user_id: user.id,
+            notification: PushNotificationDto {
+                title: "Bifrost".to_string(),
+                body: "Push notifications are working on this device.".to_string(),
+                url: Some("/auth".to_string()),
+            },
+        })
+        .await?;
+
+    Ok(StatusCode::ACCEPTED.into_response())
+}
@@ -0,0 +1,102 @@
+//! Progressive web app controller endpoints.
+//!
+//! This module serves the web app manifest, service worker, and app icon that let the Dioxus
+//! frontend be installed on phones and opened without a connection. The files are embedded in
+//! the binary and served from fixed paths at the site root, as browsers require the manifest
+//! and icon URLs to be stable and the service worker to be served from the scope it controls.
+//! These endpoints are public.
+
+use axum::{
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+
+/// OpenAPI tag for progressive web app endpoints.
+pub static PWA_TAG: &str = "pwa";
+
+/// Web app manifest describing how the app is installed.
+const MANIFEST: &str = include_str!("../../../assets/pwa/manifest.webmanifest");
+
+/// Service worker caching the app shell for offline use.
+const SERVICE_WORKER: &str = include_str!("../../../assets/pwa/sw.js");
+
+/// App icon referenced by the manifest.
+const ICON: &[u8] = include_bytes!("../../../assets/autumn-logo-dark.png");
+
+/// Cache policy for the service worker and manifest.
+///
+/// Browsers must see updated service workers promptly, so these files are revalidated on
+/// every request.
+const PWA_CACHE_CONTROL: &str = "no-cache";
+
+/// Cache policy for the app icon, which rarely changes.
+const ICON_CACHE_CONTROL: &str = "public, max-age=86400";
+
+/// Serves the web app manifest.
+///
+/// # Returns
+/// - 200 OK with the manifest as `application/manifest+json`
+#[utoipa::path(
+    get,
+    path = "/manifest.webmanifest",
+    tag = PWA_TAG,
+    responses(
+        (status = 200, description = "Web app manifest", content_type = "application/manifest+json", body = String)
+    ),
+)]
+pub async fn get_manifest() -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "application/manifest+json"),
+            (header::CACHE_CONTROL, PWA_CACHE_CONTROL),
+        ],
+        MANIFEST,
+    )
+}
+
+/// Serves the service worker script.
+///
+/// # Returns
+/// - 200 OK with the service worker as `text/javascript`
+#[utoipa::path(
+    get,
+    path = "/sw.js",
+    tag = PWA_TAG,
+    responses(
+        (status = 200, description = "Service worker script", content_type = "text/javascript", body = String)
+    ),
+)]
+pub async fn get_service_worker() -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "text/javascript"),
+            (header::CACHE_CONTROL, PWA_CACHE_CONTROL),
+        ],
+        SERVICE_WORKER,
+    )
+}
+
+/// Serves the app icon referenced by the manifest.
+///
+/// # Returns
+/// - 200 OK with the icon as `image/png`
+#[utoipa::path(
+    get,
+    path = "/icon-512.png",
+    tag = PWA_TAG,
+    responses(
+        (status = 200, description = "App icon", content_type = "image/png", body = Vec<u8>)
+    ),
+)]
+pub async fn get_icon() -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "image/png"),
+            (header::CACHE_CONTROL, ICON_CACHE_CONTROL),
+        ],
+        ICON,
+    )
+}
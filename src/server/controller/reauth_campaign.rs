@@ -0,0 +1,133 @@
+//! Re-authentication campaign controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to launch re-authentication campaigns asking
+//! the members of an audience to grant a set of ESI scopes and to track their completion, and
+//! for users to retrieve the campaigns they still have to complete. Users restricted by a
+//! campaign past its deadline can still retrieve their pending campaigns. All endpoints
+//! require an active session.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use chrono::Utc;
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        reauth_campaign::{CreateReauthCampaignDto, PendingReauthCampaignDto, ReauthCampaignDto},
+    },
+    server::{
+        controller::util::get_user::{
+            get_user_from_session, get_user_from_session_allowing_reauth,
+        },
+        error::AppError,
+        model::app::AppState,
+        service::reauth_campaign::ReauthCampaignService,
+    },
+};
+
+/// OpenAPI tag for re-authentication campaign endpoints.
+pub static REAUTH_CAMPAIGN_TAG: &str = "reauth_campaign";
+
+/// Launches a re-authentication campaign, flagging every user in its audience.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Name, scopes, audience, and optional deadline of the campaign
+///
+/// # Returns
+/// - `Ok(ReauthCampaignDto)` - 201 Created with the launched campaign
+/// - `Err(AppError)` - User not in session, invalid campaign, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/reauth-campaigns",
+    tag = REAUTH_CAMPAIGN_TAG,
+    request_body = CreateReauthCampaignDto,
+    responses(
+        (status = 201, description = "Campaign launched", body = ReauthCampaignDto),
+        (status = 400, description = "Empty name or scopes, missing audience ID, or past deadline", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_reauth_campaign(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateReauthCampaignDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let campaign = ReauthCampaignService::new(&state.db)
+        .create_campaign(user.id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(campaign)).into_response())
+}
+
+/// Retrieves all re-authentication campaigns with their completion, newest first.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<ReauthCampaignDto>)` - All campaigns with flagged and completed user counts
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/reauth-campaigns",
+    tag = REAUTH_CAMPAIGN_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving campaigns", body = Vec<ReauthCampaignDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_reauth_campaigns(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let campaigns = ReauthCampaignService::new(&state.db)
+        .get_campaigns()
+        .await?;
+
+    Ok((StatusCode::OK, Json(campaigns)).into_response())
+}
+
+/// Retrieves the re-authentication campaigns the currently authenticated user still has to
+/// complete.
+///
+/// Available to users restricted by a campaign past its deadline, so the frontend can show
+/// them how to regain access.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<PendingReauthCampaignDto>)` - Pending campaigns, oldest first
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/user/reauth-campaigns",
+    tag = REAUTH_CAMPAIGN_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving pending campaigns", body = Vec<PendingReauthCampaignDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_pending_reauth_campaigns(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session_allowing_reauth(&state, &session).await?;
+
+    let campaigns = ReauthCampaignService::new(&state.db)
+        .get_pending(user.id, Utc::now().naive_utc())
+        .await?;
+
+    Ok((StatusCode::OK, Json(campaigns)).into_response())
+}
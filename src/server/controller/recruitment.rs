@@ -0,0 +1,142 @@
+//! Recruitment controller endpoints.
+//!
+//! This module provides HTTP endpoints for public corporation recruitment listings. Listing
+//! recruiting corporations is unauthenticated and cacheable so it can back a public
+//! recruitment page, while managing a corporation's listing requires an active session for a
+//! user owning the corporation's CEO character.
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        recruitment::{RecruitmentListingDto, UpsertRecruitmentListingDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::recruitment::RecruitmentService,
+    },
+};
+
+/// OpenAPI tag for recruitment endpoints.
+pub static RECRUITMENT_TAG: &str = "recruitment";
+
+/// Cache-Control header value for the public recruitment listing.
+///
+/// Listings change rarely, so allow shared caches and browsers to reuse the response for
+/// a few minutes.
+static RECRUITMENT_CACHE_CONTROL: &str = "public, max-age=300";
+
+/// Retrieves all corporations that opted into recruitment.
+///
+/// This endpoint is public and does not require a session.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+///
+/// # Returns
+/// - `Ok(Vec<RecruitmentListingDto>)` - All listings, most recently updated first
+/// - `Err(AppError)` - Database error
+#[utoipa::path(
+    get,
+    path = "/api/recruitment",
+    tag = RECRUITMENT_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving recruitment listings", body = Vec<RecruitmentListingDto>),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_recruitment_listings(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, AppError> {
+    let listings = RecruitmentService::new(&state.db).get_listings().await?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CACHE_CONTROL, RECRUITMENT_CACHE_CONTROL)],
+        Json(listings),
+    )
+        .into_response())
+}
+
+/// Creates or updates a corporation's recruitment listing.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `corporation_id` - EVE Online corporation ID
+/// - `payload` - Listing description and timezone
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the listing was saved
+/// - `Err(AppError)` - User not in session, not the corporation's CEO, invalid input, or database error
+#[utoipa::path(
+    put,
+    path = "/api/recruitment/{corporation_id}",
+    tag = RECRUITMENT_TAG,
+    params(("corporation_id" = i64, Path, description = "EVE Online corporation ID")),
+    request_body = UpsertRecruitmentListingDto,
+    responses(
+        (status = 204, description = "Recruitment listing saved"),
+        (status = 400, description = "Invalid recruitment listing", body = ErrorDto),
+        (status = 403, description = "User is not the corporation's CEO", body = ErrorDto),
+        (status = 404, description = "User or corporation not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn upsert_recruitment_listing(
+    State(state): State<AppState>,
+    session: Session,
+    Path(corporation_id): Path<i64>,
+    Json(payload): Json<UpsertRecruitmentListingDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    RecruitmentService::new(&state.db)
+        .upsert_listing(user.id, corporation_id, payload)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Removes a corporation's recruitment listing.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `corporation_id` - EVE Online corporation ID
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the listing was removed
+/// - `Err(AppError)` - User not in session, not the corporation's CEO, no listing, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/recruitment/{corporation_id}",
+    tag = RECRUITMENT_TAG,
+    params(("corporation_id" = i64, Path, description = "EVE Online corporation ID")),
+    responses(
+        (status = 204, description = "Recruitment listing removed"),
+        (status = 403, description = "User is not the corporation's CEO", body = ErrorDto),
+        (status = 404, description = "User, corporation, or listing not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_recruitment_listing(
+    State(state): State<AppState>,
+    session: Session,
+    Path(corporation_id): Path<i64>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    RecruitmentService::new(&state.db)
+        .delete_listing(user.id, corporation_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
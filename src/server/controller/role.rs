@@ -0,0 +1,308 @@
+//! Role controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to manage the roles bundling admin
+//! permissions and assign them to users, and for users to retrieve the permissions they have
+//! been granted. All endpoints require an active session, and the admin endpoints require the
+//! `manage_roles` permission. Assigning roles that grant admin can be configured to require
+//! approval by a second admin.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        approval::{ApprovalAction, ApprovalPayload, ApprovalRequestDto},
+        role::{CreateRoleDto, Permission, RoleDto, UpdateRoleDto},
+    },
+    server::{
+        controller::{approval::request_approval, util::get_user::get_user_from_session},
+        error::AppError,
+        model::app::AppState,
+        service::role::RoleService,
+    },
+};
+
+/// OpenAPI tag for role endpoints.
+pub static ROLE_TAG: &str = "role";
+
+/// Retrieves all roles.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<RoleDto>)` - 200 OK with all roles
+/// - `Err(AppError)` - User not in session, missing permission, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/roles",
+    tag = ROLE_TAG,
+    responses(
+        (status = 200, description = "Success when listing roles", body = Vec<RoleDto>),
+        (status = 403, description = "Missing the manage_roles permission", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_roles(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let roles = RoleService::new(&state.db).get_roles().await?;
+
+    Ok((StatusCode::OK, Json(roles)).into_response())
+}
+
+/// Creates a role.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Name, description, and permissions of the role
+///
+/// # Returns
+/// - `Ok(RoleDto)` - 201 Created with the created role
+/// - `Err(AppError)` - User not in session, missing permission, invalid role, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/roles",
+    tag = ROLE_TAG,
+    request_body = CreateRoleDto,
+    responses(
+        (status = 201, description = "Role created", body = RoleDto),
+        (status = 400, description = "Empty name or name already in use", body = ErrorDto),
+        (status = 403, description = "Missing the manage_roles permission", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_role(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateRoleDto>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let role = RoleService::new(&state.db).create_role(payload).await?;
+
+    Ok((StatusCode::CREATED, Json(role)).into_response())
+}
+
+/// Updates a role.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `role_id` - ID of the role
+/// - `payload` - Name, description, and permissions of the role
+///
+/// # Returns
+/// - `Ok(RoleDto)` - 200 OK with the updated role
+/// - `Err(AppError)` - User not in session, missing permission, invalid role, change to a
+///   built-in role, role not found, or database error
+#[utoipa::path(
+    put,
+    path = "/api/admin/roles/{role_id}",
+    tag = ROLE_TAG,
+    params(("role_id" = i32, Path, description = "ID of the role")),
+    request_body = UpdateRoleDto,
+    responses(
+        (status = 200, description = "Role updated", body = RoleDto),
+        (status = 400, description = "Empty name, name already in use, or built-in role renamed", body = ErrorDto),
+        (status = 403, description = "Missing the manage_roles permission", body = ErrorDto),
+        (status = 404, description = "User or role not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn update_role(
+    State(state): State<AppState>,
+    session: Session,
+    Path(role_id): Path<i32>,
+    Json(payload): Json<UpdateRoleDto>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let role = RoleService::new(&state.db)
+        .update_role(role_id, payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(role)).into_response())
+}
+
+/// Deletes a role, unassigning it from every user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `role_id` - ID of the role
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the role was deleted
+/// - `Err(AppError)` - User not in session, missing permission, built-in role, role not found,
+///   or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/roles/{role_id}",
+    tag = ROLE_TAG,
+    params(("role_id" = i32, Path, description = "ID of the role")),
+    responses(
+        (status = 204, description = "Role deleted"),
+        (status = 400, description = "Role is built in", body = ErrorDto),
+        (status = 403, description = "Missing the manage_roles permission", body = ErrorDto),
+        (status = 404, description = "User or role not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_role(
+    State(state): State<AppState>,
+    session: Session,
+    Path(role_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    RoleService::new(&state.db).delete_role(role_id).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Assigns a role to a user.
+///
+/// If granting admin requires approval and the role includes the admin permission, a pending
+/// approval request is created instead and the role is assigned once a second admin approves
+/// it.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection, approval settings, and
+///   worker queue
+/// - `session` - User's session containing their user ID
+/// - `role_id` - ID of the role
+/// - `user_id` - ID of the user to assign the role to
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the user has the role
+/// - `Ok(ApprovalRequestDto)` - 202 Accepted with the pending approval request
+/// - `Err(AppError)` - User not in session, missing permission, member role, role or user not
+///   found, database, or worker queue error
+#[utoipa::path(
+    put,
+    path = "/api/admin/roles/{role_id}/users/{user_id}",
+    tag = ROLE_TAG,
+    params(
+        ("role_id" = i32, Path, description = "ID of the role"),
+        ("user_id" = i32, Path, description = "ID of the user to assign the role to")
+    ),
+    responses(
+        (status = 202, description = "Assigning an admin role awaits approval by a second admin", body = ApprovalRequestDto),
+        (status = 204, description = "Role assigned"),
+        (status = 400, description = "Role is the member role every user has", body = ErrorDto),
+        (status = 403, description = "Missing the manage_roles permission", body = ErrorDto),
+        (status = 404, description = "User or role not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn assign_role(
+    State(state): State<AppState>,
+    session: Session,
+    Path((role_id, user_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+    let role_service = RoleService::new(&state.db);
+
+    if state.approvals.is_required(ApprovalAction::GrantAdmin)
+        && role_service.grants_admin(role_id, user_id).await?
+    {
+        return request_approval(
+            &state,
+            user.id,
+            ApprovalPayload::GrantAdmin { role_id, user_id },
+        )
+        .await;
+    }
+
+    role_service.assign_role(user.id, role_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Removes a role from a user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `role_id` - ID of the role
+/// - `user_id` - ID of the user to remove the role from
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the user doesn't have the role
+/// - `Err(AppError)` - User not in session, missing permission, last admin, role not found, or
+///   database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/roles/{role_id}/users/{user_id}",
+    tag = ROLE_TAG,
+    params(
+        ("role_id" = i32, Path, description = "ID of the role"),
+        ("user_id" = i32, Path, description = "ID of the user to remove the role from")
+    ),
+    responses(
+        (status = 204, description = "Role removed"),
+        (status = 400, description = "User is the last admin", body = ErrorDto),
+        (status = 403, description = "Missing the manage_roles permission", body = ErrorDto),
+        (status = 404, description = "User or role not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn revoke_role(
+    State(state): State<AppState>,
+    session: Session,
+    Path((role_id, user_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    RoleService::new(&state.db)
+        .revoke_role(role_id, user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves the permissions the current user has been granted.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<Permission>)` - 200 OK with the user's permissions
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/user/permissions",
+    tag = ROLE_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving permissions", body = Vec<Permission>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_permissions(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let permissions = RoleService::new(&state.db)
+        .get_user_permissions(user.id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(permissions)).into_response())
+}
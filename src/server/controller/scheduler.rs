@@ -0,0 +1,166 @@
+//! Admin scheduler controller endpoints.
+//!
+//! This module provides HTTP endpoints for inspecting the scheduler. Previews run the batch
+//! selection of a scheduled job in read-only mode, so operators can validate configuration
+//! changes without waiting for the next cron tick or enqueuing any jobs. The freshness report
+//! shows whether the scheduler keeps cached EVE Online data within its cache durations.
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        scheduler::{
+            EntityFreshnessDto, FreshnessReportDto, ScheduledJobPreviewDto, SchedulerJobKind,
+            SchedulerPreviewDto,
+        },
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::AppError,
+        model::app::AppState,
+        scheduler::{freshness::freshness_report, preview::preview_schedule, SchedulerState},
+    },
+};
+
+/// OpenAPI tag for admin scheduler endpoints.
+pub static SCHEDULER_TAG: &str = "scheduler";
+
+/// Query parameters for the scheduler preview endpoint.
+///
+/// # Fields
+/// - `job` - Scheduled job to preview
+#[derive(Deserialize)]
+pub struct SchedulerPreviewParams {
+    /// Scheduled job to preview.
+    pub job: SchedulerJobKind,
+}
+
+/// Previews the jobs the next run of a scheduled job would enqueue.
+///
+/// Selects the entities whose cache has expired and computes the staggered execution times
+/// exactly as the scheduler would, including ESI downtime offsets, without pushing any jobs
+/// to the worker queue. Jobs already queued are included in the preview.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and worker queue
+/// - `session` - User's session containing their user ID
+/// - `params` - Query parameters selecting the scheduled job
+///
+/// # Returns
+/// - `Ok(SchedulerPreviewDto)` - 200 OK with the jobs that would be scheduled
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/scheduler/preview",
+    tag = SCHEDULER_TAG,
+    params(
+        ("job" = SchedulerJobKind, Query, description = "Scheduled job to preview"),
+    ),
+    responses(
+        (status = 200, description = "Success when previewing the scheduled job", body = SchedulerPreviewDto),
+        (status = 400, description = "Unknown scheduled job", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn preview_scheduler(
+    State(state): State<AppState>,
+    session: Session,
+    params: Query<SchedulerPreviewParams>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let scheduler_state = SchedulerState {
+        db: state.db.clone(),
+        queue: state.worker.queue.clone(),
+        offset_for_esi_downtime: true,
+        settings: state.scheduler,
+    };
+
+    let jobs = preview_schedule(&scheduler_state, params.0.job)
+        .await?
+        .into_iter()
+        .map(|(job, scheduled_at)| ScheduledJobPreviewDto {
+            job: job.to_string(),
+            scheduled_at: scheduled_at.naive_utc(),
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        Json(SchedulerPreviewDto {
+            kind: params.0.job,
+            jobs,
+        }),
+    )
+        .into_response())
+}
+
+/// Reports how long ago the entities refreshed by the scheduler were last updated.
+///
+/// For each entity type refreshed in batches, returns the number of entries, how many have an
+/// expired cache, and the median, 95th percentile, and maximum time since an entry was
+/// refreshed. Ages well above the cache duration indicate the schedule can't keep up.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(FreshnessReportDto)` - 200 OK with the freshness of each entity type
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/freshness",
+    tag = SCHEDULER_TAG,
+    responses(
+        (status = 200, description = "Success when computing the freshness report", body = FreshnessReportDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_freshness(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let scheduler_state = SchedulerState {
+        db: state.db.clone(),
+        queue: state.worker.queue.clone(),
+        offset_for_esi_downtime: true,
+        settings: state.scheduler,
+    };
+
+    let entities = freshness_report(&scheduler_state)
+        .await?
+        .into_iter()
+        .map(|(kind, cache_duration, freshness)| EntityFreshnessDto {
+            kind,
+            cache_duration_secs: cache_duration.num_seconds(),
+            count: freshness.count,
+            overdue: freshness.overdue,
+            p50_age_secs: freshness.p50_age.map(|age| age.num_seconds()),
+            p95_age_secs: freshness.p95_age.map(|age| age.num_seconds()),
+            max_age_secs: freshness.max_age.map(|age| age.num_seconds()),
+        })
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        Json(FreshnessReportDto {
+            generated_at: Utc::now().naive_utc(),
+            entities,
+        }),
+    )
+        .into_response())
+}
@@ -0,0 +1,97 @@
+//! Screening controller endpoints.
+//!
+//! This module provides HTTP endpoints for recruiters to generate screening reports for
+//! registered characters and view stored reports by ID, which serves as a shareable internal
+//! link between recruiters. Reports list every character of the screened user, so these
+//! endpoints require an active session with the `manage_members` permission.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, screening::ScreeningReportDto},
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::screening::ScreeningService,
+    },
+};
+
+/// OpenAPI tag for screening endpoints.
+pub static SCREENING_TAG: &str = "screening";
+
+/// Generates a screening report for a registered character.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `character_id` - EVE Online character ID to screen
+///
+/// # Returns
+/// - `Ok(ScreeningReportDto)` - 201 Created with the generated report
+/// - `Err(AppError)` - User not in session, character not registered, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/screening/characters/{character_id}",
+    tag = SCREENING_TAG,
+    params(("character_id" = i64, Path, description = "EVE Online character ID to screen")),
+    responses(
+        (status = 201, description = "Screening report generated", body = ScreeningReportDto),
+        (status = 403, description = "Missing the manage_members permission", body = ErrorDto),
+        (status = 404, description = "User not found or character not registered", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_screening_report(
+    State(state): State<AppState>,
+    session: Session,
+    Path(character_id): Path<i64>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let report = ScreeningService::new(&state.db)
+        .generate_report(user.id, character_id)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(report)).into_response())
+}
+
+/// Retrieves a stored screening report.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `report_id` - ID of the report to retrieve
+///
+/// # Returns
+/// - `Ok(ScreeningReportDto)` - The stored report
+/// - `Err(AppError)` - User not in session, report not found, or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/screening/{report_id}",
+    tag = SCREENING_TAG,
+    params(("report_id" = i32, Path, description = "ID of the screening report")),
+    responses(
+        (status = 200, description = "Success when retrieving screening report", body = ScreeningReportDto),
+        (status = 403, description = "Missing the manage_members permission", body = ErrorDto),
+        (status = 404, description = "User or screening report not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_screening_report(
+    State(state): State<AppState>,
+    session: Session,
+    Path(report_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let report = ScreeningService::new(&state.db)
+        .get_report(report_id)
+        .await?;
+
+    Ok((StatusCode::OK, Json(report)).into_response())
+}
@@ -0,0 +1,98 @@
+//! Search controller endpoints.
+//!
+//! This module provides the HTTP endpoint for searching characters, corporations, and alliances
+//! by name. Searches are served from Meilisearch if configured and from the database otherwise;
+//! the response is the same for both backends.
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, search::SearchResultsDto},
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::search::SearchService,
+    },
+};
+
+/// OpenAPI tag for search endpoints.
+pub static SEARCH_TAG: &str = "search";
+
+/// Number of results returned if the request doesn't specify a limit.
+const DEFAULT_SEARCH_LIMIT: u64 = 20;
+
+/// Maximum number of results a single request may return.
+const MAX_SEARCH_LIMIT: u64 = 50;
+
+/// Minimum query length in characters, shorter queries match too many names to be useful.
+const MIN_QUERY_LENGTH: usize = 2;
+
+/// Query parameters for the search endpoint.
+///
+/// # Fields
+/// - `q` - Text to search for
+/// - `limit` - Maximum number of results
+#[derive(Deserialize)]
+pub struct SearchParams {
+    /// Text to search for.
+    pub q: String,
+    /// Maximum number of results, defaults to 20 and is capped at 50.
+    pub limit: Option<u64>,
+}
+
+/// Searches characters, corporations, and alliances by name.
+///
+/// Queries shorter than two characters return no results.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and search settings
+/// - `session` - User's session containing their user ID
+/// - `params` - Query parameters with the search text and result limit
+///
+/// # Returns
+/// - `Ok(SearchResultsDto)` - 200 OK with the matching entities, most relevant first
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/search",
+    tag = SEARCH_TAG,
+    params(
+        ("q" = String, Query, description = "Text to search for"),
+        ("limit" = Option<u64>, Query, description = "Maximum number of results (default 20, max 50)"),
+    ),
+    responses(
+        (status = 200, description = "Success when searching", body = SearchResultsDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn search(
+    State(state): State<AppState>,
+    session: Session,
+    params: Query<SearchParams>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let query = params.0.q.trim();
+    let limit = params
+        .0
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+
+    let results = if query.chars().count() < MIN_QUERY_LENGTH {
+        Vec::new()
+    } else {
+        SearchService::new(&state.db, &state.search)
+            .search(query, limit)
+            .await?
+    };
+
+    Ok((StatusCode::OK, Json(SearchResultsDto { results })).into_response())
+}
@@ -0,0 +1,134 @@
+//! Skill plan controller endpoints.
+//!
+//! This module provides HTTP endpoints for publishing skill plans imported as plain text or
+//! EVEMon XML, listing them, and deleting them. These endpoints require an active session,
+//! and publishing or deleting skill plans requires the `manage_content` permission.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        skill_plan::{CreateSkillPlanDto, SkillPlanDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::skill_plan::SkillPlanService,
+    },
+};
+
+/// OpenAPI tag for skill plan endpoints.
+pub static SKILL_PLAN_TAG: &str = "skill_plan";
+
+/// Publishes a skill plan.
+///
+/// Parses the provided plan, in plain text or EVEMon XML format, to validate it and stores it
+/// as published by the currently authenticated user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Skill plan name, optional description, and plan text
+///
+/// # Returns
+/// - `Ok(SkillPlanDto)` - 201 Created with the published skill plan
+/// - `Err(AppError)` - User not in session, invalid plan, duplicate name, or database error
+#[utoipa::path(
+    post,
+    path = "/api/skill-plans",
+    tag = SKILL_PLAN_TAG,
+    request_body = CreateSkillPlanDto,
+    responses(
+        (status = 201, description = "Skill plan published", body = SkillPlanDto),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 400, description = "Invalid skill plan", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 409, description = "A skill plan with that name already exists", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_skill_plan(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateSkillPlanDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let skill_plan = SkillPlanService::new(&state.db)
+        .create_skill_plan(user.id, payload.name, payload.description, payload.plan)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(skill_plan)).into_response())
+}
+
+/// Retrieves all skill plans.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<SkillPlanDto>)` - All skill plans ordered by name
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/skill-plans",
+    tag = SKILL_PLAN_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving skill plans", body = Vec<SkillPlanDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_skill_plans(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let skill_plans = SkillPlanService::new(&state.db).get_skill_plans().await?;
+
+    Ok((StatusCode::OK, Json(skill_plans)).into_response())
+}
+
+/// Deletes a skill plan.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `skill_plan_id` - ID of the skill plan to delete
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the skill plan was deleted
+/// - `Err(AppError)` - User not in session, skill plan not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/skill-plans/{skill_plan_id}",
+    tag = SKILL_PLAN_TAG,
+    params(("skill_plan_id" = i32, Path, description = "ID of the skill plan to delete")),
+    responses(
+        (status = 204, description = "Skill plan deleted"),
+        (status = 403, description = "Missing the manage_content permission", body = ErrorDto),
+        (status = 404, description = "User or skill plan not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_skill_plan(
+    State(state): State<AppState>,
+    session: Session,
+    Path(skill_plan_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    SkillPlanService::new(&state.db)
+        .delete_skill_plan(skill_plan_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,51 @@
+//! Telemetry controller endpoints.
+//!
+//! This module provides an HTTP endpoint for administrators to see whether anonymous
+//! telemetry is enabled and exactly what is reported. This endpoint requires an active
+//! session.
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use tower_sessions::Session;
+
+use crate::{
+    model::{api::ErrorDto, telemetry::TelemetryStatusDto},
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::telemetry::TelemetryService,
+    },
+};
+
+/// OpenAPI tag for telemetry endpoints.
+pub static TELEMETRY_TAG: &str = "telemetry";
+
+/// Retrieves the telemetry status and a preview of the report that is sent.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and telemetry settings
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(TelemetryStatusDto)` - Whether telemetry is enabled, the endpoint, and the report
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/telemetry",
+    tag = TELEMETRY_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving telemetry status", body = TelemetryStatusDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_telemetry_status(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let status = TelemetryService::new(&state.db, &state.telemetry)
+        .get_status()
+        .await?;
+
+    Ok((StatusCode::OK, Json(status)).into_response())
+}
@@ -1,42 +1,72 @@
 //! User controller endpoints.
 //!
-//! This module provides HTTP endpoints for user-related operations, such as retrieving
-//! information about characters owned by the authenticated user. These endpoints require
-//! an active session and return user-specific data.
+//! This module provides HTTP endpoints for user-related operations, such as paging through
+//! the characters owned by the authenticated user and retrieving their skill snapshots,
+//! managing the user's linked Discord account, listing and revoking the sessions the user is
+//! logged in with, and merging duplicate users, which can be configured to require approval by
+//! a second admin. These endpoints require an active session.
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
 use tower_sessions::Session;
 
 use crate::{
-    model::{api::ErrorDto, user::CharacterDto},
+    model::{
+        api::ErrorDto,
+        approval::{ApprovalAction, ApprovalPayload, ApprovalRequestDto},
+        character_skill::CharacterSkillsDto,
+        user::{
+            DiscordAccountDto, UserCharacterPageDto, UserCharacterQueryDto, UserCharacterSort,
+            UserMergeDto, UserSessionDto,
+        },
+    },
     server::{
-        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
-        service::user::user_character::UserCharacterService,
+        controller::{approval::request_approval, util::get_user::get_user_from_session},
+        error::AppError,
+        model::app::AppState,
+        service::{
+            auth::discord::DiscordLinkService,
+            character_skill::CharacterSkillService,
+            session::SessionService,
+            user::{user_character::UserCharacterService, UserService},
+        },
     },
 };
 
 /// OpenAPI tag for user-related endpoints.
 pub static USER_TAG: &str = "user";
 
-/// Retrieves all characters owned by the currently authenticated user.
+/// Retrieves a page of the characters owned by the currently authenticated user.
 ///
-/// Fetches the user ID from the session, queries the database for all characters associated
-/// with that user account, and returns them as a list of character DTOs. Each character DTO
-/// includes the character's ID, name, corporation, alliance, and other relevant information.
+/// Fetches the user ID from the session and lists the characters associated with that user
+/// account, filtered by corporation or alliance and sorted by character or corporation name.
+/// The response wraps the page of character DTOs with the number of matching characters, so
+/// clients can page through users with many characters.
 ///
 /// # Arguments
 /// - `state` - Application state containing the database connection for character lookup
 /// - `session` - User's session containing their user ID
+/// - `query` - Query parameters with the page, page size, sort order, and filters
 ///
 /// # Returns
-/// - `Ok(Vec<CharacterDto>)` - List of characters owned by the user (may be empty)
+/// - `Ok(UserCharacterPageDto)` - Page of characters owned by the user (may be empty)
 /// - `Err(AppError)` - User not in session, not found in database, or database error
 #[utoipa::path(
     get,
     path = "/api/user/characters",
     tag = USER_TAG,
+    params(
+        ("page" = Option<u64>, Query, description = "Page to list, starting at 1"),
+        ("per_page" = Option<u64>, Query, description = "Number of characters per page, 50 by default and at most 200"),
+        ("sort" = Option<UserCharacterSort>, Query, description = "Sort by character `name` (default) or `corporation` name"),
+        ("corporation_id" = Option<i64>, Query, description = "EVE Online corporation ID of the characters"),
+        ("alliance_id" = Option<i64>, Query, description = "EVE Online alliance ID of the characters")
+    ),
     responses(
-        (status = 200, description = "Success when retrieving user characters", body = Vec<CharacterDto>),
+        (status = 200, description = "Success when retrieving user characters", body = UserCharacterPageDto),
         (status = 404, description = "User not found", body = ErrorDto),
         (status = 500, description = "Internal server error", body = ErrorDto)
     ),
@@ -44,12 +74,254 @@ pub static USER_TAG: &str = "user";
 pub async fn get_user_characters(
     State(state): State<AppState>,
     session: Session,
+    query: Query<UserCharacterQueryDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let page = UserCharacterService::new(&state.db)
+        .get_user_character_page(user.id, query.0)
+        .await?;
+
+    Ok((StatusCode::OK, axum::Json(page)).into_response())
+}
+
+/// Retrieves the skill snapshot of a character owned by the authenticated user.
+///
+/// Snapshots are fetched from ESI with the character's stored refresh token, so only
+/// characters that logged in granting the `esi-skills.read_skills.v1` scope have one.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `character_id` - EVE Online ID of the character
+///
+/// # Returns
+/// - `Ok(CharacterSkillsDto)` - The character's skills with the total skill points
+/// - `Err(AppError)` - User not in session or not found, the character isn't owned by the
+///   user, its skills aren't tracked, or database error
+#[utoipa::path(
+    get,
+    path = "/api/user/characters/{character_id}/skills",
+    tag = USER_TAG,
+    params(
+        ("character_id" = i64, Path, description = "EVE Online ID of the character")
+    ),
+    responses(
+        (status = 200, description = "Success when retrieving the character's skills", body = CharacterSkillsDto),
+        (status = 404, description = "Character not found or its skills aren't tracked", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_character_skills(
+    State(state): State<AppState>,
+    session: Session,
+    Path(character_id): Path<i64>,
 ) -> Result<impl IntoResponse, AppError> {
     let user = get_user_from_session(&state, &session).await?;
 
-    let character_dtos = UserCharacterService::new(&state.db)
-        .get_user_characters(user.id)
+    let skills = CharacterSkillService::new(&state.db, &state.esi_provider, &state.cipher)
+        .get_skills(user.id, character_id)
+        .await?;
+
+    Ok((StatusCode::OK, axum::Json(skills)).into_response())
+}
+
+/// Retrieves the Discord account linked to the authenticated user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Option<DiscordAccountDto>)` - The linked Discord account, `null` if none is linked
+/// - `Err(AppError)` - User not in session, not found in database, or database error
+#[utoipa::path(
+    get,
+    path = "/api/user/discord",
+    tag = USER_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving the linked Discord account", body = Option<DiscordAccountDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_discord_account(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let account = DiscordLinkService::new(&state.db, &state.discord)
+        .get_discord_account(user.id)
+        .await?;
+
+    Ok((StatusCode::OK, axum::Json(account)).into_response())
+}
+
+/// Unlinks the Discord account of the authenticated user.
+///
+/// Unlinking is allowed even if Discord linking has since been disabled, and succeeds if no
+/// account is linked.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when no Discord account is linked anymore
+/// - `Err(AppError)` - User not in session, not found in database, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/user/discord",
+    tag = USER_TAG,
+    responses(
+        (status = 204, description = "Discord account unlinked"),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn unlink_discord_account(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    DiscordLinkService::new(&state.db, &state.discord)
+        .unlink(user.id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Retrieves the active sessions of the authenticated user.
+///
+/// Lists every session the user is logged in with, including the client address and user agent
+/// it was last used from. The session the request was made with is marked as current.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and Redis pool
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<UserSessionDto>)` - Active sessions, most recently seen first
+/// - `Err(AppError)` - User not in session, not found in database, or Redis error
+#[utoipa::path(
+    get,
+    path = "/api/user/sessions",
+    tag = USER_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving user sessions", body = Vec<UserSessionDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_sessions(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let sessions = SessionService::new(&state.redis_pool)
+        .get_sessions(user.id, session.id())
+        .await?;
+
+    Ok((StatusCode::OK, axum::Json(sessions)).into_response())
+}
+
+/// Revokes a session of the authenticated user, logging out the client using it.
+///
+/// The current session can't be revoked, users end it by logging out instead.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection and Redis pool
+/// - `session` - User's session containing their user ID
+/// - `id` - ID of the session as listed by `GET /api/user/sessions`
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the session was revoked
+/// - `Err(AppError)` - Session not found, current session, user not in session, or Redis error
+#[utoipa::path(
+    delete,
+    path = "/api/user/sessions/{id}",
+    tag = USER_TAG,
+    params(
+        ("id" = String, Path, description = "ID of the session to revoke")
+    ),
+    responses(
+        (status = 204, description = "Session revoked"),
+        (status = 400, description = "Session is the current session", body = ErrorDto),
+        (status = 404, description = "User or session not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn revoke_session(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    SessionService::new(&state.redis_pool)
+        .revoke_session(user.id, &id, session.id())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Merges a duplicate user into another user.
+///
+/// Moves the removed user's characters and other records to the kept user, then deletes the
+/// removed user. The merge runs in a single transaction and is recorded in the server log.
+/// If merging users requires approval, a pending approval request is created instead and the
+/// merge runs once a second admin approves it.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection, approval settings, and
+///   worker queue
+/// - `session` - User's session containing their user ID
+/// - `keep` - ID of the user to keep
+/// - `remove` - ID of the duplicate user to merge and delete
+///
+/// # Returns
+/// - `Ok(UserMergeDto)` - 200 OK with a summary of the records moved
+/// - `Ok(ApprovalRequestDto)` - 202 Accepted with the pending approval request
+/// - `Err(AppError)` - User not in session, either user not found, both IDs are the same
+///   user, database, or worker queue error
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{keep}/merge/{remove}",
+    tag = USER_TAG,
+    params(
+        ("keep" = i32, Path, description = "ID of the user to keep"),
+        ("remove" = i32, Path, description = "ID of the duplicate user to merge and delete")
+    ),
+    responses(
+        (status = 200, description = "Success when merging users", body = UserMergeDto),
+        (status = 202, description = "Merge awaits approval by a second admin", body = ApprovalRequestDto),
+        (status = 400, description = "Cannot merge a user into itself", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn merge_users(
+    State(state): State<AppState>,
+    session: Session,
+    Path((keep, remove)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    if state.approvals.is_required(ApprovalAction::MergeUsers) {
+        return request_approval(
+            &state,
+            user.id,
+            ApprovalPayload::MergeUsers { keep, remove },
+        )
+        .await;
+    }
+
+    let merge = UserService::new(&state.db)
+        .merge_users(keep, remove)
         .await?;
 
-    Ok((StatusCode::OK, axum::Json(character_dtos)).into_response())
+    Ok((StatusCode::OK, axum::Json(merge)).into_response())
 }
@@ -7,36 +7,31 @@
 use tower_sessions::Session;
 
 use crate::server::{
-    error::{auth::AuthError, AppError},
-    model::session::auth::SessionAuthCsrf,
+    error::AppError,
+    model::session::auth_flow::{AuthFlow, SessionAuthFlow},
 };
 
-/// Validates CSRF state token from OAuth callback against the session value.
+/// Validates CSRF state token from OAuth callback against the login flow in the session.
 ///
-/// Retrieves and removes the CSRF state token from the session, then compares it with
-/// the state token provided in the OAuth callback URL. This prevents CSRF attacks by
-/// ensuring the callback originated from a legitimate login request initiated by this
-/// application. The token is removed from the session after validation (whether successful
-/// or not) to prevent reuse.
+/// Compares the state token provided in the OAuth callback URL with the token of the login
+/// flow stored by the login endpoint. This prevents CSRF attacks by ensuring the callback
+/// originated from a legitimate login request initiated by this application. A matching
+/// callback moves the flow to `CallbackPending`, so replaying it fails, while a failed
+/// validation removes the flow to prevent reuse.
 ///
 /// # Arguments
-/// - `session` - User's session containing the stored CSRF state token
+/// - `session` - User's session containing the login flow
 /// - `csrf_state` - CSRF state token from the OAuth callback URL parameters
 ///
 /// # Returns
-/// - `Ok(())` - CSRF state is valid (matches the session value)
-/// - `Err(AppError::Auth(AuthError::CsrfValidationFailed))` - State mismatch or not found in session
+/// - `Ok(AuthFlow)` - CSRF state is valid; parameters of the login the callback belongs to
+/// - `Err(AppError::Auth(AuthError::CsrfValidationFailed))` - State mismatch
+/// - `Err(AppError::Auth(AuthError::CsrfMissingValue))` - No login flow in session
+/// - `Err(AppError::Auth(AuthError::AuthFlowExpired))` - Login flow expired
+/// - `Err(AppError::Auth(AuthError::UnexpectedAuthFlowTransition))` - Callback replayed
 /// - `Err(AppError)` - Session retrieval error
-pub async fn validate_csrf(session: &Session, csrf_state: &str) -> Result<(), AppError> {
-    let stored_state = SessionAuthCsrf::remove(session).await?;
-
-    if let Some(state) = stored_state {
-        if state == csrf_state {
-            return Ok(());
-        }
-    }
-
-    Err(AppError::Auth(AuthError::CsrfValidationFailed))
+pub async fn validate_csrf(session: &Session, csrf_state: &str) -> Result<AuthFlow, AppError> {
+    SessionAuthFlow::begin_callback(session, csrf_state).await
 }
 
 #[cfg(test)]
@@ -46,13 +41,21 @@ pub mod tests {
     use bifrost_test_utils::prelude::*;
 
     use crate::server::{
-        controller::util::csrf::validate_csrf, model::session::auth::SessionAuthCsrf,
+        controller::util::csrf::validate_csrf,
+        model::session::auth_flow::{AuthFlow, SessionAuthFlow},
     };
 
+    fn flow(csrf: &str) -> AuthFlow {
+        AuthFlow {
+            csrf: csrf.to_string(),
+            ..Default::default()
+        }
+    }
+
     #[tokio::test]
     /// Tests successful validation of CSRF state.
     ///
-    /// Verifies that `validate_csrf` returns `Ok(())` when the CSRF state in the session
+    /// Verifies that `validate_csrf` returns the login flow when the CSRF state in the session
     /// matches the provided state parameter.
     ///
     /// Expected: 200 success
@@ -60,7 +63,9 @@ pub mod tests {
         let test = TestBuilder::new().build().await?;
         let state = "state";
 
-        let _ = SessionAuthCsrf::insert(&test.session, state).await.unwrap();
+        let _ = SessionAuthFlow::initiate(&test.session, flow(state))
+            .await
+            .unwrap();
         let result = validate_csrf(&test.session, state).await;
 
         assert!(result.is_ok());
@@ -79,7 +84,7 @@ pub mod tests {
         let test = TestBuilder::new().build().await?;
         let state = "state";
 
-        let _ = SessionAuthCsrf::insert(&test.session, "different_state")
+        let _ = SessionAuthFlow::initiate(&test.session, flow("different_state"))
             .await
             .unwrap();
         let result = validate_csrf(&test.session, state).await;
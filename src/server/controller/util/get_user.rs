@@ -1,9 +1,11 @@
 //! User session retrieval utilities.
 //!
 //! This module provides functions to retrieve authenticated user information from the session
-//! and database. It handles session validation, user lookup, and automatic session cleanup
-//! when users are not found in the database.
+//! and database. It handles session validation, user lookup, automatic session cleanup when
+//! users are not found in the database, and restricting users who missed the deadline of a
+//! re-authentication campaign.
 
+use chrono::Utc;
 use dioxus_logger::tracing;
 use tower_sessions::Session;
 
@@ -12,7 +14,7 @@ use crate::{
     server::{
         error::{auth::AuthError, AppError},
         model::{app::AppState, session::user::SessionUserId},
-        service::user::UserService,
+        service::{reauth_campaign::ReauthCampaignService, user::UserService},
     },
 };
 
@@ -22,7 +24,8 @@ use crate::{
 /// and returns it as a DTO. If the user ID exists in the session but the user is not found in
 /// the database (e.g., the user was deleted), the session is automatically cleared to prevent
 /// stale session state. This function is commonly used by protected endpoints that require an
-/// authenticated user.
+/// authenticated user, so users who haven't completed a re-authentication campaign past its
+/// deadline are rejected.
 ///
 /// # Arguments
 /// - `state` - Application state with database connection for user lookup
@@ -32,10 +35,39 @@ use crate::{
 /// - `Ok(UserDto)` - User found, containing user ID and main character information (ID, name)
 /// - `Err(AppError::Auth(AuthError::UserNotInSession))` - No user ID present in session
 /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User ID exists in session but user not found in database (session is cleared)
+/// - `Err(AppError::ReauthCampaign(ReauthCampaignError::ReauthRequired))` - User must re-authenticate for a campaign past its deadline
 /// - `Err(AppError)` - Database query failure or session retrieval error
 pub async fn get_user_from_session(
     state: &AppState,
     session: &Session,
+) -> Result<UserDto, AppError> {
+    let user = get_user_from_session_allowing_reauth(state, session).await?;
+
+    ReauthCampaignService::new(&state.db)
+        .ensure_not_restricted(user.id, Utc::now().naive_utc())
+        .await?;
+
+    Ok(user)
+}
+
+/// Retrieves user information from session and database, even if re-authentication is required.
+///
+/// Behaves like `get_user_from_session` without rejecting users restricted by a
+/// re-authentication campaign. Only use this for endpoints restricted users need in order to
+/// re-authenticate, such as retrieving the logged in user and their pending campaigns.
+///
+/// # Arguments
+/// - `state` - Application state with database connection for user lookup
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(UserDto)` - User found, containing user ID and main character information (ID, name)
+/// - `Err(AppError::Auth(AuthError::UserNotInSession))` - No user ID present in session
+/// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User ID exists in session but user not found in database (session is cleared)
+/// - `Err(AppError)` - Database query failure or session retrieval error
+pub async fn get_user_from_session_allowing_reauth(
+    state: &AppState,
+    session: &Session,
 ) -> Result<UserDto, AppError> {
     // Get user from session
     let Some(user_id) = SessionUserId::get(session).await? else {
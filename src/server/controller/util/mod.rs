@@ -1,8 +1,9 @@
 //! Utility functions for controller request handling.
 //!
 //! This module provides reusable helper functions used across controllers, including
-//! CSRF token validation for authentication flows and user session retrieval for
-//! protected endpoints.
+//! CSRF token validation for authentication flows, user session retrieval for
+//! protected endpoints, and post-login redirect validation.
 
 pub mod csrf;
 pub mod get_user;
+pub mod redirect;
@@ -0,0 +1,83 @@
+//! Post-login redirect validation utilities.
+//!
+//! This module validates the `next` parameter of the login endpoint before it is stored in
+//! the session. Only frontend paths on an allowlist are accepted so the OAuth callback cannot
+//! be used as an open redirect to external sites.
+
+/// Frontend paths users may be redirected to after logging in.
+static ALLOWED_REDIRECT_PATHS: &[&str] = &[
+    "/",
+    "/recruitment",
+    "/auth",
+    "/auth/consent",
+    "/auth/link-characters",
+    "/auth/admin",
+];
+
+/// Validates a requested post-login redirect against the allowlist of internal paths.
+///
+/// Accepts only paths that exactly match an allowed frontend route, ignoring a single
+/// trailing slash. Absolute URLs, protocol-relative URLs (`//host`), backslashes, query
+/// strings, and fragments are rejected as they never match an allowlist entry.
+///
+/// # Arguments
+/// - `next` - Redirect path requested in the login endpoint's query parameters
+///
+/// # Returns
+/// - `Some(&str)` - The allowlisted path to redirect to after the OAuth callback
+/// - `None` - The path is not an allowed internal path
+pub fn validate_redirect(next: &str) -> Option<&'static str> {
+    let path = match next.strip_suffix('/') {
+        Some(stripped) if !stripped.is_empty() => stripped,
+        _ => next,
+    };
+
+    ALLOWED_REDIRECT_PATHS
+        .iter()
+        .find(|allowed| **allowed == path)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that allowlisted paths are accepted.
+    ///
+    /// Expected: Some(path) for every allowed path
+    #[test]
+    fn accepts_allowed_paths() {
+        for path in ALLOWED_REDIRECT_PATHS {
+            assert_eq!(validate_redirect(path), Some(*path));
+        }
+    }
+
+    /// Tests that a trailing slash is ignored.
+    ///
+    /// Expected: Some("/auth/admin")
+    #[test]
+    fn accepts_trailing_slash() {
+        assert_eq!(validate_redirect("/auth/admin/"), Some("/auth/admin"));
+    }
+
+    /// Tests that external and unknown destinations are rejected.
+    ///
+    /// Verifies that absolute, protocol-relative, and backslash URLs as well as paths
+    /// outside the allowlist cannot be used as a redirect target.
+    ///
+    /// Expected: None for every path
+    #[test]
+    fn rejects_external_and_unknown_paths() {
+        for path in [
+            "",
+            "https://example.com",
+            "//example.com",
+            "/\\example.com",
+            "/auth/../api/auth/logout",
+            "/api/auth/logout",
+            "/auth?next=//example.com",
+        ] {
+            assert_eq!(validate_redirect(path), None, "{path}");
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Webhook controller endpoints.
+//!
+//! This module provides HTTP endpoints for admins to manage the webhooks notified when a
+//! character is linked to a user, transferred between users, or made a user's main. All
+//! endpoints require an active session.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        webhook::{CreateWebhookDto, UpdateWebhookDto, WebhookDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::notification::NotificationService,
+    },
+};
+
+/// OpenAPI tag for webhook endpoints.
+pub static WEBHOOK_TAG: &str = "webhook";
+
+/// Creates an enabled webhook.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Name, HTTPS URL, and events of the webhook
+///
+/// # Returns
+/// - `Ok(WebhookDto)` - 201 Created with the created webhook
+/// - `Err(AppError)` - User not in session, invalid webhook, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/webhooks",
+    tag = WEBHOOK_TAG,
+    request_body = CreateWebhookDto,
+    responses(
+        (status = 201, description = "Webhook created", body = WebhookDto),
+        (status = 400, description = "Empty name, URL that isn't HTTPS, or no events", body = ErrorDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateWebhookDto>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let webhook = NotificationService::new(&state.db)
+        .create_webhook(payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(webhook)).into_response())
+}
+
+/// Retrieves all webhooks.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<WebhookDto>)` - 200 OK with all webhooks
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/webhooks",
+    tag = WEBHOOK_TAG,
+    responses(
+        (status = 200, description = "Success when listing webhooks", body = Vec<WebhookDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_webhooks(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let webhooks = NotificationService::new(&state.db).get_webhooks().await?;
+
+    Ok((StatusCode::OK, Json(webhooks)).into_response())
+}
+
+/// Updates a webhook.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `webhook_id` - ID of the webhook
+/// - `payload` - Name, HTTPS URL, events, and whether the webhook is enabled
+///
+/// # Returns
+/// - `Ok(WebhookDto)` - 200 OK with the updated webhook
+/// - `Err(AppError)` - User not in session, invalid webhook, webhook not found, or database
+///   error
+#[utoipa::path(
+    put,
+    path = "/api/admin/webhooks/{webhook_id}",
+    tag = WEBHOOK_TAG,
+    params(("webhook_id" = i32, Path, description = "ID of the webhook")),
+    request_body = UpdateWebhookDto,
+    responses(
+        (status = 200, description = "Webhook updated", body = WebhookDto),
+        (status = 400, description = "Empty name, URL that isn't HTTPS, or no events", body = ErrorDto),
+        (status = 404, description = "User or webhook not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn update_webhook(
+    State(state): State<AppState>,
+    session: Session,
+    Path(webhook_id): Path<i32>,
+    Json(payload): Json<UpdateWebhookDto>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let webhook = NotificationService::new(&state.db)
+        .update_webhook(webhook_id, payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(webhook)).into_response())
+}
+
+/// Deletes a webhook.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `webhook_id` - ID of the webhook
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the webhook was deleted
+/// - `Err(AppError)` - User not in session, webhook not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/webhooks/{webhook_id}",
+    tag = WEBHOOK_TAG,
+    params(("webhook_id" = i32, Path, description = "ID of the webhook")),
+    responses(
+        (status = 204, description = "Webhook deleted"),
+        (status = 404, description = "User or webhook not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    session: Session,
+    Path(webhook_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    NotificationService::new(&state.db)
+        .delete_webhook(webhook_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
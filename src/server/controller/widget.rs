@@ -0,0 +1,233 @@
+//! Widget controller endpoints.
+//!
+//! This module provides HTTP endpoints for read-only widgets that external websites and
+//! forums can embed, either as JSON or as an HTML page inside an iframe. Widget endpoints are
+//! public and authorized only by the widget's token, while creating, listing, and revoking
+//! widgets requires an active session.
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{Html, IntoResponse},
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        widget::{CreateWidgetDto, WidgetDataDto, WidgetDto},
+    },
+    server::{
+        controller::util::get_user::get_user_from_session, error::AppError, model::app::AppState,
+        service::widget::WidgetService, util::markdown::escape_html,
+    },
+};
+
+/// OpenAPI tag for widget endpoints.
+pub static WIDGET_TAG: &str = "widget";
+
+/// Cache-Control header value for widget responses.
+///
+/// Widget data is refreshed with corporation information, which changes rarely, so allow
+/// shared caches and browsers to reuse the response for a few minutes. This keeps heavily
+/// viewed forum pages from querying the database on every view.
+static WIDGET_CACHE_CONTROL: &str = "public, max-age=300";
+
+/// Retrieves the data displayed by a widget as JSON.
+///
+/// This endpoint is public and authorized by the widget token. Responses can be fetched
+/// cross-origin so external websites can render the data themselves.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `token` - Widget token
+///
+/// # Returns
+/// - `Ok(WidgetDataDto)` - Current widget data
+/// - `Err(AppError)` - Unknown token or database error
+#[utoipa::path(
+    get,
+    path = "/api/widgets/{token}",
+    tag = WIDGET_TAG,
+    params(("token" = String, Path, description = "Widget token")),
+    responses(
+        (status = 200, description = "Success when retrieving widget data", body = WidgetDataDto),
+        (status = 404, description = "Widget not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_widget_data(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let data = WidgetService::new(&state.db)
+        .get_widget_data(&token)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CACHE_CONTROL, WIDGET_CACHE_CONTROL),
+            (header::ACCESS_CONTROL_ALLOW_ORIGIN, "*"),
+        ],
+        Json(data),
+    )
+        .into_response())
+}
+
+/// Renders a widget as a standalone HTML page for embedding in an iframe.
+///
+/// This endpoint is public and authorized by the widget token.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `token` - Widget token
+///
+/// # Returns
+/// - `Ok(Html)` - Rendered widget
+/// - `Err(AppError)` - Unknown token or database error
+#[utoipa::path(
+    get,
+    path = "/api/widgets/{token}/embed",
+    tag = WIDGET_TAG,
+    params(("token" = String, Path, description = "Widget token")),
+    responses(
+        (status = 200, description = "Success when rendering the widget", content_type = "text/html", body = String),
+        (status = 404, description = "Widget not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_widget_embed(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let data = WidgetService::new(&state.db)
+        .get_widget_data(&token)
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CACHE_CONTROL, WIDGET_CACHE_CONTROL)],
+        Html(render_widget(&data)),
+    )
+        .into_response())
+}
+
+/// Retrieves the widgets created by the current user.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<WidgetDto>)` - The user's widgets including their tokens
+/// - `Err(AppError)` - User not in session or database error
+#[utoipa::path(
+    get,
+    path = "/api/admin/widgets",
+    tag = WIDGET_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving widgets", body = Vec<WidgetDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_widgets(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let widgets = WidgetService::new(&state.db).get_widgets(user.id).await?;
+
+    Ok((StatusCode::OK, Json(widgets)).into_response())
+}
+
+/// Creates a widget with a newly generated token.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `payload` - Widget kind and EVE Online corporation ID
+///
+/// # Returns
+/// - `Ok(WidgetDto)` - 201 Created with the widget and its token
+/// - `Err(AppError)` - User not in session, corporation not found, or database error
+#[utoipa::path(
+    post,
+    path = "/api/admin/widgets",
+    tag = WIDGET_TAG,
+    request_body = CreateWidgetDto,
+    responses(
+        (status = 201, description = "Widget created", body = WidgetDto),
+        (status = 404, description = "User or corporation not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn create_widget(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<CreateWidgetDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let widget = WidgetService::new(&state.db)
+        .create_widget(user.id, payload)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(widget)).into_response())
+}
+
+/// Revokes a widget, invalidating its token.
+///
+/// Responses cached by external websites may remain visible until the cache expires.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `widget_id` - ID of the widget
+///
+/// # Returns
+/// - `Ok(())` - 204 No Content when the widget was revoked
+/// - `Err(AppError)` - User not in session, widget not found, or database error
+#[utoipa::path(
+    delete,
+    path = "/api/admin/widgets/{widget_id}",
+    tag = WIDGET_TAG,
+    params(("widget_id" = i32, Path, description = "Widget ID")),
+    responses(
+        (status = 204, description = "Widget revoked"),
+        (status = 404, description = "User or widget not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn delete_widget(
+    State(state): State<AppState>,
+    session: Session,
+    Path(widget_id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    WidgetService::new(&state.db)
+        .delete_widget(user.id, widget_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// Renders widget data as a minimal self-contained HTML page.
+fn render_widget(data: &WidgetDataDto) -> String {
+    format!(
+        concat!(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{name}</title>",
+            "<style>body{{margin:0;font-family:sans-serif;}}",
+            ".count{{font-size:2em;font-weight:bold;}}</style></head>",
+            "<body><div>{name} [{ticker}]</div>",
+            "<div class=\"count\">{count}</div><div>members</div></body></html>"
+        ),
+        name = escape_html(&data.corporation_name),
+        ticker = escape_html(&data.corporation_ticker),
+        count = data.member_count,
+    )
+}
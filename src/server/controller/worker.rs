@@ -0,0 +1,258 @@
+//! Admin worker controller endpoints.
+//!
+//! This module provides HTTP endpoints for the worker's queue, dead-letter queue, and job
+//! statuses. Admins can page through the jobs waiting in the queue, list jobs that failed
+//! permanently and requeue them, optionally with an edited payload, and check whether a queued
+//! job has run. Replays are audit logged with the replaying
+//! admin's user ID.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::{
+        api::ErrorDto,
+        worker::{
+            DeadLetterJobDto, DeadLetterReplayDto, QueuedWorkerJobDto, ReplayDeadLetterDto,
+            WorkerJobItemFailureDto, WorkerJobStatusDto, WorkerQueuePageDto, WorkerQueueQueryDto,
+        },
+    },
+    server::{
+        controller::util::get_user::get_user_from_session,
+        error::{worker::WorkerError, AppError},
+        model::app::AppState,
+        service::dead_letter::DeadLetterService,
+        worker::payload::{job_id, serialize_job},
+    },
+};
+
+/// OpenAPI tag for admin worker endpoints.
+pub static WORKER_TAG: &str = "worker";
+
+/// Number of queued jobs listed per page if no limit is requested.
+const DEFAULT_QUEUE_PAGE_SIZE: u64 = 50;
+
+/// Maximum number of queued jobs listed per page.
+const MAX_QUEUE_PAGE_SIZE: u64 = 500;
+
+/// Lists a page of the jobs waiting in the worker queue, ordered by scheduled time.
+///
+/// Jobs can be narrowed down to those scheduled within a UTC time range. Jobs scheduled well
+/// in the past are due but haven't been picked up, which points to stuck or paused workers.
+///
+/// # Arguments
+/// - `state` - Application state containing the worker queue
+/// - `session` - User's session containing their user ID
+/// - `query` - Query parameters with the scheduled time range, offset, and page size
+///
+/// # Returns
+/// - `Ok(WorkerQueuePageDto)` - 200 OK with the page of queued jobs and the number of jobs in
+///   the time range
+/// - `Err(AppError)` - User not in session or Redis error
+#[utoipa::path(
+    get,
+    path = "/api/admin/worker/queue",
+    tag = WORKER_TAG,
+    params(
+        ("from" = Option<String>, Query, description = "Earliest scheduled UTC time to list, e.g. 2026-01-01T11:00:00"),
+        ("to" = Option<String>, Query, description = "Latest scheduled UTC time to list"),
+        ("offset" = Option<u64>, Query, description = "Number of jobs to skip"),
+        ("limit" = Option<u64>, Query, description = "Maximum number of jobs to list, 50 by default and at most 500")
+    ),
+    responses(
+        (status = 200, description = "Success when listing queued jobs", body = WorkerQueuePageDto),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_queue(
+    State(state): State<AppState>,
+    session: Session,
+    query: Query<WorkerQueueQueryDto>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let offset = query.offset.unwrap_or(0);
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_QUEUE_PAGE_SIZE)
+        .clamp(1, MAX_QUEUE_PAGE_SIZE);
+    let from = query.from.map(|from| from.and_utc());
+    let to = query.to.map(|to| to.and_utc());
+
+    let page = match (from, to) {
+        (Some(from), Some(to)) => state.worker.queue.list(from..=to, offset, limit).await?,
+        (Some(from), None) => state.worker.queue.list(from.., offset, limit).await?,
+        (None, Some(to)) => state.worker.queue.list(..=to, offset, limit).await?,
+        (None, None) => state.worker.queue.list(.., offset, limit).await?,
+    };
+
+    let jobs = page
+        .jobs
+        .into_iter()
+        .map(|scheduled_job| {
+            Ok(QueuedWorkerJobDto {
+                job_id: job_id(&scheduled_job.job)?,
+                job: scheduled_job.job.to_string(),
+                payload: serialize_job(&scheduled_job.job)?,
+                scheduled_at: scheduled_job.scheduled_at.naive_utc(),
+                attempt_count: scheduled_job
+                    .retry_metadata
+                    .map(|metadata| metadata.attempt_count)
+                    .unwrap_or(0),
+            })
+        })
+        .collect::<Result<Vec<_>, WorkerError>>()?;
+
+    Ok((
+        StatusCode::OK,
+        Json(WorkerQueuePageDto {
+            total: page.total,
+            offset,
+            limit,
+            jobs,
+        }),
+    )
+        .into_response())
+}
+
+/// Retrieves all jobs in the dead-letter queue.
+///
+/// # Arguments
+/// - `state` - Application state containing the worker queue
+/// - `session` - User's session containing their user ID
+///
+/// # Returns
+/// - `Ok(Vec<DeadLetterJobDto>)` - 200 OK with the dead-lettered jobs, oldest first
+/// - `Err(AppError)` - User not in session or Redis error
+#[utoipa::path(
+    get,
+    path = "/api/admin/worker/dead-letters",
+    tag = WORKER_TAG,
+    responses(
+        (status = 200, description = "Success when retrieving dead-lettered jobs", body = Vec<DeadLetterJobDto>),
+        (status = 404, description = "User not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_dead_letters(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let dead_letters = DeadLetterService::new(&state.worker.queue)
+        .get_dead_letters()
+        .await?;
+
+    Ok((StatusCode::OK, Json(dead_letters)).into_response())
+}
+
+/// Requeues a dead-lettered job, optionally with an edited payload.
+///
+/// The job is removed from the dead-letter queue once requeued. If `payload` is provided it
+/// replaces the stored payload and must deserialize to a valid worker job.
+///
+/// # Arguments
+/// - `state` - Application state containing the worker queue
+/// - `session` - User's session containing their user ID
+/// - `id` - ID of the dead-letter entry
+/// - `payload` - Optional edited job payload
+///
+/// # Returns
+/// - `Ok(DeadLetterReplayDto)` - 200 OK with the replayed payload
+/// - `Err(AppError)` - User not in session, entry not found, invalid payload, or Redis error
+#[utoipa::path(
+    post,
+    path = "/api/admin/worker/dead-letters/{id}/replay",
+    tag = WORKER_TAG,
+    params(
+        ("id" = u64, Path, description = "ID of the dead-lettered job")
+    ),
+    request_body = ReplayDeadLetterDto,
+    responses(
+        (status = 200, description = "Success when replaying the dead-lettered job", body = DeadLetterReplayDto),
+        (status = 400, description = "Invalid worker job payload", body = ErrorDto),
+        (status = 404, description = "User or dead-lettered job not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn replay_dead_letter(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<u64>,
+    Json(payload): Json<ReplayDeadLetterDto>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = get_user_from_session(&state, &session).await?;
+
+    let replay = DeadLetterService::new(&state.worker.queue)
+        .replay(user.id, id, payload.payload)
+        .await?;
+
+    Ok((StatusCode::OK, Json(replay)).into_response())
+}
+
+/// Retrieves the last known status of a worker job.
+///
+/// Job IDs are the hex-encoded SHA-256 hash of the job's versioned payload. Statuses are kept
+/// for 24 hours after they last changed by default.
+/// Batch jobs where only some items failed are reported as partially succeeded with the
+/// failed items.
+///
+/// # Arguments
+/// - `state` - Application state containing the worker queue
+/// - `session` - User's session containing their user ID
+/// - `job_id` - ID of the job
+///
+/// # Returns
+/// - `Ok(WorkerJobStatusDto)` - 200 OK with the job's state, the error it failed with, and the
+///   items of a batch job that failed
+/// - `Err(AppError)` - User not in session, no status recorded for the job, or Redis error
+#[utoipa::path(
+    get,
+    path = "/api/admin/worker/jobs/{job_id}",
+    tag = WORKER_TAG,
+    params(
+        ("job_id" = String, Path, description = "ID of the job")
+    ),
+    responses(
+        (status = 200, description = "Success when retrieving the job status", body = WorkerJobStatusDto),
+        (status = 404, description = "User or job status not found", body = ErrorDto),
+        (status = 500, description = "Internal server error", body = ErrorDto)
+    ),
+)]
+pub async fn get_job_status(
+    State(state): State<AppState>,
+    session: Session,
+    Path(job_id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    get_user_from_session(&state, &session).await?;
+
+    let Some(status) = state.worker.queue.get_status(&job_id).await? else {
+        return Err(WorkerError::JobStatusNotFound(job_id).into());
+    };
+
+    let status = WorkerJobStatusDto {
+        job_id,
+        job: status.job,
+        state: status.state,
+        error: status.error,
+        failed_items: status
+            .failed_items
+            .into_iter()
+            .map(|failure| WorkerJobItemFailureDto {
+                id: failure.id,
+                error: failure.error,
+                retryable: failure.retryable,
+            })
+            .collect(),
+        updated_at: status.updated_at.naive_utc(),
+    };
+
+    Ok((StatusCode::OK, Json(status)).into_response())
+}
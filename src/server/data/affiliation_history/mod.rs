@@ -0,0 +1,183 @@
+//! Affiliation history data repositories.
+//!
+//! This module contains the `AffiliationHistoryRepository` for managing the intervals during
+//! which characters were members of a corporation and alliance.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use sea_orm::{
+    sea_query::Expr, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+
+use crate::server::model::db::{AffiliationHistoryModel, EveAllianceModel, EveCorporationModel};
+
+/// Repository for managing character affiliation history in the database.
+///
+/// Provides operations for opening and closing affiliation intervals as changes are found and
+/// retrieving a character's intervals along with their corporation and alliance information.
+pub struct AffiliationHistoryRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> AffiliationHistoryRepository<'a, C> {
+    /// Creates a new instance of AffiliationHistoryRepository.
+    ///
+    /// Constructs a repository for managing affiliation history records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `AffiliationHistoryRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the current affiliation intervals of characters.
+    ///
+    /// # Arguments
+    /// - `character_record_ids` - Internal database IDs of the characters
+    ///
+    /// # Returns
+    /// - `Ok(Vec<AffiliationHistoryModel>)` - Intervals that have not ended, at most one per character
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_open_by_character_ids(
+        &self,
+        character_record_ids: Vec<i32>,
+    ) -> Result<Vec<AffiliationHistoryModel>, DbErr> {
+        entity::prelude::BifrostAffiliationHistory::find()
+            .filter(
+                entity::bifrost_affiliation_history::Column::CharacterId
+                    .is_in(character_record_ids),
+            )
+            .filter(entity::bifrost_affiliation_history::Column::EndedAt.is_null())
+            .all(self.db)
+            .await
+    }
+
+    /// Ends affiliation intervals.
+    ///
+    /// # Arguments
+    /// - `ids` - IDs of the intervals to end
+    /// - `ended_at` - Time the intervals ended
+    ///
+    /// # Returns
+    /// - `Ok(())` - Intervals ended (including empty input)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn close_many(&self, ids: Vec<i32>, ended_at: NaiveDateTime) -> Result<(), DbErr> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        entity::prelude::BifrostAffiliationHistory::update_many()
+            .col_expr(
+                entity::bifrost_affiliation_history::Column::EndedAt,
+                Expr::value(ended_at),
+            )
+            .filter(entity::bifrost_affiliation_history::Column::Id.is_in(ids))
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Starts affiliation intervals for characters.
+    ///
+    /// # Arguments
+    /// - `affiliations` - Vector of tuples containing (character_id, corporation_id, optional alliance_id) record IDs
+    /// - `started_at` - Time the intervals started
+    ///
+    /// # Returns
+    /// - `Ok(())` - Intervals started (including empty input)
+    /// - `Err(DbErr)` - Database operation failed or a referenced record doesn't exist
+    pub async fn open_many(
+        &self,
+        affiliations: Vec<(i32, i32, Option<i32>)>,
+        started_at: NaiveDateTime,
+    ) -> Result<(), DbErr> {
+        if affiliations.is_empty() {
+            return Ok(());
+        }
+
+        let intervals =
+            affiliations
+                .into_iter()
+                .map(|(character_id, corporation_id, alliance_id)| {
+                    entity::bifrost_affiliation_history::ActiveModel {
+                        character_id: ActiveValue::Set(character_id),
+                        corporation_id: ActiveValue::Set(corporation_id),
+                        alliance_id: ActiveValue::Set(alliance_id),
+                        started_at: ActiveValue::Set(started_at),
+                        ended_at: ActiveValue::Set(None),
+                        ..Default::default()
+                    }
+                });
+
+        entity::prelude::BifrostAffiliationHistory::insert_many(intervals)
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the affiliation intervals of a character with corporation and alliance info.
+    ///
+    /// Intervals whose corporation is missing from the database are excluded.
+    ///
+    /// # Arguments
+    /// - `character_record_id` - Internal database ID of the character
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(AffiliationHistoryModel, EveCorporationModel, Option<EveAllianceModel>)>)` - Intervals ordered oldest first
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_character_id(
+        &self,
+        character_record_id: i32,
+    ) -> Result<
+        Vec<(
+            AffiliationHistoryModel,
+            EveCorporationModel,
+            Option<EveAllianceModel>,
+        )>,
+        DbErr,
+    > {
+        let intervals = entity::prelude::BifrostAffiliationHistory::find()
+            .filter(
+                entity::bifrost_affiliation_history::Column::CharacterId.eq(character_record_id),
+            )
+            .find_also_related(entity::prelude::EveCorporation)
+            .order_by_asc(entity::bifrost_affiliation_history::Column::StartedAt)
+            .order_by_asc(entity::bifrost_affiliation_history::Column::Id)
+            .all(self.db)
+            .await?;
+
+        let alliance_ids: Vec<i32> = intervals
+            .iter()
+            .filter_map(|(interval, _)| interval.alliance_id)
+            .collect();
+
+        let alliances: HashMap<i32, EveAllianceModel> = if alliance_ids.is_empty() {
+            HashMap::new()
+        } else {
+            entity::prelude::EveAlliance::find()
+                .filter(entity::eve_alliance::Column::Id.is_in(alliance_ids))
+                .all(self.db)
+                .await?
+                .into_iter()
+                .map(|alliance| (alliance.id, alliance))
+                .collect()
+        };
+
+        Ok(intervals
+            .into_iter()
+            .filter_map(|(interval, corporation)| {
+                let alliance = interval
+                    .alliance_id
+                    .and_then(|alliance_id| alliances.get(&alliance_id).cloned());
+                corporation.map(|corporation| (interval, corporation, alliance))
+            })
+            .collect())
+    }
+}
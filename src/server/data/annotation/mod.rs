@@ -0,0 +1,9 @@
+//! Annotation data repositories.
+//!
+//! This module contains repositories for the annotations admins attach to users, characters,
+//! and corporations: free-form tags, and timestamped officer notes. Subjects are identified by
+//! their kind and ID rather than a foreign key, as tags and notes outlive the characters and
+//! corporations they describe leaving the database.
+
+pub mod note;
+pub mod tag;
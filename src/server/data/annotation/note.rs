@@ -0,0 +1,163 @@
+//! Note data repository.
+//!
+//! This module contains the `NoteRepository` for storing the timestamped officer notes admins
+//! write about users, characters, and corporations. Notes restricted to their author are
+//! filtered out of queries for other users.
+
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, DeleteResult,
+    EntityTrait, QueryFilter, QueryOrder,
+};
+
+use crate::{model::annotation::NoteVisibility, server::model::db::NoteModel};
+
+/// Repository for managing note records in the database.
+pub struct NoteRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> NoteRepository<'a, C> {
+    /// Creates a new instance of NoteRepository.
+    ///
+    /// Constructs a repository for managing note records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `NoteRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Writes a note about a subject.
+    ///
+    /// # Arguments
+    /// - `subject_type` - Kind of the subject (`user`, `character`, or `corporation`)
+    /// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID
+    /// - `body` - Note text
+    /// - `visibility` - Who can read the note (`officers` or `author`)
+    /// - `user_id` - ID of the user writing the note
+    ///
+    /// # Returns
+    /// - `Ok(NoteModel)` - The created note record
+    /// - `Err(DbErr)` - Database operation failed or the user doesn't exist
+    pub async fn create(
+        &self,
+        subject_type: &str,
+        subject_id: i64,
+        body: String,
+        visibility: &str,
+        user_id: i32,
+    ) -> Result<NoteModel, DbErr> {
+        entity::prelude::BifrostNote::insert(entity::bifrost_note::ActiveModel {
+            subject_type: ActiveValue::Set(subject_type.to_string()),
+            subject_id: ActiveValue::Set(subject_id),
+            body: ActiveValue::Set(body),
+            visibility: ActiveValue::Set(visibility.to_string()),
+            created_by_user_id: ActiveValue::Set(user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        })
+        .exec_with_returning(self.db)
+        .await
+    }
+
+    /// Retrieves a note by ID if the user can read it.
+    ///
+    /// # Arguments
+    /// - `note_id` - ID of the note
+    /// - `user_id` - ID of the user reading the note
+    ///
+    /// # Returns
+    /// - `Ok(Some(NoteModel))` - Note found and visible to the user
+    /// - `Ok(None)` - No note with the ID exists, or it is restricted to another author
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_visible_by_id(
+        &self,
+        note_id: i32,
+        user_id: i32,
+    ) -> Result<Option<NoteModel>, DbErr> {
+        entity::prelude::BifrostNote::find_by_id(note_id)
+            .filter(visible_to(user_id))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves the notes about a subject the user can read, newest first.
+    ///
+    /// # Arguments
+    /// - `subject_type` - Kind of the subject
+    /// - `subject_id` - ID of the subject
+    /// - `user_id` - ID of the user reading the notes
+    ///
+    /// # Returns
+    /// - `Ok(Vec<NoteModel>)` - Notes shared with officers or written by the user
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_visible_by_subject(
+        &self,
+        subject_type: &str,
+        subject_id: i64,
+        user_id: i32,
+    ) -> Result<Vec<NoteModel>, DbErr> {
+        entity::prelude::BifrostNote::find()
+            .filter(entity::bifrost_note::Column::SubjectType.eq(subject_type))
+            .filter(entity::bifrost_note::Column::SubjectId.eq(subject_id))
+            .filter(visible_to(user_id))
+            .order_by_desc(entity::bifrost_note::Column::CreatedAt)
+            .order_by_desc(entity::bifrost_note::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Moves the notes about a subject to another subject of the same kind.
+    ///
+    /// # Arguments
+    /// - `subject_type` - Kind of both subjects
+    /// - `from_subject_id` - ID of the subject whose notes are moved
+    /// - `to_subject_id` - ID of the subject receiving the notes
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of notes moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_subject(
+        &self,
+        subject_type: &str,
+        from_subject_id: i64,
+        to_subject_id: i64,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostNote::update_many()
+            .col_expr(
+                entity::bifrost_note::Column::SubjectId,
+                Expr::value(to_subject_id),
+            )
+            .filter(entity::bifrost_note::Column::SubjectType.eq(subject_type))
+            .filter(entity::bifrost_note::Column::SubjectId.eq(from_subject_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Deletes a note.
+    ///
+    /// # Arguments
+    /// - `note_id` - ID of the note to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   note didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, note_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostNote::delete_by_id(note_id)
+            .exec(self.db)
+            .await
+    }
+}
+
+/// Builds the condition matching notes shared with officers or written by the user.
+fn visible_to(user_id: i32) -> Condition {
+    Condition::any()
+        .add(entity::bifrost_note::Column::Visibility.eq(NoteVisibility::Officers.as_str()))
+        .add(entity::bifrost_note::Column::CreatedByUserId.eq(user_id))
+}
@@ -0,0 +1,205 @@
+//! Tag data repository.
+//!
+//! This module contains the `TagRepository` for storing the free-form tags admins attach to
+//! users, characters, and corporations, and for finding every subject carrying a tag.
+
+use chrono::Utc;
+use sea_orm::{
+    sea_query::Expr, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect,
+};
+
+use crate::server::model::db::TagModel;
+
+/// Repository for managing tag records in the database.
+pub struct TagRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> TagRepository<'a, C> {
+    /// Creates a new instance of TagRepository.
+    ///
+    /// Constructs a repository for managing tag records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `TagRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Attaches a tag to a subject.
+    ///
+    /// # Arguments
+    /// - `subject_type` - Kind of the subject (`user`, `character`, or `corporation`)
+    /// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID
+    /// - `name` - Normalized tag name
+    /// - `user_id` - ID of the user adding the tag
+    ///
+    /// # Returns
+    /// - `Ok(TagModel)` - The created tag record
+    /// - `Err(DbErr)` - Database operation failed, the subject already carries the tag, or
+    ///   the user doesn't exist
+    pub async fn create(
+        &self,
+        subject_type: &str,
+        subject_id: i64,
+        name: String,
+        user_id: i32,
+    ) -> Result<TagModel, DbErr> {
+        entity::prelude::BifrostTag::insert(entity::bifrost_tag::ActiveModel {
+            subject_type: ActiveValue::Set(subject_type.to_string()),
+            subject_id: ActiveValue::Set(subject_id),
+            name: ActiveValue::Set(name),
+            created_by_user_id: ActiveValue::Set(user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        })
+        .exec_with_returning(self.db)
+        .await
+    }
+
+    /// Retrieves a tag by ID.
+    ///
+    /// # Arguments
+    /// - `tag_id` - ID of the tag
+    ///
+    /// # Returns
+    /// - `Ok(Some(TagModel))` - Tag found
+    /// - `Ok(None)` - No tag with the ID exists
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_id(&self, tag_id: i32) -> Result<Option<TagModel>, DbErr> {
+        entity::prelude::BifrostTag::find_by_id(tag_id)
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves a subject's tag by name.
+    ///
+    /// # Arguments
+    /// - `subject_type` - Kind of the subject
+    /// - `subject_id` - ID of the subject
+    /// - `name` - Normalized tag name
+    ///
+    /// # Returns
+    /// - `Ok(Some(TagModel))` - Subject carries the tag
+    /// - `Ok(None)` - Subject doesn't carry the tag
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_name(
+        &self,
+        subject_type: &str,
+        subject_id: i64,
+        name: &str,
+    ) -> Result<Option<TagModel>, DbErr> {
+        entity::prelude::BifrostTag::find()
+            .filter(entity::bifrost_tag::Column::SubjectType.eq(subject_type))
+            .filter(entity::bifrost_tag::Column::SubjectId.eq(subject_id))
+            .filter(entity::bifrost_tag::Column::Name.eq(name))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves the tags of a subject, ordered by name.
+    ///
+    /// # Arguments
+    /// - `subject_type` - Kind of the subject
+    /// - `subject_id` - ID of the subject
+    ///
+    /// # Returns
+    /// - `Ok(Vec<TagModel>)` - Tags of the subject (empty if it has none)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_subject(
+        &self,
+        subject_type: &str,
+        subject_id: i64,
+    ) -> Result<Vec<TagModel>, DbErr> {
+        entity::prelude::BifrostTag::find()
+            .filter(entity::bifrost_tag::Column::SubjectType.eq(subject_type))
+            .filter(entity::bifrost_tag::Column::SubjectId.eq(subject_id))
+            .order_by_asc(entity::bifrost_tag::Column::Name)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves every subject carrying a tag, in the order they were tagged.
+    ///
+    /// # Arguments
+    /// - `name` - Normalized tag name
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(String, i64)>)` - List of (subject type, subject ID) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_subjects_by_name(&self, name: &str) -> Result<Vec<(String, i64)>, DbErr> {
+        entity::prelude::BifrostTag::find()
+            .select_only()
+            .column(entity::bifrost_tag::Column::SubjectType)
+            .column(entity::bifrost_tag::Column::SubjectId)
+            .filter(entity::bifrost_tag::Column::Name.eq(name))
+            .order_by_asc(entity::bifrost_tag::Column::Id)
+            .into_tuple()
+            .all(self.db)
+            .await
+    }
+
+    /// Moves the tags of a subject to another subject of the same kind.
+    ///
+    /// Tags the receiving subject already carries are deleted from the source subject
+    /// instead of being moved, so each subject still carries a tag name at most once.
+    ///
+    /// # Arguments
+    /// - `subject_type` - Kind of both subjects
+    /// - `from_subject_id` - ID of the subject whose tags are moved
+    /// - `to_subject_id` - ID of the subject receiving the tags
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of tags moved
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn reassign_subject(
+        &self,
+        subject_type: &str,
+        from_subject_id: i64,
+        to_subject_id: i64,
+    ) -> Result<u64, DbErr> {
+        let existing: Vec<String> = self
+            .get_by_subject(subject_type, to_subject_id)
+            .await?
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+
+        entity::prelude::BifrostTag::delete_many()
+            .filter(entity::bifrost_tag::Column::SubjectType.eq(subject_type))
+            .filter(entity::bifrost_tag::Column::SubjectId.eq(from_subject_id))
+            .filter(entity::bifrost_tag::Column::Name.is_in(existing))
+            .exec(self.db)
+            .await?;
+
+        Ok(entity::prelude::BifrostTag::update_many()
+            .col_expr(
+                entity::bifrost_tag::Column::SubjectId,
+                Expr::value(to_subject_id),
+            )
+            .filter(entity::bifrost_tag::Column::SubjectType.eq(subject_type))
+            .filter(entity::bifrost_tag::Column::SubjectId.eq(from_subject_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Deletes a tag.
+    ///
+    /// # Arguments
+    /// - `tag_id` - ID of the tag to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   tag didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, tag_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostTag::delete_by_id(tag_id)
+            .exec(self.db)
+            .await
+    }
+}
@@ -0,0 +1,276 @@
+//! Announcement data repository.
+//!
+//! This module contains the `AnnouncementRepository` for storing broadcasts posted by admins
+//! and the recipient rows which make up each user's announcement inbox. Recipients double as
+//! read receipts, so leadership can see how many members have read an announcement.
+
+use chrono::Utc;
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, UpdateResult,
+};
+
+use crate::server::model::db::{AnnouncementModel, AnnouncementRecipientModel};
+
+/// Repository for managing announcement and announcement recipient records in the database.
+pub struct AnnouncementRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> AnnouncementRepository<'a, C> {
+    /// Creates a new instance of AnnouncementRepository.
+    ///
+    /// Constructs a repository for managing announcement records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `AnnouncementRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates an announcement.
+    ///
+    /// # Arguments
+    /// - `title` - Announcement title
+    /// - `body` - Announcement body as Markdown
+    /// - `audience` - Audience kind the announcement targets
+    /// - `audience_id` - EVE Online corporation or alliance ID the announcement targets
+    /// - `created_by_user_id` - ID of the user posting the announcement
+    ///
+    /// # Returns
+    /// - `Ok(AnnouncementModel)` - The newly created announcement record
+    /// - `Err(DbErr)` - Database operation failed or the user ID doesn't exist
+    pub async fn create(
+        &self,
+        title: String,
+        body: String,
+        audience: &str,
+        audience_id: Option<i64>,
+        created_by_user_id: i32,
+    ) -> Result<AnnouncementModel, DbErr> {
+        let announcement = entity::bifrost_announcement::ActiveModel {
+            title: ActiveValue::Set(title),
+            body: ActiveValue::Set(body),
+            audience: ActiveValue::Set(audience.to_string()),
+            audience_id: ActiveValue::Set(audience_id),
+            created_by_user_id: ActiveValue::Set(created_by_user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        announcement.insert(self.db).await
+    }
+
+    /// Adds an announcement to the inbox of each user.
+    ///
+    /// # Arguments
+    /// - `announcement_id` - ID of the announcement
+    /// - `user_ids` - IDs of the receiving users
+    ///
+    /// # Returns
+    /// - `Ok(())` - Recipients added (no-op if `user_ids` is empty)
+    /// - `Err(DbErr)` - Database operation failed or a user ID doesn't exist
+    pub async fn add_recipients(
+        &self,
+        announcement_id: i32,
+        user_ids: &[i32],
+    ) -> Result<(), DbErr> {
+        if user_ids.is_empty() {
+            return Ok(());
+        }
+
+        let recipients =
+            user_ids.iter().map(
+                |user_id| entity::bifrost_announcement_recipient::ActiveModel {
+                    announcement_id: ActiveValue::Set(announcement_id),
+                    user_id: ActiveValue::Set(*user_id),
+                    read_at: ActiveValue::Set(None),
+                    ..Default::default()
+                },
+            );
+
+        entity::prelude::BifrostAnnouncementRecipient::insert_many(recipients)
+            .exec_without_returning(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves all announcements, newest first.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<AnnouncementModel>)` - All announcements (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<AnnouncementModel>, DbErr> {
+        entity::prelude::BifrostAnnouncement::find()
+            .order_by_desc(entity::bifrost_announcement::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Counts the recipients of each announcement and how many of them have read it.
+    ///
+    /// Announcements without recipients are not included.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64, i64)>)` - List of (announcement ID, recipient count, read count)
+    ///   tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn count_recipients(&self) -> Result<Vec<(i32, i64, i64)>, DbErr> {
+        entity::prelude::BifrostAnnouncementRecipient::find()
+            .select_only()
+            .column(entity::bifrost_announcement_recipient::Column::AnnouncementId)
+            .column_as(
+                Func::count(Expr::col(
+                    entity::bifrost_announcement_recipient::Column::Id,
+                )),
+                "recipient_count",
+            )
+            .column_as(
+                Func::count(Expr::col(
+                    entity::bifrost_announcement_recipient::Column::ReadAt,
+                )),
+                "read_count",
+            )
+            .group_by(entity::bifrost_announcement_recipient::Column::AnnouncementId)
+            .into_tuple::<(i32, i64, i64)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the announcements in a user's inbox, newest first.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the receiving user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(AnnouncementRecipientModel, AnnouncementModel)>)` - Recipient rows of the user
+    ///   paired with their announcement
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_inbox(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<(AnnouncementRecipientModel, AnnouncementModel)>, DbErr> {
+        let rows = entity::prelude::BifrostAnnouncementRecipient::find()
+            .filter(entity::bifrost_announcement_recipient::Column::UserId.eq(user_id))
+            .find_also_related(entity::prelude::BifrostAnnouncement)
+            .order_by_desc(entity::bifrost_announcement_recipient::Column::AnnouncementId)
+            .all(self.db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(recipient, announcement)| {
+                announcement.map(|announcement| (recipient, announcement))
+            })
+            .collect())
+    }
+
+    /// Retrieves the recipient row of an announcement for a user.
+    ///
+    /// # Arguments
+    /// - `announcement_id` - ID of the announcement
+    /// - `user_id` - ID of the receiving user
+    ///
+    /// # Returns
+    /// - `Ok(Some(AnnouncementRecipientModel))` - Announcement is in the user's inbox
+    /// - `Ok(None)` - Announcement doesn't exist or wasn't sent to the user
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_recipient(
+        &self,
+        announcement_id: i32,
+        user_id: i32,
+    ) -> Result<Option<AnnouncementRecipientModel>, DbErr> {
+        entity::prelude::BifrostAnnouncementRecipient::find()
+            .filter(
+                entity::bifrost_announcement_recipient::Column::AnnouncementId.eq(announcement_id),
+            )
+            .filter(entity::bifrost_announcement_recipient::Column::UserId.eq(user_id))
+            .one(self.db)
+            .await
+    }
+
+    /// Marks a recipient row as read unless it already is.
+    ///
+    /// # Arguments
+    /// - `recipient_id` - ID of the recipient row
+    ///
+    /// # Returns
+    /// - `Ok(UpdateResult)` - Operation completed (check rows_affected: 1 if marked read, 0 if
+    ///   it was already read or doesn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn mark_read(&self, recipient_id: i32) -> Result<UpdateResult, DbErr> {
+        entity::prelude::BifrostAnnouncementRecipient::update_many()
+            .col_expr(
+                entity::bifrost_announcement_recipient::Column::ReadAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(entity::bifrost_announcement_recipient::Column::Id.eq(recipient_id))
+            .filter(entity::bifrost_announcement_recipient::Column::ReadAt.is_null())
+            .exec(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for AnnouncementRepository::count_recipients method.
+    mod count_recipients {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::announcement::AnnouncementRepository;
+
+        /// Tests counting recipients after one of two users read an announcement.
+        ///
+        /// Verifies that recipients and read receipts are counted per announcement.
+        ///
+        /// Expected: Ok with two recipients and one read
+        #[tokio::test]
+        async fn counts_read_receipts() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostAnnouncement)
+                .with_table(entity::prelude::BifrostAnnouncementRecipient)
+                .build()
+                .await?;
+            let (first_user, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let (second_user, _, _) = test
+                .user()
+                .insert_user_with_mock_character(2, 1, None, None)
+                .await?;
+
+            let repository = AnnouncementRepository::new(&test.db);
+            let announcement = repository
+                .create(
+                    "Fleet tonight".to_string(),
+                    "Form up at 19:00.".to_string(),
+                    "all",
+                    None,
+                    first_user.id,
+                )
+                .await?;
+            repository
+                .add_recipients(announcement.id, &[first_user.id, second_user.id])
+                .await?;
+            let recipient = repository
+                .get_recipient(announcement.id, second_user.id)
+                .await?
+                .unwrap();
+            repository.mark_read(recipient.id).await?;
+
+            let counts = repository.count_recipients().await?;
+
+            assert_eq!(counts, vec![(announcement.id, 2, 1)]);
+
+            Ok(())
+        }
+    }
+}
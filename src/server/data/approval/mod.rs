@@ -0,0 +1,164 @@
+//! Approval request data repository.
+//!
+//! This module contains the `ApprovalRequestRepository` for the pending, approved, rejected,
+//! and failed requests of sensitive admin actions that require a second admin. Decisions only
+//! apply to requests that are still pending, so two admins deciding the same request at once
+//! can't both succeed.
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+
+use crate::{model::approval::ApprovalStatus, server::model::db::ApprovalRequestModel};
+
+/// Repository for managing approval request records in the database.
+pub struct ApprovalRequestRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> ApprovalRequestRepository<'a, C> {
+    /// Creates a new instance of ApprovalRequestRepository.
+    ///
+    /// Constructs a repository for managing approval request records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ApprovalRequestRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a pending approval request.
+    ///
+    /// # Arguments
+    /// - `requested_by_user_id` - ID of the admin requesting the action
+    /// - `action` - Name of the sensitive action
+    /// - `payload` - Arguments of the action serialized as JSON
+    /// - `expires_at` - Timestamp after which the request can no longer be decided
+    ///
+    /// # Returns
+    /// - `Ok(ApprovalRequestModel)` - The created request
+    /// - `Err(DbErr)` - Database operation failed or the user ID doesn't exist
+    pub async fn create(
+        &self,
+        requested_by_user_id: i32,
+        action: &str,
+        payload: String,
+        expires_at: NaiveDateTime,
+    ) -> Result<ApprovalRequestModel, DbErr> {
+        let request = entity::bifrost_approval_request::ActiveModel {
+            action: ActiveValue::Set(action.to_string()),
+            payload: ActiveValue::Set(payload),
+            status: ActiveValue::Set(ApprovalStatus::Pending.as_str().to_string()),
+            requested_by_user_id: ActiveValue::Set(requested_by_user_id),
+            decided_by_user_id: ActiveValue::Set(None),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            expires_at: ActiveValue::Set(expires_at),
+            decided_at: ActiveValue::Set(None),
+            ..Default::default()
+        };
+
+        request.insert(self.db).await
+    }
+
+    /// Retrieves an approval request by ID.
+    ///
+    /// # Arguments
+    /// - `request_id` - ID of the request to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(ApprovalRequestModel))` - Request found
+    /// - `Ok(None)` - No request with the ID exists
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_id(&self, request_id: i32) -> Result<Option<ApprovalRequestModel>, DbErr> {
+        entity::prelude::BifrostApprovalRequest::find_by_id(request_id)
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves the most recent approval requests, newest first.
+    ///
+    /// # Arguments
+    /// - `limit` - Maximum number of requests to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ApprovalRequestModel>)` - The most recent requests
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_recent(&self, limit: u64) -> Result<Vec<ApprovalRequestModel>, DbErr> {
+        entity::prelude::BifrostApprovalRequest::find()
+            .order_by_desc(entity::bifrost_approval_request::Column::CreatedAt)
+            .order_by_desc(entity::bifrost_approval_request::Column::Id)
+            .limit(limit)
+            .all(self.db)
+            .await
+    }
+
+    /// Records the decision on a request if it is still pending.
+    ///
+    /// # Arguments
+    /// - `request_id` - ID of the request to decide
+    /// - `status` - Status the request is decided with
+    /// - `decided_by_user_id` - ID of the admin deciding the request
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The request was pending and is now decided
+    /// - `Ok(false)` - The request doesn't exist or was already decided
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn decide(
+        &self,
+        request_id: i32,
+        status: ApprovalStatus,
+        decided_by_user_id: i32,
+    ) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostApprovalRequest::update_many()
+            .col_expr(
+                entity::bifrost_approval_request::Column::Status,
+                Expr::value(status.as_str()),
+            )
+            .col_expr(
+                entity::bifrost_approval_request::Column::DecidedByUserId,
+                Expr::value(decided_by_user_id),
+            )
+            .col_expr(
+                entity::bifrost_approval_request::Column::DecidedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(entity::bifrost_approval_request::Column::Id.eq(request_id))
+            .filter(
+                entity::bifrost_approval_request::Column::Status
+                    .eq(ApprovalStatus::Pending.as_str()),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// Updates the status of a request regardless of its current status.
+    ///
+    /// Used to record that an approved action failed to run.
+    ///
+    /// # Arguments
+    /// - `request_id` - ID of the request to update
+    /// - `status` - New status of the request
+    ///
+    /// # Returns
+    /// - `Ok(())` - Status updated, or the request doesn't exist
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn set_status(&self, request_id: i32, status: ApprovalStatus) -> Result<(), DbErr> {
+        entity::prelude::BifrostApprovalRequest::update_many()
+            .col_expr(
+                entity::bifrost_approval_request::Column::Status,
+                Expr::value(status.as_str()),
+            )
+            .filter(entity::bifrost_approval_request::Column::Id.eq(request_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+}
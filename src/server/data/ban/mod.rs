@@ -0,0 +1,155 @@
+//! User ban data repository.
+//!
+//! This module contains the `UserBanRepository` for the bans suspending users. Bans are kept
+//! after they expire or are lifted, so admins can see a user's history of suspensions.
+
+use chrono::NaiveDateTime;
+use sea_orm::{
+    sea_query::Condition, ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr,
+    EntityTrait, IntoActiveModel, QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::UserBanModel;
+
+/// Repository for managing user ban records in the database.
+pub struct UserBanRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> UserBanRepository<'a, C> {
+    /// Creates a new instance of UserBanRepository.
+    ///
+    /// Constructs a repository for managing user ban records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `UserBanRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a ban suspending a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the suspended user
+    /// - `reason` - Reason shown to the user and admins
+    /// - `issued_by_user_id` - ID of the admin issuing the ban
+    /// - `created_at` - Time the ban is issued at
+    /// - `expires_at` - Time the ban ends, `None` for a permanent ban
+    ///
+    /// # Returns
+    /// - `Ok(UserBanModel)` - The newly created ban record
+    /// - `Err(DbErr)` - Database operation failed or the user doesn't exist
+    pub async fn create(
+        &self,
+        user_id: i32,
+        reason: String,
+        issued_by_user_id: Option<i32>,
+        created_at: NaiveDateTime,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<UserBanModel, DbErr> {
+        let ban = entity::bifrost_user_ban::ActiveModel {
+            user_id: ActiveValue::Set(user_id),
+            reason: ActiveValue::Set(reason),
+            issued_by_user_id: ActiveValue::Set(issued_by_user_id),
+            created_at: ActiveValue::Set(created_at),
+            expires_at: ActiveValue::Set(expires_at),
+            lifted_at: ActiveValue::Set(None),
+            lifted_by_user_id: ActiveValue::Set(None),
+            ..Default::default()
+        };
+
+        ban.insert(self.db).await
+    }
+
+    /// Finds a ban by ID.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the ban
+    ///
+    /// # Returns
+    /// - `Ok(Some(UserBanModel))` - Ban found
+    /// - `Ok(None)` - Ban doesn't exist
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<UserBanModel>, DbErr> {
+        entity::prelude::BifrostUserBan::find_by_id(id)
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all bans of a user, including expired and lifted bans.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserBanModel>)` - Bans of the user, most recently issued first
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(&self, user_id: i32) -> Result<Vec<UserBanModel>, DbErr> {
+        entity::prelude::BifrostUserBan::find()
+            .filter(entity::bifrost_user_ban::Column::UserId.eq(user_id))
+            .order_by_desc(entity::bifrost_user_ban::Column::CreatedAt)
+            .order_by_desc(entity::bifrost_user_ban::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the bans of a user in effect at a point in time.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `now` - Time to check the bans at
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserBanModel>)` - Bans neither lifted nor expired (empty if the user isn't
+    ///   suspended)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_active_by_user_id(
+        &self,
+        user_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<Vec<UserBanModel>, DbErr> {
+        entity::prelude::BifrostUserBan::find()
+            .filter(entity::bifrost_user_ban::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_user_ban::Column::LiftedAt.is_null())
+            .filter(
+                Condition::any()
+                    .add(entity::bifrost_user_ban::Column::ExpiresAt.is_null())
+                    .add(entity::bifrost_user_ban::Column::ExpiresAt.gt(now)),
+            )
+            .all(self.db)
+            .await
+    }
+
+    /// Lifts a ban before it ends.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the ban
+    /// - `lifted_by_user_id` - ID of the admin lifting the ban
+    /// - `lifted_at` - Time the ban is lifted at
+    ///
+    /// # Returns
+    /// - `Ok(Some(UserBanModel))` - Ban successfully lifted
+    /// - `Ok(None)` - Ban doesn't exist
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn lift(
+        &self,
+        id: i32,
+        lifted_by_user_id: i32,
+        lifted_at: NaiveDateTime,
+    ) -> Result<Option<UserBanModel>, DbErr> {
+        let Some(ban) = self.find_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        let mut ban_am = ban.into_active_model();
+        ban_am.lifted_at = ActiveValue::Set(Some(lifted_at));
+        ban_am.lifted_by_user_id = ActiveValue::Set(Some(lifted_by_user_id));
+
+        let ban = ban_am.update(self.db).await?;
+
+        Ok(Some(ban))
+    }
+}
@@ -0,0 +1,239 @@
+//! Campaign data repository.
+//!
+//! This module contains the `CampaignRepository` for storing deployment and war campaigns
+//! tracked by leadership, each staged out of one solar system over a date range.
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::CampaignModel;
+
+/// Repository for managing campaign records in the database.
+///
+/// Provides operations for creating, retrieving, and deleting campaigns.
+pub struct CampaignRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> CampaignRepository<'a, C> {
+    /// Creates a new instance of CampaignRepository.
+    ///
+    /// Constructs a repository for managing campaign records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `CampaignRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new campaign.
+    ///
+    /// # Arguments
+    /// - `name` - Unique campaign name
+    /// - `staging_system` - Name of the solar system the campaign stages out of
+    /// - `starts_at` - Timestamp when the campaign starts
+    /// - `ends_at` - Timestamp when the campaign ends, None if ongoing
+    /// - `created_by_user_id` - ID of the user creating the campaign
+    ///
+    /// # Returns
+    /// - `Ok(CampaignModel)` - The newly created campaign record
+    /// - `Err(DbErr)` - Database operation failed, a campaign with the name already exists, or
+    ///   the user ID doesn't exist
+    pub async fn create(
+        &self,
+        name: String,
+        staging_system: String,
+        starts_at: NaiveDateTime,
+        ends_at: Option<NaiveDateTime>,
+        created_by_user_id: i32,
+    ) -> Result<CampaignModel, DbErr> {
+        let campaign = entity::bifrost_campaign::ActiveModel {
+            name: ActiveValue::Set(name),
+            staging_system: ActiveValue::Set(staging_system),
+            starts_at: ActiveValue::Set(starts_at),
+            ends_at: ActiveValue::Set(ends_at),
+            created_by_user_id: ActiveValue::Set(created_by_user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            updated_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        campaign.insert(self.db).await
+    }
+
+    /// Retrieves a campaign by name.
+    ///
+    /// # Arguments
+    /// - `name` - Name of the campaign to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(CampaignModel))` - Campaign found
+    /// - `Ok(None)` - No campaign with the name exists
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_name(&self, name: &str) -> Result<Option<CampaignModel>, DbErr> {
+        entity::prelude::BifrostCampaign::find()
+            .filter(entity::bifrost_campaign::Column::Name.eq(name))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all campaigns, most recently started first.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<CampaignModel>)` - All campaigns (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<CampaignModel>, DbErr> {
+        entity::prelude::BifrostCampaign::find()
+            .order_by_desc(entity::bifrost_campaign::Column::StartsAt)
+            .order_by_asc(entity::bifrost_campaign::Column::Name)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the campaigns starting within a time range, soonest first.
+    ///
+    /// # Arguments
+    /// - `from` - Start of the range (inclusive)
+    /// - `until` - End of the range (exclusive)
+    ///
+    /// # Returns
+    /// - `Ok(Vec<CampaignModel>)` - Campaigns starting within the range
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_starting_between(
+        &self,
+        from: NaiveDateTime,
+        until: NaiveDateTime,
+    ) -> Result<Vec<CampaignModel>, DbErr> {
+        entity::prelude::BifrostCampaign::find()
+            .filter(entity::bifrost_campaign::Column::StartsAt.gte(from))
+            .filter(entity::bifrost_campaign::Column::StartsAt.lt(until))
+            .order_by_asc(entity::bifrost_campaign::Column::StartsAt)
+            .order_by_asc(entity::bifrost_campaign::Column::Name)
+            .all(self.db)
+            .await
+    }
+
+    /// Deletes a campaign by ID.
+    ///
+    /// # Arguments
+    /// - `campaign_id` - ID of the campaign to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   campaign didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, campaign_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostCampaign::delete_by_id(campaign_id)
+            .exec(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for CampaignRepository::get_all method.
+    mod get_all {
+        use bifrost_test_utils::prelude::*;
+        use chrono::NaiveDate;
+
+        use crate::server::data::campaign::CampaignRepository;
+
+        /// Tests retrieving campaigns ordered by start date.
+        ///
+        /// Verifies that the most recently started campaign is returned first.
+        ///
+        /// Expected: Ok(vec) with the later campaign first
+        #[tokio::test]
+        async fn returns_most_recent_first() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostCampaign)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let date = |day| {
+                NaiveDate::from_ymd_opt(2026, 1, day)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+            };
+
+            let repository = CampaignRepository::new(&test.db);
+            repository
+                .create(
+                    "Fountain War".to_string(),
+                    "Y-2ANO".to_string(),
+                    date(1),
+                    Some(date(10)),
+                    user_model.id,
+                )
+                .await?;
+            repository
+                .create(
+                    "Delve Deployment".to_string(),
+                    "1DQ1-A".to_string(),
+                    date(15),
+                    None,
+                    user_model.id,
+                )
+                .await?;
+            let campaigns = repository.get_all().await?;
+
+            let names: Vec<&str> = campaigns.iter().map(|c| c.name.as_str()).collect();
+            assert_eq!(names, vec!["Delve Deployment", "Fountain War"]);
+
+            Ok(())
+        }
+    }
+
+    /// Tests for CampaignRepository::delete method.
+    mod delete {
+        use bifrost_test_utils::prelude::*;
+        use chrono::Utc;
+
+        use crate::server::data::campaign::CampaignRepository;
+
+        /// Tests deleting an existing campaign.
+        ///
+        /// Expected: Ok with 1 row affected and the campaign no longer found
+        #[tokio::test]
+        async fn deletes_campaign() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostCampaign)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = CampaignRepository::new(&test.db);
+            let campaign = repository
+                .create(
+                    "Fountain War".to_string(),
+                    "Y-2ANO".to_string(),
+                    Utc::now().naive_utc(),
+                    None,
+                    user_model.id,
+                )
+                .await?;
+            let result = repository.delete(campaign.id).await?;
+
+            assert_eq!(result.rows_affected, 1);
+            assert!(repository.get_by_name("Fountain War").await?.is_none());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Character skill data repository.
+//!
+//! This module contains the `CharacterSkillRepository` for the skill snapshots fetched from ESI
+//! for characters whose refresh token grants the skills scope. Each refresh replaces the
+//! character's snapshot, so skills a character no longer has, e.g. after extracting them, are
+//! removed.
+
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+use migration::{Expr, Func, OnConflict};
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait,
+};
+
+use crate::server::model::db::CharacterSkillModel;
+
+/// Repository for managing character skill records in the database.
+pub struct CharacterSkillRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> CharacterSkillRepository<'a, C> {
+    /// Creates a new instance of CharacterSkillRepository.
+    ///
+    /// Constructs a repository for managing character skill records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `CharacterSkillRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Replaces a character's skill snapshot.
+    ///
+    /// Skills no longer in the snapshot are removed, and every listed skill is inserted or
+    /// updated with its levels, skill points, and a refreshed update timestamp.
+    ///
+    /// # Arguments
+    /// - `character_id` - Record ID of the character
+    /// - `skills` - Tuples of (skill_id, active_level, trained_level, skillpoints)
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of skills removed from the snapshot
+    /// - `Err(DbErr)` - Database operation failed or the character doesn't exist
+    ///
+    /// # Notes
+    /// - For transactional behavior, pass a transaction as the connection
+    pub async fn replace_skills(
+        &self,
+        character_id: i32,
+        skills: &[(i64, i32, i32, i64)],
+    ) -> Result<u64, DbErr> {
+        let removed = entity::prelude::EveCharacterSkill::delete_many()
+            .filter(entity::eve_character_skill::Column::CharacterId.eq(character_id))
+            .filter(
+                entity::eve_character_skill::Column::SkillId
+                    .is_not_in(skills.iter().map(|(skill_id, ..)| *skill_id)),
+            )
+            .exec(self.db)
+            .await?
+            .rows_affected;
+
+        if skills.is_empty() {
+            return Ok(removed);
+        }
+
+        let now = Utc::now().naive_utc();
+        let skills: Vec<entity::eve_character_skill::ActiveModel> = skills
+            .iter()
+            .map(|(skill_id, active_level, trained_level, skillpoints)| {
+                entity::eve_character_skill::ActiveModel {
+                    character_id: ActiveValue::Set(character_id),
+                    skill_id: ActiveValue::Set(*skill_id),
+                    active_level: ActiveValue::Set(*active_level),
+                    trained_level: ActiveValue::Set(*trained_level),
+                    skillpoints: ActiveValue::Set(*skillpoints),
+                    updated_at: ActiveValue::Set(now),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        entity::prelude::EveCharacterSkill::insert_many(skills)
+            .on_conflict(
+                OnConflict::columns([
+                    entity::eve_character_skill::Column::CharacterId,
+                    entity::eve_character_skill::Column::SkillId,
+                ])
+                .update_columns([
+                    entity::eve_character_skill::Column::ActiveLevel,
+                    entity::eve_character_skill::Column::TrainedLevel,
+                    entity::eve_character_skill::Column::Skillpoints,
+                    entity::eve_character_skill::Column::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(removed)
+    }
+
+    /// Retrieves the skill snapshot of a character.
+    ///
+    /// # Arguments
+    /// - `character_id` - Record ID of the character
+    ///
+    /// # Returns
+    /// - `Ok(Vec<CharacterSkillModel>)` - Skills ordered by skill ID (empty if the snapshot
+    ///   was never fetched)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_skills(&self, character_id: i32) -> Result<Vec<CharacterSkillModel>, DbErr> {
+        entity::prelude::EveCharacterSkill::find()
+            .filter(entity::eve_character_skill::Column::CharacterId.eq(character_id))
+            .order_by_asc(entity::eve_character_skill::Column::SkillId)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves when the skill snapshots of characters were last refreshed.
+    ///
+    /// # Arguments
+    /// - `character_ids` - Record IDs of the characters
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, NaiveDateTime)>)` - Tuples of (character_id, updated_at) for characters
+    ///   with a snapshot (characters without one are left out)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_last_updated(
+        &self,
+        character_ids: &[i32],
+    ) -> Result<Vec<(i32, NaiveDateTime)>, DbErr> {
+        entity::prelude::EveCharacterSkill::find()
+            .select_only()
+            .column(entity::eve_character_skill::Column::CharacterId)
+            .column_as(
+                Func::max(Expr::col(entity::eve_character_skill::Column::UpdatedAt)),
+                "updated_at",
+            )
+            .filter(
+                entity::eve_character_skill::Column::CharacterId
+                    .is_in(character_ids.iter().copied()),
+            )
+            .group_by(entity::eve_character_skill::Column::CharacterId)
+            .into_tuple::<(i32, NaiveDateTime)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the active skill levels of characters.
+    ///
+    /// # Arguments
+    /// - `character_ids` - Record IDs of the characters
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, HashMap<i64, i32>>)` - Active levels keyed by skill ID, keyed by
+    ///   character ID (characters without a snapshot are left out)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_active_levels(
+        &self,
+        character_ids: &[i32],
+    ) -> Result<HashMap<i32, HashMap<i64, i32>>, DbErr> {
+        if character_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let skills = entity::prelude::EveCharacterSkill::find()
+            .select_only()
+            .column(entity::eve_character_skill::Column::CharacterId)
+            .column(entity::eve_character_skill::Column::SkillId)
+            .column(entity::eve_character_skill::Column::ActiveLevel)
+            .filter(
+                entity::eve_character_skill::Column::CharacterId
+                    .is_in(character_ids.iter().copied()),
+            )
+            .into_tuple::<(i32, i64, i32)>()
+            .all(self.db)
+            .await?;
+
+        let mut levels: HashMap<i32, HashMap<i64, i32>> = HashMap::new();
+        for (character_id, skill_id, active_level) in skills {
+            levels
+                .entry(character_id)
+                .or_default()
+                .insert(skill_id, active_level);
+        }
+
+        Ok(levels)
+    }
+
+    /// Retrieves the characters of a corporation that have a skill snapshot.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - Record ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - Record IDs of the corporation's characters with a snapshot
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_tracked_character_ids_by_corporation(
+        &self,
+        corporation_id: i32,
+    ) -> Result<Vec<i32>, DbErr> {
+        entity::prelude::EveCharacterSkill::find()
+            .select_only()
+            .column(entity::eve_character_skill::Column::CharacterId)
+            .distinct()
+            .join(
+                JoinType::InnerJoin,
+                entity::eve_character_skill::Relation::EveCharacter.def(),
+            )
+            .filter(entity::eve_character::Column::CorporationId.eq(corporation_id))
+            .into_tuple::<i32>()
+            .all(self.db)
+            .await
+    }
+}
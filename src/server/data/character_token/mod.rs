@@ -0,0 +1,175 @@
+//! Character token data repository.
+//!
+//! This module contains the `CharacterTokenRepository` for the EVE SSO refresh tokens stored
+//! per character, which scoped ESI requests are made with. Tokens are stored encrypted by the
+//! service layer; the repository never sees them in plaintext.
+
+use chrono::Utc;
+use migration::{Expr, OnConflict};
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+
+use crate::server::model::db::CharacterTokenModel;
+
+/// Repository for managing character token records in the database.
+pub struct CharacterTokenRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> CharacterTokenRepository<'a, C> {
+    /// Creates a new instance of CharacterTokenRepository.
+    ///
+    /// Constructs a repository for managing character token records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `CharacterTokenRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Stores the refresh token of a character.
+    ///
+    /// Replaces the character's existing token and scopes, if any, keeping its creation time.
+    ///
+    /// # Arguments
+    /// - `character_id` - Record ID of the character
+    /// - `refresh_token` - Encrypted refresh token
+    /// - `scopes` - Space-separated ESI scopes the character granted
+    ///
+    /// # Returns
+    /// - `Ok(CharacterTokenModel)` - The stored token record
+    /// - `Err(DbErr)` - Database operation failed or the character doesn't exist
+    pub async fn upsert(
+        &self,
+        character_id: i32,
+        refresh_token: String,
+        scopes: String,
+    ) -> Result<CharacterTokenModel, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        entity::prelude::BifrostCharacterToken::insert(
+            entity::bifrost_character_token::ActiveModel {
+                character_id: ActiveValue::Set(character_id),
+                refresh_token: ActiveValue::Set(refresh_token),
+                scopes: ActiveValue::Set(scopes),
+                created_at: ActiveValue::Set(now),
+                updated_at: ActiveValue::Set(now),
+                ..Default::default()
+            },
+        )
+        .on_conflict(
+            OnConflict::column(entity::bifrost_character_token::Column::CharacterId)
+                .update_columns([
+                    entity::bifrost_character_token::Column::RefreshToken,
+                    entity::bifrost_character_token::Column::Scopes,
+                    entity::bifrost_character_token::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(self.db)
+        .await
+    }
+
+    /// Retrieves the token of a character.
+    ///
+    /// # Arguments
+    /// - `character_id` - Record ID of the character
+    ///
+    /// # Returns
+    /// - `Ok(Some(CharacterTokenModel))` - Token found
+    /// - `Ok(None)` - No token is stored for the character
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find_by_character_id(
+        &self,
+        character_id: i32,
+    ) -> Result<Option<CharacterTokenModel>, DbErr> {
+        entity::prelude::BifrostCharacterToken::find()
+            .filter(entity::bifrost_character_token::Column::CharacterId.eq(character_id))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves every stored token.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<CharacterTokenModel>)` - Tokens ordered by ID
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<CharacterTokenModel>, DbErr> {
+        entity::prelude::BifrostCharacterToken::find()
+            .order_by_asc(entity::bifrost_character_token::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the characters whose stored token grants a scope.
+    ///
+    /// # Arguments
+    /// - `scope` - ESI scope the token must grant
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64)>)` - Tuples of (record_id, character_id) ordered by record ID
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_character_ids_with_scope(
+        &self,
+        scope: &str,
+    ) -> Result<Vec<(i32, i64)>, DbErr> {
+        entity::prelude::BifrostCharacterToken::find()
+            .select_only()
+            .column(entity::eve_character::Column::Id)
+            .column(entity::eve_character::Column::CharacterId)
+            .inner_join(entity::prelude::EveCharacter)
+            .filter(entity::bifrost_character_token::Column::Scopes.contains(scope))
+            .order_by_asc(entity::eve_character::Column::Id)
+            .into_tuple::<(i32, i64)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Replaces the refresh token of a token record after EVE SSO rotated it.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the token record
+    /// - `refresh_token` - Encrypted rotated refresh token
+    ///
+    /// # Returns
+    /// - `Ok(())` - Token replaced, or the record no longer exists
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn update_refresh_token(&self, id: i32, refresh_token: String) -> Result<(), DbErr> {
+        entity::prelude::BifrostCharacterToken::update_many()
+            .col_expr(
+                entity::bifrost_character_token::Column::RefreshToken,
+                Expr::value(refresh_token),
+            )
+            .col_expr(
+                entity::bifrost_character_token::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(entity::bifrost_character_token::Column::Id.eq(id))
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes a token record.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the token record
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Token deleted
+    /// - `Ok(false)` - Token didn't exist
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostCharacterToken::delete_by_id(id)
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
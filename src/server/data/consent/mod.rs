@@ -0,0 +1,213 @@
+//! Consent data repositories.
+//!
+//! This module contains the `UserConsentRepository` for managing which categories of data
+//! each user has consented to share with the organization.
+
+use chrono::Utc;
+use migration::OnConflict;
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait, PaginatorTrait,
+    QueryFilter,
+};
+
+use crate::server::model::db::UserConsentModel;
+
+/// Repository for managing user data-sharing consent records in the database.
+///
+/// Each record represents consent granted by a user for a single data category. Categories
+/// are stored by name so new categories can be added without a migration.
+pub struct UserConsentRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> UserConsentRepository<'a, C> {
+    /// Creates a new instance of UserConsentRepository.
+    ///
+    /// Constructs a repository for managing user consent records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `UserConsentRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Records that a user granted consent for a data category.
+    ///
+    /// Granting consent that was already granted is a no-op and keeps the original
+    /// `granted_at` timestamp.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user granting consent
+    /// - `category` - Consent category name
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of rows inserted (1 if granted, 0 if consent already existed)
+    /// - `Err(DbErr)` - Database operation failed or user doesn't exist
+    pub async fn grant(&self, user_id: i32, category: &str) -> Result<u64, DbErr> {
+        entity::prelude::BifrostUserConsent::insert(entity::bifrost_user_consent::ActiveModel {
+            user_id: ActiveValue::Set(user_id),
+            category: ActiveValue::Set(category.to_string()),
+            granted_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::columns([
+                entity::bifrost_user_consent::Column::UserId,
+                entity::bifrost_user_consent::Column::Category,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(self.db)
+        .await
+    }
+
+    /// Removes a user's consent for a data category.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user revoking consent
+    /// - `category` - Consent category name
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if revoked, 0 if consent wasn't granted)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn revoke(&self, user_id: i32, category: &str) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostUserConsent::delete_many()
+            .filter(entity::bifrost_user_consent::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_user_consent::Column::Category.eq(category))
+            .exec(self.db)
+            .await
+    }
+
+    /// Retrieves all consent records for a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserConsentModel>)` - Consent granted by the user (empty if none)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(&self, user_id: i32) -> Result<Vec<UserConsentModel>, DbErr> {
+        entity::prelude::BifrostUserConsent::find()
+            .filter(entity::bifrost_user_consent::Column::UserId.eq(user_id))
+            .all(self.db)
+            .await
+    }
+
+    /// Checks whether a user has granted consent for a data category.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `category` - Consent category name
+    ///
+    /// # Returns
+    /// - `Ok(true)` - User has granted consent
+    /// - `Ok(false)` - User has not granted consent
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn has_consent(&self, user_id: i32, category: &str) -> Result<bool, DbErr> {
+        let count = entity::prelude::BifrostUserConsent::find()
+            .filter(entity::bifrost_user_consent::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_user_consent::Column::Category.eq(category))
+            .count(self.db)
+            .await?;
+
+        Ok(count > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for UserConsentRepository::grant method.
+    mod grant {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::consent::UserConsentRepository;
+
+        /// Tests granting consent for a category.
+        ///
+        /// Verifies that granted consent is reported by has_consent and that granting the
+        /// same category twice does not insert a duplicate record.
+        ///
+        /// Expected: Ok with 1 row inserted, then 0 rows on the repeated grant
+        #[tokio::test]
+        async fn grants_consent_once() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostUserConsent)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = UserConsentRepository::new(&test.db);
+
+            assert_eq!(repository.grant(user_model.id, "assets").await?, 1);
+            assert_eq!(repository.grant(user_model.id, "assets").await?, 0);
+            assert!(repository.has_consent(user_model.id, "assets").await?);
+            assert!(!repository.has_consent(user_model.id, "wallet").await?);
+            assert_eq!(repository.get_by_user_id(user_model.id).await?.len(), 1);
+
+            Ok(())
+        }
+
+        /// Tests error handling for a nonexistent user.
+        ///
+        /// Expected: Err
+        #[tokio::test]
+        async fn fails_for_nonexistent_user() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostUserConsent)
+                .build()
+                .await?;
+
+            let nonexistent_user_id = 1;
+            let result = UserConsentRepository::new(&test.db)
+                .grant(nonexistent_user_id, "assets")
+                .await;
+
+            assert!(result.is_err());
+
+            Ok(())
+        }
+    }
+
+    /// Tests for UserConsentRepository::revoke method.
+    mod revoke {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::consent::UserConsentRepository;
+
+        /// Tests revoking granted consent.
+        ///
+        /// Expected: Ok with 1 row affected and consent no longer reported
+        #[tokio::test]
+        async fn revokes_consent() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostUserConsent)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = UserConsentRepository::new(&test.db);
+            repository.grant(user_model.id, "assets").await?;
+
+            let result = repository.revoke(user_model.id, "assets").await?;
+
+            assert_eq!(result.rows_affected, 1);
+            assert!(!repository.has_consent(user_model.id, "assets").await?);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Corporation member data repository.
+//!
+//! This module contains the `CorporationMemberRepository` for the member lists fetched from ESI
+//! for corporations whose director granted the membership scope, and for the director each
+//! corporation's member list is fetched for. The director's refresh token is stored with the
+//! other character tokens, not by this repository.
+
+use chrono::{NaiveDateTime, Utc};
+use migration::{Expr, OnConflict};
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+
+use crate::server::model::db::{CorporationMemberDirectorModel, CorporationMemberModel};
+
+/// Repository for managing corporation member and member director records in the database.
+pub struct CorporationMemberRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> CorporationMemberRepository<'a, C> {
+    /// Creates a new instance of CorporationMemberRepository.
+    ///
+    /// Constructs a repository for managing corporation member records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `CorporationMemberRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Stores the director a corporation's member list is fetched for.
+    ///
+    /// Replaces the corporation's existing director, if any, keeping its creation time and
+    /// clearing its failure.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - Record ID of the corporation
+    /// - `character_id` - Record ID of the director character who granted the scope
+    ///
+    /// # Returns
+    /// - `Ok(CorporationMemberDirectorModel)` - The stored director record
+    /// - `Err(DbErr)` - Database operation failed or a record ID doesn't exist
+    pub async fn upsert_director(
+        &self,
+        corporation_id: i32,
+        character_id: i32,
+    ) -> Result<CorporationMemberDirectorModel, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        entity::prelude::BifrostCorporationMemberDirector::insert(
+            entity::bifrost_corporation_member_director::ActiveModel {
+                corporation_id: ActiveValue::Set(corporation_id),
+                character_id: ActiveValue::Set(character_id),
+                failed_at: ActiveValue::Set(None),
+                created_at: ActiveValue::Set(now),
+                updated_at: ActiveValue::Set(now),
+                ..Default::default()
+            },
+        )
+        .on_conflict(
+            OnConflict::column(entity::bifrost_corporation_member_director::Column::CorporationId)
+                .update_columns([
+                    entity::bifrost_corporation_member_director::Column::CharacterId,
+                    entity::bifrost_corporation_member_director::Column::FailedAt,
+                    entity::bifrost_corporation_member_director::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(self.db)
+        .await
+    }
+
+    /// Retrieves the member director of a corporation.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - Record ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(Some(CorporationMemberDirectorModel))` - Director found
+    /// - `Ok(None)` - No director granted the membership scope for the corporation
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_director(
+        &self,
+        corporation_id: i32,
+    ) -> Result<Option<CorporationMemberDirectorModel>, DbErr> {
+        entity::prelude::BifrostCorporationMemberDirector::find()
+            .filter(
+                entity::bifrost_corporation_member_director::Column::CorporationId
+                    .eq(corporation_id),
+            )
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves the EVE Online ID of a corporation's member director.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - Record ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(Some(i64))` - EVE Online ID of the director character
+    /// - `Ok(None)` - No director granted the membership scope for the corporation
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_director_character_id(
+        &self,
+        corporation_id: i32,
+    ) -> Result<Option<i64>, DbErr> {
+        entity::prelude::BifrostCorporationMemberDirector::find()
+            .inner_join(entity::prelude::EveCharacter)
+            .filter(
+                entity::bifrost_corporation_member_director::Column::CorporationId
+                    .eq(corporation_id),
+            )
+            .select_only()
+            .column(entity::eve_character::Column::CharacterId)
+            .into_tuple::<i64>()
+            .one(self.db)
+            .await
+    }
+
+    /// Records whether fetching a corporation's member list with its director's token failed.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - Record ID of the corporation
+    /// - `failed_at` - Time the fetch failed, or `None` once it succeeded
+    ///
+    /// # Returns
+    /// - `Ok(())` - Failure recorded (no-op if the corporation has no director)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn set_director_failed_at(
+        &self,
+        corporation_id: i32,
+        failed_at: Option<NaiveDateTime>,
+    ) -> Result<(), DbErr> {
+        entity::prelude::BifrostCorporationMemberDirector::update_many()
+            .col_expr(
+                entity::bifrost_corporation_member_director::Column::FailedAt,
+                Expr::value(failed_at),
+            )
+            .filter(
+                entity::bifrost_corporation_member_director::Column::CorporationId
+                    .eq(corporation_id),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the EVE Online IDs of every corporation with a member director.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i64>)` - Corporation IDs ordered by ID (empty if no corporation is tracked)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_tracked_corporation_ids(&self) -> Result<Vec<i64>, DbErr> {
+        entity::prelude::BifrostCorporationMemberDirector::find()
+            .inner_join(entity::prelude::EveCorporation)
+            .select_only()
+            .column(entity::eve_corporation::Column::CorporationId)
+            .order_by_asc(entity::eve_corporation::Column::CorporationId)
+            .into_tuple::<i64>()
+            .all(self.db)
+            .await
+    }
+
+    /// Replaces a corporation's member list.
+    ///
+    /// Characters no longer in the list are removed, and every listed character is inserted or
+    /// has its update timestamp refreshed.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - Record ID of the corporation
+    /// - `character_ids` - EVE Online IDs of the corporation's member characters
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of characters removed from the member list
+    /// - `Err(DbErr)` - Database operation failed or the corporation doesn't exist
+    pub async fn replace_members(
+        &self,
+        corporation_id: i32,
+        character_ids: &[i64],
+    ) -> Result<u64, DbErr> {
+        let removed = entity::prelude::EveCorporationMember::delete_many()
+            .filter(entity::eve_corporation_member::Column::CorporationId.eq(corporation_id))
+            .filter(
+                entity::eve_corporation_member::Column::CharacterId
+                    .is_not_in(character_ids.iter().copied()),
+            )
+            .exec(self.db)
+            .await?
+            .rows_affected;
+
+        if character_ids.is_empty() {
+            return Ok(removed);
+        }
+
+        let now = Utc::now().naive_utc();
+        let members: Vec<entity::eve_corporation_member::ActiveModel> = character_ids
+            .iter()
+            .map(|character_id| entity::eve_corporation_member::ActiveModel {
+                corporation_id: ActiveValue::Set(corporation_id),
+                character_id: ActiveValue::Set(*character_id),
+                updated_at: ActiveValue::Set(now),
+                ..Default::default()
+            })
+            .collect();
+
+        entity::prelude::EveCorporationMember::insert_many(members)
+            .on_conflict(
+                OnConflict::columns([
+                    entity::eve_corporation_member::Column::CorporationId,
+                    entity::eve_corporation_member::Column::CharacterId,
+                ])
+                .update_column(entity::eve_corporation_member::Column::UpdatedAt)
+                .to_owned(),
+            )
+            .exec(self.db)
+            .await?;
+
+        Ok(removed)
+    }
+
+    /// Retrieves the member list of a corporation.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - Record ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(Vec<CorporationMemberModel>)` - Members ordered by character ID (empty if the
+    ///   member list was never fetched)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_members(
+        &self,
+        corporation_id: i32,
+    ) -> Result<Vec<CorporationMemberModel>, DbErr> {
+        entity::prelude::EveCorporationMember::find()
+            .filter(entity::eve_corporation_member::Column::CorporationId.eq(corporation_id))
+            .order_by_asc(entity::eve_corporation_member::Column::CharacterId)
+            .all(self.db)
+            .await
+    }
+}
@@ -0,0 +1,281 @@
+//! Admin dashboard summary repositories.
+//!
+//! This module contains the `DashboardRepository` for computing and storing the precomputed
+//! summaries shown on the admin dashboard. Aggregating users and characters on every
+//! dashboard load would scan the user tables per request, so the aggregates are computed
+//! periodically by a worker job and stored in summary tables which the dashboard reads.
+
+use chrono::NaiveDateTime;
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveValue, ConnectionTrait, DbErr, EntityTrait, JoinType, QueryOrder, QuerySelect,
+    RelationTrait,
+};
+
+use crate::server::model::db::{
+    CharacterCountDistributionModel, CorporationUserCountModel, EveCorporationModel,
+};
+
+/// Repository for admin dashboard summary tables.
+pub struct DashboardRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> DashboardRepository<'a, C> {
+    /// Creates a new instance of DashboardRepository.
+    ///
+    /// Constructs a repository for computing and reading admin dashboard summaries.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `DashboardRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Counts users grouped by the corporation of their main character.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64)>)` - List of (corporation record ID, user count) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn count_users_by_main_corporation(&self) -> Result<Vec<(i32, i64)>, DbErr> {
+        entity::prelude::BifrostUser::find()
+            .select_only()
+            .column(entity::eve_character::Column::CorporationId)
+            .column_as(
+                Func::count(Expr::col((
+                    entity::prelude::BifrostUser,
+                    entity::bifrost_user::Column::Id,
+                ))),
+                "user_count",
+            )
+            .join(
+                JoinType::InnerJoin,
+                entity::bifrost_user::Relation::EveCharacter.def(),
+            )
+            .group_by(entity::eve_character::Column::CorporationId)
+            .into_tuple::<(i32, i64)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Counts the characters owned by each user.
+    ///
+    /// Users without any owned characters are not included.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64)>)` - List of (user ID, character count) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn count_characters_per_user(&self) -> Result<Vec<(i32, i64)>, DbErr> {
+        entity::prelude::BifrostUserCharacter::find()
+            .select_only()
+            .column(entity::bifrost_user_character::Column::UserId)
+            .column_as(
+                Func::count(Expr::col(entity::bifrost_user_character::Column::Id)),
+                "character_count",
+            )
+            .group_by(entity::bifrost_user_character::Column::UserId)
+            .into_tuple::<(i32, i64)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Replaces the stored users-per-corporation summary.
+    ///
+    /// Deletes all existing rows before inserting the new summary; call within a transaction
+    /// so the dashboard never reads a partially refreshed summary.
+    ///
+    /// # Arguments
+    /// - `counts` - List of (corporation record ID, user count) tuples
+    /// - `refreshed_at` - Timestamp the summary was computed at
+    ///
+    /// # Returns
+    /// - `Ok(())` - Summary replaced
+    /// - `Err(DbErr)` - Database delete or insert failed
+    pub async fn replace_corporation_user_counts(
+        &self,
+        counts: Vec<(i32, i64)>,
+        refreshed_at: NaiveDateTime,
+    ) -> Result<(), DbErr> {
+        entity::prelude::BifrostCorporationUserCount::delete_many()
+            .exec(self.db)
+            .await?;
+
+        if counts.is_empty() {
+            return Ok(());
+        }
+
+        let rows = counts.into_iter().map(|(corporation_id, user_count)| {
+            entity::bifrost_corporation_user_count::ActiveModel {
+                corporation_id: ActiveValue::Set(corporation_id),
+                user_count: ActiveValue::Set(user_count),
+                refreshed_at: ActiveValue::Set(refreshed_at),
+                ..Default::default()
+            }
+        });
+
+        entity::prelude::BifrostCorporationUserCount::insert_many(rows)
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Replaces the stored characters-per-user distribution.
+    ///
+    /// Deletes all existing rows before inserting the new distribution; call within a
+    /// transaction so the dashboard never reads a partially refreshed summary.
+    ///
+    /// # Arguments
+    /// - `distribution` - List of (character count, user count) tuples
+    /// - `refreshed_at` - Timestamp the distribution was computed at
+    ///
+    /// # Returns
+    /// - `Ok(())` - Distribution replaced
+    /// - `Err(DbErr)` - Database delete or insert failed
+    pub async fn replace_character_count_distribution(
+        &self,
+        distribution: Vec<(i64, i64)>,
+        refreshed_at: NaiveDateTime,
+    ) -> Result<(), DbErr> {
+        entity::prelude::BifrostCharacterCountDistribution::delete_many()
+            .exec(self.db)
+            .await?;
+
+        if distribution.is_empty() {
+            return Ok(());
+        }
+
+        let rows = distribution
+            .into_iter()
+            .map(|(character_count, user_count)| {
+                entity::bifrost_character_count_distribution::ActiveModel {
+                    character_count: ActiveValue::Set(character_count),
+                    user_count: ActiveValue::Set(user_count),
+                    refreshed_at: ActiveValue::Set(refreshed_at),
+                    ..Default::default()
+                }
+            });
+
+        entity::prelude::BifrostCharacterCountDistribution::insert_many(rows)
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the stored users-per-corporation summary with each corporation.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(CorporationUserCountModel, Option<EveCorporationModel>)>)` - Summary rows
+    ///   ordered by user count, largest first
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_corporation_user_counts(
+        &self,
+    ) -> Result<Vec<(CorporationUserCountModel, Option<EveCorporationModel>)>, DbErr> {
+        entity::prelude::BifrostCorporationUserCount::find()
+            .find_also_related(entity::prelude::EveCorporation)
+            .order_by_desc(entity::bifrost_corporation_user_count::Column::UserCount)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the stored characters-per-user distribution.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<CharacterCountDistributionModel>)` - Distribution rows ordered by character count
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_character_count_distribution(
+        &self,
+    ) -> Result<Vec<CharacterCountDistributionModel>, DbErr> {
+        entity::prelude::BifrostCharacterCountDistribution::find()
+            .order_by_asc(entity::bifrost_character_count_distribution::Column::CharacterCount)
+            .all(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for DashboardRepository::count_users_by_main_corporation method.
+    mod count_users_by_main_corporation {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::dashboard::DashboardRepository;
+
+        /// Tests grouping users by their main character's corporation.
+        ///
+        /// Verifies that alt characters in other corporations don't count towards those
+        /// corporations.
+        ///
+        /// Expected: Two users in the first corporation, none in the alt's corporation
+        #[tokio::test]
+        async fn groups_users_by_main_character_corporation() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            let (user_model, _, main) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            test.user()
+                .insert_user_with_mock_character(2, 1, None, None)
+                .await?;
+            test.user()
+                .insert_mock_character_for_user(user_model.id, 3, 2, None, None)
+                .await?;
+
+            let counts = DashboardRepository::new(&test.db)
+                .count_users_by_main_corporation()
+                .await?;
+
+            assert_eq!(counts, vec![(main.corporation_id, 2)]);
+
+            Ok(())
+        }
+    }
+
+    /// Tests for DashboardRepository::replace_corporation_user_counts method.
+    mod replace_corporation_user_counts {
+        use bifrost_test_utils::prelude::*;
+        use chrono::Utc;
+
+        use crate::server::data::dashboard::DashboardRepository;
+
+        /// Tests that a refresh replaces the previous summary.
+        ///
+        /// Expected: Only rows from the latest refresh remain
+        #[tokio::test]
+        async fn replaces_previous_summary() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostCorporationUserCount)
+                .build()
+                .await?;
+            let first = test.eve().insert_mock_corporation(1, None, None).await?;
+            let second = test.eve().insert_mock_corporation(2, None, None).await?;
+
+            let repository = DashboardRepository::new(&test.db);
+            repository
+                .replace_corporation_user_counts(vec![(first.id, 3)], Utc::now().naive_utc())
+                .await?;
+            repository
+                .replace_corporation_user_counts(
+                    vec![(first.id, 1), (second.id, 5)],
+                    Utc::now().naive_utc(),
+                )
+                .await?;
+
+            let rows = repository.get_corporation_user_counts().await?;
+            assert_eq!(
+                rows.iter()
+                    .map(|(row, _)| (row.corporation_id, row.user_count))
+                    .collect::<Vec<_>>(),
+                vec![(second.id, 5), (first.id, 1)]
+            );
+
+            Ok(())
+        }
+    }
+}
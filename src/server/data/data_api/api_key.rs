@@ -0,0 +1,130 @@
+//! API key data repository.
+//!
+//! This module contains the `ApiKeyRepository` for storing the keys BI tools use to
+//! authenticate with the data access API. Keys are looked up by the hash of the key presented
+//! with a request, as the keys themselves are never stored.
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::Expr, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::ApiKeyModel;
+
+/// Repository for managing API key records in the database.
+pub struct ApiKeyRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> ApiKeyRepository<'a, C> {
+    /// Creates a new instance of ApiKeyRepository.
+    ///
+    /// Constructs a repository for managing API key records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ApiKeyRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new API key.
+    ///
+    /// # Arguments
+    /// - `name` - Name identifying the tool using the key
+    /// - `key_hash` - Hex-encoded SHA-256 hash of the key
+    /// - `user_id` - ID of the user creating the key
+    ///
+    /// # Returns
+    /// - `Ok(ApiKeyModel)` - The created key record
+    /// - `Err(DbErr)` - Database operation failed, duplicate hash, or user doesn't exist
+    pub async fn create(
+        &self,
+        name: String,
+        key_hash: String,
+        user_id: i32,
+    ) -> Result<ApiKeyModel, DbErr> {
+        entity::prelude::BifrostApiKey::insert(entity::bifrost_api_key::ActiveModel {
+            name: ActiveValue::Set(name),
+            key_hash: ActiveValue::Set(key_hash),
+            created_by_user_id: ActiveValue::Set(user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            last_used_at: ActiveValue::Set(None),
+            ..Default::default()
+        })
+        .exec_with_returning(self.db)
+        .await
+    }
+
+    /// Retrieves an API key by the hash of the key.
+    ///
+    /// # Arguments
+    /// - `key_hash` - Hex-encoded SHA-256 hash of the presented key
+    ///
+    /// # Returns
+    /// - `Ok(Some(ApiKeyModel))` - Key found
+    /// - `Ok(None)` - No key matches the hash
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_hash(&self, key_hash: &str) -> Result<Option<ApiKeyModel>, DbErr> {
+        entity::prelude::BifrostApiKey::find()
+            .filter(entity::bifrost_api_key::Column::KeyHash.eq(key_hash))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all API keys, oldest first.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ApiKeyModel>)` - All API keys (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<ApiKeyModel>, DbErr> {
+        entity::prelude::BifrostApiKey::find()
+            .order_by_asc(entity::bifrost_api_key::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Records when an API key last authorized a request.
+    ///
+    /// # Arguments
+    /// - `api_key_id` - ID of the key
+    /// - `used_at` - Time of the request
+    ///
+    /// # Returns
+    /// - `Ok(())` - Key updated, or no key with the ID exists
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn set_last_used(
+        &self,
+        api_key_id: i32,
+        used_at: NaiveDateTime,
+    ) -> Result<(), DbErr> {
+        entity::prelude::BifrostApiKey::update_many()
+            .col_expr(
+                entity::bifrost_api_key::Column::LastUsedAt,
+                Expr::value(used_at),
+            )
+            .filter(entity::bifrost_api_key::Column::Id.eq(api_key_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Deletes an API key, revoking access for the tool using it.
+    ///
+    /// # Arguments
+    /// - `api_key_id` - ID of the key to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   key didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, api_key_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostApiKey::delete_by_id(api_key_id)
+            .exec(self.db)
+            .await
+    }
+}
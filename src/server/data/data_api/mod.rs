@@ -0,0 +1,8 @@
+//! Data access API repositories.
+//!
+//! This module contains repositories for the data access API BI tools use to read
+//! organization metrics: saved queries defined by admins, and the API keys authorizing tools
+//! to run them.
+
+pub mod api_key;
+pub mod saved_query;
@@ -0,0 +1,163 @@
+//! Saved query data repository.
+//!
+//! This module contains the `SavedQueryRepository` for storing the read-only queries admins
+//! expose through the data access API and running them against the database.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    FromQueryResult, IntoActiveModel, JsonValue, QueryFilter, QueryOrder, Statement, Value,
+};
+
+use crate::server::model::db::SavedQueryModel;
+
+/// Repository for managing saved query records in the database.
+///
+/// Provides operations for saving, retrieving, and deleting saved queries, and for executing
+/// the SQL they store.
+pub struct SavedQueryRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> SavedQueryRepository<'a, C> {
+    /// Creates a new instance of SavedQueryRepository.
+    ///
+    /// Constructs a repository for managing saved query records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `SavedQueryRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a saved query or replaces the query with the slug.
+    ///
+    /// The user who created the query is kept when an existing query is replaced.
+    ///
+    /// # Arguments
+    /// - `slug` - Unique URL segment of the query
+    /// - `name` - Query name
+    /// - `sql` - `SELECT` statement with placeholders for its parameters
+    /// - `parameters` - Space-separated `name:kind` parameters
+    /// - `row_limit` - Maximum number of rows returned per request
+    /// - `user_id` - ID of the user saving the query
+    ///
+    /// # Returns
+    /// - `Ok(SavedQueryModel)` - The created or updated query record
+    /// - `Err(DbErr)` - Database operation failed or the user ID doesn't exist
+    pub async fn upsert(
+        &self,
+        slug: &str,
+        name: String,
+        sql: String,
+        parameters: String,
+        row_limit: i32,
+        user_id: i32,
+    ) -> Result<SavedQueryModel, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        match self.get_by_slug(slug).await? {
+            Some(query) => {
+                let mut query = query.into_active_model();
+                query.name = ActiveValue::Set(name);
+                query.sql = ActiveValue::Set(sql);
+                query.parameters = ActiveValue::Set(parameters);
+                query.row_limit = ActiveValue::Set(row_limit);
+                query.updated_at = ActiveValue::Set(now);
+
+                query.update(self.db).await
+            }
+            None => {
+                let query = entity::bifrost_saved_query::ActiveModel {
+                    slug: ActiveValue::Set(slug.to_string()),
+                    name: ActiveValue::Set(name),
+                    sql: ActiveValue::Set(sql),
+                    parameters: ActiveValue::Set(parameters),
+                    row_limit: ActiveValue::Set(row_limit),
+                    created_by_user_id: ActiveValue::Set(user_id),
+                    created_at: ActiveValue::Set(now),
+                    updated_at: ActiveValue::Set(now),
+                    ..Default::default()
+                };
+
+                query.insert(self.db).await
+            }
+        }
+    }
+
+    /// Retrieves a saved query by slug.
+    ///
+    /// # Arguments
+    /// - `slug` - URL segment of the query to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(SavedQueryModel))` - Query found
+    /// - `Ok(None)` - No query with the slug exists
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_slug(&self, slug: &str) -> Result<Option<SavedQueryModel>, DbErr> {
+        entity::prelude::BifrostSavedQuery::find()
+            .filter(entity::bifrost_saved_query::Column::Slug.eq(slug))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all saved queries ordered by slug.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<SavedQueryModel>)` - All saved queries (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<SavedQueryModel>, DbErr> {
+        entity::prelude::BifrostSavedQuery::find()
+            .order_by_asc(entity::bifrost_saved_query::Column::Slug)
+            .all(self.db)
+            .await
+    }
+
+    /// Deletes a saved query by slug.
+    ///
+    /// # Arguments
+    /// - `slug` - URL segment of the query to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   query didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, slug: &str) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostSavedQuery::delete_many()
+            .filter(entity::bifrost_saved_query::Column::Slug.eq(slug))
+            .exec(self.db)
+            .await
+    }
+
+    /// Executes a saved query's SQL, returning each row as a JSON object.
+    ///
+    /// The SQL is wrapped in a subquery with a `LIMIT` so no more than `limit` rows are read,
+    /// whatever the SQL itself selects. Callers are responsible for running the statement in a
+    /// read-only transaction.
+    ///
+    /// # Arguments
+    /// - `sql` - `SELECT` statement with `$1`, `$2`, ... placeholders
+    /// - `values` - Values bound to the placeholders in order
+    /// - `limit` - Maximum number of rows to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<JsonValue>)` - Rows as JSON objects keyed by column name
+    /// - `Err(DbErr)` - SQL is invalid or the query failed
+    pub async fn execute(
+        &self,
+        sql: &str,
+        values: Vec<Value>,
+        limit: u64,
+    ) -> Result<Vec<JsonValue>, DbErr> {
+        let statement = Statement::from_sql_and_values(
+            self.db.get_database_backend(),
+            format!("SELECT * FROM ({}) AS saved_query LIMIT {}", sql, limit),
+            values,
+        );
+
+        JsonValue::find_by_statement(statement).all(self.db).await
+    }
+}
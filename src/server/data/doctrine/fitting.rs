@@ -0,0 +1,308 @@
+//! Fitting repository for ship fitting management.
+//!
+//! This module provides the `FittingRepository` for storing ship fittings imported in EFT
+//! format and retrieving them for display or doctrine assignment, along with the skill levels
+//! required to fly each fitting.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::{FittingModel, FittingSkillModel};
+
+/// Repository for managing ship fitting records in the database.
+///
+/// Provides operations for creating, retrieving, and deleting fittings. Fittings are stored
+/// with their original EFT text alongside the ship hull and fitting name extracted on import.
+pub struct FittingRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> FittingRepository<'a, C> {
+    /// Creates a new instance of FittingRepository.
+    ///
+    /// Constructs a repository for managing fitting records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `FittingRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new fitting record.
+    ///
+    /// # Arguments
+    /// - `name` - Fitting name from the EFT header
+    /// - `ship_type_name` - Ship hull name from the EFT header
+    /// - `eft` - Original fitting text in EFT format
+    /// - `created_by_user_id` - ID of the user importing the fitting
+    ///
+    /// # Returns
+    /// - `Ok(FittingModel)` - The newly created fitting record
+    /// - `Err(DbErr)` - Database operation failed or user ID doesn't exist
+    pub async fn create(
+        &self,
+        name: String,
+        ship_type_name: String,
+        eft: String,
+        created_by_user_id: i32,
+    ) -> Result<FittingModel, DbErr> {
+        let fitting = entity::bifrost_fitting::ActiveModel {
+            name: ActiveValue::Set(name),
+            ship_type_name: ActiveValue::Set(ship_type_name),
+            eft: ActiveValue::Set(eft),
+            created_by_user_id: ActiveValue::Set(created_by_user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            updated_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        fitting.insert(self.db).await
+    }
+
+    /// Retrieves a fitting by ID.
+    ///
+    /// # Arguments
+    /// - `fitting_id` - ID of the fitting to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(FittingModel))` - Fitting found
+    /// - `Ok(None)` - Fitting does not exist
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_id(&self, fitting_id: i32) -> Result<Option<FittingModel>, DbErr> {
+        entity::prelude::BifrostFitting::find_by_id(fitting_id)
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all fittings ordered by ship hull and fitting name.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FittingModel>)` - All fittings (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<FittingModel>, DbErr> {
+        entity::prelude::BifrostFitting::find()
+            .order_by_asc(entity::bifrost_fitting::Column::ShipTypeName)
+            .order_by_asc(entity::bifrost_fitting::Column::Name)
+            .all(self.db)
+            .await
+    }
+
+    /// Deletes a fitting by ID.
+    ///
+    /// Doctrine memberships referencing the fitting are removed by the cascading foreign key.
+    ///
+    /// # Arguments
+    /// - `fitting_id` - ID of the fitting to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if fitting didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, fitting_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostFitting::delete_by_id(fitting_id)
+            .exec(self.db)
+            .await
+    }
+
+    /// Replaces the skill levels required to fly a fitting.
+    ///
+    /// # Arguments
+    /// - `fitting_id` - ID of the fitting
+    /// - `skills` - Tuples of (skill_id, level), each skill listed at most once
+    ///
+    /// # Returns
+    /// - `Ok(())` - Requirements replaced
+    /// - `Err(DbErr)` - Database operation failed or the fitting doesn't exist
+    ///
+    /// # Notes
+    /// - For transactional behavior, pass a transaction as the connection
+    pub async fn replace_skills(
+        &self,
+        fitting_id: i32,
+        skills: &[(i64, i32)],
+    ) -> Result<(), DbErr> {
+        entity::prelude::BifrostFittingSkill::delete_many()
+            .filter(entity::bifrost_fitting_skill::Column::FittingId.eq(fitting_id))
+            .exec(self.db)
+            .await?;
+
+        if skills.is_empty() {
+            return Ok(());
+        }
+
+        let skills: Vec<entity::bifrost_fitting_skill::ActiveModel> = skills
+            .iter()
+            .map(
+                |(skill_id, level)| entity::bifrost_fitting_skill::ActiveModel {
+                    fitting_id: ActiveValue::Set(fitting_id),
+                    skill_id: ActiveValue::Set(*skill_id),
+                    level: ActiveValue::Set(*level),
+                    ..Default::default()
+                },
+            )
+            .collect();
+
+        entity::prelude::BifrostFittingSkill::insert_many(skills)
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the skill levels required to fly each of the provided fittings.
+    ///
+    /// # Arguments
+    /// - `fitting_ids` - IDs of the fittings to retrieve requirements for
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, Vec<FittingSkillModel>>)` - Requirements ordered by skill ID and keyed
+    ///   by fitting ID, fittings without requirements are omitted
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_skills_by_fitting_ids(
+        &self,
+        fitting_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<FittingSkillModel>>, DbErr> {
+        if fitting_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let requirements = entity::prelude::BifrostFittingSkill::find()
+            .filter(entity::bifrost_fitting_skill::Column::FittingId.is_in(fitting_ids.to_vec()))
+            .order_by_asc(entity::bifrost_fitting_skill::Column::SkillId)
+            .all(self.db)
+            .await?;
+
+        let mut skills: HashMap<i32, Vec<FittingSkillModel>> = HashMap::new();
+        for requirement in requirements {
+            skills
+                .entry(requirement.fitting_id)
+                .or_default()
+                .push(requirement);
+        }
+
+        Ok(skills)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for FittingRepository::create method.
+    mod create {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::doctrine::fitting::FittingRepository;
+
+        /// Tests creating a new fitting.
+        ///
+        /// Verifies that the fitting repository creates a fitting record owned by an
+        /// existing user.
+        ///
+        /// Expected: Ok
+        #[tokio::test]
+        async fn creates_fitting() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostFitting)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let fitting_repository = FittingRepository::new(&test.db);
+            let result = fitting_repository
+                .create(
+                    "Tackle".to_string(),
+                    "Rifter".to_string(),
+                    "[Rifter, Tackle]".to_string(),
+                    user_model.id,
+                )
+                .await;
+
+            assert!(result.is_ok());
+
+            Ok(())
+        }
+
+        /// Tests error handling for nonexistent user.
+        ///
+        /// Verifies that the fitting repository returns an error when the creating user
+        /// does not exist in the database.
+        ///
+        /// Expected: Err
+        #[tokio::test]
+        async fn fails_for_nonexistent_user() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostFitting)
+                .build()
+                .await?;
+
+            let nonexistent_user_id = 1;
+            let fitting_repository = FittingRepository::new(&test.db);
+            let result = fitting_repository
+                .create(
+                    "Tackle".to_string(),
+                    "Rifter".to_string(),
+                    "[Rifter, Tackle]".to_string(),
+                    nonexistent_user_id,
+                )
+                .await;
+
+            assert!(result.is_err());
+
+            Ok(())
+        }
+    }
+
+    /// Tests for FittingRepository::delete method.
+    mod delete {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::doctrine::fitting::FittingRepository;
+
+        /// Tests deleting an existing fitting.
+        ///
+        /// Verifies that the fitting repository deletes the fitting and it can no longer
+        /// be retrieved.
+        ///
+        /// Expected: Ok with 1 row affected
+        #[tokio::test]
+        async fn deletes_existing_fitting() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostFitting)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let fitting_repository = FittingRepository::new(&test.db);
+            let fitting = fitting_repository
+                .create(
+                    "Tackle".to_string(),
+                    "Rifter".to_string(),
+                    "[Rifter, Tackle]".to_string(),
+                    user_model.id,
+                )
+                .await?;
+            let result = fitting_repository.delete(fitting.id).await?;
+
+            assert_eq!(result.rows_affected, 1);
+            assert!(fitting_repository.get_by_id(fitting.id).await?.is_none());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,423 @@
+//! Doctrine data repositories.
+//!
+//! This module contains repositories for managing doctrines and the ship fittings assigned to
+//! them. The `DoctrineRepository` handles doctrine CRUD operations and doctrine membership,
+//! while `fitting` manages the fittings themselves.
+
+pub mod fitting;
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use migration::OnConflict;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::{DoctrineModel, FittingModel};
+
+/// Repository for managing doctrine records in the database.
+///
+/// Provides operations for creating, retrieving, and deleting doctrines as well as adding
+/// and removing the fittings that belong to each doctrine.
+pub struct DoctrineRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> DoctrineRepository<'a, C> {
+    /// Creates a new instance of DoctrineRepository.
+    ///
+    /// Constructs a repository for managing doctrine records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `DoctrineRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new doctrine.
+    ///
+    /// # Arguments
+    /// - `name` - Unique doctrine name
+    /// - `description` - Optional doctrine description
+    ///
+    /// # Returns
+    /// - `Ok(DoctrineModel)` - The newly created doctrine record
+    /// - `Err(DbErr)` - Database operation failed or a doctrine with the name already exists
+    pub async fn create(
+        &self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<DoctrineModel, DbErr> {
+        let doctrine = entity::bifrost_doctrine::ActiveModel {
+            name: ActiveValue::Set(name),
+            description: ActiveValue::Set(description),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            updated_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        doctrine.insert(self.db).await
+    }
+
+    /// Retrieves a doctrine by ID.
+    ///
+    /// # Arguments
+    /// - `doctrine_id` - ID of the doctrine to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(DoctrineModel))` - Doctrine found
+    /// - `Ok(None)` - Doctrine does not exist
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_id(&self, doctrine_id: i32) -> Result<Option<DoctrineModel>, DbErr> {
+        entity::prelude::BifrostDoctrine::find_by_id(doctrine_id)
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves a doctrine by its unique name.
+    ///
+    /// # Arguments
+    /// - `name` - Name of the doctrine to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(DoctrineModel))` - Doctrine found
+    /// - `Ok(None)` - No doctrine with that name exists
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_name(&self, name: &str) -> Result<Option<DoctrineModel>, DbErr> {
+        entity::prelude::BifrostDoctrine::find()
+            .filter(entity::bifrost_doctrine::Column::Name.eq(name))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all doctrines ordered by name.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<DoctrineModel>)` - All doctrines (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<DoctrineModel>, DbErr> {
+        entity::prelude::BifrostDoctrine::find()
+            .order_by_asc(entity::bifrost_doctrine::Column::Name)
+            .all(self.db)
+            .await
+    }
+
+    /// Deletes a doctrine by ID.
+    ///
+    /// Fitting memberships for the doctrine are removed by the cascading foreign key; the
+    /// fittings themselves are not deleted.
+    ///
+    /// # Arguments
+    /// - `doctrine_id` - ID of the doctrine to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if doctrine didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, doctrine_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostDoctrine::delete_by_id(doctrine_id)
+            .exec(self.db)
+            .await
+    }
+
+    /// Adds a fitting to a doctrine.
+    ///
+    /// Adding a fitting that already belongs to the doctrine is a no-op.
+    ///
+    /// # Arguments
+    /// - `doctrine_id` - ID of the doctrine
+    /// - `fitting_id` - ID of the fitting to add
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of rows inserted (0 if the fitting was already in the doctrine)
+    /// - `Err(DbErr)` - Database operation failed or doctrine/fitting ID doesn't exist
+    pub async fn add_fitting(&self, doctrine_id: i32, fitting_id: i32) -> Result<u64, DbErr> {
+        entity::prelude::BifrostDoctrineFitting::insert(
+            entity::bifrost_doctrine_fitting::ActiveModel {
+                doctrine_id: ActiveValue::Set(doctrine_id),
+                fitting_id: ActiveValue::Set(fitting_id),
+                created_at: ActiveValue::Set(Utc::now().naive_utc()),
+                ..Default::default()
+            },
+        )
+        .on_conflict(
+            OnConflict::columns([
+                entity::bifrost_doctrine_fitting::Column::DoctrineId,
+                entity::bifrost_doctrine_fitting::Column::FittingId,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(self.db)
+        .await
+    }
+
+    /// Removes a fitting from a doctrine.
+    ///
+    /// # Arguments
+    /// - `doctrine_id` - ID of the doctrine
+    /// - `fitting_id` - ID of the fitting to remove
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if removed, 0 if fitting wasn't in the doctrine)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn remove_fitting(
+        &self,
+        doctrine_id: i32,
+        fitting_id: i32,
+    ) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostDoctrineFitting::delete_many()
+            .filter(entity::bifrost_doctrine_fitting::Column::DoctrineId.eq(doctrine_id))
+            .filter(entity::bifrost_doctrine_fitting::Column::FittingId.eq(fitting_id))
+            .exec(self.db)
+            .await
+    }
+
+    /// Retrieves the fittings belonging to each of the provided doctrines.
+    ///
+    /// # Arguments
+    /// - `doctrine_ids` - IDs of the doctrines to retrieve fittings for
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, Vec<FittingModel>>)` - Fittings keyed by doctrine ID, doctrines without fittings are omitted
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_fittings_by_doctrine_ids(
+        &self,
+        doctrine_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<FittingModel>>, DbErr> {
+        if doctrine_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let memberships = entity::prelude::BifrostDoctrineFitting::find()
+            .filter(
+                entity::bifrost_doctrine_fitting::Column::DoctrineId.is_in(doctrine_ids.to_vec()),
+            )
+            .find_also_related(entity::prelude::BifrostFitting)
+            .order_by_asc(entity::bifrost_doctrine_fitting::Column::Id)
+            .all(self.db)
+            .await?;
+
+        let mut fittings: HashMap<i32, Vec<FittingModel>> = HashMap::new();
+        for (membership, fitting) in memberships {
+            if let Some(fitting) = fitting {
+                fittings
+                    .entry(membership.doctrine_id)
+                    .or_default()
+                    .push(fitting);
+            }
+        }
+
+        Ok(fittings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for DoctrineRepository::create method.
+    mod create {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::doctrine::DoctrineRepository;
+
+        /// Tests creating a new doctrine.
+        ///
+        /// Verifies that the doctrine repository creates a doctrine which can then be found
+        /// by name.
+        ///
+        /// Expected: Ok(Some(doctrine))
+        #[tokio::test]
+        async fn creates_doctrine() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_table(entity::prelude::BifrostDoctrine)
+                .build()
+                .await?;
+
+            let doctrine_repository = DoctrineRepository::new(&test.db);
+            let doctrine = doctrine_repository
+                .create("Shield Cruisers".to_string(), None)
+                .await?;
+            let found = doctrine_repository.get_by_name("Shield Cruisers").await?;
+
+            assert_eq!(found, Some(doctrine));
+
+            Ok(())
+        }
+
+        /// Tests error handling for duplicate doctrine names.
+        ///
+        /// Verifies that the unique constraint on the doctrine name rejects a second doctrine
+        /// with the same name.
+        ///
+        /// Expected: Err
+        #[tokio::test]
+        async fn fails_for_duplicate_name() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_table(entity::prelude::BifrostDoctrine)
+                .build()
+                .await?;
+
+            let doctrine_repository = DoctrineRepository::new(&test.db);
+            doctrine_repository
+                .create("Shield Cruisers".to_string(), None)
+                .await?;
+            let result = doctrine_repository
+                .create("Shield Cruisers".to_string(), None)
+                .await;
+
+            assert!(result.is_err());
+
+            Ok(())
+        }
+    }
+
+    /// Tests for DoctrineRepository::add_fitting method.
+    mod add_fitting {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::doctrine::{fitting::FittingRepository, DoctrineRepository};
+
+        /// Tests adding a fitting to a doctrine.
+        ///
+        /// Verifies that a fitting added to a doctrine is returned when retrieving the
+        /// doctrine's fittings and that adding it twice does not duplicate the membership.
+        ///
+        /// Expected: Ok with a single fitting for the doctrine
+        #[tokio::test]
+        async fn adds_fitting_once() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostFitting)
+                .with_table(entity::prelude::BifrostDoctrine)
+                .with_table(entity::prelude::BifrostDoctrineFitting)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let fitting = FittingRepository::new(&test.db)
+                .create(
+                    "Tackle".to_string(),
+                    "Rifter".to_string(),
+                    "[Rifter, Tackle]".to_string(),
+                    user_model.id,
+                )
+                .await?;
+            let doctrine_repository = DoctrineRepository::new(&test.db);
+            let doctrine = doctrine_repository
+                .create("Frigates".to_string(), None)
+                .await?;
+
+            let first = doctrine_repository
+                .add_fitting(doctrine.id, fitting.id)
+                .await?;
+            let second = doctrine_repository
+                .add_fitting(doctrine.id, fitting.id)
+                .await?;
+            let fittings = doctrine_repository
+                .get_fittings_by_doctrine_ids(&[doctrine.id])
+                .await?;
+
+            assert_eq!(first, 1);
+            assert_eq!(second, 0);
+            assert_eq!(fittings.get(&doctrine.id), Some(&vec![fitting]));
+
+            Ok(())
+        }
+
+        /// Tests error handling for a nonexistent fitting.
+        ///
+        /// Verifies that the foreign key constraint rejects adding a fitting that does not
+        /// exist to a doctrine.
+        ///
+        /// Expected: Err
+        #[tokio::test]
+        async fn fails_for_nonexistent_fitting() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostFitting)
+                .with_table(entity::prelude::BifrostDoctrine)
+                .with_table(entity::prelude::BifrostDoctrineFitting)
+                .build()
+                .await?;
+
+            let doctrine_repository = DoctrineRepository::new(&test.db);
+            let doctrine = doctrine_repository
+                .create("Frigates".to_string(), None)
+                .await?;
+
+            let nonexistent_fitting_id = 1;
+            let result = doctrine_repository
+                .add_fitting(doctrine.id, nonexistent_fitting_id)
+                .await;
+
+            assert!(result.is_err());
+
+            Ok(())
+        }
+    }
+
+    /// Tests for DoctrineRepository::remove_fitting method.
+    mod remove_fitting {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::doctrine::{fitting::FittingRepository, DoctrineRepository};
+
+        /// Tests removing a fitting from a doctrine.
+        ///
+        /// Verifies that the membership is removed while the fitting itself remains.
+        ///
+        /// Expected: Ok with 1 row affected and no fittings for the doctrine
+        #[tokio::test]
+        async fn removes_fitting_from_doctrine() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostFitting)
+                .with_table(entity::prelude::BifrostDoctrine)
+                .with_table(entity::prelude::BifrostDoctrineFitting)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let fitting_repository = FittingRepository::new(&test.db);
+            let fitting = fitting_repository
+                .create(
+                    "Tackle".to_string(),
+                    "Rifter".to_string(),
+                    "[Rifter, Tackle]".to_string(),
+                    user_model.id,
+                )
+                .await?;
+            let doctrine_repository = DoctrineRepository::new(&test.db);
+            let doctrine = doctrine_repository
+                .create("Frigates".to_string(), None)
+                .await?;
+            doctrine_repository
+                .add_fitting(doctrine.id, fitting.id)
+                .await?;
+
+            let result = doctrine_repository
+                .remove_fitting(doctrine.id, fitting.id)
+                .await?;
+            let fittings = doctrine_repository
+                .get_fittings_by_doctrine_ids(&[doctrine.id])
+                .await?;
+
+            assert_eq!(result.rows_affected, 1);
+            assert!(fittings.is_empty());
+            assert!(fitting_repository.get_by_id(fitting.id).await?.is_some());
+
+            Ok(())
+        }
+    }
+}
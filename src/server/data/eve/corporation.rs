@@ -209,6 +209,24 @@ impl<'a, C: ConnectionTrait> CorporationRepository<'a, C> {
             .await
     }
 
+    /// Finds a corporation by its internal database record ID.
+    ///
+    /// # Arguments
+    /// - `record_id` - Internal database record ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(Some(EveCorporation))` - Corporation found
+    /// - `Ok(None)` - Corporation not found in database
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find_by_record_id(
+        &self,
+        record_id: i32,
+    ) -> Result<Option<EveCorporationModel>, DbErr> {
+        entity::prelude::EveCorporation::find_by_id(record_id)
+            .one(self.db)
+            .await
+    }
+
     /// Updates the info_updated_at timestamp for a corporation to the current time.
     ///
     /// Sets the info_updated_at field to the current UTC timestamp for the specified
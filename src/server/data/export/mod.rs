@@ -0,0 +1,145 @@
+//! Export data repositories.
+//!
+//! This module contains the `ExportRepository` for reading large tables in fixed-size pages
+//! for admin exports. Pages are fetched with SeaORM cursors keyed on the primary key, so
+//! each page is an index range scan regardless of how far into the table the export is.
+
+use std::collections::HashMap;
+
+use sea_orm::{ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+
+use crate::server::model::db::{
+    CharacterOwnershipModel, EveAllianceModel, EveCharacterModel, EveCorporationModel,
+};
+
+/// Repository for paging through records for admin exports.
+pub struct ExportRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> ExportRepository<'a, C> {
+    /// Creates a new instance of ExportRepository.
+    ///
+    /// Constructs a repository for reading export pages from the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ExportRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the next page of characters ordered by record ID.
+    ///
+    /// # Arguments
+    /// - `after_id` - Record ID of the last character of the previous page, `None` for the first page
+    /// - `limit` - Maximum number of characters in the page
+    ///
+    /// # Returns
+    /// - `Ok(Vec<EveCharacterModel>)` - Characters after `after_id`, empty once the end is reached
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_characters_after(
+        &self,
+        after_id: Option<i32>,
+        limit: u64,
+    ) -> Result<Vec<EveCharacterModel>, DbErr> {
+        let mut cursor =
+            entity::prelude::EveCharacter::find().cursor_by(entity::eve_character::Column::Id);
+
+        if let Some(after_id) = after_id {
+            cursor.after(after_id);
+        }
+
+        cursor.first(limit).all(self.db).await
+    }
+
+    /// Retrieves the corporations and their alliances for a page of characters.
+    ///
+    /// # Arguments
+    /// - `corporation_ids` - Internal database IDs of the corporations
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, (EveCorporationModel, Option<EveAllianceModel>)>)` - Corporations keyed by record ID
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_corporations_by_ids(
+        &self,
+        corporation_ids: Vec<i32>,
+    ) -> Result<HashMap<i32, (EveCorporationModel, Option<EveAllianceModel>)>, DbErr> {
+        Ok(entity::prelude::EveCorporation::find()
+            .filter(entity::eve_corporation::Column::Id.is_in(corporation_ids))
+            .find_also_related(entity::prelude::EveAlliance)
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|(corporation, alliance)| (corporation.id, (corporation, alliance)))
+            .collect())
+    }
+
+    /// Retrieves the ownerships of a page of characters.
+    ///
+    /// # Arguments
+    /// - `character_ids` - Internal database IDs of the characters
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, CharacterOwnershipModel>)` - Ownerships keyed by character record ID,
+    ///   missing for unowned characters
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_ownerships_by_character_ids(
+        &self,
+        character_ids: Vec<i32>,
+    ) -> Result<HashMap<i32, CharacterOwnershipModel>, DbErr> {
+        Ok(entity::prelude::BifrostUserCharacter::find()
+            .filter(entity::bifrost_user_character::Column::CharacterId.is_in(character_ids))
+            .all(self.db)
+            .await?
+            .into_iter()
+            .map(|ownership| (ownership.character_id, ownership))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for ExportRepository::get_characters_after method.
+    mod get_characters_after {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::export::ExportRepository;
+
+        /// Tests paging through all characters.
+        ///
+        /// Verifies that consecutive pages continue after the last record of the previous
+        /// page without skipping or repeating characters.
+        ///
+        /// Expected: Pages of 2, 1, and 0 characters in record ID order
+        #[tokio::test]
+        async fn pages_through_characters_in_order() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            let first = test.eve().insert_mock_character(1, 1, None, None).await?;
+            let second = test.eve().insert_mock_character(2, 1, None, None).await?;
+            let third = test.eve().insert_mock_character(3, 1, None, None).await?;
+
+            let repository = ExportRepository::new(&test.db);
+
+            let page = repository.get_characters_after(None, 2).await?;
+            assert_eq!(
+                page.iter().map(|c| c.id).collect::<Vec<_>>(),
+                vec![first.id, second.id]
+            );
+
+            let page = repository.get_characters_after(Some(second.id), 2).await?;
+            assert_eq!(
+                page.iter().map(|c| c.id).collect::<Vec<_>>(),
+                vec![third.id]
+            );
+
+            let page = repository.get_characters_after(Some(third.id), 2).await?;
+            assert!(page.is_empty());
+
+            Ok(())
+        }
+    }
+}
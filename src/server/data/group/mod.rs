@@ -0,0 +1,304 @@
+//! Group data repositories.
+//!
+//! This module contains the `GroupRepository` for the groups users can join, and the
+//! `GroupMemberRepository` for their members and pending requests to join, while `rule` stores
+//! the affiliations qualifying users for a group. Join policies and membership statuses are
+//! stored by name; the service layer parses them.
+
+pub mod rule;
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::{GroupMemberModel, GroupModel};
+
+/// Repository for managing group records in the database.
+pub struct GroupRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> GroupRepository<'a, C> {
+    /// Creates a new instance of GroupRepository.
+    ///
+    /// Constructs a repository for managing group records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `GroupRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a group.
+    ///
+    /// # Arguments
+    /// - `name` - Unique name of the group
+    /// - `description` - Description shown to users and admins
+    /// - `join_policy` - Name of the policy deciding how users join the group
+    ///
+    /// # Returns
+    /// - `Ok(GroupModel)` - The newly created group record
+    /// - `Err(DbErr)` - Database operation failed or a group with the name already exists
+    pub async fn create(
+        &self,
+        name: String,
+        description: String,
+        join_policy: String,
+    ) -> Result<GroupModel, DbErr> {
+        let now = Utc::now().naive_utc();
+        let group = entity::bifrost_group::ActiveModel {
+            name: ActiveValue::Set(name),
+            description: ActiveValue::Set(description),
+            join_policy: ActiveValue::Set(join_policy),
+            created_at: ActiveValue::Set(now),
+            updated_at: ActiveValue::Set(now),
+            ..Default::default()
+        };
+
+        group.insert(self.db).await
+    }
+
+    /// Retrieves all groups.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GroupModel>)` - All groups ordered by name (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<GroupModel>, DbErr> {
+        entity::prelude::BifrostGroup::find()
+            .order_by_asc(entity::bifrost_group::Column::Name)
+            .all(self.db)
+            .await
+    }
+
+    /// Finds a group by ID.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(Some(GroupModel))` - Group found
+    /// - `Ok(None)` - Group doesn't exist
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<GroupModel>, DbErr> {
+        entity::prelude::BifrostGroup::find_by_id(id)
+            .one(self.db)
+            .await
+    }
+
+    /// Finds a group by name.
+    ///
+    /// # Arguments
+    /// - `name` - Name of the group
+    ///
+    /// # Returns
+    /// - `Ok(Some(GroupModel))` - Group found
+    /// - `Ok(None)` - No group has the name
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<GroupModel>, DbErr> {
+        entity::prelude::BifrostGroup::find()
+            .filter(entity::bifrost_group::Column::Name.eq(name))
+            .one(self.db)
+            .await
+    }
+
+    /// Deletes a group, removing its members and pending requests by cascade.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Group deleted
+    /// - `Ok(false)` - Group didn't exist
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostGroup::delete_by_id(id)
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+/// Repository for managing group members and pending requests to join in the database.
+pub struct GroupMemberRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> GroupMemberRepository<'a, C> {
+    /// Creates a new instance of GroupMemberRepository.
+    ///
+    /// Constructs a repository for managing group members and pending requests to join in the
+    /// database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `GroupMemberRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Adds a user to a group as a member or with a pending request.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    /// - `user_id` - ID of the user
+    /// - `status` - Name of the membership status
+    /// - `decided_by_user_id` - ID of the admin adding the user, `None` if the user joined or
+    ///   requested to join themselves
+    ///
+    /// # Returns
+    /// - `Ok(GroupMemberModel)` - The newly created membership record
+    /// - `Err(DbErr)` - Database operation failed, the group or user doesn't exist, or the user
+    ///   already has a membership in the group
+    pub async fn create(
+        &self,
+        group_id: i32,
+        user_id: i32,
+        status: String,
+        decided_by_user_id: Option<i32>,
+    ) -> Result<GroupMemberModel, DbErr> {
+        let now = Utc::now().naive_utc();
+        let member = entity::bifrost_group_member::ActiveModel {
+            group_id: ActiveValue::Set(group_id),
+            user_id: ActiveValue::Set(user_id),
+            status: ActiveValue::Set(status),
+            created_at: ActiveValue::Set(now),
+            decided_at: ActiveValue::Set(decided_by_user_id.map(|_| now)),
+            decided_by_user_id: ActiveValue::Set(decided_by_user_id),
+            ..Default::default()
+        };
+
+        member.insert(self.db).await
+    }
+
+    /// Finds the membership of a user in a group.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Some(GroupMemberModel))` - User is a member or has a pending request
+    /// - `Ok(None)` - User has no membership in the group
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find(
+        &self,
+        group_id: i32,
+        user_id: i32,
+    ) -> Result<Option<GroupMemberModel>, DbErr> {
+        entity::prelude::BifrostGroupMember::find()
+            .filter(entity::bifrost_group_member::Column::GroupId.eq(group_id))
+            .filter(entity::bifrost_group_member::Column::UserId.eq(user_id))
+            .one(self.db)
+            .await
+    }
+
+    /// Updates the status of a user's membership, recording the admin deciding it.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    /// - `user_id` - ID of the user
+    /// - `status` - Name of the new membership status
+    /// - `decided_by_user_id` - ID of the admin deciding the membership
+    ///
+    /// # Returns
+    /// - `Ok(Some(GroupMemberModel))` - Membership successfully updated
+    /// - `Ok(None)` - User has no membership in the group
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn set_status(
+        &self,
+        group_id: i32,
+        user_id: i32,
+        status: String,
+        decided_by_user_id: i32,
+    ) -> Result<Option<GroupMemberModel>, DbErr> {
+        let Some(member) = self.find(group_id, user_id).await? else {
+            return Ok(None);
+        };
+
+        let mut member_am = member.into_active_model();
+        member_am.status = ActiveValue::Set(status);
+        member_am.decided_at = ActiveValue::Set(Some(Utc::now().naive_utc()));
+        member_am.decided_by_user_id = ActiveValue::Set(Some(decided_by_user_id));
+
+        let member = member_am.update(self.db).await?;
+
+        Ok(Some(member))
+    }
+
+    /// Removes a user's membership or pending request from a group.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Membership removed
+    /// - `Ok(false)` - User had no membership in the group
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, group_id: i32, user_id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostGroupMember::delete_many()
+            .filter(entity::bifrost_group_member::Column::GroupId.eq(group_id))
+            .filter(entity::bifrost_group_member::Column::UserId.eq(user_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Retrieves the members and pending requests of a group.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GroupMemberModel>)` - Memberships of the group, oldest first
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_group_id(&self, group_id: i32) -> Result<Vec<GroupMemberModel>, DbErr> {
+        entity::prelude::BifrostGroupMember::find()
+            .filter(entity::bifrost_group_member::Column::GroupId.eq(group_id))
+            .order_by_asc(entity::bifrost_group_member::Column::CreatedAt)
+            .order_by_asc(entity::bifrost_group_member::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the memberships and pending requests of a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GroupMemberModel>)` - Memberships of the user (empty if none)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(&self, user_id: i32) -> Result<Vec<GroupMemberModel>, DbErr> {
+        entity::prelude::BifrostGroupMember::find()
+            .filter(entity::bifrost_group_member::Column::UserId.eq(user_id))
+            .all(self.db)
+            .await
+    }
+
+    /// Counts the memberships of a group with a status.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    /// - `status` - Name of the membership status
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of users with the status in the group
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn count(&self, group_id: i32, status: &str) -> Result<u64, DbErr> {
+        entity::prelude::BifrostGroupMember::find()
+            .filter(entity::bifrost_group_member::Column::GroupId.eq(group_id))
+            .filter(entity::bifrost_group_member::Column::Status.eq(status))
+            .count(self.db)
+            .await
+    }
+}
@@ -0,0 +1,135 @@
+//! Group rule repository.
+//!
+//! This module provides the `GroupRuleRepository` for the affiliations qualifying users for a
+//! group. Each rule names a corporation, alliance, or faction by its EVE Online ID; the kind is
+//! stored by name and parsed by the service layer.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+
+use crate::server::model::db::GroupRuleModel;
+
+/// Repository for managing group eligibility rules in the database.
+pub struct GroupRuleRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> GroupRuleRepository<'a, C> {
+    /// Creates a new instance of GroupRuleRepository.
+    ///
+    /// Constructs a repository for managing group eligibility rules in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `GroupRuleRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Adds a rule to a group.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    /// - `kind` - Name of the kind of affiliation the rule matches
+    /// - `entity_id` - EVE Online ID of the corporation, alliance, or faction
+    ///
+    /// # Returns
+    /// - `Ok(GroupRuleModel)` - The newly created rule record
+    /// - `Err(DbErr)` - Database operation failed, the group doesn't exist, or the group already
+    ///   has the rule
+    pub async fn create(
+        &self,
+        group_id: i32,
+        kind: String,
+        entity_id: i64,
+    ) -> Result<GroupRuleModel, DbErr> {
+        let rule = entity::bifrost_group_rule::ActiveModel {
+            group_id: ActiveValue::Set(group_id),
+            kind: ActiveValue::Set(kind),
+            entity_id: ActiveValue::Set(entity_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        rule.insert(self.db).await
+    }
+
+    /// Finds a rule of a group matching an affiliation.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    /// - `kind` - Name of the kind of affiliation
+    /// - `entity_id` - EVE Online ID of the corporation, alliance, or faction
+    ///
+    /// # Returns
+    /// - `Ok(Some(GroupRuleModel))` - Group has the rule
+    /// - `Ok(None)` - Group has no such rule
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find(
+        &self,
+        group_id: i32,
+        kind: &str,
+        entity_id: i64,
+    ) -> Result<Option<GroupRuleModel>, DbErr> {
+        entity::prelude::BifrostGroupRule::find()
+            .filter(entity::bifrost_group_rule::Column::GroupId.eq(group_id))
+            .filter(entity::bifrost_group_rule::Column::Kind.eq(kind))
+            .filter(entity::bifrost_group_rule::Column::EntityId.eq(entity_id))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves the rules of a group.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GroupRuleModel>)` - Rules of the group, oldest first (empty if the group is
+    ///   unrestricted)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_group_id(&self, group_id: i32) -> Result<Vec<GroupRuleModel>, DbErr> {
+        entity::prelude::BifrostGroupRule::find()
+            .filter(entity::bifrost_group_rule::Column::GroupId.eq(group_id))
+            .order_by_asc(entity::bifrost_group_rule::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the rules of every group.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GroupRuleModel>)` - All rules (empty if no group is restricted)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<GroupRuleModel>, DbErr> {
+        entity::prelude::BifrostGroupRule::find()
+            .order_by_asc(entity::bifrost_group_rule::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Removes a rule from a group.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    /// - `rule_id` - ID of the rule
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Rule removed
+    /// - `Ok(false)` - Group had no rule with the ID
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, group_id: i32, rule_id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostGroupRule::delete_many()
+            .filter(entity::bifrost_group_rule::Column::Id.eq(rule_id))
+            .filter(entity::bifrost_group_rule::Column::GroupId.eq(group_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
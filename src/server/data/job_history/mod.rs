@@ -0,0 +1,99 @@
+//! Worker job history data repository.
+//!
+//! This module contains the `JobHistoryRepository` for the record the worker pool keeps of each
+//! job it executed, with the job's type, the entity it refreshed, how long it ran, and how it
+//! finished. Unlike job statuses kept in Redis, the history outlives job retention and is used
+//! by the scheduler to hold off refreshing entities whose last refresh failed permanently.
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QuerySelect,
+};
+
+use crate::{model::worker::WorkerJobState, server::model::db::WorkerJobHistoryModel};
+
+/// Repository for managing worker job history records in the database.
+pub struct JobHistoryRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> JobHistoryRepository<'a, C> {
+    /// Creates a new instance of JobHistoryRepository.
+    ///
+    /// Constructs a repository for managing worker job history records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `JobHistoryRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Records the execution of a job.
+    ///
+    /// # Arguments
+    /// - `job_kind` - Name of the job's type, such as `UpdateAllianceInfo`
+    /// - `entity_id` - EVE Online ID of the entity the job refreshed, if it refreshed one
+    /// - `outcome` - State the job finished in
+    /// - `error` - Error or failed item summary of the job, if any
+    /// - `duration_ms` - Time the job ran for, in milliseconds
+    ///
+    /// # Returns
+    /// - `Ok(WorkerJobHistoryModel)` - The created history record
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn record(
+        &self,
+        job_kind: &str,
+        entity_id: Option<i64>,
+        outcome: WorkerJobState,
+        error: Option<String>,
+        duration_ms: i64,
+    ) -> Result<WorkerJobHistoryModel, DbErr> {
+        let history = entity::bifrost_worker_job_history::ActiveModel {
+            job_kind: ActiveValue::Set(job_kind.to_string()),
+            entity_id: ActiveValue::Set(entity_id),
+            outcome: ActiveValue::Set(outcome.as_str().to_string()),
+            error: ActiveValue::Set(error),
+            duration_ms: ActiveValue::Set(duration_ms),
+            finished_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        history.insert(self.db).await
+    }
+
+    /// Retrieves the IDs of entities a job type failed to refresh permanently since a time.
+    ///
+    /// # Arguments
+    /// - `job_kind` - Name of the job's type, such as `UpdateAllianceInfo`
+    /// - `since` - Earliest time a failure is considered
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i64>)` - Distinct EVE Online IDs of the entities whose refresh failed
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_failed_entity_ids(
+        &self,
+        job_kind: &str,
+        since: NaiveDateTime,
+    ) -> Result<Vec<i64>, DbErr> {
+        let ids: Vec<Option<i64>> = entity::prelude::BifrostWorkerJobHistory::find()
+            .filter(entity::bifrost_worker_job_history::Column::JobKind.eq(job_kind))
+            .filter(
+                entity::bifrost_worker_job_history::Column::Outcome
+                    .eq(WorkerJobState::Failed.as_str()),
+            )
+            .filter(entity::bifrost_worker_job_history::Column::EntityId.is_not_null())
+            .filter(entity::bifrost_worker_job_history::Column::FinishedAt.gte(since))
+            .select_only()
+            .column(entity::bifrost_worker_job_history::Column::EntityId)
+            .distinct()
+            .into_tuple()
+            .all(self.db)
+            .await?;
+
+        Ok(ids.into_iter().flatten().collect())
+    }
+}
@@ -0,0 +1,131 @@
+//! Member filter data repository.
+//!
+//! This module contains the `MemberFilterRepository` for the filters admins save for the
+//! admin member lists. Filters are personal to the admin who saved them and named uniquely
+//! per list.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    IntoActiveModel, QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::MemberFilterModel;
+
+/// Repository for managing saved member filter records in the database.
+pub struct MemberFilterRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> MemberFilterRepository<'a, C> {
+    /// Creates a new instance of MemberFilterRepository.
+    ///
+    /// Constructs a repository for managing saved member filter records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `MemberFilterRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Saves a filter or replaces the user's filter of the same name for the list.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user saving the filter
+    /// - `list` - Name of the member list the filter applies to
+    /// - `name` - Name of the filter
+    /// - `filter` - Filter criteria serialized as JSON
+    ///
+    /// # Returns
+    /// - `Ok(MemberFilterModel)` - The created or updated filter record
+    /// - `Err(DbErr)` - Database operation failed or the user ID doesn't exist
+    pub async fn upsert(
+        &self,
+        user_id: i32,
+        list: &str,
+        name: &str,
+        filter: String,
+    ) -> Result<MemberFilterModel, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let existing = entity::prelude::BifrostMemberFilter::find()
+            .filter(entity::bifrost_member_filter::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_member_filter::Column::List.eq(list))
+            .filter(entity::bifrost_member_filter::Column::Name.eq(name))
+            .one(self.db)
+            .await?;
+
+        match existing {
+            Some(member_filter) => {
+                let mut member_filter = member_filter.into_active_model();
+                member_filter.filter = ActiveValue::Set(filter);
+                member_filter.updated_at = ActiveValue::Set(now);
+
+                member_filter.update(self.db).await
+            }
+            None => {
+                let member_filter = entity::bifrost_member_filter::ActiveModel {
+                    user_id: ActiveValue::Set(user_id),
+                    list: ActiveValue::Set(list.to_string()),
+                    name: ActiveValue::Set(name.to_string()),
+                    filter: ActiveValue::Set(filter),
+                    created_at: ActiveValue::Set(now),
+                    updated_at: ActiveValue::Set(now),
+                    ..Default::default()
+                };
+
+                member_filter.insert(self.db).await
+            }
+        }
+    }
+
+    /// Retrieves a saved filter by ID.
+    ///
+    /// # Arguments
+    /// - `filter_id` - ID of the filter to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(MemberFilterModel))` - Filter found
+    /// - `Ok(None)` - No filter with the ID exists
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_id(&self, filter_id: i32) -> Result<Option<MemberFilterModel>, DbErr> {
+        entity::prelude::BifrostMemberFilter::find_by_id(filter_id)
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves a user's saved filters ordered by list and name.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user whose filters to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Vec<MemberFilterModel>)` - The user's filters (empty if none are saved)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(&self, user_id: i32) -> Result<Vec<MemberFilterModel>, DbErr> {
+        entity::prelude::BifrostMemberFilter::find()
+            .filter(entity::bifrost_member_filter::Column::UserId.eq(user_id))
+            .order_by_asc(entity::bifrost_member_filter::Column::List)
+            .order_by_asc(entity::bifrost_member_filter::Column::Name)
+            .all(self.db)
+            .await
+    }
+
+    /// Deletes a saved filter by ID.
+    ///
+    /// # Arguments
+    /// - `filter_id` - ID of the filter to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   filter didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, filter_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostMemberFilter::delete_by_id(filter_id)
+            .exec(self.db)
+            .await
+    }
+}
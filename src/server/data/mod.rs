@@ -1,8 +1,44 @@
 //! Data access layer repositories.
 //!
-//! This module contains all database repository implementations for the application.
-//! Repositories provide an abstraction layer over database operations, organizing
-//! data access by domain (EVE Online entities and user management).
+//! This module contains all database repository implementations for the application. Repositories
+//! provide an abstraction layer over database operations, organizing data access by domain (EVE
+//! Online entities, character affiliation history, admin tags and notes, announcements, approval
+//! requests for sensitive admin actions, user bans, campaigns, character skill snapshots, character
+//! refresh tokens, data-sharing consent, corporation member lists, admin dashboard summaries, saved
+//! queries and API keys for the data access API, doctrines, admin exports, groups and their
+//! members, worker job history, saved member list filters, admin-edited pages, onboarding steps,
+//! user preferences, push subscriptions, re-authentication campaigns, recruitment, admin roles and
+//! their assignments, screening, entity search, skill plans, user management, webhooks, and
+//! embeddable widgets).
 
+pub mod affiliation_history;
+pub mod annotation;
+pub mod announcement;
+pub mod approval;
+pub mod ban;
+pub mod campaign;
+pub mod character_skill;
+pub mod character_token;
+pub mod consent;
+pub mod corporation_member;
+pub mod dashboard;
+pub mod data_api;
+pub mod doctrine;
+pub mod group;
 pub mod eve;
+pub mod export;
+pub mod job_history;
+pub mod member;
+pub mod onboarding;
+pub mod page;
+pub mod preference;
+pub mod push;
+pub mod reauth_campaign;
+pub mod recruitment;
+pub mod role;
+pub mod screening;
+pub mod search;
+pub mod skill_plan;
 pub mod user;
+pub mod webhook;
+pub mod widget;
@@ -0,0 +1,193 @@
+//! Onboarding data repository.
+//!
+//! This module contains the `OnboardingRepository` for the onboarding steps admins define and
+//! the completions recorded for each user. Only steps completed by the user or by a login are
+//! stored; steps completed from existing data are evaluated when the checklist is read.
+
+use chrono::Utc;
+use migration::OnConflict;
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect,
+};
+
+use crate::server::model::db::{OnboardingCompletionModel, OnboardingStepModel};
+
+/// Repository for managing onboarding step and completion records in the database.
+pub struct OnboardingRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> OnboardingRepository<'a, C> {
+    /// Creates a new instance of OnboardingRepository.
+    ///
+    /// Constructs a repository for managing onboarding records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `OnboardingRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates an onboarding step at the end of the checklist.
+    ///
+    /// # Arguments
+    /// - `title` - Title shown on the checklist
+    /// - `description` - Instructions shown below the title
+    /// - `kind` - How the step is completed
+    /// - `target` - Space-separated ESI scopes, page slug, or invite URL, depending on the kind
+    /// - `created_by_user_id` - ID of the admin defining the step
+    ///
+    /// # Returns
+    /// - `Ok(OnboardingStepModel)` - The newly created step record
+    /// - `Err(DbErr)` - Database operation failed or the user ID doesn't exist
+    pub async fn create_step(
+        &self,
+        title: String,
+        description: String,
+        kind: &str,
+        target: Option<String>,
+        created_by_user_id: i32,
+    ) -> Result<OnboardingStepModel, DbErr> {
+        let last_position = entity::prelude::BifrostOnboardingStep::find()
+            .select_only()
+            .column_as(
+                Func::max(Expr::col(entity::bifrost_onboarding_step::Column::Position)),
+                "position",
+            )
+            .into_tuple::<Option<i32>>()
+            .one(self.db)
+            .await?
+            .flatten();
+
+        let step = entity::bifrost_onboarding_step::ActiveModel {
+            title: ActiveValue::Set(title),
+            description: ActiveValue::Set(description),
+            kind: ActiveValue::Set(kind.to_string()),
+            target: ActiveValue::Set(target),
+            position: ActiveValue::Set(last_position.map_or(0, |position| position + 1)),
+            created_by_user_id: ActiveValue::Set(created_by_user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        step.insert(self.db).await
+    }
+
+    /// Retrieves all onboarding steps in checklist order.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<OnboardingStepModel>)` - All steps (empty if none are defined)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_steps(&self) -> Result<Vec<OnboardingStepModel>, DbErr> {
+        entity::prelude::BifrostOnboardingStep::find()
+            .order_by_asc(entity::bifrost_onboarding_step::Column::Position)
+            .order_by_asc(entity::bifrost_onboarding_step::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves an onboarding step by ID.
+    ///
+    /// # Arguments
+    /// - `step_id` - ID of the step
+    ///
+    /// # Returns
+    /// - `Ok(Some(OnboardingStepModel))` - Step found
+    /// - `Ok(None)` - Step doesn't exist
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_step(&self, step_id: i32) -> Result<Option<OnboardingStepModel>, DbErr> {
+        entity::prelude::BifrostOnboardingStep::find_by_id(step_id)
+            .one(self.db)
+            .await
+    }
+
+    /// Deletes an onboarding step along with its completions.
+    ///
+    /// # Arguments
+    /// - `step_id` - ID of the step
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Number of steps deleted (0 if the step doesn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete_step(&self, step_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostOnboardingStep::delete_by_id(step_id)
+            .exec(self.db)
+            .await
+    }
+
+    /// Retrieves the stored completions of a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<OnboardingCompletionModel>)` - The user's completions
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_completions_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<OnboardingCompletionModel>, DbErr> {
+        entity::prelude::BifrostOnboardingCompletion::find()
+            .filter(entity::bifrost_onboarding_completion::Column::UserId.eq(user_id))
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the stored completions of every user as (step ID, user ID) pairs.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i32)>)` - List of (step ID, user ID) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all_completions(&self) -> Result<Vec<(i32, i32)>, DbErr> {
+        entity::prelude::BifrostOnboardingCompletion::find()
+            .select_only()
+            .column(entity::bifrost_onboarding_completion::Column::StepId)
+            .column(entity::bifrost_onboarding_completion::Column::UserId)
+            .into_tuple::<(i32, i32)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Records that a user completed onboarding steps, keeping earlier completions.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `step_ids` - IDs of the completed steps
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of completions recorded (no-op if `step_ids` is empty)
+    /// - `Err(DbErr)` - Database operation failed or a step or user ID doesn't exist
+    pub async fn complete(&self, user_id: i32, step_ids: &[i32]) -> Result<u64, DbErr> {
+        if step_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let now = Utc::now().naive_utc();
+        let completions =
+            step_ids.iter().map(
+                |step_id| entity::bifrost_onboarding_completion::ActiveModel {
+                    step_id: ActiveValue::Set(*step_id),
+                    user_id: ActiveValue::Set(user_id),
+                    completed_at: ActiveValue::Set(now),
+                    ..Default::default()
+                },
+            );
+
+        entity::prelude::BifrostOnboardingCompletion::insert_many(completions)
+            .on_conflict(
+                OnConflict::columns([
+                    entity::bifrost_onboarding_completion::Column::StepId,
+                    entity::bifrost_onboarding_completion::Column::UserId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec_without_returning(self.db)
+            .await
+    }
+}
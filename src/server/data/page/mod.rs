@@ -0,0 +1,225 @@
+//! Page data repository.
+//!
+//! This module contains the `PageRepository` for storing Markdown pages edited by admins and
+//! the revision history recording every saved version of a page.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    IntoActiveModel, QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::{PageModel, PageRevisionModel};
+
+/// Repository for managing page and page revision records in the database.
+///
+/// Provides operations for saving, retrieving, and deleting pages and recording revisions.
+pub struct PageRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> PageRepository<'a, C> {
+    /// Creates a new instance of PageRepository.
+    ///
+    /// Constructs a repository for managing page records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `PageRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a page or replaces the title and content of the page with the slug.
+    ///
+    /// # Arguments
+    /// - `slug` - Unique URL segment of the page
+    /// - `title` - Page title
+    /// - `content` - Page content as Markdown
+    ///
+    /// # Returns
+    /// - `Ok(PageModel)` - The created or updated page record
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn upsert(
+        &self,
+        slug: &str,
+        title: String,
+        content: String,
+    ) -> Result<PageModel, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        match self.get_by_slug(slug).await? {
+            Some(page) => {
+                let mut page = page.into_active_model();
+                page.title = ActiveValue::Set(title);
+                page.content = ActiveValue::Set(content);
+                page.updated_at = ActiveValue::Set(now);
+
+                page.update(self.db).await
+            }
+            None => {
+                let page = entity::bifrost_page::ActiveModel {
+                    slug: ActiveValue::Set(slug.to_string()),
+                    title: ActiveValue::Set(title),
+                    content: ActiveValue::Set(content),
+                    created_at: ActiveValue::Set(now),
+                    updated_at: ActiveValue::Set(now),
+                    ..Default::default()
+                };
+
+                page.insert(self.db).await
+            }
+        }
+    }
+
+    /// Retrieves a page by slug.
+    ///
+    /// # Arguments
+    /// - `slug` - URL segment of the page to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(PageModel))` - Page found
+    /// - `Ok(None)` - No page with the slug exists
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_slug(&self, slug: &str) -> Result<Option<PageModel>, DbErr> {
+        entity::prelude::BifrostPage::find()
+            .filter(entity::bifrost_page::Column::Slug.eq(slug))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all pages ordered by title.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PageModel>)` - All pages (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<PageModel>, DbErr> {
+        entity::prelude::BifrostPage::find()
+            .order_by_asc(entity::bifrost_page::Column::Title)
+            .all(self.db)
+            .await
+    }
+
+    /// Deletes a page by ID along with its revisions.
+    ///
+    /// # Arguments
+    /// - `page_id` - ID of the page to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   page didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, page_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostPage::delete_by_id(page_id)
+            .exec(self.db)
+            .await
+    }
+
+    /// Records a saved version of a page.
+    ///
+    /// # Arguments
+    /// - `page` - Page record holding the saved title and content
+    /// - `edited_by_user_id` - ID of the user who saved the page
+    ///
+    /// # Returns
+    /// - `Ok(PageRevisionModel)` - The newly created revision record
+    /// - `Err(DbErr)` - Database operation failed or the user ID doesn't exist
+    pub async fn create_revision(
+        &self,
+        page: &PageModel,
+        edited_by_user_id: i32,
+    ) -> Result<PageRevisionModel, DbErr> {
+        let revision = entity::bifrost_page_revision::ActiveModel {
+            page_id: ActiveValue::Set(page.id),
+            title: ActiveValue::Set(page.title.clone()),
+            content: ActiveValue::Set(page.content.clone()),
+            edited_by_user_id: ActiveValue::Set(edited_by_user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        revision.insert(self.db).await
+    }
+
+    /// Retrieves the revisions of a page, newest first.
+    ///
+    /// # Arguments
+    /// - `page_id` - ID of the page
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PageRevisionModel>)` - Revisions of the page (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_revisions(&self, page_id: i32) -> Result<Vec<PageRevisionModel>, DbErr> {
+        entity::prelude::BifrostPageRevision::find()
+            .filter(entity::bifrost_page_revision::Column::PageId.eq(page_id))
+            .order_by_desc(entity::bifrost_page_revision::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves a revision of a page.
+    ///
+    /// # Arguments
+    /// - `page_id` - ID of the page the revision must belong to
+    /// - `revision_id` - ID of the revision to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(PageRevisionModel))` - Revision found
+    /// - `Ok(None)` - No revision with the ID exists for the page
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_revision(
+        &self,
+        page_id: i32,
+        revision_id: i32,
+    ) -> Result<Option<PageRevisionModel>, DbErr> {
+        entity::prelude::BifrostPageRevision::find_by_id(revision_id)
+            .filter(entity::bifrost_page_revision::Column::PageId.eq(page_id))
+            .one(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for PageRepository::upsert method.
+    mod upsert {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::page::PageRepository;
+
+        /// Tests saving a page twice with the same slug.
+        ///
+        /// Verifies that the second save updates the existing page instead of creating another.
+        ///
+        /// Expected: Ok with the same page ID and the new title and content
+        #[tokio::test]
+        async fn updates_existing_page() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_table(entity::prelude::BifrostPage)
+                .build()
+                .await?;
+
+            let repository = PageRepository::new(&test.db);
+            let created = repository
+                .upsert("rules", "Rules".to_string(), "Be nice.".to_string())
+                .await?;
+            let updated = repository
+                .upsert(
+                    "rules",
+                    "Alliance Rules".to_string(),
+                    "No spying.".to_string(),
+                )
+                .await?;
+
+            assert_eq!(updated.id, created.id);
+            assert_eq!(updated.title, "Alliance Rules");
+            assert_eq!(updated.content, "No spying.");
+            assert_eq!(repository.get_all().await?.len(), 1);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Preference data repositories.
+//!
+//! This module contains the `UserPreferenceRepository` for storing per-user preferences
+//! such as the layout of the home dashboard and whether to receive the weekly digest.
+
+use chrono::Utc;
+use migration::OnConflict;
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QuerySelect,
+};
+
+use crate::server::model::db::UserPreferenceModel;
+
+/// Repository for managing user preference records in the database.
+///
+/// Each user has at most one preference record. Users without a record use the default
+/// preferences.
+pub struct UserPreferenceRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> UserPreferenceRepository<'a, C> {
+    /// Creates a new instance of UserPreferenceRepository.
+    ///
+    /// Constructs a repository for managing user preference records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `UserPreferenceRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the preference record of a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Some(UserPreferenceModel))` - User has saved preferences
+    /// - `Ok(None)` - User has never saved preferences
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(&self, user_id: i32) -> Result<Option<UserPreferenceModel>, DbErr> {
+        entity::prelude::BifrostUserPreference::find()
+            .filter(entity::bifrost_user_preference::Column::UserId.eq(user_id))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves the IDs of users who opted out of the weekly digest.
+    ///
+    /// Users without a preference record receive the digest and are not included.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - IDs of users who turned the weekly digest off
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_weekly_digest_opted_out_user_ids(&self) -> Result<Vec<i32>, DbErr> {
+        entity::prelude::BifrostUserPreference::find()
+            .select_only()
+            .column(entity::bifrost_user_preference::Column::UserId)
+            .filter(entity::bifrost_user_preference::Column::WeeklyDigest.eq(false))
+            .into_tuple::<i32>()
+            .all(self.db)
+            .await
+    }
+
+    /// Saves the preferences of a user, creating their preference record if needed.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `dashboard_layout` - Comma-separated dashboard widget names in display order
+    /// - `weekly_digest` - Whether the user receives the weekly digest
+    ///
+    /// # Returns
+    /// - `Ok(())` - Preferences saved
+    /// - `Err(DbErr)` - Database operation failed or user doesn't exist
+    pub async fn upsert(
+        &self,
+        user_id: i32,
+        dashboard_layout: &str,
+        weekly_digest: bool,
+    ) -> Result<(), DbErr> {
+        entity::prelude::BifrostUserPreference::insert(
+            entity::bifrost_user_preference::ActiveModel {
+                user_id: ActiveValue::Set(user_id),
+                dashboard_layout: ActiveValue::Set(dashboard_layout.to_string()),
+                weekly_digest: ActiveValue::Set(weekly_digest),
+                updated_at: ActiveValue::Set(Utc::now().naive_utc()),
+                ..Default::default()
+            },
+        )
+        .on_conflict(
+            OnConflict::column(entity::bifrost_user_preference::Column::UserId)
+                .update_columns([
+                    entity::bifrost_user_preference::Column::DashboardLayout,
+                    entity::bifrost_user_preference::Column::WeeklyDigest,
+                    entity::bifrost_user_preference::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_without_returning(self.db)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for UserPreferenceRepository::upsert method.
+    mod upsert {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::preference::UserPreferenceRepository;
+
+        /// Tests saving a layout for a user without preferences.
+        ///
+        /// Expected: Ok with a new preference record
+        #[tokio::test]
+        async fn creates_preference_record() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostUserPreference)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = UserPreferenceRepository::new(&test.db);
+            repository
+                .upsert(user_model.id, "updates,characters", true)
+                .await?;
+            let preference = repository.get_by_user_id(user_model.id).await?;
+
+            assert_eq!(
+                preference.map(|p| p.dashboard_layout),
+                Some("updates,characters".to_string())
+            );
+
+            Ok(())
+        }
+
+        /// Tests saving a layout for a user with existing preferences.
+        ///
+        /// Expected: Ok with the layout and weekly digest preference replaced
+        #[tokio::test]
+        async fn replaces_existing_layout() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostUserPreference)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = UserPreferenceRepository::new(&test.db);
+            repository.upsert(user_model.id, "characters", true).await?;
+            repository
+                .upsert(user_model.id, "data_sharing", false)
+                .await?;
+            let preference = repository.get_by_user_id(user_model.id).await?;
+
+            assert_eq!(
+                preference.map(|p| (p.dashboard_layout, p.weekly_digest)),
+                Some(("data_sharing".to_string(), false))
+            );
+            assert_eq!(
+                repository.get_weekly_digest_opted_out_user_ids().await?,
+                vec![user_model.id]
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Push subscription data repository.
+//!
+//! This module contains the `PushSubscriptionRepository` for storing the browsers and devices
+//! users subscribed to Web Push notifications on.
+
+use chrono::Utc;
+use migration::OnConflict;
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+
+use crate::server::model::db::PushSubscriptionModel;
+
+/// Repository for managing push subscription records in the database.
+///
+/// Each push service endpoint belongs to a single browser, so subscriptions are unique by
+/// endpoint.
+pub struct PushSubscriptionRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> PushSubscriptionRepository<'a, C> {
+    /// Creates a new instance of PushSubscriptionRepository.
+    ///
+    /// Constructs a repository for managing push subscription records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `PushSubscriptionRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Saves a push subscription, replacing any existing subscription for the endpoint.
+    ///
+    /// Browsers reuse the endpoint when another user logs in and subscribes, in which case the
+    /// subscription moves to that user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the subscribing user
+    /// - `endpoint` - Push service URL messages are sent to
+    /// - `p256dh` - Base64url-encoded P-256 public key of the browser
+    /// - `auth` - Base64url-encoded authentication secret of the browser
+    ///
+    /// # Returns
+    /// - `Ok(())` - Subscription saved
+    /// - `Err(DbErr)` - Database operation failed or user doesn't exist
+    pub async fn upsert(
+        &self,
+        user_id: i32,
+        endpoint: &str,
+        p256dh: &str,
+        auth: &str,
+    ) -> Result<(), DbErr> {
+        entity::prelude::BifrostPushSubscription::insert(
+            entity::bifrost_push_subscription::ActiveModel {
+                user_id: ActiveValue::Set(user_id),
+                endpoint: ActiveValue::Set(endpoint.to_string()),
+                p256dh: ActiveValue::Set(p256dh.to_string()),
+                auth: ActiveValue::Set(auth.to_string()),
+                created_at: ActiveValue::Set(Utc::now().naive_utc()),
+                ..Default::default()
+            },
+        )
+        .on_conflict(
+            OnConflict::column(entity::bifrost_push_subscription::Column::Endpoint)
+                .update_columns([
+                    entity::bifrost_push_subscription::Column::UserId,
+                    entity::bifrost_push_subscription::Column::P256dh,
+                    entity::bifrost_push_subscription::Column::Auth,
+                    entity::bifrost_push_subscription::Column::CreatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_without_returning(self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves all push subscriptions of a user, oldest first.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PushSubscriptionModel>)` - Subscriptions of the user (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(&self, user_id: i32) -> Result<Vec<PushSubscriptionModel>, DbErr> {
+        entity::prelude::BifrostPushSubscription::find()
+            .filter(entity::bifrost_push_subscription::Column::UserId.eq(user_id))
+            .order_by_asc(entity::bifrost_push_subscription::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Deletes a user's subscription for an endpoint.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user owning the subscription
+    /// - `endpoint` - Push service URL of the subscription
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   user has no subscription for the endpoint)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete_by_endpoint(
+        &self,
+        user_id: i32,
+        endpoint: &str,
+    ) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostPushSubscription::delete_many()
+            .filter(entity::bifrost_push_subscription::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_push_subscription::Column::Endpoint.eq(endpoint))
+            .exec(self.db)
+            .await
+    }
+
+    /// Deletes a subscription by ID.
+    ///
+    /// Used to remove subscriptions the push service reports as expired or unsubscribed.
+    ///
+    /// # Arguments
+    /// - `subscription_id` - ID of the subscription to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   subscription didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, subscription_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostPushSubscription::delete_by_id(subscription_id)
+            .exec(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for PushSubscriptionRepository::upsert method.
+    mod upsert {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::push::PushSubscriptionRepository;
+
+        /// Tests saving a new subscription.
+        ///
+        /// Expected: Ok with the subscription returned for the user
+        #[tokio::test]
+        async fn creates_subscription() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostPushSubscription)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = PushSubscriptionRepository::new(&test.db);
+            repository
+                .upsert(user_model.id, "https://push.example.com/1", "key", "auth")
+                .await?;
+            let subscriptions = repository.get_by_user_id(user_model.id).await?;
+
+            assert_eq!(subscriptions.len(), 1);
+            assert_eq!(subscriptions[0].endpoint, "https://push.example.com/1");
+
+            Ok(())
+        }
+
+        /// Tests subscribing an endpoint that is already subscribed by another user.
+        ///
+        /// Expected: Ok with the subscription moved to the new user
+        #[tokio::test]
+        async fn moves_endpoint_to_new_user() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostPushSubscription)
+                .build()
+                .await?;
+            let (first_user, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let (second_user, _, _) = test
+                .user()
+                .insert_user_with_mock_character(2, 1, None, None)
+                .await?;
+
+            let repository = PushSubscriptionRepository::new(&test.db);
+            repository
+                .upsert(first_user.id, "https://push.example.com/1", "key", "auth")
+                .await?;
+            repository
+                .upsert(
+                    second_user.id,
+                    "https://push.example.com/1",
+                    "key2",
+                    "auth2",
+                )
+                .await?;
+
+            assert!(repository.get_by_user_id(first_user.id).await?.is_empty());
+            let subscriptions = repository.get_by_user_id(second_user.id).await?;
+            assert_eq!(subscriptions.len(), 1);
+            assert_eq!(subscriptions[0].p256dh, "key2");
+
+            Ok(())
+        }
+    }
+
+    /// Tests for PushSubscriptionRepository::delete_by_endpoint method.
+    mod delete_by_endpoint {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::push::PushSubscriptionRepository;
+
+        /// Tests that users cannot delete subscriptions of other users.
+        ///
+        /// Expected: Ok with no rows affected and the subscription kept
+        #[tokio::test]
+        async fn ignores_other_users_subscription() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostPushSubscription)
+                .build()
+                .await?;
+            let (first_user, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let (second_user, _, _) = test
+                .user()
+                .insert_user_with_mock_character(2, 1, None, None)
+                .await?;
+
+            let repository = PushSubscriptionRepository::new(&test.db);
+            repository
+                .upsert(first_user.id, "https://push.example.com/1", "key", "auth")
+                .await?;
+            let result = repository
+                .delete_by_endpoint(second_user.id, "https://push.example.com/1")
+                .await?;
+
+            assert_eq!(result.rows_affected, 0);
+            assert_eq!(repository.get_by_user_id(first_user.id).await?.len(), 1);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Re-authentication campaign data repository.
+//!
+//! This module contains the `ReauthCampaignRepository` for storing campaigns launched by admins
+//! and the rows flagging each affected user. A flagged user's row is completed once they grant
+//! the campaign's scopes, so completion can be counted per campaign.
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect,
+};
+
+use crate::server::model::db::{ReauthCampaignModel, ReauthCampaignUserModel};
+
+/// Repository for managing re-authentication campaign records in the database.
+pub struct ReauthCampaignRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> ReauthCampaignRepository<'a, C> {
+    /// Creates a new instance of ReauthCampaignRepository.
+    ///
+    /// Constructs a repository for managing re-authentication campaign records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ReauthCampaignRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a re-authentication campaign.
+    ///
+    /// # Arguments
+    /// - `name` - Campaign name
+    /// - `scopes` - Space-separated ESI scopes users must grant
+    /// - `audience` - Audience kind the campaign targets
+    /// - `audience_id` - EVE Online corporation or alliance ID the campaign targets
+    /// - `deadline` - Time after which users who haven't re-authenticated lose access
+    /// - `created_by_user_id` - ID of the user launching the campaign
+    ///
+    /// # Returns
+    /// - `Ok(ReauthCampaignModel)` - The newly created campaign record
+    /// - `Err(DbErr)` - Database operation failed or the user ID doesn't exist
+    pub async fn create(
+        &self,
+        name: String,
+        scopes: String,
+        audience: &str,
+        audience_id: Option<i64>,
+        deadline: Option<NaiveDateTime>,
+        created_by_user_id: i32,
+    ) -> Result<ReauthCampaignModel, DbErr> {
+        let campaign = entity::bifrost_reauth_campaign::ActiveModel {
+            name: ActiveValue::Set(name),
+            scopes: ActiveValue::Set(scopes),
+            audience: ActiveValue::Set(audience.to_string()),
+            audience_id: ActiveValue::Set(audience_id),
+            deadline: ActiveValue::Set(deadline),
+            created_by_user_id: ActiveValue::Set(created_by_user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        campaign.insert(self.db).await
+    }
+
+    /// Flags users as affected by a campaign.
+    ///
+    /// # Arguments
+    /// - `campaign_id` - ID of the campaign
+    /// - `user_ids` - IDs of the affected users
+    ///
+    /// # Returns
+    /// - `Ok(())` - Users flagged (no-op if `user_ids` is empty)
+    /// - `Err(DbErr)` - Database operation failed or a user ID doesn't exist
+    pub async fn add_users(&self, campaign_id: i32, user_ids: &[i32]) -> Result<(), DbErr> {
+        if user_ids.is_empty() {
+            return Ok(());
+        }
+
+        let users =
+            user_ids.iter().map(
+                |user_id| entity::bifrost_reauth_campaign_user::ActiveModel {
+                    campaign_id: ActiveValue::Set(campaign_id),
+                    user_id: ActiveValue::Set(*user_id),
+                    completed_at: ActiveValue::Set(None),
+                    ..Default::default()
+                },
+            );
+
+        entity::prelude::BifrostReauthCampaignUser::insert_many(users)
+            .exec_without_returning(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves all campaigns, newest first.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ReauthCampaignModel>)` - All campaigns (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<ReauthCampaignModel>, DbErr> {
+        entity::prelude::BifrostReauthCampaign::find()
+            .order_by_desc(entity::bifrost_reauth_campaign::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Counts the users flagged by each campaign and how many of them have completed it.
+    ///
+    /// Campaigns without flagged users are not included.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64, i64)>)` - List of (campaign ID, user count, completed count) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn count_users(&self) -> Result<Vec<(i32, i64, i64)>, DbErr> {
+        entity::prelude::BifrostReauthCampaignUser::find()
+            .select_only()
+            .column(entity::bifrost_reauth_campaign_user::Column::CampaignId)
+            .column_as(
+                Func::count(Expr::col(entity::bifrost_reauth_campaign_user::Column::Id)),
+                "user_count",
+            )
+            .column_as(
+                Func::count(Expr::col(
+                    entity::bifrost_reauth_campaign_user::Column::CompletedAt,
+                )),
+                "completed_count",
+            )
+            .group_by(entity::bifrost_reauth_campaign_user::Column::CampaignId)
+            .into_tuple::<(i32, i64, i64)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the campaigns a user is flagged by and hasn't completed, oldest first.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the flagged user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(ReauthCampaignUserModel, ReauthCampaignModel)>)` - Pending rows of the user
+    ///   paired with their campaign
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_pending_for_user(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<(ReauthCampaignUserModel, ReauthCampaignModel)>, DbErr> {
+        let rows = entity::prelude::BifrostReauthCampaignUser::find()
+            .filter(entity::bifrost_reauth_campaign_user::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_reauth_campaign_user::Column::CompletedAt.is_null())
+            .find_also_related(entity::prelude::BifrostReauthCampaign)
+            .order_by_asc(entity::bifrost_reauth_campaign_user::Column::CampaignId)
+            .all(self.db)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(user, campaign)| campaign.map(|campaign| (user, campaign)))
+            .collect())
+    }
+
+    /// Marks flagged user rows as completed unless they already are.
+    ///
+    /// # Arguments
+    /// - `row_ids` - IDs of the flagged user rows
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of rows marked completed (no-op if `row_ids` is empty)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn complete(&self, row_ids: &[i32]) -> Result<u64, DbErr> {
+        if row_ids.is_empty() {
+            return Ok(0);
+        }
+
+        Ok(entity::prelude::BifrostReauthCampaignUser::update_many()
+            .col_expr(
+                entity::bifrost_reauth_campaign_user::Column::CompletedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(entity::bifrost_reauth_campaign_user::Column::Id.is_in(row_ids.to_vec()))
+            .filter(entity::bifrost_reauth_campaign_user::Column::CompletedAt.is_null())
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+}
@@ -0,0 +1,283 @@
+//! Recruitment data repositories.
+//!
+//! This module contains the `RecruitmentListingRepository` for managing the public
+//! recruitment listings of corporations that have opted into recruitment.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use migration::OnConflict;
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+
+use crate::server::model::db::{EveAllianceModel, EveCorporationModel, RecruitmentListingModel};
+
+/// Repository for managing corporation recruitment listings in the database.
+///
+/// Provides operations for creating or updating a corporation's listing, removing it, and
+/// retrieving all listings along with their corporation and alliance information.
+pub struct RecruitmentListingRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> RecruitmentListingRepository<'a, C> {
+    /// Creates a new instance of RecruitmentListingRepository.
+    ///
+    /// Constructs a repository for managing recruitment listing records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `RecruitmentListingRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates or updates the recruitment listing for a corporation.
+    ///
+    /// Inserts a new listing, or updates the description and timezone of the existing listing
+    /// if the corporation already has one.
+    ///
+    /// # Arguments
+    /// - `corporation_record_id` - Internal database ID of the corporation
+    /// - `description` - Recruitment pitch shown on the public listing
+    /// - `timezone` - Primary timezone the corporation is active in
+    ///
+    /// # Returns
+    /// - `Ok(RecruitmentListingModel)` - The created or updated listing
+    /// - `Err(DbErr)` - Database operation failed or corporation doesn't exist
+    pub async fn upsert(
+        &self,
+        corporation_record_id: i32,
+        description: String,
+        timezone: String,
+    ) -> Result<RecruitmentListingModel, DbErr> {
+        entity::prelude::BifrostRecruitmentListing::insert(
+            entity::bifrost_recruitment_listing::ActiveModel {
+                corporation_id: ActiveValue::Set(corporation_record_id),
+                description: ActiveValue::Set(description),
+                timezone: ActiveValue::Set(timezone),
+                created_at: ActiveValue::Set(Utc::now().naive_utc()),
+                updated_at: ActiveValue::Set(Utc::now().naive_utc()),
+                ..Default::default()
+            },
+        )
+        .on_conflict(
+            OnConflict::column(entity::bifrost_recruitment_listing::Column::CorporationId)
+                .update_columns([
+                    entity::bifrost_recruitment_listing::Column::Description,
+                    entity::bifrost_recruitment_listing::Column::Timezone,
+                    entity::bifrost_recruitment_listing::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_with_returning(self.db)
+        .await
+    }
+
+    /// Deletes the recruitment listing for a corporation.
+    ///
+    /// # Arguments
+    /// - `corporation_record_id` - Internal database ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if no listing existed)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete_by_corporation_id(
+        &self,
+        corporation_record_id: i32,
+    ) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostRecruitmentListing::delete_many()
+            .filter(
+                entity::bifrost_recruitment_listing::Column::CorporationId
+                    .eq(corporation_record_id),
+            )
+            .exec(self.db)
+            .await
+    }
+
+    /// Retrieves all recruitment listings with their corporation and alliance.
+    ///
+    /// Listings are ordered by most recently updated first so active recruiters appear at
+    /// the top of the public listing.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(RecruitmentListingModel, EveCorporationModel, Option<EveAllianceModel>)>)` - Listings with corporation and optional alliance
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all_with_corporation(
+        &self,
+    ) -> Result<
+        Vec<(
+            RecruitmentListingModel,
+            EveCorporationModel,
+            Option<EveAllianceModel>,
+        )>,
+        DbErr,
+    > {
+        let listings = entity::prelude::BifrostRecruitmentListing::find()
+            .find_also_related(entity::prelude::EveCorporation)
+            .order_by_desc(entity::bifrost_recruitment_listing::Column::UpdatedAt)
+            .all(self.db)
+            .await?;
+
+        let alliance_ids: Vec<i32> = listings
+            .iter()
+            .filter_map(|(_, corporation)| corporation.as_ref().and_then(|c| c.alliance_id))
+            .collect();
+
+        let alliances: HashMap<i32, EveAllianceModel> = if alliance_ids.is_empty() {
+            HashMap::new()
+        } else {
+            entity::prelude::EveAlliance::find()
+                .filter(entity::eve_alliance::Column::Id.is_in(alliance_ids))
+                .all(self.db)
+                .await?
+                .into_iter()
+                .map(|alliance| (alliance.id, alliance))
+                .collect()
+        };
+
+        // Listings always have a corporation due to the foreign key constraint
+        Ok(listings
+            .into_iter()
+            .filter_map(|(listing, corporation)| {
+                let corporation = corporation?;
+                let alliance = corporation
+                    .alliance_id
+                    .and_then(|id| alliances.get(&id).cloned());
+
+                Some((listing, corporation, alliance))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for RecruitmentListingRepository::upsert method.
+    mod upsert {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::recruitment::RecruitmentListingRepository;
+
+        /// Tests creating a new listing.
+        ///
+        /// Verifies that the repository creates a listing for a corporation without one.
+        ///
+        /// Expected: Ok with listing for the corporation
+        #[tokio::test]
+        async fn creates_listing() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostRecruitmentListing)
+                .build()
+                .await?;
+            let corporation_model = test.eve().insert_mock_corporation(1, None, None).await?;
+
+            let repository = RecruitmentListingRepository::new(&test.db);
+            let listing = repository
+                .upsert(
+                    corporation_model.id,
+                    "Nullsec PvP".to_string(),
+                    "EU".to_string(),
+                )
+                .await?;
+
+            assert_eq!(listing.corporation_id, corporation_model.id);
+            assert_eq!(listing.timezone, "EU");
+
+            Ok(())
+        }
+
+        /// Tests updating an existing listing.
+        ///
+        /// Verifies that upserting a listing for a corporation that already has one updates
+        /// the existing listing instead of creating a second one.
+        ///
+        /// Expected: Ok with the same listing ID and updated values
+        #[tokio::test]
+        async fn updates_existing_listing() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostRecruitmentListing)
+                .build()
+                .await?;
+            let corporation_model = test.eve().insert_mock_corporation(1, None, None).await?;
+
+            let repository = RecruitmentListingRepository::new(&test.db);
+            let initial = repository
+                .upsert(
+                    corporation_model.id,
+                    "Nullsec PvP".to_string(),
+                    "EU".to_string(),
+                )
+                .await?;
+            let updated = repository
+                .upsert(
+                    corporation_model.id,
+                    "Wormhole PvP".to_string(),
+                    "US".to_string(),
+                )
+                .await?;
+
+            assert_eq!(initial.id, updated.id);
+            assert_eq!(updated.description, "Wormhole PvP");
+            assert_eq!(updated.timezone, "US");
+            assert_eq!(repository.get_all_with_corporation().await?.len(), 1);
+
+            Ok(())
+        }
+    }
+
+    /// Tests for RecruitmentListingRepository::get_all_with_corporation method.
+    mod get_all_with_corporation {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::recruitment::RecruitmentListingRepository;
+
+        /// Tests retrieving listings with corporation and alliance.
+        ///
+        /// Verifies that each listing is returned with its corporation and, when the
+        /// corporation is in an alliance, its alliance.
+        ///
+        /// Expected: Ok with 2 listings, one with an alliance
+        #[tokio::test]
+        async fn returns_listings_with_alliance() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostRecruitmentListing)
+                .build()
+                .await?;
+            let corporation_model = test.eve().insert_mock_corporation(1, None, None).await?;
+            let alliance_corporation_model =
+                test.eve().insert_mock_corporation(2, Some(3), None).await?;
+
+            let repository = RecruitmentListingRepository::new(&test.db);
+            repository
+                .upsert(corporation_model.id, "A".to_string(), "EU".to_string())
+                .await?;
+            repository
+                .upsert(
+                    alliance_corporation_model.id,
+                    "B".to_string(),
+                    "US".to_string(),
+                )
+                .await?;
+
+            let listings = repository.get_all_with_corporation().await?;
+
+            assert_eq!(listings.len(), 2);
+            let (_, _, alliance) = listings
+                .iter()
+                .find(|(_, corporation, _)| corporation.corporation_id == 2)
+                .expect("Listing for corporation 2 should exist");
+            assert_eq!(alliance.as_ref().map(|a| a.alliance_id), Some(3));
+
+            Ok(())
+        }
+    }
+}
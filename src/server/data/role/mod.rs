@@ -0,0 +1,388 @@
+//! Role data repositories.
+//!
+//! This module contains the `RoleRepository` for the roles bundling admin permissions, and the
+//! `UserRoleRepository` for the roles assigned to each user. Permissions are stored
+//! space-separated; the service layer parses them.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    IntoActiveModel, JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+
+use crate::server::model::db::RoleModel;
+
+/// Repository for managing role records in the database.
+pub struct RoleRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> RoleRepository<'a, C> {
+    /// Creates a new instance of RoleRepository.
+    ///
+    /// Constructs a repository for managing role records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `RoleRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a role.
+    ///
+    /// # Arguments
+    /// - `name` - Unique name of the role
+    /// - `description` - Description shown to admins
+    /// - `permissions` - Space-separated permissions the role grants
+    /// - `built_in` - Whether the role is seeded by the application and can't be deleted
+    ///
+    /// # Returns
+    /// - `Ok(RoleModel)` - The newly created role record
+    /// - `Err(DbErr)` - Database operation failed or a role with the name already exists
+    pub async fn create(
+        &self,
+        name: String,
+        description: String,
+        permissions: String,
+        built_in: bool,
+    ) -> Result<RoleModel, DbErr> {
+        let now = Utc::now().naive_utc();
+        let role = entity::bifrost_role::ActiveModel {
+            name: ActiveValue::Set(name),
+            description: ActiveValue::Set(description),
+            permissions: ActiveValue::Set(permissions),
+            built_in: ActiveValue::Set(built_in),
+            created_at: ActiveValue::Set(now),
+            updated_at: ActiveValue::Set(now),
+            ..Default::default()
+        };
+
+        role.insert(self.db).await
+    }
+
+    /// Retrieves all roles.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<RoleModel>)` - All roles ordered by ID (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<RoleModel>, DbErr> {
+        entity::prelude::BifrostRole::find()
+            .order_by_asc(entity::bifrost_role::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Finds a role by ID.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the role
+    ///
+    /// # Returns
+    /// - `Ok(Some(RoleModel))` - Role found
+    /// - `Ok(None)` - Role doesn't exist
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<RoleModel>, DbErr> {
+        entity::prelude::BifrostRole::find_by_id(id)
+            .one(self.db)
+            .await
+    }
+
+    /// Finds a role by name.
+    ///
+    /// # Arguments
+    /// - `name` - Name of the role
+    ///
+    /// # Returns
+    /// - `Ok(Some(RoleModel))` - Role found
+    /// - `Ok(None)` - No role has the name
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<RoleModel>, DbErr> {
+        entity::prelude::BifrostRole::find()
+            .filter(entity::bifrost_role::Column::Name.eq(name))
+            .one(self.db)
+            .await
+    }
+
+    /// Updates a role.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the role
+    /// - `name` - Unique name of the role
+    /// - `description` - Description shown to admins
+    /// - `permissions` - Space-separated permissions the role grants
+    ///
+    /// # Returns
+    /// - `Ok(Some(RoleModel))` - Role successfully updated
+    /// - `Ok(None)` - Role doesn't exist
+    /// - `Err(DbErr)` - Database operation failed or another role has the name
+    pub async fn update(
+        &self,
+        id: i32,
+        name: String,
+        description: String,
+        permissions: String,
+    ) -> Result<Option<RoleModel>, DbErr> {
+        let Some(role) = self.find_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        let mut role_am = role.into_active_model();
+        role_am.name = ActiveValue::Set(name);
+        role_am.description = ActiveValue::Set(description);
+        role_am.permissions = ActiveValue::Set(permissions);
+        role_am.updated_at = ActiveValue::Set(Utc::now().naive_utc());
+
+        let role = role_am.update(self.db).await?;
+
+        Ok(Some(role))
+    }
+
+    /// Deletes a role, unassigning it from every user by cascade.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the role
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Role deleted
+    /// - `Ok(false)` - Role didn't exist
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostRole::delete_by_id(id)
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+/// Repository for managing the roles assigned to users in the database.
+pub struct UserRoleRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> UserRoleRepository<'a, C> {
+    /// Creates a new instance of UserRoleRepository.
+    ///
+    /// Constructs a repository for managing the roles assigned to users in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `UserRoleRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Assigns a role to a user.
+    ///
+    /// Assigning a role the user already has is a no-op.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `role_id` - ID of the role
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of rows inserted (1 if assigned, 0 if the user already had the role)
+    /// - `Err(DbErr)` - Database operation failed or the user or role doesn't exist
+    pub async fn assign(&self, user_id: i32, role_id: i32) -> Result<u64, DbErr> {
+        if self.has_role(user_id, role_id).await? {
+            return Ok(0);
+        }
+
+        entity::bifrost_user_role::ActiveModel {
+            user_id: ActiveValue::Set(user_id),
+            role_id: ActiveValue::Set(role_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(self.db)
+        .await?;
+
+        Ok(1)
+    }
+
+    /// Removes a role from a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `role_id` - ID of the role
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Role removed
+    /// - `Ok(false)` - User didn't have the role
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn revoke(&self, user_id: i32, role_id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostUserRole::delete_many()
+            .filter(entity::bifrost_user_role::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_user_role::Column::RoleId.eq(role_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Checks whether a user has been assigned a role.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `role_id` - ID of the role
+    ///
+    /// # Returns
+    /// - `Ok(true)` - User has the role
+    /// - `Ok(false)` - User doesn't have the role
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn has_role(&self, user_id: i32, role_id: i32) -> Result<bool, DbErr> {
+        let count = entity::prelude::BifrostUserRole::find()
+            .filter(entity::bifrost_user_role::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_user_role::Column::RoleId.eq(role_id))
+            .count(self.db)
+            .await?;
+
+        Ok(count > 0)
+    }
+
+    /// Retrieves the roles assigned to a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<RoleModel>)` - Roles of the user ordered by ID (empty if none)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_roles_by_user_id(&self, user_id: i32) -> Result<Vec<RoleModel>, DbErr> {
+        entity::prelude::BifrostRole::find()
+            .join(
+                JoinType::InnerJoin,
+                entity::bifrost_role::Relation::BifrostUserRole.def(),
+            )
+            .filter(entity::bifrost_user_role::Column::UserId.eq(user_id))
+            .order_by_asc(entity::bifrost_role::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Counts the users assigned a role.
+    ///
+    /// # Arguments
+    /// - `role_id` - ID of the role
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of users with the role
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn count_users(&self, role_id: i32) -> Result<u64, DbErr> {
+        entity::prelude::BifrostUserRole::find()
+            .filter(entity::bifrost_user_role::Column::RoleId.eq(role_id))
+            .count(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for UserRoleRepository::assign method.
+    mod assign {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::role::{RoleRepository, UserRoleRepository};
+
+        /// Tests assigning a role to a user.
+        ///
+        /// Verifies that the assigned role is returned for the user and that assigning the
+        /// same role twice does not insert a duplicate record.
+        ///
+        /// Expected: Ok with 1 row inserted, then 0 rows on the repeated assignment
+        #[tokio::test]
+        async fn assigns_role_once() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let role = RoleRepository::new(&test.db)
+                .create(
+                    "officer".to_string(),
+                    String::new(),
+                    "manage_members".to_string(),
+                    false,
+                )
+                .await?;
+
+            let repository = UserRoleRepository::new(&test.db);
+
+            assert_eq!(repository.assign(user_model.id, role.id).await?, 1);
+            assert_eq!(repository.assign(user_model.id, role.id).await?, 0);
+            assert_eq!(repository.count_users(role.id).await?, 1);
+
+            let roles = repository.get_roles_by_user_id(user_model.id).await?;
+            assert_eq!(roles.len(), 1);
+            assert_eq!(roles[0].name, "officer");
+
+            Ok(())
+        }
+
+        /// Tests error handling for a nonexistent role.
+        ///
+        /// Expected: Err
+        #[tokio::test]
+        async fn fails_for_nonexistent_role() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let nonexistent_role_id = 1;
+            let result = UserRoleRepository::new(&test.db)
+                .assign(user_model.id, nonexistent_role_id)
+                .await;
+
+            assert!(result.is_err());
+
+            Ok(())
+        }
+    }
+
+    /// Tests for RoleRepository::delete method.
+    mod delete {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::role::{RoleRepository, UserRoleRepository};
+
+        /// Tests that deleting a role unassigns it from its users.
+        ///
+        /// Expected: Ok(true) and the user no longer has any roles
+        #[tokio::test]
+        async fn unassigns_deleted_role() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let role = RoleRepository::new(&test.db)
+                .create(
+                    "officer".to_string(),
+                    String::new(),
+                    "manage_members".to_string(),
+                    false,
+                )
+                .await?;
+            let user_role_repo = UserRoleRepository::new(&test.db);
+            user_role_repo.assign(user_model.id, role.id).await?;
+
+            let deleted = RoleRepository::new(&test.db).delete(role.id).await?;
+
+            assert!(deleted);
+            assert!(user_role_repo
+                .get_roles_by_user_id(user_model.id)
+                .await?
+                .is_empty());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Screening data repositories.
+//!
+//! This module contains the `ScreeningReportRepository` for storing and retrieving
+//! recruitment screening report snapshots.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+
+use crate::server::model::db::ScreeningReportModel;
+
+/// Repository for managing screening report records in the database.
+///
+/// Reports are immutable snapshots; the repository only supports creating and reading them.
+pub struct ScreeningReportRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> ScreeningReportRepository<'a, C> {
+    /// Creates a new instance of ScreeningReportRepository.
+    ///
+    /// Constructs a repository for managing screening report records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ScreeningReportRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Stores a new screening report.
+    ///
+    /// # Arguments
+    /// - `character_record_id` - Internal database ID of the screened character
+    /// - `requested_by_user_id` - ID of the user who generated the report
+    /// - `report` - Serialized report content
+    ///
+    /// # Returns
+    /// - `Ok(ScreeningReportModel)` - The stored report
+    /// - `Err(DbErr)` - Database operation failed or character/user ID doesn't exist
+    pub async fn create(
+        &self,
+        character_record_id: i32,
+        requested_by_user_id: i32,
+        report: String,
+    ) -> Result<ScreeningReportModel, DbErr> {
+        let screening_report = entity::bifrost_screening_report::ActiveModel {
+            character_id: ActiveValue::Set(character_record_id),
+            requested_by_user_id: ActiveValue::Set(requested_by_user_id),
+            report: ActiveValue::Set(report),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        screening_report.insert(self.db).await
+    }
+
+    /// Retrieves a screening report by ID.
+    ///
+    /// # Arguments
+    /// - `report_id` - ID of the report to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(ScreeningReportModel))` - Report found
+    /// - `Ok(None)` - Report does not exist
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_id(&self, report_id: i32) -> Result<Option<ScreeningReportModel>, DbErr> {
+        entity::prelude::BifrostScreeningReport::find_by_id(report_id)
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all screening reports for a character, newest first.
+    ///
+    /// # Arguments
+    /// - `character_record_id` - Internal database ID of the screened character
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ScreeningReportModel>)` - Reports for the character (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_character_id(
+        &self,
+        character_record_id: i32,
+    ) -> Result<Vec<ScreeningReportModel>, DbErr> {
+        entity::prelude::BifrostScreeningReport::find()
+            .filter(entity::bifrost_screening_report::Column::CharacterId.eq(character_record_id))
+            .order_by_desc(entity::bifrost_screening_report::Column::CreatedAt)
+            .all(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for ScreeningReportRepository::create method.
+    mod create {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::screening::ScreeningReportRepository;
+
+        /// Tests storing a screening report.
+        ///
+        /// Verifies that a stored report can be retrieved by ID and by character.
+        ///
+        /// Expected: Ok with the report retrievable by ID and character
+        #[tokio::test]
+        async fn creates_report() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostScreeningReport)
+                .build()
+                .await?;
+            let (user_model, _, character_model) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = ScreeningReportRepository::new(&test.db);
+            let report = repository
+                .create(character_model.id, user_model.id, "{}".to_string())
+                .await?;
+
+            assert_eq!(repository.get_by_id(report.id).await?, Some(report.clone()));
+            assert_eq!(
+                repository.get_by_character_id(character_model.id).await?,
+                vec![report]
+            );
+
+            Ok(())
+        }
+
+        /// Tests error handling for a nonexistent character.
+        ///
+        /// Expected: Err
+        #[tokio::test]
+        async fn fails_for_nonexistent_character() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostScreeningReport)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let nonexistent_character_id = 100;
+            let result = ScreeningReportRepository::new(&test.db)
+                .create(nonexistent_character_id, user_model.id, "{}".to_string())
+                .await;
+
+            assert!(result.is_err());
+
+            Ok(())
+        }
+    }
+}
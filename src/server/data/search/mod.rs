@@ -0,0 +1,297 @@
+//! Search repository.
+//!
+//! This module contains the `SearchRepository` for the database search backend, which matches
+//! character, corporation, and alliance names case-insensitively. It also pages through all
+//! entities so they can be pushed to an external search index.
+
+use sea_orm::{
+    sea_query::{Expr, ExprTrait, Func, LikeExpr},
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+
+/// Escape character used in `LIKE` patterns built from user input.
+const LIKE_ESCAPE: char = '\\';
+
+/// Repository for searching EVE Online entities by name.
+pub struct SearchRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> SearchRepository<'a, C> {
+    /// Creates a new instance of SearchRepository.
+    ///
+    /// Constructs a repository for searching EVE Online entities by name.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `SearchRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Searches characters whose name contains the query, ignoring case.
+    ///
+    /// # Arguments
+    /// - `query` - Text the name must contain
+    /// - `limit` - Maximum number of characters to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i64, String)>)` - List of (character_id, name) tuples ordered by name
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn search_characters(
+        &self,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<(i64, String)>, DbErr> {
+        entity::prelude::EveCharacter::find()
+            .select_only()
+            .column(entity::eve_character::Column::CharacterId)
+            .column(entity::eve_character::Column::Name)
+            .filter(
+                Expr::expr(Func::lower(Expr::col(entity::eve_character::Column::Name)))
+                    .like(contains_pattern(query)),
+            )
+            .order_by_asc(entity::eve_character::Column::Name)
+            .limit(limit)
+            .into_tuple::<(i64, String)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Searches corporations whose name contains the query, ignoring case.
+    ///
+    /// # Arguments
+    /// - `query` - Text the name must contain
+    /// - `limit` - Maximum number of corporations to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i64, String)>)` - List of (corporation_id, name) tuples ordered by name
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn search_corporations(
+        &self,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<(i64, String)>, DbErr> {
+        entity::prelude::EveCorporation::find()
+            .select_only()
+            .column(entity::eve_corporation::Column::CorporationId)
+            .column(entity::eve_corporation::Column::Name)
+            .filter(
+                Expr::expr(Func::lower(Expr::col(
+                    entity::eve_corporation::Column::Name,
+                )))
+                .like(contains_pattern(query)),
+            )
+            .order_by_asc(entity::eve_corporation::Column::Name)
+            .limit(limit)
+            .into_tuple::<(i64, String)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Searches alliances whose name contains the query, ignoring case.
+    ///
+    /// # Arguments
+    /// - `query` - Text the name must contain
+    /// - `limit` - Maximum number of alliances to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i64, String)>)` - List of (alliance_id, name) tuples ordered by name
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn search_alliances(
+        &self,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<(i64, String)>, DbErr> {
+        entity::prelude::EveAlliance::find()
+            .select_only()
+            .column(entity::eve_alliance::Column::AllianceId)
+            .column(entity::eve_alliance::Column::Name)
+            .filter(
+                Expr::expr(Func::lower(Expr::col(entity::eve_alliance::Column::Name)))
+                    .like(contains_pattern(query)),
+            )
+            .order_by_asc(entity::eve_alliance::Column::Name)
+            .limit(limit)
+            .into_tuple::<(i64, String)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves a page of characters ordered by record ID.
+    ///
+    /// # Arguments
+    /// - `after_id` - Record ID of the last character of the previous page, `0` for the first page
+    /// - `limit` - Maximum number of characters to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64, String)>)` - List of (record_id, character_id, name) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_characters_page(
+        &self,
+        after_id: i32,
+        limit: u64,
+    ) -> Result<Vec<(i32, i64, String)>, DbErr> {
+        entity::prelude::EveCharacter::find()
+            .select_only()
+            .column(entity::eve_character::Column::Id)
+            .column(entity::eve_character::Column::CharacterId)
+            .column(entity::eve_character::Column::Name)
+            .filter(entity::eve_character::Column::Id.gt(after_id))
+            .order_by_asc(entity::eve_character::Column::Id)
+            .limit(limit)
+            .into_tuple::<(i32, i64, String)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves a page of corporations ordered by record ID.
+    ///
+    /// # Arguments
+    /// - `after_id` - Record ID of the last corporation of the previous page, `0` for the first page
+    /// - `limit` - Maximum number of corporations to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64, String)>)` - List of (record_id, corporation_id, name) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_corporations_page(
+        &self,
+        after_id: i32,
+        limit: u64,
+    ) -> Result<Vec<(i32, i64, String)>, DbErr> {
+        entity::prelude::EveCorporation::find()
+            .select_only()
+            .column(entity::eve_corporation::Column::Id)
+            .column(entity::eve_corporation::Column::CorporationId)
+            .column(entity::eve_corporation::Column::Name)
+            .filter(entity::eve_corporation::Column::Id.gt(after_id))
+            .order_by_asc(entity::eve_corporation::Column::Id)
+            .limit(limit)
+            .into_tuple::<(i32, i64, String)>()
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves a page of alliances ordered by record ID.
+    ///
+    /// # Arguments
+    /// - `after_id` - Record ID of the last alliance of the previous page, `0` for the first page
+    /// - `limit` - Maximum number of alliances to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64, String)>)` - List of (record_id, alliance_id, name) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_alliances_page(
+        &self,
+        after_id: i32,
+        limit: u64,
+    ) -> Result<Vec<(i32, i64, String)>, DbErr> {
+        entity::prelude::EveAlliance::find()
+            .select_only()
+            .column(entity::eve_alliance::Column::Id)
+            .column(entity::eve_alliance::Column::AllianceId)
+            .column(entity::eve_alliance::Column::Name)
+            .filter(entity::eve_alliance::Column::Id.gt(after_id))
+            .order_by_asc(entity::eve_alliance::Column::Id)
+            .limit(limit)
+            .into_tuple::<(i32, i64, String)>()
+            .all(self.db)
+            .await
+    }
+}
+
+/// Builds a lowercase `LIKE` pattern matching names that contain the query.
+///
+/// Wildcards in the query are escaped so they match literally.
+pub(crate) fn contains_pattern(query: &str) -> LikeExpr {
+    let mut escaped = String::with_capacity(query.len());
+    for c in query.to_lowercase().chars() {
+        if matches!(c, '%' | '_') || c == LIKE_ESCAPE {
+            escaped.push(LIKE_ESCAPE);
+        }
+        escaped.push(c);
+    }
+
+    LikeExpr::new(format!("%{}%", escaped)).escape(LIKE_ESCAPE)
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for SearchRepository::search_corporations method.
+    mod search_corporations {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::search::SearchRepository;
+
+        /// Tests that corporation names match regardless of case.
+        ///
+        /// Expected: Corporation returned for a lowercase part of its name
+        #[tokio::test]
+        async fn matches_ignoring_case() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_user_tables()
+                .with_mock_corporation(1, None, None)
+                .build()
+                .await?;
+
+            let results = SearchRepository::new(&test.db)
+                .search_corporations("order of", 10)
+                .await?;
+
+            assert_eq!(results, vec![(1, "The Order of Autumn".to_string())]);
+
+            Ok(())
+        }
+
+        /// Tests that wildcards in the query match literally.
+        ///
+        /// Expected: No corporation returned for a query consisting of a wildcard
+        #[tokio::test]
+        async fn escapes_wildcards() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_user_tables()
+                .with_mock_corporation(1, None, None)
+                .build()
+                .await?;
+
+            let results = SearchRepository::new(&test.db)
+                .search_corporations("%", 10)
+                .await?;
+
+            assert!(results.is_empty());
+
+            Ok(())
+        }
+    }
+
+    /// Tests for SearchRepository::get_characters_page method.
+    mod get_characters_page {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::search::SearchRepository;
+
+        /// Tests that pages continue after the given record ID.
+        ///
+        /// Expected: Second page holds the remaining character
+        #[tokio::test]
+        async fn pages_by_record_id() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            test.eve().insert_mock_character(1, 1, None, None).await?;
+            test.eve().insert_mock_character(2, 1, None, None).await?;
+
+            let repository = SearchRepository::new(&test.db);
+            let first = repository.get_characters_page(0, 1).await?;
+            assert_eq!(first.len(), 1);
+            assert_eq!(first[0].1, 1);
+
+            let second = repository.get_characters_page(first[0].0, 1).await?;
+            assert_eq!(second.len(), 1);
+            assert_eq!(second[0].1, 2);
+
+            Ok(())
+        }
+    }
+}
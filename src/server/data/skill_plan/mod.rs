@@ -0,0 +1,191 @@
+//! Skill plan data repository.
+//!
+//! This module contains the `SkillPlanRepository` for storing skill plans published by
+//! officers. Plans are stored as normalized plain text and parsed into entries when read.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::SkillPlanModel;
+
+/// Repository for managing skill plan records in the database.
+///
+/// Provides operations for creating, retrieving, and deleting skill plans.
+pub struct SkillPlanRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> SkillPlanRepository<'a, C> {
+    /// Creates a new instance of SkillPlanRepository.
+    ///
+    /// Constructs a repository for managing skill plan records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `SkillPlanRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new skill plan.
+    ///
+    /// # Arguments
+    /// - `name` - Unique skill plan name
+    /// - `description` - Optional skill plan description
+    /// - `plan` - Plan entries as plain text in training order
+    /// - `created_by_user_id` - ID of the user publishing the plan
+    ///
+    /// # Returns
+    /// - `Ok(SkillPlanModel)` - The newly created skill plan record
+    /// - `Err(DbErr)` - Database operation failed, a plan with the name already exists, or the
+    ///   user ID doesn't exist
+    pub async fn create(
+        &self,
+        name: String,
+        description: Option<String>,
+        plan: String,
+        created_by_user_id: i32,
+    ) -> Result<SkillPlanModel, DbErr> {
+        let skill_plan = entity::bifrost_skill_plan::ActiveModel {
+            name: ActiveValue::Set(name),
+            description: ActiveValue::Set(description),
+            plan: ActiveValue::Set(plan),
+            created_by_user_id: ActiveValue::Set(created_by_user_id),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            updated_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        skill_plan.insert(self.db).await
+    }
+
+    /// Retrieves a skill plan by name.
+    ///
+    /// # Arguments
+    /// - `name` - Name of the skill plan to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(SkillPlanModel))` - Skill plan found
+    /// - `Ok(None)` - No skill plan with the name exists
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_name(&self, name: &str) -> Result<Option<SkillPlanModel>, DbErr> {
+        entity::prelude::BifrostSkillPlan::find()
+            .filter(entity::bifrost_skill_plan::Column::Name.eq(name))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves all skill plans ordered by name.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<SkillPlanModel>)` - All skill plans (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<SkillPlanModel>, DbErr> {
+        entity::prelude::BifrostSkillPlan::find()
+            .order_by_asc(entity::bifrost_skill_plan::Column::Name)
+            .all(self.db)
+            .await
+    }
+
+    /// Deletes a skill plan by ID.
+    ///
+    /// # Arguments
+    /// - `skill_plan_id` - ID of the skill plan to delete
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if the
+    ///   skill plan didn't exist)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, skill_plan_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostSkillPlan::delete_by_id(skill_plan_id)
+            .exec(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for SkillPlanRepository::create method.
+    mod create {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::skill_plan::SkillPlanRepository;
+
+        /// Tests creating a new skill plan.
+        ///
+        /// Verifies that the created skill plan can then be found by name.
+        ///
+        /// Expected: Ok(Some(skill_plan))
+        #[tokio::test]
+        async fn creates_skill_plan() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostSkillPlan)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = SkillPlanRepository::new(&test.db);
+            let skill_plan = repository
+                .create(
+                    "Frigates".to_string(),
+                    None,
+                    "Caldari Frigate IV".to_string(),
+                    user_model.id,
+                )
+                .await?;
+            let found = repository.get_by_name("Frigates").await?;
+
+            assert_eq!(found, Some(skill_plan));
+
+            Ok(())
+        }
+
+        /// Tests error handling for duplicate skill plan names.
+        ///
+        /// Expected: Err
+        #[tokio::test]
+        async fn fails_for_duplicate_name() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostSkillPlan)
+                .build()
+                .await?;
+            let (user_model, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = SkillPlanRepository::new(&test.db);
+            repository
+                .create(
+                    "Frigates".to_string(),
+                    None,
+                    "Caldari Frigate IV".to_string(),
+                    user_model.id,
+                )
+                .await?;
+            let result = repository
+                .create(
+                    "Frigates".to_string(),
+                    None,
+                    "Navigation V".to_string(),
+                    user_model.id,
+                )
+                .await;
+
+            assert!(result.is_err());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! User Discord account repository.
+//!
+//! This module provides the `UserDiscordRepository` for the Discord accounts linked to users.
+//! Each user can link one Discord account, and each Discord account can only be linked to one
+//! user so access granted by Discord identity can't be shared between users.
+
+use chrono::Utc;
+use migration::OnConflict;
+use sea_orm::{ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+
+use crate::server::model::db::UserDiscordModel;
+
+/// Repository for managing linked Discord account records in the database.
+pub struct UserDiscordRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> UserDiscordRepository<'a, C> {
+    /// Creates a new instance of UserDiscordRepository.
+    ///
+    /// Constructs a repository for managing linked Discord account records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `UserDiscordRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the Discord account linked to a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Some(UserDiscordModel))` - User has linked a Discord account
+    /// - `Ok(None)` - User has not linked a Discord account
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(&self, user_id: i32) -> Result<Option<UserDiscordModel>, DbErr> {
+        entity::prelude::BifrostUserDiscord::find()
+            .filter(entity::bifrost_user_discord::Column::UserId.eq(user_id))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves the link of a Discord account.
+    ///
+    /// # Arguments
+    /// - `discord_id` - Discord user ID
+    ///
+    /// # Returns
+    /// - `Ok(Some(UserDiscordModel))` - Discord account is linked to a user
+    /// - `Ok(None)` - Discord account is not linked to any user
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_discord_id(
+        &self,
+        discord_id: i64,
+    ) -> Result<Option<UserDiscordModel>, DbErr> {
+        entity::prelude::BifrostUserDiscord::find()
+            .filter(entity::bifrost_user_discord::Column::DiscordId.eq(discord_id))
+            .one(self.db)
+            .await
+    }
+
+    /// Links a Discord account to a user, replacing the account the user linked before.
+    ///
+    /// Linking the same account again refreshes its username while keeping when it was first
+    /// linked.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `discord_id` - Discord user ID
+    /// - `username` - Current Discord username
+    ///
+    /// # Returns
+    /// - `Ok(())` - Discord account linked
+    /// - `Err(DbErr)` - Database operation failed, the user doesn't exist, or the Discord
+    ///   account is linked to another user
+    pub async fn upsert(&self, user_id: i32, discord_id: i64, username: &str) -> Result<(), DbErr> {
+        let now = Utc::now().naive_utc();
+
+        entity::prelude::BifrostUserDiscord::insert(entity::bifrost_user_discord::ActiveModel {
+            user_id: ActiveValue::Set(user_id),
+            discord_id: ActiveValue::Set(discord_id),
+            username: ActiveValue::Set(username.to_string()),
+            linked_at: ActiveValue::Set(now),
+            updated_at: ActiveValue::Set(now),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::column(entity::bifrost_user_discord::Column::UserId)
+                .update_columns([
+                    entity::bifrost_user_discord::Column::DiscordId,
+                    entity::bifrost_user_discord::Column::Username,
+                    entity::bifrost_user_discord::Column::UpdatedAt,
+                ])
+                .to_owned(),
+        )
+        .exec_without_returning(self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Unlinks the Discord account of a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Discord account unlinked
+    /// - `Ok(false)` - User had no Discord account linked
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete_by_user_id(&self, user_id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostUserDiscord::delete_many()
+            .filter(entity::bifrost_user_discord::Column::UserId.eq(user_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
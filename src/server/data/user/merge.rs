@@ -0,0 +1,588 @@
+//! User merge repository.
+//!
+//! This module provides the `UserMergeRepository` for moving records owned by one user to
+//! another when an admin merges duplicate user accounts. Each method reassigns all matching
+//! records in a single update so a merge runs a fixed number of queries regardless of how
+//! many records the removed user owns.
+
+use chrono::Utc;
+use sea_orm::{
+    sea_query::{Expr, Query},
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter,
+};
+
+/// Repository for reassigning user-owned records during a user merge.
+pub struct UserMergeRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> UserMergeRepository<'a, C> {
+    /// Creates a new instance of UserMergeRepository.
+    ///
+    /// Constructs a repository for reassigning records between users.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `UserMergeRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Moves all character ownerships from one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose characters are moved
+    /// - `to_user_id` - ID of the user receiving the characters
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of character ownerships moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_characters(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostUserCharacter::update_many()
+            .col_expr(
+                entity::bifrost_user_character::Column::UserId,
+                Expr::value(to_user_id),
+            )
+            .col_expr(
+                entity::bifrost_user_character::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(entity::bifrost_user_character::Column::UserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves all widgets created by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose widgets are moved
+    /// - `to_user_id` - ID of the user receiving the widgets
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of widgets moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_widgets(&self, from_user_id: i32, to_user_id: i32) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostWidget::update_many()
+            .col_expr(
+                entity::bifrost_widget::Column::UserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_widget::Column::UserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all fittings created by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose fittings are moved
+    /// - `to_user_id` - ID of the user receiving the fittings
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of fittings moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_fittings(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostFitting::update_many()
+            .col_expr(
+                entity::bifrost_fitting::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_fitting::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all skill plans published by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose skill plans are moved
+    /// - `to_user_id` - ID of the user receiving the skill plans
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of skill plans moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_skill_plans(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostSkillPlan::update_many()
+            .col_expr(
+                entity::bifrost_skill_plan::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_skill_plan::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all campaigns created by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose campaigns are moved
+    /// - `to_user_id` - ID of the user receiving the campaigns
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of campaigns moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_campaigns(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostCampaign::update_many()
+            .col_expr(
+                entity::bifrost_campaign::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_campaign::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves all push subscriptions of one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose push subscriptions are moved
+    /// - `to_user_id` - ID of the user receiving the push subscriptions
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of push subscriptions moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_push_subscriptions(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostPushSubscription::update_many()
+            .col_expr(
+                entity::bifrost_push_subscription::Column::UserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_push_subscription::Column::UserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves all screening reports requested by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose screening reports are moved
+    /// - `to_user_id` - ID of the user receiving the screening reports
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of screening reports moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_screening_reports(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostScreeningReport::update_many()
+            .col_expr(
+                entity::bifrost_screening_report::Column::RequestedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_screening_report::Column::RequestedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all page revisions saved by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose page revisions are moved
+    /// - `to_user_id` - ID of the user receiving the page revisions
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of page revisions moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_page_revisions(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostPageRevision::update_many()
+            .col_expr(
+                entity::bifrost_page_revision::Column::EditedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_page_revision::Column::EditedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all announcements posted by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose announcements are moved
+    /// - `to_user_id` - ID of the user receiving the announcements
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of announcements moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_announcements(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostAnnouncement::update_many()
+            .col_expr(
+                entity::bifrost_announcement::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_announcement::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all re-authentication campaigns launched by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose campaigns are moved
+    /// - `to_user_id` - ID of the user receiving the campaigns
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of re-authentication campaigns moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_reauth_campaigns(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostReauthCampaign::update_many()
+            .col_expr(
+                entity::bifrost_reauth_campaign::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_reauth_campaign::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all onboarding steps defined by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose onboarding steps are moved
+    /// - `to_user_id` - ID of the user receiving the onboarding steps
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of onboarding steps moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_onboarding_steps(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostOnboardingStep::update_many()
+            .col_expr(
+                entity::bifrost_onboarding_step::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_onboarding_step::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all saved queries created by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose saved queries are moved
+    /// - `to_user_id` - ID of the user receiving the saved queries
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of saved queries moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_saved_queries(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostSavedQuery::update_many()
+            .col_expr(
+                entity::bifrost_saved_query::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_saved_query::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves ownership of all data access API keys created by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose API keys are moved
+    /// - `to_user_id` - ID of the user receiving the API keys
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of API keys moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_api_keys(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostApiKey::update_many()
+            .col_expr(
+                entity::bifrost_api_key::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_api_key::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all tags added by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose tags are moved
+    /// - `to_user_id` - ID of the user receiving the tags
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of tags moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_tags(&self, from_user_id: i32, to_user_id: i32) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostTag::update_many()
+            .col_expr(
+                entity::bifrost_tag::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_tag::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves authorship of all notes written by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose notes are moved
+    /// - `to_user_id` - ID of the user receiving the notes
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of notes moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_notes(&self, from_user_id: i32, to_user_id: i32) -> Result<u64, DbErr> {
+        Ok(entity::prelude::BifrostNote::update_many()
+            .col_expr(
+                entity::bifrost_note::Column::CreatedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_note::Column::CreatedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected)
+    }
+
+    /// Moves all approval requests requested or decided by one user to another.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose approval requests are moved
+    /// - `to_user_id` - ID of the user receiving the approval requests
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of approval requests moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_approval_requests(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+    ) -> Result<u64, DbErr> {
+        let requested = entity::prelude::BifrostApprovalRequest::update_many()
+            .col_expr(
+                entity::bifrost_approval_request::Column::RequestedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_approval_request::Column::RequestedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected;
+        let decided = entity::prelude::BifrostApprovalRequest::update_many()
+            .col_expr(
+                entity::bifrost_approval_request::Column::DecidedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_approval_request::Column::DecidedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected;
+
+        Ok(requested + decided)
+    }
+
+    /// Moves all bans of, issued by, or lifted by one user to another.
+    ///
+    /// Bans would otherwise be deleted with the removed user by cascade, letting a banned user
+    /// shed their bans by merging into a fresh user.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose bans are moved
+    /// - `to_user_id` - ID of the user receiving the bans
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of bans moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_bans(&self, from_user_id: i32, to_user_id: i32) -> Result<u64, DbErr> {
+        let banned = entity::prelude::BifrostUserBan::update_many()
+            .col_expr(
+                entity::bifrost_user_ban::Column::UserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_user_ban::Column::UserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected;
+        entity::prelude::BifrostUserBan::update_many()
+            .col_expr(
+                entity::bifrost_user_ban::Column::IssuedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_user_ban::Column::IssuedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?;
+        entity::prelude::BifrostUserBan::update_many()
+            .col_expr(
+                entity::bifrost_user_ban::Column::LiftedByUserId,
+                Expr::value(to_user_id),
+            )
+            .filter(entity::bifrost_user_ban::Column::LiftedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(banned)
+    }
+
+    /// Moves all group memberships and requests to join of one user to another.
+    ///
+    /// Where both users are in the same group the stronger status is kept: a membership
+    /// replaces the other user's pending request, and otherwise the kept user's record stays.
+    /// Memberships decided by the user are moved as well.
+    ///
+    /// # Arguments
+    /// - `from_user_id` - ID of the user whose group memberships are moved
+    /// - `to_user_id` - ID of the user receiving the group memberships
+    /// - `member_status` - Name of the membership status outranking a pending request
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of group memberships and requests moved
+    /// - `Err(DbErr)` - Database update failed
+    pub async fn reassign_group_memberships(
+        &self,
+        from_user_id: i32,
+        to_user_id: i32,
+        member_status: &str,
+    ) -> Result<u64, DbErr> {
+        use entity::bifrost_group_member::Column;
+
+        // Requests of the receiving user to groups the other user is a member of are replaced
+        entity::prelude::BifrostGroupMember::delete_many()
+            .filter(Column::UserId.eq(to_user_id))
+            .filter(Column::Status.ne(member_status))
+            .filter(
+                Column::GroupId.in_subquery(
+                    Query::select()
+                        .column(Column::GroupId)
+                        .from(entity::prelude::BifrostGroupMember)
+                        .and_where(Column::UserId.eq(from_user_id))
+                        .and_where(Column::Status.eq(member_status))
+                        .to_owned(),
+                ),
+            )
+            .exec(self.db)
+            .await?;
+        // The receiving user's remaining records are at least as strong as the other user's
+        entity::prelude::BifrostGroupMember::delete_many()
+            .filter(Column::UserId.eq(from_user_id))
+            .filter(
+                Column::GroupId.in_subquery(
+                    Query::select()
+                        .column(Column::GroupId)
+                        .from(entity::prelude::BifrostGroupMember)
+                        .and_where(Column::UserId.eq(to_user_id))
+                        .to_owned(),
+                ),
+            )
+            .exec(self.db)
+            .await?;
+        let moved = entity::prelude::BifrostGroupMember::update_many()
+            .col_expr(Column::UserId, Expr::value(to_user_id))
+            .filter(Column::UserId.eq(from_user_id))
+            .exec(self.db)
+            .await?
+            .rows_affected;
+        entity::prelude::BifrostGroupMember::update_many()
+            .col_expr(Column::DecidedByUserId, Expr::value(to_user_id))
+            .filter(Column::DecidedByUserId.eq(from_user_id))
+            .exec(self.db)
+            .await?;
+
+        Ok(moved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for UserMergeRepository::reassign_characters method.
+    mod reassign_characters {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::user::{
+            merge::UserMergeRepository, user_character::UserCharacterRepository,
+        };
+
+        /// Tests moving characters between users.
+        ///
+        /// Verifies that only the source user's characters are moved.
+        ///
+        /// Expected: Ok(1) with the character owned by the target user
+        #[tokio::test]
+        async fn moves_characters_of_source_user() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            let (keep, _, _) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let (remove, _, character) = test
+                .user()
+                .insert_user_with_mock_character(2, 1, None, None)
+                .await?;
+
+            let moved = UserMergeRepository::new(&test.db)
+                .reassign_characters(remove.id, keep.id)
+                .await?;
+
+            assert_eq!(moved, 1);
+            let ownership = UserCharacterRepository::new(&test.db)
+                .get_ownership_by_character_id(character.id)
+                .await?
+                .unwrap();
+            assert_eq!(ownership.user_id, keep.id);
+
+            Ok(())
+        }
+    }
+}
@@ -2,15 +2,20 @@
 //!
 //! This module contains repositories for managing user accounts and their relationships
 //! with EVE Online characters. The `UserRepository` handles user account CRUD operations,
-//! while `user_character` manages the ownership links between users and characters.
+//! while `user_character` manages the ownership links between users and characters,
+//! `summary` stores the denormalized read model of each user's characters, and `discord`
+//! stores the Discord account linked to each user.
 
+pub mod discord;
+pub mod merge;
+pub mod summary;
 pub mod user_character;
 
 use crate::server::model::db::{EveCharacterModel, UserModel};
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
-    IntoActiveModel,
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait,
+    IntoActiveModel, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
 };
 
 /// Repository for managing user records in the database.
@@ -129,6 +134,50 @@ impl<'a, C: ConnectionTrait> UserRepository<'a, C> {
             .exec(self.db)
             .await
     }
+
+    /// Counts all registered users.
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of users
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn count(&self) -> Result<u64, DbErr> {
+        entity::prelude::BifrostUser::find().count(self.db).await
+    }
+
+    /// Retrieves the users registered since a point in time along with their main characters.
+    ///
+    /// # Arguments
+    /// - `since` - Earliest registration time to include
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(UserModel, Option<EveCharacterModel>)>)` - Users registered since the given
+    ///   time, oldest first
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_created_since(
+        &self,
+        since: NaiveDateTime,
+    ) -> Result<Vec<(UserModel, Option<EveCharacterModel>)>, DbErr> {
+        entity::prelude::BifrostUser::find()
+            .filter(entity::bifrost_user::Column::CreatedAt.gte(since))
+            .find_also_related(entity::eve_character::Entity)
+            .order_by_asc(entity::bifrost_user::Column::CreatedAt)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the IDs of all registered users.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - IDs of every user
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all_ids(&self) -> Result<Vec<i32>, DbErr> {
+        entity::prelude::BifrostUser::find()
+            .select_only()
+            .column(entity::bifrost_user::Column::Id)
+            .into_tuple::<i32>()
+            .all(self.db)
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -403,4 +452,33 @@ mod tests {
             Ok(())
         }
     }
+
+    /// Tests for UserRepository::count method.
+    mod count {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::user::UserRepository;
+
+        /// Tests counting registered users.
+        ///
+        /// Expected: Ok with 0 before and 2 after inserting two users
+        #[tokio::test]
+        async fn counts_users() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+
+            let user_repository = UserRepository::new(&test.db);
+            assert_eq!(user_repository.count().await?, 0);
+
+            test.user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            test.user()
+                .insert_user_with_mock_character(2, 2, None, None)
+                .await?;
+
+            assert_eq!(user_repository.count().await?, 2);
+
+            Ok(())
+        }
+    }
 }
@@ -0,0 +1,437 @@
+//! User character summary repository.
+//!
+//! This module provides the `UserCharacterSummaryRepository` for the denormalized read model
+//! of the characters owned by each user. The user endpoints are called on nearly every page
+//! load, so instead of joining ownerships with characters, corporations, and alliances per
+//! request, a copy of the joined rows is stored per user and rebuilt when the underlying
+//! records change.
+
+use chrono::NaiveDateTime;
+use sea_orm::{
+    sea_query::{Condition, Expr, ExprTrait, Func, IntoCondition},
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+
+use crate::server::{
+    data::search::contains_pattern,
+    model::db::{
+        EveAllianceModel, EveCharacterModel, EveCorporationModel, UserCharacterSummaryModel,
+    },
+};
+
+/// Repository for the denormalized user character summary table.
+pub struct UserCharacterSummaryRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> UserCharacterSummaryRepository<'a, C> {
+    /// Creates a new instance of UserCharacterSummaryRepository.
+    ///
+    /// Constructs a repository for reading and rebuilding user character summaries.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `UserCharacterSummaryRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Replaces the stored summary of a user's characters.
+    ///
+    /// Deletes the user's existing rows as well as any rows of the given characters stored
+    /// for a previous owner before inserting the new rows; call within a transaction so the
+    /// user endpoints never read a partially rebuilt summary.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user whose summary to replace
+    /// - `main_character_id` - Record ID of the user's main character
+    /// - `characters` - Owned characters with their corporation and optional alliance
+    /// - `refreshed_at` - Timestamp the summary was built at
+    ///
+    /// # Returns
+    /// - `Ok(())` - Summary replaced
+    /// - `Err(DbErr)` - Database delete or insert failed
+    pub async fn replace_for_user(
+        &self,
+        user_id: i32,
+        main_character_id: i32,
+        characters: Vec<(
+            EveCharacterModel,
+            EveCorporationModel,
+            Option<EveAllianceModel>,
+        )>,
+        refreshed_at: NaiveDateTime,
+    ) -> Result<(), DbErr> {
+        let character_ids: Vec<i64> = characters
+            .iter()
+            .map(|(character, _, _)| character.character_id)
+            .collect();
+
+        entity::prelude::BifrostUserCharacterSummary::delete_many()
+            .filter(
+                Condition::any()
+                    .add(entity::bifrost_user_character_summary::Column::UserId.eq(user_id))
+                    .add(
+                        entity::bifrost_user_character_summary::Column::CharacterId
+                            .is_in(character_ids),
+                    ),
+            )
+            .exec(self.db)
+            .await?;
+
+        if characters.is_empty() {
+            return Ok(());
+        }
+
+        let rows = characters
+            .into_iter()
+            .map(|(character, corporation, alliance)| {
+                entity::bifrost_user_character_summary::ActiveModel {
+                    user_id: ActiveValue::Set(user_id),
+                    is_main: ActiveValue::Set(character.id == main_character_id),
+                    character_id: ActiveValue::Set(character.character_id),
+                    character_name: ActiveValue::Set(character.name),
+                    character_info_updated_at: ActiveValue::Set(character.info_updated_at),
+                    character_affiliation_updated_at: ActiveValue::Set(
+                        character.affiliation_updated_at,
+                    ),
+                    corporation_id: ActiveValue::Set(corporation.corporation_id),
+                    corporation_name: ActiveValue::Set(corporation.name),
+                    corporation_info_updated_at: ActiveValue::Set(corporation.info_updated_at),
+                    corporation_affiliation_updated_at: ActiveValue::Set(
+                        corporation.affiliation_updated_at,
+                    ),
+                    alliance_id: ActiveValue::Set(alliance.as_ref().map(|a| a.alliance_id)),
+                    alliance_name: ActiveValue::Set(alliance.as_ref().map(|a| a.name.clone())),
+                    alliance_updated_at: ActiveValue::Set(alliance.map(|a| a.updated_at)),
+                    refreshed_at: ActiveValue::Set(refreshed_at),
+                    ..Default::default()
+                }
+            });
+
+        entity::prelude::BifrostUserCharacterSummary::insert_many(rows)
+            .exec(self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the stored summary of a user's characters.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user whose characters to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserCharacterSummaryModel>)` - Summary rows in the order they were stored
+    ///   (empty if the summary has not been built for the user)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<UserCharacterSummaryModel>, DbErr> {
+        entity::prelude::BifrostUserCharacterSummary::find()
+            .filter(entity::bifrost_user_character_summary::Column::UserId.eq(user_id))
+            .order_by_asc(entity::bifrost_user_character_summary::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the stored summary row of a user's main character.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user whose main character to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Some(UserCharacterSummaryModel))` - Summary row of the main character
+    /// - `Ok(None)` - User does not exist or the summary has not been built for the user
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_main_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<UserCharacterSummaryModel>, DbErr> {
+        entity::prelude::BifrostUserCharacterSummary::find()
+            .filter(entity::bifrost_user_character_summary::Column::UserId.eq(user_id))
+            .filter(entity::bifrost_user_character_summary::Column::IsMain.eq(true))
+            .one(self.db)
+            .await
+    }
+
+    /// Retrieves the stored summary rows of the given users, ordered by character name.
+    ///
+    /// # Arguments
+    /// - `user_ids` - IDs of the users whose characters to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserCharacterSummaryModel>)` - Summary rows of the users' characters
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_ids(
+        &self,
+        user_ids: Vec<i32>,
+    ) -> Result<Vec<UserCharacterSummaryModel>, DbErr> {
+        entity::prelude::BifrostUserCharacterSummary::find()
+            .filter(entity::bifrost_user_character_summary::Column::UserId.is_in(user_ids))
+            .order_by_asc(entity::bifrost_user_character_summary::Column::CharacterName)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the stored summary rows of the given characters.
+    ///
+    /// # Arguments
+    /// - `character_ids` - EVE Online character IDs
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserCharacterSummaryModel>)` - Summary rows of the owned characters among them
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_character_ids(
+        &self,
+        character_ids: Vec<i64>,
+    ) -> Result<Vec<UserCharacterSummaryModel>, DbErr> {
+        entity::prelude::BifrostUserCharacterSummary::find()
+            .filter(
+                entity::bifrost_user_character_summary::Column::CharacterId.is_in(character_ids),
+            )
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the stored summary rows matching the admin member list filters.
+    ///
+    /// Every given criterion must match. The owners criterion matches rows owned by any of the
+    /// users or of any of the characters, so a tag can be matched on either.
+    ///
+    /// # Arguments
+    /// - `name` - Text the character name must contain, ignoring case
+    /// - `corporation_id` - EVE Online corporation ID of the character
+    /// - `alliance_id` - EVE Online alliance ID of the character
+    /// - `owners` - IDs of users and EVE Online character IDs the row must belong to
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserCharacterSummaryModel>)` - Matching rows ordered by character name
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_filtered(
+        &self,
+        name: Option<&str>,
+        corporation_id: Option<i64>,
+        alliance_id: Option<i64>,
+        owners: Option<(Vec<i32>, Vec<i64>)>,
+    ) -> Result<Vec<UserCharacterSummaryModel>, DbErr> {
+        let mut condition = Condition::all();
+        if let Some(name) = name {
+            condition = condition.add(
+                Expr::expr(Func::lower(Expr::col(
+                    entity::bifrost_user_character_summary::Column::CharacterName,
+                )))
+                .like(contains_pattern(name)),
+            );
+        }
+        if let Some(corporation_id) = corporation_id {
+            condition = condition.add(
+                entity::bifrost_user_character_summary::Column::CorporationId.eq(corporation_id),
+            );
+        }
+        if let Some(alliance_id) = alliance_id {
+            condition = condition
+                .add(entity::bifrost_user_character_summary::Column::AllianceId.eq(alliance_id));
+        }
+        if let Some((user_ids, character_ids)) = owners {
+            condition = condition.add(
+                Condition::any()
+                    .add(entity::bifrost_user_character_summary::Column::UserId.is_in(user_ids))
+                    .add(
+                        entity::bifrost_user_character_summary::Column::CharacterId
+                            .is_in(character_ids),
+                    ),
+            );
+        }
+
+        entity::prelude::BifrostUserCharacterSummary::find()
+            .filter(condition)
+            .order_by_asc(entity::bifrost_user_character_summary::Column::CharacterName)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the IDs of users whose summary contains any of the given characters.
+    ///
+    /// # Arguments
+    /// - `character_ids` - EVE Online character IDs
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - Distinct user IDs
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_user_ids_by_character_ids(
+        &self,
+        character_ids: Vec<i64>,
+    ) -> Result<Vec<i32>, DbErr> {
+        self.get_user_ids(
+            entity::bifrost_user_character_summary::Column::CharacterId.is_in(character_ids),
+        )
+        .await
+    }
+
+    /// Retrieves the IDs of users whose summary contains characters in a corporation.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - EVE Online corporation ID
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - Distinct user IDs
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_user_ids_by_corporation_id(
+        &self,
+        corporation_id: i64,
+    ) -> Result<Vec<i32>, DbErr> {
+        self.get_user_ids(
+            entity::bifrost_user_character_summary::Column::CorporationId.eq(corporation_id),
+        )
+        .await
+    }
+
+    /// Retrieves the IDs of users whose summary contains characters in an alliance.
+    ///
+    /// # Arguments
+    /// - `alliance_id` - EVE Online alliance ID
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - Distinct user IDs
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_user_ids_by_alliance_id(&self, alliance_id: i64) -> Result<Vec<i32>, DbErr> {
+        self.get_user_ids(
+            entity::bifrost_user_character_summary::Column::AllianceId.eq(alliance_id),
+        )
+        .await
+    }
+
+    /// Retrieves the distinct IDs of users owning summary rows matching a condition.
+    async fn get_user_ids(&self, condition: impl IntoCondition) -> Result<Vec<i32>, DbErr> {
+        entity::prelude::BifrostUserCharacterSummary::find()
+            .select_only()
+            .column(entity::bifrost_user_character_summary::Column::UserId)
+            .distinct()
+            .filter(condition)
+            .into_tuple::<i32>()
+            .all(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for UserCharacterSummaryRepository::replace_for_user method.
+    mod replace_for_user {
+        use bifrost_test_utils::prelude::*;
+        use chrono::Utc;
+
+        use crate::server::data::user::{
+            summary::UserCharacterSummaryRepository, user_character::UserCharacterRepository,
+        };
+
+        /// Tests that rebuilding a user's summary stores every owned character.
+        ///
+        /// Expected: One row per character, only the main character flagged
+        #[tokio::test]
+        async fn stores_owned_characters() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            let (user_model, _, main) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let (_, alt) = test
+                .user()
+                .insert_mock_character_for_user(user_model.id, 2, 2, None, None)
+                .await?;
+
+            let characters = UserCharacterRepository::new(&test.db)
+                .get_owned_characters_by_user_id(user_model.id)
+                .await?;
+            let repository = UserCharacterSummaryRepository::new(&test.db);
+            repository
+                .replace_for_user(
+                    user_model.id,
+                    user_model.main_character_id,
+                    characters,
+                    Utc::now().naive_utc(),
+                )
+                .await?;
+
+            let rows = repository.get_by_user_id(user_model.id).await?;
+            assert_eq!(rows.len(), 2);
+            let main_row = repository
+                .get_main_by_user_id(user_model.id)
+                .await?
+                .expect("Main character row should exist");
+            assert_eq!(main_row.character_id, main.character_id);
+            let alt_row = rows
+                .iter()
+                .find(|row| row.character_id == alt.character_id)
+                .expect("Alt character row should exist");
+            assert!(!alt_row.is_main);
+            assert_eq!(alt_row.corporation_id, 2);
+            assert!(alt_row.alliance_id.is_none());
+
+            Ok(())
+        }
+
+        /// Tests that a character's row moves to its new owner.
+        ///
+        /// Verifies that rebuilding the new owner's summary removes the row stored for the
+        /// previous owner, which would otherwise violate the unique character ID.
+        ///
+        /// Expected: Character only in the new owner's summary
+        #[tokio::test]
+        async fn moves_transferred_character() -> Result<(), TestError> {
+            let mut test = TestBuilder::new().with_user_tables().build().await?;
+            let (first_user, _, character) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let (second_user, _, _) = test
+                .user()
+                .insert_user_with_mock_character(2, 1, None, None)
+                .await?;
+
+            let user_character_repo = UserCharacterRepository::new(&test.db);
+            let repository = UserCharacterSummaryRepository::new(&test.db);
+            repository
+                .replace_for_user(
+                    first_user.id,
+                    first_user.main_character_id,
+                    user_character_repo
+                        .get_owned_characters_by_user_id(first_user.id)
+                        .await?,
+                    Utc::now().naive_utc(),
+                )
+                .await?;
+
+            user_character_repo
+                .upsert(character.id, second_user.id, "owner_hash".to_string())
+                .await?;
+            repository
+                .replace_for_user(
+                    second_user.id,
+                    second_user.main_character_id,
+                    user_character_repo
+                        .get_owned_characters_by_user_id(second_user.id)
+                        .await?,
+                    Utc::now().naive_utc(),
+                )
+                .await?;
+
+            assert!(repository.get_by_user_id(first_user.id).await?.is_empty());
+            assert_eq!(repository.get_by_user_id(second_user.id).await?.len(), 2);
+            assert_eq!(
+                repository
+                    .get_user_ids_by_character_ids(vec![character.character_id])
+                    .await?,
+                vec![second_user.id]
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -10,7 +10,21 @@ use crate::server::model::db::{
 use chrono::Utc;
 use dioxus_logger::tracing;
 use migration::OnConflict;
-use sea_orm::{ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+use sea_orm::{
+    sea_query::{Expr, Func},
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+
+/// Column a page of a user's characters is ordered by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OwnedCharacterOrder {
+    /// Character name, alphabetically.
+    #[default]
+    Name,
+    /// Corporation name, then character name, alphabetically.
+    Corporation,
+}
 
 /// Repository for managing user-character ownership relationships in the database.
 ///
@@ -122,6 +136,29 @@ impl<'a, C: ConnectionTrait> UserCharacterRepository<'a, C> {
             .await
     }
 
+    /// Retrieves the characters stored for a list of EVE Online character IDs with their
+    /// ownership status.
+    ///
+    /// # Arguments
+    /// - `eve_character_ids` - EVE Online character IDs
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(character, Option<ownership>)>)` - Stored characters with their ownership
+    ///   record, if owned (IDs not in the database are left out)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_characters_with_ownership(
+        &self,
+        eve_character_ids: &[i64],
+    ) -> Result<Vec<(EveCharacterModel, Option<CharacterOwnershipModel>)>, DbErr> {
+        entity::prelude::EveCharacter::find()
+            .filter(
+                entity::eve_character::Column::CharacterId.is_in(eve_character_ids.iter().copied()),
+            )
+            .find_also_related(entity::bifrost_user_character::Entity)
+            .all(self.db)
+            .await
+    }
+
     /// Retrieves all character ownership records for a user.
     ///
     /// Fetches all user-character ownership links for the specified user ID from
@@ -143,6 +180,27 @@ impl<'a, C: ConnectionTrait> UserCharacterRepository<'a, C> {
             .await
     }
 
+    /// Counts the characters owned by each user.
+    ///
+    /// Users without characters are not included.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(i32, i64)>)` - List of (user ID, character count) tuples
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn count_by_user(&self) -> Result<Vec<(i32, i64)>, DbErr> {
+        entity::prelude::BifrostUserCharacter::find()
+            .select_only()
+            .column(entity::bifrost_user_character::Column::UserId)
+            .column_as(
+                Func::count(Expr::col(entity::bifrost_user_character::Column::Id)),
+                "character_count",
+            )
+            .group_by(entity::bifrost_user_character::Column::UserId)
+            .into_tuple::<(i32, i64)>()
+            .all(self.db)
+            .await
+    }
+
     /// Retrieves complete character information for all characters owned by a user.
     ///
     /// Fetches all characters owned by the specified user along with their corporation
@@ -174,14 +232,112 @@ impl<'a, C: ConnectionTrait> UserCharacterRepository<'a, C> {
                 .all(self.db)
                 .await?;
 
-        if user_characters.is_empty() {
+        let characters = user_characters
+            .into_iter()
+            .filter_map(|(_, eve_char)| eve_char)
+            .collect();
+
+        self.with_affiliations(characters).await
+    }
+
+    /// Retrieves a page of the characters owned by a user, filtered by affiliation.
+    ///
+    /// Characters are joined with their corporations and alliances so the filters and the
+    /// ordering apply before the page is cut, and the total counts every matching character
+    /// rather than only those on the page. Ties are ordered by character ID so pages stay
+    /// stable between requests.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user whose characters to retrieve
+    /// - `corporation_id` - EVE Online corporation ID characters must be in, if any
+    /// - `alliance_id` - EVE Online alliance ID characters must be in, if any
+    /// - `order` - Column characters are ordered by
+    /// - `offset` - Number of matching characters to skip
+    /// - `limit` - Maximum number of characters to return
+    ///
+    /// # Returns
+    /// - `Ok((Vec<(EveCharacter, EveCorporation, Option<EveAlliance>)>, u64))` - Page of
+    ///   characters with corp/alliance info and the number of matching characters
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_owned_characters_page(
+        &self,
+        user_id: i32,
+        corporation_id: Option<i64>,
+        alliance_id: Option<i64>,
+        order: OwnedCharacterOrder,
+        offset: u64,
+        limit: u64,
+    ) -> Result<
+        (
+            Vec<(
+                EveCharacterModel,
+                EveCorporationModel,
+                Option<EveAllianceModel>,
+            )>,
+            u64,
+        ),
+        DbErr,
+    > {
+        let mut query = entity::prelude::EveCharacter::find()
+            .join(
+                JoinType::InnerJoin,
+                entity::eve_character::Relation::BifrostUserCharacter.def(),
+            )
+            .join(
+                JoinType::InnerJoin,
+                entity::eve_character::Relation::EveCorporation.def(),
+            )
+            .join(
+                JoinType::LeftJoin,
+                entity::eve_corporation::Relation::EveAlliance.def(),
+            )
+            .filter(entity::bifrost_user_character::Column::UserId.eq(user_id));
+
+        if let Some(corporation_id) = corporation_id {
+            query = query.filter(entity::eve_corporation::Column::CorporationId.eq(corporation_id));
+        }
+        if let Some(alliance_id) = alliance_id {
+            query = query.filter(entity::eve_alliance::Column::AllianceId.eq(alliance_id));
+        }
+
+        let total = query.clone().count(self.db).await?;
+
+        query = match order {
+            OwnedCharacterOrder::Name => query.order_by_asc(entity::eve_character::Column::Name),
+            OwnedCharacterOrder::Corporation => query
+                .order_by_asc(entity::eve_corporation::Column::Name)
+                .order_by_asc(entity::eve_character::Column::Name),
+        };
+
+        let characters = query
+            .order_by_asc(entity::eve_character::Column::Id)
+            .offset(offset)
+            .limit(limit)
+            .all(self.db)
+            .await?;
+
+        Ok((self.with_affiliations(characters).await?, total))
+    }
+
+    /// Pairs characters with their corporations and optional alliances, keeping their order.
+    ///
+    /// Characters missing corporation data are logged as warnings and excluded from results.
+    async fn with_affiliations(
+        &self,
+        characters: Vec<EveCharacterModel>,
+    ) -> Result<
+        Vec<(
+            EveCharacterModel,
+            EveCorporationModel,
+            Option<EveAllianceModel>,
+        )>,
+        DbErr,
+    > {
+        if characters.is_empty() {
             return Ok(Vec::new());
         }
 
-        let corporation_ids: Vec<i32> = user_characters
-            .iter()
-            .filter_map(|(_, eve_char)| eve_char.as_ref().map(|c| c.corporation_id))
-            .collect();
+        let corporation_ids: Vec<i32> = characters.iter().map(|c| c.corporation_id).collect();
 
         let corporations: std::collections::HashMap<
             i32,
@@ -197,27 +353,22 @@ impl<'a, C: ConnectionTrait> UserCharacterRepository<'a, C> {
 
         // Build the result by matching corporations (with alliances) to characters
         // Filter out entries without corporations
-        let result = user_characters
+        let result = characters
             .into_iter()
-            .filter_map(|(_, eve_char)| {
-                match eve_char {
-                    Some(character) => {
-                        match corporations.get(&character.corporation_id) {
-                            Some((corporation, alliance)) => {
-                                Some((character, corporation.clone(), alliance.clone()))
-                            }
-                            None => {
-                                tracing::warn!(
-                                    character_id = character.id,
-                                    character_name = %character.name,
-                                    corporation_id = character.corporation_id,
-                                    "Failed to find related corporation for character in database - skipping character from results"
-                                );
-                                None
-                            }
-                        }
+            .filter_map(|character| {
+                match corporations.get(&character.corporation_id) {
+                    Some((corporation, alliance)) => {
+                        Some((character, corporation.clone(), alliance.clone()))
+                    }
+                    None => {
+                        tracing::warn!(
+                            character_id = character.id,
+                            character_name = %character.name,
+                            corporation_id = character.corporation_id,
+                            "Failed to find related corporation for character in database - skipping character from results"
+                        );
+                        None
                     }
-                    None => None,
                 }
             })
             .collect();
@@ -0,0 +1,157 @@
+//! Webhook data repository.
+//!
+//! This module contains the `WebhookRepository` for the webhook targets admins configure to be
+//! notified of character ownership changes. Subscribed events are stored space-separated; the
+//! service layer parses them.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    IntoActiveModel, QueryFilter, QueryOrder,
+};
+
+use crate::server::model::db::WebhookModel;
+
+/// Repository for managing webhook records in the database.
+pub struct WebhookRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> WebhookRepository<'a, C> {
+    /// Creates a new instance of WebhookRepository.
+    ///
+    /// Constructs a repository for managing webhook records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `WebhookRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates an enabled webhook.
+    ///
+    /// # Arguments
+    /// - `name` - Name shown to admins
+    /// - `url` - URL payloads are posted to
+    /// - `events` - Space-separated events the webhook is notified of
+    ///
+    /// # Returns
+    /// - `Ok(WebhookModel)` - The newly created webhook record
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn create(
+        &self,
+        name: String,
+        url: String,
+        events: String,
+    ) -> Result<WebhookModel, DbErr> {
+        let now = Utc::now().naive_utc();
+        let webhook = entity::bifrost_webhook::ActiveModel {
+            name: ActiveValue::Set(name),
+            url: ActiveValue::Set(url),
+            events: ActiveValue::Set(events),
+            enabled: ActiveValue::Set(true),
+            created_at: ActiveValue::Set(now),
+            updated_at: ActiveValue::Set(now),
+            ..Default::default()
+        };
+
+        webhook.insert(self.db).await
+    }
+
+    /// Retrieves all webhooks.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<WebhookModel>)` - All webhooks ordered by ID (empty if none exist)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_all(&self) -> Result<Vec<WebhookModel>, DbErr> {
+        entity::prelude::BifrostWebhook::find()
+            .order_by_asc(entity::bifrost_webhook::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Retrieves the enabled webhooks.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<WebhookModel>)` - Enabled webhooks ordered by ID
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_enabled(&self) -> Result<Vec<WebhookModel>, DbErr> {
+        entity::prelude::BifrostWebhook::find()
+            .filter(entity::bifrost_webhook::Column::Enabled.eq(true))
+            .order_by_asc(entity::bifrost_webhook::Column::Id)
+            .all(self.db)
+            .await
+    }
+
+    /// Finds a webhook by ID.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the webhook
+    ///
+    /// # Returns
+    /// - `Ok(Some(WebhookModel))` - Webhook found
+    /// - `Ok(None)` - Webhook doesn't exist
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn find_by_id(&self, id: i32) -> Result<Option<WebhookModel>, DbErr> {
+        entity::prelude::BifrostWebhook::find_by_id(id)
+            .one(self.db)
+            .await
+    }
+
+    /// Updates a webhook.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the webhook
+    /// - `name` - Name shown to admins
+    /// - `url` - URL payloads are posted to
+    /// - `events` - Space-separated events the webhook is notified of
+    /// - `enabled` - Whether the webhook is notified
+    ///
+    /// # Returns
+    /// - `Ok(Some(WebhookModel))` - Webhook successfully updated
+    /// - `Ok(None)` - Webhook doesn't exist
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn update(
+        &self,
+        id: i32,
+        name: String,
+        url: String,
+        events: String,
+        enabled: bool,
+    ) -> Result<Option<WebhookModel>, DbErr> {
+        let Some(webhook) = self.find_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        let mut webhook_am = webhook.into_active_model();
+        webhook_am.name = ActiveValue::Set(name);
+        webhook_am.url = ActiveValue::Set(url);
+        webhook_am.events = ActiveValue::Set(events);
+        webhook_am.enabled = ActiveValue::Set(enabled);
+        webhook_am.updated_at = ActiveValue::Set(Utc::now().naive_utc());
+
+        let webhook = webhook_am.update(self.db).await?;
+
+        Ok(Some(webhook))
+    }
+
+    /// Deletes a webhook.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the webhook
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Webhook deleted
+    /// - `Ok(false)` - Webhook didn't exist
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, id: i32) -> Result<bool, DbErr> {
+        let result = entity::prelude::BifrostWebhook::delete_by_id(id)
+            .exec(self.db)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
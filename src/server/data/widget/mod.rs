@@ -0,0 +1,240 @@
+//! Widget data repositories.
+//!
+//! This module contains the `WidgetRepository` for managing embeddable widgets and looking
+//! them up by the token external websites use to embed them.
+
+use chrono::Utc;
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, DeleteResult, EntityTrait, QueryFilter,
+    QueryOrder,
+};
+
+use crate::server::model::db::{EveCorporationModel, WidgetModel};
+
+/// Repository for managing embeddable widget records in the database.
+///
+/// Each record represents a single widget for one corporation. Widget kinds are stored by
+/// name so new kinds can be added without a migration.
+pub struct WidgetRepository<'a, C: ConnectionTrait> {
+    db: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> WidgetRepository<'a, C> {
+    /// Creates a new instance of WidgetRepository.
+    ///
+    /// Constructs a repository for managing widget records in the database.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `WidgetRepository` - New repository instance
+    pub fn new(db: &'a C) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new widget.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user creating the widget
+    /// - `corporation_record_id` - Internal database ID of the corporation the widget displays
+    /// - `kind` - Widget kind name
+    /// - `token` - Unique token authorizing access to the widget
+    ///
+    /// # Returns
+    /// - `Ok(WidgetModel)` - The created widget
+    /// - `Err(DbErr)` - Database operation failed, duplicate token, or user/corporation doesn't exist
+    pub async fn create(
+        &self,
+        user_id: i32,
+        corporation_record_id: i32,
+        kind: &str,
+        token: String,
+    ) -> Result<WidgetModel, DbErr> {
+        entity::prelude::BifrostWidget::insert(entity::bifrost_widget::ActiveModel {
+            user_id: ActiveValue::Set(user_id),
+            corporation_id: ActiveValue::Set(corporation_record_id),
+            kind: ActiveValue::Set(kind.to_string()),
+            token: ActiveValue::Set(token),
+            created_at: ActiveValue::Set(Utc::now().naive_utc()),
+            ..Default::default()
+        })
+        .exec_with_returning(self.db)
+        .await
+    }
+
+    /// Retrieves a widget and its corporation by the widget's token.
+    ///
+    /// # Arguments
+    /// - `token` - Widget token provided by the embedding website
+    ///
+    /// # Returns
+    /// - `Ok(Some((WidgetModel, EveCorporationModel)))` - Widget and the corporation it displays
+    /// - `Ok(None)` - No widget exists for the token
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_token(
+        &self,
+        token: &str,
+    ) -> Result<Option<(WidgetModel, EveCorporationModel)>, DbErr> {
+        let widget = entity::prelude::BifrostWidget::find()
+            .filter(entity::bifrost_widget::Column::Token.eq(token))
+            .find_also_related(entity::prelude::EveCorporation)
+            .one(self.db)
+            .await?;
+
+        // Widgets always have a corporation due to the foreign key constraint
+        Ok(widget.and_then(|(widget, corporation)| Some((widget, corporation?))))
+    }
+
+    /// Retrieves all widgets created by a user along with their corporations.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(WidgetModel, EveCorporationModel)>)` - Widgets oldest first (empty if none)
+    /// - `Err(DbErr)` - Database query failed
+    pub async fn get_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<(WidgetModel, EveCorporationModel)>, DbErr> {
+        let widgets = entity::prelude::BifrostWidget::find()
+            .filter(entity::bifrost_widget::Column::UserId.eq(user_id))
+            .find_also_related(entity::prelude::EveCorporation)
+            .order_by_asc(entity::bifrost_widget::Column::Id)
+            .all(self.db)
+            .await?;
+
+        Ok(widgets
+            .into_iter()
+            .filter_map(|(widget, corporation)| Some((widget, corporation?)))
+            .collect())
+    }
+
+    /// Deletes a widget owned by a user, invalidating its token.
+    ///
+    /// # Arguments
+    /// - `widget_id` - ID of the widget
+    /// - `user_id` - ID of the user who must own the widget
+    ///
+    /// # Returns
+    /// - `Ok(DeleteResult)` - Operation completed (check rows_affected: 1 if deleted, 0 if not found or not owned)
+    /// - `Err(DbErr)` - Database operation failed
+    pub async fn delete(&self, widget_id: i32, user_id: i32) -> Result<DeleteResult, DbErr> {
+        entity::prelude::BifrostWidget::delete_many()
+            .filter(entity::bifrost_widget::Column::Id.eq(widget_id))
+            .filter(entity::bifrost_widget::Column::UserId.eq(user_id))
+            .exec(self.db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for WidgetRepository::get_by_token method.
+    mod get_by_token {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::widget::WidgetRepository;
+
+        /// Tests retrieving a widget by its token.
+        ///
+        /// Expected: Ok with the widget and its corporation
+        #[tokio::test]
+        async fn finds_widget_with_corporation() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostWidget)
+                .build()
+                .await?;
+            let (user_model, _, character_model) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+
+            let repository = WidgetRepository::new(&test.db);
+            repository
+                .create(
+                    user_model.id,
+                    character_model.corporation_id,
+                    "corporation_member_count",
+                    "token".to_string(),
+                )
+                .await?;
+
+            let (widget, corporation) = repository.get_by_token("token").await?.unwrap();
+
+            assert_eq!(widget.kind, "corporation_member_count");
+            assert_eq!(corporation.id, character_model.corporation_id);
+
+            Ok(())
+        }
+
+        /// Tests retrieving a widget with an unknown token.
+        ///
+        /// Expected: Ok(None)
+        #[tokio::test]
+        async fn returns_none_for_unknown_token() -> Result<(), TestError> {
+            let test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostWidget)
+                .build()
+                .await?;
+
+            let result = WidgetRepository::new(&test.db)
+                .get_by_token("token")
+                .await?;
+
+            assert!(result.is_none());
+
+            Ok(())
+        }
+    }
+
+    /// Tests for WidgetRepository::delete method.
+    mod delete {
+        use bifrost_test_utils::prelude::*;
+
+        use crate::server::data::widget::WidgetRepository;
+
+        /// Tests that a widget can only be deleted by the user who created it.
+        ///
+        /// Expected: 0 rows affected for another user, then 1 row affected for the owner
+        #[tokio::test]
+        async fn deletes_only_owned_widget() -> Result<(), TestError> {
+            let mut test = TestBuilder::new()
+                .with_user_tables()
+                .with_table(entity::prelude::BifrostWidget)
+                .build()
+                .await?;
+            let (owner, _, character_model) = test
+                .user()
+                .insert_user_with_mock_character(1, 1, None, None)
+                .await?;
+            let (other_user, _, _) = test
+                .user()
+                .insert_user_with_mock_character(2, 2, None, None)
+                .await?;
+
+            let repository = WidgetRepository::new(&test.db);
+            let widget = repository
+                .create(
+                    owner.id,
+                    character_model.corporation_id,
+                    "corporation_member_count",
+                    "token".to_string(),
+                )
+                .await?;
+
+            let result = repository.delete(widget.id, other_user.id).await?;
+            assert_eq!(result.rows_affected, 0);
+
+            let result = repository.delete(widget.id, owner.id).await?;
+            assert_eq!(result.rows_affected, 1);
+            assert!(repository.get_by_token("token").await?.is_none());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Affiliation history error types.
+//!
+//! This module defines errors related to viewing the affiliation history of characters, such
+//! as requesting the history of a character Bifrost has never seen. These errors map to 404
+//! responses.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Affiliation history error type.
+///
+/// These errors occur when retrieving a character's affiliation history. Each variant is
+/// mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum AffiliationHistoryError {
+    /// Character is not in the database.
+    ///
+    /// History is only recorded for characters Bifrost stores, so other characters have none.
+    /// Results in a 404 Not Found response.
+    #[error("Character ID {0} not found")]
+    CharacterNotFound(i64),
+}
+
+/// Converts affiliation history errors into HTTP responses.
+///
+/// - `CharacterNotFound` → 404 Not Found with "Character not found"
+///
+/// # Returns
+/// - 404 Not Found - For characters missing from the database
+impl IntoResponse for AffiliationHistoryError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let error = match &self {
+            Self::CharacterNotFound(_) => "Character not found",
+        };
+
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorDto {
+                error: error.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,86 @@
+//! Annotation error types.
+//!
+//! This module defines errors related to the tags and notes admins attach to users,
+//! characters, and corporations, such as empty or oversized tags and notes, subjects missing
+//! from the database, and references to tags or notes that don't exist. All errors map to 400
+//! and 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::{annotation::AnnotationSubject, api::ErrorDto};
+
+/// Annotation error type.
+///
+/// These errors occur when adding, listing, or removing tags and notes. Each variant is
+/// mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum AnnotationError {
+    /// Tag name is empty or longer than the maximum length.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Tags must be 1 to {0} characters long")]
+    InvalidTag(usize),
+
+    /// Note body is empty or longer than the maximum length.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Notes must be 1 to {0} characters long")]
+    InvalidNote(usize),
+
+    /// Annotated user, character, or corporation is not in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("{} {subject_id} not found", .subject.description())]
+    SubjectNotFound {
+        /// Kind of the requested subject.
+        subject: AnnotationSubject,
+        /// Bifrost user ID or EVE Online character or corporation ID of the subject.
+        subject_id: i64,
+    },
+
+    /// Tag does not exist.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Tag ID {0} not found")]
+    TagNotFound(i32),
+
+    /// Note does not exist or is restricted to another author.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Note ID {0} not found")]
+    NoteNotFound(i32),
+}
+
+/// Converts annotation errors into HTTP responses.
+///
+/// - `InvalidTag` → 400 Bad Request
+/// - `InvalidNote` → 400 Bad Request
+/// - `SubjectNotFound` → 404 Not Found
+/// - `TagNotFound` → 404 Not Found with "Tag not found"
+/// - `NoteNotFound` → 404 Not Found with "Note not found"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid tags or notes
+/// - 404 Not Found - For missing subjects, tags, or notes
+impl IntoResponse for AnnotationError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::InvalidTag(_) | Self::InvalidNote(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            Self::SubjectNotFound { .. } => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::TagNotFound(_) => (StatusCode::NOT_FOUND, "Tag not found".to_string()),
+            Self::NoteNotFound(_) => (StatusCode::NOT_FOUND, "Note not found".to_string()),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,85 @@
+//! Announcement error types.
+//!
+//! This module defines errors related to announcements, such as announcements without a title
+//! or target, references to announcements missing from a user's inbox, and broadcasts the
+//! Discord webhook rejected. Request errors map to 400 and 404 responses with user-facing
+//! messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::{
+    model::{announcement::AnnouncementAudience, api::ErrorDto},
+    server::error::InternalServerError,
+};
+
+/// Announcement error type.
+///
+/// These errors occur when posting announcements, reading them from the inbox, or delivering
+/// them to Discord. Each variant is mapped to an appropriate HTTP status code in the
+/// `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum AnnouncementError {
+    /// Announcement title is empty.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Announcement title must not be empty")]
+    EmptyTitle,
+
+    /// Announcement targets a corporation or alliance without giving its ID.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Announcements to {} require an audience ID", .0.description().to_lowercase())]
+    MissingAudienceId(AnnouncementAudience),
+
+    /// Announcement does not exist or was not sent to the user.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Announcement ID {announcement_id} not found in inbox of user {user_id}")]
+    AnnouncementNotFound {
+        /// ID of the requested announcement.
+        announcement_id: i32,
+        /// ID of the user whose inbox was searched.
+        user_id: i32,
+    },
+
+    /// Discord webhook responded with an error status.
+    ///
+    /// Only occurs in worker jobs. Results in a 500 Internal Server Error response.
+    #[error("Discord webhook rejected announcement with status {0}")]
+    DiscordWebhookRejected(u16),
+}
+
+/// Converts announcement errors into HTTP responses.
+///
+/// - `EmptyTitle` → 400 Bad Request
+/// - `MissingAudienceId` → 400 Bad Request
+/// - `AnnouncementNotFound` → 404 Not Found with "Announcement not found"
+/// - `DiscordWebhookRejected` → 500 Internal Server Error
+///
+/// # Returns
+/// - 400 Bad Request - For invalid announcements
+/// - 404 Not Found - For announcements missing from the inbox
+/// - 500 Internal Server Error - For rejected Discord deliveries
+impl IntoResponse for AnnouncementError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::EmptyTitle | Self::MissingAudienceId(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            Self::AnnouncementNotFound { .. } => {
+                (StatusCode::NOT_FOUND, "Announcement not found".to_string())
+            }
+            Self::DiscordWebhookRejected(_) => return InternalServerError(self).into_response(),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
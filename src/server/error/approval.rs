@@ -0,0 +1,89 @@
+//! Approval request error types.
+//!
+//! This module defines errors related to the two-person rule for sensitive admin actions,
+//! such as references to approval requests that don't exist, admins approving their own
+//! requests, and decisions on requests that were already decided or have expired. All errors
+//! map to 403, 404, 409, and 500 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::{model::api::ErrorDto, server::error::InternalServerError};
+
+/// Approval request error type.
+///
+/// These errors occur when approving or rejecting requests for sensitive admin actions. Each
+/// variant is mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum ApprovalError {
+    /// Approval request does not exist.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Approval request ID {0} not found")]
+    NotFound(i32),
+
+    /// Admin tried to approve a request they created.
+    ///
+    /// Results in a 403 Forbidden response.
+    #[error("Approval request ID {0} must be approved by another admin")]
+    SelfApproval(i32),
+
+    /// Approval request was already approved or rejected.
+    ///
+    /// Results in a 409 Conflict response.
+    #[error("Approval request ID {0} was already decided")]
+    NotPending(i32),
+
+    /// Approval request expired before it was decided.
+    ///
+    /// Results in a 409 Conflict response.
+    #[error("Approval request ID {0} has expired")]
+    Expired(i32),
+
+    /// Stored action or payload of the request can't be read.
+    ///
+    /// Results in a 500 Internal Server Error response.
+    #[error("Approval request ID {id} has an invalid payload: {reason}")]
+    InvalidPayload {
+        /// ID of the approval request.
+        id: i32,
+        /// Why the payload couldn't be read.
+        reason: String,
+    },
+}
+
+/// Converts approval request errors into HTTP responses.
+///
+/// - `NotFound` → 404 Not Found with "Approval request not found"
+/// - `SelfApproval` → 403 Forbidden
+/// - `NotPending` → 409 Conflict
+/// - `Expired` → 409 Conflict
+/// - `InvalidPayload` → 500 Internal Server Error (logged as error)
+///
+/// # Returns
+/// - 403 Forbidden - For admins approving their own requests
+/// - 404 Not Found - For missing requests
+/// - 409 Conflict - For requests that can no longer be decided
+/// - 500 Internal Server Error - For unreadable requests
+impl IntoResponse for ApprovalError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::NotFound(_) => (
+                StatusCode::NOT_FOUND,
+                "Approval request not found".to_string(),
+            ),
+            Self::SelfApproval(_) => (StatusCode::FORBIDDEN, self.to_string()),
+            Self::NotPending(_) | Self::Expired(_) => (StatusCode::CONFLICT, self.to_string()),
+            Self::InvalidPayload { .. } => return InternalServerError(self).into_response(),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
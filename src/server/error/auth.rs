@@ -1,19 +1,26 @@
 //! Authentication and authorization error types.
 //!
 //! This module defines errors related to user authentication, session management, CSRF
-//! validation, and character ownership. Authentication errors are mapped to appropriate
-//! HTTP status codes (400, 404, 500) based on the error type and include user-friendly
-//! error messages suitable for API responses.
+//! validation, character ownership, Discord account linking, and suspended users.
+//! Authentication errors are mapped to appropriate HTTP status codes (400, 403, 404, 500) based
+//! on the error type and include user-friendly error messages suitable for API responses.
 
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
 };
+use chrono::NaiveDateTime;
 use dioxus_logger::tracing;
 use thiserror::Error;
 
-use crate::{model::api::ErrorDto, server::error::InternalServerError};
+use crate::{
+    model::api::ErrorDto,
+    server::{
+        error::InternalServerError, model::session::link_queue::MAX_LINK_QUEUE_LENGTH,
+        util::scope_set::ScopeSetError,
+    },
+};
 
 /// Authentication and authorization error type.
 ///
@@ -52,6 +59,26 @@ pub enum AuthError {
     #[error("Failed to login user due to CSRF state present in session store but without a value")]
     CsrfMissingValue,
 
+    /// Login flow expired before the OAuth callback.
+    ///
+    /// This error occurs when the user takes longer than `AUTH_FLOW_EXPIRY_MINUTES` to
+    /// authenticate with EVE Online. Results in a 400 Bad Request response.
+    #[error("Failed to login user due to an expired login flow")]
+    AuthFlowExpired,
+
+    /// Login flow can't move to the requested state.
+    ///
+    /// This error occurs when a callback is replayed for a login that already reached it, or
+    /// a login is completed without a validated callback. Results in a 400 Bad Request
+    /// response.
+    #[error("Unexpected login flow transition from {from} to {to}")]
+    UnexpectedAuthFlowTransition {
+        /// State the flow is in
+        from: &'static str,
+        /// State the flow was asked to move to
+        to: &'static str,
+    },
+
     /// Character is owned by a different user.
     ///
     /// This error occurs when attempting to change a user's main character to a character
@@ -74,6 +101,69 @@ pub enum AuthError {
     /// or character ownership operations. Results in a 500 Internal Server Error response.
     #[error("Character not found in database")]
     CharacterNotFound,
+
+    /// Character did not grant every ESI scope requested for the login.
+    ///
+    /// This error occurs when a login requesting additional scopes completes without the
+    /// user granting all of them. Results in a 400 Bad Request response.
+    #[error("Character did not grant the requested scopes: {0:?}")]
+    ScopesNotGranted(Vec<String>),
+
+    /// Login requested scopes that can't be resolved.
+    ///
+    /// This error occurs when the login endpoint's `scopes` parameter names a set missing
+    /// from `ESI_SCOPE_SETS`. Results in a 400 Bad Request response.
+    #[error("Login requested invalid scopes: {0}")]
+    InvalidScopes(#[from] ScopeSetError),
+
+    /// Login requested linking more characters in a row than allowed.
+    ///
+    /// This error occurs when the login endpoint's `link_count` parameter is zero or exceeds
+    /// `MAX_LINK_QUEUE_LENGTH`. Results in a 400 Bad Request response.
+    #[error("Login requested linking {0} characters in a row")]
+    InvalidLinkCount(u32),
+
+    /// Discord account linking is not configured.
+    ///
+    /// This error occurs when the Discord login or callback endpoints are called while
+    /// `DISCORD_CLIENT_ID` is not set. Results in a 404 Not Found response.
+    #[error("Discord account linking is not configured")]
+    DiscordNotConfigured,
+
+    /// Discord account is linked to a different user.
+    ///
+    /// This error occurs when a Discord callback authenticates an account already linked to
+    /// another user, as each Discord account can only be linked once. Results in a 400 Bad
+    /// Request response.
+    #[error("Discord account {0} is linked to another user")]
+    DiscordAccountLinkedToAnotherUser(i64),
+
+    /// Discord rejected a request or returned an unexpected response.
+    ///
+    /// This error occurs when exchanging the authorization code or fetching the Discord user
+    /// fails, e.g. because the code expired. Results in a 500 Internal Server Error response.
+    #[error("Discord request failed: {0}")]
+    DiscordRequestFailed(String),
+
+    /// No character transfer awaits confirmation.
+    ///
+    /// This error occurs when confirming a transfer while none is stored in the session, or
+    /// after the character stopped belonging to the user it would be transferred from. Results
+    /// in a 404 Not Found response.
+    #[error("No pending character transfer")]
+    NoPendingTransfer,
+
+    /// User is suspended by an active ban.
+    ///
+    /// This error occurs when a suspended user makes a request to the API. The response tells
+    /// the user why they were suspended and until when. Results in a 403 Forbidden response.
+    #[error("User is suspended until {until:?}: {reason}")]
+    Suspended {
+        /// Reason given for the ban
+        reason: String,
+        /// Time the suspension ends, `None` if it is permanent
+        until: Option<NaiveDateTime>,
+    },
 }
 
 impl AuthError {
@@ -99,16 +189,28 @@ impl AuthError {
 ///
 /// Maps authentication errors to appropriate HTTP status codes and user-friendly error messages:
 /// - `UserNotInSession` / `UserNotInDatabase` → 404 Not Found with "User not found"
-/// - `CsrfValidationFailed` / `CsrfMissingValue` → 400 Bad Request with "There was an issue logging you in"
+/// - `CsrfValidationFailed` / `UnexpectedAuthFlowTransition` → 400 Bad Request with "There was an
+///   issue logging you in"
+/// - `AuthFlowExpired` → 400 Bad Request with "Your login took too long"
 /// - `CharacterOwnedByAnotherUser` / `CharacterNotOwned` → 400 Bad Request with "Invalid character selection"
+/// - `ScopesNotGranted` → 400 Bad Request with "Not all requested permissions were granted"
+/// - `InvalidScopes` → 400 Bad Request with "Unknown permission set"
+/// - `InvalidLinkCount` → 400 Bad Request with the number of characters that can be linked
+/// - `DiscordNotConfigured` → 404 Not Found with "Discord linking is not enabled"
+/// - `DiscordAccountLinkedToAnotherUser` → 400 Bad Request with "This Discord account is already
+///   linked to another user"
 /// - Other errors → 500 Internal Server Error with generic message
 ///
 /// All errors are logged at debug level for diagnostics while keeping client-facing messages
 /// generic to avoid information leakage.
 ///
 /// # Returns
-/// - 400 Bad Request - For CSRF failures and invalid character operations
-/// - 404 Not Found - For missing users
+/// - 400 Bad Request - For CSRF failures, expired or replayed login flows, invalid character
+///   operations, missing or unknown scopes, invalid link counts, and Discord accounts linked to
+///   another user
+/// - 403 Forbidden - For suspended users
+/// - 404 Not Found - For missing users, disabled Discord linking, and missing pending
+///   transfers
 /// - 500 Internal Server Error - For unexpected authentication errors
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
@@ -138,6 +240,28 @@ impl IntoResponse for AuthError {
                 )
                     .into_response()
             }
+            Self::UnexpectedAuthFlowTransition { .. } => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorDto {
+                        error: "There was an issue logging you in, please try again.".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
+            Self::AuthFlowExpired => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorDto {
+                        error: "Your login took too long, please try again.".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
             Self::CharacterOwnedByAnotherUser => {
                 tracing::debug!("{}", self);
 
@@ -160,6 +284,89 @@ impl IntoResponse for AuthError {
                 )
                     .into_response()
             }
+            Self::ScopesNotGranted(_) => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorDto {
+                        error: "Not all requested permissions were granted".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
+            Self::InvalidScopes(_) => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorDto {
+                        error: "Unknown permission set".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
+            Self::InvalidLinkCount(_) => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorDto {
+                        error: format!(
+                            "You can link between 1 and {} characters in a row",
+                            MAX_LINK_QUEUE_LENGTH
+                        ),
+                    }),
+                )
+                    .into_response()
+            }
+            Self::DiscordNotConfigured => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorDto {
+                        error: "Discord linking is not enabled".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
+            Self::DiscordAccountLinkedToAnotherUser(_) => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorDto {
+                        error: "This Discord account is already linked to another user".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
+            Self::NoPendingTransfer => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorDto {
+                        error: "No character transfer is pending".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
+            Self::Suspended { reason, until } => {
+                tracing::debug!(until = ?until, "User is suspended: {}", reason);
+
+                let error = match until {
+                    Some(until) => format!(
+                        "Your account is suspended until {} UTC: {}",
+                        until.format("%Y-%m-%d %H:%M"),
+                        reason
+                    ),
+                    None => format!("Your account is permanently suspended: {}", reason),
+                };
+
+                (StatusCode::FORBIDDEN, Json(ErrorDto { error })).into_response()
+            }
             err => InternalServerError(err).into_response(),
         }
     }
@@ -0,0 +1,56 @@
+//! User ban error types.
+//!
+//! This module defines errors related to suspending users, such as bans with an empty or
+//! overly long reason, admins banning themselves, and references to bans that don't exist.
+//! Requests from users who are suspended are rejected with `AuthError::Suspended` instead. All
+//! errors map to 400 and 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// User ban error type.
+///
+/// These errors occur when admins issue or lift bans. Each variant is mapped to an appropriate
+/// HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum BanError {
+    /// Ban can't be issued as requested.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("{0}")]
+    InvalidBan(String),
+
+    /// Ban does not exist.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Ban ID {0} not found")]
+    BanNotFound(i32),
+}
+
+/// Converts user ban errors into HTTP responses.
+///
+/// - `InvalidBan` → 400 Bad Request
+/// - `BanNotFound` → 404 Not Found with "Ban not found"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid bans
+/// - 404 Not Found - For missing bans
+impl IntoResponse for BanError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidBan(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::BanNotFound(_) => (StatusCode::NOT_FOUND, "Ban not found".to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,67 @@
+//! Campaign error types.
+//!
+//! This module defines errors related to tracking deployment campaigns, such as campaigns
+//! that end before they start or references to campaigns that do not exist. These errors map
+//! to 400, 404, and 409 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Campaign error type.
+///
+/// These errors occur when creating, listing, or deleting campaigns. Each variant is mapped
+/// to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum CampaignError {
+    /// Campaign input failed validation (empty name or staging system, end before start).
+    ///
+    /// Results in a 400 Bad Request response including the validation message.
+    #[error("Invalid campaign: {0}")]
+    InvalidCampaign(String),
+
+    /// A campaign with the same name already exists.
+    ///
+    /// Results in a 409 Conflict response.
+    #[error("Campaign with name {0:?} already exists")]
+    DuplicateCampaignName(String),
+
+    /// Campaign ID does not exist in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Campaign ID {0} not found")]
+    CampaignNotFound(i32),
+}
+
+/// Converts campaign errors into HTTP responses.
+///
+/// - `InvalidCampaign` → 400 Bad Request with the validation message
+/// - `DuplicateCampaignName` → 409 Conflict
+/// - `CampaignNotFound` → 404 Not Found with "Campaign not found"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid campaign input
+/// - 404 Not Found - For missing campaigns
+/// - 409 Conflict - For duplicate campaign names
+impl IntoResponse for CampaignError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidCampaign(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::DuplicateCampaignName(_) => (
+                StatusCode::CONFLICT,
+                "A campaign with that name already exists".to_string(),
+            ),
+            Self::CampaignNotFound(_) => (StatusCode::NOT_FOUND, "Campaign not found".to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,72 @@
+//! Character skill error types.
+//!
+//! This module defines errors related to character skill snapshots, such as requests for
+//! characters the user doesn't own and characters whose skills were never fetched because no
+//! token with the skills scope is stored. All errors map to 404 responses with user-facing
+//! messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Character skill error type.
+///
+/// These errors occur when fetching or reading character skill snapshots. Each variant is
+/// mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum CharacterSkillError {
+    /// Character is not stored in the database or not owned by the requesting user.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Character ID {0} not found")]
+    CharacterNotFound(i64),
+
+    /// The character's skills were never fetched.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Skills of character ID {0} are not tracked")]
+    NotTracked(i64),
+
+    /// Corporation is not stored in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Corporation ID {0} not found")]
+    CorporationNotFound(i64),
+}
+
+/// Converts character skill errors into HTTP responses.
+///
+/// - `CharacterNotFound` → 404 Not Found with "Character not found"
+/// - `NotTracked` → 404 Not Found asking to log in with the skills scope
+/// - `CorporationNotFound` → 404 Not Found with "Corporation not found"
+///
+/// # Returns
+/// - 404 Not Found - For unknown or untracked characters and unknown corporations
+impl IntoResponse for CharacterSkillError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::CharacterNotFound(_) => {
+                (StatusCode::NOT_FOUND, "Character not found".to_string())
+            }
+            Self::NotTracked(_) => (
+                StatusCode::NOT_FOUND,
+                "Skills of this character are not tracked, log in with the character granting \
+                 the skills scope"
+                    .to_string(),
+            ),
+            Self::CorporationNotFound(_) => {
+                (StatusCode::NOT_FOUND, "Corporation not found".to_string())
+            }
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
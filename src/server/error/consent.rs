@@ -0,0 +1,72 @@
+//! Data-sharing consent error types.
+//!
+//! This module defines errors related to users granting or revoking consent for the
+//! organization to access categories of their data, and to services attempting to sync data
+//! the user has not consented to share. These errors map to 400 and 403 responses with
+//! user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::{api::ErrorDto, consent::ConsentCategory};
+
+/// Data-sharing consent error type.
+///
+/// These errors occur when managing a user's consent or when a service requires consent
+/// before syncing a category of data. Each variant is mapped to an appropriate HTTP status
+/// code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum ConsentError {
+    /// Consent category name is not recognized.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Unknown consent category {0:?}")]
+    InvalidCategory(String),
+
+    /// User has not granted consent for the data category.
+    ///
+    /// Returned by services that must not sync data without consent. Results in a 403
+    /// Forbidden response.
+    #[error("User ID {user_id} has not granted consent for {category:?} data")]
+    ConsentNotGranted {
+        /// ID of the user whose consent is missing.
+        user_id: i32,
+        /// Data category that requires consent.
+        category: ConsentCategory,
+    },
+}
+
+/// Converts consent errors into HTTP responses.
+///
+/// - `InvalidCategory` → 400 Bad Request with "Unknown consent category"
+/// - `ConsentNotGranted` → 403 Forbidden with "Consent not granted for this data category"
+///
+/// # Returns
+/// - 400 Bad Request - For unknown consent categories
+/// - 403 Forbidden - For data access without consent
+impl IntoResponse for ConsentError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidCategory(_) => (StatusCode::BAD_REQUEST, "Unknown consent category"),
+            Self::ConsentNotGranted { .. } => (
+                StatusCode::FORBIDDEN,
+                "Consent not granted for this data category",
+            ),
+        };
+
+        (
+            status,
+            Json(ErrorDto {
+                error: error.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
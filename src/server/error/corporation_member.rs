@@ -0,0 +1,62 @@
+//! Corporation member error types.
+//!
+//! This module defines errors related to tracking corporation member lists, such as requests
+//! for corporations Bifrost doesn't know and corporations whose director never granted the
+//! membership scope. All errors map to 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Corporation member error type.
+///
+/// These errors occur when tracking corporations, fetching member lists, or reading them.
+/// Each variant is mapped to an appropriate HTTP status code in the `IntoResponse`
+/// implementation.
+#[derive(Error, Debug)]
+pub enum CorporationMemberError {
+    /// Corporation is not stored in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Corporation ID {0} not found")]
+    CorporationNotFound(i64),
+
+    /// No director of the corporation granted the membership scope.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Membership of corporation ID {0} is not tracked")]
+    NotTracked(i64),
+}
+
+/// Converts corporation member errors into HTTP responses.
+///
+/// - `CorporationNotFound` → 404 Not Found with "Corporation not found"
+/// - `NotTracked` → 404 Not Found asking for a director to grant the membership scope
+///
+/// # Returns
+/// - 404 Not Found - For unknown or untracked corporations
+impl IntoResponse for CorporationMemberError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::CorporationNotFound(_) => {
+                (StatusCode::NOT_FOUND, "Corporation not found".to_string())
+            }
+            Self::NotTracked(_) => (
+                StatusCode::NOT_FOUND,
+                "Membership of this corporation is not tracked, a director has to grant the \
+                 membership scope"
+                    .to_string(),
+            ),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
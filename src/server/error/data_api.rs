@@ -0,0 +1,110 @@
+//! Data access API error types.
+//!
+//! This module defines errors related to the data access API, such as invalid saved query
+//! definitions, missing or malformed query parameters, queries the database rejected, and
+//! requests without a valid API key. These errors map to 400, 401, and 404 responses with
+//! user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Data access API error type.
+///
+/// These errors occur when admins define saved queries and API keys, or when BI tools run
+/// saved queries. Each variant is mapped to an appropriate HTTP status code in the
+/// `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum DataApiError {
+    /// Saved query failed validation (invalid slug, empty name, non-`SELECT` SQL, invalid
+    /// parameters, or row limit out of range).
+    ///
+    /// Results in a 400 Bad Request response including the validation message.
+    #[error("Invalid saved query: {0}")]
+    InvalidQuery(String),
+
+    /// Parameter passed when running a saved query is missing or doesn't match its kind.
+    ///
+    /// Results in a 400 Bad Request response including the parameter name.
+    #[error("Invalid query parameter {name:?}: {reason}")]
+    InvalidParameter {
+        /// Name of the parameter.
+        name: String,
+        /// Why the value was rejected.
+        reason: String,
+    },
+
+    /// Database rejected the saved query's SQL when it was run.
+    ///
+    /// Results in a 400 Bad Request response including the database error, since the SQL was
+    /// written by an admin rather than Bifrost.
+    #[error("Saved query {slug:?} failed: {message}")]
+    QueryFailed {
+        /// Slug of the saved query.
+        slug: String,
+        /// Error reported by the database.
+        message: String,
+    },
+
+    /// No saved query with the slug exists.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Saved query {0:?} not found")]
+    QueryNotFound(String),
+
+    /// API key name is empty.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("API key name must not be empty")]
+    EmptyKeyName,
+
+    /// No API key with the ID exists.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("API key ID {0} not found")]
+    ApiKeyNotFound(i32),
+
+    /// Request has no API key or the key is unknown or revoked.
+    ///
+    /// Results in a 401 Unauthorized response.
+    #[error("Missing or invalid API key")]
+    InvalidApiKey,
+}
+
+/// Converts data access API errors into HTTP responses.
+///
+/// - `InvalidQuery` → 400 Bad Request with the validation message
+/// - `InvalidParameter` → 400 Bad Request with the parameter name
+/// - `QueryFailed` → 400 Bad Request with the database error
+/// - `EmptyKeyName` → 400 Bad Request
+/// - `QueryNotFound` → 404 Not Found with "Saved query not found"
+/// - `ApiKeyNotFound` → 404 Not Found with "API key not found"
+/// - `InvalidApiKey` → 401 Unauthorized
+///
+/// # Returns
+/// - 400 Bad Request - For invalid queries, parameters, or key names, and failed queries
+/// - 401 Unauthorized - For requests without a valid API key
+/// - 404 Not Found - For missing saved queries or API keys
+impl IntoResponse for DataApiError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidQuery(_)
+            | Self::InvalidParameter { .. }
+            | Self::QueryFailed { .. }
+            | Self::EmptyKeyName => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::QueryNotFound(_) => (StatusCode::NOT_FOUND, "Saved query not found".to_string()),
+            Self::ApiKeyNotFound(_) => (StatusCode::NOT_FOUND, "API key not found".to_string()),
+            Self::InvalidApiKey => (StatusCode::UNAUTHORIZED, self.to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,58 @@
+//! Dead-letter queue error types.
+//!
+//! This module defines errors related to inspecting and replaying permanently failed worker
+//! jobs, such as replaying an entry that no longer exists or submitting an edited payload that
+//! isn't a valid worker job. These errors map to 4xx responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Dead-letter queue error type.
+///
+/// These errors occur when an admin inspects or replays dead-lettered worker jobs. Each
+/// variant is mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum DeadLetterError {
+    /// No dead-lettered job exists with the given ID.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Dead-lettered job {0} not found")]
+    NotFound(u64),
+
+    /// The edited payload submitted for a replay is not a valid worker job.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Invalid worker job payload: {0}")]
+    InvalidPayload(String),
+}
+
+/// Converts dead-letter queue errors into HTTP responses.
+///
+/// - `NotFound` → 404 Not Found with "Dead-lettered job not found"
+/// - `InvalidPayload` → 400 Bad Request with the deserialization error
+///
+/// # Returns
+/// - 400 Bad Request - For invalid edited payloads
+/// - 404 Not Found - For missing dead-lettered jobs
+impl IntoResponse for DeadLetterError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::NotFound(_) => (
+                StatusCode::NOT_FOUND,
+                "Dead-lettered job not found".to_string(),
+            ),
+            Self::InvalidPayload(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,88 @@
+//! Doctrine and fitting error types.
+//!
+//! This module defines errors related to managing ship fittings and doctrines, such as
+//! invalid EFT input when importing a fitting or references to fittings and doctrines that
+//! do not exist. These errors map to 400 and 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::{model::api::ErrorDto, server::util::eft::EftParseError};
+
+/// Doctrine and fitting error type.
+///
+/// These errors occur when importing fittings, creating doctrines, or modifying the fittings
+/// assigned to a doctrine. Each variant is mapped to an appropriate HTTP status code in the
+/// `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum DoctrineError {
+    /// Fitting text could not be parsed as EFT.
+    ///
+    /// Results in a 400 Bad Request response including the parse error so the user can
+    /// correct their input.
+    #[error(transparent)]
+    InvalidFitting(#[from] EftParseError),
+
+    /// A doctrine with the same name already exists.
+    ///
+    /// Results in a 409 Conflict response.
+    #[error("Doctrine with name {0:?} already exists")]
+    DuplicateDoctrineName(String),
+
+    /// Fitting ID does not exist in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Fitting ID {0} not found")]
+    FittingNotFound(i32),
+
+    /// Doctrine ID does not exist in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Doctrine ID {0} not found")]
+    DoctrineNotFound(i32),
+
+    /// A required skill is listed more than once or with a level outside 1 to 5.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Invalid requirement for skill ID {0}")]
+    InvalidSkillRequirement(i64),
+}
+
+/// Converts doctrine errors into HTTP responses.
+///
+/// - `InvalidFitting` → 400 Bad Request with the parse error message
+/// - `DuplicateDoctrineName` → 409 Conflict
+/// - `FittingNotFound` → 404 Not Found with "Fitting not found"
+/// - `DoctrineNotFound` → 404 Not Found with "Doctrine not found"
+/// - `InvalidSkillRequirement` → 400 Bad Request
+///
+/// # Returns
+/// - 400 Bad Request - For invalid fitting input or skill requirements
+/// - 404 Not Found - For missing fittings or doctrines
+/// - 409 Conflict - For duplicate doctrine names
+impl IntoResponse for DoctrineError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidFitting(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+            Self::DuplicateDoctrineName(_) => (
+                StatusCode::CONFLICT,
+                "A doctrine with that name already exists".to_string(),
+            ),
+            Self::FittingNotFound(_) => (StatusCode::NOT_FOUND, "Fitting not found".to_string()),
+            Self::DoctrineNotFound(_) => (StatusCode::NOT_FOUND, "Doctrine not found".to_string()),
+            Self::InvalidSkillRequirement(_) => (
+                StatusCode::BAD_REQUEST,
+                "Each required skill must be listed once with a level from 1 to 5".to_string(),
+            ),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
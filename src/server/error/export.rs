@@ -0,0 +1,46 @@
+//! Export error types.
+//!
+//! This module defines errors related to admin exports, such as requesting a stored export
+//! while object storage is not configured. These errors map to 404 responses with user-facing
+//! messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Export error type.
+///
+/// These errors occur when administrators request exports. Each variant is mapped to an
+/// appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum ExportError {
+    /// Stored exports are disabled because `OBJECT_STORAGE_ENDPOINT` is not configured.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Object storage is not enabled")]
+    ObjectStorageDisabled,
+}
+
+/// Converts export errors into HTTP responses.
+///
+/// - `ObjectStorageDisabled` → 404 Not Found
+///
+/// # Returns
+/// - 404 Not Found - For disabled object storage
+impl IntoResponse for ExportError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::ObjectStorageDisabled => (StatusCode::NOT_FOUND, self.to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,107 @@
+//! Group error types.
+//!
+//! This module defines errors related to groups and their members, such as groups with an empty,
+//! overly long, or duplicate name, references to groups that don't exist or are hidden from the
+//! user, memberships or requests to join that don't exist, and users whose characters don't
+//! qualify for a group's eligibility rules. All errors map to 400, 403, and 404 responses with
+//! user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Group error type.
+///
+/// These errors occur when admins manage groups or users join and leave them. Each variant is
+/// mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum GroupError {
+    /// Group can't be created as requested.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("{0}")]
+    InvalidGroup(String),
+
+    /// Eligibility rule can't be added as requested.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("{0}")]
+    InvalidRule(String),
+
+    /// None of the user's characters is affiliated with a corporation, alliance, or faction
+    /// qualifying for the group.
+    ///
+    /// Results in a 403 Forbidden response.
+    #[error("User ID {user_id} doesn't qualify for group ID {group_id}")]
+    NotEligible {
+        /// ID of the group.
+        group_id: i32,
+        /// ID of the user.
+        user_id: i32,
+    },
+
+    /// Group does not exist, or is hidden from the user.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Group ID {0} not found")]
+    GroupNotFound(i32),
+
+    /// User is neither a member of the group nor has requested to join it.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("User ID {user_id} has no membership in group ID {group_id}")]
+    MembershipNotFound {
+        /// ID of the group.
+        group_id: i32,
+        /// ID of the user.
+        user_id: i32,
+    },
+
+    /// Eligibility rule does not exist in the group.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Rule ID {0} not found")]
+    RuleNotFound(i32),
+}
+
+/// Converts group errors into HTTP responses.
+///
+/// - `InvalidGroup` → 400 Bad Request
+/// - `InvalidRule` → 400 Bad Request
+/// - `NotEligible` → 403 Forbidden with "None of your characters qualifies for this group"
+/// - `GroupNotFound` → 404 Not Found with "Group not found"
+/// - `MembershipNotFound` → 404 Not Found with "Membership not found"
+/// - `RuleNotFound` → 404 Not Found with "Rule not found"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid groups or rules
+/// - 403 Forbidden - For users who don't qualify for a group
+/// - 404 Not Found - For missing groups, memberships, or rules
+impl IntoResponse for GroupError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidGroup(_) | Self::InvalidRule(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            Self::NotEligible { .. } => (
+                StatusCode::FORBIDDEN,
+                "None of your characters qualifies for this group".to_string(),
+            ),
+            Self::GroupNotFound(_) => (StatusCode::NOT_FOUND, "Group not found".to_string()),
+            Self::MembershipNotFound { .. } => {
+                (StatusCode::NOT_FOUND, "Membership not found".to_string())
+            }
+            Self::RuleNotFound(_) => (StatusCode::NOT_FOUND, "Rule not found".to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,68 @@
+//! Image proxy error types.
+//!
+//! This module defines errors related to proxying EVE Online images, such as requesting an
+//! unsupported image category or size, or an image the EVE image server doesn't have. These
+//! errors map to 400 and 404 responses.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Image proxy error type.
+///
+/// These errors occur when fetching images through the image proxy. Each variant is mapped
+/// to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum ImageError {
+    /// Category is not one of `characters`, `corporations`, `alliances`, or `types`.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Unknown image category {0}")]
+    UnknownCategory(String),
+
+    /// Size is not one of the sizes offered by the EVE image server.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Unsupported image size {0}, must be one of 32, 64, 128, 256, 512, or 1024")]
+    UnsupportedSize(u32),
+
+    /// EVE image server has no image for the entity.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Image for {category} {id} not found")]
+    ImageNotFound {
+        /// Image category of the entity.
+        category: String,
+        /// EVE Online ID of the entity.
+        id: i64,
+    },
+}
+
+/// Converts image proxy errors into HTTP responses.
+///
+/// - `UnknownCategory` → 404 Not Found
+/// - `UnsupportedSize` → 400 Bad Request with the supported sizes
+/// - `ImageNotFound` → 404 Not Found with "Image not found"
+///
+/// # Returns
+/// - 400 Bad Request - For unsupported sizes
+/// - 404 Not Found - For unknown categories or missing images
+impl IntoResponse for ImageError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::UnknownCategory(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::UnsupportedSize(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::ImageNotFound { .. } => (StatusCode::NOT_FOUND, "Image not found".to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,60 @@
+//! Maintenance error types.
+//!
+//! This module defines errors returned while the server is under maintenance, such as
+//! requests writing to the database while read-only mode is enabled. These errors map to 503
+//! problem details responses with a `Retry-After` header so clients know to try again later.
+
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ProblemDto;
+
+/// Seconds clients are asked to wait before retrying a request rejected for maintenance.
+const RETRY_AFTER_SECS: u32 = 60;
+
+/// Maintenance error type.
+///
+/// These errors occur when a request can't be served until maintenance is over. Each variant
+/// is mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum MaintenanceError {
+    /// Request writes to the database while read-only mode is enabled.
+    ///
+    /// Results in a 503 Service Unavailable response.
+    #[error("Bifrost is in read-only mode for maintenance, changes can't be saved right now")]
+    ReadOnly,
+}
+
+/// Converts maintenance errors into HTTP responses.
+///
+/// - `ReadOnly` → 503 Service Unavailable with a `Retry-After` header
+///
+/// # Returns
+/// - 503 Service Unavailable - `application/problem+json` body for writes during read-only
+///   mode
+impl IntoResponse for MaintenanceError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let status = StatusCode::SERVICE_UNAVAILABLE;
+
+        (
+            status,
+            [
+                (header::RETRY_AFTER, RETRY_AFTER_SECS.to_string()),
+                (header::CONTENT_TYPE, "application/problem+json".to_string()),
+            ],
+            Json(ProblemDto {
+                title: "Read-only mode".to_string(),
+                status: status.as_u16(),
+                detail: self.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
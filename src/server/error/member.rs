@@ -0,0 +1,80 @@
+//! Member list error types.
+//!
+//! This module defines errors related to the admin user and character lists, such as saved
+//! filters without a valid name, references to saved filters that don't exist, and bulk
+//! actions without members, with too many members, or without a notification title. All
+//! errors map to 400 and 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Member list error type.
+///
+/// These errors occur when saving or deleting filters and running bulk actions. Each variant
+/// is mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum MemberError {
+    /// Saved filter name is empty or longer than the maximum length.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Filter names must be 1 to {0} characters long")]
+    InvalidFilterName(usize),
+
+    /// Saved filter does not exist or belongs to another admin.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Filter ID {0} not found")]
+    FilterNotFound(i32),
+
+    /// Bulk action was requested without any members.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Select at least one member")]
+    NoMembersSelected,
+
+    /// Bulk action was requested for more members than allowed at once.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Bulk actions are limited to {0} members at once")]
+    TooManyMembers(usize),
+
+    /// Notification bulk action has an empty title.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Notifications need a title")]
+    EmptyNotificationTitle,
+}
+
+/// Converts member list errors into HTTP responses.
+///
+/// - `InvalidFilterName` → 400 Bad Request
+/// - `FilterNotFound` → 404 Not Found with "Filter not found"
+/// - `NoMembersSelected` → 400 Bad Request
+/// - `TooManyMembers` → 400 Bad Request
+/// - `EmptyNotificationTitle` → 400 Bad Request
+///
+/// # Returns
+/// - 400 Bad Request - For invalid filters and bulk actions
+/// - 404 Not Found - For missing filters
+impl IntoResponse for MemberError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::InvalidFilterName(_)
+            | Self::NoMembersSelected
+            | Self::TooManyMembers(_)
+            | Self::EmptyNotificationTitle => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::FilterNotFound(_) => (StatusCode::NOT_FOUND, "Filter not found".to_string()),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
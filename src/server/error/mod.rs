@@ -5,9 +5,41 @@
 //! All errors implement `IntoResponse` for Axum HTTP responses and use `thiserror` for
 //! ergonomic error definitions with automatic `Display` and `Error` trait implementations.
 
+pub mod affiliation_history;
+pub mod annotation;
+pub mod announcement;
+pub mod approval;
 pub mod auth;
+pub mod ban;
+pub mod campaign;
+pub mod character_skill;
 pub mod config;
+pub mod consent;
+pub mod corporation_member;
+pub mod data_api;
+pub mod dead_letter;
+pub mod doctrine;
+pub mod export;
+pub mod group;
+pub mod image;
+pub mod maintenance;
+pub mod member;
+pub mod onboarding;
+pub mod page;
+pub mod preference;
+pub mod push;
+pub mod rate_limit;
+pub mod reauth_campaign;
+pub mod recruitment;
 pub mod retry;
+pub mod role;
+pub mod screening;
+pub mod session;
+pub mod skill_plan;
+pub mod token;
+pub mod user;
+pub mod webhook;
+pub mod widget;
 pub mod worker;
 
 use axum::{
@@ -20,7 +52,23 @@ use thiserror::Error;
 
 use crate::{
     model::api::ErrorDto,
-    server::error::{auth::AuthError, config::ConfigError, worker::WorkerError},
+    server::{
+        error::{
+            affiliation_history::AffiliationHistoryError, annotation::AnnotationError,
+            announcement::AnnouncementError, approval::ApprovalError, auth::AuthError,
+            ban::BanError, campaign::CampaignError, character_skill::CharacterSkillError,
+            config::ConfigError, consent::ConsentError, corporation_member::CorporationMemberError,
+            data_api::DataApiError, dead_letter::DeadLetterError, doctrine::DoctrineError,
+            export::ExportError, group::GroupError, image::ImageError,
+            maintenance::MaintenanceError, member::MemberError, onboarding::OnboardingError,
+            page::PageError, preference::PreferenceError, push::PushError,
+            rate_limit::RateLimitError, reauth_campaign::ReauthCampaignError,
+            recruitment::RecruitmentError, role::RoleError, screening::ScreeningError,
+            session::SessionError, skill_plan::SkillPlanError, token::TokenError, user::UserError,
+            webhook::WebhookError, widget::WidgetError, worker::WorkerError,
+        },
+        util::{crypto::EncryptionError, object_storage::ObjectStorageError},
+    },
 };
 
 /// Main error type for the Bifrost server application.
@@ -44,12 +92,118 @@ pub enum AppError {
     /// Authentication error (session, CSRF, user/character validation).
     #[error(transparent)]
     Auth(#[from] AuthError),
-    /// Worker queue error (job validation, serialization, scheduling).
+    /// Affiliation history error (characters missing from the database).
+    #[error(transparent)]
+    AffiliationHistory(#[from] AffiliationHistoryError),
+    /// Annotation error (invalid tags or notes, missing subjects, tags, or notes).
+    #[error(transparent)]
+    Annotation(#[from] AnnotationError),
+    /// Announcement error (invalid announcements, missing inbox entries, rejected deliveries).
+    #[error(transparent)]
+    Announcement(#[from] AnnouncementError),
+    /// Approval request error (missing requests, self-approval, requests already decided or
+    /// expired).
+    #[error(transparent)]
+    Approval(#[from] ApprovalError),
+    /// User ban error (invalid bans, self-bans, missing bans).
+    #[error(transparent)]
+    Ban(#[from] BanError),
+    /// Campaign error (invalid date ranges, duplicate names, missing campaigns).
+    #[error(transparent)]
+    Campaign(#[from] CampaignError),
+    /// Character skill error (unknown characters, characters whose skills aren't tracked).
+    #[error(transparent)]
+    CharacterSkill(#[from] CharacterSkillError),
+    /// Consent error (unknown consent categories, data access without consent).
+    #[error(transparent)]
+    Consent(#[from] ConsentError),
+    /// Corporation member error (unknown corporations, corporations whose membership isn't
+    /// tracked).
+    #[error(transparent)]
+    CorporationMember(#[from] CorporationMemberError),
+    /// Data access API error (invalid saved queries or parameters, failed queries, invalid API
+    /// keys).
+    #[error(transparent)]
+    DataApi(#[from] DataApiError),
+    /// Dead-letter queue error (missing dead-lettered jobs, invalid edited payloads).
+    #[error(transparent)]
+    DeadLetter(#[from] DeadLetterError),
+    /// Doctrine error (invalid fitting input, missing fittings or doctrines).
+    #[error(transparent)]
+    Doctrine(#[from] DoctrineError),
+    /// Export error (object storage not configured).
+    #[error(transparent)]
+    Export(#[from] ExportError),
+    /// Group error (invalid or duplicate groups or rules, ineligible users, missing or hidden
+    /// groups, missing memberships or rules).
+    #[error(transparent)]
+    Group(#[from] GroupError),
+    /// Image proxy error (unknown categories or sizes, missing images).
+    #[error(transparent)]
+    Image(#[from] ImageError),
+    /// Maintenance error (writes rejected while read-only mode is enabled).
+    #[error(transparent)]
+    Maintenance(#[from] MaintenanceError),
+    /// Member list error (invalid or missing saved filters, invalid bulk actions).
+    #[error(transparent)]
+    Member(#[from] MemberError),
+    /// Onboarding error (invalid or missing steps, steps completed automatically).
+    #[error(transparent)]
+    Onboarding(#[from] OnboardingError),
+    /// Page error (invalid slugs or titles, missing pages or revisions).
+    #[error(transparent)]
+    Page(#[from] PageError),
+    /// Preference error (invalid dashboard layouts).
+    #[error(transparent)]
+    Preference(#[from] PreferenceError),
+    /// Push notification error (push disabled, invalid or missing subscriptions).
+    #[error(transparent)]
+    Push(#[from] PushError),
+    /// Rate limit error (too many requests to a rate limited endpoint).
+    #[error(transparent)]
+    RateLimit(#[from] RateLimitError),
+    /// Re-authentication campaign error (invalid campaigns, access restricted by a campaign).
+    #[error(transparent)]
+    ReauthCampaign(#[from] ReauthCampaignError),
+    /// Recruitment error (missing corporations or listings, non-CEO access, invalid input).
+    #[error(transparent)]
+    Recruitment(#[from] RecruitmentError),
+    /// Role error (invalid or missing roles, changes to built-in roles, missing permissions).
+    #[error(transparent)]
+    Role(#[from] RoleError),
+    /// Screening error (unregistered characters, missing screening reports).
+    #[error(transparent)]
+    Screening(#[from] ScreeningError),
+    /// User session error (revoking missing sessions or the current session).
+    #[error(transparent)]
+    UserSession(#[from] SessionError),
+    /// Skill plan error (invalid plan input, duplicate names, missing skill plans).
+    #[error(transparent)]
+    SkillPlan(#[from] SkillPlanError),
+    /// Character token error (missing or revoked refresh tokens).
+    #[error(transparent)]
+    Token(#[from] TokenError),
+    /// User management error (merging a user into itself).
+    #[error(transparent)]
+    User(#[from] UserError),
+    /// Webhook error (invalid or missing webhooks, rejected notifications).
+    #[error(transparent)]
+    Webhook(#[from] WebhookError),
+    /// Widget error (missing corporations or widgets, unknown widget tokens).
+    #[error(transparent)]
+    Widget(#[from] WidgetError),
+    /// Worker queue error (job validation, serialization, scheduling, unknown job statuses).
     #[error(transparent)]
     Worker(#[from] WorkerError),
     /// Cron scheduler error (job registration, scheduler startup).
     #[error(transparent)]
     Scheduler(#[from] tokio_cron_scheduler::JobSchedulerError),
+    /// Encryption error (missing keys, unknown key ID, corrupt ciphertext).
+    #[error(transparent)]
+    Encryption(#[from] EncryptionError),
+    /// Object storage error (rejected or failed requests to the S3-compatible bucket).
+    #[error(transparent)]
+    ObjectStorage(#[from] ObjectStorageError),
     /// Parse error (failed to parse a value from string or other format).
     #[error("Failed to parse value: {0:?}")]
     Parse(String),
@@ -68,6 +222,9 @@ pub enum AppError {
     /// ESI client error (API requests, OAuth, rate limiting).
     #[error(transparent)]
     Esi(#[from] eve_esi::Error),
+    /// HTTP client error (outgoing requests other than ESI, e.g. telemetry reports).
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
     /// Database error (query failures, connection issues, constraint violations).
     #[error(transparent)]
     Database(#[from] sea_orm::DbErr),
@@ -94,6 +251,39 @@ impl IntoResponse for AppError {
         match self {
             Self::Config(err) => err.into_response(),
             Self::Auth(err) => err.into_response(),
+            Self::AffiliationHistory(err) => err.into_response(),
+            Self::Annotation(err) => err.into_response(),
+            Self::Announcement(err) => err.into_response(),
+            Self::Approval(err) => err.into_response(),
+            Self::Ban(err) => err.into_response(),
+            Self::Campaign(err) => err.into_response(),
+            Self::CharacterSkill(err) => err.into_response(),
+            Self::Consent(err) => err.into_response(),
+            Self::CorporationMember(err) => err.into_response(),
+            Self::DataApi(err) => err.into_response(),
+            Self::DeadLetter(err) => err.into_response(),
+            Self::Doctrine(err) => err.into_response(),
+            Self::Export(err) => err.into_response(),
+            Self::Group(err) => err.into_response(),
+            Self::Image(err) => err.into_response(),
+            Self::Maintenance(err) => err.into_response(),
+            Self::Member(err) => err.into_response(),
+            Self::Onboarding(err) => err.into_response(),
+            Self::Page(err) => err.into_response(),
+            Self::Preference(err) => err.into_response(),
+            Self::Push(err) => err.into_response(),
+            Self::RateLimit(err) => err.into_response(),
+            Self::ReauthCampaign(err) => err.into_response(),
+            Self::Recruitment(err) => err.into_response(),
+            Self::Role(err) => err.into_response(),
+            Self::Screening(err) => err.into_response(),
+            Self::UserSession(err) => err.into_response(),
+            Self::SkillPlan(err) => err.into_response(),
+            Self::Token(err) => err.into_response(),
+            Self::User(err) => err.into_response(),
+            Self::Webhook(err) => err.into_response(),
+            Self::Widget(err) => err.into_response(),
+            Self::Worker(err) => err.into_response(),
             err => InternalServerError(err).into_response(),
         }
     }
@@ -0,0 +1,70 @@
+//! Onboarding error types.
+//!
+//! This module defines errors related to the onboarding checklist, such as steps without a
+//! title or the target their kind needs, references to steps that don't exist, and attempts
+//! to complete steps that are only completed automatically. All errors map to 400 and 404
+//! responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Onboarding error type.
+///
+/// These errors occur when defining, deleting, or completing onboarding steps. Each variant
+/// is mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum OnboardingError {
+    /// Step input failed validation (empty title, missing target, unknown page).
+    ///
+    /// Results in a 400 Bad Request response including the validation message.
+    #[error("Invalid onboarding step: {0}")]
+    InvalidStep(String),
+
+    /// Onboarding step does not exist.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Onboarding step ID {0} not found")]
+    StepNotFound(i32),
+
+    /// Step is completed automatically and can't be marked completed by the user.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Onboarding step ID {0} is completed automatically")]
+    NotSelfReported(i32),
+}
+
+/// Converts onboarding errors into HTTP responses.
+///
+/// - `InvalidStep` → 400 Bad Request
+/// - `StepNotFound` → 404 Not Found with "Onboarding step not found"
+/// - `NotSelfReported` → 400 Bad Request with "This step is completed automatically"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid steps and steps completed automatically
+/// - 404 Not Found - For missing steps
+impl IntoResponse for OnboardingError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::InvalidStep(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::StepNotFound(_) => (
+                StatusCode::NOT_FOUND,
+                "Onboarding step not found".to_string(),
+            ),
+            Self::NotSelfReported(_) => (
+                StatusCode::BAD_REQUEST,
+                "This step is completed automatically".to_string(),
+            ),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,73 @@
+//! Page error types.
+//!
+//! This module defines errors related to admin-edited pages, such as invalid slugs, pages
+//! without a title, or references to pages and revisions that do not exist. These errors map
+//! to 400 and 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Page error type.
+///
+/// These errors occur when viewing, editing, or restoring pages. Each variant is mapped to an
+/// appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum PageError {
+    /// Slug is empty, too long, or contains characters other than lowercase letters, digits,
+    /// and hyphens.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Invalid page slug {0:?}, must be 1 to 64 lowercase letters, digits, or hyphens")]
+    InvalidSlug(String),
+
+    /// Page title is empty.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Page title must not be empty")]
+    EmptyTitle,
+
+    /// No page with the slug exists.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Page {0:?} not found")]
+    PageNotFound(String),
+
+    /// Revision ID does not exist for the page.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Page revision ID {0} not found")]
+    RevisionNotFound(i32),
+}
+
+/// Converts page errors into HTTP responses.
+///
+/// - `InvalidSlug` → 400 Bad Request with the slug requirements
+/// - `EmptyTitle` → 400 Bad Request
+/// - `PageNotFound` → 404 Not Found with "Page not found"
+/// - `RevisionNotFound` → 404 Not Found with "Page revision not found"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid slugs or titles
+/// - 404 Not Found - For missing pages or revisions
+impl IntoResponse for PageError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidSlug(_) | Self::EmptyTitle => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::PageNotFound(_) => (StatusCode::NOT_FOUND, "Page not found".to_string()),
+            Self::RevisionNotFound(_) => {
+                (StatusCode::NOT_FOUND, "Page revision not found".to_string())
+            }
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
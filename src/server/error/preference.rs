@@ -0,0 +1,46 @@
+//! User preference error types.
+//!
+//! This module defines errors related to saving user preferences, such as a dashboard layout
+//! that lists the same widget twice. These errors map to 400 responses with user-facing
+//! messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// User preference error type.
+///
+/// These errors occur when a user saves their preferences. Each variant is mapped to an
+/// appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum PreferenceError {
+    /// Dashboard layout failed validation.
+    ///
+    /// Results in a 400 Bad Request response including the validation message.
+    #[error("Invalid dashboard layout: {0}")]
+    InvalidDashboardLayout(String),
+}
+
+/// Converts user preference errors into HTTP responses.
+///
+/// - `InvalidDashboardLayout` → 400 Bad Request with the validation message
+///
+/// # Returns
+/// - 400 Bad Request - For invalid preferences
+impl IntoResponse for PreferenceError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidDashboardLayout(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,69 @@
+//! Push notification error types.
+//!
+//! This module defines errors related to Web Push subscriptions, such as subscribing while
+//! push notifications are not configured or providing malformed subscription keys. These
+//! errors map to 400 and 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Push notification error type.
+///
+/// These errors occur when users subscribe or unsubscribe from push notifications. Each
+/// variant is mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum PushError {
+    /// Push notifications are disabled because `VAPID_PRIVATE_KEY` is not configured.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Push notifications are not enabled")]
+    PushDisabled,
+
+    /// Subscription endpoint or keys provided by the browser failed validation.
+    ///
+    /// Results in a 400 Bad Request response including the validation message.
+    #[error("Invalid push subscription: {0}")]
+    InvalidSubscription(String),
+
+    /// User has no subscription for the endpoint.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Push subscription for user {user_id} not found")]
+    SubscriptionNotFound {
+        /// ID of the user that tried to unsubscribe.
+        user_id: i32,
+    },
+}
+
+/// Converts push notification errors into HTTP responses.
+///
+/// - `PushDisabled` → 404 Not Found
+/// - `InvalidSubscription` → 400 Bad Request with the validation message
+/// - `SubscriptionNotFound` → 404 Not Found with "Push subscription not found"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid subscriptions
+/// - 404 Not Found - For disabled push notifications or missing subscriptions
+impl IntoResponse for PushError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::PushDisabled => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::InvalidSubscription(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::SubscriptionNotFound { .. } => (
+                StatusCode::NOT_FOUND,
+                "Push subscription not found".to_string(),
+            ),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,56 @@
+//! Rate limit error types.
+//!
+//! This module defines errors returned when a client exceeds the request rate allowed for an
+//! endpoint, such as repeatedly starting EVE Online SSO logins. These errors map to 429
+//! responses with a `Retry-After` header so clients know when to try again.
+
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Rate limit error type.
+///
+/// These errors occur when a client made too many requests to a rate limited endpoint. Each
+/// variant is mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum RateLimitError {
+    /// Client exceeded the number of requests allowed within the rate limit window.
+    ///
+    /// Results in a 429 Too Many Requests response.
+    #[error("Too many requests, please try again in {retry_after} seconds")]
+    TooManyRequests {
+        /// Seconds until the rate limit window resets.
+        retry_after: u64,
+    },
+}
+
+/// Converts rate limit errors into HTTP responses.
+///
+/// - `TooManyRequests` → 429 Too Many Requests with a `Retry-After` header
+///
+/// # Returns
+/// - 429 Too Many Requests - For clients over the rate limit
+impl IntoResponse for RateLimitError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let retry_after = match &self {
+            Self::TooManyRequests { retry_after } => *retry_after,
+        };
+
+        (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after.to_string())],
+            Json(ErrorDto {
+                error: self.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
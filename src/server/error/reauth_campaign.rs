@@ -0,0 +1,68 @@
+//! Re-authentication campaign error types.
+//!
+//! This module defines errors related to re-authentication campaigns, such as campaigns
+//! without a name or scopes, and requests from users who lost access because they didn't
+//! re-authenticate before a campaign's deadline. These errors map to 400 and 403 responses
+//! with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Re-authentication campaign error type.
+///
+/// These errors occur when launching campaigns or when a restricted user accesses a protected
+/// endpoint. Each variant is mapped to an appropriate HTTP status code in the `IntoResponse`
+/// implementation.
+#[derive(Error, Debug)]
+pub enum ReauthCampaignError {
+    /// Campaign input failed validation (empty name or scopes, missing audience ID).
+    ///
+    /// Results in a 400 Bad Request response including the validation message.
+    #[error("Invalid re-authentication campaign: {0}")]
+    InvalidCampaign(String),
+
+    /// User's access is restricted until they complete a campaign past its deadline.
+    ///
+    /// Results in a 403 Forbidden response naming the campaign.
+    #[error("User {user_id} must re-authenticate for campaign {campaign_name:?}")]
+    ReauthRequired {
+        /// ID of the restricted user.
+        user_id: i32,
+        /// Name of the campaign the user must complete.
+        campaign_name: String,
+    },
+}
+
+/// Converts re-authentication campaign errors into HTTP responses.
+///
+/// - `InvalidCampaign` → 400 Bad Request
+/// - `ReauthRequired` → 403 Forbidden with the campaign name
+///
+/// # Returns
+/// - 400 Bad Request - For invalid campaigns
+/// - 403 Forbidden - For users restricted by a campaign
+impl IntoResponse for ReauthCampaignError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::InvalidCampaign(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::ReauthRequired { campaign_name, .. } => (
+                StatusCode::FORBIDDEN,
+                format!(
+                    "Re-authentication required for {}, please log in again to continue",
+                    campaign_name
+                ),
+            ),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,83 @@
+//! Recruitment listing error types.
+//!
+//! This module defines errors related to managing public corporation recruitment listings,
+//! such as listings for corporations unknown to Bifrost, attempts to manage a listing without
+//! being the corporation's CEO, and invalid listing input. These errors map to 400, 403, and
+//! 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Recruitment listing error type.
+///
+/// These errors occur when creating, updating, or removing a corporation's recruitment
+/// listing. Each variant is mapped to an appropriate HTTP status code in the `IntoResponse`
+/// implementation.
+#[derive(Error, Debug)]
+pub enum RecruitmentError {
+    /// Corporation ID does not exist in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Corporation ID {0} not found in database")]
+    CorporationNotFound(i64),
+
+    /// User does not own the CEO character of the corporation.
+    ///
+    /// Only a corporation's CEO may opt the corporation into recruitment. Results in a
+    /// 403 Forbidden response.
+    #[error("User does not own the CEO character of corporation ID {0}")]
+    NotCorporationCeo(i64),
+
+    /// Corporation has no recruitment listing.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Corporation ID {0} has no recruitment listing")]
+    ListingNotFound(i64),
+
+    /// Listing input failed validation.
+    ///
+    /// Results in a 400 Bad Request response including the validation message.
+    #[error("Invalid recruitment listing: {0}")]
+    InvalidListing(String),
+}
+
+/// Converts recruitment errors into HTTP responses.
+///
+/// - `CorporationNotFound` → 404 Not Found with "Corporation not found"
+/// - `NotCorporationCeo` → 403 Forbidden
+/// - `ListingNotFound` → 404 Not Found with "Recruitment listing not found"
+/// - `InvalidListing` → 400 Bad Request with the validation message
+///
+/// # Returns
+/// - 400 Bad Request - For invalid listing input
+/// - 403 Forbidden - For users who are not the corporation's CEO
+/// - 404 Not Found - For missing corporations or listings
+impl IntoResponse for RecruitmentError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::CorporationNotFound(_) => {
+                (StatusCode::NOT_FOUND, "Corporation not found".to_string())
+            }
+            Self::NotCorporationCeo(_) => (
+                StatusCode::FORBIDDEN,
+                "Only the corporation's CEO can manage its recruitment listing".to_string(),
+            ),
+            Self::ListingNotFound(_) => (
+                StatusCode::NOT_FOUND,
+                "Recruitment listing not found".to_string(),
+            ),
+            Self::InvalidListing(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -7,7 +7,7 @@
 
 use sea_orm::DbErr;
 
-use super::AppError;
+use super::{announcement::AnnouncementError, webhook::WebhookError, AppError};
 
 /// Strategy for handling errors in a retry context.
 ///
@@ -26,7 +26,7 @@ pub enum ErrorRetryStrategy {
 
     /// Rate limited by ESI, retry after the specified duration.
     ///
-    /// Used for ESI 429 responses that include a retry_after field indicating
+    /// Used for ESI 420 and 429 responses that include a retry_after field indicating
     /// when the next request can be made. If no duration is provided, falls back
     /// to exponential backoff retry behavior.
     RateLimited(Option<std::time::Duration>),
@@ -75,12 +75,12 @@ impl AppError {
             // In eve_esi 0.5.0+, HTTP error responses are returned as EsiError with a status field
             AppError::Esi(eve_esi::Error::EsiError(esi_error)) => {
                 match esi_error.status {
-                    // 429 Rate Limited - ESI rate limit exceeded
+                    // 420 Error Limited / 429 Rate Limited - ESI error or rate limit exceeded
                     //
                     // Retry after the specified duration if provided, otherwise use
                     // exponential backoff. This prevents hammering ESI when we've
-                    // exceeded our rate limit.
-                    429 => ErrorRetryStrategy::RateLimited(esi_error.retry_after),
+                    // exceeded our error budget or rate limit.
+                    420 | 429 => ErrorRetryStrategy::RateLimited(esi_error.retry_after),
 
                     // 5xx Server Errors - ESI is temporarily unavailable
                     //
@@ -121,6 +121,9 @@ impl AppError {
                 }
             }
 
+            // HTTP client errors - transient, the remote endpoint may recover
+            Self::Http(_) => ErrorRetryStrategy::Retry,
+
             // Session errors - transient, typically Redis connection issues
             Self::Session(_) => ErrorRetryStrategy::Retry,
 
@@ -136,6 +139,121 @@ impl AppError {
             // Auth errors - permanent failures (CSRF, bad credentials, missing data)
             Self::Auth(_) => ErrorRetryStrategy::Fail,
 
+            // Affiliation history errors - permanent failures (missing characters)
+            Self::AffiliationHistory(_) => ErrorRetryStrategy::Fail,
+
+            // Annotation errors - permanent failures (invalid input, missing records)
+            Self::Annotation(_) => ErrorRetryStrategy::Fail,
+
+            // Announcement errors - Discord rate limits and outages are transient, other errors
+            // are permanent failures (invalid input, missing inbox entries, rejected webhooks)
+            Self::Announcement(AnnouncementError::DiscordWebhookRejected(status))
+                if *status == 429 || *status >= 500 =>
+            {
+                ErrorRetryStrategy::Retry
+            }
+            Self::Announcement(_) => ErrorRetryStrategy::Fail,
+
+            // Approval errors - permanent failures (missing requests, decisions not allowed)
+            Self::Approval(_) => ErrorRetryStrategy::Fail,
+
+            // Ban errors - permanent failures (invalid bans, missing bans)
+            Self::Ban(_) => ErrorRetryStrategy::Fail,
+
+            // Campaign errors - permanent failures (invalid input, missing records)
+            Self::Campaign(_) => ErrorRetryStrategy::Fail,
+
+            // Character skill errors - permanent failures (missing characters or snapshots)
+            Self::CharacterSkill(_) => ErrorRetryStrategy::Fail,
+
+            // Consent errors - permanent failures (unknown category, consent not granted)
+            Self::Consent(_) => ErrorRetryStrategy::Fail,
+
+            // Corporation member errors - permanent failures (missing corporations or tokens)
+            Self::CorporationMember(_) => ErrorRetryStrategy::Fail,
+
+            // Data access API errors - permanent failures (invalid queries, invalid API keys)
+            Self::DataApi(_) => ErrorRetryStrategy::Fail,
+
+            // Dead-letter errors - permanent failures (missing entries, invalid payloads)
+            Self::DeadLetter(_) => ErrorRetryStrategy::Fail,
+
+            // Doctrine errors - permanent failures (invalid input, missing records)
+            Self::Doctrine(_) => ErrorRetryStrategy::Fail,
+
+            // Export errors - permanent failures (object storage not configured)
+            Self::Export(_) => ErrorRetryStrategy::Fail,
+
+            // Group errors - permanent failures (invalid input, ineligible users, missing records)
+            Self::Group(_) => ErrorRetryStrategy::Fail,
+
+            // Image errors - permanent failures (invalid input, missing images)
+            Self::Image(_) => ErrorRetryStrategy::Fail,
+
+            // Maintenance errors - transient, writes are accepted again once read-only mode ends
+            Self::Maintenance(_) => ErrorRetryStrategy::Retry,
+
+            // Member list errors - permanent failures (invalid input, missing filters)
+            Self::Member(_) => ErrorRetryStrategy::Fail,
+
+            // Onboarding errors - permanent failures (invalid input, missing steps)
+            Self::Onboarding(_) => ErrorRetryStrategy::Fail,
+
+            // Page errors - permanent failures (invalid input, missing pages)
+            Self::Page(_) => ErrorRetryStrategy::Fail,
+
+            // Preference errors - permanent failures (invalid input)
+            Self::Preference(_) => ErrorRetryStrategy::Fail,
+
+            // Push errors - permanent failures (push disabled, invalid input, missing records)
+            Self::Push(_) => ErrorRetryStrategy::Fail,
+
+            // Rate limit errors - transient, requests are accepted again once the window resets
+            Self::RateLimit(_) => ErrorRetryStrategy::Retry,
+
+            // Re-authentication campaign errors - permanent failures (invalid input, restricted
+            // access)
+            Self::ReauthCampaign(_) => ErrorRetryStrategy::Fail,
+
+            // Recruitment errors - permanent failures (invalid input, missing records, access)
+            Self::Recruitment(_) => ErrorRetryStrategy::Fail,
+
+            // Role errors - permanent failures (invalid input, missing records, permissions)
+            Self::Role(_) => ErrorRetryStrategy::Fail,
+
+            // Screening errors - permanent failures (missing records)
+            Self::Screening(_) => ErrorRetryStrategy::Fail,
+
+            // User session errors - permanent failures (missing sessions, current session)
+            Self::UserSession(_) => ErrorRetryStrategy::Fail,
+
+            // Skill plan errors - permanent failures (invalid input, missing records)
+            Self::SkillPlan(_) => ErrorRetryStrategy::Fail,
+
+            // Character token errors - permanent failures (missing or revoked tokens)
+            Self::Token(_) => ErrorRetryStrategy::Fail,
+
+            // User errors - permanent failures (invalid merge requests)
+            Self::User(_) => ErrorRetryStrategy::Fail,
+
+            // Webhook errors - rate limits and outages of the target are transient, other errors
+            // are permanent failures (invalid input, missing webhooks, rejected notifications)
+            Self::Webhook(WebhookError::WebhookRejected(status))
+                if *status == 429 || *status >= 500 =>
+            {
+                ErrorRetryStrategy::Retry
+            }
+            Self::Webhook(_) => ErrorRetryStrategy::Fail,
+
+            // Widget errors - permanent failures (missing records, unknown tokens)
+            Self::Widget(_) => ErrorRetryStrategy::Fail,
+
+            // Encryption errors - permanent failures (key configuration, corrupt ciphertext)
+            Self::Encryption(_) => ErrorRetryStrategy::Fail,
+
+            // Object storage errors - transient, the bucket may become reachable again
+            Self::ObjectStorage(_) => ErrorRetryStrategy::Retry,
+
             // Parse errors - permanent failures (malformed data that won't change)
             Self::Parse(_) => ErrorRetryStrategy::Fail,
 
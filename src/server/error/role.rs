@@ -0,0 +1,83 @@
+//! Role error types.
+//!
+//! This module defines errors related to roles and the permissions they grant, such as roles
+//! without a name or with a name already in use, references to roles that don't exist, changes
+//! to built-in roles, removing the last admin, and requests from users missing the permission
+//! an endpoint requires. Errors map to 400, 403, and 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::{api::ErrorDto, role::Permission};
+
+/// Role error type.
+///
+/// These errors occur when managing roles, assigning them to users, or enforcing the
+/// permissions they grant. Each variant is mapped to an appropriate HTTP status code in the
+/// `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum RoleError {
+    /// Role has no name or a name already used by another role.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("{0}")]
+    InvalidRole(String),
+
+    /// Role does not exist.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Role ID {0} not found")]
+    RoleNotFound(i32),
+
+    /// Change isn't allowed for a built-in role, such as deleting it, renaming it, or removing
+    /// the admin permission from the admin role.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Built-in role {0} can't be changed this way")]
+    BuiltInRole(String),
+
+    /// Removing the role would leave no user with the admin role.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("At least one user must keep the admin role")]
+    LastAdmin,
+
+    /// User doesn't have the permission the endpoint requires.
+    ///
+    /// Results in a 403 Forbidden response.
+    #[error("Missing required permission {}", .0.as_str())]
+    PermissionDenied(Permission),
+}
+
+/// Converts role errors into HTTP responses.
+///
+/// - `InvalidRole` → 400 Bad Request
+/// - `RoleNotFound` → 404 Not Found with "Role not found"
+/// - `BuiltInRole` → 400 Bad Request
+/// - `LastAdmin` → 400 Bad Request
+/// - `PermissionDenied` → 403 Forbidden
+///
+/// # Returns
+/// - 400 Bad Request - For invalid roles, changes to built-in roles, or removing the last admin
+/// - 403 Forbidden - For users missing the required permission
+/// - 404 Not Found - For unknown roles
+impl IntoResponse for RoleError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::InvalidRole(_) | Self::BuiltInRole(_) | Self::LastAdmin => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            Self::RoleNotFound(_) => (StatusCode::NOT_FOUND, "Role not found".to_string()),
+            Self::PermissionDenied(_) => (StatusCode::FORBIDDEN, self.to_string()),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
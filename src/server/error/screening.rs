@@ -0,0 +1,61 @@
+//! Character screening error types.
+//!
+//! This module defines errors related to generating and viewing recruitment screening
+//! reports, such as screening a character that has not registered with Bifrost or viewing
+//! a report that does not exist. These errors map to 404 responses.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Character screening error type.
+///
+/// These errors occur when generating or retrieving screening reports. Each variant is
+/// mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum ScreeningError {
+    /// Character is not in the database or not owned by any user.
+    ///
+    /// Screening relies on ownership records, so only characters registered with Bifrost
+    /// can be screened. Results in a 404 Not Found response.
+    #[error("Character ID {0} not found or not registered with any user")]
+    CharacterNotRegistered(i64),
+
+    /// Screening report ID does not exist in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Screening report ID {0} not found")]
+    ReportNotFound(i32),
+}
+
+/// Converts screening errors into HTTP responses.
+///
+/// - `CharacterNotRegistered` → 404 Not Found with "Character not registered"
+/// - `ReportNotFound` → 404 Not Found with "Screening report not found"
+///
+/// # Returns
+/// - 404 Not Found - For unregistered characters or missing reports
+impl IntoResponse for ScreeningError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let error = match &self {
+            Self::CharacterNotRegistered(_) => "Character not registered",
+            Self::ReportNotFound(_) => "Screening report not found",
+        };
+
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorDto {
+                error: error.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,65 @@
+//! User session error types.
+//!
+//! This module defines errors related to users listing and revoking the sessions they are
+//! logged in with, such as revoking a session that no longer exists. These errors map to 4xx
+//! responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// User session error type.
+///
+/// These errors occur when a user manages their sessions. Each variant is mapped to an
+/// appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum SessionError {
+    /// No active session of the user has the given ID.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Session {0} not found")]
+    NotFound(String),
+
+    /// The session to revoke is the session the request was made with.
+    ///
+    /// Users end their current session by logging out instead. Results in a 400 Bad Request
+    /// response.
+    #[error("Cannot revoke the current session")]
+    CurrentSession,
+}
+
+/// Converts user session errors into HTTP responses.
+///
+/// - `NotFound` → 404 Not Found with "Session not found"
+/// - `CurrentSession` → 400 Bad Request with "Log out to end the current session"
+///
+/// # Returns
+/// - 400 Bad Request - For revoking the current session
+/// - 404 Not Found - For missing sessions
+impl IntoResponse for SessionError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::NotFound(_) => (StatusCode::NOT_FOUND, "Session not found"),
+            Self::CurrentSession => (
+                StatusCode::BAD_REQUEST,
+                "Log out to end the current session",
+            ),
+        };
+
+        (
+            status,
+            Json(ErrorDto {
+                error: error.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
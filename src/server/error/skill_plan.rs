@@ -0,0 +1,70 @@
+//! Skill plan error types.
+//!
+//! This module defines errors related to publishing skill plans, such as plan text that
+//! cannot be parsed or references to plans that do not exist. These errors map to 400, 404,
+//! and 409 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::{model::api::ErrorDto, server::util::skill_plan::SkillPlanParseError};
+
+/// Skill plan error type.
+///
+/// These errors occur when publishing, listing, or deleting skill plans. Each variant is
+/// mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum SkillPlanError {
+    /// Skill plan text could not be parsed as plain text or EVEMon XML.
+    ///
+    /// Results in a 400 Bad Request response including the parse error so the user can
+    /// correct their input.
+    #[error(transparent)]
+    InvalidPlan(#[from] SkillPlanParseError),
+
+    /// A skill plan with the same name already exists.
+    ///
+    /// Results in a 409 Conflict response.
+    #[error("Skill plan with name {0:?} already exists")]
+    DuplicateSkillPlanName(String),
+
+    /// Skill plan ID does not exist in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Skill plan ID {0} not found")]
+    SkillPlanNotFound(i32),
+}
+
+/// Converts skill plan errors into HTTP responses.
+///
+/// - `InvalidPlan` → 400 Bad Request with the parse error message
+/// - `DuplicateSkillPlanName` → 409 Conflict
+/// - `SkillPlanNotFound` → 404 Not Found with "Skill plan not found"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid plan input
+/// - 404 Not Found - For missing skill plans
+/// - 409 Conflict - For duplicate skill plan names
+impl IntoResponse for SkillPlanError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::InvalidPlan(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+            Self::DuplicateSkillPlanName(_) => (
+                StatusCode::CONFLICT,
+                "A skill plan with that name already exists".to_string(),
+            ),
+            Self::SkillPlanNotFound(_) => {
+                (StatusCode::NOT_FOUND, "Skill plan not found".to_string())
+            }
+        };
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
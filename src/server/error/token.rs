@@ -0,0 +1,63 @@
+//! Character token error types.
+//!
+//! This module defines errors related to the EVE SSO refresh tokens stored per character, such
+//! as requesting an access token for a character without a stored token or whose token EVE SSO
+//! rejected. All errors map to 404 responses asking the user to log in with the character again.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Character token error type.
+///
+/// These errors occur when exchanging stored refresh tokens for access tokens. Each variant is
+/// mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum TokenError {
+    /// No refresh token is stored for the character.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("No refresh token stored for character ID {0}")]
+    NotFound(i64),
+
+    /// EVE SSO rejected the character's refresh token, e.g. because the character revoked it.
+    ///
+    /// The token is deleted when this error is returned. Results in a 404 Not Found response.
+    #[error("Refresh token of character ID {0} was revoked")]
+    Revoked(i64),
+}
+
+/// Converts character token errors into HTTP responses.
+///
+/// - `NotFound` → 404 Not Found asking the user to log in with the character
+/// - `Revoked` → 404 Not Found asking the user to log in with the character again
+///
+/// # Returns
+/// - 404 Not Found - For characters without a usable token
+impl IntoResponse for TokenError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::NotFound(_) => (
+                StatusCode::NOT_FOUND,
+                "No token is stored for this character, log in with the character to grant \
+                 access"
+                    .to_string(),
+            ),
+            Self::Revoked(_) => (
+                StatusCode::NOT_FOUND,
+                "Access for this character was revoked, log in with the character again"
+                    .to_string(),
+            ),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
@@ -0,0 +1,51 @@
+//! User management error types.
+//!
+//! This module defines errors related to admin user management, such as merging a user
+//! account into itself. These errors map to 400 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// User management error type.
+///
+/// These errors occur when an admin manages user accounts. Each variant is mapped to an
+/// appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum UserError {
+    /// The user to keep and the user to remove in a merge are the same user.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("Cannot merge user ID {0} into itself")]
+    MergeIntoSelf(i32),
+}
+
+/// Converts user management errors into HTTP responses.
+///
+/// - `MergeIntoSelf` → 400 Bad Request with "Cannot merge a user into itself"
+///
+/// # Returns
+/// - 400 Bad Request - For invalid merge requests
+impl IntoResponse for UserError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::MergeIntoSelf(_) => (StatusCode::BAD_REQUEST, "Cannot merge a user into itself"),
+        };
+
+        (
+            status,
+            Json(ErrorDto {
+                error: error.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,65 @@
+//! Webhook error types.
+//!
+//! This module defines errors related to the webhooks notified of character ownership changes,
+//! such as webhooks without a name, an HTTPS URL, or events, references to webhooks that don't
+//! exist, and deliveries the webhook target rejected. Request errors map to 400 and 404
+//! responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::{model::api::ErrorDto, server::error::InternalServerError};
+
+/// Webhook error type.
+///
+/// These errors occur when managing webhooks or delivering notifications to them. Each variant
+/// is mapped to an appropriate HTTP status code in the `IntoResponse` implementation.
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    /// Webhook has no name, no events, or a URL that isn't HTTPS.
+    ///
+    /// Results in a 400 Bad Request response.
+    #[error("{0}")]
+    InvalidWebhook(String),
+
+    /// Webhook does not exist.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Webhook ID {0} not found")]
+    WebhookNotFound(i32),
+
+    /// Webhook target responded with an error status.
+    ///
+    /// Only occurs in worker jobs. Results in a 500 Internal Server Error response.
+    #[error("Webhook rejected notification with status {0}")]
+    WebhookRejected(u16),
+}
+
+/// Converts webhook errors into HTTP responses.
+///
+/// - `InvalidWebhook` → 400 Bad Request
+/// - `WebhookNotFound` → 404 Not Found with "Webhook not found"
+/// - `WebhookRejected` → 500 Internal Server Error
+///
+/// # Returns
+/// - 400 Bad Request - For invalid webhooks
+/// - 404 Not Found - For unknown webhooks
+/// - 500 Internal Server Error - For rejected deliveries
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let (status, error) = match &self {
+            Self::InvalidWebhook(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::WebhookNotFound(_) => (StatusCode::NOT_FOUND, "Webhook not found".to_string()),
+            Self::WebhookRejected(_) => return InternalServerError(self).into_response(),
+        };
+
+        tracing::debug!("{}", self);
+
+        (status, Json(ErrorDto { error })).into_response()
+    }
+}
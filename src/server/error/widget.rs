@@ -0,0 +1,71 @@
+//! Embeddable widget error types.
+//!
+//! This module defines errors related to creating, managing, and serving embeddable widgets,
+//! such as widgets for corporations unknown to Bifrost and unknown widget tokens. These errors
+//! map to 404 responses with user-facing messages.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use thiserror::Error;
+
+use crate::model::api::ErrorDto;
+
+/// Embeddable widget error type.
+///
+/// These errors occur when managing widgets or when an external site requests a widget.
+/// Each variant is mapped to an appropriate HTTP status code in the `IntoResponse`
+/// implementation.
+#[derive(Error, Debug)]
+pub enum WidgetError {
+    /// Corporation ID does not exist in the database.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Corporation ID {0} not found in database")]
+    CorporationNotFound(i64),
+
+    /// Widget does not exist or is not owned by the requesting user.
+    ///
+    /// Results in a 404 Not Found response.
+    #[error("Widget ID {0} not found")]
+    WidgetNotFound(i32),
+
+    /// No widget exists for the provided token.
+    ///
+    /// The token is not included in the message to keep widget secrets out of logs. Results
+    /// in a 404 Not Found response.
+    #[error("No widget found for the provided token")]
+    InvalidToken,
+}
+
+/// Converts widget errors into HTTP responses.
+///
+/// - `CorporationNotFound` → 404 Not Found with "Corporation not found"
+/// - `WidgetNotFound` → 404 Not Found with "Widget not found"
+/// - `InvalidToken` → 404 Not Found with "Widget not found"
+///
+/// # Returns
+/// - 404 Not Found - For missing corporations, widgets, or unknown tokens
+impl IntoResponse for WidgetError {
+    fn into_response(self) -> Response {
+        tracing::debug!("{}", self);
+
+        let (status, error) = match &self {
+            Self::CorporationNotFound(_) => (StatusCode::NOT_FOUND, "Corporation not found"),
+            Self::WidgetNotFound(_) | Self::InvalidToken => {
+                (StatusCode::NOT_FOUND, "Widget not found")
+            }
+        };
+
+        (
+            status,
+            Json(ErrorDto {
+                error: error.to_string(),
+            }),
+        )
+            .into_response()
+    }
+}
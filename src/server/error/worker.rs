@@ -1,19 +1,26 @@
 //! Worker queue error types.
 //!
-//! This module defines errors related to worker job validation, serialization, and scheduling.
-//! Worker errors typically indicate programming bugs (invalid job parameters) or Redis/queue
-//! infrastructure issues that prevent jobs from being properly enqueued or processed.
+//! This module defines errors related to worker job validation, serialization, scheduling, and
+//! status lookups. Worker errors typically indicate programming bugs (invalid job parameters) or
+//! Redis/queue infrastructure issues that prevent jobs from being properly enqueued or
+//! processed.
 
-use axum::response::{IntoResponse, Response};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
 use thiserror::Error;
 
-use crate::server::error::InternalServerError;
+use crate::{model::api::ErrorDto, server::error::InternalServerError};
 
 /// Worker queue error type.
 ///
 /// These errors occur during worker job creation, validation, serialization, or scheduling.
-/// All worker errors are treated as internal server errors (500) since they indicate issues
-/// with the background job system rather than client errors.
+/// Worker errors are treated as internal server errors (500) since they indicate issues with
+/// the background job system rather than client errors, except for status lookups of unknown
+/// jobs.
 #[derive(Error, Debug)]
 pub enum WorkerError {
     /// Affiliation job batch size exceeds ESI's request limit.
@@ -41,6 +48,23 @@ pub enum WorkerError {
     #[error("Failed to serialize/deserialize WorkerJob: {0}")]
     Serialization(String),
 
+    /// A job payload was serialized with a newer payload version than this release supports.
+    ///
+    /// This error occurs when a job queued by a newer release is popped by an older release,
+    /// for example after a rollback. The job can be replayed from the dead-letter queue once
+    /// the newer release is deployed again.
+    ///
+    /// # Fields
+    /// - `version` - Payload version of the job
+    /// - `current` - Latest payload version supported by this release
+    #[error("Worker job payload version {version} is newer than supported version {current}")]
+    UnsupportedPayloadVersion {
+        /// Payload version of the job.
+        version: u32,
+        /// Latest payload version supported by this release.
+        current: u32,
+    },
+
     /// Failed to schedule a task in the worker queue.
     ///
     /// This error occurs when the worker queue system cannot accept a new job, typically
@@ -48,18 +72,47 @@ pub enum WorkerError {
     /// failures.
     #[error("Failed to schedule task: {0}")]
     Scheduler(String),
+
+    /// No registered plugin handles a custom worker job kind.
+    ///
+    /// This error occurs when a `WorkerJob::Custom` job is processed but the plugin declaring
+    /// its kind is not registered, for example after a plugin was removed while its jobs were
+    /// still queued.
+    #[error("No plugin handles custom worker job kind {0:?}")]
+    UnhandledCustomJob(String),
+
+    /// No status is recorded for a job.
+    ///
+    /// This error occurs when an admin looks up the status of a job that was never queued
+    /// under the ID, or whose status was removed by the queue's cleanup after the status TTL.
+    #[error("No status recorded for worker job {0}")]
+    JobStatusNotFound(String),
 }
 
 /// Converts worker errors into HTTP responses.
 ///
-/// All worker errors are treated as internal server errors (500) since they indicate
-/// issues with the background job system rather than client errors. The error is logged
-/// for debugging and a generic error message is returned to the client.
+/// Worker errors are treated as internal server errors (500) since they indicate issues with
+/// the background job system rather than client errors. The error is logged for debugging and
+/// a generic error message is returned to the client.
 ///
 /// # Returns
-/// A 500 Internal Server Error response with a generic error message
+/// - 404 Not Found - For `JobStatusNotFound`
+/// - 500 Internal Server Error - For all other errors, with a generic error message
 impl IntoResponse for WorkerError {
     fn into_response(self) -> Response {
-        InternalServerError(self).into_response()
+        match self {
+            Self::JobStatusNotFound(_) => {
+                tracing::debug!("{}", self);
+
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorDto {
+                        error: "Job status not found".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
+            _ => InternalServerError(self).into_response(),
+        }
     }
 }
@@ -3,7 +3,8 @@
 //! This module contains all server-side functionality for the Bifrost application, including
 //! HTTP routing, authentication, database operations, background workers, job scheduling, and
 //! EVE Online ESI integration. It provides the complete backend infrastructure for managing
-//! user accounts, EVE character data, and automated data refresh operations.
+//! user accounts, EVE character data, and automated data refresh operations, along with a
+//! plugin system for extending it.
 
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
@@ -13,6 +14,7 @@ pub mod controller;
 pub mod data;
 pub mod error;
 pub mod model;
+pub mod plugin;
 pub mod router;
 pub mod scheduler;
 pub mod service;
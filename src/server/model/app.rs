@@ -5,9 +5,23 @@
 //! like the database connection, ESI client, and worker system that handlers need to
 //! process requests and dispatch background jobs.
 
+use fred::prelude::Pool;
 use sea_orm::DatabaseConnection;
 
-use crate::server::{service::eve::esi::EsiProvider, worker::Worker};
+use crate::server::{
+    scheduler::config::SchedulerSettings,
+    service::{
+        approval::ApprovalConfig, auth::discord::DiscordConfig, eve::esi::EsiProvider,
+        image::ImageProxyConfig, push::PushConfig, search::SearchConfig,
+        telemetry::TelemetryConfig,
+    },
+    startup::TaskSupervisor,
+    util::{
+        branding::BrandingSettings, crypto::ColumnCipher, object_storage::ObjectStorage,
+        read_only::ReadOnlyMode, scope_set::ScopeSets, session_expiry::SessionExpiry,
+    },
+    worker::Worker,
+};
 
 /// Central application state shared across HTTP handlers.
 ///
@@ -21,6 +35,21 @@ use crate::server::{service::eve::esi::EsiProvider, worker::Worker};
 /// - `db` - Database connection pool for querying and persisting data
 /// - `esi_provider` - ESI provider with circuit breaker protection for EVE Online API calls
 /// - `worker` - Worker system for dispatching and managing background jobs
+/// - `telemetry` - Opt-in telemetry settings shown to administrators
+/// - `push` - Web Push settings browsers need to subscribe to push notifications
+/// - `search` - Search backend settings, selecting Meilisearch or the database
+/// - `image_proxy` - Image proxy settings, with the cache directory if EVE images are proxied
+/// - `object_storage` - S3-compatible bucket large exports are stored in, if configured
+/// - `branding` - Organization name, logo, color, and navigation links shown by the frontend
+/// - `scheduler` - Batch sizes, stagger window, and jitter used by scheduled refreshes
+/// - `approvals` - Sensitive admin actions requiring a second admin and how long requests stay open
+/// - `read_only` - Read-only mode flag rejecting writes during database maintenance
+/// - `supervisor` - Supervisor of background tasks, reporting their health for diagnostics
+/// - `cipher` - Cipher sensitive columns such as directors' refresh tokens are encrypted with
+/// - `scope_sets` - Named sets of ESI scopes logins can request
+/// - `discord` - Discord application settings used to link Discord accounts to users
+/// - `redis_pool` - Redis pool shared with the session store, holding the index of user sessions
+/// - `session_expiry` - Days of inactivity after which default and remembered sessions expire
 ///
 /// # Example
 /// ```ignore
@@ -45,4 +74,53 @@ pub struct AppState {
 
     /// Worker system for dispatching background jobs to the Redis-backed queue.
     pub worker: Worker,
+
+    /// Opt-in telemetry settings, used to show administrators what is reported.
+    pub telemetry: TelemetryConfig,
+
+    /// Web Push settings, used to hand browsers the VAPID public key when subscribing.
+    pub push: PushConfig,
+
+    /// Search settings, holding the Meilisearch client if searches aren't served from the database.
+    pub search: SearchConfig,
+
+    /// Image proxy settings, used to serve EVE portraits and logos from the disk cache.
+    pub image_proxy: ImageProxyConfig,
+
+    /// Bucket large exports are stored in, `None` if object storage is not configured.
+    pub object_storage: Option<ObjectStorage>,
+
+    /// Branding settings, served to the frontend layout for white-labeling.
+    pub branding: BrandingSettings,
+
+    /// Scheduler settings, used to preview scheduled refreshes as the scheduler runs them.
+    pub scheduler: SchedulerSettings,
+
+    /// Two-person rule settings, used to hold sensitive admin actions until a second admin
+    /// approves them.
+    pub approvals: ApprovalConfig,
+
+    /// Read-only mode flag, toggled by admins around database maintenance and shared with the
+    /// middleware rejecting writes and the worker pool.
+    pub read_only: ReadOnlyMode,
+
+    /// Supervisor owning long-running background tasks, used to report their health.
+    pub supervisor: TaskSupervisor,
+
+    /// Cipher built from the configured encryption keys, used to store the refresh tokens of
+    /// directors who granted the corporation membership scope.
+    pub cipher: ColumnCipher,
+
+    /// Named ESI scope sets, resolved by the login endpoint's `scopes` parameter.
+    pub scope_sets: ScopeSets,
+
+    /// Discord linking settings, used by the Discord OAuth flow linking accounts to users.
+    pub discord: DiscordConfig,
+
+    /// Redis connection pool shared with the session store, used to index the sessions each
+    /// user is logged in with.
+    pub redis_pool: Pool,
+
+    /// Sliding session expiry, used to extend sessions logged in with "remember me".
+    pub session_expiry: SessionExpiry,
 }
@@ -31,6 +31,30 @@ pub type UserModel = entity::bifrost_user::Model;
 /// - `updated_at` - Timestamp of the last ownership record update
 pub type CharacterOwnershipModel = entity::bifrost_user_character::Model;
 
+/// Type alias for user character summary database model.
+///
+/// Denormalized copy of an owned character with its corporation and alliance, read by the
+/// user endpoints instead of joining the ownership and EVE entity tables per request. Rows
+/// are rebuilt whenever the user's characters or the referenced EVE entities change.
+///
+/// # Fields (from `entity::bifrost_user_character_summary::Model`)
+/// - `id` - Primary key, unique summary row identifier
+/// - `user_id` - Foreign key to the owning user
+/// - `is_main` - Whether the character is the user's main character
+/// - `character_id` - EVE Online character ID (unique)
+/// - `character_name` - Character name
+/// - `character_info_updated_at` - When the character information was last updated
+/// - `character_affiliation_updated_at` - When the character affiliation was last updated
+/// - `corporation_id` - EVE Online corporation ID of the character's corporation
+/// - `corporation_name` - Corporation name
+/// - `corporation_info_updated_at` - When the corporation information was last updated
+/// - `corporation_affiliation_updated_at` - When the corporation affiliation was last updated
+/// - `alliance_id` - EVE Online alliance ID of the corporation's alliance (if any)
+/// - `alliance_name` - Alliance name (if any)
+/// - `alliance_updated_at` - When the alliance information was last updated (if any)
+/// - `refreshed_at` - Timestamp when the row was last rebuilt
+pub type UserCharacterSummaryModel = entity::bifrost_user_character_summary::Model;
+
 /// Type alias for EVE Online character database model.
 ///
 /// Represents cached data for an EVE Online character, including basic information
@@ -119,3 +143,566 @@ pub type EveAllianceModel = entity::eve_alliance::Model;
 /// - `created_at` - Timestamp when record was created in Bifrost
 /// - `updated_at` - Timestamp of last record update
 pub type EveFactionModel = entity::eve_faction::Model;
+
+/// Type alias for ship fitting database model.
+///
+/// Represents a ship fitting imported in EFT format. The original EFT text is stored so the
+/// fitting can be exported unchanged, with the ship hull and fitting name extracted on import.
+///
+/// # Fields (from `entity::bifrost_fitting::Model`)
+/// - `id` - Primary key, unique fitting identifier
+/// - `name` - Fitting name from the EFT header
+/// - `ship_type_name` - Ship hull name from the EFT header
+/// - `eft` - Original fitting text in EFT format
+/// - `created_by_user_id` - Foreign key to the user who imported the fitting
+/// - `created_at` - Timestamp when the fitting was imported
+/// - `updated_at` - Timestamp of the last fitting update
+pub type FittingModel = entity::bifrost_fitting::Model;
+
+/// Type alias for fitting skill requirement database model.
+///
+/// Represents a skill level a character needs to fly a fitting. Fittings are stored by item
+/// name, so the requirements are entered by content managers rather than derived from the
+/// fitted items.
+///
+/// # Fields (from `entity::bifrost_fitting_skill::Model`)
+/// - `id` - Primary key, unique requirement identifier
+/// - `fitting_id` - Foreign key to the fitting
+/// - `skill_id` - EVE Online type ID of the required skill
+/// - `level` - Minimum active level required, from 1 to 5
+pub type FittingSkillModel = entity::bifrost_fitting_skill::Model;
+
+/// Type alias for doctrine database model.
+///
+/// Represents a named group of fittings that members are expected to be able to fly.
+///
+/// # Fields (from `entity::bifrost_doctrine::Model`)
+/// - `id` - Primary key, unique doctrine identifier
+/// - `name` - Doctrine name (unique)
+/// - `description` - Optional doctrine description
+/// - `created_at` - Timestamp when the doctrine was created
+/// - `updated_at` - Timestamp of the last doctrine update
+pub type DoctrineModel = entity::bifrost_doctrine::Model;
+
+/// Type alias for doctrine fitting membership database model.
+///
+/// Links a fitting to a doctrine. A fitting may belong to multiple doctrines.
+///
+/// # Fields (from `entity::bifrost_doctrine_fitting::Model`)
+/// - `id` - Primary key, unique membership identifier
+/// - `doctrine_id` - Foreign key to the doctrine
+/// - `fitting_id` - Foreign key to the fitting
+/// - `created_at` - Timestamp when the fitting was added to the doctrine
+pub type DoctrineFittingModel = entity::bifrost_doctrine_fitting::Model;
+
+/// Type alias for corporation recruitment listing database model.
+///
+/// Represents a corporation that has opted into the public recruitment listing. Each
+/// corporation has at most one listing.
+///
+/// # Fields (from `entity::bifrost_recruitment_listing::Model`)
+/// - `id` - Primary key, unique listing identifier
+/// - `corporation_id` - Foreign key to the corporation record (unique)
+/// - `description` - Recruitment pitch shown on the public listing
+/// - `timezone` - Primary timezone the corporation is active in
+/// - `created_at` - Timestamp when the corporation opted into recruitment
+/// - `updated_at` - Timestamp of the last listing update
+pub type RecruitmentListingModel = entity::bifrost_recruitment_listing::Model;
+
+/// Type alias for character screening report database model.
+///
+/// Represents a stored snapshot of a recruitment screening report for a character. The report
+/// content is stored as JSON so it reflects the data at the time it was generated.
+///
+/// # Fields (from `entity::bifrost_screening_report::Model`)
+/// - `id` - Primary key, unique report identifier used in shareable links
+/// - `character_id` - Foreign key to the screened character record
+/// - `requested_by_user_id` - Foreign key to the user who generated the report
+/// - `report` - Serialized report content (JSON)
+/// - `created_at` - Timestamp when the report was generated
+pub type ScreeningReportModel = entity::bifrost_screening_report::Model;
+
+/// Type alias for skill plan database model.
+///
+/// Represents a skill plan published by an officer. The plan is stored as normalized plain
+/// text with one `Skill Name Level` entry per line, regardless of the format it was imported in.
+///
+/// # Fields (from `entity::bifrost_skill_plan::Model`)
+/// - `id` - Primary key, unique skill plan identifier
+/// - `name` - Unique skill plan name
+/// - `description` - Optional skill plan description
+/// - `plan` - Plan entries as plain text in training order
+/// - `created_by_user_id` - Foreign key to the user who published the plan
+/// - `created_at` - Timestamp when the plan was published
+/// - `updated_at` - Timestamp of the last plan update
+pub type SkillPlanModel = entity::bifrost_skill_plan::Model;
+
+/// Type alias for campaign database model.
+///
+/// Represents a deployment or war tracked by leadership, staged out of one solar system over a
+/// date range. Ongoing campaigns have no end date.
+///
+/// # Fields (from `entity::bifrost_campaign::Model`)
+/// - `id` - Primary key, unique campaign identifier
+/// - `name` - Unique campaign name
+/// - `staging_system` - Name of the solar system the campaign stages out of
+/// - `starts_at` - Timestamp when the campaign starts
+/// - `ends_at` - Timestamp when the campaign ends (None while ongoing)
+/// - `created_by_user_id` - Foreign key to the user who created the campaign
+/// - `created_at` - Timestamp when the campaign was created
+/// - `updated_at` - Timestamp of the last campaign update
+pub type CampaignModel = entity::bifrost_campaign::Model;
+
+/// Type alias for user data-sharing consent database model.
+///
+/// Represents a user granting the organization access to one category of their data (e.g.
+/// assets or wallet). Each user has at most one record per category; revoking consent deletes
+/// the record.
+///
+/// # Fields (from `entity::bifrost_user_consent::Model`)
+/// - `id` - Primary key, unique consent identifier
+/// - `user_id` - Foreign key to the user who granted consent
+/// - `category` - Consent category identifier (e.g. `assets`)
+/// - `granted_at` - Timestamp when consent was granted
+pub type UserConsentModel = entity::bifrost_user_consent::Model;
+
+/// Type alias for user preference database model.
+///
+/// Represents the preferences of one user. Users without a record use the default
+/// preferences. The dashboard layout is stored as a comma-separated list of widget names so
+/// new widgets can be added without a migration.
+///
+/// # Fields (from `entity::bifrost_user_preference::Model`)
+/// - `id` - Primary key, unique preference record identifier
+/// - `user_id` - Foreign key to the user, unique per user
+/// - `dashboard_layout` - Comma-separated dashboard widget names in display order
+/// - `updated_at` - Timestamp of the last preference update
+pub type UserPreferenceModel = entity::bifrost_user_preference::Model;
+
+/// Type alias for push subscription database model.
+///
+/// Represents a browser or device a user subscribed to Web Push notifications on. The keys
+/// are provided by the browser and used to encrypt every message sent to the endpoint.
+///
+/// # Fields (from `entity::bifrost_push_subscription::Model`)
+/// - `id` - Primary key, unique subscription identifier
+/// - `user_id` - Foreign key to the subscribed user
+/// - `endpoint` - Push service URL messages are sent to (unique)
+/// - `p256dh` - Base64url-encoded P-256 public key of the browser
+/// - `auth` - Base64url-encoded authentication secret of the browser
+/// - `created_at` - Timestamp when the subscription was created
+pub type PushSubscriptionModel = entity::bifrost_push_subscription::Model;
+
+/// Type alias for embeddable widget database model.
+///
+/// Represents a read-only widget that external websites can embed using the widget's token.
+/// Revoking a widget deletes the record, invalidating its token.
+///
+/// # Fields (from `entity::bifrost_widget::Model`)
+/// - `id` - Primary key, unique widget identifier
+/// - `user_id` - Foreign key to the user who created the widget
+/// - `corporation_id` - Foreign key to the corporation record the widget displays
+/// - `kind` - Widget kind identifier (e.g. `corporation_member_count`)
+/// - `token` - Unguessable token authorizing access to the widget (unique)
+/// - `created_at` - Timestamp when the widget was created
+pub type WidgetModel = entity::bifrost_widget::Model;
+
+/// Type alias for corporation user count summary database model.
+///
+/// Precomputed number of users whose main character is in a corporation, refreshed
+/// periodically by the `RefreshDashboardSummaries` worker job for the admin dashboard.
+///
+/// # Fields (from `entity::bifrost_corporation_user_count::Model`)
+/// - `id` - Primary key, unique summary row identifier
+/// - `corporation_id` - Foreign key to the corporation record (unique)
+/// - `user_count` - Number of users with their main character in the corporation
+/// - `refreshed_at` - Timestamp when the summary was last refreshed
+pub type CorporationUserCountModel = entity::bifrost_corporation_user_count::Model;
+
+/// Type alias for characters-per-user distribution summary database model.
+///
+/// Precomputed number of users owning a given number of characters, refreshed periodically
+/// by the `RefreshDashboardSummaries` worker job for the admin dashboard.
+///
+/// # Fields (from `entity::bifrost_character_count_distribution::Model`)
+/// - `id` - Primary key, unique summary row identifier
+/// - `character_count` - Number of characters owned (unique)
+/// - `user_count` - Number of users owning exactly `character_count` characters
+/// - `refreshed_at` - Timestamp when the summary was last refreshed
+pub type CharacterCountDistributionModel = entity::bifrost_character_count_distribution::Model;
+
+/// Type alias for page database model.
+///
+/// Represents a Markdown page edited by admins and served to visitors, such as alliance
+/// rules or join instructions. The page holds the current version; every saved version is
+/// also recorded as a `PageRevisionModel`.
+///
+/// # Fields (from `entity::bifrost_page::Model`)
+/// - `id` - Primary key, unique page identifier
+/// - `slug` - Unique URL segment the page is served at
+/// - `title` - Page title
+/// - `content` - Page content as Markdown
+/// - `created_at` - Timestamp when the page was created
+/// - `updated_at` - Timestamp of the last page edit
+pub type PageModel = entity::bifrost_page::Model;
+
+/// Type alias for page revision database model.
+///
+/// Represents one saved version of a page, kept so admins can review and restore earlier
+/// versions. Revisions are deleted with their page.
+///
+/// # Fields (from `entity::bifrost_page_revision::Model`)
+/// - `id` - Primary key, unique revision identifier
+/// - `page_id` - Foreign key to the page record
+/// - `title` - Page title of this version
+/// - `content` - Page content of this version as Markdown
+/// - `edited_by_user_id` - Foreign key to the user who saved this version
+/// - `created_at` - Timestamp when this version was saved
+pub type PageRevisionModel = entity::bifrost_page_revision::Model;
+
+/// Type alias for announcement database model.
+///
+/// Represents a broadcast posted by an admin to all members or the members of a corporation
+/// or alliance. The audience is resolved to recipients when the announcement is posted.
+///
+/// # Fields (from `entity::bifrost_announcement::Model`)
+/// - `id` - Primary key, unique announcement identifier
+/// - `title` - Announcement title
+/// - `body` - Announcement body as Markdown
+/// - `audience` - Audience kind (`all`, `corporation`, or `alliance`)
+/// - `audience_id` - EVE Online corporation or alliance ID the announcement targets
+/// - `created_by_user_id` - Foreign key to the user who posted the announcement
+/// - `created_at` - Timestamp when the announcement was posted
+pub type AnnouncementModel = entity::bifrost_announcement::Model;
+
+/// Type alias for announcement recipient database model.
+///
+/// Represents an announcement in a user's inbox, along with the read receipt aggregated
+/// for leadership. Recipients are deleted with their announcement or user.
+///
+/// # Fields (from `entity::bifrost_announcement_recipient::Model`)
+/// - `id` - Primary key, unique recipient identifier
+/// - `announcement_id` - Foreign key to the announcement record
+/// - `user_id` - Foreign key to the receiving user
+/// - `read_at` - Timestamp when the user marked the announcement read, `None` if unread
+pub type AnnouncementRecipientModel = entity::bifrost_announcement_recipient::Model;
+
+/// Type alias for re-authentication campaign database model.
+///
+/// Represents a request by an admin for the users in an audience to re-authenticate with a
+/// set of ESI scopes. The audience is resolved to flagged users when the campaign is launched.
+///
+/// # Fields (from `entity::bifrost_reauth_campaign::Model`)
+/// - `id` - Primary key, unique campaign identifier
+/// - `name` - Campaign name shown to flagged users
+/// - `scopes` - Space-separated ESI scopes users must grant
+/// - `audience` - Audience kind (`all`, `corporation`, or `alliance`)
+/// - `audience_id` - EVE Online corporation or alliance ID the campaign targets
+/// - `deadline` - Time after which flagged users who haven't re-authenticated lose access,
+///   `None` if the campaign only shows a banner
+/// - `created_by_user_id` - Foreign key to the user who launched the campaign
+/// - `created_at` - Timestamp when the campaign was launched
+pub type ReauthCampaignModel = entity::bifrost_reauth_campaign::Model;
+
+/// Type alias for re-authentication campaign user database model.
+///
+/// Represents a user flagged by a re-authentication campaign and whether they have completed
+/// it. Rows are deleted with their campaign or user.
+///
+/// # Fields (from `entity::bifrost_reauth_campaign_user::Model`)
+/// - `id` - Primary key, unique row identifier
+/// - `campaign_id` - Foreign key to the campaign record
+/// - `user_id` - Foreign key to the flagged user
+/// - `completed_at` - Timestamp when the user granted the campaign's scopes, `None` if pending
+pub type ReauthCampaignUserModel = entity::bifrost_reauth_campaign_user::Model;
+
+/// Type alias for saved query database model.
+///
+/// Represents a read-only SQL query defined by an admin and exposed to BI tools through the
+/// data access API. Queries are executed with their parameters bound and their rows limited.
+///
+/// # Fields (from `entity::bifrost_saved_query::Model`)
+/// - `id` - Primary key, unique query identifier
+/// - `slug` - Unique URL segment the query is requested by
+/// - `name` - Query name shown to admins
+/// - `sql` - `SELECT` statement with `$1`, `$2`, ... placeholders for its parameters
+/// - `parameters` - Space-separated `name:kind` parameters bound to the placeholders in order
+/// - `row_limit` - Maximum number of rows returned per request
+/// - `created_by_user_id` - Foreign key to the user who created the query
+/// - `created_at` - Timestamp when the query was created
+/// - `updated_at` - Timestamp when the query was last saved
+pub type SavedQueryModel = entity::bifrost_saved_query::Model;
+
+/// Type alias for API key database model.
+///
+/// Represents a key authorizing BI tools to run saved queries through the data access API.
+/// Only a hash of the key is stored, so keys are shown once when created.
+///
+/// # Fields (from `entity::bifrost_api_key::Model`)
+/// - `id` - Primary key, unique key identifier
+/// - `name` - Name identifying the tool using the key
+/// - `key_hash` - Hex-encoded SHA-256 hash of the key
+/// - `created_by_user_id` - Foreign key to the user who created the key
+/// - `created_at` - Timestamp when the key was created
+/// - `last_used_at` - Timestamp when the key last authorized a request, `None` if never used
+pub type ApiKeyModel = entity::bifrost_api_key::Model;
+
+/// Type alias for affiliation history database model.
+///
+/// Represents an interval during which a character was a member of a corporation and
+/// alliance. Intervals are recorded when affiliation updates from ESI find a change, so the
+/// first interval of a character starts when Bifrost first saw the affiliation.
+///
+/// # Fields (from `entity::bifrost_affiliation_history::Model`)
+/// - `id` - Primary key, unique interval identifier
+/// - `character_id` - Foreign key to the character's record
+/// - `corporation_id` - Foreign key to the corporation's record
+/// - `alliance_id` - Foreign key to the alliance's record, `None` if the corporation had no alliance
+/// - `started_at` - Timestamp when the affiliation was first seen
+/// - `ended_at` - Timestamp when a different affiliation was seen, `None` for the current one
+pub type AffiliationHistoryModel = entity::bifrost_affiliation_history::Model;
+
+/// Type alias for tag database model.
+///
+/// Represents a free-form label an admin attached to a user, character, or corporation, such
+/// as `spy` or `awox risk`. Each subject carries a tag name at most once.
+///
+/// # Fields (from `entity::bifrost_tag::Model`)
+/// - `id` - Primary key, unique tag identifier
+/// - `subject_type` - Kind of the tagged subject (`user`, `character`, or `corporation`)
+/// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID of the subject
+/// - `name` - Lowercase tag name
+/// - `created_by_user_id` - Foreign key to the user who added the tag
+/// - `created_at` - Timestamp when the tag was added
+pub type TagModel = entity::bifrost_tag::Model;
+
+/// Type alias for note database model.
+///
+/// Represents a timestamped officer note about a user, character, or corporation. Notes are
+/// visible to all officers or only to their author.
+///
+/// # Fields (from `entity::bifrost_note::Model`)
+/// - `id` - Primary key, unique note identifier
+/// - `subject_type` - Kind of the subject (`user`, `character`, or `corporation`)
+/// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID of the subject
+/// - `body` - Note text
+/// - `visibility` - Who can read the note (`officers` or `author`)
+/// - `created_by_user_id` - Foreign key to the user who wrote the note
+/// - `created_at` - Timestamp when the note was written
+pub type NoteModel = entity::bifrost_note::Model;
+
+/// Type alias for saved member filter database model.
+///
+/// Represents a named filter of the admin user or character list, saved by an admin so the
+/// same members can be listed again later. Each admin names their filters uniquely per list.
+///
+/// # Fields (from `entity::bifrost_member_filter::Model`)
+/// - `id` - Primary key, unique filter identifier
+/// - `user_id` - Foreign key to the admin who saved the filter
+/// - `list` - List the filter applies to (`users` or `characters`)
+/// - `name` - Name of the filter
+/// - `filter` - Filter criteria as JSON
+/// - `created_at` - Timestamp when the filter was first saved
+/// - `updated_at` - Timestamp when the filter was last saved
+pub type MemberFilterModel = entity::bifrost_member_filter::Model;
+
+/// Approval request model representing a sensitive admin action awaiting a second admin.
+///
+/// # Fields
+/// - `id` - Primary key, unique approval request identifier
+/// - `action` - Name of the sensitive action (e.g. `merge_users`)
+/// - `payload` - Arguments of the action as JSON
+/// - `status` - Status of the request (`pending`, `approved`, `rejected`, `expired`, or `failed`)
+/// - `requested_by_user_id` - Foreign key to the admin who requested the action
+/// - `decided_by_user_id` - ID of the admin who approved or rejected the request
+/// - `created_at` - Timestamp when the request was created
+/// - `expires_at` - Timestamp after which the request can no longer be decided
+/// - `decided_at` - Timestamp when the request was approved or rejected
+pub type ApprovalRequestModel = entity::bifrost_approval_request::Model;
+
+/// Worker job history model recording how an executed job finished.
+///
+/// # Fields
+/// - `id` - Primary key, unique history record identifier
+/// - `job_kind` - Name of the job's type (e.g. `UpdateAllianceInfo`)
+/// - `entity_id` - EVE Online ID of the entity the job refreshed, if it refreshed one
+/// - `outcome` - State the job finished in (`succeeded`, `partially_succeeded`, `failed`,
+///   `timeout`, or `queued` if it was rescheduled for a retry)
+/// - `error` - Error or failed item summary of the job
+/// - `duration_ms` - Time the job ran for, in milliseconds
+/// - `finished_at` - Timestamp when the job finished
+pub type WorkerJobHistoryModel = entity::bifrost_worker_job_history::Model;
+
+/// Onboarding step model representing an item of the checklist shown to new members.
+///
+/// # Fields
+/// - `id` - Primary key, unique step identifier
+/// - `title` - Title shown on the checklist
+/// - `description` - Instructions shown below the title
+/// - `kind` - How the step is completed (`link_alts`, `grant_scopes`, `read_page`,
+///   `join_discord`, or `manual`)
+/// - `target` - Space-separated ESI scopes, page slug, or invite URL, depending on the kind
+/// - `position` - Position of the step on the checklist, lowest first
+/// - `created_by_user_id` - Foreign key to the admin who defined the step
+/// - `created_at` - Timestamp when the step was defined
+pub type OnboardingStepModel = entity::bifrost_onboarding_step::Model;
+
+/// Onboarding completion model recording that a user completed an onboarding step.
+///
+/// Steps completed from existing data, such as linking alts, are not stored. Rows are deleted
+/// with their step or user.
+///
+/// # Fields
+/// - `id` - Primary key, unique completion identifier
+/// - `step_id` - Foreign key to the completed step
+/// - `user_id` - Foreign key to the user who completed the step
+/// - `completed_at` - Timestamp when the step was completed
+pub type OnboardingCompletionModel = entity::bifrost_onboarding_completion::Model;
+
+/// Corporation member model recording a character in a corporation's member list from ESI.
+///
+/// Only stored for corporations whose director granted Bifrost the membership scope. The
+/// character may not be known to Bifrost otherwise. Rows are deleted with their corporation.
+///
+/// # Fields
+/// - `id` - Primary key, unique member identifier
+/// - `corporation_id` - Foreign key to the corporation the character is a member of
+/// - `character_id` - EVE Online ID of the member character
+/// - `updated_at` - Timestamp when the member list last included the character
+pub type CorporationMemberModel = entity::eve_corporation_member::Model;
+
+/// Corporation member director model storing the character member lists are fetched for.
+///
+/// One director is kept per corporation. Member lists are fetched with the director's token
+/// from the character token table, and another character granting the membership scope only
+/// replaces the director once fetching with their token failed. Rows are deleted with their
+/// corporation or character.
+///
+/// # Fields
+/// - `id` - Primary key, unique director identifier
+/// - `corporation_id` - Foreign key to the corporation whose member list is tracked
+/// - `character_id` - Foreign key to the director character who granted the scope
+/// - `failed_at` - Timestamp when fetching the member list with the director's token last
+///   failed, cleared once it succeeds again
+/// - `created_at` - Timestamp when the corporation's member list was first tracked
+/// - `updated_at` - Timestamp when the director was last stored
+pub type CorporationMemberDirectorModel = entity::bifrost_corporation_member_director::Model;
+
+/// Character token model storing a character's EVE SSO refresh token.
+///
+/// One token is kept per character, replaced whenever the character logs in again. Tokens
+/// EVE SSO rejects are deleted by the `PruneRevokedTokens` worker job, and rows are deleted
+/// with their character.
+///
+/// # Fields
+/// - `id` - Primary key, unique token identifier
+/// - `character_id` - Foreign key to the character the token was issued for
+/// - `refresh_token` - Encrypted EVE SSO refresh token
+/// - `scopes` - Space-separated ESI scopes the character granted
+/// - `created_at` - Timestamp when the token was first stored for the character
+/// - `updated_at` - Timestamp when the token was last stored or rotated
+pub type CharacterTokenModel = entity::bifrost_character_token::Model;
+
+/// Character skill model recording a trained skill from a character's skill snapshot.
+///
+/// Only stored for characters whose refresh token grants the skills scope. Each refresh
+/// replaces the character's snapshot, and rows are deleted with their character.
+///
+/// # Fields
+/// - `id` - Primary key, unique skill record identifier
+/// - `character_id` - Foreign key to the character who trained the skill
+/// - `skill_id` - EVE Online type ID of the skill
+/// - `active_level` - Level the character can currently use, lower than the trained level
+///   for Alpha clones
+/// - `trained_level` - Level the character trained the skill to
+/// - `skillpoints` - Skill points the character has in the skill
+/// - `updated_at` - Timestamp when the snapshot last included the skill
+pub type CharacterSkillModel = entity::eve_character_skill::Model;
+
+/// Webhook model representing a target notified of character ownership changes.
+///
+/// # Fields
+/// - `id` - Primary key, unique webhook identifier
+/// - `name` - Name shown to admins
+/// - `url` - HTTPS URL the Discord/Slack-compatible JSON payloads are posted to
+/// - `events` - Space-separated events the webhook is notified of (`character_linked`,
+///   `character_transferred`, or `main_changed`)
+/// - `enabled` - Whether the webhook is notified, disabled webhooks are kept but skipped
+/// - `created_at` - Timestamp when the webhook was created
+/// - `updated_at` - Timestamp when the webhook was last updated
+pub type WebhookModel = entity::bifrost_webhook::Model;
+
+/// Type alias for user Discord account database model.
+///
+/// Represents the Discord account linked to a user. Each user can link one Discord account and
+/// each Discord account can only be linked to one user.
+///
+/// # Fields (from `entity::bifrost_user_discord::Model`)
+/// - `id` - Primary key, unique link identifier
+/// - `user_id` - Foreign key to the user, unique per user
+/// - `discord_id` - Discord user ID (snowflake), unique per link
+/// - `username` - Discord username as of the last time the account was linked
+/// - `linked_at` - Timestamp when the account was first linked
+/// - `updated_at` - Timestamp when the account was last linked again
+pub type UserDiscordModel = entity::bifrost_user_discord::Model;
+
+/// Role model representing a named set of admin permissions.
+///
+/// # Fields
+/// - `id` - Primary key, unique role identifier
+/// - `name` - Unique name of the role
+/// - `description` - Description shown to admins
+/// - `permissions` - Space-separated permissions the role grants (`admin`, `manage_roles`,
+///   `manage_members`, `manage_content`, `manage_integrations`, or `decide_approvals`)
+/// - `built_in` - Whether the role is seeded by the application (`admin` and `member`), built-in
+///   roles can't be renamed or deleted
+/// - `created_at` - Timestamp when the role was created
+/// - `updated_at` - Timestamp when the role was last updated
+pub type RoleModel = entity::bifrost_role::Model;
+
+/// Ban model representing a suspension of a user.
+///
+/// # Fields
+/// - `id` - Primary key, unique ban identifier
+/// - `user_id` - Foreign key to the suspended user
+/// - `reason` - Reason shown to the user and admins
+/// - `issued_by_user_id` - ID of the admin who issued the ban, `None` if issued by Bifrost
+/// - `created_at` - Timestamp when the ban was issued
+/// - `expires_at` - Timestamp when the ban ends, `None` for permanent bans
+/// - `lifted_at` - Timestamp when an admin lifted the ban early, `None` if not lifted
+/// - `lifted_by_user_id` - ID of the admin who lifted the ban, `None` if not lifted
+pub type UserBanModel = entity::bifrost_user_ban::Model;
+
+/// Group model representing a group users can be members of.
+///
+/// # Fields
+/// - `id` - Primary key, unique group identifier
+/// - `name` - Unique name of the group
+/// - `description` - Description shown to users and admins
+/// - `join_policy` - How users join the group (`open`, `request`, or `hidden`)
+/// - `created_at` - Timestamp when the group was created
+/// - `updated_at` - Timestamp when the group was last updated
+pub type GroupModel = entity::bifrost_group::Model;
+
+/// Group member model representing a user's membership or pending request to join a group.
+///
+/// # Fields
+/// - `id` - Primary key, unique membership identifier
+/// - `group_id` - Foreign key to the group
+/// - `user_id` - Foreign key to the member
+/// - `status` - Whether the user is a `member` or their request is `pending`
+/// - `created_at` - Timestamp when the user joined or requested to join
+/// - `decided_at` - Timestamp when an admin approved the request or added the user, `None` if
+///   the user joined an open group or the request is pending
+/// - `decided_by_user_id` - ID of the admin who approved the request or added the user
+pub type GroupMemberModel = entity::bifrost_group_member::Model;
+
+/// Group rule model representing an affiliation that qualifies users for a group.
+///
+/// # Fields
+/// - `id` - Primary key, unique rule identifier
+/// - `group_id` - Foreign key to the group
+/// - `kind` - What the rule matches (`corporation`, `alliance`, or `faction`)
+/// - `entity_id` - EVE Online ID of the corporation, alliance, or faction
+/// - `created_at` - Timestamp when the rule was added
+pub type GroupRuleModel = entity::bifrost_group_rule::Model;
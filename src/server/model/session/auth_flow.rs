@@ -0,0 +1,435 @@
+//! EVE Online SSO login flow session data models.
+//!
+//! This module provides the `AuthFlowState` tracking a login from the login endpoint to the
+//! OAuth callback under a single session key. The CSRF state token, login intent, requested
+//! scopes, post-login redirect, and "remember me" choice are stored together when a login is
+//! initiated, so a callback always sees the parameters of the login it belongs to and nothing
+//! left behind by an earlier, abandoned login.
+//!
+//! # Transitions
+//!
+//! A flow moves from `Initiated` to `CallbackPending` once the callback's CSRF state matches,
+//! and to `Completed` once the callback handled the character. Starting a new login replaces
+//! the flow in any state. Any other transition, such as a replayed callback reaching a
+//! completed flow, is rejected, as are callbacks arriving after the flow expired.
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+
+use crate::server::{
+    error::{auth::AuthError, AppError},
+    model::session::login_intent::LoginIntent,
+};
+
+/// Session key for storing the login flow.
+///
+/// The key is namespaced under "bifrost:auth:" to avoid collisions with other session data.
+pub const SESSION_AUTH_FLOW_KEY: &str = "bifrost:auth:flow";
+
+/// Minutes a login has to reach the OAuth callback before its flow expires.
+pub const AUTH_FLOW_EXPIRY_MINUTES: i64 = 15;
+
+/// Parameters of a login, stored when it is initiated and used by its callback.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AuthFlow {
+    /// CSRF state token the callback's `state` parameter must match.
+    pub csrf: String,
+    /// Purpose of the login.
+    pub intent: LoginIntent,
+    /// ESI scopes requested from EVE SSO, which the character must grant.
+    pub scopes: Vec<String>,
+    /// Validated internal path to redirect to after the callback.
+    pub redirect: Option<String>,
+    /// Whether the session logged in by the callback is remembered.
+    pub remember_me: bool,
+}
+
+/// State of the login flow stored in the session.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum AuthFlowState {
+    /// The login endpoint redirected the user to EVE SSO.
+    Initiated {
+        /// Parameters of the login.
+        flow: AuthFlow,
+        /// When the flow stops accepting its callback.
+        expires_at: NaiveDateTime,
+    },
+    /// The callback validated the CSRF state and is handling the character.
+    CallbackPending {
+        /// Parameters of the login.
+        flow: AuthFlow,
+        /// When the flow stops accepting its callback.
+        expires_at: NaiveDateTime,
+    },
+    /// The callback handled the character.
+    Completed {
+        /// When the callback completed.
+        completed_at: NaiveDateTime,
+    },
+}
+
+impl AuthFlowState {
+    /// Starts a flow for a newly initiated login.
+    ///
+    /// # Arguments
+    /// - `flow` - Parameters of the login
+    /// - `now` - Current time the expiry is counted from
+    ///
+    /// # Returns
+    /// - `AuthFlowState::Initiated` - Flow expiring after `AUTH_FLOW_EXPIRY_MINUTES`
+    pub fn initiate(flow: AuthFlow, now: NaiveDateTime) -> Self {
+        Self::Initiated {
+            flow,
+            expires_at: now + Duration::minutes(AUTH_FLOW_EXPIRY_MINUTES),
+        }
+    }
+
+    /// Returns the name of the state for errors and logs.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Initiated { .. } => "Initiated",
+            Self::CallbackPending { .. } => "CallbackPending",
+            Self::Completed { .. } => "Completed",
+        }
+    }
+
+    /// Moves an initiated flow to `CallbackPending` if the callback's CSRF state matches.
+    ///
+    /// # Arguments
+    /// - `csrf_state` - CSRF state token from the OAuth callback URL parameters
+    /// - `now` - Current time the expiry is checked against
+    ///
+    /// # Returns
+    /// - `Ok((AuthFlowState, AuthFlow))` - Pending flow and the parameters of the login
+    /// - `Err(AuthError::UnexpectedAuthFlowTransition)` - Flow isn't `Initiated`
+    /// - `Err(AuthError::AuthFlowExpired)` - Flow expired before the callback
+    /// - `Err(AuthError::CsrfValidationFailed)` - CSRF state doesn't match
+    pub fn begin_callback(
+        self,
+        csrf_state: &str,
+        now: NaiveDateTime,
+    ) -> Result<(Self, AuthFlow), AuthError> {
+        let (flow, expires_at) = match self {
+            Self::Initiated { flow, expires_at } => (flow, expires_at),
+            _ => {
+                return Err(AuthError::UnexpectedAuthFlowTransition {
+                    from: self.name(),
+                    to: "CallbackPending",
+                })
+            }
+        };
+
+        if now >= expires_at {
+            return Err(AuthError::AuthFlowExpired);
+        }
+        if flow.csrf != csrf_state {
+            return Err(AuthError::CsrfValidationFailed);
+        }
+
+        Ok((
+            Self::CallbackPending {
+                flow: flow.clone(),
+                expires_at,
+            },
+            flow,
+        ))
+    }
+
+    /// Moves a pending flow to `Completed`.
+    ///
+    /// # Arguments
+    /// - `now` - Current time recorded as the completion time
+    ///
+    /// # Returns
+    /// - `Ok(AuthFlowState::Completed)` - Completed flow
+    /// - `Err(AuthError::UnexpectedAuthFlowTransition)` - Flow isn't `CallbackPending`
+    pub fn complete(self, now: NaiveDateTime) -> Result<Self, AuthError> {
+        match self {
+            Self::CallbackPending { .. } => Ok(Self::Completed { completed_at: now }),
+            _ => Err(AuthError::UnexpectedAuthFlowTransition {
+                from: self.name(),
+                to: "Completed",
+            }),
+        }
+    }
+}
+
+/// Session wrapper for the login flow.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SessionAuthFlow(pub AuthFlowState);
+
+impl SessionAuthFlow {
+    /// Starts a login flow in the session.
+    ///
+    /// Replaces the flow of any earlier login, whatever state it reached.
+    ///
+    /// # Arguments
+    /// - `session` - User's session for storing the flow
+    /// - `flow` - Parameters of the login being initiated
+    ///
+    /// # Returns
+    /// - `Ok(())` - Flow stored in session as `Initiated`
+    /// - `Err(AppError)` - Session storage failed (Redis error, serialization error)
+    pub async fn initiate(session: &Session, flow: AuthFlow) -> Result<(), AppError> {
+        let state = AuthFlowState::initiate(flow, Utc::now().naive_utc());
+        session
+            .insert(SESSION_AUTH_FLOW_KEY, SessionAuthFlow(state))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Validates the callback's CSRF state and marks the flow as awaiting completion.
+    ///
+    /// The flow is removed from the session if validation fails, so a rejected callback can't
+    /// be retried against the same login.
+    ///
+    /// # Arguments
+    /// - `session` - User's session containing the flow
+    /// - `csrf_state` - CSRF state token from the OAuth callback URL parameters
+    ///
+    /// # Returns
+    /// - `Ok(AuthFlow)` - Parameters of the login the callback belongs to
+    /// - `Err(AppError::Auth(AuthError::CsrfMissingValue))` - No login flow in session
+    /// - `Err(AppError::Auth(AuthError::CsrfValidationFailed))` - CSRF state doesn't match
+    /// - `Err(AppError::Auth(AuthError::AuthFlowExpired))` - Flow expired before the callback
+    /// - `Err(AppError::Auth(AuthError::UnexpectedAuthFlowTransition))` - Callback for a flow
+    ///   that already reached it
+    /// - `Err(AppError)` - Session operation failed (Redis error)
+    pub async fn begin_callback(session: &Session, csrf_state: &str) -> Result<AuthFlow, AppError> {
+        let Some(SessionAuthFlow(state)) = session.remove(SESSION_AUTH_FLOW_KEY).await? else {
+            return Err(AuthError::CsrfMissingValue.into());
+        };
+
+        let (state, flow) = state.begin_callback(csrf_state, Utc::now().naive_utc())?;
+        session
+            .insert(SESSION_AUTH_FLOW_KEY, SessionAuthFlow(state))
+            .await?;
+
+        Ok(flow)
+    }
+
+    /// Marks the flow whose callback is being handled as completed.
+    ///
+    /// # Arguments
+    /// - `session` - User's session containing the flow
+    ///
+    /// # Returns
+    /// - `Ok(())` - Flow stored in session as `Completed`
+    /// - `Err(AppError::Auth(AuthError::UnexpectedAuthFlowTransition))` - No callback of the
+    ///   flow is being handled
+    /// - `Err(AppError)` - Session operation failed (Redis error, serialization error)
+    pub async fn complete(session: &Session) -> Result<(), AppError> {
+        let state = match Self::get(session).await? {
+            Some(state) => state.complete(Utc::now().naive_utc())?,
+            None => {
+                return Err(AuthError::UnexpectedAuthFlowTransition {
+                    from: "None",
+                    to: "Completed",
+                }
+                .into())
+            }
+        };
+        session
+            .insert(SESSION_AUTH_FLOW_KEY, SessionAuthFlow(state))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the login flow from the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to retrieve the flow from
+    ///
+    /// # Returns
+    /// - `Ok(Some(AuthFlowState))` - A login flow is in session
+    /// - `Ok(None)` - No login was initiated in this session
+    /// - `Err(AppError)` - Session retrieval failed (Redis error)
+    pub async fn get(session: &Session) -> Result<Option<AuthFlowState>, AppError> {
+        let flow: Option<SessionAuthFlow> = session.get(SESSION_AUTH_FLOW_KEY).await?;
+
+        Ok(flow.map(|flow| flow.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flow(csrf: &str) -> AuthFlow {
+        AuthFlow {
+            csrf: csrf.to_string(),
+            intent: LoginIntent::ChangeMain,
+            scopes: vec!["esi-assets.read_assets.v1".to_string()],
+            redirect: Some("/auth/admin".to_string()),
+            remember_me: true,
+        }
+    }
+
+    fn now() -> NaiveDateTime {
+        Utc::now().naive_utc()
+    }
+
+    mod begin_callback {
+        use super::*;
+
+        /// Tests that an initiated flow accepts the callback with its CSRF state.
+        ///
+        /// Expected: Ok with a CallbackPending flow and the login's parameters
+        #[test]
+        fn accepts_matching_csrf_state() {
+            let state = AuthFlowState::initiate(flow("state"), now());
+
+            let (state, login) = state.begin_callback("state", now()).unwrap();
+
+            assert_eq!(login, flow("state"));
+            assert_eq!(state.name(), "CallbackPending");
+        }
+
+        /// Tests that a callback with another CSRF state is rejected.
+        ///
+        /// Expected: Err(CsrfValidationFailed)
+        #[test]
+        fn fails_for_csrf_mismatch() {
+            let state = AuthFlowState::initiate(flow("state"), now());
+
+            let result = state.begin_callback("other", now());
+
+            assert!(matches!(result, Err(AuthError::CsrfValidationFailed)));
+        }
+
+        /// Tests that a callback after the flow expired is rejected.
+        ///
+        /// Expected: Err(AuthFlowExpired)
+        #[test]
+        fn fails_for_expired_flow() {
+            let started = now() - Duration::minutes(AUTH_FLOW_EXPIRY_MINUTES + 1);
+            let state = AuthFlowState::initiate(flow("state"), started);
+
+            let result = state.begin_callback("state", now());
+
+            assert!(matches!(result, Err(AuthError::AuthFlowExpired)));
+        }
+
+        /// Tests that a replayed callback of a completed flow is rejected.
+        ///
+        /// Expected: Err(UnexpectedAuthFlowTransition) from Completed
+        #[test]
+        fn fails_for_completed_flow() {
+            let state = AuthFlowState::Completed {
+                completed_at: now(),
+            };
+
+            let result = state.begin_callback("state", now());
+
+            assert!(matches!(
+                result,
+                Err(AuthError::UnexpectedAuthFlowTransition {
+                    from: "Completed",
+                    ..
+                })
+            ));
+        }
+    }
+
+    mod complete {
+        use super::*;
+
+        /// Tests that a flow can't complete before its callback was validated.
+        ///
+        /// Expected: Err(UnexpectedAuthFlowTransition) from Initiated
+        #[test]
+        fn fails_for_initiated_flow() {
+            let state = AuthFlowState::initiate(flow("state"), now());
+
+            let result = state.complete(now());
+
+            assert!(matches!(
+                result,
+                Err(AuthError::UnexpectedAuthFlowTransition {
+                    from: "Initiated",
+                    ..
+                })
+            ));
+        }
+    }
+
+    mod session_auth_flow {
+        use super::*;
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests a login flow through every state in the session.
+        ///
+        /// Expected: Login parameters returned once, flow Completed, replayed callback rejected
+        #[tokio::test]
+        async fn moves_through_states() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionAuthFlow::initiate(&test.session, flow("state"))
+                .await
+                .unwrap();
+            let login = SessionAuthFlow::begin_callback(&test.session, "state")
+                .await
+                .unwrap();
+            SessionAuthFlow::complete(&test.session).await.unwrap();
+            let replay = SessionAuthFlow::begin_callback(&test.session, "state").await;
+
+            assert_eq!(login, flow("state"));
+            assert!(matches!(
+                replay,
+                Err(AppError::Auth(AuthError::UnexpectedAuthFlowTransition { .. }))
+            ));
+
+            Ok(())
+        }
+
+        /// Tests that initiating a login replaces the flow of an abandoned one.
+        ///
+        /// Expected: Callback of the earlier login rejected, parameters of the latest returned
+        #[tokio::test]
+        async fn replaces_abandoned_flow() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionAuthFlow::initiate(&test.session, flow("first"))
+                .await
+                .unwrap();
+            SessionAuthFlow::initiate(&test.session, AuthFlow::default())
+                .await
+                .unwrap();
+            let login = SessionAuthFlow::begin_callback(&test.session, "")
+                .await
+                .unwrap();
+
+            assert_eq!(login, AuthFlow::default());
+
+            Ok(())
+        }
+
+        /// Tests that a rejected callback removes the flow.
+        ///
+        /// Expected: Err(CsrfValidationFailed), then Err(CsrfMissingValue) for a retry
+        #[tokio::test]
+        async fn removes_flow_after_rejected_callback() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionAuthFlow::initiate(&test.session, flow("state"))
+                .await
+                .unwrap();
+            let mismatch = SessionAuthFlow::begin_callback(&test.session, "other").await;
+            let retry = SessionAuthFlow::begin_callback(&test.session, "state").await;
+
+            assert!(matches!(
+                mismatch,
+                Err(AppError::Auth(AuthError::CsrfValidationFailed))
+            ));
+            assert!(matches!(
+                retry,
+                Err(AppError::Auth(AuthError::CsrfMissingValue))
+            ));
+
+            Ok(())
+        }
+    }
+}
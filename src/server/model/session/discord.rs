@@ -0,0 +1,199 @@
+//! Discord account linking session data models.
+//!
+//! This module provides type-safe wrappers for the session state of the Discord OAuth flow,
+//! mirroring the EVE SSO flow in `auth`. The CSRF token is generated when linking starts,
+//! stored in the session, and validated during the Discord callback. The page to return to
+//! after linking is stored alongside it. Both are kept under separate keys so a Discord link
+//! started in one tab doesn't invalidate an EVE login started in another.
+
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+
+use crate::server::error::{auth::AuthError, AppError};
+
+/// Session key for storing the Discord CSRF state token.
+///
+/// The key is namespaced under "bifrost:auth:discord:" to avoid collisions with the EVE SSO
+/// flow and other session data.
+pub const SESSION_DISCORD_CSRF_KEY: &str = "bifrost:auth:discord:csrf";
+
+/// Session key for storing the path to return to after linking a Discord account.
+///
+/// The key is namespaced under "bifrost:auth:discord:" to avoid collisions with the EVE SSO
+/// flow and other session data.
+pub const SESSION_DISCORD_REDIRECT_KEY: &str = "bifrost:auth:discord:redirect";
+
+/// Session wrapper for the Discord CSRF state token.
+///
+/// The token is randomly generated when linking starts and validated during the Discord
+/// callback to ensure the request originated from this application.
+#[derive(Default, Deserialize, Serialize, Debug)]
+pub struct SessionDiscordCsrf(pub String);
+
+impl SessionDiscordCsrf {
+    /// Inserts the Discord CSRF state token into the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session for storing the CSRF token
+    /// - `state` - CSRF state token to store (randomly generated string)
+    ///
+    /// # Returns
+    /// - `Ok(())` - CSRF token successfully stored in session
+    /// - `Err(AppError)` - Session storage failed (Redis error, serialization error)
+    pub async fn insert(session: &Session, state: &str) -> Result<(), AppError> {
+        session
+            .insert(
+                SESSION_DISCORD_CSRF_KEY,
+                SessionDiscordCsrf(state.to_string()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes the Discord CSRF state token from the session and validates it.
+    ///
+    /// The token is removed whether or not it matches, so it can only be used once.
+    ///
+    /// # Arguments
+    /// - `session` - User's session containing the stored CSRF token
+    /// - `csrf_state` - CSRF state token from the Discord callback URL parameters
+    ///
+    /// # Returns
+    /// - `Ok(())` - CSRF state matches the session value
+    /// - `Err(AppError::Auth(AuthError::CsrfValidationFailed))` - State mismatch or not found in
+    ///   session
+    /// - `Err(AppError)` - Session operation failed (Redis error)
+    pub async fn validate(session: &Session, csrf_state: &str) -> Result<(), AppError> {
+        let stored: Option<SessionDiscordCsrf> = session.remove(SESSION_DISCORD_CSRF_KEY).await?;
+
+        match stored {
+            Some(stored) if stored.0 == csrf_state => Ok(()),
+            _ => Err(AuthError::CsrfValidationFailed.into()),
+        }
+    }
+}
+
+/// Session wrapper for the path to return to after linking a Discord account.
+///
+/// The path must be validated against the redirect allowlist before insertion, as the
+/// callback redirects to it without further checks.
+#[derive(Default, Deserialize, Serialize, Debug)]
+pub struct SessionDiscordRedirect(pub String);
+
+impl SessionDiscordRedirect {
+    /// Inserts the post-linking redirect path into the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session for storing the redirect path
+    /// - `path` - Validated internal path to redirect to after the Discord callback
+    ///
+    /// # Returns
+    /// - `Ok(())` - Redirect path successfully stored in session
+    /// - `Err(AppError)` - Session storage failed (Redis error, serialization error)
+    pub async fn insert(session: &Session, path: &str) -> Result<(), AppError> {
+        session
+            .insert(
+                SESSION_DISCORD_REDIRECT_KEY,
+                SessionDiscordRedirect(path.to_string()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes and returns the post-linking redirect path from the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to remove the redirect path from
+    ///
+    /// # Returns
+    /// - `Ok(Some(String))` - Redirect path found, removed, and returned
+    /// - `Ok(None)` - No redirect path in session
+    /// - `Err(AppError)` - Session operation failed (Redis error)
+    pub async fn remove(session: &Session) -> Result<Option<String>, AppError> {
+        let redirect: Option<SessionDiscordRedirect> =
+            session.remove(SESSION_DISCORD_REDIRECT_KEY).await?;
+
+        Ok(redirect.map(|redirect| redirect.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod validate {
+        use super::*;
+        use crate::server::model::session::auth_flow::{AuthFlow, SessionAuthFlow};
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests validating the stored Discord CSRF state.
+        ///
+        /// Expected: Ok(()) for the matching state
+        #[tokio::test]
+        async fn validates_matching_state() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionDiscordCsrf::insert(&test.session, "state")
+                .await
+                .unwrap();
+            let result = SessionDiscordCsrf::validate(&test.session, "state").await;
+
+            assert!(result.is_ok());
+
+            Ok(())
+        }
+
+        /// Tests that a mismatched state is rejected and can't be retried.
+        ///
+        /// Expected: Err(CsrfValidationFailed) for both the mismatch and the retry
+        #[tokio::test]
+        async fn fails_for_mismatch_and_removes_state() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionDiscordCsrf::insert(&test.session, "state")
+                .await
+                .unwrap();
+            let mismatch = SessionDiscordCsrf::validate(&test.session, "other").await;
+            let retry = SessionDiscordCsrf::validate(&test.session, "state").await;
+
+            assert!(matches!(
+                mismatch,
+                Err(AppError::Auth(AuthError::CsrfValidationFailed))
+            ));
+            assert!(matches!(
+                retry,
+                Err(AppError::Auth(AuthError::CsrfValidationFailed))
+            ));
+
+            Ok(())
+        }
+
+        /// Tests that the EVE SSO CSRF state isn't accepted for the Discord flow.
+        ///
+        /// Expected: Err(CsrfValidationFailed)
+        #[tokio::test]
+        async fn ignores_eve_sso_state() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionAuthFlow::initiate(
+                &test.session,
+                AuthFlow {
+                    csrf: "state".to_string(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            let result = SessionDiscordCsrf::validate(&test.session, "state").await;
+
+            assert!(matches!(
+                result,
+                Err(AppError::Auth(AuthError::CsrfValidationFailed))
+            ));
+
+            Ok(())
+        }
+    }
+}
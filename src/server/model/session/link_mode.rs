@@ -0,0 +1,209 @@
+//! Character linking mode session data models.
+//!
+//! This module provides a type-safe wrapper for storing the character linking mode in the
+//! session. While linking mode is active, every OAuth callback links the authenticated
+//! character to the current user and records the outcome, allowing users with many alts to
+//! add them through consecutive logins without returning to the dashboard in between.
+
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+
+use crate::{model::user::LinkedCharacterDto, server::error::AppError};
+
+/// Session key for storing the character linking mode.
+///
+/// This constant defines the Redis key used to store the linking mode and the results of
+/// each character linked while it is active. The key is namespaced under "bifrost:user:" to
+/// avoid collisions with other session data.
+pub const SESSION_USER_LINK_MODE_KEY: &str = "bifrost:user:link_mode";
+
+/// Session wrapper for the character linking mode.
+///
+/// Linking mode is active while this value is present in the session. It holds the result of
+/// every callback processed since the mode started, oldest first.
+#[derive(Default, Deserialize, Serialize, Debug)]
+pub struct SessionUserLinkMode(pub Vec<LinkedCharacterDto>);
+
+impl SessionUserLinkMode {
+    /// Activates linking mode in the session.
+    ///
+    /// Starting linking mode while it is already active keeps the results recorded so far.
+    ///
+    /// # Arguments
+    /// - `session` - User's session for storing the linking mode
+    ///
+    /// # Returns
+    /// - `Ok(())` - Linking mode is active
+    /// - `Err(AppError)` - Session operation failed (Redis error, serialization error)
+    pub async fn start(session: &Session) -> Result<(), AppError> {
+        if Self::get(session).await?.is_none() {
+            session
+                .insert(SESSION_USER_LINK_MODE_KEY, SessionUserLinkMode::default())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the results recorded while linking mode is active.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to retrieve the linking mode from
+    ///
+    /// # Returns
+    /// - `Ok(Some(Vec<LinkedCharacterDto>))` - Linking mode is active with the recorded results
+    /// - `Ok(None)` - Linking mode is not active
+    /// - `Err(AppError)` - Session retrieval failed (Redis error)
+    pub async fn get(session: &Session) -> Result<Option<Vec<LinkedCharacterDto>>, AppError> {
+        let link_mode: Option<SessionUserLinkMode> =
+            session.get(SESSION_USER_LINK_MODE_KEY).await?;
+
+        Ok(link_mode.map(|link_mode| link_mode.0))
+    }
+
+    /// Records the result of a callback processed while linking mode is active.
+    ///
+    /// Does nothing if linking mode is not active.
+    ///
+    /// # Arguments
+    /// - `session` - User's session containing the linking mode
+    /// - `result` - Outcome of linking the authenticated character
+    ///
+    /// # Returns
+    /// - `Ok(())` - Result recorded, or linking mode not active
+    /// - `Err(AppError)` - Session operation failed (Redis error, serialization error)
+    pub async fn push(session: &Session, result: LinkedCharacterDto) -> Result<(), AppError> {
+        if let Some(mut results) = Self::get(session).await? {
+            results.push(result);
+
+            session
+                .insert(SESSION_USER_LINK_MODE_KEY, SessionUserLinkMode(results))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Exits linking mode, returning the results recorded while it was active.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to remove the linking mode from
+    ///
+    /// # Returns
+    /// - `Ok(Some(Vec<LinkedCharacterDto>))` - Linking mode exited with the recorded results
+    /// - `Ok(None)` - Linking mode was not active
+    /// - `Err(AppError)` - Session operation failed (Redis error)
+    pub async fn remove(session: &Session) -> Result<Option<Vec<LinkedCharacterDto>>, AppError> {
+        let link_mode: Option<SessionUserLinkMode> =
+            session.remove(SESSION_USER_LINK_MODE_KEY).await?;
+
+        Ok(link_mode.map(|link_mode| link_mode.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod start {
+        use super::*;
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests that starting linking mode activates it with no results.
+        ///
+        /// Expected: Ok(Some(empty))
+        #[tokio::test]
+        async fn activates_link_mode() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionUserLinkMode::start(&test.session).await.unwrap();
+
+            let results = SessionUserLinkMode::get(&test.session).await.unwrap();
+            assert_eq!(results, Some(Vec::new()));
+
+            Ok(())
+        }
+
+        /// Tests that starting linking mode again keeps recorded results.
+        ///
+        /// Expected: Ok(Some) with the result recorded before the restart
+        #[tokio::test]
+        async fn keeps_results_when_already_active() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+            let result = LinkedCharacterDto {
+                character_id: Some(1),
+                character_name: Some("Alt".to_string()),
+                linked: true,
+            };
+
+            SessionUserLinkMode::start(&test.session).await.unwrap();
+            SessionUserLinkMode::push(&test.session, result.clone())
+                .await
+                .unwrap();
+            SessionUserLinkMode::start(&test.session).await.unwrap();
+
+            let results = SessionUserLinkMode::get(&test.session).await.unwrap();
+            assert_eq!(results, Some(vec![result]));
+
+            Ok(())
+        }
+    }
+
+    mod push {
+        use super::*;
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests that results are not recorded when linking mode is not active.
+        ///
+        /// Expected: Ok(None) after pushing a result
+        #[tokio::test]
+        async fn ignores_result_when_inactive() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+            let result = LinkedCharacterDto {
+                character_id: None,
+                character_name: None,
+                linked: false,
+            };
+
+            SessionUserLinkMode::push(&test.session, result)
+                .await
+                .unwrap();
+
+            let results = SessionUserLinkMode::get(&test.session).await.unwrap();
+            assert!(results.is_none());
+
+            Ok(())
+        }
+    }
+
+    mod remove {
+        use super::*;
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests that exiting linking mode returns the results and deactivates it.
+        ///
+        /// Expected: Ok(Some) with the recorded result, then Ok(None)
+        #[tokio::test]
+        async fn returns_results_and_deactivates() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+            let result = LinkedCharacterDto {
+                character_id: Some(1),
+                character_name: Some("Alt".to_string()),
+                linked: true,
+            };
+
+            SessionUserLinkMode::start(&test.session).await.unwrap();
+            SessionUserLinkMode::push(&test.session, result.clone())
+                .await
+                .unwrap();
+
+            let removed = SessionUserLinkMode::remove(&test.session).await.unwrap();
+            assert_eq!(removed, Some(vec![result]));
+
+            let results = SessionUserLinkMode::get(&test.session).await.unwrap();
+            assert!(results.is_none());
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Character link queue session data models.
+//!
+//! This module provides a type-safe wrapper for storing the queue of characters a user is
+//! linking in one go. The login endpoint queues the number of characters requested with its
+//! `link_count` parameter, and while characters remain queued every OAuth callback sends the
+//! user straight back to EVE Online SSO for the next character instead of returning to the
+//! linking page. Queued linking always runs in linking mode, so the outcome of each callback is
+//! recorded in `SessionUserLinkMode`.
+
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+
+use crate::server::error::AppError;
+
+/// Session key for storing the character link queue.
+///
+/// The key is namespaced under "bifrost:user:" to avoid collisions with other session data.
+pub const SESSION_USER_LINK_QUEUE_KEY: &str = "bifrost:user:link_queue";
+
+/// Maximum number of characters that can be queued for linking at once.
+pub const MAX_LINK_QUEUE_LENGTH: u32 = 100;
+
+/// Session wrapper for the character link queue.
+///
+/// Holds the number of characters still to be linked after the login in progress. The queue
+/// is active while this value is present in the session.
+#[derive(Default, Deserialize, Serialize, Debug)]
+pub struct SessionUserLinkQueue(pub u32);
+
+impl SessionUserLinkQueue {
+    /// Queues characters to link after the login in progress.
+    ///
+    /// Replaces any queue left over from an earlier, abandoned run.
+    ///
+    /// # Arguments
+    /// - `session` - User's session for storing the queue
+    /// - `remaining` - Number of characters to link after the login in progress
+    ///
+    /// # Returns
+    /// - `Ok(())` - Queue stored in the session
+    /// - `Err(AppError)` - Session operation failed (Redis error, serialization error)
+    pub async fn insert(session: &Session, remaining: u32) -> Result<(), AppError> {
+        session
+            .insert(SESSION_USER_LINK_QUEUE_KEY, SessionUserLinkQueue(remaining))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the number of characters still queued for linking.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to retrieve the queue from
+    ///
+    /// # Returns
+    /// - `Ok(Some(u32))` - Queue is active with the number of characters remaining
+    /// - `Ok(None)` - No characters are being linked in a queue
+    /// - `Err(AppError)` - Session retrieval failed (Redis error)
+    pub async fn get(session: &Session) -> Result<Option<u32>, AppError> {
+        let queue: Option<SessionUserLinkQueue> = session.get(SESSION_USER_LINK_QUEUE_KEY).await?;
+
+        Ok(queue.map(|queue| queue.0))
+    }
+
+    /// Takes the next character off the queue once a callback was processed.
+    ///
+    /// The queue is removed from the session once no characters remain.
+    ///
+    /// # Arguments
+    /// - `session` - User's session containing the queue
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Another character is queued, and the user should be sent back to EVE
+    ///   Online SSO
+    /// - `Ok(false)` - The queue is exhausted or wasn't active
+    /// - `Err(AppError)` - Session operation failed (Redis error, serialization error)
+    pub async fn advance(session: &Session) -> Result<bool, AppError> {
+        match Self::get(session).await? {
+            Some(remaining) if remaining > 0 => {
+                Self::insert(session, remaining - 1).await?;
+
+                Ok(true)
+            }
+            Some(_) => {
+                Self::remove(session).await?;
+
+                Ok(false)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Removes the queue from the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to remove the queue from
+    ///
+    /// # Returns
+    /// - `Ok(Some(u32))` - Queue removed with the number of characters that were remaining
+    /// - `Ok(None)` - No queue was active
+    /// - `Err(AppError)` - Session operation failed (Redis error)
+    pub async fn remove(session: &Session) -> Result<Option<u32>, AppError> {
+        let queue: Option<SessionUserLinkQueue> =
+            session.remove(SESSION_USER_LINK_QUEUE_KEY).await?;
+
+        Ok(queue.map(|queue| queue.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod advance {
+        use super::*;
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests that advancing counts down the queue and removes it once exhausted.
+        ///
+        /// Expected: Ok(true) while characters remain, then Ok(false) with the queue removed
+        #[tokio::test]
+        async fn counts_down_and_removes_queue() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionUserLinkQueue::insert(&test.session, 2)
+                .await
+                .unwrap();
+
+            assert!(SessionUserLinkQueue::advance(&test.session).await.unwrap());
+            assert_eq!(
+                SessionUserLinkQueue::get(&test.session).await.unwrap(),
+                Some(1)
+            );
+            assert!(SessionUserLinkQueue::advance(&test.session).await.unwrap());
+            assert!(!SessionUserLinkQueue::advance(&test.session).await.unwrap());
+            assert_eq!(
+                SessionUserLinkQueue::get(&test.session).await.unwrap(),
+                None
+            );
+
+            Ok(())
+        }
+
+        /// Tests that advancing without a queue does nothing.
+        ///
+        /// Expected: Ok(false) with no queue stored
+        #[tokio::test]
+        async fn ignores_missing_queue() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            assert!(!SessionUserLinkQueue::advance(&test.session).await.unwrap());
+            assert_eq!(
+                SessionUserLinkQueue::get(&test.session).await.unwrap(),
+                None
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Login intent session data models.
+//!
+//! This module provides the `LoginIntent` describing why a user started the EVE Online SSO
+//! flow. The intent is stored in the session as part of the login's `AuthFlow` during login
+//! initiation and used by the OAuth callback, replacing separate per-flow session flags that
+//! could be left behind or combined in unintended ways.
+
+use serde::{Deserialize, Serialize};
+
+/// Purpose of an EVE Online SSO login, determining how the callback treats the character.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum LoginIntent {
+    /// Log in with the character.
+    ///
+    /// Creates a new user for unknown characters when no user is logged in. If a user is
+    /// already logged in, the character is linked to them.
+    #[default]
+    Login,
+    /// Link the character to the logged in user as an alt.
+    LinkAlt,
+    /// Link the character to the logged in user and make it their main character.
+    ChangeMain,
+    /// Grant additional ESI scopes for a character of the logged in user.
+    ///
+    /// The callback fails if the character did not grant every requested scope.
+    AddScopes {
+        /// ESI scopes requested during login.
+        scopes: Vec<String>,
+    },
+    /// Re-authenticate a character the logged in user already owns.
+    ///
+    /// The callback fails rather than linking or transferring characters the user does
+    /// not own.
+    Reauth,
+}
+
+impl LoginIntent {
+    /// Returns whether the intent requires a user to already be logged in.
+    ///
+    /// Only plain logins may create a new user; every other intent acts on the logged in
+    /// user's characters.
+    ///
+    /// # Returns
+    /// - `true` - The callback must fail if no user is in session
+    /// - `false` - The callback may create a new user
+    pub fn requires_user(&self) -> bool {
+        !matches!(self, LoginIntent::Login)
+    }
+}
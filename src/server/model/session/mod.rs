@@ -1,10 +1,16 @@
 //! Session data models and utilities.
 //!
 //! This module provides type-safe wrappers for session data storage and retrieval using
-//! tower-sessions. Each submodule defines a specific piece of session state (user ID,
-//! CSRF tokens, flags) with methods for inserting, retrieving, and removing data from
-//! the session store (Redis-backed).
+//! tower-sessions. Each submodule defines a specific piece of session state (user ID, the EVE
+//! Online SSO login flow, login intent, linking mode, queues of characters to link, Discord account
+//! linking, pending character transfers, remember me) with methods for inserting, retrieving, and
+//! removing data from the session store (Redis-backed).
 
-pub mod auth;
-pub mod change_main;
+pub mod auth_flow;
+pub mod discord;
+pub mod link_mode;
+pub mod link_queue;
+pub mod login_intent;
+pub mod remember_me;
+pub mod transfer;
 pub mod user;
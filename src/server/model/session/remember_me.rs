@@ -0,0 +1,101 @@
+//! Remember-me session data models.
+//!
+//! This module provides a type-safe wrapper marking a session as remembered. The login endpoint
+//! records the "remember me" choice in the login's `AuthFlow`, and the OAuth callback marks the
+//! logged in session once authentication succeeds. Remembered sessions stay valid for the
+//! longer `SESSION_REMEMBER_ME_DAYS` of inactivity instead of `SESSION_INACTIVITY_DAYS`.
+
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+
+use crate::server::error::AppError;
+
+/// Session key marking a logged in session as remembered.
+///
+/// The key is namespaced under "bifrost:user:" to avoid collisions with other session data.
+pub const SESSION_USER_REMEMBER_ME_KEY: &str = "bifrost:user:remember_me";
+
+/// Session wrapper marking a logged in session as remembered.
+///
+/// The session is remembered while this value is present and `true`. Logging out clears it
+/// along with the rest of the session.
+#[derive(Default, Deserialize, Serialize, Debug)]
+pub struct SessionUserRememberMe(pub bool);
+
+impl SessionUserRememberMe {
+    /// Marks the session as remembered.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to mark
+    ///
+    /// # Returns
+    /// - `Ok(())` - Session marked as remembered
+    /// - `Err(AppError)` - Session storage failed (Redis error, serialization error)
+    pub async fn insert(session: &Session) -> Result<(), AppError> {
+        session
+            .insert(SESSION_USER_REMEMBER_ME_KEY, SessionUserRememberMe(true))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns whether the session is remembered.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to check
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Session is remembered
+    /// - `Ok(false)` - Session uses the default inactivity expiry
+    /// - `Err(AppError)` - Session retrieval failed (Redis error)
+    pub async fn get(session: &Session) -> Result<bool, AppError> {
+        let remember_me: Option<SessionUserRememberMe> =
+            session.get(SESSION_USER_REMEMBER_ME_KEY).await?;
+
+        Ok(remember_me.is_some_and(|remember_me| remember_me.0))
+    }
+
+    /// Stops remembering the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to unmark
+    ///
+    /// # Returns
+    /// - `Ok(())` - Session no longer remembered
+    /// - `Err(AppError)` - Session operation failed (Redis error)
+    pub async fn remove(session: &Session) -> Result<(), AppError> {
+        session
+            .remove::<SessionUserRememberMe>(SESSION_USER_REMEMBER_ME_KEY)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod session_user_remember_me {
+        use super::*;
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests marking and unmarking a session as remembered.
+        ///
+        /// Expected: Ok(true) after inserting, Ok(false) after removing
+        #[tokio::test]
+        async fn marks_and_unmarks_session() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            assert!(!SessionUserRememberMe::get(&test.session).await.unwrap());
+
+            SessionUserRememberMe::insert(&test.session).await.unwrap();
+            assert!(SessionUserRememberMe::get(&test.session).await.unwrap());
+
+            SessionUserRememberMe::remove(&test.session).await.unwrap();
+            assert!(!SessionUserRememberMe::get(&test.session).await.unwrap());
+
+            Ok(())
+        }
+    }
+}
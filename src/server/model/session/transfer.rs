@@ -0,0 +1,138 @@
+//! Pending character transfer session data models.
+//!
+//! This module provides a type-safe wrapper for storing a pending transfer in the session.
+//! When a logged in user authenticates a character owned by another user with the same owner
+//! hash, both users belong to the same EVE Online account. Instead of silently moving the
+//! character, the callback records the other user here and the transfer is only carried out,
+//! by merging that user into the current one, once the user confirms it.
+
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+
+use crate::{model::user::PendingTransferDto, server::error::AppError};
+
+/// Session key for storing the pending character transfer.
+///
+/// The key is namespaced under "bifrost:auth:" to avoid collisions with other session data.
+pub const SESSION_AUTH_TRANSFER_KEY: &str = "bifrost:auth:transfer";
+
+/// Session wrapper for the pending character transfer.
+///
+/// Only the most recent transfer is kept, so authenticating another user's character replaces
+/// the transfer awaiting confirmation.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SessionPendingTransfer(pub PendingTransferDto);
+
+impl SessionPendingTransfer {
+    /// Inserts the pending character transfer into the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session for storing the pending transfer
+    /// - `transfer` - User owning the character and the character awaiting transfer
+    ///
+    /// # Returns
+    /// - `Ok(())` - Pending transfer successfully stored in session
+    /// - `Err(AppError)` - Session storage failed (Redis error, serialization error)
+    pub async fn insert(session: &Session, transfer: PendingTransferDto) -> Result<(), AppError> {
+        session
+            .insert(SESSION_AUTH_TRANSFER_KEY, SessionPendingTransfer(transfer))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the pending character transfer from the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to retrieve the pending transfer from
+    ///
+    /// # Returns
+    /// - `Ok(Some(PendingTransferDto))` - A transfer awaits confirmation
+    /// - `Ok(None)` - No transfer is pending
+    /// - `Err(AppError)` - Session retrieval failed (Redis error)
+    pub async fn get(session: &Session) -> Result<Option<PendingTransferDto>, AppError> {
+        let transfer: Option<SessionPendingTransfer> =
+            session.get(SESSION_AUTH_TRANSFER_KEY).await?;
+
+        Ok(transfer.map(|transfer| transfer.0))
+    }
+
+    /// Removes and returns the pending character transfer from the session.
+    ///
+    /// # Arguments
+    /// - `session` - User's session to remove the pending transfer from
+    ///
+    /// # Returns
+    /// - `Ok(Some(PendingTransferDto))` - Pending transfer found, removed, and returned
+    /// - `Ok(None)` - No transfer is pending
+    /// - `Err(AppError)` - Session operation failed (Redis error)
+    pub async fn remove(session: &Session) -> Result<Option<PendingTransferDto>, AppError> {
+        let transfer: Option<SessionPendingTransfer> =
+            session.remove(SESSION_AUTH_TRANSFER_KEY).await?;
+
+        Ok(transfer.map(|transfer| transfer.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending_transfer(from_user_id: i32) -> PendingTransferDto {
+        PendingTransferDto {
+            from_user_id,
+            character_id: 1,
+            character_name: "Alt".to_string(),
+        }
+    }
+
+    mod insert {
+        use super::*;
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests that inserting a transfer replaces the one pending before.
+        ///
+        /// Expected: Ok(Some) with the most recent transfer
+        #[tokio::test]
+        async fn replaces_pending_transfer() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionPendingTransfer::insert(&test.session, pending_transfer(1))
+                .await
+                .unwrap();
+            SessionPendingTransfer::insert(&test.session, pending_transfer(2))
+                .await
+                .unwrap();
+
+            let transfer = SessionPendingTransfer::get(&test.session).await.unwrap();
+            assert_eq!(transfer, Some(pending_transfer(2)));
+
+            Ok(())
+        }
+    }
+
+    mod remove {
+        use super::*;
+        use bifrost_test_utils::prelude::*;
+
+        /// Tests that removing the pending transfer returns it only once.
+        ///
+        /// Expected: Ok(Some) with the transfer, then Ok(None)
+        #[tokio::test]
+        async fn returns_transfer_once() -> Result<(), TestError> {
+            let test = TestBuilder::new().build().await?;
+
+            SessionPendingTransfer::insert(&test.session, pending_transfer(1))
+                .await
+                .unwrap();
+
+            let removed = SessionPendingTransfer::remove(&test.session).await.unwrap();
+            let retry = SessionPendingTransfer::remove(&test.session).await.unwrap();
+
+            assert_eq!(removed, Some(pending_transfer(1)));
+            assert!(retry.is_none());
+
+            Ok(())
+        }
+    }
+}
@@ -68,9 +68,9 @@ impl SessionUserId {
             .get::<SessionUserId>(SESSION_USER_ID_KEY)
             .await?
             .map(|SessionUserId(id_str)| {
-                id_str.parse::<i32>().map_err(|e| {
-                    AppError::Parse(format!("Failed to parse session user id: {}", e))
-                })
+                id_str
+                    .parse::<i32>()
+                    .map_err(|e| AppError::Parse(format!("Failed to parse session user id: {}", e)))
             })
             .transpose()
     }
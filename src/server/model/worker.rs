@@ -4,11 +4,20 @@
 //! can be dispatched to the worker queue. Jobs are serialized to JSON for Redis storage and
 //! deserialized by worker handlers for processing. Each job variant contains the minimal
 //! data needed to perform the task (e.g., entity IDs to refresh).
+//!
+//! Jobs are wrapped in a versioned envelope when stored, see `crate::server::worker::payload`.
+//! Changing the JSON shape of an existing variant requires a new payload version and migration
+//! so jobs queued by the previous release can still be processed.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::model::{
+    consent::ConsentCategory, push::PushNotificationDto, webhook::WebhookEvent,
+    worker::WorkerJobState,
+};
+
 /// Metadata tracking retry attempts for a worker job.
 ///
 /// This tracks how many times a job has been retried and when it first
@@ -96,7 +105,120 @@ impl fmt::Display for ScheduledWorkerJob {
     }
 }
 
-/// Background job types for EVE Online data refresh operations.
+/// Page of the jobs waiting in the worker queue, listed for inspection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkerQueuePage {
+    /// Number of queued jobs scheduled within the listed time range.
+    pub total: u64,
+    /// Jobs on the page, ordered by scheduled time.
+    pub jobs: Vec<ScheduledWorkerJob>,
+}
+
+/// Worker job that failed permanently and was moved to the dead-letter queue.
+///
+/// The job is stored as its serialized JSON payload rather than a `WorkerJob` so that entries
+/// remain readable even if the payload no longer deserializes, and so admins can edit the
+/// payload before replaying it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeadLetterJob {
+    /// Unique ID of the dead-letter entry.
+    pub id: u64,
+    /// Serialized JSON payload of the failed job.
+    pub payload: String,
+    /// Error the job failed with.
+    pub error: String,
+    /// Number of retry attempts made before the job failed permanently.
+    pub attempt_count: u32,
+    /// UTC timestamp when the job was dead-lettered.
+    pub failed_at: DateTime<Utc>,
+}
+
+/// Last known status of a worker job.
+///
+/// Stored in the Redis hash `{queue_name}:status` keyed by the job's ID, see
+/// `crate::server::worker::payload::job_id`. The status is updated as the job is queued,
+/// starts running, and finishes, so admins can check whether a triggered job actually ran.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkerJobStatus {
+    /// Readable description of the job, see the `Display` implementation of `WorkerJob`.
+    pub job: String,
+    /// Current state of the job.
+    pub state: WorkerJobState,
+    /// Error the job failed or timed out with, if any.
+    pub error: Option<String>,
+    /// Items of a batch job that failed while the rest of the batch succeeded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_items: Vec<JobItemFailure>,
+    /// UTC timestamp when the state last changed.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Item of a batch job that failed while the rest of the batch was processed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobItemFailure {
+    /// ID of the item, such as an EVE Online character ID.
+    pub id: i64,
+    /// Error the item failed with.
+    pub error: String,
+    /// Whether the item may succeed if the job is retried for it.
+    pub retryable: bool,
+}
+
+/// Outcome of a worker job that ran to completion.
+///
+/// Batch jobs such as `WorkerJob::UpdateAffiliations` report each item that failed, so the
+/// failures are logged and recorded in the job's status and only the retryable items are
+/// queued again. Jobs that aren't batches succeed or fail as a whole and report an empty
+/// outcome.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JobOutcome {
+    /// Number of items processed successfully.
+    pub succeeded: usize,
+    /// Items that failed.
+    pub failed: Vec<JobItemFailure>,
+}
+
+impl JobOutcome {
+    /// Whether some items of the job failed.
+    pub fn is_partial(&self) -> bool {
+        !self.failed.is_empty()
+    }
+
+    /// Returns the state a job that ran to completion with this outcome is recorded with.
+    pub fn state(&self) -> WorkerJobState {
+        if self.is_partial() {
+            WorkerJobState::PartiallySucceeded
+        } else {
+            WorkerJobState::Succeeded
+        }
+    }
+
+    /// Returns the IDs of the failed items that may succeed if retried.
+    pub fn retryable_ids(&self) -> Vec<i64> {
+        self.failed
+            .iter()
+            .filter(|failure| failure.retryable)
+            .map(|failure| failure.id)
+            .collect()
+    }
+
+    /// Describes how many items failed, `None` if none did.
+    pub fn summary(&self) -> Option<String> {
+        if !self.is_partial() {
+            return None;
+        }
+
+        let retryable = self.retryable_ids().len();
+        Some(format!(
+            "{} of {} items failed ({} retryable)",
+            self.failed.len(),
+            self.succeeded + self.failed.len(),
+            retryable
+        ))
+    }
+}
+
+/// Background job types for EVE Online data refresh and user data maintenance operations.
 ///
 /// Each variant represents a specific type of background task that can be enqueued to the
 /// Redis-backed worker queue. Jobs are serialized to JSON for storage and deserialized by
@@ -109,6 +231,18 @@ impl fmt::Display for ScheduledWorkerJob {
 /// - `UpdateCorporationInfo` - Refresh specific corporation metadata
 /// - `UpdateCharacterInfo` - Refresh specific character metadata
 /// - `UpdateAffiliations` - Refresh corporation/alliance affiliations for multiple characters (batched)
+/// - `UpdateCorporationMembers` - Refresh a corporation's member list with its director's token
+/// - `UpdateCharacterSkills` - Refresh a character's skill snapshot with its stored token
+/// - `PruneRevokedTokens` - Delete the stored character refresh tokens EVE SSO rejects
+/// - `DeleteConsentData` - Delete a user's stored data for a category after consent is revoked
+/// - `RefreshDashboardSummaries` - Recompute the precomputed admin dashboard summaries
+/// - `SendPushNotification` - Deliver a Web Push notification to a user's subscribed devices
+/// - `SendDiscordMessage` - Post a message to the configured Discord webhook
+/// - `SendWebhook` - Notify an admin-configured webhook of a character ownership change
+/// - `SendWeeklyDigest` - Compile the weekly digest and queue its delivery
+/// - `ExportUserData` - Store an archive of everything Bifrost stores about a user and notify them
+/// - `CheckGroupEligibility` - Remove users from groups they no longer qualify for
+/// - `Custom` - Plugin-defined job dispatched to the plugin handling its kind
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WorkerJob {
     /// Update NPC faction information for all factions.
@@ -170,6 +304,220 @@ pub enum WorkerJob {
         /// List of EVE Online character IDs to refresh (max 1000 per ESI limit).
         character_ids: Vec<i64>,
     },
+
+    /// Update the member list of a corporation.
+    ///
+    /// Exchanges the character token of the corporation's director for an access token and
+    /// fetches the corporation's member character IDs from ESI, replacing the stored member
+    /// list. Only scheduled for corporations whose director granted the
+    /// `esi-corporation.read_corporation_membership.v1` scope. ESI caches member lists for an
+    /// hour.
+    ///
+    /// # Fields
+    /// - `corporation_id` - EVE Online corporation ID whose members to refresh
+    UpdateCorporationMembers {
+        /// EVE Online corporation ID whose members to refresh.
+        corporation_id: i64,
+    },
+
+    /// Update the skill snapshot of a character.
+    ///
+    /// Exchanges the character's stored refresh token for an access token and fetches its
+    /// trained skills from ESI, replacing the stored snapshot. Only scheduled for characters
+    /// whose token grants the `esi-skills.read_skills.v1` scope.
+    ///
+    /// # Fields
+    /// - `character_id` - EVE Online character ID whose skills to refresh
+    UpdateCharacterSkills {
+        /// EVE Online character ID whose skills to refresh.
+        character_id: i64,
+    },
+
+    /// Delete the stored character refresh tokens EVE SSO rejects.
+    ///
+    /// Exchanges every stored refresh token once, storing the rotated tokens and deleting the
+    /// tokens characters revoked. Only a single job is needed since each run checks every
+    /// token.
+    PruneRevokedTokens,
+
+    /// Delete a user's stored data for a consent category.
+    ///
+    /// Scheduled when a user revokes data-sharing consent for a category. Removes all data
+    /// previously synced for the category from the user's characters so it is no longer
+    /// available to the organization.
+    ///
+    /// # Fields
+    /// - `user_id` - ID of the user who revoked consent
+    /// - `category` - Data category whose stored data should be deleted
+    DeleteConsentData {
+        /// ID of the user who revoked consent.
+        user_id: i32,
+        /// Data category whose stored data should be deleted.
+        category: ConsentCategory,
+    },
+
+    /// Recompute the precomputed admin dashboard summaries.
+    ///
+    /// Aggregates users per main character corporation and the distribution of characters
+    /// per user, replacing the stored summaries the admin dashboard reads. Only a single
+    /// refresh job is needed since each run recomputes every summary.
+    RefreshDashboardSummaries,
+
+    /// Deliver a Web Push notification to every device a user subscribed on.
+    ///
+    /// Encrypts the notification for each of the user's push subscriptions and sends it to
+    /// their push services, deleting subscriptions reported as expired. Does nothing if push
+    /// notifications are disabled.
+    ///
+    /// # Fields
+    /// - `user_id` - ID of the user to notify
+    /// - `notification` - Notification shown by the service worker
+    SendPushNotification {
+        /// ID of the user to notify.
+        user_id: i32,
+        /// Notification shown by the service worker.
+        notification: PushNotificationDto,
+    },
+
+    /// Post a message to the configured Discord webhook.
+    ///
+    /// Used to relay announcements and approval requests to a Discord channel. Mentions in the
+    /// message are not pinged. Does nothing if no Discord webhook is configured.
+    ///
+    /// # Fields
+    /// - `content` - Message content as Discord Markdown
+    SendDiscordMessage {
+        /// Message content as Discord Markdown.
+        content: String,
+    },
+
+    /// Notify an admin-configured webhook of a character ownership change.
+    ///
+    /// Posts a Discord/Slack-compatible JSON payload to the webhook's URL. Queued by
+    /// `NotificationService` for every enabled webhook subscribed to the change's event. Does
+    /// nothing if the webhook was deleted or disabled since the job was queued.
+    ///
+    /// # Fields
+    /// - `webhook_id` - ID of the webhook to notify
+    /// - `event` - Kind of ownership change
+    /// - `content` - Message describing the change as Markdown
+    SendWebhook {
+        /// ID of the webhook to notify.
+        webhook_id: i32,
+        /// Kind of ownership change.
+        event: WebhookEvent,
+        /// Message describing the change as Markdown.
+        content: String,
+    },
+
+    /// Compile the weekly digest and queue its delivery.
+    ///
+    /// Summarizes new members, upcoming campaigns, and refresh pipeline health, then queues a
+    /// `SendDiscordMessage` job with the full digest and a `SendPushNotification` job with a
+    /// short summary for every user who didn't opt out. Only a single digest job is needed
+    /// since each run covers the whole organization.
+    SendWeeklyDigest,
+
+    /// Store an archive of everything Bifrost stores about a user and notify them.
+    ///
+    /// Scheduled when a user requests a download of their data. Assembles the user's
+    /// characters, affiliation history, preferences, consents, and notifications into a JSON
+    /// archive, stores it in object storage, and queues a `SendPushNotification` job linking
+    /// to the download. Fails if object storage is not configured.
+    ///
+    /// # Fields
+    /// - `user_id` - ID of the user whose data to export
+    ExportUserData {
+        /// ID of the user whose data to export.
+        user_id: i32,
+    },
+
+    /// Re-evaluate the group memberships of users against the groups' eligibility rules.
+    ///
+    /// Queued after an `UpdateAffiliations` job refreshed the affiliations of the users'
+    /// characters. Removes the memberships and pending requests to join of users none of whose
+    /// characters belongs to a corporation, alliance, or faction a group's rules name anymore.
+    ///
+    /// # Fields
+    /// - `user_ids` - IDs of the users whose characters' affiliations were refreshed
+    CheckGroupEligibility {
+        /// IDs of the users whose characters' affiliations were refreshed.
+        user_ids: Vec<i32>,
+    },
+
+    /// Plugin-defined job.
+    ///
+    /// Dispatched to the registered plugin that declares the job kind, see
+    /// `BifrostPlugin::job_kinds`. The payload is opaque to Bifrost and interpreted by the
+    /// plugin.
+    ///
+    /// # Fields
+    /// - `0` - Job kind, prefixed with the plugin name (e.g. `fleet_tracker.sync`)
+    /// - `1` - JSON payload passed to the plugin's handler
+    Custom(String, serde_json::Value),
+}
+
+impl WorkerJob {
+    /// Returns this batch job restricted to the given items.
+    ///
+    /// Used to queue a batch job again for only the items that failed.
+    ///
+    /// # Arguments
+    /// - `ids` - IDs of the items to keep, such as EVE Online character IDs
+    ///
+    /// # Returns
+    /// - `Some(WorkerJob)` - Batch job for the items
+    /// - `None` - The job isn't a batch job
+    pub fn with_items(&self, ids: Vec<i64>) -> Option<WorkerJob> {
+        match self {
+            WorkerJob::UpdateAffiliations { .. } => {
+                Some(WorkerJob::UpdateAffiliations { character_ids: ids })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the job's type, such as `UpdateAllianceInfo`.
+    ///
+    /// Custom jobs return the kind declared by their plugin.
+    pub fn kind(&self) -> &str {
+        match self {
+            WorkerJob::UpdateFactionInfo => "UpdateFactionInfo",
+            WorkerJob::UpdateAllianceInfo { .. } => "UpdateAllianceInfo",
+            WorkerJob::UpdateCorporationInfo { .. } => "UpdateCorporationInfo",
+            WorkerJob::UpdateCharacterInfo { .. } => "UpdateCharacterInfo",
+            WorkerJob::UpdateAffiliations { .. } => "UpdateAffiliations",
+            WorkerJob::UpdateCorporationMembers { .. } => "UpdateCorporationMembers",
+            WorkerJob::UpdateCharacterSkills { .. } => "UpdateCharacterSkills",
+            WorkerJob::PruneRevokedTokens => "PruneRevokedTokens",
+            WorkerJob::DeleteConsentData { .. } => "DeleteConsentData",
+            WorkerJob::RefreshDashboardSummaries => "RefreshDashboardSummaries",
+            WorkerJob::SendPushNotification { .. } => "SendPushNotification",
+            WorkerJob::SendDiscordMessage { .. } => "SendDiscordMessage",
+            WorkerJob::SendWebhook { .. } => "SendWebhook",
+            WorkerJob::SendWeeklyDigest => "SendWeeklyDigest",
+            WorkerJob::ExportUserData { .. } => "ExportUserData",
+            WorkerJob::CheckGroupEligibility { .. } => "CheckGroupEligibility",
+            WorkerJob::Custom(kind, _) => kind,
+        }
+    }
+
+    /// Returns the EVE Online ID of the single entity the job refreshes.
+    ///
+    /// # Returns
+    /// - `Some(i64)` - Alliance, corporation, or character ID refreshed by the job, the
+    ///   corporation whose member list is refreshed, or the character whose skills are refreshed
+    /// - `None` - The job refreshes several entities or none
+    pub fn entity_id(&self) -> Option<i64> {
+        match self {
+            WorkerJob::UpdateAllianceInfo { alliance_id } => Some(*alliance_id),
+            WorkerJob::UpdateCorporationInfo { corporation_id } => Some(*corporation_id),
+            WorkerJob::UpdateCharacterInfo { character_id } => Some(*character_id),
+            WorkerJob::UpdateCorporationMembers { corporation_id } => Some(*corporation_id),
+            WorkerJob::UpdateCharacterSkills { character_id } => Some(*character_id),
+            _ => None,
+        }
+    }
 }
 
 /// Custom Display implementation for readable job logging.
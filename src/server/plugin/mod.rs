@@ -0,0 +1,263 @@
+//! Plugin system for extending Bifrost without patching core modules.
+//!
+//! Downstream forks implement [`BifrostPlugin`] to add organization-specific features and
+//! register their plugins in a [`PluginRegistry`] at startup. A plugin can contribute:
+//!
+//! - HTTP routes, merged into the API router
+//! - Worker job handlers for [`WorkerJob::Custom`] jobs of the kinds it declares
+//! - Scheduled jobs, enqueued to the worker queue on a cron schedule
+//! - Database migrations, applied after the core migrations and tracked separately
+//!
+//! # Example
+//! ```ignore
+//! struct FleetTracker;
+//!
+//! impl BifrostPlugin for FleetTracker {
+//!     fn name(&self) -> &'static str {
+//!         "fleet_tracker"
+//!     }
+//!
+//!     fn job_kinds(&self) -> Vec<&'static str> {
+//!         vec!["fleet_tracker.sync"]
+//!     }
+//!
+//!     fn handle_job<'a>(
+//!         &'a self,
+//!         ctx: PluginJobContext<'a>,
+//!         kind: &'a str,
+//!         payload: &'a serde_json::Value,
+//!     ) -> BoxFuture<'a, Result<(), AppError>> {
+//!         Box::pin(async move { sync_fleets(ctx.db, payload).await })
+//!     }
+//! }
+//!
+//! let plugins = PluginRegistry::new().with_plugin(FleetTracker);
+//! ```
+
+use std::sync::{Arc, OnceLock};
+
+use axum::Router;
+use futures::future::BoxFuture;
+use migration::{Alias, DynIden, IntoIden, MigrationTrait, MigratorTrait};
+use sea_orm::DatabaseConnection;
+
+use crate::server::{
+    error::{worker::WorkerError, AppError},
+    model::{app::AppState, worker::WorkerJob},
+    service::eve::esi::EsiProvider,
+    worker::WorkerQueue,
+};
+
+/// Name of the table tracking applied plugin migrations.
+///
+/// Kept separate from the core migration table so core migrations can be rolled back or
+/// inspected with the migration CLI without plugin migrations being reported as unknown.
+static PLUGIN_MIGRATION_TABLE: &str = "seaql_plugin_migrations";
+
+/// Plugins whose migrations are applied by [`PluginMigrator`].
+///
+/// `MigratorTrait::migrations` is a static function, so the registered plugins must be
+/// reachable without a `self` reference. Set once by [`PluginRegistry::run_migrations`].
+static MIGRATION_PLUGINS: OnceLock<Vec<Arc<dyn BifrostPlugin>>> = OnceLock::new();
+
+/// Dependencies available to plugin worker job handlers.
+#[derive(Clone, Copy)]
+pub struct PluginJobContext<'a> {
+    /// Database connection for data persistence.
+    pub db: &'a DatabaseConnection,
+    /// ESI provider with circuit breaker protection for EVE Online API calls.
+    pub esi_provider: &'a EsiProvider,
+    /// Worker queue for scheduling follow-up jobs.
+    pub queue: &'a WorkerQueue,
+}
+
+/// A job a plugin enqueues to the worker queue on a cron schedule.
+#[derive(Clone, Debug)]
+pub struct PluginScheduledJob {
+    /// Cron expression defining when the job is enqueued (e.g. `"0 0 * * * *"` for hourly).
+    pub cron: &'static str,
+    /// Human-readable name used in scheduler log messages.
+    pub name: &'static str,
+    /// Job pushed to the worker queue on each run, typically a `WorkerJob::Custom`.
+    pub job: WorkerJob,
+}
+
+/// Extension point for adding features to Bifrost.
+///
+/// All methods other than `name` have defaults contributing nothing, so a plugin only
+/// implements the extension points it needs.
+pub trait BifrostPlugin: Send + Sync + 'static {
+    /// Unique name of the plugin, used in log messages.
+    fn name(&self) -> &'static str;
+
+    /// HTTP routes added to the API router.
+    ///
+    /// Routes should be namespaced under `/api/plugins/{name}` to avoid conflicts with core
+    /// routes added in later releases.
+    fn routes(&self) -> Router<AppState> {
+        Router::new()
+    }
+
+    /// Kinds of `WorkerJob::Custom` jobs handled by this plugin.
+    ///
+    /// Kinds should be prefixed with the plugin name (e.g. `fleet_tracker.sync`) as each kind
+    /// may only be handled by a single plugin.
+    fn job_kinds(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Handles a `WorkerJob::Custom` job of one of the kinds declared by `job_kinds`.
+    ///
+    /// Errors are retried according to `AppError::to_retry_strategy`, the same as core jobs.
+    ///
+    /// # Arguments
+    /// - `ctx` - Database, ESI provider, and worker queue
+    /// - `kind` - Job kind, one of the values returned by `job_kinds`
+    /// - `payload` - JSON payload the job was enqueued with
+    fn handle_job<'a>(
+        &'a self,
+        ctx: PluginJobContext<'a>,
+        kind: &'a str,
+        payload: &'a serde_json::Value,
+    ) -> BoxFuture<'a, Result<(), AppError>> {
+        let _ = (ctx, payload);
+
+        Box::pin(async move { Err(WorkerError::UnhandledCustomJob(kind.to_string()).into()) })
+    }
+
+    /// Jobs enqueued to the worker queue on a cron schedule.
+    fn scheduled_jobs(&self) -> Vec<PluginScheduledJob> {
+        Vec::new()
+    }
+
+    /// Database migrations for tables owned by the plugin.
+    ///
+    /// Migration names must be unique across all plugins. Plugin migrations run after the
+    /// core migrations, so they may reference core tables.
+    fn migrations(&self) -> Vec<Box<dyn MigrationTrait>> {
+        Vec::new()
+    }
+}
+
+/// Registered plugins, shared by the router, worker, and scheduler.
+///
+/// Cloning is cheap as plugins are reference counted.
+#[derive(Clone, Default)]
+pub struct PluginRegistry {
+    plugins: Vec<Arc<dyn BifrostPlugin>>,
+}
+
+impl PluginRegistry {
+    /// Creates an empty plugin registry.
+    ///
+    /// # Returns
+    /// - `PluginRegistry` - Registry with no plugins
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a plugin.
+    ///
+    /// # Arguments
+    /// - `plugin` - Plugin to register
+    ///
+    /// # Returns
+    /// - `PluginRegistry` - Registry including the plugin
+    pub fn with_plugin(mut self, plugin: impl BifrostPlugin) -> Self {
+        self.plugins.push(Arc::new(plugin));
+        self
+    }
+
+    /// Returns true if no plugins are registered.
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Merges the routes of all plugins into a single router.
+    ///
+    /// # Returns
+    /// - `Router<AppState>` - Router containing every plugin route
+    pub fn routes(&self) -> Router<AppState> {
+        self.plugins.iter().fold(Router::new(), |router, plugin| {
+            router.merge(plugin.routes())
+        })
+    }
+
+    /// Returns the scheduled jobs of all plugins.
+    pub fn scheduled_jobs(&self) -> Vec<PluginScheduledJob> {
+        self.plugins
+            .iter()
+            .flat_map(|plugin| plugin.scheduled_jobs())
+            .collect()
+    }
+
+    /// Dispatches a `WorkerJob::Custom` job to the plugin handling its kind.
+    ///
+    /// # Arguments
+    /// - `ctx` - Database, ESI provider, and worker queue
+    /// - `kind` - Job kind
+    /// - `payload` - JSON payload the job was enqueued with
+    ///
+    /// # Returns
+    /// - `Ok(())` - Job handled successfully
+    /// - `Err(AppError::Worker(WorkerError::UnhandledCustomJob))` - No plugin handles the job kind
+    /// - `Err(AppError)` - Plugin failed to handle the job
+    pub async fn handle_job(
+        &self,
+        ctx: PluginJobContext<'_>,
+        kind: &str,
+        payload: &serde_json::Value,
+    ) -> Result<(), AppError> {
+        let Some(plugin) = self
+            .plugins
+            .iter()
+            .find(|plugin| plugin.job_kinds().iter().any(|k| *k == kind))
+        else {
+            return Err(WorkerError::UnhandledCustomJob(kind.to_string()).into());
+        };
+
+        plugin.handle_job(ctx, kind, payload).await
+    }
+
+    /// Applies pending migrations of all plugins.
+    ///
+    /// Must be called at most once, after the core migrations have been applied.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection
+    ///
+    /// # Returns
+    /// - `Ok(())` - All plugin migrations applied
+    /// - `Err(AppError::Internal)` - Plugin migrations were already run
+    /// - `Err(AppError::Database)` - A migration failed
+    pub async fn run_migrations(&self, db: &DatabaseConnection) -> Result<(), AppError> {
+        MIGRATION_PLUGINS
+            .set(self.plugins.clone())
+            .map_err(|_| AppError::Internal("Plugin migrations were already run".to_string()))?;
+
+        PluginMigrator::up(db, None).await?;
+
+        Ok(())
+    }
+}
+
+/// Migrator applying the migrations of registered plugins.
+struct PluginMigrator;
+
+impl MigratorTrait for PluginMigrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        MIGRATION_PLUGINS
+            .get()
+            .map(|plugins| {
+                plugins
+                    .iter()
+                    .flat_map(|plugin| plugin.migrations())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn migration_table_name() -> DynIden {
+        Alias::new(PLUGIN_MIGRATION_TABLE).into_iden()
+    }
+}
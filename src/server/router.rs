@@ -1,68 +1,514 @@
 //! HTTP routing and OpenAPI documentation configuration.
 //!
 //! This module defines the application's HTTP routes and generates OpenAPI documentation
-//! using utoipa. All API endpoints are registered here with their OpenAPI specifications,
-//! and Swagger UI is configured to provide interactive API documentation at `/api/docs`.
+//! using utoipa. All API endpoints are registered here with their OpenAPI specifications, the
+//! assembled OpenAPI document is served at `/api/openapi.json`, and Swagger UI can be enabled
+//! to provide interactive API documentation at `/api/docs`.
 
-use axum::Router;
+use axum::{routing::get, Json, Router};
 use utoipa::OpenApi;
 use utoipa_axum::{router::OpenApiRouter, routes};
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::server::{controller, model::app::AppState};
+use crate::{
+    model::{api, user},
+    server::{controller, model::app::AppState},
+};
 
-/// Builds the application's HTTP router with all API endpoints and Swagger UI documentation.
+/// Path the assembled OpenAPI document is served at.
+pub const OPENAPI_PATH: &str = "/api/openapi.json";
+
+/// Path Swagger UI is served at if enabled.
+pub const SWAGGER_UI_PATH: &str = "/api/docs";
+
+/// Builds the application's HTTP router with all API endpoints and OpenAPI documentation.
 ///
 /// Constructs an Axum router with all authentication and user management endpoints registered.
 /// Each endpoint is annotated with OpenAPI specifications via utoipa, which are collected into
-/// a unified OpenAPI document. The router includes Swagger UI at `/api/docs` for interactive
-/// API exploration and testing.
+/// a unified OpenAPI document served at `/api/openapi.json`. If enabled, the router includes
+/// Swagger UI at `/api/docs` for interactive API exploration and testing.
 ///
 /// # Registered Endpoints
 /// - `POST /api/auth/login` - Initiate EVE Online SSO authentication
 /// - `GET /api/auth/callback` - OAuth callback handler
 /// - `GET /api/auth/logout` - Logout current user
 /// - `GET /api/auth/user` - Get current user information
-/// - `GET /api/user/characters` - Get characters owned by current user
+/// - `GET /api/auth/link-mode` - Get linking mode status and linked characters
+/// - `DELETE /api/auth/link-mode` - Exit linking mode
+/// - `GET /api/auth/transfer` - Get the character transfer awaiting confirmation
+/// - `POST /api/auth/transfer/confirm` - Confirm the pending transfer, merging the other user
+/// - `GET /api/auth/discord/login` - Start linking a Discord account to the current user
+/// - `GET /api/auth/discord/callback` - Discord OAuth callback handler
+/// - `GET /api/branding` - Get the organization name, logo, color, and navigation links (public)
+/// - `GET /api/user/characters` - Get a page of the characters owned by current user, filtered and sorted
+/// - `GET /api/user/characters/{character_id}/skills` - Get the skill snapshot of a character owned by the current user
+/// - `GET /api/user/discord` - Get the Discord account linked to the current user
+/// - `DELETE /api/user/discord` - Unlink the current user's Discord account
+/// - `GET /api/user/sessions` - Get the sessions the current user is logged in with
+/// - `DELETE /api/user/sessions/{id}` - Revoke another session of the current user
+/// - `GET /api/user/consents` - Get data-sharing consent status for current user
+/// - `PUT /api/user/consents/{category}` - Grant consent for a data category
+/// - `DELETE /api/user/consents/{category}` - Revoke consent for a data category
+/// - `GET /api/user/export` - Request an archive of everything stored about the current user
+/// - `POST /api/fittings` - Import a fitting in EFT format
+/// - `GET /api/fittings` - List all fittings
+/// - `DELETE /api/fittings/{fitting_id}` - Delete a fitting
+/// - `GET /api/fittings/{fitting_id}/skills` - Get the skill levels required to fly a fitting
+/// - `PUT /api/fittings/{fitting_id}/skills` - Replace the skill levels required to fly a fitting
+/// - `POST /api/doctrines` - Create a doctrine
+/// - `GET /api/doctrines` - List all doctrines with their fittings
+/// - `DELETE /api/doctrines/{doctrine_id}` - Delete a doctrine
+/// - `PUT /api/doctrines/{doctrine_id}/fittings/{fitting_id}` - Add a fitting to a doctrine
+/// - `DELETE /api/doctrines/{doctrine_id}/fittings/{fitting_id}` - Remove a fitting from a doctrine
+/// - `GET /api/doctrines/{doctrine_id}/readiness` - Report which fittings the current user's characters can fly
+/// - `GET /api/admin/corporations/{corporation_id}/doctrines/{doctrine_id}/readiness` - Report the share of a corporation's characters able to fly a doctrine
+/// - `GET /api/recruitment` - List corporations recruiting (public)
+/// - `PUT /api/recruitment/{corporation_id}` - Create or update a corporation's recruitment listing
+/// - `DELETE /api/recruitment/{corporation_id}` - Remove a corporation's recruitment listing
+/// - `POST /api/admin/screening/characters/{character_id}` - Generate a screening report for a character
+/// - `GET /api/admin/screening/{report_id}` - Get a stored screening report
+/// - `GET /api/characters/{character_id}/affiliation-history` - Get a character's corporation and alliance history
+/// - `GET /api/admin/corporations/{corporation_id}/members` - Get a corporation's member list with registration counts
+/// - `GET /api/search` - Search characters, corporations, and alliances by name
+/// - `GET /img/{category}/{id}` - Get an EVE portrait or logo, proxied and cached if enabled (public)
+/// - `GET /api/admin/telemetry` - Get telemetry status and report preview
+/// - `GET /api/widgets/{token}` - Get widget data as JSON (public, token-authorized)
+/// - `GET /api/widgets/{token}/embed` - Render a widget for iframe embedding (public, token-authorized)
+/// - `GET /api/admin/widgets` - List widgets created by the current user
+/// - `POST /api/admin/widgets` - Create a widget
+/// - `DELETE /api/admin/widgets/{widget_id}` - Revoke a widget
+/// - `GET /api/admin/export/characters` - Stream all characters as NDJSON
+/// - `POST /api/admin/export/characters/stored` - Store all characters in object storage and get a download URL
+/// - `GET /api/admin/dashboard` - Get precomputed admin dashboard summaries
+/// - `GET /api/admin/diagnostics` - Get the health of the server's supervised background tasks
+/// - `POST /api/admin/users/{keep}/merge/{remove}` - Merge a duplicate user into another user, or request approval for it
+/// - `GET /api/admin/users/{user_id}/bans` - List a user's bans
+/// - `POST /api/admin/users/{user_id}/bans` - Ban a user temporarily or permanently with a reason
+/// - `POST /api/admin/bans/{ban_id}/lift` - Lift a ban early
+/// - `GET /api/groups` - List the groups listed to the current user with their membership
+/// - `POST /api/groups/{group_id}/join` - Join a group, or request to join it
+/// - `POST /api/groups/{group_id}/leave` - Leave a group, or cancel a request to join it
+/// - `GET /api/admin/groups` - List groups with their member and pending request counts
+/// - `POST /api/admin/groups` - Create an open, request-to-join, or hidden group
+/// - `DELETE /api/admin/groups/{group_id}` - Delete a group
+/// - `GET /api/admin/groups/{group_id}/members` - List a group's members and pending requests
+/// - `PUT /api/admin/groups/{group_id}/members/{user_id}` - Add a user to a group
+/// - `DELETE /api/admin/groups/{group_id}/members/{user_id}` - Remove a member or reject a request to join
+/// - `POST /api/admin/groups/{group_id}/members/{user_id}/approve` - Approve a request to join a group
+/// - `GET /api/admin/groups/{group_id}/rules` - List the corporations, alliances, and factions qualifying for a group
+/// - `POST /api/admin/groups/{group_id}/rules` - Restrict a group to a corporation, alliance, or faction
+/// - `DELETE /api/admin/groups/{group_id}/rules/{rule_id}` - Remove a group eligibility rule
+/// - `GET /api/admin/scheduler/preview` - Preview the jobs a scheduled job would enqueue
+/// - `GET /api/admin/freshness` - Report how long ago cached EVE data was refreshed
+/// - `GET /api/admin/worker/queue` - List a page of queued worker jobs with their scheduled times
+/// - `GET /api/admin/worker/dead-letters` - List permanently failed worker jobs
+/// - `POST /api/admin/worker/dead-letters/{id}/replay` - Requeue a failed job, optionally edited
+/// - `GET /api/admin/worker/jobs/{job_id}` - Get whether a worker job is queued, running, or finished
+/// - `GET /metrics` - Worker pool and queue metrics in the Prometheus text format (public)
+/// - `GET /api/skill-plans` - List skill plans
+/// - `POST /api/skill-plans` - Publish a skill plan from plain text or EVEMon XML
+/// - `DELETE /api/skill-plans/{skill_plan_id}` - Delete a skill plan
+/// - `GET /api/campaigns` - List deployment campaigns
+/// - `POST /api/campaigns` - Create a deployment campaign
+/// - `DELETE /api/campaigns/{campaign_id}` - Delete a deployment campaign
+/// - `GET /api/pages` - List pages (public)
+/// - `GET /api/pages/{slug}` - Get a page with its Markdown and rendered HTML (public)
+/// - `GET /pages/{slug}` - Render a page as HTML (public)
+/// - `PUT /api/admin/pages/{slug}` - Create or update a page, recording a revision
+/// - `DELETE /api/admin/pages/{slug}` - Delete a page
+/// - `GET /api/admin/pages/{slug}/revisions` - List a page's revisions
+/// - `POST /api/admin/pages/{slug}/revisions/{revision_id}/restore` - Restore a page revision
+/// - `POST /api/admin/announcements` - Post an announcement to all members, a corporation, or an alliance
+/// - `GET /api/admin/announcements` - List announcements with their read receipts
+/// - `GET /api/user/announcements` - Get the current user's announcement inbox
+/// - `POST /api/user/announcements/{announcement_id}/read` - Mark an announcement read
+/// - `POST /api/admin/reauth-campaigns` - Launch a re-authentication campaign for an audience
+/// - `GET /api/admin/reauth-campaigns` - List re-authentication campaigns with their completion
+/// - `GET /api/user/reauth-campaigns` - Get the current user's pending re-authentication campaigns
+/// - `POST /api/admin/onboarding/steps` - Define a step at the end of the onboarding checklist
+/// - `GET /api/admin/onboarding/steps` - List onboarding steps
+/// - `DELETE /api/admin/onboarding/steps/{step_id}` - Delete an onboarding step
+/// - `GET /api/admin/onboarding/incomplete` - List users who haven't completed onboarding
+/// - `GET /api/user/onboarding` - Get the current user's onboarding checklist
+/// - `POST /api/user/onboarding/{step_id}/complete` - Mark an onboarding step completed
+/// - `GET /api/data/queries` - List saved queries (public, API key-authorized)
+/// - `GET /api/data/queries/{slug}` - Run a saved query read-only (public, API key-authorized)
+/// - `GET /api/admin/saved-queries` - List saved queries for the data access API
+/// - `PUT /api/admin/saved-queries/{slug}` - Create or update a saved query
+/// - `DELETE /api/admin/saved-queries/{slug}` - Delete a saved query
+/// - `GET /api/admin/api-keys` - List data access API keys
+/// - `POST /api/admin/api-keys` - Create a data access API key
+/// - `DELETE /api/admin/api-keys/{api_key_id}` - Revoke a data access API key
+/// - `GET /api/admin/annotations/{subject}/{subject_id}` - Get the tags and notes of a user, character, or corporation
+/// - `POST /api/admin/annotations/{subject}/{subject_id}/tags` - Tag a user, character, or corporation
+/// - `POST /api/admin/annotations/{subject}/{subject_id}/notes` - Add a note about a user, character, or corporation
+/// - `GET /api/admin/tags` - Find every user, character, and corporation carrying a tag
+/// - `DELETE /api/admin/tags/{tag_id}` - Remove a tag
+/// - `DELETE /api/admin/notes/{note_id}` - Delete a note
+/// - `GET /api/admin/members/users` - List users with a character matching a filter
+/// - `GET /api/admin/members/characters` - List owned characters matching a filter
+/// - `GET /api/admin/members/filters` - List the current user's saved member filters
+/// - `POST /api/admin/members/filters` - Save a member filter
+/// - `DELETE /api/admin/members/filters/{filter_id}` - Delete a saved member filter
+/// - `POST /api/admin/members/bulk` - Queue a bulk action for the selected users or characters
+/// - `GET /api/admin/read-only` - Get whether read-only mode is enabled
+/// - `PUT /api/admin/read-only` - Enable or disable read-only mode for database maintenance
+/// - `GET /api/admin/approvals` - List approval requests of sensitive admin actions
+/// - `POST /api/admin/approvals/{request_id}/approve` - Approve a request and run its action
+/// - `POST /api/admin/approvals/{request_id}/reject` - Reject or withdraw a request
+/// - `GET /api/user/preferences` - Get the current user's preferences
+/// - `PUT /api/user/preferences` - Save the current user's preferences
+/// - `GET /api/push/config` - Get the VAPID public key browsers subscribe with
+/// - `POST /api/user/push-subscriptions` - Subscribe the current browser to push notifications
+/// - `DELETE /api/user/push-subscriptions` - Unsubscribe a browser from push notifications
+/// - `POST /api/user/push-subscriptions/test` - Send a test push notification to the current user
+/// - `POST /api/admin/webhooks` - Create a webhook notified of character ownership changes
+/// - `GET /api/admin/webhooks` - List webhooks
+/// - `PUT /api/admin/webhooks/{webhook_id}` - Update a webhook
+/// - `DELETE /api/admin/webhooks/{webhook_id}` - Delete a webhook
+/// - `GET /api/admin/roles` - List roles
+/// - `POST /api/admin/roles` - Create a role
+/// - `PUT /api/admin/roles/{role_id}` - Update a role
+/// - `DELETE /api/admin/roles/{role_id}` - Delete a role
+/// - `PUT /api/admin/roles/{role_id}/users/{user_id}` - Assign a role to a user
+/// - `DELETE /api/admin/roles/{role_id}/users/{user_id}` - Remove a role from a user
+/// - `GET /api/user/permissions` - Get the permissions granted to the current user
+/// - `GET /manifest.webmanifest` - Web app manifest for installing the app (public)
+/// - `GET /sw.js` - Service worker caching the app shell for offline use (public)
+/// - `GET /icon-512.png` - App icon referenced by the manifest (public)
+/// - `GET /api/openapi.json` - Assembled OpenAPI document (public)
+/// - `GET /api/docs` - Swagger UI, if enabled (public)
 ///
 /// # OpenAPI Documentation
-/// The OpenAPI specification is available at `/api/docs/openapi.json` and includes:
+/// The OpenAPI specification is always available at `/api/openapi.json` and includes:
 /// - Endpoint paths and HTTP methods
 /// - Request/response schemas
 /// - Authentication requirements
 /// - Error responses
 ///
+/// Typed API clients can be generated from it with any OpenAPI generator, or from the
+/// document printed by the `openapi` command without running the server.
+///
 /// # Swagger UI
-/// Interactive API documentation is served at `/api/docs`, allowing developers to:
+/// If enabled, interactive API documentation is served at `/api/docs`, allowing developers to:
 /// - Browse available endpoints
 /// - View request/response schemas
 /// - Test endpoints directly from the browser
 /// - Download the OpenAPI specification
 ///
+/// # Arguments
+/// - `api_docs_enabled` - Whether Swagger UI is served at `/api/docs`
+///
 /// # Returns
 /// An Axum `Router<AppState>` configured with all routes and middleware, ready to be
 /// merged into the main application router.
 ///
 /// # Example
 /// ```ignore
-/// let app_state = AppState { db, esi_provider, worker };
-/// let router = routes().with_state(app_state);
+/// let app_state = AppState { db, esi_provider, worker, telemetry, push, search, image_proxy, object_storage, branding, scheduler, approvals, read_only, supervisor, cipher, scope_sets, discord, redis_pool, session_expiry };
+/// let router = routes(config.api_docs_enabled).with_state(app_state);
 /// // Router is now ready to serve HTTP requests
 /// ```
-pub fn routes() -> Router<AppState> {
+pub fn routes(api_docs_enabled: bool) -> Router<AppState> {
+    let (routes, api) = api_router().split_for_parts();
+
+    let routes = if api_docs_enabled {
+        routes.merge(
+            SwaggerUi::new(SWAGGER_UI_PATH).config(utoipa_swagger_ui::Config::from(OPENAPI_PATH)),
+        )
+    } else {
+        routes
+    };
+
+    routes.route(OPENAPI_PATH, get(move || async move { Json(api) }))
+}
+
+/// Assembles the OpenAPI document describing every API route and schema.
+///
+/// Used by the `openapi` command to print the document for generating typed API clients
+/// without starting the server.
+///
+/// # Returns
+/// - `utoipa::openapi::OpenApi` - Document served at `/api/openapi.json`
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    api_router().split_for_parts().1
+}
+
+/// Registers every API route with its OpenAPI specification.
+fn api_router() -> OpenApiRouter<AppState> {
     #[derive(OpenApi)]
-    #[openapi(info(title = "Bifrost", description = "Bifrost API"), tags(
+    #[openapi(info(title = "Bifrost", description = "Bifrost API"), components(schemas(
+        api::ErrorDto,
+        api::ProblemDto,
+        api::ValidationErrorDto,
+        api::FieldErrorDto,
+        user::UserDto,
+        user::CharacterDto,
+        user::CorporationDto,
+        user::AllianceDto,
+        user::LinkModeDto,
+        user::LinkedCharacterDto,
+        user::PendingTransferDto,
+        user::UserMergeDto,
+        user::DiscordAccountDto,
+        user::UserSessionDto,
+        user::UserCharacterSort,
+        user::UserCharacterQueryDto,
+        user::UserCharacterPageDto,
+    )), tags(
+        (name = controller::affiliation_history::AFFILIATION_HISTORY_TAG, description = "Character affiliation history API routes"),
+        (name = controller::annotation::ANNOTATION_TAG, description = "Admin tag and note API routes"),
+        (name = controller::announcement::ANNOUNCEMENT_TAG, description = "Announcement API routes"),
+        (name = controller::approval::APPROVAL_TAG, description = "Sensitive admin action approval API routes"),
         (name = controller::auth::AUTH_TAG, description = "Authentication API routes"),
+        (name = controller::ban::BAN_TAG, description = "User ban API routes"),
+        (name = controller::branding::BRANDING_TAG, description = "Instance branding API routes"),
+        (name = controller::campaign::CAMPAIGN_TAG, description = "Deployment campaign API routes"),
+        (name = controller::consent::CONSENT_TAG, description = "Data-sharing consent API routes"),
+        (name = controller::corporation_member::CORPORATION_MEMBER_TAG, description = "Corporation member list API routes"),
+        (name = controller::dashboard::DASHBOARD_TAG, description = "Admin dashboard API routes"),
+        (name = controller::data_api::DATA_API_TAG, description = "Data access API routes for BI tools"),
+        (name = controller::diagnostics::DIAGNOSTICS_TAG, description = "Admin diagnostics API routes"),
+        (name = controller::doctrine::DOCTRINE_TAG, description = "Doctrine and fitting API routes"),
+        (name = controller::export::EXPORT_TAG, description = "Admin and user data export API routes"),
+        (name = controller::group::GROUP_TAG, description = "Group membership API routes"),
+        (name = controller::image::IMAGE_TAG, description = "EVE image proxy routes"),
+        (name = controller::maintenance::MAINTENANCE_TAG, description = "Admin maintenance API routes"),
+        (name = controller::member::MEMBER_TAG, description = "Admin member list API routes"),
+        (name = controller::metrics::METRICS_TAG, description = "Prometheus metrics routes"),
+        (name = controller::onboarding::ONBOARDING_TAG, description = "Onboarding checklist API routes"),
+        (name = controller::page::PAGE_TAG, description = "Admin-edited page routes"),
+        (name = controller::preference::PREFERENCE_TAG, description = "User preference API routes"),
+        (name = controller::push::PUSH_TAG, description = "Push notification API routes"),
+        (name = controller::pwa::PWA_TAG, description = "Installable web app routes"),
+        (name = controller::reauth_campaign::REAUTH_CAMPAIGN_TAG, description = "Re-authentication campaign API routes"),
+        (name = controller::recruitment::RECRUITMENT_TAG, description = "Corporation recruitment API routes"),
+        (name = controller::role::ROLE_TAG, description = "Role and permission API routes"),
+        (name = controller::scheduler::SCHEDULER_TAG, description = "Admin scheduler API routes"),
+        (name = controller::screening::SCREENING_TAG, description = "Character screening API routes"),
+        (name = controller::search::SEARCH_TAG, description = "Entity search API routes"),
+        (name = controller::skill_plan::SKILL_PLAN_TAG, description = "Skill plan API routes"),
+        (name = controller::telemetry::TELEMETRY_TAG, description = "Telemetry API routes"),
+        (name = controller::webhook::WEBHOOK_TAG, description = "Character ownership webhook API routes"),
+        (name = controller::widget::WIDGET_TAG, description = "Embeddable widget API routes"),
+        (name = controller::worker::WORKER_TAG, description = "Admin worker API routes"),
     ))]
     struct ApiDoc;
 
-    let (routes, api) = OpenApiRouter::with_openapi(ApiDoc::openapi())
+    OpenApiRouter::with_openapi(ApiDoc::openapi())
         .routes(routes!(controller::auth::login))
         .routes(routes!(controller::auth::callback))
         .routes(routes!(controller::auth::logout))
         .routes(routes!(controller::auth::get_user))
+        .routes(routes!(
+            controller::auth::get_link_mode,
+            controller::auth::exit_link_mode
+        ))
+        .routes(routes!(controller::auth::get_pending_transfer))
+        .routes(routes!(controller::auth::confirm_transfer))
+        .routes(routes!(controller::auth::discord_login))
+        .routes(routes!(controller::auth::discord_callback))
+        .routes(routes!(controller::branding::get_branding))
         .routes(routes!(controller::user::get_user_characters))
-        .split_for_parts();
-
-    routes.merge(SwaggerUi::new("/api/docs").url("/api/docs/openapi.json", api))
+        .routes(routes!(controller::user::get_character_skills))
+        .routes(routes!(
+            controller::user::get_discord_account,
+            controller::user::unlink_discord_account
+        ))
+        .routes(routes!(controller::user::get_sessions))
+        .routes(routes!(controller::user::revoke_session))
+        .routes(routes!(controller::consent::get_consents))
+        .routes(routes!(
+            controller::consent::grant_consent,
+            controller::consent::revoke_consent
+        ))
+        .routes(routes!(
+            controller::doctrine::create_fitting,
+            controller::doctrine::get_fittings
+        ))
+        .routes(routes!(controller::doctrine::delete_fitting))
+        .routes(routes!(
+            controller::doctrine::get_fitting_skills,
+            controller::doctrine::set_fitting_skills
+        ))
+        .routes(routes!(
+            controller::doctrine::create_doctrine,
+            controller::doctrine::get_doctrines
+        ))
+        .routes(routes!(controller::doctrine::delete_doctrine))
+        .routes(routes!(
+            controller::doctrine::add_doctrine_fitting,
+            controller::doctrine::remove_doctrine_fitting
+        ))
+        .routes(routes!(controller::doctrine::get_doctrine_readiness))
+        .routes(routes!(controller::recruitment::get_recruitment_listings))
+        .routes(routes!(
+            controller::recruitment::upsert_recruitment_listing,
+            controller::recruitment::delete_recruitment_listing
+        ))
+        .routes(routes!(controller::screening::create_screening_report))
+        .routes(routes!(controller::screening::get_screening_report))
+        .routes(routes!(
+            controller::affiliation_history::get_affiliation_history
+        ))
+        .routes(routes!(
+            controller::corporation_member::get_corporation_members
+        ))
+        .routes(routes!(
+            controller::doctrine::get_corporation_doctrine_readiness
+        ))
+        .routes(routes!(controller::search::search))
+        .routes(routes!(controller::image::get_image))
+        .routes(routes!(controller::telemetry::get_telemetry_status))
+        .routes(routes!(controller::widget::get_widget_data))
+        .routes(routes!(controller::widget::get_widget_embed))
+        .routes(routes!(
+            controller::widget::get_widgets,
+            controller::widget::create_widget
+        ))
+        .routes(routes!(controller::widget::delete_widget))
+        .routes(routes!(controller::export::export_characters))
+        .routes(routes!(controller::export::store_characters_export))
+        .routes(routes!(controller::export::request_user_data_export))
+        .routes(routes!(controller::dashboard::get_dashboard_summary))
+        .routes(routes!(controller::diagnostics::get_diagnostics))
+        .routes(routes!(controller::user::merge_users))
+        .routes(routes!(controller::scheduler::preview_scheduler))
+        .routes(routes!(controller::scheduler::get_freshness))
+        .routes(routes!(controller::worker::get_queue))
+        .routes(routes!(controller::worker::get_dead_letters))
+        .routes(routes!(controller::worker::replay_dead_letter))
+        .routes(routes!(controller::worker::get_job_status))
+        .routes(routes!(controller::metrics::get_metrics))
+        .routes(routes!(
+            controller::skill_plan::create_skill_plan,
+            controller::skill_plan::get_skill_plans
+        ))
+        .routes(routes!(controller::skill_plan::delete_skill_plan))
+        .routes(routes!(
+            controller::campaign::create_campaign,
+            controller::campaign::get_campaigns
+        ))
+        .routes(routes!(controller::campaign::delete_campaign))
+        .routes(routes!(controller::page::get_pages))
+        .routes(routes!(controller::page::get_page))
+        .routes(routes!(controller::page::render_page))
+        .routes(routes!(
+            controller::page::save_page,
+            controller::page::delete_page
+        ))
+        .routes(routes!(controller::page::get_page_revisions))
+        .routes(routes!(controller::page::restore_page_revision))
+        .routes(routes!(
+            controller::announcement::create_announcement,
+            controller::announcement::get_announcements
+        ))
+        .routes(routes!(controller::announcement::get_inbox))
+        .routes(routes!(controller::announcement::mark_announcement_read))
+        .routes(routes!(
+            controller::reauth_campaign::create_reauth_campaign,
+            controller::reauth_campaign::get_reauth_campaigns
+        ))
+        .routes(routes!(
+            controller::reauth_campaign::get_pending_reauth_campaigns
+        ))
+        .routes(routes!(
+            controller::onboarding::create_onboarding_step,
+            controller::onboarding::get_onboarding_steps
+        ))
+        .routes(routes!(controller::onboarding::delete_onboarding_step))
+        .routes(routes!(controller::onboarding::get_incomplete_onboarding))
+        .routes(routes!(controller::onboarding::get_onboarding_checklist))
+        .routes(routes!(controller::onboarding::complete_onboarding_step))
+        .routes(routes!(controller::data_api::list_data_queries))
+        .routes(routes!(controller::data_api::run_data_query))
+        .routes(routes!(controller::data_api::get_saved_queries))
+        .routes(routes!(
+            controller::data_api::save_saved_query,
+            controller::data_api::delete_saved_query
+        ))
+        .routes(routes!(
+            controller::data_api::get_api_keys,
+            controller::data_api::create_api_key
+        ))
+        .routes(routes!(controller::data_api::delete_api_key))
+        .routes(routes!(controller::annotation::get_annotations))
+        .routes(routes!(controller::annotation::add_tag))
+        .routes(routes!(controller::annotation::add_note))
+        .routes(routes!(controller::annotation::find_by_tag))
+        .routes(routes!(controller::annotation::remove_tag))
+        .routes(routes!(controller::annotation::delete_note))
+        .routes(routes!(controller::member::get_member_users))
+        .routes(routes!(controller::member::get_member_characters))
+        .routes(routes!(
+            controller::member::get_member_filters,
+            controller::member::save_member_filter
+        ))
+        .routes(routes!(controller::member::delete_member_filter))
+        .routes(routes!(controller::member::run_member_bulk_action))
+        .routes(routes!(
+            controller::maintenance::get_read_only,
+            controller::maintenance::set_read_only
+        ))
+        .routes(routes!(controller::approval::get_approval_requests))
+        .routes(routes!(controller::approval::approve_request))
+        .routes(routes!(controller::approval::reject_request))
+        .routes(routes!(
+            controller::ban::get_user_bans,
+            controller::ban::ban_user
+        ))
+        .routes(routes!(controller::ban::lift_ban))
+        .routes(routes!(controller::group::get_user_groups))
+        .routes(routes!(controller::group::join_group))
+        .routes(routes!(controller::group::leave_group))
+        .routes(routes!(
+            controller::group::get_groups,
+            controller::group::create_group
+        ))
+        .routes(routes!(controller::group::delete_group))
+        .routes(routes!(controller::group::get_group_members))
+        .routes(routes!(
+            controller::group::add_group_member,
+            controller::group::remove_group_member
+        ))
+        .routes(routes!(controller::group::approve_group_member))
+        .routes(routes!(
+            controller::group::get_group_rules,
+            controller::group::add_group_rule
+        ))
+        .routes(routes!(controller::group::remove_group_rule))
+        .routes(routes!(
+            controller::preference::get_preferences,
+            controller::preference::update_preferences
+        ))
+        .routes(routes!(controller::push::get_push_config))
+        .routes(routes!(
+            controller::push::create_push_subscription,
+            controller::push::delete_push_subscription
+        ))
+        .routes(routes!(controller::push::send_test_push_notification))
+        .routes(routes!(
+            controller::webhook::create_webhook,
+            controller::webhook::get_webhooks
+        ))
+        .routes(routes!(
+            controller::webhook::update_webhook,
+            controller::webhook::delete_webhook
+        ))
+        .routes(routes!(
+            controller::role::get_roles,
+            controller::role::create_role
+        ))
+        .routes(routes!(
+            controller::role::update_role,
+            controller::role::delete_role
+        ))
+        .routes(routes!(
+            controller::role::assign_role,
+            controller::role::revoke_role
+        ))
+        .routes(routes!(controller::role::get_permissions))
+        .routes(routes!(controller::pwa::get_manifest))
+        .routes(routes!(controller::pwa::get_service_worker))
+        .routes(routes!(controller::pwa::get_icon))
 }
@@ -0,0 +1,83 @@
+//! Character skill snapshot refresh scheduling.
+//!
+//! This module schedules the periodic refresh of the skill snapshots of characters whose
+//! stored token grants the skills scope. A job is enqueued per character whose snapshot is
+//! missing or older than the cache duration, except for characters whose refresh failed
+//! permanently within the hold-off period, e.g. because the token was revoked, so a broken
+//! token isn't retried every run.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use dioxus_logger::tracing;
+
+use crate::{
+    model::character_skill::CHARACTER_SKILLS_SCOPE,
+    server::{
+        data::{
+            character_skill::CharacterSkillRepository, character_token::CharacterTokenRepository,
+            job_history::JobHistoryRepository,
+        },
+        error::AppError,
+        model::worker::WorkerJob,
+        scheduler::{
+            config::character_skill::{CACHE_DURATION, FAILURE_HOLD_OFF},
+            SchedulerState,
+        },
+    },
+};
+
+/// Schedules skill refresh jobs for characters with an expired snapshot to the worker queue.
+///
+/// # Arguments
+/// - `state` - Scheduler state containing the database connection and worker queue
+///
+/// # Returns
+/// - `Ok(usize)` - Number of refresh jobs scheduled, excluding jobs already queued
+/// - `Err(AppError)` - Failed to query characters or enqueue a job
+pub async fn schedule_character_skill_update(state: SchedulerState) -> Result<usize, AppError> {
+    let characters = CharacterTokenRepository::new(&state.db)
+        .get_character_ids_with_scope(CHARACTER_SKILLS_SCOPE)
+        .await?;
+
+    let record_ids: Vec<i32> = characters.iter().map(|(record_id, _)| *record_id).collect();
+    let last_updated: HashMap<i32, _> = CharacterSkillRepository::new(&state.db)
+        .get_last_updated(&record_ids)
+        .await?
+        .into_iter()
+        .collect();
+
+    let now = Utc::now().naive_utc();
+    let failed_ids = match JobHistoryRepository::new(&state.db)
+        .get_failed_entity_ids("UpdateCharacterSkills", now - FAILURE_HOLD_OFF)
+        .await
+    {
+        Ok(failed_ids) => failed_ids,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to get characters whose skill refresh recently failed: {:?}",
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    let mut scheduled = 0;
+    for (record_id, character_id) in characters {
+        let expired = last_updated
+            .get(&record_id)
+            .is_none_or(|updated_at| *updated_at < now - CACHE_DURATION);
+        if !expired || failed_ids.contains(&character_id) {
+            continue;
+        }
+
+        let was_scheduled = state
+            .queue
+            .push(WorkerJob::UpdateCharacterSkills { character_id })
+            .await?;
+
+        scheduled += usize::from(was_scheduled);
+    }
+
+    Ok(scheduled)
+}
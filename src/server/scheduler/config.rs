@@ -1,11 +1,141 @@
-//! Configuration constants for scheduler cache durations and cron expressions.
+//! Configuration for scheduler cache durations, cron expressions, and refresh batching.
 //!
-//! This module defines cache durations, scheduling intervals, and cron expressions for all
-//! EVE Online entity types that the scheduler manages. Each entity type has its own
-//! submodule with constants that control when and how often data is refreshed.
+//! This module defines cache durations, scheduling intervals, and cron expressions for all EVE
+//! Online entity types that the scheduler manages, as well as the schedules for the admin
+//! dashboard summaries, corporation member lists, character skill snapshots, revoked token
+//! pruning, and the opt-in telemetry report. Each entity type has its own submodule with
+//! constants that control when and how often data is refreshed. The `SchedulerSettings` loaded
+//! from the environment control how many entities each run refreshes and how their jobs are
+//! spread out, while the `CronSchedules` loaded from the environment can override each job's
+//! cron expression.
 
 use chrono::Duration;
 
+use crate::server::scheduler::schedule::MIN_BATCH_LIMIT;
+
+/// Batch sizes, stagger window, and jitter applied to scheduled entity refreshes.
+///
+/// The defaults stagger each entity type's jobs across its schedule interval, refresh at least
+/// 100 entities per run, add no jitter, and don't shard affiliation updates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchedulerSettings {
+    /// Window refresh jobs are staggered across, `None` to use each entity type's schedule
+    /// interval.
+    ///
+    /// Shorter windows let small deployments finish each run sooner, longer windows let large
+    /// deployments spread the load of a run over hours. Batch sizes grow with windows longer
+    /// than the schedule interval so the whole cache period is still covered.
+    pub stagger_window: Option<Duration>,
+    /// Minimum number of entities refreshed per run.
+    pub min_batch_size: u64,
+    /// Maximum number of entities refreshed per run, `None` for no limit.
+    pub max_batch_size: Option<u64>,
+    /// Maximum random delay added to each job's staggered execution time.
+    pub jitter: Duration,
+    /// Number of shards characters are partitioned into by `character_id % shards` for
+    /// affiliation updates, `1` to consider every character on each run.
+    ///
+    /// Each run refreshes a single shard, bounding the characters a run queries and schedules
+    /// on large character tables while every shard is still refreshed once per cycle.
+    pub affiliation_shards: u32,
+}
+
+impl Default for SchedulerSettings {
+    fn default() -> Self {
+        Self {
+            stagger_window: None,
+            min_batch_size: MIN_BATCH_LIMIT,
+            max_batch_size: None,
+            jitter: Duration::zero(),
+            affiliation_shards: 1,
+        }
+    }
+}
+
+impl SchedulerSettings {
+    /// Returns the window jobs of an entity type are staggered across.
+    ///
+    /// # Arguments
+    /// - `schedule_interval` - How frequently the scheduler runs for the entity type
+    ///
+    /// # Returns
+    /// - `Duration` - The configured stagger window, or the schedule interval if unset
+    pub fn stagger_window(&self, schedule_interval: Duration) -> Duration {
+        self.stagger_window.unwrap_or(schedule_interval)
+    }
+}
+
+/// Cron expressions the built-in scheduled jobs run on.
+///
+/// The defaults are the `CRON_EXPRESSION` constants of each job's submodule. Operators can
+/// override them to tune refresh cadence without recompiling; the schedule intervals used to
+/// stagger entity refreshes are unaffected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedules {
+    /// Cron expression for faction info updates.
+    pub faction: String,
+    /// Cron expression for alliance info updates.
+    pub alliance: String,
+    /// Cron expression for corporation info updates.
+    pub corporation: String,
+    /// Cron expression for character info updates.
+    pub character: String,
+    /// Cron expression for character affiliation updates.
+    pub character_affiliation: String,
+    /// Cron expression for admin dashboard summary refreshes.
+    pub dashboard: String,
+    /// Cron expression for corporation member list refreshes.
+    pub corporation_member: String,
+    /// Cron expression for character skill snapshot refreshes.
+    pub character_skill: String,
+    /// Cron expression for pruning revoked character tokens.
+    pub token_prune: String,
+    /// Cron expression for sending the weekly digest.
+    pub digest: String,
+    /// Cron expression for sending the telemetry report.
+    pub telemetry: String,
+}
+
+impl Default for CronSchedules {
+    fn default() -> Self {
+        Self {
+            faction: eve::faction::CRON_EXPRESSION.to_string(),
+            alliance: eve::alliance::CRON_EXPRESSION.to_string(),
+            corporation: eve::corporation::CRON_EXPRESSION.to_string(),
+            character: eve::character::CRON_EXPRESSION.to_string(),
+            character_affiliation: eve::character_affiliation::CRON_EXPRESSION.to_string(),
+            dashboard: dashboard::CRON_EXPRESSION.to_string(),
+            corporation_member: corporation_member::CRON_EXPRESSION.to_string(),
+            character_skill: character_skill::CRON_EXPRESSION.to_string(),
+            token_prune: token_prune::CRON_EXPRESSION.to_string(),
+            digest: digest::CRON_EXPRESSION.to_string(),
+            telemetry: telemetry::CRON_EXPRESSION.to_string(),
+        }
+    }
+}
+
+impl CronSchedules {
+    /// Returns the name and cron expression of each built-in scheduled job.
+    ///
+    /// # Returns
+    /// - `Vec<(&'static str, &str)>` - Job names as used in log messages and their cron expressions
+    pub fn jobs(&self) -> Vec<(&'static str, &str)> {
+        vec![
+            ("faction info", &self.faction),
+            ("alliance info", &self.alliance),
+            ("corporation info", &self.corporation),
+            ("character info", &self.character),
+            ("character affiliation", &self.character_affiliation),
+            ("dashboard summary", &self.dashboard),
+            ("corporation member", &self.corporation_member),
+            ("character skill", &self.character_skill),
+            ("revoked token prune", &self.token_prune),
+            ("weekly digest", &self.digest),
+            ("telemetry report", &self.telemetry),
+        ]
+    }
+}
+
 pub mod eve {
     //! EVE Online entity scheduling configuration.
     //!
@@ -151,3 +281,97 @@ pub mod eve {
         pub const CRON_EXPRESSION: &str = "0 2,12,22,32,42,52 * * * *";
     }
 }
+
+pub mod dashboard {
+    //! Admin dashboard summary scheduling configuration.
+    //!
+    //! Dashboard summaries aggregate the user tables, so they are refreshed periodically
+    //! rather than on every dashboard load.
+
+    /// Cron expression for refreshing the admin dashboard summaries.
+    ///
+    /// Runs every 15 minutes at :04, :19, :34, and :49 past the hour.
+    /// Offset from the entity refresh schedules to distribute scheduler load.
+    pub const CRON_EXPRESSION: &str = "0 4,19,34,49 * * * *";
+}
+
+pub mod corporation_member {
+    //! Corporation member list scheduling configuration.
+    //!
+    //! ESI caches corporation member lists for an hour, so tracked corporations are refreshed
+    //! hourly. Refreshes that failed permanently are held off for a day.
+
+    use super::*;
+
+    /// Hold off corporations whose member list refresh failed permanently for 1 day.
+    ///
+    /// Failures are usually caused by a revoked token or a director losing their role, which
+    /// only a director logging in again fixes.
+    pub const FAILURE_HOLD_OFF: Duration = Duration::hours(24);
+
+    /// Cron expression for refreshing corporation member lists.
+    ///
+    /// Runs every hour at :24 past the hour.
+    /// Offset from the entity refresh schedules to distribute scheduler load.
+    pub const CRON_EXPRESSION: &str = "0 24 * * * *";
+}
+
+pub mod character_skill {
+    //! Character skill snapshot scheduling configuration.
+    //!
+    //! Skills change slowly, so snapshots are refreshed twice per day. The schedule runs hourly
+    //! to pick up characters whose snapshot expired, and refreshes that failed permanently are
+    //! held off for a day.
+
+    use super::*;
+
+    /// Cache skill snapshots for 12 hours.
+    pub const CACHE_DURATION: Duration = Duration::hours(12);
+
+    /// Hold off characters whose skill refresh failed permanently for 1 day.
+    ///
+    /// Failures are usually caused by a revoked token, which only the character logging in
+    /// again fixes.
+    pub const FAILURE_HOLD_OFF: Duration = Duration::hours(24);
+
+    /// Cron expression for refreshing character skill snapshots.
+    ///
+    /// Runs every hour at :39 past the hour.
+    /// Offset from the entity refresh schedules to distribute scheduler load.
+    pub const CRON_EXPRESSION: &str = "0 39 * * * *";
+}
+
+pub mod token_prune {
+    //! Revoked character token pruning scheduling configuration.
+    //!
+    //! Checking a token exchanges it with EVE SSO, so stored tokens are checked once per day.
+
+    /// Cron expression for pruning revoked character tokens.
+    ///
+    /// Runs daily at 03:53 UTC, away from ESI downtime and the entity refresh schedules.
+    pub const CRON_EXPRESSION: &str = "0 53 3 * * *";
+}
+
+pub mod digest {
+    //! Weekly digest scheduling configuration.
+    //!
+    //! The digest summarizes the past and coming week, so it is sent once per week.
+
+    /// Cron expression for sending the weekly digest.
+    ///
+    /// Runs every Monday at 08:17 UTC, before ESI downtime and away from the entity refresh
+    /// schedules.
+    pub const CRON_EXPRESSION: &str = "0 17 8 * * Mon";
+}
+
+pub mod telemetry {
+    //! Telemetry report scheduling configuration.
+    //!
+    //! The anonymous telemetry report only changes when the deployment is upgraded or its
+    //! user count crosses a bucket boundary, so it is sent once per day.
+
+    /// Cron expression for sending the telemetry report.
+    ///
+    /// Runs daily at 04:23 UTC, away from ESI downtime and the entity refresh schedules.
+    pub const CRON_EXPRESSION: &str = "0 23 4 * * *";
+}
@@ -0,0 +1,61 @@
+//! Corporation member list refresh scheduling.
+//!
+//! This module schedules the periodic refresh of the member lists of corporations whose
+//! director granted the membership scope. A job is enqueued per tracked corporation, except
+//! for corporations whose refresh failed permanently within the hold-off period, e.g. because
+//! the director's token was revoked, so a broken token isn't retried every run.
+
+use chrono::Utc;
+use dioxus_logger::tracing;
+
+use crate::server::{
+    data::{corporation_member::CorporationMemberRepository, job_history::JobHistoryRepository},
+    error::AppError,
+    model::worker::WorkerJob,
+    scheduler::{config::corporation_member::FAILURE_HOLD_OFF, SchedulerState},
+};
+
+/// Schedules member list refresh jobs for every tracked corporation to the worker queue.
+///
+/// # Arguments
+/// - `state` - Scheduler state containing the database connection and worker queue
+///
+/// # Returns
+/// - `Ok(usize)` - Number of refresh jobs scheduled, excluding jobs already queued
+/// - `Err(AppError)` - Failed to query tracked corporations or enqueue a job
+pub async fn schedule_corporation_member_update(state: SchedulerState) -> Result<usize, AppError> {
+    let corporation_ids = CorporationMemberRepository::new(&state.db)
+        .get_tracked_corporation_ids()
+        .await?;
+
+    let since = Utc::now().naive_utc() - FAILURE_HOLD_OFF;
+    let failed_ids = match JobHistoryRepository::new(&state.db)
+        .get_failed_entity_ids("UpdateCorporationMembers", since)
+        .await
+    {
+        Ok(failed_ids) => failed_ids,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to get corporations whose member list refresh recently failed: {:?}",
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    let mut scheduled = 0;
+    for corporation_id in corporation_ids {
+        if failed_ids.contains(&corporation_id) {
+            continue;
+        }
+
+        let was_scheduled = state
+            .queue
+            .push(WorkerJob::UpdateCorporationMembers { corporation_id })
+            .await?;
+
+        scheduled += usize::from(was_scheduled);
+    }
+
+    Ok(scheduled)
+}
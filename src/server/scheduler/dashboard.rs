@@ -0,0 +1,24 @@
+//! Admin dashboard summary refresh scheduling.
+//!
+//! This module schedules the periodic refresh of the precomputed admin dashboard summaries.
+//! Each refresh recomputes every summary, so a single job is enqueued per run.
+
+use crate::server::{error::AppError, model::worker::WorkerJob, scheduler::SchedulerState};
+
+/// Schedules a dashboard summary refresh to the worker queue.
+///
+/// # Arguments
+/// - `state` - Scheduler state containing the worker queue
+///
+/// # Returns
+/// - `Ok(1)` - Successfully scheduled the refresh job
+/// - `Ok(0)` - A refresh job is already queued
+/// - `Err(AppError)` - Failed to enqueue the job to the worker queue
+pub async fn schedule_dashboard_summary_refresh(state: SchedulerState) -> Result<usize, AppError> {
+    let was_scheduled = state
+        .queue
+        .push(WorkerJob::RefreshDashboardSummaries)
+        .await?;
+
+    Ok(usize::from(was_scheduled))
+}
@@ -0,0 +1,21 @@
+//! Weekly digest scheduling.
+//!
+//! This module schedules the weekly digest. Each digest covers the whole organization, so a
+//! single job is enqueued per run.
+
+use crate::server::{error::AppError, model::worker::WorkerJob, scheduler::SchedulerState};
+
+/// Schedules the weekly digest to the worker queue.
+///
+/// # Arguments
+/// - `state` - Scheduler state containing the worker queue
+///
+/// # Returns
+/// - `Ok(1)` - Successfully scheduled the digest job
+/// - `Ok(0)` - A digest job is already queued
+/// - `Err(AppError)` - Failed to enqueue the job to the worker queue
+pub async fn schedule_weekly_digest(state: SchedulerState) -> Result<usize, AppError> {
+    let was_scheduled = state.queue.push(WorkerJob::SendWeeklyDigest).await?;
+
+    Ok(usize::from(was_scheduled))
+}
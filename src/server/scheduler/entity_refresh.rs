@@ -3,15 +3,19 @@
 //! This module provides a generic system for tracking when cached entity data expires and
 //! scheduling refresh jobs via the worker queue. The `SchedulableEntity` trait allows any
 //! EVE entity type (alliances, corporations, characters, etc.) to participate in the
-//! scheduled refresh system by specifying their update timestamp and ID columns.
+//! scheduled refresh system by specifying their update timestamp and ID columns, and to report
+//! how fresh its cached data is. Entities whose last refresh failed permanently are held off
+//! for a cache period, so broken entries don't fail again every run.
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
 use dioxus_logger::tracing;
 use sea_orm::{
+    sea_query::{Condition, ExprTrait},
     ColumnTrait, EntityTrait, IntoSimpleExpr, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
 };
 
 use crate::server::{
+    data::job_history::JobHistoryRepository,
     error::AppError,
     model::worker::WorkerJob,
     scheduler::{
@@ -43,6 +47,32 @@ pub trait SchedulableEntity {
     /// This ID is used to construct worker jobs for refreshing specific entities.
     /// For example, `alliance_id` for alliances or `character_id` for characters.
     fn id_column() -> impl ColumnTrait + IntoSimpleExpr;
+
+    /// Returns the name of the worker job type refreshing a single entity, if any.
+    ///
+    /// When set, entities whose refresh job failed permanently within the cache duration are
+    /// skipped, as recorded in the worker job history. Entities refreshed in batches don't
+    /// record an entity per job and return `None`.
+    fn job_kind() -> Option<&'static str> {
+        None
+    }
+}
+
+/// Distribution of the time since entities of a type were last refreshed.
+///
+/// Ages are `None` when the table has no entries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EntityFreshness {
+    /// Number of entries in the table.
+    pub count: u64,
+    /// Number of entries whose cache has expired.
+    pub overdue: u64,
+    /// Median time since an entry was refreshed.
+    pub p50_age: Option<Duration>,
+    /// Time since refresh that 95% of entries are within.
+    pub p95_age: Option<Duration>,
+    /// Time since the least recently refreshed entry was refreshed.
+    pub max_age: Option<Duration>,
 }
 
 /// Tracks and schedules refresh jobs for entities with expiring cached data.
@@ -87,7 +117,8 @@ impl<'a> EntityRefreshTracker<'a> {
     /// Queries the database for entities whose `updated_at` timestamp is older than the
     /// cache expiration threshold, orders them by staleness (oldest first), and limits
     /// the result to an appropriate batch size. The batch size is calculated to spread
-    /// all entity updates evenly across the cache duration.
+    /// all entity updates evenly across the cache duration, within the batch sizes configured
+    /// in the scheduler settings.
     ///
     /// # Arguments
     /// - `S` - The `SchedulableEntity` type to query for (e.g., `AllianceInfo`, `CharacterInfo`)
@@ -108,7 +139,89 @@ impl<'a> EntityRefreshTracker<'a> {
         S::Entity: Send + Sync,
         <S::Entity as EntityTrait>::Model: Send + Sync,
     {
-        let table_entries = S::Entity::find().count(&self.state.db).await?;
+        self.find_expired_entries::<S>(Condition::all(), self.schedule_interval)
+            .await
+    }
+
+    /// Finds entity IDs needing a refresh within a single shard of the table.
+    ///
+    /// Entities are partitioned by `id % shards`, so each entity stays in the same shard as the
+    /// table grows. Each call refreshes the shard holding the entity whose cache expired the
+    /// longest ago, which rotates through the shards as they are refreshed and keeps any shard
+    /// from falling behind regardless of how often the scheduler runs. Batch sizes are
+    /// calculated from the shard's entries and the time it takes to cycle through every shard,
+    /// so each shard is covered once per cycle.
+    ///
+    /// # Arguments
+    /// - `S` - The `SchedulableEntity` type to query for (e.g., `CharacterAffiliation`)
+    /// - `shards` - Number of shards the table is partitioned into, `1` to query the whole table
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i64>)` - EVE entity IDs of a single shard that need updates
+    /// - `Err(AppError)` - Database query failed
+    pub async fn find_shard_entries_needing_update<S>(
+        &self,
+        shards: u32,
+    ) -> Result<Vec<i64>, AppError>
+    where
+        S: SchedulableEntity + Send + Sync,
+        S::Entity: Send + Sync,
+        <S::Entity as EntityTrait>::Model: Send + Sync,
+    {
+        if shards <= 1 {
+            return self.find_entries_needing_update::<S>().await;
+        }
+
+        let cache_expiry_threshold = Utc::now().naive_utc() - self.cache_duration;
+        let stalest_id: Option<i64> = S::Entity::find()
+            .filter(S::updated_at_column().lt(cache_expiry_threshold))
+            .order_by_asc(S::updated_at_column())
+            .select_only()
+            .column(S::id_column())
+            .into_tuple()
+            .one(&self.state.db)
+            .await?;
+
+        let Some(stalest_id) = stalest_id else {
+            return Ok(Vec::new());
+        };
+
+        let shard = stalest_id.rem_euclid(shards.into());
+        let shard_condition = Condition::all().add(
+            S::id_column()
+                .into_simple_expr()
+                .modulo(i64::from(shards))
+                .eq(shard),
+        );
+
+        self.find_expired_entries::<S>(shard_condition, self.schedule_interval * shards as i32)
+            .await
+    }
+
+    /// Finds the expired entity IDs matching a condition, oldest first.
+    ///
+    /// # Arguments
+    /// - `S` - The `SchedulableEntity` type to query for
+    /// - `condition` - Condition limiting the entities considered
+    /// - `cycle_interval` - How often the entities matching the condition are queried
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i64>)` - EVE entity IDs that need updates, limited to the batch size
+    /// - `Err(AppError)` - Database query failed
+    async fn find_expired_entries<S>(
+        &self,
+        condition: Condition,
+        cycle_interval: Duration,
+    ) -> Result<Vec<i64>, AppError>
+    where
+        S: SchedulableEntity + Send + Sync,
+        S::Entity: Send + Sync,
+        <S::Entity as EntityTrait>::Model: Send + Sync,
+    {
+        let table_entries = S::Entity::find()
+            .filter(condition.clone())
+            .count(&self.state.db)
+            .await?;
         if table_entries == 0 {
             return Ok(Vec::new());
         }
@@ -116,14 +229,46 @@ impl<'a> EntityRefreshTracker<'a> {
         let now = Utc::now().naive_utc();
         let cache_expiry_threshold = now - self.cache_duration;
 
-        let max_batch_size = calculate_batch_limit(
+        let settings = &self.state.settings;
+        // A stagger window longer than the schedule interval delays jobs past the next run, so
+        // batches must cover the window to keep up with the cache period
+        let batch_interval = settings
+            .stagger_window(self.schedule_interval)
+            .max(cycle_interval);
+        let mut max_batch_size = calculate_batch_limit(
             table_entries,
             self.cache_duration,
-            self.schedule_interval,
+            batch_interval,
+            settings.min_batch_size,
             self.state.offset_for_esi_downtime,
         );
+        if let Some(limit) = settings.max_batch_size {
+            max_batch_size = max_batch_size.min(limit);
+        }
+
+        let mut condition = condition;
+        if let Some(job_kind) = S::job_kind() {
+            // Hold off entries whose refresh failed permanently until a cache period has passed
+            match JobHistoryRepository::new(&self.state.db)
+                .get_failed_entity_ids(job_kind, cache_expiry_threshold)
+                .await
+            {
+                Ok(failed_ids) if !failed_ids.is_empty() => {
+                    condition = condition.add(S::id_column().is_not_in(failed_ids));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to get entities whose {} job recently failed: {:?}",
+                        job_kind,
+                        e
+                    );
+                }
+            }
+        }
 
         let ids: Vec<i64> = S::Entity::find()
+            .filter(condition)
             // Only update entries after their cache has expired to get fresh data
             .filter(S::updated_at_column().lt(cache_expiry_threshold))
             .order_by_asc(S::updated_at_column())
@@ -137,10 +282,112 @@ impl<'a> EntityRefreshTracker<'a> {
         Ok(ids)
     }
 
-    /// Schedules worker jobs with staggered execution times across the scheduling interval.
+    /// Computes the distribution of the time since entities were last refreshed.
+    ///
+    /// Percentiles use the nearest-rank method, reading the refresh timestamp at the rank's
+    /// position in an indexed order instead of loading every timestamp, so the cost stays
+    /// low on large tables.
+    ///
+    /// # Arguments
+    /// - `S` - The `SchedulableEntity` type to report on
+    ///
+    /// # Returns
+    /// - `Ok(EntityFreshness)` - Entry counts and refresh age percentiles
+    /// - `Err(AppError)` - Database query failed
+    pub async fn freshness<S>(&self) -> Result<EntityFreshness, AppError>
+    where
+        S: SchedulableEntity + Send + Sync,
+        S::Entity: Send + Sync,
+        <S::Entity as EntityTrait>::Model: Send + Sync,
+    {
+        let count = S::Entity::find().count(&self.state.db).await?;
+        if count == 0 {
+            return Ok(EntityFreshness::default());
+        }
+
+        let now = Utc::now().naive_utc();
+        let overdue = S::Entity::find()
+            .filter(S::updated_at_column().lt(now - self.cache_duration))
+            .count(&self.state.db)
+            .await?;
+
+        Ok(EntityFreshness {
+            count,
+            overdue,
+            p50_age: self
+                .age_at_rank::<S>(percentile_rank(50, count), now)
+                .await?,
+            p95_age: self
+                .age_at_rank::<S>(percentile_rank(95, count), now)
+                .await?,
+            max_age: self.age_at_rank::<S>(count, now).await?,
+        })
+    }
+
+    /// Gets the time since the entry at a rank, ordered from most to least recently refreshed,
+    /// was refreshed.
+    ///
+    /// # Arguments
+    /// - `S` - The `SchedulableEntity` type to query
+    /// - `rank` - 1-based position of the entry
+    /// - `now` - Time to measure the age from
+    ///
+    /// # Returns
+    /// - `Ok(Some(Duration))` - Age of the entry, zero if refreshed after `now`
+    /// - `Ok(None)` - The table has fewer entries than the rank
+    /// - `Err(AppError)` - Database query failed
+    async fn age_at_rank<S>(
+        &self,
+        rank: u64,
+        now: NaiveDateTime,
+    ) -> Result<Option<Duration>, AppError>
+    where
+        S: SchedulableEntity + Send + Sync,
+        S::Entity: Send + Sync,
+        <S::Entity as EntityTrait>::Model: Send + Sync,
+    {
+        let updated_at: Option<NaiveDateTime> = S::Entity::find()
+            .select_only()
+            .column(S::updated_at_column())
+            .order_by_desc(S::updated_at_column())
+            .offset(rank.saturating_sub(1))
+            .limit(1)
+            .into_tuple()
+            .one(&self.state.db)
+            .await?;
+
+        Ok(updated_at.map(|updated_at| (now - updated_at).max(Duration::zero())))
+    }
+
+    /// Computes the staggered execution times for worker jobs without scheduling them.
+    ///
+    /// Uses the same schedule as `schedule_jobs`, so the result shows what a scheduler run
+    /// would enqueue. Jobs already in the queue would be skipped as duplicates by a real run
+    /// but are included here.
+    ///
+    /// # Arguments
+    /// - `jobs` - Vector of worker jobs to compute execution times for
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(WorkerJob, DateTime<Utc>)>)` - Jobs paired with the times they would be scheduled for
+    /// - `Err(AppError)` - Failed to calculate schedule
+    pub async fn preview_jobs(
+        &self,
+        jobs: Vec<WorkerJob>,
+    ) -> Result<Vec<(WorkerJob, DateTime<Utc>)>, AppError> {
+        create_job_schedule(
+            jobs,
+            self.state.settings.stagger_window(self.schedule_interval),
+            self.state.settings.jitter,
+            self.state.offset_for_esi_downtime,
+        )
+        .await
+    }
+
+    /// Schedules worker jobs with staggered execution times across the stagger window.
     ///
     /// Takes a list of worker jobs and schedules them to execute at evenly distributed times
-    /// across the scheduling window. This spreads API load and worker queue pressure over time
+    /// across the configured stagger window, which defaults to the scheduling interval. This spreads API load and worker queue pressure over time
     /// rather than executing all jobs immediately. Jobs are deduplicated at scheduling time,
     /// so only jobs that aren't already queued will be scheduled.
     ///
@@ -169,7 +416,8 @@ impl<'a> EntityRefreshTracker<'a> {
     {
         let job_schedule = create_job_schedule(
             jobs,
-            self.schedule_interval,
+            self.state.settings.stagger_window(self.schedule_interval),
+            self.state.settings.jitter,
             self.state.offset_for_esi_downtime,
         )
         .await?;
@@ -200,3 +448,15 @@ impl<'a> EntityRefreshTracker<'a> {
         Ok(scheduled_count)
     }
 }
+
+/// Calculates the nearest rank of a percentile among a number of entries.
+///
+/// # Arguments
+/// - `percentile` - Percentile between 0 and 100
+/// - `count` - Number of entries
+///
+/// # Returns
+/// - `u64` - 1-based rank of the entry at the percentile
+fn percentile_rank(percentile: u64, count: u64) -> u64 {
+    (percentile * count).div_ceil(100).max(1)
+}
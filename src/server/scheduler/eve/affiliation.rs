@@ -4,7 +4,8 @@
 //! (corporation, alliance, faction) can change frequently as players join or leave organizations.
 //! It queries the database for characters whose affiliation cache has expired (based on a 1-hour
 //! cache duration) and schedules batch jobs to refresh multiple characters per ESI request,
-//! respecting the 1000-character limit per affiliation API call.
+//! respecting the 1000-character limit per affiliation API call. Large character tables can be
+//! partitioned into shards by `character_id % shards`, with each run refreshing one shard.
 
 use sea_orm::{ColumnTrait, IntoSimpleExpr};
 
@@ -47,6 +48,10 @@ impl SchedulableEntity for CharacterAffiliation {
 /// are batched into groups of up to 1000 character IDs per job to match ESI's bulk affiliation
 /// endpoint limit, reducing API call overhead while keeping affiliation data fresh.
 ///
+/// When `SchedulerSettings::affiliation_shards` is above 1, only the characters of the shard
+/// holding the most overdue character are considered, see
+/// `EntityRefreshTracker::find_shard_entries_needing_update`.
+///
 /// # Arguments
 /// - `state` - Scheduler state containing database connection and worker queue for querying
 ///   characters needing affiliation updates and dispatching refresh jobs
@@ -61,20 +66,16 @@ pub async fn schedule_character_affiliation_update(
 
     // Find characters that need affiliation updates (returns character_ids)
     let character_ids = refresh_tracker
-        .find_entries_needing_update::<CharacterAffiliation>()
+        .find_shard_entries_needing_update::<CharacterAffiliation>(
+            state.settings.affiliation_shards,
+        )
         .await?;
 
     if character_ids.is_empty() {
         return Ok(0);
     }
 
-    // Divide character IDs into batches that respect ESI affiliation request limit of 1000
-    let jobs: Vec<WorkerJob> = character_ids
-        .chunks(ESI_AFFILIATION_REQUEST_LIMIT)
-        .map(|chunk| WorkerJob::UpdateAffiliations {
-            character_ids: chunk.to_vec(),
-        })
-        .collect();
+    let jobs = build_character_affiliation_jobs(character_ids);
 
     let scheduled_job_count = refresh_tracker
         .schedule_jobs::<CharacterAffiliation>(&state.queue, jobs)
@@ -82,3 +83,22 @@ pub async fn schedule_character_affiliation_update(
 
     Ok(scheduled_job_count)
 }
+
+/// Builds affiliation refresh jobs for the characters needing an update.
+///
+/// Divides the character IDs into batches that respect the ESI affiliation request limit
+/// of 1000 characters.
+///
+/// # Arguments
+/// - `character_ids` - EVE Online character IDs whose cached affiliation has expired
+///
+/// # Returns
+/// - `Vec<WorkerJob>` - One `UpdateAffiliations` job per batch of characters
+pub fn build_character_affiliation_jobs(character_ids: Vec<i64>) -> Vec<WorkerJob> {
+    character_ids
+        .chunks(ESI_AFFILIATION_REQUEST_LIMIT)
+        .map(|chunk| WorkerJob::UpdateAffiliations {
+            character_ids: chunk.to_vec(),
+        })
+        .collect()
+}
@@ -35,6 +35,11 @@ impl SchedulableEntity for AllianceInfo {
     fn id_column() -> impl ColumnTrait + IntoSimpleExpr {
         entity::eve_alliance::Column::AllianceId
     }
+
+    /// Returns the `UpdateAllianceInfo` job type whose permanent failures hold off a refresh.
+    fn job_kind() -> Option<&'static str> {
+        Some("UpdateAllianceInfo")
+    }
 }
 
 /// Schedules alliance information refresh jobs for alliances with expired cache data.
@@ -64,10 +69,7 @@ pub async fn schedule_alliance_info_update(state: SchedulerState) -> Result<usiz
     }
 
     // Create and schedule jobs
-    let jobs: Vec<WorkerJob> = alliance_ids
-        .into_iter()
-        .map(|alliance_id| WorkerJob::UpdateAllianceInfo { alliance_id })
-        .collect();
+    let jobs = build_alliance_info_jobs(alliance_ids);
 
     let scheduled_job_count = refresh_tracker
         .schedule_jobs::<AllianceInfo>(&state.queue, jobs)
@@ -75,3 +77,17 @@ pub async fn schedule_alliance_info_update(state: SchedulerState) -> Result<usiz
 
     Ok(scheduled_job_count)
 }
+
+/// Builds alliance information refresh jobs for the alliances needing an update.
+///
+/// # Arguments
+/// - `alliance_ids` - EVE Online alliance IDs whose cached information has expired
+///
+/// # Returns
+/// - `Vec<WorkerJob>` - One `UpdateAllianceInfo` job per alliance
+pub fn build_alliance_info_jobs(alliance_ids: Vec<i64>) -> Vec<WorkerJob> {
+    alliance_ids
+        .into_iter()
+        .map(|alliance_id| WorkerJob::UpdateAllianceInfo { alliance_id })
+        .collect()
+}
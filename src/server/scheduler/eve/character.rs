@@ -35,6 +35,11 @@ impl SchedulableEntity for CharacterInfo {
     fn id_column() -> impl ColumnTrait + IntoSimpleExpr {
         entity::eve_character::Column::CharacterId
     }
+
+    /// Returns the `UpdateCharacterInfo` job type whose permanent failures hold off a refresh.
+    fn job_kind() -> Option<&'static str> {
+        Some("UpdateCharacterInfo")
+    }
 }
 
 /// Schedules character information refresh jobs for characters with expired cache data.
@@ -65,10 +70,7 @@ pub async fn schedule_character_info_update(state: SchedulerState) -> Result<usi
     }
 
     // Create and schedule jobs
-    let jobs: Vec<WorkerJob> = character_ids
-        .into_iter()
-        .map(|character_id| WorkerJob::UpdateCharacterInfo { character_id })
-        .collect();
+    let jobs = build_character_info_jobs(character_ids);
 
     let scheduled_job_count = refresh_tracker
         .schedule_jobs::<CharacterInfo>(&state.queue, jobs)
@@ -76,3 +78,17 @@ pub async fn schedule_character_info_update(state: SchedulerState) -> Result<usi
 
     Ok(scheduled_job_count)
 }
+
+/// Builds character information refresh jobs for the characters needing an update.
+///
+/// # Arguments
+/// - `character_ids` - EVE Online character IDs whose cached information has expired
+///
+/// # Returns
+/// - `Vec<WorkerJob>` - One `UpdateCharacterInfo` job per character
+pub fn build_character_info_jobs(character_ids: Vec<i64>) -> Vec<WorkerJob> {
+    character_ids
+        .into_iter()
+        .map(|character_id| WorkerJob::UpdateCharacterInfo { character_id })
+        .collect()
+}
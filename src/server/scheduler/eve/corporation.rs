@@ -35,6 +35,11 @@ impl SchedulableEntity for CorporationInfo {
     fn id_column() -> impl ColumnTrait + IntoSimpleExpr {
         entity::eve_corporation::Column::CorporationId
     }
+
+    /// Returns the `UpdateCorporationInfo` job type whose permanent failures hold off a refresh.
+    fn job_kind() -> Option<&'static str> {
+        Some("UpdateCorporationInfo")
+    }
 }
 
 /// Schedules corporation information refresh jobs for corporations with expired cache data.
@@ -64,10 +69,7 @@ pub async fn schedule_corporation_info_update(state: SchedulerState) -> Result<u
     }
 
     // Create and schedule jobs
-    let jobs: Vec<WorkerJob> = corporation_ids
-        .into_iter()
-        .map(|corporation_id| WorkerJob::UpdateCorporationInfo { corporation_id })
-        .collect();
+    let jobs = build_corporation_info_jobs(corporation_ids);
 
     let scheduled_job_count = refresh_tracker
         .schedule_jobs::<CorporationInfo>(&state.queue, jobs)
@@ -75,3 +77,17 @@ pub async fn schedule_corporation_info_update(state: SchedulerState) -> Result<u
 
     Ok(scheduled_job_count)
 }
+
+/// Builds corporation information refresh jobs for the corporations needing an update.
+///
+/// # Arguments
+/// - `corporation_ids` - EVE Online corporation IDs whose cached information has expired
+///
+/// # Returns
+/// - `Vec<WorkerJob>` - One `UpdateCorporationInfo` job per corporation
+pub fn build_corporation_info_jobs(corporation_ids: Vec<i64>) -> Vec<WorkerJob> {
+    corporation_ids
+        .into_iter()
+        .map(|corporation_id| WorkerJob::UpdateCorporationInfo { corporation_id })
+        .collect()
+}
@@ -0,0 +1,103 @@
+//! Freshness report of cached EVE Online data.
+//!
+//! This module measures how long ago the entities refreshed by the scheduler were last
+//! updated, so operators can check that the cache durations, schedule intervals, and batch
+//! settings keep data within its freshness target. Factions are refreshed as a whole by a
+//! single job and aren't reported.
+
+use chrono::Duration;
+
+use crate::{
+    model::scheduler::SchedulerJobKind,
+    server::{
+        error::AppError,
+        scheduler::{
+            config::eve::{
+                alliance as alliance_config, character as character_config,
+                character_affiliation as character_affiliation_config,
+                corporation as corporation_config,
+            },
+            entity_refresh::{EntityFreshness, EntityRefreshTracker, SchedulableEntity},
+            eve::{
+                affiliation::CharacterAffiliation, alliance::AllianceInfo,
+                character::CharacterInfo, corporation::CorporationInfo,
+            },
+            SchedulerState,
+        },
+    },
+};
+
+/// Computes the freshness of each entity type refreshed in batches by the scheduler.
+///
+/// # Arguments
+/// - `state` - Scheduler state containing the database connection
+///
+/// # Returns
+/// - `Ok(Vec<(SchedulerJobKind, Duration, EntityFreshness)>)` - Entity types with their cache
+///   duration and freshness
+/// - `Err(AppError)` - Database query failed
+pub async fn freshness_report(
+    state: &SchedulerState,
+) -> Result<Vec<(SchedulerJobKind, Duration, EntityFreshness)>, AppError> {
+    Ok(vec![
+        entity_freshness::<AllianceInfo>(
+            state,
+            SchedulerJobKind::Alliance,
+            alliance_config::CACHE_DURATION,
+            alliance_config::SCHEDULE_INTERVAL,
+        )
+        .await?,
+        entity_freshness::<CorporationInfo>(
+            state,
+            SchedulerJobKind::Corporation,
+            corporation_config::CACHE_DURATION,
+            corporation_config::SCHEDULE_INTERVAL,
+        )
+        .await?,
+        entity_freshness::<CharacterInfo>(
+            state,
+            SchedulerJobKind::Character,
+            character_config::CACHE_DURATION,
+            character_config::SCHEDULE_INTERVAL,
+        )
+        .await?,
+        entity_freshness::<CharacterAffiliation>(
+            state,
+            SchedulerJobKind::Affiliation,
+            character_affiliation_config::CACHE_DURATION,
+            character_affiliation_config::SCHEDULE_INTERVAL,
+        )
+        .await?,
+    ])
+}
+
+/// Computes the freshness of a single entity type.
+///
+/// # Arguments
+/// - `S` - The `SchedulableEntity` type to report on
+/// - `state` - Scheduler state containing the database connection
+/// - `kind` - Scheduled job refreshing the entity type
+/// - `cache_duration` - How long cached entity data remains valid
+/// - `schedule_interval` - How frequently the scheduler checks for expired entities
+///
+/// # Returns
+/// - `Ok((SchedulerJobKind, Duration, EntityFreshness))` - Entity type, cache duration, and
+///   freshness
+/// - `Err(AppError)` - Database query failed
+async fn entity_freshness<S>(
+    state: &SchedulerState,
+    kind: SchedulerJobKind,
+    cache_duration: Duration,
+    schedule_interval: Duration,
+) -> Result<(SchedulerJobKind, Duration, EntityFreshness), AppError>
+where
+    S: SchedulableEntity + Send + Sync,
+    S::Entity: Send + Sync,
+    <S::Entity as sea_orm::EntityTrait>::Model: Send + Sync,
+{
+    let freshness = EntityRefreshTracker::new(state, cache_duration, schedule_interval)
+        .freshness::<S>()
+        .await?;
+
+    Ok((kind, cache_duration, freshness))
+}
@@ -14,25 +14,34 @@ use tokio_cron_scheduler::{Job, JobScheduler};
 
 use crate::server::{error::AppError, worker::WorkerQueue};
 
+use self::config::{CronSchedules, SchedulerSettings};
+
+pub mod character_skill;
 pub mod config;
+pub mod corporation_member;
+pub mod dashboard;
+pub mod digest;
 pub mod entity_refresh;
 pub mod eve;
+pub mod freshness;
+pub mod preview;
 pub mod schedule;
+pub mod telemetry;
+pub mod token;
 
 #[cfg(test)]
 mod tests;
 
+use self::character_skill::schedule_character_skill_update;
+use self::corporation_member::schedule_corporation_member_update;
+use self::dashboard::schedule_dashboard_summary_refresh;
+use self::digest::schedule_weekly_digest;
 use self::eve::{
     affiliation::schedule_character_affiliation_update, alliance::schedule_alliance_info_update,
     character::schedule_character_info_update, corporation::schedule_corporation_info_update,
     faction::schedule_faction_info_update,
 };
-
-use self::config::eve::{
-    alliance as alliance_config, character as character_config,
-    character_affiliation as character_affiliation_config, corporation as corporation_config,
-    faction as faction_config,
-};
+use self::token::schedule_revoked_token_prune;
 
 /// Shared state for scheduler operations and entity refresh tracking.
 ///
@@ -45,6 +54,7 @@ use self::config::eve::{
 /// - `db` - Database connection for querying entities that need cache refresh
 /// - `queue` - Worker queue for dispatching asynchronous refresh tasks
 /// - `offset_for_esi_downtime` - Controls whether job scheduling accounts for ESI downtime
+/// - `settings` - Batch sizes, stagger window, and jitter for entity refreshes
 ///
 /// # Usage
 /// `SchedulerState` is passed to EVE entity scheduling functions (like `schedule_alliance_info_update`)
@@ -68,6 +78,8 @@ pub struct SchedulerState {
     /// Disable for testing to prevent flaky tests that could fail if executed during or near
     /// the ESI downtime window when using `Utc::now()` for time calculations.
     pub offset_for_esi_downtime: bool,
+    /// Batch sizes, stagger window, and jitter applied to entity refresh jobs.
+    pub settings: SchedulerSettings,
 }
 
 ///
@@ -91,6 +103,7 @@ impl Scheduler {
     /// - `offset_for_esi_downtime` - If `true`, adjusts job scheduling to avoid ESI downtime
     ///   window (11:00-11:05 UTC + 2 minute grace period surrounding the window). Set to `false`
     ///   for testing to prevent time-dependent failures.
+    /// - `settings` - Batch sizes, stagger window, and jitter for entity refreshes
     ///
     /// # Returns
     /// - `Ok(Scheduler)` - Successfully created scheduler instance
@@ -99,12 +112,14 @@ impl Scheduler {
         db: DatabaseConnection,
         queue: WorkerQueue,
         offset_for_esi_downtime: bool,
+        settings: SchedulerSettings,
     ) -> Result<Self, AppError> {
         let sched = JobScheduler::new().await?;
         let state = SchedulerState {
             db,
             queue,
             offset_for_esi_downtime,
+            settings,
         };
 
         Ok(Self { state, sched })
@@ -122,46 +137,81 @@ impl Scheduler {
     /// - Corporation info updates
     /// - Character info updates
     /// - Character affiliation updates
+    /// - Admin dashboard summary refreshes
+    /// - Corporation member list refreshes
+    /// - Character skill snapshot refreshes
+    /// - Revoked character token pruning
+    /// - Weekly digests
+    ///
+    /// # Arguments
+    /// - `cron` - Cron expressions of the built-in jobs, already validated by `Config::from_env`
     ///
     /// # Returns
     /// - `Ok(())` - All jobs successfully registered and scheduler started
     /// - `Err(AppError)` - Failed to register a job or start the scheduler
-    pub async fn start(mut self) -> Result<(), AppError> {
-        self.schedule_job(
-            faction_config::CRON_EXPRESSION,
-            "faction info",
-            schedule_faction_info_update,
-        )
-        .await?;
+    pub async fn start(mut self, cron: &CronSchedules) -> Result<(), AppError> {
+        self.schedule_job(&cron.faction, "faction info", schedule_faction_info_update)
+            .await?;
 
         self.schedule_job(
-            alliance_config::CRON_EXPRESSION,
+            &cron.alliance,
             "alliance info",
             schedule_alliance_info_update,
         )
         .await?;
 
         self.schedule_job(
-            corporation_config::CRON_EXPRESSION,
+            &cron.corporation,
             "corporation info",
             schedule_corporation_info_update,
         )
         .await?;
 
         self.schedule_job(
-            character_config::CRON_EXPRESSION,
+            &cron.character,
             "character info",
             schedule_character_info_update,
         )
         .await?;
 
         self.schedule_job(
-            character_affiliation_config::CRON_EXPRESSION,
+            &cron.character_affiliation,
             "character affiliation",
             schedule_character_affiliation_update,
         )
         .await?;
 
+        self.schedule_job(
+            &cron.dashboard,
+            "dashboard summary",
+            schedule_dashboard_summary_refresh,
+        )
+        .await?;
+
+        self.schedule_job(
+            &cron.corporation_member,
+            "corporation member",
+            schedule_corporation_member_update,
+        )
+        .await?;
+
+        self.schedule_job(
+            &cron.character_skill,
+            "character skill",
+            schedule_character_skill_update,
+        )
+        .await?;
+
+        self.schedule_job(
+            &cron.token_prune,
+            "revoked token prune",
+            schedule_revoked_token_prune,
+        )
+        .await?;
+
+        self.schedule_job(&cron.digest, "weekly digest", schedule_weekly_digest)
+            .await?;
+
         // Start the scheduler
         self.sched.start().await?;
 
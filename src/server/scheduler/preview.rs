@@ -0,0 +1,129 @@
+//! Read-only preview of scheduler runs.
+//!
+//! This module runs the same batch selection and stagger calculation as the scheduled EVE
+//! Online refresh jobs, but returns the jobs instead of pushing them to the worker queue. This
+//! lets operators check the effect of cache and interval configuration before the next cron
+//! tick. Jobs already in the queue are included, as deduplication only happens when a job is
+//! pushed.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::{
+    model::scheduler::SchedulerJobKind,
+    server::{
+        error::AppError,
+        model::worker::WorkerJob,
+        scheduler::{
+            config::eve::{
+                alliance as alliance_config, character as character_config,
+                character_affiliation as character_affiliation_config,
+                corporation as corporation_config,
+            },
+            entity_refresh::{EntityRefreshTracker, SchedulableEntity},
+            eve::{
+                affiliation::{build_character_affiliation_jobs, CharacterAffiliation},
+                alliance::{build_alliance_info_jobs, AllianceInfo},
+                character::{build_character_info_jobs, CharacterInfo},
+                corporation::{build_corporation_info_jobs, CorporationInfo},
+            },
+            SchedulerState,
+        },
+    },
+};
+
+/// Returns the jobs the next run of a scheduled job would enqueue, without enqueuing them.
+///
+/// # Arguments
+/// - `state` - Scheduler state containing the database connection
+/// - `kind` - Scheduled job to preview
+///
+/// # Returns
+/// - `Ok(Vec<(WorkerJob, DateTime<Utc>)>)` - Jobs paired with the times they would be scheduled for
+/// - `Err(AppError)` - Database query failed
+pub async fn preview_schedule(
+    state: &SchedulerState,
+    kind: SchedulerJobKind,
+) -> Result<Vec<(WorkerJob, DateTime<Utc>)>, AppError> {
+    match kind {
+        // The faction schedule always pushes a single job for immediate execution
+        SchedulerJobKind::Faction => Ok(vec![(WorkerJob::UpdateFactionInfo, Utc::now())]),
+        SchedulerJobKind::Alliance => {
+            preview_entity_refresh::<AllianceInfo>(
+                state,
+                alliance_config::CACHE_DURATION,
+                alliance_config::SCHEDULE_INTERVAL,
+                1,
+                build_alliance_info_jobs,
+            )
+            .await
+        }
+        SchedulerJobKind::Corporation => {
+            preview_entity_refresh::<CorporationInfo>(
+                state,
+                corporation_config::CACHE_DURATION,
+                corporation_config::SCHEDULE_INTERVAL,
+                1,
+                build_corporation_info_jobs,
+            )
+            .await
+        }
+        SchedulerJobKind::Character => {
+            preview_entity_refresh::<CharacterInfo>(
+                state,
+                character_config::CACHE_DURATION,
+                character_config::SCHEDULE_INTERVAL,
+                1,
+                build_character_info_jobs,
+            )
+            .await
+        }
+        SchedulerJobKind::Affiliation => {
+            preview_entity_refresh::<CharacterAffiliation>(
+                state,
+                character_affiliation_config::CACHE_DURATION,
+                character_affiliation_config::SCHEDULE_INTERVAL,
+                state.settings.affiliation_shards,
+                build_character_affiliation_jobs,
+            )
+            .await
+        }
+    }
+}
+
+/// Selects the entities needing an update and computes when their jobs would run.
+///
+/// # Arguments
+/// - `S` - The `SchedulableEntity` type to preview
+/// - `state` - Scheduler state containing the database connection
+/// - `cache_duration` - How long cached entity data remains valid
+/// - `schedule_interval` - How frequently the scheduler checks for expired entities
+/// - `shards` - Number of shards the entities are partitioned into, `1` for no sharding
+/// - `build_jobs` - Builds the worker jobs for the selected entity IDs
+///
+/// # Returns
+/// - `Ok(Vec<(WorkerJob, DateTime<Utc>)>)` - Jobs paired with the times they would be scheduled for
+/// - `Err(AppError)` - Database query failed
+async fn preview_entity_refresh<S>(
+    state: &SchedulerState,
+    cache_duration: Duration,
+    schedule_interval: Duration,
+    shards: u32,
+    build_jobs: fn(Vec<i64>) -> Vec<WorkerJob>,
+) -> Result<Vec<(WorkerJob, DateTime<Utc>)>, AppError>
+where
+    S: SchedulableEntity + Send + Sync,
+    S::Entity: Send + Sync,
+    <S::Entity as sea_orm::EntityTrait>::Model: Send + Sync,
+{
+    let refresh_tracker = EntityRefreshTracker::new(state, cache_duration, schedule_interval);
+
+    let ids = refresh_tracker
+        .find_shard_entries_needing_update::<S>(shards)
+        .await?;
+
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    refresh_tracker.preview_jobs(build_jobs(ids)).await
+}
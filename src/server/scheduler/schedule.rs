@@ -11,18 +11,20 @@ use crate::server::{
     error::AppError, model::worker::WorkerJob, util::eve::get_esi_downtime_remaining,
 };
 
-/// Minimum batch size for entity updates per scheduling cycle.
+/// Default minimum batch size for entity updates per scheduling cycle.
 ///
 /// Ensures at least 100 entities are updated per schedule run, even if the cache duration
 /// would allow for smaller batches. This prevents overly granular scheduling that could
-/// lead to excessive overhead.
-pub(crate) static MIN_BATCH_LIMIT: i64 = 100;
+/// lead to excessive overhead. Overridden by `SCHEDULER_MIN_BATCH_SIZE`.
+pub(crate) const MIN_BATCH_LIMIT: u64 = 100;
 
 /// Calculates the maximum number of entities to schedule for update in a single batch.
 ///
 /// Determines an appropriate batch size based on the total number of table entries, cache
 /// duration, and scheduling interval. The goal is to spread updates evenly across the cache
 /// period while respecting a minimum batch size to avoid excessive scheduling overhead.
+/// Callers staggering jobs across a window longer than the schedule interval pass the window
+/// as `schedule_interval`, so each batch covers the time its jobs are spread across.
 ///
 /// Automatically accounts for ESI downtime overlap within the scheduling interval. When
 /// downtime overlaps with the schedule window, the effective interval is reduced, which
@@ -48,15 +50,17 @@ pub(crate) static MIN_BATCH_LIMIT: i64 = 100;
 /// - `table_entries` - Total number of entities in the table that may need updates
 /// - `cache` - Duration that cached data remains valid before needing refresh
 /// - `schedule_interval` - How frequently the scheduler runs to check for expired entities
+/// - `min_batch_limit` - Minimum number of entities per batch, such as `MIN_BATCH_LIMIT`
 ///
 /// # Returns
 /// - `0` if `table_entries` is zero
 /// - `table_entries` if the cache duration is less than or equal to the schedule interval
-/// - Otherwise, `(table_entries / batches_per_cache_period)` with a minimum of 100
+/// - Otherwise, `(table_entries / batches_per_cache_period)` with a minimum of `min_batch_limit`
 pub fn calculate_batch_limit(
     table_entries: u64,
     cache: Duration,
     schedule_interval: Duration,
+    min_batch_limit: u64,
     offset_for_esi_downtime: bool,
 ) -> u64 {
     if table_entries == 0 {
@@ -82,13 +86,13 @@ pub fn calculate_batch_limit(
 
     if batches_per_cache_period > 0 {
         let scaled_min_batch = if offset_for_esi_downtime {
-            // Scale MIN_BATCH_LIMIT proportionally with downtime overlap to prevent overflow
-            // If 30% of interval is downtime, reduce MIN_BATCH_LIMIT by 30%
+            // Scale the minimum batch proportionally with downtime overlap to prevent overflow
+            // If 30% of interval is downtime, reduce the minimum batch by 30%
             let interval_ratio =
                 effective_interval.num_seconds() as f64 / schedule_interval.num_seconds() as f64;
-            (MIN_BATCH_LIMIT as f64 * interval_ratio).ceil() as u64
+            (min_batch_limit as f64 * interval_ratio).ceil() as u64
         } else {
-            MIN_BATCH_LIMIT as u64
+            min_batch_limit
         };
 
         (table_entries / batches_per_cache_period as u64).max(scaled_min_batch)
@@ -102,7 +106,8 @@ pub fn calculate_batch_limit(
 /// Takes a list of jobs and distributes their execution times evenly across the scheduling
 /// interval, starting from the current time. This prevents all jobs from executing simultaneously
 /// and spreads worker queue and API load over time. Jobs are scheduled with sub-second precision
-/// when many jobs need to fit within a short window.
+/// when many jobs need to fit within a short window. Each job is delayed by a random amount of
+/// up to `jitter`, so deployments started at the same time don't hit ESI in lockstep.
 ///
 /// # Downtime Handling
 /// ESI daily downtime occurs between 11:00 and 11:05 UTC. This function applies a 2-minute
@@ -113,6 +118,7 @@ pub fn calculate_batch_limit(
 /// # Arguments
 /// - `jobs` - Vector of worker jobs to be scheduled
 /// - `schedule_interval` - Time window across which to distribute the jobs
+/// - `jitter` - Maximum random delay added to each job, zero for none
 ///
 /// # Returns
 /// - `Ok(Vec<(WorkerJob, DateTime<Utc>)>)` - List of jobs paired with their scheduled execution times
@@ -120,15 +126,20 @@ pub fn calculate_batch_limit(
 pub async fn create_job_schedule(
     jobs: Vec<WorkerJob>,
     schedule_interval: Duration,
+    jitter: Duration,
     offset_for_esi_downtime: bool,
 ) -> Result<Vec<(WorkerJob, DateTime<Utc>)>, AppError> {
+    use rand::Rng;
+
     if jobs.is_empty() {
         return Ok(vec![]);
     }
 
     let num_jobs = jobs.len() as i64;
     let window_seconds = schedule_interval.num_seconds();
+    let jitter_millis = jitter.num_milliseconds().max(0);
     let base_time = Utc::now();
+    let mut rng = rand::rng();
 
     let mut scheduled_jobs = Vec::new();
     let mut cumulative_offset = Duration::zero();
@@ -138,6 +149,9 @@ pub async fn create_job_schedule(
         // This allows multiple jobs per second and ensures all jobs fit within the window
         let offset_seconds = (index as i64 * window_seconds) / num_jobs;
         let mut scheduled_time = base_time + Duration::seconds(offset_seconds) + cumulative_offset;
+        if jitter_millis > 0 {
+            scheduled_time += Duration::milliseconds(rng.random_range(0..=jitter_millis));
+        }
 
         // Check if this job overlaps with ESI downtime (if offset is enabled)
         //
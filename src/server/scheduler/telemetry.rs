@@ -0,0 +1,34 @@
+//! Telemetry report scheduling.
+//!
+//! This module sends the opt-in anonymous telemetry report. Unlike the EVE Online refresh
+//! schedules, the report is sent directly from the scheduler rather than through the worker
+//! queue since it is a single small request per day.
+
+use crate::server::{
+    error::AppError,
+    scheduler::SchedulerState,
+    service::telemetry::{TelemetryConfig, TelemetryService},
+};
+
+/// Sends the anonymous telemetry report to the configured endpoint.
+///
+/// # Arguments
+/// - `state` - Scheduler state containing the database connection
+/// - `config` - Telemetry settings including the endpoint
+/// - `client` - HTTP client used to send the report
+///
+/// # Returns
+/// - `Ok(1)` - Report was sent
+/// - `Ok(0)` - Telemetry is disabled
+/// - `Err(AppError)` - Failed to build or send the report
+pub async fn send_telemetry_report(
+    state: SchedulerState,
+    config: TelemetryConfig,
+    client: reqwest::Client,
+) -> Result<usize, AppError> {
+    let sent = TelemetryService::new(&state.db, &config)
+        .send_report(&client)
+        .await?;
+
+    Ok(if sent { 1 } else { 0 })
+}
@@ -0,0 +1,21 @@
+//! Revoked character token pruning scheduling.
+//!
+//! This module schedules the periodic pruning of character refresh tokens EVE SSO rejects.
+//! Each run checks every stored token, so a single job is enqueued per run.
+
+use crate::server::{error::AppError, model::worker::WorkerJob, scheduler::SchedulerState};
+
+/// Schedules a revoked token pruning job to the worker queue.
+///
+/// # Arguments
+/// - `state` - Scheduler state containing the worker queue
+///
+/// # Returns
+/// - `Ok(1)` - Successfully scheduled the pruning job
+/// - `Ok(0)` - A pruning job is already queued
+/// - `Err(AppError)` - Failed to enqueue the job to the worker queue
+pub async fn schedule_revoked_token_prune(state: SchedulerState) -> Result<usize, AppError> {
+    let was_scheduled = state.queue.push(WorkerJob::PruneRevokedTokens).await?;
+
+    Ok(usize::from(was_scheduled))
+}
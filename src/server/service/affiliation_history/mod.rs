@@ -0,0 +1,140 @@
+//! Affiliation history service layer.
+//!
+//! This module contains the `AffiliationHistoryService` for recording and retrieving the
+//! corporation and alliance membership intervals of characters. Affiliation updates from ESI
+//! are diffed against each character's current interval, which is ended and replaced by a new
+//! interval when the corporation or alliance changed. This lets diplomats answer questions
+//! such as where a character was during a war.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+
+use crate::{
+    model::affiliation_history::{AffiliationHistoryDto, AffiliationIntervalDto},
+    server::{
+        data::{
+            affiliation_history::AffiliationHistoryRepository, eve::character::CharacterRepository,
+        },
+        error::{affiliation_history::AffiliationHistoryError, AppError},
+    },
+};
+
+/// Service for recording and retrieving character affiliation history.
+pub struct AffiliationHistoryService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> AffiliationHistoryService<'a> {
+    /// Creates a new instance of AffiliationHistoryService.
+    ///
+    /// Constructs a service for retrieving character affiliation history.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `AffiliationHistoryService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the affiliation history of a character.
+    ///
+    /// # Arguments
+    /// - `character_id` - EVE Online character ID
+    ///
+    /// # Returns
+    /// - `Ok(AffiliationHistoryDto)` - Membership intervals ordered oldest first
+    /// - `Err(AppError::AffiliationHistory(AffiliationHistoryError::CharacterNotFound))` - Character is not in the database
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_history(&self, character_id: i64) -> Result<AffiliationHistoryDto, AppError> {
+        let Some(character) = CharacterRepository::new(self.db)
+            .find_by_eve_id(character_id)
+            .await?
+        else {
+            return Err(AffiliationHistoryError::CharacterNotFound(character_id).into());
+        };
+
+        let intervals = AffiliationHistoryRepository::new(self.db)
+            .get_by_character_id(character.id)
+            .await?
+            .into_iter()
+            .map(|(interval, corporation, alliance)| AffiliationIntervalDto {
+                corporation_id: corporation.corporation_id,
+                corporation_name: corporation.name,
+                alliance_id: alliance.as_ref().map(|alliance| alliance.alliance_id),
+                alliance_name: alliance.map(|alliance| alliance.name),
+                started_at: interval.started_at,
+                ended_at: interval.ended_at,
+            })
+            .collect();
+
+        Ok(AffiliationHistoryDto {
+            character_id: character.character_id,
+            character_name: character.name,
+            intervals,
+        })
+    }
+
+    /// Records the affiliations of characters, starting a new interval for each change.
+    ///
+    /// Characters without a current interval or whose corporation or alliance differs from it
+    /// get a new interval starting at `now`, and their previous interval is ended at `now`.
+    /// Unchanged characters are left as they are. Call within the transaction updating the
+    /// affiliations so the history is updated atomically with them.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection or transaction to record the history within
+    /// - `affiliations` - Vector of tuples containing (character_id, corporation_id, optional alliance_id) record IDs
+    /// - `now` - Time the affiliations were seen
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of characters whose affiliation changed or was seen for the first time
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn record_affiliations<C: ConnectionTrait>(
+        db: &C,
+        affiliations: Vec<(i32, i32, Option<i32>)>,
+        now: NaiveDateTime,
+    ) -> Result<usize, AppError> {
+        if affiliations.is_empty() {
+            return Ok(0);
+        }
+
+        let history_repo = AffiliationHistoryRepository::new(db);
+
+        let current: HashMap<i32, (i32, i32, Option<i32>)> = history_repo
+            .get_open_by_character_ids(affiliations.iter().map(|(id, _, _)| *id).collect())
+            .await?
+            .into_iter()
+            .map(|interval| {
+                (
+                    interval.character_id,
+                    (interval.id, interval.corporation_id, interval.alliance_id),
+                )
+            })
+            .collect();
+
+        let mut ended = Vec::new();
+        let mut started = Vec::new();
+        for (character_id, corporation_id, alliance_id) in affiliations {
+            match current.get(&character_id) {
+                Some((_, current_corporation_id, current_alliance_id))
+                    if *current_corporation_id == corporation_id
+                        && *current_alliance_id == alliance_id => {}
+                Some((interval_id, _, _)) => {
+                    ended.push(*interval_id);
+                    started.push((character_id, corporation_id, alliance_id));
+                }
+                None => started.push((character_id, corporation_id, alliance_id)),
+            }
+        }
+
+        let changed = started.len();
+        history_repo.close_many(ended, now).await?;
+        history_repo.open_many(started, now).await?;
+
+        Ok(changed)
+    }
+}
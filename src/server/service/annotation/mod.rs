@@ -0,0 +1,340 @@
+//! Annotation service layer.
+//!
+//! This module contains the `AnnotationService` for the free-form tags and timestamped notes
+//! admins attach to users, characters, and corporations. Tags are normalized to lowercase so
+//! the same tag added twice is stored once and can be searched across subjects. Notes are
+//! either shared with every officer or kept private to their author. Every change is written
+//! to the info log with the acting user so it can be audited.
+
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::annotation::{
+        AnnotationSubject, AnnotationsDto, CreateNoteDto, CreateTagDto, NoteDto, NoteVisibility,
+        TagDto, TaggedSubjectDto,
+    },
+    server::{
+        data::{
+            annotation::{note::NoteRepository, tag::TagRepository},
+            eve::{character::CharacterRepository, corporation::CorporationRepository},
+            user::UserRepository,
+        },
+        error::{annotation::AnnotationError, AppError},
+        model::db::{NoteModel, TagModel},
+    },
+};
+
+/// Maximum length of a tag name, in characters.
+const MAX_TAG_LENGTH: usize = 32;
+
+/// Maximum length of a note body, in characters.
+const MAX_NOTE_LENGTH: usize = 4000;
+
+/// Service for managing the tags and notes attached to users, characters, and corporations.
+pub struct AnnotationService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> AnnotationService<'a> {
+    /// Creates a new instance of AnnotationService.
+    ///
+    /// Constructs a service for managing tags and notes.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `AnnotationService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the tags and the notes the user can read for a subject.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user reading the annotations
+    /// - `subject` - Kind of the subject
+    /// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID
+    ///
+    /// # Returns
+    /// - `Ok(AnnotationsDto)` - Tags ordered by name and notes newest first
+    /// - `Err(AppError::Annotation(AnnotationError::SubjectNotFound))` - Subject doesn't exist
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_annotations(
+        &self,
+        user_id: i32,
+        subject: AnnotationSubject,
+        subject_id: i64,
+    ) -> Result<AnnotationsDto, AppError> {
+        self.ensure_subject_exists(subject, subject_id).await?;
+
+        let tags = TagRepository::new(self.db)
+            .get_by_subject(subject.as_str(), subject_id)
+            .await?;
+        let notes = NoteRepository::new(self.db)
+            .get_visible_by_subject(subject.as_str(), subject_id, user_id)
+            .await?;
+
+        Ok(AnnotationsDto {
+            subject,
+            subject_id,
+            tags: tags.into_iter().map(tag_to_dto).collect(),
+            notes: notes.into_iter().map(note_to_dto).collect(),
+        })
+    }
+
+    /// Attaches a tag to a subject.
+    ///
+    /// The tag is trimmed and lowercased. Adding a tag the subject already carries returns
+    /// the existing tag.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user adding the tag
+    /// - `subject` - Kind of the subject
+    /// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID
+    /// - `tag` - Name of the tag
+    ///
+    /// # Returns
+    /// - `Ok(TagDto)` - The added or existing tag
+    /// - `Err(AppError::Annotation(AnnotationError::InvalidTag))` - Tag is empty or too long
+    /// - `Err(AppError::Annotation(AnnotationError::SubjectNotFound))` - Subject doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn add_tag(
+        &self,
+        user_id: i32,
+        subject: AnnotationSubject,
+        subject_id: i64,
+        tag: CreateTagDto,
+    ) -> Result<TagDto, AppError> {
+        let name = normalize_tag(&tag.name)?;
+        self.ensure_subject_exists(subject, subject_id).await?;
+
+        let tag_repo = TagRepository::new(self.db);
+        if let Some(existing) = tag_repo
+            .get_by_name(subject.as_str(), subject_id, &name)
+            .await?
+        {
+            return Ok(tag_to_dto(existing));
+        }
+
+        let tag = tag_repo
+            .create(subject.as_str(), subject_id, name, user_id)
+            .await?;
+
+        tracing::info!(
+            user_id = %user_id,
+            subject = %subject.as_str(),
+            subject_id = %subject_id,
+            tag = %tag.name,
+            "Added tag"
+        );
+
+        Ok(tag_to_dto(tag))
+    }
+
+    /// Removes a tag from its subject.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user removing the tag
+    /// - `tag_id` - ID of the tag
+    ///
+    /// # Returns
+    /// - `Ok(())` - Tag removed
+    /// - `Err(AppError::Annotation(AnnotationError::TagNotFound))` - Tag doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn remove_tag(&self, user_id: i32, tag_id: i32) -> Result<(), AppError> {
+        let tag_repo = TagRepository::new(self.db);
+        let tag = tag_repo
+            .get_by_id(tag_id)
+            .await?
+            .ok_or(AnnotationError::TagNotFound(tag_id))?;
+
+        tag_repo.delete(tag_id).await?;
+
+        tracing::info!(
+            user_id = %user_id,
+            subject = %tag.subject_type,
+            subject_id = %tag.subject_id,
+            tag = %tag.name,
+            "Removed tag"
+        );
+
+        Ok(())
+    }
+
+    /// Adds a note about a subject.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user writing the note
+    /// - `subject` - Kind of the subject
+    /// - `subject_id` - Bifrost user ID or EVE Online character or corporation ID
+    /// - `note` - Body and visibility of the note
+    ///
+    /// # Returns
+    /// - `Ok(NoteDto)` - The added note
+    /// - `Err(AppError::Annotation(AnnotationError::InvalidNote))` - Note is empty or too long
+    /// - `Err(AppError::Annotation(AnnotationError::SubjectNotFound))` - Subject doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn add_note(
+        &self,
+        user_id: i32,
+        subject: AnnotationSubject,
+        subject_id: i64,
+        note: CreateNoteDto,
+    ) -> Result<NoteDto, AppError> {
+        let body = note.body.trim();
+        if body.is_empty() || body.chars().count() > MAX_NOTE_LENGTH {
+            return Err(AnnotationError::InvalidNote(MAX_NOTE_LENGTH).into());
+        }
+        self.ensure_subject_exists(subject, subject_id).await?;
+
+        let note = NoteRepository::new(self.db)
+            .create(
+                subject.as_str(),
+                subject_id,
+                body.to_string(),
+                note.visibility.as_str(),
+                user_id,
+            )
+            .await?;
+
+        tracing::info!(
+            user_id = %user_id,
+            subject = %subject.as_str(),
+            subject_id = %subject_id,
+            note_id = %note.id,
+            visibility = %note.visibility,
+            "Added note"
+        );
+
+        Ok(note_to_dto(note))
+    }
+
+    /// Deletes a note the user can read.
+    ///
+    /// Notes kept private by another author are reported as missing rather than forbidden so
+    /// their existence isn't revealed.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user deleting the note
+    /// - `note_id` - ID of the note
+    ///
+    /// # Returns
+    /// - `Ok(())` - Note deleted
+    /// - `Err(AppError::Annotation(AnnotationError::NoteNotFound))` - Note doesn't exist or is
+    ///   private to another author
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_note(&self, user_id: i32, note_id: i32) -> Result<(), AppError> {
+        let note_repo = NoteRepository::new(self.db);
+        let note = note_repo
+            .get_visible_by_id(note_id, user_id)
+            .await?
+            .ok_or(AnnotationError::NoteNotFound(note_id))?;
+
+        note_repo.delete(note_id).await?;
+
+        tracing::info!(
+            user_id = %user_id,
+            subject = %note.subject_type,
+            subject_id = %note.subject_id,
+            note_id = %note_id,
+            author_user_id = %note.created_by_user_id,
+            "Deleted note"
+        );
+
+        Ok(())
+    }
+
+    /// Finds every subject carrying a tag.
+    ///
+    /// # Arguments
+    /// - `name` - Name of the tag, matched after normalization
+    ///
+    /// # Returns
+    /// - `Ok(Vec<TaggedSubjectDto>)` - Subjects carrying the tag
+    /// - `Err(AppError::Annotation(AnnotationError::InvalidTag))` - Tag is empty or too long
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn find_by_tag(&self, name: &str) -> Result<Vec<TaggedSubjectDto>, AppError> {
+        let name = normalize_tag(name)?;
+
+        Ok(TagRepository::new(self.db)
+            .get_subjects_by_name(&name)
+            .await?
+            .into_iter()
+            .filter_map(|(subject_type, subject_id)| {
+                AnnotationSubject::from_name(&subject_type).map(|subject| TaggedSubjectDto {
+                    subject,
+                    subject_id,
+                })
+            })
+            .collect())
+    }
+
+    /// Ensures the annotated subject exists in the database.
+    async fn ensure_subject_exists(
+        &self,
+        subject: AnnotationSubject,
+        subject_id: i64,
+    ) -> Result<(), AppError> {
+        let exists = match subject {
+            AnnotationSubject::User => match i32::try_from(subject_id) {
+                Ok(user_id) => UserRepository::new(self.db)
+                    .get_by_id(user_id)
+                    .await?
+                    .is_some(),
+                Err(_) => false,
+            },
+            AnnotationSubject::Character => CharacterRepository::new(self.db)
+                .find_by_eve_id(subject_id)
+                .await?
+                .is_some(),
+            AnnotationSubject::Corporation => CorporationRepository::new(self.db)
+                .find_by_eve_id(subject_id)
+                .await?
+                .is_some(),
+        };
+
+        if !exists {
+            return Err(AnnotationError::SubjectNotFound {
+                subject,
+                subject_id,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Trims and lowercases a tag name, rejecting empty and overlong tags.
+fn normalize_tag(name: &str) -> Result<String, AnnotationError> {
+    let name = name.trim().to_lowercase();
+    if name.is_empty() || name.chars().count() > MAX_TAG_LENGTH {
+        return Err(AnnotationError::InvalidTag(MAX_TAG_LENGTH));
+    }
+
+    Ok(name)
+}
+
+/// Converts a stored tag into its DTO.
+fn tag_to_dto(tag: TagModel) -> TagDto {
+    TagDto {
+        id: tag.id,
+        name: tag.name,
+        created_by_user_id: tag.created_by_user_id,
+        created_at: tag.created_at,
+    }
+}
+
+/// Converts a stored note into its DTO.
+fn note_to_dto(note: NoteModel) -> NoteDto {
+    NoteDto {
+        id: note.id,
+        body: note.body,
+        // Unknown values can only come from manual edits, keep those notes private
+        visibility: NoteVisibility::from_name(&note.visibility).unwrap_or(NoteVisibility::Author),
+        created_by_user_id: note.created_by_user_id,
+        created_at: note.created_at,
+    }
+}
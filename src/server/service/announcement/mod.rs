@@ -0,0 +1,222 @@
+//! Announcement service layer.
+//!
+//! This module contains the `AnnouncementService` for broadcasts admins post to all members or
+//! to the members of a corporation or alliance. The audience is resolved to users when the
+//! announcement is posted, so members joining later don't receive earlier announcements and
+//! read receipts stay comparable. Delivery over Web Push and Discord is left to the caller,
+//! which enqueues worker jobs for the returned recipients.
+
+use std::collections::HashMap;
+
+use sea_orm::{DatabaseConnection, TransactionTrait};
+
+use crate::{
+    model::announcement::{
+        AnnouncementAudience, AnnouncementDto, CreateAnnouncementDto, InboxAnnouncementDto,
+    },
+    server::{
+        data::{
+            announcement::AnnouncementRepository,
+            user::{summary::UserCharacterSummaryRepository, UserRepository},
+        },
+        error::{announcement::AnnouncementError, AppError},
+        model::db::AnnouncementModel,
+        util::markdown::render_markdown,
+    },
+};
+
+/// Service for posting announcements and reading them from user inboxes.
+pub struct AnnouncementService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> AnnouncementService<'a> {
+    /// Creates a new instance of AnnouncementService.
+    ///
+    /// Constructs a service for managing announcements.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `AnnouncementService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Posts an announcement to the inbox of every user in its audience.
+    ///
+    /// Corporation and alliance audiences include every user owning a character in the
+    /// corporation or alliance, not only users whose main character is.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user posting the announcement
+    /// - `announcement` - Title, Markdown body, and audience of the announcement
+    ///
+    /// # Returns
+    /// - `Ok((AnnouncementDto, Vec<i32>))` - The posted announcement and the IDs of its
+    ///   recipients
+    /// - `Err(AppError::Announcement(AnnouncementError::EmptyTitle))` - Title is empty
+    /// - `Err(AppError::Announcement(AnnouncementError::MissingAudienceId))` - Corporation or
+    ///   alliance audience without an ID
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_announcement(
+        &self,
+        user_id: i32,
+        announcement: CreateAnnouncementDto,
+    ) -> Result<(AnnouncementDto, Vec<i32>), AppError> {
+        let title = announcement.title.trim().to_string();
+        if title.is_empty() {
+            return Err(AnnouncementError::EmptyTitle.into());
+        }
+
+        let audience_id = match announcement.audience {
+            AnnouncementAudience::All => None,
+            audience => Some(
+                announcement
+                    .audience_id
+                    .ok_or(AnnouncementError::MissingAudienceId(audience))?,
+            ),
+        };
+
+        let txn = self.db.begin().await?;
+
+        let summary_repo = UserCharacterSummaryRepository::new(&txn);
+        let recipient_ids = match (announcement.audience, audience_id) {
+            (AnnouncementAudience::Corporation, Some(corporation_id)) => {
+                summary_repo
+                    .get_user_ids_by_corporation_id(corporation_id)
+                    .await?
+            }
+            (AnnouncementAudience::Alliance, Some(alliance_id)) => {
+                summary_repo
+                    .get_user_ids_by_alliance_id(alliance_id)
+                    .await?
+            }
+            _ => UserRepository::new(&txn).get_all_ids().await?,
+        };
+
+        let announcement_repo = AnnouncementRepository::new(&txn);
+        let created = announcement_repo
+            .create(
+                title,
+                announcement.body,
+                announcement.audience.as_str(),
+                audience_id,
+                user_id,
+            )
+            .await?;
+        announcement_repo
+            .add_recipients(created.id, &recipient_ids)
+            .await?;
+
+        txn.commit().await?;
+
+        let recipient_count = recipient_ids.len() as u64;
+        let announcement =
+            announcement_to_dto(created, announcement.audience, (recipient_count, 0));
+
+        Ok((announcement, recipient_ids))
+    }
+
+    /// Retrieves all announcements with their read receipts, newest first.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<AnnouncementDto>)` - All announcements with recipient and read counts
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_announcements(&self) -> Result<Vec<AnnouncementDto>, AppError> {
+        let announcement_repo = AnnouncementRepository::new(self.db);
+
+        let counts: HashMap<i32, (u64, u64)> = announcement_repo
+            .count_recipients()
+            .await?
+            .into_iter()
+            .map(|(announcement_id, recipient_count, read_count)| {
+                (announcement_id, (recipient_count as u64, read_count as u64))
+            })
+            .collect();
+
+        Ok(announcement_repo
+            .get_all()
+            .await?
+            .into_iter()
+            .filter_map(|announcement| {
+                let audience = AnnouncementAudience::from_name(&announcement.audience)?;
+                let counts = counts.get(&announcement.id).copied().unwrap_or_default();
+
+                Some(announcement_to_dto(announcement, audience, counts))
+            })
+            .collect())
+    }
+
+    /// Retrieves the announcements in a user's inbox with their bodies rendered as HTML.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<InboxAnnouncementDto>)` - Announcements sent to the user, newest first
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_inbox(&self, user_id: i32) -> Result<Vec<InboxAnnouncementDto>, AppError> {
+        Ok(AnnouncementRepository::new(self.db)
+            .get_inbox(user_id)
+            .await?
+            .into_iter()
+            .map(|(recipient, announcement)| InboxAnnouncementDto {
+                id: announcement.id,
+                title: announcement.title,
+                html: render_markdown(&announcement.body),
+                created_at: announcement.created_at,
+                read: recipient.read_at.is_some(),
+            })
+            .collect())
+    }
+
+    /// Marks an announcement in a user's inbox as read.
+    ///
+    /// Marking an announcement read again keeps the time it was first read.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `announcement_id` - ID of the announcement
+    ///
+    /// # Returns
+    /// - `Ok(())` - Announcement marked read
+    /// - `Err(AppError::Announcement(AnnouncementError::AnnouncementNotFound))` - Announcement
+    ///   doesn't exist or wasn't sent to the user
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn mark_read(&self, user_id: i32, announcement_id: i32) -> Result<(), AppError> {
+        let announcement_repo = AnnouncementRepository::new(self.db);
+
+        let recipient = announcement_repo
+            .get_recipient(announcement_id, user_id)
+            .await?
+            .ok_or(AnnouncementError::AnnouncementNotFound {
+                announcement_id,
+                user_id,
+            })?;
+
+        announcement_repo.mark_read(recipient.id).await?;
+
+        Ok(())
+    }
+}
+
+/// Converts a stored announcement into its DTO with its (recipient, read) counts.
+fn announcement_to_dto(
+    announcement: AnnouncementModel,
+    audience: AnnouncementAudience,
+    (recipient_count, read_count): (u64, u64),
+) -> AnnouncementDto {
+    AnnouncementDto {
+        id: announcement.id,
+        audience,
+        title: announcement.title,
+        body: announcement.body,
+        audience_id: announcement.audience_id,
+        created_by_user_id: announcement.created_by_user_id,
+        created_at: announcement.created_at,
+        recipient_count,
+        read_count,
+    }
+}
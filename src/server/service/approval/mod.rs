@@ -0,0 +1,326 @@
+//! Approval service layer.
+//!
+//! This module contains the `ApprovalService` implementing the two-person rule for sensitive
+//! admin actions. When an action is configured to require approval, requesting it only
+//! records a pending approval request; the action runs once a second admin approves the
+//! request before it expires. Requests, approvals, rejections, and failed actions are written
+//! to the info log with the acting user so they can be audited.
+
+use chrono::Utc;
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::approval::{ApprovalAction, ApprovalPayload, ApprovalRequestDto, ApprovalStatus},
+    server::{
+        config::Config,
+        data::{approval::ApprovalRequestRepository, user::UserRepository},
+        error::{approval::ApprovalError, auth::AuthError, user::UserError, AppError},
+        model::db::ApprovalRequestModel,
+        service::{role::RoleService, user::UserService},
+    },
+};
+
+/// Maximum number of approval requests listed, newest first.
+const MAX_LISTED_REQUESTS: u64 = 100;
+
+/// Two-person rule settings derived from the server configuration at startup.
+#[derive(Clone, Debug, Default)]
+pub struct ApprovalConfig {
+    /// Sensitive actions that only run once a second admin approves them.
+    pub required_actions: Vec<ApprovalAction>,
+    /// How long requests can be approved or rejected before they expire.
+    pub expiry: chrono::Duration,
+}
+
+impl ApprovalConfig {
+    /// Builds two-person rule settings from the server configuration.
+    ///
+    /// # Arguments
+    /// - `config` - Server configuration loaded from environment variables
+    ///
+    /// # Returns
+    /// - `ApprovalConfig` - Actions requiring approval and the request expiry
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            required_actions: config.approval_required_actions.clone(),
+            expiry: config.approval_expiry,
+        }
+    }
+
+    /// Returns whether an action requires approval by a second admin.
+    pub fn is_required(&self, action: ApprovalAction) -> bool {
+        self.required_actions.contains(&action)
+    }
+}
+
+/// Service for approval requests of sensitive admin actions.
+pub struct ApprovalService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> ApprovalService<'a> {
+    /// Creates a new instance of ApprovalService.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ApprovalService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a pending approval request for a sensitive action.
+    ///
+    /// The action's arguments are validated up front so a second admin is only asked to
+    /// approve actions that can run.
+    ///
+    /// # Arguments
+    /// - `config` - Two-person rule settings with the request expiry
+    /// - `user_id` - ID of the admin requesting the action
+    /// - `payload` - Action and its arguments
+    ///
+    /// # Returns
+    /// - `Ok(ApprovalRequestDto)` - The pending request
+    /// - `Err(AppError::User(UserError::MergeIntoSelf))` - Users to merge are the same user
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - A user to merge or to assign the
+    ///   role to doesn't exist
+    /// - `Err(AppError::Role(RoleError::RoleNotFound))` - Role to assign doesn't exist
+    /// - `Err(AppError::Role(RoleError::BuiltInRole))` - Role to assign is the member role
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn request(
+        &self,
+        config: &ApprovalConfig,
+        user_id: i32,
+        payload: ApprovalPayload,
+    ) -> Result<ApprovalRequestDto, AppError> {
+        match &payload {
+            ApprovalPayload::MergeUsers { keep, remove } => {
+                if keep == remove {
+                    return Err(UserError::MergeIntoSelf(*keep).into());
+                }
+
+                let user_repo = UserRepository::new(self.db);
+                for user_id in [*keep, *remove] {
+                    if user_repo.get_by_id(user_id).await?.is_none() {
+                        return Err(AuthError::UserNotInDatabase(user_id).into());
+                    }
+                }
+            }
+            ApprovalPayload::GrantAdmin { role_id, user_id } => {
+                RoleService::new(self.db)
+                    .grants_admin(*role_id, *user_id)
+                    .await?;
+            }
+        }
+
+        let action = payload.action();
+        let serialized = serde_json::to_string(&payload).map_err(|e| {
+            AppError::Internal(format!("Failed to serialize approval payload: {}", e))
+        })?;
+        let request = ApprovalRequestRepository::new(self.db)
+            .create(
+                user_id,
+                action.as_str(),
+                serialized,
+                Utc::now().naive_utc() + config.expiry,
+            )
+            .await?;
+
+        tracing::info!(
+            user_id = %user_id,
+            approval_request_id = %request.id,
+            action = %action.as_str(),
+            payload = %request.payload,
+            "Requested approval for sensitive admin action"
+        );
+
+        request_to_dto(request)
+    }
+
+    /// Retrieves the most recent approval requests, newest first.
+    ///
+    /// Pending requests past their expiry are reported as expired.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ApprovalRequestDto>)` - The most recent requests
+    /// - `Err(AppError::Approval(ApprovalError::InvalidPayload))` - A stored request can't be read
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_requests(&self) -> Result<Vec<ApprovalRequestDto>, AppError> {
+        ApprovalRequestRepository::new(self.db)
+            .get_recent(MAX_LISTED_REQUESTS)
+            .await?
+            .into_iter()
+            .map(request_to_dto)
+            .collect()
+    }
+
+    /// Approves a pending request and runs its action.
+    ///
+    /// The request is marked approved before the action runs, so it can't be approved twice.
+    /// If the action fails the request is marked failed and the error is returned.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the admin approving the request
+    /// - `request_id` - ID of the request
+    ///
+    /// # Returns
+    /// - `Ok(ApprovalRequestDto)` - The approved request
+    /// - `Err(AppError::Approval(ApprovalError::NotFound))` - Request doesn't exist
+    /// - `Err(AppError::Approval(ApprovalError::SelfApproval))` - Admin requested the action
+    /// - `Err(AppError::Approval(ApprovalError::NotPending))` - Request was already decided
+    /// - `Err(AppError::Approval(ApprovalError::Expired))` - Request has expired
+    /// - `Err(AppError)` - The action failed to run
+    pub async fn approve(
+        &self,
+        user_id: i32,
+        request_id: i32,
+    ) -> Result<ApprovalRequestDto, AppError> {
+        let request = self.get_pending(request_id).await?;
+        if request.requested_by_user_id == user_id {
+            return Err(ApprovalError::SelfApproval(request_id).into());
+        }
+
+        let request_repo = ApprovalRequestRepository::new(self.db);
+        if !request_repo
+            .decide(request_id, ApprovalStatus::Approved, user_id)
+            .await?
+        {
+            // Another admin decided the request in the meantime
+            return Err(ApprovalError::NotPending(request_id).into());
+        }
+
+        let result = match request.payload {
+            ApprovalPayload::MergeUsers { keep, remove } => UserService::new(self.db)
+                .merge_users(keep, remove)
+                .await
+                .map(|_| ()),
+            ApprovalPayload::GrantAdmin {
+                role_id,
+                user_id: assignee_id,
+            } => {
+                RoleService::new(self.db)
+                    .assign_role(user_id, role_id, assignee_id)
+                    .await
+            }
+        };
+
+        if let Err(e) = result {
+            request_repo
+                .set_status(request_id, ApprovalStatus::Failed)
+                .await?;
+
+            tracing::warn!(
+                user_id = %user_id,
+                approval_request_id = %request_id,
+                action = %request.payload.action().as_str(),
+                "Approved sensitive admin action failed: {}",
+                e
+            );
+
+            return Err(e);
+        }
+
+        tracing::info!(
+            user_id = %user_id,
+            approval_request_id = %request_id,
+            requested_by_user_id = %request.requested_by_user_id,
+            action = %request.payload.action().as_str(),
+            "Approved and ran sensitive admin action"
+        );
+
+        self.get_request(request_id).await
+    }
+
+    /// Rejects a pending request without running its action.
+    ///
+    /// The admin who requested the action may reject it to withdraw the request.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the admin rejecting the request
+    /// - `request_id` - ID of the request
+    ///
+    /// # Returns
+    /// - `Ok(ApprovalRequestDto)` - The rejected request
+    /// - `Err(AppError::Approval(ApprovalError::NotFound))` - Request doesn't exist
+    /// - `Err(AppError::Approval(ApprovalError::NotPending))` - Request was already decided
+    /// - `Err(AppError::Approval(ApprovalError::Expired))` - Request has expired
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn reject(
+        &self,
+        user_id: i32,
+        request_id: i32,
+    ) -> Result<ApprovalRequestDto, AppError> {
+        let request = self.get_pending(request_id).await?;
+
+        if !ApprovalRequestRepository::new(self.db)
+            .decide(request_id, ApprovalStatus::Rejected, user_id)
+            .await?
+        {
+            return Err(ApprovalError::NotPending(request_id).into());
+        }
+
+        tracing::info!(
+            user_id = %user_id,
+            approval_request_id = %request_id,
+            requested_by_user_id = %request.requested_by_user_id,
+            action = %request.payload.action().as_str(),
+            "Rejected sensitive admin action"
+        );
+
+        self.get_request(request_id).await
+    }
+
+    /// Retrieves a request, failing unless it can still be decided.
+    async fn get_pending(&self, request_id: i32) -> Result<ApprovalRequestDto, AppError> {
+        let request = self.get_request(request_id).await?;
+
+        match request.status {
+            ApprovalStatus::Pending => Ok(request),
+            ApprovalStatus::Expired => Err(ApprovalError::Expired(request_id).into()),
+            _ => Err(ApprovalError::NotPending(request_id).into()),
+        }
+    }
+
+    /// Retrieves a request by ID.
+    async fn get_request(&self, request_id: i32) -> Result<ApprovalRequestDto, AppError> {
+        match ApprovalRequestRepository::new(self.db)
+            .get_by_id(request_id)
+            .await?
+        {
+            Some(request) => request_to_dto(request),
+            None => Err(ApprovalError::NotFound(request_id).into()),
+        }
+    }
+}
+
+/// Converts a stored request into its DTO, reporting pending requests past their expiry as
+/// expired.
+fn request_to_dto(request: ApprovalRequestModel) -> Result<ApprovalRequestDto, AppError> {
+    let invalid = |reason: String| ApprovalError::InvalidPayload {
+        id: request.id,
+        reason,
+    };
+
+    let payload: ApprovalPayload =
+        serde_json::from_str(&request.payload).map_err(|e| invalid(e.to_string()))?;
+    let status = match ApprovalStatus::from_name(&request.status) {
+        Some(ApprovalStatus::Pending) if request.expires_at <= Utc::now().naive_utc() => {
+            ApprovalStatus::Expired
+        }
+        Some(status) => status,
+        None => return Err(invalid(format!("unknown status '{}'", request.status)).into()),
+    };
+
+    Ok(ApprovalRequestDto {
+        id: request.id,
+        payload,
+        status,
+        requested_by_user_id: request.requested_by_user_id,
+        decided_by_user_id: request.decided_by_user_id,
+        created_at: request.created_at,
+        expires_at: request.expires_at,
+        decided_at: request.decided_at,
+    })
+}
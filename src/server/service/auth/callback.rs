@@ -8,13 +8,21 @@ use eve_esi::model::oauth2::EveJwtClaims;
 use oauth2::TokenResponse;
 use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
 
-use crate::server::{
-    data::user::{user_character::UserCharacterRepository, UserRepository},
-    error::AppError,
-    model::db::{CharacterOwnershipModel, EveCharacterModel},
-    service::{
-        eve::{esi::EsiProvider, orchestrator::EveEntityOrchestrator},
-        user::user_character::UserCharacterService,
+use crate::{
+    model::webhook::WebhookEvent,
+    server::{
+        data::user::{user_character::UserCharacterRepository, UserRepository},
+        error::{auth::AuthError, AppError},
+        model::{
+            db::{CharacterOwnershipModel, EveCharacterModel},
+            session::login_intent::LoginIntent,
+        },
+        service::{
+            eve::{esi::EsiProvider, orchestrator::EveEntityOrchestrator},
+            notification::OwnershipChange,
+            role::RoleService,
+            user::user_character::UserCharacterService,
+        },
     },
 };
 
@@ -71,11 +79,21 @@ pub(super) enum CharacterAction {
     TransferOwnership {
         /// If None, create a new user; otherwise use existing user ID
         to_user_id: Option<i32>,
+        /// ID of the user currently owning the character
+        from_user_id: i32,
         /// The existing character record from database
         character: EveCharacterModel,
         /// EVE Online owner hash from JWT token
         owner_hash: String,
     },
+    /// Character is owned by another user with the same owner hash; the user must confirm
+    /// merging that user before the character is transferred
+    ConfirmTransfer {
+        /// The logged in user the character would be transferred to
+        user_id: i32,
+        /// ID of the user currently owning the character
+        from_user_id: i32,
+    },
     /// Same user, owner hash changed (moved EVE accounts but still same user)
     UpdateOwnerHash {
         /// The user ID that owns the character
@@ -94,6 +112,44 @@ pub(super) enum CharacterAction {
     },
 }
 
+/// Result of a successfully processed OAuth callback.
+///
+/// `Debug` is implemented by hand so the refresh token never ends up in logs.
+#[derive(Clone, PartialEq)]
+pub struct CallbackOutcome {
+    /// ID of the user the authenticated character belongs to after the callback
+    pub user_id: i32,
+    /// EVE Online ID of the authenticated character
+    pub character_id: i64,
+    /// Name of the authenticated character
+    pub character_name: String,
+    /// Refresh token EVE SSO issued for the authenticated character, if any
+    pub refresh_token: Option<String>,
+    /// ESI scopes the authenticated character granted
+    pub scopes: Vec<String>,
+    /// Ownership changes the callback made, which webhooks are notified of
+    pub ownership_changes: Vec<OwnershipChange>,
+    /// ID of the user owning the character if transferring it awaits confirmation
+    pub pending_transfer: Option<i32>,
+}
+
+impl std::fmt::Debug for CallbackOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallbackOutcome")
+            .field("user_id", &self.user_id)
+            .field("character_id", &self.character_id)
+            .field("character_name", &self.character_name)
+            .field(
+                "refresh_token",
+                &self.refresh_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("scopes", &self.scopes)
+            .field("ownership_changes", &self.ownership_changes)
+            .field("pending_transfer", &self.pending_transfer)
+            .finish()
+    }
+}
+
 /// Service for handling OAuth2 callbacks from EVE Online SSO.
 ///
 /// This service orchestrates the authentication flow including token validation,
@@ -101,6 +157,7 @@ pub(super) enum CharacterAction {
 pub struct CallbackService<'a> {
     db: &'a DatabaseConnection,
     esi_provider: &'a EsiProvider,
+    required_scopes: Vec<String>,
 }
 
 impl<'a> CallbackService<'a> {
@@ -115,7 +172,26 @@ impl<'a> CallbackService<'a> {
     /// # Returns
     /// - `CallbackService` - New service instance
     pub fn new(db: &'a DatabaseConnection, esi_provider: &'a EsiProvider) -> Self {
-        Self { db, esi_provider }
+        Self {
+            db,
+            esi_provider,
+            required_scopes: Vec::new(),
+        }
+    }
+
+    /// Requires the authenticated character to have granted the given ESI scopes.
+    ///
+    /// Used for the scopes requested by the login, which the callback verifies in addition to
+    /// those requested by `LoginIntent::AddScopes`.
+    ///
+    /// # Arguments
+    /// - `scopes` - ESI scopes the character must have granted
+    ///
+    /// # Returns
+    /// - `CallbackService` - Service verifying the scopes on callback
+    pub fn with_required_scopes(mut self, scopes: Vec<String>) -> Self {
+        self.required_scopes = scopes;
+        self
     }
 
     /// Handles the OAuth2 callback after EVE SSO authentication.
@@ -124,13 +200,17 @@ impl<'a> CallbackService<'a> {
     /// It orchestrates the entire authentication flow including:
     /// - Validating the authorization code and extracting JWT claims
     /// - Determining the character's ownership status in the database
+    /// - Validating the login intent against the session and character status
+    /// - Verifying the character granted the scopes requested by the login
     /// - Taking appropriate action based on session state and character status
-    /// - Optionally updating the user's main character
+    /// - Updating the user's main character for `LoginIntent::ChangeMain`
     ///
     /// The function handles multiple scenarios:
     /// - New character login (fetches from ESI, persists, creates user if needed)
     /// - Existing but unowned character (links to current or new user)
     /// - Character transfer between users (updates ownership, handles main character)
+    /// - Character owned by another user of the same EVE account (no changes, transfer awaits
+    ///   confirmation)
     /// - Owner hash updates (same user, moved EVE accounts)
     /// - Already owned character (no action needed)
     ///
@@ -147,25 +227,48 @@ impl<'a> CallbackService<'a> {
     /// # Arguments
     /// - `authorization_code` - OAuth2 authorization code from EVE SSO redirect
     /// - `user_id` - Optional ID of currently logged-in user (None for new user login)
-    /// - `change_main` - Optional flag to set this character as the user's main character
+    /// - `intent` - Purpose of the login, stored in the session when the login was initiated
     ///
     /// # Returns
-    /// - `Ok(i32)` - The user ID after successful authentication and processing
+    /// - `Ok(CallbackOutcome)` - The user ID and authenticated character after successful
+    ///   processing, with the character's refresh token, granted scopes, the ownership
+    ///   changes made, and the user owning the character if its transfer awaits confirmation
     /// - `Err(AppError::Esi)` - Failed to fetch or validate OAuth2 token
     /// - `Err(AppError::Parse)` - Failed to parse character ID from JWT claims
     /// - `Err(AppError::Database)` - Database operation failed
+    /// - `Err(AppError::Auth(AuthError::UserNotInSession))` - Intent requires a logged in user but none is in session
+    /// - `Err(AppError::Auth(AuthError::ScopesNotGranted))` - Character did not grant every required scope or scope requested by `AddScopes`
+    /// - `Err(AppError::Auth(AuthError::CharacterNotOwned))` - `Reauth` for a character the user doesn't own
     /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User not found during character transfer
-    /// - `Err(AppError::Auth(AuthError::CharacterOwnedByAnotherUser))` - Attempted to set main character owned by different user
+    /// - `Err(AppError::Auth(AuthError::CharacterOwnedByAnotherUser))` - Attempted to set main character owned by different user, or `Reauth` for another user's character
     /// - `Err(AppError::Internal)` - Character persistence failed unexpectedly
     pub async fn handle_callback(
         &self,
         authorization_code: &str,
         user_id: Option<i32>,
-        change_main: Option<bool>,
-    ) -> Result<i32, AppError> {
-        let claims =
-            Self::authenticate_and_get_claims(self.esi_provider.client(), &authorization_code)
-                .await?;
+        intent: &LoginIntent,
+    ) -> Result<CallbackOutcome, AppError> {
+        let (claims, refresh_token) =
+            Self::authenticate(self.esi_provider.client(), authorization_code).await?;
+
+        if intent.requires_user() && user_id.is_none() {
+            return Err(AuthError::UserNotInSession.into());
+        }
+
+        let intent_scopes = match intent {
+            LoginIntent::AddScopes { scopes } => scopes.as_slice(),
+            _ => &[],
+        };
+        let mut missing_scopes: Vec<String> = Vec::new();
+        for scope in self.required_scopes.iter().chain(intent_scopes) {
+            if !claims.scp.contains(scope) && !missing_scopes.contains(scope) {
+                missing_scopes.push(scope.clone());
+            }
+        }
+
+        if !missing_scopes.is_empty() {
+            return Err(AuthError::ScopesNotGranted(missing_scopes).into());
+        }
 
         let character_record =
             Self::get_character_ownership_status(self.db, claims.character_id()?).await?;
@@ -175,117 +278,206 @@ impl<'a> CallbackService<'a> {
             None => Session::NotLoggedIn,
         };
 
-        let (user_id, ownership, txn) =
-            match Self::determine_character_action(session, character_record, &claims) {
-                CharacterAction::FetchAndLink {
-                    to_user_id,
-                    owner_hash,
-                } => {
-                    let character_id = claims.character_id()?;
-                    let eve_entity_orchestrator =
-                        EveEntityOrchestrator::builder(self.db, self.esi_provider)
-                            .character(character_id)
-                            .build()
-                            .await?;
+        let action = Self::determine_character_action(session, character_record, &claims);
 
-                    let txn = self.db.begin().await?;
+        if *intent == LoginIntent::Reauth {
+            Self::ensure_reauth_of_owned_character(&action)?;
+        }
 
-                    let stored_eve_entities = eve_entity_orchestrator.store(&txn).await?;
-                    let character = stored_eve_entities.get_character_or_err(&character_id)?;
+        let change_main = *intent == LoginIntent::ChangeMain;
 
-                    let user_id = Self::get_or_create_user(&txn, to_user_id, character.id).await?;
+        let character_id = claims.character_id()?;
+        let mut ownership_changes = Vec::new();
+        let ownership_change = |event, user_id, previous_user_id| OwnershipChange {
+            event,
+            character_id,
+            character_name: claims.name.clone(),
+            user_id,
+            previous_user_id,
+        };
 
-                    // Use link_character method to assign newly created character to logged in user
-                    let ownership = UserCharacterService::link_character(
-                        &txn,
-                        character.id,
-                        user_id,
-                        &owner_hash,
-                    )
-                    .await?;
+        let (user_id, ownership, txn) = match action {
+            CharacterAction::FetchAndLink {
+                to_user_id,
+                owner_hash,
+            } => {
+                let eve_entity_orchestrator =
+                    EveEntityOrchestrator::builder(self.db, self.esi_provider)
+                        .character(character_id)
+                        .build()
+                        .await?;
 
-                    (user_id, ownership, txn)
-                }
-                CharacterAction::LinkUnownedToUser {
-                    to_user_id,
-                    character,
-                    owner_hash,
-                } => {
-                    let txn = self.db.begin().await?;
+                let txn = self.db.begin().await?;
 
-                    let user_id = Self::get_or_create_user(&txn, to_user_id, character.id).await?;
+                let stored_eve_entities = eve_entity_orchestrator.store(&txn).await?;
+                let character = stored_eve_entities.get_character_or_err(&character_id)?;
 
-                    // Use link_character method to assign newly created character to logged in user
-                    let ownership = UserCharacterService::link_character(
-                        &txn,
-                        character.id,
-                        user_id,
-                        &owner_hash,
-                    )
-                    .await?;
+                let user_id = Self::get_or_create_user(&txn, to_user_id, character.id).await?;
 
-                    (user_id, ownership, txn)
-                }
-                CharacterAction::TransferOwnership {
-                    to_user_id,
-                    character,
-                    owner_hash,
-                } => {
-                    let txn = self.db.begin().await?;
+                // Use link_character method to assign newly created character to logged in user
+                let ownership =
+                    UserCharacterService::link_character(&txn, character.id, user_id, &owner_hash)
+                        .await?;
 
-                    let user_id = Self::get_or_create_user(&txn, to_user_id, character.id).await?;
+                ownership_changes.push(ownership_change(
+                    WebhookEvent::CharacterLinked,
+                    user_id,
+                    None,
+                ));
 
-                    // Transfer the character from previous user to currently logged in user
-                    let ownership = UserCharacterService::transfer_character(
-                        &txn,
-                        character.id,
-                        user_id,
-                        &owner_hash,
-                    )
-                    .await?;
+                (user_id, ownership, txn)
+            }
+            CharacterAction::LinkUnownedToUser {
+                to_user_id,
+                character,
+                owner_hash,
+            } => {
+                let txn = self.db.begin().await?;
 
-                    (user_id, ownership, txn)
-                }
-                CharacterAction::UpdateOwnerHash {
+                let user_id = Self::get_or_create_user(&txn, to_user_id, character.id).await?;
+
+                // Use link_character method to assign newly created character to logged in user
+                let ownership =
+                    UserCharacterService::link_character(&txn, character.id, user_id, &owner_hash)
+                        .await?;
+
+                ownership_changes.push(ownership_change(
+                    WebhookEvent::CharacterLinked,
                     user_id,
-                    character,
-                    owner_hash,
-                } => {
-                    let txn = self.db.begin().await?;
+                    None,
+                ));
+
+                (user_id, ownership, txn)
+            }
+            CharacterAction::TransferOwnership {
+                to_user_id,
+                from_user_id,
+                character,
+                owner_hash,
+            } => {
+                let txn = self.db.begin().await?;
 
-                    // Update owner hash via the link_character method which will upsert the hash
-                    let ownership = UserCharacterService::link_character(
-                        &txn,
-                        character.id,
-                        user_id,
-                        &owner_hash,
-                    )
-                    .await?;
+                let user_id = Self::get_or_create_user(&txn, to_user_id, character.id).await?;
 
-                    (user_id, ownership, txn)
-                }
-                CharacterAction::AlreadyOwned { user_id, ownership } => {
-                    // Handle change_main for AlreadyOwned case and return early
-                    if change_main.unwrap_or(false) {
-                        let txn = self.db.begin().await?;
+                // Transfer the character from previous user to currently logged in user
+                let ownership = UserCharacterService::transfer_character(
+                    &txn,
+                    character.id,
+                    user_id,
+                    &owner_hash,
+                )
+                .await?;
+
+                ownership_changes.push(ownership_change(
+                    WebhookEvent::CharacterTransferred,
+                    user_id,
+                    Some(from_user_id),
+                ));
+
+                (user_id, ownership, txn)
+            }
+            CharacterAction::ConfirmTransfer {
+                user_id,
+                from_user_id,
+            } => {
+                // The other user belongs to the same EVE account, leave the character with them
+                // until the logged in user confirms merging both users
+                return Ok(CallbackOutcome {
+                    user_id,
+                    character_id,
+                    character_name: claims.name,
+                    refresh_token,
+                    scopes: claims.scp,
+                    ownership_changes,
+                    pending_transfer: Some(from_user_id),
+                });
+            }
+            CharacterAction::UpdateOwnerHash {
+                user_id,
+                character,
+                owner_hash,
+            } => {
+                let txn = self.db.begin().await?;
 
-                        UserCharacterService::set_main_character(&txn, user_id, ownership).await?;
+                // Update owner hash via the link_character method which will upsert the hash
+                let ownership =
+                    UserCharacterService::link_character(&txn, character.id, user_id, &owner_hash)
+                        .await?;
 
-                        txn.commit().await?;
+                (user_id, ownership, txn)
+            }
+            CharacterAction::AlreadyOwned { user_id, ownership } => {
+                // Handle change_main for AlreadyOwned case and return early
+                if change_main {
+                    let txn = self.db.begin().await?;
+
+                    if Self::change_main(&txn, user_id, ownership).await? {
+                        ownership_changes.push(ownership_change(
+                            WebhookEvent::MainChanged,
+                            user_id,
+                            None,
+                        ));
                     }
 
-                    return Ok(user_id);
+                    txn.commit().await?;
                 }
-            };
+
+                return Ok(CallbackOutcome {
+                    user_id,
+                    character_id,
+                    character_name: claims.name,
+                    refresh_token,
+                    scopes: claims.scp,
+                    ownership_changes,
+                    pending_transfer: None,
+                });
+            }
+        };
 
         // Handle change_main within the same transaction for atomicity
-        if change_main.unwrap_or(false) {
-            UserCharacterService::set_main_character(&txn, user_id, ownership).await?;
+        if change_main && Self::change_main(&txn, user_id, ownership).await? {
+            ownership_changes.push(ownership_change(WebhookEvent::MainChanged, user_id, None));
         }
 
         txn.commit().await?;
 
-        Ok(user_id)
+        Ok(CallbackOutcome {
+            user_id,
+            character_id,
+            character_name: claims.name,
+            refresh_token,
+            scopes: claims.scp,
+            ownership_changes,
+            pending_transfer: None,
+        })
+    }
+
+    /// Sets a character as the user's main, reporting whether their main changed.
+    ///
+    /// # Arguments
+    /// - `txn` - Database transaction to execute the operation within
+    /// - `user_id` - ID of the user whose main character should be updated
+    /// - `ownership` - Ownership record of the character to set as main
+    ///
+    /// # Returns
+    /// - `Ok(true)` - The character replaced the user's previous main
+    /// - `Ok(false)` - The character already was the user's main
+    /// - `Err(AppError::Auth(AuthError::CharacterOwnedByAnotherUser))` - Character is owned by a different user
+    /// - `Err(AppError::Database)` - Database operation failed
+    async fn change_main(
+        txn: &DatabaseTransaction,
+        user_id: i32,
+        ownership: CharacterOwnershipModel,
+    ) -> Result<bool, AppError> {
+        let previous_main_id = UserRepository::new(txn)
+            .get_by_id(user_id)
+            .await?
+            .map(|(user, _)| user.main_character_id);
+        let character_record_id = ownership.character_id;
+
+        UserCharacterService::set_main_character(txn, user_id, ownership).await?;
+
+        Ok(previous_main_id != Some(character_record_id))
     }
 
     /// Exchanges an authorization code for an access token and validates it.
@@ -304,13 +496,37 @@ impl<'a> CallbackService<'a> {
         esi_client: &eve_esi::Client,
         authorization_code: &str,
     ) -> Result<EveJwtClaims, AppError> {
+        let (claims, _) = Self::authenticate(esi_client, authorization_code).await?;
+
+        Ok(claims)
+    }
+
+    /// Exchanges an authorization code for tokens and validates the access token.
+    ///
+    /// Same as `authenticate_and_get_claims`, additionally returning the refresh token EVE SSO
+    /// issued with the access token.
+    ///
+    /// # Arguments
+    /// - `authorization_code` - OAuth2 authorization code received from EVE SSO callback
+    ///
+    /// # Returns
+    /// - `Ok((EveJwtClaims, Option<String>))` - Validated JWT claims and the refresh token, if
+    ///   one was issued
+    /// - `Err(AppError::Esi)` - Failed to fetch token or validate JWT
+    async fn authenticate(
+        esi_client: &eve_esi::Client,
+        authorization_code: &str,
+    ) -> Result<(EveJwtClaims, Option<String>), AppError> {
         let token = esi_client.oauth2().get_token(authorization_code).await?;
         let claims = esi_client
             .oauth2()
             .validate_token(token.access_token().secret().to_string())
             .await?;
+        let refresh_token = token
+            .refresh_token()
+            .map(|refresh_token| refresh_token.secret().to_string());
 
-        Ok(claims)
+        Ok((claims, refresh_token))
     }
 
     /// Retrieves the ownership status of a character from the database.
@@ -345,8 +561,38 @@ impl<'a> CallbackService<'a> {
         }
     }
 
+    /// Verifies that a re-authentication only affects a character the user already owns.
+    ///
+    /// Re-authenticating may refresh the owner hash of the user's own character, but must
+    /// never link a new character or transfer one from another user.
+    ///
+    /// # Arguments
+    /// - `action` - The action determined for the authenticated character
+    ///
+    /// # Returns
+    /// - `Ok(())` - Character is owned by the logged in user
+    /// - `Err(AppError::Auth(AuthError::CharacterNotOwned))` - Character is not owned by any user
+    /// - `Err(AppError::Auth(AuthError::CharacterOwnedByAnotherUser))` - Character is owned by another user
+    pub(super) fn ensure_reauth_of_owned_character(
+        action: &CharacterAction,
+    ) -> Result<(), AppError> {
+        match action {
+            CharacterAction::AlreadyOwned { .. } | CharacterAction::UpdateOwnerHash { .. } => {
+                Ok(())
+            }
+            CharacterAction::FetchAndLink { .. } | CharacterAction::LinkUnownedToUser { .. } => {
+                Err(AuthError::CharacterNotOwned.into())
+            }
+            CharacterAction::TransferOwnership { .. } | CharacterAction::ConfirmTransfer { .. } => {
+                Err(AuthError::CharacterOwnedByAnotherUser.into())
+            }
+        }
+    }
+
     /// Gets an existing user ID or creates a new user with the given character as main.
     ///
+    /// A newly created user is made an admin if they are the first user.
+    ///
     /// # Arguments
     /// - `txn` - The database transaction to use
     /// - `to_user_id` - Optional user ID. If `Some`, returns that ID. If `None`, creates a new user.
@@ -364,7 +610,11 @@ impl<'a> CallbackService<'a> {
             Some(uid) => Ok(uid),
             None => {
                 let user_repo = UserRepository::new(txn);
-                Ok(user_repo.create(character_id).await?.id)
+                let user_id = user_repo.create(character_id).await?.id;
+
+                RoleService::grant_admin_to_first_user(txn, user_id).await?;
+
+                Ok(user_id)
             }
         }
     }
@@ -436,6 +686,7 @@ impl<'a> CallbackService<'a> {
                         } else {
                             CharacterAction::TransferOwnership {
                                 to_user_id: None, // Create a new user for the character
+                                from_user_id: ownership.user_id,
                                 character,
                                 owner_hash: claims.owner.to_string(),
                             }
@@ -454,9 +705,16 @@ impl<'a> CallbackService<'a> {
                                 character,
                                 owner_hash: claims.owner.to_string(),
                             },
-                            // Different user, regardless of hash - transfer ownership
-                            (false, _) => CharacterAction::TransferOwnership {
+                            // Different user, same owner hash - both users belong to the same
+                            // EVE account, confirm merging them before transferring
+                            (false, true) => CharacterAction::ConfirmTransfer {
+                                user_id: uid,
+                                from_user_id: ownership.user_id,
+                            },
+                            // Different user, different owner hash - transfer ownership
+                            (false, false) => CharacterAction::TransferOwnership {
                                 to_user_id: Some(uid),
+                                from_user_id: ownership.user_id,
                                 character,
                                 owner_hash: claims.owner.to_string(),
                             },
@@ -0,0 +1,339 @@
+//! Discord account linking service.
+//!
+//! This module provides the `DiscordLinkService` for linking Discord accounts to users with
+//! Discord's OAuth2 authorization code flow. Unlike EVE SSO, Discord is never used to log in:
+//! the flow starts from an existing session and only records which Discord account belongs to
+//! the user, so alliances can key access control off Discord identity. Linking is only
+//! available when `DISCORD_CLIENT_ID` is configured.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::Rng;
+use reqwest::Url;
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+
+use crate::{
+    model::user::DiscordAccountDto,
+    server::{
+        config::Config,
+        data::user::discord::UserDiscordRepository,
+        error::{auth::AuthError, AppError},
+        model::db::UserDiscordModel,
+    },
+};
+
+/// Base URL of the Discord API the token exchange and user lookup are sent to.
+pub const DISCORD_API_URL: &str = "https://discord.com/api/v10";
+
+/// Discord page users are redirected to for authorizing the application.
+const DISCORD_AUTHORIZE_URL: &str = "https://discord.com/oauth2/authorize";
+
+/// OAuth2 scope requested from Discord, which only grants reading the user's ID and username.
+const DISCORD_SCOPE: &str = "identify";
+
+/// Number of random bytes in the CSRF state token.
+const STATE_BYTES: usize = 32;
+
+/// Discord OAuth2 application credentials.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscordOAuthSettings {
+    /// Client ID of the Discord application.
+    pub client_id: String,
+    /// Client secret of the Discord application.
+    pub client_secret: String,
+    /// Callback URL registered as a redirect of the Discord application.
+    pub callback_url: Url,
+}
+
+/// Discord linking settings derived from the server configuration at startup.
+#[derive(Clone, Debug)]
+pub struct DiscordConfig {
+    /// OAuth2 application credentials, `None` if Discord linking is disabled.
+    pub oauth: Option<DiscordOAuthSettings>,
+    /// Base URL of the Discord API, replaced by a mock server in tests.
+    pub api_url: String,
+    /// HTTP client used for requests to the Discord API.
+    pub http: reqwest::Client,
+}
+
+impl Default for DiscordConfig {
+    fn default() -> Self {
+        Self {
+            oauth: None,
+            api_url: DISCORD_API_URL.to_string(),
+            http: reqwest::Client::new(),
+        }
+    }
+}
+
+impl DiscordConfig {
+    /// Builds Discord linking settings from the server configuration.
+    ///
+    /// # Arguments
+    /// - `config` - Server configuration loaded from environment variables
+    ///
+    /// # Returns
+    /// - `Ok(DiscordConfig)` - OAuth2 credentials if `DISCORD_CLIENT_ID` is set
+    /// - `Err(AppError)` - Failed to build the HTTP client
+    pub fn from_config(config: &Config) -> Result<Self, AppError> {
+        let Some(oauth) = &config.discord_oauth else {
+            return Ok(Self::default());
+        };
+
+        let http = reqwest::Client::builder()
+            .user_agent(&config.user_agent)
+            .build()?;
+
+        Ok(Self {
+            oauth: Some(oauth.clone()),
+            api_url: DISCORD_API_URL.to_string(),
+            http,
+        })
+    }
+}
+
+/// Discord authorization URL and the CSRF state token it carries.
+#[derive(Debug)]
+pub struct DiscordLogin {
+    /// URL of the Discord page asking the user to authorize the application.
+    pub login_url: String,
+    /// CSRF state token that must be stored in the session for the callback.
+    pub state: String,
+}
+
+/// Token response of the Discord authorization code exchange.
+#[derive(Deserialize)]
+struct DiscordTokenResponse {
+    access_token: String,
+}
+
+/// Discord user returned by `GET /users/@me`.
+#[derive(Deserialize)]
+struct DiscordUser {
+    id: String,
+    username: String,
+}
+
+/// Service for linking Discord accounts to users.
+pub struct DiscordLinkService<'a> {
+    db: &'a DatabaseConnection,
+    config: &'a DiscordConfig,
+}
+
+impl<'a> DiscordLinkService<'a> {
+    /// Creates a new instance of DiscordLinkService.
+    ///
+    /// Constructs a service for linking Discord accounts to users.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    /// - `config` - Discord linking settings
+    ///
+    /// # Returns
+    /// - `DiscordLinkService` - New service instance
+    pub fn new(db: &'a DatabaseConnection, config: &'a DiscordConfig) -> Self {
+        Self { db, config }
+    }
+
+    /// Generates the Discord authorization URL starting the linking flow.
+    ///
+    /// # Returns
+    /// - `Ok(DiscordLogin)` - Authorization URL and CSRF state token for callback validation
+    /// - `Err(AppError::Auth(AuthError::DiscordNotConfigured))` - Discord linking is disabled
+    pub fn generate_login_url(&self) -> Result<DiscordLogin, AppError> {
+        let oauth = self.oauth()?;
+
+        let mut bytes = [0u8; STATE_BYTES];
+        rand::rng().fill(&mut bytes);
+        let state = URL_SAFE_NO_PAD.encode(bytes);
+
+        let mut login_url =
+            Url::parse(DISCORD_AUTHORIZE_URL).expect("Discord authorize URL is valid");
+        login_url
+            .query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &oauth.client_id)
+            .append_pair("redirect_uri", oauth.callback_url.as_str())
+            .append_pair("scope", DISCORD_SCOPE)
+            .append_pair("state", &state)
+            .append_pair("prompt", "none");
+
+        Ok(DiscordLogin {
+            login_url: login_url.into(),
+            state,
+        })
+    }
+
+    /// Completes the linking flow, linking the authorized Discord account to the user.
+    ///
+    /// Exchanges the authorization code for an access token and looks up the Discord user it
+    /// belongs to. The access token is only used for this lookup and not stored. Linking a
+    /// different account replaces the account the user linked before.
+    ///
+    /// # Arguments
+    /// - `code` - Authorization code from the Discord callback
+    /// - `user_id` - ID of the user linking the account
+    ///
+    /// # Returns
+    /// - `Ok(DiscordAccountDto)` - Linked Discord account
+    /// - `Err(AppError::Auth(AuthError::DiscordNotConfigured))` - Discord linking is disabled
+    /// - `Err(AppError::Auth(AuthError::DiscordAccountLinkedToAnotherUser))` - Account is
+    ///   already linked to a different user
+    /// - `Err(AppError::Auth(AuthError::DiscordRequestFailed))` - Discord rejected the code or
+    ///   returned an unexpected user
+    /// - `Err(AppError::Http)` - Request to Discord could not be sent
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn handle_callback(
+        &self,
+        code: &str,
+        user_id: i32,
+    ) -> Result<DiscordAccountDto, AppError> {
+        let oauth = self.oauth()?;
+
+        let access_token = self.exchange_code(oauth, code).await?;
+        let discord_user = self.fetch_user(&access_token).await?;
+        let discord_id: i64 = discord_user.id.parse().map_err(|_| {
+            AuthError::DiscordRequestFailed(format!("invalid user ID {}", discord_user.id))
+        })?;
+
+        let discord_repo = UserDiscordRepository::new(self.db);
+
+        if let Some(existing) = discord_repo.get_by_discord_id(discord_id).await? {
+            if existing.user_id != user_id {
+                return Err(AuthError::DiscordAccountLinkedToAnotherUser(discord_id).into());
+            }
+        }
+
+        discord_repo
+            .upsert(user_id, discord_id, &discord_user.username)
+            .await?;
+
+        let Some(linked) = discord_repo.get_by_user_id(user_id).await? else {
+            return Err(AuthError::UserNotInDatabase(user_id).into());
+        };
+
+        Ok(Self::to_dto(linked))
+    }
+
+    /// Retrieves the Discord account linked to a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Some(DiscordAccountDto))` - User has linked a Discord account
+    /// - `Ok(None)` - User has not linked a Discord account
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_discord_account(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<DiscordAccountDto>, AppError> {
+        let linked = UserDiscordRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?;
+
+        Ok(linked.map(Self::to_dto))
+    }
+
+    /// Unlinks the Discord account of a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Discord account unlinked
+    /// - `Ok(false)` - User had no Discord account linked
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn unlink(&self, user_id: i32) -> Result<bool, AppError> {
+        Ok(UserDiscordRepository::new(self.db)
+            .delete_by_user_id(user_id)
+            .await?)
+    }
+
+    /// Returns the OAuth2 credentials, failing if Discord linking is disabled.
+    fn oauth(&self) -> Result<&'a DiscordOAuthSettings, AuthError> {
+        self.config
+            .oauth
+            .as_ref()
+            .ok_or(AuthError::DiscordNotConfigured)
+    }
+
+    /// Exchanges an authorization code for an access token.
+    ///
+    /// # Arguments
+    /// - `oauth` - OAuth2 application credentials
+    /// - `code` - Authorization code from the Discord callback
+    ///
+    /// # Returns
+    /// - `Ok(String)` - Access token of the user
+    /// - `Err(AppError::Auth(AuthError::DiscordRequestFailed))` - Discord rejected the code
+    /// - `Err(AppError::Http)` - Request could not be sent or the response not parsed
+    async fn exchange_code(
+        &self,
+        oauth: &DiscordOAuthSettings,
+        code: &str,
+    ) -> Result<String, AppError> {
+        let response = self
+            .config
+            .http
+            .post(format!("{}/oauth2/token", self.config.api_url))
+            .basic_auth(&oauth.client_id, Some(&oauth.client_secret))
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", oauth.callback_url.as_str()),
+            ])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(AuthError::DiscordRequestFailed(format!(
+                "token exchange returned {}",
+                response.status()
+            ))
+            .into());
+        }
+
+        let token: DiscordTokenResponse = response.json().await?;
+
+        Ok(token.access_token)
+    }
+
+    /// Looks up the Discord user an access token belongs to.
+    ///
+    /// # Arguments
+    /// - `access_token` - Access token of the user
+    ///
+    /// # Returns
+    /// - `Ok(DiscordUser)` - ID and username of the user
+    /// - `Err(AppError::Auth(AuthError::DiscordRequestFailed))` - Discord rejected the token
+    /// - `Err(AppError::Http)` - Request could not be sent or the response not parsed
+    async fn fetch_user(&self, access_token: &str) -> Result<DiscordUser, AppError> {
+        let response = self
+            .config
+            .http
+            .get(format!("{}/users/@me", self.config.api_url))
+            .bearer_auth(access_token)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(AuthError::DiscordRequestFailed(format!(
+                "user lookup returned {}",
+                response.status()
+            ))
+            .into());
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Converts a linked Discord account record into its DTO.
+    fn to_dto(linked: UserDiscordModel) -> DiscordAccountDto {
+        DiscordAccountDto {
+            discord_id: linked.discord_id.to_string(),
+            username: linked.username,
+            linked_at: linked.linked_at,
+        }
+    }
+}
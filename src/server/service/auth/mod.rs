@@ -2,10 +2,14 @@
 //!
 //! This module contains business logic services for handling EVE Online SSO authentication.
 //! Services manage the OAuth2 flow including login URL generation and callback processing
-//! with character ownership management.
+//! with character ownership management and confirmation of transfers between users of the
+//! same EVE Online account, as well as the separate Discord OAuth2 flow linking Discord
+//! accounts to users.
 
 pub mod callback;
+pub mod discord;
 pub mod login;
+pub mod transfer;
 
 #[cfg(test)]
 mod tests;
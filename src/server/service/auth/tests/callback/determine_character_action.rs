@@ -175,10 +175,12 @@ fn owned_character_not_logged_in_hash_differs() {
     match action {
         CharacterAction::TransferOwnership {
             to_user_id,
+            from_user_id,
             character: returned_char,
             owner_hash,
         } => {
             assert_eq!(to_user_id, None);
+            assert_eq!(from_user_id, 10);
             assert_eq!(returned_char.id, character.id);
             assert_eq!(owner_hash, "new_owner_hash");
         }
@@ -254,9 +256,10 @@ fn owned_character_same_user_hash_differs() {
 /// Tests owned character with different user logged in and matching owner hash.
 ///
 /// Verifies that when a character is owned by a different user than the one
-/// logged in, the action is to transfer ownership regardless of hash match.
+/// logged in but the owner hash matches (both users belong to the same EVE
+/// account), the transfer awaits confirmation instead of happening right away.
 ///
-/// Expected: TransferOwnership with to_user_id = Some(logged_in_user_id)
+/// Expected: ConfirmTransfer with user_id = logged_in_user_id
 #[test]
 fn owned_character_different_user_hash_matches() {
     let session = Session::LoggedIn(42);
@@ -271,16 +274,14 @@ fn owned_character_different_user_hash_matches() {
     let action = CallbackService::determine_character_action(session, record, &claims);
 
     match action {
-        CharacterAction::TransferOwnership {
-            to_user_id,
-            character: returned_char,
-            owner_hash,
+        CharacterAction::ConfirmTransfer {
+            user_id,
+            from_user_id,
         } => {
-            assert_eq!(to_user_id, Some(42));
-            assert_eq!(returned_char.id, character.id);
-            assert_eq!(owner_hash, "owner_hash_123");
+            assert_eq!(user_id, 42);
+            assert_eq!(from_user_id, 10);
         }
-        _ => panic!("Expected TransferOwnership action, got: {:?}", action),
+        _ => panic!("Expected ConfirmTransfer action, got: {:?}", action),
     }
 }
 
@@ -306,10 +307,12 @@ fn owned_character_different_user_hash_differs() {
     match action {
         CharacterAction::TransferOwnership {
             to_user_id,
+            from_user_id,
             character: returned_char,
             owner_hash,
         } => {
             assert_eq!(to_user_id, Some(42));
+            assert_eq!(from_user_id, 10);
             assert_eq!(returned_char.id, character.id);
             assert_eq!(owner_hash, "new_owner_hash");
         }
@@ -0,0 +1,112 @@
+//! Character transfer confirmation service.
+//!
+//! This module provides the `TransferService` for completing transfers the OAuth callback left
+//! awaiting confirmation. A logged in user authenticating a character owned by another user
+//! with the same owner hash proves both users belong to the same EVE Online account, so
+//! confirming the transfer merges the other user, with all of their characters, into the
+//! logged in user.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::{
+        user::{PendingTransferDto, UserMergeDto},
+        webhook::WebhookEvent,
+    },
+    server::{
+        data::user::user_character::UserCharacterRepository,
+        error::{auth::AuthError, AppError},
+        service::{notification::OwnershipChange, user::UserService},
+    },
+};
+
+/// Result of a confirmed character transfer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferOutcome {
+    /// Summary of the records moved from the merged user
+    pub merge: UserMergeDto,
+    /// Characters transferred from the merged user, which webhooks are notified of
+    pub ownership_changes: Vec<OwnershipChange>,
+}
+
+/// Service for confirming character transfers between users of the same EVE Online account.
+pub struct TransferService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> TransferService<'a> {
+    /// Creates a new instance of TransferService.
+    ///
+    /// Constructs a service for confirming character transfers.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `TransferService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Confirms a pending transfer, merging the user owning the character into the logged in
+    /// user.
+    ///
+    /// The character must still belong to the user it was owned by when the callback recorded
+    /// the transfer, so a stale confirmation never merges a user the character has since left.
+    /// Every character of the merged user is moved to the logged in user and the emptied user
+    /// is deleted in a single transaction by `UserService::merge_users`.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the logged in user confirming the transfer
+    /// - `transfer` - Pending transfer stored in the session by the callback
+    ///
+    /// # Returns
+    /// - `Ok(TransferOutcome)` - Summary of the merge and the characters transferred
+    /// - `Err(AppError::Auth(AuthError::NoPendingTransfer))` - Character no longer belongs to
+    ///   the user it would be transferred from
+    /// - `Err(AppError::User(UserError::MergeIntoSelf))` - Character already belongs to the
+    ///   logged in user
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - Either user does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn confirm_transfer(
+        &self,
+        user_id: i32,
+        transfer: &PendingTransferDto,
+    ) -> Result<TransferOutcome, AppError> {
+        let user_character_repo = UserCharacterRepository::new(self.db);
+
+        let owner_id = user_character_repo
+            .get_character_with_ownership(transfer.character_id)
+            .await?
+            .and_then(|(_, ownership)| ownership)
+            .map(|ownership| ownership.user_id);
+
+        if owner_id != Some(transfer.from_user_id) {
+            return Err(AuthError::NoPendingTransfer.into());
+        }
+
+        let characters = user_character_repo
+            .get_owned_characters_by_user_id(transfer.from_user_id)
+            .await?;
+
+        let merge = UserService::new(self.db)
+            .merge_users(user_id, transfer.from_user_id)
+            .await?;
+
+        let ownership_changes = characters
+            .into_iter()
+            .map(|(character, _, _)| OwnershipChange {
+                event: WebhookEvent::CharacterTransferred,
+                character_id: character.character_id,
+                character_name: character.name,
+                user_id,
+                previous_user_id: Some(transfer.from_user_id),
+            })
+            .collect();
+
+        Ok(TransferOutcome {
+            merge,
+            ownership_changes,
+        })
+    }
+}
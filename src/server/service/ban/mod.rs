@@ -0,0 +1,237 @@
+//! User ban service layer.
+//!
+//! This module contains the `BanService` suspending users. Admins issue temporary or permanent
+//! bans with a reason, and a user stays suspended while any of their bans is neither expired
+//! nor lifted. Requests from suspended users are rejected by the `reject_suspended_users`
+//! middleware. Issued and lifted bans are written to the info log with the acting admin so they
+//! can be audited.
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::ban::{CreateUserBanDto, UserBanDto},
+    server::{
+        data::{ban::UserBanRepository, user::UserRepository},
+        error::{auth::AuthError, ban::BanError, AppError},
+        model::db::UserBanModel,
+    },
+};
+
+/// Maximum length of a ban reason in characters.
+const MAX_REASON_LENGTH: usize = 500;
+
+/// Service for suspending users with bans.
+pub struct BanService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> BanService<'a> {
+    /// Creates a new instance of BanService.
+    ///
+    /// Constructs a service for issuing, lifting, and checking user bans.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `BanService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Bans a user, suspending them until the ban expires or is lifted.
+    ///
+    /// # Arguments
+    /// - `issued_by_user_id` - ID of the admin issuing the ban
+    /// - `user_id` - ID of the user to ban
+    /// - `ban` - Reason and duration of the ban
+    ///
+    /// # Returns
+    /// - `Ok(UserBanDto)` - The issued ban
+    /// - `Err(AppError::Ban(BanError::InvalidBan))` - Reason is empty or too long, duration is
+    ///   zero, or the admin tried to ban themselves
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn ban_user(
+        &self,
+        issued_by_user_id: i32,
+        user_id: i32,
+        ban: CreateUserBanDto,
+    ) -> Result<UserBanDto, AppError> {
+        let reason = ban.reason.trim();
+        if reason.is_empty() {
+            return Err(BanError::InvalidBan("Ban reason must not be empty".to_string()).into());
+        }
+        if reason.chars().count() > MAX_REASON_LENGTH {
+            return Err(BanError::InvalidBan(format!(
+                "Ban reason must be at most {} characters",
+                MAX_REASON_LENGTH
+            ))
+            .into());
+        }
+        if ban.duration_hours == Some(0) {
+            return Err(
+                BanError::InvalidBan("Ban duration must be at least one hour".to_string()).into(),
+            );
+        }
+        if issued_by_user_id == user_id {
+            return Err(BanError::InvalidBan("You can't ban yourself".to_string()).into());
+        }
+
+        if UserRepository::new(self.db)
+            .get_by_id(user_id)
+            .await?
+            .is_none()
+        {
+            return Err(AuthError::UserNotInDatabase(user_id).into());
+        }
+
+        let now = Utc::now().naive_utc();
+        let expires_at = ban
+            .duration_hours
+            .map(|hours| now + Duration::hours(i64::from(hours)));
+        let ban = UserBanRepository::new(self.db)
+            .create(
+                user_id,
+                reason.to_string(),
+                Some(issued_by_user_id),
+                now,
+                expires_at,
+            )
+            .await?;
+
+        tracing::info!(
+            user_id = %user_id,
+            issued_by_user_id = %issued_by_user_id,
+            ban_id = %ban.id,
+            expires_at = ?ban.expires_at,
+            "Banned user: {}",
+            ban.reason
+        );
+
+        Ok(ban_to_dto(ban, now))
+    }
+
+    /// Retrieves all bans of a user, most recently issued first.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserBanDto>)` - Bans of the user, including expired and lifted bans
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User doesn't exist
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_user_bans(&self, user_id: i32) -> Result<Vec<UserBanDto>, AppError> {
+        if UserRepository::new(self.db)
+            .get_by_id(user_id)
+            .await?
+            .is_none()
+        {
+            return Err(AuthError::UserNotInDatabase(user_id).into());
+        }
+
+        let now = Utc::now().naive_utc();
+        let bans = UserBanRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .map(|ban| ban_to_dto(ban, now))
+            .collect();
+
+        Ok(bans)
+    }
+
+    /// Lifts a ban before it ends.
+    ///
+    /// Lifting a ban that was already lifted or has expired returns it unchanged.
+    ///
+    /// # Arguments
+    /// - `lifted_by_user_id` - ID of the admin lifting the ban
+    /// - `ban_id` - ID of the ban
+    ///
+    /// # Returns
+    /// - `Ok(UserBanDto)` - The lifted ban
+    /// - `Err(AppError::Ban(BanError::BanNotFound))` - Ban doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn lift_ban(
+        &self,
+        lifted_by_user_id: i32,
+        ban_id: i32,
+    ) -> Result<UserBanDto, AppError> {
+        let ban_repo = UserBanRepository::new(self.db);
+        let now = Utc::now().naive_utc();
+
+        let Some(ban) = ban_repo.find_by_id(ban_id).await? else {
+            return Err(BanError::BanNotFound(ban_id).into());
+        };
+        if !is_active(&ban, now) {
+            return Ok(ban_to_dto(ban, now));
+        }
+
+        let Some(ban) = ban_repo.lift(ban_id, lifted_by_user_id, now).await? else {
+            return Err(BanError::BanNotFound(ban_id).into());
+        };
+
+        tracing::info!(
+            user_id = %ban.user_id,
+            lifted_by_user_id = %lifted_by_user_id,
+            ban_id = %ban.id,
+            "Lifted user ban"
+        );
+
+        Ok(ban_to_dto(ban, now))
+    }
+
+    /// Fails if the user is suspended by an active ban.
+    ///
+    /// When several bans are active the suspension is reported as lasting until the last of
+    /// them ends, with the reason of that ban.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(())` - User isn't suspended
+    /// - `Err(AppError::Auth(AuthError::Suspended))` - User is suspended
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn ensure_not_suspended(&self, user_id: i32) -> Result<(), AppError> {
+        let now = Utc::now().naive_utc();
+        let longest_ban = UserBanRepository::new(self.db)
+            .get_active_by_user_id(user_id, now)
+            .await?
+            .into_iter()
+            // Permanent bans have no expiry and outlast any temporary ban
+            .max_by_key(|ban| (ban.expires_at.is_none(), ban.expires_at));
+
+        match longest_ban {
+            Some(ban) => Err(AuthError::Suspended {
+                reason: ban.reason,
+                until: ban.expires_at,
+            }
+            .into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns whether a ban is in effect at a point in time.
+fn is_active(ban: &UserBanModel, now: NaiveDateTime) -> bool {
+    ban.lifted_at.is_none() && ban.expires_at.is_none_or(|expires_at| expires_at > now)
+}
+
+/// Converts a stored ban into its DTO.
+fn ban_to_dto(ban: UserBanModel, now: NaiveDateTime) -> UserBanDto {
+    UserBanDto {
+        active: is_active(&ban, now),
+        id: ban.id,
+        user_id: ban.user_id,
+        reason: ban.reason,
+        issued_by_user_id: ban.issued_by_user_id,
+        created_at: ban.created_at,
+        expires_at: ban.expires_at,
+        lifted_at: ban.lifted_at,
+        lifted_by_user_id: ban.lifted_by_user_id,
+    }
+}
@@ -0,0 +1,146 @@
+//! Campaign service layer.
+//!
+//! This module contains the `CampaignService` for tracking deployments and wars. A campaign
+//! records a name, the solar system it stages out of, and the date range it covers, giving
+//! leadership a fixed reference to review each deployment against.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::campaign::{CampaignDto, CreateCampaignDto},
+    server::{
+        data::campaign::CampaignRepository,
+        error::{campaign::CampaignError, AppError},
+        model::db::CampaignModel,
+    },
+};
+
+/// Service for managing deployment campaigns.
+pub struct CampaignService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> CampaignService<'a> {
+    /// Creates a new instance of CampaignService.
+    ///
+    /// Constructs a service for managing deployment campaigns.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `CampaignService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a campaign.
+    ///
+    /// Name and staging system are trimmed and must not be empty, and the end date, when
+    /// provided, must not be before the start date.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user creating the campaign
+    /// - `campaign` - Campaign name, staging system, and date range
+    ///
+    /// # Returns
+    /// - `Ok(CampaignDto)` - The created campaign
+    /// - `Err(AppError::Campaign(CampaignError::InvalidCampaign))` - Campaign input failed validation
+    /// - `Err(AppError::Campaign(CampaignError::DuplicateCampaignName))` - Name already in use
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_campaign(
+        &self,
+        user_id: i32,
+        campaign: CreateCampaignDto,
+    ) -> Result<CampaignDto, AppError> {
+        let name = campaign.name.trim().to_string();
+        let staging_system = campaign.staging_system.trim().to_string();
+
+        if name.is_empty() {
+            return Err(
+                CampaignError::InvalidCampaign("name must not be empty".to_string()).into(),
+            );
+        }
+
+        if staging_system.is_empty() {
+            return Err(CampaignError::InvalidCampaign(
+                "staging system must not be empty".to_string(),
+            )
+            .into());
+        }
+
+        if campaign
+            .ends_at
+            .is_some_and(|ends_at| ends_at < campaign.starts_at)
+        {
+            return Err(CampaignError::InvalidCampaign(
+                "end date must not be before start date".to_string(),
+            )
+            .into());
+        }
+
+        let campaign_repo = CampaignRepository::new(self.db);
+
+        if campaign_repo.get_by_name(&name).await?.is_some() {
+            return Err(CampaignError::DuplicateCampaignName(name).into());
+        }
+
+        let campaign = campaign_repo
+            .create(
+                name,
+                staging_system,
+                campaign.starts_at,
+                campaign.ends_at,
+                user_id,
+            )
+            .await?;
+
+        Ok(campaign_to_dto(campaign))
+    }
+
+    /// Retrieves all campaigns.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<CampaignDto>)` - All campaigns, most recently started first
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn get_campaigns(&self) -> Result<Vec<CampaignDto>, AppError> {
+        Ok(CampaignRepository::new(self.db)
+            .get_all()
+            .await?
+            .into_iter()
+            .map(campaign_to_dto)
+            .collect())
+    }
+
+    /// Deletes a campaign.
+    ///
+    /// # Arguments
+    /// - `campaign_id` - ID of the campaign to delete
+    ///
+    /// # Returns
+    /// - `Ok(())` - Campaign deleted
+    /// - `Err(AppError::Campaign(CampaignError::CampaignNotFound))` - Campaign does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_campaign(&self, campaign_id: i32) -> Result<(), AppError> {
+        let result = CampaignRepository::new(self.db).delete(campaign_id).await?;
+
+        if result.rows_affected == 0 {
+            return Err(CampaignError::CampaignNotFound(campaign_id).into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a stored campaign into its DTO.
+fn campaign_to_dto(campaign: CampaignModel) -> CampaignDto {
+    CampaignDto {
+        id: campaign.id,
+        name: campaign.name,
+        staging_system: campaign.staging_system,
+        starts_at: campaign.starts_at,
+        ends_at: campaign.ends_at,
+        created_at: campaign.created_at,
+        updated_at: campaign.updated_at,
+    }
+}
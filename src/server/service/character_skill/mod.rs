@@ -0,0 +1,213 @@
+//! Character skill service layer.
+//!
+//! This module contains the `CharacterSkillService` for the skill snapshots of characters
+//! whose stored refresh token grants the `esi-skills.read_skills.v1` scope. Snapshots are
+//! fetched by the `UpdateCharacterSkills` worker job, which the scheduler dispatches
+//! periodically and logins granting the scope dispatch right away. Users read the snapshots of
+//! their own characters, and the active skill levels of snapshots are compared against doctrine
+//! fittings to report who can fly them.
+
+use std::collections::HashMap;
+
+use sea_orm::{DatabaseConnection, TransactionTrait};
+
+use crate::{
+    model::character_skill::{CharacterSkillDto, CharacterSkillsDto},
+    server::{
+        data::{
+            character_skill::CharacterSkillRepository,
+            eve::{character::CharacterRepository, corporation::CorporationRepository},
+            user::user_character::UserCharacterRepository,
+        },
+        error::{auth::AuthError, character_skill::CharacterSkillError, AppError},
+        service::{eve::esi::EsiProvider, token::TokenService},
+        util::crypto::ColumnCipher,
+    },
+};
+
+/// Service for tracking the skills of characters.
+pub struct CharacterSkillService<'a> {
+    db: &'a DatabaseConnection,
+    esi_provider: &'a EsiProvider,
+    cipher: &'a ColumnCipher,
+}
+
+impl<'a> CharacterSkillService<'a> {
+    /// Creates a new instance of CharacterSkillService.
+    ///
+    /// Constructs a service for tracking character skill snapshots.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    /// - `esi_provider` - ESI provider used to refresh tokens and fetch skills
+    /// - `cipher` - Cipher refresh tokens are encrypted with
+    ///
+    /// # Returns
+    /// - `CharacterSkillService` - New service instance
+    pub fn new(
+        db: &'a DatabaseConnection,
+        esi_provider: &'a EsiProvider,
+        cipher: &'a ColumnCipher,
+    ) -> Self {
+        Self {
+            db,
+            esi_provider,
+            cipher,
+        }
+    }
+
+    /// Fetches a character's skills from ESI and replaces the stored snapshot.
+    ///
+    /// The character's stored refresh token is exchanged for an access token with
+    /// `TokenService`, which stores the rotated token and deletes a revoked one.
+    ///
+    /// # Arguments
+    /// - `character_id` - EVE Online ID of the character
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of skills the character has
+    /// - `Err(AppError::Auth(AuthError::CharacterNotFound))` - Character is not stored
+    /// - `Err(AppError::Token)` - No token is stored for the character or it was revoked
+    /// - `Err(AppError::Encryption)` - Stored token can't be decrypted with the configured keys
+    /// - `Err(AppError::Esi)` - Token doesn't grant the skills scope or the ESI request failed
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn update_skills(&self, character_id: i64) -> Result<usize, AppError> {
+        let character = CharacterRepository::new(self.db)
+            .find_by_eve_id(character_id)
+            .await?
+            .ok_or(AuthError::CharacterNotFound)?;
+
+        let access_token = TokenService::new(self.db, self.esi_provider, self.cipher)
+            .get_access_token(character_id)
+            .await?;
+
+        let skills: Vec<(i64, i32, i32, i64)> = self
+            .esi_provider
+            .character()
+            .get_character_skills(&access_token, character_id)
+            .send()
+            .await?
+            .data
+            .skills
+            .into_iter()
+            .map(|skill| {
+                (
+                    skill.skill_id,
+                    skill.active_skill_level,
+                    skill.trained_skill_level,
+                    skill.skillpoints_in_skill,
+                )
+            })
+            .collect();
+
+        let txn = self.db.begin().await?;
+        CharacterSkillRepository::new(&txn)
+            .replace_skills(character.id, &skills)
+            .await?;
+        txn.commit().await?;
+
+        Ok(skills.len())
+    }
+
+    /// Retrieves the skill snapshot of a character owned by a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the requesting user
+    /// - `character_id` - EVE Online ID of the character
+    ///
+    /// # Returns
+    /// - `Ok(CharacterSkillsDto)` - Skills ordered by skill ID with the total skill points
+    /// - `Err(AppError::CharacterSkill(CharacterSkillError::CharacterNotFound))` - Character
+    ///   is not stored or not owned by the user
+    /// - `Err(AppError::CharacterSkill(CharacterSkillError::NotTracked))` - The character's
+    ///   skills were never fetched
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_skills(
+        &self,
+        user_id: i32,
+        character_id: i64,
+    ) -> Result<CharacterSkillsDto, AppError> {
+        let character = match UserCharacterRepository::new(self.db)
+            .get_character_with_ownership(character_id)
+            .await?
+        {
+            Some((character, Some(ownership))) if ownership.user_id == user_id => character,
+            _ => return Err(CharacterSkillError::CharacterNotFound(character_id).into()),
+        };
+
+        let skills = CharacterSkillRepository::new(self.db)
+            .get_skills(character.id)
+            .await?;
+        let Some(updated_at) = skills.iter().map(|skill| skill.updated_at).max() else {
+            return Err(CharacterSkillError::NotTracked(character_id).into());
+        };
+
+        Ok(CharacterSkillsDto {
+            character_id,
+            character_name: character.name,
+            total_skillpoints: skills.iter().map(|skill| skill.skillpoints).sum(),
+            updated_at,
+            skills: skills
+                .into_iter()
+                .map(|skill| CharacterSkillDto {
+                    skill_id: skill.skill_id,
+                    active_level: skill.active_level,
+                    trained_level: skill.trained_level,
+                    skillpoints: skill.skillpoints,
+                })
+                .collect(),
+        })
+    }
+
+    /// Retrieves the active skill levels of characters.
+    ///
+    /// Active levels are used rather than trained levels as Alpha clones can't use skills
+    /// above their active level.
+    ///
+    /// # Arguments
+    /// - `character_ids` - Record IDs of the characters
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, HashMap<i64, i32>>)` - Active levels keyed by skill ID, keyed by
+    ///   character record ID (characters whose skills were never fetched are left out)
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_active_levels(
+        &self,
+        character_ids: &[i32],
+    ) -> Result<HashMap<i32, HashMap<i64, i32>>, AppError> {
+        Ok(CharacterSkillRepository::new(self.db)
+            .get_active_levels(character_ids)
+            .await?)
+    }
+
+    /// Retrieves the active skill levels of the characters in a corporation.
+    ///
+    /// Only characters whose skills were fetched are included, so the result covers the
+    /// corporation's members who logged in granting the skills scope.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - EVE Online ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(HashMap<i32, HashMap<i64, i32>>)` - Active levels keyed by skill ID, keyed by
+    ///   character record ID
+    /// - `Err(AppError::CharacterSkill(CharacterSkillError::CorporationNotFound))` -
+    ///   Corporation is not stored
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_corporation_active_levels(
+        &self,
+        corporation_id: i64,
+    ) -> Result<HashMap<i32, HashMap<i64, i32>>, AppError> {
+        let corporation = CorporationRepository::new(self.db)
+            .find_by_eve_id(corporation_id)
+            .await?
+            .ok_or(CharacterSkillError::CorporationNotFound(corporation_id))?;
+
+        let skill_repo = CharacterSkillRepository::new(self.db);
+        let character_ids = skill_repo
+            .get_tracked_character_ids_by_corporation(corporation.id)
+            .await?;
+
+        Ok(skill_repo.get_active_levels(&character_ids).await?)
+    }
+}
@@ -0,0 +1,160 @@
+//! Consent service layer.
+//!
+//! This module contains the `ConsentService` for managing which categories of data a user
+//! shares with the organization. Services that sync a category of user data must check
+//! consent through this service before syncing, and revoking consent schedules a job to
+//! delete the data already stored for that category.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::consent::{ConsentCategory, ConsentDto},
+    server::{
+        data::consent::UserConsentRepository,
+        error::{consent::ConsentError, AppError},
+    },
+};
+
+/// Service for managing user data-sharing consent.
+///
+/// Provides methods for listing, granting, and revoking consent per data category, and for
+/// other services to verify consent before syncing user data.
+pub struct ConsentService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> ConsentService<'a> {
+    /// Creates a new instance of ConsentService.
+    ///
+    /// Constructs a service for managing user data-sharing consent.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ConsentService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the consent status of every data category for a user.
+    ///
+    /// Every category requested by the organization is included, whether or not the user
+    /// has granted consent for it.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ConsentDto>)` - Consent status for each category
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_consents(&self, user_id: i32) -> Result<Vec<ConsentDto>, AppError> {
+        let granted = UserConsentRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?;
+
+        let consents = ConsentCategory::ALL
+            .into_iter()
+            .map(|category| {
+                let granted_at = granted
+                    .iter()
+                    .find(|consent| consent.category == category.as_str())
+                    .map(|consent| consent.granted_at);
+
+                ConsentDto {
+                    category,
+                    description: category.description().to_string(),
+                    granted: granted_at.is_some(),
+                    granted_at,
+                }
+            })
+            .collect();
+
+        Ok(consents)
+    }
+
+    /// Grants consent for a data category.
+    ///
+    /// Granting consent that was already granted has no effect.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user granting consent
+    /// - `category` - Data category to grant consent for
+    ///
+    /// # Returns
+    /// - `Ok(())` - Consent is granted
+    /// - `Err(AppError::Database)` - Database operation failed or user doesn't exist
+    pub async fn grant(&self, user_id: i32, category: ConsentCategory) -> Result<(), AppError> {
+        UserConsentRepository::new(self.db)
+            .grant(user_id, category.as_str())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Revokes consent for a data category.
+    ///
+    /// The caller is responsible for scheduling deletion of the category's stored data when
+    /// consent was revoked.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user revoking consent
+    /// - `category` - Data category to revoke consent for
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Consent was revoked
+    /// - `Ok(false)` - Consent was not granted
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn revoke(&self, user_id: i32, category: ConsentCategory) -> Result<bool, AppError> {
+        let result = UserConsentRepository::new(self.db)
+            .revoke(user_id, category.as_str())
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Checks whether a user has granted consent for a data category.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `category` - Data category to check
+    ///
+    /// # Returns
+    /// - `Ok(true)` - User has granted consent
+    /// - `Ok(false)` - User has not granted consent
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn has_consent(
+        &self,
+        user_id: i32,
+        category: ConsentCategory,
+    ) -> Result<bool, AppError> {
+        Ok(UserConsentRepository::new(self.db)
+            .has_consent(user_id, category.as_str())
+            .await?)
+    }
+
+    /// Ensures a user has granted consent for a data category.
+    ///
+    /// Services syncing user data call this before fetching or storing data for the
+    /// category.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `category` - Data category to check
+    ///
+    /// # Returns
+    /// - `Ok(())` - User has granted consent
+    /// - `Err(AppError::Consent(ConsentError::ConsentNotGranted))` - User has not granted consent
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn require_consent(
+        &self,
+        user_id: i32,
+        category: ConsentCategory,
+    ) -> Result<(), AppError> {
+        if !self.has_consent(user_id, category).await? {
+            return Err(ConsentError::ConsentNotGranted { user_id, category }.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+//! Corporation member service layer.
+//!
+//! This module contains the `CorporationMemberService` for tracking the member lists of
+//! corporations whose director granted the `esi-corporation.read_corporation_membership.v1`
+//! scope. The director is recorded for their corporation when they log in with the scope, and
+//! the member list is fetched with their character token by the `UpdateCorporationMembers`
+//! worker job. Recruiters read the member list with the Bifrost user owning each member,
+//! showing which characters in the corporation are registered.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+
+use crate::{
+    model::corporation_member::{CorporationMemberDto, CorporationMembersDto},
+    server::{
+        data::{
+            corporation_member::CorporationMemberRepository,
+            eve::{character::CharacterRepository, corporation::CorporationRepository},
+            user::user_character::UserCharacterRepository,
+        },
+        error::{auth::AuthError, corporation_member::CorporationMemberError, AppError},
+        service::{eve::esi::EsiProvider, token::TokenService},
+        util::crypto::ColumnCipher,
+    },
+};
+
+/// Service for tracking the member lists of corporations.
+pub struct CorporationMemberService<'a> {
+    db: &'a DatabaseConnection,
+    esi_provider: &'a EsiProvider,
+    cipher: &'a ColumnCipher,
+}
+
+impl<'a> CorporationMemberService<'a> {
+    /// Creates a new instance of CorporationMemberService.
+    ///
+    /// Constructs a service for tracking corporation member lists.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    /// - `esi_provider` - ESI provider used to refresh tokens and fetch member lists
+    /// - `cipher` - Cipher character refresh tokens are encrypted with
+    ///
+    /// # Returns
+    /// - `CorporationMemberService` - New service instance
+    pub fn new(
+        db: &'a DatabaseConnection,
+        esi_provider: &'a EsiProvider,
+        cipher: &'a ColumnCipher,
+    ) -> Self {
+        Self {
+            db,
+            esi_provider,
+            cipher,
+        }
+    }
+
+    /// Tracks the member list of the corporation of a character who granted the membership
+    /// scope.
+    ///
+    /// The character is recorded as the corporation's director unless another director's
+    /// token is already fetching the member list. Bifrost can't check the director role without
+    /// another scope, so any member granting the scope would otherwise replace a working
+    /// director. A director is only replaced once fetching with their token failed, e.g.
+    /// because they revoked it or lost the role. Their refresh token is the one stored by
+    /// `TokenService` when they logged in.
+    ///
+    /// # Arguments
+    /// - `character_id` - EVE Online ID of the character who granted the scope
+    ///
+    /// # Returns
+    /// - `Ok(Some(i64))` - EVE Online ID of the corporation now tracked with the character
+    /// - `Ok(None)` - Corporation's existing director was kept
+    /// - `Err(AppError::Auth(AuthError::CharacterNotFound))` - Character is not stored
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn track_corporation(&self, character_id: i64) -> Result<Option<i64>, AppError> {
+        let character = CharacterRepository::new(self.db)
+            .find_by_eve_id(character_id)
+            .await?
+            .ok_or(AuthError::CharacterNotFound)?;
+        let corporation = CorporationRepository::new(self.db)
+            .find_by_record_id(character.corporation_id)
+            .await?
+            .ok_or(AuthError::CharacterNotFound)?;
+
+        let member_repo = CorporationMemberRepository::new(self.db);
+        if let Some(director) = member_repo.get_director(corporation.id).await? {
+            if director.character_id != character.id && director.failed_at.is_none() {
+                return Ok(None);
+            }
+        }
+
+        member_repo
+            .upsert_director(corporation.id, character.id)
+            .await?;
+
+        Ok(Some(corporation.corporation_id))
+    }
+
+    /// Fetches a corporation's member list from ESI and replaces the stored list.
+    ///
+    /// The list is fetched with an access token for the corporation's director from
+    /// `TokenService`, which also stores the refresh token EVE SSO rotates in the exchange.
+    /// The director is marked as failing when their token is missing or rejected, or ESI
+    /// refuses the member list, letting the next character granting the scope replace them.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - EVE Online ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of members in the corporation
+    /// - `Err(AppError::CorporationMember(CorporationMemberError::CorporationNotFound))` -
+    ///   Corporation is not stored
+    /// - `Err(AppError::CorporationMember(CorporationMemberError::NotTracked))` - No director
+    ///   granted the membership scope
+    /// - `Err(AppError::Token)` - No token is stored for the director, or EVE SSO rejected it
+    /// - `Err(AppError::Encryption)` - Stored token can't be decrypted with the configured keys
+    /// - `Err(AppError::Esi)` - Token exchange failed, the character is no longer a director,
+    ///   or the ESI request failed
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn update_members(&self, corporation_id: i64) -> Result<usize, AppError> {
+        let corporation = CorporationRepository::new(self.db)
+            .find_by_eve_id(corporation_id)
+            .await?
+            .ok_or(CorporationMemberError::CorporationNotFound(corporation_id))?;
+
+        let member_repo = CorporationMemberRepository::new(self.db);
+        let director_id = member_repo
+            .get_director_character_id(corporation.id)
+            .await?
+            .ok_or(CorporationMemberError::NotTracked(corporation_id))?;
+
+        let members = match self.fetch_members(director_id, corporation_id).await {
+            Ok(members) => members,
+            Err(e) => {
+                if is_director_failure(&e) {
+                    member_repo
+                        .set_director_failed_at(corporation.id, Some(Utc::now().naive_utc()))
+                        .await?;
+                }
+
+                return Err(e);
+            }
+        };
+        member_repo
+            .set_director_failed_at(corporation.id, None)
+            .await?;
+
+        let txn = self.db.begin().await?;
+        CorporationMemberRepository::new(&txn)
+            .replace_members(corporation.id, &members)
+            .await?;
+        txn.commit().await?;
+
+        Ok(members.len())
+    }
+
+    /// Fetches a corporation's member character IDs from ESI with its director's token.
+    ///
+    /// # Arguments
+    /// - `director_id` - EVE Online ID of the corporation's director
+    /// - `corporation_id` - EVE Online ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i64>)` - Member character IDs
+    /// - `Err(AppError)` - Token exchange or ESI request failed
+    async fn fetch_members(
+        &self,
+        director_id: i64,
+        corporation_id: i64,
+    ) -> Result<Vec<i64>, AppError> {
+        let access_token = TokenService::new(self.db, self.esi_provider, self.cipher)
+            .get_access_token(director_id)
+            .await?;
+
+        let members = self
+            .esi_provider
+            .corporation()
+            .get_corporation_members(&access_token, corporation_id)
+            .send()
+            .await?
+            .data;
+
+        Ok(members)
+    }
+
+    /// Retrieves a corporation's member list with the Bifrost user owning each member.
+    ///
+    /// Members Bifrost has no character record for are listed without a name.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - EVE Online ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(CorporationMembersDto)` - Members ordered by character ID with registration counts
+    /// - `Err(AppError::CorporationMember(CorporationMemberError::CorporationNotFound))` -
+    ///   Corporation is not stored
+    /// - `Err(AppError::CorporationMember(CorporationMemberError::NotTracked))` - No director
+    ///   granted the membership scope
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_members(
+        &self,
+        corporation_id: i64,
+    ) -> Result<CorporationMembersDto, AppError> {
+        let corporation = CorporationRepository::new(self.db)
+            .find_by_eve_id(corporation_id)
+            .await?
+            .ok_or(CorporationMemberError::CorporationNotFound(corporation_id))?;
+
+        let member_repo = CorporationMemberRepository::new(self.db);
+        if member_repo
+            .get_director_character_id(corporation.id)
+            .await?
+            .is_none()
+        {
+            return Err(CorporationMemberError::NotTracked(corporation_id).into());
+        }
+
+        let members = member_repo.get_members(corporation.id).await?;
+        let character_ids: Vec<i64> = members.iter().map(|member| member.character_id).collect();
+
+        let characters: HashMap<i64, (String, Option<i32>)> = UserCharacterRepository::new(self.db)
+            .get_characters_with_ownership(&character_ids)
+            .await?
+            .into_iter()
+            .map(|(character, ownership)| {
+                (
+                    character.character_id,
+                    (character.name, ownership.map(|ownership| ownership.user_id)),
+                )
+            })
+            .collect();
+
+        let members: Vec<CorporationMemberDto> = members
+            .into_iter()
+            .map(|member| {
+                let (character_name, user_id) = characters
+                    .get(&member.character_id)
+                    .cloned()
+                    .map_or((None, None), |(name, user_id)| (Some(name), user_id));
+
+                CorporationMemberDto {
+                    character_id: member.character_id,
+                    character_name,
+                    user_id,
+                    updated_at: member.updated_at,
+                }
+            })
+            .collect();
+
+        Ok(CorporationMembersDto {
+            corporation_id,
+            corporation_name: corporation.name,
+            member_count: members.len() as u64,
+            registered_count: members
+                .iter()
+                .filter(|member| member.user_id.is_some())
+                .count() as u64,
+            members,
+        })
+    }
+}
+
+/// Returns whether a member list fetch failed because of the director rather than ESI.
+///
+/// # Arguments
+/// - `error` - Error returned by the fetch
+///
+/// # Returns
+/// - `true` - Director's token is missing or was rejected, or ESI refused the request with
+///   401 or 403, e.g. because the character is no longer a director
+/// - `false` - Fetch failed for another reason, e.g. an ESI outage
+fn is_director_failure(error: &AppError) -> bool {
+    match error {
+        AppError::Token(_) => true,
+        AppError::Esi(eve_esi::Error::EsiError(esi_error)) => {
+            matches!(esi_error.status, 401 | 403)
+        }
+        _ => false,
+    }
+}
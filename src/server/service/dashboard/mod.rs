@@ -0,0 +1,128 @@
+//! Admin dashboard service layer.
+//!
+//! This module contains the `DashboardService` for refreshing and reading the precomputed
+//! admin dashboard summaries. Summaries are refreshed by the `RefreshDashboardSummaries`
+//! worker job on a schedule, so dashboard figures may lag behind the user tables by up to one
+//! refresh interval.
+
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use dioxus_logger::tracing;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+
+use crate::{
+    model::dashboard::{CharacterCountDto, CorporationUserCountDto, DashboardSummaryDto},
+    server::{data::dashboard::DashboardRepository, error::AppError},
+};
+
+/// Service for refreshing and reading admin dashboard summaries.
+pub struct DashboardService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> DashboardService<'a> {
+    /// Creates a new instance of DashboardService.
+    ///
+    /// Constructs a service for refreshing and reading admin dashboard summaries.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `DashboardService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Recomputes all dashboard summaries from the user tables.
+    ///
+    /// The summaries are replaced within a single transaction, so the dashboard always shows
+    /// summaries from the same refresh.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Summaries refreshed
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn refresh_summaries(&self) -> Result<(), AppError> {
+        let refreshed_at = Utc::now().naive_utc();
+
+        let txn = self.db.begin().await?;
+        let repository = DashboardRepository::new(&txn);
+
+        let corporation_user_counts = repository.count_users_by_main_corporation().await?;
+
+        let mut distribution: BTreeMap<i64, i64> = BTreeMap::new();
+        for (_, character_count) in repository.count_characters_per_user().await? {
+            *distribution.entry(character_count).or_default() += 1;
+        }
+
+        tracing::debug!(
+            "Refreshing dashboard summaries for {} corporation(s) and {} character count bucket(s)",
+            corporation_user_counts.len(),
+            distribution.len()
+        );
+
+        repository
+            .replace_corporation_user_counts(corporation_user_counts, refreshed_at)
+            .await?;
+        repository
+            .replace_character_count_distribution(distribution.into_iter().collect(), refreshed_at)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the most recently refreshed dashboard summaries.
+    ///
+    /// # Returns
+    /// - `Ok(DashboardSummaryDto)` - Stored summaries, empty with no `refreshed_at` if they
+    ///   have not been refreshed yet
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_summary(&self) -> Result<DashboardSummaryDto, AppError> {
+        let repository = DashboardRepository::new(self.db);
+
+        let corporation_rows = repository.get_corporation_user_counts().await?;
+        let distribution_rows = repository.get_character_count_distribution().await?;
+
+        let refreshed_at = corporation_rows
+            .iter()
+            .map(|(row, _)| row.refreshed_at)
+            .chain(distribution_rows.iter().map(|row| row.refreshed_at))
+            .max();
+
+        let corporation_user_counts = corporation_rows
+            .into_iter()
+            .filter_map(|(row, corporation)| {
+                let Some(corporation) = corporation else {
+                    tracing::warn!(
+                        corporation_record_id = row.corporation_id,
+                        "Failed to find related corporation for dashboard summary row - skipping row"
+                    );
+                    return None;
+                };
+
+                Some(CorporationUserCountDto {
+                    corporation_id: corporation.corporation_id,
+                    corporation_name: corporation.name,
+                    user_count: row.user_count,
+                })
+            })
+            .collect();
+
+        let character_count_distribution = distribution_rows
+            .into_iter()
+            .map(|row| CharacterCountDto {
+                character_count: row.character_count,
+                user_count: row.user_count,
+            })
+            .collect();
+
+        Ok(DashboardSummaryDto {
+            corporation_user_counts,
+            character_count_distribution,
+            refreshed_at,
+        })
+    }
+}
@@ -0,0 +1,508 @@
+//! Data access API service layer.
+//!
+//! This module contains the `DataApiService` for the read-only data access API BI tools such
+//! as Grafana or Metabase use to chart organization metrics without database credentials.
+//! Admins define saved queries, `SELECT` statements with typed parameters bound to `$1`, `$2`,
+//! ... placeholders, and create API keys authorizing tools to run them. Saved queries run in a
+//! transaction that is always rolled back, read-only with a statement timeout on PostgreSQL, and
+//! are wrapped in a subquery limiting the number of rows they return.
+
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::NaiveDateTime;
+use rand::Rng;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbBackend, JsonValue, TransactionTrait, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    model::data_api::{
+        ApiKeyDto, CreateApiKeyDto, CreatedApiKeyDto, SaveSavedQueryDto, SavedQueryDto,
+        SavedQueryParameterDto, SavedQueryParameterKind, SavedQueryResultDto,
+    },
+    server::{
+        data::data_api::{api_key::ApiKeyRepository, saved_query::SavedQueryRepository},
+        error::{data_api::DataApiError, AppError},
+        model::db::{ApiKeyModel, SavedQueryModel},
+    },
+};
+
+/// Maximum length of a saved query slug.
+const MAX_SLUG_LENGTH: usize = 64;
+
+/// Maximum number of rows a saved query may return per request.
+///
+/// Keeps a single dashboard refresh from reading an unbounded result set into memory.
+const MAX_ROW_LIMIT: u32 = 10_000;
+
+/// Statement timeout for saved queries on PostgreSQL, in milliseconds.
+const QUERY_TIMEOUT_MS: u64 = 10_000;
+
+/// Number of random bytes in an API key.
+///
+/// 32 bytes (256 bits) makes keys infeasible to guess, encoded as 43 URL-safe characters.
+const API_KEY_BYTES: usize = 32;
+
+/// Service for managing saved queries and API keys and running saved queries.
+pub struct DataApiService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> DataApiService<'a> {
+    /// Creates a new instance of DataApiService.
+    ///
+    /// Constructs a service for managing the data access API.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `DataApiService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves all saved queries ordered by slug.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<SavedQueryDto>)` - All saved queries
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_saved_queries(&self) -> Result<Vec<SavedQueryDto>, AppError> {
+        Ok(SavedQueryRepository::new(self.db)
+            .get_all()
+            .await?
+            .into_iter()
+            .map(saved_query_to_dto)
+            .collect())
+    }
+
+    /// Creates a saved query or replaces the query with the slug.
+    ///
+    /// A trailing semicolon is removed from the SQL. The SQL must be a single `SELECT`
+    /// statement, optionally starting with `WITH`, and may only reference placeholders for
+    /// the declared parameters.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user saving the query
+    /// - `slug` - URL segment BI tools request the query by
+    /// - `query` - Name, SQL, parameters, and row limit of the query
+    ///
+    /// # Returns
+    /// - `Ok(SavedQueryDto)` - The saved query
+    /// - `Err(AppError::DataApi(DataApiError::InvalidQuery))` - Invalid slug, name, SQL,
+    ///   parameters, or row limit
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn save_query(
+        &self,
+        user_id: i32,
+        slug: &str,
+        query: SaveSavedQueryDto,
+    ) -> Result<SavedQueryDto, AppError> {
+        validate_slug(slug)?;
+
+        let name = query.name.trim().to_string();
+        if name.is_empty() {
+            return Err(DataApiError::InvalidQuery("name must not be empty".to_string()).into());
+        }
+
+        if !(1..=MAX_ROW_LIMIT).contains(&query.row_limit) {
+            return Err(DataApiError::InvalidQuery(format!(
+                "row limit must be between 1 and {}",
+                MAX_ROW_LIMIT
+            ))
+            .into());
+        }
+
+        validate_parameters(&query.parameters)?;
+        let sql = validate_sql(&query.sql, query.parameters.len())?;
+
+        let saved = SavedQueryRepository::new(self.db)
+            .upsert(
+                slug,
+                name,
+                sql,
+                join_parameters(&query.parameters),
+                query.row_limit as i32,
+                user_id,
+            )
+            .await?;
+
+        Ok(saved_query_to_dto(saved))
+    }
+
+    /// Deletes a saved query.
+    ///
+    /// # Arguments
+    /// - `slug` - URL segment of the query
+    ///
+    /// # Returns
+    /// - `Ok(())` - Query deleted
+    /// - `Err(AppError::DataApi(DataApiError::QueryNotFound))` - No query with the slug exists
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_query(&self, slug: &str) -> Result<(), AppError> {
+        let result = SavedQueryRepository::new(self.db).delete(slug).await?;
+
+        if result.rows_affected == 0 {
+            return Err(DataApiError::QueryNotFound(slug.to_string()).into());
+        }
+
+        Ok(())
+    }
+
+    /// Runs a saved query with the given parameter values.
+    ///
+    /// Values are parsed according to the kind of their parameter and bound to the query's
+    /// placeholders in the order the parameters were declared. Values for undeclared
+    /// parameters are ignored.
+    ///
+    /// # Arguments
+    /// - `slug` - URL segment of the query
+    /// - `values` - Parameter values by parameter name
+    ///
+    /// # Returns
+    /// - `Ok(SavedQueryResultDto)` - Rows as JSON objects, truncated to the query's row limit
+    /// - `Err(AppError::DataApi(DataApiError::QueryNotFound))` - No query with the slug exists
+    /// - `Err(AppError::DataApi(DataApiError::InvalidParameter))` - Parameter value missing or
+    ///   not valid for its kind
+    /// - `Err(AppError::DataApi(DataApiError::QueryFailed))` - Database rejected the query
+    /// - `Err(AppError::Database)` - Transaction could not be started
+    pub async fn run_query(
+        &self,
+        slug: &str,
+        values: &HashMap<String, String>,
+    ) -> Result<SavedQueryResultDto, AppError> {
+        let query = SavedQueryRepository::new(self.db)
+            .get_by_slug(slug)
+            .await?
+            .ok_or_else(|| DataApiError::QueryNotFound(slug.to_string()))?;
+
+        let bound = split_parameters(&query.parameters)
+            .into_iter()
+            .map(|parameter| bind_parameter(&parameter, values))
+            .collect::<Result<Vec<Value>, DataApiError>>()?;
+
+        let row_limit = query.row_limit.max(0) as usize;
+
+        // The transaction is always rolled back, so even a statement that slips past
+        // validation can't change data
+        let txn = self.db.begin().await?;
+        if txn.get_database_backend() == DbBackend::Postgres {
+            txn.execute_unprepared("SET TRANSACTION READ ONLY").await?;
+            txn.execute_unprepared(&format!(
+                "SET LOCAL statement_timeout = {}",
+                QUERY_TIMEOUT_MS
+            ))
+            .await?;
+        }
+
+        // Read one row past the limit to tell whether the result was truncated
+        let result = SavedQueryRepository::new(&txn)
+            .execute(&query.sql, bound, row_limit as u64 + 1)
+            .await;
+        txn.rollback().await?;
+
+        let mut rows: Vec<JsonValue> = result.map_err(|e| DataApiError::QueryFailed {
+            slug: slug.to_string(),
+            message: e.to_string(),
+        })?;
+
+        let truncated = rows.len() > row_limit;
+        rows.truncate(row_limit);
+
+        Ok(SavedQueryResultDto { rows, truncated })
+    }
+
+    /// Creates an API key for a BI tool.
+    ///
+    /// Only a hash of the key is stored, so the key is returned once and can't be retrieved
+    /// later.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user creating the key
+    /// - `api_key` - Name identifying the tool using the key
+    ///
+    /// # Returns
+    /// - `Ok(CreatedApiKeyDto)` - The created key along with the key itself
+    /// - `Err(AppError::DataApi(DataApiError::EmptyKeyName))` - Name is empty
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_api_key(
+        &self,
+        user_id: i32,
+        api_key: CreateApiKeyDto,
+    ) -> Result<CreatedApiKeyDto, AppError> {
+        let name = api_key.name.trim().to_string();
+        if name.is_empty() {
+            return Err(DataApiError::EmptyKeyName.into());
+        }
+
+        let key = generate_api_key();
+        let created = ApiKeyRepository::new(self.db)
+            .create(name, hash_api_key(&key), user_id)
+            .await?;
+
+        Ok(CreatedApiKeyDto {
+            api_key: api_key_to_dto(created),
+            key,
+        })
+    }
+
+    /// Retrieves all API keys, oldest first.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ApiKeyDto>)` - All API keys without the keys themselves
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_api_keys(&self) -> Result<Vec<ApiKeyDto>, AppError> {
+        Ok(ApiKeyRepository::new(self.db)
+            .get_all()
+            .await?
+            .into_iter()
+            .map(api_key_to_dto)
+            .collect())
+    }
+
+    /// Revokes an API key.
+    ///
+    /// # Arguments
+    /// - `api_key_id` - ID of the key
+    ///
+    /// # Returns
+    /// - `Ok(())` - Key revoked
+    /// - `Err(AppError::DataApi(DataApiError::ApiKeyNotFound))` - Key doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_api_key(&self, api_key_id: i32) -> Result<(), AppError> {
+        let result = ApiKeyRepository::new(self.db).delete(api_key_id).await?;
+
+        if result.rows_affected == 0 {
+            return Err(DataApiError::ApiKeyNotFound(api_key_id).into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks an API key presented with a request and records its use.
+    ///
+    /// # Arguments
+    /// - `key` - API key presented by the BI tool
+    /// - `used_at` - Time of the request to record as the key's last use, or `None` to skip
+    ///   recording it, e.g. while read-only mode is enabled
+    ///
+    /// # Returns
+    /// - `Ok(())` - Key is valid
+    /// - `Err(AppError::DataApi(DataApiError::InvalidApiKey))` - Key is unknown or revoked
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn authenticate(
+        &self,
+        key: &str,
+        used_at: Option<NaiveDateTime>,
+    ) -> Result<(), AppError> {
+        let api_key_repo = ApiKeyRepository::new(self.db);
+
+        let api_key = api_key_repo
+            .get_by_hash(&hash_api_key(key))
+            .await?
+            .ok_or(DataApiError::InvalidApiKey)?;
+
+        if let Some(used_at) = used_at {
+            api_key_repo.set_last_used(api_key.id, used_at).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that a slug is 1 to 64 lowercase letters, digits, or hyphens.
+///
+/// # Returns
+/// - `Ok(())` - Slug is valid
+/// - `Err(AppError::DataApi(DataApiError::InvalidQuery))` - Slug is invalid
+fn validate_slug(slug: &str) -> Result<(), AppError> {
+    let valid = (1..=MAX_SLUG_LENGTH).contains(&slug.len())
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(DataApiError::InvalidQuery(format!(
+            "slug {:?} must be 1 to {} lowercase letters, digits, or hyphens",
+            slug, MAX_SLUG_LENGTH
+        ))
+        .into())
+    }
+}
+
+/// Checks that parameter names are unique and made of lowercase letters, digits, and
+/// underscores.
+///
+/// # Returns
+/// - `Ok(())` - Parameters are valid
+/// - `Err(AppError::DataApi(DataApiError::InvalidQuery))` - A name is invalid or repeated
+fn validate_parameters(parameters: &[SavedQueryParameterDto]) -> Result<(), AppError> {
+    for (index, parameter) in parameters.iter().enumerate() {
+        let valid = !parameter.name.is_empty()
+            && parameter
+                .name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid {
+            return Err(DataApiError::InvalidQuery(format!(
+                "parameter name {:?} must be lowercase letters, digits, or underscores",
+                parameter.name
+            ))
+            .into());
+        }
+
+        if parameters[..index]
+            .iter()
+            .any(|other| other.name == parameter.name)
+        {
+            return Err(DataApiError::InvalidQuery(format!(
+                "parameter {:?} is declared more than once",
+                parameter.name
+            ))
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that SQL is a single `SELECT` statement referencing only declared placeholders.
+///
+/// # Arguments
+/// - `sql` - SQL entered by the admin
+/// - `parameter_count` - Number of declared parameters
+///
+/// # Returns
+/// - `Ok(String)` - SQL trimmed and without a trailing semicolon
+/// - `Err(AppError::DataApi(DataApiError::InvalidQuery))` - SQL is not a single `SELECT`
+///   statement or references an undeclared placeholder
+fn validate_sql(sql: &str, parameter_count: usize) -> Result<String, AppError> {
+    let sql = sql.trim().trim_end_matches(';').trim_end();
+
+    let first_keyword = sql
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    if first_keyword != "select" && first_keyword != "with" {
+        return Err(
+            DataApiError::InvalidQuery("SQL must be a SELECT statement".to_string()).into(),
+        );
+    }
+
+    if sql.contains(';') {
+        return Err(
+            DataApiError::InvalidQuery("SQL must be a single statement".to_string()).into(),
+        );
+    }
+
+    if let Some(placeholder) = placeholders(sql).find(|&n| n == 0 || n > parameter_count) {
+        return Err(DataApiError::InvalidQuery(format!(
+            "placeholder ${} has no matching parameter",
+            placeholder
+        ))
+        .into());
+    }
+
+    Ok(sql.to_string())
+}
+
+/// Finds the numbers of the `$n` placeholders in SQL.
+fn placeholders(sql: &str) -> impl Iterator<Item = usize> + '_ {
+    sql.split('$').skip(1).filter_map(|rest| {
+        let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse().ok()
+    })
+}
+
+/// Joins parameters into the space-separated `name:kind` form they are stored in.
+fn join_parameters(parameters: &[SavedQueryParameterDto]) -> String {
+    parameters
+        .iter()
+        .map(|parameter| format!("{}:{}", parameter.name, parameter.kind.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits stored `name:kind` parameters, skipping kinds not supported by this version of
+/// Bifrost.
+fn split_parameters(parameters: &str) -> Vec<SavedQueryParameterDto> {
+    parameters
+        .split_whitespace()
+        .filter_map(|parameter| {
+            let (name, kind) = parameter.split_once(':')?;
+
+            Some(SavedQueryParameterDto {
+                name: name.to_string(),
+                kind: SavedQueryParameterKind::from_name(kind)?,
+            })
+        })
+        .collect()
+}
+
+/// Parses the value passed for a parameter into the value bound to its placeholder.
+fn bind_parameter(
+    parameter: &SavedQueryParameterDto,
+    values: &HashMap<String, String>,
+) -> Result<Value, DataApiError> {
+    let invalid = |reason: &str| DataApiError::InvalidParameter {
+        name: parameter.name.clone(),
+        reason: reason.to_string(),
+    };
+
+    let value = values
+        .get(&parameter.name)
+        .ok_or_else(|| invalid("value is required"))?;
+
+    match parameter.kind {
+        SavedQueryParameterKind::Integer => value
+            .trim()
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| invalid("value must be an integer")),
+        SavedQueryParameterKind::Text => Ok(Value::from(value.clone())),
+    }
+}
+
+/// Generates a random URL-safe API key.
+fn generate_api_key() -> String {
+    let mut bytes = [0u8; API_KEY_BYTES];
+    rand::rng().fill(&mut bytes);
+
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Hashes an API key into the hex-encoded SHA-256 digest it is stored as.
+fn hash_api_key(key: &str) -> String {
+    Sha256::digest(key.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Converts a stored saved query into its DTO.
+fn saved_query_to_dto(query: SavedQueryModel) -> SavedQueryDto {
+    SavedQueryDto {
+        id: query.id,
+        parameters: split_parameters(&query.parameters),
+        slug: query.slug,
+        name: query.name,
+        sql: query.sql,
+        row_limit: query.row_limit.max(0) as u32,
+        created_by_user_id: query.created_by_user_id,
+        updated_at: query.updated_at,
+    }
+}
+
+/// Converts a stored API key into its DTO.
+fn api_key_to_dto(api_key: ApiKeyModel) -> ApiKeyDto {
+    ApiKeyDto {
+        id: api_key.id,
+        name: api_key.name,
+        created_by_user_id: api_key.created_by_user_id,
+        created_at: api_key.created_at,
+        last_used_at: api_key.last_used_at,
+    }
+}
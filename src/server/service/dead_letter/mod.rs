@@ -0,0 +1,117 @@
+//! Dead-letter queue service layer.
+//!
+//! This module contains the `DeadLetterService` for inspecting and replaying worker jobs that
+//! failed permanently. Replays may submit an edited payload, e.g. to drop a single invalid
+//! character ID from an affiliation batch, and every replay is audit logged with the admin
+//! who replayed it and the original and replayed payloads.
+
+use dioxus_logger::tracing;
+
+use crate::{
+    model::worker::{DeadLetterJobDto, DeadLetterReplayDto},
+    server::{
+        error::{dead_letter::DeadLetterError, AppError},
+        model::worker::DeadLetterJob,
+        worker::{payload::deserialize_job, WorkerQueue},
+    },
+};
+
+/// Service for inspecting and replaying dead-lettered worker jobs.
+pub struct DeadLetterService<'a> {
+    queue: &'a WorkerQueue,
+}
+
+impl<'a> DeadLetterService<'a> {
+    /// Creates a new instance of DeadLetterService.
+    ///
+    /// Constructs a service for inspecting and replaying dead-lettered worker jobs.
+    ///
+    /// # Arguments
+    /// - `queue` - Worker queue holding the dead-letter queue
+    ///
+    /// # Returns
+    /// - `DeadLetterService` - New service instance
+    pub fn new(queue: &'a WorkerQueue) -> Self {
+        Self { queue }
+    }
+
+    /// Retrieves all dead-lettered jobs.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<DeadLetterJobDto>)` - Dead-lettered jobs ordered by ID, oldest first
+    /// - `Err(AppError)` - Redis communication or deserialization failed
+    pub async fn get_dead_letters(&self) -> Result<Vec<DeadLetterJobDto>, AppError> {
+        Ok(self
+            .queue
+            .get_dead_letters()
+            .await?
+            .into_iter()
+            .map(to_dto)
+            .collect())
+    }
+
+    /// Requeues a dead-lettered job, optionally with an edited payload.
+    ///
+    /// The payload is validated as a `WorkerJob` before the job is pushed, and the entry is
+    /// only removed from the dead-letter queue once the job is back in the worker queue.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the admin replaying the job, for the audit log
+    /// - `id` - ID of the dead-letter entry
+    /// - `payload` - Edited JSON payload to replay instead of the stored payload
+    ///
+    /// # Returns
+    /// - `Ok(DeadLetterReplayDto)` - Job replayed; `queued` is false if an identical job was
+    ///   already in the queue
+    /// - `Err(AppError::DeadLetter)` - Entry not found or payload is not a valid worker job
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn replay(
+        &self,
+        user_id: i32,
+        id: u64,
+        payload: Option<String>,
+    ) -> Result<DeadLetterReplayDto, AppError> {
+        let Some(entry) = self.queue.get_dead_letter(id).await? else {
+            return Err(DeadLetterError::NotFound(id).into());
+        };
+
+        let payload = payload.unwrap_or_else(|| entry.payload.clone());
+        let job = deserialize_job(&payload)
+            .map_err(|e| DeadLetterError::InvalidPayload(e.to_string()))?;
+
+        let queued = self.queue.push(job.clone()).await?;
+        self.queue.remove_dead_letter(id).await?;
+
+        tracing::info!(
+            user_id,
+            dead_letter_id = id,
+            original_payload = %entry.payload,
+            replayed_payload = %payload,
+            edited = entry.payload != payload,
+            "Admin replayed dead-lettered job: {}",
+            job
+        );
+
+        Ok(DeadLetterReplayDto {
+            id,
+            payload,
+            queued,
+        })
+    }
+}
+
+/// Converts a dead-letter entry into its DTO, describing the job if its payload is valid.
+fn to_dto(entry: DeadLetterJob) -> DeadLetterJobDto {
+    let job = deserialize_job(&entry.payload)
+        .map(|job| job.to_string())
+        .unwrap_or_else(|_| "Invalid payload".to_string());
+
+    DeadLetterJobDto {
+        id: entry.id,
+        job,
+        payload: entry.payload,
+        error: entry.error,
+        attempt_count: entry.attempt_count,
+        failed_at: entry.failed_at.naive_utc(),
+    }
+}
@@ -0,0 +1,168 @@
+//! Weekly digest service layer.
+//!
+//! This module contains the `DigestService` for compiling the weekly digest sent by the
+//! `SendWeeklyDigest` worker job. The digest summarizes the members who registered during the
+//! past week, the campaigns starting during the coming week, and the health of the ESI refresh
+//! pipeline. Leadership receives the full digest on the configured Discord webhook, while
+//! members receive a short summary as a push notification unless they turned the digest off
+//! in their preferences.
+
+use chrono::{Duration, NaiveDateTime};
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::digest::{DigestCampaignDto, WeeklyDigestDto},
+    server::{
+        data::{
+            campaign::CampaignRepository, preference::UserPreferenceRepository,
+            user::UserRepository,
+        },
+        error::AppError,
+    },
+};
+
+/// Number of days covered by the weekly digest, both looking back and looking ahead.
+const DIGEST_PERIOD_DAYS: i64 = 7;
+
+/// Service for compiling the weekly digest and resolving who receives it.
+pub struct DigestService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> DigestService<'a> {
+    /// Creates a new instance of DigestService.
+    ///
+    /// Constructs a service for compiling the weekly digest.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `DigestService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Compiles the weekly digest for the week ending at the given time.
+    ///
+    /// Queue figures are passed in by the caller since they are read from the worker queue
+    /// rather than the database.
+    ///
+    /// # Arguments
+    /// - `now` - End of the digest period
+    /// - `queued_jobs` - Number of jobs waiting in the worker queue
+    /// - `dead_letter_jobs` - Number of jobs in the dead-letter queue
+    ///
+    /// # Returns
+    /// - `Ok(WeeklyDigestDto)` - The compiled digest
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn build_weekly_digest(
+        &self,
+        now: NaiveDateTime,
+        queued_jobs: u64,
+        dead_letter_jobs: u64,
+    ) -> Result<WeeklyDigestDto, AppError> {
+        let period = Duration::days(DIGEST_PERIOD_DAYS);
+        let period_start = now - period;
+
+        let new_members = UserRepository::new(self.db)
+            .get_created_since(period_start)
+            .await?
+            .into_iter()
+            .filter_map(|(_, main_character)| main_character.map(|character| character.name))
+            .collect();
+
+        let upcoming_campaigns = CampaignRepository::new(self.db)
+            .get_starting_between(now, now + period)
+            .await?
+            .into_iter()
+            .map(|campaign| DigestCampaignDto {
+                name: campaign.name,
+                staging_system: campaign.staging_system,
+                starts_at: campaign.starts_at,
+            })
+            .collect();
+
+        Ok(WeeklyDigestDto {
+            period_start,
+            period_end: now,
+            new_members,
+            upcoming_campaigns,
+            queued_jobs,
+            dead_letter_jobs,
+        })
+    }
+
+    /// Retrieves the IDs of users who receive the weekly digest.
+    ///
+    /// Users receive the digest unless they turned it off in their preferences.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<i32>)` - IDs of users receiving the digest
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_recipient_ids(&self) -> Result<Vec<i32>, AppError> {
+        let opted_out = UserPreferenceRepository::new(self.db)
+            .get_weekly_digest_opted_out_user_ids()
+            .await?;
+
+        Ok(UserRepository::new(self.db)
+            .get_all_ids()
+            .await?
+            .into_iter()
+            .filter(|user_id| !opted_out.contains(user_id))
+            .collect())
+    }
+}
+
+/// Renders the full digest as Discord Markdown.
+pub fn digest_to_markdown(digest: &WeeklyDigestDto) -> String {
+    let mut lines = vec![
+        format!(
+            "**Weekly digest** ({} to {})",
+            digest.period_start.format("%Y-%m-%d"),
+            digest.period_end.format("%Y-%m-%d")
+        ),
+        String::new(),
+        format!("**New members ({})**", digest.new_members.len()),
+    ];
+    if digest.new_members.is_empty() {
+        lines.push("- None".to_string());
+    }
+    lines.extend(digest.new_members.iter().map(|name| format!("- {}", name)));
+
+    lines.push(String::new());
+    lines.push(format!(
+        "**Upcoming campaigns ({})**",
+        digest.upcoming_campaigns.len()
+    ));
+    if digest.upcoming_campaigns.is_empty() {
+        lines.push("- None".to_string());
+    }
+    lines.extend(digest.upcoming_campaigns.iter().map(|campaign| {
+        format!(
+            "- {} staging in {}, starts {} UTC",
+            campaign.name,
+            campaign.staging_system,
+            campaign.starts_at.format("%Y-%m-%d %H:%M")
+        )
+    }));
+
+    lines.push(String::new());
+    lines.push("**Refresh pipeline**".to_string());
+    lines.push(format!("- {} job(s) queued", digest.queued_jobs));
+    lines.push(format!(
+        "- {} job(s) in the dead-letter queue",
+        digest.dead_letter_jobs
+    ));
+
+    lines.join("\n")
+}
+
+/// Summarizes the digest in one line for push notifications.
+pub fn digest_summary(digest: &WeeklyDigestDto) -> String {
+    format!(
+        "{} new member(s) this week, {} campaign(s) starting in the coming week",
+        digest.new_members.len(),
+        digest.upcoming_campaigns.len()
+    )
+}
@@ -0,0 +1,212 @@
+//! Fitting service for importing and managing ship fittings.
+//!
+//! This module provides the `FittingService` for importing fittings in EFT format, listing
+//! stored fittings, and deleting them. Fittings are validated by parsing the EFT text on
+//! import; the original text is stored so it can be exported unchanged. As fittings only name
+//! their items, the skills needed to fly a fitting are set separately.
+
+use std::collections::HashSet;
+
+use sea_orm::{DatabaseConnection, TransactionTrait};
+
+use crate::{
+    model::doctrine::{FittingDto, FittingItemDto, FittingSkillDto},
+    server::{
+        data::doctrine::fitting::FittingRepository,
+        error::{doctrine::DoctrineError, AppError},
+        model::db::FittingModel,
+        util::eft::parse_eft,
+    },
+};
+
+/// Service for managing ship fittings.
+///
+/// Provides methods for importing fittings from EFT text, retrieving all fittings, deleting
+/// fittings, and managing the skills required to fly them.
+pub struct FittingService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> FittingService<'a> {
+    /// Creates a new instance of FittingService.
+    ///
+    /// Constructs a service for managing ship fittings.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `FittingService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Imports a fitting from EFT text.
+    ///
+    /// Parses the EFT text to validate it and extract the ship hull and fitting name, then
+    /// stores the fitting with the original text.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user importing the fitting
+    /// - `eft` - Fitting text in EFT format
+    ///
+    /// # Returns
+    /// - `Ok(FittingDto)` - The imported fitting
+    /// - `Err(AppError::Doctrine(DoctrineError::InvalidFitting))` - EFT text could not be parsed
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn import_fitting(&self, user_id: i32, eft: String) -> Result<FittingDto, AppError> {
+        let parsed = parse_eft(&eft).map_err(DoctrineError::from)?;
+
+        let fitting = FittingRepository::new(self.db)
+            .create(parsed.name, parsed.ship_type_name, eft, user_id)
+            .await?;
+
+        fitting_to_dto(fitting)
+    }
+
+    /// Retrieves all fittings.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FittingDto>)` - All fittings ordered by ship hull and name
+    /// - `Err(AppError::Database)` - Database operation failed
+    /// - `Err(AppError::Internal)` - A stored fitting could not be parsed
+    pub async fn get_fittings(&self) -> Result<Vec<FittingDto>, AppError> {
+        FittingRepository::new(self.db)
+            .get_all()
+            .await?
+            .into_iter()
+            .map(fitting_to_dto)
+            .collect()
+    }
+
+    /// Deletes a fitting, removing it from any doctrines it belongs to.
+    ///
+    /// # Arguments
+    /// - `fitting_id` - ID of the fitting to delete
+    ///
+    /// # Returns
+    /// - `Ok(())` - Fitting deleted
+    /// - `Err(AppError::Doctrine(DoctrineError::FittingNotFound))` - Fitting does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_fitting(&self, fitting_id: i32) -> Result<(), AppError> {
+        let result = FittingRepository::new(self.db).delete(fitting_id).await?;
+
+        if result.rows_affected == 0 {
+            return Err(DoctrineError::FittingNotFound(fitting_id).into());
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the skill levels required to fly a fitting.
+    ///
+    /// # Arguments
+    /// - `fitting_id` - ID of the fitting
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FittingSkillDto>)` - Required skills ordered by skill ID
+    /// - `Err(AppError::Doctrine(DoctrineError::FittingNotFound))` - Fitting does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn get_required_skills(
+        &self,
+        fitting_id: i32,
+    ) -> Result<Vec<FittingSkillDto>, AppError> {
+        let fitting_repo = FittingRepository::new(self.db);
+
+        if fitting_repo.get_by_id(fitting_id).await?.is_none() {
+            return Err(DoctrineError::FittingNotFound(fitting_id).into());
+        }
+
+        Ok(fitting_repo
+            .get_skills_by_fitting_ids(&[fitting_id])
+            .await?
+            .remove(&fitting_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|skill| FittingSkillDto {
+                skill_id: skill.skill_id,
+                level: skill.level,
+            })
+            .collect())
+    }
+
+    /// Replaces the skill levels required to fly a fitting.
+    ///
+    /// # Arguments
+    /// - `fitting_id` - ID of the fitting
+    /// - `skills` - Required skills, each listed once with a level from 1 to 5
+    ///
+    /// # Returns
+    /// - `Ok(Vec<FittingSkillDto>)` - Required skills ordered by skill ID
+    /// - `Err(AppError::Doctrine(DoctrineError::InvalidSkillRequirement))` - A skill is listed
+    ///   twice or with a level outside 1 to 5
+    /// - `Err(AppError::Doctrine(DoctrineError::FittingNotFound))` - Fitting does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn set_required_skills(
+        &self,
+        fitting_id: i32,
+        skills: Vec<FittingSkillDto>,
+    ) -> Result<Vec<FittingSkillDto>, AppError> {
+        let mut skill_ids = HashSet::new();
+        if let Some(invalid) = skills
+            .iter()
+            .find(|skill| !(1..=5).contains(&skill.level) || !skill_ids.insert(skill.skill_id))
+        {
+            return Err(DoctrineError::InvalidSkillRequirement(invalid.skill_id).into());
+        }
+
+        if FittingRepository::new(self.db)
+            .get_by_id(fitting_id)
+            .await?
+            .is_none()
+        {
+            return Err(DoctrineError::FittingNotFound(fitting_id).into());
+        }
+
+        let requirements: Vec<(i64, i32)> = skills
+            .iter()
+            .map(|skill| (skill.skill_id, skill.level))
+            .collect();
+
+        let txn = self.db.begin().await?;
+        FittingRepository::new(&txn)
+            .replace_skills(fitting_id, &requirements)
+            .await?;
+        txn.commit().await?;
+
+        self.get_required_skills(fitting_id).await
+    }
+}
+
+/// Converts a stored fitting into its DTO, parsing the stored EFT text for the item list.
+///
+/// # Returns
+/// - `Ok(FittingDto)` - Converted fitting
+/// - `Err(AppError::Internal)` - Stored EFT text is no longer valid (should not happen as it
+///   was validated on import)
+pub(super) fn fitting_to_dto(fitting: FittingModel) -> Result<FittingDto, AppError> {
+    let parsed = parse_eft(&fitting.eft).map_err(|e| {
+        AppError::Internal(format!(
+            "Failed to parse stored EFT for fitting ID {}: {}",
+            fitting.id, e
+        ))
+    })?;
+
+    Ok(FittingDto {
+        id: fitting.id,
+        name: fitting.name,
+        ship_type_name: fitting.ship_type_name,
+        items: parsed
+            .items
+            .into_iter()
+            .map(|item| FittingItemDto {
+                type_name: item.type_name,
+                quantity: item.quantity,
+                charge_name: item.charge_name,
+            })
+            .collect(),
+        eft: fitting.eft,
+        created_at: fitting.created_at,
+        updated_at: fitting.updated_at,
+    })
+}
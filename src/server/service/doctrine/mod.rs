@@ -0,0 +1,186 @@
+//! Doctrine service layer.
+//!
+//! This module contains business logic services for doctrine operations including doctrine
+//! management and fitting assignment. The `fitting` module handles importing fittings in EFT
+//! format, while `DoctrineService` groups those fittings into named doctrines. The `readiness`
+//! module reports which characters can fly them.
+
+pub mod fitting;
+pub mod readiness;
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::doctrine::DoctrineDto,
+    server::{
+        data::doctrine::{fitting::FittingRepository, DoctrineRepository},
+        error::{doctrine::DoctrineError, AppError},
+    },
+};
+
+use self::fitting::fitting_to_dto;
+
+/// Service for managing doctrines.
+///
+/// Provides methods for creating, listing, and deleting doctrines and for adding and removing
+/// the fittings that belong to them.
+pub struct DoctrineService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> DoctrineService<'a> {
+    /// Creates a new instance of DoctrineService.
+    ///
+    /// Constructs a service for managing doctrines.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `DoctrineService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a new doctrine without any fittings.
+    ///
+    /// # Arguments
+    /// - `name` - Unique doctrine name
+    /// - `description` - Optional doctrine description
+    ///
+    /// # Returns
+    /// - `Ok(DoctrineDto)` - The created doctrine
+    /// - `Err(AppError::Doctrine(DoctrineError::DuplicateDoctrineName))` - Name already in use
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_doctrine(
+        &self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<DoctrineDto, AppError> {
+        let doctrine_repo = DoctrineRepository::new(self.db);
+
+        if doctrine_repo.get_by_name(&name).await?.is_some() {
+            return Err(DoctrineError::DuplicateDoctrineName(name).into());
+        }
+
+        let doctrine = doctrine_repo.create(name, description).await?;
+
+        Ok(DoctrineDto {
+            id: doctrine.id,
+            name: doctrine.name,
+            description: doctrine.description,
+            fittings: Vec::new(),
+            created_at: doctrine.created_at,
+            updated_at: doctrine.updated_at,
+        })
+    }
+
+    /// Retrieves all doctrines along with their fittings.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<DoctrineDto>)` - All doctrines ordered by name
+    /// - `Err(AppError::Database)` - Database operation failed
+    /// - `Err(AppError::Internal)` - A stored fitting could not be parsed
+    pub async fn get_doctrines(&self) -> Result<Vec<DoctrineDto>, AppError> {
+        let doctrine_repo = DoctrineRepository::new(self.db);
+
+        let doctrines = doctrine_repo.get_all().await?;
+        let doctrine_ids: Vec<i32> = doctrines.iter().map(|d| d.id).collect();
+        let mut fittings = doctrine_repo
+            .get_fittings_by_doctrine_ids(&doctrine_ids)
+            .await?;
+
+        doctrines
+            .into_iter()
+            .map(|doctrine| {
+                let fittings = fittings
+                    .remove(&doctrine.id)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(fitting_to_dto)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(DoctrineDto {
+                    id: doctrine.id,
+                    name: doctrine.name,
+                    description: doctrine.description,
+                    fittings,
+                    created_at: doctrine.created_at,
+                    updated_at: doctrine.updated_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Deletes a doctrine. Fittings belonging to the doctrine are kept.
+    ///
+    /// # Arguments
+    /// - `doctrine_id` - ID of the doctrine to delete
+    ///
+    /// # Returns
+    /// - `Ok(())` - Doctrine deleted
+    /// - `Err(AppError::Doctrine(DoctrineError::DoctrineNotFound))` - Doctrine does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_doctrine(&self, doctrine_id: i32) -> Result<(), AppError> {
+        let result = DoctrineRepository::new(self.db).delete(doctrine_id).await?;
+
+        if result.rows_affected == 0 {
+            return Err(DoctrineError::DoctrineNotFound(doctrine_id).into());
+        }
+
+        Ok(())
+    }
+
+    /// Adds a fitting to a doctrine. Adding a fitting already in the doctrine is a no-op.
+    ///
+    /// # Arguments
+    /// - `doctrine_id` - ID of the doctrine
+    /// - `fitting_id` - ID of the fitting to add
+    ///
+    /// # Returns
+    /// - `Ok(())` - Fitting is part of the doctrine
+    /// - `Err(AppError::Doctrine(DoctrineError::DoctrineNotFound))` - Doctrine does not exist
+    /// - `Err(AppError::Doctrine(DoctrineError::FittingNotFound))` - Fitting does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn add_fitting(&self, doctrine_id: i32, fitting_id: i32) -> Result<(), AppError> {
+        let doctrine_repo = DoctrineRepository::new(self.db);
+
+        if doctrine_repo.get_by_id(doctrine_id).await?.is_none() {
+            return Err(DoctrineError::DoctrineNotFound(doctrine_id).into());
+        }
+
+        if FittingRepository::new(self.db)
+            .get_by_id(fitting_id)
+            .await?
+            .is_none()
+        {
+            return Err(DoctrineError::FittingNotFound(fitting_id).into());
+        }
+
+        doctrine_repo.add_fitting(doctrine_id, fitting_id).await?;
+
+        Ok(())
+    }
+
+    /// Removes a fitting from a doctrine. The fitting itself is kept.
+    ///
+    /// # Arguments
+    /// - `doctrine_id` - ID of the doctrine
+    /// - `fitting_id` - ID of the fitting to remove
+    ///
+    /// # Returns
+    /// - `Ok(())` - Fitting removed from the doctrine
+    /// - `Err(AppError::Doctrine(DoctrineError::FittingNotFound))` - Fitting was not part of the doctrine
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn remove_fitting(&self, doctrine_id: i32, fitting_id: i32) -> Result<(), AppError> {
+        let result = DoctrineRepository::new(self.db)
+            .remove_fitting(doctrine_id, fitting_id)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(DoctrineError::FittingNotFound(fitting_id).into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+//! Doctrine readiness service for checking who can fly a doctrine's fittings.
+//!
+//! This module provides the `DoctrineReadinessService`, which compares the skills required by
+//! each fitting of a doctrine against the skill snapshots read through `CharacterSkillService`.
+//! Users get a report of which fittings each of their characters can fly and which skills are
+//! missing, while corporations get the share of their tracked characters able to fly each
+//! fitting. Fittings without required skills can be flown by every tracked character.
+
+use std::collections::HashMap;
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::doctrine::{
+        CharacterDoctrineReadinessDto, CorporationDoctrineReadinessDto,
+        CorporationFittingReadinessDto, FittingReadinessDto, MissingSkillDto,
+        UserDoctrineReadinessDto,
+    },
+    server::{
+        data::{
+            doctrine::{fitting::FittingRepository, DoctrineRepository},
+            user::user_character::UserCharacterRepository,
+        },
+        error::{doctrine::DoctrineError, AppError},
+        model::db::{DoctrineModel, EveCharacterModel, FittingModel, FittingSkillModel},
+        service::{character_skill::CharacterSkillService, eve::esi::EsiProvider},
+        util::crypto::ColumnCipher,
+    },
+};
+
+/// A doctrine fitting with the skills required to fly it.
+struct FittingRequirements {
+    fitting: FittingModel,
+    skills: Vec<FittingSkillModel>,
+}
+
+/// Service for reporting how ready characters are to fly doctrines.
+pub struct DoctrineReadinessService<'a> {
+    db: &'a DatabaseConnection,
+    esi_provider: &'a EsiProvider,
+    cipher: &'a ColumnCipher,
+}
+
+impl<'a> DoctrineReadinessService<'a> {
+    /// Creates a new instance of DoctrineReadinessService.
+    ///
+    /// Constructs a service for reporting doctrine readiness.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    /// - `esi_provider` - ESI provider passed on to `CharacterSkillService`
+    /// - `cipher` - Cipher passed on to `CharacterSkillService`
+    ///
+    /// # Returns
+    /// - `DoctrineReadinessService` - New service instance
+    pub fn new(
+        db: &'a DatabaseConnection,
+        esi_provider: &'a EsiProvider,
+        cipher: &'a ColumnCipher,
+    ) -> Self {
+        Self {
+            db,
+            esi_provider,
+            cipher,
+        }
+    }
+
+    /// Reports which fittings of a doctrine each of a user's characters can fly.
+    ///
+    /// Characters whose skills were never fetched are listed without fittings.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `doctrine_id` - ID of the doctrine
+    ///
+    /// # Returns
+    /// - `Ok(UserDoctrineReadinessDto)` - Characters ordered by name with the missing skills
+    ///   for each fitting
+    /// - `Err(AppError::Doctrine(DoctrineError::DoctrineNotFound))` - Doctrine does not exist
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_user_readiness(
+        &self,
+        user_id: i32,
+        doctrine_id: i32,
+    ) -> Result<UserDoctrineReadinessDto, AppError> {
+        let (doctrine, fittings) = self.get_doctrine_requirements(doctrine_id).await?;
+
+        let mut characters: Vec<EveCharacterModel> = UserCharacterRepository::new(self.db)
+            .get_owned_characters_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .map(|(character, _, _)| character)
+            .collect();
+        characters.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let character_ids: Vec<i32> = characters.iter().map(|c| c.id).collect();
+        let mut levels = CharacterSkillService::new(self.db, self.esi_provider, self.cipher)
+            .get_active_levels(&character_ids)
+            .await?;
+
+        let characters = characters
+            .into_iter()
+            .map(|character| {
+                let levels = levels.remove(&character.id);
+
+                CharacterDoctrineReadinessDto {
+                    character_id: character.character_id,
+                    character_name: character.name,
+                    skills_tracked: levels.is_some(),
+                    fittings: levels
+                        .map(|levels| {
+                            fittings
+                                .iter()
+                                .map(|requirements| fitting_readiness(requirements, &levels))
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        Ok(UserDoctrineReadinessDto {
+            doctrine_id: doctrine.id,
+            doctrine_name: doctrine.name,
+            characters,
+        })
+    }
+
+    /// Reports the share of a corporation's tracked characters able to fly a doctrine.
+    ///
+    /// Only characters whose skills were fetched count towards the percentages. The doctrine's
+    /// pilot count includes every character able to fly at least one of its fittings.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - EVE Online ID of the corporation
+    /// - `doctrine_id` - ID of the doctrine
+    ///
+    /// # Returns
+    /// - `Ok(CorporationDoctrineReadinessDto)` - Pilot counts and readiness percentages for the
+    ///   doctrine and each of its fittings
+    /// - `Err(AppError::Doctrine(DoctrineError::DoctrineNotFound))` - Doctrine does not exist
+    /// - `Err(AppError::CharacterSkill(CharacterSkillError::CorporationNotFound))` -
+    ///   Corporation is not stored
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_corporation_readiness(
+        &self,
+        corporation_id: i64,
+        doctrine_id: i32,
+    ) -> Result<CorporationDoctrineReadinessDto, AppError> {
+        let (doctrine, fittings) = self.get_doctrine_requirements(doctrine_id).await?;
+
+        let characters = CharacterSkillService::new(self.db, self.esi_provider, self.cipher)
+            .get_corporation_active_levels(corporation_id)
+            .await?;
+        let tracked_character_count = characters.len() as u64;
+
+        let can_fly: Vec<Vec<bool>> = characters
+            .values()
+            .map(|levels| {
+                fittings
+                    .iter()
+                    .map(|requirements| missing_skills(&requirements.skills, levels).is_empty())
+                    .collect()
+            })
+            .collect();
+
+        let fittings = fittings
+            .iter()
+            .enumerate()
+            .map(|(index, FittingRequirements { fitting, .. })| {
+                let pilot_count = can_fly.iter().filter(|fittings| fittings[index]).count() as u64;
+
+                CorporationFittingReadinessDto {
+                    fitting_id: fitting.id,
+                    fitting_name: fitting.name.clone(),
+                    ship_type_name: fitting.ship_type_name.clone(),
+                    pilot_count,
+                    readiness_percent: percent(pilot_count, tracked_character_count),
+                }
+            })
+            .collect();
+        let pilot_count = can_fly
+            .iter()
+            .filter(|fittings| fittings.iter().any(|flyable| *flyable))
+            .count() as u64;
+
+        Ok(CorporationDoctrineReadinessDto {
+            corporation_id,
+            doctrine_id: doctrine.id,
+            doctrine_name: doctrine.name,
+            tracked_character_count,
+            pilot_count,
+            readiness_percent: percent(pilot_count, tracked_character_count),
+            fittings,
+        })
+    }
+
+    /// Retrieves a doctrine with its fittings and the skills each fitting requires.
+    async fn get_doctrine_requirements(
+        &self,
+        doctrine_id: i32,
+    ) -> Result<(DoctrineModel, Vec<FittingRequirements>), AppError> {
+        let doctrine_repo = DoctrineRepository::new(self.db);
+
+        let doctrine = doctrine_repo
+            .get_by_id(doctrine_id)
+            .await?
+            .ok_or(DoctrineError::DoctrineNotFound(doctrine_id))?;
+        let fittings = doctrine_repo
+            .get_fittings_by_doctrine_ids(&[doctrine_id])
+            .await?
+            .remove(&doctrine_id)
+            .unwrap_or_default();
+
+        let fitting_ids: Vec<i32> = fittings.iter().map(|f| f.id).collect();
+        let mut skills = FittingRepository::new(self.db)
+            .get_skills_by_fitting_ids(&fitting_ids)
+            .await?;
+
+        let fittings = fittings
+            .into_iter()
+            .map(|fitting| FittingRequirements {
+                skills: skills.remove(&fitting.id).unwrap_or_default(),
+                fitting,
+            })
+            .collect();
+
+        Ok((doctrine, fittings))
+    }
+}
+
+/// Reports whether a character's active levels meet the requirements of a fitting.
+fn fitting_readiness(
+    requirements: &FittingRequirements,
+    levels: &HashMap<i64, i32>,
+) -> FittingReadinessDto {
+    let missing_skills = missing_skills(&requirements.skills, levels);
+
+    FittingReadinessDto {
+        fitting_id: requirements.fitting.id,
+        fitting_name: requirements.fitting.name.clone(),
+        ship_type_name: requirements.fitting.ship_type_name.clone(),
+        can_fly: missing_skills.is_empty(),
+        missing_skills,
+    }
+}
+
+/// Lists the required skills a character's active levels fall short of.
+///
+/// Skills the character hasn't trained are reported with an active level of 0.
+fn missing_skills(
+    requirements: &[FittingSkillModel],
+    levels: &HashMap<i64, i32>,
+) -> Vec<MissingSkillDto> {
+    requirements
+        .iter()
+        .filter_map(|requirement| {
+            let active_level = levels.get(&requirement.skill_id).copied().unwrap_or(0);
+
+            (active_level < requirement.level).then_some(MissingSkillDto {
+                skill_id: requirement.skill_id,
+                required_level: requirement.level,
+                active_level,
+            })
+        })
+        .collect()
+}
+
+/// Returns the percentage of a total, rounded down, or 0 if the total is 0.
+fn percent(count: u64, total: u64) -> u64 {
+    if total == 0 {
+        return 0;
+    }
+
+    count * 100 / total
+}
@@ -2,10 +2,11 @@
 //!
 //! This module provides the `AffiliationService` for bulk updating character and corporation
 //! affiliations from ESI. It handles fetching affiliation data, resolving dependencies,
-//! and updating relationships in a single transaction.
+//! updating relationships, and recording affiliation changes in a single transaction.
 
 use std::collections::HashSet;
 
+use chrono::Utc;
 use dioxus_logger::tracing;
 use eve_esi::model::character::CharacterAffiliation;
 use sea_orm::{DatabaseConnection, TransactionTrait};
@@ -13,9 +14,12 @@ use sea_orm::{DatabaseConnection, TransactionTrait};
 use crate::server::{
     data::eve::{character::CharacterRepository, corporation::CorporationRepository},
     error::AppError,
-    service::eve::{
-        esi::EsiProvider,
-        orchestrator::{EveEntityOrchestrator, StoredEntities},
+    service::{
+        affiliation_history::AffiliationHistoryService,
+        eve::{
+            esi::EsiProvider,
+            orchestrator::{EveEntityOrchestrator, StoredEntities},
+        },
     },
     util::eve::{is_valid_character_id, ESI_AFFILIATION_REQUEST_LIMIT},
 };
@@ -172,7 +176,8 @@ impl<'a> AffiliationService<'a> {
     /// Orchestrates affiliation relationship updates in the database.
     ///
     /// Processes ESI affiliation data and coordinates updates for both corporation and character
-    /// affiliations. Uses the entity record IDs to map EVE IDs to internal database record IDs.
+    /// affiliations, then records any changes in the affiliation history. Uses the entity record
+    /// IDs to map EVE IDs to internal database record IDs.
     ///
     /// # Arguments
     /// - `txn` - Database transaction to execute updates within
@@ -189,6 +194,7 @@ impl<'a> AffiliationService<'a> {
     ) -> Result<(), AppError> {
         Self::update_corporation_affiliations(txn, affiliations, &stored_entities).await?;
         Self::update_character_affiliations(txn, affiliations, &stored_entities).await?;
+        Self::record_affiliation_history(txn, affiliations, &stored_entities).await?;
         Ok(())
     }
 
@@ -318,4 +324,52 @@ impl<'a> AffiliationService<'a> {
 
         Ok(())
     }
+
+    /// Records affiliation changes in the affiliation history (character -> corporation, alliance).
+    ///
+    /// Maps EVE IDs to database record IDs, skipping characters whose character, corporation, or
+    /// alliance record is missing as their affiliation was not updated either.
+    ///
+    /// # Arguments
+    /// - `txn` - Database transaction to execute updates within
+    /// - `affiliations` - ESI affiliation data containing character/corporation/alliance relationships
+    /// - `stored_entities` - Maps of EVE IDs to database record IDs
+    ///
+    /// # Returns
+    /// - `Ok(())` - Affiliation history recorded successfully
+    /// - `Err(AppError::Database)` - Database operation failed
+    async fn record_affiliation_history(
+        txn: &sea_orm::DatabaseTransaction,
+        affiliations: &[CharacterAffiliation],
+        stored_entities: &StoredEntities,
+    ) -> Result<(), AppError> {
+        let history_updates: Vec<(i32, i32, Option<i32>)> = affiliations
+            .iter()
+            .filter_map(|a| {
+                let char_db_id = stored_entities.get_character_record_id(&a.character_id)?;
+                let corp_db_id = stored_entities.get_corporation_record_id(&a.corporation_id)?;
+                let alliance_db_id = match a.alliance_id {
+                    Some(alliance_id) => {
+                        Some(stored_entities.get_alliance_record_id(&alliance_id)?)
+                    }
+                    None => None,
+                };
+
+                Some((char_db_id, corp_db_id, alliance_db_id))
+            })
+            .collect();
+
+        let changed = AffiliationHistoryService::record_affiliations(
+            txn,
+            history_updates,
+            Utc::now().naive_utc(),
+        )
+        .await?;
+
+        if changed > 0 {
+            tracing::debug!("Recorded {} affiliation change(s)", changed);
+        }
+
+        Ok(())
+    }
 }
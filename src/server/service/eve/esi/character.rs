@@ -6,7 +6,10 @@
 
 use std::sync::Arc;
 
-use eve_esi::model::character::{Character, CharacterAffiliation};
+use eve_esi::model::{
+    character::{Character, CharacterAffiliation},
+    skills::CharacterSkills,
+};
 
 use super::{group::EndpointGroup, macros::define_esi_endpoint};
 
@@ -71,4 +74,22 @@ impl<'a> CharacterEndpoints<'a> {
         =>
         character, character_affiliation[character_ids]
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the skills a character trained and their total skill points.
+        ///
+        /// Authenticated endpoint requiring an access token of the character with the
+        /// `esi-skills.read_skills.v1` scope.
+        ///
+        /// # Arguments
+        /// - `access_token` - Access token of the character
+        /// - `character_id` - EVE Online character ID
+        pub fn get_character_skills(
+            &self,
+            access_token: &str,
+            character_id: i64,
+        ) -> EsiProviderRequest<CharacterSkills>
+        =>
+        skills, get_character_skills[access_token, character_id]
+    }
 }
@@ -55,4 +55,22 @@ impl<'a> CorporationEndpoints<'a> {
         =>
         corporation, get_corporation_information[corporation_id]
     }
+
+    define_esi_endpoint! {
+        /// Retrieves the character IDs of a corporation's members.
+        ///
+        /// Authenticated endpoint requiring an access token of a director of the corporation
+        /// with the `esi-corporation.read_corporation_membership.v1` scope.
+        ///
+        /// # Arguments
+        /// - `access_token` - Access token of a director of the corporation
+        /// - `corporation_id` - EVE Online corporation ID
+        pub fn get_corporation_members(
+            &self,
+            access_token: &str,
+            corporation_id: i64,
+        ) -> EsiProviderRequest<Vec<i64>>
+        =>
+        corporation, get_corporation_members[access_token, corporation_id]
+    }
 }
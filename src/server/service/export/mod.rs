@@ -0,0 +1,312 @@
+//! Export service layer.
+//!
+//! This module contains the `ExportService` for exporting datasets too large to build in
+//! memory, such as every character known to Bifrost. Exports are produced as NDJSON (one JSON
+//! object per line) streams that fetch the next page from the database only once the previous
+//! page has been sent, so memory use stays constant and a slow client applies backpressure to
+//! the database reads instead of buffering the export.
+//!
+//! If object storage is configured, exports can also be written to the bucket and downloaded
+//! through a presigned URL, so the download doesn't pass through Bifrost. Users can export
+//! everything Bifrost stores about them the same way, as a JSON archive assembled by a worker
+//! job.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use dioxus_logger::tracing;
+use futures::{stream, Stream, StreamExt};
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::export::{
+        CharacterExportDto, ExportedPushSubscriptionDto, StoredExportDto, UserDataArchiveDto,
+    },
+    server::{
+        data::{export::ExportRepository, push::PushSubscriptionRepository, user::UserRepository},
+        error::{auth::AuthError, AppError},
+        service::{
+            affiliation_history::AffiliationHistoryService, announcement::AnnouncementService,
+            consent::ConsentService, preference::PreferenceService,
+            user::user_character::UserCharacterService,
+        },
+        util::object_storage::ObjectStorage,
+    },
+};
+
+/// Number of records fetched from the database per streamed chunk.
+const EXPORT_PAGE_SIZE: u64 = 1000;
+
+/// Lifetime of download URLs of stored exports (1 hour).
+const DOWNLOAD_URL_LIFETIME: Duration = Duration::from_secs(60 * 60);
+
+/// Lifetime of download URLs of user data archives (7 days).
+///
+/// Longer than for admin exports since users are notified and may not open the notification
+/// right away.
+const USER_ARCHIVE_URL_LIFETIME: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Content type of NDJSON exports.
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Content type of user data archives.
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Service for streaming admin exports.
+pub struct ExportService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> ExportService<'a> {
+    /// Creates a new instance of ExportService.
+    ///
+    /// Constructs a service for streaming admin exports.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ExportService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Streams every character as NDJSON with its corporation, alliance, and owning user.
+    ///
+    /// Each item is a chunk of up to `EXPORT_PAGE_SIZE` newline-terminated JSON objects. The
+    /// stream owns a clone of the database connection so it can outlive the request handler
+    /// while the response body is sent. Characters whose corporation is missing are skipped.
+    ///
+    /// # Returns
+    /// - `Stream<Item = Result<String, AppError>>` - NDJSON chunks, ending with an error if a
+    ///   page could not be fetched
+    pub fn stream_characters(
+        &self,
+    ) -> impl Stream<Item = Result<String, AppError>> + Send + 'static {
+        let db = self.db.clone();
+
+        // The state is the record ID to continue after; `None` once the last page was sent
+        stream::try_unfold(Some(None), move |after_id: Option<Option<i32>>| {
+            let db = db.clone();
+
+            async move {
+                let Some(after_id) = after_id else {
+                    return Ok(None);
+                };
+
+                let (chunk, last_id, page_len) = character_page(&db, after_id)
+                    .await
+                    .inspect_err(|e| tracing::error!("Character export aborted: {}", e))?;
+
+                if page_len == 0 {
+                    return Ok(None);
+                }
+
+                let next = (page_len as u64 == EXPORT_PAGE_SIZE).then_some(last_id);
+
+                Ok(Some((chunk, next)))
+            }
+        })
+    }
+
+    /// Writes every character as NDJSON to object storage.
+    ///
+    /// The export is uploaded page by page, so memory use stays bounded by the upload part
+    /// size. If reading or uploading the export fails, the upload is aborted and no object is
+    /// left behind.
+    ///
+    /// # Arguments
+    /// - `storage` - Bucket the export is stored in
+    ///
+    /// # Returns
+    /// - `Ok(StoredExportDto)` - Presigned download URL of the stored export and its expiry
+    /// - `Err(AppError)` - Database query, serialization, or upload failed
+    pub async fn store_characters(
+        &self,
+        storage: &ObjectStorage,
+    ) -> Result<StoredExportDto, AppError> {
+        let now = Utc::now();
+        let key = format!("exports/characters-{}.ndjson", now.format("%Y%m%dT%H%M%SZ"));
+
+        let mut upload = storage.upload(&key, NDJSON_CONTENT_TYPE);
+        let mut chunks = Box::pin(self.stream_characters());
+        while let Some(chunk) = chunks.next().await {
+            let written = match chunk {
+                Ok(chunk) => upload.write(chunk.as_bytes()).await.map_err(AppError::from),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = written {
+                if let Err(abort_error) = upload.abort().await {
+                    tracing::warn!("Failed to abort export upload {}: {}", key, abort_error);
+                }
+                return Err(e);
+            }
+        }
+        upload.finish().await?;
+
+        Ok(StoredExportDto {
+            url: storage.download_url(&key, "characters.ndjson", DOWNLOAD_URL_LIFETIME),
+            expires_at: (now + DOWNLOAD_URL_LIFETIME).naive_utc(),
+        })
+    }
+
+    /// Assembles everything Bifrost stores about a user.
+    ///
+    /// The archive holds the user's characters with their affiliation history, preferences,
+    /// data-sharing consents, announcement inbox, and push subscriptions. Push subscription
+    /// keys are left out since they only identify the user's devices to their push services.
+    /// No data is synced for consented categories yet; it is added alongside each sync.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user to export
+    ///
+    /// # Returns
+    /// - `Ok(UserDataArchiveDto)` - Everything stored about the user
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User doesn't exist
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn build_user_archive(&self, user_id: i32) -> Result<UserDataArchiveDto, AppError> {
+        let Some((user, _)) = UserRepository::new(self.db).get_by_id(user_id).await? else {
+            return Err(AuthError::UserNotInDatabase(user_id).into());
+        };
+
+        let characters = UserCharacterService::new(self.db)
+            .get_user_characters(user_id)
+            .await?;
+
+        let history_service = AffiliationHistoryService::new(self.db);
+        let mut affiliation_history = Vec::with_capacity(characters.len());
+        for character in &characters {
+            affiliation_history.push(history_service.get_history(character.id).await?);
+        }
+
+        let push_subscriptions = PushSubscriptionRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .map(|subscription| ExportedPushSubscriptionDto {
+                endpoint: subscription.endpoint,
+                created_at: subscription.created_at,
+            })
+            .collect();
+
+        Ok(UserDataArchiveDto {
+            user_id,
+            registered_at: user.created_at,
+            exported_at: Utc::now().naive_utc(),
+            characters,
+            affiliation_history,
+            preferences: PreferenceService::new(self.db)
+                .get_preferences(user_id)
+                .await?,
+            consents: ConsentService::new(self.db).get_consents(user_id).await?,
+            announcements: AnnouncementService::new(self.db).get_inbox(user_id).await?,
+            push_subscriptions,
+        })
+    }
+
+    /// Writes everything Bifrost stores about a user to object storage as a JSON archive.
+    ///
+    /// # Arguments
+    /// - `storage` - Bucket the archive is stored in
+    /// - `user_id` - ID of the user to export
+    ///
+    /// # Returns
+    /// - `Ok(StoredExportDto)` - Presigned download URL of the stored archive and its expiry
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User doesn't exist
+    /// - `Err(AppError)` - Database query, serialization, or upload failed
+    pub async fn store_user_archive(
+        &self,
+        storage: &ObjectStorage,
+        user_id: i32,
+    ) -> Result<StoredExportDto, AppError> {
+        let archive = self.build_user_archive(user_id).await?;
+        let json = serde_json::to_vec_pretty(&archive)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize user archive: {}", e)))?;
+
+        let now = Utc::now();
+        let key = format!(
+            "exports/users/{}-{}.json",
+            user_id,
+            now.format("%Y%m%dT%H%M%SZ")
+        );
+
+        let mut upload = storage.upload(&key, JSON_CONTENT_TYPE);
+        if let Err(e) = upload.write(&json).await {
+            if let Err(abort_error) = upload.abort().await {
+                tracing::warn!(
+                    "Failed to abort user archive upload {}: {}",
+                    key,
+                    abort_error
+                );
+            }
+            return Err(e.into());
+        }
+        upload.finish().await?;
+
+        Ok(StoredExportDto {
+            url: storage.download_url(&key, "bifrost-data.json", USER_ARCHIVE_URL_LIFETIME),
+            expires_at: (now + USER_ARCHIVE_URL_LIFETIME).naive_utc(),
+        })
+    }
+}
+
+/// Fetches one page of characters and renders it as NDJSON.
+///
+/// # Arguments
+/// - `db` - Database connection
+/// - `after_id` - Record ID of the last character of the previous page
+///
+/// # Returns
+/// - `Ok((String, Option<i32>, usize))` - NDJSON chunk, record ID of the page's last character,
+///   and number of characters in the page
+/// - `Err(AppError)` - Database query or serialization failed
+async fn character_page(
+    db: &DatabaseConnection,
+    after_id: Option<i32>,
+) -> Result<(String, Option<i32>, usize), AppError> {
+    let repository = ExportRepository::new(db);
+
+    let characters = repository
+        .get_characters_after(after_id, EXPORT_PAGE_SIZE)
+        .await?;
+    let page_len = characters.len();
+    let last_id = characters.last().map(|character| character.id);
+
+    let corporations = repository
+        .get_corporations_by_ids(characters.iter().map(|c| c.corporation_id).collect())
+        .await?;
+    let ownerships = repository
+        .get_ownerships_by_character_ids(characters.iter().map(|c| c.id).collect())
+        .await?;
+
+    let mut chunk = String::new();
+    for character in characters {
+        let Some((corporation, alliance)) = corporations.get(&character.corporation_id) else {
+            tracing::warn!(
+                character_id = character.character_id,
+                corporation_id = character.corporation_id,
+                "Failed to find related corporation for character in database - skipping character from export"
+            );
+            continue;
+        };
+
+        let row = CharacterExportDto {
+            character_id: character.character_id,
+            name: character.name,
+            corporation_id: corporation.corporation_id,
+            corporation_name: corporation.name.clone(),
+            alliance_id: alliance.as_ref().map(|alliance| alliance.alliance_id),
+            user_id: ownerships
+                .get(&character.id)
+                .map(|ownership| ownership.user_id),
+        };
+
+        let line = serde_json::to_string(&row).map_err(|e| {
+            AppError::Internal(format!("Failed to serialize character export row: {}", e))
+        })?;
+        chunk.push_str(&line);
+        chunk.push('\n');
+    }
+
+    Ok((chunk, last_id, page_len))
+}
@@ -0,0 +1,539 @@
+//! Group service layer.
+//!
+//! This module contains the `GroupService` managing the groups users can join. Open groups can
+//! be joined by anyone, request-to-join groups hold requests until an admin approves them, and
+//! hidden groups are only listed to their members, who are added by admins. Groups can be
+//! restricted to users affiliated with certain corporations, alliances, or factions by the rules
+//! of `rule::GroupRuleService`. Membership changes are written to the info log with the acting
+//! user so they can be audited.
+
+pub mod rule;
+
+use std::collections::HashMap;
+
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::group::{
+        CreateGroupDto, GroupDto, GroupJoinPolicy, GroupMemberDto, GroupMemberStatus, UserGroupDto,
+    },
+    server::{
+        data::{
+            group::{GroupMemberRepository, GroupRepository},
+            user::{summary::UserCharacterSummaryRepository, UserRepository},
+        },
+        error::{auth::AuthError, group::GroupError, AppError},
+        model::db::{GroupMemberModel, GroupModel},
+        service::group::rule::GroupRuleService,
+    },
+};
+
+/// Maximum length of a group name in characters.
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Maximum length of a group description in characters.
+const MAX_DESCRIPTION_LENGTH: usize = 2000;
+
+/// Service for managing groups and their members.
+pub struct GroupService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> GroupService<'a> {
+    /// Creates a new instance of GroupService.
+    ///
+    /// Constructs a service for managing groups, joining and leaving them, and approving
+    /// requests to join.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `GroupService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a group.
+    ///
+    /// # Arguments
+    /// - `created_by_user_id` - ID of the admin creating the group
+    /// - `group` - Name, description, and join policy of the group
+    ///
+    /// # Returns
+    /// - `Ok(GroupDto)` - The created group
+    /// - `Err(AppError::Group(GroupError::InvalidGroup))` - Name is empty, too long, or already
+    ///   taken, or the description is too long
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_group(
+        &self,
+        created_by_user_id: i32,
+        group: CreateGroupDto,
+    ) -> Result<GroupDto, AppError> {
+        let name = group.name.trim();
+        if name.is_empty() {
+            return Err(
+                GroupError::InvalidGroup("Group name must not be empty".to_string()).into(),
+            );
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(GroupError::InvalidGroup(format!(
+                "Group name must be at most {} characters",
+                MAX_NAME_LENGTH
+            ))
+            .into());
+        }
+        let description = group.description.trim();
+        if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+            return Err(GroupError::InvalidGroup(format!(
+                "Group description must be at most {} characters",
+                MAX_DESCRIPTION_LENGTH
+            ))
+            .into());
+        }
+
+        let group_repo = GroupRepository::new(self.db);
+        if group_repo.find_by_name(name).await?.is_some() {
+            return Err(
+                GroupError::InvalidGroup(format!("A group named {} already exists", name)).into(),
+            );
+        }
+
+        let created = group_repo
+            .create(
+                name.to_string(),
+                description.to_string(),
+                group.join_policy.as_str().to_string(),
+            )
+            .await?;
+
+        tracing::info!(
+            created_by_user_id = %created_by_user_id,
+            group_id = %created.id,
+            join_policy = %created.join_policy,
+            "Created group {}",
+            created.name
+        );
+
+        Ok(group_to_dto(created, 0, 0))
+    }
+
+    /// Retrieves all groups with their member and pending request counts.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GroupDto>)` - All groups ordered by name
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_groups(&self) -> Result<Vec<GroupDto>, AppError> {
+        let member_repo = GroupMemberRepository::new(self.db);
+        let groups = GroupRepository::new(self.db).get_all().await?;
+
+        let mut dtos = Vec::with_capacity(groups.len());
+        for group in groups {
+            let member_count = member_repo
+                .count(group.id, GroupMemberStatus::Member.as_str())
+                .await?;
+            let pending_count = member_repo
+                .count(group.id, GroupMemberStatus::Pending.as_str())
+                .await?;
+            dtos.push(group_to_dto(group, member_count, pending_count));
+        }
+
+        Ok(dtos)
+    }
+
+    /// Deletes a group along with its members and pending requests.
+    ///
+    /// # Arguments
+    /// - `deleted_by_user_id` - ID of the admin deleting the group
+    /// - `group_id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(())` - Group deleted
+    /// - `Err(AppError::Group(GroupError::GroupNotFound))` - Group doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_group(
+        &self,
+        deleted_by_user_id: i32,
+        group_id: i32,
+    ) -> Result<(), AppError> {
+        if !GroupRepository::new(self.db).delete(group_id).await? {
+            return Err(GroupError::GroupNotFound(group_id).into());
+        }
+
+        tracing::info!(
+            deleted_by_user_id = %deleted_by_user_id,
+            group_id = %group_id,
+            "Deleted group"
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves the members and pending requests of a group, oldest first.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GroupMemberDto>)` - Members and pending requests with the users' main characters
+    /// - `Err(AppError::Group(GroupError::GroupNotFound))` - Group doesn't exist
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_members(&self, group_id: i32) -> Result<Vec<GroupMemberDto>, AppError> {
+        self.get_group(group_id).await?;
+
+        let members = GroupMemberRepository::new(self.db)
+            .get_by_group_id(group_id)
+            .await?;
+
+        self.members_to_dtos(members).await
+    }
+
+    /// Adds a user to a group as a member, approving any pending request of the user.
+    ///
+    /// Admins can add users to groups of any join policy as long as the user qualifies for the
+    /// group's rules, and adding an existing member returns their membership unchanged.
+    ///
+    /// # Arguments
+    /// - `added_by_user_id` - ID of the admin adding the user
+    /// - `group_id` - ID of the group
+    /// - `user_id` - ID of the user to add
+    ///
+    /// # Returns
+    /// - `Ok(GroupMemberDto)` - The user's membership
+    /// - `Err(AppError::Group(GroupError::GroupNotFound))` - Group doesn't exist
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User doesn't exist
+    /// - `Err(AppError::Group(GroupError::NotEligible))` - User doesn't qualify for the group
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn add_member(
+        &self,
+        added_by_user_id: i32,
+        group_id: i32,
+        user_id: i32,
+    ) -> Result<GroupMemberDto, AppError> {
+        self.get_group(group_id).await?;
+        if UserRepository::new(self.db)
+            .get_by_id(user_id)
+            .await?
+            .is_none()
+        {
+            return Err(AuthError::UserNotInDatabase(user_id).into());
+        }
+
+        let member_repo = GroupMemberRepository::new(self.db);
+        let existing = member_repo.find(group_id, user_id).await?;
+        if let Some(member) = existing
+            .as_ref()
+            .filter(|member| member.status == GroupMemberStatus::Member.as_str())
+        {
+            return self.member_to_dto(member.clone()).await;
+        }
+        GroupRuleService::new(self.db)
+            .ensure_eligible(user_id, group_id)
+            .await?;
+
+        let member = match existing {
+            Some(_) => member_repo
+                .set_status(
+                    group_id,
+                    user_id,
+                    GroupMemberStatus::Member.as_str().to_string(),
+                    added_by_user_id,
+                )
+                .await?
+                .ok_or(GroupError::MembershipNotFound { group_id, user_id })?,
+            None => {
+                member_repo
+                    .create(
+                        group_id,
+                        user_id,
+                        GroupMemberStatus::Member.as_str().to_string(),
+                        Some(added_by_user_id),
+                    )
+                    .await?
+            }
+        };
+
+        tracing::info!(
+            added_by_user_id = %added_by_user_id,
+            group_id = %group_id,
+            user_id = %user_id,
+            "Added user to group"
+        );
+
+        self.member_to_dto(member).await
+    }
+
+    /// Approves a user's pending request to join a group.
+    ///
+    /// Approving a user who is already a member returns their membership unchanged.
+    ///
+    /// # Arguments
+    /// - `approved_by_user_id` - ID of the admin approving the request
+    /// - `group_id` - ID of the group
+    /// - `user_id` - ID of the user who requested to join
+    ///
+    /// # Returns
+    /// - `Ok(GroupMemberDto)` - The user's membership
+    /// - `Err(AppError::Group(GroupError::MembershipNotFound))` - User hasn't requested to join
+    ///   the group
+    /// - `Err(AppError::Group(GroupError::NotEligible))` - User no longer qualifies for the group
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn approve_member(
+        &self,
+        approved_by_user_id: i32,
+        group_id: i32,
+        user_id: i32,
+    ) -> Result<GroupMemberDto, AppError> {
+        let member_repo = GroupMemberRepository::new(self.db);
+
+        let Some(member) = member_repo.find(group_id, user_id).await? else {
+            return Err(GroupError::MembershipNotFound { group_id, user_id }.into());
+        };
+        if member.status == GroupMemberStatus::Member.as_str() {
+            return self.member_to_dto(member).await;
+        }
+        GroupRuleService::new(self.db)
+            .ensure_eligible(user_id, group_id)
+            .await?;
+
+        let Some(member) = member_repo
+            .set_status(
+                group_id,
+                user_id,
+                GroupMemberStatus::Member.as_str().to_string(),
+                approved_by_user_id,
+            )
+            .await?
+        else {
+            return Err(GroupError::MembershipNotFound { group_id, user_id }.into());
+        };
+
+        tracing::info!(
+            approved_by_user_id = %approved_by_user_id,
+            group_id = %group_id,
+            user_id = %user_id,
+            "Approved request to join group"
+        );
+
+        self.member_to_dto(member).await
+    }
+
+    /// Removes a member from a group, or rejects their pending request to join.
+    ///
+    /// # Arguments
+    /// - `removed_by_user_id` - ID of the admin removing the user
+    /// - `group_id` - ID of the group
+    /// - `user_id` - ID of the user to remove
+    ///
+    /// # Returns
+    /// - `Ok(())` - Membership or request removed
+    /// - `Err(AppError::Group(GroupError::MembershipNotFound))` - User has no membership in the
+    ///   group
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn remove_member(
+        &self,
+        removed_by_user_id: i32,
+        group_id: i32,
+        user_id: i32,
+    ) -> Result<(), AppError> {
+        if !GroupMemberRepository::new(self.db)
+            .delete(group_id, user_id)
+            .await?
+        {
+            return Err(GroupError::MembershipNotFound { group_id, user_id }.into());
+        }
+
+        tracing::info!(
+            removed_by_user_id = %removed_by_user_id,
+            group_id = %group_id,
+            user_id = %user_id,
+            "Removed user from group"
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves the groups listed to a user with the user's membership in each.
+    ///
+    /// Open and request-to-join groups are listed to every user, while hidden groups are only
+    /// listed to their members.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserGroupDto>)` - Groups listed to the user ordered by name
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_user_groups(&self, user_id: i32) -> Result<Vec<UserGroupDto>, AppError> {
+        let statuses: HashMap<i32, GroupMemberStatus> = GroupMemberRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .filter_map(|member| {
+                GroupMemberStatus::from_name(&member.status).map(|status| (member.group_id, status))
+            })
+            .collect();
+
+        let mut groups = Vec::new();
+        for group in GroupRepository::new(self.db).get_all().await? {
+            let status = statuses.get(&group.id).copied();
+            let group = user_group_to_dto(group, status);
+            if group.join_policy == GroupJoinPolicy::Hidden && status.is_none() {
+                continue;
+            }
+            groups.push(group);
+        }
+
+        Ok(groups)
+    }
+
+    /// Joins a group, or requests to join it if its join policy requires approval.
+    ///
+    /// Joining a group the user is already a member of or has requested to join returns their
+    /// membership unchanged. Hidden groups can't be joined and are reported as not found to
+    /// users who aren't members, and groups with rules can only be joined by users who qualify.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user joining
+    /// - `group_id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(UserGroupDto)` - The group with the user's membership
+    /// - `Err(AppError::Group(GroupError::GroupNotFound))` - Group doesn't exist or is hidden
+    /// - `Err(AppError::Group(GroupError::NotEligible))` - User doesn't qualify for the group
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn join(&self, user_id: i32, group_id: i32) -> Result<UserGroupDto, AppError> {
+        let group = self.get_group(group_id).await?;
+        let member_repo = GroupMemberRepository::new(self.db);
+
+        if let Some(member) = member_repo.find(group_id, user_id).await? {
+            let status = GroupMemberStatus::from_name(&member.status);
+            return Ok(user_group_to_dto(group, status));
+        }
+
+        let status = match join_policy(&group) {
+            GroupJoinPolicy::Open => GroupMemberStatus::Member,
+            GroupJoinPolicy::Request => GroupMemberStatus::Pending,
+            GroupJoinPolicy::Hidden => return Err(GroupError::GroupNotFound(group_id).into()),
+        };
+        GroupRuleService::new(self.db)
+            .ensure_eligible(user_id, group_id)
+            .await?;
+        member_repo
+            .create(group_id, user_id, status.as_str().to_string(), None)
+            .await?;
+
+        tracing::info!(
+            user_id = %user_id,
+            group_id = %group_id,
+            status = %status.as_str(),
+            "User joined group"
+        );
+
+        Ok(user_group_to_dto(group, Some(status)))
+    }
+
+    /// Leaves a group, or cancels the user's pending request to join it.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user leaving
+    /// - `group_id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(())` - Membership or request removed
+    /// - `Err(AppError::Group(GroupError::MembershipNotFound))` - User has no membership in the
+    ///   group
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn leave(&self, user_id: i32, group_id: i32) -> Result<(), AppError> {
+        if !GroupMemberRepository::new(self.db)
+            .delete(group_id, user_id)
+            .await?
+        {
+            return Err(GroupError::MembershipNotFound { group_id, user_id }.into());
+        }
+
+        tracing::info!(user_id = %user_id, group_id = %group_id, "User left group");
+
+        Ok(())
+    }
+
+    /// Retrieves a group, failing if it doesn't exist.
+    async fn get_group(&self, group_id: i32) -> Result<GroupModel, AppError> {
+        GroupRepository::new(self.db)
+            .find_by_id(group_id)
+            .await?
+            .ok_or_else(|| GroupError::GroupNotFound(group_id).into())
+    }
+
+    /// Converts a stored membership into its DTO with the user's main character.
+    async fn member_to_dto(&self, member: GroupMemberModel) -> Result<GroupMemberDto, AppError> {
+        let mut members = self.members_to_dtos(vec![member]).await?;
+
+        Ok(members.remove(0))
+    }
+
+    /// Converts stored memberships into their DTOs with the users' main characters.
+    async fn members_to_dtos(
+        &self,
+        members: Vec<GroupMemberModel>,
+    ) -> Result<Vec<GroupMemberDto>, AppError> {
+        let mains: HashMap<i32, (i64, String)> = UserCharacterSummaryRepository::new(self.db)
+            .get_by_user_ids(members.iter().map(|member| member.user_id).collect())
+            .await?
+            .into_iter()
+            .filter(|row| row.is_main)
+            .map(|row| (row.user_id, (row.character_id, row.character_name)))
+            .collect();
+
+        let members = members
+            .into_iter()
+            .map(|member| {
+                let main = mains.get(&member.user_id);
+
+                GroupMemberDto {
+                    user_id: member.user_id,
+                    main_character_id: main.map(|(character_id, _)| *character_id),
+                    main_character_name: main.map(|(_, character_name)| character_name.clone()),
+                    status: GroupMemberStatus::from_name(&member.status)
+                        .unwrap_or(GroupMemberStatus::Pending),
+                    created_at: member.created_at,
+                    decided_at: member.decided_at,
+                    decided_by_user_id: member.decided_by_user_id,
+                }
+            })
+            .collect();
+
+        Ok(members)
+    }
+}
+
+/// Parses the stored join policy of a group, treating unknown policies as hidden.
+fn join_policy(group: &GroupModel) -> GroupJoinPolicy {
+    GroupJoinPolicy::from_name(&group.join_policy).unwrap_or(GroupJoinPolicy::Hidden)
+}
+
+/// Converts a stored group into its admin DTO.
+fn group_to_dto(group: GroupModel, member_count: u64, pending_count: u64) -> GroupDto {
+    GroupDto {
+        join_policy: join_policy(&group),
+        id: group.id,
+        name: group.name,
+        description: group.description,
+        member_count,
+        pending_count,
+        created_at: group.created_at,
+        updated_at: group.updated_at,
+    }
+}
+
+/// Converts a stored group into the DTO listed to a user.
+fn user_group_to_dto(group: GroupModel, status: Option<GroupMemberStatus>) -> UserGroupDto {
+    UserGroupDto {
+        join_policy: join_policy(&group),
+        id: group.id,
+        name: group.name,
+        description: group.description,
+        status,
+    }
+}
@@ -0,0 +1,324 @@
+//! Group eligibility rule service.
+//!
+//! This module contains the `GroupRuleService` deciding which users qualify for a group. A group
+//! without rules is open to every user, while a group with rules only admits users with a
+//! character in one of the corporations, alliances, or factions its rules name. Eligibility is
+//! checked when users join or are added, and re-evaluated by the `CheckGroupEligibility` worker
+//! job after character affiliations are refreshed, removing users who no longer qualify.
+
+use std::collections::{HashMap, HashSet};
+
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::group::{CreateGroupRuleDto, GroupRuleDto, GroupRuleKind},
+    server::{
+        data::{
+            eve::faction::FactionRepository,
+            group::{rule::GroupRuleRepository, GroupMemberRepository, GroupRepository},
+            user::user_character::UserCharacterRepository,
+        },
+        error::{group::GroupError, AppError},
+        model::db::GroupRuleModel,
+    },
+};
+
+/// Service for managing and evaluating group eligibility rules.
+pub struct GroupRuleService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> GroupRuleService<'a> {
+    /// Creates a new instance of GroupRuleService.
+    ///
+    /// Constructs a service for managing group eligibility rules and checking whether users
+    /// qualify for groups.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `GroupRuleService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Adds a rule qualifying users affiliated with a corporation, alliance, or faction.
+    ///
+    /// Existing members are not re-evaluated until their characters' affiliations are next
+    /// refreshed.
+    ///
+    /// # Arguments
+    /// - `added_by_user_id` - ID of the admin adding the rule
+    /// - `group_id` - ID of the group
+    /// - `rule` - Kind of affiliation and EVE Online ID of the entity it matches
+    ///
+    /// # Returns
+    /// - `Ok(GroupRuleDto)` - The added rule
+    /// - `Err(AppError::Group(GroupError::InvalidRule))` - Entity ID isn't positive or the group
+    ///   already has the rule
+    /// - `Err(AppError::Group(GroupError::GroupNotFound))` - Group doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn add_rule(
+        &self,
+        added_by_user_id: i32,
+        group_id: i32,
+        rule: CreateGroupRuleDto,
+    ) -> Result<GroupRuleDto, AppError> {
+        if rule.entity_id <= 0 {
+            return Err(GroupError::InvalidRule(format!(
+                "Invalid {} ID {}",
+                rule.kind.as_str(),
+                rule.entity_id
+            ))
+            .into());
+        }
+        if GroupRepository::new(self.db)
+            .find_by_id(group_id)
+            .await?
+            .is_none()
+        {
+            return Err(GroupError::GroupNotFound(group_id).into());
+        }
+
+        let rule_repo = GroupRuleRepository::new(self.db);
+        if rule_repo
+            .find(group_id, rule.kind.as_str(), rule.entity_id)
+            .await?
+            .is_some()
+        {
+            return Err(GroupError::InvalidRule(format!(
+                "The group already admits {} {}",
+                rule.kind.as_str(),
+                rule.entity_id
+            ))
+            .into());
+        }
+
+        let created = rule_repo
+            .create(group_id, rule.kind.as_str().to_string(), rule.entity_id)
+            .await?;
+
+        tracing::info!(
+            added_by_user_id = %added_by_user_id,
+            group_id = %group_id,
+            rule_id = %created.id,
+            "Added group rule admitting {} {}",
+            created.kind,
+            created.entity_id
+        );
+
+        Ok(rule_to_dto(created))
+    }
+
+    /// Retrieves the rules of a group.
+    ///
+    /// # Arguments
+    /// - `group_id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(Vec<GroupRuleDto>)` - Rules of the group, empty if every user qualifies
+    /// - `Err(AppError::Group(GroupError::GroupNotFound))` - Group doesn't exist
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_rules(&self, group_id: i32) -> Result<Vec<GroupRuleDto>, AppError> {
+        if GroupRepository::new(self.db)
+            .find_by_id(group_id)
+            .await?
+            .is_none()
+        {
+            return Err(GroupError::GroupNotFound(group_id).into());
+        }
+
+        let rules = GroupRuleRepository::new(self.db)
+            .get_by_group_id(group_id)
+            .await?
+            .into_iter()
+            .map(rule_to_dto)
+            .collect();
+
+        Ok(rules)
+    }
+
+    /// Removes a rule from a group.
+    ///
+    /// # Arguments
+    /// - `removed_by_user_id` - ID of the admin removing the rule
+    /// - `group_id` - ID of the group
+    /// - `rule_id` - ID of the rule
+    ///
+    /// # Returns
+    /// - `Ok(())` - Rule removed
+    /// - `Err(AppError::Group(GroupError::RuleNotFound))` - Group has no rule with the ID
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn remove_rule(
+        &self,
+        removed_by_user_id: i32,
+        group_id: i32,
+        rule_id: i32,
+    ) -> Result<(), AppError> {
+        if !GroupRuleRepository::new(self.db)
+            .delete(group_id, rule_id)
+            .await?
+        {
+            return Err(GroupError::RuleNotFound(rule_id).into());
+        }
+
+        tracing::info!(
+            removed_by_user_id = %removed_by_user_id,
+            group_id = %group_id,
+            rule_id = %rule_id,
+            "Removed group rule"
+        );
+
+        Ok(())
+    }
+
+    /// Fails if the user doesn't qualify for a group.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `group_id` - ID of the group
+    ///
+    /// # Returns
+    /// - `Ok(())` - Group has no rules, or one of the user's characters matches a rule
+    /// - `Err(AppError::Group(GroupError::NotEligible))` - None of the user's characters
+    ///   matches a rule of the group
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn ensure_eligible(&self, user_id: i32, group_id: i32) -> Result<(), AppError> {
+        let rules = GroupRuleRepository::new(self.db)
+            .get_by_group_id(group_id)
+            .await?;
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        let affiliations = self.get_affiliations(user_id).await?;
+        if rules.iter().any(|rule| affiliations.matches(rule)) {
+            return Ok(());
+        }
+
+        Err(GroupError::NotEligible { group_id, user_id }.into())
+    }
+
+    /// Re-evaluates the group memberships of users, removing those they no longer qualify for.
+    ///
+    /// Pending requests to join are removed as well. Memberships in groups without rules are
+    /// left untouched.
+    ///
+    /// # Arguments
+    /// - `user_ids` - IDs of the users whose characters' affiliations changed
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of memberships and pending requests removed
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn check_eligibility(&self, user_ids: Vec<i32>) -> Result<u64, AppError> {
+        let mut rules_by_group: HashMap<i32, Vec<GroupRuleModel>> = HashMap::new();
+        for rule in GroupRuleRepository::new(self.db).get_all().await? {
+            rules_by_group.entry(rule.group_id).or_default().push(rule);
+        }
+        if rules_by_group.is_empty() {
+            return Ok(0);
+        }
+
+        let member_repo = GroupMemberRepository::new(self.db);
+        let mut removed = 0;
+        for user_id in user_ids {
+            let restricted: Vec<i32> = member_repo
+                .get_by_user_id(user_id)
+                .await?
+                .into_iter()
+                .map(|member| member.group_id)
+                .filter(|group_id| rules_by_group.contains_key(group_id))
+                .collect();
+            if restricted.is_empty() {
+                continue;
+            }
+
+            let affiliations = self.get_affiliations(user_id).await?;
+            for group_id in restricted {
+                if rules_by_group[&group_id]
+                    .iter()
+                    .any(|rule| affiliations.matches(rule))
+                {
+                    continue;
+                }
+
+                if member_repo.delete(group_id, user_id).await? {
+                    removed += 1;
+                    tracing::info!(
+                        user_id = %user_id,
+                        group_id = %group_id,
+                        "Removed user no longer qualifying for group"
+                    );
+                }
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// Collects the affiliations of every character owned by a user.
+    async fn get_affiliations(&self, user_id: i32) -> Result<UserAffiliations, AppError> {
+        let characters = UserCharacterRepository::new(self.db)
+            .get_owned_characters_by_user_id(user_id)
+            .await?;
+
+        let mut affiliations = UserAffiliations::default();
+        let mut faction_record_ids = HashSet::new();
+        for (character, corporation, alliance) in characters {
+            affiliations
+                .corporation_ids
+                .insert(corporation.corporation_id);
+            if let Some(alliance) = alliance {
+                affiliations.alliance_ids.insert(alliance.alliance_id);
+            }
+            if let Some(faction_id) = character.faction_id {
+                faction_record_ids.insert(faction_id);
+            }
+        }
+
+        if !faction_record_ids.is_empty() {
+            affiliations.faction_ids = FactionRepository::new(self.db)
+                .get_all()
+                .await?
+                .into_iter()
+                .filter(|faction| faction_record_ids.contains(&faction.id))
+                .map(|faction| faction.faction_id)
+                .collect();
+        }
+
+        Ok(affiliations)
+    }
+}
+
+/// EVE Online IDs of the corporations, alliances, and factions a user's characters belong to.
+#[derive(Default)]
+struct UserAffiliations {
+    corporation_ids: HashSet<i64>,
+    alliance_ids: HashSet<i64>,
+    faction_ids: HashSet<i64>,
+}
+
+impl UserAffiliations {
+    /// Returns whether one of the user's characters matches a rule.
+    fn matches(&self, rule: &GroupRuleModel) -> bool {
+        match GroupRuleKind::from_name(&rule.kind) {
+            Some(GroupRuleKind::Corporation) => self.corporation_ids.contains(&rule.entity_id),
+            Some(GroupRuleKind::Alliance) => self.alliance_ids.contains(&rule.entity_id),
+            Some(GroupRuleKind::Faction) => self.faction_ids.contains(&rule.entity_id),
+            None => false,
+        }
+    }
+}
+
+/// Converts a stored rule into its DTO.
+fn rule_to_dto(rule: GroupRuleModel) -> GroupRuleDto {
+    GroupRuleDto {
+        kind: GroupRuleKind::from_name(&rule.kind).unwrap_or(GroupRuleKind::Corporation),
+        id: rule.id,
+        group_id: rule.group_id,
+        entity_id: rule.entity_id,
+        created_at: rule.created_at,
+    }
+}
@@ -0,0 +1,336 @@
+//! Image proxy service layer.
+//!
+//! This module contains the `ImageService` for serving EVE Online portraits and logos through
+//! Bifrost. If `IMAGE_CACHE_DIR` is configured, images are fetched from the EVE image server
+//! once and cached on disk for `IMAGE_CACHE_TTL_SECS`, so browsers never contact CCP's servers
+//! directly and repeated views don't hit the image server. Without a cache directory images
+//! aren't proxied and requests are redirected to the EVE image server instead.
+//!
+//! The DTOs returned by the user endpoints carry the image URLs built by
+//! `ImageService::image_url`, so the client doesn't construct image URLs itself.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime},
+};
+
+use dioxus_logger::tracing;
+
+use crate::server::{
+    config::Config,
+    error::{image::ImageError, AppError},
+};
+
+/// Base URL of the EVE image server.
+const IMAGE_SERVER_URL: &str = "https://images.evetech.net";
+
+/// Path of the Bifrost endpoint serving images.
+const IMAGE_ENDPOINT_PATH: &str = "/img";
+
+/// Image sizes offered by the EVE image server.
+const SUPPORTED_SIZES: [u32; 6] = [32, 64, 128, 256, 512, 1024];
+
+/// Image size served if the request doesn't specify a size.
+pub const DEFAULT_IMAGE_SIZE: u32 = 128;
+
+/// Counter making temporary file names of concurrent cache writes unique.
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Image proxy settings derived from the server configuration at startup.
+#[derive(Clone, Debug, Default)]
+pub struct ImageProxyConfig {
+    /// Directory images are cached in, `None` if images are not proxied.
+    pub cache_dir: Option<PathBuf>,
+    /// Time a cached image is served before it is fetched again.
+    pub ttl: Duration,
+    /// HTTP client used to fetch images from the EVE image server.
+    pub http: reqwest::Client,
+}
+
+impl ImageProxyConfig {
+    /// Builds image proxy settings from the server configuration.
+    ///
+    /// # Arguments
+    /// - `config` - Server configuration loaded from environment variables
+    ///
+    /// # Returns
+    /// - `Ok(ImageProxyConfig)` - Cache directory if `IMAGE_CACHE_DIR` is set
+    /// - `Err(AppError)` - Failed to build the HTTP client
+    pub fn from_config(config: &Config) -> Result<Self, AppError> {
+        let http = reqwest::Client::builder()
+            .user_agent(&config.user_agent)
+            .build()?;
+
+        Ok(Self {
+            cache_dir: config.image_cache_dir.clone(),
+            ttl: config.image_cache_ttl,
+            http,
+        })
+    }
+}
+
+/// Image served by the proxy.
+pub struct ProxiedImage {
+    /// Content type of the image, `image/png` or `image/jpeg`.
+    pub content_type: &'static str,
+    /// Raw image bytes.
+    pub bytes: Vec<u8>,
+}
+
+/// Service for proxying and caching images from the EVE image server.
+pub struct ImageService<'a> {
+    config: &'a ImageProxyConfig,
+}
+
+impl<'a> ImageService<'a> {
+    /// Creates a new instance of ImageService.
+    ///
+    /// # Arguments
+    /// - `config` - Image proxy settings with the cache directory and TTL
+    ///
+    /// # Returns
+    /// - `ImageService` - New service instance
+    pub fn new(config: &'a ImageProxyConfig) -> Self {
+        Self { config }
+    }
+
+    /// Returns the URL clients load an image from.
+    ///
+    /// The URL points at Bifrost's image endpoint, which serves the image from the cache if
+    /// the proxy is enabled and redirects to the EVE image server otherwise, so the URL is the
+    /// same either way. Clients append a `size` query parameter to request a size other than
+    /// `DEFAULT_IMAGE_SIZE`.
+    ///
+    /// # Arguments
+    /// - `category` - Image category, one of `characters`, `corporations`, `alliances`, or `types`
+    /// - `id` - EVE Online ID of the entity
+    ///
+    /// # Returns
+    /// - `String` - Path of the image on the Bifrost image endpoint
+    pub fn image_url(category: &str, id: i64) -> String {
+        format!("{}/{}/{}", IMAGE_ENDPOINT_PATH, category, id)
+    }
+
+    /// Returns the EVE image server URL of an image.
+    ///
+    /// Used to redirect clients to the image server if the proxy is disabled.
+    ///
+    /// # Arguments
+    /// - `category` - Image category, one of `characters`, `corporations`, `alliances`, or `types`
+    /// - `id` - EVE Online ID of the entity
+    /// - `size` - Image size in pixels
+    ///
+    /// # Returns
+    /// - `Ok(String)` - URL of the image on the EVE image server
+    /// - `Err(AppError)` - Unknown category or unsupported size
+    pub fn upstream_url(category: &str, id: i64, size: u32) -> Result<String, AppError> {
+        let variation = variation(category)?;
+        validate_size(size)?;
+
+        Ok(format!(
+            "{}/{}/{}/{}?size={}",
+            IMAGE_SERVER_URL, category, id, variation, size
+        ))
+    }
+
+    /// Returns an image, from the disk cache if fresh or from the EVE image server otherwise.
+    ///
+    /// Fetched images are written to the cache. If the image server can't be reached a stale
+    /// cached image is served instead, so an outage of the image server doesn't break
+    /// portraits that were viewed before.
+    ///
+    /// # Arguments
+    /// - `category` - Image category, one of `characters`, `corporations`, `alliances`, or `types`
+    /// - `id` - EVE Online ID of the entity
+    /// - `size` - Image size in pixels
+    ///
+    /// # Returns
+    /// - `Ok(Some(ProxiedImage))` - Image bytes and content type
+    /// - `Ok(None)` - Proxy disabled, redirect to `upstream_url` instead
+    /// - `Err(AppError)` - Unknown category, unsupported size, missing image, or fetch failed
+    pub async fn get_image(
+        &self,
+        category: &str,
+        id: i64,
+        size: u32,
+    ) -> Result<Option<ProxiedImage>, AppError> {
+        let url = Self::upstream_url(category, id, size)?;
+        let Some(cache_dir) = &self.config.cache_dir else {
+            return Ok(None);
+        };
+
+        let path = cache_dir.join(category).join(format!("{}-{}", id, size));
+        let cached = match tokio::fs::metadata(&path).await {
+            Ok(metadata) => Some(metadata.modified().ok()),
+            Err(_) => None,
+        };
+
+        if let Some(modified) = cached {
+            let fresh = modified
+                .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+                .is_some_and(|age| age < self.config.ttl);
+
+            if fresh {
+                if let Ok(bytes) = tokio::fs::read(&path).await {
+                    return Ok(Some(ProxiedImage {
+                        content_type: content_type(&bytes),
+                        bytes,
+                    }));
+                }
+            }
+        }
+
+        let bytes = match self.fetch(&url, category, id).await {
+            Ok(bytes) => bytes,
+            Err(e @ AppError::Image(_)) => return Err(e),
+            Err(e) => {
+                // Serve the stale image without rewriting it, so it's refetched next time
+                let Ok(bytes) = tokio::fs::read(&path).await else {
+                    return Err(e);
+                };
+                tracing::warn!(
+                    "Failed to fetch {} {} from the EVE image server, serving stale cached image: {}",
+                    category,
+                    id,
+                    e
+                );
+
+                return Ok(Some(ProxiedImage {
+                    content_type: content_type(&bytes),
+                    bytes,
+                }));
+            }
+        };
+
+        if let Err(e) = write_cache(&path, &bytes).await {
+            tracing::warn!("Failed to cache image {}: {}", path.display(), e);
+        }
+
+        Ok(Some(ProxiedImage {
+            content_type: content_type(&bytes),
+            bytes,
+        }))
+    }
+
+    /// Fetches an image from the EVE image server.
+    async fn fetch(&self, url: &str, category: &str, id: i64) -> Result<Vec<u8>, AppError> {
+        let response = self.config.http.get(url).send().await?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(ImageError::ImageNotFound {
+                category: category.to_string(),
+                id,
+            }
+            .into());
+        }
+
+        Ok(response.error_for_status()?.bytes().await?.to_vec())
+    }
+}
+
+/// Returns the image server variation of a category.
+fn variation(category: &str) -> Result<&'static str, ImageError> {
+    match category {
+        "characters" => Ok("portrait"),
+        "corporations" | "alliances" => Ok("logo"),
+        "types" => Ok("icon"),
+        _ => Err(ImageError::UnknownCategory(category.to_string())),
+    }
+}
+
+/// Checks that the EVE image server offers an image size.
+fn validate_size(size: u32) -> Result<(), ImageError> {
+    if SUPPORTED_SIZES.contains(&size) {
+        Ok(())
+    } else {
+        Err(ImageError::UnsupportedSize(size))
+    }
+}
+
+/// Detects the content type of an image from its magic bytes.
+///
+/// The EVE image server serves portraits as JPEG and logos and icons as PNG.
+fn content_type(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else {
+        "image/png"
+    }
+}
+
+/// Writes an image to the cache.
+///
+/// The image is written to a temporary file first and then renamed, so concurrent requests
+/// never read a partially written image.
+async fn write_cache(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    let temp = path.with_extension(format!(
+        "tmp-{}-{}",
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    tokio::fs::write(&temp, bytes).await?;
+    if let Err(e) = tokio::fs::rename(&temp, path).await {
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expected: Ok with the portrait variation for characters
+    #[test]
+    fn builds_upstream_url() {
+        let url = ImageService::upstream_url("characters", 2114794365, 256).unwrap();
+
+        assert_eq!(
+            url,
+            "https://images.evetech.net/characters/2114794365/portrait?size=256"
+        );
+    }
+
+    /// Expected: Path on the Bifrost image endpoint without a size
+    #[test]
+    fn builds_image_url() {
+        let url = ImageService::image_url("corporations", 98000001);
+
+        assert_eq!(url, "/img/corporations/98000001");
+    }
+
+    /// Expected: Err with UnknownCategory
+    #[test]
+    fn rejects_unknown_category() {
+        let result = ImageService::upstream_url("factions", 500001, 128);
+
+        assert!(matches!(
+            result,
+            Err(AppError::Image(ImageError::UnknownCategory(_)))
+        ));
+    }
+
+    /// Expected: Err with UnsupportedSize
+    #[test]
+    fn rejects_unsupported_size() {
+        let result = ImageService::upstream_url("alliances", 99013537, 100);
+
+        assert!(matches!(
+            result,
+            Err(AppError::Image(ImageError::UnsupportedSize(100)))
+        ));
+    }
+
+    /// Expected: JPEG for JPEG magic bytes and PNG otherwise
+    #[test]
+    fn detects_content_type() {
+        assert_eq!(content_type(&[0xFF, 0xD8, 0xFF, 0xE0]), "image/jpeg");
+        assert_eq!(content_type(&[0x89, b'P', b'N', b'G']), "image/png");
+    }
+}
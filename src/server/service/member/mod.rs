@@ -0,0 +1,401 @@
+//! Member list service layer.
+//!
+//! This module contains the `MemberService` for the admin user and character lists. The lists
+//! are read from the user character summary, so filtering by character name, corporation,
+//! alliance, or tag doesn't join the underlying records. Admins can save the filters they use
+//! often and run bulk actions on the members they selected, which are resolved here into the
+//! worker jobs the caller queues. Every bulk action is written to the info log with the acting
+//! user so it can be audited.
+
+use std::collections::{HashMap, HashSet};
+
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::{
+        annotation::AnnotationSubject,
+        member::{
+            MemberBulkAction, MemberBulkActionDto, MemberCharacterDto, MemberFilterDto, MemberList,
+            MemberUserDto, SaveMemberFilterDto, SavedMemberFilterDto,
+        },
+        push::PushNotificationDto,
+    },
+    server::{
+        data::{
+            annotation::tag::TagRepository, member::MemberFilterRepository,
+            user::summary::UserCharacterSummaryRepository,
+        },
+        error::{member::MemberError, AppError},
+        model::{
+            db::{MemberFilterModel, UserCharacterSummaryModel},
+            worker::WorkerJob,
+        },
+    },
+};
+
+/// Maximum length of a saved filter name, in characters.
+const MAX_FILTER_NAME_LENGTH: usize = 64;
+
+/// Maximum number of members a single bulk action can target.
+const MAX_BULK_MEMBERS: usize = 500;
+
+/// Worker jobs resolved for one member targeted by a bulk action.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemberBulkItem {
+    /// Bifrost user ID or EVE Online character ID of the member.
+    pub id: i64,
+    /// Jobs to queue for the member.
+    pub jobs: Vec<WorkerJob>,
+    /// Reason no jobs could be resolved for the member.
+    pub error: Option<String>,
+}
+
+/// Service for the admin member lists, their saved filters, and bulk actions.
+pub struct MemberService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> MemberService<'a> {
+    /// Creates a new instance of MemberService.
+    ///
+    /// Constructs a service for listing members and running bulk actions.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `MemberService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the users with any character matching the filter.
+    ///
+    /// Each user is listed with their main character, ordered by its name.
+    ///
+    /// # Arguments
+    /// - `filter` - Character name, corporation, alliance, and tag to match
+    ///
+    /// # Returns
+    /// - `Ok(Vec<MemberUserDto>)` - Matching users
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_users(&self, filter: MemberFilterDto) -> Result<Vec<MemberUserDto>, AppError> {
+        let summary_repo = UserCharacterSummaryRepository::new(self.db);
+        let user_ids: HashSet<i32> = self
+            .get_filtered_rows(filter)
+            .await?
+            .into_iter()
+            .map(|row| row.user_id)
+            .collect();
+
+        let mut character_counts: HashMap<i32, u64> = HashMap::new();
+        let mut mains = Vec::new();
+        for row in summary_repo
+            .get_by_user_ids(user_ids.into_iter().collect())
+            .await?
+        {
+            *character_counts.entry(row.user_id).or_default() += 1;
+            if row.is_main {
+                mains.push(row);
+            }
+        }
+
+        Ok(mains
+            .into_iter()
+            .map(|main| MemberUserDto {
+                user_id: main.user_id,
+                character_count: character_counts.get(&main.user_id).copied().unwrap_or(1),
+                main_character_id: main.character_id,
+                main_character_name: main.character_name,
+                corporation_id: main.corporation_id,
+                corporation_name: main.corporation_name,
+                alliance_id: main.alliance_id,
+                alliance_name: main.alliance_name,
+            })
+            .collect())
+    }
+
+    /// Retrieves the owned characters matching the filter, ordered by name.
+    ///
+    /// # Arguments
+    /// - `filter` - Character name, corporation, alliance, and tag to match
+    ///
+    /// # Returns
+    /// - `Ok(Vec<MemberCharacterDto>)` - Matching characters
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_characters(
+        &self,
+        filter: MemberFilterDto,
+    ) -> Result<Vec<MemberCharacterDto>, AppError> {
+        Ok(self
+            .get_filtered_rows(filter)
+            .await?
+            .into_iter()
+            .map(|row| MemberCharacterDto {
+                character_id: row.character_id,
+                character_name: row.character_name,
+                user_id: row.user_id,
+                is_main: row.is_main,
+                corporation_id: row.corporation_id,
+                corporation_name: row.corporation_name,
+                alliance_id: row.alliance_id,
+                alliance_name: row.alliance_name,
+            })
+            .collect())
+    }
+
+    /// Retrieves the filters a user saved, ordered by list and name.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user whose filters to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(Vec<SavedMemberFilterDto>)` - The user's saved filters
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_filters(&self, user_id: i32) -> Result<Vec<SavedMemberFilterDto>, AppError> {
+        Ok(MemberFilterRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .filter_map(filter_to_dto)
+            .collect())
+    }
+
+    /// Saves a filter, replacing the user's filter of the same name for the list.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user saving the filter
+    /// - `filter` - List, name, and criteria of the filter
+    ///
+    /// # Returns
+    /// - `Ok(SavedMemberFilterDto)` - The saved filter
+    /// - `Err(AppError::Member(MemberError::InvalidFilterName))` - Name is empty or too long
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn save_filter(
+        &self,
+        user_id: i32,
+        filter: SaveMemberFilterDto,
+    ) -> Result<SavedMemberFilterDto, AppError> {
+        let name = filter.name.trim();
+        if name.is_empty() || name.chars().count() > MAX_FILTER_NAME_LENGTH {
+            return Err(MemberError::InvalidFilterName(MAX_FILTER_NAME_LENGTH).into());
+        }
+
+        let criteria = serde_json::to_string(&filter.filter)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize member filter: {}", e)))?;
+        let saved = MemberFilterRepository::new(self.db)
+            .upsert(user_id, filter.list.as_str(), name, criteria)
+            .await?;
+
+        Ok(SavedMemberFilterDto {
+            id: saved.id,
+            list: filter.list,
+            name: saved.name,
+            filter: filter.filter,
+            updated_at: saved.updated_at,
+        })
+    }
+
+    /// Deletes one of the user's saved filters.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user deleting the filter
+    /// - `filter_id` - ID of the filter
+    ///
+    /// # Returns
+    /// - `Ok(())` - Filter deleted
+    /// - `Err(AppError::Member(MemberError::FilterNotFound))` - Filter doesn't exist or was saved
+    ///   by another user
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_filter(&self, user_id: i32, filter_id: i32) -> Result<(), AppError> {
+        let filter_repo = MemberFilterRepository::new(self.db);
+        match filter_repo.get_by_id(filter_id).await? {
+            Some(filter) if filter.user_id == user_id => {
+                filter_repo.delete(filter_id).await?;
+
+                Ok(())
+            }
+            _ => Err(MemberError::FilterNotFound(filter_id).into()),
+        }
+    }
+
+    /// Resolves a bulk action into the worker jobs to queue for each selected member.
+    ///
+    /// Force refreshing a user refreshes the info and affiliation of each of their characters.
+    /// Notifications are delivered to the user, or to the owner of the character. Members that
+    /// aren't listed get an error instead of jobs, without failing the rest of the action.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user running the action
+    /// - `action` - List, selected member IDs, and action to run
+    ///
+    /// # Returns
+    /// - `Ok(Vec<MemberBulkItem>)` - Jobs or error per selected member, in selection order
+    /// - `Err(AppError::Member(MemberError::NoMembersSelected))` - No members were selected
+    /// - `Err(AppError::Member(MemberError::TooManyMembers))` - Too many members were selected
+    /// - `Err(AppError::Member(MemberError::EmptyNotificationTitle))` - Notification has no title
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn resolve_bulk_action(
+        &self,
+        user_id: i32,
+        action: MemberBulkActionDto,
+    ) -> Result<Vec<MemberBulkItem>, AppError> {
+        let mut seen = HashSet::new();
+        let ids: Vec<i64> = action
+            .ids
+            .into_iter()
+            .filter(|id| seen.insert(*id))
+            .collect();
+        if ids.is_empty() {
+            return Err(MemberError::NoMembersSelected.into());
+        }
+        if ids.len() > MAX_BULK_MEMBERS {
+            return Err(MemberError::TooManyMembers(MAX_BULK_MEMBERS).into());
+        }
+
+        let notification = match &action.action {
+            MemberBulkAction::ForceRefresh => None,
+            MemberBulkAction::SendNotification { title, body } => {
+                let title = title.trim();
+                if title.is_empty() {
+                    return Err(MemberError::EmptyNotificationTitle.into());
+                }
+
+                Some(PushNotificationDto {
+                    title: title.to_string(),
+                    body: body.trim().to_string(),
+                    url: Some("/auth".to_string()),
+                })
+            }
+        };
+
+        // Group the selected members' characters by the ID they were selected with
+        let summary_repo = UserCharacterSummaryRepository::new(self.db);
+        let mut characters: HashMap<i64, Vec<UserCharacterSummaryModel>> = HashMap::new();
+        match action.list {
+            MemberList::Users => {
+                let user_ids = ids
+                    .iter()
+                    .filter_map(|id| i32::try_from(*id).ok())
+                    .collect();
+                for row in summary_repo.get_by_user_ids(user_ids).await? {
+                    characters.entry(row.user_id as i64).or_default().push(row);
+                }
+            }
+            MemberList::Characters => {
+                for row in summary_repo.get_by_character_ids(ids.clone()).await? {
+                    characters.entry(row.character_id).or_default().push(row);
+                }
+            }
+        }
+
+        let not_found = match action.list {
+            MemberList::Users => "User not found",
+            MemberList::Characters => "Character not found",
+        };
+        let items: Vec<MemberBulkItem> = ids
+            .into_iter()
+            .map(|id| match characters.remove(&id) {
+                Some(rows) => MemberBulkItem {
+                    id,
+                    jobs: match &notification {
+                        Some(notification) => vec![WorkerJob::SendPushNotification {
+                            user_id: rows[0].user_id,
+                            notification: notification.clone(),
+                        }],
+                        None => refresh_jobs(&rows),
+                    },
+                    error: None,
+                },
+                None => MemberBulkItem {
+                    id,
+                    jobs: Vec::new(),
+                    error: Some(not_found.to_string()),
+                },
+            })
+            .collect();
+
+        tracing::info!(
+            user_id = %user_id,
+            list = %action.list.as_str(),
+            action = ?action.action,
+            members = %items.len(),
+            "Ran member bulk action"
+        );
+
+        Ok(items)
+    }
+
+    /// Retrieves the summary rows matching a member list filter.
+    ///
+    /// A tag matches characters carrying it as well as every character of users carrying it.
+    async fn get_filtered_rows(
+        &self,
+        filter: MemberFilterDto,
+    ) -> Result<Vec<UserCharacterSummaryModel>, AppError> {
+        let owners = match filter
+            .tag
+            .as_deref()
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty())
+        {
+            Some(tag) => {
+                let mut user_ids = Vec::new();
+                let mut character_ids = Vec::new();
+                for (subject_type, subject_id) in TagRepository::new(self.db)
+                    .get_subjects_by_name(&tag)
+                    .await?
+                {
+                    match AnnotationSubject::from_name(&subject_type) {
+                        Some(AnnotationSubject::User) => {
+                            user_ids.extend(i32::try_from(subject_id).ok())
+                        }
+                        Some(AnnotationSubject::Character) => character_ids.push(subject_id),
+                        _ => {}
+                    }
+                }
+
+                Some((user_ids, character_ids))
+            }
+            None => None,
+        };
+
+        let name = filter
+            .name
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty());
+
+        Ok(UserCharacterSummaryRepository::new(self.db)
+            .get_filtered(name, filter.corporation_id, filter.alliance_id, owners)
+            .await?)
+    }
+}
+
+/// Builds the jobs refreshing the info and affiliation of the given characters.
+fn refresh_jobs(rows: &[UserCharacterSummaryModel]) -> Vec<WorkerJob> {
+    let mut jobs: Vec<WorkerJob> = rows
+        .iter()
+        .map(|row| WorkerJob::UpdateCharacterInfo {
+            character_id: row.character_id,
+        })
+        .collect();
+    jobs.push(WorkerJob::UpdateAffiliations {
+        character_ids: rows.iter().map(|row| row.character_id).collect(),
+    });
+
+    jobs
+}
+
+/// Converts a stored filter into its DTO, skipping filters for unknown lists.
+fn filter_to_dto(filter: MemberFilterModel) -> Option<SavedMemberFilterDto> {
+    Some(SavedMemberFilterDto {
+        id: filter.id,
+        list: MemberList::from_name(&filter.list)?,
+        name: filter.name,
+        // Criteria that no longer parse, e.g. from manual edits, show as an empty filter
+        filter: serde_json::from_str(&filter.filter).unwrap_or_default(),
+        updated_at: filter.updated_at,
+    })
+}
@@ -1,10 +1,55 @@
 //! Service layer for business logic and orchestration.
 //!
-//! This module contains the service layer that implements business logic, coordinates
-//! between repositories and external APIs, and handles complex multi-step operations.
-//! Services include authentication, EVE Online data management, orchestration for
-//! dependency resolution, retry logic, and user management.
+//! This module contains the service layer that implements business logic, coordinates between
+//! repositories and external APIs, and handles complex multi-step operations. Services include
+//! character affiliation history, admin tags and notes, announcements, approval of sensitive admin
+//! actions by a second admin, authentication, user bans suspending users, deployment campaigns,
+//! character skill snapshots, data-sharing consent, corporation member lists fetched with a
+//! director's token, admin dashboard summaries, the data access API for BI tools, dead-letter job
+//! replay, weekly digests, doctrine and fitting management, streaming admin exports, groups users
+//! join or request to join with affiliation-based eligibility rules, EVE image proxying, admin
+//! member lists with saved filters and bulk actions, the onboarding checklist for new members,
+//! admin-edited pages, webhook notifications of character ownership changes, user preferences, push
+//! notifications, request rate limits, re-authentication campaigns, recruitment listings, admin
+//! roles and permissions, character screening, the sessions users are logged in with, skill plans,
+//! opt-in telemetry, character refresh tokens with automatic rotation, embeddable widgets, EVE
+//! Online data management, orchestration for dependency resolution, retry logic, and user
+//! management.
 
+pub mod affiliation_history;
+pub mod annotation;
+pub mod announcement;
+pub mod approval;
 pub mod auth;
+pub mod ban;
+pub mod campaign;
+pub mod character_skill;
+pub mod consent;
+pub mod corporation_member;
+pub mod dashboard;
+pub mod data_api;
+pub mod dead_letter;
+pub mod digest;
+pub mod doctrine;
 pub mod eve;
+pub mod export;
+pub mod group;
+pub mod image;
+pub mod member;
+pub mod notification;
+pub mod onboarding;
+pub mod page;
+pub mod preference;
+pub mod push;
+pub mod rate_limit;
+pub mod reauth_campaign;
+pub mod recruitment;
+pub mod role;
+pub mod screening;
+pub mod search;
+pub mod session;
+pub mod skill_plan;
+pub mod telemetry;
+pub mod token;
 pub mod user;
+pub mod widget;
@@ -0,0 +1,280 @@
+//! Notification service layer.
+//!
+//! This module contains the `NotificationService` for the webhooks admins configure to be
+//! notified when a character is linked to a user, transferred between users, or made a user's
+//! main. Notifications are delivered by `SendWebhook` worker jobs, so a slow or unreachable
+//! webhook target never holds up a login and rejected deliveries are retried.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::webhook::{CreateWebhookDto, UpdateWebhookDto, WebhookDto, WebhookEvent},
+    server::{
+        data::webhook::WebhookRepository,
+        error::{webhook::WebhookError, AppError},
+        model::{db::WebhookModel, worker::WorkerJob},
+        worker::WorkerQueue,
+    },
+};
+
+/// A change of character ownership webhooks are notified of.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnershipChange {
+    /// Kind of the change
+    pub event: WebhookEvent,
+    /// EVE Online ID of the character
+    pub character_id: i64,
+    /// Name of the character
+    pub character_name: String,
+    /// ID of the user owning the character after the change
+    pub user_id: i32,
+    /// ID of the user who owned the character before a transfer
+    pub previous_user_id: Option<i32>,
+}
+
+impl OwnershipChange {
+    /// Returns the message webhooks are notified with, formatted as Markdown.
+    pub fn message(&self) -> String {
+        let character = format!("**{}** ({})", self.character_name, self.character_id);
+
+        match (self.event, self.previous_user_id) {
+            (WebhookEvent::CharacterTransferred, Some(previous_user_id)) => format!(
+                "{} was transferred from user {} to user {}",
+                character, previous_user_id, self.user_id
+            ),
+            (WebhookEvent::CharacterTransferred, None) => {
+                format!("{} was transferred to user {}", character, self.user_id)
+            }
+            (WebhookEvent::CharacterLinked, _) => {
+                format!("{} was linked to user {}", character, self.user_id)
+            }
+            (WebhookEvent::MainChanged, _) => format!(
+                "User {} changed their main character to {}",
+                self.user_id, character
+            ),
+        }
+    }
+}
+
+/// Service for managing webhooks and notifying them of character ownership changes.
+pub struct NotificationService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> NotificationService<'a> {
+    /// Creates a new instance of NotificationService.
+    ///
+    /// Constructs a service for managing webhooks and notifying them.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `NotificationService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates an enabled webhook.
+    ///
+    /// # Arguments
+    /// - `webhook` - Name, HTTPS URL, and events of the webhook
+    ///
+    /// # Returns
+    /// - `Ok(WebhookDto)` - The created webhook with duplicate events removed
+    /// - `Err(AppError::Webhook(WebhookError::InvalidWebhook))` - Name is empty, URL isn't an
+    ///   HTTPS URL, or no events are given
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_webhook(&self, webhook: CreateWebhookDto) -> Result<WebhookDto, AppError> {
+        let (name, url, events) = validate_webhook(webhook.name, webhook.url, webhook.events)?;
+
+        let created = WebhookRepository::new(self.db)
+            .create(name, url, events)
+            .await?;
+
+        Ok(webhook_to_dto(created))
+    }
+
+    /// Retrieves all webhooks.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<WebhookDto>)` - All webhooks ordered by ID
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_webhooks(&self) -> Result<Vec<WebhookDto>, AppError> {
+        Ok(WebhookRepository::new(self.db)
+            .get_all()
+            .await?
+            .into_iter()
+            .map(webhook_to_dto)
+            .collect())
+    }
+
+    /// Updates a webhook.
+    ///
+    /// # Arguments
+    /// - `webhook_id` - ID of the webhook
+    /// - `webhook` - Name, HTTPS URL, events, and whether the webhook is enabled
+    ///
+    /// # Returns
+    /// - `Ok(WebhookDto)` - The updated webhook
+    /// - `Err(AppError::Webhook(WebhookError::InvalidWebhook))` - Name is empty, URL isn't an
+    ///   HTTPS URL, or no events are given
+    /// - `Err(AppError::Webhook(WebhookError::WebhookNotFound))` - Webhook doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn update_webhook(
+        &self,
+        webhook_id: i32,
+        webhook: UpdateWebhookDto,
+    ) -> Result<WebhookDto, AppError> {
+        let (name, url, events) = validate_webhook(webhook.name, webhook.url, webhook.events)?;
+
+        let updated = WebhookRepository::new(self.db)
+            .update(webhook_id, name, url, events, webhook.enabled)
+            .await?
+            .ok_or(WebhookError::WebhookNotFound(webhook_id))?;
+
+        Ok(webhook_to_dto(updated))
+    }
+
+    /// Deletes a webhook.
+    ///
+    /// Notifications already queued for the webhook are dropped when their job runs.
+    ///
+    /// # Arguments
+    /// - `webhook_id` - ID of the webhook
+    ///
+    /// # Returns
+    /// - `Ok(())` - Webhook deleted
+    /// - `Err(AppError::Webhook(WebhookError::WebhookNotFound))` - Webhook doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_webhook(&self, webhook_id: i32) -> Result<(), AppError> {
+        if !WebhookRepository::new(self.db).delete(webhook_id).await? {
+            return Err(WebhookError::WebhookNotFound(webhook_id).into());
+        }
+
+        Ok(())
+    }
+
+    /// Builds the `SendWebhook` jobs notifying webhooks of an ownership change.
+    ///
+    /// # Arguments
+    /// - `change` - The ownership change
+    ///
+    /// # Returns
+    /// - `Ok(Vec<WorkerJob>)` - A job for each enabled webhook subscribed to the change's event
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_webhook_jobs(
+        &self,
+        change: &OwnershipChange,
+    ) -> Result<Vec<WorkerJob>, AppError> {
+        let content = change.message();
+
+        Ok(WebhookRepository::new(self.db)
+            .get_enabled()
+            .await?
+            .into_iter()
+            .filter(|webhook| parse_events(&webhook.events).contains(&change.event))
+            .map(|webhook| WorkerJob::SendWebhook {
+                webhook_id: webhook.id,
+                event: change.event,
+                content: content.clone(),
+            })
+            .collect())
+    }
+
+    /// Queues notifications of ownership changes for the subscribed webhooks.
+    ///
+    /// # Arguments
+    /// - `queue` - Worker queue the `SendWebhook` jobs are pushed to
+    /// - `changes` - The ownership changes
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of notifications queued
+    /// - `Err(AppError::Database)` - Database query failed
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn notify(
+        &self,
+        queue: &WorkerQueue,
+        changes: &[OwnershipChange],
+    ) -> Result<usize, AppError> {
+        let mut queued = 0;
+
+        for change in changes {
+            for job in self.get_webhook_jobs(change).await? {
+                if queue.push(job).await? {
+                    queued += 1;
+                }
+            }
+        }
+
+        Ok(queued)
+    }
+}
+
+/// Validates a webhook's input and serializes its events for storage.
+///
+/// # Returns
+/// - `Ok((String, String, String))` - Trimmed name, URL, and space-separated events with
+///   duplicates removed
+/// - `Err(WebhookError::InvalidWebhook)` - Name is empty, URL isn't an HTTPS URL, or no events
+///   are given
+fn validate_webhook(
+    name: String,
+    url: String,
+    events: Vec<WebhookEvent>,
+) -> Result<(String, String, String), WebhookError> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(WebhookError::InvalidWebhook(
+            "Webhook name must not be empty".to_string(),
+        ));
+    }
+
+    let url = url.trim().to_string();
+    if !reqwest::Url::parse(&url).is_ok_and(|url| url.scheme() == "https") {
+        return Err(WebhookError::InvalidWebhook(
+            "Webhook URL must be an HTTPS URL".to_string(),
+        ));
+    }
+
+    let mut unique: Vec<WebhookEvent> = Vec::new();
+    for event in events {
+        if !unique.contains(&event) {
+            unique.push(event);
+        }
+    }
+    if unique.is_empty() {
+        return Err(WebhookError::InvalidWebhook(
+            "Webhook must be notified of at least one event".to_string(),
+        ));
+    }
+
+    let events = unique
+        .iter()
+        .map(WebhookEvent::as_str)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Ok((name, url, events))
+}
+
+/// Parses the space-separated events of a stored webhook, skipping unknown events.
+fn parse_events(events: &str) -> Vec<WebhookEvent> {
+    events
+        .split_whitespace()
+        .filter_map(WebhookEvent::from_name)
+        .collect()
+}
+
+/// Converts a stored webhook into its DTO.
+fn webhook_to_dto(webhook: WebhookModel) -> WebhookDto {
+    WebhookDto {
+        id: webhook.id,
+        events: parse_events(&webhook.events),
+        name: webhook.name,
+        url: webhook.url,
+        enabled: webhook.enabled,
+        created_at: webhook.created_at,
+        updated_at: webhook.updated_at,
+    }
+}
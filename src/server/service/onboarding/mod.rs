@@ -0,0 +1,378 @@
+//! Onboarding service layer.
+//!
+//! This module contains the `OnboardingService` for the checklist admins define for new
+//! members. Steps are completed automatically where Bifrost already has the data: linking alts
+//! is complete once a user owns more than one character, and granting scopes is recorded by
+//! the `add_scopes` login that grants them. Reading a page, joining Discord, and other steps
+//! are marked completed by the user. Officers can list the users who haven't completed every
+//! step.
+
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::onboarding::{
+        CreateOnboardingStepDto, IncompleteOnboardingDto, OnboardingChecklistItemDto,
+        OnboardingStepDto, OnboardingStepKind,
+    },
+    server::{
+        data::{
+            onboarding::OnboardingRepository,
+            page::PageRepository,
+            user::{
+                summary::UserCharacterSummaryRepository, user_character::UserCharacterRepository,
+                UserRepository,
+            },
+        },
+        error::{onboarding::OnboardingError, AppError},
+        model::db::OnboardingStepModel,
+    },
+};
+
+/// Number of characters a user must own for the `link_alts` step to be complete.
+const LINKED_ALTS_CHARACTER_COUNT: i64 = 2;
+
+/// Service for defining onboarding steps and tracking their completion.
+pub struct OnboardingService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> OnboardingService<'a> {
+    /// Creates a new instance of OnboardingService.
+    ///
+    /// Constructs a service for managing the onboarding checklist.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `OnboardingService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Defines a step at the end of the onboarding checklist.
+    ///
+    /// Steps granting scopes need at least one scope, with duplicates removed, steps reading a
+    /// page need the slug of an existing page, and steps joining Discord need an HTTPS invite
+    /// URL. Other steps ignore the target.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the admin defining the step
+    /// - `step` - Title, description, kind, and target of the step
+    ///
+    /// # Returns
+    /// - `Ok(OnboardingStepDto)` - The created step
+    /// - `Err(AppError::Onboarding(OnboardingError::InvalidStep))` - Empty title, or missing or
+    ///   invalid target for the step's kind
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_step(
+        &self,
+        user_id: i32,
+        step: CreateOnboardingStepDto,
+    ) -> Result<OnboardingStepDto, AppError> {
+        let title = step.title.trim().to_string();
+        if title.is_empty() {
+            return Err(OnboardingError::InvalidStep("title must not be empty".to_string()).into());
+        }
+
+        let target = step
+            .target
+            .as_deref()
+            .map(str::trim)
+            .filter(|target| !target.is_empty());
+        let target = match step.kind {
+            OnboardingStepKind::LinkAlts | OnboardingStepKind::Manual => None,
+            OnboardingStepKind::GrantScopes => {
+                let mut scopes: Vec<&str> = Vec::new();
+                for scope in target.into_iter().flat_map(str::split_whitespace) {
+                    if !scopes.contains(&scope) {
+                        scopes.push(scope);
+                    }
+                }
+                if scopes.is_empty() {
+                    return Err(OnboardingError::InvalidStep(
+                        "at least one scope is required".to_string(),
+                    )
+                    .into());
+                }
+
+                Some(scopes.join(" "))
+            }
+            OnboardingStepKind::ReadPage => {
+                let slug = target.ok_or_else(|| {
+                    OnboardingError::InvalidStep("a page slug is required".to_string())
+                })?;
+                if PageRepository::new(self.db)
+                    .get_by_slug(slug)
+                    .await?
+                    .is_none()
+                {
+                    return Err(
+                        OnboardingError::InvalidStep(format!("page {:?} not found", slug)).into(),
+                    );
+                }
+
+                Some(slug.to_string())
+            }
+            OnboardingStepKind::JoinDiscord => match target {
+                Some(url) if url.starts_with("https://") => Some(url.to_string()),
+                _ => {
+                    return Err(OnboardingError::InvalidStep(
+                        "an HTTPS invite URL is required".to_string(),
+                    )
+                    .into())
+                }
+            },
+        };
+
+        let created = OnboardingRepository::new(self.db)
+            .create_step(
+                title,
+                step.description.trim().to_string(),
+                step.kind.as_str(),
+                target,
+                user_id,
+            )
+            .await?;
+
+        Ok(step_to_dto(created, step.kind))
+    }
+
+    /// Retrieves all onboarding steps in checklist order.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<OnboardingStepDto>)` - All steps
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_steps(&self) -> Result<Vec<OnboardingStepDto>, AppError> {
+        Ok(OnboardingRepository::new(self.db)
+            .get_steps()
+            .await?
+            .into_iter()
+            .filter_map(|step| {
+                let kind = OnboardingStepKind::from_name(&step.kind)?;
+
+                Some(step_to_dto(step, kind))
+            })
+            .collect())
+    }
+
+    /// Deletes an onboarding step along with its completions.
+    ///
+    /// # Arguments
+    /// - `step_id` - ID of the step
+    ///
+    /// # Returns
+    /// - `Ok(())` - Step deleted
+    /// - `Err(AppError::Onboarding(OnboardingError::StepNotFound))` - Step doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_step(&self, step_id: i32) -> Result<(), AppError> {
+        let result = OnboardingRepository::new(self.db)
+            .delete_step(step_id)
+            .await?;
+        if result.rows_affected == 0 {
+            return Err(OnboardingError::StepNotFound(step_id).into());
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves a user's onboarding checklist.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<OnboardingChecklistItemDto>)` - Every step in checklist order with whether the
+    ///   user completed it
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_checklist(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<OnboardingChecklistItemDto>, AppError> {
+        let steps = self.get_steps().await?;
+        if steps.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let completions: HashMap<i32, _> = OnboardingRepository::new(self.db)
+            .get_completions_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .map(|completion| (completion.step_id, completion.completed_at))
+            .collect();
+        let character_count = UserCharacterRepository::new(self.db)
+            .get_ownerships_by_user_id(user_id)
+            .await?
+            .len() as i64;
+
+        Ok(steps
+            .into_iter()
+            .map(|step| {
+                let completed_at = completions.get(&step.id).copied();
+                let completed = match step.kind {
+                    OnboardingStepKind::LinkAlts => character_count >= LINKED_ALTS_CHARACTER_COUNT,
+                    _ => completed_at.is_some(),
+                };
+
+                OnboardingChecklistItemDto {
+                    step,
+                    completed,
+                    completed_at,
+                }
+            })
+            .collect())
+    }
+
+    /// Marks a step the user reports completing themselves as completed.
+    ///
+    /// Completing a step again keeps the time it was first completed.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `step_id` - ID of the step
+    ///
+    /// # Returns
+    /// - `Ok(())` - Step completed
+    /// - `Err(AppError::Onboarding(OnboardingError::StepNotFound))` - Step doesn't exist
+    /// - `Err(AppError::Onboarding(OnboardingError::NotSelfReported))` - Step is completed
+    ///   automatically
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn complete_step(&self, user_id: i32, step_id: i32) -> Result<(), AppError> {
+        let onboarding_repo = OnboardingRepository::new(self.db);
+        let step = onboarding_repo
+            .get_step(step_id)
+            .await?
+            .ok_or(OnboardingError::StepNotFound(step_id))?;
+
+        if !OnboardingStepKind::from_name(&step.kind).is_some_and(|kind| kind.is_self_reported()) {
+            return Err(OnboardingError::NotSelfReported(step_id).into());
+        }
+
+        onboarding_repo.complete(user_id, &[step_id]).await?;
+
+        Ok(())
+    }
+
+    /// Completes the steps granting scopes that were all granted by a login.
+    ///
+    /// Called after a successful `add_scopes` login, so steps are completed by granting their
+    /// scopes with any of the user's characters.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user who logged in
+    /// - `granted_scopes` - ESI scopes granted during the login
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of steps completed by the login
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn complete_for_scopes(
+        &self,
+        user_id: i32,
+        granted_scopes: &[String],
+    ) -> Result<u64, AppError> {
+        let onboarding_repo = OnboardingRepository::new(self.db);
+
+        let completed_ids: Vec<i32> = onboarding_repo
+            .get_steps()
+            .await?
+            .into_iter()
+            .filter(|step| step.kind == OnboardingStepKind::GrantScopes.as_str())
+            .filter(|step| {
+                step.target
+                    .as_deref()
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .all(|scope| granted_scopes.iter().any(|granted| granted == scope))
+            })
+            .map(|step| step.id)
+            .collect();
+
+        Ok(onboarding_repo.complete(user_id, &completed_ids).await?)
+    }
+
+    /// Retrieves the users who haven't completed every onboarding step, ordered by user ID.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<IncompleteOnboardingDto>)` - Users with their main character and the titles
+    ///   of the steps they're missing (empty if no steps are defined)
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_incomplete(&self) -> Result<Vec<IncompleteOnboardingDto>, AppError> {
+        let steps = self.get_steps().await?;
+        if steps.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let completions: HashSet<(i32, i32)> = OnboardingRepository::new(self.db)
+            .get_all_completions()
+            .await?
+            .into_iter()
+            .collect();
+        let character_counts: HashMap<i32, i64> = UserCharacterRepository::new(self.db)
+            .count_by_user()
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut user_ids = UserRepository::new(self.db).get_all_ids().await?;
+        user_ids.sort_unstable();
+
+        let mut incomplete = Vec::new();
+        for user_id in user_ids {
+            let missing_steps: Vec<String> = steps
+                .iter()
+                .filter(|step| match step.kind {
+                    OnboardingStepKind::LinkAlts => {
+                        character_counts.get(&user_id).copied().unwrap_or_default()
+                            < LINKED_ALTS_CHARACTER_COUNT
+                    }
+                    _ => !completions.contains(&(step.id, user_id)),
+                })
+                .map(|step| step.title.clone())
+                .collect();
+            if missing_steps.is_empty() {
+                continue;
+            }
+
+            incomplete.push(IncompleteOnboardingDto {
+                user_id,
+                main_character_id: None,
+                main_character_name: None,
+                completed_count: (steps.len() - missing_steps.len()) as u64,
+                step_count: steps.len() as u64,
+                missing_steps,
+            });
+        }
+
+        let mains: HashMap<i32, (i64, String)> = UserCharacterSummaryRepository::new(self.db)
+            .get_by_user_ids(incomplete.iter().map(|user| user.user_id).collect())
+            .await?
+            .into_iter()
+            .filter(|row| row.is_main)
+            .map(|row| (row.user_id, (row.character_id, row.character_name)))
+            .collect();
+        for user in incomplete.iter_mut() {
+            if let Some((character_id, character_name)) = mains.get(&user.user_id) {
+                user.main_character_id = Some(*character_id);
+                user.main_character_name = Some(character_name.clone());
+            }
+        }
+
+        Ok(incomplete)
+    }
+}
+
+/// Converts a stored onboarding step into its DTO.
+fn step_to_dto(step: OnboardingStepModel, kind: OnboardingStepKind) -> OnboardingStepDto {
+    OnboardingStepDto {
+        id: step.id,
+        title: step.title,
+        description: step.description,
+        kind,
+        target: step.target,
+        position: step.position,
+        created_by_user_id: step.created_by_user_id,
+        created_at: step.created_at,
+    }
+}
@@ -0,0 +1,246 @@
+//! Page service layer.
+//!
+//! This module contains the `PageService` for the Markdown pages admins publish for SRP rules,
+//! recruitment info, and other corp information. Every save records a revision, so earlier
+//! versions of a page can be reviewed and restored. Pages are rendered to HTML on read so the
+//! renderer can change without migrating stored content.
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
+
+use crate::{
+    model::page::{PageDto, PageRevisionDto, PageSummaryDto, SavePageDto},
+    server::{
+        data::page::PageRepository,
+        error::{page::PageError, AppError},
+        model::db::{PageModel, PageRevisionModel},
+        util::markdown::render_markdown,
+    },
+};
+
+/// Maximum length of a page slug.
+const MAX_SLUG_LENGTH: usize = 64;
+
+/// Service for managing pages and their revisions.
+pub struct PageService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> PageService<'a> {
+    /// Creates a new instance of PageService.
+    ///
+    /// Constructs a service for managing pages.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `PageService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the slug and title of all pages.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PageSummaryDto>)` - All pages ordered by title
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn get_pages(&self) -> Result<Vec<PageSummaryDto>, AppError> {
+        Ok(PageRepository::new(self.db)
+            .get_all()
+            .await?
+            .into_iter()
+            .map(|page| PageSummaryDto {
+                slug: page.slug,
+                title: page.title,
+                updated_at: page.updated_at,
+            })
+            .collect())
+    }
+
+    /// Retrieves a page with its content rendered as HTML.
+    ///
+    /// # Arguments
+    /// - `slug` - URL segment of the page
+    ///
+    /// # Returns
+    /// - `Ok(PageDto)` - The page with its Markdown and rendered HTML
+    /// - `Err(AppError::Page(PageError::PageNotFound))` - Page does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn get_page(&self, slug: &str) -> Result<PageDto, AppError> {
+        let page = find_page(&PageRepository::new(self.db), slug).await?;
+
+        Ok(page_to_dto(page))
+    }
+
+    /// Creates or updates a page and records the saved version as a revision.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user saving the page
+    /// - `slug` - URL segment of the page
+    /// - `page` - New title and Markdown content
+    ///
+    /// # Returns
+    /// - `Ok(PageDto)` - The saved page
+    /// - `Err(AppError::Page(PageError::InvalidSlug))` - Slug is empty, too long, or has
+    ///   characters other than lowercase letters, digits, and hyphens
+    /// - `Err(AppError::Page(PageError::EmptyTitle))` - Title is empty
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn save_page(
+        &self,
+        user_id: i32,
+        slug: &str,
+        page: SavePageDto,
+    ) -> Result<PageDto, AppError> {
+        validate_slug(slug)?;
+
+        let title = page.title.trim().to_string();
+        if title.is_empty() {
+            return Err(PageError::EmptyTitle.into());
+        }
+
+        let txn = self.db.begin().await?;
+        let page_repo = PageRepository::new(&txn);
+
+        let page = page_repo.upsert(slug, title, page.content).await?;
+        page_repo.create_revision(&page, user_id).await?;
+
+        txn.commit().await?;
+
+        Ok(page_to_dto(page))
+    }
+
+    /// Deletes a page along with its revisions.
+    ///
+    /// # Arguments
+    /// - `slug` - URL segment of the page
+    ///
+    /// # Returns
+    /// - `Ok(())` - Page deleted
+    /// - `Err(AppError::Page(PageError::PageNotFound))` - Page does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_page(&self, slug: &str) -> Result<(), AppError> {
+        let page_repo = PageRepository::new(self.db);
+        let page = find_page(&page_repo, slug).await?;
+
+        page_repo.delete(page.id).await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the revisions of a page, newest first.
+    ///
+    /// # Arguments
+    /// - `slug` - URL segment of the page
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PageRevisionDto>)` - Every saved version of the page
+    /// - `Err(AppError::Page(PageError::PageNotFound))` - Page does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn get_revisions(&self, slug: &str) -> Result<Vec<PageRevisionDto>, AppError> {
+        let page_repo = PageRepository::new(self.db);
+        let page = find_page(&page_repo, slug).await?;
+
+        Ok(page_repo
+            .get_revisions(page.id)
+            .await?
+            .into_iter()
+            .map(revision_to_dto)
+            .collect())
+    }
+
+    /// Restores the title and content of a page from one of its revisions.
+    ///
+    /// The restore is saved as a new revision, so it can itself be undone.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user restoring the revision
+    /// - `slug` - URL segment of the page
+    /// - `revision_id` - ID of the revision to restore
+    ///
+    /// # Returns
+    /// - `Ok(PageDto)` - The restored page
+    /// - `Err(AppError::Page(PageError::PageNotFound))` - Page does not exist
+    /// - `Err(AppError::Page(PageError::RevisionNotFound))` - Revision does not exist for the
+    ///   page
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn restore_revision(
+        &self,
+        user_id: i32,
+        slug: &str,
+        revision_id: i32,
+    ) -> Result<PageDto, AppError> {
+        let txn = self.db.begin().await?;
+        let page_repo = PageRepository::new(&txn);
+
+        let page = find_page(&page_repo, slug).await?;
+        let revision = page_repo
+            .get_revision(page.id, revision_id)
+            .await?
+            .ok_or(PageError::RevisionNotFound(revision_id))?;
+
+        let page = page_repo
+            .upsert(slug, revision.title, revision.content)
+            .await?;
+        page_repo.create_revision(&page, user_id).await?;
+
+        txn.commit().await?;
+
+        Ok(page_to_dto(page))
+    }
+}
+
+/// Retrieves a page by slug, failing if it does not exist.
+///
+/// # Returns
+/// - `Ok(PageModel)` - Page found
+/// - `Err(AppError::Page(PageError::PageNotFound))` - Page does not exist
+/// - `Err(AppError::Database)` - Database query failed
+async fn find_page<C: ConnectionTrait>(
+    page_repo: &PageRepository<'_, C>,
+    slug: &str,
+) -> Result<PageModel, AppError> {
+    page_repo
+        .get_by_slug(slug)
+        .await?
+        .ok_or_else(|| PageError::PageNotFound(slug.to_string()).into())
+}
+
+/// Checks that a slug is 1 to 64 lowercase letters, digits, or hyphens.
+///
+/// # Returns
+/// - `Ok(())` - Slug is valid
+/// - `Err(AppError::Page(PageError::InvalidSlug))` - Slug is invalid
+fn validate_slug(slug: &str) -> Result<(), AppError> {
+    let valid = (1..=MAX_SLUG_LENGTH).contains(&slug.len())
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(PageError::InvalidSlug(slug.to_string()).into())
+    }
+}
+
+/// Converts a stored page into its DTO, rendering its content as HTML.
+fn page_to_dto(page: PageModel) -> PageDto {
+    PageDto {
+        html: render_markdown(&page.content),
+        slug: page.slug,
+        title: page.title,
+        content: page.content,
+        updated_at: page.updated_at,
+    }
+}
+
+/// Converts a stored page revision into its DTO.
+fn revision_to_dto(revision: PageRevisionModel) -> PageRevisionDto {
+    PageRevisionDto {
+        id: revision.id,
+        title: revision.title,
+        content: revision.content,
+        edited_by_user_id: revision.edited_by_user_id,
+        created_at: revision.created_at,
+    }
+}
@@ -0,0 +1,114 @@
+//! User preference service layer.
+//!
+//! This module contains the `PreferenceService` for reading and saving per-user preferences.
+//! Users who have never saved preferences get the defaults, which include receiving the weekly
+//! digest, and widget names stored by older versions that are no longer known are skipped when
+//! the layout is read.
+
+use std::collections::HashSet;
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::preference::{DashboardWidget, UserPreferencesDto},
+    server::{
+        data::preference::UserPreferenceRepository,
+        error::{preference::PreferenceError, AppError},
+    },
+};
+
+/// Service for managing user preferences.
+pub struct PreferenceService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> PreferenceService<'a> {
+    /// Creates a new instance of PreferenceService.
+    ///
+    /// Constructs a service for managing user preferences.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `PreferenceService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves the preferences of a user.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(UserPreferencesDto)` - Saved preferences, or the defaults if none were saved
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_preferences(&self, user_id: i32) -> Result<UserPreferencesDto, AppError> {
+        let preferences = match UserPreferenceRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?
+        {
+            Some(preference) => UserPreferencesDto {
+                dashboard_layout: preference
+                    .dashboard_layout
+                    .split(',')
+                    .filter_map(DashboardWidget::from_name)
+                    .collect(),
+                weekly_digest: preference.weekly_digest,
+            },
+            None => UserPreferencesDto {
+                dashboard_layout: DashboardWidget::DEFAULT_LAYOUT.to_vec(),
+                weekly_digest: true,
+            },
+        };
+
+        Ok(preferences)
+    }
+
+    /// Saves the preferences of a user.
+    ///
+    /// The dashboard layout lists the widgets to show in display order. An empty layout is
+    /// allowed and hides every widget.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `preferences` - Preferences to save
+    ///
+    /// # Returns
+    /// - `Ok(UserPreferencesDto)` - The saved preferences
+    /// - `Err(AppError::Preference(PreferenceError::InvalidDashboardLayout))` - Layout lists a
+    ///   widget more than once
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn update_preferences(
+        &self,
+        user_id: i32,
+        preferences: UserPreferencesDto,
+    ) -> Result<UserPreferencesDto, AppError> {
+        let mut seen = HashSet::new();
+        if let Some(widget) = preferences
+            .dashboard_layout
+            .iter()
+            .find(|widget| !seen.insert(**widget))
+        {
+            return Err(PreferenceError::InvalidDashboardLayout(format!(
+                "widget {} is listed more than once",
+                widget.as_str()
+            ))
+            .into());
+        }
+
+        let dashboard_layout = preferences
+            .dashboard_layout
+            .iter()
+            .map(|widget| widget.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        UserPreferenceRepository::new(self.db)
+            .upsert(user_id, &dashboard_layout, preferences.weekly_digest)
+            .await?;
+
+        Ok(preferences)
+    }
+}
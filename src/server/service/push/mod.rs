@@ -0,0 +1,274 @@
+//! Push notification service layer.
+//!
+//! This module contains the `PushService` for managing Web Push subscriptions and delivering
+//! notifications to them. Notifications reach the user's browsers and phones through their
+//! push service even when Bifrost is not open. Push notifications are only available when
+//! `VAPID_PRIVATE_KEY` is configured.
+
+use chrono::{Duration, Utc};
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::push::{CreatePushSubscriptionDto, PushConfigDto, PushNotificationDto},
+    server::{
+        config::Config,
+        data::push::PushSubscriptionRepository,
+        error::{push::PushError, AppError},
+        model::db::PushSubscriptionModel,
+        util::web_push::{
+            encrypt_payload, endpoint_origin, validate_subscription_keys, VapidKey, WebPushError,
+        },
+    },
+};
+
+/// How long push services keep undelivered messages while a device is offline (1 day).
+const PUSH_TTL_SECS: u32 = 86400;
+
+/// Lifetime of the VAPID token sent with each message, push services reject more than 24 hours.
+const VAPID_TOKEN_LIFETIME_HOURS: i64 = 12;
+
+/// Web Push settings derived from the server configuration at startup.
+///
+/// Kept separate from `Config` so it can be shared with handlers and the worker without
+/// exposing other secrets.
+#[derive(Clone, Debug, Default)]
+pub struct PushConfig {
+    /// VAPID key used to sign messages, `None` if push notifications are disabled.
+    pub vapid_key: Option<VapidKey>,
+    /// Contact URI sent to push services in VAPID tokens.
+    pub subject: String,
+}
+
+impl PushConfig {
+    /// Builds Web Push settings from the server configuration.
+    ///
+    /// # Arguments
+    /// - `config` - Server configuration loaded from environment variables
+    ///
+    /// # Returns
+    /// - `PushConfig` - VAPID key and the contact email as `mailto:` subject
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            vapid_key: config.vapid_key.clone(),
+            subject: format!("mailto:{}", config.contact_email),
+        }
+    }
+}
+
+/// Result of sending a message to a single subscription.
+enum DeliveryOutcome {
+    /// Push service accepted the message.
+    Delivered,
+    /// Push service reported the subscription as expired or unsubscribed.
+    Expired,
+    /// Push service rejected the message with the given status.
+    Rejected(reqwest::StatusCode),
+}
+
+/// Service for managing push subscriptions and delivering push notifications.
+pub struct PushService<'a> {
+    db: &'a DatabaseConnection,
+    config: &'a PushConfig,
+}
+
+impl<'a> PushService<'a> {
+    /// Creates a new instance of PushService.
+    ///
+    /// Constructs a service for managing push subscriptions and delivering notifications.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    /// - `config` - Web Push settings
+    ///
+    /// # Returns
+    /// - `PushService` - New service instance
+    pub fn new(db: &'a DatabaseConnection, config: &'a PushConfig) -> Self {
+        Self { db, config }
+    }
+
+    /// Retrieves the settings browsers need to subscribe.
+    ///
+    /// # Returns
+    /// - `PushConfigDto` - VAPID public key, `None` if push notifications are disabled
+    pub fn get_config(&self) -> PushConfigDto {
+        PushConfigDto {
+            vapid_public_key: self
+                .config
+                .vapid_key
+                .as_ref()
+                .map(VapidKey::public_key_base64),
+        }
+    }
+
+    /// Subscribes a browser to push notifications for a user.
+    ///
+    /// Subscribing an endpoint again replaces its keys, and moves it to the user if another
+    /// user subscribed it before.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the subscribing user
+    /// - `subscription` - Subscription created by the browser's push manager
+    ///
+    /// # Returns
+    /// - `Ok(())` - Subscription saved
+    /// - `Err(AppError::Push(PushError::PushDisabled))` - `VAPID_PRIVATE_KEY` is not configured
+    /// - `Err(AppError::Push(PushError::InvalidSubscription))` - Endpoint or keys are malformed
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn subscribe(
+        &self,
+        user_id: i32,
+        subscription: CreatePushSubscriptionDto,
+    ) -> Result<(), AppError> {
+        if self.config.vapid_key.is_none() {
+            return Err(PushError::PushDisabled.into());
+        }
+
+        let endpoint = subscription.endpoint.trim();
+        let p256dh = subscription.keys.p256dh.trim();
+        let auth = subscription.keys.auth.trim();
+
+        endpoint_origin(endpoint)
+            .and_then(|_| validate_subscription_keys(p256dh, auth))
+            .map_err(|e| match e {
+                WebPushError::InvalidSubscription(reason) => PushError::InvalidSubscription(reason),
+                e => PushError::InvalidSubscription(e.to_string()),
+            })?;
+
+        PushSubscriptionRepository::new(self.db)
+            .upsert(user_id, endpoint, p256dh, auth)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Unsubscribes a browser of a user from push notifications.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user owning the subscription
+    /// - `endpoint` - Push service URL of the subscription
+    ///
+    /// # Returns
+    /// - `Ok(())` - Subscription deleted
+    /// - `Err(AppError::Push(PushError::SubscriptionNotFound))` - User has no subscription for
+    ///   the endpoint
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn unsubscribe(&self, user_id: i32, endpoint: &str) -> Result<(), AppError> {
+        let result = PushSubscriptionRepository::new(self.db)
+            .delete_by_endpoint(user_id, endpoint.trim())
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(PushError::SubscriptionNotFound { user_id }.into());
+        }
+
+        Ok(())
+    }
+
+    /// Sends a notification to every subscription of a user.
+    ///
+    /// Subscriptions the push service reports as expired are deleted. Other delivery failures
+    /// are logged rather than returned, so one unreachable push service doesn't cause the
+    /// notification to be sent again to the user's other devices on retry.
+    ///
+    /// # Arguments
+    /// - `client` - HTTP client used to contact push services
+    /// - `user_id` - ID of the user to notify
+    /// - `notification` - Notification shown by the service worker
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of subscriptions the notification was delivered to, 0 if push
+    ///   notifications are disabled
+    /// - `Err(AppError::Database)` - Database operation failed
+    /// - `Err(AppError::Internal)` - Notification could not be serialized
+    pub async fn send_notification(
+        &self,
+        client: &reqwest::Client,
+        user_id: i32,
+        notification: &PushNotificationDto,
+    ) -> Result<usize, AppError> {
+        let Some(vapid_key) = &self.config.vapid_key else {
+            return Ok(0);
+        };
+
+        let payload = serde_json::to_vec(notification).map_err(|e| {
+            AppError::Internal(format!("Failed to serialize push notification: {}", e))
+        })?;
+        let expires_at = Utc::now() + Duration::hours(VAPID_TOKEN_LIFETIME_HOURS);
+
+        let subscription_repo = PushSubscriptionRepository::new(self.db);
+        let mut delivered = 0;
+
+        for subscription in subscription_repo.get_by_user_id(user_id).await? {
+            match self
+                .deliver(client, vapid_key, &subscription, &payload, expires_at)
+                .await
+            {
+                Ok(DeliveryOutcome::Delivered) => delivered += 1,
+                Ok(DeliveryOutcome::Expired) => {
+                    tracing::debug!(
+                        "Deleting expired push subscription {} of user {}",
+                        subscription.id,
+                        user_id
+                    );
+                    subscription_repo.delete(subscription.id).await?;
+                }
+                Ok(DeliveryOutcome::Rejected(status)) => {
+                    tracing::warn!(
+                        "Push service rejected notification for subscription {} of user {} with status {}",
+                        subscription.id,
+                        user_id,
+                        status
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to send notification to push subscription {} of user {}: {}",
+                        subscription.id,
+                        user_id,
+                        e
+                    );
+                }
+            }
+        }
+
+        Ok(delivered)
+    }
+
+    /// Encrypts and sends a message to a single subscription.
+    async fn deliver(
+        &self,
+        client: &reqwest::Client,
+        vapid_key: &VapidKey,
+        subscription: &PushSubscriptionModel,
+        payload: &[u8],
+        expires_at: chrono::DateTime<Utc>,
+    ) -> Result<DeliveryOutcome, AppError> {
+        let web_push_error =
+            |e: WebPushError| AppError::Internal(format!("Failed to build push message: {}", e));
+
+        let body = encrypt_payload(&subscription.p256dh, &subscription.auth, payload)
+            .map_err(web_push_error)?;
+        let authorization = vapid_key
+            .authorization(&subscription.endpoint, &self.config.subject, expires_at)
+            .map_err(web_push_error)?;
+
+        let status = client
+            .post(&subscription.endpoint)
+            .header("Authorization", authorization)
+            .header("Content-Encoding", "aes128gcm")
+            .header("Content-Type", "application/octet-stream")
+            .header("TTL", PUSH_TTL_SECS.to_string())
+            .header("Urgency", "high")
+            .body(body)
+            .send()
+            .await?
+            .status();
+
+        Ok(match status.as_u16() {
+            200..=299 => DeliveryOutcome::Delivered,
+            404 | 410 => DeliveryOutcome::Expired,
+            _ => DeliveryOutcome::Rejected(status),
+        })
+    }
+}
@@ -0,0 +1,83 @@
+//! Request rate limiting service layer.
+//!
+//! This module contains the `RateLimitService` counting requests of clients in fixed windows,
+//! so endpoints such as the EVE Online SSO login can be throttled per IP address or session.
+//! Counters are Redis keys expiring with their window, shared by every server instance using
+//! the same Redis.
+
+use std::time::Duration;
+
+use fred::prelude::*;
+
+use crate::server::error::AppError;
+
+/// Service for counting requests against rate limits.
+pub struct RateLimitService<'a> {
+    pool: &'a Pool,
+}
+
+impl<'a> RateLimitService<'a> {
+    /// Creates a new instance of RateLimitService.
+    ///
+    /// Constructs a service for counting requests against rate limits.
+    ///
+    /// # Arguments
+    /// - `pool` - Redis connection pool holding the request counters
+    ///
+    /// # Returns
+    /// - `RateLimitService` - New service instance
+    pub fn new(pool: &'a Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Counts a request against the rate limit of a client.
+    ///
+    /// The window starts with the first request counted for the client and the counter resets
+    /// once it has passed. Requests over the limit are still counted, so clients retrying
+    /// before the window resets stay limited.
+    ///
+    /// # Arguments
+    /// - `scope` - What is limited, e.g. `auth:ip`, keeping counters of limits apart
+    /// - `subject` - Client the request is counted for, e.g. its IP address
+    /// - `limit` - Number of requests allowed within the window
+    /// - `window` - Length of the window
+    ///
+    /// # Returns
+    /// - `Ok(None)` - Request is within the limit
+    /// - `Ok(Some(u64))` - Request exceeds the limit, with the seconds until the window resets
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn hit(
+        &self,
+        scope: &str,
+        subject: &str,
+        limit: u32,
+        window: Duration,
+    ) -> Result<Option<u64>, AppError> {
+        let key = limit_key(scope, subject);
+        let window_secs = window.as_secs().max(1) as i64;
+
+        let count: u64 = self.pool.incr(&key).await?;
+        if count == 1 {
+            let _: () = self.pool.expire(&key, window_secs, None).await?;
+        }
+
+        if count <= u64::from(limit) {
+            return Ok(None);
+        }
+
+        // Restore the expiry if setting it failed when the window started, as the counter
+        // would otherwise never reset
+        let remaining: i64 = self.pool.ttl(&key).await?;
+        if remaining < 0 {
+            let _: () = self.pool.expire(&key, window_secs, None).await?;
+            return Ok(Some(window_secs as u64));
+        }
+
+        Ok(Some(remaining.max(1) as u64))
+    }
+}
+
+/// Returns the Redis key of a client's request counter.
+fn limit_key(scope: &str, subject: &str) -> String {
+    format!("bifrost:rate_limit:{}:{}", scope, subject)
+}
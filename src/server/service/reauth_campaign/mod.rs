@@ -0,0 +1,296 @@
+//! Re-authentication campaign service layer.
+//!
+//! This module contains the `ReauthCampaignService` for campaigns asking the users in an
+//! audience to log in again and grant a set of ESI scopes, e.g. after a new feature needs
+//! additional scopes. The audience is resolved to flagged users when the campaign is launched.
+//! Flagged users are shown a banner until any of their characters grants the campaign's scopes
+//! through an `add_scopes` login, and campaigns with a deadline restrict access for users who
+//! haven't re-authenticated once it passes.
+
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+
+use crate::{
+    model::{
+        announcement::AnnouncementAudience,
+        reauth_campaign::{CreateReauthCampaignDto, PendingReauthCampaignDto, ReauthCampaignDto},
+    },
+    server::{
+        data::{
+            reauth_campaign::ReauthCampaignRepository,
+            user::{summary::UserCharacterSummaryRepository, UserRepository},
+        },
+        error::{reauth_campaign::ReauthCampaignError, AppError},
+        model::db::ReauthCampaignModel,
+    },
+};
+
+/// Service for launching re-authentication campaigns and tracking their completion.
+pub struct ReauthCampaignService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> ReauthCampaignService<'a> {
+    /// Creates a new instance of ReauthCampaignService.
+    ///
+    /// Constructs a service for managing re-authentication campaigns.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ReauthCampaignService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Launches a campaign and flags every user in its audience.
+    ///
+    /// Corporation and alliance audiences include every user owning a character in the
+    /// corporation or alliance, not only users whose main character is. Duplicate scopes are
+    /// removed.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user launching the campaign
+    /// - `campaign` - Name, scopes, audience, and optional deadline of the campaign
+    ///
+    /// # Returns
+    /// - `Ok(ReauthCampaignDto)` - The launched campaign with its number of flagged users
+    /// - `Err(AppError::ReauthCampaign(ReauthCampaignError::InvalidCampaign))` - Empty name or
+    ///   scopes, corporation or alliance audience without an ID, or deadline in the past
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_campaign(
+        &self,
+        user_id: i32,
+        campaign: CreateReauthCampaignDto,
+    ) -> Result<ReauthCampaignDto, AppError> {
+        let name = campaign.name.trim().to_string();
+        if name.is_empty() {
+            return Err(
+                ReauthCampaignError::InvalidCampaign("name must not be empty".to_string()).into(),
+            );
+        }
+
+        let mut scopes: Vec<String> = Vec::new();
+        for scope in campaign.scopes.iter().flat_map(|s| s.split_whitespace()) {
+            if !scopes.iter().any(|existing| existing == scope) {
+                scopes.push(scope.to_string());
+            }
+        }
+        if scopes.is_empty() {
+            return Err(ReauthCampaignError::InvalidCampaign(
+                "at least one scope is required".to_string(),
+            )
+            .into());
+        }
+
+        let audience_id = match campaign.audience {
+            AnnouncementAudience::All => None,
+            audience => Some(campaign.audience_id.ok_or_else(|| {
+                ReauthCampaignError::InvalidCampaign(format!(
+                    "campaigns for {} require an audience ID",
+                    audience.description().to_lowercase()
+                ))
+            })?),
+        };
+
+        if campaign
+            .deadline
+            .is_some_and(|deadline| deadline <= Utc::now().naive_utc())
+        {
+            return Err(ReauthCampaignError::InvalidCampaign(
+                "deadline must be in the future".to_string(),
+            )
+            .into());
+        }
+
+        let txn = self.db.begin().await?;
+
+        let summary_repo = UserCharacterSummaryRepository::new(&txn);
+        let user_ids = match (campaign.audience, audience_id) {
+            (AnnouncementAudience::Corporation, Some(corporation_id)) => {
+                summary_repo
+                    .get_user_ids_by_corporation_id(corporation_id)
+                    .await?
+            }
+            (AnnouncementAudience::Alliance, Some(alliance_id)) => {
+                summary_repo
+                    .get_user_ids_by_alliance_id(alliance_id)
+                    .await?
+            }
+            _ => UserRepository::new(&txn).get_all_ids().await?,
+        };
+
+        let campaign_repo = ReauthCampaignRepository::new(&txn);
+        let created = campaign_repo
+            .create(
+                name,
+                scopes.join(" "),
+                campaign.audience.as_str(),
+                audience_id,
+                campaign.deadline,
+                user_id,
+            )
+            .await?;
+        campaign_repo.add_users(created.id, &user_ids).await?;
+
+        txn.commit().await?;
+
+        Ok(campaign_to_dto(
+            created,
+            campaign.audience,
+            (user_ids.len() as u64, 0),
+        ))
+    }
+
+    /// Retrieves all campaigns with their completion, newest first.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<ReauthCampaignDto>)` - All campaigns with flagged and completed user counts
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_campaigns(&self) -> Result<Vec<ReauthCampaignDto>, AppError> {
+        let campaign_repo = ReauthCampaignRepository::new(self.db);
+
+        let counts: HashMap<i32, (u64, u64)> = campaign_repo
+            .count_users()
+            .await?
+            .into_iter()
+            .map(|(campaign_id, user_count, completed_count)| {
+                (campaign_id, (user_count as u64, completed_count as u64))
+            })
+            .collect();
+
+        Ok(campaign_repo
+            .get_all()
+            .await?
+            .into_iter()
+            .filter_map(|campaign| {
+                let audience = AnnouncementAudience::from_name(&campaign.audience)?;
+                let counts = counts.get(&campaign.id).copied().unwrap_or_default();
+
+                Some(campaign_to_dto(campaign, audience, counts))
+            })
+            .collect())
+    }
+
+    /// Retrieves the campaigns a user still has to complete, oldest first.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `now` - Current time, compared against campaign deadlines
+    ///
+    /// # Returns
+    /// - `Ok(Vec<PendingReauthCampaignDto>)` - Pending campaigns, marked restricted if their
+    ///   deadline has passed
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_pending(
+        &self,
+        user_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<Vec<PendingReauthCampaignDto>, AppError> {
+        Ok(ReauthCampaignRepository::new(self.db)
+            .get_pending_for_user(user_id)
+            .await?
+            .into_iter()
+            .map(|(_, campaign)| PendingReauthCampaignDto {
+                id: campaign.id,
+                restricted: campaign.deadline.is_some_and(|deadline| deadline <= now),
+                scopes: split_scopes(&campaign.scopes),
+                name: campaign.name,
+                deadline: campaign.deadline,
+            })
+            .collect())
+    }
+
+    /// Checks that a user's access isn't restricted by a campaign past its deadline.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `now` - Current time, compared against campaign deadlines
+    ///
+    /// # Returns
+    /// - `Ok(())` - User has completed every campaign past its deadline
+    /// - `Err(AppError::ReauthCampaign(ReauthCampaignError::ReauthRequired))` - User hasn't
+    ///   completed a campaign past its deadline
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn ensure_not_restricted(
+        &self,
+        user_id: i32,
+        now: NaiveDateTime,
+    ) -> Result<(), AppError> {
+        match self
+            .get_pending(user_id, now)
+            .await?
+            .into_iter()
+            .find(|campaign| campaign.restricted)
+        {
+            Some(campaign) => Err(ReauthCampaignError::ReauthRequired {
+                user_id,
+                campaign_name: campaign.name,
+            }
+            .into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Completes the user's pending campaigns whose scopes were all granted.
+    ///
+    /// Called after a successful `add_scopes` login, so campaigns are completed by granting
+    /// their scopes with any of the user's characters.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user who logged in
+    /// - `granted_scopes` - ESI scopes granted during the login
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - Number of campaigns completed by the login
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn complete_for_scopes(
+        &self,
+        user_id: i32,
+        granted_scopes: &[String],
+    ) -> Result<u64, AppError> {
+        let campaign_repo = ReauthCampaignRepository::new(self.db);
+
+        let completed_ids: Vec<i32> = campaign_repo
+            .get_pending_for_user(user_id)
+            .await?
+            .into_iter()
+            .filter(|(_, campaign)| {
+                split_scopes(&campaign.scopes)
+                    .iter()
+                    .all(|scope| granted_scopes.contains(scope))
+            })
+            .map(|(row, _)| row.id)
+            .collect();
+
+        Ok(campaign_repo.complete(&completed_ids).await?)
+    }
+}
+
+/// Splits the space-separated scopes stored for a campaign.
+fn split_scopes(scopes: &str) -> Vec<String> {
+    scopes.split_whitespace().map(str::to_string).collect()
+}
+
+/// Converts a stored campaign into its DTO with its (user, completed) counts.
+fn campaign_to_dto(
+    campaign: ReauthCampaignModel,
+    audience: AnnouncementAudience,
+    (user_count, completed_count): (u64, u64),
+) -> ReauthCampaignDto {
+    ReauthCampaignDto {
+        id: campaign.id,
+        scopes: split_scopes(&campaign.scopes),
+        name: campaign.name,
+        audience,
+        audience_id: campaign.audience_id,
+        deadline: campaign.deadline,
+        created_by_user_id: campaign.created_by_user_id,
+        created_at: campaign.created_at,
+        user_count,
+        completed_count,
+    }
+}
@@ -0,0 +1,171 @@
+//! Recruitment service layer.
+//!
+//! This module contains the `RecruitmentService` for managing public corporation recruitment
+//! listings. Listings are publicly readable, while creating, updating, or removing a listing
+//! requires the user to own the corporation's CEO character.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::recruitment::{RecruitmentListingDto, UpsertRecruitmentListingDto},
+    server::{
+        data::{
+            eve::corporation::CorporationRepository, recruitment::RecruitmentListingRepository,
+            user::user_character::UserCharacterRepository,
+        },
+        error::{recruitment::RecruitmentError, AppError},
+        model::db::EveCorporationModel,
+    },
+};
+
+/// Maximum length of a recruitment listing description in characters.
+const LISTING_DESCRIPTION_MAX_LENGTH: usize = 4000;
+
+/// Maximum length of a recruitment listing timezone in characters.
+const LISTING_TIMEZONE_MAX_LENGTH: usize = 64;
+
+/// Service for managing corporation recruitment listings.
+///
+/// Provides methods for retrieving all public listings and for a corporation's CEO to
+/// create, update, or remove their corporation's listing.
+pub struct RecruitmentService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> RecruitmentService<'a> {
+    /// Creates a new instance of RecruitmentService.
+    ///
+    /// Constructs a service for managing corporation recruitment listings.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `RecruitmentService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Retrieves all public recruitment listings.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<RecruitmentListingDto>)` - All listings, most recently updated first
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn get_listings(&self) -> Result<Vec<RecruitmentListingDto>, AppError> {
+        let listings = RecruitmentListingRepository::new(self.db)
+            .get_all_with_corporation()
+            .await?;
+
+        Ok(listings
+            .into_iter()
+            .map(|(listing, corporation, alliance)| RecruitmentListingDto {
+                corporation_id: corporation.corporation_id,
+                corporation_name: corporation.name,
+                corporation_ticker: corporation.ticker,
+                alliance_id: alliance.as_ref().map(|a| a.alliance_id),
+                alliance_name: alliance.map(|a| a.name),
+                member_count: corporation.member_count,
+                description: listing.description,
+                timezone: listing.timezone,
+                updated_at: listing.updated_at,
+            })
+            .collect())
+    }
+
+    /// Creates or updates the recruitment listing for a corporation.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user making the change, who must own the corporation's CEO
+    /// - `corporation_id` - EVE Online corporation ID
+    /// - `listing` - Listing description and timezone
+    ///
+    /// # Returns
+    /// - `Ok(())` - Listing created or updated
+    /// - `Err(AppError::Recruitment(RecruitmentError::InvalidListing))` - Listing input failed validation
+    /// - `Err(AppError::Recruitment(RecruitmentError::CorporationNotFound))` - Corporation not in database
+    /// - `Err(AppError::Recruitment(RecruitmentError::NotCorporationCeo))` - User doesn't own the CEO character
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn upsert_listing(
+        &self,
+        user_id: i32,
+        corporation_id: i64,
+        listing: UpsertRecruitmentListingDto,
+    ) -> Result<(), AppError> {
+        let description = listing.description.trim().to_string();
+        let timezone = listing.timezone.trim().to_string();
+
+        if description.is_empty() || description.chars().count() > LISTING_DESCRIPTION_MAX_LENGTH {
+            return Err(RecruitmentError::InvalidListing(format!(
+                "description must be between 1 and {} characters",
+                LISTING_DESCRIPTION_MAX_LENGTH
+            ))
+            .into());
+        }
+
+        if timezone.is_empty() || timezone.chars().count() > LISTING_TIMEZONE_MAX_LENGTH {
+            return Err(RecruitmentError::InvalidListing(format!(
+                "timezone must be between 1 and {} characters",
+                LISTING_TIMEZONE_MAX_LENGTH
+            ))
+            .into());
+        }
+
+        let corporation = self.get_ceo_corporation(user_id, corporation_id).await?;
+
+        RecruitmentListingRepository::new(self.db)
+            .upsert(corporation.id, description, timezone)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes the recruitment listing for a corporation.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user making the change, who must own the corporation's CEO
+    /// - `corporation_id` - EVE Online corporation ID
+    ///
+    /// # Returns
+    /// - `Ok(())` - Listing removed
+    /// - `Err(AppError::Recruitment(RecruitmentError::CorporationNotFound))` - Corporation not in database
+    /// - `Err(AppError::Recruitment(RecruitmentError::NotCorporationCeo))` - User doesn't own the CEO character
+    /// - `Err(AppError::Recruitment(RecruitmentError::ListingNotFound))` - Corporation has no listing
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_listing(&self, user_id: i32, corporation_id: i64) -> Result<(), AppError> {
+        let corporation = self.get_ceo_corporation(user_id, corporation_id).await?;
+
+        let result = RecruitmentListingRepository::new(self.db)
+            .delete_by_corporation_id(corporation.id)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(RecruitmentError::ListingNotFound(corporation_id).into());
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves a corporation, verifying the user owns its CEO character.
+    async fn get_ceo_corporation(
+        &self,
+        user_id: i32,
+        corporation_id: i64,
+    ) -> Result<EveCorporationModel, AppError> {
+        let corporation = CorporationRepository::new(self.db)
+            .find_by_eve_id(corporation_id)
+            .await?
+            .ok_or(RecruitmentError::CorporationNotFound(corporation_id))?;
+
+        let owns_ceo = UserCharacterRepository::new(self.db)
+            .get_owned_characters_by_user_id(user_id)
+            .await?
+            .iter()
+            .any(|(character, _, _)| character.character_id == corporation.ceo_id);
+
+        if !owns_ceo {
+            return Err(RecruitmentError::NotCorporationCeo(corporation_id).into());
+        }
+
+        Ok(corporation)
+    }
+}
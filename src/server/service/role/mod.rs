@@ -0,0 +1,444 @@
+//! Role service layer.
+//!
+//! This module contains the `RoleService` for the roles bundling admin permissions and the
+//! checks admin endpoints run against them. Two roles are built in: `admin` grants every
+//! permission, and the permissions of `member` apply to every user without being assigned.
+//! The first user to log in is made an admin so a fresh deployment can be administered.
+
+use dioxus_logger::tracing;
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+
+use crate::{
+    model::role::{CreateRoleDto, Permission, RoleDto, UpdateRoleDto, ADMIN_ROLE, MEMBER_ROLE},
+    server::{
+        data::{
+            role::{RoleRepository, UserRoleRepository},
+            user::UserRepository,
+        },
+        error::{auth::AuthError, role::RoleError, AppError},
+        model::db::RoleModel,
+    },
+};
+
+/// Service for managing roles and checking the permissions they grant.
+pub struct RoleService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> RoleService<'a> {
+    /// Creates a new instance of RoleService.
+    ///
+    /// Constructs a service for managing roles and checking the permissions they grant.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `RoleService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a role.
+    ///
+    /// # Arguments
+    /// - `role` - Name, description, and permissions of the role
+    ///
+    /// # Returns
+    /// - `Ok(RoleDto)` - The created role
+    /// - `Err(AppError::Role(RoleError::InvalidRole))` - Name is empty or already used
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_role(&self, role: CreateRoleDto) -> Result<RoleDto, AppError> {
+        let role_repo = RoleRepository::new(self.db);
+        let (name, description, permissions) =
+            validate_role(role.name, role.description, role.permissions)?;
+
+        if role_repo.find_by_name(&name).await?.is_some() {
+            return Err(
+                RoleError::InvalidRole(format!("A role named {} already exists", name)).into(),
+            );
+        }
+
+        let created = role_repo
+            .create(name, description, permissions, false)
+            .await?;
+
+        self.to_dto(created).await
+    }
+
+    /// Retrieves all roles.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<RoleDto>)` - All roles ordered by ID, including the built-in roles
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_roles(&self) -> Result<Vec<RoleDto>, AppError> {
+        let roles = RoleRepository::new(self.db).get_all().await?;
+
+        let mut dtos = Vec::with_capacity(roles.len());
+        for role in roles {
+            dtos.push(self.to_dto(role).await?);
+        }
+
+        Ok(dtos)
+    }
+
+    /// Updates a role.
+    ///
+    /// Built-in roles can't be renamed, and the admin role always keeps the admin permission.
+    ///
+    /// # Arguments
+    /// - `role_id` - ID of the role
+    /// - `role` - Name, description, and permissions of the role
+    ///
+    /// # Returns
+    /// - `Ok(RoleDto)` - The updated role
+    /// - `Err(AppError::Role(RoleError::InvalidRole))` - Name is empty or used by another role
+    /// - `Err(AppError::Role(RoleError::RoleNotFound))` - Role doesn't exist
+    /// - `Err(AppError::Role(RoleError::BuiltInRole))` - Built-in role renamed or admin
+    ///   permission removed from the admin role
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn update_role(
+        &self,
+        role_id: i32,
+        role: UpdateRoleDto,
+    ) -> Result<RoleDto, AppError> {
+        let role_repo = RoleRepository::new(self.db);
+        let (name, description, permissions) =
+            validate_role(role.name, role.description, role.permissions)?;
+
+        let existing = role_repo
+            .find_by_id(role_id)
+            .await?
+            .ok_or(RoleError::RoleNotFound(role_id))?;
+
+        if existing.built_in {
+            if existing.name != name {
+                return Err(RoleError::BuiltInRole(existing.name).into());
+            }
+            if existing.name == ADMIN_ROLE
+                && !parse_permissions(&permissions).contains(&Permission::Admin)
+            {
+                return Err(RoleError::BuiltInRole(existing.name).into());
+            }
+        }
+
+        if let Some(other) = role_repo.find_by_name(&name).await? {
+            if other.id != role_id {
+                return Err(RoleError::InvalidRole(format!(
+                    "A role named {} already exists",
+                    name
+                ))
+                .into());
+            }
+        }
+
+        let updated = role_repo
+            .update(role_id, name, description, permissions)
+            .await?
+            .ok_or(RoleError::RoleNotFound(role_id))?;
+
+        self.to_dto(updated).await
+    }
+
+    /// Deletes a role, unassigning it from every user.
+    ///
+    /// # Arguments
+    /// - `role_id` - ID of the role
+    ///
+    /// # Returns
+    /// - `Ok(())` - Role deleted
+    /// - `Err(AppError::Role(RoleError::RoleNotFound))` - Role doesn't exist
+    /// - `Err(AppError::Role(RoleError::BuiltInRole))` - Role is built in
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_role(&self, role_id: i32) -> Result<(), AppError> {
+        let role_repo = RoleRepository::new(self.db);
+
+        let role = role_repo
+            .find_by_id(role_id)
+            .await?
+            .ok_or(RoleError::RoleNotFound(role_id))?;
+
+        if role.built_in {
+            return Err(RoleError::BuiltInRole(role.name).into());
+        }
+
+        role_repo.delete(role_id).await?;
+
+        Ok(())
+    }
+
+    /// Assigns a role to a user.
+    ///
+    /// Assigning a role the user already has has no effect. The member role can't be assigned,
+    /// as it applies to every user. Assignments are written to the info log with the acting
+    /// admin so they can be audited.
+    ///
+    /// # Arguments
+    /// - `assigned_by_user_id` - ID of the admin assigning the role
+    /// - `role_id` - ID of the role
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(())` - User has the role
+    /// - `Err(AppError::Role(RoleError::RoleNotFound))` - Role doesn't exist
+    /// - `Err(AppError::Role(RoleError::BuiltInRole))` - Role is the member role
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn assign_role(
+        &self,
+        assigned_by_user_id: i32,
+        role_id: i32,
+        user_id: i32,
+    ) -> Result<(), AppError> {
+        let role = self.get_assignable_role(role_id, user_id).await?;
+
+        UserRoleRepository::new(self.db)
+            .assign(user_id, role_id)
+            .await?;
+
+        tracing::info!(
+            user_id = %user_id,
+            assigned_by_user_id = %assigned_by_user_id,
+            role_id = %role_id,
+            "Assigned role {}",
+            role.name
+        );
+
+        Ok(())
+    }
+
+    /// Returns whether assigning a role grants the admin permission.
+    ///
+    /// Used to send assignments of admin roles through the two-person rule when it is
+    /// configured for `ApprovalAction::GrantAdmin`.
+    ///
+    /// # Arguments
+    /// - `role_id` - ID of the role
+    /// - `user_id` - ID of the user the role would be assigned to
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Role includes `Permission::Admin`
+    /// - `Ok(false)` - Role doesn't include it
+    /// - `Err(AppError::Role(RoleError::RoleNotFound))` - Role doesn't exist
+    /// - `Err(AppError::Role(RoleError::BuiltInRole))` - Role is the member role
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - User doesn't exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn grants_admin(&self, role_id: i32, user_id: i32) -> Result<bool, AppError> {
+        let role = self.get_assignable_role(role_id, user_id).await?;
+
+        Ok(parse_permissions(&role.permissions).contains(&Permission::Admin))
+    }
+
+    /// Removes a role from a user.
+    ///
+    /// Removing a role the user doesn't have has no effect. The admin role can't be removed
+    /// from the last user who has it, so the admin endpoints stay reachable.
+    ///
+    /// # Arguments
+    /// - `role_id` - ID of the role
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(())` - User doesn't have the role
+    /// - `Err(AppError::Role(RoleError::RoleNotFound))` - Role doesn't exist
+    /// - `Err(AppError::Role(RoleError::LastAdmin))` - User is the last admin
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn revoke_role(&self, role_id: i32, user_id: i32) -> Result<(), AppError> {
+        let user_role_repo = UserRoleRepository::new(self.db);
+
+        let role = RoleRepository::new(self.db)
+            .find_by_id(role_id)
+            .await?
+            .ok_or(RoleError::RoleNotFound(role_id))?;
+
+        if !user_role_repo.has_role(user_id, role_id).await? {
+            return Ok(());
+        }
+
+        if role.name == ADMIN_ROLE && user_role_repo.count_users(role_id).await? <= 1 {
+            return Err(RoleError::LastAdmin.into());
+        }
+
+        user_role_repo.revoke(user_id, role_id).await?;
+
+        Ok(())
+    }
+
+    /// Retrieves the permissions a user has been granted.
+    ///
+    /// Combines the permissions of the member role with those of every role assigned to the
+    /// user. Users with the admin permission are granted every permission.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Permission>)` - Permissions of the user in `Permission::ALL` order
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_user_permissions(&self, user_id: i32) -> Result<Vec<Permission>, AppError> {
+        let mut roles = UserRoleRepository::new(self.db)
+            .get_roles_by_user_id(user_id)
+            .await?;
+        if let Some(member) = RoleRepository::new(self.db)
+            .find_by_name(MEMBER_ROLE)
+            .await?
+        {
+            roles.push(member);
+        }
+
+        let granted: Vec<Permission> = roles
+            .iter()
+            .flat_map(|role| parse_permissions(&role.permissions))
+            .collect();
+
+        if granted.contains(&Permission::Admin) {
+            return Ok(Permission::ALL.to_vec());
+        }
+
+        Ok(Permission::ALL
+            .into_iter()
+            .filter(|permission| granted.contains(permission))
+            .collect())
+    }
+
+    /// Checks whether a user has been granted a permission.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `permission` - Permission to check
+    ///
+    /// # Returns
+    /// - `Ok(true)` - User has the permission, directly or through the admin permission
+    /// - `Ok(false)` - User doesn't have the permission
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn has_permission(
+        &self,
+        user_id: i32,
+        permission: Permission,
+    ) -> Result<bool, AppError> {
+        Ok(self
+            .get_user_permissions(user_id)
+            .await?
+            .contains(&permission))
+    }
+
+    /// Makes a newly created user an admin if they are the only user.
+    ///
+    /// Called when a user is created at login, so the first user of a fresh deployment can
+    /// administer it. Skipped with a warning if the admin role is missing.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection or transaction the user was created in
+    /// - `user_id` - ID of the newly created user
+    ///
+    /// # Returns
+    /// - `Ok(true)` - User was made an admin
+    /// - `Ok(false)` - Other users exist or the admin role is missing
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn grant_admin_to_first_user<C: ConnectionTrait>(
+        db: &C,
+        user_id: i32,
+    ) -> Result<bool, AppError> {
+        if UserRepository::new(db).count().await? != 1 {
+            return Ok(false);
+        }
+
+        let Some(admin) = RoleRepository::new(db).find_by_name(ADMIN_ROLE).await? else {
+            tracing::warn!(
+                user_id = %user_id,
+                "Admin role is missing, first user was not made an admin"
+            );
+            return Ok(false);
+        };
+
+        UserRoleRepository::new(db)
+            .assign(user_id, admin.id)
+            .await?;
+
+        tracing::info!(user_id = %user_id, "Made first user an admin");
+
+        Ok(true)
+    }
+
+    /// Retrieves a role, failing unless it can be assigned to the user.
+    async fn get_assignable_role(&self, role_id: i32, user_id: i32) -> Result<RoleModel, AppError> {
+        let role = RoleRepository::new(self.db)
+            .find_by_id(role_id)
+            .await?
+            .ok_or(RoleError::RoleNotFound(role_id))?;
+
+        if role.name == MEMBER_ROLE {
+            return Err(RoleError::BuiltInRole(role.name).into());
+        }
+
+        if UserRepository::new(self.db)
+            .get_by_id(user_id)
+            .await?
+            .is_none()
+        {
+            return Err(AuthError::UserNotInDatabase(user_id).into());
+        }
+
+        Ok(role)
+    }
+
+    /// Converts a stored role into its DTO, counting the users assigned to it.
+    async fn to_dto(&self, role: RoleModel) -> Result<RoleDto, AppError> {
+        let user_count = UserRoleRepository::new(self.db)
+            .count_users(role.id)
+            .await?;
+
+        Ok(RoleDto {
+            id: role.id,
+            permissions: parse_permissions(&role.permissions),
+            name: role.name,
+            description: role.description,
+            built_in: role.built_in,
+            user_count,
+            created_at: role.created_at,
+            updated_at: role.updated_at,
+        })
+    }
+}
+
+/// Validates a role, returning its trimmed name and description and its space-separated
+/// permissions.
+///
+/// # Arguments
+/// - `name` - Name of the role
+/// - `description` - Description shown to admins
+/// - `permissions` - Permissions the role grants
+///
+/// # Returns
+/// - `Ok((String, String, String))` - Trimmed name and description, and space-separated
+///   permissions with duplicates removed
+/// - `Err(RoleError::InvalidRole)` - Name is empty
+fn validate_role(
+    name: String,
+    description: String,
+    permissions: Vec<Permission>,
+) -> Result<(String, String, String), RoleError> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(RoleError::InvalidRole(
+            "Role name must not be empty".to_string(),
+        ));
+    }
+
+    let permissions = Permission::ALL
+        .into_iter()
+        .filter(|permission| permissions.contains(permission))
+        .map(|permission| permission.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Ok((name, description.trim().to_string(), permissions))
+}
+
+/// Parses the space-separated permissions of a stored role, skipping unknown permissions.
+fn parse_permissions(permissions: &str) -> Vec<Permission> {
+    permissions
+        .split_whitespace()
+        .filter_map(Permission::from_name)
+        .collect()
+}
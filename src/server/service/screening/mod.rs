@@ -0,0 +1,144 @@
+//! Screening service layer.
+//!
+//! This module contains the `ScreeningService` for generating recruitment screening reports.
+//! A report is a snapshot of what Bifrost knows about a character at the time it was
+//! generated, stored so recruiters can share a stable link with each other.
+
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    model::{screening::ScreeningReportDto, user::CharacterDto},
+    server::{
+        data::{
+            screening::ScreeningReportRepository, user::user_character::UserCharacterRepository,
+        },
+        error::{screening::ScreeningError, AppError},
+        model::db::ScreeningReportModel,
+        service::user::user_character::UserCharacterService,
+    },
+};
+
+/// Report content stored as JSON in the `report` column.
+#[derive(Serialize, Deserialize)]
+struct ScreeningReportContent {
+    character: CharacterDto,
+    known_alts: Vec<CharacterDto>,
+}
+
+/// Service for generating and retrieving recruitment screening reports.
+///
+/// Reports currently cover the character's affiliation and known alts via ownership
+/// records. Each report is stored so it can be shared by ID.
+pub struct ScreeningService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> ScreeningService<'a> {
+    /// Creates a new instance of ScreeningService.
+    ///
+    /// Constructs a service for generating and retrieving screening reports.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `ScreeningService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Generates and stores a screening report for a character.
+    ///
+    /// Looks up the user owning the character and lists every other character owned by that
+    /// user as a known alt.
+    ///
+    /// # Arguments
+    /// - `requested_by_user_id` - ID of the user generating the report
+    /// - `character_id` - EVE Online character ID to screen
+    ///
+    /// # Returns
+    /// - `Ok(ScreeningReportDto)` - The generated report
+    /// - `Err(AppError::Screening(ScreeningError::CharacterNotRegistered))` - Character is not owned by any user
+    /// - `Err(AppError::Database)` - Database operation failed
+    /// - `Err(AppError::Internal)` - Report content could not be serialized
+    pub async fn generate_report(
+        &self,
+        requested_by_user_id: i32,
+        character_id: i64,
+    ) -> Result<ScreeningReportDto, AppError> {
+        let Some((character, Some(ownership))) = UserCharacterRepository::new(self.db)
+            .get_character_with_ownership(character_id)
+            .await?
+        else {
+            return Err(ScreeningError::CharacterNotRegistered(character_id).into());
+        };
+
+        let (screened, known_alts): (Vec<CharacterDto>, Vec<CharacterDto>) =
+            UserCharacterService::new(self.db)
+                .get_user_characters(ownership.user_id)
+                .await?
+                .into_iter()
+                .partition(|c| c.id == character_id);
+
+        let screened = screened.into_iter().next().ok_or_else(|| {
+            // Would only occur if the character's corporation is missing from the database,
+            // which the foreign key constraint on eve_character prevents
+            AppError::Internal(format!(
+                "Failed to find affiliation for owned character ID {} while screening",
+                character_id
+            ))
+        })?;
+
+        let content = ScreeningReportContent {
+            character: screened,
+            known_alts,
+        };
+        let report = serde_json::to_string(&content).map_err(|e| {
+            AppError::Internal(format!("Failed to serialize screening report: {}", e))
+        })?;
+
+        let report = ScreeningReportRepository::new(self.db)
+            .create(character.id, requested_by_user_id, report)
+            .await?;
+
+        report_to_dto(report)
+    }
+
+    /// Retrieves a stored screening report.
+    ///
+    /// # Arguments
+    /// - `report_id` - ID of the report to retrieve
+    ///
+    /// # Returns
+    /// - `Ok(ScreeningReportDto)` - The stored report
+    /// - `Err(AppError::Screening(ScreeningError::ReportNotFound))` - Report does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    /// - `Err(AppError::Internal)` - Stored report content could not be deserialized
+    pub async fn get_report(&self, report_id: i32) -> Result<ScreeningReportDto, AppError> {
+        let report = ScreeningReportRepository::new(self.db)
+            .get_by_id(report_id)
+            .await?
+            .ok_or(ScreeningError::ReportNotFound(report_id))?;
+
+        report_to_dto(report)
+    }
+}
+
+/// Converts a stored report into its DTO by deserializing the report content.
+fn report_to_dto(report: ScreeningReportModel) -> Result<ScreeningReportDto, AppError> {
+    let content: ScreeningReportContent = serde_json::from_str(&report.report).map_err(|e| {
+        AppError::Internal(format!(
+            "Failed to deserialize screening report ID {}: {}",
+            report.id, e
+        ))
+    })?;
+
+    Ok(ScreeningReportDto {
+        id: report.id,
+        requested_by_user_id: report.requested_by_user_id,
+        character: content.character,
+        known_alts: content.known_alts,
+        created_at: report.created_at,
+    })
+}
@@ -0,0 +1,243 @@
+//! Search service layer.
+//!
+//! This module contains the `SearchService` for searching characters, corporations, and
+//! alliances by name. By default names are matched case-insensitively in the database, which
+//! needs no extra infrastructure. If `MEILISEARCH_URL` is configured, searches are served from
+//! a Meilisearch index instead for typo tolerance and relevance ranking on larger instances;
+//! the index is kept in sync by the worker jobs refreshing entity info from ESI.
+
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::search::SearchResultDto,
+    server::{
+        config::Config,
+        data::search::SearchRepository,
+        error::AppError,
+        util::meilisearch::{MeilisearchClient, SearchDocument, ENTITY_INDEX},
+    },
+};
+
+/// Search result category of characters.
+pub const CHARACTER_CATEGORY: &str = "character";
+/// Search result category of corporations.
+pub const CORPORATION_CATEGORY: &str = "corporation";
+/// Search result category of alliances.
+pub const ALLIANCE_CATEGORY: &str = "alliance";
+
+/// Number of entities pushed to the search index per request while reindexing.
+const REINDEX_BATCH_SIZE: u64 = 1000;
+
+/// Search settings derived from the server configuration at startup.
+#[derive(Clone, Debug, Default)]
+pub struct SearchConfig {
+    /// Meilisearch client, `None` if searches are served from the database.
+    pub meilisearch: Option<MeilisearchClient>,
+}
+
+impl SearchConfig {
+    /// Builds search settings from the server configuration.
+    ///
+    /// # Arguments
+    /// - `config` - Server configuration loaded from environment variables
+    ///
+    /// # Returns
+    /// - `Ok(SearchConfig)` - Meilisearch client if `MEILISEARCH_URL` is set
+    /// - `Err(AppError)` - Failed to build the HTTP client
+    pub fn from_config(config: &Config) -> Result<Self, AppError> {
+        let Some(url) = &config.meilisearch_url else {
+            return Ok(Self::default());
+        };
+
+        let http = reqwest::Client::builder()
+            .user_agent(&config.user_agent)
+            .build()?;
+
+        Ok(Self {
+            meilisearch: Some(MeilisearchClient::new(
+                url,
+                config.meilisearch_api_key.clone(),
+                http,
+            )),
+        })
+    }
+}
+
+/// Service for searching EVE Online entities and keeping the search index in sync.
+pub struct SearchService<'a> {
+    db: &'a DatabaseConnection,
+    config: &'a SearchConfig,
+}
+
+impl<'a> SearchService<'a> {
+    /// Creates a new instance of SearchService.
+    ///
+    /// Constructs a service for searching entities with the configured backend.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    /// - `config` - Search settings selecting the backend
+    ///
+    /// # Returns
+    /// - `SearchService` - New service instance
+    pub fn new(db: &'a DatabaseConnection, config: &'a SearchConfig) -> Self {
+        Self { db, config }
+    }
+
+    /// Searches characters, corporations, and alliances by name.
+    ///
+    /// Uses Meilisearch if configured. If Meilisearch can't be reached the database is
+    /// searched instead, so an outage of the search instance degrades ranking rather than
+    /// breaking search.
+    ///
+    /// # Arguments
+    /// - `query` - Text to search for
+    /// - `limit` - Maximum number of results to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<SearchResultDto>)` - Matching entities, most relevant first
+    /// - `Err(AppError)` - Database query failed
+    pub async fn search(&self, query: &str, limit: u64) -> Result<Vec<SearchResultDto>, AppError> {
+        if let Some(client) = &self.config.meilisearch {
+            match client.search(ENTITY_INDEX, query, limit).await {
+                Ok(hits) => {
+                    return Ok(hits
+                        .into_iter()
+                        .map(|hit| SearchResultDto {
+                            category: hit.category,
+                            id: hit.id,
+                            name: hit.name,
+                        })
+                        .collect())
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Meilisearch search failed, falling back to database search: {}",
+                        e
+                    );
+                }
+            }
+        }
+
+        self.search_database(query, limit).await
+    }
+
+    /// Adds or replaces an entity in the search index.
+    ///
+    /// Does nothing if searches are served from the database. Failures are logged rather than
+    /// returned, since the entity is indexed again by the next refresh or reindex.
+    ///
+    /// # Arguments
+    /// - `category` - Entity category, one of the `*_CATEGORY` constants
+    /// - `id` - EVE Online ID of the entity
+    /// - `name` - Entity name
+    pub async fn index_entity(&self, category: &str, id: i64, name: String) {
+        let Some(client) = &self.config.meilisearch else {
+            return;
+        };
+
+        let document = SearchDocument::new(category, id, name);
+        if let Err(e) = client.add_documents(ENTITY_INDEX, &[document]).await {
+            tracing::warn!("Failed to index {} {}: {}", category, id, e);
+        }
+    }
+
+    /// Pushes every stored character, corporation, and alliance to the search index.
+    ///
+    /// Used at startup so entities stored before Meilisearch was configured, or while it was
+    /// unreachable, become searchable. Does nothing if searches are served from the database.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of entities pushed to the index
+    /// - `Err(AppError)` - Database query or Meilisearch request failed
+    pub async fn reindex(&self) -> Result<usize, AppError> {
+        let Some(client) = &self.config.meilisearch else {
+            return Ok(0);
+        };
+
+        let search_repo = SearchRepository::new(self.db);
+        let mut indexed = 0;
+
+        for category in [CHARACTER_CATEGORY, CORPORATION_CATEGORY, ALLIANCE_CATEGORY] {
+            let mut after_id = 0;
+            loop {
+                let page = match category {
+                    CHARACTER_CATEGORY => {
+                        search_repo
+                            .get_characters_page(after_id, REINDEX_BATCH_SIZE)
+                            .await?
+                    }
+                    CORPORATION_CATEGORY => {
+                        search_repo
+                            .get_corporations_page(after_id, REINDEX_BATCH_SIZE)
+                            .await?
+                    }
+                    _ => {
+                        search_repo
+                            .get_alliances_page(after_id, REINDEX_BATCH_SIZE)
+                            .await?
+                    }
+                };
+                let Some((last_id, _, _)) = page.last() else {
+                    break;
+                };
+                after_id = *last_id;
+
+                let documents: Vec<SearchDocument> = page
+                    .into_iter()
+                    .map(|(_, id, name)| SearchDocument::new(category, id, name))
+                    .collect();
+                client.add_documents(ENTITY_INDEX, &documents).await?;
+                indexed += documents.len();
+            }
+        }
+
+        Ok(indexed)
+    }
+
+    /// Searches entity names in the database.
+    ///
+    /// Names starting with the query are ranked before names only containing it.
+    async fn search_database(
+        &self,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<SearchResultDto>, AppError> {
+        let search_repo = SearchRepository::new(self.db);
+
+        let results = [
+            (
+                CHARACTER_CATEGORY,
+                search_repo.search_characters(query, limit).await?,
+            ),
+            (
+                CORPORATION_CATEGORY,
+                search_repo.search_corporations(query, limit).await?,
+            ),
+            (
+                ALLIANCE_CATEGORY,
+                search_repo.search_alliances(query, limit).await?,
+            ),
+        ];
+
+        let query = query.to_lowercase();
+        let mut results: Vec<SearchResultDto> = results
+            .into_iter()
+            .flat_map(|(category, matches)| {
+                matches.into_iter().map(move |(id, name)| SearchResultDto {
+                    category: category.to_string(),
+                    id,
+                    name,
+                })
+            })
+            .collect();
+        results.sort_by_cached_key(|result| {
+            let name = result.name.to_lowercase();
+            (!name.starts_with(&query), name)
+        });
+        results.truncate(limit as usize);
+
+        Ok(results)
+    }
+}
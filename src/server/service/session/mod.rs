@@ -0,0 +1,283 @@
+//! User session service layer.
+//!
+//! This module contains the `SessionService` maintaining an index of the sessions each user is
+//! logged in with, so users can see where they're logged in and revoke sessions they don't
+//! recognize. The index is a Redis hash per user, kept next to the session store and updated by
+//! the `index_sessions` middleware as logged in users make requests. Sessions are listed under
+//! an ID derived from the session ID, as the session ID itself is the secret in the session
+//! cookie.
+
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+use fred::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tower_sessions::{session::Id, SessionStore};
+use tower_sessions_redis_store::RedisStore;
+
+use crate::{
+    model::user::UserSessionDto,
+    server::{
+        error::{session::SessionError, AppError},
+        util::proxy::ClientInfo,
+    },
+};
+
+/// Seconds between index updates of the same session, so requests in quick succession don't
+/// each write to Redis.
+const TOUCH_INTERVAL_SECONDS: i64 = 60;
+
+/// Maximum number of characters of the user agent stored for a session.
+const MAX_USER_AGENT_LENGTH: usize = 256;
+
+/// Number of SHA-256 bytes making up the public ID of a session.
+const PUBLIC_ID_BYTES: usize = 16;
+
+/// Entry of a session in a user's session index.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct IndexedSession {
+    /// ID of the session in the session store
+    session_id: String,
+    /// IP address of the client when the session was last seen
+    ip_address: Option<String>,
+    /// User agent of the client when the session was last seen
+    user_agent: Option<String>,
+    /// When the session was first indexed
+    created_at: NaiveDateTime,
+    /// When the session last made a request
+    last_seen_at: NaiveDateTime,
+}
+
+/// Service for listing and revoking the sessions users are logged in with.
+pub struct SessionService<'a> {
+    pool: &'a Pool,
+}
+
+impl<'a> SessionService<'a> {
+    /// Creates a new instance of SessionService.
+    ///
+    /// Constructs a service for listing and revoking user sessions.
+    ///
+    /// # Arguments
+    /// - `pool` - Redis connection pool shared with the session store
+    ///
+    /// # Returns
+    /// - `SessionService` - New service instance
+    pub fn new(pool: &'a Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Records a request made with a session in the user's session index.
+    ///
+    /// Sessions seen within the last minute are left untouched. The index is kept until the
+    /// longest lived of the user's sessions expires.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user logged in with the session
+    /// - `session_id` - ID of the session the request was made with
+    /// - `expires_in` - Seconds until the session expires without further requests
+    /// - `client` - Client the request was made from
+    /// - `user_agent` - User agent of the client, if sent
+    ///
+    /// # Returns
+    /// - `Ok(())` - Session recorded in the index
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn touch(
+        &self,
+        user_id: i32,
+        session_id: Id,
+        expires_in: i64,
+        client: ClientInfo,
+        user_agent: Option<&str>,
+    ) -> Result<(), AppError> {
+        let key = index_key(user_id);
+        let session_id = session_id.to_string();
+        let field = public_id(&session_id);
+        let now = Utc::now().naive_utc();
+
+        let existing: Option<String> = self.pool.hget(&key, &field).await?;
+        let existing =
+            existing.and_then(|entry| serde_json::from_str::<IndexedSession>(&entry).ok());
+
+        if let Some(existing) = &existing {
+            if (now - existing.last_seen_at).num_seconds() < TOUCH_INTERVAL_SECONDS {
+                return Ok(());
+            }
+        }
+
+        let user_agent =
+            user_agent.map(|agent| agent.chars().take(MAX_USER_AGENT_LENGTH).collect());
+        let entry = IndexedSession {
+            session_id,
+            ip_address: client.ip.map(|ip| ip.to_string()),
+            user_agent,
+            created_at: existing.map_or(now, |existing| existing.created_at),
+            last_seen_at: now,
+        };
+        let entry_json =
+            serde_json::to_string(&entry).map_err(|e| AppError::Parse(e.to_string()))?;
+
+        let _: () = self.pool.hset(&key, (field, entry_json)).await?;
+
+        // Never shorten the index below the remaining lifetime of the user's other sessions
+        let remaining: i64 = self.pool.ttl(&key).await?;
+        if remaining < expires_in {
+            let _: () = self.pool.expire(&key, expires_in, None).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Retrieves the active sessions of a user.
+    ///
+    /// Sessions that expired or were logged out are removed from the index instead of being
+    /// listed.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `current_session_id` - ID of the session the request was made with, marked as current
+    ///
+    /// # Returns
+    /// - `Ok(Vec<UserSessionDto>)` - Active sessions, most recently seen first
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn get_sessions(
+        &self,
+        user_id: i32,
+        current_session_id: Option<Id>,
+    ) -> Result<Vec<UserSessionDto>, AppError> {
+        let key = index_key(user_id);
+        let current_id = current_session_id.map(|id| public_id(&id.to_string()));
+
+        let entries: HashMap<String, String> = self.pool.hgetall(&key).await?;
+
+        let mut sessions = Vec::new();
+        let mut stale = Vec::new();
+        for (id, entry) in entries {
+            let Ok(entry) = serde_json::from_str::<IndexedSession>(&entry) else {
+                stale.push(id);
+                continue;
+            };
+
+            if !self.is_active(&entry.session_id).await? {
+                stale.push(id);
+                continue;
+            }
+
+            sessions.push(UserSessionDto {
+                current: current_id.as_ref() == Some(&id),
+                id,
+                ip_address: entry.ip_address,
+                user_agent: entry.user_agent,
+                created_at: entry.created_at,
+                last_seen_at: entry.last_seen_at,
+            });
+        }
+
+        if !stale.is_empty() {
+            let _: () = self.pool.hdel(&key, stale).await?;
+        }
+
+        sessions.sort_by(|a, b| b.last_seen_at.cmp(&a.last_seen_at));
+
+        Ok(sessions)
+    }
+
+    /// Revokes a session of a user, logging out the client using it.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    /// - `id` - ID of the session as listed by `get_sessions`
+    /// - `current_session_id` - ID of the session the request was made with
+    ///
+    /// # Returns
+    /// - `Ok(())` - Session deleted from the session store and the index
+    /// - `Err(AppError::UserSession(SessionError::NotFound))` - User has no session with the ID
+    /// - `Err(AppError::UserSession(SessionError::CurrentSession))` - Session is the one the
+    ///   request was made with
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn revoke_session(
+        &self,
+        user_id: i32,
+        id: &str,
+        current_session_id: Option<Id>,
+    ) -> Result<(), AppError> {
+        if current_session_id.is_some_and(|current| public_id(&current.to_string()) == id) {
+            return Err(SessionError::CurrentSession.into());
+        }
+
+        let key = index_key(user_id);
+
+        let entry: Option<String> = self.pool.hget(&key, id).await?;
+        let Some(entry) =
+            entry.and_then(|entry| serde_json::from_str::<IndexedSession>(&entry).ok())
+        else {
+            return Err(SessionError::NotFound(id.to_string()).into());
+        };
+
+        if let Ok(session_id) = entry.session_id.parse::<Id>() {
+            RedisStore::new(self.pool.clone())
+                .delete(&session_id)
+                .await
+                .map_err(tower_sessions::session::Error::Store)?;
+        }
+
+        let _: () = self.pool.hdel(&key, id).await?;
+
+        Ok(())
+    }
+
+    /// Returns whether a session still exists in the session store.
+    async fn is_active(&self, session_id: &str) -> Result<bool, AppError> {
+        let Ok(session_id) = session_id.parse::<Id>() else {
+            return Ok(false);
+        };
+
+        let record = RedisStore::new(self.pool.clone())
+            .load(&session_id)
+            .await
+            .map_err(tower_sessions::session::Error::Store)?;
+
+        Ok(record.is_some())
+    }
+}
+
+/// Returns the Redis key of a user's session index.
+fn index_key(user_id: i32) -> String {
+    format!("bifrost:user:sessions:{}", user_id)
+}
+
+/// Derives the ID a session is listed under from its session ID.
+///
+/// The session ID authenticates the session cookie, so it is never returned by the API.
+fn public_id(session_id: &str) -> String {
+    Sha256::digest(session_id.as_bytes())
+        .iter()
+        .take(PUBLIC_ID_BYTES)
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod public_id {
+        use super::*;
+
+        /// Tests that the public ID is stable and doesn't contain the session ID.
+        ///
+        /// Expected: Same 32 hex character ID for the same session, different for another
+        #[test]
+        fn derives_stable_opaque_id() {
+            let session_id = Id::default().to_string();
+
+            let id = public_id(&session_id);
+
+            assert_eq!(id, public_id(&session_id));
+            assert_eq!(id.len(), PUBLIC_ID_BYTES * 2);
+            assert!(!id.contains(&session_id));
+            assert_ne!(id, public_id(&Id::default().to_string()));
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Skill plan service layer.
+//!
+//! This module contains the `SkillPlanService` for publishing skill plans imported as plain
+//! text or EVEMon XML, listing them, and deleting them. Plans are validated by parsing on
+//! import and stored as normalized plain text so every plan can be exported the same way.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::skill_plan::{SkillPlanDto, SkillPlanEntryDto},
+    server::{
+        data::skill_plan::SkillPlanRepository,
+        error::{skill_plan::SkillPlanError, AppError},
+        model::db::SkillPlanModel,
+        util::skill_plan::{format_skill_plan, parse_skill_plan},
+    },
+};
+
+/// Service for managing skill plans.
+pub struct SkillPlanService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> SkillPlanService<'a> {
+    /// Creates a new instance of SkillPlanService.
+    ///
+    /// Constructs a service for managing skill plans.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `SkillPlanService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Publishes a skill plan imported as plain text or EVEMon XML.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user publishing the plan
+    /// - `name` - Unique skill plan name
+    /// - `description` - Optional skill plan description
+    /// - `plan` - Plan in plain text or EVEMon XML format
+    ///
+    /// # Returns
+    /// - `Ok(SkillPlanDto)` - The published skill plan
+    /// - `Err(AppError::SkillPlan(SkillPlanError::InvalidPlan))` - Plan could not be parsed
+    /// - `Err(AppError::SkillPlan(SkillPlanError::DuplicateSkillPlanName))` - Name already in use
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_skill_plan(
+        &self,
+        user_id: i32,
+        name: String,
+        description: Option<String>,
+        plan: String,
+    ) -> Result<SkillPlanDto, AppError> {
+        let entries = parse_skill_plan(&plan).map_err(SkillPlanError::from)?;
+
+        let skill_plan_repo = SkillPlanRepository::new(self.db);
+
+        if skill_plan_repo.get_by_name(&name).await?.is_some() {
+            return Err(SkillPlanError::DuplicateSkillPlanName(name).into());
+        }
+
+        let skill_plan = skill_plan_repo
+            .create(name, description, format_skill_plan(&entries), user_id)
+            .await?;
+
+        skill_plan_to_dto(skill_plan)
+    }
+
+    /// Retrieves all skill plans.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<SkillPlanDto>)` - All skill plans ordered by name
+    /// - `Err(AppError::Database)` - Database operation failed
+    /// - `Err(AppError::Internal)` - A stored plan could not be parsed
+    pub async fn get_skill_plans(&self) -> Result<Vec<SkillPlanDto>, AppError> {
+        SkillPlanRepository::new(self.db)
+            .get_all()
+            .await?
+            .into_iter()
+            .map(skill_plan_to_dto)
+            .collect()
+    }
+
+    /// Deletes a skill plan.
+    ///
+    /// # Arguments
+    /// - `skill_plan_id` - ID of the skill plan to delete
+    ///
+    /// # Returns
+    /// - `Ok(())` - Skill plan deleted
+    /// - `Err(AppError::SkillPlan(SkillPlanError::SkillPlanNotFound))` - Skill plan does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_skill_plan(&self, skill_plan_id: i32) -> Result<(), AppError> {
+        let result = SkillPlanRepository::new(self.db)
+            .delete(skill_plan_id)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(SkillPlanError::SkillPlanNotFound(skill_plan_id).into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a stored skill plan into its DTO, parsing the stored text for the entry list.
+///
+/// # Returns
+/// - `Ok(SkillPlanDto)` - Converted skill plan
+/// - `Err(AppError::Internal)` - Stored plan text is no longer valid (should not happen as it
+///   was normalized on import)
+fn skill_plan_to_dto(skill_plan: SkillPlanModel) -> Result<SkillPlanDto, AppError> {
+    let entries = parse_skill_plan(&skill_plan.plan).map_err(|e| {
+        AppError::Internal(format!(
+            "Failed to parse stored plan for skill plan ID {}: {}",
+            skill_plan.id, e
+        ))
+    })?;
+
+    Ok(SkillPlanDto {
+        id: skill_plan.id,
+        name: skill_plan.name,
+        description: skill_plan.description,
+        entries: entries
+            .into_iter()
+            .map(|entry| SkillPlanEntryDto {
+                skill_name: entry.skill_name,
+                level: entry.level,
+            })
+            .collect(),
+        plan: skill_plan.plan,
+        created_at: skill_plan.created_at,
+        updated_at: skill_plan.updated_at,
+    })
+}
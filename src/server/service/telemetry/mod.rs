@@ -0,0 +1,174 @@
+//! Telemetry service layer.
+//!
+//! This module contains the `TelemetryService` for building and sending the opt-in anonymous
+//! deployment report. The report only contains the Bifrost version, a coarse bucket of the
+//! registered user count, and which optional features are enabled, so maintainers can
+//! prioritize features without learning anything about the deployment's users. Nothing is
+//! sent unless `TELEMETRY_ENDPOINT` is configured.
+
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::telemetry::{TelemetryReportDto, TelemetryStatusDto},
+    server::{config::Config, data::user::UserRepository, error::AppError},
+};
+
+/// Telemetry settings derived from the server configuration at startup.
+///
+/// Kept separate from `Config` so it can be shared with handlers and the scheduler without
+/// exposing secrets.
+#[derive(Clone, Debug, Default)]
+pub struct TelemetryConfig {
+    /// Endpoint reports are sent to, `None` if telemetry is disabled.
+    pub endpoint: Option<String>,
+    /// Names of optional features enabled in this deployment.
+    pub features: Vec<String>,
+}
+
+impl TelemetryConfig {
+    /// Builds telemetry settings from the server configuration.
+    ///
+    /// # Arguments
+    /// - `config` - Server configuration loaded from environment variables
+    ///
+    /// # Returns
+    /// - `TelemetryConfig` - Endpoint and enabled optional features
+    pub fn from_config(config: &Config) -> Self {
+        let mut features = Vec::new();
+        if !config.encryption_keys.is_empty() {
+            features.push("column_encryption".to_string());
+        }
+        if config.vapid_key.is_some() {
+            features.push("push_notifications".to_string());
+        }
+        if config.meilisearch_url.is_some() {
+            features.push("meilisearch".to_string());
+        }
+        if config.object_storage.is_some() {
+            features.push("object_storage".to_string());
+        }
+        if config.image_cache_dir.is_some() {
+            features.push("image_proxy".to_string());
+        }
+        if config.discord_webhook_url.is_some() {
+            features.push("discord_webhook".to_string());
+        }
+        if config.discord_oauth.is_some() {
+            features.push("discord_linking".to_string());
+        }
+
+        Self {
+            endpoint: config.telemetry_endpoint.clone(),
+            features,
+        }
+    }
+}
+
+/// Buckets a user count so reports never contain the exact number of users.
+fn user_count_bucket(count: u64) -> &'static str {
+    match count {
+        0 => "0",
+        1..=10 => "1-10",
+        11..=50 => "11-50",
+        51..=250 => "51-250",
+        251..=1000 => "251-1000",
+        _ => "1000+",
+    }
+}
+
+/// Service for building and sending anonymous telemetry reports.
+pub struct TelemetryService<'a> {
+    db: &'a DatabaseConnection,
+    config: &'a TelemetryConfig,
+}
+
+impl<'a> TelemetryService<'a> {
+    /// Creates a new instance of TelemetryService.
+    ///
+    /// Constructs a service for building and sending telemetry reports.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    /// - `config` - Telemetry settings
+    ///
+    /// # Returns
+    /// - `TelemetryService` - New service instance
+    pub fn new(db: &'a DatabaseConnection, config: &'a TelemetryConfig) -> Self {
+        Self { db, config }
+    }
+
+    /// Builds the report that is sent when telemetry is enabled.
+    ///
+    /// # Returns
+    /// - `Ok(TelemetryReportDto)` - Version, user count bucket, and enabled features
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn build_report(&self) -> Result<TelemetryReportDto, AppError> {
+        let user_count = UserRepository::new(self.db).count().await?;
+
+        Ok(TelemetryReportDto {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            user_count_bucket: user_count_bucket(user_count).to_string(),
+            features: self.config.features.clone(),
+        })
+    }
+
+    /// Retrieves whether telemetry is enabled along with the exact report that is sent.
+    ///
+    /// # Returns
+    /// - `Ok(TelemetryStatusDto)` - Telemetry status and report preview
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_status(&self) -> Result<TelemetryStatusDto, AppError> {
+        Ok(TelemetryStatusDto {
+            enabled: self.config.endpoint.is_some(),
+            endpoint: self.config.endpoint.clone(),
+            report: self.build_report().await?,
+        })
+    }
+
+    /// Sends the telemetry report to the configured endpoint.
+    ///
+    /// Does nothing if telemetry is disabled.
+    ///
+    /// # Arguments
+    /// - `client` - HTTP client used to send the report
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Report was sent
+    /// - `Ok(false)` - Telemetry is disabled
+    /// - `Err(AppError::Database)` - Database query failed
+    /// - `Err(AppError::Http)` - Request failed or endpoint returned an error status
+    pub async fn send_report(&self, client: &reqwest::Client) -> Result<bool, AppError> {
+        let Some(endpoint) = &self.config.endpoint else {
+            return Ok(false);
+        };
+
+        let report = self.build_report().await?;
+
+        client
+            .post(endpoint)
+            .json(&report)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that user counts are reported as coarse buckets.
+    ///
+    /// Expected: each count maps to the bucket containing it
+    #[test]
+    fn buckets_user_count() {
+        assert_eq!(user_count_bucket(0), "0");
+        assert_eq!(user_count_bucket(10), "1-10");
+        assert_eq!(user_count_bucket(11), "11-50");
+        assert_eq!(user_count_bucket(250), "51-250");
+        assert_eq!(user_count_bucket(1000), "251-1000");
+        assert_eq!(user_count_bucket(1001), "1000+");
+    }
+}
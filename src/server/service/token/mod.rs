@@ -0,0 +1,209 @@
+//! Character token service layer.
+//!
+//! This module contains the `TokenService` for the EVE SSO refresh tokens stored per
+//! character, the foundation for scoped ESI requests. Tokens are stored encrypted whenever a
+//! character logs in, and exchanged for short-lived access tokens on demand. EVE SSO rotates
+//! refresh tokens when exchanging them, so the rotated token is stored in place of the old one.
+//! Tokens EVE SSO rejects with `invalid_grant`, e.g. because the character revoked access, are
+//! deleted, while tokens failing for other reasons are kept.
+
+use dioxus_logger::tracing;
+use oauth2::TokenResponse;
+use sea_orm::DatabaseConnection;
+
+use crate::server::{
+    data::{character_token::CharacterTokenRepository, eve::character::CharacterRepository},
+    error::{auth::AuthError, token::TokenError, AppError},
+    model::db::CharacterTokenModel,
+    service::eve::esi::EsiProvider,
+    util::crypto::ColumnCipher,
+};
+
+/// Service for storing character refresh tokens and exchanging them for access tokens.
+pub struct TokenService<'a> {
+    db: &'a DatabaseConnection,
+    esi_provider: &'a EsiProvider,
+    cipher: &'a ColumnCipher,
+}
+
+impl<'a> TokenService<'a> {
+    /// Creates a new instance of TokenService.
+    ///
+    /// Constructs a service for storing and refreshing character tokens.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    /// - `esi_provider` - ESI provider used to exchange refresh tokens
+    /// - `cipher` - Cipher refresh tokens are encrypted with
+    ///
+    /// # Returns
+    /// - `TokenService` - New service instance
+    pub fn new(
+        db: &'a DatabaseConnection,
+        esi_provider: &'a EsiProvider,
+        cipher: &'a ColumnCipher,
+    ) -> Self {
+        Self {
+            db,
+            esi_provider,
+            cipher,
+        }
+    }
+
+    /// Stores the refresh token a character logged in with.
+    ///
+    /// Replaces the character's existing token, so the scopes granted by the latest login are
+    /// the ones requests can use.
+    ///
+    /// # Arguments
+    /// - `character_id` - EVE Online ID of the character
+    /// - `refresh_token` - Refresh token returned by EVE SSO
+    /// - `scopes` - ESI scopes the character granted
+    ///
+    /// # Returns
+    /// - `Ok(())` - Token stored
+    /// - `Err(AppError::Auth(AuthError::CharacterNotFound))` - Character is not stored
+    /// - `Err(AppError::Encryption)` - No encryption keys are configured
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn store_token(
+        &self,
+        character_id: i64,
+        refresh_token: &str,
+        scopes: &[String],
+    ) -> Result<(), AppError> {
+        let character = CharacterRepository::new(self.db)
+            .find_by_eve_id(character_id)
+            .await?
+            .ok_or(AuthError::CharacterNotFound)?;
+
+        let refresh_token = self.cipher.encrypt(refresh_token)?;
+
+        CharacterTokenRepository::new(self.db)
+            .upsert(character.id, refresh_token, scopes.join(" "))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Exchanges a character's stored refresh token for an access token.
+    ///
+    /// # Arguments
+    /// - `character_id` - EVE Online ID of the character
+    ///
+    /// # Returns
+    /// - `Ok(String)` - Access token for ESI requests on behalf of the character
+    /// - `Err(AppError::Auth(AuthError::CharacterNotFound))` - Character is not stored
+    /// - `Err(AppError::Token(TokenError::NotFound))` - No token is stored for the character
+    /// - `Err(AppError::Token(TokenError::Revoked))` - EVE SSO rejected the token, which was
+    ///   deleted
+    /// - `Err(AppError::Encryption)` - Stored token can't be decrypted with the configured keys
+    /// - `Err(AppError::Esi)` - Token exchange failed for another reason
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn get_access_token(&self, character_id: i64) -> Result<String, AppError> {
+        let character = CharacterRepository::new(self.db)
+            .find_by_eve_id(character_id)
+            .await?
+            .ok_or(AuthError::CharacterNotFound)?;
+        let token = CharacterTokenRepository::new(self.db)
+            .find_by_character_id(character.id)
+            .await?
+            .ok_or(TokenError::NotFound(character_id))?;
+
+        self.refresh(&token)
+            .await?
+            .ok_or_else(|| TokenError::Revoked(character_id).into())
+    }
+
+    /// Deletes every stored token EVE SSO rejects with `invalid_grant`.
+    ///
+    /// Each token is exchanged once, which also rotates the tokens that are still valid.
+    /// Tokens failing for other reasons, such as EVE SSO being unavailable, are kept and
+    /// logged.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - Number of tokens deleted
+    /// - `Err(AppError::Database)` - Failed to retrieve the stored tokens
+    pub async fn prune_revoked_tokens(&self) -> Result<usize, AppError> {
+        let tokens = CharacterTokenRepository::new(self.db).get_all().await?;
+
+        let mut pruned = 0;
+        for token in tokens {
+            match self.refresh(&token).await {
+                Ok(Some(_)) => {}
+                Ok(None) => pruned += 1,
+                Err(e) => tracing::warn!(
+                    "Failed to check token of character record {}: {:?}",
+                    token.character_id,
+                    e
+                ),
+            }
+        }
+
+        Ok(pruned)
+    }
+
+    /// Exchanges a stored refresh token, storing the rotated token or deleting a rejected one.
+    ///
+    /// # Arguments
+    /// - `token` - Stored token record
+    ///
+    /// # Returns
+    /// - `Ok(Some(String))` - Access token issued for the refresh token
+    /// - `Ok(None)` - EVE SSO rejected the refresh token with `invalid_grant`, and it was
+    ///   deleted
+    /// - `Err(AppError::Encryption)` - Stored token can't be decrypted with the configured keys
+    /// - `Err(AppError::Esi)` - Token exchange failed for another reason
+    /// - `Err(AppError::Database)` - Database operation failed
+    async fn refresh(&self, token: &CharacterTokenModel) -> Result<Option<String>, AppError> {
+        let token_repo = CharacterTokenRepository::new(self.db);
+        let refresh_token = self.cipher.decrypt(&token.refresh_token)?;
+
+        let tokens = match self
+            .esi_provider
+            .client()
+            .oauth2()
+            .get_token_refresh(refresh_token)
+            .await
+        {
+            Ok(tokens) => tokens,
+            // EVE SSO answers refresh tokens revoked by the character with `invalid_grant`;
+            // transport errors and outages say nothing about the token and are returned as is
+            Err(e) if is_rejected_grant(&e) => {
+                tracing::debug!(
+                    "Deleting rejected token of character record {}: {:?}",
+                    token.character_id,
+                    e
+                );
+                token_repo.delete(token.id).await?;
+
+                return Ok(None);
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        if let Some(rotated) = tokens.refresh_token() {
+            token_repo
+                .update_refresh_token(token.id, self.cipher.encrypt(rotated.secret())?)
+                .await?;
+        }
+
+        Ok(Some(tokens.access_token().secret().to_string()))
+    }
+}
+
+/// Returns whether EVE SSO rejected a refresh token grant.
+///
+/// # Arguments
+/// - `error` - Error returned by the token exchange
+///
+/// # Returns
+/// - `true` - EVE SSO answered with an `invalid_grant` error response
+/// - `false` - Exchange failed for another reason, e.g. a transport error or an SSO outage
+fn is_rejected_grant(error: &eve_esi::Error) -> bool {
+    matches!(
+        error,
+        eve_esi::Error::OAuthError(eve_esi::OAuthError::RequestTokenError(
+            oauth2::RequestTokenError::ServerResponse(response)
+        )) if *response.error() == oauth2::basic::BasicErrorResponseType::InvalidGrant
+    )
+}
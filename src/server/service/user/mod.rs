@@ -1,16 +1,32 @@
 //! User service layer.
 //!
 //! This module contains business logic services for user operations including
-//! user account management and character ownership. Services coordinate between
+//! user account management, merging duplicate users, and character ownership. Services coordinate between
 //! repositories and handle complex multi-step operations with retry logic.
 
 pub mod user_character;
 
-use sea_orm::DatabaseConnection;
+use dioxus_logger::tracing;
+use sea_orm::{DatabaseConnection, TransactionTrait};
 
 use crate::{
-    model::user::UserDto,
-    server::{data::user::UserRepository, error::AppError},
+    model::{
+        annotation::AnnotationSubject,
+        group::GroupMemberStatus,
+        user::{UserDto, UserMergeDto},
+    },
+    server::{
+        data::{
+            annotation::{note::NoteRepository, tag::TagRepository},
+            consent::UserConsentRepository,
+            role::UserRoleRepository,
+            user::{
+                merge::UserMergeRepository, summary::UserCharacterSummaryRepository, UserRepository,
+            },
+        },
+        error::{auth::AuthError, user::UserError, AppError},
+        service::{image::ImageService, user::user_character::UserCharacterService},
+    },
 };
 
 /// Service for managing user account operations.
@@ -37,8 +53,8 @@ impl<'a> UserService<'a> {
 
     /// Retrieves user information with their main character details.
     ///
-    /// Fetches the user record and associated main character information from the database.
-    /// Uses automatic retry logic to handle transient database failures.
+    /// Reads the main character from the user's character summary, falling back to joining
+    /// the user record with its main character if the summary has not been built for the user.
     ///
     /// # Arguments
     /// - `user_id` - ID of the user to retrieve
@@ -49,6 +65,18 @@ impl<'a> UserService<'a> {
     /// - `Err(AppError::Database)` - Database operation failed after retries
     /// - `Err(AppError::Internal)` - Main character record not found (FK constraint violation)
     pub async fn get_user(&self, user_id: i32) -> Result<Option<UserDto>, AppError> {
+        if let Some(main_character) = UserCharacterSummaryRepository::new(self.db)
+            .get_main_by_user_id(user_id)
+            .await?
+        {
+            return Ok(Some(UserDto {
+                id: main_character.user_id,
+                character_id: main_character.character_id,
+                character_name: main_character.character_name,
+                portrait_url: ImageService::image_url("characters", main_character.character_id),
+            }));
+        }
+
         let user_repo = UserRepository::new(self.db);
 
         match user_repo.get_by_id(user_id).await? {
@@ -68,8 +96,196 @@ impl<'a> UserService<'a> {
                     id: user.id,
                     character_id: main_character.character_id,
                     character_name: main_character.name,
+                    portrait_url: ImageService::image_url(
+                        "characters",
+                        main_character.character_id,
+                    ),
                 }))
             }
         }
     }
+
+    /// Merges a duplicate user into another user.
+    ///
+    /// Moves the removed user's characters, widgets, fitting authorship, push subscriptions,
+    /// screening reports, page revisions, posted announcements, launched re-authentication
+    /// campaigns, defined onboarding steps, saved queries, data access API keys, added tags and
+    /// notes, bans, and group memberships and requests to join to the kept user, moves the tags
+    /// and notes about the removed user to the kept user, grants the kept user every consent
+    /// category and role the removed user had, then deletes the removed user and rebuilds the
+    /// kept user's character summary. Moving the bans keeps a banned user from lifting them by
+    /// merging into a fresh user. Where both users are in the same group, a membership wins over
+    /// a pending request. The kept user's main character is unchanged. All steps run in a single
+    /// transaction, so a failed merge leaves both users untouched. The merge is recorded in the
+    /// log at info level.
+    ///
+    /// # Arguments
+    /// - `keep_user_id` - ID of the user to keep
+    /// - `remove_user_id` - ID of the duplicate user to merge and delete
+    ///
+    /// # Returns
+    /// - `Ok(UserMergeDto)` - Summary of the records moved to the kept user
+    /// - `Err(AppError::User(UserError::MergeIntoSelf))` - Both IDs refer to the same user
+    /// - `Err(AppError::Auth(AuthError::UserNotInDatabase))` - Either user does not exist
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn merge_users(
+        &self,
+        keep_user_id: i32,
+        remove_user_id: i32,
+    ) -> Result<UserMergeDto, AppError> {
+        if keep_user_id == remove_user_id {
+            return Err(UserError::MergeIntoSelf(keep_user_id).into());
+        }
+
+        let txn = self.db.begin().await?;
+        let user_repo = UserRepository::new(&txn);
+        let merge_repo = UserMergeRepository::new(&txn);
+        let consent_repo = UserConsentRepository::new(&txn);
+
+        for user_id in [keep_user_id, remove_user_id] {
+            if user_repo.get_by_id(user_id).await?.is_none() {
+                return Err(AuthError::UserNotInDatabase(user_id).into());
+            }
+        }
+
+        let characters_moved = merge_repo
+            .reassign_characters(remove_user_id, keep_user_id)
+            .await?;
+        let widgets_moved = merge_repo
+            .reassign_widgets(remove_user_id, keep_user_id)
+            .await?;
+        let fittings_moved = merge_repo
+            .reassign_fittings(remove_user_id, keep_user_id)
+            .await?;
+        let skill_plans_moved = merge_repo
+            .reassign_skill_plans(remove_user_id, keep_user_id)
+            .await?;
+        let campaigns_moved = merge_repo
+            .reassign_campaigns(remove_user_id, keep_user_id)
+            .await?;
+        let push_subscriptions_moved = merge_repo
+            .reassign_push_subscriptions(remove_user_id, keep_user_id)
+            .await?;
+        let screening_reports_moved = merge_repo
+            .reassign_screening_reports(remove_user_id, keep_user_id)
+            .await?;
+        let page_revisions_moved = merge_repo
+            .reassign_page_revisions(remove_user_id, keep_user_id)
+            .await?;
+        let announcements_moved = merge_repo
+            .reassign_announcements(remove_user_id, keep_user_id)
+            .await?;
+        let reauth_campaigns_moved = merge_repo
+            .reassign_reauth_campaigns(remove_user_id, keep_user_id)
+            .await?;
+        let onboarding_steps_moved = merge_repo
+            .reassign_onboarding_steps(remove_user_id, keep_user_id)
+            .await?;
+        let saved_queries_moved = merge_repo
+            .reassign_saved_queries(remove_user_id, keep_user_id)
+            .await?;
+        let api_keys_moved = merge_repo
+            .reassign_api_keys(remove_user_id, keep_user_id)
+            .await?;
+        let tags_moved = merge_repo
+            .reassign_tags(remove_user_id, keep_user_id)
+            .await?;
+        let notes_moved = merge_repo
+            .reassign_notes(remove_user_id, keep_user_id)
+            .await?;
+        let approval_requests_moved = merge_repo
+            .reassign_approval_requests(remove_user_id, keep_user_id)
+            .await?;
+        let bans_moved = merge_repo
+            .reassign_bans(remove_user_id, keep_user_id)
+            .await?;
+        let group_memberships_moved = merge_repo
+            .reassign_group_memberships(
+                remove_user_id,
+                keep_user_id,
+                GroupMemberStatus::Member.as_str(),
+            )
+            .await?;
+
+        let subject = AnnotationSubject::User.as_str();
+        let annotations_moved = TagRepository::new(&txn)
+            .reassign_subject(subject, remove_user_id.into(), keep_user_id.into())
+            .await?
+            + NoteRepository::new(&txn)
+                .reassign_subject(subject, remove_user_id.into(), keep_user_id.into())
+                .await?;
+
+        let mut consents_merged = 0;
+        for consent in consent_repo.get_by_user_id(remove_user_id).await? {
+            consents_merged += consent_repo.grant(keep_user_id, &consent.category).await?;
+        }
+
+        let user_role_repo = UserRoleRepository::new(&txn);
+        let mut roles_merged = 0;
+        for role in user_role_repo.get_roles_by_user_id(remove_user_id).await? {
+            roles_merged += user_role_repo.assign(keep_user_id, role.id).await?;
+        }
+
+        // Remaining consents and roles, preferences, saved member filters, announcement inbox
+        // entries, re-authentication campaign flags, onboarding completions, and the linked
+        // Discord account of the removed user are deleted with it by cascade
+        user_repo.delete(remove_user_id).await?;
+
+        UserCharacterService::refresh_summary(&txn, keep_user_id).await?;
+
+        txn.commit().await?;
+
+        tracing::info!(
+            kept_user_id = %keep_user_id,
+            removed_user_id = %remove_user_id,
+            characters_moved = %characters_moved,
+            consents_merged = %consents_merged,
+            roles_merged = %roles_merged,
+            widgets_moved = %widgets_moved,
+            fittings_moved = %fittings_moved,
+            skill_plans_moved = %skill_plans_moved,
+            campaigns_moved = %campaigns_moved,
+            push_subscriptions_moved = %push_subscriptions_moved,
+            screening_reports_moved = %screening_reports_moved,
+            page_revisions_moved = %page_revisions_moved,
+            announcements_moved = %announcements_moved,
+            reauth_campaigns_moved = %reauth_campaigns_moved,
+            onboarding_steps_moved = %onboarding_steps_moved,
+            saved_queries_moved = %saved_queries_moved,
+            api_keys_moved = %api_keys_moved,
+            tags_moved = %tags_moved,
+            notes_moved = %notes_moved,
+            annotations_moved = %annotations_moved,
+            approval_requests_moved = %approval_requests_moved,
+            bans_moved = %bans_moved,
+            group_memberships_moved = %group_memberships_moved,
+            "Merged duplicate user into another user"
+        );
+
+        Ok(UserMergeDto {
+            kept_user_id: keep_user_id,
+            removed_user_id: remove_user_id,
+            characters_moved,
+            consents_merged,
+            roles_merged,
+            widgets_moved,
+            fittings_moved,
+            skill_plans_moved,
+            campaigns_moved,
+            push_subscriptions_moved,
+            screening_reports_moved,
+            page_revisions_moved,
+            announcements_moved,
+            reauth_campaigns_moved,
+            onboarding_steps_moved,
+            saved_queries_moved,
+            api_keys_moved,
+            tags_moved,
+            notes_moved,
+            annotations_moved,
+            approval_requests_moved,
+            bans_moved,
+            group_memberships_moved,
+        })
+    }
 }
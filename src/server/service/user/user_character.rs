@@ -3,19 +3,42 @@
 //! This module provides business logic for managing user-character relationships including
 //! character ownership linking, transfers between users, and main character management.
 //! All operations use transactions to ensure data consistency.
+//!
+//! Every ownership change also rebuilds the affected users' character summaries, the
+//! denormalized read model the user endpoints read instead of joining the ownership and EVE
+//! entity tables per request. The paged character listing is the exception, joining the
+//! tables so its corporation and alliance filters and sorting apply in the database.
 
+use chrono::Utc;
 use dioxus_logger::tracing;
-use sea_orm::{DatabaseConnection, DatabaseTransaction};
+use sea_orm::{ConnectionTrait, DatabaseConnection, DatabaseTransaction, TransactionTrait};
 
 use crate::{
-    model::user::{AllianceDto, CharacterDto, CorporationDto},
+    model::user::{
+        AllianceDto, CharacterDto, CorporationDto, UserCharacterPageDto, UserCharacterQueryDto,
+        UserCharacterSort,
+    },
     server::{
-        data::user::{user_character::UserCharacterRepository, UserRepository},
+        data::user::{
+            summary::UserCharacterSummaryRepository,
+            user_character::{OwnedCharacterOrder, UserCharacterRepository},
+            UserRepository,
+        },
         error::{auth::AuthError, AppError},
-        model::db::{CharacterOwnershipModel, UserModel},
+        model::db::{
+            CharacterOwnershipModel, EveAllianceModel, EveCharacterModel, EveCorporationModel,
+            UserCharacterSummaryModel, UserModel,
+        },
+        service::image::ImageService,
     },
 };
 
+/// Number of characters listed per page if the request doesn't specify a page size.
+pub const DEFAULT_CHARACTER_PAGE_SIZE: u64 = 50;
+
+/// Maximum number of characters listed per page.
+pub const MAX_CHARACTER_PAGE_SIZE: u64 = 200;
+
 /// Service for managing user-character ownership operations.
 ///
 /// Provides methods for retrieving user characters with organizational details,
@@ -41,9 +64,8 @@ impl<'a> UserCharacterService<'a> {
 
     /// Retrieves all characters owned by a user with organizational details.
     ///
-    /// Fetches complete character information including associated corporation and alliance
-    /// data for all characters owned by the specified user. Uses automatic retry logic to
-    /// handle transient database failures.
+    /// Reads the user's character summary, falling back to joining the characters with their
+    /// corporations and alliances if the summary has not been built for the user yet.
     ///
     /// # Arguments
     /// - `user_id` - ID of the user whose characters to retrieve
@@ -52,40 +74,82 @@ impl<'a> UserCharacterService<'a> {
     /// - `Ok(Vec<CharacterDto>)` - List of characters with corporation and alliance information
     /// - `Err(AppError::Database)` - Database operation failed after retries
     pub async fn get_user_characters(&self, user_id: i32) -> Result<Vec<CharacterDto>, AppError> {
+        let summary = UserCharacterSummaryRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?;
+
+        if !summary.is_empty() {
+            return Ok(summary
+                .into_iter()
+                .map(character_dto_from_summary)
+                .collect());
+        }
+
         let user_characters = UserCharacterRepository::new(self.db)
             .get_owned_characters_by_user_id(user_id)
             .await?;
 
-        let character_dtos: Vec<CharacterDto> = user_characters
+        Ok(user_characters
             .into_iter()
             .map(|(character, corporation, alliance)| {
-                let alliance_dto = if let Some(alliance) = alliance {
-                    Some(AllianceDto {
-                        id: alliance.alliance_id,
-                        name: alliance.name.clone(),
-                        updated_at: alliance.updated_at,
-                    })
-                } else {
-                    None
-                };
-
-                CharacterDto {
-                    id: character.character_id,
-                    name: character.name,
-                    corporation: CorporationDto {
-                        id: corporation.corporation_id,
-                        name: corporation.name.clone(),
-                        info_updated_at: corporation.info_updated_at,
-                        affiliation_updated_at: corporation.affiliation_updated_at,
-                    },
-                    alliance: alliance_dto,
-                    info_updated_at: character.info_updated_at,
-                    affiliation_updated_at: character.affiliation_updated_at,
-                }
+                character_dto_from_models(character, corporation, alliance)
             })
-            .collect();
+            .collect())
+    }
+
+    /// Retrieves a page of the characters owned by a user, filtered and sorted.
+    ///
+    /// Unlike `get_user_characters` this joins the characters with their corporations and
+    /// alliances instead of reading the character summary, so filters and sorting apply to
+    /// the current affiliations. Missing query parameters fall back to the first page of
+    /// `DEFAULT_CHARACTER_PAGE_SIZE` characters sorted by name, and the page size is capped
+    /// at `MAX_CHARACTER_PAGE_SIZE`.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user whose characters to retrieve
+    /// - `query` - Page, page size, sort order, and corporation and alliance filters
+    ///
+    /// # Returns
+    /// - `Ok(UserCharacterPageDto)` - Page of characters with the number of matching characters
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn get_user_character_page(
+        &self,
+        user_id: i32,
+        query: UserCharacterQueryDto,
+    ) -> Result<UserCharacterPageDto, AppError> {
+        let page = query.page.unwrap_or(1).max(1);
+        let per_page = query
+            .per_page
+            .unwrap_or(DEFAULT_CHARACTER_PAGE_SIZE)
+            .clamp(1, MAX_CHARACTER_PAGE_SIZE);
+        let order = match query.sort.unwrap_or_default() {
+            UserCharacterSort::Name => OwnedCharacterOrder::Name,
+            UserCharacterSort::Corporation => OwnedCharacterOrder::Corporation,
+        };
+
+        let (characters, total) = UserCharacterRepository::new(self.db)
+            .get_owned_characters_page(
+                user_id,
+                query.corporation_id,
+                query.alliance_id,
+                order,
+                (page - 1).saturating_mul(per_page),
+                per_page,
+            )
+            .await?;
 
-        Ok(character_dtos)
+        Ok(UserCharacterPageDto {
+            total,
+            total_pages: total.div_ceil(per_page),
+            page,
+            per_page,
+            characters: characters
+                .into_iter()
+                .map(|(character, corporation, alliance)| {
+                    character_dto_from_models(character, corporation, alliance)
+                })
+                .collect(),
+        })
     }
 
     /// Links a character to a user or updates existing ownership.
@@ -115,6 +179,8 @@ impl<'a> UserCharacterService<'a> {
             .upsert(character_record_id, to_user_id, owner_hash.to_string())
             .await?;
 
+        Self::refresh_summary(txn, to_user_id).await?;
+
         Ok(ownership)
     }
 
@@ -156,9 +222,7 @@ impl<'a> UserCharacterService<'a> {
         // Retrieve user information to check if main character change is needed
         let Some((prev_user, maybe_main_character)) = user_repo.get_by_id(from_user_id).await?
         else {
-            return Err(AppError::Auth(AuthError::UserNotInDatabase(
-                from_user_id,
-            )));
+            return Err(AppError::Auth(AuthError::UserNotInDatabase(from_user_id)));
         };
 
         // Use link_character method to update ownership to provided user ID
@@ -212,6 +276,10 @@ impl<'a> UserCharacterService<'a> {
             }
         }
 
+        if prev_user.id != to_user_id {
+            Self::refresh_summary(txn, prev_user.id).await?;
+        }
+
         Ok(ownership)
     }
 
@@ -251,6 +319,182 @@ impl<'a> UserCharacterService<'a> {
 
         let user = user_repo.update(user_id, ownership.character_id).await?;
 
+        Self::refresh_summary(txn, user_id).await?;
+
         Ok(user)
     }
+
+    /// Rebuilds the character summary of a user from the ownership and EVE entity tables.
+    ///
+    /// Call within the transaction changing the user's characters so the summary is updated
+    /// atomically with them.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection or transaction to execute the rebuild within
+    /// - `user_id` - ID of the user whose summary to rebuild
+    ///
+    /// # Returns
+    /// - `Ok(())` - Summary rebuilt
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn refresh_summary<C: ConnectionTrait>(db: &C, user_id: i32) -> Result<(), AppError> {
+        // Summary rows of deleted users are removed with them by cascade
+        let Some((user, _)) = UserRepository::new(db).get_by_id(user_id).await? else {
+            return Ok(());
+        };
+
+        let characters = UserCharacterRepository::new(db)
+            .get_owned_characters_by_user_id(user_id)
+            .await?;
+
+        UserCharacterSummaryRepository::new(db)
+            .replace_for_user(
+                user_id,
+                user.main_character_id,
+                characters,
+                Utc::now().naive_utc(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rebuilds the character summaries of users owning any of the given characters.
+    ///
+    /// Called after character information or affiliations were updated from ESI.
+    ///
+    /// # Arguments
+    /// - `character_ids` - EVE Online character IDs that were updated
+    ///
+    /// # Returns
+    /// - `Ok(())` - Summaries rebuilt
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn refresh_summaries_for_characters(
+        &self,
+        character_ids: Vec<i64>,
+    ) -> Result<(), AppError> {
+        let user_ids = UserCharacterSummaryRepository::new(self.db)
+            .get_user_ids_by_character_ids(character_ids)
+            .await?;
+
+        self.refresh_summaries(user_ids).await
+    }
+
+    /// Rebuilds the character summaries of users with characters in a corporation.
+    ///
+    /// Called after corporation information was updated from ESI.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - EVE Online corporation ID that was updated
+    ///
+    /// # Returns
+    /// - `Ok(())` - Summaries rebuilt
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn refresh_summaries_for_corporation(
+        &self,
+        corporation_id: i64,
+    ) -> Result<(), AppError> {
+        let user_ids = UserCharacterSummaryRepository::new(self.db)
+            .get_user_ids_by_corporation_id(corporation_id)
+            .await?;
+
+        self.refresh_summaries(user_ids).await
+    }
+
+    /// Rebuilds the character summaries of users with characters in an alliance.
+    ///
+    /// Called after alliance information was updated from ESI.
+    ///
+    /// # Arguments
+    /// - `alliance_id` - EVE Online alliance ID that was updated
+    ///
+    /// # Returns
+    /// - `Ok(())` - Summaries rebuilt
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn refresh_summaries_for_alliance(&self, alliance_id: i64) -> Result<(), AppError> {
+        let user_ids = UserCharacterSummaryRepository::new(self.db)
+            .get_user_ids_by_alliance_id(alliance_id)
+            .await?;
+
+        self.refresh_summaries(user_ids).await
+    }
+
+    /// Rebuilds the character summaries of several users within a single transaction.
+    async fn refresh_summaries(&self, user_ids: Vec<i32>) -> Result<(), AppError> {
+        if user_ids.is_empty() {
+            return Ok(());
+        }
+
+        tracing::debug!(
+            "Refreshing character summaries for {} user(s)",
+            user_ids.len()
+        );
+
+        let txn = self.db.begin().await?;
+        for user_id in user_ids {
+            Self::refresh_summary(&txn, user_id).await?;
+        }
+        txn.commit().await?;
+
+        Ok(())
+    }
+}
+
+/// Converts a character with its corporation and alliance into the character DTO returned by
+/// the user endpoints.
+fn character_dto_from_models(
+    character: EveCharacterModel,
+    corporation: EveCorporationModel,
+    alliance: Option<EveAllianceModel>,
+) -> CharacterDto {
+    let alliance = alliance.map(|alliance| AllianceDto {
+        id: alliance.alliance_id,
+        name: alliance.name,
+        logo_url: ImageService::image_url("alliances", alliance.alliance_id),
+        updated_at: alliance.updated_at,
+    });
+
+    CharacterDto {
+        id: character.character_id,
+        name: character.name,
+        portrait_url: ImageService::image_url("characters", character.character_id),
+        corporation: CorporationDto {
+            id: corporation.corporation_id,
+            name: corporation.name,
+            logo_url: ImageService::image_url("corporations", corporation.corporation_id),
+            info_updated_at: corporation.info_updated_at,
+            affiliation_updated_at: corporation.affiliation_updated_at,
+        },
+        alliance,
+        info_updated_at: character.info_updated_at,
+        affiliation_updated_at: character.affiliation_updated_at,
+    }
+}
+
+/// Converts a character summary row into the character DTO returned by the user endpoints.
+fn character_dto_from_summary(row: UserCharacterSummaryModel) -> CharacterDto {
+    let alliance = match (row.alliance_id, row.alliance_name, row.alliance_updated_at) {
+        (Some(id), Some(name), Some(updated_at)) => Some(AllianceDto {
+            id,
+            name,
+            logo_url: ImageService::image_url("alliances", id),
+            updated_at,
+        }),
+        _ => None,
+    };
+
+    CharacterDto {
+        id: row.character_id,
+        name: row.character_name,
+        portrait_url: ImageService::image_url("characters", row.character_id),
+        corporation: CorporationDto {
+            id: row.corporation_id,
+            name: row.corporation_name,
+            logo_url: ImageService::image_url("corporations", row.corporation_id),
+            info_updated_at: row.corporation_info_updated_at,
+            affiliation_updated_at: row.corporation_affiliation_updated_at,
+        },
+        alliance,
+        info_updated_at: row.character_info_updated_at,
+        affiliation_updated_at: row.character_affiliation_updated_at,
+    }
 }
@@ -0,0 +1,171 @@
+//! Widget service layer.
+//!
+//! This module contains the `WidgetService` for managing read-only widgets that external
+//! websites and forums can embed. Each widget is accessed with its own unguessable token, so
+//! a widget can be revoked without affecting any other widget.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::Rng;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    model::widget::{CreateWidgetDto, WidgetDataDto, WidgetDto, WidgetKind},
+    server::{
+        data::{eve::corporation::CorporationRepository, widget::WidgetRepository},
+        error::{widget::WidgetError, AppError},
+    },
+};
+
+/// Number of random bytes in a widget token.
+///
+/// 32 bytes (256 bits) makes tokens infeasible to guess, encoded as 43 URL-safe characters.
+const WIDGET_TOKEN_BYTES: usize = 32;
+
+/// Service for managing embeddable widgets.
+///
+/// Provides methods for creating, listing, and revoking widgets, and for resolving a widget
+/// token into the data the widget displays.
+pub struct WidgetService<'a> {
+    db: &'a DatabaseConnection,
+}
+
+impl<'a> WidgetService<'a> {
+    /// Creates a new instance of WidgetService.
+    ///
+    /// Constructs a service for managing embeddable widgets.
+    ///
+    /// # Arguments
+    /// - `db` - Database connection reference
+    ///
+    /// # Returns
+    /// - `WidgetService` - New service instance
+    pub fn new(db: &'a DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Creates a widget with a newly generated token.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user creating the widget
+    /// - `widget` - Widget kind and the EVE Online corporation ID it displays
+    ///
+    /// # Returns
+    /// - `Ok(WidgetDto)` - The created widget including its token
+    /// - `Err(AppError::Widget(WidgetError::CorporationNotFound))` - Corporation not in database
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn create_widget(
+        &self,
+        user_id: i32,
+        widget: CreateWidgetDto,
+    ) -> Result<WidgetDto, AppError> {
+        let corporation = CorporationRepository::new(self.db)
+            .find_by_eve_id(widget.corporation_id)
+            .await?
+            .ok_or(WidgetError::CorporationNotFound(widget.corporation_id))?;
+
+        let model = WidgetRepository::new(self.db)
+            .create(
+                user_id,
+                corporation.id,
+                widget.kind.as_str(),
+                generate_widget_token(),
+            )
+            .await?;
+
+        Ok(WidgetDto {
+            id: model.id,
+            kind: widget.kind,
+            corporation_id: corporation.corporation_id,
+            corporation_name: corporation.name,
+            token: model.token,
+            created_at: model.created_at,
+        })
+    }
+
+    /// Retrieves all widgets created by a user.
+    ///
+    /// Widgets of kinds no longer supported by this version of Bifrost are omitted.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user
+    ///
+    /// # Returns
+    /// - `Ok(Vec<WidgetDto>)` - The user's widgets, oldest first
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_widgets(&self, user_id: i32) -> Result<Vec<WidgetDto>, AppError> {
+        let widgets = WidgetRepository::new(self.db)
+            .get_by_user_id(user_id)
+            .await?;
+
+        Ok(widgets
+            .into_iter()
+            .filter_map(|(widget, corporation)| {
+                Some(WidgetDto {
+                    id: widget.id,
+                    kind: WidgetKind::from_name(&widget.kind)?,
+                    corporation_id: corporation.corporation_id,
+                    corporation_name: corporation.name,
+                    token: widget.token,
+                    created_at: widget.created_at,
+                })
+            })
+            .collect())
+    }
+
+    /// Revokes a widget, invalidating its token.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user who created the widget
+    /// - `widget_id` - ID of the widget
+    ///
+    /// # Returns
+    /// - `Ok(())` - Widget revoked
+    /// - `Err(AppError::Widget(WidgetError::WidgetNotFound))` - Widget doesn't exist or isn't owned by the user
+    /// - `Err(AppError::Database)` - Database operation failed
+    pub async fn delete_widget(&self, user_id: i32, widget_id: i32) -> Result<(), AppError> {
+        let result = WidgetRepository::new(self.db)
+            .delete(widget_id, user_id)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(WidgetError::WidgetNotFound(widget_id).into());
+        }
+
+        Ok(())
+    }
+
+    /// Resolves a widget token into the data the widget displays.
+    ///
+    /// # Arguments
+    /// - `token` - Widget token provided by the embedding website
+    ///
+    /// # Returns
+    /// - `Ok(WidgetDataDto)` - Current data for the widget
+    /// - `Err(AppError::Widget(WidgetError::InvalidToken))` - No widget exists for the token
+    /// - `Err(AppError::Database)` - Database query failed
+    pub async fn get_widget_data(&self, token: &str) -> Result<WidgetDataDto, AppError> {
+        let (widget, corporation) = WidgetRepository::new(self.db)
+            .get_by_token(token)
+            .await?
+            .ok_or(WidgetError::InvalidToken)?;
+
+        let kind = WidgetKind::from_name(&widget.kind).ok_or(WidgetError::InvalidToken)?;
+
+        Ok(WidgetDataDto {
+            kind,
+            corporation_id: corporation.corporation_id,
+            corporation_name: corporation.name,
+            corporation_ticker: corporation.ticker,
+            member_count: corporation.member_count,
+            updated_at: corporation.info_updated_at,
+        })
+    }
+}
+
+/// Generates a random URL-safe widget token.
+fn generate_widget_token() -> String {
+    let mut bytes = [0u8; WIDGET_TOKEN_BYTES];
+    rand::rng().fill(&mut bytes);
+
+    URL_SAFE_NO_PAD.encode(bytes)
+}
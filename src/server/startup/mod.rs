@@ -0,0 +1,465 @@
+//! Server startup and initialization functions.
+//!
+//! This module provides functions for initializing and configuring all server components
+//! during application startup. This includes connecting to databases and Redis, building
+//! the ESI client with OAuth credentials, configuring session management, starting background
+//! workers, initializing the job scheduler, warming up lookup data before serving traffic,
+//! rebuilding the search index, and connecting to object storage.
+//! Each function handles a specific aspect of
+//! server initialization with proper error handling. Long-running background tasks are owned by
+//! a `TaskSupervisor`, which restarts them if they fail.
+
+pub mod supervisor;
+
+pub use supervisor::TaskSupervisor;
+
+use dioxus_logger::tracing;
+use fred::prelude::*;
+use sea_orm::DatabaseConnection;
+use tower_sessions::SessionManagerLayer;
+use tower_sessions_redis_store::RedisStore;
+
+use crate::server::{
+    config::Config,
+    error::AppError,
+    plugin::{PluginRegistry, PluginScheduledJob},
+    scheduler::{
+        config::{CronSchedules, SchedulerSettings},
+        telemetry::send_telemetry_report,
+        Scheduler,
+    },
+    service::{
+        eve::{esi::EsiProvider, faction::FactionService},
+        push::PushConfig,
+        search::{SearchConfig, SearchService},
+        telemetry::TelemetryConfig,
+    },
+    util::{
+        crypto::ColumnCipher, object_storage::ObjectStorage, query_metrics, read_only::ReadOnlyMode,
+    },
+    worker::{handler::WorkerJobHandler, pool::WorkerPoolConfig, Worker, WorkerQueue},
+};
+
+/// Builds and configures the ESI client with OAuth credentials from configuration.
+///
+/// Creates an EVE Online ESI API client configured with the application's OAuth credentials,
+/// user agent string, and callback URL. The user agent includes contact information required
+/// by ESI's guidelines. The client is used for all ESI API interactions including OAuth
+/// authentication and data fetching.
+///
+/// # Arguments
+/// - `config` - Application configuration containing ESI credentials and user agent
+///
+/// # Returns
+/// - `Ok(eve_esi::Client)` - Configured ESI client ready for API requests
+/// - `Err(AppError)` - Failed to build the ESI client (invalid configuration)
+///
+/// # Example
+/// ```ignore
+/// let config = Config::from_env()?;
+/// let esi_client = build_esi_client(&config)?;
+/// // Client is ready for OAuth flows and ESI requests
+/// ```
+pub fn build_esi_client(config: &Config) -> Result<eve_esi::Client, AppError> {
+    let esi_client = eve_esi::Client::builder()
+        .user_agent(&config.user_agent)
+        .client_id(&config.esi_client_id)
+        .client_secret(&config.esi_client_secret)
+        .callback_url(&config.esi_callback_url)
+        .build()?;
+
+    Ok(esi_client)
+}
+
+/// Connects to the PostgreSQL database and runs pending migrations.
+///
+/// Establishes a connection pool to the PostgreSQL database using the connection string from
+/// configuration, then automatically runs all pending SeaORM migrations to ensure the database
+/// schema is up-to-date. In debug builds, queries are counted per request and worker job to
+/// detect N+1 query patterns. This function must complete successfully before the application
+/// can access the database.
+///
+/// # Arguments
+/// - `config` - Application configuration containing the database URL
+///
+/// # Returns
+/// - `Ok(DatabaseConnection)` - Connected database with migrations applied
+/// - `Err(AppError)` - Failed to connect to database or run migrations
+///
+/// # Example
+/// ```ignore
+/// let config = Config::from_env()?;
+/// let db = connect_to_database(&config).await?;
+/// // Database is ready for queries
+/// ```
+pub async fn connect_to_database(config: &Config) -> Result<sea_orm::DatabaseConnection, AppError> {
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::{ConnectOptions, Database};
+
+    let mut opt = ConnectOptions::new(&config.database_url);
+    opt.sqlx_logging(false);
+
+    let mut db = Database::connect(opt).await?;
+    query_metrics::install(&mut db);
+
+    Migrator::up(&db, None).await?;
+
+    Ok(db)
+}
+
+/// Connects to Redis/Valkey and creates a connection pool.
+///
+/// Establishes a connection pool to the Redis/Valkey server using the connection string from
+/// configuration. The pool is configured with 6 connections and is used for both session
+/// storage and the worker queue backend. This function waits for the connection to be
+/// established before returning.
+///
+/// # Arguments
+/// - `config` - Application configuration containing the Valkey/Redis URL
+///
+/// # Returns
+/// - `Ok(Pool)` - Connected Redis pool ready for use
+/// - `Err(AppError)` - Failed to parse URL, create pool, or establish connection
+///
+/// # Example
+/// ```ignore
+/// let config = Config::from_env()?;
+/// let redis_pool = connect_to_redis(&config).await?;
+/// // Pool is ready for session storage and worker queue
+/// ```
+pub async fn connect_to_redis(config: &Config) -> Result<Pool, AppError> {
+    let config = fred::prelude::Config::from_url(&config.valkey_url)?;
+    let pool = Pool::new(config, None, None, None, 6)?;
+
+    pool.connect();
+    pool.wait_for_connect().await?;
+
+    Ok(pool)
+}
+
+/// Configures session management with Redis/Valkey backend.
+///
+/// Creates a session manager layer configured with Redis storage, cookie settings, and
+/// expiration policies. Cookie attributes are taken from configuration, where secure cookies
+/// default to enabled in production (release builds) and disabled in development (debug
+/// builds) to allow testing over HTTP. Sessions expire after `SESSION_INACTIVITY_DAYS` of
+/// inactivity, which the `apply_session_expiry` middleware extends to
+/// `SESSION_REMEMBER_ME_DAYS` for sessions logged in with "remember me".
+///
+/// # Cookie Configuration
+/// - **Name**: `SESSION_COOKIE_NAME`, defaults to `id`
+/// - **Domain**: `SESSION_COOKIE_DOMAIN`, defaults to the request host
+/// - **Secure**: `SESSION_COOKIE_SECURE`, defaults to enabled in release builds
+/// - **SameSite**: `SESSION_COOKIE_SAME_SITE`, defaults to Lax (allows top-level navigation)
+/// - **HttpOnly**: Enabled (prevents JavaScript access)
+/// - **Expiry**: `SESSION_INACTIVITY_DAYS` of inactivity, defaults to 7 days
+///
+/// # Arguments
+/// - `config` - Application configuration containing the session cookie attributes and expiry
+/// - `redis_pool` - Connected Redis pool for session storage
+///
+/// # Returns
+/// - `Ok(SessionManagerLayer)` - Configured session middleware ready for Axum
+/// - `Err(AppError)` - Failed to create session store (unlikely with valid pool)
+///
+/// # Example
+/// ```ignore
+/// let redis_pool = connect_to_redis(&config).await?;
+/// let session_layer = connect_to_session(&config, redis_pool).await?;
+/// // Session layer can be added to Axum router
+/// ```
+pub async fn connect_to_session(
+    config: &Config,
+    redis_pool: Pool,
+) -> Result<SessionManagerLayer<RedisStore<Pool>>, AppError> {
+    use tower_sessions::SessionManagerLayer;
+
+    let session_store = RedisStore::new(redis_pool);
+
+    let mut session = SessionManagerLayer::new(session_store)
+        .with_name(config.session_cookie_name.clone())
+        .with_secure(config.session_cookie_secure)
+        .with_same_site(config.session_cookie_same_site)
+        .with_http_only(true)
+        .with_expiry(config.session_expiry.default_expiry());
+
+    if let Some(domain) = &config.session_cookie_domain {
+        session = session.with_domain(domain.clone());
+    }
+
+    Ok(session)
+}
+
+/// Builds the object storage client from configuration.
+///
+/// # Arguments
+/// - `config` - Application configuration containing the object storage settings
+///
+/// # Returns
+/// - `Ok(Some(ObjectStorage))` - Client for the configured bucket
+/// - `Ok(None)` - Object storage is not configured
+/// - `Err(AppError)` - Failed to build the HTTP client
+pub fn build_object_storage(config: &Config) -> Result<Option<ObjectStorage>, AppError> {
+    let Some(settings) = &config.object_storage else {
+        return Ok(None);
+    };
+
+    let http = reqwest::Client::builder()
+        .user_agent(&config.user_agent)
+        .build()?;
+
+    Ok(Some(ObjectStorage::new(settings.clone(), http)))
+}
+
+/// Preloads frequently accessed lookup data before the server starts serving traffic.
+///
+/// Ensures NPC faction records are stored and within ESI's daily cache, so the first
+/// character, corporation, or alliance lookups after a deploy don't have to fetch factions
+/// from ESI while a user waits. Factions that are still fresh are not requested again.
+///
+/// Failures are not fatal: the data is loaded on demand instead, so callers should log the
+/// error and continue starting up.
+///
+/// # Arguments
+/// - `db` - Database connection the lookup data is stored in
+/// - `esi_provider` - ESI provider used if the stored data is stale
+///
+/// # Returns
+/// - `Ok(())` - Lookup data is loaded and fresh
+/// - `Err(AppError)` - Failed to fetch or store the lookup data
+pub async fn warm_up(db: &DatabaseConnection, esi_provider: &EsiProvider) -> Result<(), AppError> {
+    let started = std::time::Instant::now();
+
+    let factions = FactionService::new(db, esi_provider).update().await?;
+
+    tracing::info!(
+        "Warm-up finished in {:?} ({} faction(s) refreshed)",
+        started.elapsed(),
+        factions.len()
+    );
+
+    Ok(())
+}
+
+/// Pushes all stored entities to the search index in a supervised background task.
+///
+/// Entities stored before Meilisearch was configured, or while it was unreachable, are not in
+/// the index; worker jobs only index entities as they are refreshed. Searches keep working
+/// during the reindex, entities missing from the index just aren't found yet. If the reindex
+/// fails, the supervisor retries it after a backoff delay.
+///
+/// Does nothing if searches are served from the database.
+///
+/// # Arguments
+/// - `db` - Database connection the entities are read from
+/// - `search` - Search settings holding the Meilisearch client
+/// - `supervisor` - Supervisor owning the reindex task
+pub fn start_search_reindex(
+    db: DatabaseConnection,
+    search: SearchConfig,
+    supervisor: &TaskSupervisor,
+) {
+    if search.meilisearch.is_none() {
+        return;
+    }
+
+    supervisor.spawn("search reindex", move || {
+        let db = db.clone();
+        let search = search.clone();
+        async move {
+            let indexed = SearchService::new(&db, &search).reindex().await?;
+            tracing::info!("Search index rebuilt with {} entities", indexed);
+
+            Ok(())
+        }
+    });
+}
+
+/// Initializes and starts the background worker system.
+///
+/// Creates a worker pool with the configured number of worker threads, initializes the job
+/// handler with database and ESI client, and starts the worker pool to begin processing jobs
+/// from the Redis-backed queue. Workers handle background tasks like ESI data refresh for
+/// alliances, corporations, characters, and affiliations.
+/// Starts the background worker pool for processing EVE Online data update jobs.
+///
+/// Creates a worker queue, initializes the job handler with database and ESI provider,
+/// and starts the worker pool to begin processing jobs. The worker pool is configured
+/// with the number of workers specified in the application config.
+///
+/// # Arguments
+/// - `config` - Application configuration containing worker pool size, Web Push, and Discord
+///   webhook settings
+/// - `db` - Database connection for workers to persist data
+/// - `redis_pool` - Redis pool for the worker queue backend
+/// - `esi_provider` - ESI provider with circuit breaker protection for data endpoints
+/// - `esi_client` - ESI client for OAuth2 flows
+/// - `plugins` - Registered plugins handling custom worker jobs
+/// - `read_only` - Read-only mode flag pausing workers during database maintenance
+/// - `supervisor` - Supervisor running the queue cleanup task
+///
+/// # Returns
+/// - `Ok(Worker)` - Started worker system ready to process jobs
+/// - `Err(AppError)` - Failed to build the push, search, or object storage HTTP client or create or
+///   start worker pool
+///
+/// # Example
+/// ```ignore
+/// let worker = start_workers(&config, db, redis_pool, esi_provider, plugins.clone(), read_only.clone(), &supervisor).await?;
+/// // Workers are now processing jobs from the queue
+/// ```
+pub async fn start_workers(
+    config: &Config,
+    db: DatabaseConnection,
+    redis_pool: Pool,
+    esi_provider: EsiProvider,
+    plugins: PluginRegistry,
+    read_only: ReadOnlyMode,
+    supervisor: &TaskSupervisor,
+) -> Result<Worker, AppError> {
+    // Create queue first so it can be passed to the handler
+    let queue = WorkerQueue::new(redis_pool).with_supervisor(supervisor.clone());
+
+    // Push endpoints are provided by browsers, so redirects that could lead the worker to
+    // internal hosts are not followed
+    let push_client = reqwest::Client::builder()
+        .user_agent(&config.user_agent)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?;
+
+    // Create handler with queue and ESI downtime offset enabled
+    let handler = WorkerJobHandler::new(db, esi_provider, queue.clone(), true)
+        .with_plugins(plugins)
+        .with_push(PushConfig::from_config(config), push_client)
+        .with_search(SearchConfig::from_config(config)?)
+        .with_discord_webhook(config.discord_webhook_url.clone())
+        .with_read_only(read_only)
+        .with_object_storage(build_object_storage(config)?)
+        .with_cipher(ColumnCipher::new(config.encryption_keys.clone()));
+
+    // Create worker with pool config
+    let pool_config = WorkerPoolConfig::new(config.workers);
+    let worker = Worker::with_queue(pool_config, queue, handler);
+
+    worker.pool.start().await?;
+
+    Ok(worker)
+}
+
+/// Initialize and start the scheduler in a supervised background task.
+///
+/// Spawns a task under the supervisor that creates the scheduler, registers all EVE Online data
+/// refresh jobs (factions, alliances, corporations, characters, and affiliations), and begins
+/// executing them according to their configured cron schedules. Scheduled jobs of registered
+/// plugins are added, and if telemetry is enabled, the daily telemetry report is registered as
+/// well.
+///
+/// If creating or starting the scheduler fails, the supervisor logs the error and retries with
+/// a fresh scheduler after a backoff delay.
+///
+/// # Arguments
+/// - `db` - Database connection for querying entities that need updates
+/// - `queue` - Worker queue for dispatching asynchronous refresh tasks
+/// - `telemetry` - Telemetry settings, the report is only scheduled if an endpoint is set
+/// - `plugins` - Registered plugins whose scheduled jobs are added
+/// - `settings` - Batch sizes, stagger window, and jitter for entity refreshes
+/// - `cron` - Cron expressions of the built-in scheduled jobs
+/// - `supervisor` - Supervisor owning the scheduler task
+///
+/// # Returns
+/// - `Ok(())` - Scheduler task successfully spawned
+/// - `Err(AppError)` - Failed to build the telemetry HTTP client (occurs before spawning)
+pub async fn start_scheduler(
+    db: DatabaseConnection,
+    queue: WorkerQueue,
+    telemetry: TelemetryConfig,
+    plugins: PluginRegistry,
+    settings: SchedulerSettings,
+    cron: CronSchedules,
+    supervisor: &TaskSupervisor,
+) -> Result<(), AppError> {
+    let telemetry_client = match telemetry.endpoint {
+        Some(_) => Some(
+            reqwest::Client::builder()
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build()?,
+        ),
+        None => None,
+    };
+
+    if telemetry_client.is_some() {
+        tracing::info!("Anonymous telemetry is enabled");
+    }
+
+    supervisor.spawn("scheduler", move || {
+        run_scheduler(
+            db.clone(),
+            queue.clone(),
+            telemetry.clone(),
+            telemetry_client.clone(),
+            plugins.clone(),
+            settings,
+            cron.clone(),
+        )
+    });
+
+    Ok(())
+}
+
+/// Creates the scheduler with all jobs registered and keeps it running.
+///
+/// # Arguments
+/// - `db` - Database connection for querying entities that need updates
+/// - `queue` - Worker queue for dispatching asynchronous refresh tasks
+/// - `telemetry` - Telemetry settings sent with the report
+/// - `telemetry_client` - HTTP client for the telemetry report, `None` if telemetry is disabled
+/// - `plugins` - Registered plugins whose scheduled jobs are added
+/// - `settings` - Batch sizes, stagger window, and jitter for entity refreshes
+/// - `cron` - Cron expressions of the built-in scheduled jobs
+///
+/// # Returns
+/// - `Err(AppError)` - Failed to create the scheduler, register a job, or start the scheduler;
+///   never returns otherwise
+async fn run_scheduler(
+    db: DatabaseConnection,
+    queue: WorkerQueue,
+    telemetry: TelemetryConfig,
+    telemetry_client: Option<reqwest::Client>,
+    plugins: PluginRegistry,
+    settings: SchedulerSettings,
+    cron: CronSchedules,
+) -> Result<(), AppError> {
+    let mut scheduler = Scheduler::new(db, queue, true, settings).await?;
+
+    for PluginScheduledJob { cron, name, job } in plugins.scheduled_jobs() {
+        scheduler
+            .schedule_job(cron, name, move |state| {
+                let job = job.clone();
+
+                async move { Ok(usize::from(state.queue.push(job).await?)) }
+            })
+            .await?;
+    }
+
+    if let Some(client) = telemetry_client {
+        scheduler
+            .schedule_job(&cron.telemetry, "telemetry report", move |state| {
+                send_telemetry_report(state, telemetry.clone(), client.clone())
+            })
+            .await?;
+    }
+
+    scheduler.start(&cron).await?;
+
+    tracing::info!("Job scheduler started");
+
+    // Jobs run on tasks owned by the scheduler, keep this task alive so diagnostics show the
+    // scheduler as running rather than stopped
+    std::future::pending::<()>().await;
+
+    Ok(())
+}
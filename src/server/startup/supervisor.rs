@@ -0,0 +1,302 @@
+//! Supervision of long-running background tasks.
+//!
+//! This module provides the `TaskSupervisor`, which runs background loops such as the job
+//! scheduler and the worker queue cleanup in their own Tokio tasks and restarts them with
+//! exponential backoff if they panic or return an error. Without supervision a panic would end
+//! the task silently while the server keeps serving requests. The supervisor also records the
+//! health of each task for the admin diagnostics endpoint.
+
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use dioxus_logger::tracing;
+use tokio::task::JoinHandle;
+
+use crate::server::error::AppError;
+
+/// Delay before restarting a task after its first failure.
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Upper bound for the restart delay of a repeatedly failing task.
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Time a task must run before failing for its backoff to reset to the initial delay.
+const HEALTHY_RUN_DURATION: Duration = Duration::from_secs(300);
+
+/// Lifecycle state of a supervised task.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// Task is running.
+    Running,
+    /// Task failed and is waiting for its backoff to elapse before restarting.
+    Restarting,
+    /// Task finished or was cancelled and won't be restarted.
+    Stopped,
+}
+
+impl TaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Restarting => "restarting",
+            Self::Stopped => "stopped",
+        }
+    }
+}
+
+/// Health of a supervised task.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskHealth {
+    /// Name the task was spawned with.
+    pub name: String,
+    /// Current lifecycle state.
+    pub status: TaskStatus,
+    /// Number of times the task was restarted after failing.
+    pub restarts: u32,
+    /// Panic message or error of the most recent failure.
+    pub last_error: Option<String>,
+    /// When the current (or last) run of the task started.
+    pub started_at: DateTime<Utc>,
+}
+
+/// Owner of long-running background tasks, restarting them when they fail.
+///
+/// Cloning is cheap; clones share the recorded task health.
+#[derive(Clone)]
+pub struct TaskSupervisor {
+    tasks: Arc<Mutex<BTreeMap<String, TaskHealth>>>,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for TaskSupervisor {
+    fn default() -> Self {
+        Self::with_backoff(DEFAULT_INITIAL_BACKOFF, DEFAULT_MAX_BACKOFF)
+    }
+}
+
+impl TaskSupervisor {
+    /// Creates a supervisor restarting failed tasks after 1 second, doubling up to 1 minute.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a supervisor with custom restart delays.
+    ///
+    /// # Arguments
+    /// - `initial_backoff` - Delay before restarting a task after its first failure
+    /// - `max_backoff` - Upper bound the delay doubles towards on consecutive failures
+    ///
+    /// # Returns
+    /// - `TaskSupervisor` - Supervisor without any tasks
+    pub fn with_backoff(initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            tasks: Arc::new(Mutex::new(BTreeMap::new())),
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Spawns a supervised task.
+    ///
+    /// `task` is called to start each run of the task. A run ending with `Ok(())` stops the
+    /// task, while a run that panics or returns an error is restarted after the backoff delay.
+    /// The delay doubles with each consecutive failure and resets once a run lasted 5 minutes.
+    ///
+    /// Aborting the returned handle stops restarts but does not cancel a run in progress, so
+    /// tasks should provide their own shutdown signal.
+    ///
+    /// # Arguments
+    /// - `name` - Name shown in logs and diagnostics
+    /// - `task` - Function starting a run of the task
+    ///
+    /// # Returns
+    /// - `JoinHandle<()>` - Handle completing once the task stopped
+    pub fn spawn<F, Fut>(&self, name: &str, task: F) -> JoinHandle<()>
+    where
+        F: Fn() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), AppError>> + Send + 'static,
+    {
+        let supervisor = self.clone();
+        let name = name.to_string();
+
+        tokio::spawn(async move {
+            let mut backoff = supervisor.initial_backoff;
+
+            loop {
+                supervisor.update(&name, |health| {
+                    health.status = TaskStatus::Running;
+                    health.started_at = Utc::now();
+                });
+                let started = Instant::now();
+
+                let failure = match tokio::spawn(task()).await {
+                    Ok(Ok(())) => None,
+                    Ok(Err(e)) => Some(e.to_string()),
+                    Err(e) if e.is_panic() => Some(panic_message(e.into_panic())),
+                    Err(_) => None,
+                };
+
+                let Some(error) = failure else {
+                    tracing::info!("Background task {} stopped", name);
+                    supervisor.update(&name, |health| health.status = TaskStatus::Stopped);
+                    break;
+                };
+
+                if started.elapsed() >= HEALTHY_RUN_DURATION {
+                    backoff = supervisor.initial_backoff;
+                }
+
+                tracing::error!(
+                    "Background task {} failed, restarting in {:?}: {}",
+                    name,
+                    backoff,
+                    error
+                );
+                supervisor.update(&name, |health| {
+                    health.status = TaskStatus::Restarting;
+                    health.restarts += 1;
+                    health.last_error = Some(error);
+                });
+
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(supervisor.max_backoff);
+            }
+        })
+    }
+
+    /// Returns the health of all tasks spawned by this supervisor, ordered by name.
+    pub fn health(&self) -> Vec<TaskHealth> {
+        self.lock().values().cloned().collect()
+    }
+
+    /// Updates the recorded health of a task, adding it if it wasn't recorded yet.
+    fn update(&self, name: &str, update: impl FnOnce(&mut TaskHealth)) {
+        let mut tasks = self.lock();
+        let health = tasks.entry(name.to_string()).or_insert_with(|| TaskHealth {
+            name: name.to_string(),
+            status: TaskStatus::Running,
+            restarts: 0,
+            last_error: None,
+            started_at: Utc::now(),
+        });
+
+        update(health);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, TaskHealth>> {
+        // Health is only written in short updates, so a poisoned map is still consistent
+        self.tasks.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Extracts the message of a panic payload.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        format!("panicked: {}", message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        format!("panicked: {}", message)
+    } else {
+        "panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::server::error::config::ConfigError;
+
+    fn supervisor() -> TaskSupervisor {
+        TaskSupervisor::with_backoff(Duration::from_millis(1), Duration::from_millis(1))
+    }
+
+    /// Tests that a task finishing successfully is not restarted.
+    ///
+    /// Expected: Single run, task recorded as stopped without restarts
+    #[tokio::test]
+    async fn stops_completed_task() {
+        let supervisor = supervisor();
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let counter = runs.clone();
+        supervisor
+            .spawn("task", move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { Ok(()) }
+            })
+            .await
+            .unwrap();
+
+        let health = supervisor.health();
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert_eq!(health.len(), 1);
+        assert_eq!(health[0].status, TaskStatus::Stopped);
+        assert_eq!(health[0].restarts, 0);
+    }
+
+    /// Tests that a panicking task is restarted.
+    ///
+    /// Expected: Task restarted once with the panic message recorded
+    #[tokio::test]
+    async fn restarts_panicked_task() {
+        let supervisor = supervisor();
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let counter = runs.clone();
+        supervisor
+            .spawn("task", move || {
+                let run = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if run == 0 {
+                        panic!("boom");
+                    }
+                    Ok(())
+                }
+            })
+            .await
+            .unwrap();
+
+        let health = supervisor.health();
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        assert_eq!(health[0].status, TaskStatus::Stopped);
+        assert_eq!(health[0].restarts, 1);
+        assert_eq!(health[0].last_error.as_deref(), Some("panicked: boom"));
+    }
+
+    /// Tests that a task returning an error is restarted.
+    ///
+    /// Expected: Task restarted until it succeeds, error recorded
+    #[tokio::test]
+    async fn restarts_failed_task() {
+        let supervisor = supervisor();
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let counter = runs.clone();
+        supervisor
+            .spawn("task", move || {
+                let run = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if run < 2 {
+                        return Err(ConfigError::MissingEnvVar("TEST".to_string()).into());
+                    }
+                    Ok(())
+                }
+            })
+            .await
+            .unwrap();
+
+        let health = supervisor.health();
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        assert_eq!(health[0].restarts, 2);
+        assert!(health[0].last_error.as_deref().unwrap().contains("TEST"));
+    }
+}
@@ -0,0 +1,237 @@
+//! Instance branding settings.
+//!
+//! This module parses the `BRANDING_*` environment variables that let deployments rename
+//! Bifrost, show their own logo and primary color, and link to external tools from the
+//! navigation bar without forking the frontend. Values end up in the page's CSS and links,
+//! so colors are restricted to hex notation and URLs to `http`, `https`, or site-relative
+//! paths.
+//!
+//! # Navigation Links
+//!
+//! Links are configured as a comma-separated list of `Label|URL` pairs in the
+//! `BRANDING_NAV_LINKS` environment variable, e.g. `Wiki|https://wiki.example.com,SRP|/srp`.
+
+use thiserror::Error;
+
+use crate::model::branding::{BrandingDto, NavLinkDto};
+
+/// Error returned when parsing branding settings.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum BrandingError {
+    /// Color is not in `#rgb` or `#rrggbb` notation.
+    #[error("Invalid color {0:?}: must be in #rgb or #rrggbb notation")]
+    InvalidColor(String),
+
+    /// URL is neither an `http` or `https` URL nor a site-relative path.
+    #[error("Invalid URL {0:?}: must be an http or https URL or a path starting with /")]
+    InvalidUrl(String),
+
+    /// A `BRANDING_NAV_LINKS` entry could not be parsed.
+    #[error("Invalid navigation link {0:?}: must be in Label|URL notation")]
+    InvalidNavLink(String),
+}
+
+/// Link to an external tool shown in the navigation bar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NavLink {
+    /// Text of the link.
+    pub label: String,
+    /// Target of the link.
+    pub url: String,
+}
+
+/// Branding settings applied by the frontend layout.
+///
+/// Every setting is optional; unset settings keep Bifrost's default name, logo, and theme.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BrandingSettings {
+    /// Organization name shown instead of "Bifrost".
+    pub org_name: Option<String>,
+    /// URL of the logo shown next to the organization name.
+    pub logo_url: Option<String>,
+    /// Primary theme color in hex notation.
+    pub primary_color: Option<String>,
+    /// Links to external tools shown in the navigation bar.
+    pub nav_links: Vec<NavLink>,
+}
+
+impl BrandingSettings {
+    /// Converts the settings into the DTO served to the frontend.
+    ///
+    /// # Returns
+    /// - `BrandingDto` - Branding settings with navigation links in configured order
+    pub fn to_dto(&self) -> BrandingDto {
+        BrandingDto {
+            org_name: self.org_name.clone(),
+            logo_url: self.logo_url.clone(),
+            primary_color: self.primary_color.clone(),
+            nav_links: self
+                .nav_links
+                .iter()
+                .map(|link| NavLinkDto {
+                    label: link.label.clone(),
+                    url: link.url.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Checks that a color is in `#rgb` or `#rrggbb` notation.
+///
+/// # Arguments
+/// - `value` - Color to check
+///
+/// # Returns
+/// - `Ok(String)` - Valid color
+/// - `Err(BrandingError::InvalidColor)` - Color is in any other notation
+pub fn parse_color(value: &str) -> Result<String, BrandingError> {
+    let valid = value.strip_prefix('#').is_some_and(|hex| {
+        matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    });
+
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(BrandingError::InvalidColor(value.to_string()))
+    }
+}
+
+/// Checks that a URL is an `http` or `https` URL or a site-relative path.
+///
+/// Protocol-relative URLs such as `//example.com` are rejected along with other schemes
+/// like `javascript:`, which would run in the page when clicked.
+///
+/// # Arguments
+/// - `value` - URL to check
+///
+/// # Returns
+/// - `Ok(String)` - Valid URL
+/// - `Err(BrandingError::InvalidUrl)` - URL uses any other scheme or can't be parsed
+pub fn parse_url(value: &str) -> Result<String, BrandingError> {
+    let valid = if value.starts_with('/') {
+        !value.starts_with("//")
+    } else {
+        reqwest::Url::parse(value).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+    };
+
+    if valid {
+        Ok(value.to_string())
+    } else {
+        Err(BrandingError::InvalidUrl(value.to_string()))
+    }
+}
+
+/// Parses a comma-separated list of `Label|URL` navigation links.
+///
+/// Empty entries are ignored, so an empty string adds no links.
+///
+/// # Arguments
+/// - `value` - Comma-separated links, e.g. `Wiki|https://wiki.example.com,SRP|/srp`
+///
+/// # Returns
+/// - `Ok(Vec<NavLink>)` - Parsed links in configured order
+/// - `Err(BrandingError)` - An entry has no label or an invalid URL
+pub fn parse_nav_links(value: &str) -> Result<Vec<NavLink>, BrandingError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (label, url) = entry
+                .split_once('|')
+                .map(|(label, url)| (label.trim(), url.trim()))
+                .filter(|(label, _)| !label.is_empty())
+                .ok_or_else(|| BrandingError::InvalidNavLink(entry.to_string()))?;
+
+            Ok(NavLink {
+                label: label.to_string(),
+                url: parse_url(url)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expected: Ok for hex colors, Err(BrandingError::InvalidColor) otherwise
+    #[test]
+    fn parses_hex_colors_only() {
+        assert_eq!(parse_color("#7f1d1d"), Ok("#7f1d1d".to_string()));
+        assert_eq!(parse_color("#FFF"), Ok("#FFF".to_string()));
+
+        for color in ["7f1d1d", "#7f1d1", "#gggggg", "red; }", "oklch(50% 0.1 20)"] {
+            assert_eq!(
+                parse_color(color),
+                Err(BrandingError::InvalidColor(color.to_string())),
+                "{color}"
+            );
+        }
+    }
+
+    /// Expected: Ok for http(s) URLs and paths, Err(BrandingError::InvalidUrl) otherwise
+    #[test]
+    fn parses_safe_urls_only() {
+        for url in [
+            "https://wiki.example.com",
+            "http://example.com/logo.png",
+            "/srp",
+        ] {
+            assert_eq!(parse_url(url), Ok(url.to_string()), "{url}");
+        }
+
+        for url in [
+            "javascript:alert(1)",
+            "//example.com",
+            "example.com",
+            "ftp://example.com",
+        ] {
+            assert_eq!(
+                parse_url(url),
+                Err(BrandingError::InvalidUrl(url.to_string())),
+                "{url}"
+            );
+        }
+    }
+
+    /// Expected: Ok with links in configured order, empty entries ignored
+    #[test]
+    fn parses_nav_links() {
+        let links = parse_nav_links("Wiki | https://wiki.example.com, ,SRP|/srp").unwrap();
+
+        assert_eq!(
+            links,
+            vec![
+                NavLink {
+                    label: "Wiki".to_string(),
+                    url: "https://wiki.example.com".to_string(),
+                },
+                NavLink {
+                    label: "SRP".to_string(),
+                    url: "/srp".to_string(),
+                },
+            ]
+        );
+    }
+
+    /// Expected: Err for entries without a label or separator, or with an unsafe URL
+    #[test]
+    fn rejects_invalid_nav_links() {
+        assert_eq!(
+            parse_nav_links("https://wiki.example.com"),
+            Err(BrandingError::InvalidNavLink(
+                "https://wiki.example.com".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_nav_links("|/srp"),
+            Err(BrandingError::InvalidNavLink("|/srp".to_string()))
+        );
+        assert_eq!(
+            parse_nav_links("Wiki|javascript:alert(1)"),
+            Err(BrandingError::InvalidUrl("javascript:alert(1)".to_string()))
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Caching headers for static frontend assets.
+//!
+//! This module provides middleware adding `Cache-Control` and `ETag` headers to the static
+//! assets served for the Dioxus frontend, and answering conditional requests with
+//! `304 Not Modified`. Assets bundled with `asset!` have a content hash in their file name
+//! and are cached indefinitely; other static files are revalidated on every use.
+//!
+//! ETags are weak validators derived from the file size and modification time reported by
+//! the static file service, so the file does not have to be read and hashed per request.
+
+use axum::{
+    extract::Request,
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+/// Path prefixes the Dioxus frontend serves static files from.
+const STATIC_PATH_PREFIXES: &[&str] = &["/assets/", "/wasm/"];
+
+/// Marker Dioxus inserts before the content hash of bundled asset file names.
+const ASSET_HASH_MARKER: &str = "-dxh";
+
+/// Cache policy for assets whose file name changes with their content.
+const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Cache policy for static files that may change without their file name changing.
+const REVALIDATE_CACHE_CONTROL: &str = "no-cache";
+
+/// Returns the `Cache-Control` policy for a request path, if it is a static asset.
+///
+/// # Arguments
+/// - `path` - Request path
+///
+/// # Returns
+/// - `Some(&str)` - Cache policy for the static asset
+/// - `None` - The path is not a static asset
+pub fn cache_control_for(path: &str) -> Option<&'static str> {
+    if !STATIC_PATH_PREFIXES
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+    {
+        return None;
+    }
+
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+
+    if file_name.contains(ASSET_HASH_MARKER) {
+        Some(IMMUTABLE_CACHE_CONTROL)
+    } else {
+        Some(REVALIDATE_CACHE_CONTROL)
+    }
+}
+
+/// Derives a weak ETag from the response's `Content-Length` and `Last-Modified` headers.
+///
+/// # Arguments
+/// - `headers` - Response headers from the static file service
+///
+/// # Returns
+/// - `Some(HeaderValue)` - Weak ETag such as `W/"1024-Wed, 21 Oct 2026 07:28:00 GMT"`
+/// - `None` - Either header is missing, so no stable validator can be derived
+pub fn etag_for(headers: &HeaderMap) -> Option<HeaderValue> {
+    let length = headers.get(header::CONTENT_LENGTH)?.to_str().ok()?;
+    let modified = headers.get(header::LAST_MODIFIED)?.to_str().ok()?;
+
+    HeaderValue::from_str(&format!("W/\"{}-{}\"", length, modified)).ok()
+}
+
+/// Returns whether an `If-None-Match` header matches the ETag.
+///
+/// Uses weak comparison as required for `If-None-Match`, ignoring the `W/` prefix.
+///
+/// # Arguments
+/// - `if_none_match` - Value of the request's `If-None-Match` header
+/// - `etag` - ETag of the current representation
+///
+/// # Returns
+/// - `true` - The client's cached copy is current
+/// - `false` - The client must receive the full response
+pub fn matches_etag(if_none_match: &str, etag: &str) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = opaque(etag);
+
+    if_none_match
+        .split(',')
+        .any(|candidate| candidate.trim() == "*" || opaque(candidate) == etag)
+}
+
+/// Middleware adding caching headers to static asset responses.
+///
+/// Requests outside the static asset paths are passed through unchanged. For successful
+/// `GET` and `HEAD` requests of static assets, adds `Cache-Control` and a weak `ETag` unless
+/// already present, and responds with `304 Not Modified` if the request's `If-None-Match`
+/// matches the ETag.
+///
+/// # Arguments
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Asset response with caching headers, or `304 Not Modified`
+pub async fn static_asset_cache(request: Request, next: Next) -> Response {
+    let cache_control = match *request.method() {
+        Method::GET | Method::HEAD => cache_control_for(request.uri().path()),
+        _ => None,
+    };
+    let Some(cache_control) = cache_control else {
+        return next.run(request).await;
+    };
+
+    let if_none_match = request
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let mut response = next.run(request).await;
+
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+
+    let headers = response.headers_mut();
+    headers
+        .entry(header::CACHE_CONTROL)
+        .or_insert(HeaderValue::from_static(cache_control));
+
+    if !headers.contains_key(header::ETAG) {
+        if let Some(etag) = etag_for(headers) {
+            headers.insert(header::ETAG, etag);
+        }
+    }
+
+    let etag = headers
+        .get(header::ETAG)
+        .and_then(|value| value.to_str().ok());
+
+    if let (Some(if_none_match), Some(etag)) = (if_none_match.as_deref(), etag) {
+        if matches_etag(if_none_match, etag) {
+            let mut not_modified = StatusCode::NOT_MODIFIED.into_response();
+
+            for name in [header::CACHE_CONTROL, header::ETAG, header::LAST_MODIFIED] {
+                if let Some(value) = headers.get(&name) {
+                    not_modified.headers_mut().insert(name, value.clone());
+                }
+            }
+
+            return not_modified;
+        }
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod cache_control_for {
+        use super::*;
+
+        /// Tests that hashed bundle assets are cached indefinitely.
+        ///
+        /// Expected: Immutable cache policy
+        #[test]
+        fn caches_hashed_assets_indefinitely() {
+            assert_eq!(
+                cache_control_for("/assets/tailwind-dxh4a1b2c3d.css"),
+                Some(IMMUTABLE_CACHE_CONTROL)
+            );
+        }
+
+        /// Tests that static files without a content hash are revalidated.
+        ///
+        /// Expected: Revalidation cache policy
+        #[test]
+        fn revalidates_unhashed_assets() {
+            assert_eq!(
+                cache_control_for("/assets/favicon.ico"),
+                Some(REVALIDATE_CACHE_CONTROL)
+            );
+        }
+
+        /// Tests that non-asset paths are not affected.
+        ///
+        /// Expected: None for API and page paths
+        #[test]
+        fn ignores_non_asset_paths() {
+            assert_eq!(cache_control_for("/api/auth/user"), None);
+            assert_eq!(cache_control_for("/auth/admin"), None);
+            assert_eq!(cache_control_for("/assets"), None);
+        }
+    }
+
+    mod etag_for {
+        use super::*;
+
+        /// Tests ETag derivation from length and modification time.
+        ///
+        /// Expected: Weak ETag combining both headers
+        #[test]
+        fn derives_weak_etag() {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("1024"));
+            headers.insert(
+                header::LAST_MODIFIED,
+                HeaderValue::from_static("Wed, 21 Oct 2026 07:28:00 GMT"),
+            );
+
+            assert_eq!(
+                etag_for(&headers).unwrap(),
+                "W/\"1024-Wed, 21 Oct 2026 07:28:00 GMT\""
+            );
+        }
+
+        /// Tests that no ETag is derived without a modification time.
+        ///
+        /// Expected: None
+        #[test]
+        fn requires_last_modified() {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("1024"));
+
+            assert!(etag_for(&headers).is_none());
+        }
+    }
+
+    mod matches_etag {
+        use super::*;
+
+        /// Tests weak comparison of ETags in `If-None-Match`.
+        ///
+        /// Expected: Matches regardless of the weak prefix and within lists
+        #[test]
+        fn matches_weak_and_listed_etags() {
+            assert!(matches_etag("W/\"abc\"", "W/\"abc\""));
+            assert!(matches_etag("\"abc\"", "W/\"abc\""));
+            assert!(matches_etag("\"xyz\", W/\"abc\"", "W/\"abc\""));
+            assert!(matches_etag("*", "W/\"abc\""));
+        }
+
+        /// Tests that different ETags do not match.
+        ///
+        /// Expected: false
+        #[test]
+        fn rejects_different_etag() {
+            assert!(!matches_etag("W/\"xyz\"", "W/\"abc\""));
+        }
+    }
+}
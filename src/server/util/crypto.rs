@@ -0,0 +1,366 @@
+//! Application-layer column encryption.
+//!
+//! This module provides `ColumnCipher` for encrypting sensitive column values (such as wallet
+//! journals and character locations) before they are written to the database, so they are
+//! stored as ciphertext at rest. Values are encrypted with AES-256-GCM using the active key
+//! from `Config`, and each ciphertext records the ID of the key used so older keys can still
+//! decrypt existing rows after a key rotation.
+//!
+//! # Ciphertext Format
+//!
+//! `v{key_id}:{base64(nonce || ciphertext)}` where the nonce is a random 96-bit value
+//! generated for every encryption.
+//!
+//! # Key Rotation
+//!
+//! Keys are configured as a comma-separated list of `id:base64_key` entries in the
+//! `ENCRYPTION_KEYS` environment variable. The first entry is the active key used for new
+//! encryptions; the remaining entries are only used for decryption. To rotate, prepend a new
+//! key with a higher ID and keep the previous keys until `needs_reencryption` reports no
+//! remaining rows encrypted with them.
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use thiserror::Error;
+
+/// Length in bytes of an AES-256 key.
+const KEY_LENGTH: usize = 32;
+
+/// Length in bytes of an AES-GCM nonce.
+const NONCE_LENGTH: usize = 12;
+
+/// Error returned when configuring keys or encrypting and decrypting column values.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum EncryptionError {
+    /// An `ENCRYPTION_KEYS` entry could not be parsed.
+    #[error("Invalid encryption key entry {entry:?}: {reason}")]
+    InvalidKey {
+        /// Key ID portion of the entry, or `?` if it could not be split.
+        entry: String,
+        /// Explanation of why the entry was rejected.
+        reason: String,
+    },
+
+    /// No encryption keys are configured.
+    #[error("No encryption keys are configured, set ENCRYPTION_KEYS to store sensitive data")]
+    NoKeys,
+
+    /// Ciphertext was encrypted with a key ID that is not configured.
+    #[error("Encryption key ID {0} is not configured")]
+    UnknownKey(u32),
+
+    /// Ciphertext is not in the expected format.
+    #[error("Malformed ciphertext")]
+    MalformedCiphertext,
+
+    /// Encryption or decryption failed, e.g. because the ciphertext was tampered with.
+    #[error("Failed to encrypt or decrypt value")]
+    CipherFailure,
+}
+
+/// A single AES-256 key with the ID recorded in ciphertexts it produces.
+#[derive(Clone)]
+pub struct EncryptionKey {
+    /// Key ID stored alongside ciphertexts to select the key for decryption.
+    pub id: u32,
+    cipher: Aes256Gcm,
+}
+
+impl EncryptionKey {
+    /// Creates an encryption key from raw key bytes.
+    ///
+    /// # Arguments
+    /// - `id` - Key ID stored alongside ciphertexts
+    /// - `key` - 32-byte AES-256 key
+    ///
+    /// # Returns
+    /// - `Ok(EncryptionKey)` - Key ready for use
+    /// - `Err(EncryptionError::InvalidKey)` - Key is not 32 bytes long
+    pub fn new(id: u32, key: &[u8]) -> Result<Self, EncryptionError> {
+        let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| EncryptionError::InvalidKey {
+            entry: id.to_string(),
+            reason: format!("key must be {} bytes, got {}", KEY_LENGTH, key.len()),
+        })?;
+
+        Ok(Self { id, cipher })
+    }
+}
+
+/// Never prints key material.
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionKey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Parses the `ENCRYPTION_KEYS` value into a list of keys.
+///
+/// # Arguments
+/// - `value` - Comma-separated `id:base64_key` entries, active key first
+///
+/// # Returns
+/// - `Ok(Vec<EncryptionKey>)` - Parsed keys in configured order (empty if `value` is blank)
+/// - `Err(EncryptionError::InvalidKey)` - An entry is malformed, not 32 bytes, or has a duplicate ID
+pub fn parse_encryption_keys(value: &str) -> Result<Vec<EncryptionKey>, EncryptionError> {
+    let mut keys: Vec<EncryptionKey> = Vec::new();
+
+    for entry in value.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let invalid = |reason: &str| EncryptionError::InvalidKey {
+            entry: entry.split_once(':').map_or("?", |(id, _)| id).to_string(),
+            reason: reason.to_string(),
+        };
+
+        let (id, key) = entry
+            .split_once(':')
+            .ok_or_else(|| invalid("expected format id:base64_key"))?;
+        let id: u32 = id
+            .trim()
+            .parse()
+            .map_err(|_| invalid("key ID must be a number"))?;
+        let key = STANDARD
+            .decode(key.trim())
+            .map_err(|_| invalid("key must be valid base64"))?;
+
+        if keys.iter().any(|k| k.id == id) {
+            return Err(invalid("duplicate key ID"));
+        }
+
+        keys.push(EncryptionKey::new(id, &key)?);
+    }
+
+    Ok(keys)
+}
+
+/// Encrypts and decrypts sensitive column values.
+///
+/// Repositories storing sensitive data encrypt values with `encrypt` before writing, and
+/// services decrypt them with `decrypt` when mapping models to DTOs.
+#[derive(Clone, Debug)]
+pub struct ColumnCipher {
+    keys: Vec<EncryptionKey>,
+}
+
+impl ColumnCipher {
+    /// Creates a new ColumnCipher.
+    ///
+    /// # Arguments
+    /// - `keys` - Configured keys, the first being the active key used for encryption
+    ///
+    /// # Returns
+    /// - `ColumnCipher` - New cipher instance
+    pub fn new(keys: Vec<EncryptionKey>) -> Self {
+        Self { keys }
+    }
+
+    /// Returns true if any encryption key is configured.
+    ///
+    /// Optional features storing sensitive data check this to skip storing it instead of
+    /// failing when `ENCRYPTION_KEYS` is unset.
+    ///
+    /// # Returns
+    /// - `true` - Values can be encrypted
+    /// - `false` - `encrypt` fails with `EncryptionError::NoKeys`
+    pub fn is_configured(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// Encrypts a value with the active key.
+    ///
+    /// # Arguments
+    /// - `plaintext` - Value to encrypt
+    ///
+    /// # Returns
+    /// - `Ok(String)` - Ciphertext in `v{key_id}:{base64}` format
+    /// - `Err(EncryptionError::NoKeys)` - No encryption keys are configured
+    /// - `Err(EncryptionError::CipherFailure)` - Encryption failed
+    pub fn encrypt(&self, plaintext: &str) -> Result<String, EncryptionError> {
+        let key = self.keys.first().ok_or(EncryptionError::NoKeys)?;
+
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = key
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| EncryptionError::CipherFailure)?;
+
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&ciphertext);
+
+        Ok(format!("v{}:{}", key.id, STANDARD.encode(payload)))
+    }
+
+    /// Decrypts a value using the key it was encrypted with.
+    ///
+    /// # Arguments
+    /// - `ciphertext` - Value previously returned by `encrypt`
+    ///
+    /// # Returns
+    /// - `Ok(String)` - Decrypted value
+    /// - `Err(EncryptionError::MalformedCiphertext)` - Value is not in the expected format
+    /// - `Err(EncryptionError::UnknownKey)` - Key used for encryption is no longer configured
+    /// - `Err(EncryptionError::CipherFailure)` - Decryption failed or value was tampered with
+    pub fn decrypt(&self, ciphertext: &str) -> Result<String, EncryptionError> {
+        let (key_id, payload) = parse_ciphertext(ciphertext)?;
+
+        let key = self
+            .keys
+            .iter()
+            .find(|k| k.id == key_id)
+            .ok_or(EncryptionError::UnknownKey(key_id))?;
+
+        if payload.len() < NONCE_LENGTH {
+            return Err(EncryptionError::MalformedCiphertext);
+        }
+        let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+
+        let plaintext = key
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| EncryptionError::CipherFailure)?;
+
+        String::from_utf8(plaintext).map_err(|_| EncryptionError::CipherFailure)
+    }
+
+    /// Returns true if a value was encrypted with a key other than the active key.
+    ///
+    /// Used after a key rotation to find rows that should be decrypted and encrypted again
+    /// so the previous key can be removed from the configuration.
+    ///
+    /// # Arguments
+    /// - `ciphertext` - Value previously returned by `encrypt`
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Value should be re-encrypted with the active key
+    /// - `Ok(false)` - Value is already encrypted with the active key
+    /// - `Err(EncryptionError::MalformedCiphertext)` - Value is not in the expected format
+    /// - `Err(EncryptionError::NoKeys)` - No encryption keys are configured
+    pub fn needs_reencryption(&self, ciphertext: &str) -> Result<bool, EncryptionError> {
+        let active = self.keys.first().ok_or(EncryptionError::NoKeys)?;
+        let (key_id, _) = parse_ciphertext(ciphertext)?;
+
+        Ok(key_id != active.id)
+    }
+}
+
+/// Splits a ciphertext into its key ID and decoded nonce and ciphertext bytes.
+fn parse_ciphertext(ciphertext: &str) -> Result<(u32, Vec<u8>), EncryptionError> {
+    let (version, payload) = ciphertext
+        .strip_prefix('v')
+        .and_then(|c| c.split_once(':'))
+        .ok_or(EncryptionError::MalformedCiphertext)?;
+
+    let key_id = version
+        .parse()
+        .map_err(|_| EncryptionError::MalformedCiphertext)?;
+    let payload = STANDARD
+        .decode(payload)
+        .map_err(|_| EncryptionError::MalformedCiphertext)?;
+
+    Ok((key_id, payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a cipher from the given key IDs, using a distinct key for each ID.
+    fn cipher(ids: &[u32]) -> ColumnCipher {
+        ColumnCipher::new(
+            ids.iter()
+                .map(|&id| EncryptionKey::new(id, &[id as u8; KEY_LENGTH]).unwrap())
+                .collect(),
+        )
+    }
+
+    /// Tests that an encrypted value decrypts to the original plaintext.
+    ///
+    /// Expected: Ok with the original value, ciphertext not containing the plaintext
+    #[test]
+    fn round_trips_value() {
+        let cipher = cipher(&[1]);
+
+        let ciphertext = cipher.encrypt("Jita IV - Moon 4").unwrap();
+
+        assert!(ciphertext.starts_with("v1:"));
+        assert!(!ciphertext.contains("Jita"));
+        assert_eq!(cipher.decrypt(&ciphertext).unwrap(), "Jita IV - Moon 4");
+    }
+
+    /// Tests that values encrypted with a previous key still decrypt after rotation.
+    ///
+    /// Expected: Ok with the original value and the value flagged for re-encryption
+    #[test]
+    fn decrypts_with_rotated_key() {
+        let ciphertext = cipher(&[1]).encrypt("1000000 ISK").unwrap();
+        let rotated = cipher(&[2, 1]);
+
+        assert_eq!(rotated.decrypt(&ciphertext).unwrap(), "1000000 ISK");
+        assert!(rotated.needs_reencryption(&ciphertext).unwrap());
+        assert!(!rotated
+            .needs_reencryption(&rotated.encrypt("1000000 ISK").unwrap())
+            .unwrap());
+    }
+
+    /// Tests error handling when the key used for encryption was removed.
+    ///
+    /// Expected: Err(EncryptionError::UnknownKey)
+    #[test]
+    fn fails_for_removed_key() {
+        let ciphertext = cipher(&[1]).encrypt("secret").unwrap();
+
+        assert_eq!(
+            cipher(&[2]).decrypt(&ciphertext),
+            Err(EncryptionError::UnknownKey(1))
+        );
+    }
+
+    /// Tests error handling for tampered ciphertext.
+    ///
+    /// Expected: Err(EncryptionError::CipherFailure)
+    #[test]
+    fn fails_for_tampered_ciphertext() {
+        let cipher = cipher(&[1]);
+        let (_, payload) = parse_ciphertext(&cipher.encrypt("secret").unwrap()).unwrap();
+        let mut tampered = payload.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+
+        assert_eq!(
+            cipher.decrypt(&format!("v1:{}", STANDARD.encode(tampered))),
+            Err(EncryptionError::CipherFailure)
+        );
+    }
+
+    /// Tests error handling when no keys are configured.
+    ///
+    /// Expected: Err(EncryptionError::NoKeys)
+    #[test]
+    fn fails_to_encrypt_without_keys() {
+        assert!(!cipher(&[]).is_configured());
+        assert_eq!(cipher(&[]).encrypt("secret"), Err(EncryptionError::NoKeys));
+    }
+
+    /// Tests parsing of the `ENCRYPTION_KEYS` value.
+    ///
+    /// Expected: Ok with keys in configured order, Err for short keys and duplicate IDs
+    #[test]
+    fn parses_encryption_keys() {
+        let key = STANDARD.encode([7u8; KEY_LENGTH]);
+
+        let keys = parse_encryption_keys(&format!("2:{key}, 1:{key}")).unwrap();
+        assert_eq!(keys.iter().map(|k| k.id).collect::<Vec<_>>(), vec![2, 1]);
+
+        assert!(parse_encryption_keys("").unwrap().is_empty());
+        assert!(matches!(
+            parse_encryption_keys(&format!("1:{}", STANDARD.encode([7u8; 16]))),
+            Err(EncryptionError::InvalidKey { .. })
+        ));
+        assert!(matches!(
+            parse_encryption_keys(&format!("1:{key},1:{key}")),
+            Err(EncryptionError::InvalidKey { .. })
+        ));
+    }
+}
@@ -0,0 +1,292 @@
+//! EFT (EVE Fitting Tool) fitting format parser.
+//!
+//! This module parses ship fittings in the EFT text format used by the in-game fitting window's
+//! "Copy to Clipboard" feature and most third-party fitting tools. Parsing is purely textual:
+//! item names are not resolved to type IDs, so a fitting may reference items that do not exist
+//! if the input was hand-edited.
+
+use thiserror::Error;
+
+/// Maximum number of item lines accepted in a single EFT fitting.
+///
+/// Real fittings rarely exceed ~40 lines including drones and cargo. The limit prevents
+/// arbitrarily large pasted inputs from being stored as fittings.
+pub const EFT_MAX_ITEM_LINES: usize = 500;
+
+/// Error returned when an EFT fitting cannot be parsed.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum EftParseError {
+    /// Input contained no non-empty lines.
+    #[error("Fitting is empty")]
+    Empty,
+
+    /// First line was not a `[Ship, Fitting name]` header.
+    #[error("Invalid fitting header {0:?}, expected format [Ship, Fitting name]")]
+    InvalidHeader(String),
+
+    /// An item line had a quantity suffix that was zero or too large.
+    #[error("Invalid quantity on line {line}: {reason}")]
+    InvalidQuantity {
+        /// 1-based line number within the input.
+        line: usize,
+        /// Explanation of why the quantity was rejected.
+        reason: String,
+    },
+
+    /// Fitting exceeded `EFT_MAX_ITEM_LINES` item lines.
+    #[error("Fitting has too many item lines (maximum {0})")]
+    TooManyItems(usize),
+}
+
+/// A ship fitting parsed from EFT format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EftFitting {
+    /// Name of the ship hull, e.g. `Rifter`.
+    pub ship_type_name: String,
+    /// Name given to the fitting, e.g. `Tackle Rifter`.
+    pub name: String,
+    /// Modules, rigs, drones, and cargo in the order they appear in the fitting.
+    pub items: Vec<EftItem>,
+}
+
+/// A single item line within an EFT fitting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EftItem {
+    /// Name of the module, drone, or cargo item.
+    pub type_name: String,
+    /// Quantity of the item (`x5` suffix), 1 for fitted modules.
+    pub quantity: i32,
+    /// Name of the charge loaded into the module, if any.
+    pub charge_name: Option<String>,
+}
+
+/// Parses a fitting in EFT format.
+///
+/// The first non-empty line must be a header in the form `[Ship, Fitting name]`. Each following
+/// non-empty line is an item, optionally followed by `, Charge name` for loaded modules and/or
+/// ` xN` for stacked items like drones and cargo. Empty slot placeholders such as
+/// `[Empty High slot]` and the `/OFFLINE` module suffix are ignored.
+///
+/// # Arguments
+/// - `input` - Fitting text in EFT format
+///
+/// # Returns
+/// - `Ok(EftFitting)` - Successfully parsed fitting
+/// - `Err(EftParseError::Empty)` - Input contained no non-empty lines
+/// - `Err(EftParseError::InvalidHeader)` - First line is not a valid `[Ship, Name]` header
+/// - `Err(EftParseError::InvalidQuantity)` - An item quantity suffix was zero or out of range
+/// - `Err(EftParseError::TooManyItems)` - Fitting exceeded `EFT_MAX_ITEM_LINES` item lines
+///
+/// # Example
+/// ```ignore
+/// let fitting = parse_eft("[Rifter, Tackle]\nWarp Scrambler II\nWarrior II x3")?;
+/// assert_eq!(fitting.ship_type_name, "Rifter");
+/// assert_eq!(fitting.items[1].quantity, 3);
+/// ```
+pub fn parse_eft(input: &str) -> Result<EftFitting, EftParseError> {
+    let mut lines = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    let Some((_, header)) = lines.next() else {
+        return Err(EftParseError::Empty);
+    };
+
+    let (ship_type_name, name) = parse_header(header)?;
+
+    let mut items = Vec::new();
+    for (line_number, line) in lines {
+        if is_empty_slot(line) {
+            continue;
+        }
+
+        if items.len() >= EFT_MAX_ITEM_LINES {
+            return Err(EftParseError::TooManyItems(EFT_MAX_ITEM_LINES));
+        }
+
+        items.push(parse_item(line_number, line)?);
+    }
+
+    Ok(EftFitting {
+        ship_type_name,
+        name,
+        items,
+    })
+}
+
+/// Parses the `[Ship, Fitting name]` header line.
+fn parse_header(header: &str) -> Result<(String, String), EftParseError> {
+    let invalid = || EftParseError::InvalidHeader(header.to_string());
+
+    let inner = header
+        .strip_prefix('[')
+        .and_then(|h| h.strip_suffix(']'))
+        .ok_or_else(invalid)?;
+
+    let (ship, name) = inner.split_once(',').ok_or_else(invalid)?;
+    let (ship, name) = (ship.trim(), name.trim());
+
+    if ship.is_empty() || name.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok((ship.to_string(), name.to_string()))
+}
+
+/// Returns true for placeholder lines such as `[Empty Med slot]`.
+fn is_empty_slot(line: &str) -> bool {
+    line.starts_with("[Empty") && line.ends_with(']')
+}
+
+/// Parses a single item line, extracting the optional quantity and charge.
+fn parse_item(line_number: usize, line: &str) -> Result<EftItem, EftParseError> {
+    let line = line.strip_suffix("/OFFLINE").unwrap_or(line).trim();
+
+    let (line, quantity) = match line.rsplit_once(" x") {
+        Some((item, suffix))
+            if !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            let quantity: i32 = suffix.parse().map_err(|e| EftParseError::InvalidQuantity {
+                line: line_number,
+                reason: format!("{}", e),
+            })?;
+
+            if quantity == 0 {
+                return Err(EftParseError::InvalidQuantity {
+                    line: line_number,
+                    reason: "quantity must be greater than 0".to_string(),
+                });
+            }
+
+            (item.trim(), quantity)
+        }
+        _ => (line, 1),
+    };
+
+    let (type_name, charge_name) = match line.split_once(',') {
+        Some((module, charge)) if !charge.trim().is_empty() => {
+            (module.trim(), Some(charge.trim().to_string()))
+        }
+        Some((module, _)) => (module.trim(), None),
+        None => (line, None),
+    };
+
+    Ok(EftItem {
+        type_name: type_name.to_string(),
+        quantity,
+        charge_name,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests parsing a complete fitting with modules, charges, drones, and cargo.
+    ///
+    /// Verifies that the header, loaded charges, and stacked quantities are all extracted
+    /// and that empty slot placeholders are skipped.
+    ///
+    /// Expected: Ok with 5 items
+    #[test]
+    fn parses_complete_fitting() {
+        let input = "[Rifter, Tackle Rifter]\n\
+                     Damage Control II\n\
+                     [Empty Low slot]\n\
+                     \n\
+                     Warp Scrambler II\n\
+                     \n\
+                     200mm AutoCannon II, EMP S\n\
+                     \n\
+                     Hobgoblin II x3\n\
+                     Nanite Repair Paste x50\n";
+
+        let fitting = parse_eft(input).unwrap();
+
+        assert_eq!(fitting.ship_type_name, "Rifter");
+        assert_eq!(fitting.name, "Tackle Rifter");
+        assert_eq!(fitting.items.len(), 5);
+        assert_eq!(
+            fitting.items[2],
+            EftItem {
+                type_name: "200mm AutoCannon II".to_string(),
+                quantity: 1,
+                charge_name: Some("EMP S".to_string()),
+            }
+        );
+        assert_eq!(fitting.items[3].quantity, 3);
+        assert_eq!(fitting.items[4].quantity, 50);
+    }
+
+    /// Tests parsing a hull-only fitting.
+    ///
+    /// Verifies that a fitting with only a header line is accepted.
+    ///
+    /// Expected: Ok with no items
+    #[test]
+    fn parses_hull_only_fitting() {
+        let fitting = parse_eft("[Rifter, Empty]").unwrap();
+
+        assert!(fitting.items.is_empty());
+    }
+
+    /// Tests that offline modules are parsed without the offline marker.
+    ///
+    /// Expected: Ok with module name excluding `/OFFLINE`
+    #[test]
+    fn strips_offline_suffix() {
+        let fitting = parse_eft("[Rifter, Test]\n1MN Afterburner II /OFFLINE").unwrap();
+
+        assert_eq!(fitting.items[0].type_name, "1MN Afterburner II");
+    }
+
+    /// Tests that item names containing ` x` are not mistaken for quantities.
+    ///
+    /// Expected: Ok with quantity 1 and full item name preserved
+    #[test]
+    fn ignores_non_numeric_quantity_suffix() {
+        let fitting = parse_eft("[Rifter, Test]\nSmall Ancillary Current Router I xl").unwrap();
+
+        assert_eq!(fitting.items[0].quantity, 1);
+        assert_eq!(
+            fitting.items[0].type_name,
+            "Small Ancillary Current Router I xl"
+        );
+    }
+
+    /// Tests error handling for empty input.
+    ///
+    /// Expected: Err(EftParseError::Empty)
+    #[test]
+    fn fails_for_empty_input() {
+        assert_eq!(parse_eft("  \n\n"), Err(EftParseError::Empty));
+    }
+
+    /// Tests error handling for a header without a fitting name.
+    ///
+    /// Expected: Err(EftParseError::InvalidHeader)
+    #[test]
+    fn fails_for_invalid_header() {
+        assert!(matches!(
+            parse_eft("[Rifter]\nDamage Control II"),
+            Err(EftParseError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            parse_eft("Rifter, Tackle\nDamage Control II"),
+            Err(EftParseError::InvalidHeader(_))
+        ));
+    }
+
+    /// Tests error handling for a zero quantity.
+    ///
+    /// Expected: Err(EftParseError::InvalidQuantity) referencing line 2
+    #[test]
+    fn fails_for_zero_quantity() {
+        assert!(matches!(
+            parse_eft("[Rifter, Test]\nHobgoblin II x0"),
+            Err(EftParseError::InvalidQuantity { line: 2, .. })
+        ));
+    }
+}
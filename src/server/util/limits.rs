@@ -0,0 +1,278 @@
+//! Per-route request timeouts and body size limits.
+//!
+//! This module provides middleware bounding how long a request may take to produce a response
+//! and how large its body may be. Most API requests get a short timeout so a handler stuck on a
+//! slow ESI call can't pile up open connections, while exports get a longer one. Request bodies
+//! are limited to a small size except for endpoints importing fittings and skill plans, which
+//! accept larger pasted documents.
+//!
+//! The timeout covers producing the response headers; streamed response bodies such as
+//! exports and server-sent events are not cut off once they have started.
+
+use std::time::Duration;
+
+use axum::{
+    extract::{DefaultBodyLimit, Request, State},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dioxus_logger::tracing;
+use tower::{Layer, ServiceExt};
+
+use crate::model::api::ErrorDto;
+
+/// Default time normal requests have to produce a response.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default time exports and streaming requests have to produce a response.
+pub const DEFAULT_LONG_REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Default maximum request body size in bytes (64 KiB).
+pub const DEFAULT_BODY_LIMIT: usize = 64 * 1024;
+
+/// Default maximum request body size in bytes for import endpoints (2 MiB).
+pub const DEFAULT_IMPORT_BODY_LIMIT: usize = 2 * 1024 * 1024;
+
+/// Path prefixes of routes that may take long to respond, such as exports.
+const LONG_ROUTE_PREFIXES: &[&str] = &["/api/admin/export/"];
+
+/// Paths of routes accepting imported documents in their `POST` body.
+const IMPORT_ROUTES: &[&str] = &["/api/fittings", "/api/skill-plans"];
+
+/// Timeouts and body size limits applied to incoming requests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequestLimits {
+    /// Time normal requests have to produce a response.
+    pub timeout: Duration,
+    /// Time exports and streaming requests have to produce a response.
+    pub long_timeout: Duration,
+    /// Maximum request body size in bytes.
+    pub body_limit: usize,
+    /// Maximum request body size in bytes for import endpoints.
+    pub import_body_limit: usize,
+}
+
+impl Default for RequestLimits {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            long_timeout: DEFAULT_LONG_REQUEST_TIMEOUT,
+            body_limit: DEFAULT_BODY_LIMIT,
+            import_body_limit: DEFAULT_IMPORT_BODY_LIMIT,
+        }
+    }
+}
+
+impl RequestLimits {
+    /// Returns the timeout and body size limit for a request.
+    ///
+    /// # Arguments
+    /// - `method` - Request method
+    /// - `path` - Request path
+    ///
+    /// # Returns
+    /// - `(Duration, usize)` - Timeout and maximum body size in bytes
+    pub fn for_request(&self, method: &Method, path: &str) -> (Duration, usize) {
+        let timeout = if LONG_ROUTE_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+        {
+            self.long_timeout
+        } else {
+            self.timeout
+        };
+
+        let body_limit = if method == Method::POST && IMPORT_ROUTES.contains(&path) {
+            self.import_body_limit
+        } else {
+            self.body_limit
+        };
+
+        (timeout, body_limit)
+    }
+}
+
+/// Middleware applying the request limits for the route of each request.
+///
+/// Bodies over the limit are rejected with `413 Payload Too Large` by axum's body extractors.
+/// Requests that don't produce a response in time are answered with
+/// `503 Service Unavailable`, dropping the handler future.
+///
+/// The limit replaces axum's default of 2 MiB for the body extractors of the handler.
+///
+/// # Arguments
+/// - `limits` - Configured request limits
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler, or a timeout error
+pub async fn apply_request_limits(
+    State(limits): State<RequestLimits>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (timeout, body_limit) = limits.for_request(request.method(), request.uri().path());
+    let path = request.uri().path().to_string();
+
+    let response = DefaultBodyLimit::max(body_limit)
+        .layer(next)
+        .oneshot(request);
+
+    match tokio::time::timeout(timeout, response).await {
+        Ok(Ok(response)) => response,
+        Ok(Err(infallible)) => match infallible {},
+        Err(_) => {
+            tracing::warn!("Request to {} timed out after {:?}", path, timeout);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ErrorDto {
+                    error: "Request timed out".to_string(),
+                }),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::{body::Body, routing::post, Router};
+
+    mod for_request {
+        use super::*;
+
+        /// Tests that normal API routes get the default limits.
+        ///
+        /// Expected: Short timeout and small body limit
+        #[test]
+        fn uses_default_limits() {
+            let limits = RequestLimits::default();
+
+            assert_eq!(
+                limits.for_request(&Method::POST, "/api/user/preferences"),
+                (DEFAULT_REQUEST_TIMEOUT, DEFAULT_BODY_LIMIT)
+            );
+        }
+
+        /// Tests that export routes get the long timeout.
+        ///
+        /// Expected: Long timeout and small body limit
+        #[test]
+        fn uses_long_timeout_for_exports() {
+            let limits = RequestLimits::default();
+
+            assert_eq!(
+                limits.for_request(&Method::GET, "/api/admin/export/characters"),
+                (DEFAULT_LONG_REQUEST_TIMEOUT, DEFAULT_BODY_LIMIT)
+            );
+        }
+
+        /// Tests that only imports get the larger body limit.
+        ///
+        /// Expected: Import limit for POST, default limit for other methods
+        #[test]
+        fn uses_import_body_limit_for_imports() {
+            let limits = RequestLimits::default();
+
+            assert_eq!(
+                limits.for_request(&Method::POST, "/api/fittings").1,
+                DEFAULT_IMPORT_BODY_LIMIT
+            );
+            assert_eq!(
+                limits.for_request(&Method::POST, "/api/skill-plans").1,
+                DEFAULT_IMPORT_BODY_LIMIT
+            );
+            assert_eq!(
+                limits.for_request(&Method::GET, "/api/fittings").1,
+                DEFAULT_BODY_LIMIT
+            );
+        }
+    }
+
+    mod apply_request_limits {
+        use super::*;
+
+        fn router(limits: RequestLimits) -> Router {
+            Router::new()
+                .route("/api/echo", post(|body: String| async move { body }))
+                .route(
+                    "/api/slow",
+                    post(|| async {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                        "done"
+                    }),
+                )
+                .layer(axum::middleware::from_fn_with_state(
+                    limits,
+                    apply_request_limits,
+                ))
+        }
+
+        fn request(uri: &str, body: String) -> Request {
+            Request::builder()
+                .method(Method::POST)
+                .uri(uri)
+                .body(Body::from(body))
+                .unwrap()
+        }
+
+        /// Tests that bodies within the limit reach the handler.
+        ///
+        /// Expected: 200 OK
+        #[tokio::test]
+        async fn accepts_body_within_limit() {
+            let limits = RequestLimits {
+                body_limit: 16,
+                ..Default::default()
+            };
+
+            let response = router(limits)
+                .oneshot(request("/api/echo", "a".repeat(16)))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        /// Tests that bodies over the limit are rejected.
+        ///
+        /// Expected: 413 Payload Too Large
+        #[tokio::test]
+        async fn rejects_body_over_limit() {
+            let limits = RequestLimits {
+                body_limit: 16,
+                ..Default::default()
+            };
+
+            let response = router(limits)
+                .oneshot(request("/api/echo", "a".repeat(17)))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        /// Tests that handlers exceeding the timeout are cut off.
+        ///
+        /// Expected: 503 Service Unavailable
+        #[tokio::test]
+        async fn times_out_slow_handler() {
+            let limits = RequestLimits {
+                timeout: Duration::from_millis(50),
+                ..Default::default()
+            };
+
+            let response = router(limits)
+                .oneshot(request("/api/slow", String::new()))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Markdown rendering for admin-edited pages.
+//!
+//! This module renders the subset of Markdown pages are written in to HTML. Page content is
+//! written by admins but shown to every visitor, so raw HTML in the input is escaped rather
+//! than passed through, and links are limited to `http`, `https`, `mailto`, and site-relative
+//! URLs.
+//!
+//! # Supported Syntax
+//!
+//! - Headings (`#` to `######`), paragraphs, and horizontal rules (`---`)
+//! - Unordered (`-`, `*`) and ordered (`1.`) lists
+//! - Block quotes (`>`) and fenced code blocks (```` ``` ````)
+//! - Inline code, bold (`**`), italics (`*`, `_`), and links (`[text](url)`)
+
+/// Renders Markdown as HTML.
+///
+/// # Arguments
+/// - `markdown` - Markdown text to render
+///
+/// # Returns
+/// - `String` - HTML fragment with all raw HTML in the input escaped
+pub fn render_markdown(markdown: &str) -> String {
+    let mut renderer = Renderer::default();
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") {
+            renderer.flush();
+            let code: Vec<&str> = lines
+                .by_ref()
+                .take_while(|line| !line.trim_start().starts_with("```"))
+                .collect();
+            renderer.html.push_str(&format!(
+                "<pre><code>{}</code></pre>\n",
+                escape_html(&code.join("\n"))
+            ));
+        } else if trimmed.is_empty() {
+            renderer.flush();
+        } else if let Some((level, text)) = heading(trimmed) {
+            renderer.flush();
+            renderer
+                .html
+                .push_str(&format!("<h{level}>{}</h{level}>\n", render_inline(text)));
+        } else if matches!(trimmed, "---" | "***" | "___") {
+            renderer.flush();
+            renderer.html.push_str("<hr>\n");
+        } else if let Some(text) = trimmed.strip_prefix('>') {
+            renderer.push_block(Block::Quote, text.trim());
+        } else if let Some(text) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            renderer.push_block(Block::UnorderedList, text.trim());
+        } else if let Some(text) = ordered_item(trimmed) {
+            renderer.push_block(Block::OrderedList, text.trim());
+        } else {
+            renderer.push_block(Block::Paragraph, trimmed);
+        }
+    }
+
+    renderer.flush();
+    renderer.html
+}
+
+/// Escapes characters with special meaning in HTML.
+///
+/// # Arguments
+/// - `value` - Text to escape
+///
+/// # Returns
+/// - `String` - Text safe to embed in HTML content and attribute values
+pub fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Kind of block spanning multiple lines.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Paragraph,
+    Quote,
+    UnorderedList,
+    OrderedList,
+}
+
+/// Accumulates lines of the current block until a line of another kind ends it.
+#[derive(Default)]
+struct Renderer {
+    html: String,
+    block: Option<Block>,
+    lines: Vec<String>,
+}
+
+impl Renderer {
+    /// Adds a line to the current block, ending the current block first if it's of another kind.
+    fn push_block(&mut self, block: Block, text: &str) {
+        if self.block != Some(block) {
+            self.flush();
+            self.block = Some(block);
+        }
+        self.lines.push(render_inline(text));
+    }
+
+    /// Writes the current block to the HTML output.
+    fn flush(&mut self) {
+        let Some(block) = self.block.take() else {
+            return;
+        };
+        let lines = std::mem::take(&mut self.lines);
+
+        match block {
+            Block::Paragraph => {
+                self.html
+                    .push_str(&format!("<p>{}</p>\n", lines.join("\n")));
+            }
+            Block::Quote => {
+                self.html.push_str(&format!(
+                    "<blockquote><p>{}</p></blockquote>\n",
+                    lines.join("\n")
+                ));
+            }
+            Block::UnorderedList | Block::OrderedList => {
+                let tag = if block == Block::OrderedList {
+                    "ol"
+                } else {
+                    "ul"
+                };
+                self.html.push_str(&format!("<{}>\n", tag));
+                for line in lines {
+                    self.html.push_str(&format!("<li>{}</li>\n", line));
+                }
+                self.html.push_str(&format!("</{}>\n", tag));
+            }
+        }
+    }
+}
+
+/// Parses an ATX heading such as `## Title` into its level and text.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+
+    (1..=6).contains(&level).then(|| (level, text.trim()))
+}
+
+/// Parses an ordered list item such as `1. Item` into its text.
+fn ordered_item(line: &str) -> Option<&str> {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+    if digits == 0 {
+        return None;
+    }
+    line[digits..].strip_prefix(". ")
+}
+
+/// Renders inline code, emphasis, and links, escaping all other text.
+fn render_inline(text: &str) -> String {
+    let mut html = String::new();
+    let mut rest = text;
+    let mut previous = None;
+
+    while let Some(c) = rest.chars().next() {
+        // Underscores inside words like snake_case don't start italics
+        let in_word = c == '_' && previous.is_some_and(char::is_alphanumeric);
+
+        match inline_span(rest).filter(|_| !in_word) {
+            Some((rendered, remaining)) => {
+                html.push_str(&rendered);
+                rest = remaining;
+            }
+            None => {
+                html.push_str(&escape_html(&c.to_string()));
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        previous = Some(c);
+    }
+
+    html
+}
+
+/// Renders the inline span starting at the beginning of the text, if any.
+///
+/// Returns the rendered span and the text following it. Unclosed markers are not spans and
+/// are rendered as text by the caller.
+fn inline_span(text: &str) -> Option<(String, &str)> {
+    if let Some(rest) = text.strip_prefix('`') {
+        let end = rest.find('`')?;
+        return Some((
+            format!("<code>{}</code>", escape_html(&rest[..end])),
+            &rest[end + 1..],
+        ));
+    }
+
+    if let Some(rest) = text.strip_prefix("**") {
+        let end = rest.find("**").filter(|&end| end > 0)?;
+        return Some((
+            format!("<strong>{}</strong>", render_inline(&rest[..end])),
+            &rest[end + 2..],
+        ));
+    }
+
+    for marker in ['*', '_'] {
+        if let Some(rest) = text.strip_prefix(marker) {
+            if rest.starts_with(char::is_whitespace) {
+                return None;
+            }
+            let end = rest.find(marker).filter(|&end| end > 0)?;
+            return Some((
+                format!("<em>{}</em>", render_inline(&rest[..end])),
+                &rest[end + 1..],
+            ));
+        }
+    }
+
+    if let Some(rest) = text.strip_prefix('[') {
+        let label_end = rest.find("](")?;
+        let url_start = label_end + 2;
+        let url_end = url_start + rest[url_start..].find(')')?;
+        let label = &rest[..label_end];
+        let url = rest[url_start..url_end].trim();
+
+        // Unsafe links are rendered as their label only
+        let rendered = if is_safe_url(url) {
+            format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(url),
+                render_inline(label)
+            )
+        } else {
+            render_inline(label)
+        };
+        return Some((rendered, &rest[url_end + 1..]));
+    }
+
+    None
+}
+
+/// Returns whether a link target can't run script when clicked.
+fn is_safe_url(url: &str) -> bool {
+    if url.starts_with('/') || url.starts_with('#') {
+        return !url.starts_with("//");
+    }
+
+    let scheme = url
+        .split_once(':')
+        .map(|(scheme, _)| scheme.to_ascii_lowercase());
+    matches!(scheme.as_deref(), Some("http" | "https" | "mailto"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expected: Headings, paragraphs, and lists rendered as their HTML elements
+    #[test]
+    fn renders_blocks() {
+        let html = render_markdown(
+            "# Rules\n\nBe nice.\nNo spying.\n\n- Pay taxes\n- Join fleets\n\n1. Apply\n2. Wait",
+        );
+
+        assert_eq!(
+            html,
+            concat!(
+                "<h1>Rules</h1>\n",
+                "<p>Be nice.\nNo spying.</p>\n",
+                "<ul>\n<li>Pay taxes</li>\n<li>Join fleets</li>\n</ul>\n",
+                "<ol>\n<li>Apply</li>\n<li>Wait</li>\n</ol>\n",
+            )
+        );
+    }
+
+    /// Expected: Code block contents escaped and not parsed as Markdown
+    #[test]
+    fn renders_code_blocks() {
+        let html = render_markdown("```\n# not a heading\n<b>\n```\nAfter");
+
+        assert_eq!(
+            html,
+            "<pre><code># not a heading\n&lt;b&gt;</code></pre>\n<p>After</p>\n"
+        );
+    }
+
+    /// Expected: Inline code, bold, italics, and links rendered as their HTML elements
+    #[test]
+    fn renders_inline_spans() {
+        let html = render_markdown(
+            "**SRP** covers *doctrine* ships, see `/srp` or [the wiki](https://wiki.example.com).",
+        );
+
+        assert_eq!(
+            html,
+            concat!(
+                "<p><strong>SRP</strong> covers <em>doctrine</em> ships, see <code>/srp</code> ",
+                "or <a href=\"https://wiki.example.com\">the wiki</a>.</p>\n"
+            )
+        );
+    }
+
+    /// Expected: Raw HTML escaped and script links rendered as text
+    #[test]
+    fn escapes_html_and_unsafe_links() {
+        let html = render_markdown("<script>alert(1)</script> [click](javascript:void)");
+
+        assert_eq!(html, "<p>&lt;script&gt;alert(1)&lt;/script&gt; click</p>\n");
+    }
+
+    /// Expected: Unclosed markers rendered as text
+    #[test]
+    fn renders_unclosed_markers_as_text() {
+        let html = render_markdown("5 * 3 = 15, `unclosed, snake_case_name and [no link");
+
+        assert_eq!(
+            html,
+            "<p>5 * 3 = 15, `unclosed, snake_case_name and [no link</p>\n"
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Minimal Meilisearch HTTP client.
+//!
+//! This module provides the `MeilisearchClient` used as the external search backend. Only
+//! the two requests Bifrost needs are implemented: adding or replacing documents in an index
+//! and searching it. Meilisearch creates the index on the first document upload, so no setup
+//! is required beyond pointing `MEILISEARCH_URL` at the instance.
+
+use serde::{Deserialize, Serialize};
+
+/// Name of the index holding searchable EVE entities.
+pub const ENTITY_INDEX: &str = "bifrost_entities";
+
+/// Document stored in the Meilisearch entity index.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SearchDocument {
+    /// Primary key unique across categories, e.g. `character-2114794365`.
+    pub key: String,
+    /// Entity category (`character`, `corporation`, or `alliance`).
+    pub category: String,
+    /// EVE Online ID of the entity.
+    pub id: i64,
+    /// Entity name.
+    pub name: String,
+}
+
+impl SearchDocument {
+    /// Creates a document for an EVE entity.
+    ///
+    /// # Arguments
+    /// - `category` - Entity category (`character`, `corporation`, or `alliance`)
+    /// - `id` - EVE Online ID of the entity
+    /// - `name` - Entity name
+    ///
+    /// # Returns
+    /// - `SearchDocument` - Document keyed by category and ID
+    pub fn new(category: &str, id: i64, name: String) -> Self {
+        Self {
+            key: format!("{}-{}", category, id),
+            category: category.to_string(),
+            id,
+            name,
+        }
+    }
+}
+
+/// Response body of the Meilisearch search endpoint.
+#[derive(Deserialize)]
+struct SearchResponse {
+    hits: Vec<SearchDocument>,
+}
+
+/// Client for a Meilisearch instance.
+///
+/// Cloning is cheap; clones share the underlying HTTP connection pool.
+#[derive(Clone, Debug)]
+pub struct MeilisearchClient {
+    url: String,
+    api_key: Option<String>,
+    http: reqwest::Client,
+}
+
+impl MeilisearchClient {
+    /// Creates a client for a Meilisearch instance.
+    ///
+    /// # Arguments
+    /// - `url` - Base URL of the instance, e.g. `http://meilisearch:7700`
+    /// - `api_key` - API key sent as bearer token, `None` for instances without a master key
+    /// - `http` - HTTP client used for requests
+    ///
+    /// # Returns
+    /// - `MeilisearchClient` - Client for the instance
+    pub fn new(url: &str, api_key: Option<String>, http: reqwest::Client) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            api_key,
+            http,
+        }
+    }
+
+    /// Adds documents to an index, replacing documents with the same key.
+    ///
+    /// Meilisearch processes the documents asynchronously, so they may not be searchable
+    /// immediately after this returns.
+    ///
+    /// # Arguments
+    /// - `index` - Name of the index
+    /// - `documents` - Documents to add or replace
+    ///
+    /// # Returns
+    /// - `Ok(())` - Meilisearch accepted the documents
+    /// - `Err(reqwest::Error)` - Request failed or Meilisearch returned an error status
+    pub async fn add_documents(
+        &self,
+        index: &str,
+        documents: &[SearchDocument],
+    ) -> Result<(), reqwest::Error> {
+        if documents.is_empty() {
+            return Ok(());
+        }
+
+        self.request(
+            reqwest::Method::POST,
+            &format!("indexes/{}/documents", index),
+        )
+        .query(&[("primaryKey", "key")])
+        .json(documents)
+        .send()
+        .await?
+        .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Searches an index.
+    ///
+    /// # Arguments
+    /// - `index` - Name of the index
+    /// - `query` - Search query
+    /// - `limit` - Maximum number of documents to return
+    ///
+    /// # Returns
+    /// - `Ok(Vec<SearchDocument>)` - Matching documents ordered by relevance
+    /// - `Err(reqwest::Error)` - Request failed or Meilisearch returned an error status
+    pub async fn search(
+        &self,
+        index: &str,
+        query: &str,
+        limit: u64,
+    ) -> Result<Vec<SearchDocument>, reqwest::Error> {
+        let response: SearchResponse = self
+            .request(reqwest::Method::POST, &format!("indexes/{}/search", index))
+            .json(&serde_json::json!({ "q": query, "limit": limit }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(response.hits)
+    }
+
+    /// Builds a request to a path of the instance, authenticated with the API key if set.
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self.http.request(method, format!("{}/{}", self.url, path));
+
+        match &self.api_key {
+            Some(api_key) => request.bearer_auth(api_key),
+            None => request,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod search_document {
+        use super::*;
+
+        /// Tests that document keys are unique across categories.
+        ///
+        /// Expected: Key combines category and ID
+        #[test]
+        fn keys_by_category_and_id() {
+            let character = SearchDocument::new("character", 1, "Name".to_string());
+            let corporation = SearchDocument::new("corporation", 1, "Name".to_string());
+
+            assert_eq!(character.key, "character-1");
+            assert_ne!(character.key, corporation.key);
+        }
+    }
+}
@@ -1,7 +1,35 @@
 //! Utility functions and helpers for server operations.
 //!
-//! This module provides reusable utility functions for common server tasks, including
-//! EVE Online-specific operations (character ID validation, ESI limits). These utilities
-//! are used across services, workers, and schedulers.
+//! This module provides reusable utility functions for common server tasks, including EVE
+//! Online-specific operations (character ID validation, ESI limits), parsing of EVE fitting and
+//! skill plan formats, rendering Markdown pages, instance branding settings, named ESI scope sets
+//! requested at login, encryption of sensitive column values and Web Push messages, resolving
+//! clients behind trusted reverse proxies, caching headers for static assets, request timeouts and
+//! body size limits, permission checks for admin endpoints, rejecting requests from suspended
+//! users, rate limits of the EVE Online SSO endpoints, sliding session expiry, indexing the
+//! sessions users are logged in with, read-only mode for database maintenance, counting database
+//! queries in debug builds, talking to a Meilisearch instance, storing objects in S3-compatible
+//! buckets, and validating the configuration for the `check-config` command. These utilities are
+//! used across services, repositories, workers, and schedulers.
 
+pub mod branding;
+pub mod cache;
+pub mod config_check;
+pub mod crypto;
+pub mod eft;
 pub mod eve;
+pub mod limits;
+pub mod markdown;
+pub mod meilisearch;
+pub mod object_storage;
+pub mod permission;
+pub mod proxy;
+pub mod query_metrics;
+pub mod rate_limit;
+pub mod read_only;
+pub mod scope_set;
+pub mod session_expiry;
+pub mod session_index;
+pub mod skill_plan;
+pub mod suspension;
+pub mod web_push;
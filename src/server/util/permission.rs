@@ -0,0 +1,238 @@
+//! Permission checks for admin endpoints.
+//!
+//! This module provides the middleware enforcing the permissions granted by roles on every
+//! `/api/admin` route. Each route prefix maps to the permission it requires, and routes not
+//! listed require the admin permission, so new admin endpoints are restricted to admins until
+//! they are mapped to a narrower permission. Shared content such as fittings and doctrines is
+//! readable by every member, but changing it requires the content permission.
+
+use axum::{
+    extract::{Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tower_sessions::Session;
+
+use crate::{
+    model::role::Permission,
+    server::{
+        controller::util::get_user::get_user_from_session, error::role::RoleError,
+        model::app::AppState, service::role::RoleService,
+    },
+};
+
+/// Path prefix of the admin endpoints the middleware applies to.
+const ADMIN_PREFIX: &str = "/api/admin";
+
+/// Permissions required by admin route prefixes.
+///
+/// Checked in order, so more specific prefixes must come before the prefixes containing them.
+const ROUTE_PERMISSIONS: &[(&str, Permission)] = &[
+    ("/api/admin/roles", Permission::ManageRoles),
+    (
+        "/api/admin/onboarding/incomplete",
+        Permission::ManageMembers,
+    ),
+    ("/api/admin/onboarding", Permission::ManageContent),
+    ("/api/admin/announcements", Permission::ManageContent),
+    ("/api/admin/pages", Permission::ManageContent),
+    ("/api/admin/widgets", Permission::ManageContent),
+    ("/api/admin/annotations", Permission::ManageMembers),
+    ("/api/admin/bans", Permission::ManageMembers),
+    ("/api/admin/corporations", Permission::ManageMembers),
+    ("/api/admin/export", Permission::ManageMembers),
+    ("/api/admin/groups", Permission::ManageMembers),
+    ("/api/admin/members", Permission::ManageMembers),
+    ("/api/admin/notes", Permission::ManageMembers),
+    ("/api/admin/reauth-campaigns", Permission::ManageMembers),
+    ("/api/admin/screening", Permission::ManageMembers),
+    ("/api/admin/tags", Permission::ManageMembers),
+    ("/api/admin/users", Permission::ManageMembers),
+    ("/api/admin/api-keys", Permission::ManageIntegrations),
+    ("/api/admin/saved-queries", Permission::ManageIntegrations),
+    ("/api/admin/webhooks", Permission::ManageIntegrations),
+    ("/api/admin/approvals", Permission::DecideApprovals),
+];
+
+/// Route prefixes of shared content every member can read but only content managers change.
+const CONTENT_ROUTES: &[&str] = &[
+    "/api/fittings",
+    "/api/doctrines",
+    "/api/skill-plans",
+    "/api/campaigns",
+];
+
+/// Returns the permission a request requires.
+///
+/// # Arguments
+/// - `method` - Request method
+/// - `path` - Request path
+///
+/// # Returns
+/// - `Some(Permission)` - Path is an admin endpoint, or the request changes shared content,
+///   requiring the permission
+/// - `None` - Request requires no permission
+pub fn required_permission(method: &Method, path: &str) -> Option<Permission> {
+    if !matches_prefix(path, ADMIN_PREFIX) {
+        let changes_content = !method.is_safe()
+            && CONTENT_ROUTES
+                .iter()
+                .any(|prefix| matches_prefix(path, prefix));
+
+        return changes_content.then_some(Permission::ManageContent);
+    }
+
+    let permission = ROUTE_PERMISSIONS
+        .iter()
+        .find(|(prefix, _)| matches_prefix(path, prefix))
+        .map(|(_, permission)| *permission)
+        .unwrap_or(Permission::Admin);
+
+    Some(permission)
+}
+
+/// Returns whether a path is the prefix itself or a path below it.
+fn matches_prefix(path: &str, prefix: &str) -> bool {
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Middleware rejecting admin requests from users missing the permission the route requires.
+///
+/// Requests outside `/api/admin` are passed through unchecked, except for requests changing
+/// shared content.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler, the error response if
+///   no user is logged in, or `403 Forbidden` if the user is missing the permission
+pub async fn require_admin_permissions(
+    State(state): State<AppState>,
+    session: Session,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(permission) = required_permission(request.method(), request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let user = match get_user_from_session(&state, &session).await {
+        Ok(user) => user,
+        Err(err) => return err.into_response(),
+    };
+
+    match RoleService::new(&state.db)
+        .has_permission(user.id, permission)
+        .await
+    {
+        Ok(true) => next.run(request).await,
+        Ok(false) => RoleError::PermissionDenied(permission).into_response(),
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod required_permission {
+        use super::*;
+
+        /// Tests that routes outside the admin API require no permission.
+        ///
+        /// Expected: None for user and frontend routes, lookalike prefixes, and content reads
+        #[test]
+        fn ignores_non_admin_routes() {
+            assert_eq!(required_permission(&Method::GET, "/api/user"), None);
+            assert_eq!(required_permission(&Method::GET, "/admin"), None);
+            assert_eq!(
+                required_permission(&Method::GET, "/api/administrators"),
+                None
+            );
+            assert_eq!(required_permission(&Method::GET, "/api/doctrines"), None);
+            assert_eq!(required_permission(&Method::POST, "/api/fittingsx"), None);
+        }
+
+        /// Tests that mapped routes require their permission.
+        ///
+        /// Expected: The mapped permission for the prefix and paths below it
+        #[test]
+        fn maps_prefixes_to_permissions() {
+            assert_eq!(
+                required_permission(&Method::GET, "/api/admin/roles"),
+                Some(Permission::ManageRoles)
+            );
+            assert_eq!(
+                required_permission(&Method::GET, "/api/admin/webhooks/1"),
+                Some(Permission::ManageIntegrations)
+            );
+            assert_eq!(
+                required_permission(&Method::GET, "/api/admin/approvals/1/approve"),
+                Some(Permission::DecideApprovals)
+            );
+            assert_eq!(
+                required_permission(&Method::GET, "/api/admin/screening/characters/1"),
+                Some(Permission::ManageMembers)
+            );
+        }
+
+        /// Tests that changing shared content requires the content permission.
+        ///
+        /// Expected: ManageContent for writes to fittings, doctrines, skill plans, and campaigns
+        #[test]
+        fn requires_content_permission_for_writes() {
+            assert_eq!(
+                required_permission(&Method::DELETE, "/api/fittings/1"),
+                Some(Permission::ManageContent)
+            );
+            assert_eq!(
+                required_permission(&Method::PUT, "/api/doctrines/1/fittings/2"),
+                Some(Permission::ManageContent)
+            );
+            assert_eq!(
+                required_permission(&Method::DELETE, "/api/skill-plans/1"),
+                Some(Permission::ManageContent)
+            );
+            assert_eq!(
+                required_permission(&Method::POST, "/api/campaigns"),
+                Some(Permission::ManageContent)
+            );
+        }
+
+        /// Tests that more specific prefixes take precedence.
+        ///
+        /// Expected: ManageMembers for incomplete onboarding, ManageContent for steps
+        #[test]
+        fn prefers_specific_prefixes() {
+            assert_eq!(
+                required_permission(&Method::GET, "/api/admin/onboarding/incomplete"),
+                Some(Permission::ManageMembers)
+            );
+            assert_eq!(
+                required_permission(&Method::GET, "/api/admin/onboarding/steps/1"),
+                Some(Permission::ManageContent)
+            );
+        }
+
+        /// Tests that unmapped admin routes require the admin permission.
+        ///
+        /// Expected: Admin for the read-only toggle and worker routes
+        #[test]
+        fn defaults_to_admin() {
+            assert_eq!(
+                required_permission(&Method::GET, "/api/admin/read-only"),
+                Some(Permission::Admin)
+            );
+            assert_eq!(
+                required_permission(&Method::GET, "/api/admin/worker/queue"),
+                Some(Permission::Admin)
+            );
+        }
+    }
+}
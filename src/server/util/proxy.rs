@@ -0,0 +1,409 @@
+//! Reverse-proxy awareness for client addresses and request schemes.
+//!
+//! This module resolves the real client IP and whether the original request used HTTPS when
+//! Bifrost is deployed behind a reverse proxy such as nginx or traefik. The `X-Forwarded-For`
+//! and `X-Forwarded-Proto` headers are only honoured when the connecting peer is in the
+//! configured trust list, as any client can set them on a direct connection.
+//!
+//! # Trust List
+//!
+//! Trusted proxies are configured as a comma-separated list of IP addresses or CIDR networks
+//! in the `TRUSTED_PROXIES` environment variable, e.g. `127.0.0.1,10.0.0.0/8,fd00::/8`.
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts, Request, State},
+    http::{request::Parts, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+use dioxus_logger::tracing::{self, Instrument};
+use thiserror::Error;
+
+/// Header listing the client and proxy addresses a request was forwarded through.
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Header containing the scheme of the request as received by the first proxy.
+const X_FORWARDED_PROTO: &str = "x-forwarded-proto";
+
+/// Error returned when parsing the trusted proxy list.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ProxyError {
+    /// A `TRUSTED_PROXIES` entry could not be parsed.
+    #[error("Invalid trusted proxy entry {entry:?}: {reason}")]
+    InvalidEntry {
+        /// The entry that was rejected.
+        entry: String,
+        /// Explanation of why the entry was rejected.
+        reason: String,
+    },
+}
+
+/// A single trusted proxy address or network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrustedProxy {
+    /// Network address of the trusted range.
+    network: IpAddr,
+    /// Number of leading bits of `network` an address must share to be trusted.
+    prefix: u8,
+}
+
+impl TrustedProxy {
+    /// Parses an IP address or CIDR network such as `10.0.0.0/8`.
+    ///
+    /// # Arguments
+    /// - `entry` - IP address, trusted as a single host, or network in CIDR notation
+    ///
+    /// # Returns
+    /// - `Ok(TrustedProxy)` - Parsed trusted address or network
+    /// - `Err(ProxyError::InvalidEntry)` - Invalid address or prefix length
+    pub fn parse(entry: &str) -> Result<Self, ProxyError> {
+        let invalid = |reason: &str| ProxyError::InvalidEntry {
+            entry: entry.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let (address, prefix) = match entry.split_once('/') {
+            Some((address, prefix)) => (address, Some(prefix)),
+            None => (entry, None),
+        };
+
+        let network: IpAddr = address
+            .trim()
+            .parse()
+            .map_err(|_| invalid("expected an IP address or CIDR network"))?;
+        let max_prefix = match network {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|prefix| *prefix <= max_prefix)
+                .ok_or_else(|| invalid("prefix length is out of range"))?,
+            None => max_prefix,
+        };
+
+        Ok(Self { network, prefix })
+    }
+
+    /// Returns whether the address is within this trusted address or network.
+    ///
+    /// IPv4-mapped IPv6 addresses are compared as IPv4 addresses.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Client connection details after accounting for trusted reverse proxies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClientInfo {
+    /// IP address of the client, `None` if the peer address is unavailable.
+    pub ip: Option<IpAddr>,
+    /// Whether the client connected over HTTPS, as reported by a trusted proxy.
+    pub https: bool,
+}
+
+/// Extracts the `ClientInfo` recorded by the `record_client_info` middleware.
+///
+/// Falls back to an unknown client if the middleware is not installed.
+impl<S: Send + Sync> FromRequestParts<S> for ClientInfo {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<ClientInfo>()
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
+/// List of reverse proxies whose forwarding headers are trusted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TrustedProxies(Vec<TrustedProxy>);
+
+impl TrustedProxies {
+    /// Parses a comma-separated list of IP addresses and CIDR networks.
+    ///
+    /// Empty entries are ignored, so an empty string trusts no proxies.
+    ///
+    /// # Arguments
+    /// - `value` - Comma-separated trust list, e.g. `127.0.0.1,10.0.0.0/8`
+    ///
+    /// # Returns
+    /// - `Ok(TrustedProxies)` - Parsed trust list
+    /// - `Err(ProxyError::InvalidEntry)` - An entry is not a valid address or network
+    pub fn parse(value: &str) -> Result<Self, ProxyError> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(TrustedProxy::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+
+    /// Returns whether the address belongs to a trusted proxy.
+    pub fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.0.iter().any(|proxy| proxy.contains(ip))
+    }
+
+    /// Resolves the client connection details for a request.
+    ///
+    /// If the peer is a trusted proxy, `X-Forwarded-For` is walked from right to left,
+    /// skipping further trusted proxies, and the first untrusted address is the client.
+    /// Addresses left of it were supplied by the client and are ignored. The scheme is taken
+    /// from `X-Forwarded-Proto`. Forwarding headers from untrusted peers are ignored entirely.
+    ///
+    /// # Arguments
+    /// - `peer` - Address of the directly connected peer, if known
+    /// - `headers` - Request headers containing the forwarding headers
+    ///
+    /// # Returns
+    /// - `ClientInfo` - Resolved client IP and scheme
+    pub fn resolve(&self, peer: Option<IpAddr>, headers: &HeaderMap) -> ClientInfo {
+        let Some(peer) = peer else {
+            return ClientInfo::default();
+        };
+
+        if !self.is_trusted(peer) {
+            return ClientInfo {
+                ip: Some(peer),
+                https: false,
+            };
+        }
+
+        let mut client = peer;
+        let forwarded_for = headers
+            .get_all(X_FORWARDED_FOR)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect::<Vec<_>>();
+
+        for hop in forwarded_for.into_iter().rev() {
+            if !self.is_trusted(client) {
+                break;
+            }
+
+            match hop.parse::<IpAddr>() {
+                Ok(ip) => client = ip,
+                // Stop at malformed entries rather than trusting anything left of them
+                Err(_) => break,
+            }
+        }
+
+        let https = headers
+            .get(X_FORWARDED_PROTO)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .is_some_and(|proto| proto.trim().eq_ignore_ascii_case("https"));
+
+        ClientInfo {
+            ip: Some(client),
+            https,
+        }
+    }
+}
+
+/// Middleware resolving the client behind trusted proxies for each request.
+///
+/// Stores the resolved `ClientInfo` in the request extensions for handlers and runs the
+/// request in a tracing span recording the client IP, so log lines emitted while handling
+/// the request carry the real client address rather than the proxy's. The peer address is
+/// read from axum's `ConnectInfo` when the server provides it.
+///
+/// # Arguments
+/// - `proxies` - Configured trust list
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler
+pub async fn record_client_info(
+    State(proxies): State<TrustedProxies>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let client = proxies.resolve(peer, request.headers());
+
+    request.extensions_mut().insert(client);
+
+    let span = match client.ip {
+        Some(ip) => tracing::info_span!("request", client_ip = %ip),
+        None => tracing::info_span!("request", client_ip = "unknown"),
+    };
+
+    next.run(request).instrument(span).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::HeaderValue;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    fn forwarded(forwarded_for: &str, proto: Option<&str>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            X_FORWARDED_FOR,
+            HeaderValue::from_str(forwarded_for).unwrap(),
+        );
+        if let Some(proto) = proto {
+            headers.insert(X_FORWARDED_PROTO, HeaderValue::from_str(proto).unwrap());
+        }
+        headers
+    }
+
+    mod parse {
+        use super::*;
+
+        /// Tests parsing of addresses and networks.
+        ///
+        /// Expected: Ok with single hosts and networks matching their ranges
+        #[test]
+        fn parses_addresses_and_networks() {
+            let proxies = TrustedProxies::parse("127.0.0.1, 10.0.0.0/8,fd00::/8,").unwrap();
+
+            assert!(proxies.is_trusted(ip("127.0.0.1")));
+            assert!(!proxies.is_trusted(ip("127.0.0.2")));
+            assert!(proxies.is_trusted(ip("10.20.30.40")));
+            assert!(proxies.is_trusted(ip("fd12::1")));
+            assert!(!proxies.is_trusted(ip("2001:db8::1")));
+        }
+
+        /// Tests that IPv4-mapped IPv6 peers match IPv4 entries.
+        ///
+        /// Expected: Mapped address is trusted
+        #[test]
+        fn matches_ipv4_mapped_addresses() {
+            let proxies = TrustedProxies::parse("10.0.0.0/8").unwrap();
+
+            assert!(proxies.is_trusted(ip("::ffff:10.0.0.1")));
+        }
+
+        /// Tests that an empty list trusts no proxies.
+        ///
+        /// Expected: Ok with no trusted addresses
+        #[test]
+        fn empty_list_trusts_nothing() {
+            let proxies = TrustedProxies::parse("").unwrap();
+
+            assert!(!proxies.is_trusted(ip("127.0.0.1")));
+        }
+
+        /// Tests rejection of invalid entries.
+        ///
+        /// Expected: Err(ProxyError::InvalidEntry) for each entry
+        #[test]
+        fn rejects_invalid_entries() {
+            for entry in ["localhost", "10.0.0.0/33", "fd00::/129", "10.0.0.0/x"] {
+                assert!(
+                    matches!(
+                        TrustedProxies::parse(entry),
+                        Err(ProxyError::InvalidEntry { .. })
+                    ),
+                    "{entry}"
+                );
+            }
+        }
+    }
+
+    mod resolve {
+        use super::*;
+
+        /// Tests that forwarding headers from untrusted peers are ignored.
+        ///
+        /// Expected: Peer address without HTTPS
+        #[test]
+        fn ignores_headers_from_untrusted_peer() {
+            let proxies = TrustedProxies::parse("10.0.0.1").unwrap();
+            let headers = forwarded("203.0.113.7", Some("https"));
+
+            let client = proxies.resolve(Some(ip("198.51.100.1")), &headers);
+
+            assert_eq!(client.ip, Some(ip("198.51.100.1")));
+            assert!(!client.https);
+        }
+
+        /// Tests resolution of the client behind a trusted proxy.
+        ///
+        /// Expected: Forwarded client address with HTTPS
+        #[test]
+        fn uses_forwarded_client_from_trusted_peer() {
+            let proxies = TrustedProxies::parse("10.0.0.1").unwrap();
+            let headers = forwarded("203.0.113.7", Some("https"));
+
+            let client = proxies.resolve(Some(ip("10.0.0.1")), &headers);
+
+            assert_eq!(client.ip, Some(ip("203.0.113.7")));
+            assert!(client.https);
+        }
+
+        /// Tests that client-supplied entries left of the real client are ignored.
+        ///
+        /// Verifies that a client prepending a fake address to `X-Forwarded-For` cannot
+        /// spoof their IP when behind a chain of trusted proxies.
+        ///
+        /// Expected: Rightmost untrusted address
+        #[test]
+        fn skips_trusted_hops_and_ignores_spoofed_entries() {
+            let proxies = TrustedProxies::parse("10.0.0.0/8").unwrap();
+            let headers = forwarded("1.1.1.1, 203.0.113.7, 10.0.0.2", None);
+
+            let client = proxies.resolve(Some(ip("10.0.0.1")), &headers);
+
+            assert_eq!(client.ip, Some(ip("203.0.113.7")));
+            assert!(!client.https);
+        }
+
+        /// Tests that resolution stops at malformed entries.
+        ///
+        /// Expected: Last valid hop before the malformed entry
+        #[test]
+        fn stops_at_malformed_entry() {
+            let proxies = TrustedProxies::parse("10.0.0.0/8").unwrap();
+            let headers = forwarded("203.0.113.7, garbage, 10.0.0.2", None);
+
+            let client = proxies.resolve(Some(ip("10.0.0.1")), &headers);
+
+            assert_eq!(client.ip, Some(ip("10.0.0.2")));
+        }
+
+        /// Tests resolution without a known peer address.
+        ///
+        /// Expected: Unknown client
+        #[test]
+        fn unknown_without_peer() {
+            let proxies = TrustedProxies::parse("10.0.0.1").unwrap();
+            let headers = forwarded("203.0.113.7", Some("https"));
+
+            let client = proxies.resolve(None, &headers);
+
+            assert_eq!(client, ClientInfo::default());
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Database query counting for N+1 detection in debug builds.
+//!
+//! This module counts the database queries executed while handling each HTTP request and
+//! worker job, and logs a warning when a single request or job executes more queries than
+//! `QUERY_COUNT_WARN_THRESHOLD`. A high count usually means a service looks records up one
+//! row at a time instead of in batches. The warning is emitted within the request or job's
+//! tracing span, so it carries the originating context.
+//!
+//! Queries are counted through SeaORM's metric callback into a task-local counter, so only
+//! queries awaited within the counted future are attributed to it; work spawned onto other
+//! tasks is not counted. Counting is compiled in but inactive in release builds.
+
+use std::{
+    future::Future,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use axum::{extract::Request, middleware::Next, response::Response};
+use dioxus_logger::tracing;
+use sea_orm::DatabaseConnection;
+
+/// Number of queries within one request or job above which a warning is logged.
+pub const QUERY_COUNT_WARN_THRESHOLD: usize = 20;
+
+tokio::task_local! {
+    /// Queries executed by the request or job currently running on this task.
+    static QUERY_COUNT: AtomicUsize;
+}
+
+/// Returns whether query counting is active for this build.
+fn enabled() -> bool {
+    cfg!(debug_assertions)
+}
+
+/// Registers the query counting callback on the database connection in debug builds.
+///
+/// # Arguments
+/// - `db` - Database connection to count queries of
+pub fn install(db: &mut DatabaseConnection) {
+    if enabled() {
+        db.set_metric_callback(|_info| record_query());
+    }
+}
+
+/// Records one query against the request or job running on the current task, if any.
+pub fn record_query() {
+    let _ = QUERY_COUNT.try_with(|count| count.fetch_add(1, Ordering::Relaxed));
+}
+
+/// Runs a future while counting the queries it executes.
+///
+/// Logs a warning if the count exceeds `QUERY_COUNT_WARN_THRESHOLD`. In release builds the
+/// future is awaited without counting.
+///
+/// # Arguments
+/// - `scope` - Description of the counted work for the log message, e.g. `GET /api/auth/user`
+/// - `future` - Request or job to run
+///
+/// # Returns
+/// - `F::Output` - Output of the future
+pub async fn count_queries<F: Future>(scope: &str, future: F) -> F::Output {
+    if !enabled() {
+        return future.await;
+    }
+
+    QUERY_COUNT
+        .scope(AtomicUsize::new(0), async {
+            let output = future.await;
+            let count = QUERY_COUNT.with(|count| count.load(Ordering::Relaxed));
+
+            if count > QUERY_COUNT_WARN_THRESHOLD {
+                tracing::warn!(
+                    queries = count,
+                    threshold = QUERY_COUNT_WARN_THRESHOLD,
+                    "{} executed {} database queries, check for per-row lookups",
+                    scope,
+                    count
+                );
+            } else {
+                tracing::trace!("{} executed {} database queries", scope, count);
+            }
+
+            output
+        })
+        .await
+}
+
+/// Middleware counting the database queries executed while handling each request.
+///
+/// # Arguments
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler
+pub async fn count_request_queries(request: Request, next: Next) -> Response {
+    let scope = format!("{} {}", request.method(), request.uri().path());
+
+    count_queries(&scope, next.run(request)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that queries are attributed to the enclosing counted scope.
+    ///
+    /// Expected: Count of queries recorded inside the scope, none leaking outside
+    #[tokio::test]
+    async fn counts_queries_within_scope() {
+        if !enabled() {
+            return;
+        }
+
+        let count = count_queries("test", async {
+            record_query();
+            record_query();
+            QUERY_COUNT.with(|count| count.load(Ordering::Relaxed))
+        })
+        .await;
+
+        assert_eq!(count, 2);
+        assert!(QUERY_COUNT.try_with(|_| ()).is_err());
+    }
+
+    /// Tests that recording outside a counted scope is a no-op.
+    ///
+    /// Expected: No panic
+    #[test]
+    fn ignores_queries_outside_scope() {
+        record_query();
+    }
+}
@@ -0,0 +1,218 @@
+//! Rate limiting of EVE Online SSO endpoints.
+//!
+//! This module provides the limits on how often clients may start logins and complete
+//! callbacks, and the middleware enforcing them. Every login and callback talks to EVE
+//! Online's SSO and ESI, so unthrottled clients could exhaust Bifrost's ESI error budget or
+//! brute-force CSRF states. Requests are counted per client IP address and per session within
+//! a fixed window, and requests over either limit are answered with `429 Too Many Requests`.
+//! Callbacks of a character link queue are only counted per IP address, since the queue sends
+//! the user through EVE Online SSO once per queued character.
+
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use dioxus_logger::tracing;
+use fred::prelude::Pool;
+use tower_sessions::Session;
+
+use crate::server::{
+    error::rate_limit::RateLimitError, model::session::link_queue::SessionUserLinkQueue,
+    service::rate_limit::RateLimitService, util::proxy::ClientInfo,
+};
+
+/// Default number of SSO requests allowed per IP address within the window.
+pub const DEFAULT_AUTH_RATE_LIMIT_PER_IP: u32 = 30;
+
+/// Default number of SSO requests allowed per session within the window.
+pub const DEFAULT_AUTH_RATE_LIMIT_PER_SESSION: u32 = 10;
+
+/// Default length of the window SSO requests are counted in.
+pub const DEFAULT_AUTH_RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Path of the EVE Online SSO callback.
+const CALLBACK_ROUTE: &str = "/api/auth/callback";
+
+/// Paths of the EVE Online SSO routes that are rate limited.
+const RATE_LIMITED_ROUTES: &[&str] = &["/api/auth/login", CALLBACK_ROUTE];
+
+/// Limits on requests to the EVE Online SSO endpoints.
+///
+/// A limit of `0` disables counting requests by that subject.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AuthRateLimit {
+    /// Requests allowed per client IP address within the window.
+    pub per_ip: u32,
+    /// Requests allowed per session within the window.
+    pub per_session: u32,
+    /// Length of the window requests are counted in.
+    pub window: Duration,
+}
+
+impl Default for AuthRateLimit {
+    fn default() -> Self {
+        Self {
+            per_ip: DEFAULT_AUTH_RATE_LIMIT_PER_IP,
+            per_session: DEFAULT_AUTH_RATE_LIMIT_PER_SESSION,
+            window: DEFAULT_AUTH_RATE_LIMIT_WINDOW,
+        }
+    }
+}
+
+impl AuthRateLimit {
+    /// Returns whether requests to a path are rate limited.
+    ///
+    /// # Arguments
+    /// - `path` - Request path
+    ///
+    /// # Returns
+    /// - `true` - Path is an EVE Online SSO route
+    /// - `false` - Path isn't rate limited
+    pub fn applies_to(path: &str) -> bool {
+        RATE_LIMITED_ROUTES.contains(&path)
+    }
+
+    /// Returns whether requests to a path are counted per session.
+    ///
+    /// Callbacks while a character link queue is active aren't, since a queue may hold more
+    /// characters than the per-session limit allows callbacks within the window. They're still
+    /// counted per IP address.
+    ///
+    /// # Arguments
+    /// - `path` - Request path
+    /// - `link_queue_active` - Whether the session has characters queued for linking
+    ///
+    /// # Returns
+    /// - `true` - Request counts towards the per-session limit
+    /// - `false` - Request is a callback of an active link queue
+    pub fn counts_session(path: &str, link_queue_active: bool) -> bool {
+        !(path == CALLBACK_ROUTE && link_queue_active)
+    }
+}
+
+/// Configured SSO rate limits with the Redis pool their counters are kept in.
+#[derive(Clone)]
+pub struct AuthRateLimiter {
+    pool: Pool,
+    limits: AuthRateLimit,
+}
+
+impl AuthRateLimiter {
+    /// Creates the rate limiter of the EVE Online SSO endpoints.
+    ///
+    /// # Arguments
+    /// - `pool` - Redis connection pool holding the request counters
+    /// - `limits` - Configured SSO rate limits
+    ///
+    /// # Returns
+    /// - `AuthRateLimiter` - New rate limiter
+    pub fn new(pool: Pool, limits: AuthRateLimit) -> Self {
+        Self { pool, limits }
+    }
+}
+
+/// Middleware rejecting clients that make too many requests to the EVE Online SSO endpoints.
+///
+/// Requests are counted by client IP address and, if the request carries a session cookie, by
+/// session. Requests without a known IP address or session are only counted by the other
+/// subject, as are callbacks while characters are queued for linking. Failing to reach Redis
+/// lets the request through, so an outage doesn't lock every user out of logging in.
+///
+/// # Arguments
+/// - `limiter` - Configured SSO rate limits and the Redis pool of the counters
+/// - `session` - User's session, counted once it has been saved unless a link queue is active
+/// - `client` - Client resolved by the `record_client_info` middleware
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler, or
+///   `429 Too Many Requests` for clients over a limit
+pub async fn limit_auth_requests(
+    State(limiter): State<AuthRateLimiter>,
+    session: Session,
+    client: ClientInfo,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !AuthRateLimit::applies_to(request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    // Failing to read the link queue counts the callback per session as usual
+    let link_queue_active = matches!(SessionUserLinkQueue::get(&session).await, Ok(Some(_)));
+    let session_id = session
+        .id()
+        .filter(|_| AuthRateLimit::counts_session(request.uri().path(), link_queue_active));
+
+    let limits = limiter.limits;
+    let subjects = [
+        ("auth:ip", client.ip.map(|ip| ip.to_string()), limits.per_ip),
+        (
+            "auth:session",
+            session_id.map(|id| id.to_string()),
+            limits.per_session,
+        ),
+    ];
+
+    let service = RateLimitService::new(&limiter.pool);
+    for (scope, subject, limit) in subjects {
+        let Some(subject) = subject.filter(|_| limit > 0) else {
+            continue;
+        };
+
+        match service.hit(scope, &subject, limit, limits.window).await {
+            Ok(None) => {}
+            Ok(Some(retry_after)) => {
+                tracing::info!(
+                    "Rate limited {} request to {} for {} seconds",
+                    scope,
+                    request.uri().path(),
+                    retry_after
+                );
+                return RateLimitError::TooManyRequests { retry_after }.into_response();
+            }
+            Err(err) => tracing::warn!("Failed to check {} rate limit: {}", scope, err),
+        }
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod applies_to {
+        use super::*;
+
+        /// Tests that only the SSO login and callback are rate limited.
+        ///
+        /// Expected: true for the login and callback, false for other auth routes
+        #[test]
+        fn limits_sso_routes() {
+            assert!(AuthRateLimit::applies_to("/api/auth/login"));
+            assert!(AuthRateLimit::applies_to("/api/auth/callback"));
+            assert!(!AuthRateLimit::applies_to("/api/auth/user"));
+            assert!(!AuthRateLimit::applies_to("/api/auth/logout"));
+        }
+    }
+
+    mod counts_session {
+        use super::*;
+
+        /// Tests that only callbacks of an active link queue skip the per-session limit.
+        ///
+        /// Expected: false for a callback with a queue, true otherwise
+        #[test]
+        fn skips_link_queue_callbacks() {
+            assert!(!AuthRateLimit::counts_session("/api/auth/callback", true));
+            assert!(AuthRateLimit::counts_session("/api/auth/callback", false));
+            assert!(AuthRateLimit::counts_session("/api/auth/login", true));
+            assert!(AuthRateLimit::counts_session("/api/auth/login", false));
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Read-only mode for database maintenance.
+//!
+//! This module provides the runtime flag admins toggle before maintenance such as database
+//! migrations or failovers, and the middleware enforcing it. While read-only mode is enabled,
+//! API requests that would write to the database are answered with
+//! `503 Service Unavailable` so clients know to retry later, while reads keep working. The
+//! worker pool checks the same flag and pauses dispatching jobs until it is disabled again.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use axum::{
+    extract::{Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::server::error::maintenance::MaintenanceError;
+
+/// Paths of routes that keep accepting writes in read-only mode.
+///
+/// Admins must be able to disable read-only mode, and leaving character linking mode only
+/// writes to the session store.
+const WRITABLE_ROUTES: &[&str] = &["/api/admin/read-only", "/api/auth/link-mode"];
+
+/// Paths of `GET` routes that write to the database or queue worker jobs.
+///
+/// Reads that only record bookkeeping, such as the last use of a data API key, stay available
+/// and skip that write themselves while read-only mode is enabled.
+const WRITING_GET_ROUTES: &[&str] = &[
+    "/api/auth/callback",
+    "/api/auth/discord/callback",
+    "/api/user/export",
+];
+
+/// Runtime flag putting the server in read-only mode.
+///
+/// Clones share the same flag, so toggling it through the application state is seen by the
+/// middleware and the worker pool immediately.
+#[derive(Clone, Debug, Default)]
+pub struct ReadOnlyMode(Arc<AtomicBool>);
+
+impl ReadOnlyMode {
+    /// Creates the flag with read-only mode enabled or disabled.
+    ///
+    /// # Arguments
+    /// - `enabled` - Whether read-only mode starts enabled
+    ///
+    /// # Returns
+    /// - `ReadOnlyMode` - New flag
+    pub fn new(enabled: bool) -> Self {
+        Self(Arc::new(AtomicBool::new(enabled)))
+    }
+
+    /// Returns whether read-only mode is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Enables or disables read-only mode.
+    ///
+    /// # Arguments
+    /// - `enabled` - Whether read-only mode is enabled
+    ///
+    /// # Returns
+    /// - `bool` - Whether read-only mode was enabled before
+    pub fn set(&self, enabled: bool) -> bool {
+        self.0.swap(enabled, Ordering::Relaxed)
+    }
+
+    /// Returns whether a request writes to the database and is rejected in read-only mode.
+    ///
+    /// # Arguments
+    /// - `method` - Request method
+    /// - `path` - Request path
+    ///
+    /// # Returns
+    /// - `true` - Request writes to the database
+    /// - `false` - Request is a read, isn't an API request, or is always allowed
+    pub fn is_write(method: &Method, path: &str) -> bool {
+        if !path.starts_with("/api/") || WRITABLE_ROUTES.contains(&path) {
+            return false;
+        }
+
+        match *method {
+            Method::GET | Method::HEAD | Method::OPTIONS => WRITING_GET_ROUTES.contains(&path),
+            _ => true,
+        }
+    }
+}
+
+/// Middleware rejecting requests that write to the database while read-only mode is enabled.
+///
+/// # Arguments
+/// - `read_only` - Read-only mode flag
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler, or
+///   `503 Service Unavailable` for writes in read-only mode
+pub async fn reject_writes_when_read_only(
+    State(read_only): State<ReadOnlyMode>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if read_only.is_enabled() && ReadOnlyMode::is_write(request.method(), request.uri().path()) {
+        return MaintenanceError::ReadOnly.into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::{
+        body::Body,
+        http::{header, StatusCode},
+        routing::{get, post},
+        Router,
+    };
+    use tower::ServiceExt;
+
+    use crate::model::api::ProblemDto;
+
+    mod is_write {
+        use super::*;
+
+        /// Tests that reads are not writes.
+        ///
+        /// Expected: false for GET, HEAD, and OPTIONS requests
+        #[test]
+        fn allows_reads() {
+            for method in [Method::GET, Method::HEAD, Method::OPTIONS] {
+                assert!(!ReadOnlyMode::is_write(&method, "/api/fittings"));
+            }
+        }
+
+        /// Tests that requests changing data are writes.
+        ///
+        /// Expected: true for POST, PUT, and DELETE requests and the SSO callback
+        #[test]
+        fn rejects_writes() {
+            for method in [Method::POST, Method::PUT, Method::DELETE] {
+                assert!(ReadOnlyMode::is_write(&method, "/api/fittings"));
+            }
+            assert!(ReadOnlyMode::is_write(&Method::GET, "/api/auth/callback"));
+        }
+
+        /// Tests that the Discord OAuth callback is a write.
+        ///
+        /// Expected: true for GET requests to the Discord callback
+        #[test]
+        fn rejects_discord_callback() {
+            assert!(ReadOnlyMode::is_write(
+                &Method::GET,
+                "/api/auth/discord/callback"
+            ));
+        }
+
+        /// Tests that requesting a data export is a write.
+        ///
+        /// Expected: true for GET requests queuing an export
+        #[test]
+        fn rejects_data_export() {
+            assert!(ReadOnlyMode::is_write(&Method::GET, "/api/user/export"));
+        }
+
+        /// Tests that the read-only toggle and non-API routes stay writable.
+        ///
+        /// Expected: false for the toggle and frontend routes
+        #[test]
+        fn allows_toggle_and_frontend() {
+            assert!(!ReadOnlyMode::is_write(
+                &Method::PUT,
+                "/api/admin/read-only"
+            ));
+            assert!(!ReadOnlyMode::is_write(&Method::POST, "/auth"));
+        }
+    }
+
+    mod reject_writes_when_read_only {
+        use super::*;
+
+        fn router(read_only: ReadOnlyMode) -> Router {
+            Router::new()
+                .route(
+                    "/api/items",
+                    get(|| async { "read" }).post(|| async { "written" }),
+                )
+                .layer(axum::middleware::from_fn_with_state(
+                    read_only,
+                    reject_writes_when_read_only,
+                ))
+        }
+
+        fn request(method: Method) -> Request {
+            Request::builder()
+                .method(method)
+                .uri("/api/items")
+                .body(Body::empty())
+                .unwrap()
+        }
+
+        /// Tests that writes reach the handler while read-only mode is disabled.
+        ///
+        /// Expected: 200 OK
+        #[tokio::test]
+        async fn accepts_writes_when_disabled() {
+            let response = router(ReadOnlyMode::new(false))
+                .oneshot(request(Method::POST))
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        /// Tests that only writes are rejected while read-only mode is enabled.
+        ///
+        /// Expected: 503 Service Unavailable problem details for the write, 200 OK for the read
+        #[tokio::test]
+        async fn rejects_writes_when_enabled() {
+            let read_only = ReadOnlyMode::new(false);
+            read_only.set(true);
+
+            let write = router(read_only.clone())
+                .oneshot(request(Method::POST))
+                .await
+                .unwrap();
+            let read = router(read_only)
+                .oneshot(request(Method::GET))
+                .await
+                .unwrap();
+
+            assert_eq!(write.status(), StatusCode::SERVICE_UNAVAILABLE);
+            assert_eq!(read.status(), StatusCode::OK);
+            assert_eq!(
+                write.headers()[header::CONTENT_TYPE],
+                "application/problem+json"
+            );
+
+            let body = axum::body::to_bytes(write.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let problem: ProblemDto = serde_json::from_slice(&body).unwrap();
+            assert_eq!(problem.status, 503);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Named ESI scope sets.
+//!
+//! This module parses the `ESI_SCOPE_SETS` environment variable that lets deployments define
+//! bundles of ESI scopes requested at login, e.g. a `member_audit` set with every scope their
+//! auditing tools need, without code changes. Logins select a set by name with the `scopes`
+//! query parameter.
+//!
+//! # Format
+//!
+//! Sets are configured as a comma-separated list of `name:scopes` pairs with space-separated
+//! scopes, e.g. `member_audit:esi-skills.read_skills.v1 esi-assets.read_assets.v1,mining:esi-industry.read_character_mining.v1`.
+
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+/// Prefix every ESI scope starts with.
+const ESI_SCOPE_PREFIX: &str = "esi-";
+
+/// Error returned when parsing or resolving scope sets.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ScopeSetError {
+    /// An `ESI_SCOPE_SETS` entry could not be parsed.
+    #[error("Invalid scope set {0:?}: must be in name:scopes notation with at least one scope")]
+    InvalidSet(String),
+
+    /// A scope listed in a set is not an ESI scope.
+    #[error("Invalid scope {0:?}: ESI scopes start with \"esi-\"")]
+    InvalidScope(String),
+
+    /// Two entries define a set with the same name.
+    #[error("Scope set {0:?} is defined more than once")]
+    DuplicateSet(String),
+
+    /// A requested scope set is neither configured nor an ESI scope.
+    #[error("Unknown scope set {0:?}")]
+    UnknownSet(String),
+}
+
+/// Named bundles of ESI scopes logins can request.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScopeSets {
+    sets: BTreeMap<String, Vec<String>>,
+}
+
+impl ScopeSets {
+    /// Parses a comma-separated list of `name:scopes` scope sets.
+    ///
+    /// Empty entries are ignored, so an empty string defines no sets.
+    ///
+    /// # Arguments
+    /// - `value` - Comma-separated sets, e.g. `audit:esi-skills.read_skills.v1 esi-assets.read_assets.v1`
+    ///
+    /// # Returns
+    /// - `Ok(ScopeSets)` - Parsed sets with duplicate scopes removed
+    /// - `Err(ScopeSetError)` - An entry has no name or scopes, lists a scope not starting with
+    ///   `esi-`, or reuses the name of an earlier entry
+    pub fn parse(value: &str) -> Result<Self, ScopeSetError> {
+        let mut sets = BTreeMap::new();
+
+        for entry in value.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (name, scopes) = entry
+                .split_once(':')
+                .map(|(name, scopes)| (name.trim(), scopes))
+                .filter(|(name, scopes)| !name.is_empty() && !scopes.trim().is_empty())
+                .ok_or_else(|| ScopeSetError::InvalidSet(entry.to_string()))?;
+
+            let mut parsed: Vec<String> = Vec::new();
+            for scope in scopes.split_whitespace() {
+                if !scope.starts_with(ESI_SCOPE_PREFIX) {
+                    return Err(ScopeSetError::InvalidScope(scope.to_string()));
+                }
+                if !parsed.iter().any(|existing| existing == scope) {
+                    parsed.push(scope.to_string());
+                }
+            }
+
+            if sets.insert(name.to_string(), parsed).is_some() {
+                return Err(ScopeSetError::DuplicateSet(name.to_string()));
+            }
+        }
+
+        Ok(Self { sets })
+    }
+
+    /// Returns whether no scope sets are configured.
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+
+    /// Returns the names of the configured scope sets in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.sets.keys().map(String::as_str)
+    }
+
+    /// Resolves the `scopes` parameter of a login into the ESI scopes to request.
+    ///
+    /// Each space-separated entry is either the name of a configured set, which is replaced by
+    /// its scopes, or a single ESI scope requested as is.
+    ///
+    /// # Arguments
+    /// - `value` - Space-separated set names and ESI scopes, e.g. `member_audit`
+    ///
+    /// # Returns
+    /// - `Ok(Vec<String>)` - Requested scopes in order with duplicates removed, empty for an
+    ///   empty value
+    /// - `Err(ScopeSetError::UnknownSet)` - An entry is neither a set name nor an ESI scope
+    pub fn resolve(&self, value: &str) -> Result<Vec<String>, ScopeSetError> {
+        let mut scopes: Vec<String> = Vec::new();
+
+        for entry in value.split_whitespace() {
+            let resolved: Vec<&str> = match self.sets.get(entry) {
+                Some(set) => set.iter().map(String::as_str).collect(),
+                None if entry.starts_with(ESI_SCOPE_PREFIX) => vec![entry],
+                None => return Err(ScopeSetError::UnknownSet(entry.to_string())),
+            };
+
+            for scope in resolved {
+                if !scopes.iter().any(|existing| existing == scope) {
+                    scopes.push(scope.to_string());
+                }
+            }
+        }
+
+        Ok(scopes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expected: Ok with each set's scopes in configured order, duplicates and empty entries removed
+    #[test]
+    fn parses_scope_sets() {
+        let sets = ScopeSets::parse(
+            " audit : esi-skills.read_skills.v1  esi-assets.read_assets.v1 esi-skills.read_skills.v1, ,mining:esi-industry.read_character_mining.v1",
+        )
+        .unwrap();
+
+        assert_eq!(sets.names().collect::<Vec<_>>(), vec!["audit", "mining"]);
+        assert_eq!(
+            sets.resolve("audit").unwrap(),
+            vec!["esi-skills.read_skills.v1", "esi-assets.read_assets.v1"]
+        );
+        assert!(ScopeSets::parse("").unwrap().is_empty());
+    }
+
+    /// Expected: Err for entries without a name or scopes, non-ESI scopes, or repeated names
+    #[test]
+    fn rejects_invalid_scope_sets() {
+        assert_eq!(
+            ScopeSets::parse("esi-skills.read_skills.v1"),
+            Err(ScopeSetError::InvalidSet(
+                "esi-skills.read_skills.v1".to_string()
+            ))
+        );
+        assert_eq!(
+            ScopeSets::parse("audit: "),
+            Err(ScopeSetError::InvalidSet("audit:".to_string()))
+        );
+        assert_eq!(
+            ScopeSets::parse("audit:publicData"),
+            Err(ScopeSetError::InvalidScope("publicData".to_string()))
+        );
+        assert_eq!(
+            ScopeSets::parse("audit:esi-skills.read_skills.v1,audit:esi-assets.read_assets.v1"),
+            Err(ScopeSetError::DuplicateSet("audit".to_string()))
+        );
+    }
+
+    /// Expected: Ok with set names expanded and raw ESI scopes kept, Err for unknown names
+    #[test]
+    fn resolves_set_names_and_scopes() {
+        let sets =
+            ScopeSets::parse("audit:esi-skills.read_skills.v1 esi-assets.read_assets.v1").unwrap();
+
+        assert_eq!(
+            sets.resolve("esi-wallet.read_character_wallet.v1 audit esi-skills.read_skills.v1")
+                .unwrap(),
+            vec![
+                "esi-wallet.read_character_wallet.v1",
+                "esi-skills.read_skills.v1",
+                "esi-assets.read_assets.v1",
+            ]
+        );
+        assert_eq!(sets.resolve("").unwrap(), Vec::<String>::new());
+        assert_eq!(
+            sets.resolve("mining"),
+            Err(ScopeSetError::UnknownSet("mining".to_string()))
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Sliding session expiry settings.
+//!
+//! This module provides the settings controlling how long sessions stay valid without requests,
+//! and the middleware extending remembered sessions. Every request made with a session pushes
+//! its expiry back, so active users stay logged in while abandoned sessions expire after
+//! `SESSION_INACTIVITY_DAYS`. Users choosing "remember me" when logging in get a session that
+//! expires after the longer `SESSION_REMEMBER_ME_DAYS` of inactivity instead.
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use dioxus_logger::tracing;
+use time::Duration;
+use tower_sessions::{Expiry, Session};
+
+use crate::server::model::{app::AppState, session::remember_me::SessionUserRememberMe};
+
+/// Default number of days sessions stay valid without requests.
+const DEFAULT_INACTIVITY_DAYS: u32 = 7;
+
+/// Default number of days remembered sessions stay valid without requests.
+const DEFAULT_REMEMBER_ME_DAYS: u32 = 30;
+
+/// Days of inactivity after which sessions expire.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SessionExpiry {
+    /// Days sessions stay valid without requests.
+    pub inactivity_days: u32,
+    /// Days sessions of users who chose "remember me" stay valid without requests.
+    pub remember_me_days: u32,
+}
+
+impl Default for SessionExpiry {
+    fn default() -> Self {
+        Self {
+            inactivity_days: DEFAULT_INACTIVITY_DAYS,
+            remember_me_days: DEFAULT_REMEMBER_ME_DAYS,
+        }
+    }
+}
+
+impl SessionExpiry {
+    /// Returns the expiry of sessions that aren't remembered.
+    pub fn default_expiry(&self) -> Expiry {
+        Expiry::OnInactivity(Duration::days(self.inactivity_days.into()))
+    }
+
+    /// Returns the expiry of remembered sessions.
+    pub fn remember_me_expiry(&self) -> Expiry {
+        Expiry::OnInactivity(Duration::days(self.remember_me_days.into()))
+    }
+}
+
+/// Middleware applying the longer expiry to remembered sessions.
+///
+/// The session layer starts every request with the default expiry, so remembered sessions are
+/// extended again after each request, including the callback marking the session as
+/// remembered. Failing to read the session keeps the default expiry.
+///
+/// # Arguments
+/// - `state` - Application state containing the session expiry settings
+/// - `session` - User's session, possibly marked as remembered
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler
+pub async fn apply_session_expiry(
+    State(state): State<AppState>,
+    session: Session,
+    request: Request,
+    next: Next,
+) -> Response {
+    let response = next.run(request).await;
+
+    match SessionUserRememberMe::get(&session).await {
+        Ok(true) => session.set_expiry(Some(state.session_expiry.remember_me_expiry())),
+        Ok(false) => {}
+        Err(err) => tracing::debug!("Failed to read remember me flag from session: {}", err),
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod remember_me_expiry {
+        use super::*;
+
+        /// Tests that remembered sessions use the remember me days.
+        ///
+        /// Expected: Inactivity expiry of 30 days by default, 7 days for other sessions
+        #[test]
+        fn uses_remember_me_days() {
+            let expiry = SessionExpiry::default();
+
+            assert_eq!(
+                expiry.remember_me_expiry(),
+                Expiry::OnInactivity(Duration::days(30))
+            );
+            assert_eq!(
+                expiry.default_expiry(),
+                Expiry::OnInactivity(Duration::days(7))
+            );
+        }
+    }
+}
@@ -0,0 +1,85 @@
+//! Session index maintenance for listing user sessions.
+//!
+//! This module provides the middleware recording each request of a logged in user in their
+//! session index, so `GET /api/user/sessions` can show where they're logged in, from which
+//! address, and when each session was last used.
+
+use axum::{
+    extract::{Request, State},
+    http::header::USER_AGENT,
+    middleware::Next,
+    response::Response,
+};
+use dioxus_logger::tracing;
+use tower_sessions::Session;
+
+use crate::server::{
+    model::{app::AppState, session::user::SessionUserId},
+    service::session::SessionService,
+    util::proxy::ClientInfo,
+};
+
+/// Middleware recording requests of logged in users in their session index.
+///
+/// The session is recorded after the request is handled, so logins are indexed from the first
+/// request after the callback and logouts don't re-add the session they ended. Failing to
+/// update the index doesn't fail the request.
+///
+/// # Arguments
+/// - `state` - Application state containing the Redis pool
+/// - `session` - User's session containing their user ID
+/// - `client` - Client resolved by the `record_client_info` middleware
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler
+pub async fn index_sessions(
+    State(state): State<AppState>,
+    session: Session,
+    client: ClientInfo,
+    request: Request,
+    next: Next,
+) -> Response {
+    let user_agent = request
+        .headers()
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+
+    let response = next.run(request).await;
+
+    let Some(session_id) = session.id() else {
+        return response;
+    };
+    let user_id = match SessionUserId::get(&session).await {
+        Ok(Some(user_id)) => user_id,
+        Ok(None) => return response,
+        Err(err) => {
+            tracing::debug!(
+                "Failed to read user from session for session index: {}",
+                err
+            );
+            return response;
+        }
+    };
+
+    if let Err(err) = SessionService::new(&state.redis_pool)
+        .touch(
+            user_id,
+            session_id,
+            session.expiry_age().whole_seconds(),
+            client,
+            user_agent.as_deref(),
+        )
+        .await
+    {
+        tracing::warn!(
+            "Failed to record session of user {} in session index: {}",
+            user_id,
+            err
+        );
+    }
+
+    response
+}
@@ -0,0 +1,303 @@
+//! Skill plan text and EVEMon XML parser.
+//!
+//! This module parses skill plans pasted as plain text, one `Skill Name Level` entry per line
+//! as produced by EVEMon's text export and most skill planners, or as an EVEMon `.emp` XML
+//! export. Levels may be written as numbers (`4`) or roman numerals (`IV`). Parsing is purely
+//! textual: skill names are not resolved to type IDs, so a plan may reference skills that do
+//! not exist if the input was hand-edited.
+
+use thiserror::Error;
+
+/// Maximum number of entries accepted in a single skill plan.
+///
+/// Even plans covering whole ship lines rarely exceed a few hundred entries. The limit
+/// prevents arbitrarily large pasted inputs from being stored as plans.
+pub const SKILL_PLAN_MAX_ENTRIES: usize = 1000;
+
+/// Roman numerals for skill levels 1 through 5.
+const ROMAN_LEVELS: [&str; 5] = ["I", "II", "III", "IV", "V"];
+
+/// Error returned when a skill plan cannot be parsed.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum SkillPlanParseError {
+    /// Input contained no skill entries.
+    #[error("Skill plan is empty")]
+    Empty,
+
+    /// A plain text line was not in the form `Skill Name Level`.
+    #[error("Invalid skill plan line {line}: {content:?}, expected format Skill Name Level")]
+    InvalidLine {
+        /// 1-based line number within the input.
+        line: usize,
+        /// Content of the rejected line.
+        content: String,
+    },
+
+    /// An EVEMon XML `<entry>` was missing its skill name or had an invalid level.
+    #[error("Invalid skill plan entry {entry}: {reason}")]
+    InvalidEntry {
+        /// 1-based position of the entry within the plan.
+        entry: usize,
+        /// Explanation of why the entry was rejected.
+        reason: String,
+    },
+
+    /// Plan exceeded `SKILL_PLAN_MAX_ENTRIES` entries.
+    #[error("Skill plan has too many entries (maximum {0})")]
+    TooManyEntries(usize),
+}
+
+/// A single skill level within a skill plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillPlanEntry {
+    /// Name of the skill, e.g. `Caldari Frigate`.
+    pub skill_name: String,
+    /// Level to train the skill to, from 1 to 5.
+    pub level: i32,
+}
+
+/// Parses a skill plan in plain text or EVEMon XML format.
+///
+/// Input starting with `<` is parsed as EVEMon XML, reading the `skill` and `level` attributes
+/// of each `<entry>` element. Any other input is parsed as plain text where each non-empty
+/// line is a skill name followed by a level.
+///
+/// # Arguments
+/// - `input` - Skill plan text
+///
+/// # Returns
+/// - `Ok(Vec<SkillPlanEntry>)` - Plan entries in training order
+/// - `Err(SkillPlanParseError::Empty)` - Input contained no skill entries
+/// - `Err(SkillPlanParseError::InvalidLine)` - A plain text line has no valid level
+/// - `Err(SkillPlanParseError::InvalidEntry)` - An XML entry has no skill name or valid level
+/// - `Err(SkillPlanParseError::TooManyEntries)` - Plan exceeded `SKILL_PLAN_MAX_ENTRIES` entries
+///
+/// # Example
+/// ```ignore
+/// let entries = parse_skill_plan("Caldari Frigate IV\nMissile Launcher Operation 3")?;
+/// assert_eq!(entries[0].level, 4);
+/// ```
+pub fn parse_skill_plan(input: &str) -> Result<Vec<SkillPlanEntry>, SkillPlanParseError> {
+    let entries = if input.trim_start().starts_with('<') {
+        parse_xml(input)?
+    } else {
+        parse_text(input)?
+    };
+
+    if entries.is_empty() {
+        return Err(SkillPlanParseError::Empty);
+    }
+
+    Ok(entries)
+}
+
+/// Formats skill plan entries as plain text with roman numeral levels.
+///
+/// The output can be parsed again with `parse_skill_plan` and pasted into EVEMon.
+///
+/// # Arguments
+/// - `entries` - Plan entries in training order
+///
+/// # Returns
+/// - `String` - One `Skill Name Level` line per entry
+pub fn format_skill_plan(entries: &[SkillPlanEntry]) -> String {
+    entries
+        .iter()
+        .map(|entry| {
+            let level = usize::try_from(entry.level - 1)
+                .ok()
+                .and_then(|index| ROMAN_LEVELS.get(index))
+                .map(|level| level.to_string())
+                .unwrap_or_else(|| entry.level.to_string());
+
+            format!("{} {}", entry.skill_name, level)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parses a plain text plan with one `Skill Name Level` entry per line.
+fn parse_text(input: &str) -> Result<Vec<SkillPlanEntry>, SkillPlanParseError> {
+    let mut entries = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if entries.len() >= SKILL_PLAN_MAX_ENTRIES {
+            return Err(SkillPlanParseError::TooManyEntries(SKILL_PLAN_MAX_ENTRIES));
+        }
+
+        let invalid = || SkillPlanParseError::InvalidLine {
+            line: index + 1,
+            content: line.to_string(),
+        };
+
+        let (skill_name, level) = line.rsplit_once(' ').ok_or_else(invalid)?;
+        let skill_name = skill_name.trim();
+        let level = parse_level(level).ok_or_else(invalid)?;
+
+        if skill_name.is_empty() {
+            return Err(invalid());
+        }
+
+        entries.push(SkillPlanEntry {
+            skill_name: skill_name.to_string(),
+            level,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Parses the `<entry>` elements of an EVEMon XML plan.
+fn parse_xml(input: &str) -> Result<Vec<SkillPlanEntry>, SkillPlanParseError> {
+    let mut entries = Vec::new();
+
+    for (index, element) in input.split("<entry").skip(1).enumerate() {
+        if entries.len() >= SKILL_PLAN_MAX_ENTRIES {
+            return Err(SkillPlanParseError::TooManyEntries(SKILL_PLAN_MAX_ENTRIES));
+        }
+
+        let invalid = |reason: &str| SkillPlanParseError::InvalidEntry {
+            entry: index + 1,
+            reason: reason.to_string(),
+        };
+
+        let tag = element.split('>').next().unwrap_or_default();
+
+        let skill_name = xml_attribute(tag, "skill")
+            .map(|name| unescape_xml(name.trim()))
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| invalid("missing skill attribute"))?;
+        let level = xml_attribute(tag, "level")
+            .and_then(parse_level)
+            .ok_or_else(|| invalid("level must be between 1 and 5"))?;
+
+        entries.push(SkillPlanEntry { skill_name, level });
+    }
+
+    Ok(entries)
+}
+
+/// Reads the value of a double-quoted attribute from an XML start tag.
+fn xml_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!(" {}=\"", name);
+    let start = tag.find(&pattern)? + pattern.len();
+    let length = tag[start..].find('"')?;
+
+    Some(&tag[start..start + length])
+}
+
+/// Replaces the predefined XML entities in an attribute value.
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Parses a skill level written as a number or roman numeral.
+fn parse_level(level: &str) -> Option<i32> {
+    let level = level.trim();
+
+    let level = match ROMAN_LEVELS.iter().position(|roman| *roman == level) {
+        Some(index) => index as i32 + 1,
+        None => level.parse().ok()?,
+    };
+
+    (1..=5).contains(&level).then_some(level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests parsing a plain text plan with numeric and roman numeral levels.
+    ///
+    /// Expected: Ok with entries in order
+    #[test]
+    fn parses_text_plan() {
+        let input = "Caldari Frigate IV\n\nMissile Launcher Operation 3\n";
+
+        let entries = parse_skill_plan(input).unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                SkillPlanEntry {
+                    skill_name: "Caldari Frigate".to_string(),
+                    level: 4,
+                },
+                SkillPlanEntry {
+                    skill_name: "Missile Launcher Operation".to_string(),
+                    level: 3,
+                },
+            ]
+        );
+    }
+
+    /// Tests parsing an EVEMon XML plan.
+    ///
+    /// Expected: Ok with entries from the `skill` and `level` attributes
+    #[test]
+    fn parses_evemon_xml_plan() {
+        let input = r#"<?xml version="1.0"?>
+<plan xmlns:xsd="http://www.w3.org/2001/XMLSchema" name="Frigates" revision="4">
+  <entry skillID="3330" skill="Caldari Frigate" level="3" priority="3" type="Planned">
+    <notes>Caldari Frigate</notes>
+  </entry>
+  <entry skillID="3319" skill="Missile Launcher Operation" level="5" priority="3" type="Prerequisite" />
+</plan>"#;
+
+        let entries = parse_skill_plan(input).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].skill_name, "Caldari Frigate");
+        assert_eq!(entries[1].level, 5);
+    }
+
+    /// Tests that formatted plans parse back to the same entries.
+    ///
+    /// Expected: Ok with the original entries
+    #[test]
+    fn formats_parseable_text() {
+        let entries = parse_skill_plan("Caldari Frigate 5\nNavigation 1").unwrap();
+
+        let text = format_skill_plan(&entries);
+
+        assert_eq!(text, "Caldari Frigate V\nNavigation I");
+        assert_eq!(parse_skill_plan(&text).unwrap(), entries);
+    }
+
+    /// Tests error handling for empty input.
+    ///
+    /// Expected: Err(SkillPlanParseError::Empty)
+    #[test]
+    fn fails_for_empty_input() {
+        assert_eq!(parse_skill_plan("  \n\n"), Err(SkillPlanParseError::Empty));
+        assert_eq!(
+            parse_skill_plan("<plan name=\"Empty\"></plan>"),
+            Err(SkillPlanParseError::Empty)
+        );
+    }
+
+    /// Tests error handling for levels outside 1 to 5.
+    ///
+    /// Expected: Err(SkillPlanParseError::InvalidLine) referencing line 2
+    #[test]
+    fn fails_for_invalid_level() {
+        assert!(matches!(
+            parse_skill_plan("Navigation 1\nNavigation 6"),
+            Err(SkillPlanParseError::InvalidLine { line: 2, .. })
+        ));
+        assert!(matches!(
+            parse_skill_plan("<plan><entry skill=\"Navigation\" level=\"0\" /></plan>"),
+            Err(SkillPlanParseError::InvalidEntry { entry: 1, .. })
+        ));
+    }
+}
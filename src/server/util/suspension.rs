@@ -0,0 +1,90 @@
+//! Rejection of requests from suspended users.
+//!
+//! This module provides the middleware enforcing user bans. API requests from a logged in user
+//! with an active ban are answered with `403 Forbidden`, telling the user why they were
+//! suspended and until when. Suspended users can still log out.
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tower_sessions::Session;
+
+use crate::server::{
+    model::{app::AppState, session::user::SessionUserId},
+    service::ban::BanService,
+};
+
+/// Paths of API routes suspended users can still use.
+const UNRESTRICTED_ROUTES: &[&str] = &["/api/auth/logout"];
+
+/// Returns whether requests to a path are rejected for suspended users.
+///
+/// # Arguments
+/// - `path` - Request path
+///
+/// # Returns
+/// - `true` - Path is an API route
+/// - `false` - Path isn't an API route or suspended users can still use it
+pub fn restricts_suspended_users(path: &str) -> bool {
+    path.starts_with("/api/") && !UNRESTRICTED_ROUTES.contains(&path)
+}
+
+/// Middleware rejecting API requests from users suspended by an active ban.
+///
+/// Requests without a logged in user are passed through unchecked.
+///
+/// # Arguments
+/// - `state` - Application state containing the database connection
+/// - `session` - User's session containing their user ID
+/// - `request` - Incoming request
+/// - `next` - Remaining middleware and handler
+///
+/// # Returns
+/// - `Response` - Response from the remaining middleware and handler, or `403 Forbidden` if
+///   the user is suspended
+pub async fn reject_suspended_users(
+    State(state): State<AppState>,
+    session: Session,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !restricts_suspended_users(request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    let user_id = match SessionUserId::get(&session).await {
+        Ok(Some(user_id)) => user_id,
+        Ok(None) => return next.run(request).await,
+        Err(err) => return err.into_response(),
+    };
+
+    match BanService::new(&state.db)
+        .ensure_not_suspended(user_id)
+        .await
+    {
+        Ok(()) => next.run(request).await,
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod restricts_suspended_users {
+        use super::*;
+
+        /// Tests that API routes are restricted except logging out.
+        ///
+        /// Expected: true for API routes, false for logout and non-API routes
+        #[test]
+        fn restricts_api_routes_except_logout() {
+            assert!(restricts_suspended_users("/api/auth/user"));
+            assert!(restricts_suspended_users("/api/admin/users/1/bans"));
+            assert!(!restricts_suspended_users("/api/auth/logout"));
+            assert!(!restricts_suspended_users("/login"));
+        }
+    }
+}
@@ -0,0 +1,530 @@
+//! Web Push payload encryption and VAPID authentication.
+//!
+//! This module implements the parts of the Web Push protocol needed to deliver notifications
+//! through browser push services: encrypting payloads with the `aes128gcm` content encoding
+//! (RFC 8291) so only the subscribed browser can read them, and signing VAPID tokens that
+//! identify this server to the push service (RFC 8292).
+//!
+//! # VAPID Key
+//!
+//! The VAPID key is a P-256 private key configured as the base64url-encoded 32-byte scalar in
+//! `VAPID_PRIVATE_KEY`. Browsers receive the matching public key when subscribing and push
+//! services reject messages signed with any other key, so replacing the key invalidates every
+//! existing subscription.
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, OsRng},
+    Aes128Gcm, Nonce,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use hkdf::Hkdf;
+use p256::{
+    ecdh::EphemeralSecret,
+    ecdsa::{signature::Signer, Signature, SigningKey},
+    elliptic_curve::sec1::ToEncodedPoint,
+    PublicKey, SecretKey,
+};
+use sha2::Sha256;
+use thiserror::Error;
+
+/// Record size advertised in the `aes128gcm` header.
+///
+/// Payloads are always sent as a single record, so this only needs to be larger than the
+/// encrypted payload.
+const RECORD_SIZE: u32 = 4096;
+
+/// Length in bytes of the `aes128gcm` header: salt, record size, key ID length, and key ID.
+const HEADER_LENGTH: usize = 16 + 4 + 1 + PUBLIC_KEY_LENGTH;
+
+/// Length in bytes of an uncompressed P-256 public key.
+const PUBLIC_KEY_LENGTH: usize = 65;
+
+/// Length in bytes of a browser's authentication secret.
+const AUTH_SECRET_LENGTH: usize = 16;
+
+/// Length in bytes of the AES-GCM authentication tag.
+const TAG_LENGTH: usize = 16;
+
+/// Domain suffixes of hosts that only resolve within private networks.
+///
+/// `localhost` itself is a single-label name and rejected as such.
+const PRIVATE_DOMAIN_SUFFIXES: &[&str] = &[".localhost", ".local", ".internal", ".home.arpa"];
+
+/// Maximum length in bytes of a plaintext payload.
+///
+/// Push services are only required to accept 4096-byte message bodies, which hold the header,
+/// the payload, a 1-byte padding delimiter, and the authentication tag.
+pub const MAX_PAYLOAD_LENGTH: usize = 4096 - HEADER_LENGTH - 1 - TAG_LENGTH;
+
+/// Error returned when configuring the VAPID key or encrypting push messages.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum WebPushError {
+    /// The `VAPID_PRIVATE_KEY` value could not be parsed.
+    #[error("Invalid VAPID private key: {0}")]
+    InvalidVapidKey(String),
+
+    /// A subscription endpoint or key provided by the browser could not be parsed.
+    #[error("Invalid push subscription: {0}")]
+    InvalidSubscription(String),
+
+    /// Payload exceeded `MAX_PAYLOAD_LENGTH` bytes.
+    #[error("Push payload of {0} bytes is too large")]
+    PayloadTooLarge(usize),
+
+    /// Encryption failed.
+    #[error("Failed to encrypt push payload")]
+    Encryption,
+}
+
+/// VAPID key identifying this server to push services.
+#[derive(Clone)]
+pub struct VapidKey {
+    signing_key: SigningKey,
+    /// Uncompressed P-256 public key.
+    public_key: Vec<u8>,
+}
+
+/// Only prints the public key so the private key never ends up in logs.
+impl std::fmt::Debug for VapidKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VapidKey")
+            .field("public_key", &self.public_key_base64())
+            .finish_non_exhaustive()
+    }
+}
+
+impl VapidKey {
+    /// Parses a VAPID key from a base64url-encoded P-256 private key.
+    ///
+    /// # Arguments
+    /// - `private_key` - Base64url-encoded 32-byte private key scalar, padding is optional
+    ///
+    /// # Returns
+    /// - `Ok(VapidKey)` - Parsed key
+    /// - `Err(WebPushError::InvalidVapidKey)` - Value is not base64url or not a valid P-256 key
+    pub fn from_base64(private_key: &str) -> Result<Self, WebPushError> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(private_key.trim().trim_end_matches('='))
+            .map_err(|e| WebPushError::InvalidVapidKey(format!("invalid base64url: {}", e)))?;
+        if bytes.len() != 32 {
+            return Err(WebPushError::InvalidVapidKey(format!(
+                "expected 32 bytes, got {}",
+                bytes.len()
+            )));
+        }
+
+        let secret_key = SecretKey::from_slice(&bytes).map_err(|_| {
+            WebPushError::InvalidVapidKey("not a valid P-256 private key".to_string())
+        })?;
+
+        Ok(Self {
+            public_key: secret_key
+                .public_key()
+                .to_encoded_point(false)
+                .as_bytes()
+                .to_vec(),
+            signing_key: SigningKey::from(&secret_key),
+        })
+    }
+
+    /// Returns the public key in the base64url format browsers expect as
+    /// `applicationServerKey` when subscribing.
+    pub fn public_key_base64(&self) -> String {
+        URL_SAFE_NO_PAD.encode(&self.public_key)
+    }
+
+    /// Builds the `Authorization` header value for a push message.
+    ///
+    /// Signs a VAPID token whose audience is the origin of the push service endpoint.
+    ///
+    /// # Arguments
+    /// - `endpoint` - Push service URL the message is sent to
+    /// - `subject` - Contact URI for the push service operator, e.g. `mailto:admin@example.com`
+    /// - `expires_at` - Expiry of the token, at most 24 hours in the future
+    ///
+    /// # Returns
+    /// - `Ok(String)` - Header value in the form `vapid t=<token>, k=<public key>`
+    /// - `Err(WebPushError::InvalidSubscription)` - Endpoint is not a valid URL
+    pub fn authorization(
+        &self,
+        endpoint: &str,
+        subject: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<String, WebPushError> {
+        let audience = endpoint_origin(endpoint)?;
+
+        let header = URL_SAFE_NO_PAD.encode(r#"{"typ":"JWT","alg":"ES256"}"#);
+        let claims = URL_SAFE_NO_PAD.encode(
+            serde_json::json!({
+                "aud": audience,
+                "exp": expires_at.timestamp(),
+                "sub": subject,
+            })
+            .to_string(),
+        );
+        let unsigned_token = format!("{}.{}", header, claims);
+        let signature: Signature = self.signing_key.sign(unsigned_token.as_bytes());
+
+        Ok(format!(
+            "vapid t={}.{}, k={}",
+            unsigned_token,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes()),
+            self.public_key_base64()
+        ))
+    }
+}
+
+/// Validates a push service endpoint, requiring an HTTPS URL on a public domain name.
+///
+/// Endpoints are provided by browsers but posted to by the server, so hosts that would let a
+/// user make the server reach internal services are rejected: IP literals, which browser push
+/// services never use, `localhost`, single-label names, and names under the `local` and
+/// `internal` suffixes reserved for private networks.
+///
+/// # Arguments
+/// - `endpoint` - Push service URL provided by the browser
+///
+/// # Returns
+/// - `Ok(String)` - Origin of the endpoint, used as the VAPID token audience
+/// - `Err(WebPushError::InvalidSubscription)` - Endpoint is not an HTTPS URL or its host is
+///   not a public domain name
+pub fn endpoint_origin(endpoint: &str) -> Result<String, WebPushError> {
+    let url = reqwest::Url::parse(endpoint)
+        .map_err(|e| WebPushError::InvalidSubscription(format!("invalid endpoint: {}", e)))?;
+    if url.scheme() != "https" || url.host_str().is_none() {
+        return Err(WebPushError::InvalidSubscription(
+            "endpoint must be an https URL".to_string(),
+        ));
+    }
+
+    // `domain` is `None` for IP literals
+    let is_public = url.domain().is_some_and(|domain| {
+        let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+
+        domain.contains('.')
+            && !PRIVATE_DOMAIN_SUFFIXES
+                .iter()
+                .any(|suffix| domain.ends_with(suffix))
+    });
+    if !is_public {
+        return Err(WebPushError::InvalidSubscription(
+            "endpoint must be on a public domain name".to_string(),
+        ));
+    }
+
+    Ok(url.origin().ascii_serialization())
+}
+
+/// Validates the keys a browser provided for a subscription.
+///
+/// # Arguments
+/// - `p256dh` - Base64url-encoded P-256 public key of the browser
+/// - `auth` - Base64url-encoded authentication secret of the browser
+///
+/// # Returns
+/// - `Ok(())` - Keys can be used to encrypt messages
+/// - `Err(WebPushError::InvalidSubscription)` - A key is malformed
+pub fn validate_subscription_keys(p256dh: &str, auth: &str) -> Result<(), WebPushError> {
+    decode_subscription_keys(p256dh, auth).map(|_| ())
+}
+
+/// Encrypts a payload for a subscription using the `aes128gcm` content encoding.
+///
+/// A new ephemeral key pair and salt are generated for every message, so encrypting the same
+/// payload twice yields different bodies.
+///
+/// # Arguments
+/// - `p256dh` - Base64url-encoded P-256 public key of the browser
+/// - `auth` - Base64url-encoded authentication secret of the browser
+/// - `payload` - Plaintext payload, at most `MAX_PAYLOAD_LENGTH` bytes
+///
+/// # Returns
+/// - `Ok(Vec<u8>)` - Message body to send with `Content-Encoding: aes128gcm`
+/// - `Err(WebPushError::InvalidSubscription)` - A subscription key is malformed
+/// - `Err(WebPushError::PayloadTooLarge)` - Payload exceeds `MAX_PAYLOAD_LENGTH`
+/// - `Err(WebPushError::Encryption)` - Encryption failed
+pub fn encrypt_payload(p256dh: &str, auth: &str, payload: &[u8]) -> Result<Vec<u8>, WebPushError> {
+    if payload.len() > MAX_PAYLOAD_LENGTH {
+        return Err(WebPushError::PayloadTooLarge(payload.len()));
+    }
+
+    let (ua_public_key, auth_secret) = decode_subscription_keys(p256dh, auth)?;
+    let ua_public = ua_public_key.to_encoded_point(false);
+
+    let as_secret = EphemeralSecret::random(&mut OsRng);
+    let as_public = as_secret.public_key().to_encoded_point(false);
+    let ecdh_secret = as_secret.diffie_hellman(&ua_public_key);
+    let salt: [u8; 16] = rand::random();
+
+    let (content_key, nonce) = derive_content_key(
+        ecdh_secret.raw_secret_bytes().as_slice(),
+        &auth_secret,
+        &salt,
+        ua_public.as_bytes(),
+        as_public.as_bytes(),
+    )?;
+
+    // A single record is terminated by the 0x02 padding delimiter
+    let mut record = payload.to_vec();
+    record.push(0x02);
+
+    let ciphertext = Aes128Gcm::new_from_slice(&content_key)
+        .map_err(|_| WebPushError::Encryption)?
+        .encrypt(Nonce::from_slice(&nonce), record.as_slice())
+        .map_err(|_| WebPushError::Encryption)?;
+
+    let mut body = Vec::with_capacity(HEADER_LENGTH + ciphertext.len());
+    body.extend_from_slice(&salt);
+    body.extend_from_slice(&RECORD_SIZE.to_be_bytes());
+    body.push(PUBLIC_KEY_LENGTH as u8);
+    body.extend_from_slice(as_public.as_bytes());
+    body.extend_from_slice(&ciphertext);
+
+    Ok(body)
+}
+
+/// Decodes the public key and authentication secret of a subscription.
+fn decode_subscription_keys(
+    p256dh: &str,
+    auth: &str,
+) -> Result<(PublicKey, Vec<u8>), WebPushError> {
+    let invalid = |reason: &str| WebPushError::InvalidSubscription(reason.to_string());
+
+    let public_key = URL_SAFE_NO_PAD
+        .decode(p256dh.trim_end_matches('='))
+        .ok()
+        .filter(|key| key.len() == PUBLIC_KEY_LENGTH)
+        .and_then(|key| PublicKey::from_sec1_bytes(&key).ok())
+        .ok_or_else(|| invalid("p256dh must be an uncompressed P-256 public key"))?;
+    let auth_secret = URL_SAFE_NO_PAD
+        .decode(auth.trim_end_matches('='))
+        .ok()
+        .filter(|secret| secret.len() == AUTH_SECRET_LENGTH)
+        .ok_or_else(|| invalid("auth must be a 16-byte secret"))?;
+
+    Ok((public_key, auth_secret))
+}
+
+/// Derives the content encryption key and nonce as described in RFC 8291 section 3.4.
+///
+/// # Arguments
+/// - `ecdh_secret` - Shared secret of the ephemeral server key and the browser key
+/// - `auth_secret` - Authentication secret of the browser
+/// - `salt` - Random salt sent in the message header
+/// - `ua_public` - Uncompressed public key of the browser
+/// - `as_public` - Uncompressed ephemeral public key of the server
+///
+/// # Returns
+/// - `Ok(([u8; 16], [u8; 12]))` - Content encryption key and nonce
+/// - `Err(WebPushError::Encryption)` - Key derivation failed
+fn derive_content_key(
+    ecdh_secret: &[u8],
+    auth_secret: &[u8],
+    salt: &[u8],
+    ua_public: &[u8],
+    as_public: &[u8],
+) -> Result<([u8; 16], [u8; 12]), WebPushError> {
+    let mut key_info = b"WebPush: info\0".to_vec();
+    key_info.extend_from_slice(ua_public);
+    key_info.extend_from_slice(as_public);
+
+    let mut ikm = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(auth_secret), ecdh_secret)
+        .expand(&key_info, &mut ikm)
+        .map_err(|_| WebPushError::Encryption)?;
+
+    let prk = Hkdf::<Sha256>::new(Some(salt), &ikm);
+    let mut content_key = [0u8; 16];
+    prk.expand(b"Content-Encoding: aes128gcm\0", &mut content_key)
+        .map_err(|_| WebPushError::Encryption)?;
+    let mut nonce = [0u8; 12];
+    prk.expand(b"Content-Encoding: nonce\0", &mut nonce)
+        .map_err(|_| WebPushError::Encryption)?;
+
+    Ok((content_key, nonce))
+}
+
+#[cfg(test)]
+mod tests {
+    use p256::ecdsa::{signature::Verifier, VerifyingKey};
+
+    use super::*;
+
+    /// Creates a base64url-encoded random P-256 private key.
+    fn random_private_key() -> String {
+        URL_SAFE_NO_PAD.encode(SecretKey::random(&mut OsRng).to_bytes())
+    }
+
+    /// Tests parsing a VAPID key.
+    ///
+    /// Expected: Ok with a 65-byte uncompressed public key
+    #[test]
+    fn parses_vapid_key() {
+        let key = VapidKey::from_base64(&random_private_key()).unwrap();
+
+        let public_key = URL_SAFE_NO_PAD.decode(key.public_key_base64()).unwrap();
+
+        assert_eq!(public_key.len(), PUBLIC_KEY_LENGTH);
+        assert_eq!(public_key[0], 0x04);
+    }
+
+    /// Tests error handling for malformed VAPID keys.
+    ///
+    /// Expected: Err(WebPushError::InvalidVapidKey)
+    #[test]
+    fn fails_for_invalid_vapid_key() {
+        assert!(matches!(
+            VapidKey::from_base64("not base64!"),
+            Err(WebPushError::InvalidVapidKey(_))
+        ));
+        assert!(matches!(
+            VapidKey::from_base64(&URL_SAFE_NO_PAD.encode([1u8; 16])),
+            Err(WebPushError::InvalidVapidKey(_))
+        ));
+    }
+
+    /// Tests that the VAPID token is signed by the key and scoped to the endpoint origin.
+    ///
+    /// Expected: Ok with a verifiable token whose audience is the endpoint origin
+    #[test]
+    fn signs_vapid_token() {
+        let key = VapidKey::from_base64(&random_private_key()).unwrap();
+
+        let header = key
+            .authorization(
+                "https://push.example.com/send/abc",
+                "mailto:admin@example.com",
+                Utc::now(),
+            )
+            .unwrap();
+
+        let (token, public_key) = header
+            .strip_prefix("vapid t=")
+            .and_then(|value| value.split_once(", k="))
+            .unwrap();
+        assert_eq!(public_key, key.public_key_base64());
+
+        let (unsigned_token, signature) = token.rsplit_once('.').unwrap();
+        let claims: serde_json::Value = serde_json::from_slice(
+            &URL_SAFE_NO_PAD
+                .decode(unsigned_token.split_once('.').unwrap().1)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(claims["aud"], "https://push.example.com");
+        assert_eq!(claims["sub"], "mailto:admin@example.com");
+
+        let verifying_key =
+            VerifyingKey::from_sec1_bytes(&URL_SAFE_NO_PAD.decode(public_key).unwrap()).unwrap();
+        let signature = Signature::from_slice(&URL_SAFE_NO_PAD.decode(signature).unwrap()).unwrap();
+        assert!(verifying_key
+            .verify(unsigned_token.as_bytes(), &signature)
+            .is_ok());
+    }
+
+    /// Tests that endpoints must be HTTPS URLs.
+    ///
+    /// Expected: Err(WebPushError::InvalidSubscription)
+    #[test]
+    fn fails_for_insecure_endpoint() {
+        assert!(endpoint_origin("http://push.example.com/send/abc").is_err());
+        assert!(endpoint_origin("not a url").is_err());
+    }
+
+    /// Tests that endpoints on IP literals and private host names are rejected.
+    ///
+    /// Expected: Err(WebPushError::InvalidSubscription) for every internal host
+    #[test]
+    fn fails_for_internal_host() {
+        for endpoint in [
+            "https://127.0.0.1/send/abc",
+            "https://10.0.0.5:8443/send/abc",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/send/abc",
+            "https://[fe80::1]/send/abc",
+            "https://localhost/send/abc",
+            "https://LOCALHOST./send/abc",
+            "https://redis:6379/send/abc",
+            "https://push.localhost/send/abc",
+            "https://metadata.google.internal/send/abc",
+            "https://printer.local/send/abc",
+        ] {
+            assert!(
+                endpoint_origin(endpoint).is_err(),
+                "{} should be rejected",
+                endpoint
+            );
+        }
+
+        assert_eq!(
+            endpoint_origin("https://fcm.googleapis.com/fcm/send/abc").unwrap(),
+            "https://fcm.googleapis.com"
+        );
+    }
+
+    /// Tests that the browser can decrypt an encrypted payload.
+    ///
+    /// Derives the content key from the browser side of the key exchange using the header of
+    /// the encrypted message.
+    ///
+    /// Expected: Ok with the original payload followed by the padding delimiter
+    #[test]
+    fn encrypts_payload_for_browser() {
+        let ua_secret = SecretKey::random(&mut OsRng);
+        let ua_public = ua_secret.public_key().to_encoded_point(false);
+        let auth_secret: [u8; 16] = rand::random();
+
+        let body = encrypt_payload(
+            &URL_SAFE_NO_PAD.encode(ua_public.as_bytes()),
+            &URL_SAFE_NO_PAD.encode(auth_secret),
+            b"Structure under attack",
+        )
+        .unwrap();
+
+        let (header, ciphertext) = body.split_at(HEADER_LENGTH);
+        assert_eq!(&header[16..20], &RECORD_SIZE.to_be_bytes());
+        assert_eq!(header[20] as usize, PUBLIC_KEY_LENGTH);
+
+        let as_public = &header[21..];
+        let ecdh_secret = p256::ecdh::diffie_hellman(
+            ua_secret.to_nonzero_scalar(),
+            PublicKey::from_sec1_bytes(as_public).unwrap().as_affine(),
+        );
+        let (content_key, nonce) = derive_content_key(
+            ecdh_secret.raw_secret_bytes().as_slice(),
+            &auth_secret,
+            &header[..16],
+            ua_public.as_bytes(),
+            as_public,
+        )
+        .unwrap();
+        let record = Aes128Gcm::new_from_slice(&content_key)
+            .unwrap()
+            .decrypt(Nonce::from_slice(&nonce), ciphertext)
+            .unwrap();
+
+        assert_eq!(record, b"Structure under attack\x02");
+    }
+
+    /// Tests error handling for payloads larger than a single record.
+    ///
+    /// Expected: Err(WebPushError::PayloadTooLarge)
+    #[test]
+    fn fails_for_large_payload() {
+        let ua_public = SecretKey::random(&mut OsRng)
+            .public_key()
+            .to_encoded_point(false);
+
+        let result = encrypt_payload(
+            &URL_SAFE_NO_PAD.encode(ua_public.as_bytes()),
+            &URL_SAFE_NO_PAD.encode([0u8; 16]),
+            &[0u8; MAX_PAYLOAD_LENGTH + 1],
+        );
+
+        assert_eq!(
+            result,
+            Err(WebPushError::PayloadTooLarge(MAX_PAYLOAD_LENGTH + 1))
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::server::{error::AppError, service::character_skill::CharacterSkillService};
+
+impl WorkerJobHandler {
+    /// Refreshes the skill snapshot of a character whose token grants the skills scope.
+    ///
+    /// # Arguments
+    /// - `character_id` - EVE Online ID of the character
+    ///
+    /// # Returns
+    /// - `Ok(())` - Skill snapshot was replaced with the one fetched from ESI
+    /// - `Err(AppError)` - Character has no stored token, its token can't be decrypted or
+    ///   refreshed, or the ESI request or database operation failed
+    pub async fn update_character_skills(&self, character_id: i64) -> Result<(), AppError> {
+        tracing::debug!("Processing skill update for character_id: {}", character_id);
+
+        let skill_count = CharacterSkillService::new(&self.db, &self.esi_provider, &self.cipher)
+            .update_skills(character_id)
+            .await
+            .map_err(|e| {
+                tracing::error!(
+                    "Failed to update skills of character {}: {:?}",
+                    character_id,
+                    e
+                );
+                e
+            })?;
+
+        tracing::debug!(
+            "Successfully updated skills of character {} ({} skills)",
+            character_id,
+            skill_count
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::{model::consent::ConsentCategory, server::error::AppError};
+
+impl WorkerJobHandler {
+    /// Deletes a user's stored data for a consent category.
+    ///
+    /// Runs after a user revokes consent for a category. Each category removes the data its
+    /// sync stores for the user's characters.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user who revoked consent
+    /// - `category` - Data category whose stored data should be deleted
+    ///
+    /// # Returns
+    /// - `Ok(())` - Stored data for the category was deleted
+    /// - `Err(AppError)` - Failed to delete stored data
+    pub async fn delete_consent_data(
+        &self,
+        user_id: i32,
+        category: ConsentCategory,
+    ) -> Result<(), AppError> {
+        tracing::debug!(
+            "Processing {} data deletion for user {} after consent was revoked",
+            category.as_str(),
+            user_id
+        );
+
+        match category {
+            // No sync stores data for these categories yet; deletion is added alongside the
+            // sync for each category.
+            ConsentCategory::Assets | ConsentCategory::Wallet | ConsentCategory::Location => {
+                tracing::debug!(
+                    "No stored {} data to delete for user {}",
+                    category.as_str(),
+                    user_id
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::server::{error::AppError, service::corporation_member::CorporationMemberService};
+
+impl WorkerJobHandler {
+    /// Refreshes the member list of a corporation whose director granted the membership scope.
+    ///
+    /// # Arguments
+    /// - `corporation_id` - EVE Online ID of the corporation
+    ///
+    /// # Returns
+    /// - `Ok(())` - Member list was replaced with the one fetched from ESI
+    /// - `Err(AppError)` - Corporation isn't tracked, its token can't be decrypted or refreshed,
+    ///   or the ESI request or database operation failed
+    pub async fn update_corporation_members(&self, corporation_id: i64) -> Result<(), AppError> {
+        tracing::debug!(
+            "Processing member list update for corporation_id: {}",
+            corporation_id
+        );
+
+        let member_count =
+            CorporationMemberService::new(&self.db, &self.esi_provider, &self.cipher)
+                .update_members(corporation_id)
+                .await
+                .map_err(|e| {
+                    tracing::error!(
+                        "Failed to update member list of corporation {}: {:?}",
+                        corporation_id,
+                        e
+                    );
+                    e
+                })?;
+
+        tracing::debug!(
+            "Successfully updated member list of corporation {} ({} members)",
+            corporation_id,
+            member_count
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::server::{error::AppError, service::dashboard::DashboardService};
+
+impl WorkerJobHandler {
+    /// Recomputes the precomputed admin dashboard summaries.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Summaries were refreshed
+    /// - `Err(AppError)` - Failed to compute or store the summaries
+    pub async fn refresh_dashboard_summaries(&self) -> Result<(), AppError> {
+        tracing::debug!("Processing dashboard summary refresh");
+
+        DashboardService::new(&self.db).refresh_summaries().await
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::Utc;
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::{
+    model::push::PushNotificationDto,
+    server::{
+        error::AppError,
+        model::worker::WorkerJob,
+        service::digest::{digest_summary, digest_to_markdown, DigestService},
+    },
+};
+
+impl WorkerJobHandler {
+    /// Compiles the weekly digest and queues its delivery.
+    ///
+    /// The full digest is queued for the Discord webhook, and a summary is queued as a push
+    /// notification for every user receiving the digest. Push notifications are skipped if
+    /// they are disabled.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Digest was compiled and its delivery queued
+    /// - `Err(AppError)` - Failed to read the digest figures or enqueue the delivery jobs
+    pub async fn send_weekly_digest(&self) -> Result<(), AppError> {
+        tracing::debug!("Processing weekly digest");
+
+        let queued_jobs = self.queue.len().await? as u64;
+        let dead_letter_jobs = self.queue.get_dead_letters().await?.len() as u64;
+
+        let digest_service = DigestService::new(&self.db);
+        let digest = digest_service
+            .build_weekly_digest(Utc::now().naive_utc(), queued_jobs, dead_letter_jobs)
+            .await?;
+
+        self.queue
+            .push(WorkerJob::SendDiscordMessage {
+                content: digest_to_markdown(&digest),
+            })
+            .await?;
+
+        if self.push.vapid_key.is_none() {
+            return Ok(());
+        }
+
+        let notification = PushNotificationDto {
+            title: "Weekly digest".to_string(),
+            body: digest_summary(&digest),
+            url: Some("/auth".to_string()),
+        };
+        let recipient_ids = digest_service.get_recipient_ids().await?;
+
+        for user_id in &recipient_ids {
+            self.queue
+                .push(WorkerJob::SendPushNotification {
+                    user_id: *user_id,
+                    notification: notification.clone(),
+                })
+                .await?;
+        }
+
+        tracing::debug!("Queued weekly digest for {} user(s)", recipient_ids.len());
+
+        Ok(())
+    }
+}
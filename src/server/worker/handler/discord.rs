@@ -0,0 +1,49 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::server::error::{announcement::AnnouncementError, AppError};
+
+/// Maximum number of characters Discord accepts in a message.
+const DISCORD_MESSAGE_LIMIT: usize = 2000;
+
+impl WorkerJobHandler {
+    /// Posts a message to the configured Discord webhook.
+    ///
+    /// Messages longer than Discord allows are truncated. Mentions are not parsed, so
+    /// `@everyone` in an announcement doesn't ping the channel.
+    ///
+    /// # Arguments
+    /// - `content` - Message content as Discord Markdown
+    ///
+    /// # Returns
+    /// - `Ok(())` - Message was posted, or no Discord webhook is configured
+    /// - `Err(AppError::Announcement(AnnouncementError::DiscordWebhookRejected))` - Discord
+    ///   responded with an error status
+    /// - `Err(AppError)` - Failed to reach Discord
+    pub async fn send_discord_message(&self, content: &str) -> Result<(), AppError> {
+        let Some(url) = &self.discord_webhook_url else {
+            tracing::debug!("Skipping Discord message, no Discord webhook configured");
+            return Ok(());
+        };
+
+        let content: String = content.chars().take(DISCORD_MESSAGE_LIMIT).collect();
+        let response = self
+            .http_client
+            .post(url.clone())
+            .json(&serde_json::json!({
+                "content": content,
+                "allowed_mentions": { "parse": [] },
+            }))
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(AnnouncementError::DiscordWebhookRejected(status.as_u16()).into());
+        }
+
+        tracing::debug!("Posted message to Discord webhook");
+
+        Ok(())
+    }
+}
@@ -2,12 +2,18 @@ use dioxus_logger::tracing;
 
 use super::WorkerJobHandler;
 use crate::server::{
-    error::AppError,
-    service::eve::{
-        affiliation::AffiliationService, alliance::AllianceService, character::CharacterService,
-        corporation::CorporationService, faction::FactionService,
+    data::user::summary::UserCharacterSummaryRepository,
+    error::{retry::ErrorRetryStrategy, AppError},
+    model::worker::{JobItemFailure, JobOutcome, WorkerJob},
+    service::{
+        eve::{
+            affiliation::AffiliationService, alliance::AllianceService,
+            character::CharacterService, corporation::CorporationService, faction::FactionService,
+        },
+        search::{SearchService, ALLIANCE_CATEGORY, CHARACTER_CATEGORY, CORPORATION_CATEGORY},
+        user::user_character::UserCharacterService,
     },
-    util::eve::ESI_AFFILIATION_REQUEST_LIMIT,
+    util::eve::{is_valid_character_id, ESI_AFFILIATION_REQUEST_LIMIT},
 };
 
 impl WorkerJobHandler {
@@ -46,7 +52,9 @@ impl WorkerJobHandler {
     /// Updates alliance information from ESI.
     ///
     /// Fetches alliance data from ESI and persists it to the database. If the alliance
-    /// has faction affiliations, those dependencies are resolved first.
+    /// has faction affiliations, those dependencies are resolved first. The alliance is added
+    /// to the search index and character summaries of users with characters in the alliance
+    /// are rebuilt afterwards.
     ///
     /// # Arguments
     /// - `alliance_id` - EVE Online alliance ID to update
@@ -60,7 +68,7 @@ impl WorkerJobHandler {
             alliance_id
         );
 
-        AllianceService::new(&self.db, &self.esi_provider)
+        let alliance = AllianceService::new(&self.db, &self.esi_provider)
             .update(alliance_id)
             .await
             .map_err(|e| {
@@ -74,13 +82,21 @@ impl WorkerJobHandler {
 
         tracing::debug!("Successfully updated info for alliance {}", alliance_id);
 
-        Ok(())
+        SearchService::new(&self.db, &self.search)
+            .index_entity(ALLIANCE_CATEGORY, alliance.alliance_id, alliance.name)
+            .await;
+
+        UserCharacterService::new(&self.db)
+            .refresh_summaries_for_alliance(alliance_id)
+            .await
     }
 
     /// Updates corporation information from ESI.
     ///
     /// Fetches corporation data from ESI and persists it to the database. If the corporation
-    /// has alliance or faction affiliations, those dependencies are resolved first.
+    /// has alliance or faction affiliations, those dependencies are resolved first. The
+    /// corporation is added to the search index and character summaries of users with
+    /// characters in the corporation are rebuilt afterwards.
     ///
     /// # Arguments
     /// - `corporation_id` - EVE Online corporation ID to update
@@ -94,7 +110,7 @@ impl WorkerJobHandler {
             corporation_id
         );
 
-        CorporationService::new(&self.db, &self.esi_provider)
+        let corporation = CorporationService::new(&self.db, &self.esi_provider)
             .update(corporation_id)
             .await
             .map_err(|e| {
@@ -111,13 +127,25 @@ impl WorkerJobHandler {
             corporation_id
         );
 
-        Ok(())
+        SearchService::new(&self.db, &self.search)
+            .index_entity(
+                CORPORATION_CATEGORY,
+                corporation.corporation_id,
+                corporation.name,
+            )
+            .await;
+
+        UserCharacterService::new(&self.db)
+            .refresh_summaries_for_corporation(corporation_id)
+            .await
     }
 
     /// Updates character information from ESI.
     ///
     /// Fetches character data from ESI and persists it to the database. If the character
-    /// has corporation or faction affiliations, those dependencies are resolved first.
+    /// has corporation or faction affiliations, those dependencies are resolved first. The
+    /// character is added to the search index and the character summary of the user owning
+    /// the character is rebuilt afterwards.
     ///
     /// # Arguments
     /// - `character_id` - EVE Online character ID to update
@@ -131,7 +159,7 @@ impl WorkerJobHandler {
             character_id
         );
 
-        CharacterService::new(&self.db, &self.esi_provider)
+        let character = CharacterService::new(&self.db, &self.esi_provider)
             .update(character_id)
             .await
             .map_err(|e| {
@@ -145,48 +173,183 @@ impl WorkerJobHandler {
 
         tracing::debug!("Successfully updated info for character {}", character_id);
 
-        Ok(())
+        SearchService::new(&self.db, &self.search)
+            .index_entity(CHARACTER_CATEGORY, character.character_id, character.name)
+            .await;
+
+        UserCharacterService::new(&self.db)
+            .refresh_summaries_for_characters(vec![character_id])
+            .await
     }
 
     /// Updates affiliations for multiple characters in bulk.
     ///
     /// Fetches character affiliation data from ESI and updates both character-to-corporation
-    /// and corporation-to-alliance relationships. Validates the character ID list and
-    /// truncates to ESI's limit of 1000 characters if necessary.
+    /// and corporation-to-alliance relationships. Character summaries of users owning the
+    /// updated characters are rebuilt afterwards, and a `CheckGroupEligibility` job is queued
+    /// for those users.
+    ///
+    /// ESI rejects the whole affiliation request if a single character can't be resolved, so
+    /// a batch rejected with a client error is split in halves until the rejected characters
+    /// are isolated, and the rest of the batch is still updated. Every error response of the
+    /// split requests is recorded against the ESI error budget, and once ESI is error limited
+    /// or the budget runs low the characters not yet sent are reported as retryable failed
+    /// items. Invalid character IDs and IDs beyond ESI's limit of 1000 characters are reported
+    /// as failed items as well, the latter as retryable so they are queued in a batch of their
+    /// own.
     ///
     /// # Arguments
     /// - `character_ids` - List of EVE Online character IDs to update affiliations for
     ///
     /// # Returns
-    /// - `Ok(())` - Affiliations updated successfully
-    /// - `Err(AppError)` - Failed to fetch or persist affiliation data
-    pub async fn update_affiliations(&self, character_ids: Vec<i64>) -> Result<(), AppError> {
+    /// - `Ok(JobOutcome)` - Affiliations updated, with the characters that failed
+    /// - `Err(AppError)` - The whole batch failed to fetch or persist affiliation data, or
+    ///   rebuilding character summaries or queueing the eligibility check failed
+    pub async fn update_affiliations(
+        &self,
+        mut character_ids: Vec<i64>,
+    ) -> Result<JobOutcome, AppError> {
         let count = character_ids.len();
         tracing::debug!("Processing affiliations update for {} characters", count);
 
         if character_ids.is_empty() {
             tracing::debug!("No characters to update affiliations for");
-            return Ok(());
+            return Ok(JobOutcome::default());
         }
 
+        let mut outcome = JobOutcome::default();
         if character_ids.len() > ESI_AFFILIATION_REQUEST_LIMIT {
             tracing::warn!(
-                "Update affiliation job contains {} character IDs, exceeding ESI affiliation request limit of {}; truncating to limit",
+                "Update affiliation job contains {} character IDs, exceeding ESI affiliation request limit of {}; retrying the rest separately",
                 character_ids.len(),
                 ESI_AFFILIATION_REQUEST_LIMIT
             );
+            outcome.failed.extend(
+                character_ids
+                    .split_off(ESI_AFFILIATION_REQUEST_LIMIT)
+                    .into_iter()
+                    .map(|id| JobItemFailure {
+                        id,
+                        error: "Exceeds the ESI affiliation request limit".to_string(),
+                        retryable: true,
+                    }),
+            );
         }
 
-        AffiliationService::new(&self.db, &self.esi_provider)
-            .update_affiliations(character_ids)
-            .await
-            .map_err(|e| {
+        // An invalid ID fails the entire affiliation request, so fail only its item instead
+        let (character_ids, invalid_ids): (Vec<i64>, Vec<i64>) = character_ids
+            .into_iter()
+            .partition(|&id| is_valid_character_id(id));
+        outcome
+            .failed
+            .extend(invalid_ids.into_iter().map(|id| JobItemFailure {
+                id,
+                error: "Invalid character ID".to_string(),
+                retryable: false,
+            }));
+
+        let batch_size = character_ids.len();
+        let affiliation_service = AffiliationService::new(&self.db, &self.esi_provider);
+        let mut updated = Vec::new();
+        let mut batches = vec![character_ids];
+        while let Some(batch) = batches.pop() {
+            if batch.is_empty() {
+                continue;
+            }
+
+            // Stop sending the halves of split batches once ESI is error limited or the error
+            // budget runs low, leaving the unsent characters to be retried
+            if batch.len() < batch_size && self.esi_rate_limiter.pause_remaining().is_some() {
+                let unsent: Vec<i64> = batch
+                    .into_iter()
+                    .chain(batches.drain(..).flatten())
+                    .collect();
+                tracing::warn!(
+                    "Paused updating affiliations by the ESI error limit, retrying {} characters",
+                    unsent.len()
+                );
+                outcome
+                    .failed
+                    .extend(unsent.into_iter().map(|id| JobItemFailure {
+                        id,
+                        error: "Paused by the ESI error limit".to_string(),
+                        retryable: true,
+                    }));
+                break;
+            }
+
+            let Err(e) = affiliation_service.update_affiliations(batch.clone()).await else {
+                updated.extend(batch);
+                continue;
+            };
+
+            // The whole batch failed, retry or fail the job as a whole; the worker pool records
+            // the error against the ESI error budget
+            let split = is_esi_client_error(&e) && batch.len() > 1;
+            if batch.len() == batch_size && !split {
                 tracing::error!("Failed to update affiliations due to error: {:?}", e);
+                return Err(e);
+            }
+
+            // Errors the job recovers from never reach the worker pool, so record them here
+            self.esi_rate_limiter.record_error(&e);
+
+            // Split batches ESI rejected to isolate the characters causing the rejection
+            if split {
+                let (first, second) = batch.split_at(batch.len() / 2);
+                batches.push(second.to_vec());
+                batches.push(first.to_vec());
+                continue;
+            }
+
+            tracing::warn!(
+                "Failed to update affiliations for {} characters due to error: {:?}",
+                batch.len(),
                 e
-            })?;
+            );
+            let retryable = !matches!(e.to_retry_strategy(), ErrorRetryStrategy::Fail);
+            outcome
+                .failed
+                .extend(batch.into_iter().map(|id| JobItemFailure {
+                    id,
+                    error: e.to_string(),
+                    retryable,
+                }));
+        }
 
-        tracing::debug!("Successfully updated affiliations for {} characters", count);
+        outcome.succeeded = updated.len();
+        tracing::debug!(
+            "Updated affiliations for {} of {} characters",
+            outcome.succeeded,
+            count
+        );
 
-        Ok(())
+        if !updated.is_empty() {
+            let user_ids = UserCharacterSummaryRepository::new(&self.db)
+                .get_user_ids_by_character_ids(updated.clone())
+                .await?;
+            UserCharacterService::new(&self.db)
+                .refresh_summaries_for_characters(updated)
+                .await?;
+
+            // Affiliations decide which groups users qualify for
+            if !user_ids.is_empty() {
+                self.queue
+                    .push(WorkerJob::CheckGroupEligibility { user_ids })
+                    .await?;
+            }
+        }
+
+        Ok(outcome)
     }
 }
+
+/// Whether an error is ESI rejecting a request with a 4xx client error other than error or
+/// rate limiting.
+fn is_esi_client_error(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::Esi(eve_esi::Error::EsiError(esi_error))
+            if (400..500).contains(&esi_error.status) && !matches!(esi_error.status, 420 | 429)
+    )
+}
@@ -0,0 +1,65 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::{
+    model::push::PushNotificationDto,
+    server::{
+        error::{export::ExportError, AppError},
+        model::worker::WorkerJob,
+        service::export::ExportService,
+    },
+};
+
+impl WorkerJobHandler {
+    /// Stores an archive of everything Bifrost stores about a user and notifies them.
+    ///
+    /// The archive is stored in object storage and a push notification linking to its
+    /// download is queued for the user. The notification is skipped if push notifications
+    /// are disabled.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user whose data to export
+    ///
+    /// # Returns
+    /// - `Ok(())` - Archive was stored and the notification queued
+    /// - `Err(AppError::Export(ExportError::ObjectStorageDisabled))` - Object storage is not
+    ///   configured
+    /// - `Err(AppError)` - Failed to assemble or upload the archive, or to enqueue the
+    ///   notification
+    pub async fn export_user_data(&self, user_id: i32) -> Result<(), AppError> {
+        tracing::debug!("Processing data export for user {}", user_id);
+
+        let storage = self
+            .object_storage
+            .as_ref()
+            .ok_or(ExportError::ObjectStorageDisabled)?;
+
+        let export = ExportService::new(&self.db)
+            .store_user_archive(storage, user_id)
+            .await?;
+
+        if self.push.vapid_key.is_none() {
+            tracing::debug!(
+                "Stored data export for user {}, push notifications are disabled",
+                user_id
+            );
+            return Ok(());
+        }
+
+        self.queue
+            .push(WorkerJob::SendPushNotification {
+                user_id,
+                notification: PushNotificationDto {
+                    title: "Your data export is ready".to_string(),
+                    body: format!(
+                        "Download it before {} UTC",
+                        export.expires_at.format("%Y-%m-%d %H:%M")
+                    ),
+                    url: Some(export.url),
+                },
+            })
+            .await?;
+
+        Ok(())
+    }
+}
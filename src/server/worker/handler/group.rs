@@ -0,0 +1,33 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::server::{error::AppError, service::group::rule::GroupRuleService};
+
+impl WorkerJobHandler {
+    /// Removes users from the groups they no longer qualify for.
+    ///
+    /// # Arguments
+    /// - `user_ids` - IDs of the users whose characters' affiliations were refreshed
+    ///
+    /// # Returns
+    /// - `Ok(())` - Memberships were re-evaluated
+    /// - `Err(AppError)` - Failed to read the rules or affiliations, or to remove a membership
+    pub async fn check_group_eligibility(&self, user_ids: Vec<i32>) -> Result<(), AppError> {
+        tracing::debug!(
+            "Processing group eligibility check for {} users",
+            user_ids.len()
+        );
+
+        let removed = GroupRuleService::new(&self.db)
+            .check_eligibility(user_ids)
+            .await?;
+        if removed > 0 {
+            tracing::debug!(
+                "Removed {} group memberships of users no longer qualifying",
+                removed
+            );
+        }
+
+        Ok(())
+    }
+}
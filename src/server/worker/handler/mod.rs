@@ -1,8 +1,9 @@
 //! Worker job handler for processing background tasks with retry logic.
 //!
 //! This module provides the `WorkerJobHandler` that executes different types of worker
-//! jobs including EVE Online data updates. Each job type is dispatched to the appropriate
-//! service method with comprehensive error handling, retry logic, and logging.
+//! jobs including EVE Online data updates and plugin-defined jobs. Each job type is
+//! dispatched to the appropriate service method with comprehensive error handling, retry
+//! logic, and logging.
 //!
 //! # Retry Strategy
 //!
@@ -49,6 +50,9 @@
 //! - Parse errors (malformed data)
 //! - Exceeding max retry attempts (10 attempts)
 //!
+//! Permanently failed jobs are moved to the worker queue's dead-letter queue, where admins
+//! can inspect, edit and replay them.
+//!
 //! # ESI Downtime Handling
 //!
 //! ESI has daily downtime from 11:00-11:05 UTC. The handler applies a 2-minute
@@ -56,6 +60,18 @@
 //! within this window to execute after downtime ends. Retry metadata is preserved
 //! during downtime rescheduling.
 //!
+//! # Partial Failures
+//!
+//! Batch jobs report the items that failed in a `JobOutcome` instead of failing as a whole.
+//! The outcome is logged and recorded in the job's status, and a batch of only the retryable
+//! items is pushed back with exponential backoff, keeping the job's retry metadata.
+//!
+//! # Read-Only Mode
+//!
+//! While read-only mode is enabled for database maintenance, the worker pool stops taking
+//! jobs from the queue and the handler reschedules jobs it already took instead of running
+//! them, so they pause rather than fail on rejected writes.
+//!
 //! # Examples
 //!
 //! ## Successful Job Execution
@@ -104,6 +120,15 @@
 //! // Prevents thundering herd when rate limit expires
 //! ```
 //!
+//! ## Batch Job with Failed Items
+//!
+//! ```ignore
+//! // 3 characters of a 900 character affiliation batch fail
+//! let outcome = handler.handle(&job).await?;
+//! // -> Returns Ok(JobOutcome) with 897 succeeded and the 3 failures
+//! // -> A batch of the retryable failures is pushed back with exponential backoff
+//! ```
+//!
 //! ## Job with Permanent Failure
 //!
 //! ```ignore
@@ -120,9 +145,20 @@
 //! // -> Returns Err("Job exceeded maximum retry attempts")
 //! // -> Job is permanently removed from queue
 //! ```
+mod character_skill;
+mod consent;
+mod corporation_member;
+mod dashboard;
+mod digest;
+mod discord;
 mod eve;
+mod export;
+mod group;
+mod push;
+mod token;
+mod webhook;
 
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use chrono::Utc;
 use dioxus_logger::tracing;
@@ -130,10 +166,14 @@ use sea_orm::DatabaseConnection;
 
 use crate::server::{
     error::{retry::ErrorRetryStrategy, AppError},
-    model::worker::{RetryMetadata, ScheduledWorkerJob, WorkerJob},
-    service::eve::esi::EsiProvider,
-    util::eve::get_esi_downtime_remaining,
-    worker::queue::WorkerQueue,
+    model::worker::{JobOutcome, RetryMetadata, ScheduledWorkerJob, WorkerJob},
+    plugin::{PluginJobContext, PluginRegistry},
+    service::{eve::esi::EsiProvider, push::PushConfig, search::SearchConfig},
+    util::{
+        crypto::ColumnCipher, eve::get_esi_downtime_remaining, object_storage::ObjectStorage,
+        read_only::ReadOnlyMode,
+    },
+    worker::{pool::EsiRateLimiter, queue::WorkerQueue},
 };
 
 /// Maximum number of retry attempts before permanently failing a job.
@@ -168,6 +208,9 @@ const MAX_RETRY_DELAY_SECS: u64 = 1800; // 30 minutes
 /// This matches the stagger window used by the initial job scheduler.
 const RATE_LIMIT_STAGGER_WINDOW_SECS: u64 = 900; // 15 minutes
 
+/// Delay before a job taken while read-only mode is enabled is retried (1 minute).
+const READ_ONLY_RESCHEDULE_DELAY_SECS: i64 = 60;
+
 /// Handler for processing worker jobs from the queue.
 ///
 /// Provides a centralized interface for executing different types of worker jobs.
@@ -184,6 +227,29 @@ pub struct WorkerJobHandler {
     ///
     /// Disable for testing to prevent time-dependent test failures.
     offset_for_esi_downtime: bool,
+    /// Registered plugins handling `WorkerJob::Custom` jobs.
+    plugins: PluginRegistry,
+    /// Web Push settings used to deliver `WorkerJob::SendPushNotification` jobs.
+    push: PushConfig,
+    /// HTTP client for requests to services other than ESI, such as push services and
+    /// webhooks.
+    http_client: reqwest::Client,
+    /// Search settings used to index entities refreshed from ESI.
+    search: SearchConfig,
+    /// Discord webhook `WorkerJob::SendDiscordMessage` jobs are posted to.
+    discord_webhook_url: Option<reqwest::Url>,
+    /// Read-only mode flag pausing job processing during database maintenance.
+    read_only: ReadOnlyMode,
+    /// Bucket `WorkerJob::ExportUserData` archives are stored in.
+    object_storage: Option<ObjectStorage>,
+    /// Cipher the refresh tokens used by `WorkerJob::UpdateCorporationMembers`,
+    /// `WorkerJob::UpdateCharacterSkills`, and `WorkerJob::PruneRevokedTokens` are stored with.
+    cipher: ColumnCipher,
+    /// ESI error budget shared with the worker pool, which pauses dispatch while it is low.
+    ///
+    /// Jobs recovering from ESI errors themselves, such as affiliation batches split to isolate
+    /// rejected characters, record each error response here.
+    esi_rate_limiter: Arc<EsiRateLimiter>,
 }
 
 impl WorkerJobHandler {
@@ -212,16 +278,157 @@ impl WorkerJobHandler {
             esi_provider,
             queue,
             offset_for_esi_downtime,
+            plugins: PluginRegistry::new(),
+            push: PushConfig::default(),
+            http_client: reqwest::Client::new(),
+            search: SearchConfig::default(),
+            discord_webhook_url: None,
+            read_only: ReadOnlyMode::default(),
+            object_storage: None,
+            cipher: ColumnCipher::new(Vec::new()),
+            esi_rate_limiter: Arc::new(EsiRateLimiter::new()),
         }
     }
 
+    /// Sets the plugins that handle `WorkerJob::Custom` jobs.
+    ///
+    /// Without plugins, custom jobs fail permanently with `WorkerError::UnhandledCustomJob`.
+    ///
+    /// # Arguments
+    /// - `plugins` - Registered plugins
+    ///
+    /// # Returns
+    /// Job handler dispatching custom jobs to the plugins
+    pub fn with_plugins(mut self, plugins: PluginRegistry) -> Self {
+        self.plugins = plugins;
+        self
+    }
+
+    /// Sets the Web Push settings used to deliver push notifications.
+    ///
+    /// Without a VAPID key, `WorkerJob::SendPushNotification` jobs complete without sending
+    /// anything.
+    ///
+    /// # Arguments
+    /// - `push` - Web Push settings
+    /// - `http_client` - HTTP client used to contact push services
+    ///
+    /// # Returns
+    /// Job handler delivering push notifications with the settings
+    pub fn with_push(mut self, push: PushConfig, http_client: reqwest::Client) -> Self {
+        self.push = push;
+        self.http_client = http_client;
+        self
+    }
+
+    /// Sets the search settings used to index entities refreshed from ESI.
+    ///
+    /// Without a Meilisearch client, entities are only searchable through the database.
+    ///
+    /// # Arguments
+    /// - `search` - Search settings
+    ///
+    /// # Returns
+    /// Job handler indexing refreshed entities with the settings
+    pub fn with_search(mut self, search: SearchConfig) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Sets the Discord webhook messages are posted to.
+    ///
+    /// Without a webhook, `WorkerJob::SendDiscordMessage` jobs complete without sending
+    /// anything. Messages are posted with the HTTP client set by `with_push`.
+    ///
+    /// # Arguments
+    /// - `url` - Discord webhook URL, `None` to disable Discord messages
+    ///
+    /// # Returns
+    /// Job handler posting Discord messages to the webhook
+    pub fn with_discord_webhook(mut self, url: Option<reqwest::Url>) -> Self {
+        self.discord_webhook_url = url;
+        self
+    }
+
+    /// Sets the read-only mode flag shared with the HTTP layer.
+    ///
+    /// While the flag is enabled, jobs are rescheduled instead of being run.
+    ///
+    /// # Arguments
+    /// - `read_only` - Read-only mode flag
+    ///
+    /// # Returns
+    /// Job handler pausing while read-only mode is enabled
+    pub fn with_read_only(mut self, read_only: ReadOnlyMode) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets the bucket user data archives are stored in.
+    ///
+    /// Without object storage, `WorkerJob::ExportUserData` jobs fail permanently with
+    /// `ExportError::ObjectStorageDisabled`.
+    ///
+    /// # Arguments
+    /// - `object_storage` - Bucket client, `None` if object storage is not configured
+    ///
+    /// # Returns
+    /// Job handler storing user data archives in the bucket
+    pub fn with_object_storage(mut self, object_storage: Option<ObjectStorage>) -> Self {
+        self.object_storage = object_storage;
+        self
+    }
+
+    /// Sets the cipher refresh tokens are encrypted with.
+    ///
+    /// Without encryption keys, `WorkerJob::UpdateCorporationMembers` and
+    /// `WorkerJob::UpdateCharacterSkills` jobs fail permanently with
+    /// `EncryptionError::UnknownKey`, and `WorkerJob::PruneRevokedTokens` jobs can't check any
+    /// token.
+    ///
+    /// # Arguments
+    /// - `cipher` - Cipher built from the configured encryption keys
+    ///
+    /// # Returns
+    /// Job handler decrypting refresh tokens with the cipher
+    pub fn with_cipher(mut self, cipher: ColumnCipher) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// Whether job processing is paused because read-only mode is enabled.
+    ///
+    /// # Returns
+    /// - `true` - Jobs shouldn't be taken from the queue
+    /// - `false` - Jobs can be processed
+    pub fn is_paused(&self) -> bool {
+        self.read_only.is_enabled()
+    }
+
+    /// Gets the ESI error budget jobs record error responses against.
+    ///
+    /// # Returns
+    /// - `Arc<EsiRateLimiter>` - Error budget shared with the pool pausing dispatch
+    pub fn esi_rate_limiter(&self) -> Arc<EsiRateLimiter> {
+        Arc::clone(&self.esi_rate_limiter)
+    }
+
+    /// Gets the database connection jobs are handled with.
+    ///
+    /// # Returns
+    /// - `&DatabaseConnection` - Connection shared with the pool for recording job history
+    pub fn db(&self) -> &DatabaseConnection {
+        &self.db
+    }
+
     /// Handles a worker job by delegating to the appropriate handler method.
     ///
     /// This is the main entry point for job processing. The handler:
     /// 1. Checks retry limits to prevent infinite loops
     /// 2. Checks for ESI downtime and reschedules if needed
-    /// 3. Dispatches the job to the appropriate handler method
-    /// 4. Handles failures with exponential backoff based on retry count
+    /// 3. Reschedules the job if read-only mode is enabled
+    /// 4. Dispatches the job to the appropriate handler method
+    /// 5. Handles failures with exponential backoff based on retry count
     ///
     /// # Retry Strategy
     ///
@@ -236,9 +443,10 @@ impl WorkerJobHandler {
     /// - `scheduled_job` - The worker job to execute with its scheduled timestamp
     ///
     /// # Returns
-    /// - `Ok(())` - Job completed successfully, rescheduled due to downtime, or pushed back for retry
+    /// - `Ok(())` - Job completed successfully, rescheduled due to downtime or read-only mode, or
+    ///   pushed back for retry
     /// - `Err(AppError)` - Job failed permanently (not retryable)
-    pub async fn handle(&self, scheduled_job: &ScheduledWorkerJob) -> Result<(), AppError> {
+    pub async fn handle(&self, scheduled_job: &ScheduledWorkerJob) -> Result<JobOutcome, AppError> {
         // Check if job has exceeded retry limit
         if let Some(metadata) = &scheduled_job.retry_metadata {
             if metadata.attempt_count >= MAX_RETRY_ATTEMPTS {
@@ -250,9 +458,10 @@ impl WorkerJobHandler {
                     scheduled_job.job,
                     metadata.first_failed_at
                 );
-                return Err(AppError::Internal(
-                    "Job exceeded maximum retry attempts".to_string(),
-                ));
+                let error = AppError::Internal("Job exceeded maximum retry attempts".to_string());
+                self.dead_letter_job(scheduled_job, &error).await;
+
+                return Err(error);
             }
         }
 
@@ -264,9 +473,25 @@ impl WorkerJobHandler {
                     scheduled_job.retry_metadata.clone(),
                 )
                 .await?;
-            return Ok(());
+            return Ok(JobOutcome::default());
+        }
+
+        if self.is_paused() {
+            tracing::debug!(
+                "Read-only mode is enabled, rescheduling job: {}",
+                scheduled_job.job
+            );
+            self.queue
+                .schedule(
+                    scheduled_job.job.clone(),
+                    Utc::now() + chrono::Duration::seconds(READ_ONLY_RESCHEDULE_DELAY_SECS),
+                    scheduled_job.retry_metadata.clone(),
+                )
+                .await?;
+            return Ok(JobOutcome::default());
         }
 
+        let mut outcome = JobOutcome::default();
         let result = match &scheduled_job.job {
             WorkerJob::UpdateFactionInfo => self.update_faction_info().await,
             WorkerJob::UpdateAllianceInfo { alliance_id } => {
@@ -278,24 +503,61 @@ impl WorkerJobHandler {
             WorkerJob::UpdateCharacterInfo { character_id } => {
                 self.update_character_info(*character_id).await
             }
-            WorkerJob::UpdateAffiliations { character_ids } => {
-                self.update_affiliations(character_ids.clone()).await
+            WorkerJob::UpdateAffiliations { character_ids } => self
+                .update_affiliations(character_ids.clone())
+                .await
+                .map(|affiliation_outcome| outcome = affiliation_outcome),
+            WorkerJob::UpdateCorporationMembers { corporation_id } => {
+                self.update_corporation_members(*corporation_id).await
+            }
+            WorkerJob::UpdateCharacterSkills { character_id } => {
+                self.update_character_skills(*character_id).await
+            }
+            WorkerJob::PruneRevokedTokens => self.prune_revoked_tokens().await,
+            WorkerJob::DeleteConsentData { user_id, category } => {
+                self.delete_consent_data(*user_id, *category).await
+            }
+            WorkerJob::RefreshDashboardSummaries => self.refresh_dashboard_summaries().await,
+            WorkerJob::SendPushNotification {
+                user_id,
+                notification,
+            } => self.send_push_notification(*user_id, notification).await,
+            WorkerJob::SendDiscordMessage { content } => self.send_discord_message(content).await,
+            WorkerJob::SendWebhook {
+                webhook_id,
+                event,
+                content,
+            } => self.send_webhook(*webhook_id, *event, content).await,
+            WorkerJob::SendWeeklyDigest => self.send_weekly_digest().await,
+            WorkerJob::ExportUserData { user_id } => self.export_user_data(*user_id).await,
+            WorkerJob::CheckGroupEligibility { user_ids } => {
+                self.check_group_eligibility(user_ids.clone()).await
+            }
+            WorkerJob::Custom(kind, payload) => {
+                let ctx = PluginJobContext {
+                    db: &self.db,
+                    esi_provider: &self.esi_provider,
+                    queue: &self.queue,
+                };
+
+                self.plugins.handle_job(ctx, kind, payload).await
             }
         };
 
         let Err(e) = result else {
-            return Ok(());
+            self.retry_failed_items(scheduled_job, &outcome).await?;
+            return Ok(outcome);
         };
 
         match e.to_retry_strategy() {
             ErrorRetryStrategy::Retry => {
                 self.retry_job_with_backoff(scheduled_job, None).await?;
-                Ok(())
+                Ok(JobOutcome::default())
             }
             ErrorRetryStrategy::RateLimited(retry_after) => {
                 self.retry_job_with_backoff(scheduled_job, retry_after)
                     .await?;
-                Ok(())
+                Ok(JobOutcome::default())
             }
             ErrorRetryStrategy::Fail => {
                 tracing::error!(
@@ -303,12 +565,89 @@ impl WorkerJobHandler {
                     scheduled_job.job,
                     e
                 );
+                self.dead_letter_job(scheduled_job, &e).await;
 
                 Err(e)
             }
         }
     }
 
+    /// Logs the failed items of a batch job and pushes back a batch of the retryable ones.
+    ///
+    /// The batch keeps the job's retry metadata, so items that keep failing are given up on
+    /// after `MAX_RETRY_ATTEMPTS` like whole jobs.
+    ///
+    /// # Arguments
+    /// - `scheduled_job` - The batch job that ran
+    /// - `outcome` - Outcome of the job with its failed items
+    async fn retry_failed_items(
+        &self,
+        scheduled_job: &ScheduledWorkerJob,
+        outcome: &JobOutcome,
+    ) -> Result<(), AppError> {
+        let Some(summary) = outcome.summary() else {
+            return Ok(());
+        };
+
+        tracing::warn!(
+            "Job completed with failed items: {}. {}",
+            scheduled_job.job,
+            summary
+        );
+        for failure in &outcome.failed {
+            tracing::debug!(
+                id = failure.id,
+                retryable = failure.retryable,
+                "Item of job {} failed: {}",
+                scheduled_job.job,
+                failure.error
+            );
+        }
+
+        let retryable_ids = outcome.retryable_ids();
+        if retryable_ids.is_empty() {
+            return Ok(());
+        }
+        let Some(job) = scheduled_job.job.with_items(retryable_ids) else {
+            return Ok(());
+        };
+
+        let failed_items = ScheduledWorkerJob {
+            job,
+            scheduled_at: scheduled_job.scheduled_at,
+            retry_metadata: scheduled_job.retry_metadata.clone(),
+        };
+        self.retry_job_with_backoff(&failed_items, None).await
+    }
+
+    /// Moves a permanently failed job to the dead-letter queue.
+    ///
+    /// Failing to store the job is logged rather than returned, so the job's original error
+    /// is still reported to the caller.
+    ///
+    /// # Arguments
+    /// - `scheduled_job` - The job that failed permanently
+    /// - `error` - Error the job failed with
+    async fn dead_letter_job(&self, scheduled_job: &ScheduledWorkerJob, error: &AppError) {
+        let attempt_count = scheduled_job
+            .retry_metadata
+            .as_ref()
+            .map(|metadata| metadata.attempt_count)
+            .unwrap_or(0);
+
+        if let Err(e) = self
+            .queue
+            .dead_letter(&scheduled_job.job, &error.to_string(), attempt_count)
+            .await
+        {
+            tracing::error!(
+                "Failed to move job to dead-letter queue: {}. Error: {}",
+                scheduled_job.job,
+                e
+            );
+        }
+    }
+
     /// Retries a job with exponential backoff based on retry count.
     ///
     /// Calculates the backoff delay using exponential backoff with jitter:
@@ -0,0 +1,39 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::{
+    model::push::PushNotificationDto,
+    server::{error::AppError, service::push::PushService},
+};
+
+impl WorkerJobHandler {
+    /// Delivers a Web Push notification to every device a user subscribed on.
+    ///
+    /// # Arguments
+    /// - `user_id` - ID of the user to notify
+    /// - `notification` - Notification shown by the service worker
+    ///
+    /// # Returns
+    /// - `Ok(())` - Notification was sent to all reachable subscriptions, or push notifications
+    ///   are disabled
+    /// - `Err(AppError)` - Failed to load or clean up the user's subscriptions
+    pub async fn send_push_notification(
+        &self,
+        user_id: i32,
+        notification: &PushNotificationDto,
+    ) -> Result<(), AppError> {
+        tracing::debug!("Processing push notification for user {}", user_id);
+
+        let delivered = PushService::new(&self.db, &self.push)
+            .send_notification(&self.http_client, user_id, notification)
+            .await?;
+
+        tracing::debug!(
+            "Delivered push notification to {} subscription(s) of user {}",
+            delivered,
+            user_id
+        );
+
+        Ok(())
+    }
+}
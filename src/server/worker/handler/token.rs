@@ -0,0 +1,23 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::server::{error::AppError, service::token::TokenService};
+
+impl WorkerJobHandler {
+    /// Deletes the stored character refresh tokens EVE SSO rejects.
+    ///
+    /// # Returns
+    /// - `Ok(())` - Every token was checked, tokens failing for other reasons are logged
+    /// - `Err(AppError)` - Failed to retrieve the stored tokens
+    pub async fn prune_revoked_tokens(&self) -> Result<(), AppError> {
+        tracing::debug!("Processing revoked token pruning");
+
+        let pruned = TokenService::new(&self.db, &self.esi_provider, &self.cipher)
+            .prune_revoked_tokens()
+            .await?;
+
+        tracing::debug!("Deleted {} revoked character tokens", pruned);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use dioxus_logger::tracing;
+
+use super::WorkerJobHandler;
+use crate::{
+    model::webhook::WebhookEvent,
+    server::{
+        data::webhook::WebhookRepository,
+        error::{webhook::WebhookError, AppError},
+    },
+};
+
+/// Maximum number of characters Discord accepts in a message.
+const WEBHOOK_MESSAGE_LIMIT: usize = 2000;
+
+impl WorkerJobHandler {
+    /// Notifies an admin-configured webhook of a character ownership change.
+    ///
+    /// The payload sets both Discord's `content` and Slack's `text` field, so the same webhook
+    /// URL format works for either service. Mentions are not parsed by Discord.
+    ///
+    /// # Arguments
+    /// - `webhook_id` - ID of the webhook to notify
+    /// - `event` - Kind of ownership change
+    /// - `content` - Message describing the change as Markdown
+    ///
+    /// # Returns
+    /// - `Ok(())` - Notification was posted, or the webhook was deleted or disabled
+    /// - `Err(AppError::Webhook(WebhookError::WebhookRejected))` - The webhook target responded
+    ///   with an error status
+    /// - `Err(AppError)` - Failed to reach the webhook target or database query failed
+    pub async fn send_webhook(
+        &self,
+        webhook_id: i32,
+        event: WebhookEvent,
+        content: &str,
+    ) -> Result<(), AppError> {
+        let webhook = WebhookRepository::new(&self.db)
+            .find_by_id(webhook_id)
+            .await?;
+
+        let Some(webhook) = webhook.filter(|webhook| webhook.enabled) else {
+            tracing::debug!(
+                "Skipping notification of webhook {}, webhook was deleted or disabled",
+                webhook_id
+            );
+            return Ok(());
+        };
+
+        let content: String = content.chars().take(WEBHOOK_MESSAGE_LIMIT).collect();
+        let response = self
+            .http_client
+            .post(&webhook.url)
+            .json(&serde_json::json!({
+                "content": content,
+                "text": content,
+                "allowed_mentions": { "parse": [] },
+            }))
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(WebhookError::WebhookRejected(status.as_u16()).into());
+        }
+
+        tracing::debug!(
+            "Notified webhook {} of {} event",
+            webhook_id,
+            event.as_str()
+        );
+
+        Ok(())
+    }
+}
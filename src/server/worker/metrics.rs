@@ -0,0 +1,245 @@
+//! Worker metrics for monitoring job throughput and backlog.
+//!
+//! This module provides the `WorkerMetrics` counters recorded by the `WorkerQueue` and
+//! `WorkerPool` while jobs are popped and executed, and the `WorkerMetricsSnapshot` served in
+//! the Prometheus text exposition format by the `/metrics` endpoint. Counters are kept in
+//! memory per process and reset when the server restarts, which Prometheus handles for
+//! counters. Queue depth and active permits are read when a snapshot is taken rather than
+//! tracked.
+
+use std::{
+    fmt::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// In-memory counters of worker job processing.
+///
+/// Cheap to clone, as clones share the same counters.
+#[derive(Clone, Debug, Default)]
+pub struct WorkerMetrics {
+    inner: Arc<WorkerMetricsRef>,
+}
+
+/// Counters shared by all clones of a `WorkerMetrics`.
+#[derive(Debug, Default)]
+struct WorkerMetricsRef {
+    /// Jobs that finished executing, whatever their outcome.
+    jobs_processed: AtomicU64,
+    /// Jobs that failed permanently.
+    jobs_failed: AtomicU64,
+    /// Jobs that exceeded the job timeout.
+    jobs_timed_out: AtomicU64,
+    /// Pop attempts made against the queue.
+    pops: AtomicU64,
+    /// Total time spent popping jobs from the queue, in microseconds.
+    pop_duration_micros: AtomicU64,
+}
+
+impl WorkerMetrics {
+    /// Creates a new set of worker metrics with all counters at zero.
+    ///
+    /// # Returns
+    /// - `WorkerMetrics` - New metrics instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a job that finished executing.
+    ///
+    /// Jobs pushed back to the queue for a retry count as processed, since the attempt
+    /// finished.
+    ///
+    /// # Arguments
+    /// - `failed` - Whether the job failed permanently
+    pub fn record_job_processed(&self, failed: bool) {
+        self.inner.jobs_processed.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            self.inner.jobs_failed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Records a job that exceeded the job timeout.
+    pub fn record_job_timed_out(&self) {
+        self.inner.jobs_timed_out.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records one pop attempt against the queue, whether or not it returned a job.
+    ///
+    /// # Arguments
+    /// - `duration` - Time the pop took
+    pub fn record_pop(&self, duration: Duration) {
+        self.inner.pops.fetch_add(1, Ordering::Relaxed);
+        self.inner
+            .pop_duration_micros
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Takes a snapshot of the counters along with the current queue and pool state.
+    ///
+    /// # Arguments
+    /// - `queue_depth` - Number of jobs currently in the queue
+    /// - `active_permits` - Number of concurrency permits held by executing jobs
+    /// - `max_concurrent_jobs` - Maximum number of jobs executing at once
+    ///
+    /// # Returns
+    /// - `WorkerMetricsSnapshot` - Point-in-time values of all worker metrics
+    pub fn snapshot(
+        &self,
+        queue_depth: usize,
+        active_permits: usize,
+        max_concurrent_jobs: usize,
+    ) -> WorkerMetricsSnapshot {
+        WorkerMetricsSnapshot {
+            jobs_processed: self.inner.jobs_processed.load(Ordering::Relaxed),
+            jobs_failed: self.inner.jobs_failed.load(Ordering::Relaxed),
+            jobs_timed_out: self.inner.jobs_timed_out.load(Ordering::Relaxed),
+            pops: self.inner.pops.load(Ordering::Relaxed),
+            pop_duration: Duration::from_micros(
+                self.inner.pop_duration_micros.load(Ordering::Relaxed),
+            ),
+            queue_depth,
+            active_permits,
+            max_concurrent_jobs,
+        }
+    }
+}
+
+/// Point-in-time values of all worker metrics.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorkerMetricsSnapshot {
+    /// Jobs that finished executing, whatever their outcome.
+    pub jobs_processed: u64,
+    /// Jobs that failed permanently.
+    pub jobs_failed: u64,
+    /// Jobs that exceeded the job timeout.
+    pub jobs_timed_out: u64,
+    /// Pop attempts made against the queue.
+    pub pops: u64,
+    /// Total time spent popping jobs from the queue.
+    pub pop_duration: Duration,
+    /// Number of jobs currently in the queue.
+    pub queue_depth: usize,
+    /// Number of concurrency permits held by executing jobs.
+    pub active_permits: usize,
+    /// Maximum number of jobs executing at once.
+    pub max_concurrent_jobs: usize,
+}
+
+impl WorkerMetricsSnapshot {
+    /// Renders the metrics in the Prometheus text exposition format.
+    ///
+    /// # Returns
+    /// - `String` - Metrics with their help text and type, one sample per line
+    pub fn to_prometheus(&self) -> String {
+        let mut output = String::new();
+
+        let metrics = [
+            (
+                "bifrost_worker_jobs_processed_total",
+                "Worker jobs that finished executing, including failed jobs.",
+                "counter",
+                self.jobs_processed.to_string(),
+            ),
+            (
+                "bifrost_worker_jobs_failed_total",
+                "Worker jobs that failed permanently.",
+                "counter",
+                self.jobs_failed.to_string(),
+            ),
+            (
+                "bifrost_worker_jobs_timed_out_total",
+                "Worker jobs that exceeded the job timeout.",
+                "counter",
+                self.jobs_timed_out.to_string(),
+            ),
+            (
+                "bifrost_worker_queue_depth",
+                "Jobs currently in the worker queue, including jobs scheduled for later.",
+                "gauge",
+                self.queue_depth.to_string(),
+            ),
+            (
+                "bifrost_worker_active_permits",
+                "Concurrency permits held by executing worker jobs.",
+                "gauge",
+                self.active_permits.to_string(),
+            ),
+            (
+                "bifrost_worker_max_concurrent_jobs",
+                "Maximum number of worker jobs executing at once.",
+                "gauge",
+                self.max_concurrent_jobs.to_string(),
+            ),
+        ];
+
+        for (name, help, kind, value) in metrics {
+            let _ = writeln!(output, "# HELP {} {}", name, help);
+            let _ = writeln!(output, "# TYPE {} {}", name, kind);
+            let _ = writeln!(output, "{} {}", name, value);
+        }
+
+        let _ = writeln!(
+            output,
+            "# HELP bifrost_worker_queue_pop_duration_seconds Time spent popping jobs from the worker queue."
+        );
+        let _ = writeln!(
+            output,
+            "# TYPE bifrost_worker_queue_pop_duration_seconds summary"
+        );
+        let _ = writeln!(
+            output,
+            "bifrost_worker_queue_pop_duration_seconds_sum {}",
+            self.pop_duration.as_secs_f64()
+        );
+        let _ = writeln!(
+            output,
+            "bifrost_worker_queue_pop_duration_seconds_count {}",
+            self.pops
+        );
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for WorkerMetricsSnapshot::to_prometheus method.
+    mod to_prometheus {
+        use std::time::Duration;
+
+        use crate::server::worker::metrics::WorkerMetrics;
+
+        /// Tests rendering recorded metrics.
+        ///
+        /// Verifies that job outcomes and pop durations are counted and that every metric is
+        /// rendered with its type.
+        ///
+        /// Expected: Counters reflect the recorded jobs and pops
+        #[test]
+        fn renders_recorded_metrics() {
+            let metrics = WorkerMetrics::new();
+            metrics.record_job_processed(false);
+            metrics.record_job_processed(true);
+            metrics.record_job_timed_out();
+            metrics.record_pop(Duration::from_millis(250));
+            metrics.record_pop(Duration::from_millis(250));
+
+            let output = metrics.snapshot(7, 2, 10).to_prometheus();
+
+            assert!(output.contains("bifrost_worker_jobs_processed_total 2\n"));
+            assert!(output.contains("bifrost_worker_jobs_failed_total 1\n"));
+            assert!(output.contains("bifrost_worker_jobs_timed_out_total 1\n"));
+            assert!(output.contains("bifrost_worker_queue_depth 7\n"));
+            assert!(output.contains("bifrost_worker_active_permits 2\n"));
+            assert!(output.contains("bifrost_worker_max_concurrent_jobs 10\n"));
+            assert!(output.contains("bifrost_worker_queue_pop_duration_seconds_sum 0.5\n"));
+            assert!(output.contains("bifrost_worker_queue_pop_duration_seconds_count 2\n"));
+            assert!(output.contains("# TYPE bifrost_worker_queue_pop_duration_seconds summary\n"));
+        }
+    }
+}
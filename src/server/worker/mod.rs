@@ -3,9 +3,12 @@
 //! This module provides a Redis-backed job queue and worker pool for processing
 //! background tasks asynchronously. Jobs are scheduled with deduplication, TTL-based
 //! cleanup, and configurable concurrency limits. The system handles EVE Online data
-//! updates including faction, alliance, corporation, character info, and affiliations.
+//! updates including faction, alliance, corporation, character info, and affiliations, and
+//! records metrics on job throughput and backlog for the `/metrics` endpoint.
 
 pub mod handler;
+pub mod metrics;
+pub mod payload;
 pub mod pool;
 pub mod queue;
 
@@ -13,7 +16,10 @@ use fred::prelude::Pool;
 pub use pool::WorkerPool;
 pub use queue::WorkerQueue;
 
-use crate::server::worker::{handler::WorkerJobHandler, pool::WorkerPoolConfig};
+use crate::server::{
+    error::AppError,
+    worker::{handler::WorkerJobHandler, metrics::WorkerMetricsSnapshot, pool::WorkerPoolConfig},
+};
 
 /// Combined worker system with queue and processing pool.
 ///
@@ -42,9 +48,41 @@ impl Worker {
     /// # Returns
     /// - `Worker` - New worker system ready to start processing jobs
     pub fn new(config: WorkerPoolConfig, redis_pool: Pool, handler: WorkerJobHandler) -> Self {
-        let queue = WorkerQueue::new(redis_pool);
+        Self::with_queue(config, WorkerQueue::new(redis_pool), handler)
+    }
+
+    /// Creates a new worker system processing jobs from an existing queue.
+    ///
+    /// # Arguments
+    /// - `config` - Worker pool configuration including max concurrent jobs
+    /// - `queue` - Job queue, e.g. one running its cleanup task under a shared supervisor
+    /// - `handler` - Job handler that processes different job types
+    ///
+    /// # Returns
+    /// - `Worker` - New worker system ready to start processing jobs
+    pub fn with_queue(
+        config: WorkerPoolConfig,
+        queue: WorkerQueue,
+        handler: WorkerJobHandler,
+    ) -> Self {
         let pool = WorkerPool::new(config, queue.clone(), handler);
 
         Self { queue, pool }
     }
+
+    /// Takes a snapshot of the worker metrics along with the current queue depth and
+    /// concurrency permits in use.
+    ///
+    /// # Returns
+    /// - `Ok(WorkerMetricsSnapshot)` - Point-in-time values of all worker metrics
+    /// - `Err(AppError)` - Failed to read the queue depth from Redis
+    pub async fn metrics_snapshot(&self) -> Result<WorkerMetricsSnapshot, AppError> {
+        let queue_depth = self.queue.len().await?;
+
+        Ok(self.queue.metrics().snapshot(
+            queue_depth,
+            self.pool.active_job_count(),
+            self.pool.max_concurrent_jobs(),
+        ))
+    }
 }
@@ -0,0 +1,203 @@
+//! Versioned serialization of worker job payloads.
+//!
+//! Jobs are stored in Redis as JSON and may outlive the deploy that queued them, so a job
+//! serialized by an older release can be popped by a newer release whose `WorkerJob` enum has
+//! changed shape. To handle this, jobs are wrapped in an envelope recording the payload
+//! version they were serialized with:
+//!
+//! ```json
+//! {"version":1,"job":{"UpdateCharacterInfo":{"character_id":2114794365}}}
+//! ```
+//!
+//! When a payload is read, it is upgraded one version at a time by the migrations in
+//! `MIGRATIONS` before being deserialized into the current `WorkerJob`. Payloads queued before
+//! the envelope was introduced are bare `WorkerJob` JSON and are treated as version 0.
+//!
+//! # Changing `WorkerJob`
+//! When a change to `WorkerJob` alters the JSON of an existing variant (renaming a variant or
+//! field, changing a field's type), increment `CURRENT_PAYLOAD_VERSION` and append a migration
+//! converting the previous version's JSON to the new shape. Adding a new variant or an
+//! optional field doesn't require a new version.
+
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::server::{error::worker::WorkerError, model::worker::WorkerJob};
+
+/// Payload version written by this release.
+pub const CURRENT_PAYLOAD_VERSION: u32 = 1;
+
+/// Migrations upgrading a payload's job JSON from version `index` to version `index + 1`.
+const MIGRATIONS: [fn(Value) -> Result<Value, WorkerError>; CURRENT_PAYLOAD_VERSION as usize] =
+    [migrate_v0_to_v1];
+
+/// Envelope recording the payload version a job was serialized with.
+#[derive(Serialize)]
+struct PayloadEnvelope<'a> {
+    version: u32,
+    job: &'a WorkerJob,
+}
+
+/// Serializes a job into a versioned payload.
+///
+/// The payload is also used as the job's identity for queue deduplication, so serializing the
+/// same job always produces the same string.
+///
+/// # Arguments
+/// - `job` - Worker job to serialize
+///
+/// # Returns
+/// - `Ok(String)` - Versioned JSON payload
+/// - `Err(WorkerError::Serialization)` - Serialization failed
+pub fn serialize_job(job: &WorkerJob) -> Result<String, WorkerError> {
+    serde_json::to_string(&PayloadEnvelope {
+        version: CURRENT_PAYLOAD_VERSION,
+        job,
+    })
+    .map_err(|e| WorkerError::Serialization(e.to_string()))
+}
+
+/// Computes the ID a job's status is recorded under.
+///
+/// The ID is the hex-encoded SHA-256 hash of the job's payload, so it is stable across
+/// releases with the same payload version and identical jobs deduplicated by the queue share
+/// an ID.
+///
+/// # Arguments
+/// - `job` - Worker job to compute the ID of
+///
+/// # Returns
+/// - `Ok(String)` - 64 character hex job ID
+/// - `Err(WorkerError::Serialization)` - Serialization failed
+pub fn job_id(job: &WorkerJob) -> Result<String, WorkerError> {
+    let payload = serialize_job(job)?;
+
+    Ok(format!("{:x}", Sha256::digest(payload.as_bytes())))
+}
+
+/// Deserializes a payload into a job, upgrading it from older payload versions.
+///
+/// # Arguments
+/// - `payload` - Versioned or pre-versioning JSON payload
+///
+/// # Returns
+/// - `Ok(WorkerJob)` - Deserialized job
+/// - `Err(WorkerError::UnsupportedPayloadVersion)` - Payload was written by a newer release
+/// - `Err(WorkerError::Serialization)` - Payload is not valid JSON or doesn't match the job
+///   shape after migration
+pub fn deserialize_job(payload: &str) -> Result<WorkerJob, WorkerError> {
+    let value: Value =
+        serde_json::from_str(payload).map_err(|e| WorkerError::Serialization(e.to_string()))?;
+
+    let (version, mut job) = split_envelope(value)?;
+
+    if version > CURRENT_PAYLOAD_VERSION {
+        return Err(WorkerError::UnsupportedPayloadVersion {
+            version,
+            current: CURRENT_PAYLOAD_VERSION,
+        });
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        job = migration(job)?;
+    }
+
+    serde_json::from_value(job).map_err(|e| WorkerError::Serialization(e.to_string()))
+}
+
+/// Splits a payload into its version and job JSON.
+///
+/// Payloads without an envelope predate versioning and are returned as version 0. A bare job
+/// can't be mistaken for an envelope since job variant names are never `version` or `job`.
+fn split_envelope(value: Value) -> Result<(u32, Value), WorkerError> {
+    match value {
+        Value::Object(mut map) if map.contains_key("version") && map.contains_key("job") => {
+            let version = map
+                .get("version")
+                .and_then(Value::as_u64)
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or_else(|| {
+                    WorkerError::Serialization("Invalid worker job payload version".to_string())
+                })?;
+
+            Ok((version, map.remove("job").unwrap_or(Value::Null)))
+        }
+        value => Ok((0, value)),
+    }
+}
+
+/// Upgrades a pre-versioning payload to version 1.
+///
+/// Version 1 introduced the envelope without changing the job JSON.
+fn migrate_v0_to_v1(job: Value) -> Result<Value, WorkerError> {
+    Ok(job)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests that a serialized job deserializes back to the same job.
+    ///
+    /// Expected: Ok with the original job
+    #[test]
+    fn round_trips_current_version() {
+        let job = WorkerJob::UpdateAffiliations {
+            character_ids: vec![1, 2, 3],
+        };
+
+        let payload = serialize_job(&job).unwrap();
+
+        assert!(payload.starts_with(&format!("{{\"version\":{}", CURRENT_PAYLOAD_VERSION)));
+        assert_eq!(deserialize_job(&payload).unwrap(), job);
+    }
+
+    /// Tests that payloads queued before versioning are upgraded.
+    ///
+    /// Expected: Ok with the job from the bare payload
+    #[test]
+    fn upgrades_unversioned_payload() {
+        let job = WorkerJob::UpdateCharacterInfo { character_id: 1 };
+        let legacy = serde_json::to_string(&job).unwrap();
+
+        assert_eq!(deserialize_job(&legacy).unwrap(), job);
+        assert_eq!(
+            deserialize_job("\"UpdateFactionInfo\"").unwrap(),
+            WorkerJob::UpdateFactionInfo
+        );
+    }
+
+    /// Tests that job IDs identify a job's payload.
+    ///
+    /// Expected: Same ID for equal jobs, different IDs for different jobs
+    #[test]
+    fn derives_job_id_from_payload() {
+        let job = WorkerJob::UpdateCharacterInfo { character_id: 1 };
+
+        let id = job_id(&job).unwrap();
+
+        assert_eq!(id.len(), 64);
+        assert_eq!(id, job_id(&job.clone()).unwrap());
+        assert_ne!(
+            id,
+            job_id(&WorkerJob::UpdateCharacterInfo { character_id: 2 }).unwrap()
+        );
+    }
+
+    /// Tests that payloads written by a newer release are rejected.
+    ///
+    /// Expected: Err(WorkerError::UnsupportedPayloadVersion)
+    #[test]
+    fn rejects_newer_version() {
+        let payload = format!(
+            "{{\"version\":{},\"job\":\"UpdateFactionInfo\"}}",
+            CURRENT_PAYLOAD_VERSION + 1
+        );
+
+        assert!(matches!(
+            deserialize_job(&payload),
+            Err(WorkerError::UnsupportedPayloadVersion { .. })
+        ));
+    }
+}
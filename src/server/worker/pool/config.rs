@@ -39,6 +39,11 @@ pub struct WorkerPoolConfig {
     /// How often the queue cleanup task runs to remove stale jobs (milliseconds).
     /// The cleanup task removes jobs older than the TTL.
     pub cleanup_interval_ms: u64,
+
+    /// Maximum time to wait for in-flight jobs to finish when stopping (seconds).
+    /// When `None`, `stop()` returns once dispatchers have stopped without waiting for
+    /// in-flight jobs.
+    pub drain_timeout_seconds: Option<u64>,
 }
 
 impl WorkerPoolConfig {
@@ -64,6 +69,7 @@ impl WorkerPoolConfig {
             job_timeout_seconds: 60, // 1 minute
             shutdown_timeout_seconds: 5, // 5 seconds to wait for dispatcher shutdown
             cleanup_interval_ms: 5 * 60 * 1000, // 5 minutes
+            drain_timeout_seconds: None, // Don't wait for in-flight jobs
         }
     }
 
@@ -106,6 +112,18 @@ impl WorkerPoolConfig {
     pub fn cleanup_interval(&self) -> Duration {
         Duration::from_millis(self.cleanup_interval_ms)
     }
+
+    /// Gets the drain timeout as Duration.
+    ///
+    /// Converts the drain_timeout_seconds field to a Duration for draining in-flight jobs
+    /// on shutdown.
+    ///
+    /// # Returns
+    /// - `Some(Duration)` - Drain timeout duration
+    /// - `None` - In-flight jobs are not drained on shutdown
+    pub fn drain_timeout(&self) -> Option<Duration> {
+        self.drain_timeout_seconds.map(Duration::from_secs)
+    }
 }
 
 impl Default for WorkerPoolConfig {
@@ -149,6 +167,10 @@ mod tests {
             5 * 60 * 1000,
             "Default cleanup_interval_ms should be 300000 (5 minutes)"
         );
+        assert_eq!(
+            config.drain_timeout_seconds, None,
+            "Default drain_timeout_seconds should be None"
+        );
     }
 
     #[test]
@@ -234,6 +256,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_drain_timeout_conversion() {
+        let mut config = WorkerPoolConfig::new(50);
+        assert_eq!(
+            config.drain_timeout(),
+            None,
+            "drain_timeout() should be None when not configured"
+        );
+
+        config.drain_timeout_seconds = Some(30);
+
+        let timeout = config.drain_timeout();
+        assert_eq!(
+            timeout,
+            Some(Duration::from_secs(30)),
+            "drain_timeout() should return Duration from seconds"
+        );
+    }
+
     #[test]
     fn test_config_clone() {
         let config1 = WorkerPoolConfig::new(80);
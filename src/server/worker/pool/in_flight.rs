@@ -0,0 +1,126 @@
+//! Tracking of job tasks spawned by the worker pool.
+//!
+//! This module provides `InFlightJobs`, which counts the job tasks currently executing so the
+//! pool can report them and wait for them to finish when it is stopped. Each spawned task holds
+//! an `InFlightJob` guard that decrements the count when dropped, so tasks are untracked even if
+//! they panic.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use tokio::sync::Notify;
+
+/// Count of job tasks currently executing, with a notification when none are left.
+#[derive(Debug, Default)]
+pub struct InFlightJobs {
+    count: AtomicUsize,
+    idle: Notify,
+}
+
+impl InFlightJobs {
+    /// Creates a new tracker with no jobs in flight.
+    ///
+    /// # Returns
+    /// - `InFlightJobs` - New tracker instance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracks a job task until the returned guard is dropped.
+    ///
+    /// # Returns
+    /// - `InFlightJob` - Guard to move into the spawned job task
+    pub fn track(self: &Arc<Self>) -> InFlightJob {
+        self.count.fetch_add(1, Ordering::SeqCst);
+
+        InFlightJob {
+            jobs: Arc::clone(self),
+        }
+    }
+
+    /// Gets the number of job tasks currently executing.
+    ///
+    /// # Returns
+    /// - `usize` - Number of tracked job tasks
+    pub fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+
+    /// Waits until no job tasks are executing.
+    ///
+    /// Returns immediately if no jobs are in flight.
+    pub async fn wait_idle(&self) {
+        loop {
+            let idle = self.idle.notified();
+            tokio::pin!(idle);
+            // Register for the notification before checking the count so a job finishing in
+            // between is not missed
+            idle.as_mut().enable();
+
+            if self.count() == 0 {
+                return;
+            }
+
+            idle.await;
+        }
+    }
+}
+
+/// Guard marking a job task as in flight until it is dropped.
+#[derive(Debug)]
+pub struct InFlightJob {
+    jobs: Arc<InFlightJobs>,
+}
+
+impl Drop for InFlightJob {
+    fn drop(&mut self) {
+        if self.jobs.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.jobs.idle.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    /// Tests for InFlightJobs::wait_idle method.
+    mod wait_idle {
+        use std::{sync::Arc, time::Duration};
+
+        use crate::server::worker::pool::in_flight::InFlightJobs;
+
+        /// Tests waiting for a tracked job to finish.
+        ///
+        /// Expected: wait_idle completes once the guard is dropped and the count is 0
+        #[tokio::test]
+        async fn completes_when_jobs_finish() {
+            let jobs = Arc::new(InFlightJobs::new());
+            let guard = jobs.track();
+            assert_eq!(jobs.count(), 1);
+
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                drop(guard);
+            });
+
+            tokio::time::timeout(Duration::from_secs(1), jobs.wait_idle())
+                .await
+                .expect("wait_idle should complete once the job finishes");
+            assert_eq!(jobs.count(), 0);
+        }
+
+        /// Tests waiting without any tracked jobs.
+        ///
+        /// Expected: wait_idle completes immediately
+        #[tokio::test]
+        async fn completes_immediately_without_jobs() {
+            let jobs = InFlightJobs::new();
+
+            tokio::time::timeout(Duration::from_millis(10), jobs.wait_idle())
+                .await
+                .expect("wait_idle should complete immediately");
+        }
+    }
+}
@@ -2,22 +2,31 @@
 //!
 //! This module provides the `WorkerPool` that manages dispatcher tasks, job execution,
 //! and concurrency limits using semaphores. The pool polls Redis for jobs and spawns
-//! tasks to process them with configurable timeout and shutdown behavior.
+//! tasks to process them with configurable timeout and shutdown behavior, recording each
+//! job's status as it runs and finishes and its outcome in the job history table. Dispatch
+//! pauses while the ESI error budget, shared with the handler and tracked from failed jobs and
+//! the ESI errors jobs recover from, is low.
 
 mod config;
+mod in_flight;
+mod rate_limit;
 
 pub use config::WorkerPoolConfig;
+pub use rate_limit::EsiRateLimiter;
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use dioxus_logger::tracing;
 use tokio::sync::{Notify, RwLock, Semaphore};
 use tokio::task::JoinHandle;
 
+use crate::model::worker::WorkerJobState;
+use crate::server::data::job_history::JobHistoryRepository;
 use crate::server::model::worker::ScheduledWorkerJob;
 use crate::server::worker::handler::WorkerJobHandler;
-use crate::server::{error::AppError, worker::queue::WorkerQueue};
+use crate::server::worker::pool::in_flight::{InFlightJob, InFlightJobs};
+use crate::server::{error::AppError, util::query_metrics, worker::queue::WorkerQueue};
 
 /// Worker pool for processing jobs from the WorkerQueue.
 ///
@@ -31,7 +40,8 @@ pub struct WorkerPool {
 /// Internal worker pool reference with configuration and runtime state.
 ///
 /// Contains the worker pool configuration, job queue, handler, and runtime state including
-/// semaphores for concurrency control, shutdown notifications, and dispatcher task handles.
+/// semaphores for concurrency control, shutdown notifications, dispatcher task handles,
+/// tracking of in-flight job tasks, and the ESI error budget shared by dispatchers.
 /// This struct is wrapped in an Arc by `WorkerPool` for cheap cloning.
 #[derive(Clone)]
 pub struct WorkerPoolRef {
@@ -41,6 +51,8 @@ pub struct WorkerPoolRef {
     semaphore: Arc<Semaphore>,
     shutdown: Arc<Notify>,
     dispatcher_handles: Arc<RwLock<Vec<JoinHandle<()>>>>,
+    in_flight: Arc<InFlightJobs>,
+    rate_limiter: Arc<EsiRateLimiter>,
 }
 
 impl WorkerPool {
@@ -59,6 +71,7 @@ impl WorkerPool {
     pub fn new(config: WorkerPoolConfig, queue: WorkerQueue, handler: WorkerJobHandler) -> Self {
         let semaphore = Arc::new(Semaphore::new(config.max_concurrent_jobs));
         let shutdown = Arc::new(Notify::new());
+        let rate_limiter = handler.esi_rate_limiter();
 
         Self {
             inner: Arc::new(WorkerPoolRef {
@@ -68,6 +81,8 @@ impl WorkerPool {
                 semaphore,
                 shutdown,
                 dispatcher_handles: Arc::new(RwLock::new(Vec::new())),
+                in_flight: Arc::new(InFlightJobs::new()),
+                rate_limiter,
             }),
         }
     }
@@ -131,6 +146,8 @@ impl WorkerPool {
         let handler = Arc::clone(&self.inner.handler);
         let semaphore = Arc::clone(&self.inner.semaphore);
         let shutdown = Arc::clone(&self.inner.shutdown);
+        let in_flight = Arc::clone(&self.inner.in_flight);
+        let rate_limiter = Arc::clone(&self.inner.rate_limiter);
 
         tokio::spawn(async move {
             tracing::info!("Dispatcher {} started", id);
@@ -152,6 +169,8 @@ impl WorkerPool {
                         &queue,
                         &handler,
                         &semaphore,
+                        &in_flight,
+                        &rate_limiter,
                     ) => {
                         // Continue to next iteration
                     }
@@ -165,8 +184,9 @@ impl WorkerPool {
     /// Processes jobs from the queue.
     ///
     /// Polls Redis for a job and spawns a task to process it if available. Blocks on
-    /// semaphore if at capacity. Sleeps if queue is empty or on error. Returns jobs to
-    /// queue if semaphore is closed (shutting down).
+    /// semaphore if at capacity. Sleeps if queue is empty, on error, while the handler is
+    /// paused by read-only mode, or until the ESI error budget resets when it runs low. Returns
+    /// jobs to queue if semaphore is closed (shutting down).
     ///
     /// # Arguments
     /// - `dispatcher_id` - Dispatcher identifier for logging
@@ -174,13 +194,34 @@ impl WorkerPool {
     /// - `queue` - Job queue to poll
     /// - `handler` - Job handler for execution
     /// - `semaphore` - Concurrency limit semaphore
+    /// - `in_flight` - Tracker for spawned job tasks
+    /// - `rate_limiter` - ESI error budget shared by dispatchers
     async fn process_jobs(
         dispatcher_id: usize,
         config: &WorkerPoolConfig,
         queue: &WorkerQueue,
         handler: &Arc<WorkerJobHandler>,
         semaphore: &Arc<Semaphore>,
+        in_flight: &Arc<InFlightJobs>,
+        rate_limiter: &Arc<EsiRateLimiter>,
     ) {
+        if handler.is_paused() {
+            // Read-only mode is enabled, leave jobs queued until it ends
+            tokio::time::sleep(config.poll_interval()).await;
+            return;
+        }
+
+        if let Some(pause) = rate_limiter.pause_remaining() {
+            // ESI error budget is low or ESI is rate limiting, leave jobs queued until it resets
+            tracing::debug!(
+                "Dispatcher {} paused for {} ms by the ESI error limit",
+                dispatcher_id,
+                pause.as_millis()
+            );
+            tokio::time::sleep(pause).await;
+            return;
+        }
+
         match queue.pop().await {
             Ok(Some(scheduled_job)) => {
                 // Try to acquire a permit (blocks if at capacity)
@@ -188,11 +229,24 @@ impl WorkerPool {
                     Ok(permit) => {
                         // Clone Arc references for the spawned task
                         let handler = Arc::clone(handler);
+                        let queue = queue.clone();
+                        let rate_limiter = Arc::clone(rate_limiter);
                         let timeout = config.job_timeout();
+                        // Track the task before spawning so stop() can't miss it
+                        let in_flight_job = in_flight.track();
 
                         // Spawn task to execute the job
                         tokio::spawn(async move {
-                            Self::execute_job(scheduled_job, handler, timeout, permit).await;
+                            Self::execute_job(
+                                scheduled_job,
+                                handler,
+                                queue,
+                                rate_limiter,
+                                timeout,
+                                permit,
+                                in_flight_job,
+                            )
+                            .await;
                         });
                     }
                     Err(_) => {
@@ -220,50 +274,136 @@ impl WorkerPool {
     /// Executes a job with timeout.
     ///
     /// Wraps job execution with timeout to prevent hung jobs. The semaphore permit is
-    /// held until completion, limiting concurrency. Logs success, failure, or timeout.
+    /// held until completion, limiting concurrency, and the job is tracked as in flight until
+    /// it finishes. Logs and records success, failure, or timeout in the worker metrics and
+    /// the job's status, along with the items of a batch job that failed, then writes the
+    /// outcome and duration to the job history. Jobs rescheduled while running, e.g. for a
+    /// retry, are recorded as queued. Failing to record the status or history is logged without
+    /// affecting the job. ESI error responses are recorded against the error budget.
     ///
     /// # Arguments
     /// - `scheduled_job` - Worker job to execute with its scheduled timestamp
     /// - `handler` - Job handler for execution
+    /// - `queue` - Job queue holding the worker metrics and job statuses
+    /// - `rate_limiter` - ESI error budget to record error responses against
     /// - `timeout` - Maximum execution time
     /// - `_permit` - Semaphore permit (held until dropped)
+    /// - `_in_flight_job` - In-flight tracking guard (held until dropped)
     async fn execute_job(
         scheduled_job: ScheduledWorkerJob,
         handler: Arc<WorkerJobHandler>,
+        queue: WorkerQueue,
+        rate_limiter: Arc<EsiRateLimiter>,
         timeout: Duration,
         _permit: tokio::sync::OwnedSemaphorePermit,
+        _in_flight_job: InFlightJob,
     ) {
-        // Execute job with timeout
-        let result = tokio::time::timeout(timeout, handler.handle(&scheduled_job)).await;
+        if let Err(e) = queue
+            .set_status(&scheduled_job.job, WorkerJobState::Running, None)
+            .await
+        {
+            tracing::warn!("Failed to record status of job {}: {:?}", scheduled_job, e);
+        }
 
-        match result {
-            Ok(Ok(())) => {
-                // Job completed successfully
+        // Execute job with timeout, counting its queries in debug builds
+        let metrics = queue.metrics();
+        let scope = scheduled_job.to_string();
+        let started_at = Instant::now();
+        let result = tokio::time::timeout(
+            timeout,
+            query_metrics::count_queries(&scope, handler.handle(&scheduled_job)),
+        )
+        .await;
+        let duration = started_at.elapsed();
+
+        let (state, error, finished) = match result {
+            Ok(Ok(outcome)) => {
+                // Job completed, batch jobs may report items that failed
+                metrics.record_job_processed(false);
                 tracing::debug!("Job completed: {}", scheduled_job);
+
+                let finished = queue
+                    .finish_status_with_outcome(&scheduled_job.job, &outcome)
+                    .await;
+
+                (outcome.state(), outcome.summary(), finished)
             }
             Ok(Err(e)) => {
+                metrics.record_job_processed(true);
+                rate_limiter.record_error(&e);
                 tracing::error!("Job failed: {}, error: {:?}", scheduled_job, e);
+
+                let error = e.to_string();
+                let finished = queue
+                    .finish_status(
+                        &scheduled_job.job,
+                        WorkerJobState::Failed,
+                        Some(error.clone()),
+                    )
+                    .await;
+
+                (WorkerJobState::Failed, Some(error), finished)
             }
             Err(_) => {
+                metrics.record_job_timed_out();
                 tracing::error!(
                     "Job timed out after {} seconds: {}",
                     timeout.as_secs(),
                     scheduled_job
                 );
+
+                let error = format!("Timed out after {} seconds", timeout.as_secs());
+                let finished = queue
+                    .finish_status(
+                        &scheduled_job.job,
+                        WorkerJobState::Timeout,
+                        Some(error.clone()),
+                    )
+                    .await;
+
+                (WorkerJobState::Timeout, Some(error), finished)
+            }
+        };
+
+        // Jobs rescheduled while running, e.g. for a retry, keep their queued status
+        let state = match finished {
+            Ok(true) => state,
+            Ok(false) => WorkerJobState::Queued,
+            Err(e) => {
+                tracing::warn!("Failed to record status of job {}: {:?}", scheduled_job, e);
+                state
             }
+        };
+
+        if let Err(e) = JobHistoryRepository::new(handler.db())
+            .record(
+                scheduled_job.job.kind(),
+                scheduled_job.job.entity_id(),
+                state,
+                error,
+                duration.as_millis().try_into().unwrap_or(i64::MAX),
+            )
+            .await
+        {
+            tracing::warn!("Failed to record history of job {}: {:?}", scheduled_job, e);
         }
 
-        // Permit automatically dropped here, releasing semaphore slot
+        // Permit and in-flight guard automatically dropped here, releasing semaphore slot
     }
 
     /// Stops the worker pool gracefully.
     ///
     /// Signals all dispatchers to stop, closes the semaphore to prevent new jobs,
     /// and stops the queue cleanup task. Waits for all dispatchers to shut down with
-    /// a configured timeout. In-flight job-processing tasks continue to completion.
+    /// a configured timeout.
+    ///
+    /// If a drain timeout is configured, then waits for in-flight job-processing tasks to
+    /// finish, logging a warning with the number still running if the timeout is reached.
+    /// Otherwise in-flight tasks continue to completion in the background.
     ///
     /// This method is idempotent - calling it when already stopped returns immediately.
-    /// It blocks until all dispatchers have shut down or timeout is reached.
+    /// It blocks until all dispatchers have shut down (and in-flight jobs have drained, if
+    /// configured) or the timeouts are reached.
     ///
     /// # Returns
     /// - `Ok(())` - Pool stopped successfully (or already stopped)
@@ -312,10 +452,32 @@ impl WorkerPool {
             }
         }
 
-        tracing::info!(
-            "Worker pool shut down ({} dispatchers stopped, in-flight tasks will complete)",
-            dispatcher_count
-        );
+        // Wait for in-flight jobs to finish if configured to drain them
+        let Some(drain_timeout) = self.inner.config.drain_timeout() else {
+            tracing::info!(
+                "Worker pool shut down ({} dispatchers stopped, in-flight tasks will complete)",
+                dispatcher_count
+            );
+
+            return Ok(());
+        };
+
+        match tokio::time::timeout(drain_timeout, self.inner.in_flight.wait_idle()).await {
+            Ok(()) => {
+                tracing::info!(
+                    "Worker pool shut down ({} dispatchers stopped, in-flight jobs drained)",
+                    dispatcher_count
+                );
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Worker pool shut down ({} dispatchers stopped, {} in-flight job(s) still running after {} seconds)",
+                    dispatcher_count,
+                    self.inner.in_flight.count(),
+                    drain_timeout.as_secs()
+                );
+            }
+        }
 
         Ok(())
     }
@@ -367,4 +529,15 @@ impl WorkerPool {
     pub fn active_job_count(&self) -> usize {
         self.inner.config.max_concurrent_jobs - self.inner.semaphore.available_permits()
     }
+
+    /// Gets the number of job-processing tasks still running.
+    ///
+    /// Counts spawned job tasks directly rather than held permits, so it can be used to
+    /// monitor jobs finishing while the pool drains during shutdown.
+    ///
+    /// # Returns
+    /// - `usize` - Number of spawned job tasks that haven't finished
+    pub fn in_flight_jobs(&self) -> usize {
+        self.inner.in_flight.count()
+    }
 }
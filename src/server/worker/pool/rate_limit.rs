@@ -0,0 +1,199 @@
+//! ESI error budget tracking for pausing job dispatch.
+//!
+//! ESI allows a limited number of error responses per window before it answers every request
+//! with 420 until the window resets. This module provides `EsiRateLimiter`, a token bucket
+//! shared by the worker pool's dispatchers that spends a token on each ESI error response and
+//! refills at the start of every window. Dispatch pauses when the bucket runs low, or when ESI
+//! responds with 420 or 429, until the window resets or the `Retry-After` time reported by
+//! eve_esi has passed.
+
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::server::error::AppError;
+
+/// Number of ESI error responses allowed per error limit window.
+const ESI_ERROR_LIMIT: u32 = 100;
+
+/// Length of the ESI error limit window.
+const ESI_ERROR_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Remaining error budget at which dispatch pauses until the window resets.
+///
+/// Leaves room for the errors of jobs still in flight when the pause starts.
+const ESI_ERROR_BUDGET_FLOOR: u32 = 10;
+
+/// Token bucket of ESI error responses shared by the worker pool's dispatchers.
+#[derive(Debug)]
+pub struct EsiRateLimiter {
+    state: Mutex<EsiRateLimitState>,
+}
+
+/// Error budget of the current window and the time dispatch is paused until.
+#[derive(Debug)]
+struct EsiRateLimitState {
+    tokens: u32,
+    window_reset: Instant,
+    paused_until: Option<Instant>,
+}
+
+impl EsiRateLimiter {
+    /// Creates a new limiter with a full error budget.
+    ///
+    /// # Returns
+    /// - `EsiRateLimiter` - New limiter instance
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(EsiRateLimitState {
+                tokens: ESI_ERROR_LIMIT,
+                window_reset: Instant::now() + ESI_ERROR_LIMIT_WINDOW,
+                paused_until: None,
+            }),
+        }
+    }
+
+    /// Records the error of a failed job if it is an ESI error response.
+    ///
+    /// # Arguments
+    /// - `error` - Error the job failed with
+    pub fn record_error(&self, error: &AppError) {
+        if let AppError::Esi(eve_esi::Error::EsiError(esi_error)) = error {
+            self.record_response(esi_error.status, esi_error.retry_after);
+        }
+    }
+
+    /// Records an ESI error response, pausing dispatch if the error budget runs low.
+    ///
+    /// # Arguments
+    /// - `status` - HTTP status code of the response
+    /// - `retry_after` - Time ESI asked to wait before the next request, if provided
+    pub fn record_response(&self, status: u16, retry_after: Option<Duration>) {
+        if status < 400 {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.refill(now);
+        state.tokens = state.tokens.saturating_sub(1);
+
+        let pause_until = if status == 420 || status == 429 {
+            Some(retry_after.map_or(state.window_reset, |retry_after| now + retry_after))
+        } else if state.tokens <= ESI_ERROR_BUDGET_FLOOR {
+            Some(state.window_reset)
+        } else {
+            None
+        };
+
+        if let Some(pause_until) = pause_until {
+            state.paused_until = Some(
+                state
+                    .paused_until
+                    .map_or(pause_until, |paused_until| paused_until.max(pause_until)),
+            );
+        }
+    }
+
+    /// Gets how long dispatch remains paused.
+    ///
+    /// # Returns
+    /// - `Some(Duration)` - Time left until the error budget resets or ESI accepts requests
+    /// - `None` - Jobs may be dispatched
+    pub fn pause_remaining(&self) -> Option<Duration> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.refill(now);
+
+        match state.paused_until {
+            Some(paused_until) if paused_until > now => Some(paused_until - now),
+            Some(_) => {
+                state.paused_until = None;
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+impl Default for EsiRateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EsiRateLimitState {
+    /// Refills the error budget if the window has reset.
+    fn refill(&mut self, now: Instant) {
+        if now >= self.window_reset {
+            self.tokens = ESI_ERROR_LIMIT;
+            self.window_reset = now + ESI_ERROR_LIMIT_WINDOW;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{EsiRateLimiter, ESI_ERROR_BUDGET_FLOOR, ESI_ERROR_LIMIT};
+
+    #[test]
+    fn test_dispatches_with_full_budget() {
+        let limiter = EsiRateLimiter::new();
+
+        limiter.record_response(404, None);
+
+        assert_eq!(limiter.pause_remaining(), None);
+    }
+
+    #[test]
+    fn test_pauses_when_budget_runs_low() {
+        let limiter = EsiRateLimiter::new();
+
+        for _ in 0..(ESI_ERROR_LIMIT - ESI_ERROR_BUDGET_FLOOR) {
+            limiter.record_response(502, None);
+        }
+
+        let remaining = limiter
+            .pause_remaining()
+            .expect("Dispatch should be paused");
+        assert!(remaining <= Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_pauses_for_retry_after_of_rate_limit() {
+        let limiter = EsiRateLimiter::new();
+
+        limiter.record_response(429, Some(Duration::from_secs(5)));
+
+        let remaining = limiter
+            .pause_remaining()
+            .expect("Dispatch should be paused");
+        assert!(remaining <= Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_pauses_until_window_reset_on_error_limit() {
+        let limiter = EsiRateLimiter::new();
+
+        limiter.record_response(420, None);
+
+        let remaining = limiter
+            .pause_remaining()
+            .expect("Dispatch should be paused");
+        assert!(remaining > Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_ignores_successful_responses() {
+        let limiter = EsiRateLimiter::new();
+
+        for _ in 0..ESI_ERROR_LIMIT {
+            limiter.record_response(304, None);
+        }
+
+        assert_eq!(limiter.pause_remaining(), None);
+    }
+}
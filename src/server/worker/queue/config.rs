@@ -1,8 +1,9 @@
 //! Worker queue configuration for TTL and cleanup settings.
 //!
 //! This module provides the `WorkerQueueConfig` struct for configuring job queue
-//! behavior including queue naming, job TTL (time-to-live), and cleanup intervals.
-//! Jobs exceeding the TTL are automatically removed during cleanup operations.
+//! behavior including queue naming, job TTL (time-to-live), job status retention, and cleanup
+//! intervals. Jobs and job statuses exceeding their TTL are automatically removed during
+//! cleanup operations.
 
 use std::time::Duration;
 
@@ -12,6 +13,10 @@ const DEFAULT_QUEUE_NAME: &str = "bifrost:worker:queue";
 /// Jobs older than this will be removed by cleanup operations
 const DEFAULT_JOB_TTL: Duration = Duration::from_secs(3600);
 
+/// How long a job's status is kept after it last changed (24 hours)
+/// Statuses older than this will be removed by cleanup operations
+const DEFAULT_STATUS_TTL: Duration = Duration::from_secs(86400);
+
 /// Cleanup interval in milliseconds (5 minutes in seconds)
 /// Cleanup will run at most once per this interval
 const DEFAULT_CLEANUP_INTERVAL: Duration = Duration::from_secs(300);
@@ -27,6 +32,8 @@ pub struct WorkerQueueConfig {
     pub queue_name: String,
     /// Maximum age for jobs before considered stale and removed by cleanup
     pub job_ttl: Duration,
+    /// How long job statuses are kept after their last change before removed by cleanup
+    pub status_ttl: Duration,
     /// How often the cleanup task runs to remove stale jobs
     pub cleanup_interval: Duration,
 }
@@ -34,8 +41,8 @@ pub struct WorkerQueueConfig {
 impl WorkerQueueConfig {
     /// Creates a new queue configuration with default values.
     ///
-    /// Initializes configuration with default queue name, 1-hour job TTL, and
-    /// 5-minute cleanup interval.
+    /// Initializes configuration with default queue name, 1-hour job TTL, 24-hour status
+    /// TTL, and 5-minute cleanup interval.
     ///
     /// # Returns
     /// - `WorkerQueueConfig` - New configuration with default values
@@ -43,6 +50,7 @@ impl WorkerQueueConfig {
         Self {
             queue_name: DEFAULT_QUEUE_NAME.to_string(),
             job_ttl: DEFAULT_JOB_TTL,
+            status_ttl: DEFAULT_STATUS_TTL,
             cleanup_interval: DEFAULT_CLEANUP_INTERVAL,
         }
     }
@@ -0,0 +1,152 @@
+//! Dead-letter storage for permanently failed worker jobs.
+//!
+//! Jobs that fail permanently are stored in the Redis hash `{queue_name}:dead`, keyed by an
+//! ID allocated from the counter `{queue_name}:dead:next_id`. Entries are not subject to the
+//! queue's TTL cleanup and stay until they are replayed or removed.
+
+use chrono::Utc;
+use fred::prelude::*;
+
+use crate::server::{
+    error::{worker::WorkerError, AppError},
+    model::worker::{DeadLetterJob, WorkerJob},
+    worker::{payload::serialize_job, queue::WorkerQueue},
+};
+
+impl WorkerQueue {
+    /// Stores a permanently failed job in the dead-letter queue.
+    ///
+    /// # Arguments
+    /// - `job` - Worker job that failed permanently
+    /// - `error` - Error the job failed with
+    /// - `attempt_count` - Number of retry attempts made before the job failed
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - ID of the new dead-letter entry
+    /// - `Err(AppError::Worker)` - Serialization failed
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn dead_letter(
+        &self,
+        job: &WorkerJob,
+        error: &str,
+        attempt_count: u32,
+    ) -> Result<u64, AppError> {
+        self.dead_letter_payload(serialize_job(job)?, error, attempt_count)
+            .await
+    }
+
+    /// Stores a permanently failed job's serialized payload in the dead-letter queue.
+    ///
+    /// Used directly for payloads that can't be deserialized into a `WorkerJob`, so they are
+    /// kept for inspection and can be replayed with a corrected payload.
+    ///
+    /// # Arguments
+    /// - `payload` - Serialized payload of the job
+    /// - `error` - Error the job failed with
+    /// - `attempt_count` - Number of retry attempts made before the job failed
+    ///
+    /// # Returns
+    /// - `Ok(u64)` - ID of the new dead-letter entry
+    /// - `Err(AppError::Worker)` - Serialization failed
+    /// - `Err(AppError)` - Redis communication failed
+    pub(super) async fn dead_letter_payload(
+        &self,
+        payload: String,
+        error: &str,
+        attempt_count: u32,
+    ) -> Result<u64, AppError> {
+        let id: u64 = self
+            .inner
+            .pool
+            .incr(format!("{}:dead:next_id", self.inner.config.queue_name))
+            .await?;
+
+        let entry = DeadLetterJob {
+            id,
+            payload,
+            error: error.to_string(),
+            attempt_count,
+            failed_at: Utc::now(),
+        };
+        let entry_json = serde_json::to_string(&entry)
+            .map_err(|e| AppError::Worker(WorkerError::Serialization(e.to_string())))?;
+
+        let _: () = self
+            .inner
+            .pool
+            .hset(self.dead_letter_key(), (id.to_string(), entry_json))
+            .await?;
+
+        Ok(id)
+    }
+
+    /// Retrieves all dead-lettered jobs.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<DeadLetterJob>)` - Dead-lettered jobs ordered by ID, oldest first
+    /// - `Err(AppError::Worker)` - A stored entry could not be deserialized
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn get_dead_letters(&self) -> Result<Vec<DeadLetterJob>, AppError> {
+        let entries: Vec<String> = self.inner.pool.hvals(self.dead_letter_key()).await?;
+
+        let mut dead_letters = entries
+            .iter()
+            .map(|entry| {
+                serde_json::from_str::<DeadLetterJob>(entry)
+                    .map_err(|e| AppError::Worker(WorkerError::Serialization(e.to_string())))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        dead_letters.sort_by_key(|entry| entry.id);
+
+        Ok(dead_letters)
+    }
+
+    /// Retrieves a single dead-lettered job.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the dead-letter entry
+    ///
+    /// # Returns
+    /// - `Ok(Some(DeadLetterJob))` - Dead-lettered job found
+    /// - `Ok(None)` - No entry exists with the ID
+    /// - `Err(AppError::Worker)` - The stored entry could not be deserialized
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn get_dead_letter(&self, id: u64) -> Result<Option<DeadLetterJob>, AppError> {
+        let entry: Option<String> = self
+            .inner
+            .pool
+            .hget(self.dead_letter_key(), id.to_string())
+            .await?;
+
+        entry
+            .map(|entry| {
+                serde_json::from_str(&entry)
+                    .map_err(|e| AppError::Worker(WorkerError::Serialization(e.to_string())))
+            })
+            .transpose()
+    }
+
+    /// Removes a job from the dead-letter queue.
+    ///
+    /// # Arguments
+    /// - `id` - ID of the dead-letter entry
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Entry was removed
+    /// - `Ok(false)` - No entry exists with the ID
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn remove_dead_letter(&self, id: u64) -> Result<bool, AppError> {
+        let removed: i64 = self
+            .inner
+            .pool
+            .hdel(self.dead_letter_key(), id.to_string())
+            .await?;
+
+        Ok(removed > 0)
+    }
+
+    /// Returns the key of the Redis hash storing dead-lettered jobs.
+    fn dead_letter_key(&self) -> String {
+        format!("{}:dead", self.inner.config.queue_name)
+    }
+}
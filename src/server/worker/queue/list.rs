@@ -0,0 +1,118 @@
+//! Inspection of the jobs waiting in the worker queue.
+//!
+//! Jobs are listed by scheduled time without being removed from the queue, so operators can
+//! see the backlog and spot jobs that stay queued past their scheduled time.
+
+use std::ops::{Bound, RangeBounds};
+
+use chrono::{DateTime, Utc};
+use dioxus_logger::tracing;
+use fred::prelude::*;
+
+use crate::server::{
+    error::{worker::WorkerError, AppError},
+    model::worker::{RetryMetadata, ScheduledWorkerJob, WorkerQueuePage},
+    worker::{
+        payload::deserialize_job,
+        queue::{lua::LIST_JOBS_SCRIPT, WorkerQueue},
+    },
+};
+
+impl WorkerQueue {
+    /// Lists a page of the queued jobs scheduled within a time range.
+    ///
+    /// Jobs stay in the queue. Payloads that can't be deserialized are left out of the page
+    /// with a warning, they are moved to the dead-letter queue once popped.
+    ///
+    /// # Arguments
+    /// - `range` - Range of scheduled times to list, e.g. `..` for the whole queue
+    /// - `offset` - Number of jobs in the range to skip
+    /// - `limit` - Maximum number of jobs to return
+    ///
+    /// # Returns
+    /// - `Ok(WorkerQueuePage)` - Jobs ordered by scheduled time and the number of jobs in the
+    ///   range
+    /// - `Err(AppError::Worker)` - Stored retry metadata or a timestamp could not be read
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn list(
+        &self,
+        range: impl RangeBounds<DateTime<Utc>>,
+        offset: u64,
+        limit: u64,
+    ) -> Result<WorkerQueuePage, AppError> {
+        let retry_hash_key = format!("{}:retry", self.inner.config.queue_name);
+        let values: Vec<Value> = self
+            .inner
+            .pool
+            .eval(
+                LIST_JOBS_SCRIPT,
+                vec![&self.inner.config.queue_name, &retry_hash_key],
+                vec![
+                    score_bound(range.start_bound(), "-inf"),
+                    score_bound(range.end_bound(), "+inf"),
+                    offset.to_string(),
+                    limit.to_string(),
+                ],
+            )
+            .await?;
+
+        let mut values = values.into_iter();
+        let total: u64 = match values.next() {
+            Some(total) => total.convert()?,
+            None => 0,
+        };
+
+        let mut jobs = Vec::new();
+        let entries: Vec<Value> = values.collect();
+        for entry in entries.chunks_exact(3) {
+            let serialized: String = entry[0].clone().convert()?;
+            let score_millis: i64 = entry[1].clone().convert()?;
+            let metadata_json: String = entry[2].clone().convert()?;
+
+            let job = match deserialize_job(&serialized) {
+                Ok(job) => job,
+                Err(e) => {
+                    tracing::warn!(
+                        "Leaving unreadable job payload out of queue listing: {}. Error: {}",
+                        serialized,
+                        e
+                    );
+                    continue;
+                }
+            };
+
+            let scheduled_at = DateTime::from_timestamp_millis(score_millis).ok_or_else(|| {
+                AppError::Worker(WorkerError::Serialization(format!(
+                    "Invalid timestamp from Redis: {}",
+                    score_millis
+                )))
+            })?;
+
+            jobs.push(if metadata_json.is_empty() {
+                ScheduledWorkerJob::new(job, scheduled_at)
+            } else {
+                let metadata: RetryMetadata = serde_json::from_str(&metadata_json)
+                    .map_err(|e| AppError::Worker(WorkerError::Serialization(e.to_string())))?;
+                ScheduledWorkerJob::with_retry(job, scheduled_at, metadata)
+            });
+        }
+
+        Ok(WorkerQueuePage { total, jobs })
+    }
+}
+
+/// Converts a bound of a scheduled time range into a sorted set score bound.
+///
+/// # Arguments
+/// - `bound` - Bound of the range
+/// - `unbounded` - Score used if the range is unbounded on this side
+///
+/// # Returns
+/// - `String` - Timestamp in milliseconds, prefixed with `(` if exclusive
+fn score_bound(bound: Bound<&DateTime<Utc>>, unbounded: &str) -> String {
+    match bound {
+        Bound::Included(time) => time.timestamp_millis().to_string(),
+        Bound::Excluded(time) => format!("({}", time.timestamp_millis()),
+        Bound::Unbounded => unbounded.to_string(),
+    }
+}
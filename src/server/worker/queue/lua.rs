@@ -85,3 +85,64 @@ local result = redis.call('ZPOPMIN', queue_key, 1)
 -- result[1] is the identity string, result[2] is the score
 return {result[1], result[2]}
 "#;
+
+// Lua script to record a finished job's status only if the job is still marked running
+// Jobs rescheduled while running (retries, downtime) are marked queued again, and that state
+// must not be overwritten when the run that rescheduled them finishes
+//
+// KEYS[1]: status hash key
+// ARGV[1]: job ID
+// ARGV[2]: serialized status
+//
+// Returns:
+//   1 if the status was recorded
+//   0 if the job has no status or is not running
+pub static FINISH_JOB_STATUS_SCRIPT: &str = r#"
+local status_key = KEYS[1]
+local job_id = ARGV[1]
+
+local current = redis.call('HGET', status_key, job_id)
+if not current then
+    return 0
+end
+
+if cjson.decode(current).state ~= 'running' then
+    return 0
+end
+
+redis.call('HSET', status_key, job_id, ARGV[2])
+return 1
+"#;
+
+// Lua script to list a page of queued jobs scheduled within a time range
+// Reads the jobs and their retry metadata in one call so the page is consistent
+//
+// KEYS[1]: sorted set key (queue name)
+// KEYS[2]: retry metadata hash key
+// ARGV[1]: minimum score, `-inf` or a timestamp prefixed with `(` if exclusive
+// ARGV[2]: maximum score, `+inf` or a timestamp prefixed with `(` if exclusive
+// ARGV[3]: number of jobs to skip
+// ARGV[4]: maximum number of jobs to return
+//
+// Returns:
+//   table with {total, identity, score, metadata, ...} where total is the number of jobs in
+//   the range and metadata is an empty string for jobs without retry metadata
+pub static LIST_JOBS_SCRIPT: &str = r#"
+local queue_key = KEYS[1]
+local retry_key = KEYS[2]
+
+local total = redis.call('ZCOUNT', queue_key, ARGV[1], ARGV[2])
+local jobs = redis.call(
+    'ZRANGEBYSCORE', queue_key, ARGV[1], ARGV[2], 'WITHSCORES', 'LIMIT', ARGV[3], ARGV[4]
+)
+
+local result = {total}
+for i = 1, #jobs, 2 do
+    local metadata = redis.call('HGET', retry_key, jobs[i])
+    table.insert(result, jobs[i])
+    table.insert(result, jobs[i + 1])
+    table.insert(result, metadata or '')
+end
+
+return result
+"#;
@@ -17,7 +17,8 @@
 //! Jobs support retry tracking with exponential backoff. To maintain proper deduplication while
 //! tracking retry attempts, retry metadata is stored separately from the job identity:
 //!
-//! - **Job Identity**: Serialized job JSON used as ZSET member (for deduplication)
+//! - **Job Identity**: Versioned job payload used as ZSET member (for deduplication), see
+//!   [`crate::server::worker::payload`]
 //! - **Retry Metadata**: Stored in separate Redis hash `{queue_name}:retry`
 //!
 //! This separation ensures that a fresh job and a retrying job are considered duplicates
@@ -32,6 +33,24 @@
 //! - Stale jobs (older than TTL) are removed to prevent queue bloat
 //! - Orphaned retry metadata entries are also cleaned up during this process
 //!
+//! ## Dead-Letter Queue
+//!
+//! Jobs that fail permanently are moved to a separate Redis hash `{queue_name}:dead` by the
+//! worker handler, see [`WorkerQueue::dead_letter`]. Admins can inspect, edit and replay these
+//! jobs, after which they are removed from the dead-letter queue.
+//!
+//! ## Inspection
+//!
+//! Admins can list a page of the queued jobs within a range of scheduled times with their
+//! retry metadata, see [`WorkerQueue::list`], to inspect the backlog and spot stuck jobs.
+//!
+//! ## Job Status
+//!
+//! The status of each job is stored in a separate Redis hash `{queue_name}:status` keyed by
+//! the job's ID, see [`WorkerQueue::get_status`]. Jobs are marked queued when added to the
+//! queue and the worker pool records when they start running and how they finished. Statuses
+//! are removed by the cleanup task once they haven't changed for the status TTL.
+//!
 //! ## How this will be implemented
 //!
 //! 1. Call `get_all_of_type` when scheduling for example [`WorkerJob::UpdateAllianceInfo`].
@@ -45,23 +64,37 @@
 //! with apalis.
 pub mod config;
 
+mod dead_letter;
+mod list;
 mod lua;
+mod status;
 
 use lua::{CLEANUP_STALE_JOBS_SCRIPT, POP_JOB_SCRIPT, PUSH_JOB_SCRIPT};
 
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
 };
 
 use chrono::{DateTime, Utc};
 use dioxus_logger::tracing;
 use fred::prelude::*;
 
-use crate::server::{
-    error::{worker::WorkerError, AppError},
-    model::worker::{RetryMetadata, ScheduledWorkerJob, WorkerJob},
-    worker::queue::config::WorkerQueueConfig,
+use crate::{
+    model::worker::WorkerJobState,
+    server::{
+        error::{worker::WorkerError, AppError},
+        model::worker::{RetryMetadata, ScheduledWorkerJob, WorkerJob},
+        startup::TaskSupervisor,
+        worker::{
+            metrics::WorkerMetrics,
+            payload::{deserialize_job, serialize_job},
+            queue::config::WorkerQueueConfig,
+        },
+    },
 };
 
 /// Worker job queue with Redis backend.
@@ -86,6 +119,10 @@ pub struct WorkerQueueRef {
     cleanup_task_handle: std::sync::Arc<tokio::sync::RwLock<Option<tokio::task::JoinHandle<()>>>>,
     /// Shutdown flag for the cleanup task
     shutdown_flag: std::sync::Arc<AtomicBool>,
+    /// Supervisor restarting the cleanup task if it panics
+    supervisor: TaskSupervisor,
+    /// Metrics recorded while popping and processing jobs
+    metrics: WorkerMetrics,
 }
 
 impl WorkerQueue {
@@ -120,15 +157,37 @@ impl WorkerQueue {
                 config,
                 cleanup_task_handle: std::sync::Arc::new(tokio::sync::RwLock::new(None)),
                 shutdown_flag: std::sync::Arc::new(AtomicBool::new(false)),
+                supervisor: TaskSupervisor::new(),
+                metrics: WorkerMetrics::new(),
             }),
         }
     }
 
+    /// Runs the background cleanup task under the given supervisor.
+    ///
+    /// Without a supervisor set, the queue restarts its cleanup task with a private supervisor
+    /// whose health isn't shown in diagnostics. Must be called before `start_cleanup`.
+    ///
+    /// # Arguments
+    /// - `supervisor` - Supervisor shared with the application's other background tasks
+    ///
+    /// # Returns
+    /// - `WorkerQueue` - Queue using the supervisor for its cleanup task
+    pub fn with_supervisor(self, supervisor: TaskSupervisor) -> Self {
+        let mut inner = Arc::unwrap_or_clone(self.inner);
+        inner.supervisor = supervisor;
+
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
     /// Starts the background cleanup task for periodic removal of stale jobs.
     ///
-    /// Spawns a tokio task that runs every cleanup_interval to remove jobs older than
-    /// the configured job_ttl. The task respects the shutdown flag and exits cleanly.
-    /// This method is idempotent - calling it when already running has no effect.
+    /// Spawns a supervised tokio task that runs every cleanup_interval to remove jobs older
+    /// than the configured job_ttl. The task respects the shutdown flag and exits cleanly, and
+    /// is restarted if it panics. This method is idempotent - calling it when already running
+    /// has no effect.
     ///
     /// # Note
     /// The task will stop when `stop_cleanup()` is called or the queue is dropped.
@@ -144,40 +203,51 @@ impl WorkerQueue {
         let pool = self.inner.pool.clone();
         let shutdown_flag = self.inner.shutdown_flag.clone();
 
-        let task_handle = tokio::spawn(async move {
-            let mut interval_timer = tokio::time::interval(config.cleanup_interval);
-
-            tracing::info!(
-                "Worker queue cleanup task started with interval of {:?} seconds",
-                config.cleanup_interval.as_secs()
-            );
-
-            loop {
-                // Check shutdown flag before waiting
-                if shutdown_flag.load(Ordering::Relaxed) {
-                    tracing::info!("Worker queue cleanup task received shutdown signal");
-                    break;
-                }
-
-                tokio::select! {
-                    biased;
-
-                    _ = interval_timer.tick() => {
-                        // Check again after waking up
+        let task_handle = self
+            .inner
+            .supervisor
+            .spawn("worker queue cleanup", move || {
+                let config = config.clone();
+                let pool = pool.clone();
+                let shutdown_flag = shutdown_flag.clone();
+
+                async move {
+                    let mut interval_timer = tokio::time::interval(config.cleanup_interval);
+
+                    tracing::info!(
+                        "Worker queue cleanup task started with interval of {:?} seconds",
+                        config.cleanup_interval.as_secs()
+                    );
+
+                    loop {
+                        // Check shutdown flag before waiting
                         if shutdown_flag.load(Ordering::Relaxed) {
                             tracing::info!("Worker queue cleanup task received shutdown signal");
                             break;
                         }
 
-                        if let Err(e) = Self::cleanup_stale_jobs_internal(&config, &pool).await {
-                            tracing::warn!("Failed to cleanup stale worker queue jobs: {}", e);
+                        tokio::select! {
+                            biased;
+
+                            _ = interval_timer.tick() => {
+                                // Check again after waking up
+                                if shutdown_flag.load(Ordering::Relaxed) {
+                                    tracing::info!("Worker queue cleanup task received shutdown signal");
+                                    break;
+                                }
+
+                                if let Err(e) = Self::cleanup_stale_jobs_internal(&config, &pool).await {
+                                    tracing::warn!("Failed to cleanup stale worker queue jobs: {}", e);
+                                }
+                            }
                         }
                     }
-                }
-            }
 
-            tracing::info!("Worker queue cleanup task stopped");
-        });
+                    tracing::info!("Worker queue cleanup task stopped");
+
+                    Ok(())
+                }
+            });
 
         *handle = Some(task_handle);
     }
@@ -247,6 +317,7 @@ impl WorkerQueue {
     /// Uses a Lua script to atomically check for duplicates and add the job to the queue
     /// with the specified timestamp. Jobs with identical serialized JSON are deduplicated.
     /// Retry metadata is stored separately in a Redis hash to avoid affecting deduplication.
+    /// Added jobs are marked queued in the job status hash.
     ///
     /// # Arguments
     /// - `job` - Worker job to add to the queue
@@ -264,8 +335,7 @@ impl WorkerQueue {
         scheduled_at: DateTime<Utc>,
         retry_metadata: Option<RetryMetadata>,
     ) -> Result<bool, AppError> {
-        let serialized = serde_json::to_string(&job)
-            .map_err(|e| AppError::Worker(WorkerError::Serialization(e.to_string())))?;
+        let serialized = serialize_job(&job)?;
         let score = scheduled_at.timestamp_millis() as f64;
 
         // Execute Lua script atomically
@@ -283,8 +353,10 @@ impl WorkerQueue {
         // result is 1 if added, 0 if duplicate exists
         let was_added = result == 1;
 
-        // If job was added and has retry metadata, store it separately
         if was_added {
+            self.set_status(&job, WorkerJobState::Queued, None).await?;
+
+            // If job has retry metadata, store it separately
             if let Some(metadata) = retry_metadata {
                 let retry_hash_key = format!("{}:retry", self.inner.config.queue_name);
                 let metadata_json = serde_json::to_string(&metadata)
@@ -318,6 +390,7 @@ impl WorkerQueue {
     pub async fn pop(&self) -> Result<Option<ScheduledWorkerJob>, AppError> {
         // Execute Lua script to atomically pop earliest job that is due
         let now = Utc::now().timestamp_millis();
+        let started = Instant::now();
         let result: Option<Vec<Value>> = self
             .inner
             .pool
@@ -327,6 +400,7 @@ impl WorkerQueue {
                 vec![now.to_string()],
             )
             .await?;
+        self.inner.metrics.record_pop(started.elapsed());
 
         match result {
             None => Ok(None),
@@ -340,9 +414,22 @@ impl WorkerQueue {
                 let serialized: String = values[0].clone().convert()?;
                 let score_millis: i64 = values[1].clone().convert()?;
 
-                // Deserialize JSON back into WorkerJob
-                let job: WorkerJob = serde_json::from_str(&serialized)
-                    .map_err(|e| AppError::Worker(WorkerError::Serialization(e.to_string())))?;
+                // Deserialize JSON back into WorkerJob, upgrading payloads from older releases.
+                // Payloads that can't be read are moved to the dead-letter queue rather than lost.
+                let job = match deserialize_job(&serialized) {
+                    Ok(job) => job,
+                    Err(e) => {
+                        tracing::error!(
+                            "Moving unreadable job payload to dead-letter queue: {}. Error: {}",
+                            serialized,
+                            e
+                        );
+                        self.dead_letter_payload(serialized, &e.to_string(), 0)
+                            .await?;
+
+                        return Err(e.into());
+                    }
+                };
 
                 // Convert score (milliseconds) to DateTime<Utc>
                 let scheduled_at =
@@ -378,6 +465,14 @@ impl WorkerQueue {
         }
     }
 
+    /// Gets the metrics recorded for this queue and the worker pool processing its jobs.
+    ///
+    /// # Returns
+    /// - `&WorkerMetrics` - Metrics shared by all clones of this queue
+    pub fn metrics(&self) -> &WorkerMetrics {
+        &self.inner.metrics
+    }
+
     /// Gets the number of jobs currently in the queue.
     ///
     /// This method is useful for monitoring queue depth and ensuring
@@ -405,7 +500,7 @@ impl WorkerQueue {
     ///
     /// This method is called automatically by the background cleanup task at regular
     /// intervals, but can also be called manually for immediate cleanup. Also cleans
-    /// up orphaned retry metadata from the hash.
+    /// up orphaned retry metadata from the hash and job statuses older than the status TTL.
     ///
     /// # Returns
     /// - `Ok(u64)` - Number of stale jobs removed from the queue
@@ -417,7 +512,8 @@ impl WorkerQueue {
     /// Internal implementation of cleanup that can be called from the background task.
     ///
     /// Performs the actual cleanup logic using Redis Lua script to remove stale jobs.
-    /// Also removes orphaned retry metadata for jobs that no longer exist in the queue.
+    /// Also removes orphaned retry metadata for jobs that no longer exist in the queue and job
+    /// statuses that haven't changed within the status TTL.
     /// This is separated from the public method to allow both manual and automatic cleanup.
     ///
     /// # Arguments
@@ -472,6 +568,14 @@ impl WorkerQueue {
             }
         }
 
+        let removed_statuses = status::cleanup_stale_statuses(config, pool).await?;
+        if removed_statuses > 0 {
+            tracing::info!(
+                "Cleaned up {} stale job statuses from worker queue",
+                removed_statuses
+            );
+        }
+
         Ok(removed as u64)
     }
 }
@@ -0,0 +1,214 @@
+//! Status tracking for worker jobs.
+//!
+//! The status of each job is stored in the Redis hash `{queue_name}:status`, keyed by the
+//! job's ID, see [`crate::server::worker::payload::job_id`]. Jobs are marked queued when they
+//! are added to the queue, running when the worker pool starts executing them, and succeeded,
+//! partially succeeded, failed, or timeout when they finish. Batch jobs that partially
+//! succeeded record the items that failed. Statuses are removed by the queue's cleanup task
+//! once they haven't changed for the configured status TTL.
+
+use std::collections::HashMap;
+
+use chrono::Utc;
+use fred::prelude::*;
+
+use crate::{
+    model::worker::WorkerJobState,
+    server::{
+        error::{worker::WorkerError, AppError},
+        model::worker::{JobItemFailure, JobOutcome, WorkerJob, WorkerJobStatus},
+        worker::{
+            payload::job_id,
+            queue::{config::WorkerQueueConfig, lua::FINISH_JOB_STATUS_SCRIPT, WorkerQueue},
+        },
+    },
+};
+
+impl WorkerQueue {
+    /// Retrieves the last known status of a job.
+    ///
+    /// # Arguments
+    /// - `job_id` - ID of the job, see [`crate::server::worker::payload::job_id`]
+    ///
+    /// # Returns
+    /// - `Ok(Some(WorkerJobStatus))` - Status of the job
+    /// - `Ok(None)` - No status is recorded for the job, or it was removed by cleanup
+    /// - `Err(AppError::Worker)` - The stored status could not be deserialized
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn get_status(&self, job_id: &str) -> Result<Option<WorkerJobStatus>, AppError> {
+        let status: Option<String> = self.inner.pool.hget(self.status_key(), job_id).await?;
+
+        status
+            .map(|status| {
+                serde_json::from_str(&status)
+                    .map_err(|e| AppError::Worker(WorkerError::Serialization(e.to_string())))
+            })
+            .transpose()
+    }
+
+    /// Records the status of a job, replacing any previous status.
+    ///
+    /// # Arguments
+    /// - `job` - Worker job to record the status of
+    /// - `state` - New state of the job
+    /// - `error` - Error the job failed with, if any
+    ///
+    /// # Returns
+    /// - `Ok(())` - Status recorded
+    /// - `Err(AppError::Worker)` - Serialization failed
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn set_status(
+        &self,
+        job: &WorkerJob,
+        state: WorkerJobState,
+        error: Option<String>,
+    ) -> Result<(), AppError> {
+        let (id, status) = serialize_status(job, state, error, Vec::new())?;
+
+        let _: () = self
+            .inner
+            .pool
+            .hset(self.status_key(), (id, status))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Records the outcome of a job that finished running.
+    ///
+    /// The status is only recorded if the job is still marked running. Jobs rescheduled while
+    /// running, e.g. for a retry, are marked queued again and keep that status.
+    ///
+    /// # Arguments
+    /// - `job` - Worker job that finished
+    /// - `state` - Outcome of the job
+    /// - `error` - Error the job failed or timed out with, if any
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Status recorded
+    /// - `Ok(false)` - Job was not marked running, the status was left unchanged
+    /// - `Err(AppError::Worker)` - Serialization failed
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn finish_status(
+        &self,
+        job: &WorkerJob,
+        state: WorkerJobState,
+        error: Option<String>,
+    ) -> Result<bool, AppError> {
+        let (id, status) = serialize_status(job, state, error, Vec::new())?;
+
+        self.record_finished_status(id, status).await
+    }
+
+    /// Records the outcome of a job that ran to completion, with the items that failed.
+    ///
+    /// Jobs with failed items are marked partially succeeded, other jobs succeeded. As with
+    /// `finish_status`, the status is only recorded if the job is still marked running.
+    ///
+    /// # Arguments
+    /// - `job` - Worker job that finished
+    /// - `outcome` - Outcome of the job with its failed items
+    ///
+    /// # Returns
+    /// - `Ok(true)` - Status recorded
+    /// - `Ok(false)` - Job was not marked running, the status was left unchanged
+    /// - `Err(AppError::Worker)` - Serialization failed
+    /// - `Err(AppError)` - Redis communication failed
+    pub async fn finish_status_with_outcome(
+        &self,
+        job: &WorkerJob,
+        outcome: &JobOutcome,
+    ) -> Result<bool, AppError> {
+        let (id, status) = serialize_status(
+            job,
+            outcome.state(),
+            outcome.summary(),
+            outcome.failed.clone(),
+        )?;
+
+        self.record_finished_status(id, status).await
+    }
+
+    /// Records a serialized status if the job is still marked running.
+    async fn record_finished_status(&self, id: String, status: String) -> Result<bool, AppError> {
+        let recorded: i64 = self
+            .inner
+            .pool
+            .eval(
+                FINISH_JOB_STATUS_SCRIPT,
+                vec![self.status_key()],
+                vec![id, status],
+            )
+            .await?;
+
+        Ok(recorded == 1)
+    }
+
+    /// Returns the key of the Redis hash storing job statuses.
+    fn status_key(&self) -> String {
+        status_key(&self.inner.config)
+    }
+}
+
+/// Removes job statuses that haven't changed for longer than the configured status TTL.
+///
+/// # Arguments
+/// - `config` - Queue configuration with the status TTL
+/// - `pool` - Redis connection pool
+///
+/// # Returns
+/// - `Ok(u64)` - Number of statuses removed
+/// - `Err(AppError)` - Redis operation failed
+pub(super) async fn cleanup_stale_statuses(
+    config: &WorkerQueueConfig,
+    pool: &Pool,
+) -> Result<u64, AppError> {
+    let status_key = status_key(config);
+    let cutoff = Utc::now() - config.status_ttl;
+
+    let statuses: HashMap<String, String> = pool.hgetall(&status_key).await?;
+    let stale_ids: Vec<String> = statuses
+        .into_iter()
+        .filter(|(_, status)| {
+            // Unreadable statuses are removed as well rather than kept forever
+            !matches!(
+                serde_json::from_str::<WorkerJobStatus>(status),
+                Ok(status) if status.updated_at >= cutoff
+            )
+        })
+        .map(|(id, _)| id)
+        .collect();
+
+    if stale_ids.is_empty() {
+        return Ok(0);
+    }
+
+    let removed: i64 = pool.hdel(&status_key, stale_ids).await?;
+
+    Ok(removed as u64)
+}
+
+/// Serializes a job's status along with the job's ID.
+fn serialize_status(
+    job: &WorkerJob,
+    state: WorkerJobState,
+    error: Option<String>,
+    failed_items: Vec<JobItemFailure>,
+) -> Result<(String, String), AppError> {
+    let status = WorkerJobStatus {
+        job: job.to_string(),
+        state,
+        error,
+        failed_items,
+        updated_at: Utc::now(),
+    };
+    let status_json = serde_json::to_string(&status)
+        .map_err(|e| AppError::Worker(WorkerError::Serialization(e.to_string())))?;
+
+    Ok((job_id(job)?, status_json))
+}
+
+/// Returns the key of the Redis hash storing job statuses.
+fn status_key(config: &WorkerQueueConfig) -> String {
+    format!("{}:status", config.queue_name)
+}
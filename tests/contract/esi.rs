@@ -0,0 +1,88 @@
+//! Contract tests for ESI response payloads.
+//!
+//! Each test checks a recorded ESI response in `tests/fixtures/esi` against the `eve_esi`
+//! model it deserializes into and the factory used to mock the endpoint.
+
+use bifrost_test_utils::factory;
+use eve_esi::model::{
+    alliance::Alliance,
+    character::{Character, CharacterAffiliation},
+    corporation::Corporation,
+    universe::Faction,
+};
+
+use super::*;
+
+/// Reads a recorded ESI response from `tests/fixtures/esi`.
+macro_rules! snapshot {
+    ($file:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/esi/",
+            $file
+        ))
+    };
+}
+
+/// Tests the `GET /alliances/{alliance_id}` contract.
+///
+/// Expected: Snapshot and factory payload agree with the Alliance model
+#[test]
+fn alliance_matches_snapshot() {
+    assert_contract::<Alliance>(
+        "alliance.json",
+        snapshot!("alliance.json"),
+        factory::mock_alliance(None),
+    );
+}
+
+/// Tests the `GET /characters/{character_id}` contract.
+///
+/// Expected: Snapshot and factory payload agree with the Character model
+#[test]
+fn character_matches_snapshot() {
+    assert_contract::<Character>(
+        "character.json",
+        snapshot!("character.json"),
+        factory::mock_character(98785281, Some(99013534), None),
+    );
+}
+
+/// Tests the `POST /characters/affiliation` contract.
+///
+/// Expected: Snapshot and factory payload agree with the CharacterAffiliation model
+#[test]
+fn character_affiliation_matches_snapshot() {
+    assert_contract::<Vec<CharacterAffiliation>>(
+        "character_affiliation.json",
+        snapshot!("character_affiliation.json"),
+        vec![
+            factory::mock_character_affiliation(2114794365, 98785281, Some(99013534), None),
+            factory::mock_character_affiliation(95465499, 1000009, None, Some(500001)),
+        ],
+    );
+}
+
+/// Tests the `GET /corporations/{corporation_id}` contract.
+///
+/// Expected: Snapshot and factory payload agree with the Corporation model
+#[test]
+fn corporation_matches_snapshot() {
+    assert_contract::<Corporation>(
+        "corporation.json",
+        snapshot!("corporation.json"),
+        factory::mock_corporation(Some(99013534), None),
+    );
+}
+
+/// Tests the `GET /universe/factions` contract.
+///
+/// Expected: Snapshot and factory payload agree with the Faction model
+#[test]
+fn factions_match_snapshot() {
+    assert_contract::<Vec<Faction>>(
+        "factions.json",
+        snapshot!("factions.json"),
+        vec![factory::mock_faction(500001)],
+    );
+}
@@ -0,0 +1,72 @@
+//! Contract tests for external API payloads.
+//!
+//! This module checks the models Bifrost deserializes external responses into, and the mock
+//! payloads the test factories serve, against responses recorded from the real APIs in
+//! `tests/fixtures`. A failure means either the recorded API changed or the local models
+//! and factories drifted from it.
+
+mod esi;
+
+use std::collections::BTreeSet;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// Collects the names of the fields with a non-null value of every object in a payload.
+///
+/// Arrays are flattened so a list response yields the fields of all its entries.
+fn field_names(value: &Value) -> BTreeSet<String> {
+    match value {
+        Value::Array(entries) => entries.iter().flat_map(field_names).collect(),
+        Value::Object(fields) => fields
+            .iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(name, _)| name.clone())
+            .collect(),
+        _ => BTreeSet::new(),
+    }
+}
+
+/// Asserts a recorded response and a factory payload agree with the local model `T`.
+///
+/// Checks that:
+/// - the snapshot deserializes into `T`
+/// - `T` keeps every field of the snapshot, so no field ESI sends is silently dropped
+/// - the factory payload survives a JSON round trip through `T`, as served by the mock server
+/// - the factory only populates fields present in the snapshot
+///
+/// # Arguments
+/// - `name` - Snapshot name used in failure messages
+/// - `snapshot` - Recorded response body
+/// - `mock` - Payload produced by the test factory
+fn assert_contract<T: Serialize + DeserializeOwned>(name: &str, snapshot: &str, mock: T) {
+    let recorded: Value = serde_json::from_str(snapshot)
+        .unwrap_or_else(|e| panic!("Snapshot {} is not valid JSON: {}", name, e));
+    let model: T = serde_json::from_value(recorded.clone())
+        .unwrap_or_else(|e| panic!("Snapshot {} no longer deserializes: {}", name, e));
+
+    let recorded_fields = field_names(&recorded);
+    let model_fields = field_names(&serde_json::to_value(&model).unwrap());
+    let dropped: Vec<_> = recorded_fields.difference(&model_fields).collect();
+    assert!(
+        dropped.is_empty(),
+        "Model for snapshot {} drops fields {:?}",
+        name,
+        dropped
+    );
+
+    let mock = serde_json::to_value(&mock).unwrap();
+    serde_json::from_value::<T>(mock.clone())
+        .unwrap_or_else(|e| panic!("Factory payload for {} doesn't deserialize: {}", name, e));
+
+    let unknown: Vec<_> = field_names(&mock)
+        .into_iter()
+        .filter(|field| !recorded_fields.contains(field))
+        .collect();
+    assert!(
+        unknown.is_empty(),
+        "Factory payload for {} sets fields {:?} missing from the snapshot",
+        name,
+        unknown
+    );
+}
@@ -6,16 +6,31 @@
 
 use axum::{
     extract::{Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
 };
 use bifrost::server::{
     controller::auth::{callback, CallbackParams},
-    model::session::{auth::SessionAuthCsrf, user::SessionUserId},
+    model::session::{
+        auth_flow::{AuthFlow, AuthFlowState, SessionAuthFlow},
+        link_mode::SessionUserLinkMode,
+        link_queue::SessionUserLinkQueue,
+        login_intent::LoginIntent,
+        remember_me::SessionUserRememberMe,
+        user::SessionUserId,
+    },
 };
 
 use super::*;
 
+/// Creates the auth flow of a plain login using the provided CSRF state.
+fn login_flow(csrf: &str) -> AuthFlow {
+    AuthFlow {
+        csrf: csrf.to_string(),
+        ..Default::default()
+    }
+}
+
 /// Tests successful redirect after OAuth callback for new user.
 ///
 /// Verifies that when a new character logs in via EVE SSO, the callback endpoint
@@ -42,7 +57,7 @@ async fn redirects_for_new_user() -> Result<(), TestError> {
         state: "state".to_string(),
         code: "code".to_string(),
     };
-    SessionAuthCsrf::insert(&test.session, &params.state)
+    SessionAuthFlow::initiate(&test.session, login_flow(&params.state))
         .await
         .unwrap();
 
@@ -100,7 +115,7 @@ async fn redirects_for_existing_user() -> Result<(), TestError> {
         code: "code".to_string(),
     };
 
-    SessionAuthCsrf::insert(&test.session, &params.state)
+    SessionAuthFlow::initiate(&test.session, login_flow(&params.state))
         .await
         .unwrap();
     let result = callback(
@@ -146,7 +161,7 @@ async fn fails_for_invalid_csrf_state() -> Result<(), TestError> {
         state: "state".to_string(),
         code: "code".to_string(),
     };
-    SessionAuthCsrf::insert(&test.session, &params.state)
+    SessionAuthFlow::initiate(&test.session, login_flow(&params.state))
         .await
         .unwrap();
     params.state = "incorrect_state".to_string();
@@ -176,7 +191,7 @@ async fn fails_when_esi_unavailable() -> Result<(), TestError> {
         code: "code".to_string(),
     };
 
-    SessionAuthCsrf::insert(&test.session, &params.state)
+    SessionAuthFlow::initiate(&test.session, login_flow(&params.state))
         .await
         .unwrap();
     let result = callback(State(test.into_app_state()), test.session, Query(params)).await;
@@ -188,16 +203,16 @@ async fn fails_when_esi_unavailable() -> Result<(), TestError> {
     Ok(())
 }
 
-/// Tests that change_main flag from session is used during callback.
+/// Tests that the login intent from session is used during callback.
 ///
-/// Verifies that when the change_main flag is set in session (from login),
+/// Verifies that when the ChangeMain intent is set in session (from login),
 /// the callback endpoint retrieves it and uses it to update the user's main
 /// character after successful authentication.
 ///
 /// Expected: Ok with 308 PERMANENT_REDIRECT and main character updated
 #[tokio::test]
-async fn uses_change_main_flag_from_session() -> Result<(), TestError> {
-    use bifrost::server::model::session::change_main::SessionUserChangeMain;
+async fn uses_login_intent_from_session() -> Result<(), TestError> {
+    use bifrost::server::model::session::login_intent::LoginIntent;
 
     let character_id_1 = 111111111;
     let character_id_2 = 222222222;
@@ -228,11 +243,8 @@ async fn uses_change_main_flag_from_session() -> Result<(), TestError> {
         .exec(&test.db)
         .await?;
 
-    // Set up session with user logged in and change_main flag
+    // Set up session with user logged in
     SessionUserId::insert(&test.session, user.id).await.unwrap();
-    SessionUserChangeMain::insert(&test.session, true)
-        .await
-        .unwrap();
 
     let jwt_endpoints = test.auth().create_jwt_endpoints(character_id_2, owner_hash);
 
@@ -240,9 +252,16 @@ async fn uses_change_main_flag_from_session() -> Result<(), TestError> {
         state: "state".to_string(),
         code: "code".to_string(),
     };
-    SessionAuthCsrf::insert(&test.session, &params.state)
-        .await
-        .unwrap();
+    SessionAuthFlow::initiate(
+        &test.session,
+        AuthFlow {
+            csrf: params.state.clone(),
+            intent: LoginIntent::ChangeMain,
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
 
     let result = callback(
         State(test.into_app_state()),
@@ -260,9 +279,9 @@ async fn uses_change_main_flag_from_session() -> Result<(), TestError> {
     let (updated_user, _) = user_repo.get_by_id(user.id).await?.unwrap();
     assert_eq!(updated_user.main_character_id, char2.id);
 
-    // Verify change_main flag was removed from session
-    let change_main = SessionUserChangeMain::remove(&test.session).await.unwrap();
-    assert_eq!(change_main, None);
+    // Verify the auth flow was completed so the intent can't be reused
+    let flow = SessionAuthFlow::get(&test.session).await.unwrap();
+    assert!(matches!(flow, Some(AuthFlowState::Completed { .. })));
 
     for endpoint in jwt_endpoints {
         endpoint.assert();
@@ -308,7 +327,7 @@ async fn does_not_reinsert_user_id_when_already_logged_in() -> Result<(), TestEr
         state: "state".to_string(),
         code: "code".to_string(),
     };
-    SessionAuthCsrf::insert(&test.session, &params.state)
+    SessionAuthFlow::initiate(&test.session, login_flow(&params.state))
         .await
         .unwrap();
 
@@ -331,10 +350,10 @@ async fn does_not_reinsert_user_id_when_already_logged_in() -> Result<(), TestEr
     Ok(())
 }
 
-/// Tests CSRF validation failure when no token in session.
+/// Tests CSRF validation failure when no auth flow in session.
 ///
-/// Verifies that the callback endpoint rejects requests when no CSRF token
-/// was ever stored in the session, preventing unauthorized callback attempts.
+/// Verifies that the callback endpoint rejects requests when no login was ever
+/// initiated in the session, preventing unauthorized callback attempts.
 ///
 /// Expected: Err with 500 INTERNAL_SERVER_ERROR response
 #[tokio::test]
@@ -346,7 +365,7 @@ async fn fails_when_no_csrf_token_in_session() -> Result<(), TestError> {
         code: "code".to_string(),
     };
 
-    // Don't insert CSRF token in session
+    // Don't initiate an auth flow in session
 
     let result = callback(State(test.into_app_state()), test.session, Query(params)).await;
 
@@ -356,3 +375,316 @@ async fn fails_when_no_csrf_token_in_session() -> Result<(), TestError> {
 
     Ok(())
 }
+
+/// Tests that callbacks in linking mode record the linked character.
+///
+/// Verifies that while linking mode is active, the callback links the character, records
+/// it in the session, and redirects back to the linking page instead of the dashboard.
+///
+/// Expected: Ok with 307 TEMPORARY_REDIRECT and the character recorded as linked
+#[tokio::test]
+async fn records_character_in_link_mode() -> Result<(), TestError> {
+    let corporation_id = 1;
+    let character_id = 1;
+    let mock_corporation = factory::mock_corporation(None, None);
+    let mock_character = factory::mock_character(corporation_id, None, None);
+
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(corporation_id, mock_corporation, 1)
+        .with_character_endpoint(character_id, mock_character, 1)
+        .with_jwt_endpoints(character_id, "owner_hash")
+        .build()
+        .await?;
+
+    SessionUserLinkMode::start(&test.session).await.unwrap();
+    let params = CallbackParams {
+        state: "state".to_string(),
+        code: "code".to_string(),
+    };
+    SessionAuthFlow::initiate(&test.session, login_flow(&params.state))
+        .await
+        .unwrap();
+
+    let result = callback(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    let resp = result.unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::TEMPORARY_REDIRECT);
+
+    let results = SessionUserLinkMode::get(&test.session)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].linked);
+    assert_eq!(results[0].character_id, Some(character_id));
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests that failed callbacks in linking mode are recorded instead of returned.
+///
+/// Verifies that a failure to link one character does not end linking mode, so the user
+/// can continue adding their remaining characters.
+///
+/// Expected: Ok with 307 TEMPORARY_REDIRECT and a failed result recorded
+#[tokio::test]
+async fn records_failure_in_link_mode() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+
+    SessionUserLinkMode::start(&test.session).await.unwrap();
+    let params = CallbackParams {
+        state: "state".to_string(),
+        code: "code".to_string(),
+    };
+    SessionAuthFlow::initiate(&test.session, login_flow(&params.state))
+        .await
+        .unwrap();
+
+    let result = callback(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    let resp = result.unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::TEMPORARY_REDIRECT);
+
+    let results = SessionUserLinkMode::get(&test.session)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].linked);
+
+    Ok(())
+}
+
+/// Tests that callbacks with characters queued send the user back to EVE Online SSO.
+///
+/// Verifies that a failed callback in a link queue records the failure, takes the next
+/// character off the queue, and starts a login linking it with the same scopes.
+///
+/// Expected: Ok with 307 TEMPORARY_REDIRECT away from the linking page, the queue exhausted,
+/// and a new LinkAlt flow initiated
+#[tokio::test]
+async fn continues_link_queue() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+
+    SessionUserLinkMode::start(&test.session).await.unwrap();
+    SessionUserLinkQueue::insert(&test.session, 1)
+        .await
+        .unwrap();
+    let params = CallbackParams {
+        state: "state".to_string(),
+        code: "code".to_string(),
+    };
+    let scopes = vec!["esi-skills.read_skills.v1".to_string()];
+    SessionAuthFlow::initiate(
+        &test.session,
+        AuthFlow {
+            csrf: params.state.clone(),
+            intent: LoginIntent::LinkAlt,
+            scopes: scopes.clone(),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let result = callback(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    let resp = result.unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::TEMPORARY_REDIRECT);
+    assert_ne!(
+        resp.headers().get(header::LOCATION).unwrap(),
+        "/auth/link-characters"
+    );
+
+    let results = SessionUserLinkMode::get(&test.session)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].linked);
+    assert_eq!(
+        SessionUserLinkQueue::get(&test.session).await.unwrap(),
+        Some(0)
+    );
+
+    match SessionAuthFlow::get(&test.session).await.unwrap() {
+        Some(AuthFlowState::Initiated { flow, .. }) => {
+            assert_ne!(flow.csrf, "state");
+            assert_eq!(flow.intent, LoginIntent::LinkAlt);
+            assert_eq!(flow.scopes, scopes);
+        }
+        state => panic!("expected an initiated auth flow, found {:?}", state),
+    }
+
+    Ok(())
+}
+
+/// Tests that the callback redirects to the path stored by the login endpoint.
+///
+/// Verifies that the user returns to the page they originally tried to access and that the
+/// auth flow is completed so later logins default to the dashboard again.
+///
+/// Expected: Ok with 308 PERMANENT_REDIRECT to the stored path
+#[tokio::test]
+async fn redirects_to_stored_next_path() -> Result<(), TestError> {
+    let corporation_id = 1;
+    let character_id = 1;
+    let mock_corporation = factory::mock_corporation(None, None);
+    let mock_character = factory::mock_character(corporation_id, None, None);
+
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(corporation_id, mock_corporation, 1)
+        .with_character_endpoint(character_id, mock_character, 1)
+        .with_jwt_endpoints(character_id, "owner_hash")
+        .build()
+        .await?;
+
+    let params = CallbackParams {
+        state: "state".to_string(),
+        code: "code".to_string(),
+    };
+    SessionAuthFlow::initiate(
+        &test.session,
+        AuthFlow {
+            csrf: params.state.clone(),
+            redirect: Some("/auth/admin".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let result = callback(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    let resp = result.unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(resp.headers().get(header::LOCATION).unwrap(), "/auth/admin");
+
+    let flow = SessionAuthFlow::get(&test.session).await.unwrap();
+    assert!(matches!(flow, Some(AuthFlowState::Completed { .. })));
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests that a login with remember me marks the session as remembered.
+///
+/// Expected: Ok with 308 PERMANENT_REDIRECT and the session remembered
+#[tokio::test]
+async fn remembers_session_when_requested() -> Result<(), TestError> {
+    let corporation_id = 1;
+    let character_id = 1;
+    let mock_corporation = factory::mock_corporation(None, None);
+    let mock_character = factory::mock_character(corporation_id, None, None);
+
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(corporation_id, mock_corporation, 1)
+        .with_character_endpoint(character_id, mock_character, 1)
+        .with_jwt_endpoints(character_id, "owner_hash")
+        .build()
+        .await?;
+
+    let params = CallbackParams {
+        state: "state".to_string(),
+        code: "code".to_string(),
+    };
+    SessionAuthFlow::initiate(
+        &test.session,
+        AuthFlow {
+            csrf: params.state.clone(),
+            remember_me: true,
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let result = callback(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    let resp = result.unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::PERMANENT_REDIRECT);
+    assert!(SessionUserRememberMe::get(&test.session).await.unwrap());
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests that a callback can't be replayed after the login completed.
+///
+/// Verifies that a second callback with the same CSRF state is rejected once the auth flow
+/// has been completed, so a leaked callback URL can't be used to log in again.
+///
+/// Expected: Err with 400 BAD_REQUEST response for the replayed callback
+#[tokio::test]
+async fn fails_for_replayed_callback() -> Result<(), TestError> {
+    let corporation_id = 1;
+    let character_id = 1;
+    let mock_corporation = factory::mock_corporation(None, None);
+    let mock_character = factory::mock_character(corporation_id, None, None);
+
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(corporation_id, mock_corporation, 1)
+        .with_character_endpoint(character_id, mock_character, 1)
+        .with_jwt_endpoints(character_id, "owner_hash")
+        .build()
+        .await?;
+
+    let params = CallbackParams {
+        state: "state".to_string(),
+        code: "code".to_string(),
+    };
+    SessionAuthFlow::initiate(&test.session, login_flow(&params.state))
+        .await
+        .unwrap();
+
+    let state = test.into_app_state();
+    let result = callback(State(state.clone()), test.session.clone(), Query(params)).await;
+    assert!(result.is_ok());
+
+    let replayed = CallbackParams {
+        state: "state".to_string(),
+        code: "code".to_string(),
+    };
+    let result = callback(State(state), test.session.clone(), Query(replayed)).await;
+
+    assert!(result.is_err());
+    let resp = result.err().unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    test.assert_mocks();
+
+    Ok(())
+}
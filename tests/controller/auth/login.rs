@@ -9,11 +9,28 @@ use axum::{
     http::StatusCode,
     response::IntoResponse,
 };
-use bifrost::server::controller::auth::{login, LoginParams};
+use bifrost::server::{
+    controller::auth::{login, LoginIntentParam, LoginParams},
+    model::session::{
+        auth_flow::{AuthFlow, AuthFlowState, SessionAuthFlow},
+        link_mode::SessionUserLinkMode,
+        link_queue::SessionUserLinkQueue,
+        login_intent::LoginIntent,
+    },
+    util::scope_set::ScopeSets,
+};
 use bifrost_test_utils::constant::TEST_USER_AGENT;
 
 use super::*;
 
+/// Returns the flow of the login initiated in the session.
+async fn initiated_flow(session: &tower_sessions::Session) -> AuthFlow {
+    match SessionAuthFlow::get(session).await.unwrap() {
+        Some(AuthFlowState::Initiated { flow, .. }) => flow,
+        state => panic!("expected an initiated auth flow, found {:?}", state),
+    }
+}
+
 /// Tests successful redirect to EVE Online login page.
 ///
 /// Verifies that the login endpoint returns a 307 temporary redirect response
@@ -24,7 +41,14 @@ use super::*;
 async fn redirects_to_eve_login() -> Result<(), TestError> {
     let test = TestBuilder::new().build().await?;
 
-    let params = LoginParams { change_main: None };
+    let params = LoginParams {
+        intent: None,
+        scopes: None,
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: None,
+    };
     let result = login(State(test.into_app_state()), test.session, Query(params)).await;
 
     assert!(result.is_ok());
@@ -48,7 +72,14 @@ async fn fails_when_oauth2_not_configured() -> Result<(), TestError> {
     let esi_client = eve_esi::Client::new(TEST_USER_AGENT).unwrap();
     test.esi_client = esi_client;
 
-    let params = LoginParams { change_main: None };
+    let params = LoginParams {
+        intent: None,
+        scopes: None,
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: None,
+    };
     let result = login(State(test.into_app_state()), test.session, Query(params)).await;
 
     assert!(result.is_err());
@@ -58,21 +89,203 @@ async fn fails_when_oauth2_not_configured() -> Result<(), TestError> {
     Ok(())
 }
 
-/// Tests that change_main parameter sets session flag.
+/// Tests that the intent parameter is stored in the session.
+///
+/// Verifies that when the login endpoint is called with intent=change_main, the intent
+/// is stored in the session for the callback to update the user's main character.
+///
+/// Expected: Ok with 307 TEMPORARY_REDIRECT and ChangeMain intent in session
+#[tokio::test]
+async fn stores_intent_in_session() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    let params = LoginParams {
+        intent: Some(LoginIntentParam::ChangeMain),
+        scopes: None,
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: None,
+    };
+    let result = login(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
+    let resp = result.unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::TEMPORARY_REDIRECT);
+
+    let flow = initiated_flow(&test.session).await;
+    assert_eq!(flow.intent, LoginIntent::ChangeMain);
+
+    Ok(())
+}
+
+/// Tests that a plain login is stored when no intent is provided.
 ///
-/// Verifies that when the login endpoint is called with change_main=true,
-/// the flag is properly stored in the session for later use during the
-/// callback to update the user's main character.
+/// Verifies that a login without parameters stores the Login intent, replacing the intent
+/// of any earlier login that was never completed.
 ///
-/// Expected: Ok with 307 TEMPORARY_REDIRECT and change_main flag in session
+/// Expected: Ok with 307 TEMPORARY_REDIRECT and Login intent in session
 #[tokio::test]
-async fn sets_change_main_flag_in_session() -> Result<(), TestError> {
-    use bifrost::server::model::session::change_main::SessionUserChangeMain;
+async fn stores_login_intent_by_default() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    SessionAuthFlow::initiate(
+        &test.session,
+        AuthFlow {
+            csrf: "earlier".to_string(),
+            intent: LoginIntent::ChangeMain,
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let params = LoginParams {
+        intent: None,
+        scopes: None,
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: None,
+    };
+    let result = login(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
+
+    let flow = initiated_flow(&test.session).await;
+    assert_eq!(flow.intent, LoginIntent::Login);
+    assert_ne!(flow.csrf, "earlier");
+
+    Ok(())
+}
+
+/// Tests that the scopes parameter is stored with the add_scopes intent.
+///
+/// Expected: Ok with AddScopes intent containing each space-separated scope
+#[tokio::test]
+async fn stores_requested_scopes_with_add_scopes_intent() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    let params = LoginParams {
+        intent: Some(LoginIntentParam::AddScopes),
+        scopes: Some("esi-assets.read_assets.v1 esi-wallet.read_character_wallet.v1".to_string()),
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: None,
+    };
+    let result = login(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
+
+    let flow = initiated_flow(&test.session).await;
+    assert_eq!(
+        flow.intent,
+        LoginIntent::AddScopes {
+            scopes: vec![
+                "esi-assets.read_assets.v1".to_string(),
+                "esi-wallet.read_character_wallet.v1".to_string(),
+            ]
+        }
+    );
+
+    Ok(())
+}
+
+/// Tests that a configured scope set is resolved and its scopes stored in the session.
+///
+/// Verifies that a plain login naming a scope set stores the set's scopes for the callback
+/// to verify, while the intent remains a plain login.
+///
+/// Expected: Ok with the scope set's scopes in session and Login intent
+#[tokio::test]
+async fn stores_scopes_of_named_scope_set() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    let mut state = test.into_app_state();
+    state.scope_sets =
+        ScopeSets::parse("member_audit:esi-skills.read_skills.v1 esi-assets.read_assets.v1")
+            .unwrap();
+
+    let params = LoginParams {
+        intent: None,
+        scopes: Some("member_audit".to_string()),
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: None,
+    };
+    let result = login(State(state), test.session.clone(), Query(params)).await;
+
+    assert!(result.is_ok());
+
+    let flow = initiated_flow(&test.session).await;
+    assert_eq!(
+        flow.scopes,
+        vec![
+            "esi-skills.read_skills.v1".to_string(),
+            "esi-assets.read_assets.v1".to_string(),
+        ]
+    );
+
+    assert_eq!(flow.intent, LoginIntent::Login);
+
+    Ok(())
+}
+
+/// Tests that a scope set missing from the configuration is rejected.
+///
+/// Expected: Err with 400 BAD_REQUEST response
+#[tokio::test]
+async fn fails_for_unknown_scope_set() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    let params = LoginParams {
+        intent: None,
+        scopes: Some("member_audit".to_string()),
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: None,
+    };
+    let result = login(State(test.into_app_state()), test.session, Query(params)).await;
+
+    assert!(result.is_err());
+    let resp = result.err().unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
 
+    Ok(())
+}
+
+/// Tests that an allowlisted next path is stored in the session.
+///
+/// Expected: Ok with 307 TEMPORARY_REDIRECT and the path in session
+#[tokio::test]
+async fn stores_allowed_next_path_in_session() -> Result<(), TestError> {
     let test = TestBuilder::new().build().await?;
 
     let params = LoginParams {
-        change_main: Some(true),
+        intent: None,
+        scopes: None,
+        link_mode: None,
+        link_count: None,
+        next: Some("/auth/admin".to_string()),
+        remember_me: None,
     };
     let result = login(
         State(test.into_app_state()),
@@ -85,28 +298,70 @@ async fn sets_change_main_flag_in_session() -> Result<(), TestError> {
     let resp = result.unwrap().into_response();
     assert_eq!(resp.status(), StatusCode::TEMPORARY_REDIRECT);
 
-    // Verify change_main flag was set in session
-    let change_main = SessionUserChangeMain::remove(&test.session).await.unwrap();
-    assert_eq!(change_main, Some(true));
+    let flow = initiated_flow(&test.session).await;
+    assert_eq!(flow.redirect, Some("/auth/admin".to_string()));
 
     Ok(())
 }
 
-/// Tests that change_main parameter not set when false.
+/// Tests that a next path outside the allowlist is ignored.
 ///
-/// Verifies that when the login endpoint is called without change_main or
-/// with change_main=false, no session flag is set, preventing unintended
-/// main character changes.
+/// Verifies that external URLs are not stored and that the redirect of an earlier,
+/// incomplete login is cleared so it cannot leak into this one.
 ///
-/// Expected: Ok with 307 TEMPORARY_REDIRECT and no change_main flag in session
+/// Expected: Ok with 307 TEMPORARY_REDIRECT and no path in session
 #[tokio::test]
-async fn does_not_set_change_main_flag_when_false() -> Result<(), TestError> {
-    use bifrost::server::model::session::change_main::SessionUserChangeMain;
+async fn ignores_next_path_not_allowed() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    SessionAuthFlow::initiate(
+        &test.session,
+        AuthFlow {
+            csrf: "earlier".to_string(),
+            redirect: Some("/recruitment".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    let params = LoginParams {
+        intent: None,
+        scopes: None,
+        link_mode: None,
+        link_count: None,
+        next: Some("https://example.com".to_string()),
+        remember_me: None,
+    };
+    let result = login(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
 
+    let flow = initiated_flow(&test.session).await;
+    assert_eq!(flow.redirect, None);
+
+    Ok(())
+}
+
+/// Tests that the remember me choice is stored in the session for the callback.
+///
+/// Expected: Ok with 307 TEMPORARY_REDIRECT and the choice in session
+#[tokio::test]
+async fn stores_remember_me_in_session() -> Result<(), TestError> {
     let test = TestBuilder::new().build().await?;
 
     let params = LoginParams {
-        change_main: Some(false),
+        intent: None,
+        scopes: None,
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: Some(true),
     };
     let result = login(
         State(test.into_app_state()),
@@ -116,12 +371,116 @@ async fn does_not_set_change_main_flag_when_false() -> Result<(), TestError> {
     .await;
 
     assert!(result.is_ok());
+
+    let flow = initiated_flow(&test.session).await;
+    assert!(flow.remember_me);
+
+    Ok(())
+}
+
+/// Tests that a link count queues the characters to link after the first login.
+///
+/// Verifies that linking mode is started and the characters after the one logging in now
+/// are queued for the callback.
+///
+/// Expected: Ok with 307 TEMPORARY_REDIRECT, linking mode active, and two characters queued
+#[tokio::test]
+async fn queues_characters_with_link_count() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    let params = LoginParams {
+        intent: Some(LoginIntentParam::LinkAlt),
+        scopes: None,
+        link_mode: None,
+        link_count: Some(3),
+        next: None,
+        remember_me: None,
+    };
+    let result = login(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
     let resp = result.unwrap().into_response();
     assert_eq!(resp.status(), StatusCode::TEMPORARY_REDIRECT);
 
-    // Verify change_main flag was not set in session
-    let change_main = SessionUserChangeMain::remove(&test.session).await.unwrap();
-    assert_eq!(change_main, None);
+    assert!(SessionUserLinkMode::get(&test.session)
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        SessionUserLinkQueue::get(&test.session).await.unwrap(),
+        Some(2)
+    );
+
+    Ok(())
+}
+
+/// Tests that a login without a link count discards an abandoned queue.
+///
+/// Expected: Ok with 307 TEMPORARY_REDIRECT and no characters queued
+#[tokio::test]
+async fn discards_abandoned_link_queue() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    SessionUserLinkQueue::insert(&test.session, 5)
+        .await
+        .unwrap();
+    let params = LoginParams {
+        intent: Some(LoginIntentParam::LinkAlt),
+        scopes: None,
+        link_mode: None,
+        link_count: None,
+        next: None,
+        remember_me: None,
+    };
+    let result = login(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        SessionUserLinkQueue::get(&test.session).await.unwrap(),
+        None
+    );
+
+    Ok(())
+}
+
+/// Tests that linking no characters in a row is rejected.
+///
+/// Expected: Err with 400 BAD_REQUEST response and no queue in session
+#[tokio::test]
+async fn fails_for_zero_link_count() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+
+    let params = LoginParams {
+        intent: Some(LoginIntentParam::LinkAlt),
+        scopes: None,
+        link_mode: None,
+        link_count: Some(0),
+        next: None,
+        remember_me: None,
+    };
+    let result = login(
+        State(test.into_app_state()),
+        test.session.clone(),
+        Query(params),
+    )
+    .await;
+
+    assert!(result.is_err());
+    let resp = result.err().unwrap().into_response();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        SessionUserLinkQueue::get(&test.session).await.unwrap(),
+        None
+    );
 
     Ok(())
 }
@@ -0,0 +1,152 @@
+//! End-to-end tests for logging in and managing the main character.
+//!
+//! These tests log in through the mocked EVE SSO flow, load the data the dashboard shows
+//! and change the main character, verifying the session carries the user across requests.
+
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use bifrost::{
+    model::user::{UserCharacterPageDto, UserDto},
+    server::util::rate_limit::{AuthRateLimit, AuthRateLimiter},
+};
+
+use super::*;
+use crate::util::redis::RedisTest;
+
+/// Tests logging in as a new user and loading the dashboard.
+///
+/// Expected: Callback redirects to the dashboard, which then loads the user and their
+/// character
+#[tokio::test]
+async fn login_and_load_dashboard() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(1, factory::mock_corporation(None, None), 1)
+        .with_character_endpoint(1, factory::mock_character(1, None, None), 1)
+        .with_sso_login(1, "owner_hash")
+        .build()
+        .await?;
+    let mut app = TestApp::new(&test);
+
+    let callback = app.login("").await;
+    assert_eq!(callback.status, StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(callback.location(), Some("/auth"));
+
+    let user = app.get("/api/auth/user").await;
+    assert_eq!(user.status, StatusCode::OK);
+    assert_eq!(user.json::<UserDto>().character_id, 1);
+
+    let characters = app.get("/api/user/characters").await;
+    assert_eq!(characters.status, StatusCode::OK);
+    let page = characters.json::<UserCharacterPageDto>();
+    assert_eq!(page.total, 1);
+    assert!(page.characters.iter().any(|character| character.id == 1));
+
+    Ok(())
+}
+
+/// Tests changing the main character to a newly linked character.
+///
+/// Expected: User keeps their account with the second character as main
+#[tokio::test]
+async fn change_main_to_new_character() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(1, factory::mock_corporation(None, None), 1)
+        .with_character_endpoint(1, factory::mock_character(1, None, None), 1)
+        .with_character_endpoint(2, factory::mock_character(1, None, None), 1)
+        .with_sso_login(1, "owner_hash")
+        .with_sso_login(2, "owner_hash_2")
+        .build()
+        .await?;
+    let mut app = TestApp::new(&test);
+
+    app.login("").await;
+    let first_user = app.get("/api/auth/user").await.json::<UserDto>();
+
+    let callback = app.login("?intent=change_main").await;
+    assert_eq!(callback.status, StatusCode::PERMANENT_REDIRECT);
+
+    let user = app.get("/api/auth/user").await.json::<UserDto>();
+    assert_eq!(user.id, first_user.id);
+    assert_eq!(user.character_id, 2);
+
+    Ok(())
+}
+
+/// Tests that logging out ends the session.
+///
+/// Expected: User is no longer found after logout
+#[tokio::test]
+async fn logout_ends_session() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(1, factory::mock_corporation(None, None), 1)
+        .with_character_endpoint(1, factory::mock_character(1, None, None), 1)
+        .with_sso_login(1, "owner_hash")
+        .build()
+        .await?;
+    let mut app = TestApp::new(&test);
+
+    app.login("").await;
+    let logout = app.get("/api/auth/logout").await;
+    assert_eq!(logout.status, StatusCode::TEMPORARY_REDIRECT);
+
+    let user = app.get("/api/auth/user").await;
+    assert_eq!(user.status, StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+/// Tests linking more characters in a queue than the per-session rate limit allows callbacks.
+///
+/// Expected: Every queued callback is answered without 429 Too Many Requests, sending the user
+/// back to EVE SSO until the queue is exhausted and then to the linking page
+#[tokio::test]
+async fn links_queue_longer_than_session_rate_limit() -> Result<(), TestError> {
+    let redis = RedisTest::new().await?;
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(1, factory::mock_corporation(None, None), 1)
+        .with_character_endpoint(1, factory::mock_character(1, None, None), 1)
+        .with_character_endpoint(2, factory::mock_character(1, None, None), 1)
+        .with_character_endpoint(3, factory::mock_character(1, None, None), 1)
+        .with_character_endpoint(4, factory::mock_character(1, None, None), 1)
+        .with_sso_login(1, "owner_hash")
+        .with_sso_login(2, "owner_hash_2")
+        .with_sso_login(3, "owner_hash_3")
+        .with_sso_login(4, "owner_hash_4")
+        .build()
+        .await?;
+    let limits = AuthRateLimit {
+        per_ip: 0,
+        per_session: 2,
+        window: Duration::from_secs(60),
+    };
+    let mut app = TestApp::with_auth_rate_limit(
+        &test,
+        AuthRateLimiter::new(redis.redis_pool.clone(), limits),
+    );
+
+    app.login("").await;
+
+    let mut callback = app.login("?intent=link_alt&link_count=3").await;
+    for _ in 0..2 {
+        assert_eq!(callback.status, StatusCode::TEMPORARY_REDIRECT);
+        let state = callback
+            .sso_state()
+            .expect("Queued callback should redirect to EVE SSO")
+            .to_string();
+
+        callback = app
+            .get(&format!("/api/auth/callback?state={}&code=code", state))
+            .await;
+    }
+    assert_eq!(callback.status, StatusCode::TEMPORARY_REDIRECT);
+    assert_eq!(callback.location(), Some("/auth/link-characters"));
+
+    redis.cleanup().await?;
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! End-to-end tests of user flows.
+//!
+//! This module contains tests walking through flows that span several endpoints with a
+//! single session, driving the application routes the way the frontend does through
+//! `TestApp` instead of calling controllers directly.
+
+mod login;
+mod openapi;
+mod roles;
+
+use bifrost_test_utils::prelude::*;
+
+use crate::util::e2e::TestApp;
@@ -0,0 +1,53 @@
+//! End-to-end tests for the served OpenAPI document.
+//!
+//! These tests fetch the assembled OpenAPI document without logging in, verifying it
+//! describes the API routes and the shared DTO schemas clients are generated from.
+
+use axum::http::StatusCode;
+use serde_json::Value;
+
+use super::*;
+
+/// Tests that the OpenAPI document is served with routes and schemas registered.
+///
+/// Expected: Document lists the user route and the error and user DTO schemas
+#[tokio::test]
+async fn serves_assembled_document() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+    let mut app = TestApp::new(&test);
+
+    let response = app.get("/api/openapi.json").await;
+    assert_eq!(response.status, StatusCode::OK);
+
+    let document: Value = response.json();
+    assert!(document["paths"]["/api/auth/user"].is_object());
+    for schema in [
+        "ErrorDto",
+        "ProblemDto",
+        "ValidationErrorDto",
+        "UserDto",
+        "UserSessionDto",
+    ] {
+        assert!(
+            document["components"]["schemas"][schema].is_object(),
+            "{} should be registered",
+            schema
+        );
+    }
+
+    Ok(())
+}
+
+/// Tests that Swagger UI isn't served while disabled.
+///
+/// Expected: Swagger UI returns 404 while the document is still served
+#[tokio::test]
+async fn omits_swagger_ui_when_disabled() -> Result<(), TestError> {
+    let test = TestBuilder::new().build().await?;
+    let mut app = TestApp::new(&test);
+
+    assert_eq!(app.get("/api/docs/").await.status, StatusCode::NOT_FOUND);
+    assert_eq!(app.get("/api/openapi.json").await.status, StatusCode::OK);
+
+    Ok(())
+}
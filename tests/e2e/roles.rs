@@ -0,0 +1,121 @@
+//! End-to-end tests for permission checks on admin endpoints.
+//!
+//! These tests log in through the mocked EVE SSO flow and call admin and content endpoints,
+//! verifying the permission middleware lets admins through and rejects users without the
+//! permission.
+
+use axum::http::{Method, StatusCode};
+use bifrost::{
+    model::role::{Permission, RoleDto},
+    server::data::role::RoleRepository,
+};
+
+use super::*;
+
+/// Tests that the first user to log in can manage roles.
+///
+/// Expected: First user is made an admin and can list roles
+#[tokio::test]
+async fn first_user_can_manage_roles() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(1, factory::mock_corporation(None, None), 1)
+        .with_character_endpoint(1, factory::mock_character(1, None, None), 1)
+        .with_sso_login(1, "owner_hash")
+        .build()
+        .await?;
+    RoleRepository::new(&test.db)
+        .create(
+            "admin".to_string(),
+            String::new(),
+            "admin".to_string(),
+            true,
+        )
+        .await?;
+    let mut app = TestApp::new(&test);
+
+    app.login("").await;
+
+    let permissions = app.get("/api/user/permissions").await;
+    assert_eq!(permissions.status, StatusCode::OK);
+    assert_eq!(
+        permissions.json::<Vec<Permission>>(),
+        Permission::ALL.to_vec()
+    );
+
+    let roles = app.get("/api/admin/roles").await;
+    assert_eq!(roles.status, StatusCode::OK);
+    assert_eq!(roles.json::<Vec<RoleDto>>()[0].user_count, 1);
+
+    Ok(())
+}
+
+/// Tests that users without the permission are rejected.
+///
+/// Expected: 403 Forbidden for the admin endpoint
+#[tokio::test]
+async fn rejects_user_without_permission() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(1, factory::mock_corporation(None, None), 1)
+        .with_character_endpoint(1, factory::mock_character(1, None, None), 1)
+        .with_sso_login(1, "owner_hash")
+        .build()
+        .await?;
+    let mut app = TestApp::new(&test);
+
+    app.login("").await;
+
+    let roles = app.get("/api/admin/roles").await;
+    assert_eq!(roles.status, StatusCode::FORBIDDEN);
+
+    Ok(())
+}
+
+/// Tests that users without the content permission can't change shared content.
+///
+/// Expected: 403 Forbidden for deleting fittings, doctrines, skill plans, and campaigns
+#[tokio::test]
+async fn rejects_content_changes_without_permission() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_corporation_endpoint(1, factory::mock_corporation(None, None), 1)
+        .with_character_endpoint(1, factory::mock_character(1, None, None), 1)
+        .with_sso_login(1, "owner_hash")
+        .build()
+        .await?;
+    let mut app = TestApp::new(&test);
+
+    app.login("").await;
+
+    for uri in [
+        "/api/fittings/1",
+        "/api/doctrines/1",
+        "/api/doctrines/1/fittings/1",
+        "/api/skill-plans/1",
+        "/api/campaigns/1",
+    ] {
+        let response = app.request(Method::DELETE, uri, None).await;
+        assert_eq!(response.status, StatusCode::FORBIDDEN, "{}", uri);
+    }
+    let response = app
+        .request(Method::POST, "/api/doctrines", Some(serde_json::json!({})))
+        .await;
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+
+    Ok(())
+}
+
+/// Tests that admin endpoints require a logged in user.
+///
+/// Expected: 404 Not Found as no user is in the session
+#[tokio::test]
+async fn rejects_anonymous_request() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+    let mut app = TestApp::new(&test);
+
+    let roles = app.get("/api/admin/roles").await;
+    assert_eq!(roles.status, StatusCode::NOT_FOUND);
+
+    Ok(())
+}
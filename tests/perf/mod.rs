@@ -0,0 +1,156 @@
+//! Load and performance tests for the worker pipeline.
+//!
+//! These tests enqueue large numbers of synthetic jobs against a local Redis and measure
+//! queue script latency, dispatch throughput, and semaphore contention of the worker pool.
+//! They only assert that every job was processed; the measurements are printed and written
+//! as a JSON report to `{CARGO_TARGET_TMPDIR}/perf` so queue redesigns can be compared
+//! against a baseline.
+//!
+//! Run with `cargo test --features perf-test perf -- --nocapture --test-threads 1`. Set
+//! `BIFROST_PERF_JOBS` to change the number of jobs enqueued per test (default 20000).
+
+mod pool;
+mod queue;
+
+use std::time::Duration;
+
+use bifrost::{
+    model::push::PushNotificationDto,
+    server::{
+        model::worker::WorkerJob,
+        worker::{queue::config::WorkerQueueConfig, WorkerQueue},
+    },
+};
+use serde::Serialize;
+
+use crate::util::redis::RedisTest;
+
+/// Default number of jobs enqueued per test.
+const DEFAULT_JOB_COUNT: usize = 20_000;
+
+/// Number of jobs to enqueue per test, read from `BIFROST_PERF_JOBS`.
+pub fn job_count() -> usize {
+    std::env::var("BIFROST_PERF_JOBS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_JOB_COUNT)
+}
+
+/// Creates a queue with a job TTL long enough that cleanup never removes pending jobs.
+pub fn setup_perf_queue(redis: &RedisTest) -> WorkerQueue {
+    let config = WorkerQueueConfig {
+        queue_name: redis.queue_name(),
+        job_ttl: Duration::from_secs(3600),
+        status_ttl: Duration::from_secs(86400),
+        cleanup_interval: Duration::from_secs(3600),
+    };
+
+    WorkerQueue::with_config(redis.redis_pool.clone(), config)
+}
+
+/// Creates a unique job that the handler completes without touching ESI or the database.
+///
+/// Push notifications are disabled in tests, so the handler returns immediately and the
+/// measurements only reflect queue and dispatch overhead.
+pub fn synthetic_job(index: usize) -> WorkerJob {
+    WorkerJob::SendPushNotification {
+        user_id: index as i32,
+        notification: PushNotificationDto {
+            title: "Load test".to_string(),
+            body: "Synthetic job".to_string(),
+            url: None,
+        },
+    }
+}
+
+/// Latency distribution of repeated operations in microseconds.
+#[derive(Serialize)]
+pub struct LatencySummary {
+    pub p50_us: u128,
+    pub p95_us: u128,
+    pub p99_us: u128,
+    pub max_us: u128,
+}
+
+impl LatencySummary {
+    /// Summarizes recorded latencies, returning `None` if nothing was recorded.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+
+        let percentile = |p: usize| samples[(samples.len() - 1) * p / 100].as_micros();
+
+        Some(Self {
+            p50_us: percentile(50),
+            p95_us: percentile(95),
+            p99_us: percentile(99),
+            max_us: samples[samples.len() - 1].as_micros(),
+        })
+    }
+}
+
+/// Result of a single measurement.
+#[derive(Serialize)]
+pub struct Measurement {
+    pub name: String,
+    pub operations: usize,
+    pub elapsed_ms: u128,
+    pub throughput_per_sec: f64,
+    pub latency: Option<LatencySummary>,
+    /// Measurement specific values, such as semaphore saturation.
+    pub extra: Vec<(String, f64)>,
+}
+
+impl Measurement {
+    pub fn new(name: &str, operations: usize, elapsed: Duration) -> Self {
+        Self {
+            name: name.to_string(),
+            operations,
+            elapsed_ms: elapsed.as_millis(),
+            throughput_per_sec: operations as f64 / elapsed.as_secs_f64(),
+            latency: None,
+            extra: Vec::new(),
+        }
+    }
+
+    pub fn with_latency(mut self, samples: Vec<Duration>) -> Self {
+        self.latency = LatencySummary::from_samples(samples);
+        self
+    }
+
+    pub fn with_extra(mut self, name: &str, value: f64) -> Self {
+        self.extra.push((name.to_string(), value));
+        self
+    }
+}
+
+/// Prints measurements and writes them as JSON to `{CARGO_TARGET_TMPDIR}/perf/{report}.json`.
+pub fn write_report(report: &str, measurements: &[Measurement]) {
+    for measurement in measurements {
+        print!(
+            "[perf] {}: {} ops in {} ms ({:.0} ops/s)",
+            measurement.name,
+            measurement.operations,
+            measurement.elapsed_ms,
+            measurement.throughput_per_sec
+        );
+        if let Some(latency) = &measurement.latency {
+            print!(
+                ", p50 {} us, p95 {} us, p99 {} us, max {} us",
+                latency.p50_us, latency.p95_us, latency.p99_us, latency.max_us
+            );
+        }
+        for (name, value) in &measurement.extra {
+            print!(", {} {:.3}", name, value);
+        }
+        println!();
+    }
+
+    let dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("perf");
+    std::fs::create_dir_all(&dir).expect("Failed to create perf report directory");
+    let json = serde_json::to_string_pretty(measurements).expect("Failed to serialize report");
+    std::fs::write(dir.join(format!("{}.json", report)), json)
+        .expect("Failed to write perf report");
+}
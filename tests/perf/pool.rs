@@ -0,0 +1,102 @@
+//! Dispatch throughput and semaphore contention of the worker pool.
+
+use std::time::{Duration, Instant};
+
+use bifrost::server::{
+    service::eve::esi::EsiProvider,
+    worker::{
+        handler::WorkerJobHandler,
+        pool::{WorkerPool, WorkerPoolConfig},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+use super::*;
+
+/// How often the pool's permits are sampled while jobs are processed.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Maximum time to wait for the pool to drain the queue.
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Processes a full queue with a pool of the given size and measures it.
+async fn measure_dispatch(max_concurrent_jobs: usize) -> Measurement {
+    let test = TestBuilder::new()
+        .build()
+        .await
+        .expect("Failed to create test setup");
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let queue = setup_perf_queue(&redis);
+    let jobs = job_count();
+
+    for index in 0..jobs {
+        queue
+            .push(synthetic_job(index))
+            .await
+            .expect("Failed to push job");
+    }
+
+    let handler = WorkerJobHandler::new(
+        test.db.clone(),
+        EsiProvider::new(test.esi_client.clone()),
+        queue.clone(),
+        false,
+    );
+    let mut config = WorkerPoolConfig::new(max_concurrent_jobs);
+    config.poll_interval_ms = 1;
+    let dispatchers = config.dispatcher_count;
+    let pool = WorkerPool::new(config, queue.clone(), handler);
+
+    let started = Instant::now();
+    pool.start().await.expect("Failed to start pool");
+
+    // Sample how often every permit is taken, meaning dispatchers wait on the semaphore
+    let mut samples = 0usize;
+    let mut saturated = 0usize;
+    loop {
+        let remaining = queue.len().await.expect("Failed to get queue length");
+        if remaining == 0 && pool.active_job_count() == 0 {
+            break;
+        }
+        assert!(
+            started.elapsed() < DRAIN_TIMEOUT,
+            "Pool did not drain the queue in time, {} jobs remaining",
+            remaining
+        );
+
+        samples += 1;
+        if pool.available_permits() == 0 {
+            saturated += 1;
+        }
+        tokio::time::sleep(SAMPLE_INTERVAL).await;
+    }
+    let elapsed = started.elapsed();
+
+    pool.stop().await.expect("Failed to stop pool");
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+
+    Measurement::new(
+        &format!("pool_dispatch_{}", max_concurrent_jobs),
+        jobs,
+        elapsed,
+    )
+    .with_extra("dispatchers", dispatchers as f64)
+    .with_extra(
+        "semaphore_saturation",
+        saturated as f64 / samples.max(1) as f64,
+    )
+}
+
+/// Measures dispatch throughput for several pool sizes.
+///
+/// Expected: Every job is processed for each pool size
+#[tokio::test]
+async fn dispatch_throughput() {
+    let mut measurements = Vec::new();
+
+    for max_concurrent_jobs in [4, 40, 160] {
+        measurements.push(measure_dispatch(max_concurrent_jobs).await);
+    }
+
+    write_report("pool", &measurements);
+}
@@ -0,0 +1,65 @@
+//! Throughput and Lua script latency of the worker queue.
+
+use std::time::Instant;
+
+use futures::{stream, StreamExt};
+
+use super::*;
+
+/// Number of concurrent producers or consumers, similar to the dispatchers of a large pool.
+const CONCURRENCY: usize = 16;
+
+/// Measures pushing and popping jobs through the queue's Lua scripts.
+///
+/// Expected: Every pushed job is popped again and the queue is empty
+#[tokio::test]
+async fn push_and_pop_throughput() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let queue = setup_perf_queue(&redis);
+    let jobs = job_count();
+
+    let started = Instant::now();
+    let push_latencies: Vec<_> = stream::iter(0..jobs)
+        .map(|index| {
+            let queue = queue.clone();
+            async move {
+                let started = Instant::now();
+                let added = queue
+                    .push(synthetic_job(index))
+                    .await
+                    .expect("Failed to push job");
+                assert!(added, "Synthetic jobs should be unique");
+                started.elapsed()
+            }
+        })
+        .buffer_unordered(CONCURRENCY)
+        .collect()
+        .await;
+    let push = Measurement::new("queue_push", jobs, started.elapsed()).with_latency(push_latencies);
+
+    assert_eq!(queue.len().await.expect("Failed to get queue length"), jobs);
+
+    let started = Instant::now();
+    let pop_latencies: Vec<_> = stream::iter(0..jobs)
+        .map(|_| {
+            let queue = queue.clone();
+            async move {
+                let started = Instant::now();
+                queue
+                    .pop()
+                    .await
+                    .expect("Failed to pop job")
+                    .expect("Queue should contain a due job");
+                started.elapsed()
+            }
+        })
+        .buffer_unordered(CONCURRENCY)
+        .collect()
+        .await;
+    let pop = Measurement::new("queue_pop", jobs, started.elapsed()).with_latency(pop_latencies);
+
+    assert!(queue.is_empty().await.expect("Failed to check queue"));
+
+    write_report("queue", &[push, pop]);
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
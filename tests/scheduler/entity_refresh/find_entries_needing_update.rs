@@ -2,10 +2,11 @@
 //!
 //! This module verifies the behavior of finding entries that need cache refresh based on
 //! their updated_at timestamps. Tests cover empty tables, fresh cache detection, expired
-//! entry identification, ordering by age, batch limits, and error handling.
+//! entry identification, ordering by age, batch limits, configured batch sizes, and error
+//! handling.
 
 use super::*;
-use bifrost::server::scheduler::SchedulerState;
+use bifrost::server::scheduler::{config::SchedulerSettings, SchedulerState};
 
 /// Tests finding entries when database table is empty.
 ///
@@ -28,6 +29,7 @@ async fn returns_empty_when_no_entries() -> Result<(), TestError> {
         db: test.db.clone(),
         queue,
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -67,6 +69,7 @@ async fn returns_empty_when_all_up_to_date() -> Result<(), TestError> {
         db: test.db.clone(),
         queue,
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -118,6 +121,7 @@ async fn returns_entries_with_expired_cache() -> Result<(), TestError> {
         db: test.db.clone(),
         queue,
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -184,6 +188,7 @@ async fn returns_oldest_updated_first() -> Result<(), TestError> {
         db: test.db.clone(),
         queue,
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -243,6 +248,7 @@ async fn respects_batch_limit() -> Result<(), TestError> {
         db: test.db.clone(),
         queue,
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -261,6 +267,61 @@ async fn respects_batch_limit() -> Result<(), TestError> {
     Ok(())
 }
 
+/// Tests capping the batch at the configured maximum batch size.
+///
+/// Verifies that `max_batch_size` in the scheduler settings limits the number of
+/// entries returned even though the minimum batch size would allow all of them.
+///
+/// Expected: Ok with Vec containing the 3 oldest alliance_ids
+#[tokio::test]
+async fn respects_max_batch_size_setting() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .build()
+        .await?;
+
+    // Create 5 alliances with expired cache, older alliance IDs expired longer ago
+    for i in 1..=5 {
+        let alliance = test.eve().insert_mock_alliance(i, None).await?;
+        EveAlliance::update_many()
+            .col_expr(
+                entity::eve_alliance::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc() - Duration::hours(30 - i as i64)),
+            )
+            .filter(entity::eve_alliance::Column::Id.eq(alliance.id))
+            .exec(&test.db)
+            .await?;
+    }
+
+    let redis = RedisTest::new().await?;
+    let queue = setup_test_queue(&redis);
+
+    let state = SchedulerState {
+        db: test.db.clone(),
+        queue,
+        offset_for_esi_downtime: false,
+        settings: SchedulerSettings {
+            max_batch_size: Some(3),
+            ..SchedulerSettings::default()
+        },
+    };
+
+    let tracker = EntityRefreshTracker::new(
+        &state,
+        alliance_config::CACHE_DURATION,
+        alliance_config::SCHEDULE_INTERVAL,
+    );
+
+    let result = tracker.find_entries_needing_update::<AllianceInfo>().await;
+
+    assert!(result.is_ok());
+    let ids = result.unwrap();
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    Ok(())
+}
+
 /// Tests finding a single expired entry.
 ///
 /// Verifies that the entity refresh tracker correctly handles the case where
@@ -293,6 +354,7 @@ async fn handles_single_entry() -> Result<(), TestError> {
         db: test.db.clone(),
         queue,
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -328,6 +390,7 @@ async fn fails_when_tables_missing() -> Result<(), TestError> {
         db: test.db.clone(),
         queue,
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
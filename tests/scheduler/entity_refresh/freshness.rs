@@ -0,0 +1,96 @@
+//! Tests for EntityRefreshTracker::freshness method.
+//!
+//! This module verifies the refresh age distribution reported for an entity type, covering
+//! empty tables, overdue counts, and nearest-rank percentiles.
+
+use super::*;
+use bifrost::server::scheduler::{
+    config::SchedulerSettings, entity_refresh::EntityFreshness, SchedulerState,
+};
+
+/// Tests reporting freshness of an empty table.
+///
+/// Expected: Ok with zero counts and no ages
+#[tokio::test]
+async fn returns_empty_freshness_when_no_entries() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .build()
+        .await?;
+
+    let redis = RedisTest::new().await?;
+    let queue = setup_test_queue(&redis);
+
+    let state = SchedulerState {
+        db: test.db.clone(),
+        queue,
+        offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
+    };
+
+    let tracker = EntityRefreshTracker::new(
+        &state,
+        alliance_config::CACHE_DURATION,
+        alliance_config::SCHEDULE_INTERVAL,
+    );
+
+    let freshness = tracker.freshness::<AllianceInfo>().await.unwrap();
+
+    assert_eq!(freshness, EntityFreshness::default());
+
+    Ok(())
+}
+
+/// Tests reporting the age distribution of refreshed entries.
+///
+/// Verifies that entries past the cache duration are counted as overdue and that the
+/// percentiles pick the nearest-rank entry ordered from most to least recently refreshed.
+///
+/// Expected: 4 entries, 1 overdue, p50 of 2 hours, p95 and max of 25 hours
+#[tokio::test]
+async fn reports_age_percentiles_and_overdue_entries() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .build()
+        .await?;
+
+    for (alliance_id, hours) in [(1, 1), (2, 2), (3, 3), (4, 25)] {
+        let alliance = test.eve().insert_mock_alliance(alliance_id, None).await?;
+        EveAlliance::update_many()
+            .col_expr(
+                entity::eve_alliance::Column::UpdatedAt,
+                Expr::value(Utc::now().naive_utc() - Duration::hours(hours)),
+            )
+            .filter(entity::eve_alliance::Column::Id.eq(alliance.id))
+            .exec(&test.db)
+            .await?;
+    }
+
+    let redis = RedisTest::new().await?;
+    let queue = setup_test_queue(&redis);
+
+    let state = SchedulerState {
+        db: test.db.clone(),
+        queue,
+        offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
+    };
+
+    let tracker = EntityRefreshTracker::new(
+        &state,
+        alliance_config::CACHE_DURATION,
+        alliance_config::SCHEDULE_INTERVAL,
+    );
+
+    let freshness = tracker.freshness::<AllianceInfo>().await.unwrap();
+
+    assert_eq!(freshness.count, 4);
+    assert_eq!(freshness.overdue, 1);
+    assert_eq!(freshness.p50_age.map(|age| age.num_hours()), Some(2));
+    assert_eq!(freshness.p95_age.map(|age| age.num_hours()), Some(25));
+    assert_eq!(freshness.max_age.map(|age| age.num_hours()), Some(25));
+
+    Ok(())
+}
@@ -2,7 +2,8 @@
 //!
 //! This module verifies the entity refresh tracker behavior, including finding
 //! entries that need updating based on cache expiration, scheduling jobs with
-//! staggered execution times, batch limiting, and handling empty tables.
+//! staggered execution times, batch limiting, handling empty tables, and reporting the
+//! freshness of cached entries.
 
 use bifrost::server::scheduler::{
     config::eve::alliance as alliance_config,
@@ -32,4 +33,5 @@ impl SchedulableEntity for AllianceInfo {
 }
 
 mod find_entries_needing_update;
+mod freshness;
 mod schedule_jobs;
@@ -5,7 +5,7 @@
 //! job lists, duplicate detection, and large batch handling.
 
 use bifrost::server::model::worker::WorkerJob;
-use bifrost::server::scheduler::SchedulerState;
+use bifrost::server::scheduler::{config::SchedulerSettings, SchedulerState};
 
 use super::*;
 
@@ -29,6 +29,7 @@ async fn schedules_single_job() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -70,6 +71,7 @@ async fn schedules_multiple_jobs() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -119,6 +121,7 @@ async fn returns_zero_for_empty_jobs() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -159,6 +162,7 @@ async fn handles_duplicate_jobs() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -207,6 +211,7 @@ async fn schedules_many_jobs() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let tracker = EntityRefreshTracker::new(
@@ -1,11 +1,13 @@
 //! Tests for schedule_character_affiliation_update scheduler.
 //!
 //! This module verifies the scheduler correctly identifies characters with expired
-//! affiliation cache, batches character IDs according to ESI limits, and handles
-//! edge cases like empty tables and duplicate scheduling attempts.
+//! affiliation cache, batches character IDs according to ESI limits, schedules a single shard
+//! when sharding is enabled, and handles edge cases like empty tables and duplicate scheduling
+//! attempts.
 
+use bifrost::server::model::worker::WorkerJob;
 use bifrost::server::scheduler::eve::affiliation::schedule_character_affiliation_update;
-use bifrost::server::scheduler::SchedulerState;
+use bifrost::server::scheduler::{config::SchedulerSettings, SchedulerState};
 use bifrost_test_utils::prelude::*;
 use chrono::{Duration, Utc};
 use entity::prelude::EveCharacter;
@@ -31,6 +33,7 @@ async fn returns_zero_when_no_characters() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -74,6 +77,7 @@ async fn returns_zero_when_all_characters_up_to_date() -> Result<(), TestError>
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -122,6 +126,7 @@ async fn schedules_single_expired_character_affiliation() -> Result<(), TestErro
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -172,6 +177,7 @@ async fn schedules_multiple_expired_character_affiliations() -> Result<(), TestE
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -231,6 +237,7 @@ async fn schedules_only_expired_character_affiliations() -> Result<(), TestError
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -310,6 +317,7 @@ async fn schedules_oldest_affiliations_first() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -358,6 +366,7 @@ async fn handles_duplicate_scheduling_attempts() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     // Schedule first time
@@ -398,6 +407,7 @@ async fn fails_when_tables_missing() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -445,6 +455,7 @@ async fn batches_characters_when_over_esi_limit() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -494,6 +505,7 @@ async fn batches_exactly_at_esi_limit() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -542,6 +554,7 @@ async fn batches_just_over_esi_limit() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -591,6 +604,7 @@ async fn schedules_many_characters() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_affiliation_update(state).await;
@@ -605,3 +619,62 @@ async fn schedules_many_characters() -> Result<(), TestError> {
     redis.cleanup().await?;
     Ok(())
 }
+
+/// Tests scheduling a single shard of characters when affiliation updates are sharded.
+///
+/// Verifies that with characters partitioned by `character_id % shards`, the scheduler only
+/// schedules the expired characters sharing a shard with the most overdue character.
+///
+/// Expected: Ok(1) and one batch job containing only the characters of the overdue shard
+#[tokio::test]
+async fn schedules_shard_of_most_overdue_character() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let redis = RedisTest::new().await?;
+    let queue = setup_test_queue(&redis);
+
+    let corporation = test.eve().insert_mock_corporation(1, None, None).await?;
+
+    // Character 3 is the most overdue, so shard 1 of 2 (characters 1, 3, and 5) is scheduled
+    for i in 1..=6 {
+        let character = test
+            .eve()
+            .insert_mock_character(i, corporation.corporation_id, None, None)
+            .await?;
+        let minutes = if i == 3 { 300 } else { 61 };
+        EveCharacter::update_many()
+            .col_expr(
+                entity::eve_character::Column::AffiliationUpdatedAt,
+                Expr::value(Utc::now().naive_utc() - Duration::minutes(minutes)),
+            )
+            .filter(entity::eve_character::Column::Id.eq(character.id))
+            .exec(&test.db)
+            .await?;
+    }
+
+    let state = SchedulerState {
+        db: test.db.clone(),
+        queue: queue.clone(),
+        offset_for_esi_downtime: false,
+        settings: SchedulerSettings {
+            affiliation_shards: 2,
+            ..SchedulerSettings::default()
+        },
+    };
+
+    let result = schedule_character_affiliation_update(state).await;
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 1);
+
+    let page = queue.list(.., 0, 10).await.unwrap();
+    assert_eq!(page.jobs.len(), 1);
+    match &page.jobs[0].job {
+        WorkerJob::UpdateAffiliations { character_ids } => {
+            assert_eq!(character_ids, &vec![3, 1, 5]);
+        }
+        job => panic!("Expected an affiliation job, got {}", job),
+    }
+
+    redis.cleanup().await?;
+    Ok(())
+}
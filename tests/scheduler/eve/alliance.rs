@@ -4,8 +4,10 @@
 //! cache, prioritizes oldest entries first, and handles edge cases like empty tables,
 //! duplicate scheduling attempts, and large batch processing.
 
+use bifrost::model::worker::WorkerJobState;
+use bifrost::server::data::job_history::JobHistoryRepository;
 use bifrost::server::scheduler::eve::alliance::schedule_alliance_info_update;
-use bifrost::server::scheduler::SchedulerState;
+use bifrost::server::scheduler::{config::SchedulerSettings, SchedulerState};
 use bifrost_test_utils::prelude::*;
 use chrono::{Duration, Utc};
 use entity::prelude::EveAlliance;
@@ -35,6 +37,7 @@ async fn returns_zero_when_no_alliances() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_alliance_info_update(state).await;
@@ -74,6 +77,7 @@ async fn returns_zero_when_all_alliances_up_to_date() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_alliance_info_update(state).await;
@@ -122,6 +126,7 @@ async fn schedules_single_expired_alliance() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_alliance_info_update(state).await;
@@ -171,6 +176,7 @@ async fn schedules_multiple_expired_alliances() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_alliance_info_update(state).await;
@@ -224,6 +230,7 @@ async fn schedules_only_expired_alliances() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_alliance_info_update(state).await;
@@ -239,6 +246,66 @@ async fn schedules_only_expired_alliances() -> Result<(), TestError> {
     Ok(())
 }
 
+/// Tests skipping alliances whose last refresh failed permanently.
+///
+/// Verifies that the alliance scheduler holds off alliances with a failed
+/// `UpdateAllianceInfo` job recorded in the worker job history within the cache
+/// duration, while still scheduling other expired alliances.
+///
+/// Expected: Ok(1) and one job in queue (failed alliance skipped)
+#[tokio::test]
+async fn skips_alliances_with_recent_permanent_failure() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::BifrostWorkerJobHistory)
+        .build()
+        .await?;
+    let redis = RedisTest::new().await?;
+    let queue = setup_test_queue(&redis);
+
+    let old_timestamp = Utc::now().naive_utc() - Duration::hours(25);
+    for i in 1..=2 {
+        let alliance = test.eve().insert_mock_alliance(i, None).await?;
+        EveAlliance::update_many()
+            .col_expr(
+                entity::eve_alliance::Column::UpdatedAt,
+                Expr::value(old_timestamp),
+            )
+            .filter(entity::eve_alliance::Column::Id.eq(alliance.id))
+            .exec(&test.db)
+            .await?;
+    }
+
+    JobHistoryRepository::new(&test.db)
+        .record(
+            "UpdateAllianceInfo",
+            Some(1),
+            WorkerJobState::Failed,
+            Some("Alliance not found".to_string()),
+            20,
+        )
+        .await?;
+
+    let state = SchedulerState {
+        db: test.db.clone(),
+        queue: queue.clone(),
+        offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
+    };
+
+    let result = schedule_alliance_info_update(state).await;
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 1);
+
+    // Verify only the alliance without a failure is in the queue
+    assert_eq!(queue.len().await.unwrap(), 1);
+
+    redis.cleanup().await?;
+    Ok(())
+}
+
 /// Tests that oldest alliances are prioritized for scheduling.
 ///
 /// Verifies that the alliance scheduler processes alliances in order of
@@ -287,6 +354,7 @@ async fn schedules_oldest_alliances_first() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_alliance_info_update(state).await;
@@ -334,6 +402,7 @@ async fn handles_duplicate_scheduling_attempts() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     // Schedule first time
@@ -374,6 +443,7 @@ async fn fails_when_tables_missing() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_alliance_info_update(state).await;
@@ -419,6 +489,7 @@ async fn schedules_many_alliances() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_alliance_info_update(state).await;
@@ -5,7 +5,7 @@
 //! duplicate scheduling attempts, and large batch processing.
 
 use bifrost::server::scheduler::eve::character::schedule_character_info_update;
-use bifrost::server::scheduler::SchedulerState;
+use bifrost::server::scheduler::{config::SchedulerSettings, SchedulerState};
 use bifrost_test_utils::prelude::*;
 use chrono::{Duration, Utc};
 use entity::prelude::EveCharacter;
@@ -31,6 +31,7 @@ async fn returns_zero_when_no_characters() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_info_update(state).await;
@@ -74,6 +75,7 @@ async fn returns_zero_when_all_characters_up_to_date() -> Result<(), TestError>
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_info_update(state).await;
@@ -122,6 +124,7 @@ async fn schedules_single_expired_character() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_info_update(state).await;
@@ -172,6 +175,7 @@ async fn schedules_multiple_expired_characters() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_info_update(state).await;
@@ -230,6 +234,7 @@ async fn schedules_only_expired_characters() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_info_update(state).await;
@@ -309,6 +314,7 @@ async fn schedules_oldest_characters_first() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_info_update(state).await;
@@ -356,6 +362,7 @@ async fn handles_duplicate_scheduling_attempts() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     // Schedule first time
@@ -396,6 +403,7 @@ async fn fails_when_tables_missing() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_info_update(state).await;
@@ -442,6 +450,7 @@ async fn schedules_many_characters() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_character_info_update(state).await;
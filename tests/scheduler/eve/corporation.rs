@@ -5,7 +5,7 @@
 //! duplicate scheduling attempts, and large batch processing.
 
 use bifrost::server::scheduler::eve::corporation::schedule_corporation_info_update;
-use bifrost::server::scheduler::SchedulerState;
+use bifrost::server::scheduler::{config::SchedulerSettings, SchedulerState};
 use bifrost_test_utils::prelude::*;
 use chrono::{Duration, Utc};
 use entity::prelude::EveCorporation;
@@ -31,6 +31,7 @@ async fn returns_zero_when_no_corporations() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_corporation_info_update(state).await;
@@ -66,6 +67,7 @@ async fn returns_zero_when_all_corporations_up_to_date() -> Result<(), TestError
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_corporation_info_update(state).await;
@@ -110,6 +112,7 @@ async fn schedules_single_expired_corporation() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_corporation_info_update(state).await;
@@ -155,6 +158,7 @@ async fn schedules_multiple_expired_corporations() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_corporation_info_update(state).await;
@@ -204,6 +208,7 @@ async fn schedules_only_expired_corporations() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_corporation_info_update(state).await;
@@ -272,6 +277,7 @@ async fn schedules_oldest_corporations_first() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_corporation_info_update(state).await;
@@ -315,6 +321,7 @@ async fn handles_duplicate_scheduling_attempts() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     // Schedule first time
@@ -355,6 +362,7 @@ async fn fails_when_tables_missing() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_corporation_info_update(state).await;
@@ -396,6 +404,7 @@ async fn schedules_many_corporations() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_corporation_info_update(state).await;
@@ -6,8 +6,10 @@
 //! one job since there are only a small, fixed number of NPC factions in EVE Online.
 
 use bifrost::server::{
-    model::worker::WorkerJob, scheduler::eve::faction::schedule_faction_info_update,
-    scheduler::SchedulerState,
+    model::worker::WorkerJob,
+    scheduler::{
+        config::SchedulerSettings, eve::faction::schedule_faction_info_update, SchedulerState,
+    },
 };
 use bifrost_test_utils::prelude::*;
 
@@ -31,6 +33,7 @@ async fn schedules_faction_update_job() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_faction_info_update(state).await;
@@ -71,6 +74,7 @@ async fn schedules_without_faction_table() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_faction_info_update(state).await;
@@ -111,6 +115,7 @@ async fn schedules_with_existing_factions() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_faction_info_update(state).await;
@@ -151,6 +156,7 @@ async fn handles_duplicate_scheduling_attempts() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     // Schedule first time
@@ -192,6 +198,7 @@ async fn schedules_multiple_times_after_queue_clear() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     // Schedule first time
@@ -211,6 +218,7 @@ async fn schedules_multiple_times_after_queue_clear() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue2.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
     let result2 = schedule_faction_info_update(state2).await;
     assert!(result2.is_ok());
@@ -241,6 +249,7 @@ async fn schedules_with_multiple_concurrent_calls() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let handle1 = tokio::spawn(async move { schedule_faction_info_update(state1).await });
@@ -249,6 +258,7 @@ async fn schedules_with_multiple_concurrent_calls() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let handle2 = tokio::spawn(async move { schedule_faction_info_update(state2).await });
@@ -288,6 +298,7 @@ async fn returns_consistent_result_on_success() -> Result<(), TestError> {
             db: test.db.clone(),
             queue: queue.clone(),
             offset_for_esi_downtime: false,
+            settings: SchedulerSettings::default(),
         };
 
         let result = schedule_faction_info_update(state).await;
@@ -322,6 +333,7 @@ async fn works_with_minimal_database_setup() -> Result<(), TestError> {
         db: test.db.clone(),
         queue: queue.clone(),
         offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
     };
 
     let result = schedule_faction_info_update(state).await;
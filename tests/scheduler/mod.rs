@@ -1,2 +1,3 @@
 pub mod entity_refresh;
 pub mod eve;
+pub mod preview;
@@ -0,0 +1,66 @@
+//! Tests for preview_schedule.
+//!
+//! This module verifies that previewing a scheduled job selects the same entries as a
+//! scheduler run without pushing any jobs to the worker queue.
+
+use bifrost::model::scheduler::SchedulerJobKind;
+use bifrost::server::model::worker::WorkerJob;
+use bifrost::server::scheduler::preview::preview_schedule;
+use bifrost::server::scheduler::{config::SchedulerSettings, SchedulerState};
+use bifrost_test_utils::prelude::*;
+use chrono::{Duration, Utc};
+use entity::prelude::EveAlliance;
+use migration::Expr;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+use crate::util::redis::RedisTest;
+use crate::worker::queue::setup_test_queue;
+
+/// Tests previewing the alliance schedule with one expired and one fresh alliance.
+///
+/// Expected: Ok with a job for the expired alliance only and an empty queue
+#[tokio::test]
+async fn previews_expired_alliances_without_queueing() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .build()
+        .await?;
+    let redis = RedisTest::new().await?;
+    let queue = setup_test_queue(&redis);
+
+    let expired = test.eve().insert_mock_alliance(1, None).await?;
+    test.eve().insert_mock_alliance(2, None).await?;
+
+    // Set updated_at to 25 hours ago (cache is 24 hours)
+    let old_timestamp = Utc::now().naive_utc() - Duration::hours(25);
+    EveAlliance::update_many()
+        .col_expr(
+            entity::eve_alliance::Column::UpdatedAt,
+            Expr::value(old_timestamp),
+        )
+        .filter(entity::eve_alliance::Column::Id.eq(expired.id))
+        .exec(&test.db)
+        .await?;
+
+    let state = SchedulerState {
+        db: test.db.clone(),
+        queue: queue.clone(),
+        offset_for_esi_downtime: false,
+        settings: SchedulerSettings::default(),
+    };
+
+    let preview = preview_schedule(&state, SchedulerJobKind::Alliance)
+        .await
+        .unwrap();
+
+    assert_eq!(preview.len(), 1);
+    assert_eq!(
+        preview[0].0,
+        WorkerJob::UpdateAllianceInfo { alliance_id: 1 }
+    );
+    assert_eq!(queue.len().await.unwrap(), 0);
+
+    redis.cleanup().await?;
+    Ok(())
+}
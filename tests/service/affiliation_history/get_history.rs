@@ -0,0 +1,65 @@
+//! Tests for AffiliationHistoryService::get_history method.
+//!
+//! This module verifies retrieving the history of characters without recorded intervals and
+//! rejecting characters missing from the database.
+
+use bifrost::server::{
+    error::{affiliation_history::AffiliationHistoryError, AppError},
+    service::affiliation_history::AffiliationHistoryService,
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests retrieving the history of a character whose affiliation was never recorded.
+///
+/// Expected: Ok with the character's name and no intervals
+#[tokio::test]
+async fn returns_empty_history_without_intervals() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .build()
+        .await?;
+
+    let history = AffiliationHistoryService::new(&test.db)
+        .get_history(95_000_001)
+        .await
+        .unwrap();
+
+    assert_eq!(history.character_id, 95_000_001);
+    assert!(!history.character_name.is_empty());
+    assert!(history.intervals.is_empty());
+
+    Ok(())
+}
+
+/// Tests error handling for characters missing from the database.
+///
+/// Expected: Err(AppError::AffiliationHistory(AffiliationHistoryError::CharacterNotFound))
+#[tokio::test]
+async fn fails_for_unknown_character() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
+        .build()
+        .await?;
+
+    let result = AffiliationHistoryService::new(&test.db)
+        .get_history(95_000_001)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::AffiliationHistory(
+            AffiliationHistoryError::CharacterNotFound(95_000_001)
+        ))
+    ));
+
+    Ok(())
+}
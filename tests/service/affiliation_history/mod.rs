@@ -0,0 +1,2 @@
+mod get_history;
+mod record_affiliations;
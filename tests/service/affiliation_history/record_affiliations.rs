@@ -0,0 +1,95 @@
+//! Tests for AffiliationHistoryService::record_affiliations method.
+//!
+//! This module verifies that affiliation intervals are started for characters seen for the
+//! first time or whose affiliation changed, and left alone for unchanged characters.
+
+use bifrost::server::service::affiliation_history::AffiliationHistoryService;
+use bifrost_test_utils::prelude::*;
+use chrono::{Duration, NaiveDate};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+/// Tests recording a character moving to another corporation.
+///
+/// Verifies that recording the same affiliation twice keeps a single interval and that a
+/// corporation change ends it and starts a new one, which shows up in the character's history
+/// without an alliance.
+///
+/// Expected: Ok with two intervals, the first ended when the change was seen
+#[tokio::test]
+async fn starts_interval_on_change() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
+        .with_mock_character(95_000_001, 98_000_001, Some(99_000_001), None)
+        .with_mock_corporation(98_000_002, None, None)
+        .build()
+        .await?;
+
+    let character = entity::prelude::EveCharacter::find()
+        .one(&test.db)
+        .await?
+        .unwrap();
+    let old_corporation = entity::prelude::EveCorporation::find()
+        .filter(entity::eve_corporation::Column::CorporationId.eq(98_000_001))
+        .one(&test.db)
+        .await?
+        .unwrap();
+    let new_corporation = entity::prelude::EveCorporation::find()
+        .filter(entity::eve_corporation::Column::CorporationId.eq(98_000_002))
+        .one(&test.db)
+        .await?
+        .unwrap();
+
+    let joined_at = NaiveDate::from_ymd_opt(2026, 9, 1)
+        .unwrap()
+        .and_hms_opt(19, 0, 0)
+        .unwrap();
+    let refreshed_at = joined_at + Duration::days(1);
+    let left_at = joined_at + Duration::days(2);
+    let old_affiliation = (
+        character.id,
+        old_corporation.id,
+        old_corporation.alliance_id,
+    );
+
+    let first =
+        AffiliationHistoryService::record_affiliations(&test.db, vec![old_affiliation], joined_at)
+            .await
+            .unwrap();
+    let unchanged = AffiliationHistoryService::record_affiliations(
+        &test.db,
+        vec![old_affiliation],
+        refreshed_at,
+    )
+    .await
+    .unwrap();
+    let changed = AffiliationHistoryService::record_affiliations(
+        &test.db,
+        vec![(character.id, new_corporation.id, None)],
+        left_at,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!((first, unchanged, changed), (1, 0, 1));
+
+    let history = AffiliationHistoryService::new(&test.db)
+        .get_history(95_000_001)
+        .await
+        .unwrap();
+    assert_eq!(history.intervals.len(), 2);
+    assert_eq!(history.intervals[0].corporation_id, 98_000_001);
+    assert_eq!(history.intervals[0].alliance_id, Some(99_000_001));
+    assert!(history.intervals[0].alliance_name.is_some());
+    assert_eq!(history.intervals[0].started_at, joined_at);
+    assert_eq!(history.intervals[0].ended_at, Some(left_at));
+    assert_eq!(history.intervals[1].corporation_id, 98_000_002);
+    assert_eq!(history.intervals[1].alliance_id, None);
+    assert_eq!(history.intervals[1].started_at, left_at);
+    assert_eq!(history.intervals[1].ended_at, None);
+
+    Ok(())
+}
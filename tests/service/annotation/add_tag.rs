@@ -0,0 +1,142 @@
+//! Tests for AnnotationService::add_tag method.
+//!
+//! This module verifies normalizing tags, returning the existing tag when a subject is tagged
+//! twice, finding tagged subjects, and rejecting invalid tags and missing subjects.
+
+use bifrost::{
+    model::annotation::{AnnotationSubject, CreateTagDto, TaggedSubjectDto},
+    server::{
+        error::{annotation::AnnotationError, AppError},
+        service::annotation::AnnotationService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds a tag with the given name.
+fn tag(name: &str) -> CreateTagDto {
+    CreateTagDto {
+        name: name.to_string(),
+    }
+}
+
+/// Tests tagging a character twice with differently formatted names.
+///
+/// Verifies that the tag is trimmed and lowercased, stored once, and found by tag search.
+///
+/// Expected: Ok with the same tag returned both times
+#[tokio::test]
+async fn normalizes_and_deduplicates_tags() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .build()
+        .await?;
+    let (user_model, _, character) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let annotation_service = AnnotationService::new(&test.db);
+    let first = annotation_service
+        .add_tag(
+            user_model.id,
+            AnnotationSubject::Character,
+            character.character_id,
+            tag(" Spy Risk "),
+        )
+        .await
+        .unwrap();
+    let second = annotation_service
+        .add_tag(
+            user_model.id,
+            AnnotationSubject::Character,
+            character.character_id,
+            tag("spy risk"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(first.name, "spy risk");
+    assert_eq!(first.id, second.id);
+
+    let subjects = annotation_service.find_by_tag("SPY RISK").await.unwrap();
+    assert_eq!(
+        subjects,
+        vec![TaggedSubjectDto {
+            subject: AnnotationSubject::Character,
+            subject_id: character.character_id,
+        }]
+    );
+
+    Ok(())
+}
+
+/// Tests error handling for tagging a corporation that isn't in the database.
+///
+/// Expected: Err(AppError::Annotation(AnnotationError::SubjectNotFound))
+#[tokio::test]
+async fn fails_for_missing_subject() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = AnnotationService::new(&test.db)
+        .add_tag(
+            user_model.id,
+            AnnotationSubject::Corporation,
+            98_000_001,
+            tag("hostile"),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Annotation(AnnotationError::SubjectNotFound {
+            subject: AnnotationSubject::Corporation,
+            subject_id: 98_000_001,
+        }))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for blank tags.
+///
+/// Expected: Err(AppError::Annotation(AnnotationError::InvalidTag))
+#[tokio::test]
+async fn fails_for_empty_tag() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = AnnotationService::new(&test.db)
+        .add_tag(
+            user_model.id,
+            AnnotationSubject::User,
+            i64::from(user_model.id),
+            tag("   "),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Annotation(AnnotationError::InvalidTag(_)))
+    ));
+
+    Ok(())
+}
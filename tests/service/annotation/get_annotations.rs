@@ -0,0 +1,126 @@
+//! Tests for AnnotationService::get_annotations method.
+//!
+//! This module verifies that notes kept private by their author are hidden from other users,
+//! both when listing a subject's notes and when deleting a note.
+
+use bifrost::{
+    model::annotation::{AnnotationSubject, CreateNoteDto, NoteVisibility},
+    server::{
+        error::{annotation::AnnotationError, AppError},
+        service::annotation::AnnotationService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds a note with the given body and visibility.
+fn note(body: &str, visibility: NoteVisibility) -> CreateNoteDto {
+    CreateNoteDto {
+        body: body.to_string(),
+        visibility,
+    }
+}
+
+/// Tests listing notes shared with officers and notes private to their author.
+///
+/// Expected: Ok with the private note only returned to its author
+#[tokio::test]
+async fn hides_private_notes_from_other_users() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .build()
+        .await?;
+    let (author, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (officer, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let annotation_service = AnnotationService::new(&test.db);
+    annotation_service
+        .add_note(
+            author.id,
+            AnnotationSubject::Corporation,
+            1,
+            note("Recruits from our space", NoteVisibility::Officers),
+        )
+        .await
+        .unwrap();
+    annotation_service
+        .add_note(
+            author.id,
+            AnnotationSubject::Corporation,
+            1,
+            note("CEO alt suspected", NoteVisibility::Author),
+        )
+        .await
+        .unwrap();
+
+    let author_view = annotation_service
+        .get_annotations(author.id, AnnotationSubject::Corporation, 1)
+        .await
+        .unwrap();
+    assert_eq!(author_view.notes.len(), 2);
+    assert_eq!(author_view.notes[0].body, "CEO alt suspected");
+
+    let officer_view = annotation_service
+        .get_annotations(officer.id, AnnotationSubject::Corporation, 1)
+        .await
+        .unwrap();
+    assert_eq!(officer_view.notes.len(), 1);
+    assert_eq!(officer_view.notes[0].body, "Recruits from our space");
+
+    Ok(())
+}
+
+/// Tests error handling for deleting another author's private note.
+///
+/// Expected: Err(AppError::Annotation(AnnotationError::NoteNotFound)) and the note is kept
+#[tokio::test]
+async fn fails_to_delete_private_note_of_other_user() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .build()
+        .await?;
+    let (author, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (officer, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let annotation_service = AnnotationService::new(&test.db);
+    let private_note = annotation_service
+        .add_note(
+            author.id,
+            AnnotationSubject::User,
+            i64::from(officer.id),
+            note("Asked for a corp hangar role", NoteVisibility::Author),
+        )
+        .await
+        .unwrap();
+
+    let result = annotation_service
+        .delete_note(officer.id, private_note.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Annotation(AnnotationError::NoteNotFound(note_id))) if note_id == private_note.id
+    ));
+    let author_view = annotation_service
+        .get_annotations(author.id, AnnotationSubject::User, i64::from(officer.id))
+        .await
+        .unwrap();
+    assert_eq!(author_view.notes.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+mod add_tag;
+mod get_annotations;
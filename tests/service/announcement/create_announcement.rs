@@ -0,0 +1,184 @@
+//! Tests for AnnouncementService::create_announcement method.
+//!
+//! This module verifies resolving an announcement's audience to recipients and rejecting
+//! announcements without a title or audience ID.
+
+use bifrost::{
+    model::announcement::{AnnouncementAudience, CreateAnnouncementDto},
+    server::{
+        error::{announcement::AnnouncementError, AppError},
+        service::{announcement::AnnouncementService, user::user_character::UserCharacterService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds an announcement to the audience without extra delivery channels.
+fn announcement(
+    title: &str,
+    audience: AnnouncementAudience,
+    audience_id: Option<i64>,
+) -> CreateAnnouncementDto {
+    CreateAnnouncementDto {
+        title: title.to_string(),
+        body: "Form up at **19:00**.".to_string(),
+        audience,
+        audience_id,
+        send_push: false,
+        send_discord: false,
+    }
+}
+
+/// Tests posting an announcement to the members of a corporation.
+///
+/// Verifies that only users owning a character in the corporation receive the announcement
+/// and that it shows up in their inbox rendered as HTML.
+///
+/// Expected: Ok with the corporation member as the only recipient
+#[tokio::test]
+async fn sends_to_corporation_members() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .build()
+        .await?;
+    let (member, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (outsider, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 2, None, None)
+        .await?;
+    for user_id in [member.id, outsider.id] {
+        UserCharacterService::refresh_summary(&test.db, user_id)
+            .await
+            .expect("Refreshing the summary should succeed");
+    }
+
+    let announcement_service = AnnouncementService::new(&test.db);
+    let (announcement, recipient_ids) = announcement_service
+        .create_announcement(
+            outsider.id,
+            announcement(
+                " Fleet tonight ",
+                AnnouncementAudience::Corporation,
+                Some(1),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(announcement.title, "Fleet tonight");
+    assert_eq!(announcement.recipient_count, 1);
+    assert_eq!(recipient_ids, vec![member.id]);
+
+    let inbox = announcement_service.get_inbox(member.id).await.unwrap();
+    assert_eq!(inbox.len(), 1);
+    assert_eq!(inbox[0].html, "<p>Form up at <strong>19:00</strong>.</p>\n");
+    assert!(!inbox[0].read);
+    assert!(announcement_service
+        .get_inbox(outsider.id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    Ok(())
+}
+
+/// Tests posting an announcement to all members.
+///
+/// Expected: Ok with every user as a recipient
+#[tokio::test]
+async fn sends_to_all_members() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .build()
+        .await?;
+    let (first_user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.user()
+        .insert_user_with_mock_character(2, 2, None, None)
+        .await?;
+
+    let (announcement, recipient_ids) = AnnouncementService::new(&test.db)
+        .create_announcement(
+            first_user.id,
+            announcement("Maintenance", AnnouncementAudience::All, Some(1)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(announcement.audience_id, None);
+    assert_eq!(recipient_ids.len(), 2);
+
+    Ok(())
+}
+
+/// Tests error handling for corporation announcements without a corporation ID.
+///
+/// Expected: Err(AppError::Announcement(AnnouncementError::MissingAudienceId))
+#[tokio::test]
+async fn fails_for_missing_audience_id() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = AnnouncementService::new(&test.db)
+        .create_announcement(
+            user_model.id,
+            announcement("Fleet tonight", AnnouncementAudience::Corporation, None),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Announcement(
+            AnnouncementError::MissingAudienceId(AnnouncementAudience::Corporation)
+        ))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for blank titles.
+///
+/// Expected: Err(AppError::Announcement(AnnouncementError::EmptyTitle))
+#[tokio::test]
+async fn fails_for_empty_title() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = AnnouncementService::new(&test.db)
+        .create_announcement(
+            user_model.id,
+            announcement("   ", AnnouncementAudience::All, None),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Announcement(AnnouncementError::EmptyTitle))
+    ));
+
+    Ok(())
+}
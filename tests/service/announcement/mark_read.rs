@@ -0,0 +1,99 @@
+//! Tests for AnnouncementService::mark_read method.
+//!
+//! This module verifies recording read receipts and rejecting announcements that aren't in
+//! the user's inbox.
+
+use bifrost::{
+    model::announcement::{AnnouncementAudience, CreateAnnouncementDto},
+    server::{
+        error::{announcement::AnnouncementError, AppError},
+        service::announcement::AnnouncementService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests marking an announcement read.
+///
+/// Verifies that the read receipt is shown in the inbox and counted for leadership.
+///
+/// Expected: Ok with one of two recipients having read the announcement
+#[tokio::test]
+async fn records_read_receipt() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .build()
+        .await?;
+    let (reader, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let announcement_service = AnnouncementService::new(&test.db);
+    let (announcement, _) = announcement_service
+        .create_announcement(
+            reader.id,
+            CreateAnnouncementDto {
+                title: "Fleet tonight".to_string(),
+                body: "Form up at 19:00.".to_string(),
+                audience: AnnouncementAudience::All,
+                audience_id: None,
+                send_push: false,
+                send_discord: false,
+            },
+        )
+        .await
+        .unwrap();
+    announcement_service
+        .mark_read(reader.id, announcement.id)
+        .await
+        .unwrap();
+    announcement_service
+        .mark_read(reader.id, announcement.id)
+        .await
+        .unwrap();
+
+    let inbox = announcement_service.get_inbox(reader.id).await.unwrap();
+    assert!(inbox[0].read);
+
+    let announcements = announcement_service.get_announcements().await.unwrap();
+    assert_eq!(announcements.len(), 1);
+    assert_eq!(announcements[0].recipient_count, 2);
+    assert_eq!(announcements[0].read_count, 1);
+
+    Ok(())
+}
+
+/// Tests error handling for announcements not sent to the user.
+///
+/// Expected: Err(AppError::Announcement(AnnouncementError::AnnouncementNotFound))
+#[tokio::test]
+async fn fails_for_announcement_not_in_inbox() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = AnnouncementService::new(&test.db)
+        .mark_read(user_model.id, 1)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Announcement(
+            AnnouncementError::AnnouncementNotFound { .. }
+        ))
+    ));
+
+    Ok(())
+}
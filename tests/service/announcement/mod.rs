@@ -0,0 +1,2 @@
+mod create_announcement;
+mod mark_read;
@@ -0,0 +1,290 @@
+//! Tests for ApprovalService::approve method.
+//!
+//! This module verifies that approving a request runs its action, that admins can't approve
+//! their own requests, and that expired or already decided requests can't be approved.
+
+use bifrost::{
+    model::approval::{ApprovalAction, ApprovalPayload, ApprovalStatus},
+    server::{
+        data::{
+            role::{RoleRepository, UserRoleRepository},
+            user::UserRepository,
+        },
+        error::{approval::ApprovalError, AppError},
+        service::approval::{ApprovalConfig, ApprovalService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+fn config(expiry: chrono::Duration) -> ApprovalConfig {
+    ApprovalConfig {
+        required_actions: vec![ApprovalAction::MergeUsers],
+        expiry,
+    }
+}
+
+/// Tests approving a merge requested by another admin.
+///
+/// Expected: Ok with the request approved and the duplicate user merged
+#[tokio::test]
+async fn runs_action_approved_by_second_admin() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .with_table(entity::prelude::BifrostWidget)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .with_table(entity::prelude::BifrostCampaign)
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .with_table(entity::prelude::BifrostApiKey)
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (requester, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (approver, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let (duplicate, _, _) = test
+        .user()
+        .insert_user_with_mock_character(3, 1, None, None)
+        .await?;
+
+    let approval_service = ApprovalService::new(&test.db);
+    let request = approval_service
+        .request(
+            &config(chrono::Duration::hours(24)),
+            requester.id,
+            ApprovalPayload::MergeUsers {
+                keep: requester.id,
+                remove: duplicate.id,
+            },
+        )
+        .await
+        .unwrap();
+
+    let approved = approval_service
+        .approve(approver.id, request.id)
+        .await
+        .unwrap();
+
+    assert_eq!(approved.status, ApprovalStatus::Approved);
+    assert_eq!(approved.decided_by_user_id, Some(approver.id));
+    assert!(approved.decided_at.is_some());
+    assert!(UserRepository::new(&test.db)
+        .get_by_id(duplicate.id)
+        .await?
+        .is_none());
+
+    let result = approval_service.approve(approver.id, request.id).await;
+    assert!(matches!(
+        result,
+        Err(AppError::Approval(ApprovalError::NotPending(_)))
+    ));
+
+    Ok(())
+}
+
+/// Tests approving an admin role assignment requested by another admin.
+///
+/// Expected: Ok with the request approved and the role assigned to the user
+#[tokio::test]
+async fn assigns_admin_role_approved_by_second_admin() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .build()
+        .await?;
+    let (requester, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (approver, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(3, 1, None, None)
+        .await?;
+    let admin = RoleRepository::new(&test.db)
+        .create(
+            "admin".to_string(),
+            String::new(),
+            "admin".to_string(),
+            true,
+        )
+        .await?;
+
+    let approval_service = ApprovalService::new(&test.db);
+    let request = approval_service
+        .request(
+            &config(chrono::Duration::hours(24)),
+            requester.id,
+            ApprovalPayload::GrantAdmin {
+                role_id: admin.id,
+                user_id: user.id,
+            },
+        )
+        .await
+        .unwrap();
+    let user_role_repo = UserRoleRepository::new(&test.db);
+    assert!(!user_role_repo.has_role(user.id, admin.id).await?);
+
+    let approved = approval_service
+        .approve(approver.id, request.id)
+        .await
+        .unwrap();
+
+    assert_eq!(approved.status, ApprovalStatus::Approved);
+    assert!(user_role_repo.has_role(user.id, admin.id).await?);
+
+    Ok(())
+}
+
+/// Tests approving a request by the admin who requested it.
+///
+/// Expected: Err(AppError::Approval(ApprovalError::SelfApproval)) and the request stays pending
+#[tokio::test]
+async fn fails_for_own_request() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (requester, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (duplicate, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let approval_service = ApprovalService::new(&test.db);
+    let request = approval_service
+        .request(
+            &config(chrono::Duration::hours(24)),
+            requester.id,
+            ApprovalPayload::MergeUsers {
+                keep: requester.id,
+                remove: duplicate.id,
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = approval_service.approve(requester.id, request.id).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Approval(ApprovalError::SelfApproval(_)))
+    ));
+    let requests = approval_service.get_requests().await.unwrap();
+    assert_eq!(requests[0].status, ApprovalStatus::Pending);
+
+    Ok(())
+}
+
+/// Tests approving a request past its expiry.
+///
+/// Expected: Err(AppError::Approval(ApprovalError::Expired)) and the duplicate user is kept
+#[tokio::test]
+async fn fails_for_expired_request() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (requester, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (approver, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let (duplicate, _, _) = test
+        .user()
+        .insert_user_with_mock_character(3, 1, None, None)
+        .await?;
+
+    let approval_service = ApprovalService::new(&test.db);
+    let request = approval_service
+        .request(
+            &config(chrono::Duration::hours(-1)),
+            requester.id,
+            ApprovalPayload::MergeUsers {
+                keep: requester.id,
+                remove: duplicate.id,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(request.status, ApprovalStatus::Expired);
+
+    let result = approval_service.approve(approver.id, request.id).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Approval(ApprovalError::Expired(_)))
+    ));
+    assert!(UserRepository::new(&test.db)
+        .get_by_id(duplicate.id)
+        .await?
+        .is_some());
+
+    Ok(())
+}
+
+/// Tests approving a request that doesn't exist.
+///
+/// Expected: Err(AppError::Approval(ApprovalError::NotFound))
+#[tokio::test]
+async fn fails_for_missing_request() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (approver, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = ApprovalService::new(&test.db).approve(approver.id, 1).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Approval(ApprovalError::NotFound(1)))
+    ));
+
+    Ok(())
+}
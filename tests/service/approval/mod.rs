@@ -0,0 +1,3 @@
+mod approve;
+mod reject;
+mod request;
@@ -0,0 +1,75 @@
+//! Tests for ApprovalService::reject method.
+//!
+//! This module verifies that rejecting a request records the decision without running its
+//! action, including requesters withdrawing their own requests.
+
+use bifrost::{
+    model::approval::{ApprovalAction, ApprovalPayload, ApprovalStatus},
+    server::{
+        data::user::UserRepository,
+        error::{approval::ApprovalError, AppError},
+        service::approval::{ApprovalConfig, ApprovalService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests the requester withdrawing a merge request.
+///
+/// Expected: Ok with the request rejected, the duplicate user kept, and the request no longer
+/// approvable
+#[tokio::test]
+async fn rejects_without_running_action() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .build()
+        .await?;
+    let (requester, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (approver, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let (duplicate, _, _) = test
+        .user()
+        .insert_user_with_mock_character(3, 1, None, None)
+        .await?;
+
+    let approval_service = ApprovalService::new(&test.db);
+    let request = approval_service
+        .request(
+            &ApprovalConfig {
+                required_actions: vec![ApprovalAction::MergeUsers],
+                expiry: chrono::Duration::hours(24),
+            },
+            requester.id,
+            ApprovalPayload::MergeUsers {
+                keep: requester.id,
+                remove: duplicate.id,
+            },
+        )
+        .await
+        .unwrap();
+
+    let rejected = approval_service
+        .reject(requester.id, request.id)
+        .await
+        .unwrap();
+
+    assert_eq!(rejected.status, ApprovalStatus::Rejected);
+    assert_eq!(rejected.decided_by_user_id, Some(requester.id));
+    assert!(UserRepository::new(&test.db)
+        .get_by_id(duplicate.id)
+        .await?
+        .is_some());
+
+    let result = approval_service.approve(approver.id, request.id).await;
+    assert!(matches!(
+        result,
+        Err(AppError::Approval(ApprovalError::NotPending(_)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,131 @@
+//! Tests for ApprovalService::request method.
+//!
+//! This module verifies that requesting a sensitive action records a pending approval request
+//! expiring after the configured time, and that invalid actions are rejected up front.
+
+use bifrost::{
+    model::approval::{ApprovalAction, ApprovalPayload, ApprovalStatus},
+    server::{
+        error::{auth::AuthError, user::UserError, AppError},
+        service::approval::{ApprovalConfig, ApprovalService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+fn config() -> ApprovalConfig {
+    ApprovalConfig {
+        required_actions: vec![ApprovalAction::MergeUsers],
+        expiry: chrono::Duration::hours(24),
+    }
+}
+
+/// Tests requesting approval to merge two existing users.
+///
+/// Expected: Ok with a pending request expiring after the configured time
+#[tokio::test]
+async fn creates_pending_request() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (duplicate, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let payload = ApprovalPayload::MergeUsers {
+        keep: admin.id,
+        remove: duplicate.id,
+    };
+    let request = ApprovalService::new(&test.db)
+        .request(&config(), admin.id, payload.clone())
+        .await
+        .unwrap();
+
+    assert_eq!(request.payload, payload);
+    assert_eq!(request.status, ApprovalStatus::Pending);
+    assert_eq!(request.requested_by_user_id, admin.id);
+    assert_eq!(request.decided_by_user_id, None);
+    assert!(request.expires_at > request.created_at + chrono::Duration::hours(23));
+
+    let requests = ApprovalService::new(&test.db).get_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].id, request.id);
+
+    Ok(())
+}
+
+/// Tests requesting approval to merge a user into itself.
+///
+/// Expected: Err(AppError::User(UserError::MergeIntoSelf)) and no request is created
+#[tokio::test]
+async fn fails_for_merge_into_self() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let approval_service = ApprovalService::new(&test.db);
+    let result = approval_service
+        .request(
+            &config(),
+            admin.id,
+            ApprovalPayload::MergeUsers {
+                keep: admin.id,
+                remove: admin.id,
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::User(UserError::MergeIntoSelf(_)))
+    ));
+    assert!(approval_service.get_requests().await.unwrap().is_empty());
+
+    Ok(())
+}
+
+/// Tests requesting approval to merge a user that doesn't exist.
+///
+/// Expected: Err(AppError::Auth(AuthError::UserNotInDatabase))
+#[tokio::test]
+async fn fails_for_missing_user() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = ApprovalService::new(&test.db)
+        .request(
+            &config(),
+            admin.id,
+            ApprovalPayload::MergeUsers {
+                keep: admin.id,
+                remove: admin.id + 100,
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::UserNotInDatabase(_)))
+    ));
+
+    Ok(())
+}
@@ -1,10 +1,14 @@
 //! Tests for CallbackService::get_or_create_user method.
 //!
 //! This module verifies the user retrieval and creation logic,
-//! including returning existing user IDs when provided and creating
-//! new users when no user ID is specified.
-
-use bifrost::server::{error::AppError, service::auth::callback::CallbackService};
+//! including returning existing user IDs when provided, creating
+//! new users when no user ID is specified, and making the first user an admin.
+
+use bifrost::server::{
+    data::role::{RoleRepository, UserRoleRepository},
+    error::AppError,
+    service::auth::callback::CallbackService,
+};
 use bifrost_test_utils::prelude::*;
 use sea_orm::TransactionTrait;
 
@@ -172,6 +176,48 @@ async fn user_not_persisted_on_transaction_rollback() -> Result<(), TestError> {
     Ok(())
 }
 
+/// Tests that only the first user created is made an admin.
+///
+/// Verifies that the admin role is assigned to the user created while no other users exist,
+/// and not to users created after it.
+///
+/// Expected: Ok with the first user assigned the admin role and the second user not
+#[tokio::test]
+async fn makes_first_user_admin() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let admin = RoleRepository::new(&test.db)
+        .create(
+            "admin".to_string(),
+            String::new(),
+            "admin".to_string(),
+            true,
+        )
+        .await?;
+    let char_model_1 = test
+        .eve()
+        .insert_mock_character(111111111, 1, None, None)
+        .await?;
+    let char_model_2 = test
+        .eve()
+        .insert_mock_character(222222222, 1, None, None)
+        .await?;
+
+    let txn = test.db.begin().await?;
+    let first_user_id = CallbackService::get_or_create_user(&txn, None, char_model_1.id)
+        .await
+        .unwrap();
+    let second_user_id = CallbackService::get_or_create_user(&txn, None, char_model_2.id)
+        .await
+        .unwrap();
+    txn.commit().await?;
+
+    let user_role_repo = UserRoleRepository::new(&test.db);
+    assert!(user_role_repo.has_role(first_user_id, admin.id).await?);
+    assert!(!user_role_repo.has_role(second_user_id, admin.id).await?);
+
+    Ok(())
+}
+
 /// Tests error handling when database tables are missing.
 ///
 /// Verifies that the method returns a database error when attempting to
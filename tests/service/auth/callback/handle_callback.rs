@@ -2,12 +2,16 @@
 //!
 //! This module verifies the complete OAuth callback flow orchestration,
 //! including authentication, character ownership management, user creation,
-//! and main character updates across various scenarios.
-
-use bifrost::server::{
-    data::user::UserRepository,
-    error::AppError,
-    service::{auth::callback::CallbackService, eve::esi::EsiProvider},
+//! main character updates, and login intent handling across various scenarios.
+
+use bifrost::{
+    model::webhook::WebhookEvent,
+    server::{
+        data::user::UserRepository,
+        error::{auth::AuthError, AppError},
+        model::session::login_intent::LoginIntent,
+        service::{auth::callback::CallbackService, eve::esi::EsiProvider},
+    },
 };
 use bifrost_test_utils::prelude::*;
 
@@ -41,16 +45,17 @@ async fn creates_new_user_for_new_character() -> Result<(), TestError> {
     let service = CallbackService::new(&test.db, &esi_provider);
 
     let result = service
-        .handle_callback("auth_code", None, None)
+        .handle_callback("auth_code", None, &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
     // Should create first user
-    assert_eq!(result, 1);
+    assert_eq!(result.user_id, 1);
+    assert_eq!(result.character_id, character_id);
 
     // Verify user exists with character as main
     let user_repo = UserRepository::new(&test.db);
-    let (user, _) = user_repo.get_by_id(result).await?.unwrap();
+    let (user, _) = user_repo.get_by_id(result.user_id).await?.unwrap();
     assert_eq!(user.id, 1);
 
     test.assert_mocks();
@@ -91,12 +96,12 @@ async fn links_new_character_to_existing_user() -> Result<(), TestError> {
     let service = CallbackService::new(&test.db, &esi_provider);
 
     let result = service
-        .handle_callback("auth_code", Some(existing_user.id), None)
+        .handle_callback("auth_code", Some(existing_user.id), &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
     // Should return existing user ID
-    assert_eq!(result, existing_user.id);
+    assert_eq!(result.user_id, existing_user.id);
 
     test.assert_mocks();
 
@@ -129,11 +134,11 @@ async fn creates_user_for_unowned_character() -> Result<(), TestError> {
     let service = CallbackService::new(&test.db, &esi_provider);
 
     let result = service
-        .handle_callback("auth_code", None, None)
+        .handle_callback("auth_code", None, &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, 1);
+    assert_eq!(result.user_id, 1);
 
     test.assert_mocks();
 
@@ -172,11 +177,11 @@ async fn links_unowned_character_to_logged_in_user() -> Result<(), TestError> {
     let service = CallbackService::new(&test.db, &esi_provider);
 
     let result = service
-        .handle_callback("auth_code", Some(existing_user.id), None)
+        .handle_callback("auth_code", Some(existing_user.id), &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, existing_user.id);
+    assert_eq!(result.user_id, existing_user.id);
 
     test.assert_mocks();
 
@@ -220,11 +225,11 @@ async fn logs_in_existing_user_with_matching_hash() -> Result<(), TestError> {
     let service = CallbackService::new(&test.db, &esi_provider);
 
     let result = service
-        .handle_callback("auth_code", None, None)
+        .handle_callback("auth_code", None, &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, user.id);
+    assert_eq!(result.user_id, user.id);
 
     test.assert_mocks();
 
@@ -249,7 +254,7 @@ async fn transfers_character_to_logged_in_user() -> Result<(), TestError> {
         .await?;
 
     // Create first user with character
-    let (_user1, _, _) = test
+    let (user1, _, _) = test
         .user()
         .insert_user_with_mock_character(character_id, 1, None, None)
         .await?;
@@ -265,11 +270,66 @@ async fn transfers_character_to_logged_in_user() -> Result<(), TestError> {
 
     // Character from user1 logs in with user2 session
     let result = service
-        .handle_callback("auth_code", Some(user2.id), None)
+        .handle_callback("auth_code", Some(user2.id), &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, user2.id);
+    assert_eq!(result.user_id, user2.id);
+
+    // Webhooks are notified of the transfer
+    assert_eq!(result.ownership_changes.len(), 1);
+    let change = &result.ownership_changes[0];
+    assert_eq!(change.event, WebhookEvent::CharacterTransferred);
+    assert_eq!(change.user_id, user2.id);
+    assert_eq!(change.previous_user_id, Some(user1.id));
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests callback for a character owned by another user of the same EVE account.
+///
+/// Verifies that when a character logs in with a different user session but the owner hash
+/// matches, the character stays with its owner until the transfer is confirmed.
+///
+/// Expected: Ok with logged-in user ID, the owner as pending transfer, and no ownership changes
+#[tokio::test]
+async fn defers_transfer_for_matching_owner_hash() -> Result<(), TestError> {
+    let character_id = 123456789;
+
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, "owner_hash")
+        .build()
+        .await?;
+
+    let (user1, ownership, _) = test
+        .user()
+        .insert_user_with_mock_character(character_id, 1, None, None)
+        .await?;
+    let (user2, _, _) = test
+        .user()
+        .insert_user_with_mock_character(987654321, 2, None, None)
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let result = service
+        .handle_callback("auth_code", Some(user2.id), &LoginIntent::Login)
+        .await
+        .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
+
+    assert_eq!(result.user_id, user2.id);
+    assert_eq!(result.pending_transfer, Some(user1.id));
+    assert!(result.ownership_changes.is_empty());
+
+    let stored = entity::bifrost_user_character::Entity::find_by_id(ownership.id)
+        .one(&test.db)
+        .await?
+        .unwrap();
+    assert_eq!(stored.user_id, user1.id);
 
     test.assert_mocks();
 
@@ -304,21 +364,21 @@ async fn updates_owner_hash_for_same_user() -> Result<(), TestError> {
 
     // Same user logs in with new owner hash
     let result = service
-        .handle_callback("auth_code", Some(user.id), None)
+        .handle_callback("auth_code", Some(user.id), &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, user.id);
+    assert_eq!(result.user_id, user.id);
 
     test.assert_mocks();
 
     Ok(())
 }
 
-/// Tests callback with change_main flag for already owned character.
+/// Tests callback with ChangeMain intent for already owned character.
 ///
-/// Verifies that when a user logs in with their own character and
-/// change_main is true, the main character is updated.
+/// Verifies that when a user logs in with their own character using the
+/// ChangeMain intent, the main character is updated.
 ///
 /// Expected: Ok with user ID and main character updated
 #[tokio::test]
@@ -360,11 +420,11 @@ async fn updates_main_character_when_flag_set() -> Result<(), TestError> {
 
     // Login with second character and change_main=true
     let result = service
-        .handle_callback("auth_code", Some(user.id), Some(true))
+        .handle_callback("auth_code", Some(user.id), &LoginIntent::ChangeMain)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, user.id);
+    assert_eq!(result.user_id, user.id);
 
     // Verify main character was updated
     let user_repo = UserRepository::new(&test.db);
@@ -377,10 +437,10 @@ async fn updates_main_character_when_flag_set() -> Result<(), TestError> {
     Ok(())
 }
 
-/// Tests callback with change_main=false doesn't update main character.
+/// Tests callback with Login intent doesn't update main character.
 ///
-/// Verifies that when change_main is explicitly false, the main character
-/// remains unchanged even if logging in with a different character.
+/// Verifies that with a plain Login intent, the main character remains
+/// unchanged even if logging in with a different character.
 ///
 /// Expected: Ok with user ID and main character unchanged
 #[tokio::test]
@@ -422,11 +482,11 @@ async fn does_not_update_main_character_when_flag_false() -> Result<(), TestErro
 
     // Login with second character and change_main=false
     let result = service
-        .handle_callback("auth_code", Some(user.id), Some(false))
+        .handle_callback("auth_code", Some(user.id), &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, user.id);
+    assert_eq!(result.user_id, user.id);
 
     // Verify main character was NOT updated
     let user_repo = UserRepository::new(&test.db);
@@ -451,7 +511,9 @@ async fn fails_when_esi_unavailable() -> Result<(), TestError> {
     let esi_provider = EsiProvider::new(test.esi_client.clone());
     let service = CallbackService::new(&test.db, &esi_provider);
 
-    let result = service.handle_callback("auth_code", None, None).await;
+    let result = service
+        .handle_callback("auth_code", None, &LoginIntent::Login)
+        .await;
 
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), AppError::Esi(_)));
@@ -478,7 +540,9 @@ async fn fails_when_database_tables_missing() -> Result<(), TestError> {
     let esi_provider = EsiProvider::new(test.esi_client.clone());
     let service = CallbackService::new(&test.db, &esi_provider);
 
-    let result = service.handle_callback("auth_code", None, None).await;
+    let result = service
+        .handle_callback("auth_code", None, &LoginIntent::Login)
+        .await;
 
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), AppError::Database(_)));
@@ -516,11 +580,11 @@ async fn handles_character_with_alliance() -> Result<(), TestError> {
     let service = CallbackService::new(&test.db, &esi_provider);
 
     let result = service
-        .handle_callback("auth_code", None, None)
+        .handle_callback("auth_code", None, &LoginIntent::Login)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, 1);
+    assert_eq!(result.user_id, 1);
 
     test.assert_mocks();
 
@@ -556,13 +620,349 @@ async fn handles_character_with_faction() -> Result<(), TestError> {
     let service = CallbackService::new(&test.db, &esi_provider);
 
     let result = service
-        .handle_callback("auth_code", None, None)
+        .handle_callback("auth_code", None, &LoginIntent::Login)
+        .await
+        .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
+
+    assert_eq!(result.user_id, 1);
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests LinkAlt intent without a logged-in user.
+///
+/// Verifies that intents which add to an existing account are rejected when
+/// no user is present in the session.
+///
+/// Expected: Err with AuthError::UserNotInSession
+#[tokio::test]
+async fn fails_link_alt_without_user() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "owner_hash_123";
+
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let result = service
+        .handle_callback("auth_code", None, &LoginIntent::LinkAlt)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::UserNotInSession))
+    ));
+
+    Ok(())
+}
+
+/// Tests LinkAlt intent for a character owned by another user.
+///
+/// Verifies that the LinkAlt intent follows the same ownership rules as a
+/// plain login and transfers the character to the logged-in user.
+///
+/// Expected: Ok with logged-in user ID
+#[tokio::test]
+async fn links_alt_to_logged_in_user() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "new_owner_hash";
+
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let (_user1, _, _) = test
+        .user()
+        .insert_user_with_mock_character(character_id, 1, None, None)
+        .await?;
+    let (user2, _, _) = test
+        .user()
+        .insert_user_with_mock_character(987654321, 2, None, None)
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let result = service
+        .handle_callback("auth_code", Some(user2.id), &LoginIntent::LinkAlt)
+        .await
+        .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
+
+    assert_eq!(result.user_id, user2.id);
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests ChangeMain intent without a logged-in user.
+///
+/// Expected: Err with AuthError::UserNotInSession
+#[tokio::test]
+async fn fails_change_main_without_user() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "owner_hash_123";
+
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let result = service
+        .handle_callback("auth_code", None, &LoginIntent::ChangeMain)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::UserNotInSession))
+    ));
+
+    Ok(())
+}
+
+/// Tests AddScopes intent when EVE SSO did not grant a requested scope.
+///
+/// Verifies that the callback compares the requested scopes against the
+/// scopes present in the JWT and reports the ones that are missing.
+///
+/// Expected: Err with AuthError::ScopesNotGranted listing the missing scope
+#[tokio::test]
+async fn fails_add_scopes_when_scope_not_granted() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "owner_hash_123";
+
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(character_id, 1, None, None)
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let intent = LoginIntent::AddScopes {
+        scopes: vec!["esi-assets.read_assets.v1".to_string()],
+    };
+    let result = service
+        .handle_callback("auth_code", Some(user.id), &intent)
+        .await;
+
+    match result {
+        Err(AppError::Auth(AuthError::ScopesNotGranted(missing))) => {
+            assert_eq!(missing, vec!["esi-assets.read_assets.v1".to_string()]);
+        }
+        other => panic!(
+            "expected ScopesNotGranted, got {:?}",
+            other.map(|o| o.user_id)
+        ),
+    }
+
+    Ok(())
+}
+
+/// Tests a plain login requiring scopes from a scope set that EVE SSO did not grant.
+///
+/// Verifies that the scopes stored in the session by the login are checked for every
+/// intent, not only AddScopes, and that the character is not stored.
+///
+/// Expected: Err with AuthError::ScopesNotGranted listing the missing scope
+#[tokio::test]
+async fn fails_login_when_required_scope_not_granted() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "owner_hash_123";
+
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider)
+        .with_required_scopes(vec!["esi-skills.read_skills.v1".to_string()]);
+
+    let result = service
+        .handle_callback("auth_code", None, &LoginIntent::Login)
+        .await;
+
+    match result {
+        Err(AppError::Auth(AuthError::ScopesNotGranted(missing))) => {
+            assert_eq!(missing, vec!["esi-skills.read_skills.v1".to_string()]);
+        }
+        other => panic!(
+            "expected ScopesNotGranted, got {:?}",
+            other.map(|o| o.user_id)
+        ),
+    }
+
+    let character = entity::prelude::EveCharacter::find()
+        .filter(entity::eve_character::Column::CharacterId.eq(character_id))
+        .one(&test.db)
+        .await?;
+    assert!(character.is_none());
+
+    Ok(())
+}
+
+/// Tests AddScopes intent when no additional scopes were requested.
+///
+/// Expected: Ok with logged-in user ID
+#[tokio::test]
+async fn succeeds_add_scopes_when_all_granted() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "owner_hash_123";
+
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(character_id, 1, None, None)
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let intent = LoginIntent::AddScopes { scopes: Vec::new() };
+    let result = service
+        .handle_callback("auth_code", Some(user.id), &intent)
         .await
         .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
 
-    assert_eq!(result, 1);
+    assert_eq!(result.user_id, user.id);
 
     test.assert_mocks();
 
     Ok(())
 }
+
+/// Tests Reauth intent for a character owned by the logged-in user.
+///
+/// Expected: Ok with logged-in user ID
+#[tokio::test]
+async fn reauthenticates_owned_character() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "owner_hash_123";
+
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(character_id, 1, None, None)
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let result = service
+        .handle_callback("auth_code", Some(user.id), &LoginIntent::Reauth)
+        .await
+        .map_err(|e| TestError::DbErr(sea_orm::DbErr::Custom(e.to_string())))?;
+
+    assert_eq!(result.user_id, user.id);
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests Reauth intent for a character owned by another user.
+///
+/// Verifies that re-authentication never transfers ownership of a character.
+///
+/// Expected: Err with AuthError::CharacterOwnedByAnotherUser
+#[tokio::test]
+async fn fails_reauth_for_character_owned_by_another_user() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "owner_hash_123";
+
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let (_user1, _, _) = test
+        .user()
+        .insert_user_with_mock_character(character_id, 1, None, None)
+        .await?;
+    let (user2, _, _) = test
+        .user()
+        .insert_user_with_mock_character(987654321, 2, None, None)
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let result = service
+        .handle_callback("auth_code", Some(user2.id), &LoginIntent::Reauth)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::CharacterOwnedByAnotherUser))
+    ));
+
+    Ok(())
+}
+
+/// Tests Reauth intent for a character that is not in the database.
+///
+/// Verifies that re-authentication never links a new character.
+///
+/// Expected: Err with AuthError::CharacterNotOwned
+#[tokio::test]
+async fn fails_reauth_for_unknown_character() -> Result<(), TestError> {
+    let character_id = 123456789;
+    let owner_hash = "owner_hash_123";
+
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_jwt_endpoints(character_id, owner_hash)
+        .build()
+        .await?;
+
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(987654321, 1, None, None)
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let service = CallbackService::new(&test.db, &esi_provider);
+
+    let result = service
+        .handle_callback("auth_code", Some(user.id), &LoginIntent::Reauth)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::CharacterNotOwned))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! Tests for TransferService::confirm_transfer method.
+//!
+//! This module verifies confirming a pending character transfer, merging the user owning the
+//! character into the logged in user, and rejecting transfers that are no longer valid.
+
+use bifrost::{
+    model::{user::PendingTransferDto, webhook::WebhookEvent},
+    server::{
+        data::user::{user_character::UserCharacterRepository, UserRepository},
+        error::{auth::AuthError, AppError},
+        service::auth::transfer::TransferService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests confirming the transfer of a character owned by a user with two characters.
+///
+/// Expected: Ok with both characters moved, reported as transferred, and the other user
+/// deleted
+#[tokio::test]
+async fn merges_user_owning_character() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .with_table(entity::prelude::BifrostWidget)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .with_table(entity::prelude::BifrostCampaign)
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .with_table(entity::prelude::BifrostApiKey)
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (other, _, character) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    test.user()
+        .insert_mock_character_for_user(other.id, 3, 1, None, None)
+        .await?;
+
+    let transfer = PendingTransferDto {
+        from_user_id: other.id,
+        character_id: character.character_id,
+        character_name: character.name.clone(),
+    };
+    let outcome = TransferService::new(&test.db)
+        .confirm_transfer(user.id, &transfer)
+        .await
+        .unwrap();
+
+    assert_eq!(outcome.merge.kept_user_id, user.id);
+    assert_eq!(outcome.merge.removed_user_id, other.id);
+    assert_eq!(outcome.merge.characters_moved, 2);
+
+    assert_eq!(outcome.ownership_changes.len(), 2);
+    assert!(outcome.ownership_changes.iter().all(|change| {
+        change.event == WebhookEvent::CharacterTransferred
+            && change.user_id == user.id
+            && change.previous_user_id == Some(other.id)
+    }));
+
+    let ownerships = UserCharacterRepository::new(&test.db)
+        .get_ownerships_by_user_id(user.id)
+        .await?;
+    assert_eq!(ownerships.len(), 3);
+    assert!(UserRepository::new(&test.db)
+        .get_by_id(other.id)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+/// Tests confirming a transfer after the character left the user it was owned by.
+///
+/// Expected: Err(AppError::Auth(AuthError::NoPendingTransfer)) with both users unchanged
+#[tokio::test]
+async fn fails_when_character_changed_owner() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (other, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let (_, _, character) = test
+        .user()
+        .insert_user_with_mock_character(3, 1, None, None)
+        .await?;
+
+    let transfer = PendingTransferDto {
+        from_user_id: other.id,
+        character_id: character.character_id,
+        character_name: character.name.clone(),
+    };
+    let result = TransferService::new(&test.db)
+        .confirm_transfer(user.id, &transfer)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::NoPendingTransfer))
+    ));
+    assert!(UserRepository::new(&test.db)
+        .get_by_id(other.id)
+        .await?
+        .is_some());
+
+    Ok(())
+}
@@ -0,0 +1 @@
+mod confirm_transfer;
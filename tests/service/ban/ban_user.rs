@@ -0,0 +1,176 @@
+//! Tests for BanService::ban_user method.
+//!
+//! This module verifies issuing temporary and permanent bans, and rejecting bans without a
+//! reason, with a zero duration, against the issuing admin, or against missing users.
+
+use bifrost::{
+    model::ban::CreateUserBanDto,
+    server::{
+        error::{auth::AuthError, ban::BanError, AppError},
+        service::ban::BanService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests issuing a temporary ban.
+///
+/// Expected: Ok with an active ban expiring after the duration, with the trimmed reason
+#[tokio::test]
+async fn issues_temporary_ban() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let ban = BanService::new(&test.db)
+        .ban_user(
+            admin.id,
+            user.id,
+            CreateUserBanDto {
+                reason: " Spamming corp chat ".to_string(),
+                duration_hours: Some(24),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert!(ban.active);
+    assert_eq!(ban.user_id, user.id);
+    assert_eq!(ban.reason, "Spamming corp chat");
+    assert_eq!(ban.issued_by_user_id, Some(admin.id));
+    assert_eq!(
+        ban.expires_at,
+        Some(ban.created_at + chrono::Duration::hours(24))
+    );
+
+    Ok(())
+}
+
+/// Tests issuing a permanent ban.
+///
+/// Expected: Ok with an active ban without an expiry, listed for the user
+#[tokio::test]
+async fn issues_permanent_ban() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let ban_service = BanService::new(&test.db);
+    let ban = ban_service
+        .ban_user(
+            admin.id,
+            user.id,
+            CreateUserBanDto {
+                reason: "Awoxing".to_string(),
+                duration_hours: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert!(ban.active);
+    assert_eq!(ban.expires_at, None);
+    assert_eq!(ban_service.get_user_bans(user.id).await.unwrap(), vec![ban]);
+
+    Ok(())
+}
+
+/// Tests rejecting invalid bans.
+///
+/// Expected: Err with InvalidBan for an empty reason, a zero duration, and a self-ban
+#[tokio::test]
+async fn rejects_invalid_ban() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let ban_service = BanService::new(&test.db);
+    let invalid_bans = [
+        (user.id, "   ", None),
+        (user.id, "Awoxing", Some(0)),
+        (admin.id, "Awoxing", None),
+    ];
+    for (user_id, reason, duration_hours) in invalid_bans {
+        let result = ban_service
+            .ban_user(
+                admin.id,
+                user_id,
+                CreateUserBanDto {
+                    reason: reason.to_string(),
+                    duration_hours,
+                },
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Ban(BanError::InvalidBan(_)))
+        ));
+    }
+    assert!(ban_service.get_user_bans(user.id).await.unwrap().is_empty());
+
+    Ok(())
+}
+
+/// Tests banning a user that doesn't exist.
+///
+/// Expected: Err with UserNotInDatabase
+#[tokio::test]
+async fn fails_for_missing_user() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = BanService::new(&test.db)
+        .ban_user(
+            admin.id,
+            admin.id + 1,
+            CreateUserBanDto {
+                reason: "Awoxing".to_string(),
+                duration_hours: None,
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::UserNotInDatabase(_)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! Tests for BanService::ensure_not_suspended method.
+//!
+//! This module verifies that users are suspended while a ban is in effect, that expired bans
+//! no longer suspend them, and that permanent bans outlast temporary ones.
+
+use bifrost::server::{
+    data::ban::UserBanRepository,
+    error::{auth::AuthError, AppError},
+    service::ban::BanService,
+};
+use bifrost_test_utils::prelude::*;
+use chrono::{Duration, Utc};
+
+/// Tests checking a user without bans.
+///
+/// Expected: Ok
+#[tokio::test]
+async fn passes_user_without_bans() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = BanService::new(&test.db)
+        .ensure_not_suspended(user.id)
+        .await;
+
+    assert!(result.is_ok());
+
+    Ok(())
+}
+
+/// Tests checking a user whose ban has expired.
+///
+/// Expected: Ok
+#[tokio::test]
+async fn passes_user_with_expired_ban() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let now = Utc::now().naive_utc();
+    UserBanRepository::new(&test.db)
+        .create(
+            user.id,
+            "Spamming corp chat".to_string(),
+            None,
+            now - Duration::hours(2),
+            Some(now - Duration::hours(1)),
+        )
+        .await?;
+
+    let result = BanService::new(&test.db)
+        .ensure_not_suspended(user.id)
+        .await;
+
+    assert!(result.is_ok());
+
+    Ok(())
+}
+
+/// Tests checking a user with a temporary and a permanent ban.
+///
+/// Expected: Err with Suspended reporting the permanent ban
+#[tokio::test]
+async fn reports_longest_active_ban() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let now = Utc::now().naive_utc();
+    let ban_repo = UserBanRepository::new(&test.db);
+    ban_repo
+        .create(
+            user.id,
+            "Spamming corp chat".to_string(),
+            None,
+            now,
+            Some(now + Duration::hours(24)),
+        )
+        .await?;
+    ban_repo
+        .create(user.id, "Awoxing".to_string(), None, now, None)
+        .await?;
+
+    let result = BanService::new(&test.db)
+        .ensure_not_suspended(user.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::Suspended { ref reason, until: None })) if reason == "Awoxing"
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! Tests for BanService::lift_ban method.
+//!
+//! This module verifies that lifting a ban ends the suspension, that lifting a ban twice keeps
+//! the first lift, and that lifting a missing ban fails.
+
+use bifrost::{
+    model::ban::CreateUserBanDto,
+    server::{
+        error::{ban::BanError, AppError},
+        service::ban::BanService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests lifting an active ban.
+///
+/// Expected: Ok with the ban inactive and lifted by the admin, the user no longer suspended,
+/// and lifting it again by another admin keeping the first lift
+#[tokio::test]
+async fn lifts_active_ban() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (other_admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(3, 1, None, None)
+        .await?;
+
+    let ban_service = BanService::new(&test.db);
+    let ban = ban_service
+        .ban_user(
+            admin.id,
+            user.id,
+            CreateUserBanDto {
+                reason: "Awoxing".to_string(),
+                duration_hours: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    let lifted = ban_service.lift_ban(admin.id, ban.id).await.unwrap();
+
+    assert!(!lifted.active);
+    assert!(lifted.lifted_at.is_some());
+    assert_eq!(lifted.lifted_by_user_id, Some(admin.id));
+    assert!(ban_service.ensure_not_suspended(user.id).await.is_ok());
+
+    let lifted_again = ban_service.lift_ban(other_admin.id, ban.id).await.unwrap();
+    assert_eq!(lifted_again, lifted);
+
+    Ok(())
+}
+
+/// Tests lifting a ban that doesn't exist.
+///
+/// Expected: Err with BanNotFound
+#[tokio::test]
+async fn fails_for_missing_ban() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserBan)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = BanService::new(&test.db).lift_ban(admin.id, 1).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Ban(BanError::BanNotFound(1)))
+    ));
+
+    Ok(())
+}
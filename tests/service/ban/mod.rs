@@ -0,0 +1,3 @@
+mod ban_user;
+mod ensure_not_suspended;
+mod lift_ban;
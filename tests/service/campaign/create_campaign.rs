@@ -0,0 +1,133 @@
+//! Tests for CampaignService::create_campaign method.
+//!
+//! This module verifies creating campaigns with trimmed input and rejection of invalid date
+//! ranges and duplicate names.
+
+use bifrost::{
+    model::campaign::CreateCampaignDto,
+    server::{
+        error::{campaign::CampaignError, AppError},
+        service::campaign::CampaignService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+use chrono::{NaiveDate, NaiveDateTime};
+
+/// Builds a timestamp at midnight on the given day of January 2026.
+fn date(day: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 1, day)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+/// Tests creating an ongoing campaign.
+///
+/// Verifies that the name and staging system are stored trimmed.
+///
+/// Expected: Ok(CampaignDto) with trimmed fields and no end date
+#[tokio::test]
+async fn creates_ongoing_campaign() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostCampaign)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let campaign = CampaignService::new(&test.db)
+        .create_campaign(
+            user_model.id,
+            CreateCampaignDto {
+                name: " Fountain War ".to_string(),
+                staging_system: " Y-2ANO ".to_string(),
+                starts_at: date(1),
+                ends_at: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(campaign.name, "Fountain War");
+    assert_eq!(campaign.staging_system, "Y-2ANO");
+    assert_eq!(campaign.starts_at, date(1));
+    assert_eq!(campaign.ends_at, None);
+
+    Ok(())
+}
+
+/// Tests error handling for a campaign ending before it starts.
+///
+/// Expected: Err(AppError::Campaign(CampaignError::InvalidCampaign))
+#[tokio::test]
+async fn fails_for_end_before_start() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostCampaign)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = CampaignService::new(&test.db)
+        .create_campaign(
+            user_model.id,
+            CreateCampaignDto {
+                name: "Fountain War".to_string(),
+                staging_system: "Y-2ANO".to_string(),
+                starts_at: date(10),
+                ends_at: Some(date(1)),
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Campaign(CampaignError::InvalidCampaign(_)))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for a duplicate campaign name.
+///
+/// Expected: Err(AppError::Campaign(CampaignError::DuplicateCampaignName))
+#[tokio::test]
+async fn fails_for_duplicate_name() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostCampaign)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let campaign = CreateCampaignDto {
+        name: "Fountain War".to_string(),
+        staging_system: "Y-2ANO".to_string(),
+        starts_at: date(1),
+        ends_at: None,
+    };
+    let campaign_service = CampaignService::new(&test.db);
+    campaign_service
+        .create_campaign(user_model.id, campaign.clone())
+        .await
+        .unwrap();
+    let result = campaign_service
+        .create_campaign(user_model.id, campaign)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Campaign(CampaignError::DuplicateCampaignName(_)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//! Tests for CharacterSkillService::get_skills method.
+//!
+//! This module verifies reading the skill snapshot of a character owned by the user, and
+//! rejecting characters owned by other users or without a snapshot.
+
+use bifrost::server::{
+    data::character_skill::CharacterSkillRepository,
+    error::{character_skill::CharacterSkillError, AppError},
+    service::{character_skill::CharacterSkillService, eve::esi::EsiProvider},
+    util::crypto::ColumnCipher,
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests reading the skill snapshot of an owned character.
+///
+/// Verifies that skills are ordered by skill ID and that the total sums their skill points.
+///
+/// Expected: Ok with 2 skills totalling 301,000 skill points
+#[tokio::test]
+async fn returns_skills_of_owned_character() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .build()
+        .await?;
+    let (user, _, character) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    CharacterSkillRepository::new(&test.db)
+        .replace_skills(
+            character.id,
+            &[(3_300, 4, 5, 45_255), (3_301, 5, 5, 256_000)],
+        )
+        .await?;
+
+    let skills = CharacterSkillService::new(&test.db, &esi_provider, &cipher)
+        .get_skills(user.id, 95_000_001)
+        .await
+        .unwrap();
+
+    assert_eq!(skills.character_id, 95_000_001);
+    assert_eq!(skills.character_name, character.name);
+    assert_eq!(skills.total_skillpoints, 301_255);
+    assert_eq!(skills.skills.len(), 2);
+    assert_eq!(skills.skills[0].skill_id, 3_300);
+    assert_eq!(skills.skills[0].active_level, 4);
+    assert_eq!(skills.skills[0].trained_level, 5);
+    assert_eq!(skills.skills[1].skill_id, 3_301);
+
+    Ok(())
+}
+
+/// Tests that replacing a snapshot removes skills the character no longer has.
+///
+/// Expected: Ok with only the skill listed in the latest snapshot
+#[tokio::test]
+async fn returns_latest_snapshot() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .build()
+        .await?;
+    let (user, _, character) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    let skill_repo = CharacterSkillRepository::new(&test.db);
+    skill_repo
+        .replace_skills(
+            character.id,
+            &[(3_300, 4, 5, 45_255), (3_301, 5, 5, 256_000)],
+        )
+        .await?;
+    skill_repo
+        .replace_skills(character.id, &[(3_301, 5, 5, 256_000)])
+        .await?;
+
+    let skills = CharacterSkillService::new(&test.db, &esi_provider, &cipher)
+        .get_skills(user.id, 95_000_001)
+        .await
+        .unwrap();
+
+    assert_eq!(skills.total_skillpoints, 256_000);
+    assert_eq!(skills.skills.len(), 1);
+    assert_eq!(skills.skills[0].skill_id, 3_301);
+
+    Ok(())
+}
+
+/// Tests error handling for characters owned by another user.
+///
+/// Expected: Err(AppError::CharacterSkill(CharacterSkillError::CharacterNotFound))
+#[tokio::test]
+async fn fails_for_character_of_other_user() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .build()
+        .await?;
+    let (_, _, character) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    let (other_user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(95_000_002, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    CharacterSkillRepository::new(&test.db)
+        .replace_skills(character.id, &[(3_300, 4, 5, 45_255)])
+        .await?;
+
+    let result = CharacterSkillService::new(&test.db, &esi_provider, &cipher)
+        .get_skills(other_user.id, 95_000_001)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::CharacterSkill(
+            CharacterSkillError::CharacterNotFound(95_000_001)
+        ))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for characters whose skills were never fetched.
+///
+/// Expected: Err(AppError::CharacterSkill(CharacterSkillError::NotTracked))
+#[tokio::test]
+async fn fails_for_untracked_character() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    let result = CharacterSkillService::new(&test.db, &esi_provider, &cipher)
+        .get_skills(user.id, 95_000_001)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::CharacterSkill(CharacterSkillError::NotTracked(
+            95_000_001
+        )))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1 @@
+mod get_skills;
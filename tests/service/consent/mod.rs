@@ -0,0 +1 @@
+mod require_consent;
@@ -0,0 +1,98 @@
+//! Tests for ConsentService::require_consent method.
+//!
+//! This module verifies that services syncing user data are only allowed to proceed for
+//! categories the user has consented to, and that revoking consent takes effect
+//! immediately.
+
+use bifrost::{
+    model::consent::ConsentCategory,
+    server::{
+        error::{consent::ConsentError, AppError},
+        service::consent::ConsentService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests that consent is required only for categories the user has not granted.
+///
+/// Expected: Ok for the granted category, Err(ConsentNotGranted) for the others
+#[tokio::test]
+async fn requires_granted_category() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let consent_service = ConsentService::new(&test.db);
+    consent_service
+        .grant(user_model.id, ConsentCategory::Assets)
+        .await
+        .unwrap();
+
+    let granted = consent_service
+        .require_consent(user_model.id, ConsentCategory::Assets)
+        .await;
+    let not_granted = consent_service
+        .require_consent(user_model.id, ConsentCategory::Wallet)
+        .await;
+
+    assert!(granted.is_ok());
+    assert!(matches!(
+        not_granted,
+        Err(AppError::Consent(ConsentError::ConsentNotGranted {
+            category: ConsentCategory::Wallet,
+            ..
+        }))
+    ));
+
+    Ok(())
+}
+
+/// Tests that revoked consent is no longer accepted.
+///
+/// Verifies that revoke reports the consent as revoked, that require_consent fails
+/// afterwards, and that get_consents lists the category as not granted.
+///
+/// Expected: Err(ConsentNotGranted) after revoking
+#[tokio::test]
+async fn fails_after_revoking() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let consent_service = ConsentService::new(&test.db);
+    consent_service
+        .grant(user_model.id, ConsentCategory::Location)
+        .await
+        .unwrap();
+
+    let revoked = consent_service
+        .revoke(user_model.id, ConsentCategory::Location)
+        .await
+        .unwrap();
+    let result = consent_service
+        .require_consent(user_model.id, ConsentCategory::Location)
+        .await;
+    let consents = consent_service.get_consents(user_model.id).await.unwrap();
+
+    assert!(revoked);
+    assert!(matches!(
+        result,
+        Err(AppError::Consent(ConsentError::ConsentNotGranted { .. }))
+    ));
+    assert_eq!(consents.len(), ConsentCategory::ALL.len());
+    assert!(consents.iter().all(|consent| !consent.granted));
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! Tests for CorporationMemberService::get_members method.
+//!
+//! This module verifies listing the members of a tracked corporation with the users owning
+//! them, and rejecting corporations no director granted the membership scope for.
+
+use bifrost::server::{
+    data::{
+        corporation_member::CorporationMemberRepository, eve::corporation::CorporationRepository,
+    },
+    error::{corporation_member::CorporationMemberError, AppError},
+    service::{corporation_member::CorporationMemberService, eve::esi::EsiProvider},
+    util::crypto::{ColumnCipher, EncryptionKey},
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests listing the members of a tracked corporation.
+///
+/// Verifies that registered members carry their owning user and that members Bifrost has no
+/// character record for are listed without a name.
+///
+/// Expected: Ok with 2 members of which 1 is registered
+#[tokio::test]
+async fn lists_members_with_owning_users() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCorporationMember)
+        .with_table(entity::prelude::BifrostCorporationMemberDirector)
+        .build()
+        .await?;
+    let (user, _, character) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let service = CorporationMemberService::new(&test.db, &esi_provider, &cipher);
+    service.track_corporation(95_000_001).await.unwrap();
+
+    let corporation = CorporationRepository::new(&test.db)
+        .find_by_eve_id(98_000_001)
+        .await?
+        .unwrap();
+    CorporationMemberRepository::new(&test.db)
+        .replace_members(corporation.id, &[95_000_001, 95_000_002])
+        .await?;
+
+    let members = service.get_members(98_000_001).await.unwrap();
+
+    assert_eq!(members.corporation_id, 98_000_001);
+    assert_eq!(members.member_count, 2);
+    assert_eq!(members.registered_count, 1);
+    assert_eq!(members.members[0].character_id, 95_000_001);
+    assert_eq!(members.members[0].character_name, Some(character.name));
+    assert_eq!(members.members[0].user_id, Some(user.id));
+    assert_eq!(members.members[1].character_id, 95_000_002);
+    assert_eq!(members.members[1].character_name, None);
+    assert_eq!(members.members[1].user_id, None);
+
+    Ok(())
+}
+
+/// Tests error handling for corporations no director granted the membership scope for.
+///
+/// Expected: Err(AppError::CorporationMember(CorporationMemberError::NotTracked))
+#[tokio::test]
+async fn fails_for_untracked_corporation() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCorporationMember)
+        .with_table(entity::prelude::BifrostCorporationMemberDirector)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let result = CorporationMemberService::new(&test.db, &esi_provider, &cipher)
+        .get_members(98_000_001)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::CorporationMember(
+            CorporationMemberError::NotTracked(98_000_001)
+        ))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+mod get_members;
+mod track_corporation;
+mod update_members;
@@ -0,0 +1,168 @@
+//! Tests for CorporationMemberService::track_corporation method.
+//!
+//! This module verifies recording a character who granted the membership scope as their
+//! corporation's director, keeping a working director in place of other characters, and
+//! rejecting characters missing from the database.
+
+use bifrost::server::{
+    data::{
+        corporation_member::CorporationMemberRepository, eve::corporation::CorporationRepository,
+    },
+    error::{auth::AuthError, AppError},
+    service::{corporation_member::CorporationMemberService, eve::esi::EsiProvider},
+    util::crypto::{ColumnCipher, EncryptionKey},
+};
+use bifrost_test_utils::prelude::*;
+use chrono::Utc;
+
+/// Tests tracking the corporation of a character who granted the membership scope.
+///
+/// Verifies that the character is recorded as the director of their corporation.
+///
+/// Expected: Ok with the character's corporation ID and the character as director
+#[tokio::test]
+async fn records_character_as_director() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCorporationMemberDirector)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let corporation_id = CorporationMemberService::new(&test.db, &esi_provider, &cipher)
+        .track_corporation(95_000_001)
+        .await
+        .unwrap();
+
+    assert_eq!(corporation_id, Some(98_000_001));
+
+    let corporation = CorporationRepository::new(&test.db)
+        .find_by_eve_id(98_000_001)
+        .await?
+        .unwrap();
+    let director_id = CorporationMemberRepository::new(&test.db)
+        .get_director_character_id(corporation.id)
+        .await?;
+
+    assert_eq!(director_id, Some(95_000_001));
+
+    Ok(())
+}
+
+/// Tests another character of the corporation granting the scope while the director works.
+///
+/// Verifies that a member without the director role can't replace a working director.
+///
+/// Expected: Ok(None) with the first character still director
+#[tokio::test]
+async fn keeps_working_director() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCorporationMemberDirector)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .with_mock_character(95_000_002, 98_000_001, None, None)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let service = CorporationMemberService::new(&test.db, &esi_provider, &cipher);
+    service.track_corporation(95_000_001).await.unwrap();
+    let result = service.track_corporation(95_000_002).await;
+
+    assert!(matches!(result, Ok(None)));
+
+    let corporation = CorporationRepository::new(&test.db)
+        .find_by_eve_id(98_000_001)
+        .await?
+        .unwrap();
+    let director_id = CorporationMemberRepository::new(&test.db)
+        .get_director_character_id(corporation.id)
+        .await?;
+
+    assert_eq!(director_id, Some(95_000_001));
+
+    Ok(())
+}
+
+/// Tests another character of the corporation granting the scope after the director failed.
+///
+/// Verifies that the failing director is replaced and the failure cleared.
+///
+/// Expected: Ok with the corporation ID and the second character as director
+#[tokio::test]
+async fn replaces_failing_director() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCorporationMemberDirector)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .with_mock_character(95_000_002, 98_000_001, None, None)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let corporation = CorporationRepository::new(&test.db)
+        .find_by_eve_id(98_000_001)
+        .await?
+        .unwrap();
+    let member_repo = CorporationMemberRepository::new(&test.db);
+
+    let service = CorporationMemberService::new(&test.db, &esi_provider, &cipher);
+    service.track_corporation(95_000_001).await.unwrap();
+    member_repo
+        .set_director_failed_at(corporation.id, Some(Utc::now().naive_utc()))
+        .await?;
+    let result = service.track_corporation(95_000_002).await;
+
+    assert!(matches!(result, Ok(Some(98_000_001))));
+
+    let director = member_repo.get_director(corporation.id).await?.unwrap();
+    let director_id = member_repo
+        .get_director_character_id(corporation.id)
+        .await?;
+
+    assert_eq!(director_id, Some(95_000_002));
+    assert!(director.failed_at.is_none());
+
+    Ok(())
+}
+
+/// Tests error handling for characters missing from the database.
+///
+/// Expected: Err(AppError::Auth(AuthError::CharacterNotFound))
+#[tokio::test]
+async fn fails_for_unknown_character() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCorporationMemberDirector)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let result = CorporationMemberService::new(&test.db, &esi_provider, &cipher)
+        .track_corporation(95_000_001)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::CharacterNotFound))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+//! Tests for CorporationMemberService::update_members method.
+//!
+//! This module verifies fetching a tracked corporation's member list with the director's
+//! character token, and marking directors without a stored token as failing.
+
+use bifrost::server::{
+    data::{
+        character_token::CharacterTokenRepository,
+        corporation_member::CorporationMemberRepository,
+        eve::{character::CharacterRepository, corporation::CorporationRepository},
+    },
+    error::{token::TokenError, AppError},
+    service::{
+        corporation_member::CorporationMemberService, eve::esi::EsiProvider, token::TokenService,
+    },
+    util::crypto::{ColumnCipher, EncryptionKey},
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests fetching the member list with the director's character token.
+///
+/// Verifies that the director's stored character token is exchanged, its rotated token stored,
+/// and the fetched member list stored for the corporation.
+///
+/// Expected: Ok(2) with both members stored and the rotated token stored for the director
+#[tokio::test]
+async fn fetches_members_with_director_token() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::EveCorporationMember)
+        .with_table(entity::prelude::BifrostCorporationMemberDirector)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .with_mock_endpoint(|server| {
+            server
+                .mock("POST", "/v2/oauth/token")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::json!({
+                        "access_token": "access_token",
+                        "token_type": "Bearer",
+                        "expires_in": 1199,
+                        "refresh_token": "rotated_refresh_token",
+                    })
+                    .to_string(),
+                )
+                .expect(1)
+                .create()
+        })
+        .with_mock_endpoint(|server| {
+            server
+                .mock("GET", "/corporations/98000001/members")
+                .match_header("authorization", "Bearer access_token")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(serde_json::json!([95_000_001, 95_000_002]).to_string())
+                .expect(1)
+                .create()
+        })
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    TokenService::new(&test.db, &esi_provider, &cipher)
+        .store_token(95_000_001, "refresh_token", &[])
+        .await
+        .unwrap();
+    let service = CorporationMemberService::new(&test.db, &esi_provider, &cipher);
+    service.track_corporation(95_000_001).await.unwrap();
+
+    let result = service.update_members(98_000_001).await;
+
+    assert!(matches!(result, Ok(2)));
+    test.assert_mocks();
+
+    let corporation = CorporationRepository::new(&test.db)
+        .find_by_eve_id(98_000_001)
+        .await?
+        .unwrap();
+    let members = CorporationMemberRepository::new(&test.db)
+        .get_members(corporation.id)
+        .await?;
+    let member_ids: Vec<i64> = members.iter().map(|member| member.character_id).collect();
+
+    assert_eq!(member_ids, vec![95_000_001, 95_000_002]);
+
+    let character = CharacterRepository::new(&test.db)
+        .find_by_eve_id(95_000_001)
+        .await?
+        .unwrap();
+    let token = CharacterTokenRepository::new(&test.db)
+        .find_by_character_id(character.id)
+        .await?
+        .unwrap();
+
+    assert_eq!(
+        cipher.decrypt(&token.refresh_token).unwrap(),
+        "rotated_refresh_token"
+    );
+
+    Ok(())
+}
+
+/// Tests error handling for directors whose character token is missing.
+///
+/// Verifies that the director is marked as failing, so another character can replace them.
+///
+/// Expected: Err(AppError::Token(TokenError::NotFound)) with the director's failure recorded
+#[tokio::test]
+async fn fails_for_director_without_token() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::EveCorporationMember)
+        .with_table(entity::prelude::BifrostCorporationMemberDirector)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let service = CorporationMemberService::new(&test.db, &esi_provider, &cipher);
+    service.track_corporation(95_000_001).await.unwrap();
+
+    let result = service.update_members(98_000_001).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Token(TokenError::NotFound(95_000_001)))
+    ));
+
+    let corporation = CorporationRepository::new(&test.db)
+        .find_by_eve_id(98_000_001)
+        .await?
+        .unwrap();
+    let director = CorporationMemberRepository::new(&test.db)
+        .get_director(corporation.id)
+        .await?
+        .unwrap();
+
+    assert!(director.failed_at.is_some());
+
+    Ok(())
+}
@@ -0,0 +1 @@
+mod refresh_summaries;
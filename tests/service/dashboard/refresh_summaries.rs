@@ -0,0 +1,76 @@
+//! Tests for DashboardService::refresh_summaries method.
+//!
+//! This module verifies that refreshing the dashboard summaries aggregates users per main
+//! character corporation and the characters-per-user distribution.
+
+use bifrost::{model::dashboard::CharacterCountDto, server::service::dashboard::DashboardService};
+use bifrost_test_utils::prelude::*;
+
+/// Tests refreshing summaries for users with one and two characters.
+///
+/// Expected: Both users counted in their main character's corporation, one user per
+/// character count bucket
+#[tokio::test]
+async fn aggregates_users_and_characters() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostCorporationUserCount)
+        .with_table(entity::prelude::BifrostCharacterCountDistribution)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    test.user()
+        .insert_mock_character_for_user(user_model.id, 3, 2, None, None)
+        .await?;
+
+    let service = DashboardService::new(&test.db);
+    service.refresh_summaries().await.unwrap();
+    let summary = service.get_summary().await.unwrap();
+
+    assert_eq!(summary.corporation_user_counts.len(), 1);
+    assert_eq!(summary.corporation_user_counts[0].corporation_id, 1);
+    assert_eq!(summary.corporation_user_counts[0].user_count, 2);
+    assert_eq!(
+        summary.character_count_distribution,
+        vec![
+            CharacterCountDto {
+                character_count: 1,
+                user_count: 1
+            },
+            CharacterCountDto {
+                character_count: 2,
+                user_count: 1
+            },
+        ]
+    );
+    assert!(summary.refreshed_at.is_some());
+
+    Ok(())
+}
+
+/// Tests reading summaries before the first refresh.
+///
+/// Expected: Empty summaries without a refresh timestamp
+#[tokio::test]
+async fn returns_empty_summary_before_refresh() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostCorporationUserCount)
+        .with_table(entity::prelude::BifrostCharacterCountDistribution)
+        .build()
+        .await?;
+
+    let summary = DashboardService::new(&test.db).get_summary().await.unwrap();
+
+    assert!(summary.corporation_user_counts.is_empty());
+    assert!(summary.character_count_distribution.is_empty());
+    assert_eq!(summary.refreshed_at, None);
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! Tests for DataApiService::authenticate method.
+//!
+//! This module verifies that keys returned when created authorize requests until they are
+//! revoked, and that use of a key is recorded unless recording is skipped.
+
+use bifrost::{
+    model::data_api::CreateApiKeyDto,
+    server::{
+        error::{data_api::DataApiError, AppError},
+        service::data_api::DataApiService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+use chrono::Utc;
+
+/// Tests authenticating with a created key.
+///
+/// Expected: Ok with the key's last use recorded
+#[tokio::test]
+async fn accepts_created_key() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApiKey)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let data_api_service = DataApiService::new(&test.db);
+    let created = data_api_service
+        .create_api_key(
+            user_model.id,
+            CreateApiKeyDto {
+                name: "Grafana".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(created.api_key.last_used_at, None);
+
+    data_api_service
+        .authenticate(&created.key, Some(Utc::now().naive_utc()))
+        .await
+        .unwrap();
+
+    let api_keys = data_api_service.get_api_keys().await.unwrap();
+    assert_eq!(api_keys.len(), 1);
+    assert!(api_keys[0].last_used_at.is_some());
+
+    Ok(())
+}
+
+/// Tests authenticating without recording the key's use, as done in read-only mode.
+///
+/// Expected: Ok with the key's last use still unset
+#[tokio::test]
+async fn accepts_key_without_recording_use() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApiKey)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let data_api_service = DataApiService::new(&test.db);
+    let created = data_api_service
+        .create_api_key(
+            user_model.id,
+            CreateApiKeyDto {
+                name: "Grafana".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    data_api_service
+        .authenticate(&created.key, None)
+        .await
+        .unwrap();
+
+    let api_keys = data_api_service.get_api_keys().await.unwrap();
+    assert_eq!(api_keys[0].last_used_at, None);
+
+    Ok(())
+}
+
+/// Tests error handling for unknown and revoked keys.
+///
+/// Expected: Err(AppError::DataApi(DataApiError::InvalidApiKey))
+#[tokio::test]
+async fn fails_for_unknown_or_revoked_key() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostApiKey)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let data_api_service = DataApiService::new(&test.db);
+    let created = data_api_service
+        .create_api_key(
+            user_model.id,
+            CreateApiKeyDto {
+                name: "Grafana".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    data_api_service
+        .delete_api_key(created.api_key.id)
+        .await
+        .unwrap();
+
+    for key in ["unknown", created.key.as_str()] {
+        let result = data_api_service
+            .authenticate(key, Some(Utc::now().naive_utc()))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::DataApi(DataApiError::InvalidApiKey))
+        ));
+    }
+
+    Ok(())
+}
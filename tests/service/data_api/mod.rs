@@ -0,0 +1,3 @@
+mod authenticate;
+mod run_query;
+mod save_query;
@@ -0,0 +1,132 @@
+//! Tests for DataApiService::run_query method.
+//!
+//! This module verifies binding parameter values, limiting the rows returned, and rejecting
+//! missing or malformed parameter values.
+
+use std::collections::HashMap;
+
+use bifrost::{
+    model::data_api::{SaveSavedQueryDto, SavedQueryParameterDto, SavedQueryParameterKind},
+    server::{
+        error::{data_api::DataApiError, AppError},
+        service::data_api::DataApiService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds a query listing user IDs from a minimum ID with the given row limit.
+fn member_ids(row_limit: u32) -> SaveSavedQueryDto {
+    SaveSavedQueryDto {
+        name: "Member IDs".to_string(),
+        sql: "SELECT id FROM bifrost_user WHERE id >= $1 ORDER BY id".to_string(),
+        parameters: vec![SavedQueryParameterDto {
+            name: "min_id".to_string(),
+            kind: SavedQueryParameterKind::Integer,
+        }],
+        row_limit,
+    }
+}
+
+/// Tests running a query matching more rows than its row limit.
+///
+/// Verifies that the parameter is bound and that the result is truncated to the row limit.
+///
+/// Expected: Ok with the first matching row and `truncated` set
+#[tokio::test]
+async fn returns_rows_up_to_row_limit() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .build()
+        .await?;
+    let (first_user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (second_user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    test.user()
+        .insert_user_with_mock_character(3, 1, None, None)
+        .await?;
+
+    let data_api_service = DataApiService::new(&test.db);
+    data_api_service
+        .save_query(first_user.id, "member-ids", member_ids(1))
+        .await
+        .unwrap();
+
+    let result = data_api_service
+        .run_query(
+            "member-ids",
+            &HashMap::from([("min_id".to_string(), second_user.id.to_string())]),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.rows.len(), 1);
+    assert_eq!(result.rows[0]["id"], second_user.id);
+    assert!(result.truncated);
+
+    Ok(())
+}
+
+/// Tests error handling for missing and malformed parameter values.
+///
+/// Expected: Err(AppError::DataApi(DataApiError::InvalidParameter))
+#[tokio::test]
+async fn fails_for_invalid_parameter() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let data_api_service = DataApiService::new(&test.db);
+    data_api_service
+        .save_query(user_model.id, "member-ids", member_ids(100))
+        .await
+        .unwrap();
+
+    for values in [
+        HashMap::new(),
+        HashMap::from([("min_id".to_string(), "one".to_string())]),
+    ] {
+        let result = data_api_service.run_query("member-ids", &values).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::DataApi(DataApiError::InvalidParameter { .. }))
+        ));
+    }
+
+    Ok(())
+}
+
+/// Tests error handling for slugs without a saved query.
+///
+/// Expected: Err(AppError::DataApi(DataApiError::QueryNotFound))
+#[tokio::test]
+async fn fails_for_unknown_query() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .build()
+        .await?;
+
+    let result = DataApiService::new(&test.db)
+        .run_query("member-ids", &HashMap::new())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::DataApi(DataApiError::QueryNotFound(_)))
+    ));
+
+    Ok(())
+}
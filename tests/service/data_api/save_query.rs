@@ -0,0 +1,140 @@
+//! Tests for DataApiService::save_query method.
+//!
+//! This module verifies creating and replacing saved queries and rejecting SQL that isn't a
+//! single `SELECT` statement or references undeclared placeholders.
+
+use bifrost::{
+    model::data_api::{SaveSavedQueryDto, SavedQueryParameterDto, SavedQueryParameterKind},
+    server::{
+        error::{data_api::DataApiError, AppError},
+        service::data_api::DataApiService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds a saved query taking a single integer parameter.
+fn saved_query(name: &str, sql: &str) -> SaveSavedQueryDto {
+    SaveSavedQueryDto {
+        name: name.to_string(),
+        sql: sql.to_string(),
+        parameters: vec![SavedQueryParameterDto {
+            name: "min_id".to_string(),
+            kind: SavedQueryParameterKind::Integer,
+        }],
+        row_limit: 100,
+    }
+}
+
+/// Tests creating a saved query and replacing it by saving the same slug again.
+///
+/// Verifies that the trailing semicolon is removed and that parameters are stored with their
+/// kind.
+///
+/// Expected: Ok with a single query holding the replaced name
+#[tokio::test]
+async fn saves_and_replaces_query() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let data_api_service = DataApiService::new(&test.db);
+    data_api_service
+        .save_query(
+            user_model.id,
+            "members",
+            saved_query("Members", "SELECT id FROM bifrost_user WHERE id >= $1;"),
+        )
+        .await
+        .unwrap();
+    let saved = data_api_service
+        .save_query(
+            user_model.id,
+            "members",
+            saved_query(" Member IDs ", "SELECT id FROM bifrost_user WHERE id >= $1"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(saved.name, "Member IDs");
+    assert_eq!(saved.sql, "SELECT id FROM bifrost_user WHERE id >= $1");
+    assert_eq!(saved.parameters[0].kind, SavedQueryParameterKind::Integer);
+
+    let queries = data_api_service.get_saved_queries().await.unwrap();
+    assert_eq!(queries.len(), 1);
+    assert_eq!(queries[0], saved);
+
+    Ok(())
+}
+
+/// Tests error handling for SQL that isn't a `SELECT` statement.
+///
+/// Expected: Err(AppError::DataApi(DataApiError::InvalidQuery))
+#[tokio::test]
+async fn fails_for_non_select_sql() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let data_api_service = DataApiService::new(&test.db);
+    for sql in [
+        "DELETE FROM bifrost_user WHERE id >= $1",
+        "SELECT id FROM bifrost_user WHERE id >= $1; DELETE FROM bifrost_user",
+    ] {
+        let result = data_api_service
+            .save_query(user_model.id, "members", saved_query("Members", sql))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::DataApi(DataApiError::InvalidQuery(_)))
+        ));
+    }
+
+    Ok(())
+}
+
+/// Tests error handling for placeholders without a declared parameter.
+///
+/// Expected: Err(AppError::DataApi(DataApiError::InvalidQuery))
+#[tokio::test]
+async fn fails_for_undeclared_placeholder() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = DataApiService::new(&test.db)
+        .save_query(
+            user_model.id,
+            "members",
+            saved_query(
+                "Members",
+                "SELECT id FROM bifrost_user WHERE id >= $1 AND id <= $2",
+            ),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::DataApi(DataApiError::InvalidQuery(_)))
+    ));
+
+    Ok(())
+}
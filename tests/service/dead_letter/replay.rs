@@ -0,0 +1,104 @@
+//! Tests for DeadLetterService::replay method.
+//!
+//! This module verifies requeueing dead-lettered jobs with their stored or edited payloads,
+//! and that invalid replays leave the dead-letter entry in place.
+
+use bifrost::server::{
+    error::{dead_letter::DeadLetterError, AppError},
+    model::worker::WorkerJob,
+    service::dead_letter::DeadLetterService,
+};
+
+use crate::util::redis::RedisTest;
+use crate::worker::queue::setup_test_queue;
+
+/// Tests replaying a job with its stored payload.
+///
+/// Expected: Job pushed to the queue and removed from the dead-letter queue
+#[tokio::test]
+async fn requeues_stored_payload() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let queue = setup_test_queue(&redis);
+    let job = WorkerJob::UpdateCorporationInfo { corporation_id: 1 };
+    let id = queue.dead_letter(&job, "Failed", 10).await.unwrap();
+
+    let replay = DeadLetterService::new(&queue)
+        .replay(1, id, None)
+        .await
+        .unwrap();
+
+    assert!(replay.queued);
+    assert_eq!(queue.pop().await.unwrap().unwrap().job, job);
+    assert!(queue.get_dead_letter(id).await.unwrap().is_none());
+
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests replaying an affiliation batch with a bad character ID removed.
+///
+/// Expected: Edited job pushed to the queue instead of the stored job
+#[tokio::test]
+async fn requeues_edited_payload() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let queue = setup_test_queue(&redis);
+    let job = WorkerJob::UpdateAffiliations {
+        character_ids: vec![1, 2, 3],
+    };
+    let id = queue.dead_letter(&job, "Failed", 0).await.unwrap();
+
+    let edited = WorkerJob::UpdateAffiliations {
+        character_ids: vec![1, 3],
+    };
+    DeadLetterService::new(&queue)
+        .replay(1, id, Some(serde_json::to_string(&edited).unwrap()))
+        .await
+        .unwrap();
+
+    assert_eq!(queue.pop().await.unwrap().unwrap().job, edited);
+
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests replaying with a payload that isn't a valid worker job.
+///
+/// Expected: Err(DeadLetterError::InvalidPayload) with the entry kept and nothing queued
+#[tokio::test]
+async fn rejects_invalid_payload() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let queue = setup_test_queue(&redis);
+    let id = queue
+        .dead_letter(&WorkerJob::UpdateFactionInfo, "Failed", 0)
+        .await
+        .unwrap();
+
+    let result = DeadLetterService::new(&queue)
+        .replay(1, id, Some("{\"UnknownJob\":{}}".to_string()))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::DeadLetter(DeadLetterError::InvalidPayload(_)))
+    ));
+    assert!(queue.get_dead_letter(id).await.unwrap().is_some());
+    assert!(queue.is_empty().await.unwrap());
+
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests replaying an entry that doesn't exist.
+///
+/// Expected: Err(DeadLetterError::NotFound)
+#[tokio::test]
+async fn fails_for_missing_entry() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let queue = setup_test_queue(&redis);
+
+    let result = DeadLetterService::new(&queue).replay(1, 42, None).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::DeadLetter(DeadLetterError::NotFound(42)))
+    ));
+
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
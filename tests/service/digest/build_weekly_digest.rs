@@ -0,0 +1,112 @@
+//! Tests for DigestService::build_weekly_digest method.
+//!
+//! This module verifies that the digest lists members who registered during the past week and
+//! campaigns starting during the coming week.
+
+use bifrost::{
+    model::campaign::CreateCampaignDto,
+    server::service::{
+        campaign::CampaignService,
+        digest::{digest_to_markdown, DigestService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+use chrono::{Duration, NaiveDateTime, Utc};
+use sea_orm::{ActiveModelTrait, ActiveValue};
+
+/// Builds a campaign staged out of 1DQ1-A starting at the given time.
+fn campaign(name: &str, starts_at: NaiveDateTime) -> CreateCampaignDto {
+    CreateCampaignDto {
+        name: name.to_string(),
+        staging_system: "1DQ1-A".to_string(),
+        starts_at,
+        ends_at: None,
+    }
+}
+
+/// Tests compiling the digest for the past week.
+///
+/// Verifies that members registered more than a week ago and campaigns starting after the
+/// coming week are left out, and that the queue figures are included as given.
+///
+/// Expected: Ok with the new member and the upcoming campaign
+#[tokio::test]
+async fn summarizes_past_and_coming_week() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostCampaign)
+        .build()
+        .await?;
+    let now = Utc::now().naive_utc();
+    let (new_member, _, new_member_character) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (veteran, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    entity::bifrost_user::ActiveModel {
+        id: ActiveValue::Unchanged(veteran.id),
+        created_at: ActiveValue::Set(now - Duration::days(30)),
+        ..Default::default()
+    }
+    .update(&test.db)
+    .await?;
+
+    let campaign_service = CampaignService::new(&test.db);
+    campaign_service
+        .create_campaign(
+            new_member.id,
+            campaign("Delve Deployment", now + Duration::days(2)),
+        )
+        .await
+        .unwrap();
+    campaign_service
+        .create_campaign(
+            new_member.id,
+            campaign("Fountain War", now + Duration::days(20)),
+        )
+        .await
+        .unwrap();
+
+    let digest = DigestService::new(&test.db)
+        .build_weekly_digest(now, 12, 3)
+        .await
+        .unwrap();
+
+    assert_eq!(digest.new_members, vec![new_member_character.name]);
+    assert_eq!(digest.upcoming_campaigns.len(), 1);
+    assert_eq!(digest.upcoming_campaigns[0].name, "Delve Deployment");
+    assert_eq!(digest.queued_jobs, 12);
+    assert_eq!(digest.dead_letter_jobs, 3);
+
+    let markdown = digest_to_markdown(&digest);
+    assert!(markdown.contains("Delve Deployment staging in 1DQ1-A"));
+    assert!(markdown.contains("- 3 job(s) in the dead-letter queue"));
+
+    Ok(())
+}
+
+/// Tests compiling the digest for a quiet week.
+///
+/// Expected: Ok with no new members or campaigns, rendered as "None"
+#[tokio::test]
+async fn renders_empty_sections() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostCampaign)
+        .build()
+        .await?;
+
+    let digest = DigestService::new(&test.db)
+        .build_weekly_digest(Utc::now().naive_utc(), 0, 0)
+        .await
+        .unwrap();
+
+    assert!(digest.new_members.is_empty());
+    assert!(digest.upcoming_campaigns.is_empty());
+    assert_eq!(digest_to_markdown(&digest).matches("- None").count(), 2);
+
+    Ok(())
+}
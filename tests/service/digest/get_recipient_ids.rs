@@ -0,0 +1,61 @@
+//! Tests for DigestService::get_recipient_ids method.
+//!
+//! This module verifies that users receive the weekly digest by default and that users who
+//! turned it off in their preferences are skipped.
+
+use bifrost::{
+    model::preference::{DashboardWidget, UserPreferencesDto},
+    server::service::{digest::DigestService, preference::PreferenceService},
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests resolving the recipients of the weekly digest.
+///
+/// Verifies that a user without saved preferences and a user who kept the digest on receive
+/// it, while a user who turned it off doesn't.
+///
+/// Expected: Ok with every user except the one who opted out
+#[tokio::test]
+async fn skips_opted_out_users() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserPreference)
+        .build()
+        .await?;
+    let (default_user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (opted_in, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let (opted_out, _, _) = test
+        .user()
+        .insert_user_with_mock_character(3, 1, None, None)
+        .await?;
+
+    let preference_service = PreferenceService::new(&test.db);
+    for (user_id, weekly_digest) in [(opted_in.id, true), (opted_out.id, false)] {
+        preference_service
+            .update_preferences(
+                user_id,
+                UserPreferencesDto {
+                    dashboard_layout: DashboardWidget::DEFAULT_LAYOUT.to_vec(),
+                    weekly_digest,
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    let mut recipient_ids = DigestService::new(&test.db)
+        .get_recipient_ids()
+        .await
+        .unwrap();
+    recipient_ids.sort();
+
+    assert_eq!(recipient_ids, vec![default_user.id, opted_in.id]);
+
+    Ok(())
+}
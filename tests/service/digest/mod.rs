@@ -0,0 +1,2 @@
+mod build_weekly_digest;
+mod get_recipient_ids;
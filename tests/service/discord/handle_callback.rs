@@ -0,0 +1,177 @@
+//! Tests for DiscordLinkService::handle_callback method.
+//!
+//! This module verifies linking the Discord account an authorization code belongs to,
+//! replacing a previously linked account, and rejecting accounts linked to another user or
+//! callbacks while Discord linking is disabled.
+
+use bifrost::server::{
+    data::user::discord::UserDiscordRepository,
+    error::{auth::AuthError, AppError},
+    service::auth::discord::{DiscordConfig, DiscordLinkService, DiscordOAuthSettings},
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds Discord linking settings sending requests to the mocked Discord API.
+fn discord_config(api_url: String) -> DiscordConfig {
+    DiscordConfig {
+        oauth: Some(DiscordOAuthSettings {
+            client_id: "client_id".to_string(),
+            client_secret: "client_secret".to_string(),
+            callback_url: reqwest::Url::parse("http://localhost:8080/api/auth/discord/callback")
+                .unwrap(),
+        }),
+        api_url,
+        ..Default::default()
+    }
+}
+
+/// Tests linking a Discord account to a user without one.
+///
+/// Verifies that the code is exchanged, the Discord user is looked up, and the account is
+/// stored for the user.
+///
+/// Expected: Ok with the linked account
+#[tokio::test]
+async fn links_discord_account() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserDiscord)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let config = discord_config(test.auth().discord_api_url());
+    let mocks = test
+        .auth()
+        .create_discord_endpoints(80351110224678912, "nelly");
+
+    let account = DiscordLinkService::new(&test.db, &config)
+        .handle_callback("code", user.id)
+        .await
+        .unwrap();
+
+    assert_eq!(account.discord_id, "80351110224678912");
+    assert_eq!(account.username, "nelly");
+
+    let stored = UserDiscordRepository::new(&test.db)
+        .get_by_user_id(user.id)
+        .await?
+        .unwrap();
+    assert_eq!(stored.discord_id, 80351110224678912);
+
+    for mock in mocks {
+        mock.assert();
+    }
+
+    Ok(())
+}
+
+/// Tests linking a different Discord account to a user who already linked one.
+///
+/// Expected: Ok with the previous account replaced
+#[tokio::test]
+async fn replaces_previously_linked_account() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserDiscord)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    UserDiscordRepository::new(&test.db)
+        .upsert(user.id, 1, "old_account")
+        .await?;
+    let config = discord_config(test.auth().discord_api_url());
+    let _mocks = test.auth().create_discord_endpoints(2, "new_account");
+
+    let account = DiscordLinkService::new(&test.db, &config)
+        .handle_callback("code", user.id)
+        .await
+        .unwrap();
+
+    assert_eq!(account.discord_id, "2");
+    assert_eq!(account.username, "new_account");
+
+    let discord_repo = UserDiscordRepository::new(&test.db);
+    assert!(discord_repo.get_by_discord_id(1).await?.is_none());
+    assert!(discord_repo.get_by_discord_id(2).await?.is_some());
+
+    Ok(())
+}
+
+/// Tests linking a Discord account that is already linked to another user.
+///
+/// Expected: Err(AuthError::DiscordAccountLinkedToAnotherUser) and the link unchanged
+#[tokio::test]
+async fn fails_for_account_linked_to_another_user() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserDiscord)
+        .build()
+        .await?;
+    let (owner, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    UserDiscordRepository::new(&test.db)
+        .upsert(owner.id, 1, "shared_account")
+        .await?;
+    let config = discord_config(test.auth().discord_api_url());
+    let _mocks = test.auth().create_discord_endpoints(1, "shared_account");
+
+    let result = DiscordLinkService::new(&test.db, &config)
+        .handle_callback("code", user.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(
+            AuthError::DiscordAccountLinkedToAnotherUser(1)
+        ))
+    ));
+
+    let discord_repo = UserDiscordRepository::new(&test.db);
+    assert_eq!(
+        discord_repo.get_by_discord_id(1).await?.unwrap().user_id,
+        owner.id
+    );
+    assert!(discord_repo.get_by_user_id(user.id).await?.is_none());
+
+    Ok(())
+}
+
+/// Tests completing the callback while Discord linking is disabled.
+///
+/// Expected: Err(AuthError::DiscordNotConfigured) without requests to Discord
+#[tokio::test]
+async fn fails_when_not_configured() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserDiscord)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let config = DiscordConfig::default();
+
+    let result = DiscordLinkService::new(&test.db, &config)
+        .handle_callback("code", user.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::DiscordNotConfigured))
+    ));
+
+    Ok(())
+}
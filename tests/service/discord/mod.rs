@@ -0,0 +1 @@
+mod handle_callback;
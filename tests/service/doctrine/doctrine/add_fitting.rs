@@ -0,0 +1,108 @@
+//! Tests for DoctrineService::add_fitting method.
+//!
+//! This module verifies adding fittings to doctrines, including the fitting appearing in
+//! the doctrine listing and error handling for nonexistent doctrines and fittings.
+
+use bifrost::server::{
+    error::{doctrine::DoctrineError, AppError},
+    service::doctrine::{fitting::FittingService, DoctrineService},
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests adding a fitting to a doctrine.
+///
+/// Verifies that the fitting is returned as part of the doctrine when listing doctrines.
+///
+/// Expected: Ok with the doctrine containing the fitting
+#[tokio::test]
+async fn adds_fitting_to_doctrine() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostDoctrine)
+        .with_table(entity::prelude::BifrostDoctrineFitting)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let fitting = FittingService::new(&test.db)
+        .import_fitting(user_model.id, "[Rifter, Tackle Rifter]".to_string())
+        .await
+        .unwrap();
+    let doctrine_service = DoctrineService::new(&test.db);
+    let doctrine = doctrine_service
+        .create_doctrine("Frigates".to_string(), None)
+        .await
+        .unwrap();
+
+    let result = doctrine_service.add_fitting(doctrine.id, fitting.id).await;
+
+    assert!(result.is_ok());
+    let doctrines = doctrine_service.get_doctrines().await.unwrap();
+    assert_eq!(doctrines.len(), 1);
+    assert_eq!(doctrines[0].fittings.len(), 1);
+    assert_eq!(doctrines[0].fittings[0].id, fitting.id);
+
+    Ok(())
+}
+
+/// Tests error handling for a nonexistent fitting.
+///
+/// Expected: Err(AppError::Doctrine(DoctrineError::FittingNotFound))
+#[tokio::test]
+async fn fails_for_nonexistent_fitting() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostDoctrine)
+        .with_table(entity::prelude::BifrostDoctrineFitting)
+        .build()
+        .await?;
+
+    let doctrine_service = DoctrineService::new(&test.db);
+    let doctrine = doctrine_service
+        .create_doctrine("Frigates".to_string(), None)
+        .await
+        .unwrap();
+
+    let nonexistent_fitting_id = 1;
+    let result = doctrine_service
+        .add_fitting(doctrine.id, nonexistent_fitting_id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Doctrine(DoctrineError::FittingNotFound(1)))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for a nonexistent doctrine.
+///
+/// Expected: Err(AppError::Doctrine(DoctrineError::DoctrineNotFound))
+#[tokio::test]
+async fn fails_for_nonexistent_doctrine() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostDoctrine)
+        .with_table(entity::prelude::BifrostDoctrineFitting)
+        .build()
+        .await?;
+
+    let nonexistent_doctrine_id = 1;
+    let result = DoctrineService::new(&test.db)
+        .add_fitting(nonexistent_doctrine_id, 1)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Doctrine(DoctrineError::DoctrineNotFound(1)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//! Tests for DoctrineService::create_doctrine method.
+//!
+//! This module verifies doctrine creation behavior, including successful creation of a
+//! doctrine without fittings and rejection of duplicate doctrine names.
+
+use bifrost::server::{
+    error::{doctrine::DoctrineError, AppError},
+    service::doctrine::DoctrineService,
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests creating a new doctrine.
+///
+/// Verifies that the doctrine service creates a doctrine with the provided name and
+/// description and no fittings.
+///
+/// Expected: Ok(DoctrineDto) with no fittings
+#[tokio::test]
+async fn creates_doctrine() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::BifrostDoctrine)
+        .build()
+        .await?;
+
+    let doctrine_service = DoctrineService::new(&test.db);
+    let result = doctrine_service
+        .create_doctrine(
+            "Shield Cruisers".to_string(),
+            Some("Home defense".to_string()),
+        )
+        .await;
+
+    assert!(result.is_ok());
+    let doctrine = result.unwrap();
+    assert_eq!(doctrine.name, "Shield Cruisers");
+    assert_eq!(doctrine.description.as_deref(), Some("Home defense"));
+    assert!(doctrine.fittings.is_empty());
+
+    Ok(())
+}
+
+/// Tests error handling for a duplicate doctrine name.
+///
+/// Verifies that the doctrine service rejects creating a doctrine whose name is already
+/// in use.
+///
+/// Expected: Err(AppError::Doctrine(DoctrineError::DuplicateDoctrineName))
+#[tokio::test]
+async fn fails_for_duplicate_name() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::BifrostDoctrine)
+        .build()
+        .await?;
+
+    let doctrine_service = DoctrineService::new(&test.db);
+    doctrine_service
+        .create_doctrine("Shield Cruisers".to_string(), None)
+        .await
+        .unwrap();
+    let result = doctrine_service
+        .create_doctrine("Shield Cruisers".to_string(), None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Doctrine(DoctrineError::DuplicateDoctrineName(_)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+pub mod add_fitting;
+pub mod create_doctrine;
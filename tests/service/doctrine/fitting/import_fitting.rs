@@ -0,0 +1,77 @@
+//! Tests for FittingService::import_fitting method.
+//!
+//! This module verifies fitting import behavior, including extraction of the ship hull,
+//! fitting name, and items from EFT text, and rejection of invalid EFT input.
+
+use bifrost::server::{
+    error::{doctrine::DoctrineError, AppError},
+    service::doctrine::fitting::FittingService,
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests importing a valid EFT fitting.
+///
+/// Verifies that the fitting service stores the fitting and returns a DTO with the ship hull,
+/// fitting name, and parsed items.
+///
+/// Expected: Ok(FittingDto) with parsed fields
+#[tokio::test]
+async fn imports_valid_fitting() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let eft = "[Rifter, Tackle Rifter]\nWarp Scrambler II\nWarrior II x3".to_string();
+    let fitting_service = FittingService::new(&test.db);
+    let result = fitting_service
+        .import_fitting(user_model.id, eft.clone())
+        .await;
+
+    assert!(result.is_ok());
+    let fitting = result.unwrap();
+    assert_eq!(fitting.ship_type_name, "Rifter");
+    assert_eq!(fitting.name, "Tackle Rifter");
+    assert_eq!(fitting.items.len(), 2);
+    assert_eq!(fitting.items[1].quantity, 3);
+    assert_eq!(fitting.eft, eft);
+
+    Ok(())
+}
+
+/// Tests error handling for invalid EFT text.
+///
+/// Verifies that the fitting service rejects text without a valid `[Ship, Name]` header and
+/// does not store a fitting.
+///
+/// Expected: Err(AppError::Doctrine(DoctrineError::InvalidFitting))
+#[tokio::test]
+async fn fails_for_invalid_eft() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let fitting_service = FittingService::new(&test.db);
+    let result = fitting_service
+        .import_fitting(user_model.id, "Warp Scrambler II".to_string())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Doctrine(DoctrineError::InvalidFitting(_)))
+    ));
+    assert!(fitting_service.get_fittings().await.unwrap().is_empty());
+
+    Ok(())
+}
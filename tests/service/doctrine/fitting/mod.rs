@@ -0,0 +1,2 @@
+pub mod import_fitting;
+pub mod set_required_skills;
@@ -0,0 +1,199 @@
+//! Tests for FittingService::set_required_skills method.
+//!
+//! This module verifies replacing the skills required to fly a fitting, and rejecting skills
+//! listed twice, levels outside 1 to 5, and nonexistent fittings.
+
+use bifrost::{
+    model::doctrine::FittingSkillDto,
+    server::{
+        error::{doctrine::DoctrineError, AppError},
+        service::doctrine::fitting::FittingService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests replacing the required skills of a fitting.
+///
+/// Verifies that skills from an earlier call are removed and the requirements are returned
+/// ordered by skill ID.
+///
+/// Expected: Ok with only the skills of the latest call
+#[tokio::test]
+async fn replaces_required_skills() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let fitting_service = FittingService::new(&test.db);
+    let fitting = fitting_service
+        .import_fitting(user_model.id, "[Rifter, Tackle Rifter]".to_string())
+        .await
+        .unwrap();
+    fitting_service
+        .set_required_skills(
+            fitting.id,
+            vec![FittingSkillDto {
+                skill_id: 3_330,
+                level: 3,
+            }],
+        )
+        .await
+        .unwrap();
+
+    let skills = fitting_service
+        .set_required_skills(
+            fitting.id,
+            vec![
+                FittingSkillDto {
+                    skill_id: 3_331,
+                    level: 4,
+                },
+                FittingSkillDto {
+                    skill_id: 3_300,
+                    level: 1,
+                },
+            ],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(skills.len(), 2);
+    assert_eq!(skills[0].skill_id, 3_300);
+    assert_eq!(skills[1].skill_id, 3_331);
+    assert_eq!(skills[1].level, 4);
+    let stored = fitting_service
+        .get_required_skills(fitting.id)
+        .await
+        .unwrap();
+    assert_eq!(stored.len(), 2);
+    assert_eq!(stored[0].skill_id, 3_300);
+
+    Ok(())
+}
+
+/// Tests error handling for a level outside 1 to 5.
+///
+/// Expected: Err(AppError::Doctrine(DoctrineError::InvalidSkillRequirement))
+#[tokio::test]
+async fn fails_for_invalid_level() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let fitting_service = FittingService::new(&test.db);
+    let fitting = fitting_service
+        .import_fitting(user_model.id, "[Rifter, Tackle Rifter]".to_string())
+        .await
+        .unwrap();
+
+    let result = fitting_service
+        .set_required_skills(
+            fitting.id,
+            vec![FittingSkillDto {
+                skill_id: 3_330,
+                level: 6,
+            }],
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Doctrine(DoctrineError::InvalidSkillRequirement(
+            3_330
+        )))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for a skill listed twice.
+///
+/// Expected: Err(AppError::Doctrine(DoctrineError::InvalidSkillRequirement))
+#[tokio::test]
+async fn fails_for_duplicate_skill() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let fitting_service = FittingService::new(&test.db);
+    let fitting = fitting_service
+        .import_fitting(user_model.id, "[Rifter, Tackle Rifter]".to_string())
+        .await
+        .unwrap();
+
+    let result = fitting_service
+        .set_required_skills(
+            fitting.id,
+            vec![
+                FittingSkillDto {
+                    skill_id: 3_330,
+                    level: 3,
+                },
+                FittingSkillDto {
+                    skill_id: 3_330,
+                    level: 4,
+                },
+            ],
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Doctrine(DoctrineError::InvalidSkillRequirement(
+            3_330
+        )))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for a nonexistent fitting.
+///
+/// Expected: Err(AppError::Doctrine(DoctrineError::FittingNotFound))
+#[tokio::test]
+async fn fails_for_nonexistent_fitting() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .build()
+        .await?;
+
+    let result = FittingService::new(&test.db)
+        .set_required_skills(
+            1,
+            vec![FittingSkillDto {
+                skill_id: 3_330,
+                level: 3,
+            }],
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Doctrine(DoctrineError::FittingNotFound(1)))
+    ));
+
+    Ok(())
+}
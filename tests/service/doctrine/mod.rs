@@ -0,0 +1,3 @@
+mod doctrine;
+mod fitting;
+mod readiness;
@@ -0,0 +1,175 @@
+//! Tests for DoctrineReadinessService::get_corporation_readiness method.
+//!
+//! This module verifies computing the share of a corporation's tracked characters able to fly
+//! a doctrine and each of its fittings, and error handling for unknown corporations.
+
+use bifrost::{
+    model::doctrine::FittingSkillDto,
+    server::{
+        data::character_skill::CharacterSkillRepository,
+        error::{character_skill::CharacterSkillError, AppError},
+        service::{
+            doctrine::{
+                fitting::FittingService, readiness::DoctrineReadinessService, DoctrineService,
+            },
+            eve::esi::EsiProvider,
+        },
+        util::crypto::ColumnCipher,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests computing readiness percentages.
+///
+/// Verifies that only the corporation's characters with a skill snapshot count, that each
+/// fitting reports the share of them meeting its requirements, and that the doctrine counts
+/// characters able to fly any of its fittings.
+///
+/// Expected: Ok with 4 tracked characters, 1 and 2 pilots per fitting, and 2 for the doctrine
+#[tokio::test]
+async fn computes_readiness_percentages() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .with_table(entity::prelude::BifrostDoctrine)
+        .with_table(entity::prelude::BifrostDoctrineFitting)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    let snapshots: [(i64, i64, &[(i64, i32, i32, i64)]); 5] = [
+        (
+            95_000_002,
+            98_000_001,
+            &[(3_330, 5, 5, 256_000), (3_331, 3, 3, 40_000)],
+        ),
+        (95_000_003, 98_000_001, &[(3_330, 3, 3, 40_000)]),
+        (95_000_004, 98_000_001, &[(3_331, 4, 4, 135_765)]),
+        (95_000_005, 98_000_001, &[(3_300, 1, 1, 250)]),
+        (
+            95_000_006,
+            98_000_002,
+            &[(3_330, 5, 5, 256_000), (3_331, 5, 5, 256_000)],
+        ),
+    ];
+    for (character_id, corporation_id, skills) in snapshots {
+        let character = test
+            .eve()
+            .insert_mock_character(character_id, corporation_id, None, None)
+            .await?;
+        CharacterSkillRepository::new(&test.db)
+            .replace_skills(character.id, skills)
+            .await?;
+    }
+
+    let fitting_service = FittingService::new(&test.db);
+    let rifter = fitting_service
+        .import_fitting(user.id, "[Rifter, Tackle Rifter]".to_string())
+        .await
+        .unwrap();
+    fitting_service
+        .set_required_skills(
+            rifter.id,
+            vec![
+                FittingSkillDto {
+                    skill_id: 3_330,
+                    level: 4,
+                },
+                FittingSkillDto {
+                    skill_id: 3_331,
+                    level: 3,
+                },
+            ],
+        )
+        .await
+        .unwrap();
+    let thrasher = fitting_service
+        .import_fitting(user.id, "[Thrasher, Artillery Thrasher]".to_string())
+        .await
+        .unwrap();
+    fitting_service
+        .set_required_skills(
+            thrasher.id,
+            vec![FittingSkillDto {
+                skill_id: 3_331,
+                level: 3,
+            }],
+        )
+        .await
+        .unwrap();
+    let doctrine_service = DoctrineService::new(&test.db);
+    let doctrine = doctrine_service
+        .create_doctrine("Frigates".to_string(), None)
+        .await
+        .unwrap();
+    doctrine_service
+        .add_fitting(doctrine.id, rifter.id)
+        .await
+        .unwrap();
+    doctrine_service
+        .add_fitting(doctrine.id, thrasher.id)
+        .await
+        .unwrap();
+
+    let readiness = DoctrineReadinessService::new(&test.db, &esi_provider, &cipher)
+        .get_corporation_readiness(98_000_001, doctrine.id)
+        .await
+        .unwrap();
+
+    assert_eq!(readiness.corporation_id, 98_000_001);
+    assert_eq!(readiness.tracked_character_count, 4);
+    assert_eq!(readiness.pilot_count, 2);
+    assert_eq!(readiness.readiness_percent, 50);
+    assert_eq!(readiness.fittings.len(), 2);
+    assert_eq!(readiness.fittings[0].fitting_id, rifter.id);
+    assert_eq!(readiness.fittings[0].pilot_count, 1);
+    assert_eq!(readiness.fittings[0].readiness_percent, 25);
+    assert_eq!(readiness.fittings[1].fitting_id, thrasher.id);
+    assert_eq!(readiness.fittings[1].pilot_count, 2);
+    assert_eq!(readiness.fittings[1].readiness_percent, 50);
+
+    Ok(())
+}
+
+/// Tests error handling for a corporation that isn't stored.
+///
+/// Expected: Err(AppError::CharacterSkill(CharacterSkillError::CorporationNotFound))
+#[tokio::test]
+async fn fails_for_unknown_corporation() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .with_table(entity::prelude::BifrostDoctrine)
+        .with_table(entity::prelude::BifrostDoctrineFitting)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    let doctrine = DoctrineService::new(&test.db)
+        .create_doctrine("Frigates".to_string(), None)
+        .await
+        .unwrap();
+
+    let result = DoctrineReadinessService::new(&test.db, &esi_provider, &cipher)
+        .get_corporation_readiness(98_000_001, doctrine.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::CharacterSkill(
+            CharacterSkillError::CorporationNotFound(98_000_001)
+        ))
+    ));
+
+    Ok(())
+}
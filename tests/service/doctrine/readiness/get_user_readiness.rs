@@ -0,0 +1,229 @@
+//! Tests for DoctrineReadinessService::get_user_readiness method.
+//!
+//! This module verifies reporting which fittings of a doctrine a user's characters can fly,
+//! including the missing skills, characters whose skills aren't tracked, and nonexistent
+//! doctrines.
+
+use bifrost::{
+    model::doctrine::FittingSkillDto,
+    server::{
+        data::character_skill::CharacterSkillRepository,
+        error::{doctrine::DoctrineError, AppError},
+        service::{
+            doctrine::{
+                fitting::FittingService, readiness::DoctrineReadinessService, DoctrineService,
+            },
+            eve::esi::EsiProvider,
+        },
+        util::crypto::ColumnCipher,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests reporting the fittings a character can fly.
+///
+/// Verifies that a fitting whose required levels the character's active levels meet can be
+/// flown, while a fitting requiring a higher or untrained skill lists those skills as missing.
+///
+/// Expected: Ok with the first fitting flyable and 2 missing skills for the second
+#[tokio::test]
+async fn reports_missing_skills() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .with_table(entity::prelude::BifrostDoctrine)
+        .with_table(entity::prelude::BifrostDoctrineFitting)
+        .build()
+        .await?;
+    let (user, _, character) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    CharacterSkillRepository::new(&test.db)
+        .replace_skills(
+            character.id,
+            &[(3_330, 4, 4, 135_765), (3_331, 2, 5, 256_000)],
+        )
+        .await?;
+    let fitting_service = FittingService::new(&test.db);
+    let rifter = fitting_service
+        .import_fitting(user.id, "[Rifter, Tackle Rifter]".to_string())
+        .await
+        .unwrap();
+    fitting_service
+        .set_required_skills(
+            rifter.id,
+            vec![FittingSkillDto {
+                skill_id: 3_330,
+                level: 3,
+            }],
+        )
+        .await
+        .unwrap();
+    let thrasher = fitting_service
+        .import_fitting(user.id, "[Thrasher, Artillery Thrasher]".to_string())
+        .await
+        .unwrap();
+    fitting_service
+        .set_required_skills(
+            thrasher.id,
+            vec![
+                FittingSkillDto {
+                    skill_id: 3_331,
+                    level: 3,
+                },
+                FittingSkillDto {
+                    skill_id: 3_332,
+                    level: 1,
+                },
+            ],
+        )
+        .await
+        .unwrap();
+    let doctrine_service = DoctrineService::new(&test.db);
+    let doctrine = doctrine_service
+        .create_doctrine("Frigates".to_string(), None)
+        .await
+        .unwrap();
+    doctrine_service
+        .add_fitting(doctrine.id, rifter.id)
+        .await
+        .unwrap();
+    doctrine_service
+        .add_fitting(doctrine.id, thrasher.id)
+        .await
+        .unwrap();
+
+    let readiness = DoctrineReadinessService::new(&test.db, &esi_provider, &cipher)
+        .get_user_readiness(user.id, doctrine.id)
+        .await
+        .unwrap();
+
+    assert_eq!(readiness.doctrine_name, "Frigates");
+    assert_eq!(readiness.characters.len(), 1);
+    let character = &readiness.characters[0];
+    assert_eq!(character.character_id, 95_000_001);
+    assert!(character.skills_tracked);
+    assert_eq!(character.fittings.len(), 2);
+    assert_eq!(character.fittings[0].fitting_id, rifter.id);
+    assert!(character.fittings[0].can_fly);
+    assert!(character.fittings[0].missing_skills.is_empty());
+    assert_eq!(character.fittings[1].fitting_id, thrasher.id);
+    assert!(!character.fittings[1].can_fly);
+    let missing = &character.fittings[1].missing_skills;
+    assert_eq!(missing.len(), 2);
+    assert_eq!(missing[0].skill_id, 3_331);
+    assert_eq!(missing[0].required_level, 3);
+    assert_eq!(missing[0].active_level, 2);
+    assert_eq!(missing[1].skill_id, 3_332);
+    assert_eq!(missing[1].active_level, 0);
+
+    Ok(())
+}
+
+/// Tests listing a character whose skills were never fetched.
+///
+/// Verifies that the character is reported as untracked without fittings rather than
+/// reported as unable to fly them, while the user's tracked character is still checked.
+///
+/// Expected: Ok with the untracked character listed without fittings
+#[tokio::test]
+async fn lists_untracked_character_without_fittings() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .with_table(entity::prelude::BifrostDoctrine)
+        .with_table(entity::prelude::BifrostDoctrineFitting)
+        .build()
+        .await?;
+    let (user, _, character) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    test.user()
+        .insert_mock_character_for_user(user.id, 95_000_002, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    CharacterSkillRepository::new(&test.db)
+        .replace_skills(character.id, &[(3_330, 4, 4, 135_765)])
+        .await?;
+    let fitting = FittingService::new(&test.db)
+        .import_fitting(user.id, "[Rifter, Tackle Rifter]".to_string())
+        .await
+        .unwrap();
+    let doctrine_service = DoctrineService::new(&test.db);
+    let doctrine = doctrine_service
+        .create_doctrine("Frigates".to_string(), None)
+        .await
+        .unwrap();
+    doctrine_service
+        .add_fitting(doctrine.id, fitting.id)
+        .await
+        .unwrap();
+
+    let readiness = DoctrineReadinessService::new(&test.db, &esi_provider, &cipher)
+        .get_user_readiness(user.id, doctrine.id)
+        .await
+        .unwrap();
+
+    assert_eq!(readiness.characters.len(), 2);
+    let tracked = readiness
+        .characters
+        .iter()
+        .find(|c| c.character_id == 95_000_001)
+        .unwrap();
+    assert!(tracked.skills_tracked);
+    assert_eq!(tracked.fittings.len(), 1);
+    assert!(tracked.fittings[0].can_fly);
+    let untracked = readiness
+        .characters
+        .iter()
+        .find(|c| c.character_id == 95_000_002)
+        .unwrap();
+    assert!(!untracked.skills_tracked);
+    assert!(untracked.fittings.is_empty());
+
+    Ok(())
+}
+
+/// Tests error handling for a nonexistent doctrine.
+///
+/// Expected: Err(AppError::Doctrine(DoctrineError::DoctrineNotFound))
+#[tokio::test]
+async fn fails_for_nonexistent_doctrine() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::EveCharacterSkill)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostFittingSkill)
+        .with_table(entity::prelude::BifrostDoctrine)
+        .with_table(entity::prelude::BifrostDoctrineFitting)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(95_000_001, 98_000_001, None, None)
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(Vec::new());
+
+    let result = DoctrineReadinessService::new(&test.db, &esi_provider, &cipher)
+        .get_user_readiness(user.id, 1)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Doctrine(DoctrineError::DoctrineNotFound(1)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+pub mod get_corporation_readiness;
+pub mod get_user_readiness;
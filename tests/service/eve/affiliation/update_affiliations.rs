@@ -31,6 +31,7 @@ async fn updates_single_character_affiliation() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(
             vec![factory::mock_character_affiliation(
                 character_id,
@@ -116,6 +117,7 @@ async fn updates_multiple_character_affiliations() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(
             vec![
                 factory::mock_character_affiliation(char1_id, corp1_id, Some(alliance_id), None),
@@ -189,6 +191,7 @@ async fn updates_affiliations_with_existing_entities() -> Result<(), TestError>
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_mock_faction(faction_id)
         .with_mock_alliance(alliance_id, Some(faction_id))
         .with_mock_corporation(corporation_id, Some(alliance_id), Some(faction_id))
@@ -268,6 +271,7 @@ async fn updates_corporation_alliance_affiliation() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_mock_alliance(old_alliance_id, None)
         .with_mock_corporation(corporation_id, Some(old_alliance_id), None)
         .with_mock_character(character_id, corporation_id, Some(old_alliance_id), None)
@@ -333,6 +337,7 @@ async fn updates_character_corporation_affiliation() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_mock_corporation(old_corp_id, None, None)
         .with_mock_character(character_id, old_corp_id, None, None)
         .with_character_affiliation_endpoint(
@@ -398,6 +403,7 @@ async fn updates_character_faction_affiliation() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_mock_corporation(corporation_id, None, None)
         .with_mock_character(character_id, corporation_id, None, None)
         .with_character_affiliation_endpoint(
@@ -452,6 +458,7 @@ async fn handles_empty_character_list() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(vec![], 0)
         .build()
         .await?;
@@ -497,6 +504,7 @@ async fn truncates_character_ids_exceeding_esi_limit() -> Result<(), TestError>
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(affiliations, 1)
         .with_corporation_endpoint(98_000_001, factory::mock_corporation(None, None), 1);
 
@@ -550,6 +558,7 @@ async fn filters_invalid_character_ids() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(
             vec![factory::mock_character_affiliation(
                 valid_char_id,
@@ -607,6 +616,7 @@ async fn handles_all_invalid_character_ids() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(vec![], 0)
         .build()
         .await?;
@@ -645,6 +655,7 @@ async fn retries_on_esi_server_error() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_mock_endpoint(|server| {
             server
                 .mock("POST", "/characters/affiliation")
@@ -703,6 +714,7 @@ async fn fails_after_max_esi_retries() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_mock_endpoint(|server| {
             server
                 .mock("POST", "/characters/affiliation")
@@ -743,6 +755,7 @@ async fn fails_when_esi_unavailable() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .build()
         .await?;
 
@@ -818,6 +831,7 @@ async fn handles_empty_affiliation_response() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(vec![], 1)
         .build()
         .await?;
@@ -855,6 +869,7 @@ async fn updates_affiliations_with_no_alliance() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(
             vec![factory::mock_character_affiliation(
                 character_id,
@@ -913,6 +928,7 @@ async fn updates_affiliations_with_no_faction() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(
             vec![factory::mock_character_affiliation(
                 character_id,
@@ -974,6 +990,7 @@ async fn updates_complete_affiliation_hierarchy() -> Result<(), TestError> {
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(
             vec![factory::mock_character_affiliation(
                 character_id,
@@ -1056,6 +1073,7 @@ async fn deduplicates_entity_ids_in_affiliation_data() -> Result<(), TestError>
         .with_table(entity::prelude::EveAlliance)
         .with_table(entity::prelude::EveCorporation)
         .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
         .with_character_affiliation_endpoint(
             vec![
                 factory::mock_character_affiliation(
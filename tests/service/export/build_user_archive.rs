@@ -0,0 +1,91 @@
+//! Tests for ExportService::build_user_archive method.
+//!
+//! This module verifies that a user's data archive contains their characters with their
+//! affiliation history, consents, and push subscriptions, and that unknown users are rejected.
+
+use bifrost::{
+    model::consent::ConsentCategory,
+    server::{
+        data::push::PushSubscriptionRepository,
+        error::{auth::AuthError, AppError},
+        service::{consent::ConsentService, export::ExportService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests building the archive of a user with a character, a consent, and a push subscription.
+///
+/// Expected: Ok with the user's character, its affiliation history, the granted consent, and
+/// the subscription endpoint without its keys
+#[tokio::test]
+async fn includes_user_data() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostAffiliationHistory)
+        .with_table(entity::prelude::BifrostUserPreference)
+        .with_table(entity::prelude::BifrostUserConsent)
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .build()
+        .await?;
+    let (user_model, _, character_model) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    ConsentService::new(&test.db)
+        .grant(user_model.id, ConsentCategory::Assets)
+        .await
+        .unwrap();
+    PushSubscriptionRepository::new(&test.db)
+        .upsert(
+            user_model.id,
+            "https://push.example.com/1",
+            "p256dh",
+            "auth",
+        )
+        .await?;
+
+    let archive = ExportService::new(&test.db)
+        .build_user_archive(user_model.id)
+        .await
+        .unwrap();
+
+    assert_eq!(archive.user_id, user_model.id);
+    assert_eq!(archive.characters.len(), 1);
+    assert_eq!(archive.characters[0].id, character_model.character_id);
+    assert_eq!(archive.affiliation_history.len(), 1);
+    assert_eq!(
+        archive.affiliation_history[0].character_id,
+        character_model.character_id
+    );
+    assert!(archive
+        .consents
+        .iter()
+        .any(|consent| consent.category == ConsentCategory::Assets && consent.granted));
+    assert_eq!(archive.push_subscriptions.len(), 1);
+    assert_eq!(
+        archive.push_subscriptions[0].endpoint,
+        "https://push.example.com/1"
+    );
+    assert!(archive.announcements.is_empty());
+
+    Ok(())
+}
+
+/// Tests building the archive of a user that doesn't exist.
+///
+/// Expected: Err with AuthError::UserNotInDatabase
+#[tokio::test]
+async fn fails_for_unknown_user() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+
+    let result = ExportService::new(&test.db).build_user_archive(1).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::UserNotInDatabase(1)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+mod build_user_archive;
+mod store_characters;
+mod stream_characters;
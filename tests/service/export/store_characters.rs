@@ -0,0 +1,38 @@
+//! Tests for ExportService::store_characters method.
+//!
+//! This module verifies that failures to reach the object store are reported rather than
+//! returning a download URL for an export that was never stored.
+
+use bifrost::server::{
+    error::AppError,
+    service::export::ExportService,
+    util::object_storage::{ObjectStorage, ObjectStorageSettings},
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests storing an export while the object store is unreachable.
+///
+/// Expected: Err with AppError::ObjectStorage
+#[tokio::test]
+async fn fails_when_storage_unreachable() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    test.eve().insert_mock_character(1, 1, None, None).await?;
+    let storage = ObjectStorage::new(
+        ObjectStorageSettings {
+            endpoint: reqwest::Url::parse("http://127.0.0.1:1").unwrap(),
+            bucket: "bifrost".to_string(),
+            region: "us-east-1".to_string(),
+            access_key_id: "access".to_string(),
+            secret_access_key: "secret".to_string(),
+        },
+        reqwest::Client::new(),
+    );
+
+    let result = ExportService::new(&test.db)
+        .store_characters(&storage)
+        .await;
+
+    assert!(matches!(result, Err(AppError::ObjectStorage(_))));
+
+    Ok(())
+}
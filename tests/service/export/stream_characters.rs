@@ -0,0 +1,63 @@
+//! Tests for ExportService::stream_characters method.
+//!
+//! This module verifies that the NDJSON character export contains every character with its
+//! corporation, alliance, and owning user.
+
+use bifrost::{model::export::CharacterExportDto, server::service::export::ExportService};
+use bifrost_test_utils::prelude::*;
+use futures::TryStreamExt;
+
+/// Tests exporting owned and unowned characters.
+///
+/// Expected: One NDJSON line per character with the owning user for owned characters
+#[tokio::test]
+async fn exports_all_characters_as_ndjson() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, Some(100), None)
+        .await?;
+    test.eve().insert_mock_character(2, 2, None, None).await?;
+
+    let chunks: Vec<String> = ExportService::new(&test.db)
+        .stream_characters()
+        .try_collect()
+        .await
+        .unwrap();
+
+    let rows: Vec<CharacterExportDto> = chunks
+        .concat()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].character_id, 1);
+    assert_eq!(rows[0].corporation_id, 1);
+    assert_eq!(rows[0].alliance_id, Some(100));
+    assert_eq!(rows[0].user_id, Some(user_model.id));
+    assert_eq!(rows[1].character_id, 2);
+    assert_eq!(rows[1].corporation_id, 2);
+    assert_eq!(rows[1].alliance_id, None);
+    assert_eq!(rows[1].user_id, None);
+
+    Ok(())
+}
+
+/// Tests exporting an empty character table.
+///
+/// Expected: Stream ends without any chunks
+#[tokio::test]
+async fn exports_nothing_without_characters() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+
+    let chunks: Vec<String> = ExportService::new(&test.db)
+        .stream_characters()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert!(chunks.is_empty());
+
+    Ok(())
+}
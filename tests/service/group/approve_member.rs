@@ -0,0 +1,107 @@
+//! Tests for GroupService::approve_member method.
+//!
+//! This module verifies that approving a pending request makes the user a member decided by
+//! the approving admin, and that approving a user who hasn't requested to join fails.
+
+use bifrost::{
+    model::group::{CreateGroupDto, GroupJoinPolicy, GroupMemberStatus},
+    server::{
+        error::{group::GroupError, AppError},
+        service::{group::GroupService, user::user_character::UserCharacterService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+fn create_dto() -> CreateGroupDto {
+    CreateGroupDto {
+        name: "Capitals".to_string(),
+        description: String::new(),
+        join_policy: GroupJoinPolicy::Request,
+    }
+}
+
+/// Tests approving a pending request to join.
+///
+/// Expected: Ok with the user a member decided by the admin and named by their main character
+#[tokio::test]
+async fn approves_pending_request() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let group = group_service
+        .create_group(admin.id, create_dto())
+        .await
+        .unwrap();
+    group_service.join(user.id, group.id).await.unwrap();
+
+    let member = group_service
+        .approve_member(admin.id, group.id, user.id)
+        .await
+        .unwrap();
+
+    assert_eq!(member.user_id, user.id);
+    assert_eq!(member.status, GroupMemberStatus::Member);
+    assert_eq!(member.main_character_id, Some(main.character_id));
+    assert_eq!(member.main_character_name, Some(main.name));
+    assert_eq!(member.decided_by_user_id, Some(admin.id));
+    assert!(member.decided_at.is_some());
+    assert_eq!(
+        group_service.get_members(group.id).await.unwrap(),
+        vec![member]
+    );
+
+    Ok(())
+}
+
+/// Tests approving a user who hasn't requested to join.
+///
+/// Expected: Err with MembershipNotFound
+#[tokio::test]
+async fn fails_without_request() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let group = group_service
+        .create_group(admin.id, create_dto())
+        .await
+        .unwrap();
+
+    let result = group_service
+        .approve_member(admin.id, group.id, user.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Group(GroupError::MembershipNotFound { .. }))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Tests for GroupRuleService::check_eligibility method.
+//!
+//! This module verifies that users are removed from groups whose rules none of their characters
+//! qualifies for anymore, while qualifying users and unrestricted groups are left untouched.
+
+use bifrost::{
+    model::group::{CreateGroupDto, CreateGroupRuleDto, GroupJoinPolicy, GroupRuleKind},
+    server::service::group::{rule::GroupRuleService, GroupService},
+};
+use bifrost_test_utils::prelude::*;
+
+fn create_dto(name: &str) -> CreateGroupDto {
+    CreateGroupDto {
+        name: name.to_string(),
+        description: String::new(),
+        join_policy: GroupJoinPolicy::Open,
+    }
+}
+
+/// Tests re-evaluating memberships after a group was restricted to a faction.
+///
+/// Expected: Ok(1) with only the user outside the faction removed from the restricted group,
+/// and their membership in the unrestricted group kept
+#[tokio::test]
+async fn removes_users_no_longer_qualifying() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (militia, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, Some(500001))
+        .await?;
+    let (outsider, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 2, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let restricted = group_service
+        .create_group(militia.id, create_dto("Militia"))
+        .await
+        .unwrap();
+    let unrestricted = group_service
+        .create_group(militia.id, create_dto("Social"))
+        .await
+        .unwrap();
+    for user_id in [militia.id, outsider.id] {
+        group_service.join(user_id, restricted.id).await.unwrap();
+        group_service.join(user_id, unrestricted.id).await.unwrap();
+    }
+
+    let rule_service = GroupRuleService::new(&test.db);
+    rule_service
+        .add_rule(
+            militia.id,
+            restricted.id,
+            CreateGroupRuleDto {
+                kind: GroupRuleKind::Faction,
+                entity_id: 500001,
+            },
+        )
+        .await
+        .unwrap();
+
+    let removed = rule_service
+        .check_eligibility(vec![militia.id, outsider.id])
+        .await
+        .unwrap();
+
+    assert_eq!(removed, 1);
+    let restricted_members: Vec<i32> = group_service
+        .get_members(restricted.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|member| member.user_id)
+        .collect();
+    assert_eq!(restricted_members, vec![militia.id]);
+    assert_eq!(
+        group_service
+            .get_members(unrestricted.id)
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Tests for GroupService::create_group method.
+//!
+//! This module verifies that groups are created with a trimmed name and their join policy, and
+//! that empty and duplicate names are rejected.
+
+use bifrost::{
+    model::group::{CreateGroupDto, GroupJoinPolicy},
+    server::{
+        error::{group::GroupError, AppError},
+        service::group::GroupService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+fn create_dto(name: &str, join_policy: GroupJoinPolicy) -> CreateGroupDto {
+    CreateGroupDto {
+        name: name.to_string(),
+        description: "Fleet pings for capital pilots".to_string(),
+        join_policy,
+    }
+}
+
+/// Tests creating a group.
+///
+/// Expected: Ok with the trimmed name, the join policy, and no members or pending requests
+#[tokio::test]
+async fn creates_group() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let group = group_service
+        .create_group(
+            admin.id,
+            create_dto("  Capitals  ", GroupJoinPolicy::Request),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(group.name, "Capitals");
+    assert_eq!(group.join_policy, GroupJoinPolicy::Request);
+    assert_eq!(group.member_count, 0);
+    assert_eq!(group.pending_count, 0);
+    assert_eq!(group_service.get_groups().await.unwrap(), vec![group]);
+
+    Ok(())
+}
+
+/// Tests creating a group with a blank name.
+///
+/// Expected: Err with InvalidGroup
+#[tokio::test]
+async fn fails_for_empty_name() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = GroupService::new(&test.db)
+        .create_group(admin.id, create_dto("   ", GroupJoinPolicy::Open))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Group(GroupError::InvalidGroup(_)))
+    ));
+
+    Ok(())
+}
+
+/// Tests creating a group with the name of an existing group.
+///
+/// Expected: Err with InvalidGroup
+#[tokio::test]
+async fn fails_for_duplicate_name() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    group_service
+        .create_group(admin.id, create_dto("Capitals", GroupJoinPolicy::Open))
+        .await
+        .unwrap();
+    let result = group_service
+        .create_group(admin.id, create_dto("Capitals", GroupJoinPolicy::Hidden))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Group(GroupError::InvalidGroup(_)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+//! Tests for GroupService::join method.
+//!
+//! This module verifies that users join open groups directly, request to join groups requiring
+//! approval, can't join hidden groups or groups whose rules they don't qualify for, and that
+//! joining twice keeps the first membership.
+
+use bifrost::{
+    model::group::{
+        CreateGroupDto, CreateGroupRuleDto, GroupJoinPolicy, GroupMemberStatus, GroupRuleKind,
+    },
+    server::{
+        error::{group::GroupError, AppError},
+        service::group::{rule::GroupRuleService, GroupService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+fn create_dto(join_policy: GroupJoinPolicy) -> CreateGroupDto {
+    CreateGroupDto {
+        name: format!("{} group", join_policy.as_str()),
+        description: String::new(),
+        join_policy,
+    }
+}
+
+/// Tests joining an open group.
+///
+/// Expected: Ok with the user a member, counted as a member of the group
+#[tokio::test]
+async fn joins_open_group() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let group = group_service
+        .create_group(admin.id, create_dto(GroupJoinPolicy::Open))
+        .await
+        .unwrap();
+
+    let joined = group_service.join(user.id, group.id).await.unwrap();
+
+    assert_eq!(joined.status, Some(GroupMemberStatus::Member));
+    let groups = group_service.get_groups().await.unwrap();
+    assert_eq!(groups[0].member_count, 1);
+    assert_eq!(groups[0].pending_count, 0);
+
+    Ok(())
+}
+
+/// Tests joining a group requiring approval, twice.
+///
+/// Expected: Ok with a pending request both times, counted once as pending
+#[tokio::test]
+async fn requests_to_join_group() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let group = group_service
+        .create_group(admin.id, create_dto(GroupJoinPolicy::Request))
+        .await
+        .unwrap();
+
+    let requested = group_service.join(user.id, group.id).await.unwrap();
+    let requested_again = group_service.join(user.id, group.id).await.unwrap();
+
+    assert_eq!(requested.status, Some(GroupMemberStatus::Pending));
+    assert_eq!(requested_again, requested);
+    let groups = group_service.get_groups().await.unwrap();
+    assert_eq!(groups[0].member_count, 0);
+    assert_eq!(groups[0].pending_count, 1);
+
+    Ok(())
+}
+
+/// Tests joining a hidden group.
+///
+/// Expected: Err with GroupNotFound, and the group not listed to the user until an admin adds
+/// them
+#[tokio::test]
+async fn fails_for_hidden_group() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let group = group_service
+        .create_group(admin.id, create_dto(GroupJoinPolicy::Hidden))
+        .await
+        .unwrap();
+
+    let result = group_service.join(user.id, group.id).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Group(GroupError::GroupNotFound(_)))
+    ));
+    assert!(group_service
+        .get_user_groups(user.id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    group_service
+        .add_member(admin.id, group.id, user.id)
+        .await
+        .unwrap();
+    let groups = group_service.get_user_groups(user.id).await.unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].status, Some(GroupMemberStatus::Member));
+
+    Ok(())
+}
+
+/// Tests joining a group restricted to a corporation.
+///
+/// Expected: Err with NotEligible for a user in another corporation, Ok for a user in the
+/// corporation
+#[tokio::test]
+async fn requires_qualifying_corporation() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (outsider, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 2, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let group = group_service
+        .create_group(admin.id, create_dto(GroupJoinPolicy::Open))
+        .await
+        .unwrap();
+    GroupRuleService::new(&test.db)
+        .add_rule(
+            admin.id,
+            group.id,
+            CreateGroupRuleDto {
+                kind: GroupRuleKind::Corporation,
+                entity_id: 1,
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = group_service.join(outsider.id, group.id).await;
+    assert!(matches!(
+        result,
+        Err(AppError::Group(GroupError::NotEligible { .. }))
+    ));
+
+    let joined = group_service.join(admin.id, group.id).await.unwrap();
+    assert_eq!(joined.status, Some(GroupMemberStatus::Member));
+
+    Ok(())
+}
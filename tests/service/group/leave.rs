@@ -0,0 +1,64 @@
+//! Tests for GroupService::leave method.
+//!
+//! This module verifies that leaving removes the user's membership and that leaving a group
+//! without a membership fails.
+
+use bifrost::{
+    model::group::{CreateGroupDto, GroupJoinPolicy},
+    server::{
+        error::{group::GroupError, AppError},
+        service::group::GroupService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests leaving a group the user joined.
+///
+/// Expected: Ok with the group listed without a membership, and leaving again failing with
+/// MembershipNotFound
+#[tokio::test]
+async fn leaves_group() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .with_table(entity::prelude::BifrostGroupRule)
+        .build()
+        .await?;
+    let (admin, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let group_service = GroupService::new(&test.db);
+    let group = group_service
+        .create_group(
+            admin.id,
+            CreateGroupDto {
+                name: "Logistics".to_string(),
+                description: String::new(),
+                join_policy: GroupJoinPolicy::Open,
+            },
+        )
+        .await
+        .unwrap();
+    group_service.join(user.id, group.id).await.unwrap();
+
+    group_service.leave(user.id, group.id).await.unwrap();
+
+    let groups = group_service.get_user_groups(user.id).await.unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].status, None);
+
+    let result = group_service.leave(user.id, group.id).await;
+    assert!(matches!(
+        result,
+        Err(AppError::Group(GroupError::MembershipNotFound { .. }))
+    ));
+
+    Ok(())
+}
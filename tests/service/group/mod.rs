@@ -0,0 +1,5 @@
+mod approve_member;
+mod check_eligibility;
+mod create_group;
+mod join;
+mod leave;
@@ -0,0 +1,117 @@
+//! Tests for ImageService::get_image method.
+//!
+//! This module verifies serving images from the disk cache, falling back to a stale cached
+//! image when the EVE image server can't be reached, and skipping the proxy when no cache
+//! directory is configured.
+
+use std::{path::PathBuf, time::Duration};
+
+use bifrost::server::{
+    error::AppError,
+    service::image::{ImageProxyConfig, ImageService},
+};
+
+/// PNG magic bytes, enough for the content type to be detected.
+const PNG_BYTES: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// Creates an empty cache directory unique to the test.
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("bifrost-image-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("corporations")).unwrap();
+    dir
+}
+
+/// Creates an HTTP client that can't reach the EVE image server.
+fn unreachable_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all("http://127.0.0.1:1").unwrap())
+        .build()
+        .unwrap()
+}
+
+/// Tests serving a freshly cached image.
+///
+/// Expected: Ok with the cached bytes, without contacting the image server
+#[tokio::test]
+async fn serves_fresh_cached_image() -> Result<(), AppError> {
+    let dir = cache_dir("fresh");
+    std::fs::write(dir.join("corporations").join("1-64"), PNG_BYTES).unwrap();
+    let config = ImageProxyConfig {
+        cache_dir: Some(dir.clone()),
+        ttl: Duration::from_secs(3600),
+        http: unreachable_client(),
+    };
+
+    let image = ImageService::new(&config)
+        .get_image("corporations", 1, 64)
+        .await?
+        .expect("Proxy should be enabled");
+
+    assert_eq!(image.bytes, PNG_BYTES);
+    assert_eq!(image.content_type, "image/png");
+
+    std::fs::remove_dir_all(dir).unwrap();
+    Ok(())
+}
+
+/// Tests serving an expired cached image while the image server is unreachable.
+///
+/// Expected: Ok with the stale cached bytes
+#[tokio::test]
+async fn serves_stale_image_when_unreachable() -> Result<(), AppError> {
+    let dir = cache_dir("stale");
+    std::fs::write(dir.join("corporations").join("1-64"), PNG_BYTES).unwrap();
+    let config = ImageProxyConfig {
+        cache_dir: Some(dir.clone()),
+        ttl: Duration::ZERO,
+        http: unreachable_client(),
+    };
+
+    let image = ImageService::new(&config)
+        .get_image("corporations", 1, 64)
+        .await?
+        .expect("Proxy should be enabled");
+
+    assert_eq!(image.bytes, PNG_BYTES);
+
+    std::fs::remove_dir_all(dir).unwrap();
+    Ok(())
+}
+
+/// Tests fetching an uncached image while the image server is unreachable.
+///
+/// Expected: Err with the HTTP error
+#[tokio::test]
+async fn fails_without_cached_image_when_unreachable() {
+    let dir = cache_dir("missing");
+    let config = ImageProxyConfig {
+        cache_dir: Some(dir.clone()),
+        ttl: Duration::from_secs(3600),
+        http: unreachable_client(),
+    };
+
+    let result = ImageService::new(&config)
+        .get_image("corporations", 1, 64)
+        .await;
+
+    assert!(matches!(result, Err(AppError::Http(_))));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+/// Tests requesting an image while no cache directory is configured.
+///
+/// Expected: Ok(None), so the caller redirects to the image server
+#[tokio::test]
+async fn skips_proxy_without_cache_dir() -> Result<(), AppError> {
+    let config = ImageProxyConfig::default();
+
+    let image = ImageService::new(&config)
+        .get_image("characters", 2114794365, 128)
+        .await?;
+
+    assert!(image.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+//! Tests for MemberService::delete_filter method.
+//!
+//! This module verifies that admins can only delete the filters they saved themselves.
+
+use bifrost::{
+    model::member::{MemberFilterDto, MemberList, SaveMemberFilterDto},
+    server::{
+        error::{member::MemberError, AppError},
+        service::member::MemberService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests deleting a filter saved by another user.
+///
+/// Expected: Err(AppError::Member(MemberError::FilterNotFound)) and the filter is kept
+#[tokio::test]
+async fn fails_for_other_users_filter() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostMemberFilter)
+        .build()
+        .await?;
+    let (owner, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (other_user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 2, None, None)
+        .await?;
+
+    let member_service = MemberService::new(&test.db);
+    let filter = member_service
+        .save_filter(
+            owner.id,
+            SaveMemberFilterDto {
+                list: MemberList::Characters,
+                name: "Alts".to_string(),
+                filter: MemberFilterDto::default(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = member_service.delete_filter(other_user.id, filter.id).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Member(MemberError::FilterNotFound(_)))
+    ));
+    assert_eq!(member_service.get_filters(owner.id).await.unwrap().len(), 1);
+
+    member_service
+        .delete_filter(owner.id, filter.id)
+        .await
+        .unwrap();
+    assert!(member_service
+        .get_filters(owner.id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! Tests for MemberService::get_users method.
+//!
+//! This module verifies listing users with any character matching the corporation, name, and
+//! tag filters, each shown with their main character and character count.
+
+use bifrost::{
+    model::{
+        annotation::{AnnotationSubject, CreateTagDto},
+        member::MemberFilterDto,
+    },
+    server::service::{
+        annotation::AnnotationService, member::MemberService,
+        user::user_character::UserCharacterService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests filtering users by the corporation of an alt.
+///
+/// Verifies that a user is listed with their main character when any of their characters is
+/// in the corporation.
+///
+/// Expected: Ok with only the user owning a character in the corporation
+#[tokio::test]
+async fn filters_by_alt_corporation() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (member, _, main) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.user()
+        .insert_mock_character_for_user(member.id, 2, 2, None, None)
+        .await?;
+    let (outsider, _, _) = test
+        .user()
+        .insert_user_with_mock_character(3, 3, None, None)
+        .await?;
+    for user_id in [member.id, outsider.id] {
+        UserCharacterService::refresh_summary(&test.db, user_id)
+            .await
+            .expect("Refreshing the summary should succeed");
+    }
+
+    let users = MemberService::new(&test.db)
+        .get_users(MemberFilterDto {
+            corporation_id: Some(2),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].user_id, member.id);
+    assert_eq!(users[0].main_character_id, main.character_id);
+    assert_eq!(users[0].corporation_id, 1);
+    assert_eq!(users[0].character_count, 2);
+
+    Ok(())
+}
+
+/// Tests filtering users by character name and tag.
+///
+/// Verifies that names match case-insensitively and that a tag on a user lists that user.
+///
+/// Expected: Ok with every user for a matching name, only the tagged user for the tag
+#[tokio::test]
+async fn filters_by_name_and_tag() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostTag)
+        .build()
+        .await?;
+    let (tagged, _, main) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (untagged, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 2, None, None)
+        .await?;
+    for user_id in [tagged.id, untagged.id] {
+        UserCharacterService::refresh_summary(&test.db, user_id)
+            .await
+            .expect("Refreshing the summary should succeed");
+    }
+    AnnotationService::new(&test.db)
+        .add_tag(
+            untagged.id,
+            AnnotationSubject::User,
+            tagged.id.into(),
+            CreateTagDto {
+                name: "Recruit".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let member_service = MemberService::new(&test.db);
+    let by_name = member_service
+        .get_users(MemberFilterDto {
+            name: Some(main.name[..3].to_uppercase()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let by_tag = member_service
+        .get_users(MemberFilterDto {
+            tag: Some(" recruit ".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(by_name.len(), 2);
+    assert_eq!(by_tag.len(), 1);
+    assert_eq!(by_tag[0].user_id, tagged.id);
+
+    Ok(())
+}
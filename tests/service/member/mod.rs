@@ -0,0 +1,4 @@
+mod delete_filter;
+mod get_users;
+mod resolve_bulk_action;
+mod save_filter;
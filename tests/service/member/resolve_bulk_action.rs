@@ -0,0 +1,160 @@
+//! Tests for MemberService::resolve_bulk_action method.
+//!
+//! This module verifies resolving bulk actions into worker jobs per selected member, reporting
+//! members that aren't listed, and rejecting actions without members or a notification title.
+
+use bifrost::{
+    model::member::{MemberBulkAction, MemberBulkActionDto, MemberList},
+    server::{
+        error::{member::MemberError, AppError},
+        model::worker::WorkerJob,
+        service::{member::MemberService, user::user_character::UserCharacterService},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests force refreshing a user.
+///
+/// Verifies that every character of the user gets an info update and that their affiliations
+/// are updated in one batch, while an unknown user is reported without failing the action.
+///
+/// Expected: Ok with three jobs for the user and an error for the unknown user
+#[tokio::test]
+async fn refreshes_user_characters() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user_model, _, main) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (_, alt) = test
+        .user()
+        .insert_mock_character_for_user(user_model.id, 2, 2, None, None)
+        .await?;
+    UserCharacterService::refresh_summary(&test.db, user_model.id)
+        .await
+        .expect("Refreshing the summary should succeed");
+
+    let items = MemberService::new(&test.db)
+        .resolve_bulk_action(
+            user_model.id,
+            MemberBulkActionDto {
+                list: MemberList::Users,
+                ids: vec![user_model.id.into(), user_model.id.into(), 999],
+                action: MemberBulkAction::ForceRefresh,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].id, i64::from(user_model.id));
+    assert_eq!(items[0].jobs.len(), 3);
+    assert!(items[0].jobs.contains(&WorkerJob::UpdateCharacterInfo {
+        character_id: alt.character_id
+    }));
+    assert!(items[0].jobs.iter().any(|job| matches!(
+        job,
+        WorkerJob::UpdateAffiliations { character_ids }
+            if character_ids.contains(&main.character_id)
+                && character_ids.contains(&alt.character_id)
+    )));
+    assert_eq!(items[1].id, 999);
+    assert!(items[1].jobs.is_empty());
+    assert!(items[1].error.is_some());
+
+    Ok(())
+}
+
+/// Tests notifying the owner of a character.
+///
+/// Expected: Ok with a push notification job for the owning user
+#[tokio::test]
+async fn notifies_character_owner() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user_model, _, character) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    UserCharacterService::refresh_summary(&test.db, user_model.id)
+        .await
+        .expect("Refreshing the summary should succeed");
+
+    let items = MemberService::new(&test.db)
+        .resolve_bulk_action(
+            user_model.id,
+            MemberBulkActionDto {
+                list: MemberList::Characters,
+                ids: vec![character.character_id],
+                action: MemberBulkAction::SendNotification {
+                    title: " Fleet tonight ".to_string(),
+                    body: "Form up at 19:00".to_string(),
+                },
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(items.len(), 1);
+    assert!(matches!(
+        items[0].jobs.as_slice(),
+        [WorkerJob::SendPushNotification { user_id, notification }]
+            if *user_id == user_model.id && notification.title == "Fleet tonight"
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for bulk actions without members.
+///
+/// Expected: Err(AppError::Member(MemberError::NoMembersSelected))
+#[tokio::test]
+async fn fails_without_members() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+
+    let result = MemberService::new(&test.db)
+        .resolve_bulk_action(
+            1,
+            MemberBulkActionDto {
+                list: MemberList::Users,
+                ids: Vec::new(),
+                action: MemberBulkAction::ForceRefresh,
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Member(MemberError::NoMembersSelected))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for notifications without a title.
+///
+/// Expected: Err(AppError::Member(MemberError::EmptyNotificationTitle))
+#[tokio::test]
+async fn fails_for_empty_notification_title() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+
+    let result = MemberService::new(&test.db)
+        .resolve_bulk_action(
+            1,
+            MemberBulkActionDto {
+                list: MemberList::Users,
+                ids: vec![1],
+                action: MemberBulkAction::SendNotification {
+                    title: " ".to_string(),
+                    body: String::new(),
+                },
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Member(MemberError::EmptyNotificationTitle))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! Tests for MemberService::save_filter method.
+//!
+//! This module verifies saving filters per list, replacing a filter saved under the same name,
+//! and rejecting filters without a name.
+
+use bifrost::{
+    model::member::{MemberFilterDto, MemberList, SaveMemberFilterDto},
+    server::{
+        error::{member::MemberError, AppError},
+        service::member::MemberService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests saving a filter under a name already in use for the list.
+///
+/// Verifies that the existing filter is replaced, while the same name on the other list is a
+/// separate filter.
+///
+/// Expected: Ok with two saved filters, the users filter holding the new criteria
+#[tokio::test]
+async fn replaces_filter_with_same_name() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostMemberFilter)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let member_service = MemberService::new(&test.db);
+    for (list, corporation_id) in [
+        (MemberList::Users, 1),
+        (MemberList::Characters, 1),
+        (MemberList::Users, 2),
+    ] {
+        member_service
+            .save_filter(
+                user_model.id,
+                SaveMemberFilterDto {
+                    list,
+                    name: " Corp ".to_string(),
+                    filter: MemberFilterDto {
+                        corporation_id: Some(corporation_id),
+                        ..Default::default()
+                    },
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    let filters = member_service.get_filters(user_model.id).await.unwrap();
+    assert_eq!(filters.len(), 2);
+    let users_filter = filters
+        .iter()
+        .find(|filter| filter.list == MemberList::Users)
+        .expect("Users filter should exist");
+    assert_eq!(users_filter.name, "Corp");
+    assert_eq!(users_filter.filter.corporation_id, Some(2));
+
+    Ok(())
+}
+
+/// Tests error handling for blank filter names.
+///
+/// Expected: Err(AppError::Member(MemberError::InvalidFilterName))
+#[tokio::test]
+async fn fails_for_empty_name() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostMemberFilter)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = MemberService::new(&test.db)
+        .save_filter(
+            user_model.id,
+            SaveMemberFilterDto {
+                list: MemberList::Users,
+                name: "  ".to_string(),
+                filter: MemberFilterDto::default(),
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Member(MemberError::InvalidFilterName(_)))
+    ));
+
+    Ok(())
+}
@@ -1,3 +1,40 @@
+mod affiliation_history;
+mod annotation;
+mod announcement;
+mod approval;
 mod auth;
+mod ban;
+mod campaign;
+mod character_skill;
+mod consent;
+mod corporation_member;
+mod dashboard;
+mod data_api;
+#[cfg(feature = "redis-test")]
+mod dead_letter;
+mod digest;
+mod discord;
+mod doctrine;
 mod eve;
+mod export;
+mod group;
+mod image;
+mod member;
+mod notification;
+mod onboarding;
+mod page;
+mod preference;
+mod push;
+#[cfg(feature = "redis-test")]
+mod rate_limit;
+mod reauth_campaign;
+mod recruitment;
+mod role;
+mod screening;
+mod search;
+#[cfg(feature = "redis-test")]
+mod session;
+mod skill_plan;
+mod token;
 mod user;
+mod widget;
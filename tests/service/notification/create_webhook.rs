@@ -0,0 +1,112 @@
+//! Tests for NotificationService::create_webhook method.
+//!
+//! This module verifies storing webhooks with their events deduplicated and rejecting webhooks
+//! without a name, an HTTPS URL, or events.
+
+use bifrost::{
+    model::webhook::{CreateWebhookDto, WebhookEvent},
+    server::{
+        error::{webhook::WebhookError, AppError},
+        service::notification::NotificationService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds a webhook with the given name, URL, and events.
+fn webhook(name: &str, url: &str, events: Vec<WebhookEvent>) -> CreateWebhookDto {
+    CreateWebhookDto {
+        name: name.to_string(),
+        url: url.to_string(),
+        events,
+    }
+}
+
+/// Tests creating a webhook.
+///
+/// Verifies that the name and URL are trimmed, duplicate events are removed, and the webhook
+/// is enabled.
+///
+/// Expected: Ok with the created webhook listed
+#[tokio::test]
+async fn creates_enabled_webhook() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::BifrostWebhook)
+        .build()
+        .await?;
+
+    let notification_service = NotificationService::new(&test.db);
+    let created = notification_service
+        .create_webhook(webhook(
+            " Recruitment ",
+            " https://discord.com/api/webhooks/1/token ",
+            vec![
+                WebhookEvent::CharacterTransferred,
+                WebhookEvent::CharacterLinked,
+                WebhookEvent::CharacterTransferred,
+            ],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(created.name, "Recruitment");
+    assert_eq!(created.url, "https://discord.com/api/webhooks/1/token");
+    assert_eq!(
+        created.events,
+        vec![
+            WebhookEvent::CharacterTransferred,
+            WebhookEvent::CharacterLinked
+        ]
+    );
+    assert!(created.enabled);
+
+    let webhooks = notification_service.get_webhooks().await.unwrap();
+    assert_eq!(webhooks, vec![created]);
+
+    Ok(())
+}
+
+/// Tests rejecting invalid webhooks.
+///
+/// Verifies that webhooks need a name, an HTTPS URL, and at least one event.
+///
+/// Expected: Err(AppError::Webhook(WebhookError::InvalidWebhook)) for each webhook
+#[tokio::test]
+async fn rejects_invalid_webhooks() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::BifrostWebhook)
+        .build()
+        .await?;
+
+    let notification_service = NotificationService::new(&test.db);
+    for invalid in [
+        webhook(
+            "  ",
+            "https://discord.com/api/webhooks/1/token",
+            vec![WebhookEvent::MainChanged],
+        ),
+        webhook(
+            "Recruitment",
+            "http://discord.com/api/webhooks/1/token",
+            vec![WebhookEvent::MainChanged],
+        ),
+        webhook(
+            "Recruitment",
+            "https://discord.com/api/webhooks/1/token",
+            vec![],
+        ),
+    ] {
+        let result = notification_service.create_webhook(invalid).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Webhook(WebhookError::InvalidWebhook(_)))
+        ));
+    }
+    assert!(notification_service
+        .get_webhooks()
+        .await
+        .unwrap()
+        .is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Tests for NotificationService::get_webhook_jobs method.
+//!
+//! This module verifies that ownership changes are only delivered to the enabled webhooks
+//! subscribed to the change's event.
+
+use bifrost::{
+    model::webhook::{CreateWebhookDto, UpdateWebhookDto, WebhookEvent},
+    server::{
+        model::worker::WorkerJob,
+        service::notification::{NotificationService, OwnershipChange},
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests building jobs for the subscribed webhooks.
+///
+/// Verifies that a transfer is only delivered to the enabled webhook subscribed to transfers,
+/// and that the message names the character and both users.
+///
+/// Expected: Ok with a single `SendWebhook` job
+#[tokio::test]
+async fn builds_jobs_for_subscribed_webhooks() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::BifrostWebhook)
+        .build()
+        .await?;
+
+    let notification_service = NotificationService::new(&test.db);
+    let mut webhook_ids = Vec::new();
+    for events in [
+        vec![WebhookEvent::CharacterTransferred],
+        vec![
+            WebhookEvent::CharacterTransferred,
+            WebhookEvent::MainChanged,
+        ],
+        vec![WebhookEvent::CharacterLinked],
+    ] {
+        let webhook = notification_service
+            .create_webhook(CreateWebhookDto {
+                name: "Security".to_string(),
+                url: "https://hooks.slack.com/services/T0/B0/token".to_string(),
+                events,
+            })
+            .await
+            .unwrap();
+        webhook_ids.push(webhook.id);
+    }
+    notification_service
+        .update_webhook(
+            webhook_ids[1],
+            UpdateWebhookDto {
+                name: "Security".to_string(),
+                url: "https://hooks.slack.com/services/T0/B0/token".to_string(),
+                events: vec![WebhookEvent::CharacterTransferred],
+                enabled: false,
+            },
+        )
+        .await
+        .unwrap();
+
+    let change = OwnershipChange {
+        event: WebhookEvent::CharacterTransferred,
+        character_id: 2114794365,
+        character_name: "Test Character".to_string(),
+        user_id: 2,
+        previous_user_id: Some(1),
+    };
+    let jobs = notification_service
+        .get_webhook_jobs(&change)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        jobs,
+        vec![WorkerJob::SendWebhook {
+            webhook_id: webhook_ids[0],
+            event: WebhookEvent::CharacterTransferred,
+            content: "**Test Character** (2114794365) was transferred from user 1 to user 2"
+                .to_string(),
+        }]
+    );
+
+    Ok(())
+}
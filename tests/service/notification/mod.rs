@@ -0,0 +1,2 @@
+mod create_webhook;
+mod get_webhook_jobs;
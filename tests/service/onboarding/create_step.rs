@@ -0,0 +1,114 @@
+//! Tests for OnboardingService::create_step method.
+//!
+//! This module verifies appending steps to the end of the checklist and rejecting steps
+//! without the target their kind needs.
+
+use bifrost::{
+    model::onboarding::{CreateOnboardingStepDto, OnboardingStepKind},
+    server::{
+        error::{onboarding::OnboardingError, AppError},
+        service::onboarding::OnboardingService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds a step of the given kind and target.
+fn step(kind: OnboardingStepKind, target: Option<&str>) -> CreateOnboardingStepDto {
+    CreateOnboardingStepDto {
+        title: format!(" {} ", kind.description()),
+        description: "Required for new members".to_string(),
+        kind,
+        target: target.map(str::to_string),
+    }
+}
+
+/// Tests defining steps in order.
+///
+/// Verifies that steps are appended after the last step, that titles are trimmed, that
+/// duplicate scopes are removed, and that steps without a target ignore the one given.
+///
+/// Expected: Ok with the steps listed in the order they were defined
+#[tokio::test]
+async fn appends_steps_in_order() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let onboarding_service = OnboardingService::new(&test.db);
+    let scopes = onboarding_service
+        .create_step(
+            user_model.id,
+            step(
+                OnboardingStepKind::GrantScopes,
+                Some("esi-assets.read_assets.v1 esi-assets.read_assets.v1"),
+            ),
+        )
+        .await
+        .unwrap();
+    let alts = onboarding_service
+        .create_step(
+            user_model.id,
+            step(OnboardingStepKind::LinkAlts, Some("ignored")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(scopes.title, "Grant ESI scopes");
+    assert_eq!(scopes.target.as_deref(), Some("esi-assets.read_assets.v1"));
+    assert_eq!(alts.target, None);
+    assert!(alts.position > scopes.position);
+
+    let steps = onboarding_service.get_steps().await.unwrap();
+    let ids: Vec<i32> = steps.iter().map(|step| step.id).collect();
+    assert_eq!(ids, vec![scopes.id, alts.id]);
+
+    Ok(())
+}
+
+/// Tests rejecting steps without a valid target.
+///
+/// Verifies that steps granting scopes need a scope, steps reading a page need an existing
+/// page, and steps joining Discord need an HTTPS invite URL.
+///
+/// Expected: Err(AppError::Onboarding(OnboardingError::InvalidStep)) for each step
+#[tokio::test]
+async fn rejects_steps_without_valid_target() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let onboarding_service = OnboardingService::new(&test.db);
+    for invalid in [
+        step(OnboardingStepKind::GrantScopes, Some("  ")),
+        step(OnboardingStepKind::ReadPage, Some("rules")),
+        step(
+            OnboardingStepKind::JoinDiscord,
+            Some("http://discord.gg/example"),
+        ),
+    ] {
+        let result = onboarding_service.create_step(user_model.id, invalid).await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::Onboarding(OnboardingError::InvalidStep(_)))
+        ));
+    }
+    assert!(onboarding_service.get_steps().await.unwrap().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+//! Tests for OnboardingService::get_checklist method.
+//!
+//! This module verifies that steps are completed from owned characters, by logins granting
+//! scopes, and by the user marking them completed, and that steps completed automatically
+//! can't be marked completed by the user.
+
+use bifrost::{
+    model::onboarding::{CreateOnboardingStepDto, OnboardingStepKind},
+    server::{
+        error::{onboarding::OnboardingError, AppError},
+        service::onboarding::OnboardingService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Builds a step of the given kind and target.
+fn step(kind: OnboardingStepKind, target: Option<&str>) -> CreateOnboardingStepDto {
+    CreateOnboardingStepDto {
+        title: kind.description().to_string(),
+        description: String::new(),
+        kind,
+        target: target.map(str::to_string),
+    }
+}
+
+/// Tests completing steps from owned characters and granted scopes.
+///
+/// Verifies that linking alts is complete once the user owns a second character and that a
+/// login granting the step's scopes completes it.
+///
+/// Expected: Ok with both steps incomplete at first and completed afterwards
+#[tokio::test]
+async fn completes_steps_from_existing_data() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let onboarding_service = OnboardingService::new(&test.db);
+    onboarding_service
+        .create_step(user_model.id, step(OnboardingStepKind::LinkAlts, None))
+        .await
+        .unwrap();
+    onboarding_service
+        .create_step(
+            user_model.id,
+            step(
+                OnboardingStepKind::GrantScopes,
+                Some("esi-assets.read_assets.v1 esi-skills.read_skills.v1"),
+            ),
+        )
+        .await
+        .unwrap();
+
+    let checklist = onboarding_service
+        .get_checklist(user_model.id)
+        .await
+        .unwrap();
+    assert!(checklist.iter().all(|item| !item.completed));
+
+    test.user()
+        .insert_mock_character_for_user(user_model.id, 2, 1, None, None)
+        .await?;
+    let completed = onboarding_service
+        .complete_for_scopes(
+            user_model.id,
+            &[
+                "esi-skills.read_skills.v1".to_string(),
+                "esi-assets.read_assets.v1".to_string(),
+            ],
+        )
+        .await
+        .unwrap();
+    assert_eq!(completed, 1);
+
+    let checklist = onboarding_service
+        .get_checklist(user_model.id)
+        .await
+        .unwrap();
+    assert!(checklist.iter().all(|item| item.completed));
+    assert!(checklist[0].completed_at.is_none());
+    assert!(checklist[1].completed_at.is_some());
+
+    Ok(())
+}
+
+/// Tests marking steps completed by the user.
+///
+/// Verifies that a manual step is completed by the user who marked it, but not for other
+/// users, and that steps completed automatically are rejected.
+///
+/// Expected: Ok for the manual step, Err(AppError::Onboarding(OnboardingError::NotSelfReported))
+/// for linking alts
+#[tokio::test]
+async fn completes_self_reported_steps() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (other_user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let onboarding_service = OnboardingService::new(&test.db);
+    let manual = onboarding_service
+        .create_step(user_model.id, step(OnboardingStepKind::Manual, None))
+        .await
+        .unwrap();
+    let alts = onboarding_service
+        .create_step(user_model.id, step(OnboardingStepKind::LinkAlts, None))
+        .await
+        .unwrap();
+
+    onboarding_service
+        .complete_step(user_model.id, manual.id)
+        .await
+        .unwrap();
+    // Completing a step again keeps the first completion
+    onboarding_service
+        .complete_step(user_model.id, manual.id)
+        .await
+        .unwrap();
+    let result = onboarding_service
+        .complete_step(user_model.id, alts.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Onboarding(OnboardingError::NotSelfReported(_)))
+    ));
+
+    let checklist = onboarding_service
+        .get_checklist(user_model.id)
+        .await
+        .unwrap();
+    assert!(checklist[0].completed);
+    assert!(!checklist[1].completed);
+
+    let other_checklist = onboarding_service
+        .get_checklist(other_user.id)
+        .await
+        .unwrap();
+    assert!(!other_checklist[0].completed);
+
+    Ok(())
+}
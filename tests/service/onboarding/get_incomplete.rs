@@ -0,0 +1,74 @@
+//! Tests for OnboardingService::get_incomplete method.
+//!
+//! This module verifies that officers see only the users missing a step, with their main
+//! character and the titles of the steps they're missing.
+
+use bifrost::{
+    model::onboarding::{CreateOnboardingStepDto, OnboardingStepKind},
+    server::service::{onboarding::OnboardingService, user::user_character::UserCharacterService},
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests listing users with incomplete onboarding.
+///
+/// Verifies that a user who completed every step is left out and that the other user is
+/// listed with their main character and missing step.
+///
+/// Expected: Ok with only the user who hasn't completed the manual step
+#[tokio::test]
+async fn lists_users_missing_steps() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .build()
+        .await?;
+    let (done, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (pending, _, pending_main) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    for user_id in [done.id, pending.id] {
+        UserCharacterService::refresh_summary(&test.db, user_id)
+            .await
+            .expect("Refreshing the summary should succeed");
+    }
+
+    let onboarding_service = OnboardingService::new(&test.db);
+    let step = onboarding_service
+        .create_step(
+            done.id,
+            CreateOnboardingStepDto {
+                title: "Read the rules".to_string(),
+                description: String::new(),
+                kind: OnboardingStepKind::Manual,
+                target: None,
+            },
+        )
+        .await
+        .unwrap();
+    onboarding_service
+        .complete_step(done.id, step.id)
+        .await
+        .unwrap();
+
+    let incomplete = onboarding_service.get_incomplete().await.unwrap();
+
+    assert_eq!(incomplete.len(), 1);
+    assert_eq!(incomplete[0].user_id, pending.id);
+    assert_eq!(
+        incomplete[0].main_character_id,
+        Some(pending_main.character_id)
+    );
+    assert_eq!(incomplete[0].completed_count, 0);
+    assert_eq!(incomplete[0].step_count, 1);
+    assert_eq!(
+        incomplete[0].missing_steps,
+        vec!["Read the rules".to_string()]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+mod create_step;
+mod get_checklist;
+mod get_incomplete;
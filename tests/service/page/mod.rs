@@ -0,0 +1,2 @@
+mod restore_revision;
+mod save_page;
@@ -0,0 +1,104 @@
+//! Tests for PageService::restore_revision method.
+//!
+//! This module verifies restoring a page to an earlier revision and rejecting revisions that
+//! belong to another page.
+
+use bifrost::{
+    model::page::SavePageDto,
+    server::{
+        error::{page::PageError, AppError},
+        service::page::PageService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests restoring a page to its first revision.
+///
+/// Verifies that the restore is recorded as a new revision.
+///
+/// Expected: Ok(PageDto) with the first revision's content and three revisions
+#[tokio::test]
+async fn restores_earlier_revision() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let page_service = PageService::new(&test.db);
+    for content in ["Be nice.", "Be mean."] {
+        page_service
+            .save_page(
+                user_model.id,
+                "rules",
+                SavePageDto {
+                    title: "Rules".to_string(),
+                    content: content.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+    let first_revision = page_service.get_revisions("rules").await.unwrap()[1].clone();
+
+    let page = page_service
+        .restore_revision(user_model.id, "rules", first_revision.id)
+        .await
+        .unwrap();
+
+    assert_eq!(page.content, "Be nice.");
+    let revisions = page_service.get_revisions("rules").await.unwrap();
+    assert_eq!(revisions.len(), 3);
+    assert_eq!(revisions[0].content, "Be nice.");
+
+    Ok(())
+}
+
+/// Tests error handling for a revision of another page.
+///
+/// Expected: Err(AppError::Page(PageError::RevisionNotFound))
+#[tokio::test]
+async fn fails_for_revision_of_other_page() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let page_service = PageService::new(&test.db);
+    for slug in ["rules", "srp"] {
+        page_service
+            .save_page(
+                user_model.id,
+                slug,
+                SavePageDto {
+                    title: slug.to_string(),
+                    content: String::new(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+    let srp_revision = page_service.get_revisions("srp").await.unwrap()[0].clone();
+
+    let result = page_service
+        .restore_revision(user_model.id, "rules", srp_revision.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Page(PageError::RevisionNotFound(_)))
+    ));
+
+    Ok(())
+}
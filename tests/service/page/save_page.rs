@@ -0,0 +1,138 @@
+//! Tests for PageService::save_page method.
+//!
+//! This module verifies creating and updating pages, recording a revision for every save, and
+//! rejecting invalid slugs and titles.
+
+use bifrost::{
+    model::page::SavePageDto,
+    server::{
+        error::{page::PageError, AppError},
+        service::page::PageService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests saving a new page and then updating it.
+///
+/// Verifies that the content is rendered as HTML and that each save records a revision.
+///
+/// Expected: Ok(PageDto) with rendered HTML and two revisions, newest first
+#[tokio::test]
+async fn saves_page_and_records_revisions() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let page_service = PageService::new(&test.db);
+    page_service
+        .save_page(
+            user_model.id,
+            "srp",
+            SavePageDto {
+                title: "SRP".to_string(),
+                content: "Losses are covered.".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    let page = page_service
+        .save_page(
+            user_model.id,
+            "srp",
+            SavePageDto {
+                title: " SRP Policy ".to_string(),
+                content: "# Coverage\n\n**Doctrine** ships only.".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.title, "SRP Policy");
+    assert_eq!(
+        page.html,
+        "<h1>Coverage</h1>\n<p><strong>Doctrine</strong> ships only.</p>\n"
+    );
+
+    let revisions = page_service.get_revisions("srp").await.unwrap();
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].title, "SRP Policy");
+    assert_eq!(revisions[1].content, "Losses are covered.");
+    assert_eq!(revisions[0].edited_by_user_id, user_model.id);
+
+    Ok(())
+}
+
+/// Tests error handling for slugs with characters other than lowercase letters, digits, and
+/// hyphens.
+///
+/// Expected: Err(AppError::Page(PageError::InvalidSlug))
+#[tokio::test]
+async fn fails_for_invalid_slug() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = PageService::new(&test.db)
+        .save_page(
+            user_model.id,
+            "SRP Policy",
+            SavePageDto {
+                title: "SRP".to_string(),
+                content: String::new(),
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Page(PageError::InvalidSlug(_)))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for blank titles.
+///
+/// Expected: Err(AppError::Page(PageError::EmptyTitle))
+#[tokio::test]
+async fn fails_for_empty_title() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = PageService::new(&test.db)
+        .save_page(
+            user_model.id,
+            "srp",
+            SavePageDto {
+                title: "   ".to_string(),
+                content: String::new(),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Page(PageError::EmptyTitle))));
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! Tests for PreferenceService::update_preferences method.
+//!
+//! This module verifies saving the dashboard layout, reading it back in order, and rejecting
+//! layouts that list a widget more than once.
+
+use bifrost::{
+    model::preference::{DashboardWidget, UserPreferencesDto},
+    server::{
+        error::{preference::PreferenceError, AppError},
+        service::preference::PreferenceService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests saving a dashboard layout and reading it back.
+///
+/// Verifies that users start with the default layout and the weekly digest turned on, and
+/// that a saved layout keeps the widget order.
+///
+/// Expected: Ok with the saved layout returned by get_preferences
+#[tokio::test]
+async fn saves_dashboard_layout() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserPreference)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let preference_service = PreferenceService::new(&test.db);
+    let defaults = preference_service
+        .get_preferences(user_model.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        defaults.dashboard_layout,
+        DashboardWidget::DEFAULT_LAYOUT.to_vec()
+    );
+    assert!(defaults.weekly_digest);
+
+    let layout = vec![DashboardWidget::DataSharing, DashboardWidget::Characters];
+    preference_service
+        .update_preferences(
+            user_model.id,
+            UserPreferencesDto {
+                dashboard_layout: layout.clone(),
+                weekly_digest: false,
+            },
+        )
+        .await
+        .unwrap();
+    let preferences = preference_service
+        .get_preferences(user_model.id)
+        .await
+        .unwrap();
+
+    assert_eq!(preferences.dashboard_layout, layout);
+    assert!(!preferences.weekly_digest);
+
+    Ok(())
+}
+
+/// Tests error handling for a layout listing a widget twice.
+///
+/// Expected: Err(AppError::Preference(PreferenceError::InvalidDashboardLayout))
+#[tokio::test]
+async fn fails_for_duplicate_widget() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserPreference)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = PreferenceService::new(&test.db)
+        .update_preferences(
+            user_model.id,
+            UserPreferencesDto {
+                dashboard_layout: vec![DashboardWidget::Updates, DashboardWidget::Updates],
+                weekly_digest: true,
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Preference(
+            PreferenceError::InvalidDashboardLayout(_)
+        ))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+//! Tests for PushService::subscribe method.
+//!
+//! This module verifies saving push subscriptions and rejecting subscriptions while push
+//! notifications are disabled, when the browser provided malformed keys, or when the database
+//! fails.
+
+use bifrost::{
+    model::push::{CreatePushSubscriptionDto, PushSubscriptionKeysDto},
+    server::{
+        error::{push::PushError, AppError},
+        service::push::{PushConfig, PushService},
+        util::web_push::VapidKey,
+    },
+};
+use bifrost_test_utils::prelude::*;
+use sea_orm::EntityTrait;
+
+/// Uncompressed P-256 generator point, a valid browser public key.
+const P256DH: &str =
+    "BGsX0fLhLEJH-Lzm5WOkQPJ3A32BLeszoPShOUXYmMKWT-NC4v4af5uO5-tKfA-eFivOM1drMV7Oy7ZAaDe_UfU";
+
+/// 16-byte authentication secret.
+const AUTH: &str = "AgICAgICAgICAgICAgICAg";
+
+/// Creates push settings with a fixed VAPID key.
+fn enabled_config() -> PushConfig {
+    PushConfig {
+        vapid_key: Some(
+            VapidKey::from_base64("AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE").unwrap(),
+        ),
+        subject: "mailto:admin@example.com".to_string(),
+    }
+}
+
+/// Creates a subscription for a push service endpoint.
+fn subscription(endpoint: &str, p256dh: &str) -> CreatePushSubscriptionDto {
+    CreatePushSubscriptionDto {
+        endpoint: endpoint.to_string(),
+        keys: PushSubscriptionKeysDto {
+            p256dh: p256dh.to_string(),
+            auth: AUTH.to_string(),
+        },
+    }
+}
+
+/// Tests subscribing a browser.
+///
+/// Expected: Ok with the subscription stored for the user
+#[tokio::test]
+async fn saves_subscription() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let config = enabled_config();
+    PushService::new(&test.db, &config)
+        .subscribe(
+            user_model.id,
+            subscription("https://push.example.com/send/1", P256DH),
+        )
+        .await
+        .unwrap();
+
+    let subscriptions = entity::prelude::BifrostPushSubscription::find()
+        .all(&test.db)
+        .await?;
+    assert_eq!(subscriptions.len(), 1);
+    assert_eq!(subscriptions[0].user_id, user_model.id);
+    assert_eq!(subscriptions[0].p256dh, P256DH);
+
+    Ok(())
+}
+
+/// Tests error handling when push notifications are not configured.
+///
+/// Expected: Err(AppError::Push(PushError::PushDisabled))
+#[tokio::test]
+async fn fails_when_push_disabled() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let config = PushConfig::default();
+    let result = PushService::new(&test.db, &config)
+        .subscribe(
+            user_model.id,
+            subscription("https://push.example.com/send/1", P256DH),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Push(PushError::PushDisabled))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for malformed keys and insecure endpoints.
+///
+/// Expected: Err(AppError::Push(PushError::InvalidSubscription))
+#[tokio::test]
+async fn fails_for_invalid_subscription() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let config = enabled_config();
+    let push_service = PushService::new(&test.db, &config);
+    let invalid_key = push_service
+        .subscribe(
+            user_model.id,
+            subscription("https://push.example.com/send/1", "invalid"),
+        )
+        .await;
+    let insecure_endpoint = push_service
+        .subscribe(
+            user_model.id,
+            subscription("http://push.example.com/send/1", P256DH),
+        )
+        .await;
+
+    assert!(matches!(
+        invalid_key,
+        Err(AppError::Push(PushError::InvalidSubscription(_)))
+    ));
+    assert!(matches!(
+        insecure_endpoint,
+        Err(AppError::Push(PushError::InvalidSubscription(_)))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling when the database fails mid-test, and recovery afterwards.
+///
+/// Expected: Err(AppError::Database) while writes fail, then Ok once the database recovers
+#[tokio::test]
+async fn fails_while_database_rejects_writes() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let config = enabled_config();
+    let push_service = PushService::new(&test.db, &config);
+
+    test.set_database_read_only(true).await?;
+    let failed = push_service
+        .subscribe(
+            user_model.id,
+            subscription("https://push.example.com/send/1", P256DH),
+        )
+        .await;
+
+    test.set_database_read_only(false).await?;
+    let recovered = push_service
+        .subscribe(
+            user_model.id,
+            subscription("https://push.example.com/send/1", P256DH),
+        )
+        .await;
+
+    assert!(matches!(failed, Err(AppError::Database(_))));
+    assert!(recovered.is_ok());
+
+    Ok(())
+}
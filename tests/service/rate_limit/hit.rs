@@ -0,0 +1,84 @@
+//! Tests for RateLimitService::hit method.
+//!
+//! This module verifies requests are allowed up to the limit within a window, rejected with
+//! the seconds until the window resets afterwards, and counted separately per client.
+
+use std::time::Duration;
+
+use bifrost::server::service::rate_limit::RateLimitService;
+use fred::prelude::*;
+
+use super::delete_counter;
+use crate::util::redis::RedisTest;
+
+/// Length of the windows used by tests.
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Tests that requests up to the limit are allowed and further requests rejected.
+///
+/// Expected: Ok(None) for the first 3 requests, Ok(Some) within the window for the 4th
+#[tokio::test]
+async fn rejects_requests_over_limit() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let service = RateLimitService::new(&redis.redis_pool);
+    let subject = redis.queue_name();
+
+    for _ in 0..3 {
+        let result = service.hit("test", &subject, 3, WINDOW).await.unwrap();
+        assert_eq!(result, None);
+    }
+
+    let retry_after = service.hit("test", &subject, 3, WINDOW).await.unwrap();
+    assert!(retry_after.is_some_and(|secs| secs > 0 && secs <= WINDOW.as_secs()));
+
+    delete_counter(&redis.redis_pool, "test", &subject).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests that clients are counted separately.
+///
+/// Expected: Ok(None) for another client after the first client reached the limit
+#[tokio::test]
+async fn counts_clients_separately() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let service = RateLimitService::new(&redis.redis_pool);
+    let limited = format!("{}:limited", redis.queue_name());
+    let other = format!("{}:other", redis.queue_name());
+
+    service.hit("test", &limited, 1, WINDOW).await.unwrap();
+    let result = service.hit("test", &limited, 1, WINDOW).await.unwrap();
+    assert!(result.is_some());
+
+    let result = service.hit("test", &other, 1, WINDOW).await.unwrap();
+    assert_eq!(result, None);
+
+    delete_counter(&redis.redis_pool, "test", &limited).await;
+    delete_counter(&redis.redis_pool, "test", &other).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests that a counter left without an expiry gets one when the client is rejected.
+///
+/// Expected: Ok(Some) with the full window and the counter expiring within the window
+#[tokio::test]
+async fn restores_missing_expiry() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let service = RateLimitService::new(&redis.redis_pool);
+    let subject = redis.queue_name();
+    let key = format!("bifrost:rate_limit:test:{}", subject);
+
+    let _: () = redis
+        .redis_pool
+        .set(&key, 5, None, None, false)
+        .await
+        .unwrap();
+
+    let retry_after = service.hit("test", &subject, 1, WINDOW).await.unwrap();
+    assert_eq!(retry_after, Some(WINDOW.as_secs()));
+
+    let ttl: i64 = redis.redis_pool.ttl(&key).await.unwrap();
+    assert!(ttl > 0 && ttl <= WINDOW.as_secs() as i64);
+
+    delete_counter(&redis.redis_pool, "test", &subject).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
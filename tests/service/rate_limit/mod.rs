@@ -0,0 +1,10 @@
+use fred::prelude::*;
+
+mod hit;
+
+/// Deletes the request counter of a client.
+async fn delete_counter(pool: &Pool, scope: &str, subject: &str) {
+    pool.del::<(), _>(format!("bifrost:rate_limit:{}:{}", scope, subject))
+        .await
+        .expect("Failed to delete rate limit counter");
+}
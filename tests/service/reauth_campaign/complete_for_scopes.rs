@@ -0,0 +1,98 @@
+//! Tests for ReauthCampaignService::complete_for_scopes method.
+//!
+//! This module verifies that granting scopes completes only the campaigns asking for a subset
+//! of them and that completion is counted per campaign.
+
+use bifrost::{
+    model::{announcement::AnnouncementAudience, reauth_campaign::CreateReauthCampaignDto},
+    server::service::reauth_campaign::ReauthCampaignService,
+};
+use bifrost_test_utils::prelude::*;
+use chrono::Utc;
+
+/// Builds a campaign for all members requesting the given scopes.
+fn campaign(name: &str, scopes: &[&str]) -> CreateReauthCampaignDto {
+    CreateReauthCampaignDto {
+        name: name.to_string(),
+        scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+        audience: AnnouncementAudience::All,
+        audience_id: None,
+        deadline: None,
+    }
+}
+
+/// Tests completing campaigns after a login granting scopes.
+///
+/// Verifies that a campaign asking for a scope that wasn't granted stays pending and that
+/// the completed campaign counts one of its two flagged users as completed.
+///
+/// Expected: Ok(1) with only the covered campaign completed
+#[tokio::test]
+async fn completes_campaigns_covered_by_granted_scopes() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    let reauth_service = ReauthCampaignService::new(&test.db);
+    let assets = reauth_service
+        .create_campaign(
+            user_model.id,
+            campaign("Asset audit", &["esi-assets.read_assets.v1"]),
+        )
+        .await
+        .unwrap();
+    let wallet = reauth_service
+        .create_campaign(
+            user_model.id,
+            campaign(
+                "Wallet audit",
+                &[
+                    "esi-assets.read_assets.v1",
+                    "esi-wallet.read_character_wallet.v1",
+                ],
+            ),
+        )
+        .await
+        .unwrap();
+
+    let completed = reauth_service
+        .complete_for_scopes(
+            user_model.id,
+            &[
+                "esi-assets.read_assets.v1".to_string(),
+                "esi-skills.read_skills.v1".to_string(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(completed, 1);
+
+    let pending = reauth_service
+        .get_pending(user_model.id, Utc::now().naive_utc())
+        .await
+        .unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].id, wallet.id);
+
+    let campaigns = reauth_service.get_campaigns().await.unwrap();
+    let assets = campaigns
+        .iter()
+        .find(|campaign| campaign.id == assets.id)
+        .unwrap();
+    assert_eq!(assets.user_count, 2);
+    assert_eq!(assets.completed_count, 1);
+    assert_eq!(assets.completion_percent(), 50);
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+//! Tests for ReauthCampaignService::create_campaign method.
+//!
+//! This module verifies flagging the users in a campaign's audience and rejecting campaigns
+//! without scopes or with a deadline in the past.
+
+use bifrost::{
+    model::{announcement::AnnouncementAudience, reauth_campaign::CreateReauthCampaignDto},
+    server::{
+        error::{reauth_campaign::ReauthCampaignError, AppError},
+        service::{
+            reauth_campaign::ReauthCampaignService, user::user_character::UserCharacterService,
+        },
+    },
+};
+use bifrost_test_utils::prelude::*;
+use chrono::{Duration, NaiveDateTime, Utc};
+
+/// Builds a campaign for the audience requesting the given scopes.
+fn campaign(
+    scopes: &[&str],
+    audience: AnnouncementAudience,
+    audience_id: Option<i64>,
+    deadline: Option<NaiveDateTime>,
+) -> CreateReauthCampaignDto {
+    CreateReauthCampaignDto {
+        name: " Asset audit ".to_string(),
+        scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+        audience,
+        audience_id,
+        deadline,
+    }
+}
+
+/// Tests launching a campaign for the members of a corporation.
+///
+/// Verifies that only users owning a character in the corporation are flagged, that
+/// duplicate scopes are removed, and that the campaign starts without completions.
+///
+/// Expected: Ok with the corporation member as the only flagged user
+#[tokio::test]
+async fn flags_corporation_members() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .build()
+        .await?;
+    let (member, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (outsider, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 2, None, None)
+        .await?;
+    for user_id in [member.id, outsider.id] {
+        UserCharacterService::refresh_summary(&test.db, user_id)
+            .await
+            .expect("Refreshing the summary should succeed");
+    }
+
+    let reauth_service = ReauthCampaignService::new(&test.db);
+    let created = reauth_service
+        .create_campaign(
+            outsider.id,
+            campaign(
+                &[
+                    "esi-assets.read_assets.v1 esi-wallet.read_character_wallet.v1",
+                    "esi-assets.read_assets.v1",
+                ],
+                AnnouncementAudience::Corporation,
+                Some(1),
+                Some(Utc::now().naive_utc() + Duration::days(7)),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(created.name, "Asset audit");
+    assert_eq!(
+        created.scopes,
+        vec![
+            "esi-assets.read_assets.v1".to_string(),
+            "esi-wallet.read_character_wallet.v1".to_string()
+        ]
+    );
+    assert_eq!(created.user_count, 1);
+    assert_eq!(created.completed_count, 0);
+    assert_eq!(created.completion_percent(), 0);
+
+    let now = Utc::now().naive_utc();
+    assert_eq!(
+        reauth_service
+            .get_pending(member.id, now)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(reauth_service
+        .get_pending(outsider.id, now)
+        .await
+        .unwrap()
+        .is_empty());
+
+    Ok(())
+}
+
+/// Tests error handling for campaigns without scopes.
+///
+/// Expected: Err(AppError::ReauthCampaign(ReauthCampaignError::InvalidCampaign))
+#[tokio::test]
+async fn fails_for_empty_scopes() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = ReauthCampaignService::new(&test.db)
+        .create_campaign(
+            user_model.id,
+            campaign(&["  "], AnnouncementAudience::All, None, None),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::ReauthCampaign(
+            ReauthCampaignError::InvalidCampaign(_)
+        ))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for deadlines in the past.
+///
+/// Verifies that a campaign can't restrict every flagged user as soon as it is launched.
+///
+/// Expected: Err(AppError::ReauthCampaign(ReauthCampaignError::InvalidCampaign))
+#[tokio::test]
+async fn fails_for_past_deadline() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = ReauthCampaignService::new(&test.db)
+        .create_campaign(
+            user_model.id,
+            campaign(
+                &["esi-assets.read_assets.v1"],
+                AnnouncementAudience::All,
+                None,
+                Some(Utc::now().naive_utc() - Duration::hours(1)),
+            ),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::ReauthCampaign(
+            ReauthCampaignError::InvalidCampaign(_)
+        ))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! Tests for ReauthCampaignService::ensure_not_restricted method.
+//!
+//! This module verifies that users lose access once a campaign they haven't completed passes
+//! its deadline and regain it by completing the campaign.
+
+use bifrost::{
+    model::{announcement::AnnouncementAudience, reauth_campaign::CreateReauthCampaignDto},
+    server::{
+        error::{reauth_campaign::ReauthCampaignError, AppError},
+        service::reauth_campaign::ReauthCampaignService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+use chrono::{Duration, Utc};
+
+/// Tests restricting a user after a campaign's deadline.
+///
+/// Verifies that the user keeps access before the deadline, is restricted after it, and
+/// regains access once they grant the campaign's scopes.
+///
+/// Expected: Err(AppError::ReauthCampaign(ReauthCampaignError::ReauthRequired)) only while the
+/// campaign is past its deadline and pending
+#[tokio::test]
+async fn restricts_after_deadline_until_completed() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let deadline = Utc::now().naive_utc() + Duration::hours(1);
+    let reauth_service = ReauthCampaignService::new(&test.db);
+    reauth_service
+        .create_campaign(
+            user_model.id,
+            CreateReauthCampaignDto {
+                name: "Asset audit".to_string(),
+                scopes: vec!["esi-assets.read_assets.v1".to_string()],
+                audience: AnnouncementAudience::All,
+                audience_id: None,
+                deadline: Some(deadline),
+            },
+        )
+        .await
+        .unwrap();
+
+    let before_deadline = deadline - Duration::minutes(1);
+    let after_deadline = deadline + Duration::minutes(1);
+
+    assert!(reauth_service
+        .ensure_not_restricted(user_model.id, before_deadline)
+        .await
+        .is_ok());
+
+    let pending = reauth_service
+        .get_pending(user_model.id, after_deadline)
+        .await
+        .unwrap();
+    assert!(pending[0].restricted);
+
+    let result = reauth_service
+        .ensure_not_restricted(user_model.id, after_deadline)
+        .await;
+    assert!(matches!(
+        result,
+        Err(AppError::ReauthCampaign(
+            ReauthCampaignError::ReauthRequired { .. }
+        ))
+    ));
+
+    reauth_service
+        .complete_for_scopes(user_model.id, &["esi-assets.read_assets.v1".to_string()])
+        .await
+        .unwrap();
+
+    assert!(reauth_service
+        .ensure_not_restricted(user_model.id, after_deadline)
+        .await
+        .is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+mod complete_for_scopes;
+mod create_campaign;
+mod ensure_not_restricted;
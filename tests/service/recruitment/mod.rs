@@ -0,0 +1 @@
+mod upsert_listing;
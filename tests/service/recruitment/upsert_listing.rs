@@ -0,0 +1,120 @@
+//! Tests for RecruitmentService::upsert_listing method.
+//!
+//! This module verifies recruitment listing management, including creation of a listing by
+//! the corporation's CEO, rejection of users who do not own the CEO character, and
+//! validation of listing input.
+
+use bifrost::{
+    model::recruitment::UpsertRecruitmentListingDto,
+    server::{
+        error::{recruitment::RecruitmentError, AppError},
+        service::recruitment::RecruitmentService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Character ID of the CEO used by the mock corporation factory.
+const MOCK_CEO_CHARACTER_ID: i64 = 2114794365;
+
+/// Builds a valid listing payload.
+fn listing() -> UpsertRecruitmentListingDto {
+    UpsertRecruitmentListingDto {
+        description: "Nullsec PvP corporation".to_string(),
+        timezone: "EU".to_string(),
+    }
+}
+
+/// Tests creating a listing as the corporation's CEO.
+///
+/// Verifies that a user owning the CEO character can create a listing and that it appears
+/// in the public listings.
+///
+/// Expected: Ok with the listing returned by get_listings
+#[tokio::test]
+async fn creates_listing_for_ceo() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostRecruitmentListing)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(MOCK_CEO_CHARACTER_ID, 1, None, None)
+        .await?;
+
+    let recruitment_service = RecruitmentService::new(&test.db);
+    let result = recruitment_service
+        .upsert_listing(user_model.id, 1, listing())
+        .await;
+
+    assert!(result.is_ok());
+    let listings = recruitment_service.get_listings().await.unwrap();
+    assert_eq!(listings.len(), 1);
+    assert_eq!(listings[0].corporation_id, 1);
+    assert_eq!(listings[0].timezone, "EU");
+
+    Ok(())
+}
+
+/// Tests error handling for a user who is not the corporation's CEO.
+///
+/// Expected: Err(AppError::Recruitment(RecruitmentError::NotCorporationCeo))
+#[tokio::test]
+async fn fails_for_non_ceo() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostRecruitmentListing)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = RecruitmentService::new(&test.db)
+        .upsert_listing(user_model.id, 1, listing())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Recruitment(RecruitmentError::NotCorporationCeo(
+            1
+        )))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for an empty description.
+///
+/// Expected: Err(AppError::Recruitment(RecruitmentError::InvalidListing))
+#[tokio::test]
+async fn fails_for_empty_description() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostRecruitmentListing)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(MOCK_CEO_CHARACTER_ID, 1, None, None)
+        .await?;
+
+    let result = RecruitmentService::new(&test.db)
+        .upsert_listing(
+            user_model.id,
+            1,
+            UpsertRecruitmentListingDto {
+                description: "   ".to_string(),
+                timezone: "EU".to_string(),
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Recruitment(RecruitmentError::InvalidListing(_)))
+    ));
+
+    Ok(())
+}
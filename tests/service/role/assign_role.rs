@@ -0,0 +1,67 @@
+//! Tests for RoleService::assign_role method.
+//!
+//! This module verifies assigning roles to users and rejecting the member role every user
+//! already has.
+
+use bifrost::server::{
+    data::role::UserRoleRepository,
+    error::{role::RoleError, AppError},
+    service::role::RoleService,
+};
+use bifrost_test_utils::prelude::*;
+
+use super::seed_built_in_roles;
+
+/// Tests assigning the admin role to a user.
+///
+/// Expected: Ok with the user having the role
+#[tokio::test]
+async fn assigns_role() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (admin, _) = seed_built_in_roles(&test).await?;
+    let (first, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (second, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+
+    RoleService::new(&test.db)
+        .assign_role(first.id, admin.id, second.id)
+        .await
+        .unwrap();
+
+    assert!(
+        UserRoleRepository::new(&test.db)
+            .has_role(second.id, admin.id)
+            .await?
+    );
+
+    Ok(())
+}
+
+/// Tests error handling for assigning the member role.
+///
+/// Expected: Err(BuiltInRole)
+#[tokio::test]
+async fn fails_for_member_role() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (_, member) = seed_built_in_roles(&test).await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = RoleService::new(&test.db)
+        .assign_role(user.id, member.id, user.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Role(RoleError::BuiltInRole(_)))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! Tests for RoleService::get_user_permissions method.
+//!
+//! This module verifies combining the permissions of the member role with those of the roles
+//! assigned to a user, and granting every permission to admins.
+
+use bifrost::{
+    model::role::Permission,
+    server::{
+        data::role::{RoleRepository, UserRoleRepository},
+        service::role::RoleService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+use super::seed_built_in_roles;
+
+/// Tests the permissions of a user without roles.
+///
+/// Expected: Ok with an empty list
+#[tokio::test]
+async fn grants_nothing_without_roles() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    seed_built_in_roles(&test).await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let permissions = RoleService::new(&test.db)
+        .get_user_permissions(user.id)
+        .await
+        .unwrap();
+
+    assert!(permissions.is_empty());
+
+    Ok(())
+}
+
+/// Tests combining the member role with an assigned role.
+///
+/// Verifies that permissions given to the member role apply without assignment and that
+/// duplicates are only listed once.
+///
+/// Expected: Ok with the permissions of both roles
+#[tokio::test]
+async fn combines_member_and_assigned_roles() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (_, member) = seed_built_in_roles(&test).await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let role_repo = RoleRepository::new(&test.db);
+    role_repo
+        .update(
+            member.id,
+            member.name,
+            member.description,
+            "manage_content".to_string(),
+        )
+        .await?;
+    let officer = role_repo
+        .create(
+            "officer".to_string(),
+            String::new(),
+            "manage_members manage_content".to_string(),
+            false,
+        )
+        .await?;
+    UserRoleRepository::new(&test.db)
+        .assign(user.id, officer.id)
+        .await?;
+
+    let permissions = RoleService::new(&test.db)
+        .get_user_permissions(user.id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        permissions,
+        vec![Permission::ManageMembers, Permission::ManageContent]
+    );
+
+    Ok(())
+}
+
+/// Tests that the admin role grants every permission.
+///
+/// Expected: Ok with every permission
+#[tokio::test]
+async fn grants_admin_every_permission() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (admin, _) = seed_built_in_roles(&test).await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    UserRoleRepository::new(&test.db)
+        .assign(user.id, admin.id)
+        .await?;
+
+    let service = RoleService::new(&test.db);
+    let permissions = service.get_user_permissions(user.id).await.unwrap();
+
+    assert_eq!(permissions, Permission::ALL.to_vec());
+    assert!(service
+        .has_permission(user.id, Permission::DecideApprovals)
+        .await
+        .unwrap());
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! Tests for RoleService::grants_admin method.
+//!
+//! This module verifies detecting roles whose assignment grants the admin permission.
+
+use bifrost::{
+    model::role::{CreateRoleDto, Permission},
+    server::service::role::RoleService,
+};
+use bifrost_test_utils::prelude::*;
+
+use super::seed_built_in_roles;
+
+/// Tests checking the admin role and a role without the admin permission.
+///
+/// Expected: Ok(true) for the admin role, Ok(false) for the other role
+#[tokio::test]
+async fn detects_admin_permission() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (admin, _) = seed_built_in_roles(&test).await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let role_service = RoleService::new(&test.db);
+    let recruiter = role_service
+        .create_role(CreateRoleDto {
+            name: "Recruiter".to_string(),
+            description: String::new(),
+            permissions: vec![Permission::ManageMembers],
+        })
+        .await
+        .unwrap();
+
+    assert!(role_service.grants_admin(admin.id, user.id).await.unwrap());
+    assert!(!role_service
+        .grants_admin(recruiter.id, user.id)
+        .await
+        .unwrap());
+
+    Ok(())
+}
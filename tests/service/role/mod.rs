@@ -0,0 +1,26 @@
+mod assign_role;
+mod get_user_permissions;
+mod grants_admin;
+mod revoke_role;
+mod update_role;
+
+use bifrost::server::{data::role::RoleRepository, model::db::RoleModel};
+use bifrost_test_utils::prelude::*;
+
+/// Seeds the built-in admin and member roles the migration creates.
+async fn seed_built_in_roles(test: &TestContext) -> Result<(RoleModel, RoleModel), TestError> {
+    let role_repo = RoleRepository::new(&test.db);
+    let admin = role_repo
+        .create(
+            "admin".to_string(),
+            String::new(),
+            "admin".to_string(),
+            true,
+        )
+        .await?;
+    let member = role_repo
+        .create("member".to_string(), String::new(), String::new(), true)
+        .await?;
+
+    Ok((admin, member))
+}
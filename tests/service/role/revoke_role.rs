@@ -0,0 +1,67 @@
+//! Tests for RoleService::revoke_role method.
+//!
+//! This module verifies removing roles from users and keeping at least one user with the
+//! admin role.
+
+use bifrost::server::{
+    data::role::UserRoleRepository,
+    error::{role::RoleError, AppError},
+    service::role::RoleService,
+};
+use bifrost_test_utils::prelude::*;
+
+use super::seed_built_in_roles;
+
+/// Tests removing the admin role while another admin remains.
+///
+/// Expected: Ok with the role removed from the user
+#[tokio::test]
+async fn revokes_admin_with_other_admins() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (admin, _) = seed_built_in_roles(&test).await?;
+    let (first, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (second, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let user_role_repo = UserRoleRepository::new(&test.db);
+    user_role_repo.assign(first.id, admin.id).await?;
+    user_role_repo.assign(second.id, admin.id).await?;
+
+    RoleService::new(&test.db)
+        .revoke_role(admin.id, first.id)
+        .await
+        .unwrap();
+
+    assert!(!user_role_repo.has_role(first.id, admin.id).await?);
+    assert!(user_role_repo.has_role(second.id, admin.id).await?);
+
+    Ok(())
+}
+
+/// Tests removing the admin role from the last admin.
+///
+/// Expected: Err(LastAdmin) and the user keeps the role
+#[tokio::test]
+async fn fails_for_last_admin() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (admin, _) = seed_built_in_roles(&test).await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let user_role_repo = UserRoleRepository::new(&test.db);
+    user_role_repo.assign(user.id, admin.id).await?;
+
+    let result = RoleService::new(&test.db)
+        .revoke_role(admin.id, user.id)
+        .await;
+
+    assert!(matches!(result, Err(AppError::Role(RoleError::LastAdmin))));
+    assert!(user_role_repo.has_role(user.id, admin.id).await?);
+
+    Ok(())
+}
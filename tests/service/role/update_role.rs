@@ -0,0 +1,96 @@
+//! Tests for RoleService::update_role method.
+//!
+//! This module verifies updating roles and protecting the built-in roles from being renamed or
+//! losing the admin permission.
+
+use bifrost::{
+    model::role::{Permission, UpdateRoleDto},
+    server::{
+        error::{role::RoleError, AppError},
+        service::role::RoleService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+use super::seed_built_in_roles;
+
+/// Tests changing the permissions of the member role.
+///
+/// Expected: Ok with the new permissions and the name unchanged
+#[tokio::test]
+async fn updates_member_permissions() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+    let (_, member) = seed_built_in_roles(&test).await?;
+
+    let role = RoleService::new(&test.db)
+        .update_role(
+            member.id,
+            UpdateRoleDto {
+                name: "member".to_string(),
+                description: "Every user".to_string(),
+                permissions: vec![Permission::ManageContent],
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(role.name, "member");
+    assert_eq!(role.permissions, vec![Permission::ManageContent]);
+    assert!(role.built_in);
+
+    Ok(())
+}
+
+/// Tests renaming a built-in role.
+///
+/// Expected: Err(BuiltInRole)
+#[tokio::test]
+async fn fails_to_rename_built_in_role() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+    let (_, member) = seed_built_in_roles(&test).await?;
+
+    let result = RoleService::new(&test.db)
+        .update_role(
+            member.id,
+            UpdateRoleDto {
+                name: "everyone".to_string(),
+                description: String::new(),
+                permissions: Vec::new(),
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Role(RoleError::BuiltInRole(_)))
+    ));
+
+    Ok(())
+}
+
+/// Tests removing the admin permission from the admin role.
+///
+/// Expected: Err(BuiltInRole)
+#[tokio::test]
+async fn fails_to_remove_admin_permission() -> Result<(), TestError> {
+    let test = TestBuilder::new().with_user_tables().build().await?;
+    let (admin, _) = seed_built_in_roles(&test).await?;
+
+    let result = RoleService::new(&test.db)
+        .update_role(
+            admin.id,
+            UpdateRoleDto {
+                name: "admin".to_string(),
+                description: String::new(),
+                permissions: vec![Permission::ManageRoles],
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Role(RoleError::BuiltInRole(_)))
+    ));
+
+    Ok(())
+}
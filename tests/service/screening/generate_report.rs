@@ -0,0 +1,77 @@
+//! Tests for ScreeningService::generate_report method.
+//!
+//! This module verifies screening report generation, including detection of known alts via
+//! ownership records, retrieval of the stored report, and error handling for characters not
+//! registered with any user.
+
+use bifrost::server::{
+    error::{screening::ScreeningError, AppError},
+    service::screening::ScreeningService,
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests generating a report for a character with alts.
+///
+/// Verifies that the report contains the screened character and lists the other characters
+/// owned by the same user as known alts, and that the stored report can be retrieved by ID.
+///
+/// Expected: Ok with 1 known alt
+#[tokio::test]
+async fn lists_known_alts() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.user()
+        .insert_mock_character_for_user(user_model.id, 2, 1, None, None)
+        .await?;
+
+    let screening_service = ScreeningService::new(&test.db);
+    let result = screening_service.generate_report(user_model.id, 1).await;
+
+    assert!(result.is_ok());
+    let report = result.unwrap();
+    assert_eq!(report.character.id, 1);
+    assert_eq!(report.known_alts.len(), 1);
+    assert_eq!(report.known_alts[0].id, 2);
+
+    let stored = screening_service.get_report(report.id).await.unwrap();
+    assert!(stored == report);
+
+    Ok(())
+}
+
+/// Tests error handling for a character not owned by any user.
+///
+/// Expected: Err(AppError::Screening(ScreeningError::CharacterNotRegistered))
+#[tokio::test]
+async fn fails_for_unregistered_character() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.eve().insert_mock_character(2, 1, None, None).await?;
+
+    let result = ScreeningService::new(&test.db)
+        .generate_report(user_model.id, 2)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Screening(ScreeningError::CharacterNotRegistered(
+            2
+        )))
+    ));
+
+    Ok(())
+}
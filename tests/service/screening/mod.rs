@@ -0,0 +1 @@
+mod generate_report;
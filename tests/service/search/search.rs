@@ -0,0 +1,87 @@
+//! Tests for SearchService::search method.
+//!
+//! This module verifies searching entity names in the database, ranking names starting with
+//! the query first, and falling back to the database when Meilisearch is unreachable.
+
+use bifrost::server::{
+    service::search::{SearchConfig, SearchService, ALLIANCE_CATEGORY, CORPORATION_CATEGORY},
+    util::meilisearch::MeilisearchClient,
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests searching across entity categories.
+///
+/// Expected: Ok with the alliance, whose name starts with the query, before the corporation
+#[tokio::test]
+async fn ranks_prefix_matches_first() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_mock_corporation(1, Some(2), None)
+        .build()
+        .await?;
+    let config = SearchConfig::default();
+
+    let results = SearchService::new(&test.db, &config)
+        .search("autumn", 10)
+        .await
+        .expect("Search should succeed");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].category, ALLIANCE_CATEGORY);
+    assert_eq!(results[0].id, 2);
+    assert_eq!(results[1].category, CORPORATION_CATEGORY);
+    assert_eq!(results[1].id, 1);
+
+    Ok(())
+}
+
+/// Tests that the limit applies across categories.
+///
+/// Expected: Ok with a single result
+#[tokio::test]
+async fn truncates_to_limit() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_mock_corporation(1, Some(2), None)
+        .build()
+        .await?;
+    let config = SearchConfig::default();
+
+    let results = SearchService::new(&test.db, &config)
+        .search("autumn", 1)
+        .await
+        .expect("Search should succeed");
+
+    assert_eq!(results.len(), 1);
+
+    Ok(())
+}
+
+/// Tests searching while the configured Meilisearch instance is unreachable.
+///
+/// Expected: Ok with the results of the database search
+#[tokio::test]
+async fn falls_back_to_database() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_user_tables()
+        .with_mock_corporation(1, None, None)
+        .build()
+        .await?;
+    let config = SearchConfig {
+        meilisearch: Some(MeilisearchClient::new(
+            "http://127.0.0.1:1",
+            None,
+            reqwest::Client::new(),
+        )),
+    };
+
+    let results = SearchService::new(&test.db, &config)
+        .search("order", 10)
+        .await
+        .expect("Search should succeed");
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].category, CORPORATION_CATEGORY);
+
+    Ok(())
+}
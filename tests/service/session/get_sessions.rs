@@ -0,0 +1,103 @@
+//! Tests for SessionService::get_sessions method.
+//!
+//! This module verifies listing the sessions recorded by `touch`, marking the current session,
+//! and pruning sessions that no longer exist in the session store.
+
+use bifrost::server::service::session::SessionService;
+use tower_sessions::{session::Id, SessionStore};
+use tower_sessions_redis_store::RedisStore;
+
+use super::{client, create_session, delete_index, EXPIRES_IN};
+use crate::util::redis::RedisTest;
+
+/// Tests listing the sessions of a user with the current session marked.
+///
+/// Expected: Both sessions listed with their client details, only one marked as current
+#[tokio::test]
+async fn lists_sessions_with_current_marked() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let user_id = 910_001;
+    let service = SessionService::new(&redis.redis_pool);
+    let current = create_session(&redis.redis_pool).await;
+    let other = create_session(&redis.redis_pool).await;
+
+    service
+        .touch(
+            user_id,
+            current,
+            EXPIRES_IN,
+            client("10.0.0.1"),
+            Some("Firefox"),
+        )
+        .await
+        .unwrap();
+    service
+        .touch(user_id, other, EXPIRES_IN, client("10.0.0.2"), None)
+        .await
+        .unwrap();
+
+    let sessions = service.get_sessions(user_id, Some(current)).await.unwrap();
+
+    assert_eq!(sessions.len(), 2);
+    let current_session = sessions.iter().find(|s| s.current).unwrap();
+    assert_eq!(current_session.ip_address.as_deref(), Some("10.0.0.1"));
+    assert_eq!(current_session.user_agent.as_deref(), Some("Firefox"));
+    assert_eq!(sessions.iter().filter(|s| s.current).count(), 1);
+    assert!(sessions
+        .iter()
+        .all(|s| !s.id.contains(&current.to_string()) && !s.id.contains(&other.to_string())));
+
+    delete_index(&redis.redis_pool, user_id).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests that sessions deleted from the session store are pruned from the index.
+///
+/// Expected: Only the remaining session listed
+#[tokio::test]
+async fn prunes_ended_sessions() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let user_id = 910_002;
+    let service = SessionService::new(&redis.redis_pool);
+    let active = create_session(&redis.redis_pool).await;
+    let ended = create_session(&redis.redis_pool).await;
+
+    service
+        .touch(user_id, active, EXPIRES_IN, client("10.0.0.1"), None)
+        .await
+        .unwrap();
+    service
+        .touch(user_id, ended, EXPIRES_IN, client("10.0.0.1"), None)
+        .await
+        .unwrap();
+    RedisStore::new(redis.redis_pool.clone())
+        .delete(&ended)
+        .await
+        .unwrap();
+
+    let sessions = service.get_sessions(user_id, None).await.unwrap();
+    let retry = service.get_sessions(user_id, None).await.unwrap();
+
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions, retry);
+
+    delete_index(&redis.redis_pool, user_id).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests listing the sessions of a user without an index.
+///
+/// Expected: Ok with an empty list
+#[tokio::test]
+async fn returns_empty_without_index() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+
+    let sessions = SessionService::new(&redis.redis_pool)
+        .get_sessions(910_003, Some(Id::default()))
+        .await
+        .unwrap();
+
+    assert!(sessions.is_empty());
+
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
@@ -0,0 +1,45 @@
+use bifrost::server::util::proxy::ClientInfo;
+use fred::prelude::*;
+use time::{Duration, OffsetDateTime};
+use tower_sessions::{
+    session::{Id, Record},
+    SessionStore,
+};
+use tower_sessions_redis_store::RedisStore;
+
+mod get_sessions;
+mod revoke_session;
+mod touch;
+
+/// Seconds until the sessions created by tests expire.
+const EXPIRES_IN: i64 = 60 * 60;
+
+/// Creates a session record in the Redis session store and returns its ID.
+async fn create_session(pool: &Pool) -> Id {
+    let mut record = Record {
+        id: Id::default(),
+        data: Default::default(),
+        expiry_date: OffsetDateTime::now_utc() + Duration::seconds(EXPIRES_IN),
+    };
+    RedisStore::new(pool.clone())
+        .create(&mut record)
+        .await
+        .expect("Failed to create session");
+
+    record.id
+}
+
+/// Returns client info for a client connecting from the given IP address.
+fn client(ip: &str) -> ClientInfo {
+    ClientInfo {
+        ip: Some(ip.parse().unwrap()),
+        https: true,
+    }
+}
+
+/// Deletes the session index of a user.
+async fn delete_index(pool: &Pool, user_id: i32) {
+    pool.del::<(), _>(format!("bifrost:user:sessions:{}", user_id))
+        .await
+        .expect("Failed to delete session index");
+}
@@ -0,0 +1,118 @@
+//! Tests for SessionService::revoke_session method.
+//!
+//! This module verifies revoking another session logs it out, while the current session and
+//! sessions of other users can't be revoked.
+
+use bifrost::server::{
+    error::{session::SessionError, AppError},
+    service::session::SessionService,
+};
+use tower_sessions::SessionStore;
+use tower_sessions_redis_store::RedisStore;
+
+use super::{client, create_session, delete_index, EXPIRES_IN};
+use crate::util::redis::RedisTest;
+
+/// Tests revoking another session of the user.
+///
+/// Expected: Session deleted from the session store and no longer listed
+#[tokio::test]
+async fn revokes_other_session() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let user_id = 920_001;
+    let service = SessionService::new(&redis.redis_pool);
+    let current = create_session(&redis.redis_pool).await;
+    let other = create_session(&redis.redis_pool).await;
+    service
+        .touch(user_id, current, EXPIRES_IN, client("10.0.0.1"), None)
+        .await
+        .unwrap();
+    service
+        .touch(user_id, other, EXPIRES_IN, client("10.0.0.2"), None)
+        .await
+        .unwrap();
+    let sessions = service.get_sessions(user_id, Some(current)).await.unwrap();
+    let other_id = sessions.iter().find(|s| !s.current).unwrap().id.clone();
+
+    service
+        .revoke_session(user_id, &other_id, Some(current))
+        .await
+        .unwrap();
+
+    let record = RedisStore::new(redis.redis_pool.clone())
+        .load(&other)
+        .await
+        .unwrap();
+    assert!(record.is_none());
+    let sessions = service.get_sessions(user_id, Some(current)).await.unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert!(sessions[0].current);
+
+    delete_index(&redis.redis_pool, user_id).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests revoking the session the request was made with.
+///
+/// Expected: Err(SessionError::CurrentSession) with the session left in place
+#[tokio::test]
+async fn fails_for_current_session() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let user_id = 920_002;
+    let service = SessionService::new(&redis.redis_pool);
+    let current = create_session(&redis.redis_pool).await;
+    service
+        .touch(user_id, current, EXPIRES_IN, client("10.0.0.1"), None)
+        .await
+        .unwrap();
+    let current_id = service.get_sessions(user_id, Some(current)).await.unwrap()[0]
+        .id
+        .clone();
+
+    let result = service
+        .revoke_session(user_id, &current_id, Some(current))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::UserSession(SessionError::CurrentSession))
+    ));
+    assert_eq!(service.get_sessions(user_id, None).await.unwrap().len(), 1);
+
+    delete_index(&redis.redis_pool, user_id).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests revoking a session of another user.
+///
+/// Expected: Err(SessionError::NotFound) with the other user's session left in place
+#[tokio::test]
+async fn fails_for_session_of_other_user() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let user_id = 920_003;
+    let other_user_id = 920_004;
+    let service = SessionService::new(&redis.redis_pool);
+    let other = create_session(&redis.redis_pool).await;
+    service
+        .touch(other_user_id, other, EXPIRES_IN, client("10.0.0.1"), None)
+        .await
+        .unwrap();
+    let other_id = service.get_sessions(other_user_id, None).await.unwrap()[0]
+        .id
+        .clone();
+
+    let result = service.revoke_session(user_id, &other_id, None).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::UserSession(SessionError::NotFound(_)))
+    ));
+    let record = RedisStore::new(redis.redis_pool.clone())
+        .load(&other)
+        .await
+        .unwrap();
+    assert!(record.is_some());
+
+    delete_index(&redis.redis_pool, other_user_id).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
@@ -0,0 +1,47 @@
+//! Tests for SessionService::touch method.
+//!
+//! This module verifies the session index is kept until the longest lived session of the user
+//! expires.
+
+use bifrost::server::service::session::SessionService;
+use fred::prelude::*;
+
+use super::{client, create_session, delete_index, EXPIRES_IN};
+use crate::util::redis::RedisTest;
+
+/// Tests that a session expiring sooner doesn't shorten the index.
+///
+/// Expected: Index expires with the remembered session
+#[tokio::test]
+async fn keeps_expiry_of_longest_session() {
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let user_id = 930_001;
+    let service = SessionService::new(&redis.redis_pool);
+    let remembered = create_session(&redis.redis_pool).await;
+    let other = create_session(&redis.redis_pool).await;
+
+    service
+        .touch(
+            user_id,
+            remembered,
+            EXPIRES_IN * 24,
+            client("10.0.0.1"),
+            None,
+        )
+        .await
+        .unwrap();
+    service
+        .touch(user_id, other, EXPIRES_IN, client("10.0.0.2"), None)
+        .await
+        .unwrap();
+
+    let ttl: i64 = redis
+        .redis_pool
+        .ttl(format!("bifrost:user:sessions:{}", user_id))
+        .await
+        .unwrap();
+    assert!(ttl > EXPIRES_IN);
+
+    delete_index(&redis.redis_pool, user_id).await;
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
@@ -0,0 +1,125 @@
+//! Tests for SkillPlanService::create_skill_plan method.
+//!
+//! This module verifies publishing skill plans from plain text and EVEMon XML, normalization
+//! of the stored plan, and rejection of invalid plans and duplicate names.
+
+use bifrost::server::{
+    error::{skill_plan::SkillPlanError, AppError},
+    service::skill_plan::SkillPlanService,
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests publishing a plan imported from EVEMon XML.
+///
+/// Verifies that the entries are parsed in order and the plan is stored as plain text.
+///
+/// Expected: Ok(SkillPlanDto) with parsed entries and normalized plan text
+#[tokio::test]
+async fn publishes_evemon_xml_plan() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let plan = r#"<plan name="Frigates">
+  <entry skillID="3330" skill="Caldari Frigate" level="4" />
+  <entry skillID="3449" skill="Navigation" level="3" />
+</plan>"#;
+    let skill_plan = SkillPlanService::new(&test.db)
+        .create_skill_plan(
+            user_model.id,
+            "Frigates".to_string(),
+            None,
+            plan.to_string(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(skill_plan.entries.len(), 2);
+    assert_eq!(skill_plan.entries[0].skill_name, "Caldari Frigate");
+    assert_eq!(skill_plan.entries[1].level, 3);
+    assert_eq!(skill_plan.plan, "Caldari Frigate IV\nNavigation III");
+
+    Ok(())
+}
+
+/// Tests error handling for plan text without valid levels.
+///
+/// Expected: Err(AppError::SkillPlan(SkillPlanError::InvalidPlan))
+#[tokio::test]
+async fn fails_for_invalid_plan() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = SkillPlanService::new(&test.db)
+        .create_skill_plan(
+            user_model.id,
+            "Frigates".to_string(),
+            None,
+            "Caldari Frigate".to_string(),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::SkillPlan(SkillPlanError::InvalidPlan(_)))
+    ));
+
+    Ok(())
+}
+
+/// Tests error handling for a duplicate skill plan name.
+///
+/// Expected: Err(AppError::SkillPlan(SkillPlanError::DuplicateSkillPlanName))
+#[tokio::test]
+async fn fails_for_duplicate_name() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .build()
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let skill_plan_service = SkillPlanService::new(&test.db);
+    skill_plan_service
+        .create_skill_plan(
+            user_model.id,
+            "Frigates".to_string(),
+            None,
+            "Navigation V".to_string(),
+        )
+        .await
+        .unwrap();
+    let result = skill_plan_service
+        .create_skill_plan(
+            user_model.id,
+            "Frigates".to_string(),
+            None,
+            "Navigation IV".to_string(),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::SkillPlan(SkillPlanError::DuplicateSkillPlanName(
+            _
+        )))
+    ));
+
+    Ok(())
+}
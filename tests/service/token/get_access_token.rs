@@ -0,0 +1,154 @@
+//! Tests for TokenService::get_access_token method.
+//!
+//! This module verifies rejecting characters without a stored refresh token, storing the
+//! refresh token EVE SSO rotates, and deleting tokens only when EVE SSO rejects them.
+
+use bifrost::server::{
+    data::character_token::CharacterTokenRepository,
+    error::{token::TokenError, AppError},
+    service::{eve::esi::EsiProvider, token::TokenService},
+    util::crypto::{ColumnCipher, EncryptionKey},
+};
+use bifrost_test_utils::prelude::*;
+
+use super::{refresh_endpoint, rejected_refresh_endpoint, unavailable_refresh_endpoint};
+
+/// Tests error handling for characters that never logged in with a refresh token.
+///
+/// Expected: Err(AppError::Token(TokenError::NotFound))
+#[tokio::test]
+async fn fails_for_character_without_token() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let result = TokenService::new(&test.db, &esi_provider, &cipher)
+        .get_access_token(95_000_001)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Token(TokenError::NotFound(95_000_001)))
+    ));
+
+    Ok(())
+}
+
+/// Tests exchanging a stored token that EVE SSO rotates.
+///
+/// Expected: Ok with the access token, and the rotated refresh token stored encrypted
+#[tokio::test]
+async fn stores_rotated_token() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .with_mock_endpoint(|server| refresh_endpoint(server, "refresh_token"))
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+    let token_service = TokenService::new(&test.db, &esi_provider, &cipher);
+    token_service
+        .store_token(95_000_001, "refresh_token", &[])
+        .await
+        .unwrap();
+
+    let result = token_service.get_access_token(95_000_001).await;
+
+    assert_eq!(result.unwrap(), "access_token");
+    let tokens = CharacterTokenRepository::new(&test.db).get_all().await?;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+        cipher.decrypt(&tokens[0].refresh_token).unwrap(),
+        "rotated_refresh_token"
+    );
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests that a token EVE SSO rejects with `invalid_grant` is deleted.
+///
+/// Expected: Err(AppError::Token(TokenError::Revoked)) with no token stored afterwards
+#[tokio::test]
+async fn deletes_token_rejected_with_invalid_grant() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .with_mock_endpoint(|server| rejected_refresh_endpoint(server, "refresh_token"))
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+    let token_service = TokenService::new(&test.db, &esi_provider, &cipher);
+    token_service
+        .store_token(95_000_001, "refresh_token", &[])
+        .await
+        .unwrap();
+
+    let result = token_service.get_access_token(95_000_001).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Token(TokenError::Revoked(95_000_001)))
+    ));
+    assert!(CharacterTokenRepository::new(&test.db)
+        .get_all()
+        .await?
+        .is_empty());
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests that a token is kept while EVE SSO is unavailable.
+///
+/// Expected: Err(AppError::Esi) with the token still stored unchanged
+#[tokio::test]
+async fn keeps_token_when_sso_unavailable() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .with_mock_endpoint(|server| unavailable_refresh_endpoint(server, "refresh_token"))
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+    let token_service = TokenService::new(&test.db, &esi_provider, &cipher);
+    token_service
+        .store_token(95_000_001, "refresh_token", &[])
+        .await
+        .unwrap();
+
+    let result = token_service.get_access_token(95_000_001).await;
+
+    assert!(matches!(result, Err(AppError::Esi(_))));
+    let tokens = CharacterTokenRepository::new(&test.db).get_all().await?;
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+        cipher.decrypt(&tokens[0].refresh_token).unwrap(),
+        "refresh_token"
+    );
+
+    Ok(())
+}
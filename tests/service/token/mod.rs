@@ -0,0 +1,63 @@
+use mockito::{Matcher, Mock, ServerGuard};
+
+mod get_access_token;
+mod prune_revoked_tokens;
+mod store_token;
+
+/// Mocks EVE SSO exchanging a refresh token, rotating it to `rotated_<refresh_token>`.
+fn refresh_endpoint(server: &mut ServerGuard, refresh_token: &str) -> Mock {
+    server
+        .mock("POST", "/v2/oauth/token")
+        .match_body(Matcher::UrlEncoded(
+            "refresh_token".to_string(),
+            refresh_token.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "access_token": "access_token",
+                "token_type": "Bearer",
+                "expires_in": 1199,
+                "refresh_token": format!("rotated_{}", refresh_token),
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create()
+}
+
+/// Mocks EVE SSO rejecting a revoked refresh token with `invalid_grant`.
+fn rejected_refresh_endpoint(server: &mut ServerGuard, refresh_token: &str) -> Mock {
+    server
+        .mock("POST", "/v2/oauth/token")
+        .match_body(Matcher::UrlEncoded(
+            "refresh_token".to_string(),
+            refresh_token.to_string(),
+        ))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "error": "invalid_grant",
+                "error_description": "Invalid refresh token. Token missing/expired.",
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create()
+}
+
+/// Mocks EVE SSO being unavailable while exchanging a refresh token.
+fn unavailable_refresh_endpoint(server: &mut ServerGuard, refresh_token: &str) -> Mock {
+    server
+        .mock("POST", "/v2/oauth/token")
+        .match_body(Matcher::UrlEncoded(
+            "refresh_token".to_string(),
+            refresh_token.to_string(),
+        ))
+        .with_status(503)
+        .with_body("Service Unavailable")
+        .expect_at_least(1)
+        .create()
+}
@@ -0,0 +1,63 @@
+//! Tests for TokenService::prune_revoked_tokens method.
+//!
+//! This module verifies deleting the tokens EVE SSO rejects with `invalid_grant` while
+//! rotating the valid tokens and keeping tokens that fail while EVE SSO is unavailable.
+
+use bifrost::server::{
+    data::character_token::CharacterTokenRepository,
+    service::{eve::esi::EsiProvider, token::TokenService},
+    util::crypto::{ColumnCipher, EncryptionKey},
+};
+use bifrost_test_utils::prelude::*;
+
+use super::{refresh_endpoint, rejected_refresh_endpoint, unavailable_refresh_endpoint};
+
+/// Tests pruning a valid, a revoked, and a temporarily failing token.
+///
+/// Expected: Ok(1) with the revoked token deleted, the valid token rotated, and the failing
+/// token kept unchanged
+#[tokio::test]
+async fn deletes_only_rejected_tokens() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .with_mock_character(95_000_002, 98_000_001, None, None)
+        .with_mock_character(95_000_003, 98_000_001, None, None)
+        .with_mock_endpoint(|server| refresh_endpoint(server, "valid_token"))
+        .with_mock_endpoint(|server| rejected_refresh_endpoint(server, "revoked_token"))
+        .with_mock_endpoint(|server| unavailable_refresh_endpoint(server, "failing_token"))
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+    let token_service = TokenService::new(&test.db, &esi_provider, &cipher);
+    for (character_id, refresh_token) in [
+        (95_000_001, "valid_token"),
+        (95_000_002, "revoked_token"),
+        (95_000_003, "failing_token"),
+    ] {
+        token_service
+            .store_token(character_id, refresh_token, &[])
+            .await
+            .unwrap();
+    }
+
+    let result = token_service.prune_revoked_tokens().await;
+
+    assert_eq!(result.unwrap(), 1);
+    let mut remaining: Vec<String> = CharacterTokenRepository::new(&test.db)
+        .get_all()
+        .await?
+        .iter()
+        .map(|token| cipher.decrypt(&token.refresh_token).unwrap())
+        .collect();
+    remaining.sort();
+    assert_eq!(remaining, vec!["failing_token", "rotated_valid_token"]);
+    test.assert_mocks();
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Tests for TokenService::store_token method.
+//!
+//! This module verifies storing a character's refresh token encrypted with its granted scopes,
+//! replacing the token of a previous login, and rejecting characters missing from the database.
+
+use bifrost::server::{
+    data::character_token::CharacterTokenRepository,
+    error::{auth::AuthError, AppError},
+    service::{eve::esi::EsiProvider, token::TokenService},
+    util::crypto::{ColumnCipher, EncryptionKey},
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests storing the refresh tokens of two logins of the same character.
+///
+/// Verifies that the token is never stored in plaintext and that the second login replaces
+/// the token and scopes of the first.
+///
+/// Expected: Ok with a single encrypted token record holding the second login's scopes
+#[tokio::test]
+async fn stores_encrypted_token_replacing_previous_login() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .with_mock_character(95_000_001, 98_000_001, None, None)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let token_service = TokenService::new(&test.db, &esi_provider, &cipher);
+    token_service
+        .store_token(95_000_001, "first_token", &[])
+        .await
+        .unwrap();
+    token_service
+        .store_token(
+            95_000_001,
+            "second_token",
+            &[
+                "esi-skills.read_skills.v1".to_string(),
+                "esi-wallet.read_character_wallet.v1".to_string(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let tokens = CharacterTokenRepository::new(&test.db).get_all().await?;
+
+    assert_eq!(tokens.len(), 1);
+    assert!(!tokens[0].refresh_token.contains("second_token"));
+    assert_eq!(
+        cipher.decrypt(&tokens[0].refresh_token).unwrap(),
+        "second_token"
+    );
+    assert_eq!(
+        tokens[0].scopes,
+        "esi-skills.read_skills.v1 esi-wallet.read_character_wallet.v1"
+    );
+
+    Ok(())
+}
+
+/// Tests error handling for characters missing from the database.
+///
+/// Expected: Err(AppError::Auth(AuthError::CharacterNotFound))
+#[tokio::test]
+async fn fails_for_unknown_character() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostCharacterToken)
+        .build()
+        .await?;
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let cipher = ColumnCipher::new(vec![EncryptionKey::new(1, &[1u8; 32]).unwrap()]);
+
+    let result = TokenService::new(&test.db, &esi_provider, &cipher)
+        .store_token(95_000_001, "refresh_token", &[])
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::CharacterNotFound))
+    ));
+
+    Ok(())
+}
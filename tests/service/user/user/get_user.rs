@@ -35,7 +35,7 @@ async fn returns_user() -> Result<(), TestError> {
 /// Tests that returned UserDto contains correct data.
 ///
 /// Verifies that the user service correctly maps database records to UserDto,
-/// including user ID, main character ID, and main character name.
+/// including user ID, main character ID, main character name, and portrait URL.
 ///
 /// Expected: Ok(Some(UserDto)) with correct fields
 #[tokio::test]
@@ -55,6 +55,7 @@ async fn returns_user_dto_with_correct_fields() -> Result<(), TestError> {
     assert_eq!(user_dto.id, user_model.id);
     assert_eq!(user_dto.character_id, character_model.character_id);
     assert_eq!(user_dto.character_name, character_model.name);
+    assert_eq!(user_dto.portrait_url, "/img/characters/123456789");
 
     Ok(())
 }
@@ -0,0 +1,346 @@
+//! Tests for UserService::merge_users method.
+//!
+//! This module verifies merging a duplicate user into another user, including moving the
+//! removed user's characters, consents, roles, bans, and group memberships, deleting the
+//! removed user, and rejecting invalid merges.
+
+use bifrost::server::{
+    data::{
+        ban::UserBanRepository,
+        consent::UserConsentRepository,
+        group::{GroupMemberRepository, GroupRepository},
+        role::{RoleRepository, UserRoleRepository},
+        user::UserRepository,
+    },
+    error::{auth::AuthError, user::UserError, AppError},
+    service::user::UserService,
+};
+use bifrost_test_utils::prelude::*;
+use chrono::Utc;
+
+/// Tests merging a user with two characters, a consent, and a role into another user.
+///
+/// Expected: Ok with characters, consent, and role moved and the removed user deleted
+#[tokio::test]
+async fn moves_records_and_deletes_removed_user() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .with_table(entity::prelude::BifrostWidget)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .with_table(entity::prelude::BifrostCampaign)
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .with_table(entity::prelude::BifrostApiKey)
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (keep, _, keep_main) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (remove, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    test.user()
+        .insert_mock_character_for_user(remove.id, 3, 1, None, None)
+        .await?;
+    UserConsentRepository::new(&test.db)
+        .grant(remove.id, "assets")
+        .await?;
+    let role = RoleRepository::new(&test.db)
+        .create(
+            "officer".to_string(),
+            String::new(),
+            "manage_members".to_string(),
+            false,
+        )
+        .await?;
+    UserRoleRepository::new(&test.db)
+        .assign(remove.id, role.id)
+        .await?;
+
+    let merge = UserService::new(&test.db)
+        .merge_users(keep.id, remove.id)
+        .await
+        .unwrap();
+
+    assert_eq!(merge.characters_moved, 2);
+    assert_eq!(merge.consents_merged, 1);
+    assert_eq!(merge.roles_merged, 1);
+
+    let user_repo = UserRepository::new(&test.db);
+    assert!(user_repo.get_by_id(remove.id).await?.is_none());
+    let (kept_user, _) = user_repo.get_by_id(keep.id).await?.unwrap();
+    assert_eq!(kept_user.main_character_id, keep_main.id);
+    assert!(
+        UserConsentRepository::new(&test.db)
+            .has_consent(keep.id, "assets")
+            .await?
+    );
+    assert!(
+        UserRoleRepository::new(&test.db)
+            .has_role(keep.id, role.id)
+            .await?
+    );
+
+    Ok(())
+}
+
+/// Tests that merging a banned user into a fresh user keeps the ban.
+///
+/// Expected: Ok with the removed user's active ban moved to the kept user
+#[tokio::test]
+async fn moves_bans_to_kept_user() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .with_table(entity::prelude::BifrostWidget)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .with_table(entity::prelude::BifrostCampaign)
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .with_table(entity::prelude::BifrostApiKey)
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (keep, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (remove, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let now = Utc::now().naive_utc();
+    let ban = UserBanRepository::new(&test.db)
+        .create(remove.id, "Spamming".to_string(), None, now, None)
+        .await?;
+
+    let merge = UserService::new(&test.db)
+        .merge_users(keep.id, remove.id)
+        .await
+        .unwrap();
+
+    assert_eq!(merge.bans_moved, 1);
+    let active_bans = UserBanRepository::new(&test.db)
+        .get_active_by_user_id(keep.id, now)
+        .await?;
+    assert_eq!(active_bans.len(), 1);
+    assert_eq!(active_bans[0].id, ban.id);
+
+    Ok(())
+}
+
+/// Tests merging users in the same groups and a group only the removed user is in.
+///
+/// Expected: Ok with the kept user a member of every group, a membership replacing the kept
+/// user's pending request and the kept user's membership winning over a pending request
+#[tokio::test]
+async fn moves_group_memberships_keeping_stronger_status() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .with_table(entity::prelude::BifrostWidget)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .with_table(entity::prelude::BifrostCampaign)
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .with_table(entity::prelude::BifrostApiKey)
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (keep, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (remove, _, _) = test
+        .user()
+        .insert_user_with_mock_character(2, 1, None, None)
+        .await?;
+    let group_repo = GroupRepository::new(&test.db);
+    let member_repo = GroupMemberRepository::new(&test.db);
+    let mut group_ids = Vec::new();
+    for (name, join_policy) in [
+        ("Logistics", "request"),
+        ("Capitals", "request"),
+        ("Directors", "hidden"),
+    ] {
+        let group = group_repo
+            .create(name.to_string(), String::new(), join_policy.to_string())
+            .await?;
+        group_ids.push(group.id);
+    }
+    let (upgraded, kept, hidden) = (group_ids[0], group_ids[1], group_ids[2]);
+    for (group_id, user_id, status) in [
+        (upgraded, remove.id, "member"),
+        (upgraded, keep.id, "pending"),
+        (kept, remove.id, "pending"),
+        (kept, keep.id, "member"),
+        (hidden, remove.id, "member"),
+    ] {
+        member_repo
+            .create(group_id, user_id, status.to_string(), None)
+            .await?;
+    }
+
+    let merge = UserService::new(&test.db)
+        .merge_users(keep.id, remove.id)
+        .await
+        .unwrap();
+
+    assert_eq!(merge.group_memberships_moved, 2);
+    let memberships = member_repo.get_by_user_id(keep.id).await?;
+    assert_eq!(memberships.len(), 3);
+    assert!(memberships
+        .iter()
+        .all(|membership| membership.status == "member"));
+
+    Ok(())
+}
+
+/// Tests merging a user into itself.
+///
+/// Expected: Err(AppError::User(UserError::MergeIntoSelf))
+#[tokio::test]
+async fn fails_when_merging_user_into_itself() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .with_table(entity::prelude::BifrostWidget)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .with_table(entity::prelude::BifrostCampaign)
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .with_table(entity::prelude::BifrostApiKey)
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (user, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = UserService::new(&test.db)
+        .merge_users(user.id, user.id)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::User(UserError::MergeIntoSelf(_)))
+    ));
+
+    Ok(())
+}
+
+/// Tests merging a user that does not exist.
+///
+/// Expected: Err(AppError::Auth(AuthError::UserNotInDatabase)) with the kept user unchanged
+#[tokio::test]
+async fn fails_when_removed_user_missing() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostUserConsent)
+        .with_table(entity::prelude::BifrostWidget)
+        .with_table(entity::prelude::BifrostFitting)
+        .with_table(entity::prelude::BifrostSkillPlan)
+        .with_table(entity::prelude::BifrostCampaign)
+        .with_table(entity::prelude::BifrostPushSubscription)
+        .with_table(entity::prelude::BifrostScreeningReport)
+        .with_table(entity::prelude::BifrostPage)
+        .with_table(entity::prelude::BifrostPageRevision)
+        .with_table(entity::prelude::BifrostAnnouncement)
+        .with_table(entity::prelude::BifrostAnnouncementRecipient)
+        .with_table(entity::prelude::BifrostReauthCampaign)
+        .with_table(entity::prelude::BifrostReauthCampaignUser)
+        .with_table(entity::prelude::BifrostOnboardingStep)
+        .with_table(entity::prelude::BifrostOnboardingCompletion)
+        .with_table(entity::prelude::BifrostSavedQuery)
+        .with_table(entity::prelude::BifrostApiKey)
+        .with_table(entity::prelude::BifrostTag)
+        .with_table(entity::prelude::BifrostNote)
+        .with_table(entity::prelude::BifrostApprovalRequest)
+        .with_table(entity::prelude::BifrostUserBan)
+        .with_table(entity::prelude::BifrostGroup)
+        .with_table(entity::prelude::BifrostGroupMember)
+        .build()
+        .await?;
+    let (keep, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    let result = UserService::new(&test.db).merge_users(keep.id, 9999).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Auth(AuthError::UserNotInDatabase(9999)))
+    ));
+    assert!(UserRepository::new(&test.db)
+        .get_by_id(keep.id)
+        .await?
+        .is_some());
+
+    Ok(())
+}
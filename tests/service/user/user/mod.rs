@@ -1 +1,2 @@
 pub mod get_user;
+pub mod merge_users;
@@ -0,0 +1,195 @@
+//! Tests for UserCharacterService::get_user_character_page method.
+//!
+//! This module verifies paging through a user's characters, including the page envelope
+//! totals, clamping of out of range page parameters, corporation and alliance filters, and
+//! sorting by character or corporation name.
+
+use bifrost::{
+    model::user::{CharacterDto, UserCharacterQueryDto, UserCharacterSort},
+    server::service::user::user_character::UserCharacterService,
+};
+use bifrost_test_utils::prelude::*;
+use sea_orm::{ActiveModelTrait, ActiveValue};
+
+/// Tests that a page holds its share of the characters with the totals of all of them.
+///
+/// Expected: Ok with the last character on page 2 of 2 and a total of 3
+#[tokio::test]
+async fn returns_page_with_totals() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    for character_id in [2, 3] {
+        test.user()
+            .insert_mock_character_for_user(user_model.id, character_id, 1, None, None)
+            .await?;
+    }
+
+    let page = UserCharacterService::new(&test.db)
+        .get_user_character_page(
+            user_model.id,
+            UserCharacterQueryDto {
+                page: Some(2),
+                per_page: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.total, 3);
+    assert_eq!(page.total_pages, 2);
+    assert_eq!(page.page, 2);
+    assert_eq!(page.per_page, 2);
+    assert_eq!(page.characters.len(), 1);
+
+    Ok(())
+}
+
+/// Tests that out of range page parameters are clamped.
+///
+/// Expected: Ok with page 1 of single character pages
+#[tokio::test]
+async fn clamps_page_parameters() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user_model, _, character_model) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.user()
+        .insert_mock_character_for_user(user_model.id, 2, 1, None, None)
+        .await?;
+
+    let page = UserCharacterService::new(&test.db)
+        .get_user_character_page(
+            user_model.id,
+            UserCharacterQueryDto {
+                page: Some(0),
+                per_page: Some(0),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(page.page, 1);
+    assert_eq!(page.per_page, 1);
+    assert_eq!(page.total_pages, 2);
+    assert_eq!(page.characters.len(), 1);
+    assert_eq!(page.characters[0].id, character_model.character_id);
+
+    Ok(())
+}
+
+/// Tests filtering characters by corporation and by alliance.
+///
+/// Expected: Ok with only the character in the filtered corporation or alliance
+#[tokio::test]
+async fn filters_by_corporation_and_alliance() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    test.eve()
+        .insert_mock_corporation(2, Some(10), None)
+        .await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    test.user()
+        .insert_mock_character_for_user(user_model.id, 2, 2, Some(10), None)
+        .await?;
+    let user_character_service = UserCharacterService::new(&test.db);
+
+    let by_corporation = user_character_service
+        .get_user_character_page(
+            user_model.id,
+            UserCharacterQueryDto {
+                corporation_id: Some(1),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let by_alliance = user_character_service
+        .get_user_character_page(
+            user_model.id,
+            UserCharacterQueryDto {
+                alliance_id: Some(10),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(by_corporation.total, 1);
+    assert_eq!(by_corporation.characters[0].id, 1);
+    assert_eq!(by_alliance.total, 1);
+    assert_eq!(by_alliance.characters[0].id, 2);
+    assert_eq!(by_alliance.characters[0].alliance.as_ref().unwrap().id, 10);
+
+    Ok(())
+}
+
+/// Tests sorting characters by character name and by corporation name.
+///
+/// Expected: Ok with the characters ordered alphabetically by the chosen name
+#[tokio::test]
+async fn sorts_by_name_and_corporation() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user_model, _, character_model_1) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (_, character_model_2) = test
+        .user()
+        .insert_mock_character_for_user(user_model.id, 2, 2, None, None)
+        .await?;
+    for (character_model, name, corporation_name) in [
+        (&character_model_1, "Charlie", "Alpha Corporation"),
+        (&character_model_2, "Bravo", "Zulu Corporation"),
+    ] {
+        entity::eve_character::ActiveModel {
+            id: ActiveValue::Unchanged(character_model.id),
+            name: ActiveValue::Set(name.to_string()),
+            ..Default::default()
+        }
+        .update(&test.db)
+        .await?;
+        entity::eve_corporation::ActiveModel {
+            id: ActiveValue::Unchanged(character_model.corporation_id),
+            name: ActiveValue::Set(corporation_name.to_string()),
+            ..Default::default()
+        }
+        .update(&test.db)
+        .await?;
+    }
+    let user_character_service = UserCharacterService::new(&test.db);
+
+    let by_name = user_character_service
+        .get_user_character_page(
+            user_model.id,
+            UserCharacterQueryDto {
+                sort: Some(UserCharacterSort::Name),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let by_corporation = user_character_service
+        .get_user_character_page(
+            user_model.id,
+            UserCharacterQueryDto {
+                sort: Some(UserCharacterSort::Corporation),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let ids = |characters: &[CharacterDto]| characters.iter().map(|c| c.id).collect::<Vec<_>>();
+    assert_eq!(ids(&by_name.characters), vec![2, 1]);
+    assert_eq!(ids(&by_corporation.characters), vec![1, 2]);
+
+    Ok(())
+}
@@ -84,6 +84,11 @@ async fn returns_character_with_alliance() -> Result<(), TestError> {
     assert_eq!(alliance_dto.name, alliance_model.name);
     assert_eq!(alliance_dto.updated_at, alliance_model.updated_at);
 
+    // Verify image URLs point at the image endpoint
+    assert_eq!(character_dto.portrait_url, "/img/characters/1");
+    assert_eq!(character_dto.corporation.logo_url, "/img/corporations/1");
+    assert_eq!(alliance_dto.logo_url, "/img/alliances/1");
+
     Ok(())
 }
 
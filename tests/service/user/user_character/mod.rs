@@ -1,4 +1,6 @@
+mod get_user_character_page;
 mod get_user_characters;
 mod link_character;
+mod refresh_summary;
 mod set_main_character;
 mod transfer_character;
@@ -0,0 +1,116 @@
+//! Tests for UserCharacterService::refresh_summary and the summary read model.
+//!
+//! This module verifies that ownership changes rebuild the denormalized character summary
+//! and that the user endpoints' service methods read from it.
+
+use bifrost::server::{
+    data::user::summary::UserCharacterSummaryRepository,
+    service::user::{user_character::UserCharacterService, UserService},
+};
+use bifrost_test_utils::prelude::*;
+use sea_orm::TransactionTrait;
+
+/// Tests that linking a character rebuilds the owner's summary.
+///
+/// Expected: Summary contains the linked character with its corporation
+#[tokio::test]
+async fn link_character_rebuilds_summary() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let character_model = test.eve().insert_mock_character(1, 1, None, None).await?;
+    let user_model = test.user().insert_user(character_model.id).await?;
+
+    let txn = test.db.begin().await?;
+    UserCharacterService::link_character(&txn, character_model.id, user_model.id, "owner_hash")
+        .await
+        .expect("Linking should succeed");
+    txn.commit().await?;
+
+    let rows = UserCharacterSummaryRepository::new(&test.db)
+        .get_by_user_id(user_model.id)
+        .await?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].character_id, character_model.character_id);
+    assert_eq!(rows[0].corporation_id, 1);
+    assert!(rows[0].is_main);
+
+    Ok(())
+}
+
+/// Tests that changing the main character is reflected by the user service.
+///
+/// Expected: get_user returns the new main character read from the summary
+#[tokio::test]
+async fn set_main_character_updates_user() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+    let (ownership, alt) = test
+        .user()
+        .insert_mock_character_for_user(user_model.id, 2, 2, None, None)
+        .await?;
+
+    let txn = test.db.begin().await?;
+    UserCharacterService::set_main_character(&txn, user_model.id, ownership)
+        .await
+        .expect("Setting main should succeed");
+    txn.commit().await?;
+
+    let main_row = UserCharacterSummaryRepository::new(&test.db)
+        .get_main_by_user_id(user_model.id)
+        .await?
+        .expect("Summary should contain the main character");
+    assert_eq!(main_row.character_id, alt.character_id);
+
+    let user = UserService::new(&test.db)
+        .get_user(user_model.id)
+        .await
+        .expect("Service call failed")
+        .expect("User should exist");
+    assert_eq!(user.character_id, alt.character_id);
+    assert_eq!(user.character_name, alt.name);
+
+    Ok(())
+}
+
+/// Tests that the characters list is served from the summary once built.
+///
+/// Verifies that the service returns the stored summary rather than the joined tables by
+/// checking that a character linked without rebuilding the summary is not yet listed.
+///
+/// Expected: Only the characters present when the summary was built
+#[tokio::test]
+async fn get_user_characters_reads_summary() -> Result<(), TestError> {
+    let mut test = TestBuilder::new().with_user_tables().build().await?;
+    let (user_model, _, main) = test
+        .user()
+        .insert_user_with_mock_character(1, 1, None, None)
+        .await?;
+
+    UserCharacterService::refresh_summary(&test.db, user_model.id)
+        .await
+        .expect("Refreshing the summary should succeed");
+    test.user()
+        .insert_mock_character_for_user(user_model.id, 2, 2, None, None)
+        .await?;
+
+    let characters = UserCharacterService::new(&test.db)
+        .get_user_characters(user_model.id)
+        .await
+        .expect("Service call failed");
+    assert_eq!(characters.len(), 1);
+    assert_eq!(characters[0].id, main.character_id);
+
+    UserCharacterService::refresh_summary(&test.db, user_model.id)
+        .await
+        .expect("Refreshing the summary should succeed");
+
+    let characters = UserCharacterService::new(&test.db)
+        .get_user_characters(user_model.id)
+        .await
+        .expect("Service call failed");
+    assert_eq!(characters.len(), 2);
+
+    Ok(())
+}
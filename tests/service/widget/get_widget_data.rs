@@ -0,0 +1,126 @@
+//! Tests for WidgetService::get_widget_data method.
+//!
+//! This module verifies that widget data is only served for tokens of existing widgets and
+//! that revoking a widget invalidates its token immediately.
+
+use bifrost::{
+    model::widget::{CreateWidgetDto, WidgetKind},
+    server::{
+        error::{widget::WidgetError, AppError},
+        service::widget::WidgetService,
+    },
+};
+use bifrost_test_utils::prelude::*;
+
+/// Tests resolving a widget token into the corporation's data.
+///
+/// Expected: Ok with the data of the widget's corporation
+#[tokio::test]
+async fn returns_data_for_widget_token() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostWidget)
+        .build()
+        .await?;
+    let corporation_id = 1;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, corporation_id, None, None)
+        .await?;
+
+    let widget_service = WidgetService::new(&test.db);
+    let widget = widget_service
+        .create_widget(
+            user_model.id,
+            CreateWidgetDto {
+                kind: WidgetKind::CorporationMemberCount,
+                corporation_id,
+            },
+        )
+        .await
+        .unwrap();
+
+    let data = widget_service.get_widget_data(&widget.token).await.unwrap();
+
+    assert_eq!(data.kind, WidgetKind::CorporationMemberCount);
+    assert_eq!(data.corporation_id, corporation_id);
+
+    Ok(())
+}
+
+/// Tests that each widget receives its own token.
+///
+/// Expected: Different tokens for two widgets of the same corporation
+#[tokio::test]
+async fn generates_unique_tokens() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostWidget)
+        .build()
+        .await?;
+    let corporation_id = 1;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, corporation_id, None, None)
+        .await?;
+
+    let widget_service = WidgetService::new(&test.db);
+    let widget = CreateWidgetDto {
+        kind: WidgetKind::CorporationMemberCount,
+        corporation_id,
+    };
+    let first = widget_service
+        .create_widget(user_model.id, widget.clone())
+        .await
+        .unwrap();
+    let second = widget_service
+        .create_widget(user_model.id, widget)
+        .await
+        .unwrap();
+
+    assert_ne!(first.token, second.token);
+
+    Ok(())
+}
+
+/// Tests that a revoked widget's token is rejected.
+///
+/// Expected: Err(InvalidToken) after the widget is deleted
+#[tokio::test]
+async fn fails_for_revoked_widget() -> Result<(), TestError> {
+    let mut test = TestBuilder::new()
+        .with_user_tables()
+        .with_table(entity::prelude::BifrostWidget)
+        .build()
+        .await?;
+    let corporation_id = 1;
+    let (user_model, _, _) = test
+        .user()
+        .insert_user_with_mock_character(1, corporation_id, None, None)
+        .await?;
+
+    let widget_service = WidgetService::new(&test.db);
+    let widget = widget_service
+        .create_widget(
+            user_model.id,
+            CreateWidgetDto {
+                kind: WidgetKind::CorporationMemberCount,
+                corporation_id,
+            },
+        )
+        .await
+        .unwrap();
+    widget_service
+        .delete_widget(user_model.id, widget.id)
+        .await
+        .unwrap();
+
+    let result = widget_service.get_widget_data(&widget.token).await;
+
+    assert!(matches!(
+        result,
+        Err(AppError::Widget(WidgetError::InvalidToken))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1 @@
+mod warm_up;
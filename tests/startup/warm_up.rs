@@ -0,0 +1,82 @@
+//! Tests for the startup warm-up phase.
+//!
+//! This module verifies that warm-up loads NPC factions into an empty database, skips ESI
+//! when the stored factions are still fresh, and reports ESI failures without panicking so
+//! startup can continue.
+
+use bifrost::server::{service::eve::esi::EsiProvider, startup};
+use bifrost_test_utils::prelude::*;
+
+use sea_orm::{EntityTrait, PaginatorTrait};
+
+/// Tests warming up with an empty factions table.
+///
+/// Expected: Ok with factions fetched from ESI and stored
+#[tokio::test]
+async fn loads_factions_into_empty_table() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_faction_endpoint(vec![factory::mock_faction(1), factory::mock_faction(2)], 1)
+        .build()
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let result = startup::warm_up(&test.db, &esi_provider).await;
+
+    assert!(result.is_ok());
+    let count = entity::prelude::EveFaction::find().count(&test.db).await?;
+    assert_eq!(count, 2);
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests warming up while stored factions are within ESI's cache.
+///
+/// Expected: Ok without requesting factions from ESI
+#[tokio::test]
+async fn skips_fresh_factions() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_mock_faction(1)
+        .with_faction_endpoint(vec![factory::mock_faction(1)], 0)
+        .build()
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let result = startup::warm_up(&test.db, &esi_provider).await;
+
+    assert!(result.is_ok());
+
+    test.assert_mocks();
+
+    Ok(())
+}
+
+/// Tests warming up while ESI is unavailable.
+///
+/// Expected: Err so startup can log it and continue
+#[tokio::test]
+async fn fails_without_esi() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .build()
+        .await?;
+
+    let esi_provider = EsiProvider::new(test.esi_client.clone());
+    let result = startup::warm_up(&test.db, &esi_provider).await;
+
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -1,14 +1,26 @@
+#[cfg(feature = "server")]
+mod contract;
+
 #[cfg(feature = "server")]
 mod controller;
 
+#[cfg(feature = "server")]
+mod e2e;
+
 #[cfg(feature = "redis-test")]
 mod worker;
 
 #[cfg(feature = "redis-test")]
 mod scheduler;
 
+#[cfg(feature = "perf-test")]
+mod perf;
+
 #[cfg(feature = "server")]
 mod service;
 
+#[cfg(feature = "server")]
+mod startup;
+
 #[cfg(feature = "server")]
 mod util;
@@ -0,0 +1,182 @@
+//! End-to-end test harness driving the HTTP application like a browser.
+//!
+//! `TestApp` serves the API routes behind the session and permission middleware using a
+//! `TestContext` provisioned by `TestBuilder`, and keeps the session cookie between requests so
+//! tests can walk through flows spanning several endpoints, such as the EVE SSO login, with a
+//! single session.
+
+use axum::{
+    body::{Body, Bytes},
+    http::{header, HeaderMap, Method, Request, StatusCode},
+    Router,
+};
+use bifrost::server::{
+    router,
+    util::{permission, rate_limit},
+};
+use bifrost_test_utils::TestContext;
+use serde::de::DeserializeOwned;
+use tower::ServiceExt;
+use tower_sessions::{MemoryStore, SessionManagerLayer};
+
+use crate::util::TestContextExt;
+
+/// Response of a request sent through `TestApp`.
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl TestResponse {
+    /// Deserializes the response body as JSON, panicking if it doesn't match `T`.
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).expect("Response body should be valid JSON")
+    }
+
+    /// Returns the target of a redirect response.
+    pub fn location(&self) -> Option<&str> {
+        self.headers
+            .get(header::LOCATION)
+            .and_then(|value| value.to_str().ok())
+    }
+
+    /// Returns the CSRF state of a redirect to the EVE SSO login page.
+    pub fn sso_state(&self) -> Option<&str> {
+        self.location().and_then(|url| {
+            url.split(['?', '&'])
+                .find_map(|param| param.strip_prefix("state="))
+        })
+    }
+}
+
+/// Application instance for end-to-end tests, acting as a single browser session.
+pub struct TestApp {
+    router: Router,
+    cookie: Option<String>,
+}
+
+impl TestApp {
+    /// Builds the application routes with session and permission middleware for a test context.
+    ///
+    /// Sessions are kept in memory and the cookie is not marked secure since requests are
+    /// sent over plain HTTP.
+    pub fn new(test: &TestContext) -> Self {
+        let session = SessionManagerLayer::new(MemoryStore::default()).with_secure(false);
+        let state = test.into_app_state();
+        let router = router::routes(false)
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                permission::require_admin_permissions,
+            ))
+            .with_state(state)
+            .layer(session);
+
+        Self {
+            router,
+            cookie: None,
+        }
+    }
+
+    /// Builds the application routes like `new`, with the EVE SSO endpoints rate limited.
+    ///
+    /// Requests carry no client IP address, so they are only counted per session.
+    pub fn with_auth_rate_limit(test: &TestContext, limiter: rate_limit::AuthRateLimiter) -> Self {
+        let session = SessionManagerLayer::new(MemoryStore::default()).with_secure(false);
+        let state = test.into_app_state();
+        let router = router::routes(false)
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                permission::require_admin_permissions,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                limiter,
+                rate_limit::limit_auth_requests,
+            ))
+            .with_state(state)
+            .layer(session);
+
+        Self {
+            router,
+            cookie: None,
+        }
+    }
+
+    /// Sends a request with the session cookie, storing any cookie set by the response.
+    pub async fn request(
+        &mut self,
+        method: Method,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> TestResponse {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(cookie) = &self.cookie {
+            request = request.header(header::COOKIE, cookie);
+        }
+        let body = match body {
+            Some(json) => {
+                request = request.header(header::CONTENT_TYPE, "application/json");
+                Body::from(json.to_string())
+            }
+            None => Body::empty(),
+        };
+
+        let response = self
+            .router
+            .clone()
+            .oneshot(request.body(body).expect("Request should be valid"))
+            .await
+            .expect("Router should not fail");
+
+        if let Some(set_cookie) = response.headers().get(header::SET_COOKIE) {
+            // Browsers only send back the name and value of the cookie
+            let cookie = set_cookie
+                .to_str()
+                .expect("Session cookie should be valid ASCII")
+                .split(';')
+                .next()
+                .unwrap_or_default();
+            self.cookie = Some(cookie.to_string());
+        }
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("Response body should be readable");
+
+        TestResponse {
+            status,
+            headers,
+            body,
+        }
+    }
+
+    /// Sends a GET request.
+    pub async fn get(&mut self, uri: &str) -> TestResponse {
+        self.request(Method::GET, uri, None).await
+    }
+
+    /// Logs in through EVE SSO with the next login mocked by `TestBuilder::with_sso_login`.
+    ///
+    /// Follows the redirect to the SSO login page only as far as reading the CSRF state, then
+    /// completes the callback as EVE SSO would after the user authorized the character.
+    ///
+    /// # Arguments
+    /// - `query` - Query string of the login request, e.g. `?intent=change_main`
+    ///
+    /// # Returns
+    /// - `TestResponse` - Response of the callback
+    pub async fn login(&mut self, query: &str) -> TestResponse {
+        let login = self.get(&format!("/api/auth/login{}", query)).await;
+        assert_eq!(login.status, StatusCode::TEMPORARY_REDIRECT);
+
+        let state = login
+            .sso_state()
+            .expect("SSO login URL should contain a CSRF state")
+            .to_string();
+
+        self.get(&format!("/api/auth/callback?state={}&code=code", state))
+            .await
+    }
+}
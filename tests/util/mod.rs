@@ -1,6 +1,7 @@
 #[cfg(feature = "redis-test")]
 pub mod redis;
 
+pub mod e2e;
 pub mod test_utils;
 
 pub use test_utils::TestContextExt;
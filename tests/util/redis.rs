@@ -25,6 +25,29 @@ impl RedisTest {
         })
     }
 
+    /// Create a new RedisTest instance connecting to Redis through a chaos proxy
+    ///
+    /// The returned proxy can inject latency or outages between the pool and Redis. The pool
+    /// reconnects as soon as the proxy accepts connections again, so tests can verify
+    /// recovery. Switch the proxy back to `ChaosMode::Pass` before calling `cleanup`.
+    pub async fn with_chaos_proxy() -> Result<(Self, ChaosProxy), TestError> {
+        let proxy = ChaosProxy::start("127.0.0.1:6379").await?;
+        let redis_config = Config::from_url(&format!("redis://{}", proxy.addr()))?;
+        let reconnect_policy = ReconnectPolicy::new_constant(0, 50);
+        let redis_pool = Pool::new(redis_config, None, None, Some(reconnect_policy), 1)?;
+        redis_pool.init().await?;
+
+        let queue_name = Self::generate_unique_queue_name();
+
+        Ok((
+            RedisTest {
+                redis_pool,
+                queue_name,
+            },
+            proxy,
+        ))
+    }
+
     /// Get the unique Redis queue name for this test instance
     ///
     /// This ensures each test uses a unique queue to prevent collisions
@@ -60,7 +83,14 @@ impl RedisTest {
     /// Call this at the end of your test to ensure cleanup completes.
     /// If not called, cleanup will be attempted on drop but may not complete.
     pub async fn cleanup(self) -> Result<(), TestError> {
-        self.redis_pool.del::<(), _>(&self.queue_name).await?;
+        self.redis_pool
+            .del::<(), _>(vec![
+                self.queue_name.clone(),
+                format!("{}:dead", self.queue_name),
+                format!("{}:dead:next_id", self.queue_name),
+                format!("{}:status", self.queue_name),
+            ])
+            .await?;
         Ok(())
     }
 }
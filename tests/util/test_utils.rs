@@ -2,19 +2,34 @@
 
 use bifrost::server::{
     model::app::AppState,
-    service::eve::esi::EsiProvider,
+    scheduler::config::SchedulerSettings,
+    service::{
+        approval::ApprovalConfig, auth::discord::DiscordConfig, eve::esi::EsiProvider,
+        image::ImageProxyConfig, push::PushConfig, search::SearchConfig,
+        telemetry::TelemetryConfig,
+    },
+    startup::TaskSupervisor,
+    util::{
+        branding::BrandingSettings, crypto::ColumnCipher, read_only::ReadOnlyMode,
+        scope_set::ScopeSets, session_expiry::SessionExpiry,
+    },
     worker::{handler::WorkerJobHandler, pool::WorkerPoolConfig, Worker, WorkerQueue},
 };
 use bifrost_test_utils::TestContext;
 use fred::prelude::*;
 use sea_orm::DatabaseConnection;
 
+/// Creates a Redis pool that is never connected, so commands fail without a Redis server.
+pub fn create_dummy_redis_pool() -> Pool {
+    // Create a Redis config that won't actually connect
+    let config = Config::default();
+    Pool::new(config, None, None, None, 1).expect("Failed to create dummy Redis pool")
+}
+
 /// Creates a dummy Worker instance for testing purposes.
 /// This worker uses a disconnected Redis pool and won't actually process jobs.
 pub fn create_dummy_worker(db: DatabaseConnection, esi_provider: EsiProvider) -> Worker {
-    // Create a Redis config that won't actually connect
-    let config = Config::default();
-    let pool = Pool::new(config, None, None, None, 1).expect("Failed to create dummy Redis pool");
+    let pool = create_dummy_redis_pool();
 
     // Create queue first
     let queue = WorkerQueue::new(pool.clone());
@@ -42,6 +57,21 @@ impl TestContextExt for TestContext {
             db: self.db.clone(),
             esi_provider,
             worker,
+            telemetry: TelemetryConfig::default(),
+            push: PushConfig::default(),
+            search: SearchConfig::default(),
+            image_proxy: ImageProxyConfig::default(),
+            object_storage: None,
+            branding: BrandingSettings::default(),
+            scheduler: SchedulerSettings::default(),
+            approvals: ApprovalConfig::default(),
+            read_only: ReadOnlyMode::default(),
+            supervisor: TaskSupervisor::new(),
+            cipher: ColumnCipher::new(Vec::new()),
+            scope_sets: ScopeSets::default(),
+            discord: DiscordConfig::default(),
+            redis_pool: create_dummy_redis_pool(),
+            session_expiry: SessionExpiry::default(),
         }
     }
 }
@@ -0,0 +1,6 @@
+//! Tests for WorkerJobHandler job methods.
+//!
+//! This module contains tests for job methods whose behavior goes beyond the service they
+//! call, such as splitting batches ESI rejected.
+
+mod update_affiliations;
@@ -0,0 +1,71 @@
+//! Tests for WorkerJobHandler::update_affiliations method.
+//!
+//! This module verifies that the ESI errors of split affiliation batches are recorded against
+//! the shared ESI error budget, and that splitting stops once ESI is error limited.
+
+use bifrost::server::{
+    service::eve::esi::EsiProvider,
+    worker::{handler::WorkerJobHandler, WorkerQueue},
+};
+use bifrost_test_utils::prelude::*;
+
+use crate::util::test_utils::create_dummy_redis_pool;
+
+/// Tests that a split batch stops sending requests once ESI responds with 420.
+///
+/// Expected: Ok with the error limited half and the unsent half failed as retryable, and
+/// dispatch paused by the shared error budget
+#[tokio::test]
+async fn stops_splitting_when_error_limited() -> Result<(), TestError> {
+    let test = TestBuilder::new()
+        .with_table(entity::prelude::EveFaction)
+        .with_table(entity::prelude::EveAlliance)
+        .with_table(entity::prelude::EveCorporation)
+        .with_table(entity::prelude::EveCharacter)
+        .with_table(entity::prelude::BifrostAffiliationHistory)
+        .with_mock_endpoint(|server| {
+            server
+                .mock("POST", "/characters/affiliation")
+                .with_status(404)
+                .with_body(r#"{"error":"Character not found"}"#)
+                .expect(1)
+                .create()
+        })
+        .with_mock_endpoint(|server| {
+            server
+                .mock("POST", "/characters/affiliation")
+                .with_status(420)
+                .with_body(r#"{"error":"This software has exceeded the error limit for ESI."}"#)
+                .expect_at_least(1)
+                .create()
+        })
+        .build()
+        .await?;
+    let handler = WorkerJobHandler::new(
+        test.db.clone(),
+        EsiProvider::new(test.esi_client.clone()),
+        WorkerQueue::new(create_dummy_redis_pool()),
+        false,
+    );
+
+    let result = handler
+        .update_affiliations(vec![95_000_001, 95_000_002, 95_000_003, 95_000_004])
+        .await;
+
+    let outcome = result.unwrap();
+    assert_eq!(outcome.succeeded, 0);
+    assert_eq!(outcome.failed.len(), 4);
+    assert!(outcome.failed.iter().all(|failure| failure.retryable));
+    let mut paused: Vec<i64> = outcome
+        .failed
+        .iter()
+        .filter(|failure| failure.error == "Paused by the ESI error limit")
+        .map(|failure| failure.id)
+        .collect();
+    paused.sort();
+    assert_eq!(paused, vec![95_000_003, 95_000_004]);
+    assert!(handler.esi_rate_limiter().pause_remaining().is_some());
+    test.assert_mocks();
+
+    Ok(())
+}
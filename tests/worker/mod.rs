@@ -1,2 +1,3 @@
+mod handler;
 mod pool;
 pub mod queue;
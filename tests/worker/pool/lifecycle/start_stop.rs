@@ -2,7 +2,11 @@
 //!
 //! This module verifies the behavior of pool lifecycle state transitions, including
 //! starting and stopping the pool, checking running state, idempotent operations,
-//! restart capabilities, and safe handling of edge cases.
+//! restart capabilities, draining in-flight jobs, and safe handling of edge cases.
+
+use std::time::Duration;
+
+use bifrost::server::model::worker::WorkerJob;
 
 use super::*;
 
@@ -210,3 +214,66 @@ async fn stop_without_start_is_safe() {
 
     redis.cleanup().await.expect("Failed to cleanup Redis");
 }
+
+/// Tests that a new pool has no in-flight jobs.
+///
+/// Expected: in_flight_jobs() returns 0 before start() is called
+#[tokio::test]
+async fn no_in_flight_jobs_initially() {
+    let test = TestBuilder::new()
+        .build()
+        .await
+        .expect("Failed to create test setup");
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let pool = create_test_pool(&test, &redis).await;
+
+    assert_eq!(
+        pool.in_flight_jobs(),
+        0,
+        "New pool should have no in-flight jobs"
+    );
+
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests draining in-flight jobs on shutdown.
+///
+/// Verifies that when a drain timeout is configured, stop() waits for jobs that were
+/// already dispatched to finish before returning.
+///
+/// Expected: in_flight_jobs() returns 0 once stop() returns
+#[tokio::test]
+async fn stop_drains_in_flight_jobs() {
+    let test = TestBuilder::new()
+        .build()
+        .await
+        .expect("Failed to create test setup");
+    let redis = RedisTest::new().await.expect("Failed to create Redis test");
+    let queue = setup_test_queue(&redis);
+
+    for character_id in [1, 2, 3] {
+        queue
+            .push(WorkerJob::UpdateCharacterInfo { character_id })
+            .await
+            .expect("Failed to push job to queue");
+    }
+
+    let mut config = test_config();
+    config.drain_timeout_seconds = Some(5);
+    let pool = create_test_pool_with_config(&test, &redis, config).await;
+
+    pool.start().await.expect("Failed to start pool");
+
+    // Give the dispatcher time to pick up the jobs
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    pool.stop().await.expect("Failed to stop pool");
+
+    assert_eq!(
+        pool.in_flight_jobs(),
+        0,
+        "All in-flight jobs should have finished once stop() returns"
+    );
+
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
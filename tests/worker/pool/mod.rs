@@ -20,6 +20,7 @@ pub fn setup_test_queue(redis: &RedisTest) -> WorkerQueue {
     let config = WorkerQueueConfig {
         queue_name: redis.queue_name(),
         job_ttl: std::time::Duration::from_secs(5),
+        status_ttl: std::time::Duration::from_secs(86400),
         cleanup_interval: std::time::Duration::from_millis(50),
     };
 
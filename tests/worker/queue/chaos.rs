@@ -0,0 +1,89 @@
+//! Resilience tests for WorkerQueue against Redis failures.
+//!
+//! This module injects outages and latency between the queue and Redis with a chaos proxy,
+//! verifying that queue operations fail instead of succeeding silently during an outage,
+//! recover once Redis is reachable again, and stay correct under added latency.
+
+use std::time::{Duration, Instant};
+
+use bifrost::server::model::worker::WorkerJob;
+use bifrost_test_utils::prelude::*;
+
+use crate::util::redis::RedisTest;
+
+use super::setup_test_queue;
+
+/// Maximum time to wait for the pool to reconnect after an outage.
+const RECOVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Tests pushing jobs while Redis is unreachable and after it recovers.
+///
+/// Expected: Push does not succeed during the outage, and succeeds again once the proxy
+/// passes traffic
+#[tokio::test]
+async fn push_recovers_after_redis_outage() {
+    let (redis, proxy) = RedisTest::with_chaos_proxy()
+        .await
+        .expect("Failed to create Redis test");
+    let queue = setup_test_queue(&redis);
+
+    proxy.set_mode(ChaosMode::Refuse);
+    let result = tokio::time::timeout(
+        Duration::from_millis(500),
+        queue.push(WorkerJob::UpdateCharacterInfo { character_id: 1 }),
+    )
+    .await;
+    assert!(
+        !matches!(result, Ok(Ok(true))),
+        "Push should not succeed while Redis is unreachable"
+    );
+
+    proxy.set_mode(ChaosMode::Pass);
+    let started = Instant::now();
+    loop {
+        let result = tokio::time::timeout(
+            Duration::from_millis(500),
+            queue.push(WorkerJob::UpdateCharacterInfo { character_id: 2 }),
+        )
+        .await;
+        if let Ok(Ok(added)) = result {
+            assert!(added, "Job should be added after recovery");
+            break;
+        }
+        assert!(
+            started.elapsed() < RECOVERY_TIMEOUT,
+            "Queue did not recover after Redis became reachable"
+        );
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
+
+/// Tests popping jobs while Redis responds slowly.
+///
+/// Expected: Pop waits for the added latency and returns the pushed job
+#[tokio::test]
+async fn pop_succeeds_with_redis_latency() {
+    let (redis, proxy) = RedisTest::with_chaos_proxy()
+        .await
+        .expect("Failed to create Redis test");
+    let queue = setup_test_queue(&redis);
+
+    let job = WorkerJob::UpdateCharacterInfo { character_id: 1 };
+    queue.push(job.clone()).await.expect("Failed to push job");
+
+    proxy.set_mode(ChaosMode::Latency(Duration::from_millis(100)));
+    let started = Instant::now();
+    let popped = queue
+        .pop()
+        .await
+        .expect("Pop should succeed despite latency")
+        .expect("Queue should contain the pushed job");
+
+    assert!(started.elapsed() >= Duration::from_millis(100));
+    assert_eq!(popped.job, job);
+
+    proxy.set_mode(ChaosMode::Pass);
+    redis.cleanup().await.expect("Failed to cleanup Redis");
+}
@@ -0,0 +1,115 @@
+//! Tests for WorkerQueue dead-letter methods.
+//!
+//! This module verifies storing permanently failed jobs in the dead-letter queue, listing
+//! them in order, and removing them.
+
+use bifrost::server::{model::worker::WorkerJob, worker::payload::serialize_job};
+
+use crate::util::redis::RedisTest;
+
+use super::setup_test_queue;
+
+mod dead_letter {
+    use super::*;
+
+    /// Tests that a dead-lettered job can be retrieved with its failure details.
+    ///
+    /// Expected: Entry with the job's payload, error, and attempt count
+    #[tokio::test]
+    async fn stores_job_with_failure_details() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let job = WorkerJob::UpdateCharacterInfo {
+            character_id: 12345,
+        };
+
+        let id = queue
+            .dead_letter(&job, "ESI returned 404", 3)
+            .await
+            .expect("Should dead-letter job");
+
+        let entry = queue
+            .get_dead_letter(id)
+            .await
+            .expect("Should get dead letter")
+            .expect("Dead letter should exist");
+        assert_eq!(entry.id, id);
+        assert_eq!(entry.payload, serialize_job(&job).unwrap());
+        assert_eq!(entry.error, "ESI returned 404");
+        assert_eq!(entry.attempt_count, 3);
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests that dead-lettering does not add the job back to the worker queue.
+    ///
+    /// Expected: Worker queue remains empty
+    #[tokio::test]
+    async fn does_not_queue_job() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        queue
+            .dead_letter(&WorkerJob::UpdateFactionInfo, "Failed", 0)
+            .await
+            .expect("Should dead-letter job");
+
+        assert!(queue.is_empty().await.expect("Should check queue"));
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+}
+
+mod get_dead_letters {
+    use super::*;
+
+    /// Tests that dead-lettered jobs are listed oldest first.
+    ///
+    /// Expected: Entries in the order they were dead-lettered, including duplicates
+    #[tokio::test]
+    async fn lists_entries_in_order() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let job = WorkerJob::UpdateAllianceInfo { alliance_id: 1 };
+        let first = queue.dead_letter(&job, "First", 0).await.unwrap();
+        let second = queue.dead_letter(&job, "Second", 0).await.unwrap();
+
+        let entries = queue
+            .get_dead_letters()
+            .await
+            .expect("Should list dead letters");
+
+        assert_eq!(
+            entries.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            vec![first, second]
+        );
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+}
+
+mod remove_dead_letter {
+    use super::*;
+
+    /// Tests removing an existing dead-lettered job.
+    ///
+    /// Expected: Ok(true), then Ok(false) for the already removed entry
+    #[tokio::test]
+    async fn removes_entry_once() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let id = queue
+            .dead_letter(&WorkerJob::UpdateFactionInfo, "Failed", 0)
+            .await
+            .unwrap();
+
+        assert!(queue.remove_dead_letter(id).await.unwrap());
+        assert!(!queue.remove_dead_letter(id).await.unwrap());
+        assert!(queue.get_dead_letter(id).await.unwrap().is_none());
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+}
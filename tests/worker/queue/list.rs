@@ -0,0 +1,200 @@
+//! Tests for WorkerQueue::list method.
+//!
+//! This module verifies listing queued jobs for inspection. Tests cover empty queues,
+//! ordering by scheduled time, filtering by a scheduled time range, pagination, retry
+//! metadata, and that listed jobs stay in the queue.
+
+use bifrost::server::model::worker::{RetryMetadata, WorkerJob};
+use chrono::{Duration, DurationRound, Utc};
+
+use crate::util::redis::RedisTest;
+
+use super::setup_test_queue;
+
+mod list {
+    use super::*;
+
+    /// Tests that listing an empty queue returns no jobs.
+    ///
+    /// Expected: Empty page with a total of 0
+    #[tokio::test]
+    async fn returns_empty_page_for_empty_queue() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let page = queue.list(.., 0, 10).await.expect("Should list queue");
+        assert_eq!(page.total, 0);
+        assert!(page.jobs.is_empty());
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests that jobs are listed by scheduled time with their timestamps.
+    ///
+    /// Verifies that jobs scheduled out of order are listed earliest first, and that listing
+    /// doesn't remove them from the queue.
+    ///
+    /// Expected: Jobs ordered by scheduled time, queue length unchanged
+    #[tokio::test]
+    async fn lists_jobs_ordered_by_scheduled_time() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let now = Utc::now()
+            .duration_trunc(Duration::milliseconds(1))
+            .unwrap();
+        for (character_id, minutes) in [(1, 30), (2, 10), (3, 20)] {
+            queue
+                .schedule(
+                    WorkerJob::UpdateCharacterInfo { character_id },
+                    now + Duration::minutes(minutes),
+                    None,
+                )
+                .await
+                .expect("Should schedule job");
+        }
+
+        let page = queue.list(.., 0, 10).await.expect("Should list queue");
+        assert_eq!(page.total, 3);
+        let listed: Vec<_> = page
+            .jobs
+            .iter()
+            .map(|job| (job.job.clone(), job.scheduled_at))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![
+                (
+                    WorkerJob::UpdateCharacterInfo { character_id: 2 },
+                    now + Duration::minutes(10)
+                ),
+                (
+                    WorkerJob::UpdateCharacterInfo { character_id: 3 },
+                    now + Duration::minutes(20)
+                ),
+                (
+                    WorkerJob::UpdateCharacterInfo { character_id: 1 },
+                    now + Duration::minutes(30)
+                ),
+            ]
+        );
+        assert_eq!(queue.len().await.expect("Should get queue length"), 3);
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests that only jobs scheduled within the range are listed.
+    ///
+    /// Expected: Only the job inside the inclusive range is listed and counted
+    #[tokio::test]
+    async fn filters_by_scheduled_time_range() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let now = Utc::now();
+        for (character_id, minutes) in [(1, 10), (2, 20), (3, 30)] {
+            queue
+                .schedule(
+                    WorkerJob::UpdateCharacterInfo { character_id },
+                    now + Duration::minutes(minutes),
+                    None,
+                )
+                .await
+                .expect("Should schedule job");
+        }
+
+        let page = queue
+            .list(
+                now + Duration::minutes(15)..=now + Duration::minutes(25),
+                0,
+                10,
+            )
+            .await
+            .expect("Should list queue");
+        assert_eq!(page.total, 1);
+        assert_eq!(
+            page.jobs[0].job,
+            WorkerJob::UpdateCharacterInfo { character_id: 2 }
+        );
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests paging through the queue with an offset and limit.
+    ///
+    /// Expected: Second page holds the next jobs, total counts the whole range
+    #[tokio::test]
+    async fn pages_with_offset_and_limit() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let now = Utc::now();
+        for character_id in 1..=5 {
+            queue
+                .schedule(
+                    WorkerJob::UpdateCharacterInfo { character_id },
+                    now + Duration::minutes(character_id),
+                    None,
+                )
+                .await
+                .expect("Should schedule job");
+        }
+
+        let page = queue.list(.., 2, 2).await.expect("Should list queue");
+        assert_eq!(page.total, 5);
+        let character_ids: Vec<_> = page
+            .jobs
+            .iter()
+            .map(|job| match job.job {
+                WorkerJob::UpdateCharacterInfo { character_id } => character_id,
+                _ => panic!("Unexpected job {}", job.job),
+            })
+            .collect();
+        assert_eq!(character_ids, vec![3, 4]);
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests that retry metadata is listed with retrying jobs.
+    ///
+    /// Expected: Retrying job carries its attempt count, fresh job has no metadata
+    #[tokio::test]
+    async fn includes_retry_metadata() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let now = Utc::now();
+        let retry_metadata = RetryMetadata {
+            attempt_count: 4,
+            first_failed_at: now - Duration::hours(1),
+        };
+        queue
+            .schedule(
+                WorkerJob::UpdateCharacterInfo { character_id: 1 },
+                now,
+                Some(retry_metadata),
+            )
+            .await
+            .expect("Should schedule job");
+        queue
+            .schedule(
+                WorkerJob::UpdateCharacterInfo { character_id: 2 },
+                now + Duration::minutes(1),
+                None,
+            )
+            .await
+            .expect("Should schedule job");
+
+        let page = queue.list(.., 0, 10).await.expect("Should list queue");
+        assert_eq!(
+            page.jobs[0]
+                .retry_metadata
+                .as_ref()
+                .map(|metadata| metadata.attempt_count),
+            Some(4)
+        );
+        assert!(page.jobs[1].retry_metadata.is_none());
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+}
@@ -1,10 +1,14 @@
+pub mod chaos;
 pub mod cleanup;
+pub mod dead_letter;
 pub mod is_empty;
 pub mod len;
+pub mod list;
 pub mod pop;
 pub mod push;
 pub mod schedule;
 pub mod schedule_retry;
+pub mod status;
 
 use bifrost::server::worker::{queue::config::WorkerQueueConfig, WorkerQueue};
 
@@ -14,6 +18,7 @@ pub fn setup_test_queue(redis: &RedisTest) -> WorkerQueue {
     let config = WorkerQueueConfig {
         queue_name: redis.queue_name(),
         job_ttl: std::time::Duration::from_secs(3600),
+        status_ttl: std::time::Duration::from_secs(86400),
         cleanup_interval: std::time::Duration::from_millis(100),
     };
 
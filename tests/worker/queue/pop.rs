@@ -6,6 +6,7 @@
 
 use bifrost::server::model::worker::WorkerJob;
 use chrono::{Duration, Utc};
+use fred::interfaces::SortedSetsInterface;
 
 use crate::util::redis::RedisTest;
 
@@ -512,4 +513,97 @@ mod pop {
 
         redis.cleanup().await.expect("Failed to cleanup Redis");
     }
+
+    /// Tests popping a job queued before payloads were versioned.
+    ///
+    /// Verifies that a bare `WorkerJob` payload left in Redis by an older release is
+    /// upgraded instead of failing deserialization.
+    ///
+    /// Expected: Pop returns the legacy job
+    #[tokio::test]
+    async fn upgrades_unversioned_payload() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+        let job = WorkerJob::UpdateCharacterInfo {
+            character_id: 12345,
+        };
+
+        let legacy = serde_json::to_string(&job).unwrap();
+        let _: () = redis
+            .redis_pool
+            .zadd(
+                &redis.queue_name(),
+                None,
+                None,
+                false,
+                false,
+                (Utc::now().timestamp_millis() as f64, legacy),
+            )
+            .await
+            .expect("Should add legacy payload");
+
+        let popped = queue.pop().await.expect("Pop should succeed");
+        assert_eq!(popped.map(|scheduled| scheduled.job), Some(job));
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests popping a payload that can't be deserialized.
+    ///
+    /// Verifies that the payload is moved to the dead-letter queue instead of being lost.
+    ///
+    /// Expected: Pop returns an error and the payload is dead-lettered
+    #[tokio::test]
+    async fn dead_letters_unreadable_payload() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let payload = "{\"version\":1,\"job\":\"UnknownJob\"}".to_string();
+        let _: () = redis
+            .redis_pool
+            .zadd(
+                &redis.queue_name(),
+                None,
+                None,
+                false,
+                false,
+                (Utc::now().timestamp_millis() as f64, payload.clone()),
+            )
+            .await
+            .expect("Should add payload");
+
+        assert!(queue.pop().await.is_err(), "Pop should fail");
+
+        let dead_letters = queue.get_dead_letters().await.unwrap();
+        assert_eq!(dead_letters.len(), 1);
+        assert_eq!(dead_letters[0].payload, payload);
+        assert!(queue.is_empty().await.unwrap());
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests that pops are recorded in the queue's metrics.
+    ///
+    /// Verifies that pop attempts are counted whether or not they return a job.
+    ///
+    /// Expected: Two pops recorded after popping a job and then an empty queue
+    #[tokio::test]
+    async fn records_pop_metrics() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        queue
+            .push(WorkerJob::UpdateCharacterInfo {
+                character_id: 12345,
+            })
+            .await
+            .expect("Should push job");
+        queue.pop().await.expect("Pop should succeed");
+        queue.pop().await.expect("Pop should succeed");
+
+        let snapshot = queue.metrics().snapshot(0, 0, 1);
+        assert_eq!(snapshot.pops, 2);
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
 }
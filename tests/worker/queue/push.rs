@@ -4,7 +4,7 @@
 //! queue. Tests cover pushing new jobs, duplicate detection across different job types,
 //! timestamp storage verification, and handling of edge cases like large batches.
 
-use bifrost::server::model::worker::WorkerJob;
+use bifrost::server::{model::worker::WorkerJob, worker::payload::serialize_job};
 use chrono::Utc;
 use fred::interfaces::SortedSetsInterface;
 
@@ -429,7 +429,7 @@ mod push {
         let after = Utc::now().timestamp_millis();
 
         // Verify job was stored with a timestamp in the correct range
-        let serialized = serde_json::to_string(&job).expect("Should serialize job");
+        let serialized = serialize_job(&job).expect("Should serialize job");
         let score: Option<f64> = redis
             .redis_pool
             .zscore(&redis.queue_name(), &serialized)
@@ -4,7 +4,7 @@
 //! execution times to the worker queue. Tests cover scheduling at future times, duplicate
 //! detection across different job types, timestamp verification, and interaction with push.
 
-use bifrost::server::model::worker::WorkerJob;
+use bifrost::server::{model::worker::WorkerJob, worker::payload::serialize_job};
 use chrono::{Duration, Utc};
 use fred::interfaces::SortedSetsInterface;
 
@@ -446,7 +446,7 @@ mod schedule {
         assert!(result.is_ok() && result.unwrap(), "Job should be added");
 
         // Verify job was stored with the correct timestamp
-        let serialized = serde_json::to_string(&job).expect("Should serialize job");
+        let serialized = serialize_job(&job).expect("Should serialize job");
         let score: Option<f64> = redis
             .redis_pool
             .zscore(&redis.queue_name(), &serialized)
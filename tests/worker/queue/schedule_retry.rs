@@ -7,7 +7,10 @@
 //! - Cleanup removes orphaned retry metadata
 //! - Multiple jobs can have independent retry metadata
 
-use bifrost::server::model::worker::{RetryMetadata, WorkerJob};
+use bifrost::server::{
+    model::worker::{RetryMetadata, WorkerJob},
+    worker::payload::serialize_job,
+};
 use chrono::{Duration, Utc};
 use fred::interfaces::HashesInterface;
 
@@ -46,7 +49,7 @@ mod schedule_retry {
 
         // Verify retry metadata is stored in hash
         let retry_hash_key = format!("{}:retry", redis.queue_name());
-        let job_key = serde_json::to_string(&job).unwrap();
+        let job_key = serialize_job(&job).unwrap();
         let stored_metadata: Option<String> = redis
             .redis_pool
             .hget(&retry_hash_key, &job_key)
@@ -141,7 +144,7 @@ mod schedule_retry {
 
         // Verify no retry metadata was stored (since job was duplicate)
         let retry_hash_key = format!("{}:retry", redis.queue_name());
-        let job_key = serde_json::to_string(&job).unwrap();
+        let job_key = serialize_job(&job).unwrap();
         let stored_metadata: Option<String> = redis
             .redis_pool
             .hget(&retry_hash_key, &job_key)
@@ -201,7 +204,7 @@ mod schedule_retry {
 
         // Verify retry metadata was removed from hash
         let retry_hash_key = format!("{}:retry", redis.queue_name());
-        let job_key = serde_json::to_string(&job).unwrap();
+        let job_key = serialize_job(&job).unwrap();
         let remaining_metadata: Option<String> = redis
             .redis_pool
             .hget(&retry_hash_key, &job_key)
@@ -303,7 +306,7 @@ mod schedule_retry {
         // Verify all retry metadata is stored correctly
         let retry_hash_key = format!("{}:retry", redis.queue_name());
 
-        let job1_key = serde_json::to_string(&job1).unwrap();
+        let job1_key = serialize_job(&job1).unwrap();
         let stored_metadata1: String = redis
             .redis_pool
             .hget(&retry_hash_key, &job1_key)
@@ -312,7 +315,7 @@ mod schedule_retry {
         let parsed_metadata1: RetryMetadata = serde_json::from_str(&stored_metadata1).unwrap();
         assert_eq!(parsed_metadata1.attempt_count, 1);
 
-        let job2_key = serde_json::to_string(&job2).unwrap();
+        let job2_key = serialize_job(&job2).unwrap();
         let stored_metadata2: String = redis
             .redis_pool
             .hget(&retry_hash_key, &job2_key)
@@ -321,7 +324,7 @@ mod schedule_retry {
         let parsed_metadata2: RetryMetadata = serde_json::from_str(&stored_metadata2).unwrap();
         assert_eq!(parsed_metadata2.attempt_count, 5);
 
-        let job3_key = serde_json::to_string(&job3).unwrap();
+        let job3_key = serialize_job(&job3).unwrap();
         let stored_metadata3: String = redis
             .redis_pool
             .hget(&retry_hash_key, &job3_key)
@@ -361,7 +364,7 @@ mod schedule_retry {
 
         // Verify retry metadata exists
         let retry_hash_key = format!("{}:retry", redis.queue_name());
-        let job_key = serde_json::to_string(&job).unwrap();
+        let job_key = serialize_job(&job).unwrap();
         let metadata_before: Option<String> = redis
             .redis_pool
             .hget(&retry_hash_key, &job_key)
@@ -439,7 +442,7 @@ mod schedule_retry {
 
         // Verify stale job's retry metadata was removed
         let retry_hash_key = format!("{}:retry", redis.queue_name());
-        let stale_key = serde_json::to_string(&stale_job).unwrap();
+        let stale_key = serialize_job(&stale_job).unwrap();
         let stale_metadata_after: Option<String> = redis
             .redis_pool
             .hget(&retry_hash_key, &stale_key)
@@ -451,7 +454,7 @@ mod schedule_retry {
         );
 
         // Verify active job's retry metadata is preserved
-        let active_key = serde_json::to_string(&active_job).unwrap();
+        let active_key = serialize_job(&active_job).unwrap();
         let active_metadata_after: Option<String> = redis
             .redis_pool
             .hget(&retry_hash_key, &active_key)
@@ -516,7 +519,7 @@ mod schedule_retry {
 
         // Verify updated metadata is stored
         let retry_hash_key = format!("{}:retry", redis.queue_name());
-        let job_key = serde_json::to_string(&job).unwrap();
+        let job_key = serialize_job(&job).unwrap();
         let stored_metadata: String = redis
             .redis_pool
             .hget(&retry_hash_key, &job_key)
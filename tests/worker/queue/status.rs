@@ -0,0 +1,222 @@
+//! Tests for WorkerQueue job status methods.
+//!
+//! This module verifies that queued jobs are marked queued, that finished jobs only replace a
+//! running status, that batch jobs record the items that failed, and that stale statuses are
+//! removed by cleanup.
+
+use bifrost::{
+    model::worker::WorkerJobState,
+    server::{
+        model::worker::{JobItemFailure, JobOutcome, WorkerJob},
+        worker::payload::job_id,
+    },
+};
+
+use crate::util::redis::RedisTest;
+
+use super::setup_test_queue;
+
+mod get_status {
+    use super::*;
+
+    /// Tests that pushing a job records it as queued.
+    ///
+    /// Expected: Queued status with the job's description
+    #[tokio::test]
+    async fn marks_pushed_job_queued() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let job = WorkerJob::UpdateCharacterInfo {
+            character_id: 12345,
+        };
+        queue.push(job.clone()).await.expect("Should push job");
+
+        let status = queue
+            .get_status(&job_id(&job).unwrap())
+            .await
+            .expect("Should get status")
+            .expect("Status should exist");
+        assert_eq!(status.state, WorkerJobState::Queued);
+        assert_eq!(status.job, job.to_string());
+        assert_eq!(status.error, None);
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests looking up a job that was never queued.
+    ///
+    /// Expected: None
+    #[tokio::test]
+    async fn returns_none_for_unknown_job() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let status = queue
+            .get_status(&job_id(&WorkerJob::UpdateFactionInfo).unwrap())
+            .await
+            .expect("Should get status");
+        assert!(status.is_none());
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+}
+
+mod finish_status {
+    use super::*;
+
+    /// Tests recording the outcome of a running job.
+    ///
+    /// Expected: Failed status with the error
+    #[tokio::test]
+    async fn records_outcome_of_running_job() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let job = WorkerJob::UpdateAllianceInfo {
+            alliance_id: 99000001,
+        };
+        queue
+            .set_status(&job, WorkerJobState::Running, None)
+            .await
+            .expect("Should set status");
+
+        let recorded = queue
+            .finish_status(
+                &job,
+                WorkerJobState::Failed,
+                Some("ESI returned 404".to_string()),
+            )
+            .await
+            .expect("Should finish status");
+
+        assert!(recorded);
+        let status = queue
+            .get_status(&job_id(&job).unwrap())
+            .await
+            .expect("Should get status")
+            .expect("Status should exist");
+        assert_eq!(status.state, WorkerJobState::Failed);
+        assert_eq!(status.error.as_deref(), Some("ESI returned 404"));
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests that a job requeued while running keeps its queued status.
+    ///
+    /// Verifies that the outcome of the run that rescheduled a job, e.g. for a retry, doesn't
+    /// hide that the job is queued again.
+    ///
+    /// Expected: Status stays queued
+    #[tokio::test]
+    async fn keeps_status_of_requeued_job() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let job = WorkerJob::UpdateCorporationInfo {
+            corporation_id: 98000001,
+        };
+        queue
+            .set_status(&job, WorkerJobState::Running, None)
+            .await
+            .expect("Should set status");
+        queue.push(job.clone()).await.expect("Should push job");
+
+        let recorded = queue
+            .finish_status(&job, WorkerJobState::Succeeded, None)
+            .await
+            .expect("Should finish status");
+
+        assert!(!recorded);
+        let status = queue
+            .get_status(&job_id(&job).unwrap())
+            .await
+            .expect("Should get status")
+            .expect("Status should exist");
+        assert_eq!(status.state, WorkerJobState::Queued);
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+}
+
+mod finish_status_with_outcome {
+    use super::*;
+
+    /// Tests recording the outcome of a batch job with failed items.
+    ///
+    /// Expected: PartiallySucceeded status with a summary and the failed items
+    #[tokio::test]
+    async fn records_failed_items_of_batch_job() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let job = WorkerJob::UpdateAffiliations {
+            character_ids: vec![2114794365, 2117053828, 1],
+        };
+        queue
+            .set_status(&job, WorkerJobState::Running, None)
+            .await
+            .expect("Should set status");
+
+        let failure = JobItemFailure {
+            id: 1,
+            error: "Invalid EVE Online character ID".to_string(),
+            retryable: false,
+        };
+        let outcome = JobOutcome {
+            succeeded: 2,
+            failed: vec![failure.clone()],
+        };
+        let recorded = queue
+            .finish_status_with_outcome(&job, &outcome)
+            .await
+            .expect("Should finish status");
+
+        assert!(recorded);
+        let status = queue
+            .get_status(&job_id(&job).unwrap())
+            .await
+            .expect("Should get status")
+            .expect("Status should exist");
+        assert_eq!(status.state, WorkerJobState::PartiallySucceeded);
+        assert_eq!(
+            status.error.as_deref(),
+            Some("1 of 3 items failed (0 retryable)")
+        );
+        assert_eq!(status.failed_items, vec![failure]);
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+
+    /// Tests recording the outcome of a job without failed items.
+    ///
+    /// Expected: Succeeded status without an error
+    #[tokio::test]
+    async fn records_success_without_failed_items() {
+        let redis = RedisTest::new().await.expect("Failed to create Redis test");
+        let queue = setup_test_queue(&redis);
+
+        let job = WorkerJob::UpdateFactionInfo;
+        queue
+            .set_status(&job, WorkerJobState::Running, None)
+            .await
+            .expect("Should set status");
+
+        let recorded = queue
+            .finish_status_with_outcome(&job, &JobOutcome::default())
+            .await
+            .expect("Should finish status");
+
+        assert!(recorded);
+        let status = queue
+            .get_status(&job_id(&job).unwrap())
+            .await
+            .expect("Should get status")
+            .expect("Status should exist");
+        assert_eq!(status.state, WorkerJobState::Succeeded);
+        assert_eq!(status.error, None);
+        assert!(status.failed_items.is_empty());
+
+        redis.cleanup().await.expect("Failed to cleanup Redis");
+    }
+}